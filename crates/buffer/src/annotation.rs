@@ -0,0 +1,493 @@
+// Chunk: docs/chunks/buffer_annotations - Annotation layer over buffer views
+//!
+//! Line annotations.
+//!
+//! An [`Annotations`] layer lets code that doesn't own a buffer mark up its
+//! lines: underline a range of columns, put an icon in a line's gutter, or
+//! show dimmed text after the end of a line.
+//! Each producer (a problem matcher, a language server, a spell checker)
+//! publishes under its own [`AnnotationSource`], and publishing replaces only
+//! that source's annotations.
+//!
+//! [`AnnotatedView`] wraps any [`BufferView`] and applies a layer to the lines
+//! it returns, so the renderer needs no knowledge of where annotations come
+//! from.
+//!
+//! Publishing records the lines whose annotations changed. The owner drains
+//! them with [`Annotations::take_dirty`] and invalidates those lines the same
+//! way as a buffer edit.
+
+use std::ops::Range;
+
+use crate::buffer_view::{BufferView, Color, CursorInfo, Span, Style, StyledLine, UnderlineStyle};
+use crate::types::{BlockSelection, DirtyLines, Position};
+
+/// Names the producer of a set of annotations, e.g. `"problems"`.
+pub type AnnotationSource = &'static str;
+
+/// An icon drawn in the gutter beside an annotated line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GutterIcon {
+    /// The character drawn as the icon.
+    pub glyph: char,
+    /// The icon's color.
+    pub color: Color,
+}
+
+/// A mark on one line of a buffer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    /// The annotated line (0-indexed).
+    pub line: usize,
+    /// The first annotated column (0-indexed, in characters).
+    pub start_col: usize,
+    /// The column after the last annotated one. When equal to `start_col`,
+    /// the word at `start_col` is annotated, or the single character there
+    /// if it isn't part of a word.
+    pub end_col: usize,
+    /// How the annotated columns are underlined.
+    pub underline: UnderlineStyle,
+    /// The underline color (None = use the text's color).
+    pub color: Option<Color>,
+    /// An icon for the line's gutter.
+    pub gutter: Option<GutterIcon>,
+    // Chunk: docs/chunks/git_blame_inline - End-of-line virtual text
+    /// Text drawn dimmed after the end of the line, in `color`. It is not
+    /// part of the buffer: the cursor can't reach it.
+    pub trailing: Option<String>,
+}
+
+impl Annotation {
+    /// Creates an annotation underlining columns `start_col..end_col` of `line`.
+    pub fn underline(
+        line: usize,
+        start_col: usize,
+        end_col: usize,
+        underline: UnderlineStyle,
+        color: Option<Color>,
+    ) -> Self {
+        Self {
+            line,
+            start_col,
+            end_col,
+            underline,
+            color,
+            gutter: None,
+            trailing: None,
+        }
+    }
+
+    // Chunk: docs/chunks/git_blame_inline - End-of-line virtual text
+    /// Creates an annotation showing `text` dimmed after the end of `line`.
+    pub fn trailing(line: usize, text: impl Into<String>, color: Option<Color>) -> Self {
+        Self {
+            trailing: Some(text.into()),
+            ..Self::underline(line, 0, 0, UnderlineStyle::None, color)
+        }
+    }
+
+    /// Creates an annotation underlining the word at `col` of `line`.
+    pub fn word_at(
+        line: usize,
+        col: usize,
+        underline: UnderlineStyle,
+        color: Option<Color>,
+    ) -> Self {
+        Self::underline(line, col, col, underline, color)
+    }
+
+    /// Adds a gutter icon to the annotation.
+    pub fn with_gutter(mut self, icon: GutterIcon) -> Self {
+        self.gutter = Some(icon);
+        self
+    }
+
+    /// Returns the columns to underline in a line of `chars`.
+    fn columns(&self, chars: &[char]) -> (usize, usize) {
+        if self.end_col > self.start_col || chars.is_empty() {
+            return (self.start_col, self.end_col);
+        }
+        let is_word = |c: char| c.is_alphanumeric() || c == '_';
+        let col = self.start_col.min(chars.len() - 1);
+        let (mut start, mut end) = (col, col + 1);
+        if is_word(chars[col]) {
+            while start > 0 && is_word(chars[start - 1]) {
+                start -= 1;
+            }
+            while end < chars.len() && is_word(chars[end]) {
+                end += 1;
+            }
+        }
+        (start, end)
+    }
+}
+
+/// The annotations on one buffer, grouped by source.
+#[derive(Debug, Clone, Default)]
+pub struct Annotations {
+    /// Each source's annotations, in the order the sources first published.
+    sources: Vec<(AnnotationSource, Vec<Annotation>)>,
+    /// Lines whose annotations changed since the last `take_dirty`.
+    dirty: DirtyLines,
+}
+
+impl Annotations {
+    /// Creates an empty layer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the annotations published by `source`.
+    ///
+    /// The lines of both the old and new annotations are marked dirty.
+    pub fn set(&mut self, source: AnnotationSource, annotations: Vec<Annotation>) {
+        let index = self.sources.iter().position(|(s, _)| *s == source);
+        let old = match index {
+            Some(i) if annotations.is_empty() => self.sources.remove(i).1,
+            Some(i) => std::mem::replace(&mut self.sources[i].1, annotations),
+            None if annotations.is_empty() => Vec::new(),
+            None => {
+                self.sources.push((source, annotations));
+                Vec::new()
+            }
+        };
+        let lines: Vec<usize> = old
+            .iter()
+            .chain(self.from_source(source))
+            .map(|annotation| annotation.line)
+            .collect();
+        for line in lines {
+            self.dirty.merge(DirtyLines::Single(line));
+        }
+    }
+
+    /// Removes the annotations published by `source`.
+    pub fn clear(&mut self, source: AnnotationSource) {
+        self.set(source, Vec::new());
+    }
+
+    /// Returns the annotations published by `source`.
+    pub fn from_source(&self, source: AnnotationSource) -> &[Annotation] {
+        self.sources
+            .iter()
+            .find(|(s, _)| *s == source)
+            .map(|(_, annotations)| annotations.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns true if no source has annotations.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Returns the annotations on `line`, from every source.
+    pub fn on_line(&self, line: usize) -> impl Iterator<Item = &Annotation> {
+        self.sources
+            .iter()
+            .flat_map(|(_, annotations)| annotations.iter())
+            .filter(move |annotation| annotation.line == line)
+    }
+
+    /// Returns the gutter icon for `line`: the first one on the line, taking
+    /// sources in the order they first published.
+    pub fn gutter_icon(&self, line: usize) -> Option<GutterIcon> {
+        self.on_line(line).find_map(|annotation| annotation.gutter)
+    }
+
+    /// Underlines the annotated columns of `line` in its styled text, then
+    /// appends the line's trailing texts.
+    ///
+    /// Trailing texts follow the line's last visible character: blank
+    /// padding at the end of the line, as in a terminal row, is dropped first.
+    pub fn apply(&self, line: usize, styled: &mut StyledLine) {
+        let mut on_line = self.on_line(line).peekable();
+        if on_line.peek().is_none() {
+            return;
+        }
+        let chars: Vec<char> = styled
+            .spans
+            .iter()
+            .flat_map(|span| span.text.chars())
+            .collect();
+        for annotation in on_line {
+            if annotation.underline == UnderlineStyle::None {
+                continue;
+            }
+            let (start, end) = annotation.columns(&chars);
+            styled.restyle_range(start, end, |style| {
+                style.underline = annotation.underline;
+                style.underline_color = annotation.color;
+            });
+        }
+        // Chunk: docs/chunks/terminal_command_status - Trailing text on padded rows
+        let has_trailing = self.on_line(line).any(|a| a.trailing.is_some());
+        if has_trailing {
+            trim_blank_padding(styled);
+        }
+        for annotation in self.on_line(line) {
+            if let Some(text) = &annotation.trailing {
+                let style = Style {
+                    fg: annotation.color.unwrap_or_default(),
+                    dim: true,
+                    italic: true,
+                    ..Style::default()
+                };
+                styled.spans.push(Span::new(text.clone(), style));
+            }
+        }
+    }
+
+    /// Drains the lines whose annotations changed since the last call.
+    pub fn take_dirty(&mut self) -> DirtyLines {
+        std::mem::take(&mut self.dirty)
+    }
+}
+
+// Chunk: docs/chunks/terminal_command_status - Trailing text on padded rows
+/// Drops trailing spaces that draw nothing: those without a background or
+/// decoration of their own.
+fn trim_blank_padding(styled: &mut StyledLine) {
+    while let Some(span) = styled.spans.last_mut() {
+        let style = &span.style;
+        let blank = style.bg == Color::Default
+            && !style.inverse
+            && style.underline == UnderlineStyle::None
+            && !style.strikethrough;
+        if !blank {
+            return;
+        }
+        let kept = span.text.trim_end_matches(' ').len();
+        span.text.truncate(kept);
+        if !span.text.is_empty() {
+            return;
+        }
+        styled.spans.pop();
+    }
+}
+
+/// A view that applies an annotation layer to another view's lines.
+///
+/// Everything but `styled_line` and `gutter_icon` is delegated unchanged.
+pub struct AnnotatedView<'a> {
+    view: &'a dyn BufferView,
+    annotations: &'a Annotations,
+}
+
+impl<'a> AnnotatedView<'a> {
+    /// Creates a view of `view` with `annotations` applied.
+    pub fn new(view: &'a dyn BufferView, annotations: &'a Annotations) -> Self {
+        Self { view, annotations }
+    }
+}
+
+impl BufferView for AnnotatedView<'_> {
+    fn line_count(&self) -> usize {
+        self.view.line_count()
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        let mut styled = self.view.styled_line(line)?;
+        self.annotations.apply(line, &mut styled);
+        Some(styled)
+    }
+
+    // Chunk: docs/chunks/styled_lines_batch - Keep the wrapped view's batching
+    fn styled_lines(&self, lines: Range<usize>) -> Vec<StyledLine> {
+        let start = lines.start;
+        let mut styled = self.view.styled_lines(lines);
+        for (offset, line) in styled.iter_mut().enumerate() {
+            self.annotations.apply(start + offset, line);
+        }
+        styled
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.view.line_len(line)
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        // The wrapped view is borrowed immutably; its owner drains it
+        DirtyLines::None
+    }
+
+    fn is_editable(&self) -> bool {
+        self.view.is_editable()
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        self.view.cursor_info()
+    }
+
+    fn selection_range(&self) -> Option<(Position, Position)> {
+        self.view.selection_range()
+    }
+
+    fn block_selection(&self) -> Option<BlockSelection> {
+        self.view.block_selection()
+    }
+
+    fn gutter_icon(&self, line: usize) -> Option<GutterIcon> {
+        self.annotations
+            .gutter_icon(line)
+            .or_else(|| self.view.gutter_icon(line))
+    }
+
+    fn wraps_lines(&self) -> bool {
+        self.view.wraps_lines()
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer_view::NamedColor;
+    use crate::TextBuffer;
+
+    const RED: Option<Color> = Some(Color::Named(NamedColor::Red));
+
+    fn underlined(line: &StyledLine) -> String {
+        line.spans
+            .iter()
+            .filter(|span| span.style.underline != UnderlineStyle::None)
+            .map(|span| span.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_word_annotation_underlines_whole_word() {
+        let mut layer = Annotations::new();
+        layer.set(
+            "problems",
+            vec![Annotation::word_at(0, 7, UnderlineStyle::Curly, RED)],
+        );
+
+        let mut line = StyledLine::plain("let my_value = 1;");
+        layer.apply(0, &mut line);
+        assert_eq!(underlined(&line), "my_value");
+        let span = line.spans.iter().find(|s| s.text == "my_value").unwrap();
+        assert_eq!(span.style.underline, UnderlineStyle::Curly);
+        assert_eq!(span.style.underline_color, RED);
+
+        // Past the end of the line, the last character is marked
+        layer.set(
+            "problems",
+            vec![Annotation::word_at(0, 40, UnderlineStyle::Curly, RED)],
+        );
+        let mut line = StyledLine::plain("foo();");
+        layer.apply(0, &mut line);
+        assert_eq!(underlined(&line), ";");
+    }
+
+    // Chunk: docs/chunks/git_blame_inline - End-of-line virtual text
+    #[test]
+    fn test_trailing_text_follows_the_line() {
+        let mut layer = Annotations::new();
+        let blame = Annotation::trailing(0, "  Ada, 2 days ago", None);
+        layer.set("blame", vec![blame]);
+
+        let mut line = StyledLine::plain("let x = 1;");
+        layer.apply(0, &mut line);
+        assert_eq!(line.spans.len(), 2);
+        assert_eq!(line.spans[1].text, "  Ada, 2 days ago");
+        assert!(line.spans[1].style.dim);
+        assert_eq!(underlined(&line), "");
+
+        let mut other = StyledLine::plain("let y = 2;");
+        layer.apply(1, &mut other);
+        assert_eq!(other.spans.len(), 1);
+    }
+
+    // Chunk: docs/chunks/terminal_command_status - Trailing text on padded rows
+    #[test]
+    fn test_trailing_text_replaces_blank_padding() {
+        let mut layer = Annotations::new();
+        layer.set("commands", vec![Annotation::trailing(0, "  ✓ 2.3s", None)]);
+
+        // A terminal row: text, a highlighted blank, then padding
+        let highlight = Style {
+            bg: Color::Named(NamedColor::Blue),
+            ..Style::default()
+        };
+        let mut line = StyledLine::new(vec![
+            Span::new("$ ls", Style::default()),
+            Span::new(" ", highlight),
+            Span::new("     ", Style::default()),
+        ]);
+        layer.apply(0, &mut line);
+        let text: Vec<_> = line.spans.iter().map(|span| span.text.as_str()).collect();
+        assert_eq!(text, vec!["$ ls", " ", "  ✓ 2.3s"]);
+
+        // Lines without trailing text keep their padding
+        let mut other = StyledLine::plain("$    ");
+        layer.apply(1, &mut other);
+        assert_eq!(other.spans[0].text, "$    ");
+    }
+
+    #[test]
+    fn test_sources_replace_only_their_own_annotations() {
+        let mut layer = Annotations::new();
+        let spelling = Annotation::underline(0, 0, 3, UnderlineStyle::Dotted, None);
+        let icon = GutterIcon {
+            glyph: '●',
+            color: Color::Named(NamedColor::Red),
+        };
+        let problem = Annotation::word_at(2, 0, UnderlineStyle::Curly, RED).with_gutter(icon);
+        layer.set("spelling", vec![spelling.clone()]);
+        layer.set("problems", vec![problem]);
+        assert_eq!(layer.gutter_icon(2), Some(icon));
+        assert_eq!(layer.gutter_icon(0), None);
+
+        layer.clear("problems");
+        assert_eq!(layer.from_source("spelling"), &[spelling]);
+        assert!(layer.from_source("problems").is_empty());
+        assert_eq!(layer.gutter_icon(2), None);
+
+        layer.clear("spelling");
+        assert!(layer.is_empty());
+    }
+
+    #[test]
+    fn test_set_marks_old_and_new_lines_dirty() {
+        let mut layer = Annotations::new();
+        let at = |line| Annotation::word_at(line, 0, UnderlineStyle::Curly, RED);
+        layer.set("problems", vec![at(4)]);
+        assert_eq!(layer.take_dirty(), DirtyLines::Single(4));
+        assert_eq!(layer.take_dirty(), DirtyLines::None);
+
+        layer.set("problems", vec![at(9)]);
+        assert_eq!(layer.take_dirty(), DirtyLines::Range { from: 4, to: 10 });
+
+        layer.clear("problems");
+        assert_eq!(layer.take_dirty(), DirtyLines::Single(9));
+
+        // Clearing a source with nothing published changes no lines
+        layer.clear("spelling");
+        assert_eq!(layer.take_dirty(), DirtyLines::None);
+    }
+
+    #[test]
+    fn test_annotated_view_applies_layer() {
+        let buffer = TextBuffer::from_str("fn main() {}\nlet x = 1;");
+        let mut layer = Annotations::new();
+        layer.set(
+            "problems",
+            vec![Annotation::underline(1, 4, 5, UnderlineStyle::Single, None)],
+        );
+
+        let view = AnnotatedView::new(&buffer, &layer);
+        assert_eq!(view.line_count(), 2);
+        assert_eq!(underlined(&view.styled_line(1).unwrap()), "x");
+        assert_eq!(underlined(&view.styled_line(0).unwrap()), "");
+        assert!(view.styled_line(2).is_none());
+        assert_eq!(view.cursor_info(), buffer.cursor_info());
+
+        // A batch fetch annotates each line by its own index
+        let lines = view.styled_lines(1..5);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(underlined(&lines[0]), "x");
+    }
+}
@@ -25,7 +25,10 @@
 //! - [`Span`]: A run of text with uniform styling
 //! - [`StyledLine`]: A sequence of spans comprising a single line
 
-use crate::types::{DirtyLines, Position};
+use std::ops::Range;
+
+use crate::annotation::GutterIcon;
+use crate::types::{BlockSelection, DirtyLines, Position};
 
 // =============================================================================
 // Color Types
@@ -228,6 +231,41 @@ impl StyledLine {
     pub fn char_count(&self) -> usize {
         self.spans.iter().map(|s| s.text.chars().count()).sum()
     }
+
+    // Chunk: docs/chunks/buffer_annotations - Restyle a column range
+    /// Applies `restyle` to the characters in columns `start..end`, splitting
+    /// spans where the range begins or ends inside them.
+    ///
+    /// Columns past the end of the line are ignored.
+    pub fn restyle_range(&mut self, start: usize, end: usize, restyle: impl Fn(&mut Style)) {
+        let mut spans = Vec::with_capacity(self.spans.len() + 2);
+        let mut offset = 0;
+        for span in self.spans.drain(..) {
+            let len = span.text.chars().count();
+            let from = start.clamp(offset, offset + len);
+            let to = end.clamp(offset, offset + len);
+            if from == to {
+                spans.push(span);
+            } else {
+                let chars: Vec<char> = span.text.chars().collect();
+                let piece = |range: std::ops::Range<usize>| -> String {
+                    let (a, b) = (range.start - offset, range.end - offset);
+                    chars[a..b].iter().collect()
+                };
+                if from > offset {
+                    spans.push(Span::new(piece(offset..from), span.style));
+                }
+                let mut style = span.style;
+                restyle(&mut style);
+                spans.push(Span::new(piece(from..to), style));
+                if to < offset + len {
+                    spans.push(Span::new(piece(to..offset + len), span.style));
+                }
+            }
+            offset += len;
+        }
+        self.spans = spans;
+    }
 }
 
 // =============================================================================
@@ -322,6 +360,17 @@ pub trait BufferView {
     /// Returns `None` if the line index is out of bounds.
     fn styled_line(&self, line: usize) -> Option<StyledLine>;
 
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of a line range
+    /// Returns the styled lines in `lines`, in order: element `i` is line
+    /// `lines.start + i`. The result stops early at the end of the buffer.
+    ///
+    /// The default calls `styled_line()` once per line. Views that pay a
+    /// fixed cost per call (taking a lock, seeking in a file, preparing a
+    /// highlighter) override it to pay that cost once for the range.
+    fn styled_lines(&self, lines: Range<usize>) -> Vec<StyledLine> {
+        lines.map_while(|line| self.styled_line(line)).collect()
+    }
+
     /// Returns the length of the specified line in characters.
     ///
     /// Returns 0 if the line index is out of bounds.
@@ -352,6 +401,33 @@ pub trait BufferView {
     fn selection_range(&self) -> Option<(Position, Position)> {
         None
     }
+
+    // Chunk: docs/chunks/column_selection - Block selection for the renderer
+    /// Returns the block (column) selection, if one is active.
+    ///
+    /// When present, the renderer draws it instead of `selection_range`.
+    fn block_selection(&self) -> Option<BlockSelection> {
+        None
+    }
+
+    // Chunk: docs/chunks/buffer_annotations - Gutter icons from annotations
+    /// Returns the icon to draw in the gutter beside `line`, if any.
+    ///
+    /// Views without annotations have none.
+    fn gutter_icon(&self, _line: usize) -> Option<GutterIcon> {
+        None
+    }
+
+    // Chunk: docs/chunks/file_view_mode - Views drawn one row per line
+    /// Returns whether lines longer than the pane is wide wrap onto further
+    /// screen rows.
+    ///
+    /// Views that don't wrap are drawn one row per line, cut off at the
+    /// pane's edge, so the first visible line is found without measuring
+    /// every line above it.
+    fn wraps_lines(&self) -> bool {
+        true
+    }
 }
 
 // =============================================================================
@@ -546,6 +622,22 @@ mod tests {
         assert!(!line.is_empty());
     }
 
+    #[test]
+    fn test_styled_line_restyle_range_splits_spans() {
+        let mut line = StyledLine::new(vec![Span::plain("let "), Span::plain("value = 1;")]);
+        line.restyle_range(2, 9, |style| style.bold = true);
+
+        let texts: Vec<&str> = line.spans.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["le", "t ", "value", " = 1;"]);
+        let bold: Vec<bool> = line.spans.iter().map(|s| s.style.bold).collect();
+        assert_eq!(bold, vec![false, true, true, false]);
+
+        // A range past the end of the line changes nothing
+        let before = line.clone();
+        line.restyle_range(20, 30, |style| style.italic = true);
+        assert_eq!(line, before);
+    }
+
     // ==================== CursorShape Tests ====================
 
     #[test]
@@ -644,6 +736,19 @@ mod tests {
         assert!(boxed.styled_line(2).is_none());
     }
 
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of a line range
+    #[test]
+    fn test_styled_lines_stops_at_the_end() {
+        let mock = MockBufferView::new(vec!["one", "two", "three"]);
+        let view: &dyn BufferView = &mock;
+
+        let lines = view.styled_lines(1..3);
+        assert_eq!(lines, vec![StyledLine::plain("two"), StyledLine::plain("three")]);
+        assert_eq!(view.styled_lines(2..10), vec![StyledLine::plain("three")]);
+        assert!(view.styled_lines(5..8).is_empty());
+        assert!(view.styled_lines(1..1).is_empty());
+    }
+
     #[test]
     fn test_buffer_view_object_safe_ref() {
         // Verify we can use &dyn BufferView
@@ -0,0 +1,132 @@
+// Chunk: docs/chunks/unicode_width_awareness - Display column arithmetic for cursor movement
+//!
+//! Display (on-screen) column arithmetic for buffer lines.
+//!
+//! `Position.col` counts chars, but the renderer lays chars out by display
+//! width: tabs run to the next tab stop, East Asian wide characters and emoji
+//! take two columns, and combining marks take none. Vertical cursor movement
+//! keeps the display column rather than the char column so the cursor stays
+//! visually above or below where it started.
+//!
+//! The editor's tab-aware layout helpers delegate to `char_display_width`, so
+//! the buffer and the renderer always agree on widths.
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::grapheme::snap_to_grapheme_boundary;
+
+/// The number of columns per tab stop.
+pub const TAB_WIDTH: usize = 4;
+
+/// Returns the display width of `c` when it starts at `display_col`.
+///
+/// - Tabs span to the next multiple of `TAB_WIDTH`.
+/// - Wide characters (CJK, emoji) return 2.
+/// - Control and zero-width characters (including combining marks) return 0.
+/// - Everything else returns 1.
+#[inline]
+pub fn char_display_width(c: char, display_col: usize) -> usize {
+    if c == '\t' {
+        TAB_WIDTH - display_col % TAB_WIDTH
+    } else {
+        c.width().unwrap_or(0)
+    }
+}
+
+/// Returns the display column at which char offset `char_col` starts.
+///
+/// Offsets past the end return the line's total display width.
+pub fn display_col(chars: &[char], char_col: usize) -> usize {
+    let mut col = 0;
+    for &c in chars.iter().take(char_col) {
+        col += char_display_width(c, col);
+    }
+    col
+}
+
+/// Returns the char offset whose display column is closest to `target` from
+/// the left, snapped to a grapheme cluster boundary.
+///
+/// A target inside a wide character or tab maps to the start of that
+/// character. A target past the end of the line maps to the line end.
+pub fn char_col_for_display_col(chars: &[char], target: usize) -> usize {
+    let mut col = 0;
+    for (i, &c) in chars.iter().enumerate() {
+        let width = char_display_width(c, col);
+        if target < col + width {
+            return snap_to_grapheme_boundary(chars, i);
+        }
+        col += width;
+    }
+    chars.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn test_char_display_width() {
+        assert_eq!(char_display_width('a', 0), 1);
+        assert_eq!(char_display_width('中', 0), 2);
+        assert_eq!(char_display_width('😀', 0), 2);
+        assert_eq!(char_display_width('\u{0301}', 1), 0);
+        assert_eq!(char_display_width('\t', 0), 4);
+        assert_eq!(char_display_width('\t', 3), 1);
+    }
+
+    #[test]
+    fn test_display_col_ascii() {
+        let line = chars("hello");
+        assert_eq!(display_col(&line, 0), 0);
+        assert_eq!(display_col(&line, 3), 3);
+        assert_eq!(display_col(&line, 10), 5);
+    }
+
+    #[test]
+    fn test_display_col_wide_and_combining() {
+        // 中 (2) + e (1) + combining acute (0) + x (1)
+        let line = chars("中e\u{0301}x");
+        assert_eq!(display_col(&line, 1), 2);
+        assert_eq!(display_col(&line, 3), 3);
+        assert_eq!(display_col(&line, 4), 4);
+    }
+
+    #[test]
+    fn test_char_col_for_display_col_wide() {
+        let line = chars("中文ab");
+        assert_eq!(char_col_for_display_col(&line, 0), 0);
+        // Inside the first wide char maps to its start
+        assert_eq!(char_col_for_display_col(&line, 1), 0);
+        assert_eq!(char_col_for_display_col(&line, 2), 1);
+        assert_eq!(char_col_for_display_col(&line, 4), 2);
+        assert_eq!(char_col_for_display_col(&line, 99), 4);
+    }
+
+    #[test]
+    fn test_char_col_for_display_col_skips_combining_marks() {
+        let line = chars("e\u{0301}x");
+        // Display column 1 is 'x', after the whole é cluster
+        assert_eq!(char_col_for_display_col(&line, 1), 2);
+    }
+
+    #[test]
+    fn test_char_col_for_display_col_never_splits_cluster() {
+        // Man ZWJ woman: the woman emoji starts at display column 2 but is
+        // inside the cluster
+        let line = chars("👨\u{200D}👩x");
+        assert_eq!(char_col_for_display_col(&line, 2), 0);
+        assert_eq!(char_col_for_display_col(&line, 4), 3);
+    }
+
+    #[test]
+    fn test_char_col_for_display_col_tab() {
+        let line = chars("\tb");
+        assert_eq!(char_col_for_display_col(&line, 2), 0);
+        assert_eq!(char_col_for_display_col(&line, 4), 1);
+    }
+}
@@ -232,6 +232,22 @@ pub fn is_grapheme_boundary(chars: &[char], char_offset: usize) -> bool {
     char_offset == chars.len()
 }
 
+// Chunk: docs/chunks/unicode_width_awareness - Snap arbitrary columns to cluster starts
+/// Snaps `char_offset` to the start of the grapheme cluster containing it.
+///
+/// Offsets already on a boundary (including 0 and the line end) are returned
+/// unchanged. Offsets past the end are clamped to `chars.len()`. Used wherever
+/// a column comes from outside the cursor-movement code (mouse clicks, vertical
+/// movement) so the cursor never lands inside a cluster.
+pub fn snap_to_grapheme_boundary(chars: &[char], char_offset: usize) -> usize {
+    let char_offset = char_offset.min(chars.len());
+    if is_grapheme_boundary(chars, char_offset) {
+        char_offset
+    } else {
+        grapheme_boundary_left(chars, char_offset)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -458,4 +474,37 @@ mod tests {
         assert_eq!(grapheme_len_before(&chars, 10), 1);
         assert_eq!(grapheme_len_at(&chars, 10), 0);
     }
+
+    // ==================== Snapping Tests ====================
+    // Chunk: docs/chunks/unicode_width_awareness
+
+    #[test]
+    fn test_snap_on_boundary_unchanged() {
+        let chars: Vec<char> = "héllo".chars().collect();
+        assert_eq!(snap_to_grapheme_boundary(&chars, 0), 0);
+        assert_eq!(snap_to_grapheme_boundary(&chars, 2), 2);
+        assert_eq!(snap_to_grapheme_boundary(&chars, 5), 5);
+    }
+
+    #[test]
+    fn test_snap_inside_combining_sequence() {
+        // "e" + combining acute accent
+        let chars: Vec<char> = "ae\u{0301}b".chars().collect();
+        assert_eq!(snap_to_grapheme_boundary(&chars, 2), 1);
+        assert_eq!(snap_to_grapheme_boundary(&chars, 3), 3);
+    }
+
+    #[test]
+    fn test_snap_inside_zwj_sequence() {
+        let chars: Vec<char> = "👨\u{200D}👩x".chars().collect();
+        assert_eq!(snap_to_grapheme_boundary(&chars, 1), 0);
+        assert_eq!(snap_to_grapheme_boundary(&chars, 2), 0);
+        assert_eq!(snap_to_grapheme_boundary(&chars, 3), 3);
+    }
+
+    #[test]
+    fn test_snap_past_end_clamps() {
+        let chars: Vec<char> = "ab".chars().collect();
+        assert_eq!(snap_to_grapheme_boundary(&chars, 10), 2);
+    }
 }
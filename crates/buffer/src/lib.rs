@@ -22,6 +22,12 @@
 //! [`Span`], and [`StyledLine`] enable rich text rendering with terminal-grade
 //! attributes.
 //!
+//! # Annotations
+//!
+//! An [`Annotations`] layer carries underlines and gutter icons published by
+//! code that doesn't own the buffer, such as a problem matcher. Wrapping a view
+//! in an [`AnnotatedView`] applies them to its styled lines.
+//!
 //! # Example
 //!
 //! ```
@@ -51,7 +57,11 @@
 //! - `DirtyLines::FromLineToEnd(line)` - All lines from `line` to the end changed
 //!   (used when lines are split or joined)
 
+// Chunk: docs/chunks/buffer_annotations - Annotation layer over buffer views
+mod annotation;
 mod buffer_view;
+// Chunk: docs/chunks/unicode_width_awareness - Display column arithmetic
+mod display_width;
 mod gap_buffer;
 // Chunk: docs/chunks/grapheme_cluster_awareness - Grapheme cluster boundary detection
 mod grapheme;
@@ -59,11 +69,17 @@ mod line_index;
 mod text_buffer;
 mod types;
 
+// Chunk: docs/chunks/buffer_annotations - Export the annotation layer
+pub use annotation::{AnnotatedView, Annotation, AnnotationSource, Annotations, GutterIcon};
 // BufferView types
 pub use buffer_view::{
     BufferView, Color, CursorInfo, CursorShape, NamedColor, Span, Style, StyledLine, UnderlineStyle,
 };
+// Chunk: docs/chunks/unicode_width_awareness - Export width and grapheme helpers for layout
+pub use display_width::{char_col_for_display_col, char_display_width, display_col, TAB_WIDTH};
+pub use grapheme::snap_to_grapheme_boundary;
 // Chunk: docs/chunks/unicode_ime_input - Export MarkedTextState for IME support
 pub use text_buffer::{MarkedTextState, TextBuffer};
 // Chunk: docs/chunks/incremental_parse - Export EditInfo and MutationResult for incremental parsing
-pub use types::{DirtyLines, EditInfo, MutationResult, Position};
+// Chunk: docs/chunks/column_selection - Export BlockSelection for the renderer
+pub use types::{BlockSelection, DirtyLines, EditInfo, MutationResult, Position};
@@ -12,10 +12,14 @@
 use crate::buffer_view::{BufferView, CursorInfo, Span, Style, StyledLine, UnderlineStyle};
 use crate::gap_buffer::GapBuffer;
 // Chunk: docs/chunks/grapheme_cluster_awareness - Import grapheme cluster boundary helpers
-use crate::grapheme::{grapheme_boundary_left, grapheme_boundary_right, grapheme_len_at, grapheme_len_before, is_grapheme_boundary};
+use crate::grapheme::{grapheme_boundary_left, grapheme_boundary_right, grapheme_len_at, grapheme_len_before, is_grapheme_boundary, snap_to_grapheme_boundary};
+// Chunk: docs/chunks/unicode_width_awareness - Display columns for vertical movement
+use crate::display_width::{char_col_for_display_col, display_col};
 use crate::line_index::LineIndex;
 // Chunk: docs/chunks/incremental_parse - Import EditInfo and MutationResult for tracked mutations
 use crate::types::{DirtyLines, EditInfo, MutationResult, Position};
+// Chunk: docs/chunks/column_selection - Rectangular selection
+use crate::types::BlockSelection;
 
 // Chunk: docs/chunks/word_triclass_boundaries - Three-class word boundary classification
 // Spec: docs/trunk/SPEC.md#word-model
@@ -144,6 +148,10 @@ pub struct TextBuffer {
     /// Selection anchor position. When `Some`, the selection spans from anchor to cursor.
     /// The anchor may come before or after the cursor (both directions are valid).
     selection_anchor: Option<Position>,
+    // Chunk: docs/chunks/column_selection - Rectangular selection
+    /// Block (column) selection. When `Some`, it takes the place of the
+    /// anchor-cursor selection and edits apply to every line of the block.
+    block_selection: Option<BlockSelection>,
     /// IME marked text state. When `Some`, the marked text is being composed.
     /// The marked text is rendered with an underline to indicate it's uncommitted.
     marked_text: Option<MarkedTextState>,
@@ -163,6 +171,7 @@ impl TextBuffer {
             line_index: LineIndex::new(),
             cursor: Position::default(),
             selection_anchor: None,
+            block_selection: None,
             marked_text: None,
             dirty_lines: DirtyLines::None,
             #[cfg(debug_assertions)]
@@ -185,6 +194,7 @@ impl TextBuffer {
             line_index,
             cursor: Position::default(),
             selection_anchor: None,
+            block_selection: None,
             marked_text: None,
             dirty_lines: DirtyLines::None,
             #[cfg(debug_assertions)]
@@ -294,6 +304,7 @@ impl TextBuffer {
         let line = pos.line.min(self.line_count().saturating_sub(1));
         let col = pos.col.min(self.line_len(line));
         self.selection_anchor = Some(Position::new(line, col));
+        self.block_selection = None;
     }
 
     /// Sets the selection anchor to the current cursor position.
@@ -301,11 +312,15 @@ impl TextBuffer {
     /// This is a convenience method for starting a selection at the cursor.
     pub fn set_selection_anchor_at_cursor(&mut self) {
         self.selection_anchor = Some(self.cursor);
+        self.block_selection = None;
     }
 
     /// Clears the selection anchor (no selection).
+    ///
+    /// Also ends any block selection.
     pub fn clear_selection(&mut self) {
         self.selection_anchor = None;
+        self.block_selection = None;
     }
 
     // Chunk: docs/chunks/text_selection_rendering - Exposes selection anchor position for selection_range() calculation
@@ -315,7 +330,12 @@ impl TextBuffer {
     }
 
     /// Returns true if there is an active selection (anchor is set and differs from cursor).
+    ///
+    /// A block selection counts if it covers at least one column.
     pub fn has_selection(&self) -> bool {
+        if let Some(block) = self.block_selection {
+            return !block.is_empty();
+        }
         match self.selection_anchor {
             Some(anchor) => anchor != self.cursor,
             None => false,
@@ -342,6 +362,24 @@ impl TextBuffer {
     ///
     /// Returns `None` if there is no active selection.
     pub fn selected_text(&self) -> Option<String> {
+        // Chunk: docs/chunks/column_selection - A block's text is its rows, one per line
+        if let Some(block) = self.block_selection {
+            if block.is_empty() {
+                return None;
+            }
+            let rows: Vec<String> = self
+                .block_line_ranges()
+                .into_iter()
+                .map(|(line, range)| {
+                    self.line_content(line)
+                        .chars()
+                        .skip(range.start)
+                        .take(range.len())
+                        .collect()
+                })
+                .collect();
+            return Some(rows.join("\n"));
+        }
         let (start, end) = self.selection_range()?;
         let start_offset = self.position_to_offset(start);
         let end_offset = self.position_to_offset(end);
@@ -353,6 +391,7 @@ impl TextBuffer {
     /// Sets the anchor to the start of the buffer and cursor to the end.
     pub fn select_all(&mut self) {
         self.selection_anchor = Some(Position::new(0, 0));
+        self.block_selection = None;
         // Set cursor to buffer end without clearing selection
         let last_line = self.line_count().saturating_sub(1);
         let last_col = self.line_len(last_line);
@@ -406,6 +445,25 @@ impl TextBuffer {
         true
     }
 
+    // Chunk: docs/chunks/triple_click_drag_autoscroll - Triple-click line selection
+    /// Selects the whole of `line`, including its trailing newline.
+    ///
+    /// The anchor goes to the start of the line and the cursor to the start of
+    /// the next line, so deleting or replacing the selection removes the line.
+    /// On the last line, which has no newline, the cursor goes to the line end.
+    /// `line` is clamped to the buffer.
+    pub fn select_line_at(&mut self, line: usize) {
+        let last_line = self.line_count().saturating_sub(1);
+        let line = line.min(last_line);
+        self.block_selection = None;
+        self.selection_anchor = Some(Position::new(line, 0));
+        self.cursor = if line < last_line {
+            Position::new(line + 1, 0)
+        } else {
+            Position::new(line, self.line_len(line))
+        };
+    }
+
     /// Deletes the selected text and places the cursor at the start of the former selection.
     ///
     /// Returns `DirtyLines::None` if there is no active selection.
@@ -462,6 +520,357 @@ impl TextBuffer {
         self.accumulate_dirty(dirty)
     }
 
+    // ==================== Block Selection ====================
+    // Chunk: docs/chunks/column_selection - Rectangular selection and per-line edits
+
+    /// Starts or updates a block (column) selection.
+    ///
+    /// `anchor` and `head` are (line, display column). Lines are clamped to the
+    /// buffer; columns are not, so the block may extend past short lines.
+    /// Replaces any anchor-cursor selection and puts the cursor at the head,
+    /// or at the end of the head's line if the head is past it.
+    pub fn set_block_selection(&mut self, anchor: Position, head: Position) {
+        let last_line = self.line_count().saturating_sub(1);
+        let anchor = Position::new(anchor.line.min(last_line), anchor.col);
+        let head = Position::new(head.line.min(last_line), head.col);
+        self.selection_anchor = None;
+        self.block_selection = Some(BlockSelection::new(anchor, head));
+        self.cursor = Position::new(head.line, self.char_col_at_display_col(head.line, head.col));
+    }
+
+    /// Returns the block selection, if one is active.
+    pub fn block_selection(&self) -> Option<BlockSelection> {
+        self.block_selection
+    }
+
+    /// Returns the character range the block covers on each of its lines,
+    /// top to bottom.
+    ///
+    /// Lines ending before the block's left edge get an empty range at their
+    /// end. Wide characters only partly inside the block are left out.
+    pub fn block_line_ranges(&self) -> Vec<(usize, std::ops::Range<usize>)> {
+        let Some(block) = self.block_selection else {
+            return Vec::new();
+        };
+        (block.top()..=block.bottom())
+            .map(|line| {
+                let chars: Vec<char> = self.line_content(line).chars().collect();
+                let start = char_col_for_display_col(&chars, block.left());
+                let end = char_col_for_display_col(&chars, block.right()).max(start);
+                (line, start..end)
+            })
+            .collect()
+    }
+
+    /// Replaces the block on every line with `text`, leaving a column of
+    /// carets after the inserted text.
+    ///
+    /// Text with as many lines as the block puts one line on each row, so a
+    /// copied block pastes back as a block. Other multi-line text ends the
+    /// block and is inserted once at the cursor.
+    pub fn block_insert_str(&mut self, text: &str) -> MutationResult {
+        let Some(block) = self.block_selection else {
+            return self.insert_str_tracked(text);
+        };
+        let row_count = block.bottom() - block.top() + 1;
+        let rows: Vec<&str> = if !text.contains('\n') {
+            vec![text; row_count]
+        } else {
+            let lines: Vec<&str> = text.split('\n').collect();
+            if lines.len() != row_count {
+                self.block_selection = None;
+                return self.insert_str_tracked(text);
+            }
+            lines
+        };
+
+        let head_line = block.head.line;
+        let mut head_col = self.cursor.col;
+        let result = self.edit_block_rows(|buffer, row, line, range| {
+            let start = range.start;
+            buffer.replace_in_line(line, range, rows[row]);
+            if line == head_line {
+                head_col = start + rows[row].chars().count();
+            }
+        });
+        self.collapse_block_at(head_line, head_col);
+        result
+    }
+
+    /// Deletes the block's contents on every line, or for a column of carets,
+    /// the character before each caret.
+    ///
+    /// Lines that end before the carets are left alone.
+    pub fn block_delete_backward(&mut self) -> MutationResult {
+        self.block_delete(true)
+    }
+
+    /// Deletes the block's contents on every line, or for a column of carets,
+    /// the character after each caret.
+    pub fn block_delete_forward(&mut self) -> MutationResult {
+        self.block_delete(false)
+    }
+
+    fn block_delete(&mut self, backward: bool) -> MutationResult {
+        let Some(block) = self.block_selection else {
+            return MutationResult::none();
+        };
+        let head_line = block.head.line;
+        let mut head_col = self.cursor.col;
+
+        if !block.is_empty() {
+            let result = self.edit_block_rows(|buffer, _, line, range| {
+                let start = range.start;
+                buffer.replace_in_line(line, range, "");
+                if line == head_line {
+                    head_col = start;
+                }
+            });
+            self.collapse_block_at(head_line, head_col);
+            return result;
+        }
+
+        // Only lines that reach the caret column take part
+        let left = block.left();
+        let result = self.edit_block_rows(|buffer, _, line, range| {
+            let chars: Vec<char> = buffer.line_content(line).chars().collect();
+            if display_col(&chars, range.start) != left {
+                return;
+            }
+            let target = if backward {
+                let len = grapheme_len_before(&chars, range.start);
+                range.start - len..range.start
+            } else {
+                range.start..range.start + grapheme_len_at(&chars, range.start)
+            };
+            if line == head_line {
+                head_col = target.start;
+            }
+            buffer.replace_in_line(line, target, "");
+        });
+        self.collapse_block_at(head_line, head_col);
+        result
+    }
+
+    /// Applies `edit` to each row of the block, bottom to top so earlier rows'
+    /// positions stay valid, and reports the change as one edit spanning the
+    /// block's lines.
+    ///
+    /// `edit` receives the row index (0 for the top line), the line and the
+    /// block's character range on it.
+    fn edit_block_rows<F>(&mut self, mut edit: F) -> MutationResult
+    where
+        F: FnMut(&mut Self, usize, usize, std::ops::Range<usize>),
+    {
+        let ranges = self.block_line_ranges();
+        let (top, bottom) = match (ranges.first(), ranges.last()) {
+            (Some((top, _)), Some((bottom, _))) => (*top, *bottom),
+            _ => return MutationResult::none(),
+        };
+
+        let start_byte = self.byte_offset_at(top, 0);
+        let old_end_col = self.line_len(bottom);
+        let old_end_byte = self.byte_offset_at(bottom, old_end_col);
+        let old_rows: Vec<String> = (top..=bottom).map(|line| self.line_content(line)).collect();
+
+        // Each row is edited as a plain selection, so the block steps aside
+        let block = self.block_selection.take();
+        self.selection_anchor = None;
+        for (row, (line, range)) in ranges.into_iter().enumerate().rev() {
+            edit(self, row, line, range);
+        }
+        self.block_selection = block;
+
+        let new_end_col = self.line_len(bottom);
+        let new_end_byte = self.byte_offset_at(bottom, new_end_col);
+        // A same-length replacement leaves the end where it was, so compare
+        // the rows themselves
+        if (top..=bottom).map(|line| self.line_content(line)).eq(old_rows) {
+            return MutationResult::none();
+        }
+
+        let dirty_lines = self.accumulate_dirty(DirtyLines::Range {
+            from: top,
+            to: bottom + 1,
+        });
+        let edit_info = Some(EditInfo {
+            start_byte,
+            old_end_byte,
+            new_end_byte,
+            start_row: top,
+            start_col: 0,
+            old_end_row: bottom,
+            old_end_col,
+            new_end_row: bottom,
+            new_end_col,
+        });
+        MutationResult::new(dirty_lines, edit_info)
+    }
+
+    /// Replaces `range` on `line` with `text` (which has no newlines).
+    fn replace_in_line(&mut self, line: usize, range: std::ops::Range<usize>, text: &str) {
+        self.cursor = Position::new(line, range.end);
+        self.selection_anchor = Some(Position::new(line, range.start));
+        self.delete_selection();
+        self.selection_anchor = None;
+        self.insert_str(text);
+    }
+
+    /// Collapses the block to carets at the display column of `char_col` on
+    /// `head_line`, with the cursor there.
+    fn collapse_block_at(&mut self, head_line: usize, char_col: usize) {
+        let Some(block) = self.block_selection else {
+            return;
+        };
+        let chars: Vec<char> = self.line_content(head_line).chars().collect();
+        let char_col = char_col.min(chars.len());
+        self.block_selection = Some(block.collapsed_to(display_col(&chars, char_col)));
+        self.cursor = Position::new(head_line, char_col);
+    }
+
+    /// Converts a display column on `line` to a character column, clamped to
+    /// the line's end.
+    fn char_col_at_display_col(&self, line: usize, col: usize) -> usize {
+        let chars: Vec<char> = self.line_content(line).chars().collect();
+        char_col_for_display_col(&chars, col)
+    }
+
+    // ==================== Line Moves ====================
+    // Chunk: docs/chunks/move_duplicate_lines - Move and duplicate whole lines
+
+    /// Moves the lines the cursor or selection covers up one line, keeping
+    /// the selection on them.
+    ///
+    /// The line above moves below them. Does nothing on the first line.
+    pub fn move_lines_up_tracked(&mut self) -> MutationResult {
+        let (first, last) = self.selected_line_span();
+        if first == 0 {
+            return MutationResult::none();
+        }
+        let moved = self.lines_text(first, last);
+        let above = self.line_content(first - 1);
+        let (cursor, anchor) = (self.cursor, self.selection_anchor);
+
+        let result = self.replace_lines(first - 1, last, &format!("{}\n{}", moved, above));
+
+        self.cursor = Position::new(cursor.line - 1, cursor.col);
+        self.selection_anchor = anchor.map(|a| Position::new(a.line - 1, a.col));
+        result
+    }
+
+    /// Moves the lines the cursor or selection covers down one line, keeping
+    /// the selection on them.
+    ///
+    /// The line below moves above them. Does nothing on the last line.
+    pub fn move_lines_down_tracked(&mut self) -> MutationResult {
+        let (first, last) = self.selected_line_span();
+        if last + 1 >= self.line_count() {
+            return MutationResult::none();
+        }
+        let moved = self.lines_text(first, last);
+        let below = self.line_content(last + 1);
+        let (cursor, anchor) = (self.cursor, self.selection_anchor);
+
+        let result = self.replace_lines(first, last + 1, &format!("{}\n{}", below, moved));
+
+        self.cursor = Position::new(cursor.line + 1, cursor.col);
+        self.selection_anchor = anchor.map(|a| Position::new(a.line + 1, a.col));
+        result
+    }
+
+    /// Inserts a copy of the lines the cursor or selection covers below them,
+    /// and moves the cursor and selection onto the copy.
+    pub fn duplicate_lines_tracked(&mut self) -> MutationResult {
+        let (first, last) = self.selected_line_span();
+        let copy = format!("\n{}", self.lines_text(first, last));
+        let (cursor, anchor) = (self.cursor, self.selection_anchor);
+        let shift = last - first + 1;
+
+        let start_col = self.line_len(last);
+        let start_byte = self.byte_offset_at(last, start_col);
+        let saved_dirty = std::mem::take(&mut self.dirty_lines);
+        self.block_selection = None;
+        self.selection_anchor = None;
+        self.cursor = Position::new(last, start_col);
+        self.insert_str(&copy);
+        self.dirty_lines = saved_dirty;
+
+        let end_row = last + shift;
+        let edit_info = Some(EditInfo::for_insert(
+            start_byte,
+            last,
+            start_col,
+            copy.len(),
+            end_row,
+            self.line_len(end_row),
+        ));
+        let dirty_lines = self.accumulate_dirty(DirtyLines::FromLineToEnd(last + 1));
+
+        self.cursor = Position::new(cursor.line + shift, cursor.col);
+        self.selection_anchor = anchor.map(|a| Position::new(a.line + shift, a.col));
+        MutationResult::new(dirty_lines, edit_info)
+    }
+
+    /// Returns the first and last lines the cursor or selection covers.
+    ///
+    /// A selection ending at column 0 doesn't cover the line it ends on.
+    fn selected_line_span(&self) -> (usize, usize) {
+        match self.selection_range() {
+            Some((start, end)) if end.col == 0 && end.line > start.line => {
+                (start.line, end.line - 1)
+            }
+            Some((start, end)) => (start.line, end.line),
+            None => (self.cursor.line, self.cursor.line),
+        }
+    }
+
+    /// Returns lines `first..=last` joined by newlines.
+    fn lines_text(&self, first: usize, last: usize) -> String {
+        (first..=last)
+            .map(|line| self.line_content(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Replaces lines `first..=last` (without the final newline) with `text`,
+    /// which must have the same number of lines, as one edit.
+    ///
+    /// Only lines `first..=last` are reported dirty, since nothing outside
+    /// them moves. Leaves the cursor at the end of the replacement.
+    fn replace_lines(&mut self, first: usize, last: usize, text: &str) -> MutationResult {
+        let start_byte = self.byte_offset_at(first, 0);
+        let old_end_col = self.line_len(last);
+        let old_end_byte = self.byte_offset_at(last, old_end_col);
+
+        // The primitives below report the lines after a multi-line edit as
+        // dirty too; keep the narrower range instead.
+        let saved_dirty = std::mem::take(&mut self.dirty_lines);
+        self.block_selection = None;
+        self.selection_anchor = Some(Position::new(first, 0));
+        self.cursor = Position::new(last, old_end_col);
+        self.delete_selection();
+        self.insert_str(text);
+        self.dirty_lines = saved_dirty;
+
+        let new_end_col = self.line_len(last);
+        let dirty_lines = self.accumulate_dirty(DirtyLines::Range {
+            from: first,
+            to: last + 1,
+        });
+        let edit_info = Some(EditInfo {
+            start_byte,
+            old_end_byte,
+            new_end_byte: start_byte + text.len(),
+            start_row: first,
+            start_col: 0,
+            old_end_row: last,
+            old_end_col,
+            new_end_row: last,
+            new_end_col,
+        });
+        MutationResult::new(dirty_lines, edit_info)
+    }
+
     // ==================== Marked Text (IME Support) ====================
     // Chunk: docs/chunks/unicode_ime_input - Marked text API for IME composition
 
@@ -662,32 +1071,45 @@ impl TextBuffer {
 
     /// Moves the cursor up by one line.
     ///
-    /// The column is clamped to the length of the target line.
+    /// The cursor keeps its display column (see `move_to_line`).
     /// If at the first line, does nothing.
     /// Clears any active selection.
     pub fn move_up(&mut self) {
         self.clear_selection();
         if self.cursor.line > 0 {
-            self.cursor.line -= 1;
-            let line_len = self.line_len(self.cursor.line);
-            self.cursor.col = self.cursor.col.min(line_len);
+            self.move_to_line(self.cursor.line - 1);
         }
     }
 
     /// Moves the cursor down by one line.
     ///
-    /// The column is clamped to the length of the target line.
+    /// The cursor keeps its display column (see `move_to_line`).
     /// If at the last line, does nothing.
     /// Clears any active selection.
     pub fn move_down(&mut self) {
         self.clear_selection();
         if self.cursor.line + 1 < self.line_count() {
-            self.cursor.line += 1;
-            let line_len = self.line_len(self.cursor.line);
-            self.cursor.col = self.cursor.col.min(line_len);
+            self.move_to_line(self.cursor.line + 1);
         }
     }
 
+    // Chunk: docs/chunks/unicode_width_awareness - Display-column-preserving vertical movement
+    /// Moves the cursor to `line`, keeping its display column.
+    ///
+    /// The column is measured in screen cells, so moving between lines with
+    /// tabs, wide characters or combining marks keeps the cursor visually
+    /// aligned. On the target line the cursor lands at the start of the
+    /// grapheme cluster under that column, or at the line end if the line is
+    /// shorter. The line is clamped to the buffer. Clears any active selection.
+    pub fn move_to_line(&mut self, line: usize) {
+        self.clear_selection();
+        let line = line.min(self.line_count().saturating_sub(1));
+        let current: Vec<char> = self.line_content(self.cursor.line).chars().collect();
+        let target_col = display_col(&current, self.cursor.col);
+        let target: Vec<char> = self.line_content(line).chars().collect();
+        self.cursor = Position::new(line, char_col_for_display_col(&target, target_col));
+    }
+
     /// Moves the cursor to the start of the current line.
     /// Clears any active selection.
     pub fn move_to_line_start(&mut self) {
@@ -695,6 +1117,22 @@ impl TextBuffer {
         self.cursor.col = 0;
     }
 
+    // Chunk: docs/chunks/smart_home_navigation - Smart Home
+    /// Moves the cursor to the first non-whitespace character of the current
+    /// line, or to column 0 if it is already there.
+    ///
+    /// On a line that is all whitespace the first target is the line end.
+    /// Clears any active selection.
+    pub fn move_to_smart_line_start(&mut self) {
+        self.clear_selection();
+        let indent = self
+            .line_content(self.cursor.line)
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .count();
+        self.cursor.col = if self.cursor.col == indent { 0 } else { indent };
+    }
+
     /// Moves the cursor to the end of the current line.
     /// Clears any active selection.
     pub fn move_to_line_end(&mut self) {
@@ -794,13 +1232,12 @@ impl TextBuffer {
 
     /// Sets the cursor to an arbitrary position.
     ///
-    /// The position is clamped to valid bounds.
+    /// The position is clamped to valid bounds and snapped to the start of
+    /// the grapheme cluster containing it.
     /// Clears any active selection.
     pub fn set_cursor(&mut self, pos: Position) {
         self.clear_selection();
-        let line = pos.line.min(self.line_count().saturating_sub(1));
-        let col = pos.col.min(self.line_len(line));
-        self.cursor = Position::new(line, col);
+        self.cursor = self.clamp_to_grapheme(pos);
     }
 
     // Chunk: docs/chunks/mouse_drag_selection - Mouse drag selection
@@ -809,9 +1246,20 @@ impl TextBuffer {
     /// This is used during drag operations where we want to extend the selection
     /// from a fixed anchor. The position is clamped to valid bounds.
     pub fn move_cursor_preserving_selection(&mut self, pos: Position) {
+        self.cursor = self.clamp_to_grapheme(pos);
+    }
+
+    // Chunk: docs/chunks/unicode_width_awareness - Keep externally set cursors out of clusters
+    /// Clamps a position to the buffer and snaps its column to a grapheme
+    /// cluster boundary, so edits at the cursor never split a cluster.
+    fn clamp_to_grapheme(&self, pos: Position) -> Position {
         let line = pos.line.min(self.line_count().saturating_sub(1));
         let col = pos.col.min(self.line_len(line));
-        self.cursor = Position::new(line, col);
+        if col == 0 || col == self.line_len(line) {
+            return Position::new(line, col);
+        }
+        let chars: Vec<char> = self.line_content(line).chars().collect();
+        Position::new(line, snap_to_grapheme_boundary(&chars, col))
     }
 
     // ==================== Validation ====================
@@ -915,6 +1363,9 @@ impl TextBuffer {
         if ch == '\n' {
             return self.insert_newline_tracked();
         }
+        if self.block_selection.is_some() {
+            return self.block_insert_str(ch.encode_utf8(&mut [0; 4]));
+        }
 
         // Handle selection deletion first - this produces its own edit
         // For selection-then-insert, we capture the selection bounds first,
@@ -967,6 +1418,9 @@ impl TextBuffer {
     /// Like `insert_newline`, but also returns byte offset information needed for
     /// tree-sitter's incremental parsing API.
     pub fn insert_newline_tracked(&mut self) -> MutationResult {
+        // A newline ends a block selection and goes in once, at the cursor
+        self.block_selection = None;
+
         // Handle selection deletion first
         let had_selection = self.has_selection();
 
@@ -1194,6 +1648,10 @@ impl TextBuffer {
     /// Like `delete_backward`, but also returns byte offset information needed for
     /// tree-sitter's incremental parsing API.
     pub fn delete_backward_tracked(&mut self) -> MutationResult {
+        if self.block_selection.is_some() {
+            return self.block_delete_backward();
+        }
+
         // If there's a selection, delete it and return
         if self.has_selection() {
             return self.delete_selection_tracked();
@@ -1312,6 +1770,10 @@ impl TextBuffer {
     /// Like `delete_forward`, but also returns byte offset information needed for
     /// tree-sitter's incremental parsing API.
     pub fn delete_forward_tracked(&mut self) -> MutationResult {
+        if self.block_selection.is_some() {
+            return self.block_delete_forward();
+        }
+
         // If there's a selection, delete it and return
         if self.has_selection() {
             return self.delete_selection_tracked();
@@ -1436,6 +1898,10 @@ impl TextBuffer {
     /// Like `delete_selection`, but also returns byte offset information needed for
     /// tree-sitter's incremental parsing API.
     pub fn delete_selection_tracked(&mut self) -> MutationResult {
+        if self.has_selection() && self.block_selection.is_some() {
+            return self.block_delete_backward();
+        }
+
         let (start, end) = match self.selection_range() {
             Some(range) => range,
             None => return MutationResult::none(),
@@ -2064,6 +2530,9 @@ impl TextBuffer {
         if s.is_empty() {
             return MutationResult::none();
         }
+        if self.block_selection.is_some() {
+            return self.block_insert_str(s);
+        }
 
         // Capture info for EditInfo BEFORE any mutations
         // When there's a selection, the edit spans from selection start to selection end.
@@ -2255,6 +2724,10 @@ impl BufferView for TextBuffer {
             Some((self.cursor, anchor))
         }
     }
+
+    fn block_selection(&self) -> Option<BlockSelection> {
+        self.block_selection
+    }
 }
 
 impl Default for TextBuffer {
@@ -4099,6 +4572,22 @@ mod tests {
         assert_eq!(buf.selected_text(), Some("second".to_string()));
     }
 
+    #[test]
+    fn test_select_line_at_includes_newline() {
+        let mut buf = TextBuffer::from_str("one\ntwo\nthree");
+        buf.select_line_at(1);
+        assert_eq!(buf.selected_text(), Some("two\n".to_string()));
+        assert_eq!(buf.cursor_position(), Position::new(2, 0));
+    }
+
+    #[test]
+    fn test_select_line_at_last_line() {
+        let mut buf = TextBuffer::from_str("one\ntwo");
+        buf.select_line_at(5);
+        assert_eq!(buf.selected_text(), Some("two".to_string()));
+        assert_eq!(buf.cursor_position(), Position::new(1, 3));
+    }
+
     // ==================== Triclass Word Operation Integration Tests ====================
     // Chunk: docs/chunks/word_triclass_boundaries - Three-class word boundary classification
 
@@ -4935,4 +5424,223 @@ mod tests {
         assert_eq!(edit.old_end_row, 1);
         assert_eq!(edit.old_end_col, 0);
     }
+
+    // ==================== Block Selection Tests ====================
+
+    #[test]
+    fn test_block_selection_text_is_one_row_per_line() {
+        let mut buf = TextBuffer::from_str("abcdef\nab\nabcdef");
+        buf.set_block_selection(Position::new(0, 1), Position::new(2, 4));
+
+        assert!(buf.has_selection());
+        assert_eq!(buf.selected_text().as_deref(), Some("bcd\nb\nbcd"));
+        assert_eq!(buf.cursor_position(), Position::new(2, 4));
+    }
+
+    #[test]
+    fn test_block_insert_types_on_every_line() {
+        let mut buf = TextBuffer::from_str("one\ntwo\nthree");
+        buf.set_block_selection(Position::new(0, 0), Position::new(2, 0));
+
+        buf.insert_char_tracked('-');
+        buf.insert_char_tracked(' ');
+
+        assert_eq!(buf.content(), "- one\n- two\n- three");
+        assert_eq!(buf.cursor_position(), Position::new(2, 2));
+        let block = buf.block_selection().unwrap();
+        assert_eq!((block.left(), block.top(), block.bottom()), (2, 0, 2));
+        assert!(block.is_empty());
+    }
+
+    #[test]
+    fn test_block_insert_replaces_block() {
+        let mut buf = TextBuffer::from_str("a123b\na456b");
+        buf.set_block_selection(Position::new(0, 1), Position::new(1, 4));
+
+        let result = buf.insert_str_tracked("x");
+
+        assert_eq!(buf.content(), "axb\naxb");
+        let edit = result.edit_info.unwrap();
+        assert_eq!(edit.start_byte, 0);
+        assert_eq!(edit.old_end_byte, 11);
+        assert_eq!(edit.new_end_byte, 7);
+    }
+
+    #[test]
+    fn test_block_insert_same_length_replacement_is_an_edit() {
+        let mut buf = TextBuffer::from_str("a1b\na2b");
+        buf.set_block_selection(Position::new(0, 1), Position::new(1, 2));
+
+        let result = buf.insert_str_tracked("x");
+
+        assert_eq!(buf.content(), "axb\naxb");
+        assert_eq!(result.dirty_lines, DirtyLines::Range { from: 0, to: 2 });
+        let edit = result.edit_info.unwrap();
+        assert_eq!((edit.start_byte, edit.old_end_byte, edit.new_end_byte), (0, 7, 7));
+        assert_eq!((edit.old_end_row, edit.new_end_row), (1, 1));
+    }
+
+    #[test]
+    fn test_block_paste_distributes_lines() {
+        let mut buf = TextBuffer::from_str("ab\nab");
+        buf.set_block_selection(Position::new(0, 1), Position::new(1, 1));
+
+        buf.insert_str_tracked("1\n2");
+
+        assert_eq!(buf.content(), "a1b\na2b");
+    }
+
+    #[test]
+    fn test_block_paste_with_other_line_count_ends_block() {
+        let mut buf = TextBuffer::from_str("ab\nab");
+        buf.set_block_selection(Position::new(0, 1), Position::new(1, 1));
+
+        buf.insert_str_tracked("1\n2\n3");
+
+        assert_eq!(buf.block_selection(), None);
+        assert_eq!(buf.content(), "ab\na1\n2\n3b");
+    }
+
+    #[test]
+    fn test_block_delete_backward_skips_short_lines() {
+        let mut buf = TextBuffer::from_str("abc\na\nabc");
+        buf.set_block_selection(Position::new(0, 2), Position::new(2, 2));
+
+        buf.delete_backward_tracked();
+
+        assert_eq!(buf.content(), "ac\na\nac");
+        assert_eq!(buf.cursor_position(), Position::new(2, 1));
+    }
+
+    #[test]
+    fn test_block_delete_forward_removes_block() {
+        let mut buf = TextBuffer::from_str("abcd\nabcd");
+        buf.set_block_selection(Position::new(1, 3), Position::new(0, 1));
+
+        buf.delete_forward_tracked();
+
+        assert_eq!(buf.content(), "ad\nad");
+        assert!(!buf.has_selection());
+        assert_eq!(buf.cursor_position(), Position::new(0, 1));
+    }
+
+    #[test]
+    fn test_block_uses_display_columns() {
+        let mut buf = TextBuffer::from_str("日本x\nabcdx");
+        buf.set_block_selection(Position::new(0, 0), Position::new(1, 4));
+
+        assert_eq!(buf.selected_text().as_deref(), Some("日本\nabcd"));
+    }
+
+    #[test]
+    fn test_clear_selection_ends_block() {
+        let mut buf = TextBuffer::from_str("abc\nabc");
+        buf.set_block_selection(Position::new(0, 0), Position::new(1, 2));
+        buf.clear_selection();
+
+        assert_eq!(buf.block_selection(), None);
+        assert!(!buf.has_selection());
+    }
+
+    // ==================== Line Move Tests ====================
+
+    #[test]
+    fn test_move_line_up_swaps_with_line_above() {
+        let mut buf = TextBuffer::from_str("one\ntwo\nthree");
+        buf.set_cursor(Position::new(1, 2));
+
+        let result = buf.move_lines_up_tracked();
+
+        assert_eq!(buf.content(), "two\none\nthree");
+        assert_eq!(buf.cursor_position(), Position::new(0, 2));
+        assert_eq!(result.dirty_lines, DirtyLines::Range { from: 0, to: 2 });
+        let edit = result.edit_info.unwrap();
+        assert_eq!(edit.old_end_byte, 7);
+        assert_eq!(edit.new_end_byte, 7);
+    }
+
+    #[test]
+    fn test_move_line_up_on_first_line_is_noop() {
+        let mut buf = TextBuffer::from_str("one\ntwo");
+        let result = buf.move_lines_up_tracked();
+
+        assert_eq!(buf.content(), "one\ntwo");
+        assert!(result.edit_info.is_none());
+    }
+
+    #[test]
+    fn test_move_selected_lines_down_keeps_selection() {
+        let mut buf = TextBuffer::from_str("a\nb\nc\nd");
+        buf.set_cursor(Position::new(0, 0));
+        buf.set_selection_anchor_at_cursor();
+        buf.move_cursor_preserving_selection(Position::new(2, 0));
+
+        let result = buf.move_lines_down_tracked();
+
+        // The selection ends at column 0 of "c", so "c" isn't moved
+        assert_eq!(buf.content(), "c\na\nb\nd");
+        assert_eq!(buf.selected_text().as_deref(), Some("a\nb\n"));
+        assert_eq!(result.dirty_lines, DirtyLines::Range { from: 0, to: 3 });
+    }
+
+    #[test]
+    fn test_move_last_line_down_is_noop() {
+        let mut buf = TextBuffer::from_str("one\ntwo");
+        buf.set_cursor(Position::new(1, 0));
+
+        assert!(buf.move_lines_down_tracked().edit_info.is_none());
+        assert_eq!(buf.content(), "one\ntwo");
+    }
+
+    #[test]
+    fn test_duplicate_line_moves_cursor_to_copy() {
+        let mut buf = TextBuffer::from_str("one\ntwo");
+        buf.set_cursor(Position::new(0, 1));
+
+        let result = buf.duplicate_lines_tracked();
+
+        assert_eq!(buf.content(), "one\none\ntwo");
+        assert_eq!(buf.cursor_position(), Position::new(1, 1));
+        assert_eq!(result.dirty_lines, DirtyLines::FromLineToEnd(1));
+        let edit = result.edit_info.unwrap();
+        assert_eq!((edit.start_byte, edit.new_end_byte), (3, 7));
+    }
+
+    #[test]
+    fn test_duplicate_selected_lines() {
+        let mut buf = TextBuffer::from_str("a\nb\nc");
+        buf.set_cursor(Position::new(0, 0));
+        buf.set_selection_anchor_at_cursor();
+        buf.move_cursor_preserving_selection(Position::new(1, 1));
+
+        buf.duplicate_lines_tracked();
+
+        assert_eq!(buf.content(), "a\nb\na\nb\nc");
+        assert_eq!(buf.selected_text().as_deref(), Some("a\nb"));
+        assert_eq!(buf.cursor_position(), Position::new(3, 1));
+    }
+
+    // ==================== Smart Home Tests ====================
+
+    #[test]
+    fn test_smart_line_start_toggles_between_indent_and_column_zero() {
+        let mut buf = TextBuffer::from_str("    let x = 1;");
+        buf.set_cursor(Position::new(0, 10));
+
+        buf.move_to_smart_line_start();
+        assert_eq!(buf.cursor_position(), Position::new(0, 4));
+        buf.move_to_smart_line_start();
+        assert_eq!(buf.cursor_position(), Position::new(0, 0));
+        buf.move_to_smart_line_start();
+        assert_eq!(buf.cursor_position(), Position::new(0, 4));
+    }
+
+    #[test]
+    fn test_smart_line_start_inside_indent_goes_to_indent() {
+        let mut buf = TextBuffer::from_str("\t\tx");
+        buf.set_cursor(Position::new(0, 1));
+
+        buf.move_to_smart_line_start();
+        assert_eq!(buf.cursor_position(), Position::new(0, 2));
+    }
 }
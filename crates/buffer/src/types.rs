@@ -30,6 +30,62 @@ impl Ord for Position {
     }
 }
 
+// Chunk: docs/chunks/column_selection - Rectangular selection in display columns
+/// A rectangular (column) selection.
+///
+/// Unlike `Position`, the `col` of `anchor` and `head` is a display column,
+/// so the block keeps its shape across lines with tabs or wide characters
+/// and may extend past the end of short lines. The block covers display
+/// columns `left()..right()` on every line from `top()` to `bottom()`. A
+/// block with `left() == right()` is a column of carets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockSelection {
+    /// Where the block was started (line, display column)
+    pub anchor: Position,
+    /// The corner that moves (line, display column)
+    pub head: Position,
+}
+
+impl BlockSelection {
+    /// Creates a block between two corners.
+    pub fn new(anchor: Position, head: Position) -> Self {
+        Self { anchor, head }
+    }
+
+    /// First line of the block.
+    pub fn top(&self) -> usize {
+        self.anchor.line.min(self.head.line)
+    }
+
+    /// Last line of the block (inclusive).
+    pub fn bottom(&self) -> usize {
+        self.anchor.line.max(self.head.line)
+    }
+
+    /// Leftmost display column covered.
+    pub fn left(&self) -> usize {
+        self.anchor.col.min(self.head.col)
+    }
+
+    /// Display column just past the block's right edge.
+    pub fn right(&self) -> usize {
+        self.anchor.col.max(self.head.col)
+    }
+
+    /// Returns true if the block covers no columns (a column of carets).
+    pub fn is_empty(&self) -> bool {
+        self.anchor.col == self.head.col
+    }
+
+    /// Returns the block collapsed to carets at display column `col`.
+    pub fn collapsed_to(&self, col: usize) -> Self {
+        Self {
+            anchor: Position::new(self.anchor.line, col),
+            head: Position::new(self.head.line, col),
+        }
+    }
+}
+
 /// Information about which lines were dirtied by a mutation.
 /// Used by the render loop to compute DirtyRegion.
 // Chunk: docs/chunks/buffer_view_trait - Added Default derive for BufferView::take_dirty()
@@ -444,3 +444,84 @@ fn test_navigate_through_mixed_content() {
     buf.move_right(); // é -> end (past combining char)
     assert_eq!(buf.cursor_position().col, 7);
 }
+
+// ==================== Display Width Tests ====================
+// Chunk: docs/chunks/unicode_width_awareness
+
+#[test]
+fn test_move_down_keeps_display_column_after_wide_chars() {
+    // "中文" is 4 display columns wide
+    let mut buf = TextBuffer::from_str("中文x\nabcdef");
+    buf.set_cursor(Position::new(0, 2)); // after 中文, display column 4
+
+    buf.move_down();
+
+    assert_eq!(buf.cursor_position(), Position::new(1, 4));
+}
+
+#[test]
+fn test_move_up_onto_wide_char_lands_at_its_start() {
+    let mut buf = TextBuffer::from_str("中文\nabc");
+    buf.set_cursor(Position::new(1, 3)); // display column 3, inside 文
+
+    buf.move_up();
+
+    assert_eq!(buf.cursor_position(), Position::new(0, 1));
+}
+
+#[test]
+fn test_move_down_skips_combining_marks() {
+    // "e\u{0301}" is one column wide
+    let mut buf = TextBuffer::from_str("ab\ne\u{0301}x");
+    buf.set_cursor(Position::new(0, 1));
+
+    buf.move_down();
+
+    // Display column 1 is 'x', after the whole é cluster
+    assert_eq!(buf.cursor_position(), Position::new(1, 2));
+}
+
+#[test]
+fn test_move_down_never_lands_inside_zwj_sequence() {
+    let mut buf = TextBuffer::from_str("abcd\n👨\u{200D}👩x");
+    buf.set_cursor(Position::new(0, 2)); // display column 2: where 👩 starts
+
+    buf.move_down();
+
+    assert_eq!(buf.cursor_position(), Position::new(1, 0));
+}
+
+#[test]
+fn test_move_to_line_keeps_display_column() {
+    let mut buf = TextBuffer::from_str("\tfoo\nabcdef\nxy");
+    buf.set_cursor(Position::new(0, 1)); // after the tab, display column 4
+
+    buf.move_to_line(1);
+    assert_eq!(buf.cursor_position(), Position::new(1, 4));
+
+    buf.move_to_line(99);
+    assert_eq!(buf.cursor_position(), Position::new(2, 2));
+}
+
+#[test]
+fn test_set_cursor_snaps_out_of_cluster() {
+    let mut buf = TextBuffer::from_str("ae\u{0301}b");
+    buf.set_cursor(Position::new(0, 2)); // between 'e' and the combining accent
+
+    assert_eq!(buf.cursor_position(), Position::new(0, 1));
+
+    // Typing now inserts before the whole cluster instead of splitting it
+    buf.insert_char('z');
+    assert_eq!(buf.content(), "aze\u{0301}b");
+}
+
+#[test]
+fn test_drag_cursor_snaps_out_of_cluster() {
+    let mut buf = TextBuffer::from_str("a🇺🇸b");
+    buf.set_cursor(Position::new(0, 0));
+    buf.set_selection_anchor_at_cursor();
+
+    buf.move_cursor_preserving_selection(Position::new(0, 2)); // between the regional indicators
+
+    assert_eq!(buf.cursor_position(), Position::new(0, 1));
+}
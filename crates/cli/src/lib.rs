@@ -0,0 +1,270 @@
+// Chunk: docs/chunks/cli_launcher - Protocol between `lite` and lite-edit
+//!
+//! Protocol between the `lite` command and a running lite-edit.
+//!
+//! The editor listens on a unix socket at [`socket_path`]. The command
+//! connects, writes one [`Request`] and reads the replies:
+//!
+//! ```text
+//! wait
+//! open    /Users/me/project/src/main.rs    42    7
+//! open    /Users/me/project
+//!
+//! ```
+//!
+//! Each `open` line holds tab-separated fields (shown as spaces above): an
+//! absolute path, then an optional 1-based line and column, which may be
+//! empty or left off. `wait` asks the editor to hold the connection until the
+//! tabs of the opened files have closed. A blank line ends the request.
+//!
+//! The editor replies [`REPLY_OPENED`] once everything is open and, for a
+//! waiting request, [`REPLY_CLOSED`] once those tabs have closed. Each reply
+//! is one line.
+//!
+//! The editor depends on this library for the protocol; the `lite` binary in
+//! this crate is the other end.
+
+use std::path::{Path, PathBuf};
+
+/// Directory name under the platform data directory, shared with the
+/// editor's session file.
+const APP_NAME: &str = "lite-edit";
+
+/// Name of the socket in the app's data directory.
+const SOCKET_FILENAME: &str = "lite.sock";
+
+/// Reply sent once a request's files are open.
+pub const REPLY_OPENED: &str = "opened";
+
+/// Reply sent to a waiting request once its files' tabs have closed.
+pub const REPLY_CLOSED: &str = "closed";
+
+/// A file or directory to open, with an optional cursor position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// The file or directory. Absolute in a [`Request`].
+    pub path: PathBuf,
+    /// 1-based line to put the cursor on.
+    pub line: Option<usize>,
+    /// 1-based column on `line`.
+    pub column: Option<usize>,
+}
+
+/// One request from the `lite` command.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Request {
+    /// What to open, in order. Empty just brings the editor forward.
+    pub locations: Vec<Location>,
+    /// Reply [`REPLY_CLOSED`] once every opened file's tab has closed.
+    pub wait: bool,
+}
+
+impl Request {
+    /// Encodes the request, including the blank line that ends it.
+    ///
+    /// Returns `None` if a path isn't UTF-8 or contains a tab or newline,
+    /// since it couldn't be decoded again.
+    pub fn encode(&self) -> Option<String> {
+        let mut text = String::new();
+        if self.wait {
+            text.push_str("wait\n");
+        }
+        for location in &self.locations {
+            let path = location.path.to_str()?;
+            if path.contains(['\t', '\n']) {
+                return None;
+            }
+            let number = |n: Option<usize>| n.map(|n| n.to_string()).unwrap_or_default();
+            text.push_str(&format!(
+                "open\t{}\t{}\t{}\n",
+                path,
+                number(location.line),
+                number(location.column)
+            ));
+        }
+        text.push('\n');
+        Some(text)
+    }
+
+    /// Decodes the lines of a request, not including the blank line that
+    /// ends it.
+    ///
+    /// Returns `None` for an unknown line, a relative path, or a position
+    /// that isn't a number.
+    pub fn decode<'a>(lines: impl IntoIterator<Item = &'a str>) -> Option<Request> {
+        let mut request = Request::default();
+        for line in lines {
+            let mut fields = line.split('\t');
+            match fields.next()? {
+                "wait" => request.wait = true,
+                "open" => {
+                    let path = PathBuf::from(fields.next()?);
+                    if !path.is_absolute() {
+                        return None;
+                    }
+                    let line = decode_number(fields.next().unwrap_or(""))?;
+                    let column = decode_number(fields.next().unwrap_or(""))?;
+                    request.locations.push(Location { path, line, column });
+                }
+                _ => return None,
+            }
+        }
+        Some(request)
+    }
+}
+
+/// Decodes an optional line or column number field: empty for none. Numbers
+/// are 1-based, so 0 is malformed.
+fn decode_number(field: &str) -> Option<Option<usize>> {
+    if field.is_empty() {
+        return Some(None);
+    }
+    field.parse().ok().filter(|&n| n > 0).map(Some)
+}
+
+/// Returns the path of the socket the editor listens on.
+///
+/// On macOS, this is `~/Library/Application Support/lite-edit/lite.sock`.
+/// Returns `None` if the data directory cannot be determined.
+pub fn socket_path() -> Option<PathBuf> {
+    Some(dirs::data_dir()?.join(APP_NAME).join(SOCKET_FILENAME))
+}
+
+/// Parses a command-line argument such as `src/main.rs:42:7` into a
+/// location.
+///
+/// An argument naming an existing file is a path even if it ends in `:N`.
+/// Zero line or column numbers are ignored. The path is returned as given,
+/// relative or not.
+pub fn parse_location(arg: &str) -> Location {
+    let whole = Location {
+        path: PathBuf::from(arg),
+        line: None,
+        column: None,
+    };
+    if Path::new(arg).exists() {
+        return whole;
+    }
+
+    // Peel up to two trailing `:N` suffixes, column last
+    let mut path = arg;
+    let mut numbers = Vec::new();
+    while numbers.len() < 2 {
+        let Some((head, tail)) = path.rsplit_once(':') else {
+            break;
+        };
+        let Ok(number) = tail.parse::<usize>() else {
+            break;
+        };
+        if head.is_empty() {
+            break;
+        }
+        numbers.insert(0, number);
+        path = head;
+    }
+
+    let position = |index: usize| numbers.get(index).copied().filter(|&n| n > 0);
+    Location {
+        path: PathBuf::from(path),
+        line: position(0),
+        column: position(1),
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_round_trips() {
+        let request = Request {
+            locations: vec![
+                Location {
+                    path: PathBuf::from("/project/src/main.rs"),
+                    line: Some(42),
+                    column: Some(7),
+                },
+                Location {
+                    path: PathBuf::from("/project"),
+                    line: None,
+                    column: None,
+                },
+            ],
+            wait: true,
+        };
+        let text = request.encode().unwrap();
+        assert!(text.ends_with("\n\n"));
+        let decoded = Request::decode(text.trim_end_matches('\n').lines()).unwrap();
+        assert_eq!(decoded, request);
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed_lines() {
+        assert!(Request::decode(["open\trelative.rs\t\t"]).is_none());
+        assert!(Request::decode(["open\t/a.rs\tten\t"]).is_none());
+        assert!(Request::decode(["close\t/a.rs\t\t"]).is_none());
+        // Lines and columns start at 1
+        assert!(Request::decode(["open\t/a.rs\t0\t"]).is_none());
+        assert!(Request::decode(["open\t/a.rs\t3\t0"]).is_none());
+        assert_eq!(Request::decode(Vec::new()), Some(Request::default()));
+
+        // Trailing position fields may be left off
+        let request = Request::decode(["open\t/a.rs"]).unwrap();
+        assert_eq!(request.locations[0].path, PathBuf::from("/a.rs"));
+        assert_eq!(request.locations[0].line, None);
+    }
+
+    #[test]
+    fn test_encode_refuses_paths_it_cannot_decode() {
+        let request = Request {
+            locations: vec![Location {
+                path: PathBuf::from("/odd\tname.rs"),
+                line: None,
+                column: None,
+            }],
+            wait: false,
+        };
+        assert!(request.encode().is_none());
+    }
+
+    #[test]
+    fn test_parse_location() {
+        let location = parse_location("src/main.rs:42");
+        assert_eq!(location.path, PathBuf::from("src/main.rs"));
+        assert_eq!((location.line, location.column), (Some(42), None));
+
+        let location = parse_location("src/main.rs:42:7");
+        assert_eq!(location.path, PathBuf::from("src/main.rs"));
+        assert_eq!((location.line, location.column), (Some(42), Some(7)));
+
+        let location = parse_location("notes.md");
+        assert_eq!(location.path, PathBuf::from("notes.md"));
+        assert_eq!((location.line, location.column), (None, None));
+
+        // Only numeric suffixes are positions
+        let location = parse_location("host:path");
+        assert_eq!(location.path, PathBuf::from("host:path"));
+        assert_eq!(location.line, None);
+
+        assert_eq!(parse_location("a.rs:0").line, None);
+        assert_eq!(parse_location(":12").path, PathBuf::from(":12"));
+    }
+
+    #[test]
+    fn test_parse_location_prefers_existing_file() {
+        let temp = std::env::temp_dir().join(format!("lite-cli-test-{}", std::process::id()));
+        std::fs::create_dir_all(&temp).unwrap();
+        let file = temp.join("odd:12");
+        std::fs::write(&file, "").unwrap();
+
+        let location = parse_location(file.to_str().unwrap());
+        assert_eq!(location.path, file);
+        assert_eq!(location.line, None);
+
+        std::fs::remove_dir_all(&temp).unwrap();
+    }
+}
@@ -0,0 +1,175 @@
+// Chunk: docs/chunks/cli_launcher - The `lite` command
+//!
+//! `lite`: open files and directories in lite-edit from a shell.
+//!
+//! ```text
+//! lite [--wait] [PATH[:LINE[:COLUMN]]]...
+//! lite --install [DIR]
+//! ```
+//!
+//! The request goes to the running editor over its socket; if nothing is
+//! listening, the app is launched first. With `--wait` the command returns
+//! only once the opened files' tabs have closed, so `lite --wait` can be
+//! git's `core.editor`.
+
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use lite_edit_cli::{parse_location, socket_path, Location, Request, REPLY_CLOSED, REPLY_OPENED};
+
+/// Bundle identifier of the app, used to launch it when it isn't running.
+const BUNDLE_ID: &str = "com.liteedit.app";
+
+/// How long a freshly launched app has to start listening.
+const LAUNCH_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Delay between connection attempts while the app launches.
+const CONNECT_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Where `--install` puts the command by default.
+const DEFAULT_INSTALL_DIR: &str = "/usr/local/bin";
+
+const USAGE: &str = "\
+usage: lite [--wait] [PATH[:LINE[:COLUMN]]]...
+       lite --install [DIR]
+
+Opens files and directories in lite-edit, launching it if needed.
+
+  -w, --wait     return once the opened files' tabs are closed
+      --install  link this command into DIR (default /usr/local/bin)
+  -h, --help     show this help";
+
+fn main() -> ExitCode {
+    match run(env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("lite: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Vec<String>) -> Result<(), String> {
+    let cwd = env::current_dir().map_err(|e| format!("current directory: {}", e))?;
+    let mut request = Request::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            "-w" | "--wait" => request.wait = true,
+            "--install" => return install(args.next()),
+            "--" => {
+                for arg in args.by_ref() {
+                    request.locations.push(absolute_location(&cwd, &arg));
+                }
+            }
+            _ if arg.starts_with('-') => {
+                return Err(format!("unknown option {}\n\n{}", arg, USAGE));
+            }
+            _ => request.locations.push(absolute_location(&cwd, &arg)),
+        }
+    }
+
+    let encoded = request
+        .encode()
+        .ok_or("paths containing tabs or newlines can't be opened")?;
+    let mut stream = connect()?;
+    stream
+        .write_all(encoded.as_bytes())
+        .map_err(|e| format!("sending request: {}", e))?;
+
+    // The editor replies once the files are open, and to a waiting request
+    // again once their tabs have closed
+    let mut opened = false;
+    for reply in BufReader::new(stream).lines() {
+        let reply = reply.map_err(|e| format!("reading reply: {}", e))?;
+        if reply == REPLY_OPENED {
+            opened = true;
+            if !request.wait {
+                return Ok(());
+            }
+        } else if reply == REPLY_CLOSED {
+            return Ok(());
+        }
+    }
+
+    // Closing the connection before opening anything refuses the request.
+    // Afterwards it means the editor quit, which also ends a wait.
+    if opened {
+        Ok(())
+    } else {
+        Err("lite-edit refused the request".to_string())
+    }
+}
+
+/// Parses `arg` and makes its path absolute against `cwd`.
+fn absolute_location(cwd: &Path, arg: &str) -> Location {
+    let mut location = parse_location(arg);
+    let path = cwd.join(&location.path);
+    location.path = path.canonicalize().unwrap_or(path);
+    location
+}
+
+/// Connects to the running editor, launching it if nothing is listening.
+fn connect() -> Result<UnixStream, String> {
+    let socket = socket_path().ok_or("can't find the application support directory")?;
+    if let Ok(stream) = UnixStream::connect(&socket) {
+        return Ok(stream);
+    }
+
+    let launched = Command::new("open")
+        .args(["-b", BUNDLE_ID])
+        .status()
+        .map_err(|e| format!("launching lite-edit: {}", e))?;
+    if !launched.success() {
+        return Err("couldn't launch lite-edit; is it installed?".to_string());
+    }
+
+    let deadline = Instant::now() + LAUNCH_TIMEOUT;
+    loop {
+        match UnixStream::connect(&socket) {
+            Ok(stream) => return Ok(stream),
+            Err(e) if Instant::now() >= deadline => {
+                return Err(format!("lite-edit didn't start listening: {}", e));
+            }
+            Err(_) => thread::sleep(CONNECT_RETRY_INTERVAL),
+        }
+    }
+}
+
+/// Links this executable into `dir` as `lite`.
+///
+/// Replaces an existing `lite` symlink (from an earlier install), but not a
+/// file that is something else.
+fn install(dir: Option<String>) -> Result<(), String> {
+    let exe = env::current_exe().map_err(|e| format!("finding this executable: {}", e))?;
+    let dir = PathBuf::from(dir.as_deref().unwrap_or(DEFAULT_INSTALL_DIR));
+    let link = dir.join("lite");
+
+    match fs::symlink_metadata(&link) {
+        Ok(metadata) if metadata.file_type().is_symlink() => {
+            fs::remove_file(&link).map_err(|e| format!("replacing {}: {}", link.display(), e))?;
+        }
+        Ok(_) => return Err(format!("{} exists and isn't a link", link.display())),
+        Err(_) => {}
+    }
+    std::os::unix::fs::symlink(&exe, &link).map_err(|e| {
+        format!(
+            "linking {}: {} (a system directory may need sudo)",
+            link.display(),
+            e
+        )
+    })?;
+
+    println!("{} -> {}", link.display(), exe.display());
+    Ok(())
+}
@@ -88,3 +88,19 @@ fragment float4 glyph_fragment(
     // Apply per-vertex color with glyph alpha
     return float4(in.color.rgb, in.color.a * alpha);
 }
+
+// Chunk: docs/chunks/terminal_kitty_graphics - Terminal image compositing
+// Samples an RGBA image texture (terminal graphics) with linear filtering.
+// The per-vertex alpha scales the image's own alpha.
+fragment float4 image_fragment(
+    FragmentInput in [[stage_in]],
+    texture2d<float> image [[texture(0)]]
+) {
+    constexpr sampler image_sampler(
+        filter::linear,
+        address::clamp_to_edge
+    );
+
+    float4 texel = image.sample(image_sampler, in.uv);
+    return float4(texel.rgb, texel.a * in.color.a);
+}
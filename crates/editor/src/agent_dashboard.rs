@@ -0,0 +1,352 @@
+// Chunk: docs/chunks/agent_resource_usage - Agents dashboard
+//!
+//! The Agents tab: every workspace's agent at a glance.
+//!
+//! An [`AgentDashboardBuffer`] lists the agent of each workspace with its
+//! state and the live CPU and memory use of its process tree, as sampled by
+//! `AgentHandle::resource_usage()`, so a stuck or runaway agent stands out
+//! among several running in parallel. Agents waiting in the editor's
+//! `AgentQueue` follow, with their place in the queue. The table is presented
+//! through `BufferView`, so a `TabKind::Agents` tab renders through the same
+//! pipeline as the Terminal Memory tab, and the editor refreshes it as agents
+//! run.
+
+use lite_edit_buffer::{BufferView, CursorInfo, DirtyLines, Span, Style, StyledLine};
+use lite_edit_terminal::{AgentState, ResourceUsage};
+
+use crate::terminal_memory::format_bytes;
+use crate::view_colors::{MAUVE, RED};
+
+/// CPU use above which an agent's figure is highlighted, where 100 is one
+/// core kept busy.
+pub const BUSY_CPU_PERCENT: f32 = 90.0;
+
+/// Columns the workspace and agent names are padded to.
+const NAME_WIDTH: usize = 24;
+
+/// Rows above the first agent: title, queue limit, blank, column headings.
+const HEADER_ROWS: usize = 4;
+
+/// One agent's row of the table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentSummary {
+    /// The workspace the agent runs in.
+    pub workspace: String,
+    /// The agent's command, e.g. "claude".
+    pub command: String,
+    /// The agent's state, e.g. "needs input".
+    pub state: String,
+    /// CPU and memory of the agent's processes, None once it has exited.
+    pub usage: Option<ResourceUsage>,
+    // Chunk: docs/chunks/agent_queue - Queue position
+    /// Place in the queue, 1 for the next to start, while the agent waits.
+    pub queue_position: Option<usize>,
+}
+
+impl AgentSummary {
+    // Chunk: docs/chunks/agent_queue - Queue position
+    /// Describes an agent waiting in the queue; it will run in a workspace
+    /// named `label`.
+    pub fn queued(label: &str, command: &str, position: usize) -> Self {
+        Self {
+            workspace: label.to_string(),
+            command: command_name(command).to_string(),
+            state: format!("queued #{}", position),
+            usage: None,
+            queue_position: Some(position),
+        }
+    }
+}
+
+/// Returns the file name of an agent's command, e.g. "claude" for
+/// "/usr/local/bin/claude".
+pub fn command_name(command: &str) -> &str {
+    command.rsplit('/').next().unwrap_or(command)
+}
+
+/// Describes an agent's state in a few words.
+pub fn state_label(state: &AgentState) -> String {
+    match state {
+        AgentState::Starting => "starting".to_string(),
+        AgentState::Running => "running".to_string(),
+        AgentState::NeedsInput { .. } => "needs input".to_string(),
+        AgentState::Stale { .. } => "idle".to_string(),
+        AgentState::Exited { code } => format!("exited ({})", code),
+    }
+}
+
+/// Formats CPU and memory use, e.g. "12% CPU • 340.5 MB • 4 processes".
+pub fn format_usage(usage: &ResourceUsage) -> String {
+    let processes = match usage.processes {
+        1 => "1 process".to_string(),
+        count => format!("{} processes", count),
+    };
+    format!(
+        "{:.0}% CPU • {} • {}",
+        usage.cpu_percent,
+        format_bytes(usage.memory_bytes),
+        processes
+    )
+}
+
+/// The Agents tab's table.
+pub struct AgentDashboardBuffer {
+    agents: Vec<AgentSummary>,
+    /// How many agents the queue runs at once.
+    max_concurrent: usize,
+    dirty: DirtyLines,
+}
+
+impl AgentDashboardBuffer {
+    /// Creates the table for `agents`, of which at most `max_concurrent` run
+    /// at once.
+    pub fn new(agents: Vec<AgentSummary>, max_concurrent: usize) -> Self {
+        Self {
+            agents,
+            max_concurrent,
+            dirty: DirtyLines::FromLineToEnd(0),
+        }
+    }
+
+    /// Returns the listed agents.
+    pub fn agents(&self) -> &[AgentSummary] {
+        &self.agents
+    }
+
+    /// Shows fresh figures, redrawing only if something changed.
+    pub fn set_agents(&mut self, agents: Vec<AgentSummary>, max_concurrent: usize) {
+        if agents != self.agents || max_concurrent != self.max_concurrent {
+            self.agents = agents;
+            self.max_concurrent = max_concurrent;
+            self.dirty.merge(DirtyLines::FromLineToEnd(0));
+        }
+    }
+
+    fn render_row(&self, row: usize) -> Option<StyledLine> {
+        let dim = Style {
+            dim: true,
+            ..Style::default()
+        };
+        let line = match row {
+            0 => StyledLine::new(vec![
+                Span::new(
+                    "Agents",
+                    Style {
+                        fg: MAUVE,
+                        bold: true,
+                        ..Style::default()
+                    },
+                ),
+                Span::new(
+                    "  CPU and memory include every process an agent started",
+                    dim,
+                ),
+            ]),
+            1 => {
+                let queued = self.agents.iter().filter(|a| a.queue_position.is_some());
+                StyledLine::new(vec![Span::new(
+                    format!(
+                        "{} queued, running at most {} at once (Settings › Agents)",
+                        queued.count(),
+                        self.max_concurrent,
+                    ),
+                    dim,
+                )])
+            }
+            2 => StyledLine::empty(),
+            3 => StyledLine::new(vec![Span::new(
+                format!(
+                    "{:<width$}{:<width$}{:<14}{:>8}{:>12}{:>11}",
+                    "Workspace",
+                    "Agent",
+                    "State",
+                    "CPU",
+                    "Memory",
+                    "Processes",
+                    width = NAME_WIDTH,
+                ),
+                dim,
+            )]),
+            _ if self.agents.is_empty() => match row - HEADER_ROWS {
+                0 => StyledLine::new(vec![Span::new("No agents are running", dim)]),
+                _ => return None,
+            },
+            _ => agent_row(self.agents.get(row - HEADER_ROWS)?),
+        };
+        Some(line)
+    }
+}
+
+/// Cuts `name` to fit its column, marking the cut with an ellipsis.
+fn fit(name: &str) -> String {
+    let mut fitted: String = name.chars().take(NAME_WIDTH - 2).collect();
+    if fitted.chars().count() < name.chars().count() {
+        fitted.push('…');
+    }
+    format!("{:<width$}", fitted, width = NAME_WIDTH)
+}
+
+/// Formats one agent's row, with its CPU colored while it keeps a core busy.
+fn agent_row(agent: &AgentSummary) -> StyledLine {
+    let mut spans = vec![Span::plain(format!(
+        "{}{}{:<14}",
+        fit(&agent.workspace),
+        fit(&agent.command),
+        agent.state
+    ))];
+    match &agent.usage {
+        Some(usage) => {
+            let cpu_style = if usage.cpu_percent > BUSY_CPU_PERCENT {
+                Style {
+                    fg: RED,
+                    ..Style::default()
+                }
+            } else {
+                Style::default()
+            };
+            spans.push(Span::new(format!("{:>7.0}%", usage.cpu_percent), cpu_style));
+            spans.push(Span::plain(format!(
+                "{:>12}{:>11}",
+                format_bytes(usage.memory_bytes),
+                usage.processes
+            )));
+        }
+        None => spans.push(Span::new(
+            format!("{:>8}{:>12}{:>11}", "–", "–", "–"),
+            Style {
+                dim: true,
+                ..Style::default()
+            },
+        )),
+    }
+    StyledLine::new(spans)
+}
+
+impl BufferView for AgentDashboardBuffer {
+    fn line_count(&self) -> usize {
+        HEADER_ROWS + self.agents.len().max(1)
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        self.render_row(line)
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.render_row(line)
+            .map(|row| row.char_count())
+            .unwrap_or(0)
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        None
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &StyledLine) -> String {
+        line.spans.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    fn agent(workspace: &str, cpu_percent: f32) -> AgentSummary {
+        AgentSummary {
+            workspace: workspace.to_string(),
+            command: "claude".to_string(),
+            state: "running".to_string(),
+            usage: Some(ResourceUsage {
+                cpu_percent,
+                memory_bytes: 340 * 1024 * 1024,
+                processes: 4,
+            }),
+            queue_position: None,
+        }
+    }
+
+    #[test]
+    fn test_format_usage() {
+        let usage = ResourceUsage {
+            cpu_percent: 12.4,
+            memory_bytes: 1536,
+            processes: 1,
+        };
+        assert_eq!(format_usage(&usage), "12% CPU • 1.5 KB • 1 process");
+        assert_eq!(state_label(&AgentState::Exited { code: 2 }), "exited (2)");
+    }
+
+    #[test]
+    fn test_rows_highlight_busy_agents() {
+        let mut exited = agent("docs", 0.0);
+        exited.state = "exited (0)".to_string();
+        exited.usage = None;
+        let agents = vec![agent("app", 12.0), agent("api", 180.0), exited];
+        let buffer = AgentDashboardBuffer::new(agents, 4);
+
+        assert_eq!(buffer.line_count(), HEADER_ROWS + 3);
+        let first = text(&buffer.styled_line(HEADER_ROWS).unwrap());
+        assert!(first.starts_with("app "));
+        assert!(first.contains("claude") && first.contains("running"));
+        assert!(first.contains("12%") && first.contains("340.0 MB"));
+
+        let busy = buffer.styled_line(HEADER_ROWS + 1).unwrap();
+        assert_eq!(busy.spans[1].style.fg, RED);
+        let calm = buffer.styled_line(HEADER_ROWS).unwrap();
+        assert_eq!(calm.spans[1].style.fg, Style::default().fg);
+
+        let gone = text(&buffer.styled_line(HEADER_ROWS + 2).unwrap());
+        assert!(gone.contains("exited (0)") && gone.contains('–'));
+        assert!(buffer.styled_line(HEADER_ROWS + 3).is_none());
+    }
+
+    #[test]
+    fn test_no_agents_and_refresh() {
+        let mut buffer = AgentDashboardBuffer::new(Vec::new(), 4);
+        assert_eq!(buffer.line_count(), HEADER_ROWS + 1);
+        let empty = buffer.styled_line(HEADER_ROWS).unwrap();
+        assert_eq!(text(&empty), "No agents are running");
+        buffer.take_dirty();
+
+        // Unchanged figures don't redraw; new ones do
+        buffer.set_agents(Vec::new(), 4);
+        assert_eq!(buffer.take_dirty(), DirtyLines::None);
+        buffer.set_agents(vec![agent("app", 5.0)], 4);
+        assert_eq!(buffer.take_dirty(), DirtyLines::FromLineToEnd(0));
+        assert_eq!(buffer.agents().len(), 1);
+    }
+
+    // Chunk: docs/chunks/agent_queue - Queue position
+    #[test]
+    fn test_queued_agents_show_their_place() {
+        let agents = vec![
+            agent("app", 12.0),
+            AgentSummary::queued("lint parser", "/usr/local/bin/claude", 1),
+            AgentSummary::queued("lint lexer", "aider", 2),
+        ];
+        let buffer = AgentDashboardBuffer::new(agents, 1);
+
+        let limit = text(&buffer.styled_line(1).unwrap());
+        assert_eq!(
+            limit,
+            "2 queued, running at most 1 at once (Settings › Agents)"
+        );
+        let next = text(&buffer.styled_line(HEADER_ROWS + 1).unwrap());
+        assert!(next.starts_with("lint parser "));
+        assert!(next.contains("claude ") && !next.contains("/usr"));
+        assert!(next.contains("queued #1") && next.contains('–'));
+        let last = text(&buffer.styled_line(HEADER_ROWS + 2).unwrap());
+        assert!(last.contains("aider") && last.contains("queued #2"));
+    }
+}
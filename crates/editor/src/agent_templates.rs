@@ -0,0 +1,182 @@
+// Chunk: docs/chunks/agent_templates - Saved agent launch templates
+//!
+//! Agent templates.
+//!
+//! "New Agent…" (Cmd+Option+A) lists the templates saved under
+//! `"agents": { "templates": [...] }` in the config file and queues the
+//! chosen one as an agent in a workspace of its own:
+//!
+//! ```json
+//! {
+//!   "name": "Review PR",
+//!   "command": "claude",
+//!   "args": ["--model", "opus"],
+//!   "cwd": "services/api",
+//!   "env": { "GH_PAGER": "" },
+//!   "prompt": "Review the open pull request and summarize the risks."
+//! }
+//! ```
+//!
+//! Only `name` and `command` are required. A relative `cwd` is taken from
+//! the active workspace's root, and no `cwd` means the root itself. The
+//! `prompt` is typed into the agent the first time it waits for input.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use lite_edit_terminal::AgentConfig;
+use serde::{Deserialize, Serialize};
+
+/// A saved way of launching an agent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgentTemplate {
+    /// How the template is listed, and the name of the agent's workspace
+    pub name: String,
+    /// The agent's command, e.g. `"claude"`
+    pub command: String,
+    /// Arguments for the command
+    pub args: Vec<String>,
+    /// Working directory, relative to the workspace root unless absolute
+    pub cwd: Option<PathBuf>,
+    /// Environment variables set for the agent
+    pub env: BTreeMap<String, String>,
+    /// Text typed into the agent once it first waits for input
+    pub prompt: Option<String>,
+    /// Whether the agent reports structured events (see the Timeline tab)
+    pub structured_events: bool,
+}
+
+impl AgentTemplate {
+    /// Returns the command line the template runs, as listed in the picker.
+    pub fn command_line(&self) -> String {
+        std::iter::once(self.command.as_str())
+            .chain(self.args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns how the template is listed: its name, then its command line.
+    pub fn list_item(&self) -> String {
+        format!("{} — {}", self.name, self.command_line())
+    }
+
+    /// Returns the agent configuration for launching the template from the
+    /// workspace at `root`.
+    pub fn to_config(&self, root: &Path) -> AgentConfig {
+        let cwd = match &self.cwd {
+            Some(cwd) => root.join(cwd),
+            None => root.to_path_buf(),
+        };
+        let mut config = AgentConfig::new(self.command.as_str())
+            .with_args(self.args.clone())
+            .with_cwd(cwd);
+        for (key, value) in &self.env {
+            config = config.with_env(key.as_str(), value.as_str());
+        }
+        if let Some(prompt) = self.prompt.as_ref().filter(|p| !p.is_empty()) {
+            config = config.with_initial_prompt(prompt.as_str());
+        }
+        if self.structured_events {
+            config = config.with_structured_events();
+        }
+        config
+    }
+}
+
+/// Returns the templates that can be launched: those with a command, whose
+/// name or command line contains every whitespace-separated term of `query`
+/// (case-insensitive), in their configured order.
+pub fn filter_templates<'a>(templates: &'a [AgentTemplate], query: &str) -> Vec<&'a AgentTemplate> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    templates
+        .iter()
+        .filter(|template| !template.command.trim().is_empty())
+        .filter(|template| {
+            let haystack = template.list_item().to_lowercase();
+            terms.iter().all(|term| haystack.contains(term.as_str()))
+        })
+        .collect()
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(name: &str, command: &str) -> AgentTemplate {
+        AgentTemplate {
+            name: name.to_string(),
+            command: command.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_template_parses_with_only_name_and_command() {
+        let json = r#"{ "name": "Fix tests", "command": "aider", "env": { "B": "2", "A": "1" } }"#;
+        let parsed: AgentTemplate = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed.name, "Fix tests");
+        assert!(parsed.args.is_empty());
+        assert_eq!(parsed.cwd, None);
+        assert_eq!(parsed.prompt, None);
+        assert!(!parsed.structured_events);
+
+        let config = parsed.to_config(Path::new("/work"));
+        assert_eq!(config.cwd, PathBuf::from("/work"));
+        let env = vec![
+            ("A".to_string(), "1".to_string()),
+            ("B".to_string(), "2".to_string()),
+        ];
+        assert_eq!(config.env, env);
+        assert_eq!(config.initial_prompt, None);
+    }
+
+    #[test]
+    fn test_to_config_resolves_cwd_and_prompt() {
+        let mut review = template("Review", "claude");
+        review.args = vec!["--model".to_string(), "opus".to_string()];
+        review.cwd = Some(PathBuf::from("services/api"));
+        review.prompt = Some("Review the open PR".to_string());
+        review.structured_events = true;
+
+        let config = review.to_config(Path::new("/work"));
+        assert_eq!(config.command, "claude");
+        assert_eq!(config.args, vec!["--model", "opus"]);
+        assert_eq!(config.cwd, PathBuf::from("/work/services/api"));
+        assert_eq!(config.initial_prompt.as_deref(), Some("Review the open PR"));
+        assert!(config.structured_events);
+        // Built-in prompt patterns still follow the command
+        let builtin = AgentConfig::builtin_input_patterns("claude");
+        assert_eq!(config.input_patterns.len(), builtin.len());
+
+        review.cwd = Some(PathBuf::from("/elsewhere"));
+        review.prompt = Some(String::new());
+        let config = review.to_config(Path::new("/work"));
+        assert_eq!(config.cwd, PathBuf::from("/elsewhere"));
+        assert_eq!(config.initial_prompt, None);
+    }
+
+    #[test]
+    fn test_filter_templates_matches_name_and_command() {
+        let mut review = template("Review PR", "claude");
+        review.args = vec!["--model".to_string(), "opus".to_string()];
+        let broken = template("Broken", " ");
+        let templates = vec![review, template("Fix tests", "aider"), broken];
+        assert_eq!(templates[0].list_item(), "Review PR — claude --model opus");
+
+        let names = |query: &str| -> Vec<String> {
+            filter_templates(&templates, query)
+                .iter()
+                .map(|t| t.name.clone())
+                .collect()
+        };
+        assert_eq!(names(""), vec!["Review PR", "Fix tests"]);
+        assert_eq!(names("OPUS"), vec!["Review PR"]);
+        assert_eq!(names("fix aider"), vec!["Fix tests"]);
+        assert!(names("broken").is_empty());
+    }
+}
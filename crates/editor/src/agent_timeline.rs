@@ -0,0 +1,248 @@
+// Chunk: docs/chunks/agent_events - Agent event timeline
+//!
+//! The Timeline tab: what an agent says it is doing.
+//!
+//! An [`AgentTimelineBuffer`] lists the events a workspace's agent reported
+//! in structured mode (see `lite_edit_terminal::AgentEvent`), oldest first,
+//! each with the time since the agent started: progress, files edited and
+//! questions waiting for an answer. It opens in a pane beside the agent's
+//! terminal, so the raw output and the agent's own account of it can be
+//! read side by side. The table is presented through `BufferView` and
+//! refreshed by the editor as events arrive.
+
+use std::time::Duration;
+
+use lite_edit_buffer::{BufferView, CursorInfo, DirtyLines, Span, Style, StyledLine};
+use lite_edit_terminal::{AgentEvent, AgentTimeline, TimelineEntry};
+
+use crate::view_colors::{BLUE, MAUVE, YELLOW};
+
+/// Rows above the first event: title and a blank line.
+const HEADER_ROWS: usize = 2;
+
+/// The Timeline tab's list of events.
+pub struct AgentTimelineBuffer {
+    entries: Vec<TimelineEntry>,
+    /// The timeline revision shown, None before the first refresh.
+    revision: Option<u64>,
+    /// Whether the agent runs in structured mode and so reports events.
+    structured: bool,
+    dirty: DirtyLines,
+}
+
+impl AgentTimelineBuffer {
+    /// Creates an empty timeline for an agent that does or doesn't report
+    /// events.
+    pub fn new(structured: bool) -> Self {
+        Self {
+            entries: Vec::new(),
+            revision: None,
+            structured,
+            dirty: DirtyLines::FromLineToEnd(0),
+        }
+    }
+
+    /// Returns the listed events.
+    pub fn entries(&self) -> &[TimelineEntry] {
+        &self.entries
+    }
+
+    /// Shows the agent's events, redrawing only if there are new ones.
+    /// Returns true if the list changed.
+    pub fn set_timeline(&mut self, timeline: &AgentTimeline, structured: bool) -> bool {
+        let revision = Some(timeline.revision());
+        if revision == self.revision && structured == self.structured {
+            return false;
+        }
+        self.entries = timeline.entries().cloned().collect();
+        self.revision = revision;
+        self.structured = structured;
+        self.dirty.merge(DirtyLines::FromLineToEnd(0));
+        true
+    }
+
+    fn render_row(&self, row: usize) -> Option<StyledLine> {
+        let dim = Style {
+            dim: true,
+            ..Style::default()
+        };
+        let line = match row {
+            0 => StyledLine::new(vec![
+                Span::new(
+                    "Timeline",
+                    Style {
+                        fg: MAUVE,
+                        bold: true,
+                        ..Style::default()
+                    },
+                ),
+                Span::new("  what the agent reported, oldest first", dim),
+            ]),
+            1 => StyledLine::empty(),
+            _ if self.entries.is_empty() => match row - HEADER_ROWS {
+                0 if self.structured => StyledLine::new(vec![Span::new("No events yet", dim)]),
+                0 => StyledLine::new(vec![Span::new(
+                    "This agent doesn't report events; its state is inferred from its output",
+                    dim,
+                )]),
+                _ => return None,
+            },
+            _ => event_row(self.entries.get(row - HEADER_ROWS)?),
+        };
+        Some(line)
+    }
+}
+
+/// Formats time since the agent started, e.g. "0:05" or "1:02:03".
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    match secs / 3600 {
+        0 => format!("{}:{:02}", secs / 60, secs % 60),
+        hours => format!("{}:{:02}:{:02}", hours, secs / 60 % 60, secs % 60),
+    }
+}
+
+/// Formats one event's row: when it arrived, a marker and what it says.
+fn event_row(entry: &TimelineEntry) -> StyledLine {
+    let dim = Style {
+        dim: true,
+        ..Style::default()
+    };
+    let time = Span::new(format!("{:>8}  ", format_elapsed(entry.elapsed)), dim);
+    let colored = |fg| Style {
+        fg,
+        ..Style::default()
+    };
+    let spans = match &entry.event {
+        AgentEvent::Progress { message, fraction } => {
+            let mut text = format!("▸ {}", message);
+            if let Some(fraction) = fraction {
+                text.push_str(&format!(" ({:.0}%)", fraction.clamp(0.0, 1.0) * 100.0));
+            }
+            vec![time, Span::plain(text)]
+        }
+        AgentEvent::FileEdit {
+            path,
+            summary,
+            proposed,
+        } => {
+            let mut spans = vec![
+                time,
+                Span::new(format!("✎ {}", path.display()), colored(BLUE)),
+            ];
+            if let Some(summary) = summary {
+                spans.push(Span::plain(format!(" — {}", summary)));
+            }
+            // Chunk: docs/chunks/agent_edit_review - Edits waiting for review
+            if proposed.is_some() {
+                spans.push(Span::new(" (to review)", dim));
+            }
+            spans
+        }
+        AgentEvent::Question { text } => {
+            vec![time, Span::new(format!("? {}", text), colored(YELLOW))]
+        }
+    };
+    StyledLine::new(spans)
+}
+
+impl BufferView for AgentTimelineBuffer {
+    fn line_count(&self) -> usize {
+        HEADER_ROWS + self.entries.len().max(1)
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        self.render_row(line)
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.render_row(line)
+            .map(|row| row.char_count())
+            .unwrap_or(0)
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        None
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::time::Instant;
+
+    fn text(line: &StyledLine) -> String {
+        line.spans.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_rows_show_each_event() {
+        let started = Instant::now();
+        let mut timeline = AgentTimeline::new(started);
+        let progress = AgentEvent::Progress {
+            message: "Running tests".to_string(),
+            fraction: Some(0.4),
+        };
+        timeline.push(progress, started + Duration::from_secs(5));
+        let edit = AgentEvent::FileEdit {
+            path: PathBuf::from("src/lib.rs"),
+            summary: Some("Add retry".to_string()),
+            proposed: Some(PathBuf::from("/tmp/lib.rs.proposed")),
+        };
+        timeline.push(edit, started + Duration::from_secs(75));
+        let question = AgentEvent::Question {
+            text: "Deploy?".to_string(),
+        };
+        timeline.push(question, started + Duration::from_secs(3723));
+
+        let mut buffer = AgentTimelineBuffer::new(true);
+        assert!(buffer.set_timeline(&timeline, true));
+        assert_eq!(buffer.line_count(), HEADER_ROWS + 3);
+        let rows: Vec<_> = (HEADER_ROWS..HEADER_ROWS + 3)
+            .map(|row| text(&buffer.styled_line(row).unwrap()))
+            .collect();
+        assert_eq!(rows[0], "    0:05  ▸ Running tests (40%)");
+        assert_eq!(rows[1], "    1:15  ✎ src/lib.rs — Add retry (to review)");
+        assert_eq!(rows[2], " 1:02:03  ? Deploy?");
+        let question = buffer.styled_line(HEADER_ROWS + 2).unwrap();
+        assert_eq!(question.spans[1].style.fg, YELLOW);
+        assert!(buffer.styled_line(HEADER_ROWS + 3).is_none());
+    }
+
+    #[test]
+    fn test_refreshes_only_on_new_events() {
+        let started = Instant::now();
+        let mut timeline = AgentTimeline::new(started);
+        let mut buffer = AgentTimelineBuffer::new(false);
+        let empty = text(&buffer.styled_line(HEADER_ROWS).unwrap());
+        assert!(empty.starts_with("This agent doesn't report events"));
+
+        assert!(buffer.set_timeline(&timeline, true));
+        let empty = text(&buffer.styled_line(HEADER_ROWS).unwrap());
+        assert_eq!(empty, "No events yet");
+        buffer.take_dirty();
+        assert!(!buffer.set_timeline(&timeline, true));
+        assert_eq!(buffer.take_dirty(), DirtyLines::None);
+
+        let question = AgentEvent::Question {
+            text: "Deploy?".to_string(),
+        };
+        timeline.push(question, started);
+        assert!(buffer.set_timeline(&timeline, true));
+        assert_eq!(buffer.take_dirty(), DirtyLines::FromLineToEnd(0));
+        assert_eq!(buffer.entries().len(), 1);
+    }
+}
@@ -0,0 +1,211 @@
+// Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb strip over file panes
+//!
+//! The breadcrumbs shown along the top of a file pane.
+//!
+//! A file's trail reads `workspace ▸ src ▸ editor.rs ▸ Editor ▸ save`: the
+//! workspace, each component of the file's path relative to the workspace
+//! root, then the definitions enclosing the cursor. Each crumb says what a
+//! click on it opens ([`CrumbTarget`]); `EditorState` builds the trail and
+//! the renderer draws [`crumb_text`] in a one-line strip.
+
+use std::path::{Component, Path};
+
+/// Drawn between crumbs.
+pub const CRUMB_SEPARATOR: &str = " ▸ ";
+
+/// What clicking a crumb opens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrumbTarget {
+    /// The workspace switcher
+    Workspace,
+    /// The file picker, with its query set to this directory (relative to
+    /// the workspace root and ending in `/`; empty for the root itself)
+    Directory(String),
+    /// The file's symbol outline, with the symbol whose name starts at this
+    /// byte offset selected
+    Symbol(usize),
+}
+
+/// One step of the trail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crumb {
+    pub label: String,
+    pub target: CrumbTarget,
+}
+
+/// Returns the trail for the file at `relative_path` in the workspace
+/// labelled `workspace`, inside the definitions `symbols` (name and name
+/// byte offset, outermost first).
+///
+/// A directory's crumb filters the file picker to that directory, and the
+/// file name's crumb to the directory holding the file.
+pub fn file_crumbs(
+    workspace: &str,
+    relative_path: &Path,
+    symbols: &[(String, usize)],
+) -> Vec<Crumb> {
+    let mut crumbs = vec![Crumb {
+        label: workspace.to_string(),
+        target: CrumbTarget::Workspace,
+    }];
+    let names: Vec<String> = relative_path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect();
+    let mut directory = String::new();
+    for (i, name) in names.iter().enumerate() {
+        if i + 1 < names.len() {
+            directory.push_str(name);
+            directory.push('/');
+        }
+        crumbs.push(Crumb {
+            label: name.clone(),
+            target: CrumbTarget::Directory(directory.clone()),
+        });
+    }
+    crumbs.extend(symbols.iter().map(|(name, name_byte)| Crumb {
+        label: name.clone(),
+        target: CrumbTarget::Symbol(*name_byte),
+    }));
+    crumbs
+}
+
+/// Returns the strip's text: the crumbs' labels joined by separators.
+pub fn crumb_text(crumbs: &[Crumb]) -> String {
+    crumbs
+        .iter()
+        .map(|crumb| crumb.label.as_str())
+        .collect::<Vec<_>>()
+        .join(CRUMB_SEPARATOR)
+}
+
+/// Returns the index of the crumb drawn at char column `col` of
+/// [`crumb_text`], or `None` for a separator or past the end.
+pub fn crumb_at_column(crumbs: &[Crumb], col: usize) -> Option<usize> {
+    let separator_len = CRUMB_SEPARATOR.chars().count();
+    let mut start = 0;
+    for (index, crumb) in crumbs.iter().enumerate() {
+        let end = start + crumb.label.chars().count();
+        if (start..end).contains(&col) {
+            return Some(index);
+        }
+        start = end + separator_len;
+    }
+    None
+}
+
+/// An entry in a file's symbol outline, the list a symbol crumb opens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineItem {
+    pub name: String,
+    /// Number of definitions enclosing this one
+    pub depth: usize,
+    /// Byte offset of the name, as in [`CrumbTarget::Symbol`]
+    pub name_byte: usize,
+    /// Line and char column of the name
+    pub line: usize,
+    pub col: usize,
+}
+
+impl OutlineItem {
+    /// Returns the item's row in the outline, indented by depth.
+    pub fn label(&self) -> String {
+        format!("{}{}", "  ".repeat(self.depth), self.name)
+    }
+}
+
+/// Returns the items whose name contains every whitespace-separated term of
+/// `query` (case-insensitive), in outline order.
+pub fn filter_outline<'a>(items: &'a [OutlineItem], query: &str) -> Vec<&'a OutlineItem> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    items
+        .iter()
+        .filter(|item| {
+            let name = item.name.to_lowercase();
+            terms.iter().all(|term| name.contains(term.as_str()))
+        })
+        .collect()
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbols() -> Vec<(String, usize)> {
+        vec![("Editor".to_string(), 40), ("save".to_string(), 120)]
+    }
+
+    #[test]
+    fn test_file_crumbs_targets() {
+        let path = Path::new("crates/editor/src/main.rs");
+        let crumbs = file_crumbs("lite-edit", path, &symbols());
+        let dir = |path: &str| CrumbTarget::Directory(path.to_string());
+        let targets: Vec<(&str, CrumbTarget)> = crumbs
+            .iter()
+            .map(|crumb| (crumb.label.as_str(), crumb.target.clone()))
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                ("lite-edit", CrumbTarget::Workspace),
+                ("crates", dir("crates/")),
+                ("editor", dir("crates/editor/")),
+                ("src", dir("crates/editor/src/")),
+                ("main.rs", dir("crates/editor/src/")),
+                ("Editor", CrumbTarget::Symbol(40)),
+                ("save", CrumbTarget::Symbol(120)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_file_at_workspace_root() {
+        let crumbs = file_crumbs("notes", Path::new("todo.md"), &[]);
+        assert_eq!(crumb_text(&crumbs), "notes ▸ todo.md");
+        assert_eq!(crumbs[1].target, CrumbTarget::Directory(String::new()));
+    }
+
+    #[test]
+    fn test_crumb_at_column() {
+        let crumbs = file_crumbs("ws", Path::new("src/a.rs"), &symbols());
+        // "ws ▸ src ▸ a.rs ▸ Editor ▸ save"
+        assert_eq!(crumb_text(&crumbs), "ws ▸ src ▸ a.rs ▸ Editor ▸ save");
+        assert_eq!(crumb_at_column(&crumbs, 0), Some(0));
+        assert_eq!(crumb_at_column(&crumbs, 1), Some(0));
+        assert_eq!(crumb_at_column(&crumbs, 3), None);
+        assert_eq!(crumb_at_column(&crumbs, 5), Some(1));
+        assert_eq!(crumb_at_column(&crumbs, 18), Some(3));
+        assert_eq!(crumb_at_column(&crumbs, 30), Some(4));
+        assert_eq!(crumb_at_column(&crumbs, 31), None);
+    }
+
+    #[test]
+    fn test_outline_labels_and_filter() {
+        let item = |name: &str, depth| OutlineItem {
+            name: name.to_string(),
+            depth,
+            name_byte: 0,
+            line: 0,
+            col: 0,
+        };
+        let items = vec![item("Editor", 0), item("save", 1), item("save_as", 1)];
+        assert_eq!(items[1].label(), "  save");
+
+        let names = |query| -> Vec<String> {
+            filter_outline(&items, query)
+                .iter()
+                .map(|item| item.name.clone())
+                .collect()
+        };
+        assert_eq!(names("SAVE"), vec!["save", "save_as"]);
+        assert_eq!(names("save as"), vec!["save_as"]);
+        assert_eq!(names("").len(), 3);
+    }
+}
@@ -20,9 +20,7 @@
 use crate::context::EditorContext;
 use crate::focus::{FocusLayer, FocusTarget, Handled};
 use crate::font::FontMetrics;
-use crate::input::{Key, KeyEvent, MouseEvent, MouseEventKind, ScrollDelta};
-// Chunk: docs/chunks/tab_rendering - Tab-aware visual column to character column conversion
-use crate::tab_width;
+use crate::input::{Key, KeyEvent, MouseButton, MouseEvent, MouseEventKind, ScrollDelta};
 use crate::viewport::Viewport;
 use crate::wrap_layout::WrapLayout;
 use lite_edit_buffer::Position;
@@ -31,7 +29,7 @@ use lite_edit_buffer::Position;
 ///
 /// These are resolved from key events by the stateless `resolve_command` function.
 #[derive(Debug, Clone, PartialEq)]
-enum Command {
+pub(crate) enum Command {
     /// Insert a character at the cursor
     InsertChar(char),
     /// Insert a newline at the cursor
@@ -62,6 +60,9 @@ enum Command {
     MoveDown,
     /// Move cursor to the start of the line
     MoveToLineStart,
+    // Chunk: docs/chunks/smart_home_navigation - Smart Home and word selection commands
+    /// Move cursor to the first non-whitespace character, then column 0 (Cmd+Left, Home)
+    MoveToSmartLineStart,
     /// Move cursor to the end of the line
     MoveToLineEnd,
     /// Move cursor to the start of the buffer
@@ -86,6 +87,12 @@ enum Command {
     SelectDown,
     /// Extend selection to line start (Shift+Home, Shift+Cmd+Left)
     SelectToLineStart,
+    /// Extend selection to the first non-whitespace character, then column 0 (Shift+Cmd+Left, Shift+Home)
+    SelectToSmartLineStart,
+    /// Extend selection to the previous word boundary (Shift+Option+Left)
+    SelectWordLeft,
+    /// Extend selection to the next word boundary (Shift+Option+Right)
+    SelectWordRight,
     /// Extend selection to line end (Shift+End, Shift+Cmd+Right)
     SelectToLineEnd,
     /// Extend selection to buffer start (Shift+Cmd+Up)
@@ -102,6 +109,24 @@ enum Command {
     // Chunk: docs/chunks/clipboard_cut - Cut command variant
     /// Cut selection to clipboard (Cmd+X)
     Cut,
+    // Chunk: docs/chunks/emacs_kill_ring - Mark and kill ring commands
+    /// Set the mark at the cursor so plain movement extends a selection (Ctrl+Space)
+    SetMark,
+    /// Deactivate the mark and clear the selection (Ctrl+G)
+    CancelMark,
+    /// Kill the selection into the kill ring (Ctrl+W)
+    KillRegion,
+    /// Insert the newest kill (Ctrl+Y)
+    Yank,
+    /// Replace the text just yanked with the next older kill (Option+Y)
+    YankPop,
+    // Chunk: docs/chunks/move_duplicate_lines - Line move and duplicate commands
+    /// Move the current line or selected lines up one line (Option+Up)
+    MoveLinesUp,
+    /// Move the current line or selected lines down one line (Option+Down)
+    MoveLinesDown,
+    /// Duplicate the current line or selected lines below them (Cmd+Shift+D)
+    DuplicateLines,
     // Chunk: docs/chunks/viewport_emacs_navigation - Page Up/Down navigation
     /// Scroll viewport and cursor up by one page
     PageUp,
@@ -121,7 +146,7 @@ enum Command {
 /// modifier+key combinations, so no state machine is needed.
 // Chunk: docs/chunks/shift_arrow_selection - Shift+Arrow key selection
 // Chunk: docs/chunks/line_nav_keybindings - Home/End and Ctrl+A/Ctrl+E line navigation
-fn resolve_command(event: &KeyEvent) -> Option<Command> {
+pub(crate) fn resolve_command(event: &KeyEvent) -> Option<Command> {
     let mods = &event.modifiers;
 
     match &event.key {
@@ -148,6 +173,10 @@ fn resolve_command(event: &KeyEvent) -> Option<Command> {
         // Option+D → delete forward by word (must come before generic Char)
         Key::Char('d') if mods.option && !mods.command => Some(Command::DeleteForwardWord),
 
+        // Chunk: docs/chunks/smart_home_navigation - Option+Forward Delete deletes a word
+        // Option+Delete (forward delete) → delete forward by word
+        Key::Delete if mods.option && !mods.command => Some(Command::DeleteForwardWord),
+
         // Backspace (Delete backward)
         Key::Backspace => Some(Command::DeleteBackward),
 
@@ -157,15 +186,20 @@ fn resolve_command(event: &KeyEvent) -> Option<Command> {
         // === Selection commands (Shift held) ===
         // Chunk: docs/chunks/shift_arrow_selection - Shift+Arrow key selection
 
+        // Chunk: docs/chunks/smart_home_navigation - Shift+Option+Left/Right select by word
+        // Shift+Option+Left/Right → extend selection by word (must come before Shift+Left/Right)
+        Key::Left if mods.shift && mods.option && !mods.command => Some(Command::SelectWordLeft),
+        Key::Right if mods.shift && mods.option && !mods.command => Some(Command::SelectWordRight),
+
         // Shift+Arrow keys (without Command) → extend selection
         Key::Left if mods.shift && !mods.command => Some(Command::SelectLeft),
         Key::Right if mods.shift && !mods.command => Some(Command::SelectRight),
         Key::Up if mods.shift && !mods.command => Some(Command::SelectUp),
         Key::Down if mods.shift && !mods.command => Some(Command::SelectDown),
 
-        // Shift+Cmd+Left or Shift+Home → select to line start
-        Key::Left if mods.shift && mods.command => Some(Command::SelectToLineStart),
-        Key::Home if mods.shift => Some(Command::SelectToLineStart),
+        // Shift+Cmd+Left or Shift+Home → select to first non-whitespace, then line start
+        Key::Left if mods.shift && mods.command => Some(Command::SelectToSmartLineStart),
+        Key::Home if mods.shift => Some(Command::SelectToSmartLineStart),
 
         // Shift+Cmd+Right or Shift+End → select to line end
         Key::Right if mods.shift && mods.command => Some(Command::SelectToLineEnd),
@@ -196,15 +230,21 @@ fn resolve_command(event: &KeyEvent) -> Option<Command> {
         // Option+Right → move word right (must come before plain Right)
         Key::Right if mods.option && !mods.command && !mods.shift => Some(Command::MoveWordRight),
 
+        // Chunk: docs/chunks/move_duplicate_lines - Option+Up/Down move lines
+        // Option+Up/Down → move lines (must come before plain Up/Down)
+        Key::Up if mods.option && !mods.command && !mods.shift => Some(Command::MoveLinesUp),
+        Key::Down if mods.option && !mods.command && !mods.shift => Some(Command::MoveLinesDown),
+
         // Arrow keys
         Key::Left if !mods.command => Some(Command::MoveLeft),
         Key::Right if !mods.command => Some(Command::MoveRight),
         Key::Up if !mods.command => Some(Command::MoveUp),
         Key::Down if !mods.command => Some(Command::MoveDown),
 
-        // Cmd+Left or Home → start of line
-        Key::Left if mods.command => Some(Command::MoveToLineStart),
-        Key::Home => Some(Command::MoveToLineStart),
+        // Chunk: docs/chunks/smart_home_navigation - Cmd+Left and Home are smart
+        // Cmd+Left or Home → first non-whitespace character, then start of line
+        Key::Left if mods.command => Some(Command::MoveToSmartLineStart),
+        Key::Home => Some(Command::MoveToSmartLineStart),
 
         // Cmd+Right or End → end of line
         Key::Right if mods.command => Some(Command::MoveToLineEnd),
@@ -230,6 +270,11 @@ fn resolve_command(event: &KeyEvent) -> Option<Command> {
         // Cmd+X → cut selection to clipboard
         Key::Char('x') if mods.command && !mods.control => Some(Command::Cut),
 
+        // Chunk: docs/chunks/move_duplicate_lines - Cmd+Shift+D duplicates lines
+        Key::Char('d') if mods.command && mods.shift && !mods.control => {
+            Some(Command::DuplicateLines)
+        }
+
         // Ctrl+A → start of line (Emacs-style)
         Key::Char('a') if mods.control && !mods.command => Some(Command::MoveToLineStart),
 
@@ -240,6 +285,22 @@ fn resolve_command(event: &KeyEvent) -> Option<Command> {
         // Ctrl+K → kill line (delete to end of line)
         Key::Char('k') if mods.control && !mods.command => Some(Command::DeleteToLineEnd),
 
+        // Chunk: docs/chunks/emacs_kill_ring - Mark and kill ring bindings
+        // Ctrl+Space → set mark
+        Key::Char(' ') if mods.control && !mods.command => Some(Command::SetMark),
+
+        // Ctrl+G → deactivate mark (keyboard-quit)
+        Key::Char('g') if mods.control && !mods.command => Some(Command::CancelMark),
+
+        // Ctrl+W → kill region
+        Key::Char('w') if mods.control && !mods.command => Some(Command::KillRegion),
+
+        // Ctrl+Y → yank
+        Key::Char('y') if mods.control && !mods.command => Some(Command::Yank),
+
+        // Option+Y → yank-pop (Meta+Y)
+        Key::Char('y') if mods.option && !mods.command && !mods.control => Some(Command::YankPop),
+
         // Chunk: docs/chunks/viewport_emacs_navigation - Page Up/Down and Emacs navigation bindings
         // Page Up / Page Down → scroll by viewport height
         Key::PageUp => Some(Command::PageUp),
@@ -277,22 +338,129 @@ fn resolve_command(event: &KeyEvent) -> Option<Command> {
     }
 }
 
+// Chunk: docs/chunks/emacs_kill_ring - Plain movement commands an active mark extends
+impl Command {
+    /// Returns true for cursor movement that doesn't edit or select by itself.
+    fn is_movement(&self) -> bool {
+        matches!(
+            self,
+            Command::MoveLeft
+                | Command::MoveRight
+                | Command::MoveUp
+                | Command::MoveDown
+                | Command::MoveToLineStart
+                | Command::MoveToSmartLineStart
+                | Command::MoveToLineEnd
+                | Command::MoveToBufferStart
+                | Command::MoveToBufferEnd
+                | Command::MoveWordLeft
+                | Command::MoveWordRight
+                | Command::PageUp
+                | Command::PageDown
+        )
+    }
+
+    // Chunk: docs/chunks/column_selection - Commands that act on every row of a block
+    /// Returns true for commands that apply to a block selection as a whole.
+    /// Any other command ends the block first.
+    fn edits_block(&self) -> bool {
+        matches!(
+            self,
+            Command::InsertChar(_)
+                | Command::InsertTab
+                | Command::DeleteBackward
+                | Command::DeleteForward
+                | Command::Copy
+                | Command::Cut
+                | Command::Paste
+        )
+    }
+}
+
+// Chunk: docs/chunks/read_only_preview_tabs - Commands a read-only buffer refuses
+impl Command {
+    /// Returns true for commands that change the buffer's content.
+    fn mutates(&self) -> bool {
+        matches!(
+            self,
+            Command::InsertChar(_)
+                | Command::InsertNewline
+                | Command::InsertTab
+                | Command::DeleteBackward
+                | Command::DeleteForward
+                | Command::DeleteBackwardWord
+                | Command::DeleteForwardWord
+                | Command::DeleteToLineEnd
+                | Command::DeleteToLineStart
+                | Command::Paste
+                | Command::Cut
+                | Command::KillRegion
+                | Command::Yank
+                | Command::YankPop
+                | Command::MoveLinesUp
+                | Command::MoveLinesDown
+                | Command::DuplicateLines
+        )
+    }
+}
+
+// Chunk: docs/chunks/emacs_kill_ring - Text removed by kill-line
+/// Returns the text Ctrl+K would delete: the rest of the line, or the
+/// newline when the cursor is already at the line end.
+fn text_to_line_end(buffer: &lite_edit_buffer::TextBuffer) -> String {
+    let cursor = buffer.cursor_position();
+    if cursor.col < buffer.line_len(cursor.line) {
+        buffer
+            .line_content(cursor.line)
+            .chars()
+            .skip(cursor.col)
+            .collect()
+    } else if cursor.line + 1 < buffer.line_count() {
+        "\n".to_string()
+    } else {
+        String::new()
+    }
+}
+
 /// The focus target for the main text buffer.
 ///
-/// Handles standard editing keystrokes via stateless chord resolution.
+/// Handles standard editing keystrokes via stateless chord resolution. The
+/// only state kept between keystrokes is the Emacs mark and whether the
+/// previous command was a kill or a yank.
 #[derive(Debug, Default)]
-pub struct BufferFocusTarget;
+pub struct BufferFocusTarget {
+    // Chunk: docs/chunks/emacs_kill_ring - Mark and kill/yank sequence state
+    /// Whether Ctrl+Space set a mark that plain movement extends from
+    mark_active: bool,
+    /// Cursor position after the previous command's kill, if it was a kill.
+    /// A kill starting there appends to the same ring entry.
+    last_kill_end: Option<Position>,
+    /// The text inserted by the previous command, if it was a yank
+    last_yank: Option<YankSpan>,
+    // Chunk: docs/chunks/column_selection - Option+drag anchor
+    /// Where an Option+drag block selection started (line, display column)
+    block_drag_anchor: Option<Position>,
+}
+
+// Chunk: docs/chunks/emacs_kill_ring - Span replaced by yank-pop
+/// Where a yank inserted its text and which ring entry it was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct YankSpan {
+    start: Position,
+    end: Position,
+    ring_index: usize,
+}
 
 impl BufferFocusTarget {
     /// Creates a new BufferFocusTarget.
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
 
     /// Executes a command on the buffer through the editor context.
     // Chunk: docs/chunks/line_nav_keybindings - MoveToLineStart/MoveToLineEnd execution
     // Chunk: docs/chunks/incremental_parse - Use tracked variants for incremental parsing
-    fn execute_command(&self, cmd: Command, ctx: &mut EditorContext) {
+    fn execute_command(&mut self, cmd: Command, ctx: &mut EditorContext) {
         // Chunk: docs/chunks/incremental_parse - Use tracked variants to capture edit info
         // For mutation commands, use the `_tracked` variants that return MutationResult
         // with edit info for incremental syntax parsing.
@@ -338,8 +506,27 @@ impl BufferFocusTarget {
             }
             // Chunk: docs/chunks/kill_line - Execute DeleteToLineEnd command
             // Chunk: docs/chunks/incremental_parse - Use tracked variant for incremental parsing
+            // Chunk: docs/chunks/emacs_kill_ring - Kill-line feeds the kill ring
             Command::DeleteToLineEnd => {
+                let killed = text_to_line_end(ctx.buffer);
                 let result = ctx.buffer.delete_to_line_end_tracked();
+                if result.edit_info.is_some() {
+                    self.record_kill(&killed, ctx.buffer.cursor_position());
+                }
+                ctx.edit_info = result.edit_info;
+                result.dirty_lines
+            }
+            // Chunk: docs/chunks/move_duplicate_lines - Line moves are single tracked edits
+            Command::MoveLinesUp | Command::MoveLinesDown | Command::DuplicateLines => {
+                let result = match cmd {
+                    Command::MoveLinesUp => ctx.buffer.move_lines_up_tracked(),
+                    Command::MoveLinesDown => ctx.buffer.move_lines_down_tracked(),
+                    _ => ctx.buffer.duplicate_lines_tracked(),
+                };
+                if result.edit_info.is_none() {
+                    // Already at the top or bottom of the buffer
+                    return;
+                }
                 ctx.edit_info = result.edit_info;
                 result.dirty_lines
             }
@@ -381,6 +568,11 @@ impl BufferFocusTarget {
                 ctx.mark_cursor_dirty();
                 return;
             }
+            Command::MoveToSmartLineStart => {
+                ctx.buffer.move_to_smart_line_start();
+                ctx.mark_cursor_dirty();
+                return;
+            }
             Command::MoveToLineEnd => {
                 ctx.buffer.move_to_line_end();
                 ctx.mark_cursor_dirty();
@@ -439,6 +631,19 @@ impl BufferFocusTarget {
                 self.extend_selection_with_move(ctx, |buf| buf.move_to_line_start());
                 return;
             }
+            // Chunk: docs/chunks/smart_home_navigation - Smart Home and word selection execution
+            Command::SelectToSmartLineStart => {
+                self.extend_selection_with_move(ctx, |buf| buf.move_to_smart_line_start());
+                return;
+            }
+            Command::SelectWordLeft => {
+                self.extend_selection_with_move(ctx, |buf| buf.move_word_left());
+                return;
+            }
+            Command::SelectWordRight => {
+                self.extend_selection_with_move(ctx, |buf| buf.move_word_right());
+                return;
+            }
             Command::SelectToLineEnd => {
                 self.extend_selection_with_move(ctx, |buf| buf.move_to_line_end());
                 return;
@@ -460,8 +665,9 @@ impl BufferFocusTarget {
             }
             Command::Copy => {
                 // Get selected text; no-op if no selection
+                // Chunk: docs/chunks/emacs_kill_ring - Copies join the kill ring
                 if let Some(text) = ctx.buffer.selected_text() {
-                    crate::clipboard::copy_to_clipboard(&text);
+                    crate::clipboard_history::record(&text);
                 }
                 // Do not modify buffer or clear selection (standard copy behavior)
                 return;
@@ -485,7 +691,8 @@ impl BufferFocusTarget {
                 // Get selected text; if no selection, this is a no-op
                 if let Some(text) = ctx.buffer.selected_text() {
                     // Copy to clipboard first (before mutation)
-                    crate::clipboard::copy_to_clipboard(&text);
+                    // Chunk: docs/chunks/emacs_kill_ring - Cuts join the kill ring
+                    crate::clipboard_history::record(&text);
                     // Delete the selection using tracked variant
                     let result = ctx.buffer.delete_selection_tracked();
                     ctx.edit_info = result.edit_info;
@@ -495,6 +702,49 @@ impl BufferFocusTarget {
                 }
                 return;
             }
+            // Chunk: docs/chunks/emacs_kill_ring - Mark, kill-region and yank execution
+            Command::SetMark => {
+                if ctx.buffer.has_selection() {
+                    ctx.dirty_region
+                        .merge(crate::dirty_region::DirtyRegion::FullViewport);
+                }
+                ctx.buffer.set_selection_anchor_at_cursor();
+                self.mark_active = true;
+                return;
+            }
+            Command::CancelMark => {
+                if ctx.buffer.has_selection() {
+                    ctx.dirty_region
+                        .merge(crate::dirty_region::DirtyRegion::FullViewport);
+                }
+                ctx.buffer.clear_selection();
+                return;
+            }
+            Command::KillRegion => {
+                // Like Cut, but consecutive kills append to one ring entry
+                if let Some(text) = ctx.buffer.selected_text() {
+                    let result = ctx.buffer.delete_selection_tracked();
+                    self.record_kill(&text, ctx.buffer.cursor_position());
+                    ctx.edit_info = result.edit_info;
+                    ctx.mark_dirty(result.dirty_lines);
+                    ctx.set_content_mutated();
+                    ctx.ensure_cursor_visible();
+                }
+                return;
+            }
+            Command::Yank => {
+                self.insert_yank(ctx, 0);
+                return;
+            }
+            Command::YankPop => {
+                // Only valid straight after a yank whose text is still under the cursor
+                let Some(span) = self.last_yank else {
+                    return;
+                };
+                ctx.buffer.set_selection_anchor(span.start);
+                self.insert_yank(ctx, span.ring_index + 1);
+                return;
+            }
             // Chunk: docs/chunks/viewport_emacs_navigation - Page Up/Down command execution
             Command::PageUp => {
                 // Get page size from viewport (number of screen rows visible)
@@ -503,17 +753,15 @@ impl BufferFocusTarget {
                     return;
                 }
 
-                // Get current cursor position and preserve column
+                // Get current cursor position
                 let cursor = ctx.buffer.cursor_position();
-                let current_col = cursor.col;
 
                 // Calculate new line, clamped to buffer bounds
                 let new_line = cursor.line.saturating_sub(page_size);
 
-                // Move cursor to new position, clamping column to line length
-                let line_len = ctx.buffer.line_len(new_line);
-                let new_col = current_col.min(line_len);
-                ctx.buffer.set_cursor(Position::new(new_line, new_col));
+                // Move cursor to the new line, preserving its display column
+                // Chunk: docs/chunks/unicode_width_awareness - Display column across wide chars
+                ctx.buffer.move_to_line(new_line);
 
                 // Scroll viewport up by page_size lines (in pixels)
                 let line_height = ctx.viewport.line_height();
@@ -544,18 +792,16 @@ impl BufferFocusTarget {
                     return;
                 }
 
-                // Get current cursor position and preserve column
+                // Get current cursor position
                 let cursor = ctx.buffer.cursor_position();
-                let current_col = cursor.col;
 
                 // Calculate new line, clamped to buffer bounds
                 let last_line = ctx.buffer.line_count().saturating_sub(1);
                 let new_line = (cursor.line + page_size).min(last_line);
 
-                // Move cursor to new position, clamping column to line length
-                let line_len = ctx.buffer.line_len(new_line);
-                let new_col = current_col.min(line_len);
-                ctx.buffer.set_cursor(Position::new(new_line, new_col));
+                // Move cursor to the new line, preserving its display column
+                // Chunk: docs/chunks/unicode_width_awareness - Display column across wide chars
+                ctx.buffer.move_to_line(new_line);
 
                 // Scroll viewport down by page_size lines (in pixels)
                 let line_height = ctx.viewport.line_height();
@@ -601,6 +847,64 @@ impl BufferFocusTarget {
         ctx.ensure_cursor_visible();
     }
 
+    // Chunk: docs/chunks/emacs_kill_ring - Mark and kill/yank sequence bookkeeping
+    /// Ends the mark, kill and yank sequences that `cmd` doesn't continue.
+    ///
+    /// The mark stays active only through plain movement. A kill appends to
+    /// the previous one, and a yank-pop replaces the previous yank, only if
+    /// nothing moved the cursor in between.
+    fn begin_command(&mut self, cmd: &Command, ctx: &EditorContext) {
+        let cursor = ctx.buffer.cursor_position();
+
+        if !cmd.is_movement() || ctx.buffer.selection_anchor().is_none() {
+            self.mark_active = false;
+        }
+        if !matches!(cmd, Command::DeleteToLineEnd | Command::KillRegion)
+            || self.last_kill_end != Some(cursor)
+        {
+            self.last_kill_end = None;
+        }
+        let yank_under_cursor = self
+            .last_yank
+            .is_some_and(|span| span.end == cursor && !ctx.buffer.has_selection());
+        if *cmd != Command::YankPop || !yank_under_cursor {
+            self.last_yank = None;
+        }
+    }
+
+    /// Pushes killed text onto the kill ring, appending to the previous kill
+    /// if this continues a run of kills.
+    fn record_kill(&mut self, text: &str, cursor: Position) {
+        if self.last_kill_end.is_some() {
+            crate::clipboard_history::record_append(text);
+        } else {
+            crate::clipboard_history::record(text);
+        }
+        self.last_kill_end = Some(cursor);
+    }
+
+    /// Inserts the kill ring entry `ring_index` steps back, replacing any
+    /// selection, and remembers where it went for a following yank-pop.
+    fn insert_yank(&mut self, ctx: &mut EditorContext, ring_index: usize) {
+        let Some(text) = crate::clipboard_history::yank(ring_index) else {
+            return;
+        };
+        let start = match ctx.buffer.selection_range() {
+            Some((start, _)) => start,
+            None => ctx.buffer.cursor_position(),
+        };
+        let result = ctx.buffer.insert_str_tracked(&text);
+        ctx.edit_info = result.edit_info;
+        ctx.mark_dirty(result.dirty_lines);
+        ctx.set_content_mutated();
+        ctx.ensure_cursor_visible();
+        self.last_yank = Some(YankSpan {
+            start,
+            end: ctx.buffer.cursor_position(),
+            ring_index,
+        });
+    }
+
     /// Extends the selection by executing a movement operation.
     ///
     /// This implements the core selection extension logic:
@@ -654,8 +958,29 @@ impl FocusTarget for BufferFocusTarget {
 
     fn handle_key(&mut self, event: KeyEvent, ctx: &mut EditorContext) -> Handled {
         match resolve_command(&event) {
+            // Chunk: docs/chunks/read_only_preview_tabs - Refuse edits to read-only buffers
+            Some(cmd) if ctx.read_only && cmd.mutates() => {
+                ctx.edit_refused = true;
+                Handled::Yes
+            }
             Some(cmd) => {
-                self.execute_command(cmd, ctx);
+                // Chunk: docs/chunks/column_selection - Other commands end the block
+                if ctx.buffer.block_selection().is_some() && !cmd.edits_block() {
+                    ctx.buffer.clear_selection();
+                    ctx.dirty_region
+                        .merge(crate::dirty_region::DirtyRegion::FullViewport);
+                }
+                self.begin_command(&cmd, ctx);
+                // Chunk: docs/chunks/emacs_kill_ring - Movement extends the selection from an active mark
+                if self.mark_active {
+                    let anchor = ctx.buffer.selection_anchor();
+                    self.execute_command(cmd, ctx);
+                    if let Some(anchor) = anchor {
+                        ctx.buffer.set_selection_anchor(anchor);
+                    }
+                } else {
+                    self.execute_command(cmd, ctx);
+                }
                 Handled::Yes
             }
             None => Handled::No,
@@ -670,14 +995,28 @@ impl FocusTarget for BufferFocusTarget {
         // Positive dy = scroll down (content moves up, scroll_offset increases)
         // Negative dy = scroll up (content moves down, scroll_offset decreases)
         let current_px = ctx.viewport.scroll_offset_px();
-        let new_px = current_px + delta.dy as f32;
-
         let line_count = ctx.buffer.line_count();
 
         // Use wrap-aware clamping to compute max scroll position based on total
         // screen rows, not buffer lines. This fixes the scroll deadzone at the
         // bottom when wrapped lines produce more screen rows than buffer lines.
         let wrap_layout = ctx.wrap_layout();
+
+        // Chunk: docs/chunks/smooth_scroll_animation - Ease wheel ticks toward the target
+        // Animated deltas accumulate on the running animation's target; the
+        // scroll frame timer moves the visible offset, so nothing is dirty yet.
+        if delta.animate {
+            let target_px = ctx.viewport.scroll_target_px() + delta.dy as f32;
+            ctx.viewport.animate_scroll_offset_px_wrapped(
+                target_px,
+                line_count,
+                &wrap_layout,
+                |line| ctx.buffer.line_len(line),
+            );
+            return;
+        }
+
+        let new_px = current_px + delta.dy as f32;
         ctx.viewport.set_scroll_offset_px_wrapped(
             new_px,
             line_count,
@@ -696,12 +1035,71 @@ impl FocusTarget for BufferFocusTarget {
     // Chunk: docs/chunks/mouse_drag_selection - Mouse drag selection
     // Chunk: docs/chunks/word_double_click_select - Double-click word selection
     // Chunk: docs/chunks/line_wrap_rendering - Wrap-aware hit-testing
+    // Chunk: docs/chunks/column_selection - Option+drag block selection
     fn handle_mouse(&mut self, event: MouseEvent, ctx: &mut EditorContext) {
         // Create wrap layout for hit-testing
         let wrap_layout = ctx.wrap_layout();
 
+        // Chunk: docs/chunks/mouse_buttons - Only the left button selects
+        // A right-click moves the cursor to the click, unless it lands in the
+        // selection, so the context menu acts on what was clicked.
+        if event.button != MouseButton::Left {
+            if event.button == MouseButton::Right && event.kind == MouseEventKind::Down {
+                let position = pixel_to_buffer_position_wrapped(
+                    event.position,
+                    ctx.view_height,
+                    &wrap_layout,
+                    ctx.viewport.scroll_fraction_px(),
+                    ctx.viewport.first_visible_line(),
+                    ctx.buffer.line_count(),
+                    |line| ctx.buffer.line_len(line),
+                    |line| ctx.buffer.line_content(line),
+                );
+                let in_selection = ctx
+                    .buffer
+                    .selection_range()
+                    .is_some_and(|(start, end)| start <= position && position < end);
+                if !in_selection {
+                    if ctx.buffer.has_selection() || ctx.buffer.block_selection().is_some() {
+                        ctx.dirty_region
+                            .merge(crate::dirty_region::DirtyRegion::FullViewport);
+                    }
+                    self.mark_active = false;
+                    ctx.buffer.set_cursor(position);
+                    ctx.mark_cursor_dirty();
+                }
+            }
+            return;
+        }
+
         match event.kind {
             MouseEventKind::Down => {
+                // Chunk: docs/chunks/emacs_kill_ring - A click deactivates the mark
+                self.mark_active = false;
+                self.block_drag_anchor = None;
+                if ctx.buffer.block_selection().is_some() {
+                    ctx.buffer.clear_selection();
+                    ctx.dirty_region
+                        .merge(crate::dirty_region::DirtyRegion::FullViewport);
+                }
+
+                // Option+click starts a block selection at the nearest column boundary
+                if event.modifiers.option && event.click_count == 1 {
+                    let anchor = pixel_to_display_position_wrapped(
+                        event.position,
+                        &wrap_layout,
+                        ctx.viewport.scroll_fraction_px(),
+                        ctx.viewport.first_visible_line(),
+                        ctx.buffer.line_count(),
+                        |line| ctx.buffer.line_len(line),
+                        |line| ctx.buffer.line_content(line),
+                    );
+                    ctx.buffer.set_block_selection(anchor, anchor);
+                    self.block_drag_anchor = Some(anchor);
+                    ctx.mark_cursor_dirty();
+                    return;
+                }
+
                 // Convert pixel position to buffer position using wrap-aware mapping
                 // Chunk: docs/chunks/tab_rendering - Tab-aware mouse hit-testing
                 let position = pixel_to_buffer_position_wrapped(
@@ -716,7 +1114,13 @@ impl FocusTarget for BufferFocusTarget {
                 );
 
                 // Spec: docs/trunk/SPEC.md#word-model
-                if event.click_count == 2 {
+                if event.click_count >= 3 {
+                    // Chunk: docs/chunks/triple_click_drag_autoscroll - Triple-click selects the line
+                    let line = position.line;
+                    ctx.buffer.select_line_at(line);
+                    ctx.dirty_region
+                        .merge(crate::dirty_region::DirtyRegion::line_range(line, line + 2));
+                } else if event.click_count == 2 {
                     // Double-click: select word or whitespace run at click position
                     ctx.buffer.set_cursor(position); // Sets cursor.line
                     if ctx.buffer.select_word_at(position.col) {
@@ -731,6 +1135,23 @@ impl FocusTarget for BufferFocusTarget {
                 }
             }
             MouseEventKind::Moved => {
+                // Block drag: the block spans from the anchor to the pointer
+                if let Some(anchor) = self.block_drag_anchor {
+                    let head = pixel_to_display_position_wrapped(
+                        event.position,
+                        &wrap_layout,
+                        ctx.viewport.scroll_fraction_px(),
+                        ctx.viewport.first_visible_line(),
+                        ctx.buffer.line_count(),
+                        |line| ctx.buffer.line_len(line),
+                        |line| ctx.buffer.line_content(line),
+                    );
+                    ctx.buffer.set_block_selection(anchor, head);
+                    ctx.dirty_region
+                        .merge(crate::dirty_region::DirtyRegion::FullViewport);
+                    return;
+                }
+
                 // Drag: extend selection from anchor to new position
                 let old_cursor = ctx.buffer.cursor_position();
 
@@ -767,6 +1188,18 @@ impl FocusTarget for BufferFocusTarget {
                 }
             }
             MouseEventKind::Up => {
+                // An Option+click without a drag down more lines is a plain click
+                if self.block_drag_anchor.take().is_some() {
+                    let single_caret = ctx
+                        .buffer
+                        .block_selection()
+                        .is_some_and(|block| block.is_empty() && block.top() == block.bottom());
+                    if single_caret {
+                        ctx.buffer.clear_selection();
+                    }
+                    return;
+                }
+
                 // Finalize selection: if anchor equals cursor, clear selection (click without drag)
                 if !ctx.buffer.has_selection() {
                     ctx.buffer.clear_selection();
@@ -774,6 +1207,8 @@ impl FocusTarget for BufferFocusTarget {
                 // Otherwise, leave selection active for subsequent copy/replace operations
                 // No cursor position change on mouse-up
             }
+            // Chunk: docs/chunks/cursor_position_history - Handled by EditorState's cursor history
+            MouseEventKind::Back | MouseEventKind::Forward | MouseEventKind::Hover => {}
         }
     }
 }
@@ -896,9 +1331,110 @@ where
     F: Fn(usize) -> usize,
     G: Fn(usize) -> String,
 {
-    let (x, y) = position;
-    let line_height = wrap_layout.line_height();
+    if line_count == 0 {
+        return Position::new(0, 0);
+    }
+
+    let (x, _) = position;
+    let glyph_width = wrap_layout.glyph_width();
+    let (buffer_line, row_offset_in_line, line_content) = wrapped_row_at(
+        position,
+        wrap_layout,
+        scroll_fraction_px,
+        first_visible_screen_row,
+        line_count,
+        line_len_fn,
+        line_content_fn,
+    );
+
+    // Compute screen column from x position (this is a visual column within the screen row)
+    let screen_col = if x >= 0.0 && glyph_width > 0.0 {
+        (x / glyph_width as f64).floor() as usize
+    } else {
+        0
+    };
+
+    // Convert (row_offset, screen_col) to a character column, accounting for tabs
+    // and wide characters and never landing inside a grapheme cluster
+    // Chunk: docs/chunks/tab_rendering - Tab-aware visual to character column conversion
+    // Chunk: docs/chunks/unicode_width_awareness - Grapheme-snapped hit-testing
+    let char_col =
+        wrap_layout.screen_pos_to_char_col(&line_content, row_offset_in_line, screen_col);
+
+    // Clamp to line length (in characters)
+    let line_char_count = line_content.chars().count();
+    let clamped_col = char_col.min(line_char_count);
+
+    Position::new(buffer_line, clamped_col)
+}
+
+// Chunk: docs/chunks/column_selection - Hit-testing in display columns for block selection
+/// Converts a pixel position to a (buffer line, display column) pair for
+/// block selection.
+///
+/// Unlike [`pixel_to_buffer_position_wrapped`], the column is not clamped to
+/// the line, so a block can extend past short lines, and it rounds to the
+/// nearest column boundary rather than the cell under the pointer.
+pub fn pixel_to_display_position_wrapped<F, G>(
+    position: (f64, f64),
+    wrap_layout: &WrapLayout,
+    scroll_fraction_px: f32,
+    first_visible_screen_row: usize,
+    line_count: usize,
+    line_len_fn: F,
+    line_content_fn: G,
+) -> Position
+where
+    F: Fn(usize) -> usize,
+    G: Fn(usize) -> String,
+{
+    if line_count == 0 {
+        return Position::new(0, 0);
+    }
+
+    let (x, _) = position;
     let glyph_width = wrap_layout.glyph_width();
+    let (buffer_line, row_offset_in_line, _) = wrapped_row_at(
+        position,
+        wrap_layout,
+        scroll_fraction_px,
+        first_visible_screen_row,
+        line_count,
+        line_len_fn,
+        line_content_fn,
+    );
+
+    let screen_col = if x >= 0.0 && glyph_width > 0.0 {
+        ((x / glyph_width as f64).round() as usize).min(wrap_layout.cols_per_row())
+    } else {
+        0
+    };
+
+    Position::new(
+        buffer_line,
+        wrap_layout.screen_pos_to_buffer_col(row_offset_in_line, screen_col),
+    )
+}
+
+/// Finds the buffer line under a pixel position, the wrapped row within it,
+/// and the line's content.
+///
+/// `line_count` must be non-zero.
+fn wrapped_row_at<F, G>(
+    position: (f64, f64),
+    wrap_layout: &WrapLayout,
+    scroll_fraction_px: f32,
+    first_visible_screen_row: usize,
+    line_count: usize,
+    line_len_fn: F,
+    line_content_fn: G,
+) -> (usize, usize, String)
+where
+    F: Fn(usize) -> usize,
+    G: Fn(usize) -> String,
+{
+    let (_, y) = position;
+    let line_height = wrap_layout.line_height();
 
     // y is already in screen space (y=0 at top of content area)
     // No flip needed - coordinates are pre-flipped at handle_mouse entry
@@ -912,20 +1448,15 @@ where
         0
     };
 
-    // Convert viewport-relative row to absolute screen row
-    let absolute_screen_row = first_visible_screen_row + viewport_relative_row;
-
-    if line_count == 0 {
-        return Position::new(0, 0);
-    }
-
     // Convert absolute screen row to buffer line using the same logic as the renderer.
     // This ensures click handling agrees with what's actually rendered.
-    // Note: Viewport::buffer_line_for_screen_row uses line_len_fn which returns character count.
-    // For tab-aware wrapping, this should use visual width, but the viewport function would
-    // need similar updates. For now, we handle the tab-aware conversion at the column level.
-    let (buffer_line, row_offset_in_line, _) = Viewport::buffer_line_for_screen_row(
-        absolute_screen_row,
+    // Chunk: docs/chunks/unicode_width_awareness - Visible rows laid out by visual width
+    // The renderer finds the first visible line by character count
+    // (Viewport::buffer_line_for_screen_row), then lays out the visible lines by
+    // visual width, so tabs and wide characters can add rows. Walk the visible
+    // lines the same way.
+    let (first_line, first_row_offset, _) = Viewport::buffer_line_for_screen_row(
+        first_visible_screen_row,
         line_count,
         wrap_layout,
         &line_len_fn,
@@ -933,27 +1464,25 @@ where
 
     // Get line content for tab-aware visual column conversion
     // Chunk: docs/chunks/tab_rendering - Get line content for tab-aware hit-testing
-    let line_content = line_content_fn(buffer_line);
-
-    // Compute screen column from x position (this is a visual column within the screen row)
-    let screen_col = if x >= 0.0 && glyph_width > 0.0 {
-        (x / glyph_width as f64).floor() as usize
-    } else {
-        0
-    };
-
-    // Convert (row_offset, screen_col) to visual column within the line
-    let visual_col = wrap_layout.screen_pos_to_buffer_col(row_offset_in_line, screen_col);
-
-    // Convert visual column to character column using tab-aware conversion
-    // Chunk: docs/chunks/tab_rendering - Tab-aware visual to character column conversion
-    let char_col = tab_width::visual_col_to_char_col(&line_content, visual_col);
-
-    // Clamp to line length (in characters)
-    let line_char_count = line_content.chars().count();
-    let clamped_col = char_col.min(line_char_count);
+    let mut buffer_line = first_line;
+    let mut row_offset_in_line = first_row_offset + viewport_relative_row;
+    let mut line_content = line_content_fn(buffer_line);
+    loop {
+        let rows_for_line = wrap_layout.screen_rows_for_line_content(&line_content);
+        if row_offset_in_line < rows_for_line {
+            break;
+        }
+        if buffer_line + 1 >= line_count {
+            // Below the end of the document: first row of the last line
+            row_offset_in_line = 0;
+            break;
+        }
+        row_offset_in_line -= rows_for_line;
+        buffer_line += 1;
+        line_content = line_content_fn(buffer_line);
+    }
 
-    Position::new(buffer_line, clamped_col)
+    (buffer_line, row_offset_in_line, line_content)
 }
 
 #[cfg(test)]
@@ -1633,6 +2162,7 @@ mod tests {
                 position: (16.0, 20.0), // x=16 (col 2), y=20 (line 1)
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -1695,6 +2225,7 @@ mod tests {
                 position: (0.0, 10.0), // y=10 in screen space
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -1868,6 +2399,155 @@ mod tests {
         assert_eq!(buffer.cursor_position(), Position::new(0, 0));
     }
 
+    // ==================== Kill Ring and Mark Tests ====================
+    // Chunk: docs/chunks/emacs_kill_ring - Kill ring and mark tests
+
+    /// Sends a sequence of key events to one target, as consecutive keystrokes.
+    fn send_keys(target: &mut BufferFocusTarget, buffer: &mut TextBuffer, events: &[KeyEvent]) {
+        let mut viewport = Viewport::new(16.0);
+        viewport.update_size(160.0, 100);
+        let mut dirty = DirtyRegion::None;
+        let mut dirty_lines = DirtyLines::None;
+        let mut ctx = EditorContext::new(
+            buffer,
+            &mut viewport,
+            &mut dirty,
+            &mut dirty_lines,
+            test_font_metrics(),
+            160.0,
+            800.0,
+        );
+        for event in events {
+            target.handle_key(event.clone(), &mut ctx);
+        }
+    }
+
+    fn ctrl(ch: char) -> KeyEvent {
+        KeyEvent::new(
+            Key::Char(ch),
+            Modifiers {
+                control: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn option(ch: char) -> KeyEvent {
+        KeyEvent::new(
+            Key::Char(ch),
+            Modifiers {
+                option: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_consecutive_kills_yank_as_one() {
+        let mut buffer = TextBuffer::from_str("one\ntwo\nthree");
+        buffer.set_cursor(Position::new(0, 0));
+        let mut target = BufferFocusTarget::new();
+
+        send_keys(&mut target, &mut buffer, &[ctrl('k'), ctrl('k')]);
+        assert_eq!(buffer.content(), "two\nthree");
+        assert_eq!(
+            crate::clipboard::paste_from_clipboard().as_deref(),
+            Some("one\n")
+        );
+
+        buffer.move_to_buffer_end();
+        send_keys(&mut target, &mut buffer, &[ctrl('y')]);
+        assert_eq!(buffer.content(), "two\nthreeone\n");
+    }
+
+    #[test]
+    fn test_kill_after_movement_starts_new_entry() {
+        let mut buffer = TextBuffer::from_str("ab\ncd");
+        buffer.set_cursor(Position::new(0, 0));
+        let mut target = BufferFocusTarget::new();
+
+        send_keys(
+            &mut target,
+            &mut buffer,
+            &[ctrl('k'), ctrl('n'), ctrl('a'), ctrl('k')],
+        );
+        assert_eq!(crate::clipboard_history::yank(0).as_deref(), Some("cd"));
+        assert_eq!(crate::clipboard_history::yank(1).as_deref(), Some("ab"));
+    }
+
+    #[test]
+    fn test_yank_pop_cycles_older_kills() {
+        let mut buffer = TextBuffer::from_str("");
+        let mut target = BufferFocusTarget::new();
+        crate::clipboard_history::record("first");
+        crate::clipboard_history::record("second");
+
+        send_keys(&mut target, &mut buffer, &[ctrl('y')]);
+        assert_eq!(buffer.content(), "second");
+        send_keys(&mut target, &mut buffer, &[option('y')]);
+        assert_eq!(buffer.content(), "first");
+        assert_eq!(buffer.cursor_position(), Position::new(0, 5));
+    }
+
+    #[test]
+    fn test_yank_pop_without_yank_is_noop() {
+        let mut buffer = TextBuffer::from_str("text");
+        buffer.move_to_buffer_end();
+        let mut target = BufferFocusTarget::new();
+        crate::clipboard_history::record("kill");
+
+        send_keys(
+            &mut target,
+            &mut buffer,
+            &[ctrl('y'), ctrl('b'), option('y')],
+        );
+        assert_eq!(buffer.content(), "textkill");
+    }
+
+    #[test]
+    fn test_mark_extends_selection_and_kill_region() {
+        let mut buffer = TextBuffer::from_str("hello world");
+        buffer.set_cursor(Position::new(0, 0));
+        let mut target = BufferFocusTarget::new();
+
+        let events = [
+            ctrl(' '),
+            ctrl('f'),
+            ctrl('f'),
+            KeyEvent::new(
+                Key::Right,
+                Modifiers {
+                    option: true,
+                    ..Default::default()
+                },
+            ),
+        ];
+        send_keys(&mut target, &mut buffer, &events);
+        assert_eq!(buffer.selected_text().as_deref(), Some("hello"));
+
+        send_keys(&mut target, &mut buffer, &[ctrl('w')]);
+        assert_eq!(buffer.content(), " world");
+        assert_eq!(
+            crate::clipboard::paste_from_clipboard().as_deref(),
+            Some("hello")
+        );
+    }
+
+    #[test]
+    fn test_ctrl_g_deactivates_mark() {
+        let mut buffer = TextBuffer::from_str("hello");
+        buffer.set_cursor(Position::new(0, 0));
+        let mut target = BufferFocusTarget::new();
+
+        send_keys(
+            &mut target,
+            &mut buffer,
+            &[ctrl(' '), ctrl('f'), ctrl('g'), ctrl('f')],
+        );
+        assert!(!buffer.has_selection());
+        assert_eq!(buffer.cursor_position(), Position::new(0, 2));
+    }
+
     // ==================== Shift+Arrow Selection Tests ====================
     // Chunk: docs/chunks/shift_arrow_selection - Unit tests for selection extension
 
@@ -3440,6 +4120,7 @@ mod tests {
                 position: (0.0, 70.0), // y=70 in screen space → row 4
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3552,6 +4233,7 @@ mod tests {
                 position: (0.0, 150.0), // y=150 in screen space → row 9
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3597,6 +4279,7 @@ mod tests {
                 position: (16.0, 20.0), // y=20 in screen space → line 1
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3639,6 +4322,7 @@ mod tests {
                 position: (8.0, 5.0), // y=5 in screen space → line 0
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3664,6 +4348,7 @@ mod tests {
                 position: (24.0, 20.0), // y=20 in screen space → line 1
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3695,6 +4380,7 @@ mod tests {
                 position: (16.0, 124.0), // flipped_y = 36, line 2, x = 16 for col 2
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3732,6 +4418,7 @@ mod tests {
                 position: (16.0, 155.0),
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3752,6 +4439,7 @@ mod tests {
                 position: (16.0, 155.0),
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3786,6 +4474,7 @@ mod tests {
                 position: (8.0, 155.0),
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3806,6 +4495,7 @@ mod tests {
                 position: (32.0, 155.0), // x = 32 for column 4
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3826,6 +4516,7 @@ mod tests {
                 position: (32.0, 155.0),
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3863,6 +4554,7 @@ mod tests {
                 position: (0.0, 5.0), // y=5 → line 0
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3883,6 +4575,7 @@ mod tests {
                 position: (80.0, 5.0), // y=5 → line 0
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3918,6 +4611,7 @@ mod tests {
                 position: (0.0, 155.0),
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3939,6 +4633,7 @@ mod tests {
                 position: (0.0, 60.0), // flipped_y = 100, line 6 if existed
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3975,6 +4670,7 @@ mod tests {
                 position: (0.0, 20.0), // y=20 → line 1
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -3996,6 +4692,7 @@ mod tests {
                 position: (0.0, -40.0), // y < 0, above view
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -4033,6 +4730,7 @@ mod tests {
                     position: (8.0, 5.0), // y=5 → line 0
                     modifiers: Modifiers::default(),
                     click_count: 1,
+                    button: MouseButton::Left,
                 },
                 &mut ctx,
             );
@@ -4059,6 +4757,7 @@ mod tests {
                     position: (16.0, 20.0), // y=20 → line 1
                     modifiers: Modifiers::default(),
                     click_count: 1,
+                    button: MouseButton::Left,
                 },
                 &mut ctx,
             );
@@ -4087,6 +4786,7 @@ mod tests {
                     position: (8.0, 36.0), // y=36 → line 2
                     modifiers: Modifiers::default(),
                     click_count: 1,
+                    button: MouseButton::Left,
                 },
                 &mut ctx,
             );
@@ -4112,6 +4812,7 @@ mod tests {
                     position: (8.0, 36.0), // y=36 → line 2
                     modifiers: Modifiers::default(),
                     click_count: 1,
+                    button: MouseButton::Left,
                 },
                 &mut ctx,
             );
@@ -4148,6 +4849,7 @@ mod tests {
                     position: (32.0, 155.0),
                     modifiers: Modifiers::default(),
                     click_count: 1,
+                    button: MouseButton::Left,
                 },
                 &mut ctx,
             );
@@ -4172,6 +4874,7 @@ mod tests {
                     position: (8.0, 155.0),
                     modifiers: Modifiers::default(),
                     click_count: 1,
+                    button: MouseButton::Left,
                 },
                 &mut ctx,
             );
@@ -4214,6 +4917,7 @@ mod tests {
                     position: (0.0, 20.0), // y=20 → line 1
                     modifiers: Modifiers::default(),
                     click_count: 1,
+                    button: MouseButton::Left,
                 },
                 &mut ctx,
             );
@@ -4241,6 +4945,7 @@ mod tests {
                     position: (16.0, 52.0), // y=52 → line 3
                     modifiers: Modifiers::default(),
                     click_count: 1,
+                    button: MouseButton::Left,
                 },
                 &mut ctx,
             );
@@ -4431,6 +5136,7 @@ mod tests {
                 position: (16.0, 155.0), // x = 16 for col 2
                 modifiers: Modifiers::default(),
                 click_count: 2,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -4469,6 +5175,7 @@ mod tests {
                 position: (0.0, 155.0), // x = 0 for col 0
                 modifiers: Modifiers::default(),
                 click_count: 2,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -4507,6 +5214,7 @@ mod tests {
                 position: (40.0, 155.0), // x = 40 for col 5
                 modifiers: Modifiers::default(),
                 click_count: 2,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -4543,6 +5251,7 @@ mod tests {
                 position: (0.0, 155.0),
                 modifiers: Modifiers::default(),
                 click_count: 2,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -4577,6 +5286,7 @@ mod tests {
                 position: (80.0, 155.0), // Past line end
                 modifiers: Modifiers::default(),
                 click_count: 2,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -4589,6 +5299,41 @@ mod tests {
         assert_eq!(buffer.selected_text(), Some("hello".to_string()));
     }
 
+    // Chunk: docs/chunks/triple_click_drag_autoscroll - Triple-click line selection
+    #[test]
+    fn test_triple_click_selects_line_with_newline() {
+        let mut buffer = TextBuffer::from_str("first\nsecond line\nthird");
+        let mut viewport = Viewport::new(16.0);
+        viewport.update_size(160.0, 100);
+        let mut dirty = DirtyRegion::None;
+        let mut dirty_lines = DirtyLines::None;
+        let mut target = BufferFocusTarget::new();
+
+        {
+            let mut ctx = EditorContext::new(
+                &mut buffer,
+                &mut viewport,
+                &mut dirty,
+                &mut dirty_lines,
+                test_font_metrics(),
+                160.0,
+                800.0,
+            );
+            let event = MouseEvent {
+                kind: MouseEventKind::Down,
+                position: (24.0, 20.0), // line 1, col 3
+                modifiers: Modifiers::default(),
+                click_count: 3,
+                button: MouseButton::Left,
+            };
+            target.handle_mouse(event, &mut ctx);
+        }
+
+        assert_eq!(buffer.selected_text(), Some("second line\n".to_string()));
+        assert_eq!(buffer.cursor_position(), Position::new(2, 0));
+        assert!(dirty.is_dirty());
+    }
+
     #[test]
     fn test_single_click_still_positions_cursor() {
         // Single click (click_count: 1) should position cursor without selection
@@ -4614,6 +5359,7 @@ mod tests {
                 position: (16.0, 155.0), // col 2
                 modifiers: Modifiers::default(),
                 click_count: 1,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -4651,6 +5397,7 @@ mod tests {
                 position: (16.0, 140.0), // line 1, col 2
                 modifiers: Modifiers::default(),
                 click_count: 2,
+                button: MouseButton::Left,
             };
             target.handle_mouse(event, &mut ctx);
         }
@@ -4861,6 +5608,54 @@ mod tests {
     // Chunk: docs/chunks/tiling_workspace_integration - Tests use screen-space coordinates (y=0 at top)
     // =========================================================================
 
+    // Chunk: docs/chunks/unicode_width_awareness - Hit-testing below wrapped wide-char lines
+    /// A line of CJK characters wraps by its visual width (two columns per char),
+    /// not its char count. Clicks below it must land on the line the renderer drew
+    /// there.
+    #[test]
+    fn test_click_below_wrapped_wide_char_line() {
+        let metrics = test_font_metrics(); // 8px glyph width, 16px line height
+        let wrap_layout = WrapLayout::new(80.0, &metrics); // 10 cols
+
+        // Line 0: 6 wide chars = 12 columns = 2 screen rows (but only 6 chars)
+        let lines = ["一二三四五六", "abc", "xyz"];
+
+        let position = pixel_to_buffer_position_wrapped(
+            (8.0, 40.0), // col 1, screen row 2
+            64.0,
+            &wrap_layout,
+            0.0,
+            0,
+            lines.len(),
+            |line| lines[line].chars().count(),
+            |line| lines[line].to_string(),
+        );
+
+        assert_eq!(position, Position::new(1, 1));
+    }
+
+    #[test]
+    fn test_click_on_wide_char_continuation_row() {
+        let metrics = test_font_metrics();
+        let wrap_layout = WrapLayout::new(80.0, &metrics); // 10 cols
+        let lines = ["一二三四五六"];
+
+        // Screen row 1, col 1: visual column 11, the second half of 六
+        let position = pixel_to_buffer_position_wrapped(
+            (8.0, 20.0),
+            64.0,
+            &wrap_layout,
+            0.0,
+            0,
+            lines.len(),
+            |line| lines[line].chars().count(),
+            |line| lines[line].to_string(),
+        );
+
+        // 六 spans visual columns 10-11, so the cursor goes before it
+        assert_eq!(position, Position::new(0, 5));
+    }
+
     /// Test that clicking on a continuation row (wrapped row) computes the correct buffer column.
     ///
     /// This test exercises the bug where the renderer and click handler use different widths
@@ -5502,4 +6297,240 @@ mod tests {
             "Viewport should have scrolled up"
         );
     }
+
+    // ==================== Column Selection Tests ====================
+    // Chunk: docs/chunks/column_selection - Option+drag block selection
+
+    fn send_option_mouse(
+        target: &mut BufferFocusTarget,
+        buffer: &mut TextBuffer,
+        events: &[(MouseEventKind, (f64, f64))],
+    ) {
+        let mut viewport = Viewport::new(16.0);
+        viewport.update_size(160.0, 100);
+        let mut dirty = DirtyRegion::None;
+        let mut dirty_lines = DirtyLines::None;
+        let mut ctx = EditorContext::new(
+            buffer,
+            &mut viewport,
+            &mut dirty,
+            &mut dirty_lines,
+            test_font_metrics(),
+            160.0,
+            800.0,
+        );
+        for &(kind, position) in events {
+            let event = MouseEvent {
+                kind,
+                position,
+                modifiers: Modifiers {
+                    option: true,
+                    ..Default::default()
+                },
+                click_count: 1,
+                button: MouseButton::Left,
+            };
+            target.handle_mouse(event, &mut ctx);
+        }
+    }
+
+    #[test]
+    fn test_option_drag_selects_block() {
+        let mut buffer = TextBuffer::from_str("abcdef\nab\nabcdef");
+        let mut target = BufferFocusTarget::new();
+
+        // Column boundaries are 8px apart and rows 16px tall
+        send_option_mouse(
+            &mut target,
+            &mut buffer,
+            &[
+                (MouseEventKind::Down, (9.0, 5.0)),
+                (MouseEventKind::Moved, (31.0, 36.0)),
+                (MouseEventKind::Up, (31.0, 36.0)),
+            ],
+        );
+
+        assert_eq!(buffer.selected_text().as_deref(), Some("bcd\nb\nbcd"));
+
+        send_keys(&mut target, &mut buffer, &[KeyEvent::char('X')]);
+        assert_eq!(buffer.content(), "aXef\naX\naXef");
+    }
+
+    #[test]
+    fn test_option_click_without_drag_is_plain_click() {
+        let mut buffer = TextBuffer::from_str("hello\nworld");
+        let mut target = BufferFocusTarget::new();
+
+        send_option_mouse(
+            &mut target,
+            &mut buffer,
+            &[
+                (MouseEventKind::Down, (16.0, 20.0)),
+                (MouseEventKind::Up, (16.0, 20.0)),
+            ],
+        );
+
+        assert_eq!(buffer.block_selection(), None);
+        assert_eq!(buffer.cursor_position(), Position::new(1, 2));
+    }
+
+    #[test]
+    fn test_movement_ends_block() {
+        let mut buffer = TextBuffer::from_str("abc\nabc");
+        let mut target = BufferFocusTarget::new();
+        buffer.set_block_selection(Position::new(0, 1), Position::new(1, 2));
+
+        send_keys(
+            &mut target,
+            &mut buffer,
+            &[KeyEvent::new(Key::Right, Modifiers::default())],
+        );
+
+        assert_eq!(buffer.block_selection(), None);
+        assert_eq!(buffer.content(), "abc\nabc");
+    }
+
+    // ==================== Line Move Tests ====================
+    // Chunk: docs/chunks/move_duplicate_lines - Option+Up/Down and Cmd+Shift+D
+
+    #[test]
+    fn test_line_move_bindings() {
+        let option_up = KeyEvent::new(
+            Key::Up,
+            Modifiers {
+                option: true,
+                ..Default::default()
+            },
+        );
+        let cmd_shift_d = KeyEvent::new(
+            Key::Char('d'),
+            Modifiers {
+                command: true,
+                shift: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(resolve_command(&option_up), Some(Command::MoveLinesUp));
+        assert_eq!(resolve_command(&cmd_shift_d), Some(Command::DuplicateLines));
+    }
+
+    #[test]
+    fn test_option_down_then_duplicate() {
+        let mut buffer = TextBuffer::from_str("one\ntwo\nthree");
+        let mut target = BufferFocusTarget::new();
+        let option_down = KeyEvent::new(
+            Key::Down,
+            Modifiers {
+                option: true,
+                ..Default::default()
+            },
+        );
+        let cmd_shift_d = KeyEvent::new(
+            Key::Char('d'),
+            Modifiers {
+                command: true,
+                shift: true,
+                ..Default::default()
+            },
+        );
+
+        send_keys(&mut target, &mut buffer, &[option_down, cmd_shift_d]);
+
+        assert_eq!(buffer.content(), "two\none\none\nthree");
+        assert_eq!(buffer.cursor_position(), Position::new(2, 0));
+    }
+
+    // ==================== Smart Home Tests ====================
+    // Chunk: docs/chunks/smart_home_navigation - Smart Home and word selection
+
+    #[test]
+    fn test_home_goes_to_first_non_blank_then_line_start() {
+        let mut buffer = TextBuffer::from_str("    indented");
+        buffer.set_cursor(Position::new(0, 8));
+        let mut target = BufferFocusTarget::new();
+        let home = KeyEvent::new(Key::Home, Modifiers::default());
+
+        send_keys(&mut target, &mut buffer, std::slice::from_ref(&home));
+        assert_eq!(buffer.cursor_position(), Position::new(0, 4));
+        send_keys(&mut target, &mut buffer, &[home]);
+        assert_eq!(buffer.cursor_position(), Position::new(0, 0));
+    }
+
+    #[test]
+    fn test_ctrl_a_goes_to_column_zero() {
+        let mut buffer = TextBuffer::from_str("    indented");
+        buffer.set_cursor(Position::new(0, 8));
+        let mut target = BufferFocusTarget::new();
+
+        send_keys(&mut target, &mut buffer, &[ctrl('a')]);
+        assert_eq!(buffer.cursor_position(), Position::new(0, 0));
+    }
+
+    #[test]
+    fn test_shift_option_right_selects_word() {
+        let mut buffer = TextBuffer::from_str("hello world");
+        buffer.set_cursor(Position::new(0, 0));
+        let mut target = BufferFocusTarget::new();
+        let shift_option_right = KeyEvent::new(
+            Key::Right,
+            Modifiers {
+                shift: true,
+                option: true,
+                ..Default::default()
+            },
+        );
+
+        send_keys(&mut target, &mut buffer, &[shift_option_right]);
+        assert_eq!(buffer.selected_text().as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn test_option_forward_delete_deletes_word() {
+        let mut buffer = TextBuffer::from_str("hello world");
+        buffer.set_cursor(Position::new(0, 0));
+        let mut target = BufferFocusTarget::new();
+        let option_delete = KeyEvent::new(
+            Key::Delete,
+            Modifiers {
+                option: true,
+                ..Default::default()
+            },
+        );
+
+        send_keys(&mut target, &mut buffer, &[option_delete]);
+        assert_eq!(buffer.content(), " world");
+    }
+
+    // Chunk: docs/chunks/read_only_preview_tabs - Read-only buffers refuse edits
+
+    #[test]
+    fn test_read_only_buffer_refuses_edits_but_moves() {
+        let mut buffer = TextBuffer::from_str("hello");
+        let mut viewport = Viewport::new(16.0);
+        viewport.update_size(160.0, 100);
+        let mut dirty = DirtyRegion::None;
+        let mut dirty_lines = DirtyLines::None;
+        let mut target = BufferFocusTarget::new();
+        let mut ctx = EditorContext::new(
+            &mut buffer,
+            &mut viewport,
+            &mut dirty,
+            &mut dirty_lines,
+            test_font_metrics(),
+            160.0,
+            800.0,
+        );
+        ctx.read_only = true;
+
+        target.handle_key(KeyEvent::new(Key::Right, Modifiers::default()), &mut ctx);
+        assert!(!ctx.edit_refused);
+        let backspace = KeyEvent::new(Key::Backspace, Modifiers::default());
+        target.handle_key(KeyEvent::char('x'), &mut ctx);
+        target.handle_key(backspace, &mut ctx);
+        assert!(ctx.edit_refused);
+        assert!(!ctx.content_mutated);
+
+        assert_eq!(buffer.content(), "hello");
+        assert_eq!(buffer.cursor_position(), Position::new(0, 1));
+    }
 }
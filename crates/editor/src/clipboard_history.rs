@@ -0,0 +1,202 @@
+// Chunk: docs/chunks/emacs_kill_ring - Kill ring shared with the system clipboard
+//!
+//! Clipboard history (the Emacs kill ring).
+//!
+//! Every kill, copy and cut pushes its text onto a bounded ring and also
+//! writes it to the system clipboard, so Cmd+V and other apps see the latest
+//! kill. Reading goes the other way: when the clipboard holds text the ring
+//! hasn't seen (copied in another app), that text is pushed first, so Ctrl+Y
+//! yanks whatever Cmd+V would paste and Option+Y can then cycle back to
+//! older kills.
+//!
+//! [`KillRing`] is plain data and fully unit-testable. The module-level
+//! functions operate on one ring per thread. The editor only touches it from
+//! the main thread, so all buffers and minibuffers share it.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+use crate::clipboard::{copy_to_clipboard, paste_from_clipboard};
+
+/// Number of kills kept before the oldest is dropped.
+pub const KILL_RING_CAPACITY: usize = 60;
+
+/// A bounded history of killed text, newest first.
+#[derive(Debug, Clone)]
+pub struct KillRing {
+    entries: VecDeque<String>,
+    capacity: usize,
+}
+
+impl KillRing {
+    /// Creates an empty ring holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Returns the number of entries in the ring.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if nothing has been killed yet.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Pushes a new kill as the newest entry.
+    ///
+    /// Empty text is ignored, and text equal to the newest entry isn't
+    /// duplicated.
+    pub fn push(&mut self, text: &str) {
+        if text.is_empty() || self.entries.front().map(String::as_str) == Some(text) {
+            return;
+        }
+        self.entries.push_front(text.to_string());
+        self.entries.truncate(self.capacity);
+    }
+
+    /// Appends text to the newest entry, as consecutive Ctrl+K presses do.
+    ///
+    /// Starts a new entry if the ring is empty.
+    pub fn append(&mut self, text: &str) {
+        match self.entries.front_mut() {
+            Some(newest) => newest.push_str(text),
+            None => self.push(text),
+        }
+    }
+
+    /// Returns the entry `index` steps back from the newest, wrapping around
+    /// the ring.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        self.entries
+            .get(index % self.entries.len())
+            .map(String::as_str)
+    }
+
+    /// Records clipboard text copied outside the ring.
+    ///
+    /// Does nothing if the clipboard already matches the newest entry.
+    pub fn sync_from_clipboard(&mut self, clipboard: Option<String>) {
+        if let Some(text) = clipboard {
+            self.push(&text);
+        }
+    }
+}
+
+impl Default for KillRing {
+    fn default() -> Self {
+        Self::new(KILL_RING_CAPACITY)
+    }
+}
+
+thread_local! {
+    /// The kill ring shared by every buffer on this thread.
+    static KILL_RING: RefCell<KillRing> = RefCell::new(KillRing::default());
+}
+
+/// Pushes text onto the kill ring and the system clipboard.
+pub fn record(text: &str) {
+    KILL_RING.with(|ring| ring.borrow_mut().push(text));
+    copy_to_clipboard(text);
+}
+
+/// Appends text to the newest kill and puts the combined text on the
+/// system clipboard.
+pub fn record_append(text: &str) {
+    let combined = KILL_RING.with(|ring| {
+        let mut ring = ring.borrow_mut();
+        ring.sync_from_clipboard(paste_from_clipboard());
+        ring.append(text);
+        ring.get(0).map(str::to_string)
+    });
+    if let Some(combined) = combined {
+        copy_to_clipboard(&combined);
+    }
+}
+
+/// Returns the entry `index` steps back from the newest.
+///
+/// Index 0 picks up text copied in other apps first, so it always matches
+/// what Cmd+V would paste.
+pub fn yank(index: usize) -> Option<String> {
+    KILL_RING.with(|ring| {
+        let mut ring = ring.borrow_mut();
+        if index == 0 {
+            ring.sync_from_clipboard(paste_from_clipboard());
+        }
+        ring.get(index).map(str::to_string)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_newest_first() {
+        let mut ring = KillRing::new(10);
+        ring.push("one");
+        ring.push("two");
+        assert_eq!(ring.get(0), Some("two"));
+        assert_eq!(ring.get(1), Some("one"));
+    }
+
+    #[test]
+    fn test_push_skips_empty_and_duplicates() {
+        let mut ring = KillRing::new(10);
+        ring.push("");
+        ring.push("same");
+        ring.push("same");
+        assert_eq!(ring.len(), 1);
+    }
+
+    #[test]
+    fn test_capacity_drops_oldest() {
+        let mut ring = KillRing::new(2);
+        ring.push("a");
+        ring.push("b");
+        ring.push("c");
+        assert_eq!(ring.len(), 2);
+        assert_eq!(ring.get(1), Some("b"));
+    }
+
+    #[test]
+    fn test_get_wraps_around() {
+        let mut ring = KillRing::new(10);
+        ring.push("old");
+        ring.push("new");
+        assert_eq!(ring.get(2), Some("new"));
+        assert_eq!(KillRing::new(10).get(0), None);
+    }
+
+    #[test]
+    fn test_append_extends_newest() {
+        let mut ring = KillRing::new(10);
+        ring.append("line one");
+        ring.append("\n");
+        assert_eq!(ring.get(0), Some("line one\n"));
+        assert_eq!(ring.len(), 1);
+    }
+
+    #[test]
+    fn test_yank_picks_up_outside_copy() {
+        record("killed");
+        copy_to_clipboard("copied elsewhere");
+        assert_eq!(yank(0).as_deref(), Some("copied elsewhere"));
+        assert_eq!(yank(1).as_deref(), Some("killed"));
+    }
+
+    #[test]
+    fn test_record_append_updates_clipboard() {
+        record("hello");
+        record_append(" world");
+        assert_eq!(paste_from_clipboard().as_deref(), Some("hello world"));
+    }
+}
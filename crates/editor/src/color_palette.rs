@@ -11,9 +11,12 @@
 //! - True color RGB
 //!
 //! The palette also handles style transformations like inverse video and dim.
+//! It also holds the per-pane background tints that tell tab kinds apart.
 
 use lite_edit_buffer::{Color, NamedColor, Style};
 
+use crate::workspace::TabKind;
+
 // =============================================================================
 // Catppuccin Mocha Theme Colors
 // =============================================================================
@@ -34,6 +37,18 @@ const DEFAULT_BG: [f32; 4] = [
     1.0,
 ];
 
+// Chunk: docs/chunks/pane_tint - Per-pane background tints
+/// Terminal pane tint: black at 18%, a slightly darker base than file panes.
+const TERMINAL_PANE_TINT: [f32; 4] = [0.0, 0.0, 0.0, 0.18];
+
+/// Agent pane tint: #cba6f7 (Catppuccin Mocha "mauve") at 6%.
+const AGENT_PANE_TINT: [f32; 4] = [
+    0.796, // 0xcb / 255
+    0.651, // 0xa6 / 255
+    0.969, // 0xf7 / 255
+    0.06,
+];
+
 /// Catppuccin Mocha 16-color ANSI palette.
 /// These are the standard terminal colors themed for Catppuccin Mocha.
 const ANSI_COLORS: [[f32; 4]; 16] = [
@@ -73,6 +88,11 @@ pub struct ColorPalette {
     pub default_bg: [f32; 4],
     /// The 16 ANSI colors
     pub ansi_colors: [[f32; 4]; 16],
+    // Chunk: docs/chunks/pane_tint - Per-pane background tints
+    /// Tint blended over the background of terminal panes
+    pub terminal_pane_tint: [f32; 4],
+    /// Tint blended over the background of agent panes
+    pub agent_pane_tint: [f32; 4],
 }
 
 impl Default for ColorPalette {
@@ -88,6 +108,35 @@ impl ColorPalette {
             default_fg: DEFAULT_FG,
             default_bg: DEFAULT_BG,
             ansi_colors: ANSI_COLORS,
+            terminal_pane_tint: TERMINAL_PANE_TINT,
+            agent_pane_tint: AGENT_PANE_TINT,
+        }
+    }
+
+    // Chunk: docs/chunks/pane_tint - Tint lookup by tab kind
+    /// Returns the background tint for a pane showing a tab of `kind`.
+    ///
+    /// The tint is drawn as a translucent quad over the pane's content area
+    /// before its text, so cells with an explicit background still cover it.
+    /// File, diff, problems, Changes, Settings, Keyboard Shortcuts and
+    /// Terminal Memory panes keep the plain background (`None`).
+    pub fn pane_tint(&self, kind: TabKind) -> Option<[f32; 4]> {
+        match kind {
+            // Chunk: docs/chunks/shell_command_runner - Command output reads as terminal output
+            TabKind::Terminal | TabKind::CommandOutput => Some(self.terminal_pane_tint),
+            // Chunk: docs/chunks/terminal_replay - A replayed session reads as terminal output
+            TabKind::Replay => Some(self.terminal_pane_tint),
+            TabKind::AgentOutput => Some(self.agent_pane_tint),
+            TabKind::File
+            | TabKind::Diff
+            | TabKind::Problems
+            | TabKind::Changes
+            | TabKind::Settings
+            | TabKind::Shortcuts
+            | TabKind::TerminalMemory
+            | TabKind::Agents
+            | TabKind::AgentTimeline
+            | TabKind::Review => None,
         }
     }
 
@@ -260,6 +309,35 @@ mod tests {
         assert!(colors_approx_eq(&bg, &DEFAULT_BG));
     }
 
+    // Chunk: docs/chunks/pane_tint - Tints per tab kind
+    #[test]
+    fn test_pane_tint_by_tab_kind() {
+        let palette = ColorPalette::default();
+
+        assert_eq!(palette.pane_tint(TabKind::File), None);
+        assert_eq!(palette.pane_tint(TabKind::Diff), None);
+        assert_eq!(palette.pane_tint(TabKind::Problems), None);
+        assert_eq!(palette.pane_tint(TabKind::Changes), None);
+        assert_eq!(palette.pane_tint(TabKind::Settings), None);
+        assert_eq!(palette.pane_tint(TabKind::Shortcuts), None);
+        assert_eq!(palette.pane_tint(TabKind::TerminalMemory), None);
+        assert_eq!(palette.pane_tint(TabKind::Agents), None);
+        assert_eq!(palette.pane_tint(TabKind::AgentTimeline), None);
+        assert_eq!(palette.pane_tint(TabKind::Review), None);
+        assert_eq!(palette.pane_tint(TabKind::Terminal), Some(TERMINAL_PANE_TINT));
+        assert_eq!(
+            palette.pane_tint(TabKind::CommandOutput),
+            Some(TERMINAL_PANE_TINT)
+        );
+        assert_eq!(palette.pane_tint(TabKind::Replay), Some(TERMINAL_PANE_TINT));
+        assert_eq!(palette.pane_tint(TabKind::AgentOutput), Some(AGENT_PANE_TINT));
+
+        // Tints are translucent so the base background shows through
+        for tint in [TERMINAL_PANE_TINT, AGENT_PANE_TINT] {
+            assert!(tint[3] > 0.0 && tint[3] < 0.25);
+        }
+    }
+
     #[test]
     fn test_named_colors() {
         let palette = ColorPalette::default();
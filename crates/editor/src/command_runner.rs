@@ -0,0 +1,146 @@
+// Chunk: docs/chunks/shell_command_runner - One-off shell commands in an output tab
+//!
+//! One-off shell commands.
+//!
+//! "Run command" (Cmd+Shift+R) runs a command line in the workspace root and
+//! streams its output into a read-only `TabKind::CommandOutput` tab. The
+//! command runs under the user's login shell in a PTY, so it finds the same
+//! tools as a terminal tab and programs keep their colors. Unlike a terminal
+//! tab there is no interactive shell: keys aren't sent to the program, apart
+//! from Ctrl+C to stop it, and Cmd+R runs the command again in the same tab.
+
+use std::path::{Path, PathBuf};
+
+/// Longest command shown in a tab label before it is cut with an ellipsis.
+const MAX_LABEL_CHARS: usize = 32;
+
+/// A command run in a command output tab.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandRun {
+    /// The command line, as typed.
+    pub command: String,
+    /// The directory the command runs in.
+    pub cwd: PathBuf,
+    /// The exit code, once the command has finished.
+    pub exit_code: Option<i32>,
+}
+
+impl CommandRun {
+    /// Creates a run of `command` in `cwd` that hasn't finished yet.
+    pub fn new(command: impl Into<String>, cwd: impl Into<PathBuf>) -> Self {
+        Self {
+            command: command.into(),
+            cwd: cwd.into(),
+            exit_code: None,
+        }
+    }
+
+    /// Returns true until the command has exited.
+    pub fn is_running(&self) -> bool {
+        self.exit_code.is_none()
+    }
+
+    /// Returns the tab label: the command, shortened if long, after a marker.
+    ///
+    /// The marker is `$` while the command runs, then `✓` if it succeeded or
+    /// `✗` if it failed.
+    // Chunk: docs/chunks/task_runner - Outcome marker in the label
+    pub fn tab_label(&self) -> String {
+        let marker = match self.exit_code {
+            None => '$',
+            Some(0) => '✓',
+            Some(_) => '✗',
+        };
+        let command = self.command.trim();
+        if command.chars().count() <= MAX_LABEL_CHARS {
+            format!("{} {}", marker, command)
+        } else {
+            let head: String = command.chars().take(MAX_LABEL_CHARS - 1).collect();
+            format!("{} {}…", marker, head.trim_end())
+        }
+    }
+
+    /// Returns the status bar text for the tab: what's running, or how it ended.
+    pub fn status_text(&self) -> String {
+        match self.exit_code {
+            None => "Running - Ctrl+C to stop".to_string(),
+            Some(0) => "Command finished - Cmd+R to run again".to_string(),
+            Some(code) => format!("Command exited with code {} - Cmd+R to run again", code),
+        }
+    }
+}
+
+/// Returns the program and arguments that run `command` in the user's login shell.
+///
+/// The shell comes from `$SHELL`, falling back to `/bin/sh`. `-l` sources the
+/// login profile, so PATH matches a terminal tab even when the app was
+/// launched from the Finder.
+pub fn shell_invocation(command: &str) -> (String, Vec<String>) {
+    let shell = std::env::var("SHELL")
+        .ok()
+        .filter(|shell| !shell.is_empty())
+        .unwrap_or_else(|| "/bin/sh".to_string());
+    (shell, vec!["-l".to_string(), "-c".to_string(), command.to_string()])
+}
+
+/// Returns the prompt line shown while typing a command for `cwd`.
+pub fn prompt_hint(cwd: &Path) -> String {
+    format!("Run a shell command in {} (type a command)", cwd.display())
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_run_is_running() {
+        let run = CommandRun::new("cargo test", "/tmp");
+        assert!(run.is_running());
+        assert_eq!(run.status_text(), "Running - Ctrl+C to stop");
+    }
+
+    #[test]
+    fn test_status_text_reports_exit_code() {
+        let mut run = CommandRun::new("false", "/tmp");
+        run.exit_code = Some(0);
+        assert!(!run.is_running());
+        assert_eq!(run.status_text(), "Command finished - Cmd+R to run again");
+
+        run.exit_code = Some(101);
+        assert_eq!(
+            run.status_text(),
+            "Command exited with code 101 - Cmd+R to run again"
+        );
+    }
+
+    #[test]
+    fn test_tab_label_shortens_long_commands() {
+        assert_eq!(CommandRun::new(" make ", "/tmp").tab_label(), "$ make");
+
+        let long = "cargo test --workspace --all-features -- --nocapture";
+        let label = CommandRun::new(long, "/tmp").tab_label();
+        assert!(label.starts_with("$ cargo test"));
+        assert!(label.ends_with('…'));
+        assert_eq!(label.chars().count(), 2 + MAX_LABEL_CHARS);
+    }
+
+    #[test]
+    fn test_tab_label_marks_outcome() {
+        let mut run = CommandRun::new("make", "/tmp");
+        run.exit_code = Some(0);
+        assert_eq!(run.tab_label(), "✓ make");
+        run.exit_code = Some(2);
+        assert_eq!(run.tab_label(), "✗ make");
+    }
+
+    #[test]
+    fn test_shell_invocation_runs_login_shell_command() {
+        let (shell, args) = shell_invocation("ls -la");
+        assert!(!shell.is_empty());
+        assert_eq!(args, vec!["-l", "-c", "ls -la"]);
+    }
+}
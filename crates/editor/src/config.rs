@@ -0,0 +1,546 @@
+// Chunk: docs/chunks/line_highlight_rulers - User configuration file
+//!
+//! User configuration for the editor.
+//!
+//! Settings are read once at startup from a JSON file. Every field is
+//! optional: missing fields take their defaults and unknown fields are
+//! ignored, so an empty object (or no file at all) gives the stock editor.
+//!
+//! The Settings tab (see [`settings`](crate::settings)) changes one option at
+//! a time with [`save_setting`], which keeps the rest of the file as it is.
+//!
+//! ## File Location
+//!
+//! The config file is read from:
+//! - macOS: `~/Library/Application Support/lite-edit/config.json`
+//!
+//! ## Example
+//!
+//! ```json
+//! {
+//!   "font_family": "SF Mono",
+//!   "font_size": 13,
+//!   "highlight_current_line": true,
+//!   "rulers": [80, 100, 120],
+//!   "dim_inactive_panes": true,
+//!   "breadcrumbs": true,
+//!   "agent_finished_sound": "Glass",
+//!   "save": { "trim_trailing_whitespace": true, "ensure_final_newline": true },
+//!   "terminal": { "hot_scrollback_lines": 5000, "cold_cache_kb": 512, "recording_mb": 64 },
+//!   "agents": { "max_concurrent": 4, "templates": [{ "name": "Review", "command": "claude" }] },
+//!   "syntax_styles": { "keyword": { "bold": true }, "comment": { "italic": false } },
+//!   "keybindings": [{ "context": "Global", "action": "Pin / unpin tab", "keys": "Cmd+K" }]
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use lite_edit_syntax::{StyleModifiers, SyntaxTheme};
+use lite_edit_terminal::{AgentQueue, Recording, TerminalBuffer};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::agent_templates::AgentTemplate;
+use crate::font::{MAX_FONT_SIZE, MIN_FONT_SIZE};
+use crate::keymap::KeyOverride;
+use crate::save_transforms::SaveConfig;
+
+/// Application name used for the config directory.
+const APP_NAME: &str = "lite-edit";
+
+/// Config file name.
+const CONFIG_FILENAME: &str = "config.json";
+
+// Chunk: docs/chunks/font_config_zoom - Default font size
+/// Font size used when the config doesn't set one, in points
+pub const DEFAULT_FONT_SIZE: f64 = 14.0;
+
+/// User-facing editor settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EditorConfig {
+    // Chunk: docs/chunks/font_config_zoom - Font family and size options
+    /// Installed font family or PostScript name; `None` uses the bundled Intel One Mono
+    pub font_family: Option<String>,
+    /// Font size in points
+    pub font_size: f64,
+    /// Tint the background of the cursor's line in file tabs
+    pub highlight_current_line: bool,
+    /// Visual columns at which to draw vertical rulers in file tabs
+    pub rulers: Vec<usize>,
+    // Chunk: docs/chunks/dim_inactive_panes - Dim unfocused panes
+    /// Dim every pane but the focused one when the workspace is split
+    pub dim_inactive_panes: bool,
+    // Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb strip over file panes
+    /// Show workspace, path and enclosing symbol along the top of file panes
+    pub breadcrumbs: bool,
+    // Chunk: docs/chunks/feedback_hooks - Sound and haptic feedback options
+    /// Sound the system alert when a command has nothing to act on
+    pub alert_sound: bool,
+    /// Tap a Force Touch trackpad when find wraps around the buffer
+    pub haptic_feedback: bool,
+    /// System sound (e.g. "Glass") to play when an agent finishes; `None` plays none
+    pub agent_finished_sound: Option<String>,
+    // Chunk: docs/chunks/save_transforms - Save-time whitespace cleanups
+    /// Whitespace cleanups applied when saving, globally and per language
+    pub save: SaveConfig,
+    // Chunk: docs/chunks/syntax_style_modifiers - Per-capture style modifiers
+    /// Weight, slant and underline by syntax capture name (e.g. `"keyword"`)
+    pub syntax_styles: HashMap<String, SyntaxStyle>,
+    // Chunk: docs/chunks/keybinding_conflicts - User keybinding overrides
+    /// Chords moving built-in bindings, edited in the Keyboard Shortcuts tab
+    pub keybindings: Vec<KeyOverride>,
+    // Chunk: docs/chunks/terminal_memory_budget - Terminal scrollback limits
+    /// How much of each terminal's scrollback is kept in memory
+    pub terminal: TerminalConfig,
+    // Chunk: docs/chunks/agent_queue - Agents run at once
+    /// How queued agents are run
+    pub agents: AgentsConfig,
+}
+
+// Chunk: docs/chunks/terminal_memory_budget - Terminal scrollback limits
+/// How much of each terminal's scrollback is kept in memory. Older lines go
+/// to a file on disk and are read back through a cache when scrolled to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TerminalConfig {
+    /// Scrollback lines kept in memory before older ones are written to disk
+    pub hot_scrollback_lines: usize,
+    /// Memory, in KB, for scrollback read back from disk
+    pub cold_cache_kb: usize,
+    // Chunk: docs/chunks/terminal_replay - Session recording limit
+    /// Size limit, in MB, of each terminal's timestamped recording on disk;
+    /// 0 turns recording off
+    pub recording_mb: usize,
+}
+
+impl Default for TerminalConfig {
+    fn default() -> Self {
+        Self {
+            hot_scrollback_lines: TerminalBuffer::DEFAULT_HOT_SCROLLBACK_LIMIT,
+            cold_cache_kb: TerminalBuffer::DEFAULT_PAGE_CACHE_BYTES / 1024,
+            recording_mb: (Recording::DEFAULT_MAX_BYTES / (1024 * 1024)) as usize,
+        }
+    }
+}
+
+impl TerminalConfig {
+    /// Applies the limits to `terminal`.
+    pub fn apply(&self, terminal: &mut TerminalBuffer) {
+        terminal.set_hot_scrollback_limit(self.hot_scrollback_lines);
+        terminal.set_page_cache_bytes(self.cold_cache_kb.saturating_mul(1024));
+        terminal.set_recording_max_bytes((self.recording_mb as u64).saturating_mul(1024 * 1024));
+    }
+}
+
+// Chunk: docs/chunks/agent_queue - Agents run at once
+/// How queued agents are run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgentsConfig {
+    /// Queued agents running at once; the rest wait for one to exit
+    pub max_concurrent: usize,
+    // Chunk: docs/chunks/agent_templates - Saved agent launch templates
+    /// Agents offered by "New Agent…", in the order listed
+    pub templates: Vec<AgentTemplate>,
+}
+
+impl Default for AgentsConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent: AgentQueue::DEFAULT_MAX_CONCURRENT,
+            templates: Vec::new(),
+        }
+    }
+}
+
+// Chunk: docs/chunks/syntax_style_modifiers - Per-capture style modifiers
+/// Weight, slant and underline for one syntax capture; unset fields keep the
+/// theme's style.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyntaxStyle {
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub underline: Option<bool>,
+}
+
+impl Default for EditorConfig {
+    fn default() -> Self {
+        Self {
+            font_family: None,
+            font_size: DEFAULT_FONT_SIZE,
+            highlight_current_line: true,
+            rulers: Vec::new(),
+            dim_inactive_panes: false,
+            breadcrumbs: false,
+            alert_sound: true,
+            haptic_feedback: true,
+            agent_finished_sound: None,
+            save: SaveConfig::default(),
+            syntax_styles: HashMap::new(),
+            keybindings: Vec::new(),
+            terminal: TerminalConfig::default(),
+            agents: AgentsConfig::default(),
+        }
+    }
+}
+
+impl EditorConfig {
+    /// Parses a config from JSON text.
+    pub fn from_json(text: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(text)
+    }
+
+    // Chunk: docs/chunks/font_config_zoom - Font size validation
+    /// Returns the configured font size, clamped to the zoom range.
+    ///
+    /// A non-positive or non-finite size falls back to `DEFAULT_FONT_SIZE`.
+    pub fn font_point_size(&self) -> f64 {
+        if self.font_size.is_finite() && self.font_size > 0.0 {
+            self.font_size.clamp(MIN_FONT_SIZE, MAX_FONT_SIZE)
+        } else {
+            DEFAULT_FONT_SIZE
+        }
+    }
+
+    /// Returns the ruler columns sorted and deduplicated, so repeated
+    /// entries don't draw the same column twice.
+    pub fn ruler_columns(&self) -> Vec<usize> {
+        let mut rulers = self.rulers.clone();
+        rulers.sort_unstable();
+        rulers.dedup();
+        rulers
+    }
+
+    // Chunk: docs/chunks/syntax_style_modifiers - Per-capture style modifiers
+    /// Returns the syntax theme with the configured style modifiers applied.
+    pub fn syntax_theme(&self) -> SyntaxTheme {
+        let modifiers = self.syntax_styles.iter().map(|(name, style)| {
+            let modifiers = StyleModifiers {
+                bold: style.bold,
+                italic: style.italic,
+                underline: style.underline,
+            };
+            (name.as_str(), modifiers)
+        });
+        SyntaxTheme::catppuccin_mocha().with_modifiers(modifiers)
+    }
+
+    // Chunk: docs/chunks/settings_tab - One option changed by its dotted key
+    /// Returns a copy with the option at the dotted `key` (e.g.
+    /// `"save.ensure_final_newline"`) set to `value`, or an error if the
+    /// value doesn't fit the option.
+    pub fn with_setting(&self, key: &str, value: Value) -> Result<Self, serde_json::Error> {
+        let mut json = serde_json::to_value(self)?;
+        json_set(&mut json, key, value);
+        serde_json::from_value(json)
+    }
+}
+
+// Chunk: docs/chunks/settings_tab - Dotted keys into the config's JSON form
+/// Returns the value at the dotted `key` of `root`, if there is one.
+pub fn json_get<'a>(root: &'a Value, key: &str) -> Option<&'a Value> {
+    key.split('.').try_fold(root, |value, part| value.get(part))
+}
+
+/// Sets the value at the dotted `key` of `root`, replacing anything along
+/// the way that isn't an object.
+pub fn json_set(root: &mut Value, key: &str, value: Value) {
+    if !root.is_object() {
+        *root = Value::Object(Map::new());
+    }
+    let Some(map) = root.as_object_mut() else {
+        return;
+    };
+    match key.split_once('.') {
+        Some((first, rest)) => json_set(map.entry(first).or_insert(Value::Null), rest, value),
+        None => {
+            map.insert(key.to_string(), value);
+        }
+    }
+}
+
+/// Returns the path to the config file.
+///
+/// On macOS, this is `~/Library/Application Support/lite-edit/config.json`.
+/// Returns `None` if the application support directory cannot be determined.
+pub fn config_file_path() -> Option<PathBuf> {
+    Some(dirs::data_dir()?.join(APP_NAME).join(CONFIG_FILENAME))
+}
+
+/// Loads the user config, falling back to defaults.
+///
+/// A missing file is not an error. An unreadable or malformed file is
+/// reported on stderr and the defaults are used instead.
+pub fn load_config() -> EditorConfig {
+    let Some(path) = config_file_path() else {
+        return EditorConfig::default();
+    };
+
+    if !path.exists() {
+        return EditorConfig::default();
+    }
+
+    let contents = match fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Failed to read config file: {}", e);
+            return EditorConfig::default();
+        }
+    };
+
+    match EditorConfig::from_json(&contents) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to parse config file: {}", e);
+            EditorConfig::default()
+        }
+    }
+}
+
+// Chunk: docs/chunks/settings_tab - Writing one option back to the file
+/// Sets the option at the dotted `key` in the config file to `value`,
+/// creating the file if there isn't one.
+///
+/// Under `cfg(test)` nothing is written, so unit tests that change settings
+/// never touch the developer's config.
+#[cfg(not(test))]
+pub fn save_setting(key: &str, value: Value) -> io::Result<()> {
+    let path = config_file_path()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+    save_setting_to(&path, key, value)
+}
+
+#[cfg(test)]
+pub fn save_setting(_key: &str, _value: Value) -> io::Result<()> {
+    Ok(())
+}
+
+/// Sets the option at the dotted `key` in the config file at `path`.
+///
+/// The rest of the file, unknown fields included, is kept. A file that
+/// isn't valid JSON is left alone and reported as an error rather than
+/// overwritten.
+pub fn save_setting_to(path: &Path, key: &str, value: Value) -> io::Result<()> {
+    let mut json = match fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text).map_err(io::Error::other)?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Value::Object(Map::new()),
+        Err(e) => return Err(e),
+    };
+    json_set(&mut json, key, value);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let text = serde_json::to_string_pretty(&json).map_err(io::Error::other)?;
+    fs::write(path, text + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = EditorConfig::default();
+        assert!(config.highlight_current_line);
+        assert!(config.rulers.is_empty());
+        assert_eq!(config.font_family, None);
+        assert_eq!(config.font_size, DEFAULT_FONT_SIZE);
+    }
+
+    // Chunk: docs/chunks/font_config_zoom - Font family and size options
+    #[test]
+    fn test_font_options() {
+        let config =
+            EditorConfig::from_json(r#"{ "font_family": "SF Mono", "font_size": 13 }"#).unwrap();
+        assert_eq!(config.font_family.as_deref(), Some("SF Mono"));
+        assert_eq!(config.font_point_size(), 13.0);
+    }
+
+    // Chunk: docs/chunks/terminal_memory_budget - Terminal scrollback limits
+    #[test]
+    fn test_terminal_limits() {
+        let config =
+            EditorConfig::from_json(r#"{ "terminal": { "cold_cache_kb": 256 } }"#).unwrap();
+        assert_eq!(
+            config.terminal.hot_scrollback_lines,
+            TerminalBuffer::DEFAULT_HOT_SCROLLBACK_LIMIT
+        );
+
+        let mut terminal = TerminalBuffer::new(80, 24, 100);
+        config.terminal.apply(&mut terminal);
+        assert_eq!(
+            terminal.hot_scrollback_limit(),
+            TerminalBuffer::DEFAULT_HOT_SCROLLBACK_LIMIT
+        );
+        assert_eq!(terminal.page_cache_bytes(), 256 * 1024);
+        assert_eq!(terminal.recording_max_bytes(), Recording::DEFAULT_MAX_BYTES);
+
+        // Chunk: docs/chunks/terminal_replay - Session recording limit
+        let config = EditorConfig::from_json(r#"{ "terminal": { "recording_mb": 0 } }"#).unwrap();
+        config.terminal.apply(&mut terminal);
+        assert_eq!(terminal.recording_max_bytes(), 0);
+    }
+
+    // Chunk: docs/chunks/agent_queue - Agents run at once
+    #[test]
+    fn test_agents_max_concurrent() {
+        let config = EditorConfig::from_json("{}").unwrap();
+        let default = AgentQueue::DEFAULT_MAX_CONCURRENT;
+        assert_eq!(config.agents.max_concurrent, default);
+        let config = EditorConfig::from_json(r#"{ "agents": { "max_concurrent": 2 } }"#).unwrap();
+        assert_eq!(config.agents.max_concurrent, 2);
+        assert!(config.agents.templates.is_empty());
+    }
+
+    // Chunk: docs/chunks/agent_templates - Saved agent launch templates
+    #[test]
+    fn test_agent_templates() {
+        let json = r#"{ "agents": { "templates": [{ "name": "Review", "command": "claude" }] } }"#;
+        let config = EditorConfig::from_json(json).unwrap();
+        let default = AgentQueue::DEFAULT_MAX_CONCURRENT;
+        assert_eq!(config.agents.max_concurrent, default);
+        assert_eq!(config.agents.templates.len(), 1);
+        assert_eq!(config.agents.templates[0].command_line(), "claude");
+    }
+
+    #[test]
+    fn test_font_point_size_validation() {
+        let mut config = EditorConfig {
+            font_size: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(config.font_point_size(), DEFAULT_FONT_SIZE);
+        config.font_size = 500.0;
+        assert_eq!(config.font_point_size(), MAX_FONT_SIZE);
+        config.font_size = 2.0;
+        assert_eq!(config.font_point_size(), MIN_FONT_SIZE);
+    }
+
+    #[test]
+    fn test_empty_object_uses_defaults() {
+        let config = EditorConfig::from_json("{}").unwrap();
+        assert_eq!(config, EditorConfig::default());
+    }
+
+    #[test]
+    fn test_partial_config() {
+        let config = EditorConfig::from_json(r#"{ "rulers": [80, 120] }"#).unwrap();
+        assert!(config.highlight_current_line);
+        assert_eq!(config.rulers, vec![80, 120]);
+        assert!(!config.dim_inactive_panes);
+        assert!(!config.breadcrumbs);
+    }
+
+    // Chunk: docs/chunks/feedback_hooks - Sound and haptic feedback options
+    #[test]
+    fn test_feedback_options() {
+        let config =
+            EditorConfig::from_json(r#"{ "alert_sound": false, "agent_finished_sound": "Glass" }"#)
+                .unwrap();
+        assert!(!config.alert_sound);
+        assert!(config.haptic_feedback);
+        assert_eq!(config.agent_finished_sound.as_deref(), Some("Glass"));
+    }
+
+    // Chunk: docs/chunks/save_transforms - Save-time whitespace cleanups
+    #[test]
+    fn test_save_options() {
+        let config = EditorConfig::from_json(
+            r#"{ "save": { "ensure_final_newline": true, "languages": { "go": {} } } }"#,
+        )
+        .unwrap();
+        assert!(config.save.transforms.ensure_final_newline);
+        assert!(!config.save.transforms.trim_trailing_whitespace);
+        assert!(config.save.languages.contains_key("go"));
+    }
+
+    // Chunk: docs/chunks/syntax_style_modifiers - Per-capture style modifiers
+    #[test]
+    fn test_syntax_styles() {
+        let config = EditorConfig::from_json(
+            r#"{ "syntax_styles": { "keyword": { "bold": true }, "comment": { "italic": false } } }"#,
+        )
+        .unwrap();
+        let theme = config.syntax_theme();
+        assert!(theme.style_for_capture("keyword").unwrap().bold);
+        assert!(!theme.style_for_capture("comment").unwrap().italic);
+        // Untouched captures keep the stock style
+        assert!(theme.style_for_capture("type.builtin").unwrap().italic);
+    }
+
+    #[test]
+    fn test_unknown_fields_ignored() {
+        let config =
+            EditorConfig::from_json(r#"{ "highlight_current_line": false, "theme": "x" }"#)
+                .unwrap();
+        assert!(!config.highlight_current_line);
+    }
+
+    #[test]
+    fn test_malformed_config_is_error() {
+        assert!(EditorConfig::from_json(r#"{ "rulers": "80" }"#).is_err());
+    }
+
+    #[test]
+    fn test_ruler_columns_sorted_and_deduped() {
+        let config = EditorConfig {
+            highlight_current_line: false,
+            rulers: vec![120, 80, 100, 80],
+            ..EditorConfig::default()
+        };
+        assert_eq!(config.ruler_columns(), vec![80, 100, 120]);
+    }
+
+    // Chunk: docs/chunks/settings_tab - Dotted keys into the config's JSON form
+    #[test]
+    fn test_with_setting() {
+        let config = EditorConfig::default();
+        let changed = config
+            .with_setting("save.ensure_final_newline", Value::Bool(true))
+            .unwrap();
+        assert!(changed.save.transforms.ensure_final_newline);
+        let changed = changed
+            .with_setting("rulers", serde_json::json!([100]))
+            .unwrap();
+        assert_eq!(changed.rulers, vec![100]);
+        assert!(config
+            .with_setting("font_size", Value::from("big"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_save_setting_keeps_the_rest_of_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lite-edit").join(CONFIG_FILENAME);
+        save_setting_to(&path, "breadcrumbs", Value::Bool(true)).unwrap();
+        assert!(load_from(&path).breadcrumbs);
+
+        fs::write(
+            &path,
+            r#"{ "theme": "x", "save": { "languages": { "go": {} } } }"#,
+        )
+        .unwrap();
+        save_setting_to(&path, "save.trim_trailing_whitespace", Value::Bool(true)).unwrap();
+        let json: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json_get(&json, "theme"), Some(&Value::from("x")));
+        assert!(json_get(&json, "save.languages.go").is_some());
+        assert_eq!(
+            json_get(&json, "save.trim_trailing_whitespace"),
+            Some(&Value::Bool(true))
+        );
+
+        // A malformed file isn't overwritten
+        fs::write(&path, "{ oops").unwrap();
+        assert!(save_setting_to(&path, "breadcrumbs", Value::Bool(true)).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{ oops");
+    }
+
+    fn load_from(path: &Path) -> EditorConfig {
+        EditorConfig::from_json(&fs::read_to_string(path).unwrap()).unwrap()
+    }
+}
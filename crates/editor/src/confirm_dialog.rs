@@ -8,7 +8,7 @@
 //! selection for binary confirmation dialogs (e.g., "Abandon unsaved changes?").
 //!
 //! The widget handles keyboard navigation:
-//! - **Tab/Left/Right**: Toggle between Cancel and Abandon buttons
+//! - **Tab/Left/Right**: Move between the Cancel, optional alternate and Abandon buttons
 //! - **Enter**: Confirm the selected button
 //! - **Escape**: Always cancels (shortcut for Cancel button)
 //!
@@ -91,6 +91,34 @@ pub enum ConfirmDialogContext {
         /// The path that was deleted (for recreating the file).
         deleted_path: PathBuf,
     },
+    // Chunk: docs/chunks/terminal_paste_confirm - Multi-line paste confirmation
+    /// Pasting multi-line text into a terminal without bracketed paste mode.
+    ///
+    /// Without bracketed paste, the shell executes each line as it arrives, so
+    /// the paste is held until the user confirms it.
+    PasteMultiLine {
+        /// The pane containing the terminal tab.
+        pane_id: PaneId,
+        /// The index of the terminal tab within the pane.
+        tab_idx: usize,
+        /// The clipboard text to paste on confirmation.
+        text: String,
+    },
+    // Chunk: docs/chunks/picker_file_ops - Delete confirmation from the picker
+    /// Deleting a file or directory chosen in the file picker.
+    DeletePath {
+        /// The absolute path to delete on confirmation.
+        path: PathBuf,
+    },
+    // Chunk: docs/chunks/quit_running_summary - Quit with running processes
+    /// Quitting while agents or terminal commands are still running.
+    ///
+    /// Confirm kills everything and quits, the alternate button waits for the
+    /// processes to finish and then quits.
+    QuitWithRunningProcesses {
+        /// Number of running processes (listed in the dialog details).
+        running_count: usize,
+    },
 }
 
 /// Which button is currently selected in the confirm dialog.
@@ -99,16 +127,21 @@ pub enum ConfirmButton {
     /// The Cancel button (safe default)
     #[default]
     Cancel,
+    // Chunk: docs/chunks/quit_running_summary - Optional middle button
+    /// The middle button, offered only when the dialog has an alternate label
+    Alternate,
     /// The Abandon button (destructive action)
     Abandon,
 }
 
 impl ConfirmButton {
     /// Toggles between Cancel and Abandon.
+    ///
+    /// The alternate button toggles to Cancel.
     pub fn toggle(self) -> Self {
         match self {
             ConfirmButton::Cancel => ConfirmButton::Abandon,
-            ConfirmButton::Abandon => ConfirmButton::Cancel,
+            ConfirmButton::Alternate | ConfirmButton::Abandon => ConfirmButton::Cancel,
         }
     }
 }
@@ -120,6 +153,9 @@ pub enum ConfirmOutcome {
     Cancelled,
     /// User pressed Enter with Abandon selected
     Confirmed,
+    // Chunk: docs/chunks/quit_running_summary - Outcome of the middle button
+    /// User pressed Enter with the alternate button selected
+    Alternate,
     /// Dialog is still open, waiting for user input
     Pending,
 }
@@ -145,6 +181,11 @@ pub struct ConfirmDialog {
     pub cancel_label: String,
     /// Label for the confirm (right) button
     pub confirm_label: String,
+    // Chunk: docs/chunks/quit_running_summary - Detail lines and a third button
+    /// Lines listed between the prompt and the buttons (empty for a plain dialog)
+    pub details: Vec<String>,
+    /// Label for the optional middle button between Cancel and confirm
+    pub alternate_label: Option<String>,
 }
 
 impl ConfirmDialog {
@@ -158,6 +199,8 @@ impl ConfirmDialog {
             selected: ConfirmButton::Cancel,
             cancel_label: DEFAULT_CANCEL_LABEL.to_string(),
             confirm_label: DEFAULT_CONFIRM_LABEL.to_string(),
+            details: Vec::new(),
+            alternate_label: None,
         }
     }
 
@@ -175,36 +218,84 @@ impl ConfirmDialog {
             selected: ConfirmButton::Cancel,
             cancel_label: cancel_label.into(),
             confirm_label: confirm_label.into(),
+            details: Vec::new(),
+            alternate_label: None,
+        }
+    }
+
+    /// Lists `details` below the prompt, one per line.
+    // Chunk: docs/chunks/quit_running_summary - Detail lines builder
+    pub fn with_details(mut self, details: Vec<String>) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// Adds a middle button labelled `label` between Cancel and confirm.
+    // Chunk: docs/chunks/quit_running_summary - Alternate button builder
+    pub fn with_alternate(mut self, label: impl Into<String>) -> Self {
+        self.alternate_label = Some(label.into());
+        self
+    }
+
+    /// Returns the dialog's buttons from left to right.
+    fn buttons(&self) -> &'static [ConfirmButton] {
+        if self.alternate_label.is_some() {
+            &[
+                ConfirmButton::Cancel,
+                ConfirmButton::Alternate,
+                ConfirmButton::Abandon,
+            ]
+        } else {
+            &[ConfirmButton::Cancel, ConfirmButton::Abandon]
         }
     }
 
+    /// Returns the button `step` places from the selected one, clamped to the
+    /// ends of the row, or wrapping around with `wrap`.
+    fn neighbour(&self, step: isize, wrap: bool) -> ConfirmButton {
+        let buttons = self.buttons();
+        let len = buttons.len() as isize;
+        let current = buttons
+            .iter()
+            .position(|&b| b == self.selected)
+            .unwrap_or(0) as isize;
+        let index = if wrap {
+            (current + step).rem_euclid(len)
+        } else {
+            (current + step).clamp(0, len - 1)
+        };
+        buttons[index as usize]
+    }
+
     /// Handles a keyboard event and returns the appropriate outcome.
     ///
     /// # Behavior
     ///
-    /// - **Tab**: Toggles between Cancel and Abandon, returns `Pending`
-    /// - **Left arrow**: Selects Cancel, returns `Pending`
-    /// - **Right arrow**: Selects Abandon, returns `Pending`
+    /// - **Tab**: Selects the next button, wrapping around, returns `Pending`
+    /// - **Left arrow**: Selects the button to the left, returns `Pending`
+    /// - **Right arrow**: Selects the button to the right, returns `Pending`
     /// - **Return/Enter**: Returns `Cancelled` if Cancel is selected,
+    ///   `Alternate` if the alternate button is selected,
     ///   `Confirmed` if Abandon is selected
     /// - **Escape**: Returns `Cancelled` (shortcut for Cancel)
     /// - **All other keys**: Returns `Pending` (no-op)
     pub fn handle_key(&mut self, event: &KeyEvent) -> ConfirmOutcome {
         match &event.key {
             Key::Tab => {
-                self.selected = self.selected.toggle();
+                self.selected = self.neighbour(1, true);
                 ConfirmOutcome::Pending
             }
             Key::Left => {
-                self.selected = ConfirmButton::Cancel;
+                self.selected = self.neighbour(-1, false);
                 ConfirmOutcome::Pending
             }
             Key::Right => {
-                self.selected = ConfirmButton::Abandon;
+                self.selected = self.neighbour(1, false);
                 ConfirmOutcome::Pending
             }
             Key::Return => match self.selected {
                 ConfirmButton::Cancel => ConfirmOutcome::Cancelled,
+                ConfirmButton::Alternate => ConfirmOutcome::Alternate,
                 ConfirmButton::Abandon => ConfirmOutcome::Confirmed,
             },
             Key::Escape => ConfirmOutcome::Cancelled,
@@ -240,6 +331,11 @@ pub struct ConfirmDialogGeometry {
     pub cancel_button_x: f32,
     /// X coordinate of the Abandon button's left edge
     pub abandon_button_x: f32,
+    // Chunk: docs/chunks/quit_running_summary - Middle button and detail lines
+    /// X coordinate of the alternate button's left edge, if the dialog has one
+    pub alternate_button_x: Option<f32>,
+    /// Y coordinate of the first detail line's baseline
+    pub details_y: f32,
     /// Y coordinate of the button row
     pub buttons_y: f32,
     /// Width of each button
@@ -266,6 +362,17 @@ impl ConfirmDialogGeometry {
             && y >= self.buttons_y
             && y < self.buttons_y + self.button_height
     }
+
+    /// Returns true if (x, y) is inside the alternate button.
+    // Chunk: docs/chunks/quit_running_summary - Hit testing for the middle button
+    pub fn is_alternate_button(&self, x: f32, y: f32) -> bool {
+        self.alternate_button_x.is_some_and(|button_x| {
+            x >= button_x
+                && x < button_x + self.button_width
+                && y >= self.buttons_y
+                && y < self.buttons_y + self.button_height
+        })
+    }
 }
 
 // Padding and sizing constants
@@ -278,8 +385,8 @@ const BUTTON_GAP: f32 = 16.0;
 /// The dialog is:
 /// - Horizontally centered
 /// - Vertically positioned at ~40% from the top (for visual balance)
-/// - Wide enough for the prompt and two buttons side by side
-/// - Two rows tall: prompt row + buttons row (plus padding)
+/// - Wide enough for the prompt, the detail lines and the buttons side by side
+/// - Prompt row, one row per detail line, then the buttons row (plus padding)
 ///
 /// # Arguments
 ///
@@ -299,21 +406,50 @@ pub fn calculate_confirm_dialog_geometry(
     // Calculate button dimensions using the dialog's actual labels
     let cancel_label_width = dialog.cancel_label.len() as f32 * glyph_width;
     let confirm_label_width = dialog.confirm_label.len() as f32 * glyph_width;
-    let button_width = cancel_label_width.max(confirm_label_width) + 2.0 * BUTTON_PADDING;
+    // Chunk: docs/chunks/quit_running_summary - Room for the middle button
+    let alternate_label_width = dialog
+        .alternate_label
+        .as_ref()
+        .map_or(0.0, |label| label.len() as f32 * glyph_width);
+    let button_width = cancel_label_width
+        .max(confirm_label_width)
+        .max(alternate_label_width)
+        + 2.0 * BUTTON_PADDING;
     let button_height = line_height + BUTTON_PADDING;
 
-    // Calculate total buttons width (two buttons + gap)
-    let buttons_total_width = 2.0 * button_width + BUTTON_GAP;
+    // Calculate total buttons width (two or three buttons + gaps)
+    let button_count = if dialog.alternate_label.is_some() {
+        3.0
+    } else {
+        2.0
+    };
+    let buttons_total_width = button_count * button_width + (button_count - 1.0) * BUTTON_GAP;
 
     // Calculate prompt width using the actual prompt
     let prompt_width = dialog.prompt.len() as f32 * glyph_width;
-
-    // Panel width is the larger of buttons row or prompt, plus padding
-    let content_width = buttons_total_width.max(prompt_width);
+    // Chunk: docs/chunks/quit_running_summary - Room for the detail lines
+    let details_width = dialog
+        .details
+        .iter()
+        .map(|line| line.chars().count() as f32 * glyph_width)
+        .fold(0.0, f32::max);
+    let details_height = if dialog.details.is_empty() {
+        0.0
+    } else {
+        dialog.details.len() as f32 * line_height + DIALOG_PADDING
+    };
+
+    // Panel width is the larger of buttons row, prompt or details, plus padding
+    let content_width = buttons_total_width.max(prompt_width).max(details_width);
     let panel_width = content_width + 2.0 * DIALOG_PADDING;
 
-    // Panel height: padding + prompt line + gap + button row + padding
-    let panel_height = DIALOG_PADDING + line_height + DIALOG_PADDING + button_height + DIALOG_PADDING;
+    // Panel height: padding + prompt line + gap + details + button row + padding
+    let panel_height = DIALOG_PADDING
+        + line_height
+        + DIALOG_PADDING
+        + details_height
+        + button_height
+        + DIALOG_PADDING;
 
     // Center horizontally
     let panel_x = (view_width - panel_width) / 2.0;
@@ -328,8 +464,13 @@ pub fn calculate_confirm_dialog_geometry(
     // Button positions (centered within panel)
     let buttons_start_x = panel_x + (panel_width - buttons_total_width) / 2.0;
     let cancel_button_x = buttons_start_x;
-    let abandon_button_x = buttons_start_x + button_width + BUTTON_GAP;
-    let buttons_y = panel_y + DIALOG_PADDING + line_height + DIALOG_PADDING;
+    let alternate_button_x = dialog
+        .alternate_label
+        .as_ref()
+        .map(|_| buttons_start_x + button_width + BUTTON_GAP);
+    let abandon_button_x = buttons_start_x + (button_count - 1.0) * (button_width + BUTTON_GAP);
+    let details_y = prompt_y + DIALOG_PADDING + line_height;
+    let buttons_y = panel_y + DIALOG_PADDING + line_height + DIALOG_PADDING + details_height;
 
     ConfirmDialogGeometry {
         panel_x,
@@ -340,6 +481,8 @@ pub fn calculate_confirm_dialog_geometry(
         prompt_y,
         cancel_button_x,
         abandon_button_x,
+        alternate_button_x,
+        details_y,
         buttons_y,
         button_width,
         button_height,
@@ -493,6 +636,29 @@ mod tests {
         }
     }
 
+    // =========================================================================
+    // PasteMultiLine context tests
+    // Chunk: docs/chunks/terminal_paste_confirm - Tests for PasteMultiLine variant
+    // =========================================================================
+
+    #[test]
+    fn test_context_paste_multi_line_stores_pane_tab_and_text() {
+        let ctx = ConfirmDialogContext::PasteMultiLine {
+            pane_id: 7,
+            tab_idx: 2,
+            text: "make clean\nmake install\n".to_string(),
+        };
+
+        match ctx {
+            ConfirmDialogContext::PasteMultiLine { pane_id, tab_idx, text } => {
+                assert_eq!(pane_id, 7);
+                assert_eq!(tab_idx, 2);
+                assert_eq!(text, "make clean\nmake install\n");
+            }
+            _ => panic!("Expected PasteMultiLine variant"),
+        }
+    }
+
     // =========================================================================
     // Button label parameterization tests
     // Chunk: docs/chunks/generic_yes_no_modal - Tests for parameterized labels
@@ -925,6 +1091,68 @@ mod tests {
             "Longer labels should produce wider buttons"
         );
     }
+
+    // =========================================================================
+    // Detail lines and alternate button tests
+    // Chunk: docs/chunks/quit_running_summary - Three-button summary dialog
+    // =========================================================================
+
+    fn summary_dialog() -> ConfirmDialog {
+        ConfirmDialog::with_labels("Quit with 2 running processes?", "Cancel", "Kill All")
+            .with_alternate("Wait")
+            .with_details(vec![
+                "main: agent (running)".to_string(),
+                "main: Terminal 1 (running a command)".to_string(),
+            ])
+    }
+
+    #[test]
+    fn test_alternate_button_keyboard_navigation() {
+        let mut dialog = summary_dialog();
+        let tab = KeyEvent::new(Key::Tab, Modifiers::default());
+        let right = KeyEvent::new(Key::Right, Modifiers::default());
+        let left = KeyEvent::new(Key::Left, Modifiers::default());
+
+        dialog.handle_key(&tab);
+        assert_eq!(dialog.selected, ConfirmButton::Alternate);
+        dialog.handle_key(&right);
+        assert_eq!(dialog.selected, ConfirmButton::Abandon);
+        dialog.handle_key(&left);
+        assert_eq!(dialog.selected, ConfirmButton::Alternate);
+
+        let outcome = dialog.handle_key(&KeyEvent::new(Key::Return, Modifiers::default()));
+        assert_eq!(outcome, ConfirmOutcome::Alternate);
+
+        // Tab wraps from the last button back to Cancel
+        dialog.handle_key(&tab);
+        dialog.handle_key(&tab);
+        assert_eq!(dialog.selected, ConfirmButton::Cancel);
+    }
+
+    #[test]
+    fn test_geometry_with_details_and_alternate() {
+        let plain =
+            ConfirmDialog::with_labels("Quit with 2 running processes?", "Cancel", "Kill All");
+        let dialog = summary_dialog();
+        let plain_geom = calculate_confirm_dialog_geometry(800.0, 600.0, 16.0, 8.0, &plain);
+        let geom = calculate_confirm_dialog_geometry(800.0, 600.0, 16.0, 8.0, &dialog);
+
+        // Two detail lines plus a gap make the panel taller
+        assert_eq!(
+            geom.panel_height,
+            plain_geom.panel_height + 2.0 * 16.0 + DIALOG_PADDING
+        );
+        assert!(geom.details_y < geom.buttons_y);
+        assert!(plain_geom.alternate_button_x.is_none());
+
+        // The alternate button sits between Cancel and the confirm button
+        let alternate_x = geom.alternate_button_x.unwrap();
+        assert!(geom.cancel_button_x < alternate_x && alternate_x < geom.abandon_button_x);
+        let (x, y) = (alternate_x + 1.0, geom.buttons_y + 1.0);
+        assert!(geom.is_alternate_button(x, y));
+        assert!(!geom.is_cancel_button(x, y));
+        assert!(!geom.is_confirm_button(x, y));
+    }
 }
 
 // =============================================================================
@@ -952,15 +1180,19 @@ const BUTTON_SELECTED_COLOR: [f32; 4] = [0.54, 0.36, 0.72, 1.0]; // mauve
 const BUTTON_TEXT_COLOR: [f32; 4] = [0.804, 0.839, 0.957, 1.0];
 /// Prompt text color (subtext1)
 const PROMPT_TEXT_COLOR: [f32; 4] = [0.71, 0.75, 0.86, 1.0];
+// Chunk: docs/chunks/quit_running_summary - Detail line color
+/// Detail line text color (overlay1)
+const DETAIL_TEXT_COLOR: [f32; 4] = [0.498, 0.518, 0.612, 1.0];
 
 /// Manages vertex and index buffers for rendering the confirm dialog.
 ///
 /// Similar to `FindStripGlyphBuffer` but specialized for the modal confirm dialog.
 /// The dialog renders:
 /// 1. Panel background
-/// 2. Prompt text
+/// 2. Prompt text and detail lines
 /// 3. Cancel button (background + text)
-/// 4. Abandon button (background + text)
+/// 4. Alternate button, if any (background + text)
+/// 5. Abandon button (background + text)
 ///
 /// The selected button gets a highlighted background.
 // Chunk: docs/chunks/quad_buffer_prealloc - Persistent buffer fields
@@ -993,6 +1225,13 @@ pub struct ConfirmDialogGlyphBuffer {
     cancel_text_range: QuadRange,
     /// Abandon button text glyphs
     abandon_text_range: QuadRange,
+    // Chunk: docs/chunks/quit_running_summary - Detail lines and alternate button ranges
+    /// Alternate button background quad
+    alternate_bg_range: QuadRange,
+    /// Alternate button text glyphs
+    alternate_text_range: QuadRange,
+    /// Detail line glyphs
+    details_range: QuadRange,
 }
 
 impl ConfirmDialogGlyphBuffer {
@@ -1012,6 +1251,9 @@ impl ConfirmDialogGlyphBuffer {
             prompt_range: QuadRange::default(),
             cancel_text_range: QuadRange::default(),
             abandon_text_range: QuadRange::default(),
+            alternate_bg_range: QuadRange::default(),
+            alternate_text_range: QuadRange::default(),
+            details_range: QuadRange::default(),
         }
     }
 
@@ -1061,6 +1303,21 @@ impl ConfirmDialogGlyphBuffer {
         self.abandon_text_range
     }
 
+    /// Returns the index range for the alternate button background
+    pub fn alternate_bg_range(&self) -> QuadRange {
+        self.alternate_bg_range
+    }
+
+    /// Returns the index range for alternate button text glyphs
+    pub fn alternate_text_range(&self) -> QuadRange {
+        self.alternate_text_range
+    }
+
+    /// Returns the index range for detail line glyphs
+    pub fn details_range(&self) -> QuadRange {
+        self.details_range
+    }
+
     /// Updates the buffers with confirm dialog content
     ///
     /// # Arguments
@@ -1081,7 +1338,9 @@ impl ConfirmDialogGlyphBuffer {
         let prompt_len = dialog.prompt.chars().count();
         let cancel_len = dialog.cancel_label.len();
         let confirm_len = dialog.confirm_label.len();
-        let estimated_quads = 3 + prompt_len + cancel_len + confirm_len;
+        let alternate_len = dialog.alternate_label.as_ref().map_or(0, |label| label.len());
+        let details_len: usize = dialog.details.iter().map(|line| line.chars().count()).sum();
+        let estimated_quads = 4 + prompt_len + cancel_len + confirm_len + alternate_len + details_len;
 
         // Chunk: docs/chunks/quad_buffer_prealloc - Clear and reserve persistent buffers
         self.persistent_vertices.clear();
@@ -1101,6 +1360,9 @@ impl ConfirmDialogGlyphBuffer {
         self.prompt_range = QuadRange::default();
         self.cancel_text_range = QuadRange::default();
         self.abandon_text_range = QuadRange::default();
+        self.alternate_bg_range = QuadRange::default();
+        self.alternate_text_range = QuadRange::default();
+        self.details_range = QuadRange::default();
 
         let solid_glyph = atlas.solid_glyph();
 
@@ -1165,6 +1427,30 @@ impl ConfirmDialogGlyphBuffer {
         }
         self.abandon_bg_range = QuadRange::new(abandon_bg_start, self.persistent_indices.len() - abandon_bg_start);
 
+        // ==================== Phase 3b: Alternate Button Background ====================
+        // Chunk: docs/chunks/quit_running_summary - Middle button background
+        let alternate_bg_start = self.persistent_indices.len();
+        if let Some(button_x) = geometry.alternate_button_x {
+            let color = if dialog.selected == ConfirmButton::Alternate {
+                BUTTON_SELECTED_COLOR
+            } else {
+                BUTTON_BACKGROUND_COLOR
+            };
+            let quad = self.create_rect_quad(
+                button_x,
+                geometry.buttons_y,
+                geometry.button_width,
+                geometry.button_height,
+                solid_glyph,
+                color,
+            );
+            self.persistent_vertices.extend_from_slice(&quad);
+            Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+            vertex_offset += 4;
+        }
+        self.alternate_bg_range =
+            QuadRange::new(alternate_bg_start, self.persistent_indices.len() - alternate_bg_start);
+
         // ==================== Phase 4: Prompt Text ====================
         let prompt_start = self.persistent_indices.len();
         {
@@ -1189,6 +1475,35 @@ impl ConfirmDialogGlyphBuffer {
         }
         self.prompt_range = QuadRange::new(prompt_start, self.persistent_indices.len() - prompt_start);
 
+        // ==================== Phase 4b: Detail Lines ====================
+        // Chunk: docs/chunks/quit_running_summary - One detail per line under the prompt
+        let details_start = self.persistent_indices.len();
+        {
+            let glyph_width = self.layout.glyph_width;
+            let line_height = self.layout.line_height;
+
+            for (row, line) in dialog.details.iter().enumerate() {
+                let mut x = geometry.prompt_x;
+                let y = geometry.details_y + row as f32 * line_height - line_height;
+
+                for c in line.chars() {
+                    if c == ' ' {
+                        x += glyph_width;
+                        continue;
+                    }
+
+                    if let Some(glyph) = atlas.get_glyph(c) {
+                        let quad = self.create_glyph_quad_at(x, y, glyph, DETAIL_TEXT_COLOR);
+                        self.persistent_vertices.extend_from_slice(&quad);
+                        Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+                        vertex_offset += 4;
+                    }
+                    x += glyph_width;
+                }
+            }
+        }
+        self.details_range = QuadRange::new(details_start, self.persistent_indices.len() - details_start);
+
         // ==================== Phase 5: Cancel Button Text ====================
         // Chunk: docs/chunks/generic_yes_no_modal - Use dialog's actual cancel label
         let cancel_text_start = self.persistent_indices.len();
@@ -1234,6 +1549,35 @@ impl ConfirmDialogGlyphBuffer {
                     continue;
                 }
 
+                if let Some(glyph) = atlas.get_glyph(c) {
+                    let quad = self.create_glyph_quad_at(x, y, glyph, BUTTON_TEXT_COLOR);
+                    self.persistent_vertices.extend_from_slice(&quad);
+                    Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+                    vertex_offset += 4;
+                }
+                x += glyph_width;
+            }
+        }
+        self.abandon_text_range = QuadRange::new(abandon_text_start, self.persistent_indices.len() - abandon_text_start);
+
+        // ==================== Phase 7: Alternate Button Text ====================
+        // Chunk: docs/chunks/quit_running_summary - Middle button label
+        let alternate_text_start = self.persistent_indices.len();
+        if let (Some(label), Some(button_x)) =
+            (dialog.alternate_label.as_ref(), geometry.alternate_button_x)
+        {
+            let glyph_width = self.layout.glyph_width;
+            let text_width = label.len() as f32 * glyph_width;
+            // Center the text in the button
+            let mut x = button_x + (geometry.button_width - text_width) / 2.0;
+            let y = geometry.buttons_y + (geometry.button_height - self.layout.line_height) / 2.0;
+
+            for c in label.chars() {
+                if c == ' ' {
+                    x += glyph_width;
+                    continue;
+                }
+
                 if let Some(glyph) = atlas.get_glyph(c) {
                     let quad = self.create_glyph_quad_at(x, y, glyph, BUTTON_TEXT_COLOR);
                     self.persistent_vertices.extend_from_slice(&quad);
@@ -1244,7 +1588,8 @@ impl ConfirmDialogGlyphBuffer {
                 x += glyph_width;
             }
         }
-        self.abandon_text_range = QuadRange::new(abandon_text_start, self.persistent_indices.len() - abandon_text_start);
+        self.alternate_text_range =
+            QuadRange::new(alternate_text_start, self.persistent_indices.len() - alternate_text_start);
 
         // ==================== Create GPU Buffers ====================
         // Chunk: docs/chunks/quad_buffer_prealloc - Use persistent buffers for GPU upload
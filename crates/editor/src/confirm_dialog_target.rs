@@ -74,7 +74,7 @@ impl FocusTarget for ConfirmDialogFocusTarget {
             ConfirmOutcome::Pending => {
                 // No action needed, dialog is still open
             }
-            ConfirmOutcome::Confirmed | ConfirmOutcome::Cancelled => {
+            ConfirmOutcome::Confirmed | ConfirmOutcome::Alternate | ConfirmOutcome::Cancelled => {
                 self.pending_outcome = Some(outcome);
             }
         }
@@ -0,0 +1,283 @@
+// Chunk: docs/chunks/conflict_markers - Merge conflict navigation and resolution
+//!
+//! Finding and resolving merge conflict regions in a buffer.
+//!
+//! A conflict is the block git (or the editor's own three-way merge, see
+//! [`merge`](crate::merge)) writes where two sides changed the same lines:
+//!
+//! ```text
+//! <<<<<<< ours
+//! our lines
+//! ||||||| base        (only with merge.conflictStyle=diff3)
+//! the common ancestor's lines
+//! =======
+//! their lines
+//! >>>>>>> theirs
+//! ```
+//!
+//! [`find_conflicts`] locates the blocks, the glyph buffer tints their lines
+//! by [`ConflictPart`], and [`Conflict::resolve`] produces the text that
+//! replaces a block when one side, or both, is accepted.
+
+/// One conflict block, by line numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conflict {
+    /// The `<<<<<<<` line
+    pub start: usize,
+    /// The `|||||||` line, when the block shows the common ancestor
+    pub base: Option<usize>,
+    /// The `=======` line
+    pub separator: usize,
+    /// The `>>>>>>>` line
+    pub end: usize,
+}
+
+/// What a line of a conflict block holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictPart {
+    /// One of the marker lines
+    Marker,
+    /// Our side (between `<<<<<<<` and `|||||||` or `=======`)
+    Ours,
+    /// The common ancestor (between `|||||||` and `=======`)
+    Base,
+    /// Their side (between `=======` and `>>>>>>>`)
+    Theirs,
+}
+
+/// Which side of a conflict to keep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    Ours,
+    Theirs,
+    /// Our lines followed by theirs
+    Both,
+}
+
+impl Resolution {
+    /// Every resolution, in the order they are offered.
+    pub const ALL: [Resolution; 3] = [Resolution::Ours, Resolution::Theirs, Resolution::Both];
+
+    /// Returns the label offered in the selector.
+    pub fn label(self) -> &'static str {
+        match self {
+            Resolution::Ours => "Accept ours",
+            Resolution::Theirs => "Accept theirs",
+            Resolution::Both => "Accept both",
+        }
+    }
+}
+
+impl Conflict {
+    /// Returns true if `line` is part of the block, markers included.
+    pub fn contains(&self, line: usize) -> bool {
+        (self.start..=self.end).contains(&line)
+    }
+
+    /// Returns what `line` holds, or `None` outside the block.
+    pub fn part(&self, line: usize) -> Option<ConflictPart> {
+        if !self.contains(line) {
+            return None;
+        }
+        let ours_end = self.base.unwrap_or(self.separator);
+        let markers = [self.start, ours_end, self.separator, self.end];
+        Some(if markers.contains(&line) {
+            ConflictPart::Marker
+        } else if line < ours_end {
+            ConflictPart::Ours
+        } else if line < self.separator {
+            ConflictPart::Base
+        } else {
+            ConflictPart::Theirs
+        })
+    }
+
+    /// Returns the lines that replace the block for `resolution`, given the
+    /// buffer's lines. The markers and the common ancestor are dropped.
+    pub fn resolve<S: AsRef<str>>(&self, lines: &[S], resolution: Resolution) -> Vec<String> {
+        let ours = &lines[self.start + 1..self.base.unwrap_or(self.separator)];
+        let theirs = &lines[self.separator + 1..self.end];
+        let kept: Vec<&S> = match resolution {
+            Resolution::Ours => ours.iter().collect(),
+            Resolution::Theirs => theirs.iter().collect(),
+            Resolution::Both => ours.iter().chain(theirs).collect(),
+        };
+        kept.into_iter()
+            .map(|line| line.as_ref().to_string())
+            .collect()
+    }
+}
+
+/// Returns the marker `line` starts with, if it is one: seven marker
+/// characters, then the end of the line or a space and a label.
+fn marker(line: &str) -> Option<char> {
+    let first = line.chars().next()?;
+    if !matches!(first, '<' | '|' | '=' | '>') {
+        return None;
+    }
+    let rest = line.strip_prefix(&first.to_string().repeat(7))?;
+    (rest.is_empty() || rest.starts_with([' ', '\t'])).then_some(first)
+}
+
+/// Returns every complete conflict block in `lines`, in order.
+///
+/// Blocks missing a marker (for instance while one is being resolved by
+/// hand) are skipped, as are `=======` lines outside a block, which are
+/// common in Markdown and other prose.
+pub fn find_conflicts<S: AsRef<str>>(lines: impl IntoIterator<Item = S>) -> Vec<Conflict> {
+    let mut conflicts = Vec::new();
+    let mut open: Option<Conflict> = None;
+    for (line, text) in lines.into_iter().enumerate() {
+        let Some(mark) = marker(text.as_ref()) else {
+            continue;
+        };
+        match (mark, open.as_mut()) {
+            ('<', _) => {
+                open = Some(Conflict {
+                    start: line,
+                    base: None,
+                    separator: usize::MAX,
+                    end: usize::MAX,
+                });
+            }
+            ('|', Some(conflict)) if conflict.separator == usize::MAX => {
+                conflict.base = Some(line);
+            }
+            ('=', Some(conflict)) if conflict.separator == usize::MAX => {
+                conflict.separator = line;
+            }
+            ('>', Some(conflict)) if conflict.separator != usize::MAX => {
+                conflict.end = line;
+                conflicts.push(*conflict);
+                open = None;
+            }
+            _ => {}
+        }
+    }
+    conflicts
+}
+
+/// Returns the first conflict starting after `line`, wrapping around to the
+/// first one.
+pub fn next_conflict(conflicts: &[Conflict], line: usize) -> Option<&Conflict> {
+    conflicts
+        .iter()
+        .find(|conflict| conflict.start > line)
+        .or_else(|| conflicts.first())
+}
+
+/// Returns the last conflict starting before `line`, wrapping around to the
+/// last one.
+pub fn previous_conflict(conflicts: &[Conflict], line: usize) -> Option<&Conflict> {
+    conflicts
+        .iter()
+        .rev()
+        .find(|conflict| conflict.start < line)
+        .or_else(|| conflicts.last())
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "fn main() {\n\
+                        <<<<<<< HEAD\n\
+                        \x20   ours();\n\
+                        =======\n\
+                        \x20   theirs();\n\
+                        >>>>>>> feature\n\
+                        }\n\
+                        <<<<<<< buffer\n\
+                        a\n\
+                        ||||||| base\n\
+                        b\n\
+                        =======\n\
+                        c\n\
+                        >>>>>>> disk";
+
+    fn lines() -> Vec<&'static str> {
+        TEXT.lines().collect()
+    }
+
+    #[test]
+    fn test_find_conflicts() {
+        let conflicts = find_conflicts(lines());
+        assert_eq!(
+            conflicts,
+            vec![
+                Conflict {
+                    start: 1,
+                    base: None,
+                    separator: 3,
+                    end: 5,
+                },
+                Conflict {
+                    start: 7,
+                    base: Some(9),
+                    separator: 11,
+                    end: 13,
+                },
+            ]
+        );
+        let parts: Vec<Option<ConflictPart>> =
+            (6..=13).map(|line| conflicts[1].part(line)).collect();
+        use ConflictPart::*;
+        assert_eq!(
+            parts,
+            vec![
+                None,
+                Some(Marker),
+                Some(Ours),
+                Some(Marker),
+                Some(Base),
+                Some(Marker),
+                Some(Theirs),
+                Some(Marker)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_incomplete_blocks_and_prose_are_skipped() {
+        let text = [
+            "Title",
+            "=======",
+            "<<<<<<< HEAD",
+            "x",
+            "=======",
+            "<<<<<<<<",
+            ">>>>>>>>",
+        ];
+        assert!(find_conflicts(text).is_empty());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let lines = lines();
+        let conflicts = find_conflicts(lines.iter());
+        assert_eq!(
+            conflicts[0].resolve(&lines, Resolution::Ours),
+            vec!["    ours();"]
+        );
+        assert_eq!(conflicts[1].resolve(&lines, Resolution::Theirs), vec!["c"]);
+        assert_eq!(
+            conflicts[1].resolve(&lines, Resolution::Both),
+            vec!["a", "c"]
+        );
+    }
+
+    #[test]
+    fn test_navigation_wraps() {
+        let conflicts = find_conflicts(lines());
+        assert_eq!(next_conflict(&conflicts, 0).unwrap().start, 1);
+        assert_eq!(next_conflict(&conflicts, 1).unwrap().start, 7);
+        assert_eq!(next_conflict(&conflicts, 8).unwrap().start, 1);
+        assert_eq!(previous_conflict(&conflicts, 7).unwrap().start, 1);
+        assert_eq!(previous_conflict(&conflicts, 1).unwrap().start, 7);
+        assert!(next_conflict(&[], 0).is_none());
+    }
+}
@@ -0,0 +1,498 @@
+// Chunk: docs/chunks/project_content_search - Trigram index over workspace file contents
+//!
+//! A trigram index over the contents of a workspace's files, backing project
+//! search (Cmd+Shift+F) and find references (Shift+F12).
+//!
+//! Every indexed file is reduced to the set of three-byte sequences it
+//! contains (ASCII case-folded). A query's own trigrams select the files that
+//! can possibly contain it, and only those are read and scanned line by line.
+//! Queries shorter than three bytes have no trigrams and scan every file.
+//!
+//! The file list comes from the workspace's `FileIndex` crawl
+//! ([`ContentIndex::sync_with`]), and file contents are read on a background
+//! thread. Files that are known but not yet indexed are always scanned, so
+//! results are complete while the index is still being built. Saves and
+//! watcher events keep the index current through [`ContentIndex::update_file`],
+//! [`ContentIndex::remove_file`] and [`ContentIndex::rename_file`].
+
+use crate::file_index::FileIndex;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, RwLock};
+use std::thread::{self, JoinHandle};
+
+/// Files larger than this are neither indexed nor searched.
+const MAX_INDEXED_FILE_BYTES: u64 = 2 * 1024 * 1024;
+
+/// Number of leading bytes checked for NUL when deciding a file is binary.
+const BINARY_SNIFF_BYTES: usize = 8192;
+
+/// Searches stop after this many hits.
+pub const MAX_SEARCH_HITS: usize = 2000;
+
+/// Three ASCII-case-folded bytes packed into the low 24 bits.
+type Trigram = u32;
+
+/// Index into `IndexData::files`.
+type FileId = u32;
+
+/// A matching line from a content search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchHit {
+    /// Path relative to the index root.
+    pub path: PathBuf,
+    /// Line number (0-indexed).
+    pub line: usize,
+    /// Char column where the first match on the line starts (0-indexed).
+    pub col: usize,
+    /// Length of the match in chars.
+    pub len: usize,
+    /// The line's text, without its line terminator.
+    pub text: String,
+}
+
+/// Work for the background indexing thread.
+enum Job {
+    /// (Re)read and index the file at this relative path. The generation
+    /// identifies the request; only the latest one for a path is applied.
+    Index(PathBuf, u64),
+}
+
+#[derive(Default)]
+struct IndexData {
+    /// Relative path → id of its indexed entry.
+    ids: HashMap<PathBuf, FileId>,
+    /// Indexed files by id; `None` once removed.
+    files: Vec<Option<IndexedFile>>,
+    /// Trigram → files containing it.
+    postings: HashMap<Trigram, HashSet<FileId>>,
+    /// Files known to exist but not yet (re)indexed, with the generation of
+    /// their latest index request. Always scanned.
+    pending: HashMap<PathBuf, u64>,
+    /// Source of index request generations.
+    next_generation: u64,
+}
+
+struct IndexedFile {
+    path: PathBuf,
+    trigrams: Vec<Trigram>,
+}
+
+impl IndexData {
+    fn remove(&mut self, path: &Path) {
+        self.pending.remove(path);
+        let Some(id) = self.ids.remove(path) else {
+            return;
+        };
+        if let Some(file) = self.files[id as usize].take() {
+            for trigram in file.trigrams {
+                if let Some(ids) = self.postings.get_mut(&trigram) {
+                    ids.remove(&id);
+                    if ids.is_empty() {
+                        self.postings.remove(&trigram);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Replaces the entry for `path`. `None` trigrams (unreadable, binary or
+    /// oversized files) just drop it.
+    fn insert(&mut self, path: PathBuf, trigrams: Option<Vec<Trigram>>) {
+        self.remove(&path);
+        let Some(trigrams) = trigrams else {
+            return;
+        };
+        let id = self.files.len() as FileId;
+        for &trigram in &trigrams {
+            self.postings.entry(trigram).or_default().insert(id);
+        }
+        self.ids.insert(path.clone(), id);
+        self.files.push(Some(IndexedFile { path, trigrams }));
+    }
+
+    /// Marks `path` as pending and returns the job that will index it.
+    fn request(&mut self, path: PathBuf) -> Job {
+        self.next_generation += 1;
+        self.pending.insert(path.clone(), self.next_generation);
+        Job::Index(path, self.next_generation)
+    }
+
+    /// Files that may contain `needle`, sorted by path.
+    fn candidates(&self, needle: &str) -> Vec<PathBuf> {
+        let wanted = trigrams_of(needle.as_bytes());
+        let mut paths: Vec<PathBuf> = if wanted.is_empty() {
+            self.ids.keys().cloned().collect()
+        } else {
+            let mut sets: Vec<&HashSet<FileId>> = Vec::with_capacity(wanted.len());
+            for trigram in &wanted {
+                match self.postings.get(trigram) {
+                    Some(ids) => sets.push(ids),
+                    None => {
+                        sets.clear();
+                        break;
+                    }
+                }
+            }
+            sets.sort_by_key(|ids| ids.len());
+            match sets.split_first() {
+                Some((smallest, rest)) => smallest
+                    .iter()
+                    .filter(|id| rest.iter().all(|ids| ids.contains(*id)))
+                    .filter_map(|&id| self.files[id as usize].as_ref().map(|f| f.path.clone()))
+                    .collect(),
+                None => Vec::new(),
+            }
+        };
+        paths.extend(self.pending.keys().cloned());
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+}
+
+/// Thread-safe content index for one workspace root.
+pub struct ContentIndex {
+    root: PathBuf,
+    data: Arc<RwLock<IndexData>>,
+    jobs: Sender<Job>,
+    /// `FileIndex::cache_version` at the last `sync_with`.
+    synced_version: AtomicU64,
+    _worker_handle: JoinHandle<()>,
+}
+
+impl ContentIndex {
+    /// Creates an empty index for `root` and starts its background thread.
+    ///
+    /// Files are added with [`sync_with`](Self::sync_with) or
+    /// [`sync_paths`](Self::sync_paths).
+    pub fn start(root: PathBuf) -> Self {
+        let data = Arc::new(RwLock::new(IndexData::default()));
+        let (jobs, rx) = mpsc::channel();
+
+        let worker_root = root.clone();
+        let worker_data = Arc::clone(&data);
+        let handle = thread::spawn(move || run_worker(&worker_root, &worker_data, rx));
+
+        Self {
+            root,
+            data,
+            jobs,
+            synced_version: AtomicU64::new(u64::MAX),
+            _worker_handle: handle,
+        }
+    }
+
+    /// Returns true while some known files have not been indexed yet.
+    pub fn is_indexing(&self) -> bool {
+        !self.data.read().unwrap().pending.is_empty()
+    }
+
+    /// Brings the file list in line with `file_index`'s crawl.
+    ///
+    /// Cheap when the file index hasn't changed since the last call.
+    pub fn sync_with(&self, file_index: &FileIndex) {
+        let version = file_index.cache_version();
+        if self.synced_version.swap(version, Ordering::Relaxed) == version {
+            return;
+        }
+        let paths: Vec<PathBuf> = file_index.query("").into_iter().map(|r| r.path).collect();
+        self.sync_paths(&paths);
+    }
+
+    /// Makes `paths` (relative to the root) the set of files to index.
+    ///
+    /// New paths are queued for indexing; paths no longer listed are dropped.
+    pub fn sync_paths(&self, paths: &[PathBuf]) {
+        let listed: HashSet<&PathBuf> = paths.iter().collect();
+        let mut data = self.data.write().unwrap();
+
+        let gone: Vec<PathBuf> = data
+            .ids
+            .keys()
+            .chain(data.pending.keys())
+            .filter(|p| !listed.contains(p))
+            .cloned()
+            .collect();
+        for path in gone {
+            data.remove(&path);
+        }
+
+        for path in paths {
+            if !data.ids.contains_key(path) && !data.pending.contains_key(path) {
+                let job = data.request(path.clone());
+                let _ = self.jobs.send(job);
+            }
+        }
+    }
+
+    /// Re-indexes the file at `path` (absolute) after it changed on disk.
+    ///
+    /// Paths outside the root are ignored.
+    pub fn update_file(&self, path: &Path) {
+        let Ok(relative) = path.strip_prefix(&self.root) else {
+            return;
+        };
+        let job = self.data.write().unwrap().request(relative.to_path_buf());
+        let _ = self.jobs.send(job);
+    }
+
+    /// Drops the file at `path` (absolute) from the index.
+    pub fn remove_file(&self, path: &Path) {
+        if let Ok(relative) = path.strip_prefix(&self.root) {
+            self.data.write().unwrap().remove(relative);
+        }
+    }
+
+    /// Moves the entry for `from` to `to` (both absolute).
+    pub fn rename_file(&self, from: &Path, to: &Path) {
+        self.remove_file(from);
+        self.update_file(to);
+    }
+
+    /// Finds lines containing `query`, ignoring ASCII case.
+    ///
+    /// Hits are ordered by path, then line; each line is reported once, at
+    /// its first match.
+    pub fn search(&self, query: &str) -> Vec<SearchHit> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let needle = query.as_bytes();
+        self.scan(query, |line| {
+            line.as_bytes()
+                .windows(needle.len())
+                .position(|window| window.eq_ignore_ascii_case(needle))
+        })
+    }
+
+    /// Finds whole-word, case-sensitive occurrences of `word`, as used for
+    /// find references.
+    pub fn find_word(&self, word: &str) -> Vec<SearchHit> {
+        if word.is_empty() {
+            return Vec::new();
+        }
+        self.scan(word, |line| {
+            line.match_indices(word).map(|(start, _)| start).find(|&start| {
+                let before = line[..start].chars().next_back();
+                let after = line[start + word.len()..].chars().next();
+                !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char)
+            })
+        })
+    }
+
+    /// Scans the candidate files for `needle` using `find`, which returns the
+    /// byte offset of the first match on a line.
+    fn scan(&self, needle: &str, find: impl Fn(&str) -> Option<usize>) -> Vec<SearchHit> {
+        let candidates = self.data.read().unwrap().candidates(needle);
+        let len = needle.chars().count();
+        let mut hits = Vec::new();
+
+        for path in candidates {
+            let Some(text) = read_text(&self.root.join(&path)) else {
+                continue;
+            };
+            for (line, content) in text.lines().enumerate() {
+                if let Some(start) = find(content) {
+                    hits.push(SearchHit {
+                        path: path.clone(),
+                        line,
+                        col: content[..start].chars().count(),
+                        len,
+                        text: content.to_string(),
+                    });
+                    if hits.len() >= MAX_SEARCH_HITS {
+                        return hits;
+                    }
+                }
+            }
+        }
+        hits
+    }
+}
+
+/// Background thread: indexes queued files until the index is dropped.
+fn run_worker(root: &Path, data: &Arc<RwLock<IndexData>>, rx: Receiver<Job>) {
+    while let Ok(job) = rx.recv() {
+        match job {
+            Job::Index(path, generation) => {
+                let trigrams = read_text(&root.join(&path)).map(|text| {
+                    let mut trigrams: Vec<Trigram> =
+                        trigrams_of(text.as_bytes()).into_iter().collect();
+                    trigrams.sort_unstable();
+                    trigrams
+                });
+                let mut data = data.write().unwrap();
+                // Skip if the file was dropped while queued, or changed again
+                // and a newer request will index it
+                if data.pending.get(&path) != Some(&generation) {
+                    continue;
+                }
+                data.insert(path, trigrams);
+            }
+        }
+    }
+}
+
+/// Reads a file for indexing or scanning.
+///
+/// Returns `None` for unreadable, oversized or binary (NUL-containing) files.
+fn read_text(path: &Path) -> Option<String> {
+    let metadata = fs::metadata(path).ok()?;
+    if !metadata.is_file() || metadata.len() > MAX_INDEXED_FILE_BYTES {
+        return None;
+    }
+    let bytes = fs::read(path).ok()?;
+    if bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0) {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// The distinct case-folded trigrams of `bytes`.
+fn trigrams_of(bytes: &[u8]) -> HashSet<Trigram> {
+    bytes
+        .windows(3)
+        .map(|w| {
+            (w[0].to_ascii_lowercase() as u32) << 16
+                | (w[1].to_ascii_lowercase() as u32) << 8
+                | w[2].to_ascii_lowercase() as u32
+        })
+        .collect()
+}
+
+/// Characters that continue an identifier for whole-word matching.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn indexed(files: &[(&str, &str)]) -> (TempDir, ContentIndex) {
+        let temp = TempDir::new().unwrap();
+        let mut paths = Vec::new();
+        for (name, content) in files {
+            let path = temp.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, content).unwrap();
+            paths.push(PathBuf::from(name));
+        }
+        let index = ContentIndex::start(temp.path().to_path_buf());
+        index.sync_paths(&paths);
+        wait_for(&index);
+        (temp, index)
+    }
+
+    fn wait_for(index: &ContentIndex) {
+        while index.is_indexing() {
+            thread::sleep(std::time::Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_search_reports_line_and_column() {
+        let (_temp, index) = indexed(&[
+            ("src/main.rs", "fn main() {\n    let Config = load();\n}\n"),
+            ("README.md", "No match here\n"),
+        ]);
+
+        let hits = index.search("config");
+
+        assert_eq!(
+            hits,
+            vec![SearchHit {
+                path: PathBuf::from("src/main.rs"),
+                line: 1,
+                col: 8,
+                len: 6,
+                text: "    let Config = load();".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_candidates_use_trigrams() {
+        let (_temp, index) = indexed(&[("a.rs", "alpha beta"), ("b.rs", "gamma delta")]);
+
+        let data = index.data.read().unwrap();
+        assert_eq!(data.candidates("beta"), vec![PathBuf::from("a.rs")]);
+        assert!(data.candidates("zeta").is_empty());
+        // Too short for a trigram: every file is a candidate
+        assert_eq!(data.candidates("ta").len(), 2);
+    }
+
+    #[test]
+    fn test_short_query_scans_every_file() {
+        let (_temp, index) = indexed(&[("a.rs", "xy"), ("b.rs", "zz\nxy")]);
+
+        let hits = index.search("xy");
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!((hits[1].path.as_path(), hits[1].line), (Path::new("b.rs"), 1));
+    }
+
+    #[test]
+    fn test_find_word_matches_whole_words_only() {
+        let (_temp, index) = indexed(&[(
+            "lib.rs",
+            "fn parse() {}\nfn parse_all() { parse(); reparse(); }\n",
+        )]);
+
+        let hits = index.find_word("parse");
+
+        let positions: Vec<(usize, usize)> = hits.iter().map(|h| (h.line, h.col)).collect();
+        assert_eq!(positions, vec![(0, 3), (1, 17)]);
+        assert!(index.find_word("Parse").is_empty());
+    }
+
+    #[test]
+    fn test_update_file_picks_up_new_content() {
+        let (temp, index) = indexed(&[("notes.md", "old text")]);
+        let path = temp.path().join("notes.md");
+
+        fs::write(&path, "brand new text").unwrap();
+        index.update_file(&path);
+        wait_for(&index);
+
+        assert_eq!(index.search("brand").len(), 1);
+        assert!(index.search("old").is_empty());
+    }
+
+    #[test]
+    fn test_remove_and_rename() {
+        let (temp, index) = indexed(&[("a.rs", "needle"), ("b.rs", "needle")]);
+
+        fs::remove_file(temp.path().join("a.rs")).unwrap();
+        index.remove_file(&temp.path().join("a.rs"));
+        fs::rename(temp.path().join("b.rs"), temp.path().join("c.rs")).unwrap();
+        index.rename_file(&temp.path().join("b.rs"), &temp.path().join("c.rs"));
+        wait_for(&index);
+
+        let paths: Vec<PathBuf> = index.search("needle").into_iter().map(|h| h.path).collect();
+        assert_eq!(paths, vec![PathBuf::from("c.rs")]);
+    }
+
+    #[test]
+    fn test_sync_paths_drops_unlisted_files() {
+        let (_temp, index) = indexed(&[("a.rs", "needle"), ("b.rs", "needle")]);
+
+        index.sync_paths(&[PathBuf::from("b.rs")]);
+
+        let paths: Vec<PathBuf> = index.search("needle").into_iter().map(|h| h.path).collect();
+        assert_eq!(paths, vec![PathBuf::from("b.rs")]);
+    }
+
+    #[test]
+    fn test_binary_files_are_skipped() {
+        let (_temp, index) = indexed(&[("image.bin", "needle\0\x01\x02"), ("a.rs", "needle")]);
+
+        let paths: Vec<PathBuf> = index.search("needle").into_iter().map(|h| h.path).collect();
+        assert_eq!(paths, vec![PathBuf::from("a.rs")]);
+    }
+}
@@ -56,6 +56,13 @@ pub struct EditorContext<'a> {
     /// parsing API. The caller (e.g., `handle_key_buffer`) should check this field
     /// after handling the event and call `Tab::notify_edit()` if set.
     pub edit_info: Option<EditInfo>,
+    // Chunk: docs/chunks/read_only_preview_tabs - Read-only buffers refuse edits
+    /// Whether the buffer is read-only. Commands that would change its
+    /// content are refused instead of executed.
+    pub read_only: bool,
+    /// Set when a command was refused because the buffer is read-only, so
+    /// the caller can tell the user why nothing happened.
+    pub edit_refused: bool,
 }
 
 impl<'a> EditorContext<'a> {
@@ -90,6 +97,8 @@ impl<'a> EditorContext<'a> {
             content_mutated: false,
             // Chunk: docs/chunks/incremental_parse - Initialize edit_info to None
             edit_info: None,
+            read_only: false,
+            edit_refused: false,
         }
     }
 
@@ -0,0 +1,992 @@
+// Chunk: docs/chunks/diff_view - Read-only diff buffer
+//!
+//! Read-only diff views.
+//!
+//! A [`DiffBuffer`] holds the hunks of a diff between two versions of one or
+//! more files and presents them through `BufferView`, so a `TabKind::Diff` tab
+//! renders through the same pipeline as file and terminal tabs. The diff can be
+//! built by comparing two texts ([`DiffBuffer::from_texts`]) or by parsing a
+//! unified diff such as an agent-produced patch or `git diff` output
+//! ([`DiffBuffer::from_patch`]).
+//!
+//! The view is laid out either unified (one column, `-`/`+` lines) or side by
+//! side (old on the left, new on the right). In both layouts, a removed line
+//! paired with an added line has the changed characters highlighted more
+//! strongly than the rest of the line.
+//!
+//! Working-tree diffs are read with `git` subprocesses, like `git_remote`. The
+//! diff model and layout are pure so they can be unit-tested without a
+//! repository.
+
+use std::ops::Range;
+use std::path::Path;
+use std::process::Command;
+
+use lite_edit_buffer::{BufferView, Color, CursorInfo, DirtyLines, Span, Style, StyledLine};
+use similar::{ChangeTag, DiffTag, TextDiff};
+
+use crate::view_colors::{BLUE, GREEN, MAUVE, RED};
+
+/// Unchanged lines shown around each change.
+const CONTEXT_LINES: usize = 3;
+
+/// Widest the left column of the side-by-side layout gets; longer lines are
+/// truncated.
+const MAX_SIDE_WIDTH: usize = 100;
+
+/// Changed lines are only highlighted character by character when at least
+/// this fraction of the pair is unchanged; otherwise the lines are unrelated
+/// and the whole line is the change.
+const MIN_PAIR_SIMILARITY: f32 = 0.5;
+
+/// Tab stops used when expanding tabs, so side-by-side columns line up.
+const TAB_WIDTH: usize = 4;
+
+// Colors (Catppuccin Mocha, matching the editor's palette)
+const REMOVED_BG: Color = Color::Rgb { r: 0x3c, g: 0x28, b: 0x33 };
+const ADDED_BG: Color = Color::Rgb { r: 0x28, g: 0x3b, b: 0x2f };
+const REMOVED_EMPHASIS_BG: Color = Color::Rgb { r: 0x6e, g: 0x38, b: 0x4a };
+const ADDED_EMPHASIS_BG: Color = Color::Rgb { r: 0x3b, g: 0x62, b: 0x44 };
+
+// =============================================================================
+// Diff model
+// =============================================================================
+
+/// How a diff is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiffLayout {
+    /// One column: removed lines followed by the added lines replacing them.
+    #[default]
+    Unified,
+    /// Two columns: the old version on the left, the new one on the right.
+    SideBySide,
+}
+
+/// Whether a diff line is unchanged, removed or added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    Context,
+    Removed,
+    Added,
+}
+
+/// One line of a hunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: LineKind,
+    /// 1-based line number in the old version (None for added lines).
+    pub old_line: Option<usize>,
+    /// 1-based line number in the new version (None for removed lines).
+    pub new_line: Option<usize>,
+    /// The line's text, without its newline and with tabs expanded.
+    pub text: String,
+    /// Character ranges of `text` that differ from the paired line.
+    pub emphasis: Vec<Range<usize>>,
+}
+
+/// A run of changed lines with surrounding context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    /// The `@@ -a,b +c,d @@` header, including any section text after it.
+    pub header: String,
+    pub lines: Vec<DiffLine>,
+}
+
+/// The changes to one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    /// The file's path before the change.
+    pub old_path: String,
+    /// The file's path after the change.
+    pub new_path: String,
+    pub hunks: Vec<Hunk>,
+}
+
+impl FileDiff {
+    /// The title shown above the file's hunks.
+    fn title(&self) -> String {
+        if self.old_path == self.new_path || self.old_path == "/dev/null" {
+            self.new_path.clone()
+        } else if self.new_path == "/dev/null" {
+            format!("{} (deleted)", self.old_path)
+        } else {
+            format!("{} → {}", self.old_path, self.new_path)
+        }
+    }
+}
+
+// Chunk: docs/chunks/agent_edit_review - Hunks with the lines they replace
+/// A hunk of a diff between two texts, with the line ranges it covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TextHunk {
+    /// 0-based lines of the old text the hunk covers, context included.
+    pub old_range: Range<usize>,
+    /// 0-based lines of the new text replacing them.
+    pub new_range: Range<usize>,
+    pub hunk: Hunk,
+}
+
+/// Diffs `old` against `new` line by line into hunks with a few lines of
+/// context each.
+pub(crate) fn text_hunks(old: &str, new: &str) -> Vec<TextHunk> {
+    let diff = TextDiff::from_lines(old, new);
+    let mut hunks = Vec::new();
+
+    for group in diff.grouped_ops(CONTEXT_LINES) {
+        let (Some(first), Some(last)) = (group.first(), group.last()) else {
+            continue;
+        };
+        let old_range = first.old_range().start..last.old_range().end;
+        let new_range = first.new_range().start..last.new_range().end;
+
+        let mut lines = Vec::new();
+        for op in &group {
+            for change in diff.iter_changes(op) {
+                let kind = match change.tag() {
+                    ChangeTag::Equal => LineKind::Context,
+                    ChangeTag::Delete => LineKind::Removed,
+                    ChangeTag::Insert => LineKind::Added,
+                };
+                let text = change.value().trim_end_matches(['\n', '\r']);
+                lines.push(DiffLine {
+                    kind,
+                    old_line: change.old_index().map(|i| i + 1),
+                    new_line: change.new_index().map(|i| i + 1),
+                    text: expand_tabs(text),
+                    emphasis: Vec::new(),
+                });
+            }
+        }
+        highlight_changed_pairs(&mut lines);
+
+        let header = format!(
+            "@@ -{} +{} @@",
+            hunk_range(old_range.start, old_range.len()),
+            hunk_range(new_range.start, new_range.len())
+        );
+        hunks.push(TextHunk {
+            old_range,
+            new_range,
+            hunk: Hunk { header, lines },
+        });
+    }
+    hunks
+}
+
+// =============================================================================
+// DiffBuffer
+// =============================================================================
+
+/// A read-only diff presented as styled lines.
+#[derive(Debug, Clone)]
+pub struct DiffBuffer {
+    files: Vec<FileDiff>,
+    layout: DiffLayout,
+    /// The laid-out rows, rebuilt when the layout changes.
+    rows: Vec<StyledLine>,
+    dirty: DirtyLines,
+}
+
+impl DiffBuffer {
+    /// Creates a diff buffer for the given file diffs.
+    pub fn new(files: Vec<FileDiff>) -> Self {
+        let mut buffer = Self {
+            files,
+            layout: DiffLayout::default(),
+            rows: Vec::new(),
+            dirty: DirtyLines::None,
+        };
+        buffer.relayout();
+        buffer
+    }
+
+    /// Diffs `old` against `new`, labelling the result with `path`.
+    pub fn from_texts(path: &str, old: &str, new: &str) -> Self {
+        Self::compare_texts(path, old, path, new)
+    }
+
+    // Chunk: docs/chunks/compare_tabs - Diffs between differently named texts
+    /// Diffs `old` against `new` where the two sides have their own labels,
+    /// such as two open tabs; the title reads `old_path → new_path`.
+    pub fn compare_texts(old_path: &str, old: &str, new_path: &str, new: &str) -> Self {
+        let hunks: Vec<Hunk> = text_hunks(old, new)
+            .into_iter()
+            .map(|text_hunk| text_hunk.hunk)
+            .collect();
+
+        let files = if hunks.is_empty() {
+            Vec::new()
+        } else {
+            vec![FileDiff {
+                old_path: old_path.to_string(),
+                new_path: new_path.to_string(),
+                hunks,
+            }]
+        };
+        Self::new(files)
+    }
+
+    /// Parses a unified diff, e.g. `git diff` output or a patch produced by an
+    /// agent.
+    ///
+    /// Lines outside of hunks other than the `---`/`+++` file headers (commit
+    /// messages, `diff --git`, `index` lines) are ignored, so `git show` and
+    /// `git format-patch` output parse too.
+    pub fn from_patch(patch: &str) -> Self {
+        let mut files: Vec<FileDiff> = Vec::new();
+        let mut old_path: Option<String> = None;
+        // Remaining old/new lines in the current hunk
+        let mut remaining = (0usize, 0usize);
+        let mut next_line = (0usize, 0usize);
+
+        for raw in patch.lines() {
+            let in_hunk = remaining != (0, 0);
+            if !in_hunk {
+                if let Some(path) = raw.strip_prefix("--- ") {
+                    old_path = Some(patch_path(path));
+                    continue;
+                }
+                if let Some(path) = raw.strip_prefix("+++ ") {
+                    files.push(FileDiff {
+                        old_path: old_path.take().unwrap_or_default(),
+                        new_path: patch_path(path),
+                        hunks: Vec::new(),
+                    });
+                    continue;
+                }
+                if let Some((old_start, old_len, new_start, new_len)) = parse_hunk_header(raw) {
+                    if files.is_empty() {
+                        // A bare hunk with no file header
+                        files.push(FileDiff {
+                            old_path: String::new(),
+                            new_path: String::new(),
+                            hunks: Vec::new(),
+                        });
+                    }
+                    if let Some(file) = files.last_mut() {
+                        file.hunks.push(Hunk {
+                            header: raw.to_string(),
+                            lines: Vec::new(),
+                        });
+                    }
+                    remaining = (old_len, new_len);
+                    next_line = (old_start.max(1), new_start.max(1));
+                }
+                continue;
+            }
+
+            let Some(hunk) = files.last_mut().and_then(|f| f.hunks.last_mut()) else {
+                continue;
+            };
+            let (kind, text) = match raw.as_bytes().first() {
+                Some(b'-') => (LineKind::Removed, &raw[1..]),
+                Some(b'+') => (LineKind::Added, &raw[1..]),
+                Some(b' ') => (LineKind::Context, &raw[1..]),
+                // Some tools strip the space from empty context lines
+                None => (LineKind::Context, ""),
+                // "\ No newline at end of file"
+                Some(b'\\') => continue,
+                Some(_) => {
+                    // Malformed hunk: stop reading it
+                    remaining = (0, 0);
+                    continue;
+                }
+            };
+
+            let (old_line, new_line) = match kind {
+                LineKind::Context => (Some(next_line.0), Some(next_line.1)),
+                LineKind::Removed => (Some(next_line.0), None),
+                LineKind::Added => (None, Some(next_line.1)),
+            };
+            if old_line.is_some() {
+                next_line.0 += 1;
+                remaining.0 = remaining.0.saturating_sub(1);
+            }
+            if new_line.is_some() {
+                next_line.1 += 1;
+                remaining.1 = remaining.1.saturating_sub(1);
+            }
+            hunk.lines.push(DiffLine {
+                kind,
+                old_line,
+                new_line,
+                text: expand_tabs(text),
+                emphasis: Vec::new(),
+            });
+        }
+
+        for file in &mut files {
+            for hunk in &mut file.hunks {
+                highlight_changed_pairs(&mut hunk.lines);
+            }
+        }
+        files.retain(|f| !f.hunks.is_empty());
+        Self::new(files)
+    }
+
+    /// Returns the file diffs.
+    pub fn files(&self) -> &[FileDiff] {
+        &self.files
+    }
+
+    /// Returns true if the diff has no changes.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Returns the current layout.
+    pub fn layout(&self) -> DiffLayout {
+        self.layout
+    }
+
+    /// Sets the layout, re-laying out the rows if it changed.
+    pub fn set_layout(&mut self, layout: DiffLayout) {
+        if self.layout != layout {
+            self.layout = layout;
+            self.relayout();
+        }
+    }
+
+    /// Switches between the unified and side-by-side layouts.
+    pub fn toggle_layout(&mut self) {
+        self.set_layout(match self.layout {
+            DiffLayout::Unified => DiffLayout::SideBySide,
+            DiffLayout::SideBySide => DiffLayout::Unified,
+        });
+    }
+
+    /// Rebuilds the rows for the current layout and marks everything dirty.
+    fn relayout(&mut self) {
+        self.rows = if self.files.is_empty() {
+            vec![StyledLine::new(vec![Span::new("No changes", dim())])]
+        } else {
+            match self.layout {
+                DiffLayout::Unified => self.unified_rows(),
+                DiffLayout::SideBySide => self.side_by_side_rows(),
+            }
+        };
+        self.dirty = DirtyLines::FromLineToEnd(0);
+    }
+
+    /// Width of the line-number gutter: enough digits for the largest number.
+    fn number_width(&self) -> usize {
+        let max = self
+            .files
+            .iter()
+            .flat_map(|f| &f.hunks)
+            .flat_map(|h| &h.lines)
+            .flat_map(|l| l.old_line.into_iter().chain(l.new_line))
+            .max()
+            .unwrap_or(1);
+        max.to_string().len()
+    }
+
+    fn unified_rows(&self) -> Vec<StyledLine> {
+        let width = self.number_width();
+        let mut rows = Vec::new();
+
+        for (i, file) in self.files.iter().enumerate() {
+            push_file_header(&mut rows, file, i);
+            for hunk in &file.hunks {
+                rows.push(hunk_header(&hunk.header));
+                for line in &hunk.lines {
+                    rows.push(unified_row(line, width));
+                }
+            }
+        }
+        rows
+    }
+
+    fn side_by_side_rows(&self) -> Vec<StyledLine> {
+        let width = self.number_width();
+        let column = self
+            .files
+            .iter()
+            .flat_map(|f| &f.hunks)
+            .flat_map(|h| &h.lines)
+            .filter(|l| l.kind != LineKind::Added)
+            .map(|l| l.text.chars().count())
+            .max()
+            .unwrap_or(0)
+            .min(MAX_SIDE_WIDTH);
+        let mut rows = Vec::new();
+
+        for (i, file) in self.files.iter().enumerate() {
+            push_file_header(&mut rows, file, i);
+            for hunk in &file.hunks {
+                rows.push(hunk_header(&hunk.header));
+                for (left, right) in pair_rows(&hunk.lines) {
+                    let mut spans = Vec::new();
+                    spans.extend(side_spans(left, left.and_then(|l| l.old_line), width, Some(column)));
+                    spans.push(Span::new(" │ ", dim()));
+                    spans.extend(side_spans(right, right.and_then(|l| l.new_line), width, None));
+                    rows.push(StyledLine::new(spans));
+                }
+            }
+        }
+        rows
+    }
+}
+
+impl BufferView for DiffBuffer {
+    fn line_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        self.rows.get(line).cloned()
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.rows.get(line).map(|row| row.char_count()).unwrap_or(0)
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        None
+    }
+}
+
+// =============================================================================
+// Layout helpers
+// =============================================================================
+
+fn dim() -> Style {
+    Style {
+        dim: true,
+        ..Style::default()
+    }
+}
+
+/// The style of a line's text outside of its emphasized ranges.
+fn line_style(kind: LineKind, emphasized: bool) -> Style {
+    match (kind, emphasized) {
+        (LineKind::Context, _) => Style::default(),
+        (LineKind::Removed, false) => Style { fg: RED, bg: REMOVED_BG, ..Style::default() },
+        (LineKind::Removed, true) => Style { fg: RED, bg: REMOVED_EMPHASIS_BG, ..Style::default() },
+        (LineKind::Added, false) => Style { fg: GREEN, bg: ADDED_BG, ..Style::default() },
+        (LineKind::Added, true) => Style { fg: GREEN, bg: ADDED_EMPHASIS_BG, ..Style::default() },
+    }
+}
+
+fn line_number(number: Option<usize>, width: usize) -> String {
+    match number {
+        Some(n) => format!("{:>width$}", n),
+        None => " ".repeat(width),
+    }
+}
+
+/// Pushes a file's title row, preceded by a blank row between files.
+fn push_file_header(rows: &mut Vec<StyledLine>, file: &FileDiff, index: usize) {
+    if index > 0 {
+        rows.push(StyledLine::empty());
+    }
+    let title = file.title();
+    if !title.is_empty() {
+        rows.push(StyledLine::new(vec![Span::new(
+            title,
+            Style { fg: MAUVE, bold: true, ..Style::default() },
+        )]));
+    }
+}
+
+// Chunk: docs/chunks/agent_edit_review - Unified rows shared with the Review tab
+/// Formats a line of the unified layout: both line numbers, padded to
+/// `width`, then the sign and the text.
+pub(crate) fn unified_row(line: &DiffLine, width: usize) -> StyledLine {
+    let mut spans = vec![Span::new(
+        format!(
+            "{} {} ",
+            line_number(line.old_line, width),
+            line_number(line.new_line, width)
+        ),
+        dim(),
+    )];
+    let sign = match line.kind {
+        LineKind::Context => " ",
+        LineKind::Removed => "-",
+        LineKind::Added => "+",
+    };
+    spans.push(Span::new(sign, line_style(line.kind, false)));
+    spans.push(Span::new(" ", line_style(line.kind, false)));
+    spans.extend(text_spans(line, None));
+    StyledLine::new(spans)
+}
+
+fn hunk_header(header: &str) -> StyledLine {
+    StyledLine::new(vec![Span::new(header, Style { fg: BLUE, ..Style::default() })])
+}
+
+/// Splits a line's text into spans, emphasizing its changed ranges.
+///
+/// With `column`, the text is truncated or padded to exactly that many
+/// characters (padding keeps the line's background so the column is even).
+fn text_spans(line: &DiffLine, column: Option<usize>) -> Vec<Span> {
+    let mut chars: Vec<char> = line.text.chars().collect();
+    let mut truncated = false;
+    if let Some(column) = column {
+        if chars.len() > column {
+            chars.truncate(column.saturating_sub(1));
+            truncated = true;
+        }
+    }
+
+    let mut spans: Vec<Span> = Vec::new();
+    let mut push = |text: String, emphasized: bool| {
+        if text.is_empty() {
+            return;
+        }
+        let style = line_style(line.kind, emphasized);
+        match spans.last_mut() {
+            Some(last) if last.style == style => last.text.push_str(&text),
+            _ => spans.push(Span::new(text, style)),
+        }
+    };
+
+    let mut pos = 0;
+    for range in &line.emphasis {
+        let start = range.start.min(chars.len());
+        let end = range.end.min(chars.len());
+        if start < pos || start >= end {
+            continue;
+        }
+        push(chars[pos..start].iter().collect(), false);
+        push(chars[start..end].iter().collect(), true);
+        pos = end;
+    }
+    push(chars[pos..].iter().collect(), false);
+
+    if truncated {
+        push("…".to_string(), false);
+    }
+    if let Some(column) = column {
+        let used = chars.len() + usize::from(truncated);
+        push(" ".repeat(column.saturating_sub(used)), false);
+    }
+    spans
+}
+
+/// Spans for one side of a side-by-side row: line number then text.
+fn side_spans(line: Option<&DiffLine>, number: Option<usize>, width: usize, column: Option<usize>) -> Vec<Span> {
+    let mut spans = vec![Span::new(format!("{} ", line_number(number, width)), dim())];
+    match line {
+        Some(line) => spans.extend(text_spans(line, column)),
+        None => {
+            if let Some(column) = column {
+                spans.push(Span::plain(" ".repeat(column)));
+            }
+        }
+    }
+    spans
+}
+
+/// Pairs a hunk's lines into side-by-side rows.
+///
+/// Context lines appear on both sides. A run of removed lines and the run of
+/// added lines after it are placed next to each other; the shorter run is
+/// padded with empty cells.
+fn pair_rows(lines: &[DiffLine]) -> Vec<(Option<&DiffLine>, Option<&DiffLine>)> {
+    let mut rows = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].kind == LineKind::Context {
+            rows.push((Some(&lines[i]), Some(&lines[i])));
+            i += 1;
+            continue;
+        }
+        let removed_start = i;
+        while i < lines.len() && lines[i].kind == LineKind::Removed {
+            i += 1;
+        }
+        let added_start = i;
+        while i < lines.len() && lines[i].kind == LineKind::Added {
+            i += 1;
+        }
+        let removed = &lines[removed_start..added_start];
+        let added = &lines[added_start..i];
+        for row in 0..removed.len().max(added.len()) {
+            rows.push((removed.get(row), added.get(row)));
+        }
+    }
+    rows
+}
+
+// =============================================================================
+// Intra-line highlighting
+// =============================================================================
+
+/// Computes emphasis ranges for each removed line paired with an added line.
+///
+/// Within each run of removed lines followed by added lines, the n-th removed
+/// line is compared with the n-th added line character by character.
+fn highlight_changed_pairs(lines: &mut [DiffLine]) {
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].kind != LineKind::Removed {
+            i += 1;
+            continue;
+        }
+        let removed_start = i;
+        while i < lines.len() && lines[i].kind == LineKind::Removed {
+            i += 1;
+        }
+        let added_start = i;
+        while i < lines.len() && lines[i].kind == LineKind::Added {
+            i += 1;
+        }
+
+        let pairs = (added_start - removed_start).min(i - added_start);
+        for n in 0..pairs {
+            let (old, new) = changed_ranges(&lines[removed_start + n].text, &lines[added_start + n].text);
+            lines[removed_start + n].emphasis = old;
+            lines[added_start + n].emphasis = new;
+        }
+    }
+}
+
+/// Returns the character ranges that differ between `old` and `new`, or no
+/// ranges if the lines are too different for character highlighting to help.
+fn changed_ranges(old: &str, new: &str) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    let diff = TextDiff::from_chars(old, new);
+    if diff.ratio() < MIN_PAIR_SIMILARITY {
+        return (Vec::new(), Vec::new());
+    }
+
+    let mut old_ranges: Vec<Range<usize>> = Vec::new();
+    let mut new_ranges: Vec<Range<usize>> = Vec::new();
+    for op in diff.ops() {
+        if op.tag() == DiffTag::Equal {
+            continue;
+        }
+        push_range(&mut old_ranges, op.old_range());
+        push_range(&mut new_ranges, op.new_range());
+    }
+    (old_ranges, new_ranges)
+}
+
+/// Appends `range`, merging it into the previous range when they touch.
+fn push_range(ranges: &mut Vec<Range<usize>>, range: Range<usize>) {
+    if range.is_empty() {
+        return;
+    }
+    match ranges.last_mut() {
+        Some(last) if last.end == range.start => last.end = range.end,
+        _ => ranges.push(range),
+    }
+}
+
+// =============================================================================
+// Parsing helpers
+// =============================================================================
+
+/// Formats a hunk range as `start,len` (1-based; `start` is the line before an
+/// empty range, as in `diff -u`).
+fn hunk_range(start: usize, len: usize) -> String {
+    let start = if len == 0 { start } else { start + 1 };
+    if len == 1 {
+        start.to_string()
+    } else {
+        format!("{},{}", start, len)
+    }
+}
+
+/// Parses `@@ -a[,b] +c[,d] @@ ...` into `(a, b, c, d)`.
+fn parse_hunk_header(line: &str) -> Option<(usize, usize, usize, usize)> {
+    let rest = line.strip_prefix("@@ -")?;
+    let (ranges, _) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let parse = |range: &str| -> Option<(usize, usize)> {
+        match range.split_once(',') {
+            Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+            None => Some((range.parse().ok()?, 1)),
+        }
+    };
+    let (old_start, old_len) = parse(old)?;
+    let (new_start, new_len) = parse(new)?;
+    Some((old_start, old_len, new_start, new_len))
+}
+
+/// Extracts the path from a `---`/`+++` header, dropping git's `a/`/`b/`
+/// prefixes and any trailing timestamp.
+fn patch_path(header: &str) -> String {
+    let path = header.split('\t').next().unwrap_or(header).trim_end();
+    if path == "/dev/null" {
+        return path.to_string();
+    }
+    path.strip_prefix("a/")
+        .or_else(|| path.strip_prefix("b/"))
+        .unwrap_or(path)
+        .to_string()
+}
+
+/// Replaces tabs with spaces up to the next tab stop.
+fn expand_tabs(text: &str) -> String {
+    if !text.contains('\t') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut col = 0;
+    for c in text.chars() {
+        if c == '\t' {
+            let spaces = TAB_WIDTH - col % TAB_WIDTH;
+            out.extend(std::iter::repeat_n(' ', spaces));
+            col += spaces;
+        } else {
+            out.push(c);
+            col += 1;
+        }
+    }
+    out
+}
+
+// =============================================================================
+// Git working-tree diffs
+// =============================================================================
+
+/// Reasons a working-tree diff can't be produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitDiffError {
+    /// The path is not inside a git working tree.
+    NotInRepo,
+    /// `git diff` failed.
+    GitFailed(String),
+}
+
+impl std::fmt::Display for GitDiffError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GitDiffError::NotInRepo => write!(f, "Not in a git repository"),
+            GitDiffError::GitFailed(message) => write!(f, "git diff failed: {}", message),
+        }
+    }
+}
+
+/// Diffs `current` (the file's content, including unsaved edits) against the
+/// version of `file` at `HEAD`.
+///
+/// A file that isn't in `HEAD` yet (new or untracked) is diffed against an
+/// empty file.
+pub fn file_diff_against_head(file: &Path, current: &str) -> Result<DiffBuffer, GitDiffError> {
+    let dir = file.parent().ok_or(GitDiffError::NotInRepo)?;
+    let name = file.file_name().ok_or(GitDiffError::NotInRepo)?.to_string_lossy();
+
+    if git_stdout(dir, &["rev-parse", "--is-inside-work-tree"]).is_err() {
+        return Err(GitDiffError::NotInRepo);
+    }
+    // "HEAD:./name" resolves relative to the working directory
+    let head = git_stdout(dir, &["show", &format!("HEAD:./{}", name)]).unwrap_or_default();
+
+    Ok(DiffBuffer::from_texts(&name, &head, current))
+}
+
+/// Diffs the working tree (staged and unstaged changes) at `root` against `HEAD`.
+pub fn working_tree_diff(root: &Path) -> Result<DiffBuffer, GitDiffError> {
+    if git_stdout(root, &["rev-parse", "--is-inside-work-tree"]).is_err() {
+        return Err(GitDiffError::NotInRepo);
+    }
+    let patch = git_stdout(root, &["diff", "HEAD", "--no-color", "--no-ext-diff"])
+        // A repository with no commits has no HEAD: show the unstaged changes
+        .or_else(|_| git_stdout(root, &["diff", "--no-color", "--no-ext-diff"]))
+        .map_err(GitDiffError::GitFailed)?;
+
+    Ok(DiffBuffer::from_patch(&patch))
+}
+
+/// Runs `git <args>` in `dir` and returns its stdout, or its stderr on failure.
+fn git_stdout(dir: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row_text(buffer: &DiffBuffer, line: usize) -> String {
+        buffer
+            .styled_line(line)
+            .unwrap()
+            .spans
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect()
+    }
+
+    fn all_rows(buffer: &DiffBuffer) -> Vec<String> {
+        (0..buffer.line_count()).map(|i| row_text(buffer, i)).collect()
+    }
+
+    #[test]
+    fn test_from_texts_unified_layout() {
+        let old = "a\nb\nc\nd\n";
+        let new = "a\nB\nc\nd\ne\n";
+        let diff = DiffBuffer::from_texts("f.txt", old, new);
+
+        assert_eq!(
+            all_rows(&diff),
+            vec![
+                "f.txt",
+                "@@ -1,4 +1,5 @@",
+                "1 1   a",
+                "2   - b",
+                "  2 + B",
+                "3 3   c",
+                "4 4   d",
+                "  5 + e",
+            ]
+        );
+    }
+
+    // Chunk: docs/chunks/compare_tabs - Both labels in the title
+    #[test]
+    fn test_compare_texts_titles_both_sides() {
+        let diff = DiffBuffer::compare_texts("main.rs", "a\n", "Agent output", "b\n");
+        assert_eq!(row_text(&diff, 0), "main.rs → Agent output");
+        assert_eq!(diff.files()[0].hunks[0].lines.len(), 2);
+    }
+
+    #[test]
+    fn test_identical_texts_show_no_changes() {
+        let diff = DiffBuffer::from_texts("f.txt", "same\n", "same\n");
+        assert!(diff.is_empty());
+        assert_eq!(all_rows(&diff), vec!["No changes"]);
+    }
+
+    #[test]
+    fn test_distant_changes_get_separate_hunks() {
+        let old: String = (1..=20).map(|i| format!("{}\n", i)).collect();
+        let new: String = (1..=20)
+            .map(|i| match i {
+                2 => "two\n".to_string(),
+                19 => "nineteen\n".to_string(),
+                _ => format!("{}\n", i),
+            })
+            .collect();
+        let diff = DiffBuffer::from_texts("n.txt", &old, &new);
+
+        let hunks = &diff.files()[0].hunks;
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].header, "@@ -1,5 +1,5 @@");
+        assert_eq!(hunks[1].header, "@@ -16,5 +16,5 @@");
+    }
+
+    #[test]
+    fn test_intra_line_emphasis() {
+        let diff = DiffBuffer::from_texts("f.rs", "let x = 1;\n", "let x = 42;\n");
+        let lines = &diff.files()[0].hunks[0].lines;
+
+        assert_eq!(lines[0].kind, LineKind::Removed);
+        assert_eq!(lines[0].emphasis, vec![8..9]);
+        assert_eq!(lines[1].kind, LineKind::Added);
+        assert_eq!(lines[1].emphasis, vec![8..10]);
+
+        // The emphasized characters get their own span with the stronger background
+        let row = diff.styled_line(3).unwrap();
+        let emphasized: Vec<&str> = row
+            .spans
+            .iter()
+            .filter(|s| s.style.bg == ADDED_EMPHASIS_BG)
+            .map(|s| s.text.as_str())
+            .collect();
+        assert_eq!(emphasized, vec!["42"]);
+    }
+
+    #[test]
+    fn test_unrelated_lines_are_not_emphasized() {
+        let diff = DiffBuffer::from_texts("f.txt", "completely different\n", "xyz\n");
+        let lines = &diff.files()[0].hunks[0].lines;
+        assert!(lines.iter().all(|l| l.emphasis.is_empty()));
+    }
+
+    #[test]
+    fn test_from_patch_parses_git_diff() {
+        let patch = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 123..456 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -10,3 +10,3 @@ fn main() {
+ one
+-let a = 1;
++let a = 2;
+ three
+diff --git a/new.txt b/new.txt
+new file mode 100644
+--- /dev/null
++++ b/new.txt
+@@ -0,0 +1 @@
++hello
+\\ No newline at end of file
+";
+        let diff = DiffBuffer::from_patch(patch);
+        let files = diff.files();
+        assert_eq!(files.len(), 2);
+
+        assert_eq!(files[0].new_path, "src/lib.rs");
+        let lines = &files[0].hunks[0].lines;
+        assert_eq!(lines.len(), 4);
+        assert_eq!((lines[1].old_line, lines[1].new_line), (Some(11), None));
+        assert_eq!((lines[2].old_line, lines[2].new_line), (None, Some(11)));
+        assert_eq!((lines[3].old_line, lines[3].new_line), (Some(12), Some(12)));
+        assert_eq!(lines[1].emphasis, vec![8..9]);
+
+        assert_eq!(files[1].old_path, "/dev/null");
+        assert_eq!(files[1].title(), "new.txt");
+        assert_eq!(files[1].hunks[0].lines.len(), 1);
+    }
+
+    #[test]
+    fn test_side_by_side_layout() {
+        let mut diff = DiffBuffer::from_texts("f.txt", "keep\nold line\nremoved\n", "keep\nnew line\n");
+        diff.take_dirty();
+
+        diff.toggle_layout();
+        assert_eq!(diff.layout(), DiffLayout::SideBySide);
+        assert_eq!(diff.take_dirty(), DirtyLines::FromLineToEnd(0));
+
+        assert_eq!(
+            all_rows(&diff),
+            vec![
+                "f.txt",
+                "@@ -1,3 +1,2 @@",
+                "1 keep     │ 1 keep",
+                "2 old line │ 2 new line",
+                "3 removed  │   ",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_side_by_side_truncates_long_left_lines() {
+        let long = "x".repeat(MAX_SIDE_WIDTH + 10);
+        let mut diff = DiffBuffer::from_texts("f.txt", &format!("{}\n", long), "short\n");
+        diff.set_layout(DiffLayout::SideBySide);
+
+        let row = row_text(&diff, 2);
+        let (left, right) = row.split_once(" │ ").unwrap();
+        assert_eq!(left.chars().count(), "1 ".len() + MAX_SIDE_WIDTH);
+        assert!(left.ends_with('…'));
+        assert_eq!(right, "1 short");
+    }
+
+    #[test]
+    fn test_tabs_expanded() {
+        assert_eq!(expand_tabs("\tx\ty"), "    x   y");
+    }
+}
@@ -12,6 +12,7 @@
 //! NSView callbacks ─────────────────────┐
 //! PTY reader thread ────────────────────┤──→ EventSender ──→ mpsc channel
 //! Blink timer ──────────────────────────┤
+//! Scroll frame timer ───────────────────┤
 //! Window delegate ──────────────────────┘
 //!                                                               │
 //!                                                               ▼
@@ -24,17 +25,28 @@
 //!                                       EditorController (owned directly)
 //! ```
 
+use std::io::Write;
+use std::os::unix::net::UnixStream;
+use std::ptr::NonNull;
+use std::time::{Duration, Instant};
+
+use block2::RcBlock;
+use lite_edit_cli::{Request, REPLY_CLOSED, REPLY_OPENED};
 use objc2::rc::Retained;
 use objc2_app_kit::NSApplication;
-use objc2_foundation::{MainThreadMarker, NSString};
+use objc2_foundation::{MainThreadMarker, NSRunLoop, NSString, NSTimer};
 
+use crate::config::EditorConfig;
 use crate::dirty_region::InvalidationKind;
 use crate::editor_event::EditorEvent;
 // Chunk: docs/chunks/focus_stack - Use FocusLayer for render decisions
 use crate::editor_state::EditorState;
 use crate::focus::FocusLayer;
+use crate::glyph_buffer::LineDecorations;
 use crate::event_channel::{EventReceiver, EventSender};
 use crate::input::{KeyEvent, MarkedTextEvent, MouseEvent, ScrollDelta, TextInputEvent};
+use crate::menu::{self, MenuStates};
+use crate::services::ServiceSelectionSnapshot;
 use crate::metal_view::{CursorRect, CursorRegions, MetalView};
 use crate::renderer::Renderer;
 use crate::confirm_dialog::calculate_confirm_dialog_geometry;
@@ -42,9 +54,24 @@ use crate::confirm_dialog::calculate_confirm_dialog_geometry;
 // Chunk: docs/chunks/gotodef_status_render - Import StatusBarState for status message rendering
 use crate::selector_overlay::{calculate_overlay_geometry, FindStripState, StatusBarState};
 use crate::left_rail::RAIL_WIDTH;
-use crate::pane_layout::calculate_pane_rects;
+use crate::pane_layout::{calculate_divider_rects, SplitDirection};
+use crate::recents;
+use crate::session::{self, SessionAutosave};
 use crate::tab_bar::TAB_BAR_HEIGHT;
 
+// Chunk: docs/chunks/smooth_scroll_animation - Scroll frame cadence
+/// Interval of the scroll frame timer (one display frame at 60Hz).
+const SCROLL_FRAME_INTERVAL: f64 = 1.0 / 60.0;
+
+/// Longest frame step fed to a smooth scroll. A stalled frame finishes the
+/// animation sooner instead of jumping past it.
+const MAX_SCROLL_FRAME_STEP: f32 = 0.1;
+
+// Chunk: docs/chunks/pty_graceful_shutdown - Grace period for shells on quit
+/// How long shells and agents get to exit after SIGHUP and SIGTERM before
+/// they are killed when the app terminates.
+const PTY_SHUTDOWN_GRACE: Duration = Duration::from_millis(300);
+
 /// The event drain loop that owns the editor controller.
 ///
 /// This is the single point of access to the `EditorController`. The drain loop:
@@ -69,6 +96,36 @@ pub struct EventDrainLoop {
     /// Performance statistics collector (perf-instrumentation feature only)
     #[cfg(feature = "perf-instrumentation")]
     perf_stats: crate::perf_stats::PerfStats,
+    // Chunk: docs/chunks/render_stats_hud - PTY throughput for the HUD
+    /// Output rate of each terminal, shown in the render stats HUD
+    #[cfg(feature = "perf-instrumentation")]
+    pty_throughput: crate::perf_hud::PtyThroughput,
+    // Chunk: docs/chunks/session_idle_autosave - Idle session autosave state
+    /// Tracks periodic session saves made while the user is idle
+    session_autosave: SessionAutosave,
+    // Chunk: docs/chunks/smooth_scroll_animation - Scroll frame timer state
+    /// Timer sending `ScrollFrame` events; runs only while a smooth scroll animates
+    scroll_frame_timer: Option<Retained<NSTimer>>,
+    /// When the last scroll frame was stepped, for frame-rate independent easing
+    last_scroll_frame: Option<Instant>,
+    // Chunk: docs/chunks/menu_bar - Menu item states read by menu validation
+    /// Snapshot of menu item states, shared with the menu bar
+    menu_states: Option<MenuStates>,
+    // Chunk: docs/chunks/macos_services - Selection read by the Services menu
+    /// Snapshot of the selection offered to services, shared with the app delegate
+    service_selection: Option<ServiceSelectionSnapshot>,
+    // Chunk: docs/chunks/cli_launcher - Connections of `lite --wait` clients
+    /// `lite --wait` clients, waiting for their tabs to close
+    remote_waiters: Vec<RemoteWaiter>,
+}
+
+// Chunk: docs/chunks/cli_launcher - A waiting `lite` client
+/// A `lite --wait` client and the tabs it is waiting on.
+struct RemoteWaiter {
+    /// Opened tabs that haven't closed yet
+    tabs: Vec<crate::workspace::TabId>,
+    /// The client's connection, replied on once `tabs` is empty
+    reply: UnixStream,
 }
 
 impl EventDrainLoop {
@@ -96,6 +153,14 @@ impl EventDrainLoop {
             sender,
             #[cfg(feature = "perf-instrumentation")]
             perf_stats: crate::perf_stats::PerfStats::new(),
+            #[cfg(feature = "perf-instrumentation")]
+            pty_throughput: crate::perf_hud::PtyThroughput::new(),
+            session_autosave: SessionAutosave::new(std::time::Instant::now()),
+            scroll_frame_timer: None,
+            last_scroll_frame: None,
+            menu_states: None,
+            service_selection: None,
+            remote_waiters: Vec::new(),
         }
     }
 
@@ -112,6 +177,22 @@ impl EventDrainLoop {
         &self.state.editor
     }
 
+    // Chunk: docs/chunks/pty_graceful_shutdown - Graceful shutdown on app termination
+    /// Shuts down all shells and agents, giving them `PTY_SHUTDOWN_GRACE` to exit.
+    ///
+    /// This is called during application termination, after the session is saved.
+    pub fn shutdown_terminals(&mut self) {
+        self.state.editor.shutdown_terminals(PTY_SHUTDOWN_GRACE);
+    }
+
+    // Chunk: docs/chunks/background_file_io - Quitting waits for background saves
+    /// Blocks until every queued background save has been written.
+    ///
+    /// This is called during application termination.
+    pub fn finish_file_io(&mut self) {
+        self.state.finish_file_io();
+    }
+
     /// Provides mutable access to the renderer for initial setup.
     pub fn renderer_mut(&mut self) -> &mut Renderer {
         &mut self.renderer
@@ -175,6 +256,12 @@ impl EventDrainLoop {
             self.sender.clear_wakeup_pending();
         }
 
+        // Chunk: docs/chunks/smooth_scroll_animation - Run frames only while animating
+        self.update_scroll_frame_timer();
+
+        // Chunk: docs/chunks/cli_launcher - Release `lite --wait` clients
+        self.notify_remote_waiters();
+
         // Render once after processing all events
         self.render_if_dirty();
     }
@@ -207,6 +294,14 @@ impl EventDrainLoop {
             EditorEvent::FileDrop { paths, position } => {
                 self.handle_file_drop(paths, position);
             }
+            // Chunk: docs/chunks/finder_open - Files opened from Finder or `open`
+            EditorEvent::OpenFiles(paths) => {
+                self.state.open_paths(paths);
+            }
+            // Chunk: docs/chunks/cli_launcher - Requests from the `lite` command
+            EditorEvent::RemoteRequest { request, reply } => {
+                self.handle_remote_request(request, reply);
+            }
             // Chunk: docs/chunks/file_change_events - External file modification handling
             EditorEvent::FileChanged(path) => {
                 self.handle_file_changed(path);
@@ -240,6 +335,96 @@ impl EventDrainLoop {
             EditorEvent::ResumeFileWatchers => {
                 self.state.resume_file_watchers();
             }
+            // Chunk: docs/chunks/lazy_subsystem_init - Incremental post-launch warm-up
+            EditorEvent::Warmup => {
+                self.handle_warmup();
+            }
+            // Chunk: docs/chunks/smooth_scroll_animation - Smooth scroll frame tick
+            EditorEvent::ScrollFrame => {
+                self.handle_scroll_frame();
+            }
+            // Chunk: docs/chunks/settings_tab - Settings changed in the Settings tab
+            EditorEvent::SettingsChanged(config) => {
+                self.handle_settings_changed(*config);
+            }
+            // Chunk: docs/chunks/background_file_io - Background read and write results
+            EditorEvent::FileIo(event) => {
+                self.state.handle_file_io(event);
+            }
+        }
+    }
+
+    // Chunk: docs/chunks/settings_tab - Live settings changes
+    /// Applies a config changed in the Settings tab: the renderer's options,
+    /// then the editor state's. A new font family or size rebuilds the font
+    /// and re-lays out every viewport as a font zoom would.
+    fn handle_settings_changed(&mut self, config: EditorConfig) {
+        let font_changed = config.font_family != self.state.config.font_family
+            || config.font_point_size() != self.state.config.font_point_size();
+
+        self.renderer.set_line_decorations(LineDecorations {
+            highlight_current_line: config.highlight_current_line,
+            rulers: config.ruler_columns(),
+        });
+        self.renderer
+            .set_dim_inactive_panes(config.dim_inactive_panes);
+        if font_changed {
+            self.renderer
+                .set_configured_font(config.font_family.clone(), config.font_point_size());
+        }
+        self.state.apply_config(config);
+
+        if font_changed {
+            self.state.set_font_metrics(self.renderer.font_metrics());
+            self.handle_resize();
+        } else {
+            self.state.mark_full_dirty();
+        }
+    }
+
+    // Chunk: docs/chunks/smooth_scroll_animation - Smooth scroll frame tick
+    /// Advances running smooth scrolls by the time since the previous frame.
+    fn handle_scroll_frame(&mut self) {
+        let now = Instant::now();
+        let dt = self
+            .last_scroll_frame
+            .map(|last| now.duration_since(last).as_secs_f32())
+            .unwrap_or(SCROLL_FRAME_INTERVAL as f32)
+            .min(MAX_SCROLL_FRAME_STEP);
+        self.last_scroll_frame = Some(now);
+        self.state.step_scroll_animations(dt);
+    }
+
+    // Chunk: docs/chunks/smooth_scroll_animation - Start/stop the scroll frame timer
+    /// Starts the scroll frame timer when a smooth scroll begins and stops it
+    /// once every animation has settled, so an idle editor gets no frame wakeups.
+    fn update_scroll_frame_timer(&mut self) {
+        let animating = self.state.is_scroll_animating();
+        if animating && self.scroll_frame_timer.is_none() {
+            self.scroll_frame_timer = Some(start_scroll_frame_timer(self.sender.clone()));
+        } else if !animating {
+            if let Some(timer) = self.scroll_frame_timer.take() {
+                timer.invalidate();
+            }
+            self.last_scroll_frame = None;
+        }
+    }
+
+    // Chunk: docs/chunks/lazy_subsystem_init - Incremental post-launch warm-up
+    /// Performs one warm-up step, re-queueing the event while work remains.
+    ///
+    /// Re-sending (rather than looping) puts the next step behind any input
+    /// that arrived in the meantime.
+    fn handle_warmup(&mut self) {
+        if self.state.warm_up_step() {
+            let _ = self.sender.send_warmup();
+        } else {
+            #[cfg(feature = "perf-instrumentation")]
+            {
+                let startup = self.perf_stats.startup_mut();
+                startup.mark("warm-up complete");
+                eprint!("{}", startup.report());
+            }
         }
     }
 
@@ -269,6 +454,9 @@ impl EventDrainLoop {
     /// Tabs in conflict mode are skipped - they suppress auto-merge until
     /// the user saves to signal conflict resolution completion.
     fn handle_file_changed(&mut self, path: std::path::PathBuf) {
+        // Chunk: docs/chunks/project_content_search - Keep project search current
+        self.state.reindex_changed_file(&path);
+
         // Check if this is a self-triggered event (our own save)
         if self.state.is_file_change_suppressed(&path) {
             // Ignore - this was our own write
@@ -281,6 +469,11 @@ impl EventDrainLoop {
             return;
         }
 
+        // Chunk: docs/chunks/background_file_io - Ignore events for tabs still loading
+        if self.state.is_tab_loading(&path) {
+            return;
+        }
+
         // Chunk: docs/chunks/base_snapshot_reload - File change event handler
         if self.state.reload_file_tab(&path) {
             return;
@@ -324,14 +517,37 @@ impl EventDrainLoop {
             return;
         }
 
+        // Chunk: docs/chunks/font_config_zoom - Apply Cmd+Plus/Minus/0
+        self.apply_font_zoom();
+
         // Poll immediately after input for responsive terminal echo
         self.poll_after_input();
     }
 
+    // Chunk: docs/chunks/font_config_zoom - Live font size change
+    /// Applies a pending font zoom: rebuilds the renderer's font and atlas,
+    /// hands the new metrics to the editor state, then re-lays out every
+    /// viewport and terminal grid as a resize would.
+    fn apply_font_zoom(&mut self) {
+        let Some(zoom) = self.state.take_font_zoom() else {
+            return;
+        };
+        if !self.renderer.apply_font_zoom(zoom) {
+            return;
+        }
+        self.state.set_font_metrics(self.renderer.font_metrics());
+        self.handle_resize();
+    }
+
     /// Handles a mouse event by forwarding to the editor state.
     fn handle_mouse(&mut self, event: MouseEvent) {
         self.state.handle_mouse(event);
         self.poll_after_input();
+
+        // Chunk: docs/chunks/quit_running_summary - Quit dialog buttons can be clicked
+        if self.state.should_quit {
+            self.terminate_app();
+        }
     }
 
     /// Handles a scroll event by forwarding to the editor state.
@@ -362,6 +578,11 @@ impl EventDrainLoop {
         if needs_rewakeup {
             let _ = self.sender.send_pty_wakeup_followup();
         }
+
+        // Chunk: docs/chunks/quit_running_summary - A deferred quit fires once processes exit
+        if self.state.should_quit {
+            self.terminate_app();
+        }
     }
 
     /// Handles cursor blink timer by toggling visibility.
@@ -384,12 +605,49 @@ impl EventDrainLoop {
             let _ = self.sender.send_pty_wakeup_followup();
         }
 
+        // Chunk: docs/chunks/quit_running_summary - A deferred quit fires once processes exit
+        if self.state.should_quit {
+            self.terminate_app();
+            return;
+        }
+
         // Check for picker streaming updates
         let picker_dirty = self.state.tick_picker();
         if picker_dirty.is_dirty() {
             // Chunk: docs/chunks/invalidation_separation - Overlay invalidation for picker updates
             self.state.invalidation.merge(InvalidationKind::Overlay);
         }
+
+        self.autosave_session();
+    }
+
+    // Chunk: docs/chunks/session_idle_autosave - Save session during idle periods
+    /// Writes the session file if the user has been idle long enough.
+    ///
+    /// Runs on the blink timer tick, so a crash or force-kill loses at most
+    /// `session::AUTOSAVE_INTERVAL` of session state rather than everything
+    /// since launch.
+    // Chunk: docs/chunks/welcome_recents - Recents saved alongside the session
+    fn autosave_session(&mut self) {
+        let last_activity = self.state.last_keystroke.max(self.state.last_overlay_keystroke);
+        let now = std::time::Instant::now();
+        if !self.session_autosave.is_due(now, last_activity) {
+            return;
+        }
+        if self.state.editor.recents.take_changed() {
+            if let Err(e) = recents::save_recents(&self.state.editor.recents) {
+                eprintln!("Failed to save recents: {}", e);
+            }
+        }
+        let Some(path) = session::session_file_path() else {
+            return;
+        };
+        if let Err(e) = self
+            .session_autosave
+            .maybe_save(&self.state.editor, &path, now, last_activity)
+        {
+            eprintln!("Failed to autosave session: {}", e);
+        }
     }
 
     /// Handles window resize.
@@ -418,6 +676,36 @@ impl EventDrainLoop {
         self.poll_after_input();
     }
 
+    // Chunk: docs/chunks/cli_launcher - Requests from the `lite` command
+    /// Opens what the `lite` command asked for and brings the app forward.
+    ///
+    /// Replies once the files are open. A waiting request keeps its
+    /// connection until the opened tabs have all closed.
+    fn handle_remote_request(&mut self, request: Request, mut reply: UnixStream) {
+        let tabs = self.state.open_locations(request.locations);
+
+        let mtm = MainThreadMarker::new().expect("must be on main thread");
+        #[allow(deprecated)]
+        NSApplication::sharedApplication(mtm).activateIgnoringOtherApps(true);
+
+        if writeln!(reply, "{}", REPLY_OPENED).is_ok() && request.wait {
+            self.remote_waiters.push(RemoteWaiter { tabs, reply });
+        }
+    }
+
+    /// Replies to `lite --wait` clients whose tabs have all closed.
+    fn notify_remote_waiters(&mut self) {
+        let editor = &self.state.editor;
+        self.remote_waiters.retain_mut(|waiter| {
+            waiter.tabs.retain(|&tab_id| editor.has_tab(tab_id));
+            if !waiter.tabs.is_empty() {
+                return true;
+            }
+            let _ = writeln!(waiter.reply, "{}", REPLY_CLOSED);
+            false
+        });
+    }
+
     // Chunk: docs/chunks/unicode_ime_input - Text input event handlers
 
     /// Handles text insertion events from IME, keyboard, paste, or dictation.
@@ -462,6 +750,9 @@ impl EventDrainLoop {
             // Chunk: docs/chunks/invalidation_separation - Overlay invalidation for picker updates
             self.state.invalidation.merge(InvalidationKind::Overlay);
         }
+
+        // Chunk: docs/chunks/recent_files_switcher - Feed tab activations into the MRU list
+        self.state.record_active_file();
     }
 
     /// Terminates the macOS application.
@@ -479,6 +770,9 @@ impl EventDrainLoop {
         self.update_window_title_if_needed();
 
         if self.state.is_dirty() {
+            self.update_menu_states();
+            self.update_service_selection();
+
             // Chunk: docs/chunks/pane_scroll_isolation - Viewport sync removed
             // Viewport sync used to happen here, but now render_with_editor and render_pane
             // configure the viewport from the active tab's viewport before rendering.
@@ -492,6 +786,8 @@ impl EventDrainLoop {
             if invalidation.requires_layout_recalc() {
                 self.renderer.invalidate_pane_layout();
             }
+            // Chunk: docs/chunks/partial_redraw - Frame damage from the invalidation
+            self.renderer.set_frame_invalidation(invalidation);
 
             // Convert to dirty region for backward compatibility with perf-instrumentation
             let _dirty = match &invalidation {
@@ -502,6 +798,13 @@ impl EventDrainLoop {
                 }
             };
 
+            // Chunk: docs/chunks/prose_word_count - Recount edited lines before they're consumed
+            self.state.refresh_word_count();
+            // Chunk: docs/chunks/spell_check - Recheck edited lines before they're consumed
+            self.state.refresh_spelling();
+            // Chunk: docs/chunks/git_blame_inline - Move the blame text to the cursor line
+            self.state.refresh_blame();
+
             // Chunk: docs/chunks/styled_line_cache - Handle styled line cache invalidation
             // Check if the cache should be fully cleared (e.g., on tab switch)
             if self.state.take_clear_styled_line_cache() {
@@ -517,6 +820,24 @@ impl EventDrainLoop {
             #[cfg(feature = "perf-instrumentation")]
             self.perf_stats.record_dirty_region(&_dirty);
 
+            // Chunk: docs/chunks/tab_drag_drop - Pass drop highlight to renderer
+            self.renderer.set_tab_drop_highlight(self.state.tab_drop_highlight());
+            // Chunk: docs/chunks/tab_reorder_drag - Pass the drop gap to renderer
+            self.renderer.set_tab_reorder_slot(self.state.tab_reorder_slot());
+            // Chunk: docs/chunks/pane_scrollbar - Pass scrollbar thumbs to renderer
+            self.renderer.set_scrollbars(self.state.scrollbars());
+            // Chunk: docs/chunks/find_scrollbar_ticks - Pass find-match ticks to renderer
+            self.renderer.set_match_ticks(self.state.find_match_ticks());
+            // Chunk: docs/chunks/terminal_shell_integration - Pass prompt marks to renderer
+            self.renderer.set_prompt_marks(self.state.prompt_marks());
+            // Chunk: docs/chunks/find_match_highlights - Pass on-screen find matches to renderer
+            self.renderer.set_find_matches(self.state.find_match_highlights());
+            // Chunk: docs/chunks/pane_breadcrumbs - Pass breadcrumb text to renderer
+            self.renderer.set_breadcrumbs(self.state.breadcrumb_strips());
+            // Chunk: docs/chunks/render_stats_hud - Pass the render stats HUD to renderer
+            #[cfg(feature = "perf-instrumentation")]
+            self.update_perf_hud();
+
             // Chunk: docs/chunks/focus_stack - Render based on focus layer
             // Render based on current focus layer (derived from FocusStack)
             match self.state.focus_layer() {
@@ -551,6 +872,7 @@ impl EventDrainLoop {
                                 query,
                                 cursor_col,
                                 cursor_visible: self.state.overlay_cursor_visible,
+                                options: self.state.find_options,
                             }),
                             None, // No status bar when find is active (find strip takes precedence)
                         );
@@ -559,10 +881,11 @@ impl EventDrainLoop {
                 FocusLayer::Buffer | FocusLayer::GlobalShortcuts => {
                     self.renderer.set_cursor_visible(self.state.cursor_visible);
                     // Chunk: docs/chunks/gotodef_status_render - Pass status message to renderer
-                    // Get the current status message (if any) and build StatusBarState.
-                    // Note: current_status_message() takes &mut self because it clears expired messages.
-                    // We extract the text to a local String to avoid borrow conflicts.
-                    let status_text = self.state.current_status_message().map(|s| s.to_owned());
+                    // Chunk: docs/chunks/prose_word_count - Word count shown when no message is active
+                    // Get the current status bar text (if any) and build StatusBarState.
+                    // Note: status_bar_text() takes &mut self because it clears expired messages.
+                    // It returns an owned String to avoid borrow conflicts.
+                    let status_text = self.state.status_bar_text();
                     let status_bar = status_text.as_ref().map(|text| StatusBarState { text });
                     self.renderer.render_with_editor(
                         &self.metal_view,
@@ -587,6 +910,9 @@ impl EventDrainLoop {
             // Update cursor regions after rendering
             self.update_cursor_regions();
 
+            // Chunk: docs/chunks/ime_dead_key_composition - Keep the IME candidate window at the cursor
+            self.update_text_input_rect();
+
             // Record styled_line timing from the renderer
             #[cfg(feature = "perf-instrumentation")]
             if let Some((duration, line_count)) = self.renderer.take_styled_line_timing() {
@@ -620,6 +946,41 @@ impl EventDrainLoop {
         }
     }
 
+    // Chunk: docs/chunks/render_stats_hud - Render stats HUD
+    /// Passes the render stats HUD's lines to the renderer, or hides it.
+    ///
+    /// The figures are those of the frames drawn so far; the frame about to
+    /// be drawn shows up in the next one.
+    #[cfg(feature = "perf-instrumentation")]
+    fn update_perf_hud(&mut self) {
+        if !self.state.show_perf_hud {
+            self.renderer.set_perf_hud(None);
+            return;
+        }
+
+        let now = Instant::now();
+        let mut terminals = Vec::new();
+        let mut ids = Vec::new();
+        for workspace in &self.state.editor.workspaces {
+            for (id, name, bytes) in workspace.terminal_output_bytes() {
+                terminals.push((name, self.pty_throughput.update(id, bytes, now)));
+                ids.push(id);
+            }
+        }
+        self.pty_throughput.retain(&ids);
+
+        let stats = crate::perf_hud::HudStats {
+            frame_time: self.perf_stats.last_frame_latency(),
+            frame_time_p95: self.perf_stats.frame_latency_p95(),
+            styled_lines: self.perf_stats.last_styled_line_batch(),
+            glyph_quads: self.renderer.frame_quad_count(),
+            layout_skip_rate: self.renderer.layout_skip_rate(),
+            terminals,
+        };
+        self.renderer
+            .set_perf_hud(Some(crate::perf_hud::hud_lines(&stats)));
+    }
+
     /// Updates the window title if it has changed.
     // Chunk: docs/chunks/file_save - Updates NSWindow title when associated file changes
     fn update_window_title_if_needed(&mut self) {
@@ -667,7 +1028,7 @@ impl EventDrainLoop {
                     view_width_px - RAIL_WIDTH,
                     view_height_px,
                 );
-                let pane_rects = calculate_pane_rects(bounds, &workspace.pane_root);
+                let pane_rects = workspace.visible_pane_rects(bounds);
 
                 for pane_rect in &pane_rects {
                     // Each pane's tab bar is at the top of its bounds
@@ -687,6 +1048,26 @@ impl EventDrainLoop {
                         tab_bar_height_pt,
                     ));
                 }
+
+                // Chunk: docs/chunks/pane_divider_drag - Resize cursor over pane dividers
+                let dividers = if workspace.zoomed_pane_id().is_some() {
+                    Vec::new()
+                } else {
+                    calculate_divider_rects(bounds, &workspace.pane_root)
+                };
+                for divider in dividers {
+                    let (x, y, width, height) = divider.grab_rect();
+                    let rect = CursorRect::new(
+                        x as f64 / scale,
+                        px_to_pt(y, height),
+                        width as f64 / scale,
+                        height as f64 / scale,
+                    );
+                    match divider.direction {
+                        SplitDirection::Horizontal => regions.add_resize_horizontal(rect),
+                        SplitDirection::Vertical => regions.add_resize_vertical(rect),
+                    }
+                }
             }
         }
 
@@ -766,6 +1147,16 @@ impl EventDrainLoop {
                     button_width_pt,
                     button_height_pt,
                 ));
+
+                // Chunk: docs/chunks/quit_running_summary - Alternate button region
+                if let Some(alternate_x) = geometry.alternate_button_x {
+                    regions.add_pointer(CursorRect::new(
+                        alternate_x as f64 / scale,
+                        cancel_y_pt,
+                        button_width_pt,
+                        button_height_pt,
+                    ));
+                }
             }
         }
 
@@ -794,9 +1185,89 @@ impl EventDrainLoop {
         self.metal_view.set_cursor_regions(regions);
     }
 
+    // Chunk: docs/chunks/ime_dead_key_composition - Candidate window follows the cursor
+    /// Hands the focused cursor's cell to the view, converted from top-left
+    /// pixels to bottom-left points, for `firstRectForCharacterRange:`.
+    fn update_text_input_rect(&self) {
+        let Some((x, y, width, height)) = self.renderer.text_input_cursor() else {
+            return;
+        };
+
+        let frame = self.metal_view.frame();
+        let scale = self.metal_view.scale_factor();
+        self.metal_view.set_text_input_rect(CursorRect::new(
+            x as f64 / scale,
+            frame.size.height - (y + height) as f64 / scale,
+            width as f64 / scale,
+            height as f64 / scale,
+        ));
+    }
+
+    // Chunk: docs/chunks/menu_bar - Menu item states follow the editor state
+    /// Shares the menu item states that the menu bar validates against.
+    pub fn set_menu_states(&mut self, states: MenuStates) {
+        self.menu_states = Some(states);
+    }
+
+    /// Refreshes the menu item states from the editor state.
+    ///
+    /// Runs whenever the editor is dirty, so the snapshot is current by the
+    /// time the user opens a menu or types a key equivalent.
+    fn update_menu_states(&self) {
+        let Some(states) = &self.menu_states else {
+            return;
+        };
+        let mut states = states.borrow_mut();
+        for keys in menu::command_keys() {
+            states.insert(keys, self.state.menu_item_state(keys));
+        }
+    }
+
+    // Chunk: docs/chunks/macos_services - Service selection follows the editor state
+    /// Shares the selection snapshot that the app delegate offers to services.
+    pub fn set_service_selection(&mut self, selection: ServiceSelectionSnapshot) {
+        self.service_selection = Some(selection);
+    }
+
+    /// Refreshes the service selection from the editor state, alongside the
+    /// menu item states.
+    fn update_service_selection(&self) {
+        if let Some(selection) = &self.service_selection {
+            *selection.borrow_mut() = self.state.service_selection();
+        }
+    }
+
     /// Performs initial render.
     pub fn initial_render(&mut self) {
         self.state.mark_full_dirty();
         self.render_if_dirty();
     }
+
+    // Chunk: docs/chunks/lazy_subsystem_init - Hand launch timings to the perf stats
+    /// Stores the startup phases recorded during launch.
+    #[cfg(feature = "perf-instrumentation")]
+    pub fn set_startup_profile(&mut self, profile: crate::perf_stats::StartupProfile) {
+        self.perf_stats.set_startup_profile(profile);
+    }
+}
+
+// Chunk: docs/chunks/smooth_scroll_animation - Scroll frame timer
+/// Schedules a repeating timer that sends a `ScrollFrame` event every display
+/// frame. The caller invalidates it when the animation ends.
+fn start_scroll_frame_timer(sender: EventSender) -> Retained<NSTimer> {
+    let block = RcBlock::new(move |_timer: NonNull<NSTimer>| {
+        let _ = sender.send_scroll_frame();
+    });
+
+    let timer = unsafe {
+        NSTimer::scheduledTimerWithTimeInterval_repeats_block(SCROLL_FRAME_INTERVAL, true, &block)
+    };
+
+    // Add to common run loop modes so frames keep coming during tracking (resize/drag)
+    let run_loop = NSRunLoop::currentRunLoop();
+    unsafe {
+        run_loop.addTimer_forMode(&timer, objc2_foundation::NSRunLoopCommonModes);
+    }
+
+    timer
 }
@@ -0,0 +1,519 @@
+// Chunk: docs/chunks/agent_edit_review - Review of agent-proposed edits
+//!
+//! The Review tab: edits an agent proposes, accepted or rejected hunk by hunk.
+//!
+//! A structured agent can propose an edit instead of making it: it writes
+//! the new contents to a file of its own and reports both paths in a
+//! `file_edit` event (see `lite_edit_terminal::AgentEvent::FileEdit`). Each
+//! proposal becomes a [`ProposedEdit`]. An [`EditReviewBuffer`] lists a
+//! workspace's proposals as diffs against the files as they were, with one
+//! hunk selected at a time, and presents them through `BufferView`.
+//!
+//! Every hunk is accepted or rejected. Once all of a file's hunks are
+//! decided, [`EditReviewBuffer::take_decided`] hands the editor the file's
+//! contents with only the accepted hunks applied; the buffer never writes
+//! files itself. The hunks were diffed against the file as it was when the
+//! edit was proposed, so [`ReviewedFile::is_current`] tells the editor
+//! whether the file has changed since. A newer proposal for a file replaces
+//! one still under review.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use lite_edit_buffer::{BufferView, CursorInfo, DirtyLines, Span, Style, StyledLine};
+
+use crate::diff_view::{text_hunks, unified_row, TextHunk};
+use crate::view_colors::{BLUE, GREEN, MAUVE, RED, SELECTED_BG};
+
+/// Rows above the first file: the heading, the keys and a blank line.
+const HEADER_ROWS: usize = 3;
+
+/// The keys' summary shown below the heading.
+const HINT: &str = "A accepts · R rejects · W writes decided files · Up/Down select a hunk";
+
+/// What the reviewer made of a hunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Undecided,
+    Accepted,
+    Rejected,
+}
+
+/// An edit an agent proposed for one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProposedEdit {
+    /// The file the edit is for.
+    pub path: PathBuf,
+    /// What the edit does, as the agent put it.
+    pub summary: Option<String>,
+    /// The file's contents when the edit was proposed, empty for a new file.
+    pub original: String,
+    /// The contents the agent proposes.
+    pub proposed: String,
+}
+
+impl ProposedEdit {
+    /// Reads the contents the agent wrote to `proposed` for the file at
+    /// `path`. A file that doesn't exist yet counts as empty.
+    pub fn read(path: PathBuf, proposed: &Path, summary: Option<String>) -> io::Result<Self> {
+        Ok(Self {
+            original: read_or_empty(&path)?,
+            path,
+            summary,
+            proposed: std::fs::read_to_string(proposed)?,
+        })
+    }
+}
+
+/// A file whose hunks are all decided, ready to be written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewedFile {
+    /// The file to write.
+    pub path: PathBuf,
+    /// The contents the proposal was diffed against.
+    pub original: String,
+    /// The original contents with the accepted hunks applied.
+    pub contents: String,
+}
+
+impl ReviewedFile {
+    /// Returns true if the file on disk still has the contents the proposal
+    /// was diffed against, so writing it loses nothing saved since.
+    pub fn is_current(&self) -> io::Result<bool> {
+        Ok(read_or_empty(&self.path)? == self.original)
+    }
+}
+
+/// Reads a file's contents, counting a file that doesn't exist as empty.
+fn read_or_empty(path: &Path) -> io::Result<String> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => Ok(text),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// One file's proposal with a decision per hunk.
+struct FileReview {
+    edit: ProposedEdit,
+    hunks: Vec<TextHunk>,
+    decisions: Vec<Decision>,
+}
+
+impl FileReview {
+    fn new(edit: ProposedEdit) -> Self {
+        let hunks = text_hunks(&edit.original, &edit.proposed);
+        let decisions = vec![Decision::Undecided; hunks.len()];
+        Self {
+            edit,
+            hunks,
+            decisions,
+        }
+    }
+
+    fn is_decided(&self) -> bool {
+        !self.decisions.contains(&Decision::Undecided)
+    }
+
+    /// Returns the original contents with the accepted hunks applied.
+    fn merged(&self) -> String {
+        let old: Vec<&str> = self.edit.original.split_inclusive('\n').collect();
+        let new: Vec<&str> = self.edit.proposed.split_inclusive('\n').collect();
+        let mut merged = String::new();
+        let mut next = 0;
+        for (hunk, decision) in self.hunks.iter().zip(&self.decisions) {
+            merged.push_str(&old[next..hunk.old_range.start].concat());
+            match decision {
+                Decision::Accepted => merged.push_str(&new[hunk.new_range.clone()].concat()),
+                _ => merged.push_str(&old[hunk.old_range.clone()].concat()),
+            }
+            next = hunk.old_range.end;
+        }
+        merged.push_str(&old[next..].concat());
+        merged
+    }
+}
+
+/// The Review tab's proposals, with one hunk selected.
+pub struct EditReviewBuffer {
+    /// The workspace root; paths are shown relative to it
+    root: PathBuf,
+    files: Vec<FileReview>,
+    /// Index of the selected hunk among all files' hunks
+    selected: usize,
+    /// The laid-out rows, rebuilt when a proposal or decision changes
+    rows: Vec<StyledLine>,
+    /// The row of each hunk's header, in order
+    hunk_rows: Vec<usize>,
+    dirty: DirtyLines,
+}
+
+impl EditReviewBuffer {
+    /// Creates an empty review for the workspace at `root`.
+    pub fn new(root: PathBuf) -> Self {
+        let mut buffer = Self {
+            root,
+            files: Vec::new(),
+            selected: 0,
+            rows: Vec::new(),
+            hunk_rows: Vec::new(),
+            dirty: DirtyLines::None,
+        };
+        buffer.relayout();
+        buffer
+    }
+
+    /// Adds a proposal, replacing any still under review for the same file.
+    pub fn add(&mut self, edit: ProposedEdit) {
+        let review = FileReview::new(edit);
+        let path = &review.edit.path;
+        match self.files.iter().position(|f| &f.edit.path == path) {
+            Some(index) => self.files[index] = review,
+            None => self.files.push(review),
+        }
+        self.relayout();
+    }
+
+    /// Returns the files with proposals under review.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|file| file.edit.path.as_path())
+    }
+
+    /// Returns true if nothing is under review.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Returns the selected hunk's decision, if there are any hunks.
+    pub fn selected_decision(&self) -> Option<Decision> {
+        let (file, hunk) = self.hunk_at(self.selected)?;
+        Some(self.files[file].decisions[hunk])
+    }
+
+    /// Returns the selected hunk's header row.
+    pub fn selected_row(&self) -> usize {
+        let row = self.hunk_rows.get(self.selected);
+        row.copied().unwrap_or(HEADER_ROWS)
+    }
+
+    /// Moves the selection `delta` hunks down (or up, if negative), stopping
+    /// at the first and last hunk.
+    pub fn move_selection(&mut self, delta: isize) {
+        let last = self.hunk_rows.len().saturating_sub(1) as isize;
+        let target = (self.selected as isize + delta).clamp(0, last) as usize;
+        if target != self.selected {
+            self.selected = target;
+            self.relayout();
+        }
+    }
+
+    /// Decides the selected hunk and selects the next one.
+    pub fn decide(&mut self, decision: Decision) {
+        let Some((file, hunk)) = self.hunk_at(self.selected) else {
+            return;
+        };
+        self.files[file].decisions[hunk] = decision;
+        self.selected = (self.selected + 1).min(self.hunk_rows.len() - 1);
+        self.relayout();
+    }
+
+    /// Takes the files whose hunks are all decided out of the review,
+    /// returning the new contents of those with an accepted hunk. Files
+    /// with every hunk rejected are dropped without a write.
+    pub fn take_decided(&mut self) -> Vec<ReviewedFile> {
+        let count = self.files.len();
+        let mut writes = Vec::new();
+        let mut kept = Vec::new();
+        for file in std::mem::take(&mut self.files) {
+            if !file.is_decided() {
+                kept.push(file);
+            } else if file.decisions.contains(&Decision::Accepted) {
+                let contents = file.merged();
+                writes.push(ReviewedFile {
+                    path: file.edit.path,
+                    original: file.edit.original,
+                    contents,
+                });
+            }
+        }
+        if kept.len() < count {
+            self.selected = 0;
+        }
+        self.files = kept;
+        self.relayout();
+        writes
+    }
+
+    /// Returns the file and hunk indices of the `index`th hunk.
+    fn hunk_at(&self, mut index: usize) -> Option<(usize, usize)> {
+        for (file_index, file) in self.files.iter().enumerate() {
+            if index < file.hunks.len() {
+                return Some((file_index, index));
+            }
+            index -= file.hunks.len();
+        }
+        None
+    }
+
+    /// Rebuilds the rows and marks everything dirty.
+    fn relayout(&mut self) {
+        let dim = Style {
+            dim: true,
+            ..Style::default()
+        };
+        let count = match self.files.len() {
+            1 => "1 file".to_string(),
+            count => format!("{} files", count),
+        };
+        let mut rows = vec![
+            StyledLine::new(vec![
+                Span::new(
+                    "Review",
+                    Style {
+                        fg: MAUVE,
+                        bold: true,
+                        ..Style::default()
+                    },
+                ),
+                Span::new(format!("  edits the agent proposed to {}", count), dim),
+            ]),
+            StyledLine::new(vec![Span::new(HINT, dim)]),
+            StyledLine::empty(),
+        ];
+        let mut hunk_rows = Vec::new();
+
+        if self.files.is_empty() {
+            rows.push(StyledLine::new(vec![Span::new("No edits to review", dim)]));
+        }
+        for file in &self.files {
+            let path = file.edit.path.strip_prefix(&self.root);
+            let mut spans = vec![Span::new(
+                path.unwrap_or(&file.edit.path).display().to_string(),
+                Style {
+                    bold: true,
+                    ..Style::default()
+                },
+            )];
+            if let Some(summary) = &file.edit.summary {
+                spans.push(Span::plain(format!(" — {}", summary)));
+            }
+            if file.hunks.is_empty() {
+                spans.push(Span::new("  (no changes)", dim));
+            }
+            rows.push(StyledLine::new(spans));
+
+            let width = number_width(&file.hunks);
+            for (text_hunk, decision) in file.hunks.iter().zip(&file.decisions) {
+                let selected = hunk_rows.len() == self.selected;
+                hunk_rows.push(rows.len());
+                rows.push(hunk_header(&text_hunk.hunk.header, *decision, selected));
+                for line in &text_hunk.hunk.lines {
+                    rows.push(unified_row(line, width));
+                }
+            }
+            rows.push(StyledLine::empty());
+        }
+
+        self.rows = rows;
+        self.hunk_rows = hunk_rows;
+        self.selected = self.selected.min(self.hunk_rows.len().saturating_sub(1));
+        self.dirty = DirtyLines::FromLineToEnd(0);
+    }
+}
+
+/// Width of a file's line-number gutter: enough digits for its largest number.
+fn number_width(hunks: &[TextHunk]) -> usize {
+    let max = hunks
+        .iter()
+        .flat_map(|h| &h.hunk.lines)
+        .flat_map(|l| l.old_line.into_iter().chain(l.new_line))
+        .max()
+        .unwrap_or(1);
+    max.to_string().len()
+}
+
+/// Formats a hunk's header with a checkbox showing its decision.
+fn hunk_header(header: &str, decision: Decision, selected: bool) -> StyledLine {
+    let (mark, fg) = match decision {
+        Decision::Undecided => ("[ ] ", Style::default().fg),
+        Decision::Accepted => ("[✓] ", GREEN),
+        Decision::Rejected => ("[✗] ", RED),
+    };
+    let mut spans = vec![
+        Span::new(
+            mark,
+            Style {
+                fg,
+                ..Style::default()
+            },
+        ),
+        Span::new(
+            header,
+            Style {
+                fg: BLUE,
+                ..Style::default()
+            },
+        ),
+    ];
+    if selected {
+        for span in &mut spans {
+            span.style.bg = SELECTED_BG;
+        }
+    }
+    StyledLine::new(spans)
+}
+
+impl BufferView for EditReviewBuffer {
+    fn line_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        self.rows.get(line).cloned()
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.rows.get(line).map(|row| row.char_count()).unwrap_or(0)
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        None
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &StyledLine) -> String {
+        line.spans.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    /// Twenty numbered lines, so edits at both ends land in separate hunks.
+    fn numbered() -> String {
+        (1..=20).map(|n| format!("line {}\n", n)).collect()
+    }
+
+    fn edit(path: &str, proposed: String) -> ProposedEdit {
+        ProposedEdit {
+            path: PathBuf::from("/repo").join(path),
+            summary: Some("Tidy".to_string()),
+            original: numbered(),
+            proposed,
+        }
+    }
+
+    #[test]
+    fn test_only_accepted_hunks_are_written() {
+        let proposed = numbered()
+            .replace("line 2\n", "line two\n")
+            .replace("line 19\n", "line 19\nline 19.5\n");
+        let mut review = EditReviewBuffer::new(PathBuf::from("/repo"));
+        review.add(edit("src/lib.rs", proposed));
+
+        let header = text(&review.styled_line(review.selected_row()).unwrap());
+        assert_eq!(header, "[ ] @@ -1,5 +1,5 @@");
+        review.decide(Decision::Rejected);
+        assert_eq!(review.selected_decision(), Some(Decision::Undecided));
+
+        // Nothing is written while a hunk is undecided
+        assert!(review.take_decided().is_empty());
+        assert!(!review.is_empty());
+
+        review.decide(Decision::Accepted);
+        let header = text(&review.styled_line(review.selected_row()).unwrap());
+        assert!(header.starts_with("[✓] @@"), "{}", header);
+        let writes = review.take_decided();
+        assert_eq!(writes.len(), 1);
+        assert_eq!(writes[0].path, PathBuf::from("/repo/src/lib.rs"));
+        assert_eq!(writes[0].original, numbered());
+        let expected = numbered().replace("line 19\n", "line 19\nline 19.5\n");
+        assert_eq!(writes[0].contents, expected);
+        assert!(review.is_empty());
+    }
+
+    #[test]
+    fn test_rows_and_replaced_proposals() {
+        let mut review = EditReviewBuffer::new(PathBuf::from("/repo"));
+        let empty = text(&review.styled_line(HEADER_ROWS).unwrap());
+        assert_eq!(empty, "No edits to review");
+
+        review.add(edit("a.rs", numbered().replace("line 1\n", "")));
+        review.add(edit("b.rs", numbered().replace("line 20\n", "")));
+        let title = text(&review.styled_line(0).unwrap());
+        assert_eq!(title, "Review  edits the agent proposed to 2 files");
+        let file = text(&review.styled_line(HEADER_ROWS).unwrap());
+        assert_eq!(file, "a.rs — Tidy");
+        let removed = text(&review.styled_line(HEADER_ROWS + 2).unwrap());
+        assert_eq!(removed, "1   - line 1");
+
+        // The second file's hunk follows the first file's
+        review.move_selection(5);
+        let header = review.styled_line(review.selected_row()).unwrap();
+        assert_eq!(header.spans[1].style.bg, SELECTED_BG);
+        assert!(review.selected_row() > HEADER_ROWS + 5);
+
+        // A newer proposal for a file replaces the one under review
+        review.add(edit("a.rs", numbered()));
+        let paths: Vec<_> = review.paths().collect();
+        assert_eq!(paths, [Path::new("/repo/a.rs"), Path::new("/repo/b.rs")]);
+        let file = text(&review.styled_line(HEADER_ROWS).unwrap());
+        assert_eq!(file, "a.rs — Tidy  (no changes)");
+
+        // A file with nothing accepted is dropped without a write
+        review.move_selection(-5);
+        review.decide(Decision::Rejected);
+        assert!(review.take_decided().is_empty());
+        assert!(review.is_empty());
+    }
+
+    #[test]
+    fn test_read_proposal_for_new_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let proposed = dir.path().join("proposed");
+        std::fs::write(&proposed, "fn main() {}\n").unwrap();
+        let path = dir.path().join("main.rs");
+
+        let edit = ProposedEdit::read(path.clone(), &proposed, None).unwrap();
+        assert_eq!(edit.original, "");
+        assert_eq!(edit.proposed, "fn main() {}\n");
+        let missing = dir.path().join("missing");
+        assert!(ProposedEdit::read(path, &missing, None).is_err());
+    }
+
+    #[test]
+    fn test_reviewed_file_is_current_until_saved_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lib.rs");
+        std::fs::write(&path, numbered()).unwrap();
+        let file = ReviewedFile {
+            path: path.clone(),
+            original: numbered(),
+            contents: String::new(),
+        };
+        assert!(file.is_current().unwrap());
+
+        std::fs::write(&path, "saved since\n").unwrap();
+        assert!(!file.is_current().unwrap());
+
+        // A new file is current while it still doesn't exist
+        let new = ReviewedFile {
+            path: dir.path().join("new.rs"),
+            original: String::new(),
+            contents: String::new(),
+        };
+        assert!(new.is_current().unwrap());
+    }
+}
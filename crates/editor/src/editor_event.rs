@@ -9,8 +9,13 @@
 //! The event queue is drained by a single callback that owns the controller directly
 //! (no `Rc`, no `RefCell`), ensuring exclusive access during event processing.
 
+use std::os::unix::net::UnixStream;
 use std::path::PathBuf;
 
+use lite_edit_cli::Request;
+
+use crate::config::EditorConfig;
+use crate::file_io::FileIoEvent;
 use crate::input::{KeyEvent, MarkedTextEvent, MouseEvent, ScrollDelta, TextInputEvent};
 
 /// Unified event type for all editor events.
@@ -57,6 +62,26 @@ pub enum EditorEvent {
         position: (f64, f64),
     },
 
+    // Chunk: docs/chunks/finder_open - Files opened from Finder or `open`
+    /// Files or directories were opened from outside the app
+    ///
+    /// Sent by `application:openURLs:` when the user opens documents from
+    /// Finder, the Dock, or the `open` command. The paths are absolute.
+    OpenFiles(Vec<PathBuf>),
+
+    // Chunk: docs/chunks/cli_launcher - Requests from the `lite` command
+    /// The `lite` command asked to open files or directories
+    ///
+    /// Sent by the remote server thread. The drain loop replies on `reply`
+    /// once the files are open and, for a waiting request, again once their
+    /// tabs have closed.
+    RemoteRequest {
+        /// What to open
+        request: Request,
+        /// The client's connection
+        reply: UnixStream,
+    },
+
     // Chunk: docs/chunks/file_change_events - External file modification detection
     /// A file was modified externally (on disk)
     ///
@@ -132,6 +157,36 @@ pub enum EditorEvent {
     /// indicating the app is returning to the foreground. File watchers should
     /// be resumed and any files modified while paused should be detected.
     ResumeFileWatchers,
+
+    // Chunk: docs/chunks/lazy_subsystem_init - Incremental post-launch warm-up
+    /// Perform the next step of the post-launch warm-up.
+    ///
+    /// Sent once after the first frame is presented, and re-sent by the drain
+    /// loop while warm-up work remains. Each step is small, so user input queued
+    /// behind it is never delayed by more than one step.
+    Warmup,
+
+    // Chunk: docs/chunks/smooth_scroll_animation - Smooth scroll frame tick
+    /// The scroll frame timer fired - advance running smooth scrolls.
+    ///
+    /// The timer only runs while a smooth scroll is animating.
+    ScrollFrame,
+
+    // Chunk: docs/chunks/settings_tab - Settings changed in the Settings tab
+    /// The user config was changed in the Settings tab
+    ///
+    /// Carries the whole new config. The drain loop applies it to the editor
+    /// state and the renderer, as if the app had started with it. Boxed, as
+    /// the config is much larger than any other event.
+    SettingsChanged(Box<EditorConfig>),
+
+    // Chunk: docs/chunks/background_file_io - Background read and write results
+    /// A background read delivered part of a file, or a background read or
+    /// write finished.
+    ///
+    /// Sent from a `FileIoPool` worker thread. Not a priority event: a large
+    /// file arrives in many chunks, which shouldn't hold up input.
+    FileIo(FileIoEvent),
 }
 
 impl EditorEvent {
@@ -159,9 +214,11 @@ impl EditorEvent {
     /// Returns true if this event should be processed before PTY wakeup events.
     ///
     /// Priority events include all user input events plus Resize (window resize
-    /// should be responsive) and file change events (external edits, deletions,
-    /// and renames should be processed promptly). CursorBlink is NOT included
-    /// since it's cosmetic.
+    /// should be responsive), files opened from Finder or `lite`, and file
+    /// change events (external edits, deletions, and renames should be
+    /// processed promptly). A settings change follows a keystroke in the
+    /// Settings tab, so it is a priority event too. CursorBlink is NOT
+    /// included since it's cosmetic.
     /// This ensures input latency is bounded by the cost of processing priority
     /// events, not by accumulated terminal output.
     pub fn is_priority_event(&self) -> bool {
@@ -171,6 +228,8 @@ impl EditorEvent {
                 | EditorEvent::Mouse(_)
                 | EditorEvent::Scroll(_)
                 | EditorEvent::FileDrop { .. }
+                | EditorEvent::OpenFiles(_)
+                | EditorEvent::RemoteRequest { .. }
                 | EditorEvent::Resize
                 | EditorEvent::FileChanged(_)
                 | EditorEvent::FileDeleted(_)
@@ -178,6 +237,7 @@ impl EditorEvent {
                 | EditorEvent::InsertText(_)
                 | EditorEvent::SetMarkedText(_)
                 | EditorEvent::UnmarkText
+                | EditorEvent::SettingsChanged(_)
         )
     }
 
@@ -191,7 +251,7 @@ impl EditorEvent {
 mod tests {
     use super::*;
     use std::path::PathBuf;
-    use lite_edit_input::{KeyEvent, MouseEvent, MouseEventKind, Modifiers, ScrollDelta};
+    use lite_edit_input::{KeyEvent, MouseButton, MouseEvent, MouseEventKind, Modifiers, ScrollDelta};
 
     // Chunk: docs/chunks/terminal_flood_starvation - Tests for is_priority_event
 
@@ -208,6 +268,7 @@ mod tests {
             position: (0.0, 0.0),
             modifiers: Modifiers::default(),
             click_count: 1,
+            button: MouseButton::Left,
         });
         assert!(event.is_priority_event());
     }
@@ -218,6 +279,7 @@ mod tests {
             dx: 0.0,
             dy: 10.0,
             mouse_position: None,
+            animate: false,
         });
         assert!(event.is_priority_event());
     }
@@ -231,6 +293,13 @@ mod tests {
         assert!(event.is_priority_event());
     }
 
+    // Chunk: docs/chunks/finder_open - Opening files is a priority event
+    #[test]
+    fn test_open_files_is_priority() {
+        let event = EditorEvent::OpenFiles(vec![PathBuf::from("/path/to/file.rs")]);
+        assert!(event.is_priority_event());
+    }
+
     #[test]
     fn test_resize_is_priority() {
         let event = EditorEvent::Resize;
@@ -249,6 +318,43 @@ mod tests {
         assert!(!event.is_priority_event());
     }
 
+    // Chunk: docs/chunks/lazy_subsystem_init - Warm-up yields to input
+    #[test]
+    fn test_warmup_is_not_priority() {
+        let event = EditorEvent::Warmup;
+        assert!(!event.is_priority_event());
+        assert!(!event.is_user_input());
+    }
+
+    // Chunk: docs/chunks/smooth_scroll_animation - Scroll frames run after input
+    #[test]
+    fn test_scroll_frame_is_not_priority() {
+        let event = EditorEvent::ScrollFrame;
+        assert!(!event.is_priority_event());
+        assert!(!event.is_user_input());
+    }
+
+    // Chunk: docs/chunks/settings_tab - Settings changes apply before terminal output
+    #[test]
+    fn test_settings_changed_is_priority() {
+        let event = EditorEvent::SettingsChanged(Box::default());
+        assert!(event.is_priority_event());
+        assert!(!event.is_user_input());
+    }
+
+    // Chunk: docs/chunks/background_file_io - File reads yield to input
+    #[test]
+    fn test_file_io_is_not_priority() {
+        let event = EditorEvent::FileIo(FileIoEvent::ReadChunk {
+            tab_id: 1,
+            text: "hello".to_string(),
+            bytes_read: 5,
+            total: 10,
+        });
+        assert!(!event.is_priority_event());
+        assert!(!event.is_user_input());
+    }
+
     // Chunk: docs/chunks/file_change_events - Tests for FileChanged event
     #[test]
     fn test_file_changed_is_priority() {
@@ -304,11 +410,13 @@ mod tests {
                 position: (0.0, 0.0),
                 modifiers: Modifiers::default(),
                 click_count: 0,
+                button: MouseButton::Left,
             }),
             EditorEvent::Scroll(ScrollDelta {
                 dx: 0.0,
                 dy: 0.0,
                 mouse_position: None,
+                animate: false,
             }),
             EditorEvent::FileDrop {
                 paths: vec![],
@@ -39,14 +39,18 @@
 //! The follow-up wakeup path (`send_pty_wakeup_followup`) bypasses debouncing
 //! to ensure byte-budget continuations are always delivered.
 
+use std::os::unix::net::UnixStream;
 use std::path::PathBuf;
 use std::sync::mpsc::{self, Receiver, SendError, Sender};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
+use lite_edit_cli::Request;
 use lite_edit_input::{KeyEvent, MarkedTextEvent, MouseEvent, ScrollDelta, TextInputEvent, WakeupSignal};
 
+use crate::config::EditorConfig;
 use crate::editor_event::EditorEvent;
+use crate::file_io::FileIoEvent;
 
 /// Sender half of the event channel.
 ///
@@ -201,6 +205,22 @@ impl EventSender {
         result
     }
 
+    // Chunk: docs/chunks/smooth_scroll_animation - Scroll frame event sender
+    /// Sends a scroll frame event to the channel.
+    pub fn send_scroll_frame(&self) -> Result<(), SendError<EditorEvent>> {
+        let result = self.inner.sender.send(EditorEvent::ScrollFrame);
+        (self.inner.run_loop_waker)();
+        result
+    }
+
+    // Chunk: docs/chunks/lazy_subsystem_init - Warm-up step event sender
+    /// Sends a warm-up step event to the channel.
+    pub fn send_warmup(&self) -> Result<(), SendError<EditorEvent>> {
+        let result = self.inner.sender.send(EditorEvent::Warmup);
+        (self.inner.run_loop_waker)();
+        result
+    }
+
     /// Sends a resize event to the channel.
     pub fn send_resize(&self) -> Result<(), SendError<EditorEvent>> {
         let result = self.inner.sender.send(EditorEvent::Resize);
@@ -220,6 +240,29 @@ impl EventSender {
         result
     }
 
+    // Chunk: docs/chunks/finder_open - Open files event sender
+    /// Sends files or directories opened from Finder or `open` to the channel.
+    pub fn send_open_files(&self, paths: Vec<PathBuf>) -> Result<(), SendError<EditorEvent>> {
+        let result = self.inner.sender.send(EditorEvent::OpenFiles(paths));
+        (self.inner.run_loop_waker)();
+        result
+    }
+
+    // Chunk: docs/chunks/cli_launcher - Remote request event sender
+    /// Sends a request from the `lite` command, with the connection to reply on.
+    pub fn send_remote_request(
+        &self,
+        request: Request,
+        reply: UnixStream,
+    ) -> Result<(), SendError<EditorEvent>> {
+        let result = self
+            .inner
+            .sender
+            .send(EditorEvent::RemoteRequest { request, reply });
+        (self.inner.run_loop_waker)();
+        result
+    }
+
     // Chunk: docs/chunks/file_change_events - File change event sender
     /// Sends a file-changed event to the channel.
     ///
@@ -253,6 +296,16 @@ impl EventSender {
         result
     }
 
+    // Chunk: docs/chunks/background_file_io - Background file IO event sender
+    /// Sends the result of a background file read or write to the channel.
+    ///
+    /// This is called from `FileIoPool` worker threads.
+    pub fn send_file_io(&self, event: FileIoEvent) -> Result<(), SendError<EditorEvent>> {
+        let result = self.inner.sender.send(EditorEvent::FileIo(event));
+        (self.inner.run_loop_waker)();
+        result
+    }
+
     // Chunk: docs/chunks/unicode_ime_input - Text input event senders
 
     /// Sends a text insertion event to the channel.
@@ -318,6 +371,17 @@ impl EventSender {
         (self.inner.run_loop_waker)();
         result
     }
+
+    // Chunk: docs/chunks/settings_tab - Settings change event sender
+    /// Sends a config changed in the Settings tab to the channel.
+    pub fn send_settings_changed(
+        &self,
+        config: EditorConfig,
+    ) -> Result<(), SendError<EditorEvent>> {
+        let result = self.inner.sender.send(EditorEvent::SettingsChanged(Box::new(config)));
+        (self.inner.run_loop_waker)();
+        result
+    }
 }
 
 // Implement WakeupSignal so EventSender can be used by the terminal crate
@@ -452,7 +516,7 @@ mod tests {
 
     #[test]
     fn test_send_mouse_calls_waker() {
-        use lite_edit_input::{MouseEventKind, Modifiers};
+        use lite_edit_input::{MouseButton, MouseEventKind, Modifiers};
 
         let waker_called = Arc::new(AtomicUsize::new(0));
         let waker_called_clone = waker_called.clone();
@@ -466,6 +530,7 @@ mod tests {
             position: (10.0, 20.0),
             modifiers: Modifiers { shift: false, command: false, option: false, control: false },
             click_count: 0,
+            button: MouseButton::Left,
         };
         sender.send_mouse(event).unwrap();
 
@@ -481,7 +546,7 @@ mod tests {
             waker_called_clone.fetch_add(1, Ordering::SeqCst);
         });
 
-        sender.send_scroll(ScrollDelta { dx: 0.0, dy: 10.0, mouse_position: None }).unwrap();
+        sender.send_scroll(ScrollDelta { dx: 0.0, dy: 10.0, mouse_position: None, animate: false }).unwrap();
 
         assert_eq!(waker_called.load(Ordering::SeqCst), 1, "Waker should be called after send_scroll");
     }
@@ -680,4 +745,29 @@ mod tests {
             _ => panic!("Expected FileRenamed event"),
         }
     }
+
+    // Chunk: docs/chunks/background_file_io - Tests for send_file_io
+    #[test]
+    fn test_send_file_io() {
+        let waker_called = Arc::new(AtomicUsize::new(0));
+        let waker_called_clone = waker_called.clone();
+
+        let (sender, receiver) = create_event_channel(move || {
+            waker_called_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let done = FileIoEvent::WriteDone {
+            tab_id: 3,
+            path: PathBuf::from("/path/to/big.log"),
+            result: Ok(None),
+        };
+        sender.send_file_io(done.clone()).unwrap();
+
+        assert_eq!(waker_called.load(Ordering::SeqCst), 1, "Waker should be called after send_file_io");
+
+        match receiver.try_recv().unwrap() {
+            EditorEvent::FileIo(event) => assert_eq!(event, done),
+            _ => panic!("Expected FileIo event"),
+        }
+    }
 }
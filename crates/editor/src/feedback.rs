@@ -0,0 +1,174 @@
+// Chunk: docs/chunks/feedback_hooks - Sound and haptic feedback
+//!
+//! Sound and haptic feedback for editor events.
+//!
+//! Callers report what happened as a [`Feedback`] event; the user's
+//! [`FeedbackSettings`] decide whether it is felt or heard and how. Like
+//! `notifications`, the AppKit side is intentionally minimal ("humble object"
+//! pattern): [`feedback_output`] makes the decision and [`play`] only carries
+//! it out.
+//!
+//! ## Test isolation
+//!
+//! Under `cfg(test)` nothing is played. Instead, outputs are recorded in a
+//! `thread_local!` list that tests can inspect with `take_played()`.
+
+use crate::config::EditorConfig;
+
+/// Something that happened that the user may want to feel or hear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    /// A command had nothing to act on, such as Enter in the find strip with
+    /// no match
+    Alert,
+    /// Find wrapped around to the start of the buffer
+    FindWrapped,
+    /// An agent finished its task
+    AgentFinished,
+}
+
+/// How feedback is given, from the user config.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeedbackSettings {
+    /// Sound the system alert for `Feedback::Alert`
+    pub alert_sound: bool,
+    /// Tap the trackpad for `Feedback::FindWrapped`
+    pub haptics: bool,
+    /// System sound played for `Feedback::AgentFinished`, if any
+    pub agent_finished_sound: Option<String>,
+}
+
+impl FeedbackSettings {
+    /// Returns the feedback settings from the user config.
+    pub fn from_config(config: &EditorConfig) -> Self {
+        Self {
+            alert_sound: config.alert_sound,
+            haptics: config.haptic_feedback,
+            agent_finished_sound: config.agent_finished_sound.clone(),
+        }
+    }
+}
+
+/// What the user feels or hears.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedbackOutput {
+    /// The system alert sound (`NSBeep`)
+    Beep,
+    /// A light tap on a Force Touch trackpad
+    HapticTick,
+    /// A named system sound, e.g. `"Glass"`
+    Sound(String),
+}
+
+/// Returns what to play for `feedback` under `settings`, or `None` when it
+/// is turned off.
+pub fn feedback_output(feedback: Feedback, settings: &FeedbackSettings) -> Option<FeedbackOutput> {
+    match feedback {
+        Feedback::Alert => settings.alert_sound.then_some(FeedbackOutput::Beep),
+        Feedback::FindWrapped => settings.haptics.then_some(FeedbackOutput::HapticTick),
+        Feedback::AgentFinished => settings
+            .agent_finished_sound
+            .clone()
+            .map(FeedbackOutput::Sound),
+    }
+}
+
+/// Gives `feedback` as `settings` say.
+pub fn give(feedback: Feedback, settings: &FeedbackSettings) {
+    if let Some(output) = feedback_output(feedback, settings) {
+        play(output);
+    }
+}
+
+// ── production feedback (AppKit) ─────────────────────────────────────────────
+
+#[cfg(not(test))]
+use objc2_app_kit::{
+    NSBeep, NSHapticFeedbackManager, NSHapticFeedbackPattern, NSHapticFeedbackPerformanceTime,
+    NSHapticFeedbackPerformer, NSSound,
+};
+#[cfg(not(test))]
+use objc2_foundation::NSString;
+
+/// Plays `output`.
+///
+/// Haptics only reach a Force Touch trackpad the user is touching, and an
+/// unknown sound name plays nothing.
+#[cfg(not(test))]
+pub fn play(output: FeedbackOutput) {
+    match output {
+        FeedbackOutput::Beep => NSBeep(),
+        FeedbackOutput::HapticTick => {
+            NSHapticFeedbackManager::defaultPerformer().performFeedbackPattern_performanceTime(
+                NSHapticFeedbackPattern::Generic,
+                NSHapticFeedbackPerformanceTime::Now,
+            );
+        }
+        FeedbackOutput::Sound(name) => {
+            if let Some(sound) = NSSound::soundNamed(&NSString::from_str(&name)) {
+                sound.play();
+            }
+        }
+    }
+}
+
+// ── test feedback (thread-local record) ──────────────────────────────────────
+
+#[cfg(test)]
+use std::cell::RefCell;
+
+#[cfg(test)]
+thread_local! {
+    /// Outputs played on the current thread.
+    static PLAYED: RefCell<Vec<FeedbackOutput>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records `output` instead of playing it.
+#[cfg(test)]
+pub fn play(output: FeedbackOutput) {
+    PLAYED.with(|p| p.borrow_mut().push(output));
+}
+
+/// Returns and clears the outputs played on the current thread.
+///
+/// This function is only available in test builds.
+#[cfg(test)]
+pub fn take_played() -> Vec<FeedbackOutput> {
+    PLAYED.with(|p| std::mem::take(&mut *p.borrow_mut()))
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_feedback() {
+        let settings = FeedbackSettings::from_config(&EditorConfig::default());
+        assert_eq!(
+            feedback_output(Feedback::Alert, &settings),
+            Some(FeedbackOutput::Beep)
+        );
+        assert_eq!(
+            feedback_output(Feedback::FindWrapped, &settings),
+            Some(FeedbackOutput::HapticTick)
+        );
+        assert_eq!(feedback_output(Feedback::AgentFinished, &settings), None);
+    }
+
+    #[test]
+    fn test_disabled_feedback_plays_nothing() {
+        let settings = FeedbackSettings::default();
+        give(Feedback::Alert, &settings);
+        give(Feedback::FindWrapped, &settings);
+        assert!(take_played().is_empty());
+
+        let settings = FeedbackSettings {
+            agent_finished_sound: Some("Glass".to_string()),
+            ..settings
+        };
+        give(Feedback::AgentFinished, &settings);
+        assert_eq!(take_played(), vec![FeedbackOutput::Sound("Glass".to_string())]);
+    }
+}
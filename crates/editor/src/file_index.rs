@@ -22,6 +22,7 @@
 //! files in the workspace. Events are debounced (100ms by default) to coalesce
 //! rapid successive writes.
 
+use crate::fuzzy::fuzzy_match_path;
 use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use notify::event::{DataChange, ModifyKind};
 use std::collections::{HashMap, VecDeque};
@@ -80,6 +81,11 @@ pub struct MatchResult {
     pub path: PathBuf,
     /// Match score (higher is better).
     pub score: u32,
+    // Chunk: docs/chunks/fuzzy_ranked_scoring - Match positions for highlighting
+    /// Char indices into `path.to_string_lossy()` of the matched query characters.
+    ///
+    /// Empty for empty-query results.
+    pub match_positions: Vec<usize>,
 }
 
 /// Internal shared state protected by Arc<Mutex<_>>.
@@ -101,8 +107,11 @@ pub struct FileIndex {
     version: Arc<AtomicU64>,
     /// True while the initial recursive walk is still running.
     indexing: Arc<AtomicBool>,
-    /// Handle to the walker thread (joined on drop).
-    _walker_handle: Option<JoinHandle<()>>,
+    // Chunk: docs/chunks/lazy_subsystem_init - Walk deferred until first use
+    /// The initial walk, held until first use and then run on the walker thread.
+    pending_walk: Mutex<Option<Box<dyn FnOnce() + Send>>>,
+    /// Handle to the walker thread, once the walk has started.
+    walker_handle: Mutex<Option<JoinHandle<()>>>,
     /// Handle to the watcher thread (joined on drop).
     _watcher_handle: Option<JoinHandle<()>>,
     /// Sender to signal watcher thread to stop.
@@ -125,7 +134,8 @@ impl FileIndex {
     /// Start indexing `root` in a background thread.
     ///
     /// Loads the persisted recency list from `<root>/.lite-edit-recent` if it exists.
-    /// Returns immediately; the walk proceeds concurrently.
+    /// Returns immediately. The filesystem watcher starts right away, but the
+    /// initial walk is deferred until the index is first used (see `start_walk()`).
     pub fn start(root: PathBuf) -> Self {
         let callbacks = FileEventCallbacks {
             on_change: None,
@@ -201,36 +211,31 @@ impl FileIndex {
             recency,
         }));
         let version = Arc::new(AtomicU64::new(0));
-        let indexing = Arc::new(AtomicBool::new(true));
         // Chunk: docs/chunks/app_nap_file_watcher_pause - Initialize pause state
         let paused = Arc::new(AtomicBool::new(false));
         let stored_callbacks = Arc::new(Mutex::new(Some(callbacks.clone())));
 
-        // Check if root exists before starting the walk
+        // Check if root exists before preparing the walk
         let root_exists = root.is_dir();
+        // A non-existent root has nothing to walk, so it is never indexing
+        let indexing = Arc::new(AtomicBool::new(root_exists));
 
         // Chunk: docs/chunks/fuzzy_finder_hidden_files - Detect git repo for file discovery
         let is_git = root_exists && is_git_repo(&root);
 
-        // Spawn the walker thread
+        // Prepare the walk; start_walk() spawns it on first use
         let walker_state = Arc::clone(&state);
         let walker_version = Arc::clone(&version);
         let walker_indexing = Arc::clone(&indexing);
         let walker_root = root.clone();
         let walker_is_git = is_git;
 
-        let walker_handle = thread::spawn(move || {
-            if !root_exists {
-                // Non-existent root: immediately mark as done
-                walker_indexing.store(false, Ordering::Relaxed);
-                return;
-            }
-
+        let walk = move || {
             // Chunk: docs/chunks/fuzzy_finder_hidden_files - Git-based or fallback walk
             if walker_is_git {
                 // In a git repo, use git ls-files for an atomic snapshot of all
                 // tracked + untracked-but-not-ignored files.
-                if let Some(paths) = git_ls_files(&walker_root) {
+                if let Some(paths) = git_ls_files(&walker_root, Path::new("")) {
                     let file_paths: Vec<PathBuf> = paths
                         .into_iter()
                         .filter(|p| walker_root.join(p).is_file())
@@ -240,6 +245,8 @@ impl FileIndex {
                         let mut state = walker_state.lock().unwrap();
                         state.cache.extend(file_paths);
                         state.cache.sort();
+                        // The watcher may have added some of these already
+                        state.cache.dedup();
                         drop(state);
                         walker_version.fetch_add(1, Ordering::Relaxed);
                     }
@@ -254,7 +261,12 @@ impl FileIndex {
 
             // Mark indexing as complete
             walker_indexing.store(false, Ordering::Relaxed);
-        });
+        };
+        let pending_walk: Option<Box<dyn FnOnce() + Send>> = if root_exists {
+            Some(Box::new(walk))
+        } else {
+            None
+        };
 
         // Create channels for watcher communication
         let (event_tx, event_rx) = mpsc::channel::<Event>();
@@ -309,7 +321,8 @@ impl FileIndex {
             state,
             version,
             indexing,
-            _walker_handle: Some(walker_handle),
+            pending_walk: Mutex::new(pending_walk),
+            walker_handle: Mutex::new(None),
             _watcher_handle: Some(watcher_handle),
             _watcher_stop_tx: Some(stop_tx),
             _watcher: watcher,
@@ -319,14 +332,41 @@ impl FileIndex {
         }
     }
 
+    // Chunk: docs/chunks/terminal_cwd_picker - Root accessor
+    /// Returns the directory being indexed.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    // Chunk: docs/chunks/lazy_subsystem_init - Start the deferred walk
+    /// Starts the initial walk on a background thread if it hasn't started yet.
+    ///
+    /// Called implicitly by `query()`, `cache_version()` and `is_indexing()`, so
+    /// the walk runs no later than the first time the index is used. Call it
+    /// directly to warm the index up ahead of that.
+    pub fn start_walk(&self) {
+        let walk = self.pending_walk.lock().unwrap().take();
+        if let Some(walk) = walk {
+            *self.walker_handle.lock().unwrap() = Some(thread::spawn(walk));
+        }
+    }
+
+    /// Returns true once the initial walk has been started (or there is nothing to walk).
+    pub fn walk_started(&self) -> bool {
+        self.pending_walk.lock().unwrap().is_none()
+    }
+
     /// Score `query` against the current path cache and return results sorted by
     /// descending score.
     ///
-    /// Never blocks — returns whatever has been discovered so far.
+    /// Never blocks — returns whatever has been discovered so far (starting the
+    /// initial walk if this is the first use).
     ///
     /// When `query` is empty, recent files are prepended in recency order (most
     /// recent first) before the remaining cached paths (alphabetical).
     pub fn query(&self, query: &str) -> Vec<MatchResult> {
+        self.start_walk();
+
         // Clone the cache and recency under lock
         let (cache, recency) = {
             let state = self.state.lock().unwrap();
@@ -357,6 +397,7 @@ impl FileIndex {
                 results.push(MatchResult {
                     path: path.clone(),
                     score: u32::MAX,
+                    match_positions: Vec::new(),
                 });
             }
         }
@@ -370,7 +411,11 @@ impl FileIndex {
         remaining.sort();
 
         for path in remaining {
-            results.push(MatchResult { path, score: 1 });
+            results.push(MatchResult {
+                path,
+                score: 1,
+                match_positions: Vec::new(),
+            });
         }
 
         results
@@ -378,52 +423,32 @@ impl FileIndex {
 
     /// Handle non-empty query: fuzzy matching with scoring.
     ///
-    /// Scores each path against both the filename and the full path:
-    /// - If the query matches the filename: use `filename_score * 2 + path_score`
-    /// - If the query only matches the path: use `path_score` as the sole score
-    /// - If no match: filter out the path
+    /// Each path is scored with [`fuzzy_match_path`], which finds the best
+    /// alignment of the query in the whole relative path, favouring word
+    /// boundaries, camelCase humps, consecutive runs and the file name. This
+    /// lets users type either a file name or directory fragments (`src/main`).
     ///
-    /// This ensures filename matches dominate (2× weight) while path-only matches
-    /// still appear (users can type directory names).
+    /// Ties are broken by shorter path, then alphabetically.
+    // Chunk: docs/chunks/fuzzy_ranked_scoring - Ranked fuzzy scoring
     fn query_fuzzy(&self, cache: &[PathBuf], query: &str) -> Vec<MatchResult> {
         let mut results: Vec<MatchResult> = cache
             .iter()
             .filter(|p| !self.should_exclude(p))
             .filter_map(|path| {
-                // Compute filename score
-                let filename_score = path
-                    .file_name()
-                    .and_then(|f| f.to_str())
-                    .and_then(|filename| score_match(query, filename));
-
-                // Compute path score
-                let path_score = score_path_match(query, path);
-
-                // Compute final score based on which matches succeeded
-                let final_score = match (filename_score, path_score) {
-                    // Both match: filename score dominates (2×) + path score bonus
-                    (Some(fs), Some(ps)) => {
-                        Some(fs.saturating_mul(2).saturating_add(ps))
-                    }
-                    // Only filename matches
-                    (Some(fs), None) => Some(fs.saturating_mul(2)),
-                    // Only path matches (path-only result)
-                    (None, Some(ps)) => Some(ps),
-                    // Neither matches: filter out
-                    (None, None) => None,
-                };
-
-                final_score.map(|score| MatchResult {
+                let m = fuzzy_match_path(query, &path.to_string_lossy())?;
+                Some(MatchResult {
                     path: path.clone(),
-                    score,
+                    score: m.score.max(0) as u32,
+                    match_positions: m.positions,
                 })
             })
             .collect();
 
-        // Sort by descending score, then alphabetically by path
+        // Sort by descending score, then shorter path, then alphabetically
         results.sort_by(|a, b| {
             b.score
                 .cmp(&a.score)
+                .then_with(|| a.path.as_os_str().len().cmp(&b.path.as_os_str().len()))
                 .then_with(|| a.path.cmp(&b.path))
         });
 
@@ -436,11 +461,13 @@ impl FileIndex {
     /// a path added/removed by a filesystem event. The file picker polls this to
     /// detect when it should re-evaluate the current query and refresh the item list.
     pub fn cache_version(&self) -> u64 {
+        self.start_walk();
         self.version.load(Ordering::Relaxed)
     }
 
-    /// True while the initial recursive walk is still running.
+    /// True while the initial recursive walk is pending or still running.
     pub fn is_indexing(&self) -> bool {
+        self.start_walk();
         self.indexing.load(Ordering::Relaxed)
     }
 
@@ -454,6 +481,12 @@ impl FileIndex {
         {
             let mut state = self.state.lock().unwrap();
 
+            // Chunk: docs/chunks/recent_files_switcher - Skip the write when nothing changes
+            // Tab activations record the active file after every input event.
+            if state.recency.front() == Some(&relative_path) {
+                return;
+            }
+
             // Remove any existing occurrence
             state.recency.retain(|p| p != &relative_path);
 
@@ -470,6 +503,21 @@ impl FileIndex {
         }
     }
 
+    // Chunk: docs/chunks/recent_files_switcher - MRU list for the recent files selector
+    /// Returns the recency list (most recent first), skipping files that no
+    /// longer exist on disk.
+    ///
+    /// Paths under the root are relative to it; other paths are absolute.
+    /// Unlike an empty `query`, files that are not in the cache (e.g. opened
+    /// from outside the root) are included.
+    pub fn recent_files(&self) -> Vec<PathBuf> {
+        let recency = self.state.lock().unwrap().recency.clone();
+        recency
+            .into_iter()
+            .filter(|path| self.root.join(path).exists())
+            .collect()
+    }
+
     // Chunk: docs/chunks/app_nap_file_watcher_pause - Pause watcher for App Nap
     /// Pauses the file watcher to allow App Nap when the app is backgrounded.
     ///
@@ -515,8 +563,10 @@ impl FileIndex {
     ///
     /// This method:
     /// 1. Clears the paused flag so the watcher thread resumes processing
-    /// 2. Checks if any recently accessed files were modified while paused
-    /// 3. Emits FileChanged events for modified files
+    /// 2. Re-lists the tree in the background to pick up files created or
+    ///    removed while events were being discarded
+    /// 3. Checks if any recently accessed files were modified while paused
+    /// 4. Emits FileChanged events for modified files
     ///
     /// # Arguments
     ///
@@ -529,6 +579,19 @@ impl FileIndex {
             return;
         }
 
+        // Chunk: docs/chunks/file_index_incremental_watch - Catch up on events dropped while paused
+        if self.walk_started() && self.root.is_dir() {
+            let root = self.root.clone();
+            let state = Arc::clone(&self.state);
+            let version = Arc::clone(&self.version);
+            let is_git = self.is_git;
+            thread::spawn(move || {
+                if sync_subtree(&root, Path::new(""), &state, is_git) {
+                    version.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+
         // Get the on_change callback if available
         let on_change = {
             let callbacks_guard = self.callbacks.lock().unwrap();
@@ -627,10 +690,16 @@ fn is_git_repo(root: &Path) -> bool {
 /// Returns the list of files tracked by git (and untracked but not ignored).
 ///
 /// Runs `git ls-files --cached --others --exclude-standard -z` from the given
-/// root directory. Returns `None` if the command fails.
-fn git_ls_files(root: &Path) -> Option<Vec<PathBuf>> {
-    let output = Command::new("git")
-        .args(["ls-files", "--cached", "--others", "--exclude-standard", "-z"])
+/// root directory, limited to `dir` (relative to root) unless it is empty.
+/// Returns `None` if the command fails.
+fn git_ls_files(root: &Path, dir: &Path) -> Option<Vec<PathBuf>> {
+    let mut command = Command::new("git");
+    command.args(["ls-files", "--cached", "--others", "--exclude-standard", "-z"]);
+    // Chunk: docs/chunks/file_index_incremental_watch - Subtree listing
+    if !dir.as_os_str().is_empty() {
+        command.arg("--").arg(dir);
+    }
+    let output = command
         .current_dir(root)
         .stderr(std::process::Stdio::null())
         .output()
@@ -812,11 +881,81 @@ fn walk_directory(
         let mut state = state.lock().unwrap();
         state.cache.extend(batch);
         state.cache.sort(); // Keep sorted for deterministic ordering
+        state.cache.dedup(); // The watcher may have added some of these already
         drop(state);
         version.fetch_add(1, Ordering::Relaxed);
     }
 }
 
+// Chunk: docs/chunks/file_index_incremental_watch - Re-list one subtree
+/// Returns the files under `dir` (relative to `root`; empty for the whole tree),
+/// as paths relative to `root`, applying the same exclusions as the initial walk.
+fn list_files(root: &Path, dir: &Path, is_git: bool) -> Vec<PathBuf> {
+    if is_git {
+        if let Some(paths) = git_ls_files(root, dir) {
+            return paths.into_iter().filter(|p| root.join(p).is_file()).collect();
+        }
+    }
+    let mut files = Vec::new();
+    collect_files(root, &root.join(dir), &mut files);
+    files
+}
+
+/// Recursively collects non-excluded files under `dir` into `files`.
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let relative = match path.strip_prefix(root) {
+            Ok(r) => r.to_path_buf(),
+            Err(_) => continue,
+        };
+        if is_excluded_fallback(&relative) {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(root, &path, files);
+        } else if path.is_file() {
+            files.push(relative);
+        }
+    }
+}
+
+// Chunk: docs/chunks/file_index_incremental_watch - Re-list one subtree
+/// Replaces the cached paths under `dir` (relative to `root`; empty for the
+/// whole tree) with what is currently on disk.
+///
+/// Used where one event stands for many files: a directory created, moved or
+/// deleted as a whole, or an FSEvents rescan request. Returns true if the
+/// cache changed.
+fn sync_subtree(root: &Path, dir: &Path, state: &Arc<Mutex<SharedState>>, is_git: bool) -> bool {
+    let mut fresh = list_files(root, dir, is_git);
+    fresh.sort();
+
+    let mut state = state.lock().unwrap();
+    let cached: Vec<&PathBuf> = state.cache.iter().filter(|p| p.starts_with(dir)).collect();
+    if cached.len() == fresh.len() && cached.iter().zip(&fresh).all(|(a, b)| *a == b) {
+        return false;
+    }
+    state.cache.retain(|p| !p.starts_with(dir));
+    state.cache.extend(fresh);
+    state.cache.sort();
+    state.cache.dedup();
+    true
+}
+
+/// Removes `dir` and every cached path below it. Returns true if any were cached.
+fn remove_subtree(dir: &Path, state: &Arc<Mutex<SharedState>>) -> bool {
+    let mut state = state.lock().unwrap();
+    let len_before = state.cache.len();
+    state.cache.retain(|p| !p.starts_with(dir));
+    state.cache.len() != len_before
+}
+
 // =============================================================================
 // Filesystem Watcher Event Processing
 // =============================================================================
@@ -905,6 +1044,28 @@ fn handle_fs_event(
 ) {
     let mut changed = false;
 
+    // Chunk: docs/chunks/file_index_incremental_watch - FSEvents dropped events
+    // FSEvents sets MustScanSubDirs when it coalesced or dropped events below a
+    // path; notify surfaces that as the rescan flag. Re-list those subtrees.
+    if event.need_rescan() {
+        let dirs: Vec<PathBuf> = if event.paths.is_empty() {
+            vec![PathBuf::new()]
+        } else {
+            event
+                .paths
+                .iter()
+                .filter_map(|p| p.strip_prefix(root).ok().map(Path::to_path_buf))
+                .collect()
+        };
+        for dir in dirs {
+            changed |= sync_subtree(root, &dir, state, is_git);
+        }
+        if changed {
+            version.fetch_add(1, Ordering::Relaxed);
+        }
+        return;
+    }
+
     for path in &event.paths {
         let relative = match path.strip_prefix(root) {
             Ok(r) => r.to_path_buf(),
@@ -942,11 +1103,16 @@ fn handle_fs_event(
                     if was_known {
                         debouncer.register(path.clone(), Instant::now());
                     }
+                } else if path.is_dir() {
+                    // Chunk: docs/chunks/file_index_incremental_watch - Directories arriving whole
+                    // A directory copied or unpacked into the tree may not get
+                    // a Create event for every file inside it
+                    changed |= sync_subtree(root, &relative, state, is_git);
                 }
             }
             EventKind::Remove(_) => {
-                let mut state = state.lock().unwrap();
-                state.cache.retain(|p| p != &relative);
+                // Chunk: docs/chunks/file_index_incremental_watch - Directory removal drops its files
+                remove_subtree(&relative, state);
                 changed = true;
 
                 // Chunk: docs/chunks/deletion_rename_handling - Invoke deletion callback
@@ -983,7 +1149,8 @@ fn handle_fs_event(
                                     // write (rename over existing file). Register with debouncer
                                     // so the file change callback fires.
                                     let to_was_known = state.cache.contains(&to_rel.to_path_buf());
-                                    state.cache.retain(|p| p != from_rel);
+                                    // Chunk: docs/chunks/file_index_incremental_watch - Directory renames move their files
+                                    state.cache.retain(|p| !p.starts_with(from_rel));
                                     if to_path.is_file() && !to_excluded &&
                                        !state.cache.contains(&to_rel.to_path_buf()) {
                                         state.cache.push(to_rel.to_path_buf());
@@ -994,6 +1161,9 @@ fn handle_fs_event(
                                         debouncer.register(to_path.clone(), Instant::now());
                                     }
                                 }
+                                if to_path.is_dir() && !to_excluded {
+                                    sync_subtree(root, to_rel, state, is_git);
+                                }
                             }
 
                             // Invoke rename callback with both paths
@@ -1007,6 +1177,11 @@ fn handle_fs_event(
                     _ => {
                         // RenameMode::From, To, or Any: separate events for source and target
                         // We handle both add and remove based on whether the path exists
+                        // Chunk: docs/chunks/file_index_incremental_watch - Directory moved in
+                        if path.is_dir() {
+                            changed |= sync_subtree(root, &relative, state, is_git);
+                            continue;
+                        }
                         let mut state = state.lock().unwrap();
                         if path.exists() && path.is_file() {
                             // Chunk: docs/chunks/external_edit_reload - Detect atomic-write renames (non-Both)
@@ -1023,9 +1198,9 @@ fn handle_fs_event(
                                 debouncer.register(path.clone(), Instant::now());
                             }
                         } else {
-                            // Old path (source of rename)
+                            // Old path (source of rename); a directory takes its files along
                             let len_before = state.cache.len();
-                            state.cache.retain(|p| p != &relative);
+                            state.cache.retain(|p| !p.starts_with(&relative));
                             if state.cache.len() != len_before {
                                 changed = true;
                             }
@@ -1061,137 +1236,6 @@ fn handle_fs_event(
     }
 }
 
-// =============================================================================
-// Scoring Algorithm
-// =============================================================================
-
-/// Scores a query against a filename.
-///
-/// Returns None if the query doesn't match (not all characters found as subsequence).
-/// Returns Some(score) if the query matches, with higher scores being better.
-fn score_match(query: &str, filename: &str) -> Option<u32> {
-    let filename_lower = filename.to_lowercase();
-    let query_chars: Vec<char> = query.chars().collect();
-    let filename_chars: Vec<char> = filename_lower.chars().collect();
-
-    if query_chars.is_empty() {
-        return Some(1);
-    }
-
-    // Find match positions using subsequence matching
-    let positions = find_match_positions(&query_chars, &filename_chars)?;
-
-    // Base score
-    let mut score: u32 = 100;
-
-    // Consecutive run bonus: runs of ≥2 consecutively matched characters
-    let mut consecutive_bonus: u32 = 0;
-    let mut run_length = 1;
-    for window in positions.windows(2) {
-        if window[1] == window[0] + 1 {
-            run_length += 1;
-        } else {
-            if run_length >= 2 {
-                consecutive_bonus += run_length as u32 * 10;
-            }
-            run_length = 1;
-        }
-    }
-    if run_length >= 2 {
-        consecutive_bonus += run_length as u32 * 10;
-    }
-    score += consecutive_bonus;
-
-    // Prefix bonus: matched characters beginning at position 0
-    if !positions.is_empty() && positions[0] == 0 {
-        // Count how many consecutive matches start from position 0
-        let prefix_len = positions
-            .iter()
-            .enumerate()
-            .take_while(|(i, &pos)| pos == *i)
-            .count();
-        score += prefix_len as u32 * 50;
-    }
-
-    // Shorter filename bonus: shorter filenames score higher
-    // Use inverse of length (capped to prevent overflow)
-    let length_penalty = filename.len().min(255) as u32;
-    score += 255 - length_penalty;
-
-    Some(score)
-}
-
-/// Scores a query against a full relative path string.
-///
-/// Returns None if the query doesn't match (not all characters found as subsequence).
-/// Returns Some(score) if the query matches, with higher scores being better.
-///
-/// Unlike `score_match`, this function does NOT apply filename-specific bonuses
-/// (prefix bonus, shorter-length bonus). It only applies:
-/// - Base score
-/// - Consecutive-run bonus
-fn score_path_match(query: &str, path: &Path) -> Option<u32> {
-    let path_str = path.to_string_lossy().to_lowercase();
-    let query_chars: Vec<char> = query.chars().collect();
-    let path_chars: Vec<char> = path_str.chars().collect();
-
-    if query_chars.is_empty() {
-        return Some(1);
-    }
-
-    // Find match positions using subsequence matching
-    let positions = find_match_positions(&query_chars, &path_chars)?;
-
-    // Base score
-    let mut score: u32 = 100;
-
-    // Consecutive run bonus: runs of ≥2 consecutively matched characters
-    let mut consecutive_bonus: u32 = 0;
-    let mut run_length = 1;
-    for window in positions.windows(2) {
-        if window[1] == window[0] + 1 {
-            run_length += 1;
-        } else {
-            if run_length >= 2 {
-                consecutive_bonus = consecutive_bonus.saturating_add(run_length as u32 * 10);
-            }
-            run_length = 1;
-        }
-    }
-    if run_length >= 2 {
-        consecutive_bonus = consecutive_bonus.saturating_add(run_length as u32 * 10);
-    }
-    score = score.saturating_add(consecutive_bonus);
-
-    Some(score)
-}
-
-/// Finds the positions in `target` where each character of `query` matches.
-///
-/// Uses a greedy left-to-right scan. Returns None if not all characters match.
-fn find_match_positions(query: &[char], target: &[char]) -> Option<Vec<usize>> {
-    let mut positions = Vec::with_capacity(query.len());
-    let mut target_idx = 0;
-
-    for &qc in query {
-        let mut found = false;
-        while target_idx < target.len() {
-            if target[target_idx] == qc {
-                positions.push(target_idx);
-                target_idx += 1;
-                found = true;
-                break;
-            }
-            target_idx += 1;
-        }
-        if !found {
-            return None;
-        }
-    }
-
-    Some(positions)
-}
-
 // =============================================================================
 // Tests
 // =============================================================================
@@ -1311,6 +1355,27 @@ mod tests {
         assert!(!index.is_indexing());
     }
 
+    // Chunk: docs/chunks/lazy_subsystem_init - Walk is deferred until first use
+    #[test]
+    fn test_walk_deferred_until_first_use() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        File::create(root.join("main.rs")).unwrap();
+
+        let index = FileIndex::start(root.to_path_buf());
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(!index.walk_started());
+        assert!(index.state.lock().unwrap().cache.is_empty());
+
+        let mut attempts = 0;
+        while index.is_indexing() && attempts < 100 {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            attempts += 1;
+        }
+        assert!(index.walk_started());
+        assert!(index.query("").iter().any(|r| r.path == Path::new("main.rs")));
+    }
+
     #[test]
     fn test_is_indexing_transitions() {
         let temp = TempDir::new().unwrap();
@@ -1659,6 +1724,28 @@ mod tests {
         assert_eq!(file_count, 1);
     }
 
+    // Chunk: docs/chunks/recent_files_switcher - MRU list tests
+    #[test]
+    fn test_recent_files_most_recent_first_skipping_missing() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+
+        File::create(root.join("a.rs")).unwrap();
+        File::create(root.join("b.rs")).unwrap();
+
+        let index = FileIndex::start(root.to_path_buf());
+
+        index.record_selection(&root.join("a.rs"));
+        index.record_selection(Path::new("gone.rs"));
+        index.record_selection(&root.join("b.rs"));
+        index.record_selection(&root.join("a.rs"));
+
+        assert_eq!(
+            index.recent_files(),
+            vec![PathBuf::from("a.rs"), PathBuf::from("b.rs")]
+        );
+    }
+
     #[test]
     fn test_record_selection_persistence() {
         let temp = TempDir::new().unwrap();
@@ -1815,51 +1902,6 @@ mod tests {
         assert!(removed, "Removed file should not appear in query results");
     }
 
-    // -------------------------------------------------------------------------
-    // Scoring Algorithm Unit Tests
-    // -------------------------------------------------------------------------
-
-    #[test]
-    fn test_score_match_exact_prefix() {
-        let score = score_match("main", "main.rs").unwrap();
-        // Should have high score due to prefix match
-        assert!(score > 200);
-    }
-
-    #[test]
-    fn test_score_match_no_match() {
-        let score = score_match("xyz", "main.rs");
-        assert!(score.is_none());
-    }
-
-    #[test]
-    fn test_score_match_subsequence() {
-        let score = score_match("mr", "main.rs");
-        assert!(score.is_some());
-    }
-
-    #[test]
-    fn test_score_match_case_insensitive() {
-        let score = score_match("main", "MAIN.RS");
-        assert!(score.is_some());
-    }
-
-    #[test]
-    fn test_find_match_positions_basic() {
-        let query: Vec<char> = "mr".chars().collect();
-        let target: Vec<char> = "main.rs".chars().collect();
-        let positions = find_match_positions(&query, &target).unwrap();
-        assert_eq!(positions, vec![0, 5]); // m at 0, r at 5
-    }
-
-    #[test]
-    fn test_find_match_positions_no_match() {
-        let query: Vec<char> = "xyz".chars().collect();
-        let target: Vec<char> = "main.rs".chars().collect();
-        let positions = find_match_positions(&query, &target);
-        assert!(positions.is_none());
-    }
-
     // -------------------------------------------------------------------------
     // Path-Segment Matching Tests
     // -------------------------------------------------------------------------
@@ -2021,47 +2063,29 @@ mod tests {
     }
 
     #[test]
-    fn test_score_path_match_basic() {
-        // Basic test for score_path_match function
-        let path = Path::new("docs/chunks/feature/GOAL.md");
-
-        // Query that matches the path
-        let score = score_path_match("docs", path);
-        assert!(score.is_some(), "Expected score for 'docs' in path");
-        assert!(score.unwrap() >= 100, "Score should include base score");
-
-        // Query that doesn't match the path
-        let no_score = score_path_match("xyz", path);
-        assert!(no_score.is_none(), "Expected no match for 'xyz'");
-    }
+    fn test_query_reports_match_positions_in_path() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
 
-    #[test]
-    fn test_score_path_match_consecutive_bonus() {
-        // Test that consecutive characters in path get bonus
-        let path = Path::new("testing/feature/main.rs");
+        fs::create_dir_all(root.join("src")).unwrap();
+        File::create(root.join("src/file_index.rs")).unwrap();
 
-        // "test" appears consecutively
-        let consecutive_score = score_path_match("test", path);
-        assert!(consecutive_score.is_some());
+        let index = FileIndex::start(root.to_path_buf());
 
-        // "tig" requires skipping characters (t-i-g from "testing")
-        let sparse_score = score_path_match("tig", path);
-        assert!(sparse_score.is_some());
+        while index.is_indexing() {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
 
-        // Consecutive should score higher
-        assert!(
-            consecutive_score.unwrap() > sparse_score.unwrap(),
-            "Consecutive match should score higher than sparse match"
-        );
-    }
+        // The boundary-aligned "i" of "index", not the greedy "i" of "file"
+        let results = index.query("ix");
+        let result = results
+            .iter()
+            .find(|r| r.path == Path::new("src/file_index.rs"))
+            .unwrap();
+        assert_eq!(result.match_positions, vec![9, 13]);
 
-    #[test]
-    fn test_empty_query_path_match() {
-        // Empty query should return a score (handled in score_path_match)
-        let path = Path::new("src/main.rs");
-        let score = score_path_match("", path);
-        assert!(score.is_some(), "Empty query should match any path");
-        assert_eq!(score.unwrap(), 1, "Empty query should return score 1");
+        // Empty queries carry no positions
+        assert!(index.query("").iter().all(|r| r.match_positions.is_empty()));
     }
 
     #[test]
@@ -2471,7 +2495,7 @@ mod tests {
         Command::new("git").args(["add", "hello.rs", ".github/ci.yml"])
             .current_dir(root).stdout(std::process::Stdio::null()).status().unwrap();
 
-        let files = git_ls_files(root).expect("git ls-files should succeed");
+        let files = git_ls_files(root, Path::new("")).expect("git ls-files should succeed");
         assert!(files.contains(&PathBuf::from("hello.rs")));
         assert!(files.contains(&PathBuf::from(".github/ci.yml")));
     }
@@ -2494,7 +2518,7 @@ mod tests {
         File::create(root.join("debug.log")).unwrap();
         File::create(root.join("main.rs")).unwrap();
 
-        let files = git_ls_files(root).expect("git ls-files should succeed");
+        let files = git_ls_files(root, Path::new("")).expect("git ls-files should succeed");
         assert!(!files.contains(&PathBuf::from("debug.log")));
         assert!(files.contains(&PathBuf::from("main.rs")));
     }
@@ -2611,4 +2635,118 @@ mod tests {
             ready
         );
     }
+
+    // -------------------------------------------------------------------------
+    // Directory-Level Watcher Events
+    // Chunk: docs/chunks/file_index_incremental_watch
+    // -------------------------------------------------------------------------
+
+    fn apply_event(root: &Path, cache: Vec<PathBuf>, event: Event) -> Vec<PathBuf> {
+        use crate::file_change_debouncer::FileChangeDebouncer;
+
+        let state = Arc::new(Mutex::new(SharedState {
+            cache,
+            recency: VecDeque::new(),
+        }));
+        let version = Arc::new(AtomicU64::new(0));
+        let mut debouncer = FileChangeDebouncer::with_default();
+        let callbacks = FileEventCallbacks {
+            on_change: None,
+            on_delete: None,
+            on_rename: None,
+        };
+        handle_fs_event(root, &state, &version, &event, &mut debouncer, &callbacks, false);
+        let cache = state.lock().unwrap().cache.clone();
+        cache
+    }
+
+    #[test]
+    fn test_directory_create_event_indexes_its_files() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("vendor/lib")).unwrap();
+        File::create(root.join("vendor/a.rs")).unwrap();
+        File::create(root.join("vendor/lib/b.rs")).unwrap();
+
+        let event = Event::new(EventKind::Create(notify::event::CreateKind::Folder))
+            .add_path(root.join("vendor"));
+        let cache = apply_event(root, vec![PathBuf::from("main.rs")], event);
+
+        assert_eq!(
+            cache,
+            vec![
+                PathBuf::from("main.rs"),
+                PathBuf::from("vendor/a.rs"),
+                PathBuf::from("vendor/lib/b.rs"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_directory_remove_event_drops_its_files() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+
+        let event = Event::new(EventKind::Remove(notify::event::RemoveKind::Folder))
+            .add_path(root.join("src"));
+        let cache = apply_event(
+            root,
+            vec![
+                PathBuf::from("src/lib.rs"),
+                PathBuf::from("src/nested/mod.rs"),
+                PathBuf::from("src_notes.md"),
+            ],
+            event,
+        );
+
+        assert_eq!(cache, vec![PathBuf::from("src_notes.md")]);
+    }
+
+    #[test]
+    fn test_directory_rename_event_moves_its_files() {
+        use notify::event::RenameMode;
+
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("new")).unwrap();
+        File::create(root.join("new/a.rs")).unwrap();
+
+        let event = Event::new(EventKind::Modify(ModifyKind::Name(RenameMode::Both)))
+            .add_path(root.join("old"))
+            .add_path(root.join("new"));
+        let cache = apply_event(root, vec![PathBuf::from("old/a.rs")], event);
+
+        assert_eq!(cache, vec![PathBuf::from("new/a.rs")]);
+    }
+
+    #[test]
+    fn test_rescan_event_relists_subtree() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        File::create(root.join("src/kept.rs")).unwrap();
+        File::create(root.join("src/added.rs")).unwrap();
+
+        let event = Event::new(EventKind::Other)
+            .add_path(root.join("src"))
+            .set_flag(notify::event::Flag::Rescan);
+        let cache = apply_event(
+            root,
+            vec![
+                PathBuf::from("README.md"),
+                PathBuf::from("src/kept.rs"),
+                PathBuf::from("src/removed.rs"),
+            ],
+            event,
+        );
+
+        assert_eq!(
+            cache,
+            vec![
+                PathBuf::from("README.md"),
+                PathBuf::from("src/added.rs"),
+                PathBuf::from("src/kept.rs"),
+            ]
+        );
+    }
 }
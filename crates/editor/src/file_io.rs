@@ -0,0 +1,560 @@
+// Chunk: docs/chunks/background_file_io - Large files read and written off the main thread
+//!
+//! Background file reads and writes.
+//!
+//! Reading a file of a few hundred megabytes or writing it back blocks the
+//! main thread for as long as the disk takes. [`FileIoPool`] runs those reads
+//! and writes on a small pool of worker threads and reports back through a
+//! callback; in the app the callback sends an `EditorEvent::FileIo` through
+//! the event channel, so results are handled by the drain loop like any
+//! other event.
+//!
+//! Reads are reported in chunks as they come off the disk. The tab shows
+//! each chunk as it arrives, read-only, with its progress on the tab label,
+//! until the whole file is in.
+//!
+//! Jobs for the same path always run on the same worker, in the order they
+//! were queued, so two saves of a file can't land out of order and a reload
+//! after a save reads what was written.
+//!
+//! Writes go to a temporary file next to the original, which is then renamed
+//! over it, so a failed write or a crash part way through leaves the
+//! original whole. Dropping the pool waits for queued writes to finish and
+//! abandons reads.
+//!
+//! Only files of at least [`BACKGROUND_IO_THRESHOLD`] bytes go through the
+//! pool; smaller ones are read and written in place, which is faster than
+//! the round trip through the event loop.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::SystemTime;
+
+use crate::workspace::TabId;
+
+/// Files at least this large are read and written in the background.
+pub const BACKGROUND_IO_THRESHOLD: u64 = 1 << 20;
+
+/// How much of a file is read before its tab is updated.
+const CHUNK_SIZE: usize = 1 << 20;
+
+/// Number of worker threads.
+const WORKER_COUNT: usize = 2;
+
+/// A result of a background read or write.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileIoEvent {
+    /// The next part of a file being read into `tab_id`
+    ReadChunk {
+        tab_id: TabId,
+        /// The text read since the last chunk
+        text: String,
+        /// Bytes read so far
+        bytes_read: u64,
+        /// The file's size when the read started
+        total: u64,
+    },
+    /// A read into `tab_id` finished. `Ok` carries the file's modification
+    /// time; the text has all been sent in chunks.
+    ReadDone {
+        tab_id: TabId,
+        path: PathBuf,
+        result: Result<Option<SystemTime>, String>,
+    },
+    /// A write of `tab_id`'s content finished. `Ok` carries the file's new
+    /// modification time.
+    WriteDone {
+        tab_id: TabId,
+        path: PathBuf,
+        result: Result<Option<SystemTime>, String>,
+    },
+}
+
+/// How far a background read of a tab's file has got.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LoadProgress {
+    /// Bytes read so far
+    pub bytes_read: u64,
+    /// The file's size
+    pub total: u64,
+}
+
+impl LoadProgress {
+    /// Progress of a read of `total` bytes that hasn't started.
+    pub fn new(total: u64) -> Self {
+        Self {
+            bytes_read: 0,
+            total,
+        }
+    }
+
+    /// Returns the percentage read, 0 to 100.
+    pub fn percent(&self) -> u8 {
+        if self.total == 0 {
+            return 100;
+        }
+        (self.bytes_read.min(self.total) * 100 / self.total) as u8
+    }
+}
+
+/// A tab's content being written in the background.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingSave {
+    /// The content of the latest write queued
+    pub content: String,
+    /// Writes queued and not yet finished
+    pub writes: usize,
+}
+
+/// Returns the size of `path` if it is a file large enough to be read in
+/// the background.
+pub fn background_read_size(path: &Path) -> Option<u64> {
+    std::fs::metadata(path)
+        .ok()
+        .filter(|m| m.is_file() && m.len() >= BACKGROUND_IO_THRESHOLD)
+        .map(|m| m.len())
+}
+
+/// A read or write queued on a worker.
+enum Job {
+    Read { tab_id: TabId, path: PathBuf },
+    Write { tab_id: TabId, path: PathBuf, content: String },
+}
+
+type Deliver = Arc<dyn Fn(FileIoEvent) + Send + Sync>;
+
+/// Worker threads that read and write files.
+///
+/// Dropping the pool blocks until every queued write has finished; reads
+/// still queued or in progress are abandoned without reporting.
+pub struct FileIoPool {
+    workers: Vec<Sender<Job>>,
+    handles: Vec<JoinHandle<()>>,
+    /// Set when the pool is dropped, telling workers to skip reads.
+    stopping: Arc<AtomicBool>,
+}
+
+impl FileIoPool {
+    /// Starts the workers. `deliver` is called on a worker thread with each
+    /// chunk read and each finished read or write.
+    pub fn new(deliver: impl Fn(FileIoEvent) + Send + Sync + 'static) -> Self {
+        let deliver: Deliver = Arc::new(deliver);
+        let stopping = Arc::new(AtomicBool::new(false));
+        let (workers, handles) = (0..WORKER_COUNT)
+            .map(|_| {
+                let (sender, jobs) = mpsc::channel();
+                let deliver = deliver.clone();
+                let stopping = stopping.clone();
+                let handle = thread::spawn(move || {
+                    for job in jobs {
+                        run_job(job, &*deliver, &stopping);
+                    }
+                });
+                (sender, handle)
+            })
+            .unzip();
+        Self {
+            workers,
+            handles,
+            stopping,
+        }
+    }
+
+    /// Queues a read of `path` into `tab_id`.
+    pub fn read(&self, tab_id: TabId, path: PathBuf) {
+        self.queue(Job::Read { tab_id, path });
+    }
+
+    /// Queues a write of `content` to `path` on behalf of `tab_id`.
+    pub fn write(&self, tab_id: TabId, path: PathBuf, content: String) {
+        self.queue(Job::Write {
+            tab_id,
+            path,
+            content,
+        });
+    }
+
+    fn queue(&self, job: Job) {
+        let path = match &job {
+            Job::Read { path, .. } | Job::Write { path, .. } => path,
+        };
+        let mut hasher = DefaultHasher::new();
+        path.hash(&mut hasher);
+        let worker = hasher.finish() as usize % self.workers.len();
+        let _ = self.workers[worker].send(job);
+    }
+}
+
+impl Drop for FileIoPool {
+    fn drop(&mut self) {
+        self.stopping.store(true, Ordering::Relaxed);
+        // Closing the queues ends each worker once its writes are done
+        self.workers.clear();
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn run_job(job: Job, deliver: &dyn Fn(FileIoEvent), stopping: &AtomicBool) {
+    match job {
+        Job::Read { tab_id, path } => {
+            if stopping.load(Ordering::Relaxed) {
+                return;
+            }
+            let result = read_in_chunks(&path, stopping, |text, bytes_read, total| {
+                deliver(FileIoEvent::ReadChunk {
+                    tab_id,
+                    text,
+                    bytes_read,
+                    total,
+                })
+            });
+            deliver(FileIoEvent::ReadDone {
+                tab_id,
+                path,
+                result: result.map_err(|e| e.to_string()),
+            });
+        }
+        Job::Write {
+            tab_id,
+            path,
+            content,
+        } => {
+            let result = write_replacing(&path, content.as_bytes())
+                .map(|()| modified_time(&path))
+                .map_err(|e| e.to_string());
+            deliver(FileIoEvent::WriteDone {
+                tab_id,
+                path,
+                result,
+            });
+        }
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Writes `bytes` to a temporary file in `path`'s directory and renames it
+/// over `path`, keeping the original's permissions. If `path` is a symlink
+/// the file it points to is replaced.
+///
+/// The original is untouched until the new contents are fully on disk; on
+/// failure the temporary file is removed. A file that can't be written in
+/// place fails as it would with `std::fs::write`.
+pub(crate) fn write_replacing(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let target = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    if target.exists() {
+        std::fs::OpenOptions::new().write(true).open(&target)?;
+    }
+    let name = target
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "not a file path"))?;
+    let temp = target.with_file_name(format!(
+        ".{}.{}.save",
+        name.to_string_lossy(),
+        std::process::id()
+    ));
+    let result = (|| {
+        let mut file = File::create(&temp)?;
+        file.write_all(bytes)?;
+        if let Ok(metadata) = std::fs::metadata(&target) {
+            file.set_permissions(metadata.permissions())?;
+        }
+        file.sync_all()?;
+        std::fs::rename(&temp, &target)
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp);
+    }
+    result
+}
+
+/// Reads `path`, passing each chunk's text, the bytes read so far and the
+/// file's size to `on_chunk`. Returns the file's modification time.
+///
+/// Invalid UTF-8 is replaced as by `String::from_utf8_lossy`; a character
+/// split across chunks is kept whole. Fails with `Interrupted` once
+/// `stopping` is set.
+fn read_in_chunks(
+    path: &Path,
+    stopping: &AtomicBool,
+    mut on_chunk: impl FnMut(String, u64, u64),
+) -> std::io::Result<Option<SystemTime>> {
+    let mut file = File::open(path)?;
+    let metadata = file.metadata()?;
+    let total = metadata.len();
+    let mut decoder = Utf8Chunks::default();
+    let mut buf = vec![0; CHUNK_SIZE];
+    let mut bytes_read = 0;
+    loop {
+        if stopping.load(Ordering::Relaxed) {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        let n = file.read(&mut buf)?;
+        bytes_read += n as u64;
+        let text = decoder.decode(&buf[..n], n == 0);
+        if !text.is_empty() {
+            on_chunk(text, bytes_read, total.max(bytes_read));
+        }
+        if n == 0 {
+            return Ok(metadata.modified().ok());
+        }
+    }
+}
+
+/// Decodes a byte stream as UTF-8 a chunk at a time.
+#[derive(Debug, Default)]
+struct Utf8Chunks {
+    /// The start of a character the last chunk ended in the middle of
+    pending: Vec<u8>,
+}
+
+impl Utf8Chunks {
+    /// Decodes `bytes` after whatever the last chunk left over. Unless
+    /// `last`, a character cut off at the end is held back for the next
+    /// chunk.
+    fn decode(&mut self, bytes: &[u8], last: bool) -> String {
+        self.pending.extend_from_slice(bytes);
+        let keep = if last {
+            0
+        } else {
+            incomplete_tail_len(&self.pending)
+        };
+        let split = self.pending.len() - keep;
+        let text = String::from_utf8_lossy(&self.pending[..split]).into_owned();
+        self.pending.drain(..split);
+        text
+    }
+}
+
+/// Returns how many bytes at the end of `bytes` are the start of a
+/// multi-byte character whose remaining bytes are missing.
+fn incomplete_tail_len(bytes: &[u8]) -> usize {
+    // A character is at most 4 bytes, so its lead byte is within the last 3
+    for back in 1..=bytes.len().min(3) {
+        let byte = bytes[bytes.len() - back];
+        if byte & 0xC0 == 0x80 {
+            // Continuation byte; keep looking for the lead
+            continue;
+        }
+        let len = match byte {
+            0xF0..=0xFF => 4,
+            0xE0..=0xEF => 3,
+            0xC0..=0xDF => 2,
+            _ => 1,
+        };
+        return if len > back { back } else { 0 };
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::Receiver;
+    use std::time::Duration;
+
+    fn pool() -> (FileIoPool, Receiver<FileIoEvent>) {
+        let (sender, events) = mpsc::channel();
+        let sender = std::sync::Mutex::new(sender);
+        let pool = FileIoPool::new(move |event| {
+            let _ = sender.lock().unwrap().send(event);
+        });
+        (pool, events)
+    }
+
+    fn next(events: &Receiver<FileIoEvent>) -> FileIoEvent {
+        events.recv_timeout(Duration::from_secs(10)).unwrap()
+    }
+
+    #[test]
+    fn test_utf8_chunks_keep_split_characters_whole() {
+        let text = "añ€😀b";
+        let bytes = text.as_bytes();
+        for split in 0..=bytes.len() {
+            let mut decoder = Utf8Chunks::default();
+            let mut out = decoder.decode(&bytes[..split], false);
+            out += &decoder.decode(&bytes[split..], false);
+            out += &decoder.decode(&[], true);
+            assert_eq!(out, text, "split at {}", split);
+        }
+    }
+
+    #[test]
+    fn test_utf8_chunks_replace_invalid_bytes() {
+        let mut decoder = Utf8Chunks::default();
+        let mut out = decoder.decode(b"a\xffb\xe2\x82", false);
+        assert_eq!(out, "a\u{FFFD}b");
+        // The file ends in the middle of a character
+        out = decoder.decode(&[], true);
+        assert_eq!(out, "\u{FFFD}");
+    }
+
+    #[test]
+    fn test_load_progress_percent() {
+        let mut progress = LoadProgress::new(400);
+        assert_eq!(progress.percent(), 0);
+        progress.bytes_read = 100;
+        assert_eq!(progress.percent(), 25);
+        assert_eq!(LoadProgress::new(0).percent(), 100);
+    }
+
+    #[test]
+    fn test_read_delivers_chunks_then_done() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("big.txt");
+        let line = "0123456789abcdef\n";
+        let content = line.repeat(CHUNK_SIZE / line.len() * 2 + 10);
+        std::fs::write(&path, &content).unwrap();
+
+        let (pool, events) = pool();
+        pool.read(7, path.clone());
+        let mut text = String::new();
+        let mut chunks = 0;
+        loop {
+            match next(&events) {
+                FileIoEvent::ReadChunk {
+                    tab_id,
+                    text: chunk,
+                    bytes_read,
+                    total,
+                } => {
+                    assert_eq!(tab_id, 7);
+                    assert_eq!(total, content.len() as u64);
+                    text += &chunk;
+                    assert_eq!(bytes_read, text.len() as u64);
+                    chunks += 1;
+                }
+                FileIoEvent::ReadDone {
+                    tab_id,
+                    path: done,
+                    result,
+                } => {
+                    assert_eq!((tab_id, done), (7, path));
+                    assert!(result.unwrap().is_some());
+                    break;
+                }
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+        assert_eq!(text, content);
+        assert_eq!(chunks, 3);
+    }
+
+    #[test]
+    fn test_read_of_missing_file_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let (pool, events) = pool();
+        pool.read(1, dir.path().join("missing.txt"));
+        match next(&events) {
+            FileIoEvent::ReadDone { result, .. } => assert!(result.is_err()),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_jobs_for_a_path_run_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("file.txt");
+        let (pool, events) = pool();
+        pool.write(1, path.clone(), "first".to_string());
+        pool.write(1, path.clone(), "second".to_string());
+        pool.read(2, path.clone());
+
+        for _ in 0..2 {
+            match next(&events) {
+                FileIoEvent::WriteDone { tab_id, result, .. } => {
+                    assert_eq!(tab_id, 1);
+                    assert!(result.unwrap().is_some());
+                }
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+        match next(&events) {
+            FileIoEvent::ReadChunk { tab_id, text, .. } => {
+                assert_eq!((tab_id, text.as_str()), (2, "second"));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_replaces_the_file_whole() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("file.txt");
+        std::fs::write(&path, "old contents").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o640)).unwrap();
+
+        write_replacing(&path, b"new").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o640);
+        // Only the file itself is left in the directory
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_through_symlink_replaces_target() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("target.txt");
+        let link = dir.path().join("link.txt");
+        std::fs::write(&target, "old").unwrap();
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        write_replacing(&link, b"new").unwrap();
+        assert!(std::fs::symlink_metadata(&link).unwrap().file_type().is_symlink());
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "new");
+    }
+
+    #[test]
+    fn test_write_into_missing_directory_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing").join("file.txt");
+        assert!(write_replacing(&path, b"new").is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_drop_waits_for_queued_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths: Vec<PathBuf> = (0..8)
+            .map(|i| dir.path().join(format!("file{}.txt", i)))
+            .collect();
+        let content = "x".repeat(CHUNK_SIZE);
+        let (pool, _events) = pool();
+        for path in &paths {
+            pool.write(1, path.clone(), content.clone());
+        }
+        drop(pool);
+        for path in &paths {
+            assert_eq!(std::fs::read_to_string(path).unwrap(), content);
+        }
+    }
+
+    #[test]
+    fn test_background_read_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let small = dir.path().join("small.txt");
+        let large = dir.path().join("large.txt");
+        std::fs::write(&small, "hello").unwrap();
+        std::fs::write(&large, vec![b'x'; BACKGROUND_IO_THRESHOLD as usize]).unwrap();
+        assert_eq!(background_read_size(&small), None);
+        assert_eq!(background_read_size(&large), Some(BACKGROUND_IO_THRESHOLD));
+        assert_eq!(background_read_size(dir.path()), None);
+        assert_eq!(background_read_size(&dir.path().join("missing.txt")), None);
+    }
+}
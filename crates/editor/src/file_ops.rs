@@ -0,0 +1,142 @@
+// Chunk: docs/chunks/picker_file_ops - Filesystem operations behind the picker's file actions
+//!
+//! File management for the file picker.
+//!
+//! These functions back the picker's create (Cmd+Return), rename, delete and
+//! reveal actions. They take absolute paths and report failures as
+//! `io::Error`; `EditorState` turns those into status messages.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+/// Creates `path` along with any missing parent directories.
+///
+/// With `as_directory` the path itself is created as a directory; otherwise
+/// an empty file is created. Fails with `AlreadyExists` if a file is requested
+/// and one already exists at `path`.
+pub fn create_path(path: &Path, as_directory: bool) -> io::Result<()> {
+    if as_directory {
+        return fs::create_dir_all(path);
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map(|_| ())
+}
+
+/// Renames `from` to `to`, creating any missing parent directories of `to`.
+///
+/// Refuses to overwrite: fails with `AlreadyExists` if `to` exists.
+pub fn rename_path(from: &Path, to: &Path) -> io::Result<()> {
+    if to.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", to.display()),
+        ));
+    }
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(from, to)
+}
+
+/// Deletes the file or directory (recursively) at `path`.
+pub fn delete_path(path: &Path) -> io::Result<()> {
+    if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// Selects `path` in a new Finder window (`open -R`).
+pub fn reveal_in_finder(path: &Path) -> io::Result<()> {
+    Command::new("open").arg("-R").arg(path).spawn().map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_create_path_creates_parent_directories() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("src/nested/mod.rs");
+
+        create_path(&path, false).unwrap();
+
+        assert!(path.is_file());
+    }
+
+    #[test]
+    fn test_create_path_directory() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("a/b/c");
+
+        create_path(&path, true).unwrap();
+
+        assert!(path.is_dir());
+    }
+
+    #[test]
+    fn test_create_path_refuses_existing_file() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("main.rs");
+        fs::write(&path, "fn main() {}").unwrap();
+
+        let err = create_path(&path, false).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fn main() {}");
+    }
+
+    #[test]
+    fn test_rename_path_into_new_directory() {
+        let temp = TempDir::new().unwrap();
+        let from = temp.path().join("old.rs");
+        let to = temp.path().join("moved/new.rs");
+        fs::write(&from, "x").unwrap();
+
+        rename_path(&from, &to).unwrap();
+
+        assert!(!from.exists());
+        assert_eq!(fs::read_to_string(&to).unwrap(), "x");
+    }
+
+    #[test]
+    fn test_rename_path_refuses_to_overwrite() {
+        let temp = TempDir::new().unwrap();
+        let from = temp.path().join("a.rs");
+        let to = temp.path().join("b.rs");
+        fs::write(&from, "a").unwrap();
+        fs::write(&to, "b").unwrap();
+
+        let err = rename_path(&from, &to).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(&to).unwrap(), "b");
+    }
+
+    #[test]
+    fn test_delete_path_file_and_directory() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("a.rs");
+        let dir = temp.path().join("dir");
+        fs::write(&file, "a").unwrap();
+        fs::create_dir(&dir).unwrap();
+        fs::write(dir.join("b.rs"), "b").unwrap();
+
+        delete_path(&file).unwrap();
+        delete_path(&dir).unwrap();
+
+        assert!(!file.exists());
+        assert!(!dir.exists());
+    }
+}
@@ -0,0 +1,551 @@
+// Chunk: docs/chunks/file_view_mode - Memory-mapped view of giant files
+//!
+//! Read-only views of files too large to edit.
+//!
+//! A [`FileView`] shows a file by memory-mapping it rather than reading it
+//! into a `TextBuffer`. Opening a multi-gigabyte log costs one pass to find
+//! where its lines start; after that only the pages drawn or searched are
+//! read from disk, and lines are decoded as they are drawn. The view has no
+//! cursor: find-in-file selects the match it lands on, and editing means
+//! loading the file into an ordinary buffer first.
+//!
+//! Line starts are recorded every [`LINE_STRIDE`] lines, so the index of a
+//! file with 30 million lines takes about a megabyte. A line between two
+//! recorded starts is found by scanning forward from the one before it.
+//!
+//! Reading a mapped page past the end of a file that another process
+//! truncated kills the editor with SIGBUS, so the file's size is checked
+//! before each batch of reads and nothing past it is touched. The view
+//! remaps the file when the watcher reports the change.
+
+use std::borrow::Cow;
+use std::fs::File;
+use std::io;
+use std::ops::Range;
+use std::os::fd::AsRawFd;
+use std::path::{Path, PathBuf};
+
+use lite_edit_buffer::{BufferView, CursorInfo, DirtyLines, Position, StyledLine};
+
+/// Files at least this large open as a read-only view.
+pub const VIEW_MODE_THRESHOLD: u64 = 64 << 20;
+
+/// A line start is recorded every this many lines.
+const LINE_STRIDE: usize = 256;
+
+/// Bytes counted at a time while indexing; blocks with no recorded line
+/// start in them are only counted, not walked.
+const SCAN_BLOCK: usize = 4096;
+
+/// Lines are cut off after this many bytes, well past the right edge of
+/// any pane.
+const MAX_LINE_BYTES: usize = 4096;
+
+/// Returns the size of the file at `path` if it is large enough to open as
+/// a view.
+pub fn view_mode_size(path: &Path) -> Option<u64> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let size = metadata.len();
+    (metadata.is_file() && size >= VIEW_MODE_THRESHOLD).then_some(size)
+}
+
+// =============================================================================
+// Mapping
+// =============================================================================
+
+/// A file mapped read-only into memory.
+struct Mapping {
+    ptr: *mut libc::c_void,
+    len: usize,
+}
+
+// The mapping is never written through and is unmapped only on drop.
+unsafe impl Send for Mapping {}
+unsafe impl Sync for Mapping {}
+
+impl Mapping {
+    fn new(file: &File) -> io::Result<Self> {
+        let len = file.metadata()?.len() as usize;
+        if len == 0 {
+            // mmap refuses empty mappings
+            return Ok(Self {
+                ptr: std::ptr::null_mut(),
+                len: 0,
+            });
+        }
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_SHARED,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { ptr, len })
+    }
+
+    fn bytes(&self) -> &[u8] {
+        if self.len == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            unsafe {
+                libc::munmap(self.ptr, self.len);
+            }
+        }
+    }
+}
+
+// =============================================================================
+// LineIndex
+// =============================================================================
+
+/// Where every `LINE_STRIDE`th line starts.
+#[derive(Debug, Clone, PartialEq)]
+struct LineIndex {
+    /// `starts[i]` is the byte offset of line `i * LINE_STRIDE`.
+    starts: Vec<usize>,
+    line_count: usize,
+}
+
+impl LineIndex {
+    fn new(bytes: &[u8]) -> Self {
+        let mut index = Self {
+            starts: vec![0],
+            line_count: 1,
+        };
+        index.scan(bytes, 0, 0);
+        index
+    }
+
+    /// Re-indexes `bytes` after the file grew, keeping the starts recorded
+    /// before the last one.
+    fn extend(&mut self, bytes: &[u8]) {
+        let last = self.starts.len() - 1;
+        let offset = self.starts[last];
+        self.scan(bytes, last * LINE_STRIDE, offset);
+    }
+
+    /// Counts lines from `offset`, where `line` starts, to the end.
+    fn scan(&mut self, bytes: &[u8], mut line: usize, mut offset: usize) {
+        while offset < bytes.len() {
+            let end = (offset + SCAN_BLOCK).min(bytes.len());
+            let block = &bytes[offset..end];
+            let next_start = (line / LINE_STRIDE + 1) * LINE_STRIDE;
+            let newlines = block.iter().filter(|&&b| b == b'\n').count();
+            if line + newlines < next_start {
+                line += newlines;
+            } else {
+                for (i, _) in block.iter().enumerate().filter(|(_, &b)| b == b'\n') {
+                    line += 1;
+                    if line.is_multiple_of(LINE_STRIDE) {
+                        self.starts.push(offset + i + 1);
+                    }
+                }
+            }
+            offset = end;
+        }
+        self.line_count = line + 1;
+    }
+
+    /// Returns the byte offset `line` starts at, or `None` if `bytes` was
+    /// cut short before it.
+    fn line_start(&self, bytes: &[u8], line: usize) -> Option<usize> {
+        let mut start = self.starts[line / LINE_STRIDE];
+        for _ in 0..line % LINE_STRIDE {
+            if start > bytes.len() {
+                return None;
+            }
+            start = line_end(bytes, start) + 1;
+        }
+        (start <= bytes.len()).then_some(start)
+    }
+}
+
+/// Returns the offset of the newline ending the line at `start`, or the end
+/// of `bytes` for the last line.
+fn line_end(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |i| start + i)
+}
+
+// =============================================================================
+// FileView
+// =============================================================================
+
+/// A read-only, memory-mapped view of a file.
+pub struct FileView {
+    path: PathBuf,
+    /// The mapped file, kept open to check its size before reading.
+    file: File,
+    map: Mapping,
+    index: LineIndex,
+    /// The find match shown as selected.
+    selection: Option<(Position, Position)>,
+    dirty: DirtyLines,
+}
+
+impl FileView {
+    /// Maps the file at `path` and indexes its lines.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let map = Mapping::new(&file)?;
+        let index = LineIndex::new(map.bytes());
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            map,
+            index,
+            selection: None,
+            dirty: DirtyLines::None,
+        })
+    }
+
+    /// Maps the file again after it changed on disk.
+    ///
+    /// A file that only grew, like a log being written, keeps the lines
+    /// already indexed; anything else is indexed from scratch.
+    pub fn reload(&mut self) -> io::Result<()> {
+        let file = File::open(&self.path)?;
+        let map = Mapping::new(&file)?;
+        if map.len >= self.map.len {
+            self.index.extend(map.bytes());
+        } else {
+            self.index = LineIndex::new(map.bytes());
+        }
+        self.file = file;
+        self.map = map;
+        if self
+            .selection
+            .is_some_and(|(_, end)| end.line >= self.index.line_count)
+        {
+            self.selection = None;
+        }
+        self.dirty = DirtyLines::FromLineToEnd(0);
+        Ok(())
+    }
+
+    /// The file this view shows.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The size of the file in bytes, as mapped.
+    pub fn len(&self) -> u64 {
+        self.map.len as u64
+    }
+
+    /// Returns true if the file is empty.
+    pub fn is_empty(&self) -> bool {
+        self.map.len == 0
+    }
+
+    /// Selects `selection`, or clears the selection.
+    pub fn set_selection(&mut self, selection: Option<(Position, Position)>) {
+        self.selection = selection;
+        self.dirty = DirtyLines::FromLineToEnd(0);
+    }
+
+    /// Returns the mapped bytes that are still in the file.
+    ///
+    /// Called once per batch of reads: the file may have been truncated
+    /// since it was mapped, and touching a page past its end raises SIGBUS.
+    fn bytes(&self) -> &[u8] {
+        let bytes = self.map.bytes();
+        let len = self.file.metadata().map_or(0, |m| m.len());
+        &bytes[..usize::try_from(len).map_or(bytes.len(), |len| len.min(bytes.len()))]
+    }
+
+    /// Returns the byte ranges of the lines of `bytes` from `line` on,
+    /// newlines excluded.
+    fn line_ranges<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        line: usize,
+    ) -> impl Iterator<Item = Range<usize>> + 'a {
+        let mut next = (line < self.index.line_count)
+            .then(|| self.index.line_start(bytes, line))
+            .flatten();
+        std::iter::from_fn(move || {
+            let start = next?;
+            let end = line_end(bytes, start);
+            next = (end < bytes.len()).then_some(end + 1);
+            Some(start..end)
+        })
+    }
+
+    /// Returns the text of the line at `range` as drawn: without a
+    /// carriage return before the newline, and cut off after
+    /// `MAX_LINE_BYTES`.
+    fn line_text<'a>(bytes: &'a [u8], range: Range<usize>) -> Cow<'a, str> {
+        let mut bytes = &bytes[range];
+        bytes = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+        String::from_utf8_lossy(&bytes[..bytes.len().min(MAX_LINE_BYTES)])
+    }
+
+    /// Finds the first match at or after `from`, wrapping around to the
+    /// start of the file, as find-in-file does in a buffer.
+    ///
+    /// `find_in_line` returns the columns the first match in a line starts
+    /// and ends at, searching from a column. Each line is searched as drawn,
+    /// so matches past where a long line is cut off aren't found.
+    pub fn find(
+        &self,
+        from: Position,
+        find_in_line: impl Fn(&str, usize) -> Option<(usize, usize)>,
+    ) -> Option<(Position, Position)> {
+        let bytes = self.bytes();
+        let find_at = |line: usize, range: Range<usize>, from_col: usize| {
+            let (start, end) = find_in_line(&Self::line_text(bytes, range), from_col)?;
+            Some((Position::new(line, start), Position::new(line, end)))
+        };
+
+        let from_line = from.line.min(self.index.line_count - 1);
+        let forward = self
+            .line_ranges(bytes, from_line)
+            .enumerate()
+            .find_map(|(i, range)| {
+                let from_col = if i == 0 { from.col } else { 0 };
+                find_at(from_line + i, range, from_col)
+            });
+        forward.or_else(|| {
+            self.line_ranges(bytes, 0)
+                .take(from_line + 1)
+                .enumerate()
+                .find_map(|(line, range)| find_at(line, range, 0))
+                .filter(|(start, _)| *start < from)
+        })
+    }
+}
+
+impl BufferView for FileView {
+    fn line_count(&self) -> usize {
+        self.index.line_count
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        let bytes = self.bytes();
+        let range = self.line_ranges(bytes, line).next()?;
+        Some(StyledLine::plain(Self::line_text(bytes, range)))
+    }
+
+    // Finds the first line once and walks on from there
+    fn styled_lines(&self, lines: Range<usize>) -> Vec<StyledLine> {
+        let bytes = self.bytes();
+        self.line_ranges(bytes, lines.start)
+            .take(lines.len())
+            .map(|range| StyledLine::plain(Self::line_text(bytes, range)))
+            .collect()
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        let bytes = self.bytes();
+        self.line_ranges(bytes, line)
+            .next()
+            .map_or(0, |range| Self::line_text(bytes, range).chars().count())
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        None
+    }
+
+    fn selection_range(&self) -> Option<(Position, Position)> {
+        self.selection
+    }
+
+    fn wraps_lines(&self) -> bool {
+        false
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn view_of(content: &[u8]) -> (tempfile::TempDir, FileView) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("big.log");
+        std::fs::write(&path, content).unwrap();
+        let view = FileView::open(&path).unwrap();
+        (dir, view)
+    }
+
+    fn text(line: &StyledLine) -> String {
+        line.spans.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    fn numbered_lines(count: usize) -> String {
+        (0..count).map(|i| format!("line {}\n", i)).collect()
+    }
+
+    #[test]
+    fn test_lines_across_recorded_starts() {
+        let content = numbered_lines(LINE_STRIDE * 3 + 7);
+        let (_dir, view) = view_of(content.as_bytes());
+
+        // The trailing newline ends in an empty last line, as in a buffer
+        assert_eq!(view.line_count(), LINE_STRIDE * 3 + 8);
+        for line in [0, 1, LINE_STRIDE - 1, LINE_STRIDE, 2 * LINE_STRIDE + 5, 3 * LINE_STRIDE + 6] {
+            let styled = view.styled_line(line).unwrap();
+            assert_eq!(text(&styled), format!("line {}", line));
+        }
+        assert_eq!(text(&view.styled_line(LINE_STRIDE * 3 + 7).unwrap()), "");
+        assert!(view.styled_line(LINE_STRIDE * 3 + 8).is_none());
+        assert_eq!(view.line_len(LINE_STRIDE + 10), "line 266".len());
+    }
+
+    #[test]
+    fn test_styled_lines_batch_matches_single_lines() {
+        let content = numbered_lines(LINE_STRIDE + 20);
+        let (_dir, view) = view_of(content.as_bytes());
+
+        let batch = view.styled_lines(LINE_STRIDE - 3..LINE_STRIDE + 3);
+        let single: Vec<_> = (LINE_STRIDE - 3..LINE_STRIDE + 3)
+            .map(|line| view.styled_line(line).unwrap())
+            .collect();
+        assert_eq!(batch, single);
+
+        // The batch stops at the end of the file
+        let tail = view.styled_lines(LINE_STRIDE + 19..LINE_STRIDE + 40);
+        assert_eq!(tail.len(), 2);
+    }
+
+    #[test]
+    fn test_crlf_invalid_utf8_and_long_lines() {
+        let mut content = b"first\r\nbad \xff byte\n".to_vec();
+        content.extend(std::iter::repeat_n(b'x', MAX_LINE_BYTES * 2));
+        let (_dir, view) = view_of(&content);
+
+        assert_eq!(text(&view.styled_line(0).unwrap()), "first");
+        assert_eq!(text(&view.styled_line(1).unwrap()), "bad \u{fffd} byte");
+        assert_eq!(view.line_len(2), MAX_LINE_BYTES);
+        assert!(!view.is_editable());
+        assert!(!view.wraps_lines());
+        assert!(view.cursor_info().is_none());
+    }
+
+    #[test]
+    fn test_empty_file() {
+        let (_dir, view) = view_of(b"");
+        assert!(view.is_empty());
+        assert_eq!(view.line_count(), 1);
+        assert_eq!(text(&view.styled_line(0).unwrap()), "");
+        assert!(view.find(Position::new(0, 0), |_, _| None).is_none());
+    }
+
+    #[test]
+    fn test_find_forward_and_wrapping() {
+        let content = numbered_lines(LINE_STRIDE * 2);
+        let (_dir, view) = view_of(content.as_bytes());
+        let find = |query: &'static str| {
+            move |line: &str, from_col: usize| {
+                let start = from_col + line.get(from_col..)?.find(query)?;
+                Some((start, start + query.len()))
+            }
+        };
+
+        let found = view.find(Position::new(0, 0), find("line 300"));
+        assert_eq!(found, Some((Position::new(300, 0), Position::new(300, 8))));
+
+        // Matches don't span lines
+        assert!(view.find(Position::new(0, 0), find("3\nline")).is_none());
+
+        // From past the only match, the search wraps around to it
+        let found = view.find(Position::new(400, 0), find("line 3"));
+        assert_eq!(found, Some((Position::new(3, 0), Position::new(3, 6))));
+
+        // Within the starting line, matches before the column are skipped
+        let found = view.find(Position::new(10, 4), find("e"));
+        assert_eq!(found, Some((Position::new(11, 3), Position::new(11, 4))));
+    }
+
+    #[test]
+    fn test_reload_after_the_file_grew_or_shrank() {
+        let content = numbered_lines(LINE_STRIDE + 10);
+        let (dir, mut view) = view_of(content.as_bytes());
+        let path = dir.path().join("big.log");
+        view.set_selection(Some((Position::new(5, 0), Position::new(5, 4))));
+        view.take_dirty();
+
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(numbered_lines(LINE_STRIDE * 2).as_bytes()).unwrap();
+        drop(file);
+        view.reload().unwrap();
+
+        let grown = LineIndex::new(std::fs::read(&path).unwrap().as_slice());
+        assert_eq!(view.index, grown);
+        assert_eq!(view.line_count(), LINE_STRIDE * 3 + 11);
+        assert_eq!(text(&view.styled_line(LINE_STRIDE + 10).unwrap()), "line 0");
+        assert_eq!(view.take_dirty(), DirtyLines::FromLineToEnd(0));
+        assert!(view.selection_range().is_some());
+
+        std::fs::write(&path, "short\n").unwrap();
+        view.reload().unwrap();
+        assert_eq!(view.line_count(), 2);
+        assert_eq!(view.len(), 6);
+        assert!(view.selection_range().is_none());
+    }
+
+    #[test]
+    fn test_reads_stop_at_the_end_of_a_truncated_file() {
+        let content = numbered_lines(LINE_STRIDE * 40);
+        let (dir, view) = view_of(content.as_bytes());
+        let path = dir.path().join("big.log");
+
+        // Pages past the new end are gone; reading them would raise SIGBUS
+        let file = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+        file.set_len(14).unwrap();
+
+        assert_eq!(view.line_count(), LINE_STRIDE * 40 + 1);
+        let lines = view.styled_lines(0..view.line_count());
+        let texts: Vec<_> = lines.iter().map(text).collect();
+        assert_eq!(texts, ["line 0", "line 1", ""]);
+        assert!(view.styled_line(LINE_STRIDE * 30).is_none());
+        assert_eq!(view.line_len(LINE_STRIDE * 30), 0);
+        let found = view.find(Position::new(LINE_STRIDE * 30, 0), |line, _| {
+            line.find("line 1").map(|start| (start, start + 6))
+        });
+        assert_eq!(found, Some((Position::new(1, 0), Position::new(1, 6))));
+    }
+
+    #[test]
+    fn test_view_mode_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let small = dir.path().join("small.log");
+        std::fs::write(&small, "tiny").unwrap();
+        assert_eq!(view_mode_size(&small), None);
+        assert_eq!(view_mode_size(dir.path()), None);
+        assert_eq!(view_mode_size(&dir.path().join("missing.log")), None);
+
+        let big = dir.path().join("big.log");
+        let file = File::create(&big).unwrap();
+        file.set_len(VIEW_MODE_THRESHOLD).unwrap();
+        assert_eq!(view_mode_size(&big), Some(VIEW_MODE_THRESHOLD));
+    }
+}
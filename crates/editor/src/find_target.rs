@@ -10,6 +10,11 @@
 //! the find strip is active won't propagate to lower layers.
 //!
 //! After handling, check `pending_outcome` to see what action the user took.
+//!
+//! [`FindOptions`] holds the case-sensitivity and whole-word toggles that the
+//! search honours.
+
+use std::borrow::Cow;
 
 use crate::context::EditorContext;
 use crate::focus::{FocusLayer, FocusTarget, Handled};
@@ -27,6 +32,88 @@ pub enum FindOutcome {
     FindNext,
 }
 
+// Chunk: docs/chunks/find_options - Case-sensitivity and whole-word toggles
+/// How the find query is matched, toggled from the find strip.
+///
+/// The default is case-insensitive substring matching.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FindOptions {
+    /// Match letter case exactly (Option+C)
+    pub case_sensitive: bool,
+    /// Only match whole words (Option+W)
+    pub whole_word: bool,
+}
+
+impl FindOptions {
+    /// Returns `text` as it should be compared: lowercased, unless matching
+    /// is case-sensitive.
+    pub fn fold<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.case_sensitive {
+            Cow::Borrowed(text)
+        } else {
+            Cow::Owned(text.to_lowercase())
+        }
+    }
+
+    /// Returns the byte offset of the first match of `query` in `text` that
+    /// starts at or after `from`.
+    ///
+    /// Both strings must already be folded. With `whole_word`, a match must
+    /// not have a word character (alphanumeric or `_`) right before or after
+    /// it.
+    pub fn find_from(&self, text: &str, query: &str, from: usize) -> Option<usize> {
+        if query.is_empty() {
+            return None;
+        }
+        let mut pos = from;
+        while pos <= text.len() {
+            let start = pos + text.get(pos..)?.find(query)?;
+            let end = start + query.len();
+            if !self.whole_word || is_word_boundary(text, start, end) {
+                return Some(start);
+            }
+            // Retry one character further on
+            pos = start + text[start..].chars().next().map_or(1, char::len_utf8);
+        }
+        None
+    }
+
+    /// Returns the byte offsets of every match of `query` in `text`, in
+    /// order. Both strings must already be folded; matches don't overlap.
+    pub fn find_all(&self, text: &str, query: &str) -> Vec<usize> {
+        let mut offsets = Vec::new();
+        let mut pos = 0;
+        while let Some(start) = self.find_from(text, query, pos) {
+            offsets.push(start);
+            pos = start + query.len();
+        }
+        offsets
+    }
+
+    // Chunk: docs/chunks/file_view_mode - One line at a time, for file views
+    /// Returns the columns the first match of `query` in `line`, at or after
+    /// column `from_col`, starts and ends at. Neither string is folded yet.
+    pub fn find_in_line(&self, line: &str, query: &str, from_col: usize) -> Option<(usize, usize)> {
+        let line = self.fold(line);
+        let query = self.fold(query);
+        let from = line
+            .char_indices()
+            .nth(from_col)
+            .map_or(line.len(), |(i, _)| i);
+        let start = self.find_from(&line, &query, from)?;
+        let col = line[..start].chars().count();
+        Some((col, col + query.chars().count()))
+    }
+}
+
+/// Returns true if `text[start..end]` has no word character on either side.
+fn is_word_boundary(text: &str, start: usize, end: usize) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    !before.is_some_and(is_word) && !after.is_some_and(is_word)
+}
+
 /// Focus target for the find-in-file strip.
 ///
 /// This wraps a [`MiniBuffer`] and provides the FocusTarget interface.
@@ -335,4 +422,45 @@ mod tests {
         assert_eq!(target.query(), "hello");
         assert!(target.query_changed());
     }
+
+    // Chunk: docs/chunks/find_options - Case and whole-word matching
+    #[test]
+    fn test_find_options_case() {
+        let insensitive = FindOptions::default();
+        assert_eq!(insensitive.fold("Hello"), "hello");
+        let text = insensitive.fold("say Hello");
+        let query = insensitive.fold("HELLO");
+        assert_eq!(insensitive.find_from(&text, &query, 0), Some(4));
+
+        let sensitive = FindOptions {
+            case_sensitive: true,
+            ..Default::default()
+        };
+        assert_eq!(sensitive.find_from("say Hello", "hello", 0), None);
+        assert_eq!(sensitive.find_from("say Hello hello", "hello", 0), Some(10));
+    }
+
+    #[test]
+    fn test_find_options_whole_word() {
+        let options = FindOptions {
+            whole_word: true,
+            ..Default::default()
+        };
+        // "cat" in "concat" and "cats" is skipped
+        assert_eq!(options.find_from("concat cats cat.", "cat", 0), Some(12));
+        assert_eq!(options.find_from("my_cat cat", "cat", 0), Some(7));
+        assert_eq!(options.find_all("cat concat cat", "cat"), vec![0, 11]);
+
+        let substring = FindOptions::default();
+        assert_eq!(substring.find_all("cat concat cat", "cat"), vec![0, 7, 11]);
+    }
+
+    #[test]
+    fn test_find_options_in_line_columns() {
+        let options = FindOptions::default();
+        assert_eq!(options.find_in_line("né Cat cat", "CAT", 0), Some((3, 6)));
+        assert_eq!(options.find_in_line("né Cat cat", "CAT", 4), Some((7, 10)));
+        assert_eq!(options.find_in_line("né Cat cat", "CAT", 8), None);
+        assert_eq!(options.find_in_line("short", "t", 99), None);
+    }
 }
@@ -15,7 +15,7 @@ use objc2_core_foundation::{
     CFData, CFIndex, CFRange, CFRetained, CFString, CGAffineTransform, CGFloat, CGSize,
 };
 use objc2_core_graphics::{CGDataProvider, CGFont};
-use objc2_core_text::{CTFont, CTFontOrientation};
+use objc2_core_text::{CTFont, CTFontOrientation, CTFontSymbolicTraits};
 
 // =============================================================================
 // Glyph Source (Fallback Support)
@@ -59,6 +59,91 @@ pub struct FontMetrics {
     pub point_size: f64,
 }
 
+// Chunk: docs/chunks/color_emoji_wide_glyphs - Color glyph font detection
+/// `kCTFontTraitColorGlyphs`: the font contains color bitmap or SVG glyphs
+const COLOR_GLYPHS_TRAIT: u32 = 1 << 13;
+
+// Chunk: docs/chunks/syntax_style_modifiers - Bold and italic faces
+/// `kCTFontTraitItalic`
+const ITALIC_TRAIT: u32 = 1 << 0;
+/// `kCTFontTraitBold`
+const BOLD_TRAIT: u32 = 1 << 1;
+
+/// The weight and slant a glyph is drawn with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontFace {
+    Regular,
+    Bold,
+    Italic,
+    BoldItalic,
+}
+
+impl FontFace {
+    /// Returns the face for a span's bold and italic attributes.
+    pub fn for_style(bold: bool, italic: bool) -> Self {
+        match (bold, italic) {
+            (false, false) => FontFace::Regular,
+            (true, false) => FontFace::Bold,
+            (false, true) => FontFace::Italic,
+            (true, true) => FontFace::BoldItalic,
+        }
+    }
+
+    /// Returns true for the bold faces
+    pub fn is_bold(self) -> bool {
+        matches!(self, FontFace::Bold | FontFace::BoldItalic)
+    }
+
+    /// Returns true for the italic faces
+    pub fn is_italic(self) -> bool {
+        matches!(self, FontFace::Italic | FontFace::BoldItalic)
+    }
+
+    /// Core Text symbolic traits for this face
+    fn traits(self) -> u32 {
+        let bold = if self.is_bold() { BOLD_TRAIT } else { 0 };
+        let italic = if self.is_italic() { ITALIC_TRAIT } else { 0 };
+        bold | italic
+    }
+}
+
+// =============================================================================
+// Font Size Zoom
+// =============================================================================
+
+// Chunk: docs/chunks/font_config_zoom - Runtime font size changes
+/// Smallest font size zoom-out will reach, in points
+pub const MIN_FONT_SIZE: f64 = 6.0;
+/// Largest font size zoom-in will reach, in points
+pub const MAX_FONT_SIZE: f64 = 72.0;
+/// Points added or removed by one zoom step
+const FONT_SIZE_STEP: f64 = 1.0;
+
+/// A font size change requested from the keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontZoom {
+    /// Grow the font one step (Cmd+Plus)
+    In,
+    /// Shrink the font one step (Cmd+Minus)
+    Out,
+    /// Return to the configured size (Cmd+0)
+    Reset,
+}
+
+impl FontZoom {
+    /// Returns the point size after applying this zoom to `current`.
+    ///
+    /// `configured` is the size from the user's config, which `Reset` returns to.
+    /// Steps are clamped to `[MIN_FONT_SIZE, MAX_FONT_SIZE]`.
+    pub fn apply(self, current: f64, configured: f64) -> f64 {
+        match self {
+            FontZoom::In => (current + FONT_SIZE_STEP).min(MAX_FONT_SIZE).max(current),
+            FontZoom::Out => (current - FONT_SIZE_STEP).max(MIN_FONT_SIZE).min(current),
+            FontZoom::Reset => configured,
+        }
+    }
+}
+
 // =============================================================================
 // Font
 // =============================================================================
@@ -181,6 +266,24 @@ impl Font {
         Self { ct_font, metrics }
     }
 
+    // Chunk: docs/chunks/font_config_zoom - System font lookup by family
+    /// Loads an installed font by family or PostScript name, scaled for the display
+    ///
+    /// Core Text substitutes a default font when a name isn't installed, so the
+    /// loaded font's family and PostScript names are checked against `name`.
+    /// Returns `None` when neither matches (case-insensitively).
+    pub fn from_family(name: &str, point_size: f64, scale_factor: f64) -> Option<Self> {
+        let font = Self::new(name, point_size, scale_factor);
+
+        let family = unsafe { font.ct_font.family_name() }.to_string();
+        let postscript = unsafe { font.ct_font.post_script_name() }.to_string();
+        if family.eq_ignore_ascii_case(name) || postscript.eq_ignore_ascii_case(name) {
+            Some(font)
+        } else {
+            None
+        }
+    }
+
     /// Returns the Core Text font
     pub fn ct_font(&self) -> &CTFont {
         &self.ct_font
@@ -204,6 +307,36 @@ impl Font {
         (ascent, descent, line_height)
     }
 
+    // Chunk: docs/chunks/color_emoji_wide_glyphs - Color glyph font detection
+    /// Returns true if the font draws its glyphs in color (e.g. Apple Color Emoji).
+    ///
+    /// Glyphs from such fonts go into the atlas's RGBA page so their colors
+    /// survive; rasterizing them as coverage would flatten them to a silhouette.
+    pub fn has_color_glyphs(ct_font: &CTFont) -> bool {
+        let traits = unsafe { ct_font.symbolic_traits() };
+        traits.0 & COLOR_GLYPHS_TRAIT != 0
+    }
+
+    // Chunk: docs/chunks/syntax_style_modifiers - Bold and italic faces
+    /// Returns the family's own font for `face`, at the same size.
+    ///
+    /// Returns `None` for `FontFace::Regular`, and when the family has no
+    /// such face, as with the bundled font, which is loaded from data without
+    /// its sibling faces. Callers synthesize the face in that case.
+    pub fn face(&self, face: FontFace) -> Option<CFRetained<CTFont>> {
+        if face == FontFace::Regular {
+            return None;
+        }
+        let traits = CTFontSymbolicTraits(face.traits());
+        let styled = unsafe {
+            self.ct_font
+                .copy_with_symbolic_traits(0.0, std::ptr::null(), traits, traits)
+        }?;
+        // Core Text may hand back a face that lacks some of the traits
+        let actual = unsafe { styled.symbolic_traits() }.0;
+        (actual & face.traits() == face.traits()).then_some(styled)
+    }
+
     /// Gets the advance width for a representative monospace character
     fn get_advance_width(ct_font: &CTFont) -> f64 {
         // Use 'M' as the representative character
@@ -369,7 +502,7 @@ impl Font {
 
     // Chunk: docs/chunks/font_fallback_rendering - Helper for fallback glyph lookup
     /// Gets a glyph ID from a specific font (helper for fallback lookup)
-    fn glyph_id_from_font(&self, font: &CTFont, c: char) -> Option<u16> {
+    pub fn glyph_id_from_font(&self, font: &CTFont, c: char) -> Option<u16> {
         let code = c as u32;
 
         if code <= 0xFFFF {
@@ -523,6 +656,56 @@ mod tests {
         );
     }
 
+    // Chunk: docs/chunks/font_config_zoom - System font lookup by family
+    #[test]
+    fn test_from_family_finds_installed_font() {
+        let font = Font::from_family("Menlo", 14.0, 1.0);
+        assert!(font.is_some(), "Menlo should be found by family name");
+
+        let font = Font::from_family("menlo-regular", 14.0, 1.0);
+        assert!(font.is_some(), "PostScript names should match case-insensitively");
+    }
+
+    #[test]
+    fn test_from_family_rejects_missing_font() {
+        assert!(Font::from_family("No Such Font Family 12345", 14.0, 1.0).is_none());
+    }
+
+    // Chunk: docs/chunks/font_config_zoom - Runtime font size changes
+    #[test]
+    fn test_font_zoom_steps() {
+        assert_eq!(FontZoom::In.apply(14.0, 14.0), 15.0);
+        assert_eq!(FontZoom::Out.apply(14.0, 14.0), 13.0);
+        assert_eq!(FontZoom::Reset.apply(20.0, 14.0), 14.0);
+    }
+
+    #[test]
+    fn test_font_zoom_clamps() {
+        assert_eq!(FontZoom::In.apply(MAX_FONT_SIZE, 14.0), MAX_FONT_SIZE);
+        assert_eq!(FontZoom::Out.apply(MIN_FONT_SIZE, 14.0), MIN_FONT_SIZE);
+        // A configured size outside the zoom range never snaps back into it
+        assert_eq!(FontZoom::In.apply(80.0, 80.0), 80.0);
+        assert_eq!(FontZoom::Out.apply(4.0, 4.0), 4.0);
+    }
+
+    // Chunk: docs/chunks/syntax_style_modifiers - Bold and italic faces
+    #[test]
+    fn test_font_face_for_style() {
+        assert_eq!(FontFace::for_style(false, false), FontFace::Regular);
+        assert_eq!(FontFace::for_style(true, true), FontFace::BoldItalic);
+        assert!(FontFace::Bold.is_bold() && !FontFace::Bold.is_italic());
+        assert!(FontFace::Italic.is_italic() && !FontFace::Italic.is_bold());
+    }
+
+    #[test]
+    fn test_menlo_has_bold_and_italic_faces() {
+        let font = Font::new("Menlo-Regular", 14.0, 1.0);
+        assert!(font.face(FontFace::Regular).is_none());
+        for face in [FontFace::Bold, FontFace::Italic, FontFace::BoldItalic] {
+            assert!(font.face(face).is_some(), "Menlo has no {:?} face", face);
+        }
+    }
+
     #[test]
     fn test_font_scaling() {
         let font_1x = Font::new("Menlo-Regular", 14.0, 1.0);
@@ -0,0 +1,268 @@
+// Chunk: docs/chunks/format_on_save - External formatters run on save
+//!
+//! Formatting a buffer with an external command when it is saved.
+//!
+//! Formatters are configured per language in the `"save"` section of the user
+//! config (see `SaveConfig::formatter_for`). A formatter reads the file's
+//! contents on stdin and writes the formatted contents to stdout; `$FILE` in
+//! its arguments is replaced with the file's path, for formatters such as
+//! prettier that pick their parser from it:
+//!
+//! ```json
+//! "save": {
+//!   "formatters": {
+//!     "rust": ["rustfmt", "--edition", "2021"],
+//!     "go": ["gofmt"],
+//!     "typescript": ["prettier", "--stdin-filepath", "$FILE"]
+//!   }
+//! }
+//! ```
+//!
+//! Rather than replacing the buffer, [`line_edits`] diffs the output against
+//! it and [`apply_line_edits`] rewrites only the lines that changed, so the
+//! cursor stays on the text it was on.
+
+use std::io::{Read, Write};
+use std::ops::Range;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use lite_edit_buffer::{Position, TextBuffer};
+use similar::{DiffTag, TextDiff};
+
+/// Placeholder in formatter arguments for the path of the file being saved.
+pub const FILE_PLACEHOLDER: &str = "$FILE";
+
+/// How long a formatter may run before it is killed and the file is saved
+/// unformatted.
+const FORMAT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Runs the formatter `command` (program then arguments) on `content`, the
+/// contents of `path`, and returns the formatted contents.
+///
+/// The formatter runs in `path`'s directory, so it finds the project's
+/// configuration file. An error describes a formatter that couldn't start,
+/// failed, timed out or printed something other than UTF-8.
+pub fn run_formatter(command: &[String], path: &Path, content: &str) -> Result<String, String> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| "empty formatter command".to_string())?;
+    let file = path.to_string_lossy();
+    let mut process = Command::new(program);
+    process
+        .args(args.iter().map(|arg| arg.replace(FILE_PLACEHOLDER, &file)))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(dir) = path.parent().filter(|dir| dir.is_dir()) {
+        process.current_dir(dir);
+    }
+    let mut child = process
+        .spawn()
+        .map_err(|e| format!("{}: {}", program, e))?;
+
+    // Feed stdin and drain the output on their own threads, so a formatter
+    // that writes before reading everything can't deadlock against us.
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let input = content.to_string();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let stdout = read_in_background(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_in_background(child.stderr.take().expect("stderr is piped"));
+
+    let deadline = Instant::now() + FORMAT_TIMEOUT;
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(format!("{} timed out", program));
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    let _ = writer.join();
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    if !status.success() {
+        let message = String::from_utf8_lossy(&stderr);
+        let first_line = message.lines().find(|line| !line.trim().is_empty());
+        return Err(match first_line {
+            Some(line) => format!("{}: {}", program, line.trim()),
+            None => format!("{} exited with {}", program, status),
+        });
+    }
+    String::from_utf8(stdout).map_err(|_| format!("{} printed invalid UTF-8", program))
+}
+
+/// Reads `source` to the end on a new thread.
+fn read_in_background(mut source: impl Read + Send + 'static) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = source.read_to_end(&mut bytes);
+        bytes
+    })
+}
+
+/// A run of whole lines to replace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineEdit {
+    /// The lines replaced, by index in the old text; empty for an insertion
+    /// before `lines.start`
+    pub lines: Range<usize>,
+    /// The replacement, including each line's newline
+    pub text: String,
+    /// Number of lines in `text`
+    pub new_line_count: usize,
+}
+
+/// Returns the line edits that turn `old` into `new`, last first, so each
+/// can be applied without shifting the lines of the ones after it.
+pub fn line_edits(old: &str, new: &str) -> Vec<LineEdit> {
+    let diff = TextDiff::from_lines(old, new);
+    let new_lines = diff.new_slices();
+    let mut edits: Vec<LineEdit> = diff
+        .ops()
+        .iter()
+        .filter(|op| op.tag() != DiffTag::Equal)
+        .map(|op| LineEdit {
+            lines: op.old_range(),
+            text: new_lines[op.new_range()].concat(),
+            new_line_count: op.new_range().len(),
+        })
+        .collect();
+    edits.reverse();
+    edits
+}
+
+/// Returns where `position` ends up after `edits` (as returned by
+/// [`line_edits`]).
+///
+/// Lines after an edit move by the number of lines it adds or removes. A
+/// position inside replaced lines keeps its offset into the edit, clamped to
+/// the replacement; the column is clamped by the buffer when it is set.
+pub fn map_position(position: Position, edits: &[LineEdit]) -> Position {
+    let mut line = position.line;
+    let mut shift = 0isize;
+    for edit in edits {
+        if edit.lines.end <= position.line {
+            shift += edit.new_line_count as isize - edit.lines.len() as isize;
+        } else if edit.lines.contains(&position.line) {
+            let offset = position.line - edit.lines.start;
+            line = edit.lines.start + offset.min(edit.new_line_count.saturating_sub(1));
+        }
+    }
+    Position::new(line.saturating_add_signed(shift), position.col)
+}
+
+/// Applies `edits` (as returned by [`line_edits`] for the buffer's content)
+/// to `buffer` and puts the cursor back on the line it was on.
+pub fn apply_line_edits(buffer: &mut TextBuffer, edits: &[LineEdit]) {
+    let cursor = map_position(buffer.cursor_position(), edits);
+    for edit in edits {
+        // An edit running to the end of a buffer with no final newline ends
+        // at the end of its last line
+        let end = if edit.lines.end < buffer.line_count() {
+            Position::new(edit.lines.end, 0)
+        } else {
+            let last = buffer.line_count().saturating_sub(1);
+            Position::new(last, buffer.line_len(last))
+        };
+        buffer.set_cursor(end);
+        buffer.set_selection_anchor(Position::new(edit.lines.start, 0));
+        buffer.delete_selection();
+        buffer.insert_str(&edit.text);
+    }
+    buffer.set_cursor(cursor);
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_run_formatter_pipes_content() {
+        let path = std::env::temp_dir().join("format_on_save.rs");
+        let formatted = run_formatter(&command(&["tr", "a-z", "A-Z"]), &path, "fn a() {}\n");
+        assert_eq!(formatted, Ok("FN A() {}\n".to_string()));
+    }
+
+    #[test]
+    fn test_run_formatter_substitutes_file() {
+        let path = std::env::temp_dir().join("format_on_save.ts");
+        let formatted = run_formatter(&command(&["echo", "$FILE"]), &path, "");
+        assert_eq!(formatted, Ok(format!("{}\n", path.display())));
+    }
+
+    #[test]
+    fn test_run_formatter_errors() {
+        let path = std::env::temp_dir().join("format_on_save.rs");
+        assert!(run_formatter(&command(&["false"]), &path, "x").is_err());
+        assert!(run_formatter(&command(&["lite-edit-no-such-formatter"]), &path, "x").is_err());
+        assert!(run_formatter(&[], &path, "x").is_err());
+    }
+
+    #[test]
+    fn test_line_edits_touch_only_changed_lines() {
+        let edits = line_edits("a\nb  \nc\nd\n", "a\nb\nc\nd\ne\n");
+        assert_eq!(
+            edits,
+            vec![
+                LineEdit {
+                    lines: 4..4,
+                    text: "e\n".to_string(),
+                    new_line_count: 1,
+                },
+                LineEdit {
+                    lines: 1..2,
+                    text: "b\n".to_string(),
+                    new_line_count: 1,
+                },
+            ]
+        );
+        assert!(line_edits("same\n", "same\n").is_empty());
+    }
+
+    #[test]
+    fn test_apply_line_edits_keeps_cursor_on_its_line() {
+        let old = "use a;\nuse b;\n\nfn main()   {\n    x();\n}\n";
+        let new = "use a;\n\nfn main() {\n    x();\n}\n";
+        let mut buffer = TextBuffer::from_str(old);
+        buffer.set_cursor(Position::new(4, 6));
+
+        apply_line_edits(&mut buffer, &line_edits(old, new));
+
+        assert_eq!(buffer.content(), new);
+        // `x();` moved up one line along with the cursor
+        assert_eq!(buffer.cursor_position(), Position::new(3, 6));
+    }
+
+    #[test]
+    fn test_apply_line_edits_without_final_newline() {
+        let old = "a\nb";
+        let new = "a\nB\n";
+        let mut buffer = TextBuffer::from_str(old);
+        apply_line_edits(&mut buffer, &line_edits(old, new));
+        assert_eq!(buffer.content(), new);
+    }
+
+    #[test]
+    fn test_map_position() {
+        let edits = line_edits("a\nb\nc\nd\n", "a\nx\ny\nz\nd\n");
+        // Before the edit, inside it, and after it
+        assert_eq!(map_position(Position::new(0, 1), &edits), Position::new(0, 1));
+        assert_eq!(map_position(Position::new(2, 0), &edits), Position::new(2, 0));
+        assert_eq!(map_position(Position::new(3, 1), &edits), Position::new(4, 1));
+    }
+}
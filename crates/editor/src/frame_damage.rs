@@ -0,0 +1,275 @@
+// Chunk: docs/chunks/partial_redraw - Damage-based partial redraw
+//!
+//! Frame damage tracking for partial redraws
+//!
+//! The renderer keeps the previous frame in an offscreen texture. When only
+//! some panes changed since that frame, it redraws just those panes over the
+//! retained texture instead of re-encoding every pane's quads.
+//!
+//! This module decides which panes that is. It compares the layout of the
+//! frame about to be drawn with the previous one and combines the result
+//! with the frame's `InvalidationKind`. It is pure Rust and knows nothing
+//! about Metal.
+
+use crate::dirty_region::{DirtyRegion, InvalidationKind};
+use crate::pane_layout::PaneId;
+
+/// What has to be redrawn for a frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameDamage {
+    /// Clear and redraw the whole frame
+    Full,
+    /// Redraw only these panes over the previous frame
+    Panes(Vec<PaneId>),
+}
+
+impl FrameDamage {
+    /// Returns true if the whole frame has to be redrawn
+    pub fn is_full(&self) -> bool {
+        matches!(self, FrameDamage::Full)
+    }
+}
+
+/// One pane of a frame, as far as damage tracking is concerned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaneFrame {
+    /// The pane
+    pub pane_id: PaneId,
+    /// The pane's rectangle (x, y, width, height) in screen pixels
+    pub rect: (f32, f32, f32, f32),
+    /// A fingerprint of everything drawn for the pane outside its content:
+    /// scrollbar thumbs, prompt marks, match ticks, breadcrumbs and, in the
+    /// focused pane, the find strip or status bar. The pane is redrawn
+    /// whenever this changes.
+    pub chrome: u64,
+}
+
+/// The parts of a frame's layout that decide whether it can reuse the
+/// previous frame.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FrameLayout {
+    /// The panes, in render order
+    pub panes: Vec<PaneFrame>,
+    /// The pane that receives content invalidations and shows the status bar
+    pub focused_pane_id: PaneId,
+    /// Whether anything is drawn across pane boundaries (selector, tab drag
+    /// feedback)
+    pub overlay: bool,
+}
+
+/// Remembers the previous frame's layout to compute each frame's damage.
+#[derive(Debug, Default)]
+pub struct DamageTracker {
+    last: Option<FrameLayout>,
+}
+
+impl DamageTracker {
+    /// Creates a tracker with no previous frame; the first frame is full.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forgets the previous frame, so the next frame is full.
+    ///
+    /// Called when the retained frame no longer holds what this tracker
+    /// remembers (the texture was recreated or something else was drawn).
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    /// Returns the damage of a frame with `layout` after `invalidation`, and
+    /// remembers `layout` as the previous frame.
+    ///
+    /// The frame is full when there is no previous frame, when the
+    /// invalidation is `Layout`, `Overlay` or a full-viewport content change,
+    /// when an overlay is or was on screen, when there is a single pane, or
+    /// when the panes or the focus moved. Otherwise the damaged panes are the
+    /// focused pane if its content is dirty plus every pane whose chrome
+    /// changed.
+    pub fn damage(&mut self, invalidation: InvalidationKind, layout: FrameLayout) -> FrameDamage {
+        let damage = match self.last.as_ref() {
+            Some(last) => Self::damage_since(last, invalidation, &layout),
+            None => FrameDamage::Full,
+        };
+        self.last = Some(layout);
+        damage
+    }
+
+    fn damage_since(
+        last: &FrameLayout,
+        invalidation: InvalidationKind,
+        layout: &FrameLayout,
+    ) -> FrameDamage {
+        let content = match invalidation {
+            InvalidationKind::None => DirtyRegion::None,
+            InvalidationKind::Content(region) => region,
+            InvalidationKind::Overlay | InvalidationKind::Layout => return FrameDamage::Full,
+        };
+        if content == DirtyRegion::FullViewport
+            || layout.overlay
+            || last.overlay
+            || layout.panes.len() < 2
+            || layout.focused_pane_id != last.focused_pane_id
+            || layout.panes.len() != last.panes.len()
+        {
+            return FrameDamage::Full;
+        }
+
+        let mut damaged = Vec::new();
+        for (pane, previous) in layout.panes.iter().zip(&last.panes) {
+            if pane.pane_id != previous.pane_id || pane.rect != previous.rect {
+                return FrameDamage::Full;
+            }
+            let content_dirty = pane.pane_id == layout.focused_pane_id && content.is_dirty();
+            if content_dirty || pane.chrome != previous.chrome {
+                damaged.push(pane.pane_id);
+            }
+        }
+
+        if damaged.len() == layout.panes.len() {
+            FrameDamage::Full
+        } else {
+            FrameDamage::Panes(damaged)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pane(pane_id: PaneId, x: f32) -> PaneFrame {
+        PaneFrame {
+            pane_id,
+            rect: (x, 0.0, 400.0, 600.0),
+            chrome: 0,
+        }
+    }
+
+    fn two_panes() -> FrameLayout {
+        FrameLayout {
+            panes: vec![pane(1, 0.0), pane(2, 400.0)],
+            focused_pane_id: 1,
+            overlay: false,
+        }
+    }
+
+    fn typing() -> InvalidationKind {
+        InvalidationKind::Content(DirtyRegion::single_line(3))
+    }
+
+    /// A tracker that has already drawn `two_panes()`.
+    fn warmed_up() -> DamageTracker {
+        let mut tracker = DamageTracker::new();
+        tracker.damage(InvalidationKind::Layout, two_panes());
+        tracker
+    }
+
+    #[test]
+    fn first_frame_is_full() {
+        let mut tracker = DamageTracker::new();
+        assert_eq!(tracker.damage(typing(), two_panes()), FrameDamage::Full);
+    }
+
+    #[test]
+    fn line_edit_damages_only_the_focused_pane() {
+        let mut tracker = warmed_up();
+        assert_eq!(
+            tracker.damage(typing(), two_panes()),
+            FrameDamage::Panes(vec![1])
+        );
+    }
+
+    #[test]
+    fn nothing_changed_damages_nothing() {
+        let mut tracker = warmed_up();
+        assert_eq!(
+            tracker.damage(InvalidationKind::None, two_panes()),
+            FrameDamage::Panes(vec![])
+        );
+    }
+
+    #[test]
+    fn layout_overlay_and_full_viewport_are_full() {
+        for invalidation in [
+            InvalidationKind::Layout,
+            InvalidationKind::Overlay,
+            InvalidationKind::Content(DirtyRegion::FullViewport),
+        ] {
+            let mut tracker = warmed_up();
+            assert_eq!(tracker.damage(invalidation, two_panes()), FrameDamage::Full);
+        }
+    }
+
+    #[test]
+    fn single_pane_is_always_full() {
+        let mut tracker = DamageTracker::new();
+        let layout = FrameLayout {
+            panes: vec![pane(1, 0.0)],
+            focused_pane_id: 1,
+            overlay: false,
+        };
+        tracker.damage(InvalidationKind::Layout, layout.clone());
+        assert_eq!(tracker.damage(typing(), layout), FrameDamage::Full);
+    }
+
+    #[test]
+    fn overlay_forces_full_while_shown_and_once_after() {
+        let mut tracker = warmed_up();
+        let mut with_overlay = two_panes();
+        with_overlay.overlay = true;
+        assert_eq!(tracker.damage(typing(), with_overlay), FrameDamage::Full);
+        // The overlay's pixels are still in the retained frame
+        assert_eq!(tracker.damage(typing(), two_panes()), FrameDamage::Full);
+        assert_eq!(
+            tracker.damage(typing(), two_panes()),
+            FrameDamage::Panes(vec![1])
+        );
+    }
+
+    #[test]
+    fn focus_change_is_full() {
+        let mut tracker = warmed_up();
+        let mut layout = two_panes();
+        layout.focused_pane_id = 2;
+        assert_eq!(tracker.damage(typing(), layout), FrameDamage::Full);
+    }
+
+    #[test]
+    fn moved_or_replaced_pane_is_full() {
+        let mut tracker = warmed_up();
+        let mut resized = two_panes();
+        resized.panes[1].rect.2 = 300.0;
+        assert_eq!(tracker.damage(typing(), resized.clone()), FrameDamage::Full);
+
+        let mut replaced = resized;
+        replaced.panes[1].pane_id = 3;
+        assert_eq!(tracker.damage(typing(), replaced), FrameDamage::Full);
+    }
+
+    #[test]
+    fn every_pane_damaged_is_full() {
+        let mut tracker = warmed_up();
+        let mut layout = two_panes();
+        layout.panes[1].chrome = 42;
+        assert_eq!(tracker.damage(typing(), layout), FrameDamage::Full);
+    }
+
+    #[test]
+    fn chrome_change_without_content_damages_only_that_pane() {
+        let mut tracker = warmed_up();
+        let mut layout = two_panes();
+        layout.panes[1].chrome = 42;
+        assert_eq!(
+            tracker.damage(InvalidationKind::None, layout),
+            FrameDamage::Panes(vec![2])
+        );
+    }
+
+    #[test]
+    fn reset_makes_the_next_frame_full() {
+        let mut tracker = warmed_up();
+        tracker.reset();
+        assert_eq!(tracker.damage(typing(), two_panes()), FrameDamage::Full);
+    }
+}
@@ -0,0 +1,335 @@
+// Chunk: docs/chunks/fuzzy_ranked_scoring - fzf-style fuzzy scorer
+//!
+//! Fuzzy matching with ranked scoring, modelled on fzf's v2 algorithm.
+//!
+//! A query matches a candidate when its characters appear in order (case-
+//! insensitively). Among all such alignments, the one with the highest score
+//! is chosen:
+//!
+//! - every matched character scores [`SCORE_MATCH`];
+//! - characters at word boundaries (start of text, after `/`, `_`, `-`, `.`,
+//!   whitespace) and camelCase humps earn a bonus, doubled for the first
+//!   query character;
+//! - consecutive matches keep the bonus of the run they extend;
+//! - gaps between matched characters are penalized.
+//!
+//! [`fuzzy_match_path`] adds a small bonus for characters matched inside the
+//! file name, so `config` prefers `config.rs` over `config/GOAL.md`.
+//!
+//! The matched character indices are returned with the score so the selector
+//! can highlight them.
+
+/// Score for each matched character.
+const SCORE_MATCH: i32 = 16;
+/// Penalty for the first skipped character of a gap.
+const SCORE_GAP_START: i32 = -3;
+/// Penalty for each further skipped character of a gap.
+const SCORE_GAP_EXTENSION: i32 = -1;
+/// Bonus for a match after a non-word character (`_`, `-`, `.`).
+const BONUS_BOUNDARY: i32 = SCORE_MATCH / 2;
+/// Bonus for matching a non-word character itself.
+const BONUS_NON_WORD: i32 = SCORE_MATCH / 2;
+/// Bonus for a match at the start of the text or after whitespace.
+const BONUS_BOUNDARY_WHITE: i32 = BONUS_BOUNDARY + 2;
+/// Bonus for a match after a path delimiter (`/`, `:`, ...).
+const BONUS_BOUNDARY_DELIMITER: i32 = BONUS_BOUNDARY + 1;
+/// Bonus for a camelCase hump or the first digit of a number.
+const BONUS_CAMEL_123: i32 = BONUS_BOUNDARY + SCORE_GAP_EXTENSION;
+/// Minimum bonus for a character that extends a consecutive run.
+const BONUS_CONSECUTIVE: i32 = -(SCORE_GAP_START + SCORE_GAP_EXTENSION);
+/// The first query character's bonus is multiplied by this.
+const BONUS_FIRST_CHAR_MULTIPLIER: i32 = 2;
+/// Extra score per character matched inside a path's file name.
+const BONUS_FILE_NAME: i32 = 2;
+
+/// A successful fuzzy match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Match score (higher is better). May be negative for widely spread matches.
+    pub score: i32,
+    /// Char indices into the candidate of each matched query character, ascending.
+    pub positions: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    White,
+    NonWord,
+    Delimiter,
+    Lower,
+    Upper,
+    Letter,
+    Number,
+}
+
+fn char_class(c: char) -> CharClass {
+    if c.is_ascii_lowercase() {
+        CharClass::Lower
+    } else if c.is_ascii_uppercase() {
+        CharClass::Upper
+    } else if c.is_ascii_digit() {
+        CharClass::Number
+    } else if c.is_whitespace() {
+        CharClass::White
+    } else if matches!(c, '/' | ',' | ':' | ';' | '|') {
+        CharClass::Delimiter
+    } else if c.is_alphabetic() {
+        if c.is_uppercase() {
+            CharClass::Upper
+        } else {
+            CharClass::Letter
+        }
+    } else if c.is_numeric() {
+        CharClass::Number
+    } else {
+        CharClass::NonWord
+    }
+}
+
+fn is_word(class: CharClass) -> bool {
+    matches!(
+        class,
+        CharClass::Lower | CharClass::Upper | CharClass::Letter | CharClass::Number
+    )
+}
+
+/// Bonus for matching a character of class `cur` that follows one of class `prev`.
+fn bonus_for(prev: CharClass, cur: CharClass) -> i32 {
+    if is_word(cur) {
+        match prev {
+            CharClass::White => return BONUS_BOUNDARY_WHITE,
+            CharClass::Delimiter => return BONUS_BOUNDARY_DELIMITER,
+            CharClass::NonWord => return BONUS_BOUNDARY,
+            _ => {}
+        }
+    }
+    if (prev == CharClass::Lower && cur == CharClass::Upper)
+        || (prev != CharClass::Number && cur == CharClass::Number)
+    {
+        return BONUS_CAMEL_123;
+    }
+    match cur {
+        CharClass::NonWord | CharClass::Delimiter => BONUS_NON_WORD,
+        CharClass::White => BONUS_BOUNDARY_WHITE,
+        _ => 0,
+    }
+}
+
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Matches `query` against `text`, case-insensitively.
+///
+/// Returns `None` unless every query character appears in `text` in order.
+/// An empty query matches everything with score 0.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let text: Vec<char> = text.chars().collect();
+    score_alignment(query, &text, text.len())
+}
+
+/// Like [`fuzzy_match`], with a bonus for characters matched in the file
+/// name (the part after the last `/`).
+pub fn fuzzy_match_path(query: &str, path: &str) -> Option<FuzzyMatch> {
+    let text: Vec<char> = path.chars().collect();
+    let file_name_start = text
+        .iter()
+        .rposition(|&c| c == '/')
+        .map(|idx| idx + 1)
+        .unwrap_or(0);
+    score_alignment(query, &text, file_name_start)
+}
+
+/// Finds the best-scoring alignment of `query` in `text`.
+///
+/// Characters at or after `file_name_start` earn [`BONUS_FILE_NAME`].
+fn score_alignment(query: &str, text: &[char], file_name_start: usize) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query.chars().map(fold).collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch { score: 0, positions: Vec::new() });
+    }
+    let folded: Vec<char> = text.iter().map(|&c| fold(c)).collect();
+
+    // Cheap rejection: greedy subsequence scan
+    let mut qi = 0;
+    for &c in &folded {
+        if qi < query.len() && c == query[qi] {
+            qi += 1;
+        }
+    }
+    if qi < query.len() {
+        return None;
+    }
+
+    let m = query.len();
+    let n = text.len();
+
+    let mut prev_class = CharClass::White;
+    let bonus: Vec<i32> = text
+        .iter()
+        .enumerate()
+        .map(|(j, &c)| {
+            let class = char_class(c);
+            let mut b = bonus_for(prev_class, class);
+            prev_class = class;
+            if j >= file_name_start {
+                b += BONUS_FILE_NAME;
+            }
+            b
+        })
+        .collect();
+
+    // score[i][j]: best score with query[i] matched at text[j]
+    // run_bonus[i][j]: bonus at the start of the consecutive run ending there
+    // from[i][j]: column of query[i - 1] in that alignment
+    let mut score = vec![vec![None::<i32>; n]; m];
+    let mut run_bonus = vec![vec![0i32; n]; m];
+    let mut from = vec![vec![0usize; n]; m];
+
+    for j in 0..n {
+        if folded[j] == query[0] {
+            score[0][j] = Some(SCORE_MATCH + bonus[j] * BONUS_FIRST_CHAR_MULTIPLIER);
+            run_bonus[0][j] = bonus[j];
+        }
+    }
+
+    for i in 1..m {
+        // Best (score, column) of row i - 1 reachable through a gap,
+        // already charged the gap penalty for ending just before j
+        let mut gap_best: Option<(i32, usize)> = None;
+        for j in i..n {
+            if j >= 2 {
+                let extended = gap_best.map(|(s, k)| (s + SCORE_GAP_EXTENSION, k));
+                let started = score[i - 1][j - 2].map(|s| (s + SCORE_GAP_START, j - 2));
+                gap_best = match (extended, started) {
+                    (Some(a), Some(b)) => Some(if b.0 >= a.0 { b } else { a }),
+                    (a, b) => a.or(b),
+                };
+            }
+            if folded[j] != query[i] {
+                continue;
+            }
+
+            let consecutive = score[i - 1][j - 1].map(|s| {
+                let start_bonus = run_bonus[i - 1][j - 1];
+                let b = bonus[j].max(start_bonus).max(BONUS_CONSECUTIVE);
+                (s + SCORE_MATCH + b, start_bonus)
+            });
+            let gapped = gap_best.map(|(s, k)| (s + SCORE_MATCH + bonus[j], k));
+
+            match (consecutive, gapped) {
+                (Some((cs, start_bonus)), Some((gs, _))) if cs >= gs => {
+                    score[i][j] = Some(cs);
+                    run_bonus[i][j] = start_bonus;
+                    from[i][j] = j - 1;
+                }
+                (Some((cs, start_bonus)), None) => {
+                    score[i][j] = Some(cs);
+                    run_bonus[i][j] = start_bonus;
+                    from[i][j] = j - 1;
+                }
+                (_, Some((gs, k))) => {
+                    score[i][j] = Some(gs);
+                    run_bonus[i][j] = bonus[j];
+                    from[i][j] = k;
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    // Best end column; earliest wins ties
+    let (mut j, best) = (0..n)
+        .filter_map(|j| score[m - 1][j].map(|s| (j, s)))
+        .fold(None, |acc: Option<(usize, i32)>, (j, s)| match acc {
+            Some((_, best)) if best >= s => acc,
+            _ => Some((j, s)),
+        })?;
+
+    let mut positions = vec![0; m];
+    for i in (0..m).rev() {
+        positions[i] = j;
+        j = from[i][j];
+    }
+
+    Some(FuzzyMatch { score: best, positions })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_match() {
+        assert!(fuzzy_match("xyz", "main.rs").is_none());
+        assert!(fuzzy_match("sm", "main.rs").is_none()); // Out of order
+    }
+
+    #[test]
+    fn test_subsequence_positions_prefer_boundaries() {
+        // 'r' after '.' beats nothing earlier; 'm' at the start
+        let m = fuzzy_match("mr", "main.rs").unwrap();
+        assert_eq!(m.positions, vec![0, 5]);
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let m = fuzzy_match("main", "MAIN.RS").unwrap();
+        assert_eq!(m.positions, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_empty_query_matches_everything() {
+        let m = fuzzy_match("", "src/main.rs").unwrap();
+        assert_eq!(m.score, 0);
+        assert!(m.positions.is_empty());
+    }
+
+    #[test]
+    fn test_consecutive_beats_sparse() {
+        let consecutive = fuzzy_match("test", "testing/feature/main.rs").unwrap();
+        let sparse = fuzzy_match("tig", "testing/feature/main.rs").unwrap();
+        assert!(consecutive.score > sparse.score);
+    }
+
+    #[test]
+    fn test_prefix_beats_middle() {
+        let prefix = fuzzy_match("main", "main.rs").unwrap();
+        let middle = fuzzy_match("main", "domain.rs").unwrap();
+        assert!(prefix.score > middle.score);
+    }
+
+    #[test]
+    fn test_optimal_alignment_over_greedy() {
+        // Greedy would take the 'b' in "abc"; the best alignment is the
+        // boundary-aligned "bar" in the file name
+        let m = fuzzy_match("bar", "abc/foo_bar.rs").unwrap();
+        assert_eq!(m.positions, vec![8, 9, 10]);
+    }
+
+    #[test]
+    fn test_camel_case_humps() {
+        let m = fuzzy_match("fb", "FooBar.rs").unwrap();
+        assert_eq!(m.positions, vec![0, 3]);
+        let hump = fuzzy_match("fb", "FooBar.rs").unwrap();
+        let plain = fuzzy_match("fb", "Foobar.rs").unwrap();
+        assert!(hump.score > plain.score);
+    }
+
+    #[test]
+    fn test_path_prefers_file_name() {
+        let in_name = fuzzy_match_path("config", "config.rs").unwrap();
+        let in_dir = fuzzy_match_path("config", "docs/chunks/config_feature/GOAL.md").unwrap();
+        assert!(in_name.score > in_dir.score);
+
+        // Within a single path, the file name copy of the query is chosen
+        let m = fuzzy_match_path("main", "main/src/main.rs").unwrap();
+        assert_eq!(m.positions, vec![9, 10, 11, 12]);
+    }
+
+    #[test]
+    fn test_positions_are_char_indices() {
+        let m = fuzzy_match("ü", "grün.rs").unwrap();
+        assert_eq!(m.positions, vec![2]);
+    }
+}
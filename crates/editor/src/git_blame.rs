@@ -0,0 +1,402 @@
+// Chunk: docs/chunks/git_blame_inline - Inline git blame for the cursor line
+//!
+//! Git blame shown as virtual text after the cursor line.
+//!
+//! With blame mode on, the cursor line of a file ends with who last changed
+//! it, when, and the commit's summary: `Ada Lovelace, 3 days ago • Fix
+//! parser`. The blame comes from `git blame --porcelain --contents -`, fed
+//! the buffer's text so unsaved edits line up (edited lines show as
+//! uncommitted).
+//!
+//! [`BlameCache`] runs blames on background threads, one file per tab, and
+//! keeps the latest result until the buffer's text changes. While a blame
+//! runs, further changes queue at most one follow-up run, so typing doesn't
+//! start a git process per keystroke.
+//!
+//! Porcelain parsing and the annotation text are pure functions so they can
+//! be unit-tested without a repository.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use lite_edit_buffer::AnnotationSource;
+
+use crate::event_channel::EventSender;
+use crate::workspace::TabId;
+
+/// The annotation source blame text is published under.
+pub const BLAME_SOURCE: AnnotationSource = "blame";
+
+/// Blank columns between the end of the line and the blame text.
+const BLAME_GAP: usize = 4;
+
+/// The blame text is left out when fewer columns than this remain for it.
+const MIN_BLAME_COLS: usize = 12;
+
+/// The commit git reports for lines that aren't committed yet.
+const UNCOMMITTED: &str = "0000000000000000000000000000000000000000";
+
+/// Who last changed one line, and in which commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlameLine {
+    /// The full commit hash (all zeros for an uncommitted line)
+    pub commit: String,
+    pub author: String,
+    /// Author time, in seconds since the Unix epoch
+    pub author_time: i64,
+    /// The first line of the commit message
+    pub summary: String,
+}
+
+impl BlameLine {
+    /// Returns true if the line has changes that aren't committed.
+    pub fn is_uncommitted(&self) -> bool {
+        self.commit == UNCOMMITTED
+    }
+
+    /// Returns the text shown after the line at time `now` (seconds since
+    /// the Unix epoch).
+    pub fn label(&self, now: i64) -> String {
+        if self.is_uncommitted() {
+            return "Uncommitted changes".to_string();
+        }
+        let age = relative_age(now - self.author_time);
+        format!("{}, {} • {}", self.author, age, self.summary)
+    }
+}
+
+/// Returns how long ago something `seconds` old happened, e.g. "3 days ago".
+pub fn relative_age(seconds: i64) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    let (count, unit) = match seconds.max(0) {
+        s if s < MINUTE => return "just now".to_string(),
+        s if s < HOUR => (s / MINUTE, "minute"),
+        s if s < DAY => (s / HOUR, "hour"),
+        s if s < 30 * DAY => (s / DAY, "day"),
+        s if s < 365 * DAY => (s / (30 * DAY), "month"),
+        s => (s / (365 * DAY), "year"),
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{} {}{} ago", count, unit, plural)
+}
+
+/// Parses `git blame --porcelain` output into one entry per line of the
+/// file, in line order.
+///
+/// Each commit's details appear only the first time the commit does, so
+/// they are remembered by hash.
+pub fn parse_porcelain(output: &str) -> Vec<BlameLine> {
+    let mut commits: HashMap<&str, BlameLine> = HashMap::new();
+    let mut lines: Vec<(usize, &str)> = Vec::new();
+    let mut current: Option<(&str, usize)> = None;
+
+    for line in output.lines() {
+        if line.starts_with('\t') {
+            if let Some((commit, final_line)) = current.take() {
+                lines.push((final_line, commit));
+            }
+            continue;
+        }
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        let is_hash = key.bytes().all(|b| b.is_ascii_hexdigit());
+        let is_header = key.len() == UNCOMMITTED.len() && is_hash;
+        if is_header {
+            let final_line = value.split(' ').nth(1).and_then(|n| n.parse().ok());
+            current = final_line.map(|final_line| (key, final_line));
+            commits.entry(key).or_insert_with(|| BlameLine {
+                commit: key.to_string(),
+                author: String::new(),
+                author_time: 0,
+                summary: String::new(),
+            });
+            continue;
+        }
+        let Some(commit) = current.and_then(|(commit, _)| commits.get_mut(commit)) else {
+            continue;
+        };
+        match key {
+            "author" => commit.author = value.to_string(),
+            "author-time" => commit.author_time = value.parse().unwrap_or(0),
+            "summary" => commit.summary = value.to_string(),
+            _ => {}
+        }
+    }
+
+    lines.sort_by_key(|(final_line, _)| *final_line);
+    lines
+        .into_iter()
+        .filter_map(|(_, commit)| commits.get(commit).cloned())
+        .collect()
+}
+
+/// Fits `text` into the `cols` columns left after a line, after a gap:
+/// returns the gap and the text, cut short with `…` if needed, or `None`
+/// when too little room remains.
+pub fn fit_after_line(text: &str, cols: usize) -> Option<String> {
+    let room = cols.checked_sub(BLAME_GAP)?;
+    if room < MIN_BLAME_COLS {
+        return None;
+    }
+    let gap = " ".repeat(BLAME_GAP);
+    if text.chars().count() <= room {
+        return Some(format!("{}{}", gap, text));
+    }
+    let cut: String = text.chars().take(room - 1).collect();
+    Some(format!("{}{}…", gap, cut.trim_end()))
+}
+
+/// Runs `git blame` on the file at `path`, whose current text is
+/// `contents`, in the file's directory.
+///
+/// An error describes a file outside a repository, an untracked file, or a
+/// `git` that couldn't run.
+pub fn run_blame(path: &Path, contents: &str) -> Result<Vec<BlameLine>, String> {
+    let dir = path.parent().ok_or("file has no directory")?;
+    let name = path.file_name().ok_or("not a file")?;
+    let mut child = Command::new("git")
+        .arg("blame")
+        .arg("--porcelain")
+        .arg("--contents")
+        .arg("-")
+        .arg("--")
+        .arg(name)
+        .current_dir(dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("git: {}", e))?;
+
+    // Feed stdin on its own thread so a full stdout pipe can't deadlock us
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let input = contents.to_string();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    let _ = writer.join();
+
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stderr);
+        let first_line = message.lines().next().unwrap_or("git blame failed");
+        return Err(first_line.to_string());
+    }
+    Ok(parse_porcelain(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Returns the hash identifying a version of a buffer's text.
+fn text_hash(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A finished background blame.
+struct BlameResult {
+    tab: TabId,
+    hash: u64,
+    lines: Result<Vec<BlameLine>, String>,
+}
+
+/// The blame of one tab's file.
+#[derive(Default)]
+struct BlameEntry {
+    /// Hash of the text the latest run blamed, or is blaming
+    hash: u64,
+    /// True while a run is going
+    running: bool,
+    /// The latest run's result
+    lines: Option<Result<Vec<BlameLine>, String>>,
+    /// A run to start when the current one finishes: the file and its text
+    queued: Option<(PathBuf, String)>,
+}
+
+/// Blames per tab, run in the background.
+pub struct BlameCache {
+    entries: HashMap<TabId, BlameEntry>,
+    sender: Sender<BlameResult>,
+    results: Receiver<BlameResult>,
+    /// Wakes the event loop when a run finishes
+    wakeup: Option<EventSender>,
+}
+
+impl Default for BlameCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BlameCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        let (sender, results) = mpsc::channel();
+        Self {
+            entries: HashMap::new(),
+            sender,
+            results,
+            wakeup: None,
+        }
+    }
+
+    /// Sets the sender used to wake the event loop when a blame finishes.
+    pub fn set_wakeup(&mut self, sender: EventSender) {
+        self.wakeup = Some(sender);
+    }
+
+    /// Makes sure tab `tab`'s blame describes `text`, the contents of
+    /// `path`, starting a run if it doesn't.
+    ///
+    /// If a run is already going, this one waits for it, replacing any run
+    /// queued before.
+    pub fn request(&mut self, tab: TabId, path: &Path, text: String) {
+        let hash = text_hash(&text);
+        let entry = self.entries.entry(tab).or_default();
+        if entry.running {
+            entry.queued = (entry.hash != hash).then(|| (path.to_path_buf(), text));
+            return;
+        }
+        if entry.lines.is_some() && entry.hash == hash {
+            return;
+        }
+        self.start(tab, path.to_path_buf(), text, hash);
+    }
+
+    /// Starts a background run for tab `tab`.
+    fn start(&mut self, tab: TabId, path: PathBuf, text: String, hash: u64) {
+        let entry = self.entries.entry(tab).or_default();
+        entry.hash = hash;
+        entry.running = true;
+        entry.lines = None;
+        let sender = self.sender.clone();
+        let wakeup = self.wakeup.clone();
+        thread::spawn(move || {
+            let lines = run_blame(&path, &text);
+            let _ = sender.send(BlameResult { tab, hash, lines });
+            if let Some(wakeup) = wakeup {
+                let _ = wakeup.send_pty_wakeup();
+            }
+        });
+    }
+
+    /// Takes the results of finished runs, starting queued runs. Returns
+    /// true if any result arrived.
+    pub fn poll(&mut self) -> bool {
+        let mut any = false;
+        while let Ok(result) = self.results.try_recv() {
+            let Some(entry) = self.entries.get_mut(&result.tab) else {
+                continue;
+            };
+            if entry.hash != result.hash {
+                continue;
+            }
+            entry.running = false;
+            entry.lines = Some(result.lines);
+            any = true;
+            if let Some((path, text)) = entry.queued.take() {
+                let hash = text_hash(&text);
+                self.start(result.tab, path, text, hash);
+            }
+        }
+        any
+    }
+
+    /// Returns true if tab `tab`'s file has been blamed or is being blamed.
+    pub fn contains(&self, tab: TabId) -> bool {
+        self.entries.contains_key(&tab)
+    }
+
+    /// Returns the blame of `line` in tab `tab`'s file, once known.
+    pub fn line(&self, tab: TabId, line: usize) -> Option<&BlameLine> {
+        let entry = self.entries.get(&tab)?;
+        entry.lines.as_ref()?.as_ref().ok()?.get(line)
+    }
+
+    /// Forgets every blame.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AAAA: &str = "aaaa000000000000000000000000000000000000";
+
+    fn porcelain() -> String {
+        format!(
+            "\
+{AAAA} 1 1 2
+author Ada Lovelace
+author-mail <ada@example.com>
+author-time 1700000000
+author-tz +0000
+committer Ada Lovelace
+summary Add the engine
+filename engine.rs
+\tfn main() {{
+{AAAA} 2 2
+\t    run();
+{UNCOMMITTED} 3 3 1
+author Not Committed Yet
+author-time 1700100000
+summary Version of engine.rs from engine.rs
+filename engine.rs
+\t    stop();
+"
+        )
+    }
+
+    #[test]
+    fn test_parse_porcelain_lines_in_order() {
+        let lines = parse_porcelain(&porcelain());
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].author, "Ada Lovelace");
+        assert_eq!(lines[0].summary, "Add the engine");
+        // The repeated commit has no details of its own
+        assert_eq!(lines[1], lines[0]);
+        assert!(lines[2].is_uncommitted());
+    }
+
+    #[test]
+    fn test_labels() {
+        let lines = parse_porcelain(&porcelain());
+        let three_days = 3 * 24 * 60 * 60;
+        assert_eq!(
+            lines[0].label(1700000000 + three_days),
+            "Ada Lovelace, 3 days ago • Add the engine"
+        );
+        assert_eq!(lines[2].label(1700100000), "Uncommitted changes");
+    }
+
+    #[test]
+    fn test_relative_age() {
+        assert_eq!(relative_age(5), "just now");
+        assert_eq!(relative_age(60), "1 minute ago");
+        assert_eq!(relative_age(2 * 60 * 60), "2 hours ago");
+        assert_eq!(relative_age(45 * 24 * 60 * 60), "1 month ago");
+        assert_eq!(relative_age(800 * 24 * 60 * 60), "2 years ago");
+        // A clock behind the commit isn't the future
+        assert_eq!(relative_age(-30), "just now");
+    }
+
+    #[test]
+    fn test_fit_after_line() {
+        let fits = fit_after_line("Ada, 1 day ago", 40).unwrap();
+        assert_eq!(fits, "    Ada, 1 day ago");
+        let cut = fit_after_line("Ada Lovelace, 3 days ago", 20).unwrap();
+        assert_eq!(cut, "    Ada Lovelace, 3…");
+        assert_eq!(cut.chars().count(), 20);
+        assert!(fit_after_line("Ada", 10).is_none());
+    }
+}
@@ -0,0 +1,330 @@
+// Chunk: docs/chunks/git_commit_flow - Changes tab for staging and committing
+//!
+//! The Changes tab: a workspace's changed files with staging checkboxes.
+//!
+//! A [`ChangesBuffer`] lists the files `git status` reports under the
+//! workspace root, one per row, and presents them through `BufferView`, so a
+//! `TabKind::Changes` tab renders through the same pipeline as the problems
+//! list. One file is selected at a time. Its checkbox shows whether the
+//! change is staged (`[x]`), partly staged (`[~]`) or not (`[ ]`).
+//!
+//! The buffer never runs git itself. Keys in the tab turn into a
+//! [`ChangesCommand`] that the editor carries out with the background
+//! [`GitRunner`](crate::git_command::GitRunner); the list is refreshed from
+//! the workspace's next `git status`.
+
+use std::path::{Path, PathBuf};
+
+use lite_edit_buffer::{BufferView, CursorInfo, DirtyLines, Span, Style, StyledLine};
+
+use crate::git_command::GitAction;
+use crate::git_status::{ChangedFile, RepoStatus};
+use crate::view_colors::{GREEN, MAUVE, SELECTED_BG, YELLOW};
+
+/// Rows above the first file: the heading and a blank line.
+const HEADER_ROWS: usize = 2;
+
+/// The keys' summary shown below the files.
+const HINT: &str = "Space stages or unstages · A stages all · C commits · Return opens";
+
+/// What a key in the Changes tab asks the editor to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangesCommand {
+    /// Run a git command in the workspace root
+    Git(GitAction),
+    /// Ask for a commit message and commit what is staged
+    Commit,
+    /// Open the file
+    Open(ChangedFile),
+}
+
+/// The changed files of one workspace, with one selected.
+pub struct ChangesBuffer {
+    /// The workspace root; paths are shown and staged relative to it
+    root: PathBuf,
+    status: RepoStatus,
+    /// Index of the selected file
+    selected: usize,
+    dirty: DirtyLines,
+}
+
+impl ChangesBuffer {
+    /// Creates the list for the workspace at `root`, whose repository is in
+    /// `status`.
+    pub fn new(root: PathBuf, status: RepoStatus) -> Self {
+        Self {
+            root,
+            status,
+            selected: 0,
+            dirty: DirtyLines::FromLineToEnd(0),
+        }
+    }
+
+    /// Returns the workspace root.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Replaces the listed status, keeping the selection on the same file
+    /// when it is still listed.
+    pub fn set_status(&mut self, status: RepoStatus) {
+        if status == self.status {
+            return;
+        }
+        let selected_path = self.selected_file().map(|file| file.path.clone());
+        let same_file =
+            selected_path.and_then(|path| status.changed.iter().position(|file| file.path == path));
+        self.selected =
+            same_file.unwrap_or(self.selected.min(status.changed.len().saturating_sub(1)));
+        self.status = status;
+        self.dirty = DirtyLines::FromLineToEnd(0);
+    }
+
+    /// Returns the listed files.
+    pub fn files(&self) -> &[ChangedFile] {
+        &self.status.changed
+    }
+
+    /// Returns the selected file, if there are any.
+    pub fn selected_file(&self) -> Option<&ChangedFile> {
+        self.status.changed.get(self.selected)
+    }
+
+    /// Returns the selected file's row.
+    pub fn selected_row(&self) -> usize {
+        HEADER_ROWS + self.selected
+    }
+
+    /// Moves the selection `delta` files down (or up, if negative), stopping
+    /// at the first and last file.
+    pub fn move_selection(&mut self, delta: isize) {
+        let last = self.status.changed.len().saturating_sub(1) as isize;
+        let target = (self.selected as isize + delta).clamp(0, last) as usize;
+        if target != self.selected {
+            self.dirty.merge(DirtyLines::Single(self.selected_row()));
+            self.selected = target;
+            self.dirty.merge(DirtyLines::Single(self.selected_row()));
+        }
+    }
+
+    /// Returns the number of files with staged changes.
+    pub fn staged_count(&self) -> usize {
+        self.status
+            .changed
+            .iter()
+            .filter(|file| file.is_staged())
+            .count()
+    }
+
+    /// Returns the status bar text, e.g. "main • 2 staged, 1 unstaged".
+    pub fn summary(&self) -> String {
+        let unstaged = self
+            .files()
+            .iter()
+            .filter(|file| file.has_unstaged())
+            .count();
+        format!(
+            "{} • {} staged, {} unstaged",
+            self.status.branch_label(),
+            self.staged_count(),
+            unstaged
+        )
+    }
+
+    /// Returns the branch name, or "detached HEAD".
+    pub fn branch_label(&self) -> &str {
+        self.status.branch_label()
+    }
+
+    /// Returns the command that flips the selected file's checkbox: stage
+    /// whatever isn't staged yet, or unstage a fully staged change.
+    pub fn toggle_selected(&self) -> Option<ChangesCommand> {
+        let file = self.selected_file()?;
+        let paths = vec![self.relative(file)];
+        Some(ChangesCommand::Git(if file.has_unstaged() {
+            GitAction::Stage(paths)
+        } else {
+            GitAction::Unstage(paths)
+        }))
+    }
+
+    /// Returns the command that stages every listed file, if any has
+    /// unstaged changes.
+    pub fn stage_all(&self) -> Option<ChangesCommand> {
+        let paths: Vec<PathBuf> = self
+            .files()
+            .iter()
+            .filter(|file| file.has_unstaged())
+            .map(|file| self.relative(file))
+            .collect();
+        (!paths.is_empty()).then_some(ChangesCommand::Git(GitAction::Stage(paths)))
+    }
+
+    /// Returns `file`'s path relative to the workspace root.
+    fn relative(&self, file: &ChangedFile) -> PathBuf {
+        file.path
+            .strip_prefix(&self.root)
+            .unwrap_or(&file.path)
+            .to_path_buf()
+    }
+
+    fn render_row(&self, row: usize) -> Option<StyledLine> {
+        let dim = Style {
+            dim: true,
+            ..Style::default()
+        };
+        let files = &self.status.changed;
+        if row == 0 {
+            let branch = Style {
+                fg: MAUVE,
+                bold: true,
+                ..Style::default()
+            };
+            let count = match files.len() {
+                0 => " • nothing to commit".to_string(),
+                1 => " • 1 changed file".to_string(),
+                n => format!(" • {} changed files", n),
+            };
+            return Some(StyledLine::new(vec![
+                Span::new(self.status.branch_label().to_string(), branch),
+                Span::new(count, dim),
+            ]));
+        }
+        let index = row.checked_sub(HEADER_ROWS)?;
+        if let Some(file) = files.get(index) {
+            let checkbox = match (file.is_staged(), file.has_unstaged()) {
+                (true, false) => "[x]",
+                (true, true) => "[~]",
+                _ => "[ ]",
+            };
+            let code_style = Style {
+                fg: if file.has_unstaged() {
+                    YELLOW
+                } else {
+                    GREEN
+                },
+                ..Style::default()
+            };
+            let mut spans = vec![
+                Span::plain(format!("  {} ", checkbox)),
+                Span::new(file.code.clone(), code_style),
+                Span::plain(format!("  {}", self.relative(file).display())),
+            ];
+            if index == self.selected {
+                for span in &mut spans {
+                    span.style.bg = SELECTED_BG;
+                }
+            }
+            return Some(StyledLine::new(spans));
+        }
+        match row - HEADER_ROWS - files.len() {
+            0 => Some(StyledLine::empty()),
+            1 => Some(StyledLine::new(vec![Span::new(HINT, dim)])),
+            _ => None,
+        }
+    }
+}
+
+impl BufferView for ChangesBuffer {
+    fn line_count(&self) -> usize {
+        HEADER_ROWS + self.status.changed.len() + 2
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        self.render_row(line)
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.render_row(line)
+            .map(|row| row.char_count())
+            .unwrap_or(0)
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        None
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer() -> ChangesBuffer {
+        let file = |code: &str, path: &str| ChangedFile {
+            code: code.to_string(),
+            path: PathBuf::from("/ws").join(path),
+        };
+        let status = RepoStatus {
+            branch: Some("main".to_string()),
+            changed: vec![
+                file("M ", "staged.rs"),
+                file("MM", "partly.rs"),
+                file("??", "new.md"),
+            ],
+        };
+        ChangesBuffer::new(PathBuf::from("/ws"), status)
+    }
+
+    fn text(buffer: &ChangesBuffer, row: usize) -> String {
+        let line = buffer.styled_line(row).unwrap();
+        line.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_rows_show_checkboxes() {
+        let buffer = buffer();
+        assert_eq!(text(&buffer, 0), "main • 3 changed files");
+        assert_eq!(text(&buffer, 2), "  [x] M   staged.rs");
+        assert_eq!(text(&buffer, 3), "  [~] MM  partly.rs");
+        assert_eq!(text(&buffer, 4), "  [ ] ??  new.md");
+        assert_eq!(text(&buffer, 6), HINT);
+        assert_eq!(buffer.line_count(), 7);
+        assert_eq!(buffer.summary(), "main • 2 staged, 2 unstaged");
+    }
+
+    #[test]
+    fn test_toggle_stages_the_rest_or_unstages() {
+        let mut buffer = buffer();
+        let unstage = GitAction::Unstage(vec![PathBuf::from("staged.rs")]);
+        assert_eq!(buffer.toggle_selected(), Some(ChangesCommand::Git(unstage)));
+        buffer.move_selection(1);
+        let stage = GitAction::Stage(vec![PathBuf::from("partly.rs")]);
+        assert_eq!(buffer.toggle_selected(), Some(ChangesCommand::Git(stage)));
+
+        let all = GitAction::Stage(vec![PathBuf::from("partly.rs"), PathBuf::from("new.md")]);
+        assert_eq!(buffer.stage_all(), Some(ChangesCommand::Git(all)));
+    }
+
+    #[test]
+    fn test_selection_follows_the_file_across_refreshes() {
+        let mut buffer = buffer();
+        buffer.move_selection(5);
+        assert_eq!(buffer.selected_row(), 4);
+
+        // The first file was committed; the selection stays on new.md
+        let mut status = buffer.status.clone();
+        status.changed.remove(0);
+        buffer.set_status(status);
+        assert_eq!(buffer.selected_file().unwrap().code, "??");
+
+        // Everything was committed
+        let mut status = buffer.status.clone();
+        status.changed.clear();
+        buffer.set_status(status);
+        assert_eq!(buffer.selected_file(), None);
+        assert_eq!(text(&buffer, 0), "main • nothing to commit");
+        assert_eq!(buffer.toggle_selected(), None);
+    }
+}
@@ -0,0 +1,202 @@
+// Chunk: docs/chunks/git_commit_flow - Git commands run in the background
+//!
+//! Git commands that change a repository: staging, unstaging and committing.
+//!
+//! [`GitRunner`] runs them on one worker thread, in the order they were asked
+//! for, so that staging a file and then committing never race for the index
+//! lock, and the editor never waits on git. Results come back over a channel
+//! and are picked up by [`GitRunner::poll`].
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use crate::event_channel::EventSender;
+
+/// A change to make to a repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitAction {
+    /// Stage these paths (relative to the workspace root), deletions included
+    Stage(Vec<PathBuf>),
+    /// Unstage these paths (relative to the workspace root)
+    Unstage(Vec<PathBuf>),
+    /// Commit what is staged, with this message
+    Commit(String),
+}
+
+impl GitAction {
+    /// Returns the arguments to pass to `git`.
+    pub fn args(&self) -> Vec<String> {
+        let with_paths = |args: &[&str], paths: &[PathBuf]| {
+            let mut args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+            args.push("--".to_string());
+            args.extend(paths.iter().map(|path| path.to_string_lossy().into_owned()));
+            args
+        };
+        match self {
+            GitAction::Stage(paths) => with_paths(&["add", "-A"], paths),
+            GitAction::Unstage(paths) => with_paths(&["reset", "-q"], paths),
+            GitAction::Commit(message) => {
+                vec!["commit".into(), "-q".into(), "-m".into(), message.clone()]
+            }
+        }
+    }
+}
+
+/// A finished git command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitOutcome {
+    /// The directory git ran in
+    pub root: PathBuf,
+    pub action: GitAction,
+    /// Git's output, or the first line of its error
+    pub result: Result<String, String>,
+}
+
+/// Runs `action` in `root` and waits for it to finish.
+pub fn run_action(root: &Path, action: &GitAction) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(action.args())
+        .current_dir(root)
+        .output()
+        .map_err(|err| format!("Couldn't run git: {}", err))?;
+    if output.status.success() {
+        return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let message = stderr
+        .lines()
+        .chain(stdout.lines())
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string);
+    Err(message.unwrap_or_else(|| format!("git failed ({})", output.status)))
+}
+
+/// A command waiting for the worker.
+struct Job {
+    root: PathBuf,
+    action: GitAction,
+    wakeup: Option<EventSender>,
+}
+
+/// Runs git commands one at a time on a background thread.
+pub struct GitRunner {
+    /// Feeds the worker, which starts with the first command
+    jobs: Option<Sender<Job>>,
+    sender: Sender<GitOutcome>,
+    results: Receiver<GitOutcome>,
+    /// Wakes the event loop when a command finishes
+    wakeup: Option<EventSender>,
+    /// Commands sent but not yet picked up by `poll`
+    pending: usize,
+}
+
+impl Default for GitRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GitRunner {
+    /// Creates a runner. No thread is started until the first command.
+    pub fn new() -> Self {
+        let (sender, results) = mpsc::channel();
+        Self {
+            jobs: None,
+            sender,
+            results,
+            wakeup: None,
+            pending: 0,
+        }
+    }
+
+    /// Sets the sender used to wake the event loop when a command finishes.
+    pub fn set_wakeup(&mut self, sender: EventSender) {
+        self.wakeup = Some(sender);
+    }
+
+    /// Queues `action` to run in `root` after the commands before it.
+    pub fn run(&mut self, root: &Path, action: GitAction) {
+        let jobs = self.jobs.get_or_insert_with(|| {
+            let (jobs, queue) = mpsc::channel::<Job>();
+            let sender = self.sender.clone();
+            thread::spawn(move || {
+                for job in queue {
+                    let result = run_action(&job.root, &job.action);
+                    let outcome = GitOutcome {
+                        root: job.root,
+                        action: job.action,
+                        result,
+                    };
+                    if sender.send(outcome).is_err() {
+                        break;
+                    }
+                    if let Some(wakeup) = job.wakeup {
+                        let _ = wakeup.send_pty_wakeup();
+                    }
+                }
+            });
+            jobs
+        });
+        let job = Job {
+            root: root.to_path_buf(),
+            action,
+            wakeup: self.wakeup.clone(),
+        };
+        if jobs.send(job).is_ok() {
+            self.pending += 1;
+        }
+    }
+
+    /// Returns true while a command is queued or running.
+    pub fn is_busy(&self) -> bool {
+        self.pending > 0
+    }
+
+    /// Takes the outcomes of the commands that finished, in order.
+    pub fn poll(&mut self) -> Vec<GitOutcome> {
+        let outcomes: Vec<GitOutcome> = self.results.try_iter().collect();
+        self.pending = self.pending.saturating_sub(outcomes.len());
+        outcomes
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_args() {
+        let paths = vec![PathBuf::from("src/a.rs"), PathBuf::from("-odd name")];
+        assert_eq!(
+            GitAction::Stage(paths.clone()).args(),
+            vec!["add", "-A", "--", "src/a.rs", "-odd name"]
+        );
+        assert_eq!(
+            GitAction::Unstage(paths).args(),
+            vec!["reset", "-q", "--", "src/a.rs", "-odd name"]
+        );
+        assert_eq!(
+            GitAction::Commit("Fix it".to_string()).args(),
+            vec!["commit", "-q", "-m", "Fix it"]
+        );
+    }
+
+    #[test]
+    fn test_failure_reports_the_first_error_line() {
+        let dir = std::env::temp_dir().join("lite-edit-git-command-test");
+        let _ = std::fs::create_dir_all(&dir);
+        // Either git is missing or the directory isn't a repository
+        let result = run_action(&dir, &GitAction::Commit("x".to_string()));
+        let error = result.unwrap_err();
+        assert!(!error.is_empty());
+        assert!(!error.contains('\n'));
+    }
+}
@@ -0,0 +1,337 @@
+// Chunk: docs/chunks/git_status_watcher - Branch and changed files per workspace
+//!
+//! The git branch and changed files of each workspace.
+//!
+//! [`GitStatusWatcher`] runs `git status` in the background for every
+//! workspace root every few seconds (and right after a save), and keeps the
+//! last [`RepoStatus`] of each. `EditorState` shows the branch and the number
+//! of changed files in the status bar and when the pointer is over a
+//! workspace's tile in the left rail, and lists the changed files when the
+//! status bar is clicked.
+//!
+//! Output parsing is a pure function so it can be unit-tested without a
+//! repository.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::event_channel::EventSender;
+
+/// How long a workspace's status is kept before `git status` runs again.
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A file with uncommitted changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedFile {
+    /// The two-letter `git status --porcelain` code: the index's state, then
+    /// the work tree's (e.g. `" M"`, `"A "`, `"??"`)
+    pub code: String,
+    /// Absolute path of the file
+    pub path: PathBuf,
+}
+
+impl ChangedFile {
+    /// Returns true if the file is gone from the work tree.
+    pub fn is_deleted(&self) -> bool {
+        self.code.ends_with('D')
+    }
+
+    // Chunk: docs/chunks/git_commit_flow - Staged and unstaged halves of a change
+    /// Returns true if some of the change is staged for the next commit.
+    pub fn is_staged(&self) -> bool {
+        !self.code.starts_with([' ', '?'])
+    }
+
+    /// Returns true if some of the change is not staged, untracked files
+    /// included.
+    pub fn has_unstaged(&self) -> bool {
+        !self.code.ends_with(' ')
+    }
+}
+
+/// The state of the repository holding a workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoStatus {
+    /// The checked-out branch, or `None` for a detached `HEAD`
+    pub branch: Option<String>,
+    /// Files with uncommitted changes, untracked files included
+    pub changed: Vec<ChangedFile>,
+}
+
+impl RepoStatus {
+    /// Returns the branch name, or "detached HEAD".
+    pub fn branch_label(&self) -> &str {
+        self.branch.as_deref().unwrap_or("detached HEAD")
+    }
+
+    /// Returns the status bar text, e.g. "main • 3 changed" or "main • clean".
+    pub fn summary(&self) -> String {
+        if self.changed.is_empty() {
+            format!("{} • clean", self.branch_label())
+        } else {
+            format!("{} • {} changed", self.branch_label(), self.changed.len())
+        }
+    }
+}
+
+/// Parses the output of `git status --porcelain -b -z -- .` run in `root`,
+/// which is `prefix` (as printed by `git rev-parse --show-prefix`, e.g.
+/// `"crates/editor/"`) below the repository's top-level directory.
+///
+/// Git prints paths from the top-level directory; they are made absolute
+/// with `root` so they match the workspace's paths even when `root` is
+/// reached through a symlink. Returns `None` if the output has no branch
+/// header.
+pub fn parse_status(output: &str, root: &Path, prefix: &str) -> Option<RepoStatus> {
+    let mut entries = output.split('\0').filter(|entry| !entry.is_empty());
+    let header = entries.next()?.strip_prefix("## ")?;
+    let branch = parse_branch(header);
+
+    let mut changed = Vec::new();
+    while let Some(entry) = entries.next() {
+        let (Some(code), Some(path)) = (entry.get(..2), entry.get(3..)) else {
+            continue;
+        };
+        // A rename or copy is followed by the path it came from
+        if code.contains(['R', 'C']) {
+            entries.next();
+        }
+        let path = path.strip_prefix(prefix).unwrap_or(path);
+        changed.push(ChangedFile {
+            code: code.to_string(),
+            path: root.join(path),
+        });
+    }
+    Some(RepoStatus { branch, changed })
+}
+
+/// Returns the branch named by a `## ` header, e.g. `main...origin/main
+/// [ahead 1]` or `No commits yet on main`.
+fn parse_branch(header: &str) -> Option<String> {
+    if header.starts_with("HEAD (no branch)") {
+        return None;
+    }
+    let header = header
+        .strip_prefix("No commits yet on ")
+        .or_else(|| header.strip_prefix("Initial commit on "))
+        .unwrap_or(header);
+    let end = [header.find("..."), header.find(' ')]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(header.len());
+    Some(header[..end].to_string())
+}
+
+/// Runs `git status` for the files under `root`. Returns `None` outside a
+/// repository or when git fails.
+pub fn run_status(root: &Path) -> Option<RepoStatus> {
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+    let prefix = git(&["rev-parse", "--show-prefix"])?;
+    let output = git(&["status", "--porcelain", "-b", "-z", "--", "."])?;
+    parse_status(&output, root, prefix.trim_end_matches('\n'))
+}
+
+/// A finished `git status` run.
+struct StatusResult {
+    root: PathBuf,
+    status: Option<RepoStatus>,
+}
+
+/// What is known about one workspace root.
+#[derive(Default)]
+struct WatchedRoot {
+    /// The last status, once a run has finished (`Some(None)` outside a
+    /// repository)
+    status: Option<Option<RepoStatus>>,
+    /// When the last run started
+    checked: Option<Instant>,
+    /// True while a run is going
+    running: bool,
+    /// True if the status was asked for again while the run was going
+    stale: bool,
+}
+
+/// Keeps the git status of each workspace root up to date.
+///
+/// At most one run per root is in flight; results come back over a channel
+/// and are picked up by [`poll`](Self::poll).
+pub struct GitStatusWatcher {
+    roots: HashMap<PathBuf, WatchedRoot>,
+    sender: Sender<StatusResult>,
+    results: Receiver<StatusResult>,
+    /// Wakes the event loop when a run finishes
+    wakeup: Option<EventSender>,
+}
+
+impl Default for GitStatusWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GitStatusWatcher {
+    /// Creates a watcher with no roots.
+    pub fn new() -> Self {
+        let (sender, results) = mpsc::channel();
+        Self {
+            roots: HashMap::new(),
+            sender,
+            results,
+            wakeup: None,
+        }
+    }
+
+    /// Sets the sender used to wake the event loop when a run finishes.
+    pub fn set_wakeup(&mut self, sender: EventSender) {
+        self.wakeup = Some(sender);
+    }
+
+    /// Starts a run for each of `roots` whose status is older than
+    /// [`REFRESH_INTERVAL`], and forgets roots not in the list.
+    pub fn watch(&mut self, roots: &[PathBuf]) {
+        self.roots.retain(|root, _| roots.contains(root));
+        let now = Instant::now();
+        for root in roots {
+            let watched = self.roots.entry(root.clone()).or_default();
+            let due = watched
+                .checked
+                .is_none_or(|checked| now.duration_since(checked) >= REFRESH_INTERVAL);
+            if watched.running || !due {
+                continue;
+            }
+            watched.running = true;
+            watched.checked = Some(now);
+            let root = root.clone();
+            let sender = self.sender.clone();
+            let wakeup = self.wakeup.clone();
+            thread::spawn(move || {
+                let status = run_status(&root);
+                let _ = sender.send(StatusResult { root, status });
+                if let Some(wakeup) = wakeup {
+                    let _ = wakeup.send_pty_wakeup();
+                }
+            });
+        }
+    }
+
+    /// Makes the next [`watch`](Self::watch) run `git status` in `root`
+    /// again, e.g. after a file in it was saved.
+    pub fn refresh(&mut self, root: &Path) {
+        if let Some(watched) = self.roots.get_mut(root) {
+            watched.checked = None;
+            watched.stale = watched.running;
+        }
+    }
+
+    /// Takes the results of finished runs. Returns true if any root's
+    /// status changed.
+    pub fn poll(&mut self) -> bool {
+        let mut changed = false;
+        while let Ok(result) = self.results.try_recv() {
+            let Some(watched) = self.roots.get_mut(&result.root) else {
+                continue;
+            };
+            watched.running = false;
+            if std::mem::take(&mut watched.stale) {
+                watched.checked = None;
+            }
+            if watched.status.as_ref() != Some(&result.status) {
+                watched.status = Some(result.status);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Returns the status of the repository holding `root`, once known and
+    /// if there is one.
+    pub fn status(&self, root: &Path) -> Option<&RepoStatus> {
+        self.roots.get(root)?.status.as_ref()?.as_ref()
+    }
+
+    /// Records `status` for `root` as if a run had just finished.
+    #[cfg(test)]
+    pub fn set_status(&mut self, root: &Path, status: Option<RepoStatus>) {
+        let watched = self.roots.entry(root.to_path_buf()).or_default();
+        watched.status = Some(status);
+        watched.checked = Some(Instant::now());
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_status_entries() {
+        let output = "## main...origin/main [ahead 1]\0 M src/lib.rs\0\
+                      R  new.rs\0old.rs\0?? notes.md\0 D gone.rs\0";
+        let status = parse_status(output, Path::new("/repo"), "").unwrap();
+        assert_eq!(status.branch.as_deref(), Some("main"));
+        let changed: Vec<(&str, &Path)> = status
+            .changed
+            .iter()
+            .map(|file| (file.code.as_str(), file.path.as_path()))
+            .collect();
+        assert_eq!(
+            changed,
+            vec![
+                (" M", Path::new("/repo/src/lib.rs")),
+                ("R ", Path::new("/repo/new.rs")),
+                ("??", Path::new("/repo/notes.md")),
+                (" D", Path::new("/repo/gone.rs")),
+            ]
+        );
+        assert!(status.changed[3].is_deleted());
+        assert!(!status.changed[0].is_staged() && status.changed[0].has_unstaged());
+        assert!(status.changed[1].is_staged() && !status.changed[1].has_unstaged());
+        assert!(!status.changed[2].is_staged() && status.changed[2].has_unstaged());
+        assert_eq!(status.summary(), "main • 4 changed");
+    }
+
+    #[test]
+    fn test_parse_branch_headers() {
+        let branch = |header: &str| {
+            let output = format!("## {}\0", header);
+            parse_status(&output, Path::new("/repo"), "").unwrap().branch
+        };
+        assert_eq!(branch("feature/x"), Some("feature/x".to_string()));
+        assert_eq!(branch("main...origin/main"), Some("main".to_string()));
+        assert_eq!(branch("No commits yet on trunk"), Some("trunk".to_string()));
+        assert_eq!(branch("HEAD (no branch)"), None);
+    }
+
+    #[test]
+    fn test_summary_of_clean_and_detached() {
+        let status = parse_status("## HEAD (no branch)\0", Path::new("/repo"), "").unwrap();
+        assert_eq!(status.summary(), "detached HEAD • clean");
+        assert!(parse_status("", Path::new("/repo"), "").is_none());
+    }
+
+    #[test]
+    fn test_paths_are_relative_to_the_workspace_root() {
+        let output = "## main\0 M crates/editor/src/main.rs\0";
+        let root = Path::new("/home/me/link-to-editor");
+        let status = parse_status(output, root, "crates/editor/").unwrap();
+        assert_eq!(status.changed[0].path, root.join("src/main.rs"));
+    }
+}
@@ -64,6 +64,109 @@ pub enum GlobalAction {
     MoveTab(Direction),
     /// Switch focus to pane in direction (Cmd+Option+Arrow)
     SwitchFocus(Direction),
+    // Chunk: docs/chunks/shortcut_help_overlay - Shortcut help action
+    /// Toggle the keyboard shortcut help overlay (Cmd+?)
+    ShortcutHelp,
+    // Chunk: docs/chunks/diff_view - Working-tree diff action
+    /// Show the uncommitted changes in a diff tab (Cmd+Shift+G)
+    ShowDiff,
+    // Chunk: docs/chunks/pane_zoom - Pane zoom action
+    /// Toggle zoom of the focused pane (Cmd+Shift+Return)
+    ZoomPane,
+    // Chunk: docs/chunks/workspace_rename_reorder - Workspace rename action
+    /// Rename the active workspace (Cmd+Shift+N)
+    RenameWorkspace,
+    // Chunk: docs/chunks/recent_files_switcher - Recent files action
+    /// Show the workspace's recently active files (Cmd+E)
+    RecentFiles,
+    // Chunk: docs/chunks/project_content_search - Project search action
+    /// Search file contents across the workspace (Cmd+Shift+F)
+    ProjectSearch,
+    // Chunk: docs/chunks/font_config_zoom - Font size actions
+    /// Increase the font size (Cmd+Plus)
+    IncreaseFontSize,
+    /// Decrease the font size (Cmd+Minus)
+    DecreaseFontSize,
+    /// Reset the font size to the configured size (Cmd+0)
+    ResetFontSize,
+    // Chunk: docs/chunks/shell_command_runner - Run command action
+    /// Run a shell command in a command output tab (Cmd+Shift+R)
+    RunCommand,
+    // Chunk: docs/chunks/task_runner - Run task action
+    /// Run a workspace task in a command output tab (Cmd+Option+R)
+    RunTask,
+    // Chunk: docs/chunks/problem_matcher - Problems action
+    /// Show compiler problems in a problems tab (Cmd+Shift+M)
+    ShowProblems,
+    // Chunk: docs/chunks/terminal_memory_budget - Terminal Memory action
+    /// Show each terminal's memory in a Terminal Memory tab (Cmd+Shift+Option+M)
+    ShowTerminalMemory,
+    // Chunk: docs/chunks/agent_resource_usage - Agents action
+    /// Show every agent's state and resource use in an Agents tab (Cmd+Shift+Option+A)
+    ShowAgents,
+    // Chunk: docs/chunks/agent_events - Agent timeline action
+    /// Show the agent's reported events beside its terminal (Cmd+Shift+Option+E)
+    ShowAgentTimeline,
+    // Chunk: docs/chunks/agent_edit_review - Review action
+    /// Review the edits the agent proposed (Cmd+Shift+Option+D)
+    ShowEditReview,
+    // Chunk: docs/chunks/agent_templates - New agent action
+    /// Launch an agent from a saved template (Cmd+Option+A)
+    NewAgent,
+    // Chunk: docs/chunks/print_pdf_export - Print and PDF export actions
+    /// Print the active file (Cmd+Option+P)
+    Print,
+    /// Export the active file as a PDF (Cmd+Shift+Option+P)
+    ExportPdf,
+    // Chunk: docs/chunks/read_only_preview_tabs - Read-only toggle action
+    /// Make the active file tab read-only or editable (Cmd+Shift+L)
+    ToggleReadOnly,
+    // Chunk: docs/chunks/pinned_tabs_overflow - Pin toggle action
+    /// Pin the active tab to the left of its pane, or unpin it (Cmd+Shift+K)
+    TogglePinTab,
+    // Chunk: docs/chunks/last_tab_toggle - Last active tab action
+    /// Switch back to the pane's previously active tab (Cmd+Shift+`)
+    LastActiveTab,
+    // Chunk: docs/chunks/scratch_buffers - New scratch buffer action
+    /// Create a scratch buffer, choosing its language (Cmd+Option+N)
+    NewScratchBuffer,
+    // Chunk: docs/chunks/compare_tabs - Compare with tab action
+    /// Compare the active tab with another open tab (Cmd+Option+G)
+    CompareWith,
+    // Chunk: docs/chunks/git_blame_inline - Blame mode action
+    /// Show or hide the cursor line's git blame (Cmd+Option+B)
+    ToggleBlame,
+    // Chunk: docs/chunks/git_commit_flow - Changes tab action
+    /// Show the Changes tab for staging and committing (Cmd+Option+C)
+    ShowChanges,
+    // Chunk: docs/chunks/settings_tab - Settings tab action
+    /// Show the Settings tab (Cmd+,)
+    ShowSettings,
+    // Chunk: docs/chunks/keybinding_conflicts - Keyboard Shortcuts tab action
+    /// Show the Keyboard Shortcuts tab (Cmd+Option+K)
+    EditShortcuts,
+}
+
+// Chunk: docs/chunks/press_and_hold_key_repeat - Which shortcuts auto-repeat
+impl GlobalAction {
+    /// Returns true if holding the shortcut should repeat the action.
+    ///
+    /// Stepping through tabs, workspaces, panes and font sizes repeats like
+    /// arrow keys do. Everything else fires once per press, so holding Cmd+W
+    /// or Cmd+T doesn't close or open a tab per repeat.
+    pub fn repeats(self) -> bool {
+        matches!(
+            self,
+            GlobalAction::NextTab
+                | GlobalAction::PrevTab
+                | GlobalAction::NextWorkspace
+                | GlobalAction::PrevWorkspace
+                | GlobalAction::MoveTab(_)
+                | GlobalAction::SwitchFocus(_)
+                | GlobalAction::IncreaseFontSize
+                | GlobalAction::DecreaseFontSize
+        )
+    }
 }
 
 /// Global keyboard shortcut focus target.
@@ -109,68 +212,128 @@ impl GlobalShortcutTarget {
     }
 
     /// Resolves a key event to a global action.
-    ///
-    /// Returns `Some(action)` if the key event is a global shortcut,
-    /// `None` otherwise.
     fn resolve_action(&self, event: &KeyEvent) -> Option<GlobalAction> {
-        // Only handle Cmd+key shortcuts (without Ctrl)
-        if !event.modifiers.command || event.modifiers.control {
-            return None;
-        }
+        resolve_global_action(event)
+    }
+}
 
-        match &event.key {
-            Key::Char('q') => Some(GlobalAction::Quit),
-            Key::Char('p') => Some(GlobalAction::ToggleFilePicker),
-            Key::Char('s') => Some(GlobalAction::Save),
-            Key::Char('f') => Some(GlobalAction::Find),
-            Key::Char('n') if !event.modifiers.shift => Some(GlobalAction::NewWorkspace),
-            Key::Char('o') => Some(GlobalAction::OpenFilePicker),
-            Key::Char('w') if event.modifiers.shift => Some(GlobalAction::CloseWorkspace),
-            Key::Char('w') => Some(GlobalAction::CloseTab),
-            Key::Char(']') if event.modifiers.shift => Some(GlobalAction::NextTab),
-            Key::Char('[') if event.modifiers.shift => Some(GlobalAction::PrevTab),
-            Key::Char(']') => Some(GlobalAction::NextWorkspace),
-            Key::Char('[') => Some(GlobalAction::PrevWorkspace),
-            Key::Char('t') if event.modifiers.shift => Some(GlobalAction::NewTerminalTab),
-            Key::Char('t') => Some(GlobalAction::NewTab),
-            Key::Char(c) if !event.modifiers.shift => {
-                // Cmd+1..9 for workspace switching
-                if let Some(digit) = c.to_digit(10) {
-                    if digit >= 1 && digit <= 9 {
-                        let idx = (digit - 1) as usize;
-                        return Some(GlobalAction::SwitchWorkspace(idx));
-                    }
+/// Resolves a key event to a global action.
+///
+/// Returns `Some(action)` if the key event is a global shortcut,
+/// `None` otherwise.
+pub fn resolve_global_action(event: &KeyEvent) -> Option<GlobalAction> {
+    // Only handle Cmd+key shortcuts (without Ctrl)
+    if !event.modifiers.command || event.modifiers.control {
+        return None;
+    }
+
+    match &event.key {
+        Key::Char('q') => Some(GlobalAction::Quit),
+        Key::Char('p') if event.modifiers.option && event.modifiers.shift => {
+            Some(GlobalAction::ExportPdf)
+        }
+        Key::Char('p') if event.modifiers.option => Some(GlobalAction::Print),
+        Key::Char('p') => Some(GlobalAction::ToggleFilePicker),
+        Key::Char('s') => Some(GlobalAction::Save),
+        Key::Char('f') if event.modifiers.shift => Some(GlobalAction::ProjectSearch),
+        Key::Char('f') => Some(GlobalAction::Find),
+        Key::Char('n') if event.modifiers.option && !event.modifiers.shift => {
+            Some(GlobalAction::NewScratchBuffer)
+        }
+        Key::Char('n') if !event.modifiers.shift => Some(GlobalAction::NewWorkspace),
+        Key::Char('n') => Some(GlobalAction::RenameWorkspace),
+        Key::Char('e') if event.modifiers.shift && event.modifiers.option => {
+            Some(GlobalAction::ShowAgentTimeline)
+        }
+        Key::Char('d') if event.modifiers.shift && event.modifiers.option => {
+            Some(GlobalAction::ShowEditReview)
+        }
+        Key::Char('e') if !event.modifiers.shift => Some(GlobalAction::RecentFiles),
+        Key::Char('o') => Some(GlobalAction::OpenFilePicker),
+        Key::Char('w') if event.modifiers.shift => Some(GlobalAction::CloseWorkspace),
+        Key::Char('w') => Some(GlobalAction::CloseTab),
+        Key::Char(']') if event.modifiers.shift => Some(GlobalAction::NextTab),
+        Key::Char('[') if event.modifiers.shift => Some(GlobalAction::PrevTab),
+        Key::Char(']') => Some(GlobalAction::NextWorkspace),
+        Key::Char('[') => Some(GlobalAction::PrevWorkspace),
+        Key::Char('t') if event.modifiers.shift => Some(GlobalAction::NewTerminalTab),
+        Key::Char('t') => Some(GlobalAction::NewTab),
+        Key::Char('?') => Some(GlobalAction::ShortcutHelp),
+        Key::Char('/') if event.modifiers.shift => Some(GlobalAction::ShortcutHelp),
+        Key::Char('g') if event.modifiers.option && !event.modifiers.shift => {
+            Some(GlobalAction::CompareWith)
+        }
+        Key::Char('g') if event.modifiers.shift => Some(GlobalAction::ShowDiff),
+        Key::Char('b') if event.modifiers.option && !event.modifiers.shift => {
+            Some(GlobalAction::ToggleBlame)
+        }
+        Key::Char('c') if event.modifiers.option && !event.modifiers.shift => {
+            Some(GlobalAction::ShowChanges)
+        }
+        Key::Char(',') if !event.modifiers.shift && !event.modifiers.option => {
+            Some(GlobalAction::ShowSettings)
+        }
+        Key::Char('r') if event.modifiers.shift => Some(GlobalAction::RunCommand),
+        Key::Char('r') if event.modifiers.option => Some(GlobalAction::RunTask),
+        Key::Char('m') if event.modifiers.shift && event.modifiers.option => {
+            Some(GlobalAction::ShowTerminalMemory)
+        }
+        Key::Char('m') if event.modifiers.shift => Some(GlobalAction::ShowProblems),
+        Key::Char('a') if event.modifiers.shift && event.modifiers.option => {
+            Some(GlobalAction::ShowAgents)
+        }
+        Key::Char('a') if event.modifiers.option && !event.modifiers.shift => {
+            Some(GlobalAction::NewAgent)
+        }
+        Key::Char('l') if event.modifiers.shift => Some(GlobalAction::ToggleReadOnly),
+        Key::Char('k') if event.modifiers.option && !event.modifiers.shift => {
+            Some(GlobalAction::EditShortcuts)
+        }
+        Key::Char('k') if event.modifiers.shift => Some(GlobalAction::TogglePinTab),
+        Key::Char('`') | Key::Char('~') if event.modifiers.shift => {
+            Some(GlobalAction::LastActiveTab)
+        }
+        Key::Return if event.modifiers.shift => Some(GlobalAction::ZoomPane),
+        Key::Char('=') | Key::Char('+') => Some(GlobalAction::IncreaseFontSize),
+        Key::Char('-') if !event.modifiers.shift => Some(GlobalAction::DecreaseFontSize),
+        Key::Char('0') if !event.modifiers.shift => Some(GlobalAction::ResetFontSize),
+        Key::Char(c) if !event.modifiers.shift => {
+            // Cmd+1..9 for workspace switching
+            if let Some(digit) = c.to_digit(10) {
+                if digit >= 1 && digit <= 9 {
+                    let idx = (digit - 1) as usize;
+                    return Some(GlobalAction::SwitchWorkspace(idx));
                 }
-                None
-            }
-            // Cmd+Shift+Arrow for tab movement
-            Key::Right if event.modifiers.shift && !event.modifiers.option => {
-                Some(GlobalAction::MoveTab(Direction::Right))
-            }
-            Key::Left if event.modifiers.shift && !event.modifiers.option => {
-                Some(GlobalAction::MoveTab(Direction::Left))
-            }
-            Key::Down if event.modifiers.shift && !event.modifiers.option => {
-                Some(GlobalAction::MoveTab(Direction::Down))
-            }
-            Key::Up if event.modifiers.shift && !event.modifiers.option => {
-                Some(GlobalAction::MoveTab(Direction::Up))
-            }
-            // Cmd+Option+Arrow for focus switching
-            Key::Right if event.modifiers.option && !event.modifiers.shift => {
-                Some(GlobalAction::SwitchFocus(Direction::Right))
-            }
-            Key::Left if event.modifiers.option && !event.modifiers.shift => {
-                Some(GlobalAction::SwitchFocus(Direction::Left))
-            }
-            Key::Down if event.modifiers.option && !event.modifiers.shift => {
-                Some(GlobalAction::SwitchFocus(Direction::Down))
             }
-            Key::Up if event.modifiers.option && !event.modifiers.shift => {
-                Some(GlobalAction::SwitchFocus(Direction::Up))
-            }
-            _ => None,
+            None
+        }
+        // Cmd+Shift+Arrow for tab movement
+        Key::Right if event.modifiers.shift && !event.modifiers.option => {
+            Some(GlobalAction::MoveTab(Direction::Right))
+        }
+        Key::Left if event.modifiers.shift && !event.modifiers.option => {
+            Some(GlobalAction::MoveTab(Direction::Left))
+        }
+        Key::Down if event.modifiers.shift && !event.modifiers.option => {
+            Some(GlobalAction::MoveTab(Direction::Down))
+        }
+        Key::Up if event.modifiers.shift && !event.modifiers.option => {
+            Some(GlobalAction::MoveTab(Direction::Up))
         }
+        // Cmd+Option+Arrow for focus switching
+        Key::Right if event.modifiers.option && !event.modifiers.shift => {
+            Some(GlobalAction::SwitchFocus(Direction::Right))
+        }
+        Key::Left if event.modifiers.option && !event.modifiers.shift => {
+            Some(GlobalAction::SwitchFocus(Direction::Left))
+        }
+        Key::Down if event.modifiers.option && !event.modifiers.shift => {
+            Some(GlobalAction::SwitchFocus(Direction::Down))
+        }
+        Key::Up if event.modifiers.option && !event.modifiers.shift => {
+            Some(GlobalAction::SwitchFocus(Direction::Up))
+        }
+        _ => None,
     }
 }
 
@@ -273,6 +436,214 @@ mod tests {
         assert_eq!(target.take_action(), Some(GlobalAction::Save));
     }
 
+    // Chunk: docs/chunks/shortcut_help_overlay - Cmd+? resolves to ShortcutHelp
+    #[test]
+    fn global_target_handles_cmd_question_mark() {
+        let mut target = GlobalShortcutTarget::new();
+        let mut buffer = TextBuffer::new();
+        let mut viewport = Viewport::new(16.0);
+        let mut dirty = DirtyRegion::None;
+        let mut dirty_lines = DirtyLines::None;
+        let mut ctx = make_test_context(&mut buffer, &mut viewport, &mut dirty, &mut dirty_lines);
+
+        let result = target.handle_key(cmd_key('?'), &mut ctx);
+
+        assert_eq!(result, Handled::Yes);
+        assert_eq!(target.take_action(), Some(GlobalAction::ShortcutHelp));
+    }
+
+    // Chunk: docs/chunks/diff_view - Cmd+Shift+G resolves to ShowDiff
+    #[test]
+    fn global_target_handles_cmd_shift_g() {
+        let mut target = GlobalShortcutTarget::new();
+        let mut buffer = TextBuffer::new();
+        let mut viewport = Viewport::new(16.0);
+        let mut dirty = DirtyRegion::None;
+        let mut dirty_lines = DirtyLines::None;
+        let mut ctx = make_test_context(&mut buffer, &mut viewport, &mut dirty, &mut dirty_lines);
+
+        let cmd_shift_g = KeyEvent::new(
+            Key::Char('g'),
+            Modifiers {
+                command: true,
+                shift: true,
+                ..Default::default()
+            },
+        );
+        let result = target.handle_key(cmd_shift_g, &mut ctx);
+
+        assert_eq!(result, Handled::Yes);
+        assert_eq!(target.take_action(), Some(GlobalAction::ShowDiff));
+    }
+
+    // Chunk: docs/chunks/read_only_preview_tabs - Cmd+Shift+L resolves to ToggleReadOnly
+    #[test]
+    fn global_target_handles_cmd_shift_l() {
+        let cmd_shift_l = KeyEvent::new(
+            Key::Char('l'),
+            Modifiers {
+                command: true,
+                shift: true,
+                ..Default::default()
+            },
+        );
+        let action = resolve_global_action(&cmd_shift_l);
+        assert_eq!(action, Some(GlobalAction::ToggleReadOnly));
+    }
+
+    // Chunk: docs/chunks/pinned_tabs_overflow - Cmd+Shift+K resolves to TogglePinTab
+    #[test]
+    fn global_target_handles_cmd_shift_k() {
+        let cmd_shift_k = KeyEvent::new(
+            Key::Char('k'),
+            Modifiers {
+                command: true,
+                shift: true,
+                ..Default::default()
+            },
+        );
+        let action = resolve_global_action(&cmd_shift_k);
+        assert_eq!(action, Some(GlobalAction::TogglePinTab));
+    }
+
+    // Chunk: docs/chunks/last_tab_toggle - Cmd+Shift+` resolves to LastActiveTab
+    #[test]
+    fn global_target_handles_cmd_shift_backtick() {
+        for ch in ['`', '~'] {
+            let event = KeyEvent::new(
+                Key::Char(ch),
+                Modifiers {
+                    command: true,
+                    shift: true,
+                    ..Default::default()
+                },
+            );
+            let action = resolve_global_action(&event);
+            assert_eq!(action, Some(GlobalAction::LastActiveTab));
+        }
+    }
+
+    // Chunk: docs/chunks/pane_zoom - Cmd+Shift+Return resolves to ZoomPane
+    #[test]
+    fn global_target_handles_cmd_shift_return() {
+        let mut target = GlobalShortcutTarget::new();
+        let mut buffer = TextBuffer::new();
+        let mut viewport = Viewport::new(16.0);
+        let mut dirty = DirtyRegion::None;
+        let mut dirty_lines = DirtyLines::None;
+        let mut ctx = make_test_context(&mut buffer, &mut viewport, &mut dirty, &mut dirty_lines);
+
+        let cmd_shift_return = KeyEvent::new(
+            Key::Return,
+            Modifiers {
+                command: true,
+                shift: true,
+                ..Default::default()
+            },
+        );
+        let result = target.handle_key(cmd_shift_return, &mut ctx);
+
+        assert_eq!(result, Handled::Yes);
+        assert_eq!(target.take_action(), Some(GlobalAction::ZoomPane));
+    }
+
+    // Chunk: docs/chunks/workspace_rename_reorder - Cmd+Shift+N resolves to RenameWorkspace
+    #[test]
+    fn global_target_handles_cmd_shift_n() {
+        let mut target = GlobalShortcutTarget::new();
+        let mut buffer = TextBuffer::new();
+        let mut viewport = Viewport::new(16.0);
+        let mut dirty = DirtyRegion::None;
+        let mut dirty_lines = DirtyLines::None;
+        let mut ctx = make_test_context(&mut buffer, &mut viewport, &mut dirty, &mut dirty_lines);
+
+        let cmd_shift_n = KeyEvent::new(
+            Key::Char('n'),
+            Modifiers {
+                command: true,
+                shift: true,
+                ..Default::default()
+            },
+        );
+        let result = target.handle_key(cmd_shift_n, &mut ctx);
+
+        assert_eq!(result, Handled::Yes);
+        assert_eq!(target.take_action(), Some(GlobalAction::RenameWorkspace));
+    }
+
+    // Chunk: docs/chunks/recent_files_switcher - Cmd+E resolves to RecentFiles
+    #[test]
+    fn global_target_handles_cmd_e() {
+        let mut target = GlobalShortcutTarget::new();
+        let mut buffer = TextBuffer::new();
+        let mut viewport = Viewport::new(16.0);
+        let mut dirty = DirtyRegion::None;
+        let mut dirty_lines = DirtyLines::None;
+        let mut ctx = make_test_context(&mut buffer, &mut viewport, &mut dirty, &mut dirty_lines);
+
+        let cmd_e = KeyEvent::new(
+            Key::Char('e'),
+            Modifiers {
+                command: true,
+                ..Default::default()
+            },
+        );
+        let result = target.handle_key(cmd_e, &mut ctx);
+
+        assert_eq!(result, Handled::Yes);
+        assert_eq!(target.take_action(), Some(GlobalAction::RecentFiles));
+    }
+
+    // Chunk: docs/chunks/project_content_search - Cmd+Shift+F resolves to ProjectSearch
+    #[test]
+    fn global_target_handles_cmd_shift_f() {
+        let mut target = GlobalShortcutTarget::new();
+        let mut buffer = TextBuffer::new();
+        let mut viewport = Viewport::new(16.0);
+        let mut dirty = DirtyRegion::None;
+        let mut dirty_lines = DirtyLines::None;
+        let mut ctx = make_test_context(&mut buffer, &mut viewport, &mut dirty, &mut dirty_lines);
+
+        let cmd_shift_f = KeyEvent::new(
+            Key::Char('f'),
+            Modifiers {
+                command: true,
+                shift: true,
+                ..Default::default()
+            },
+        );
+        let result = target.handle_key(cmd_shift_f, &mut ctx);
+
+        assert_eq!(result, Handled::Yes);
+        assert_eq!(target.take_action(), Some(GlobalAction::ProjectSearch));
+    }
+
+    // Chunk: docs/chunks/font_config_zoom - Cmd+Plus/Minus/0 resolve to font size actions
+    #[test]
+    fn global_target_handles_font_size_keys() {
+        let mut target = GlobalShortcutTarget::new();
+        let mut buffer = TextBuffer::new();
+        let mut viewport = Viewport::new(16.0);
+        let mut dirty = DirtyRegion::None;
+        let mut dirty_lines = DirtyLines::None;
+        let mut ctx = make_test_context(&mut buffer, &mut viewport, &mut dirty, &mut dirty_lines);
+
+        let cmd = Modifiers {
+            command: true,
+            ..Default::default()
+        };
+        let cases = [
+            ('=', GlobalAction::IncreaseFontSize),
+            ('-', GlobalAction::DecreaseFontSize),
+            ('0', GlobalAction::ResetFontSize),
+        ];
+        for (c, action) in cases {
+            let result = target.handle_key(KeyEvent::new(Key::Char(c), cmd), &mut ctx);
+            assert_eq!(result, Handled::Yes);
+            assert_eq!(target.take_action(), Some(action));
+        }
+    }
+
     #[test]
     fn global_target_ignores_plain_keys() {
         let mut target = GlobalShortcutTarget::new();
@@ -343,4 +714,28 @@ mod tests {
         let target = GlobalShortcutTarget::new();
         assert_eq!(target.layer(), FocusLayer::GlobalShortcuts);
     }
+
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Repeatable shortcuts
+    #[test]
+    fn stepping_shortcuts_repeat_and_others_fire_once() {
+        let shift_cmd = |ch| {
+            KeyEvent::new(
+                Key::Char(ch),
+                Modifiers {
+                    command: true,
+                    shift: true,
+                    ..Default::default()
+                },
+            )
+        };
+        let repeats = |event: KeyEvent| resolve_global_action(&event).map(GlobalAction::repeats);
+
+        assert_eq!(repeats(shift_cmd(']')), Some(true));
+        assert_eq!(repeats(cmd_key('[')), Some(true));
+        assert_eq!(repeats(cmd_key('=')), Some(true));
+        assert_eq!(repeats(cmd_key('w')), Some(false));
+        assert_eq!(repeats(cmd_key('t')), Some(false));
+        assert_eq!(repeats(cmd_key('q')), Some(false));
+        assert_eq!(repeats(plain_key('w')), None);
+    }
 }
@@ -1,6 +1,7 @@
 // Subsystem: docs/subsystems/renderer - GPU-accelerated text and UI rendering
 // Chunk: docs/chunks/glyph_rendering - Monospace glyph atlas + text rendering
 // Chunk: docs/chunks/terminal_background_box_drawing - On-demand glyph addition for terminal rendering
+// Chunk: docs/chunks/color_emoji_wide_glyphs - RGBA color page and double-cell wide glyphs
 //!
 //! Glyph atlas for texture-based text rendering
 //!
@@ -13,20 +14,30 @@
 //! - Pre-populate printable ASCII (0x20-0x7E) at startup
 //!
 //! Each glyph is stored with its UV coordinates for texture sampling.
+//!
+//! Glyphs from color fonts (Apple Color Emoji) requested for buffer text are
+//! kept in a second RGBA page, `color_texture`, so they keep their colors.
+//! East Asian wide characters and emoji are rasterized two cells wide.
+//!
+//! Bold and italic text is drawn from the font family's own faces, kept apart
+//! from regular glyphs by face. A family without a face (such as the bundled
+//! font) gets it synthesized: bold by striking the glyph twice, italic by
+//! slanting it.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ptr::NonNull;
 
 use objc2::rc::Retained;
 use objc2::runtime::ProtocolObject;
-use objc2_core_foundation::{CGFloat, CGPoint, CGRect, CGSize};
+use objc2_core_foundation::{CFRetained, CGAffineTransform, CGFloat, CGPoint, CGRect, CGSize};
 use objc2_core_graphics::{
     CGBitmapContextCreate, CGBitmapContextGetData, CGColorSpace, CGContext, CGImageAlphaInfo,
 };
 use objc2_metal::{MTLDevice, MTLPixelFormat, MTLRegion, MTLTexture, MTLTextureDescriptor};
 use objc2_core_text::CTFont;
+use unicode_width::UnicodeWidthChar;
 
-use crate::font::{Font, GlyphFont, GlyphSource};
+use crate::font::{Font, FontFace, GlyphFont, GlyphSource};
 
 // =============================================================================
 // Constants
@@ -35,6 +46,11 @@ use crate::font::{Font, GlyphFont, GlyphSource};
 /// Default atlas size (1024x1024 gives ~16K glyphs at 8x16 cell size)
 pub const ATLAS_SIZE: usize = 1024;
 
+// Chunk: docs/chunks/syntax_style_modifiers - Synthesized italic
+/// Horizontal shear of a synthesized italic glyph, per unit of height above
+/// the baseline (about 11 degrees)
+const SYNTHETIC_ITALIC_SLANT: f64 = 0.2;
+
 // =============================================================================
 // GlyphInfo
 // =============================================================================
@@ -80,6 +96,29 @@ pub struct GlyphAtlas {
 
     /// Padding between glyphs to prevent texture bleeding
     padding: usize,
+
+    // Chunk: docs/chunks/color_emoji_wide_glyphs - RGBA page for color glyphs
+    /// RGBA texture storing color glyphs with straight (non-premultiplied) alpha
+    color_texture: Retained<ProtocolObject<dyn MTLTexture>>,
+
+    /// Mapping from character to glyph info in the color page
+    color_glyphs: HashMap<char, GlyphInfo>,
+
+    /// Characters whose font draws in color but which were added to the
+    /// coverage page by `ensure_glyph` (e.g. for chrome labels)
+    color_chars: HashSet<char>,
+
+    /// Packing position and row height in the color page
+    color_cursor_x: usize,
+    color_cursor_y: usize,
+    color_row_height: usize,
+
+    // Chunk: docs/chunks/syntax_style_modifiers - Bold and italic glyphs
+    /// Glyphs drawn in a bold or italic face, in the coverage page
+    styled_glyphs: HashMap<(char, FontFace), GlyphInfo>,
+
+    /// The font's own faces, looked up once each; `None` means synthesized
+    faces: HashMap<FontFace, Option<CFRetained<CTFont>>>,
 }
 
 impl GlyphAtlas {
@@ -108,6 +147,19 @@ impl GlyphAtlas {
             .newTextureWithDescriptor(&descriptor)
             .expect("Failed to create atlas texture");
 
+        let color_descriptor = unsafe {
+            MTLTextureDescriptor::texture2DDescriptorWithPixelFormat_width_height_mipmapped(
+                MTLPixelFormat::RGBA8Unorm,
+                ATLAS_SIZE,
+                ATLAS_SIZE,
+                false,
+            )
+        };
+
+        let color_texture = device
+            .newTextureWithDescriptor(&color_descriptor)
+            .expect("Failed to create color atlas texture");
+
         let mut atlas = Self {
             texture,
             glyphs: HashMap::new(),
@@ -117,6 +169,14 @@ impl GlyphAtlas {
             cell_width,
             cell_height,
             padding: 1,
+            color_texture,
+            color_glyphs: HashMap::new(),
+            color_chars: HashSet::new(),
+            color_cursor_x: 0,
+            color_cursor_y: 0,
+            color_row_height: 0,
+            styled_glyphs: HashMap::new(),
+            faces: HashMap::new(),
         };
 
         // Pre-populate printable ASCII (0x20-0x7E)
@@ -137,6 +197,11 @@ impl GlyphAtlas {
         &self.texture
     }
 
+    /// Returns the RGBA texture holding color glyphs
+    pub fn color_texture(&self) -> &ProtocolObject<dyn MTLTexture> {
+        &self.color_texture
+    }
+
     /// Gets the glyph info for a character, or None if not in atlas
     pub fn get_glyph(&self, c: char) -> Option<&GlyphInfo> {
         self.glyphs.get(&c)
@@ -265,7 +330,8 @@ impl GlyphAtlas {
         }
 
         // Check if we have space
-        let glyph_width = self.cell_width;
+        // Chunk: docs/chunks/color_emoji_wide_glyphs - Wide characters get two cells
+        let glyph_width = self.glyph_width_for(c);
         let glyph_height = self.cell_height;
 
         // Check if we need to move to next row
@@ -289,6 +355,12 @@ impl GlyphAtlas {
                 self.rasterize_glyph(font, source.glyph_id, glyph_width, glyph_height)
             }
             GlyphFont::Fallback(fallback_font) => {
+                // Remember color glyphs drawn as coverage so buffer text can
+                // still get the color version from `ensure_text_glyph`
+                if Font::has_color_glyphs(fallback_font) {
+                    self.color_chars.insert(c);
+                }
+
                 // Query the fallback font's own metrics for proper scaling and positioning
                 let (fb_ascent, fb_descent, fb_line_height) =
                     Font::get_ct_font_metrics(fallback_font);
@@ -300,6 +372,7 @@ impl GlyphAtlas {
                     source.glyph_id,
                     glyph_width,
                     glyph_height,
+                    FontFace::Regular,
                 )
             }
         };
@@ -358,6 +431,109 @@ impl GlyphAtlas {
         true
     }
 
+    // Chunk: docs/chunks/color_emoji_wide_glyphs - Double-cell glyphs for wide characters
+    /// Returns the bitmap width for a character.
+    ///
+    /// East Asian wide characters and emoji occupy two terminal columns, so
+    /// they're rasterized across two cells instead of being clipped to one.
+    /// The anti-aliasing padding is kept once, not per cell.
+    fn glyph_width_for(&self, c: char) -> usize {
+        if UnicodeWidthChar::width(c) == Some(2) {
+            2 * self.cell_width - 2
+        } else {
+            self.cell_width
+        }
+    }
+
+    // Chunk: docs/chunks/color_emoji_wide_glyphs - Color glyphs in the RGBA page
+    /// Adds a glyph from a color font to the RGBA page.
+    ///
+    /// Returns true if the glyph was added, false if the color page is full.
+    fn add_color_glyph(&mut self, font: &Font, c: char, ct_font: &CTFont, glyph_id: u16) -> bool {
+        if self.color_glyphs.contains_key(&c) {
+            return true;
+        }
+
+        let glyph_width = self.glyph_width_for(c);
+        let glyph_height = self.cell_height;
+
+        // Check if we need to move to next row
+        if self.color_cursor_x + glyph_width + self.padding > ATLAS_SIZE {
+            self.color_cursor_x = 0;
+            self.color_cursor_y += self.color_row_height + self.padding;
+            self.color_row_height = 0;
+        }
+
+        // Check if we've run out of vertical space
+        if self.color_cursor_y + glyph_height > ATLAS_SIZE {
+            eprintln!("Warning: Color glyph atlas is full, cannot add '{}'", c);
+            return false;
+        }
+
+        let (_, fb_descent, fb_line_height) = Font::get_ct_font_metrics(ct_font);
+        let bitmap = self.rasterize_color_glyph(
+            ct_font,
+            fb_descent,
+            fb_line_height,
+            glyph_id,
+            glyph_width,
+            glyph_height,
+        );
+
+        let region = MTLRegion {
+            origin: objc2_metal::MTLOrigin {
+                x: self.color_cursor_x,
+                y: self.color_cursor_y,
+                z: 0,
+            },
+            size: objc2_metal::MTLSize {
+                width: glyph_width,
+                height: glyph_height,
+                depth: 1,
+            },
+        };
+
+        let bytes_ptr = NonNull::new(bitmap.as_ptr() as *mut std::ffi::c_void)
+            .expect("bitmap pointer should not be null");
+
+        // SAFETY: We're uploading a valid RGBA bitmap to the texture
+        unsafe {
+            self.color_texture
+                .replaceRegion_mipmapLevel_withBytes_bytesPerRow(
+                    region,
+                    0,
+                    bytes_ptr,
+                    glyph_width * 4,
+                );
+        }
+
+        let atlas_size = ATLAS_SIZE as f32;
+        let uv_min = (
+            self.color_cursor_x as f32 / atlas_size,
+            self.color_cursor_y as f32 / atlas_size,
+        );
+        let uv_max = (
+            (self.color_cursor_x + glyph_width) as f32 / atlas_size,
+            (self.color_cursor_y + glyph_height) as f32 / atlas_size,
+        );
+
+        let info = GlyphInfo {
+            uv_min,
+            uv_max,
+            width: glyph_width as f32,
+            height: glyph_height as f32,
+            bearing_x: 1.0, // Padding offset
+            bearing_y: font.metrics.ascent as f32,
+        };
+
+        self.color_glyphs.insert(c, info);
+
+        self.color_cursor_x += glyph_width + self.padding;
+        self.color_row_height = self.color_row_height.max(glyph_height);
+
+        true
+    }
+
     /// Adds a fully opaque (white) cell to the atlas.
     ///
     /// This provides a solid UV region that the cursor and other non-glyph
@@ -433,6 +609,7 @@ impl GlyphAtlas {
             glyph_id,
             width,
             height,
+            FontFace::Regular,
         )
     }
 
@@ -452,6 +629,8 @@ impl GlyphAtlas {
     /// * `glyph_id` - The glyph ID to rasterize
     /// * `width` - Target bitmap width (cell width)
     /// * `height` - Target bitmap height (cell height)
+    /// * `synthetic` - The face to fake on top of `ct_font` (`Regular` for none)
+    #[allow(clippy::too_many_arguments)]
     fn rasterize_glyph_with_ct_font(
         &self,
         ct_font: &CTFont,
@@ -461,6 +640,7 @@ impl GlyphAtlas {
         glyph_id: u16,
         width: usize,
         height: usize,
+        synthetic: FontFace,
     ) -> Vec<u8> {
         // Create a grayscale color space
         let color_space = CGColorSpace::new_device_gray();
@@ -505,86 +685,269 @@ impl GlyphAtlas {
         // Set the text color to white (this is what we'll draw the glyph with)
         CGContext::set_gray_fill_color(Some(&*context), 1.0, 1.0);
 
-        // Compute scale factor: scale down if the font's line_height exceeds cell height
-        let cell_height = height as f64;
-        let scale = if font_line_height > cell_height {
-            cell_height / font_line_height
-        } else {
-            1.0
+        draw_glyph_fitted(
+            &context,
+            ct_font,
+            font_descent,
+            font_line_height,
+            glyph_id,
+            height,
+            synthetic,
+        );
+
+        // Extract the bitmap data
+        let data = CGBitmapContextGetData(Some(&*context));
+
+        if data.is_null() {
+            return vec![0u8; width * height];
+        }
+
+        // Copy the data (Core Graphics manages the buffer)
+        let byte_count = width * height;
+        let mut result = vec![0u8; byte_count];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(data as *const u8, result.as_mut_ptr(), byte_count);
+        }
+
+        result
+    }
+
+    // Chunk: docs/chunks/color_emoji_wide_glyphs - RGBA rasterization for color fonts
+    /// Rasterizes a glyph from a color font into a straight-alpha RGBA bitmap.
+    ///
+    /// Scaling and positioning match `rasterize_glyph_with_ct_font`.
+    fn rasterize_color_glyph(
+        &self,
+        ct_font: &CTFont,
+        font_descent: f64,
+        font_line_height: f64,
+        glyph_id: u16,
+        width: usize,
+        height: usize,
+    ) -> Vec<u8> {
+        let color_space = CGColorSpace::new_device_rgb();
+
+        // RGBA with premultiplied alpha, the only alpha layout Core Graphics
+        // can draw into
+        let bitmap_info: u32 = CGImageAlphaInfo::PremultipliedLast.0;
+
+        let context = unsafe {
+            CGBitmapContextCreate(
+                std::ptr::null_mut(),
+                width,
+                height,
+                8,         // bits per component
+                width * 4, // bytes per row
+                color_space.as_deref(),
+                bitmap_info,
+            )
         };
 
-        // Position for drawing. Core Graphics has origin at bottom-left.
-        // The baseline is positioned so that:
-        // - For scale=1.0: baseline at y = descent (standard positioning)
-        // - For scale<1.0: glyph is scaled and vertically centered
-        let (draw_x, draw_y) = if scale < 1.0 {
-            // Apply scaling transform to fit oversized glyph in cell
-            // CGContextScaleCTM scales around the origin, so we need to adjust position
-            //
-            // After scaling by `scale`, the glyph's visual extent is:
-            //   scaled_height = font_line_height * scale = cell_height
-            //
-            // To center vertically:
-            //   y_offset = (cell_height - scaled_height) / 2.0 = 0 (exactly fits)
-            //
-            // The baseline in the scaled coordinate system:
-            //   The font's descent determines how far below the baseline the glyph extends.
-            //   In the scaled space, we want the glyph centered, so position baseline at:
-            //   y = scaled_descent + vertical_centering_offset
-            //
-            // Since we're drawing at scale, positions are divided by scale in the
-            // transform, so we specify positions in scaled (cell) coordinates.
-            let scaled_descent = font_descent * scale;
-
-            // For horizontal positioning, keep small padding
-            let x = 1.0 / scale; // Account for scale transform
-
-            // Vertically center: the scaled glyph height equals cell_height,
-            // so we position the baseline at scaled_descent from the bottom
-            let y = scaled_descent;
-
-            // Apply the scale transform
-            CGContext::scale_ctm(Some(&*context), scale, scale);
-
-            (x, y)
-        } else {
-            // No scaling needed - use standard positioning
-            (1.0, font_descent)
+        let context = match context {
+            Some(ctx) => ctx,
+            None => {
+                eprintln!("Failed to create color bitmap context");
+                return vec![0u8; width * height * 4];
+            }
         };
 
-        let position = CGPoint {
-            x: draw_x,
-            y: draw_y,
+        // Clear to fully transparent
+        let rect = CGRect {
+            origin: CGPoint { x: 0.0, y: 0.0 },
+            size: CGSize {
+                width: width as CGFloat,
+                height: height as CGFloat,
+            },
         };
+        CGContext::clear_rect(Some(&*context), rect);
 
-        // Draw the glyph
-        unsafe {
-            ct_font.draw_glyphs(
-                NonNull::from(&glyph_id),
-                NonNull::from(&position),
-                1,
-                &*context,
-            );
-        }
+        draw_glyph_fitted(
+            &context,
+            ct_font,
+            font_descent,
+            font_line_height,
+            glyph_id,
+            height,
+            FontFace::Regular,
+        );
 
-        // Extract the bitmap data
         let data = CGBitmapContextGetData(Some(&*context));
 
         if data.is_null() {
-            return vec![0u8; width * height];
+            return vec![0u8; width * height * 4];
         }
 
-        // Copy the data (Core Graphics manages the buffer)
-        let byte_count = width * height;
+        let byte_count = width * height * 4;
         let mut result = vec![0u8; byte_count];
 
         unsafe {
             std::ptr::copy_nonoverlapping(data as *const u8, result.as_mut_ptr(), byte_count);
         }
 
+        unpremultiply_rgba(&mut result);
         result
     }
 
+    // Chunk: docs/chunks/color_emoji_wide_glyphs - Color-aware lookup for buffer text
+    /// Ensures a glyph for buffer or terminal text is in the atlas.
+    ///
+    /// Works like `ensure_glyph`, except that characters whose fallback font
+    /// draws in color are rasterized into the RGBA page. Returns the glyph and
+    /// whether it lives in `color_texture` rather than the coverage texture.
+    pub fn ensure_text_glyph(&mut self, font: &Font, c: char) -> Option<(&GlyphInfo, bool)> {
+        if self.color_glyphs.contains_key(&c) {
+            return self.color_glyphs.get(&c).map(|glyph| (glyph, true));
+        }
+        if self.glyphs.contains_key(&c) && !self.color_chars.contains(&c) {
+            return self.glyphs.get(&c).map(|glyph| (glyph, false));
+        }
+
+        if let Some(source) = font.glyph_for_char_with_fallback(c) {
+            if let GlyphFont::Fallback(fallback_font) = &source.font {
+                if Font::has_color_glyphs(fallback_font)
+                    && self.add_color_glyph(font, c, fallback_font, source.glyph_id)
+                {
+                    return self.color_glyphs.get(&c).map(|glyph| (glyph, true));
+                }
+            }
+        }
+
+        // Not a color glyph, or the color page is full: use the coverage page
+        // and stop retrying the color lookup for this character
+        self.ensure_glyph(font, c);
+        self.color_chars.remove(&c);
+        self.ensure_glyph(font, c).map(|glyph| (glyph, false))
+    }
+
+    // Chunk: docs/chunks/syntax_style_modifiers - Bold and italic glyphs
+    /// Ensures a glyph for buffer or terminal text is in the atlas, in `face`.
+    ///
+    /// Works like `ensure_text_glyph`. Only characters the primary font has
+    /// get a bold or italic glyph; fallback and color glyphs, and any glyph
+    /// the atlas has no room for, are drawn regular.
+    pub fn ensure_styled_text_glyph(
+        &mut self,
+        font: &Font,
+        c: char,
+        face: FontFace,
+    ) -> Option<(&GlyphInfo, bool)> {
+        if face == FontFace::Regular {
+            return self.ensure_text_glyph(font, c);
+        }
+        if !self.styled_glyphs.contains_key(&(c, face)) && !self.add_styled_glyph(font, c, face) {
+            return self.ensure_text_glyph(font, c);
+        }
+        let glyph = self.styled_glyphs.get(&(c, face))?;
+        Some((glyph, false))
+    }
+
+    /// Adds the glyph for `c` in `face` to the coverage page, from the
+    /// family's own face or synthesized from the primary font.
+    ///
+    /// Returns false if the primary font has no glyph for `c` or the atlas is
+    /// full.
+    fn add_styled_glyph(&mut self, font: &Font, c: char, face: FontFace) -> bool {
+        let Some(regular_id) = font.glyph_for_char(c) else {
+            return false;
+        };
+
+        let glyph_width = self.glyph_width_for(c);
+        let glyph_height = self.cell_height;
+
+        // Check if we need to move to next row
+        if self.cursor_x + glyph_width + self.padding > ATLAS_SIZE {
+            self.cursor_x = 0;
+            self.cursor_y += self.row_height + self.padding;
+            self.row_height = 0;
+        }
+
+        // Check if we've run out of vertical space
+        if self.cursor_y + glyph_height > ATLAS_SIZE {
+            eprintln!("Warning: Glyph atlas is full, cannot add '{}'", c);
+            return false;
+        }
+
+        let own_face = self.faces.entry(face).or_insert_with(|| font.face(face));
+        let own_glyph = own_face.clone().and_then(|ct_font| {
+            let glyph_id = font.glyph_id_from_font(&ct_font, c)?;
+            Some((ct_font, glyph_id))
+        });
+        let bitmap = match own_glyph {
+            Some((ct_font, glyph_id)) => {
+                let (ascent, descent, line_height) = Font::get_ct_font_metrics(&ct_font);
+                self.rasterize_glyph_with_ct_font(
+                    &ct_font,
+                    ascent,
+                    descent,
+                    line_height,
+                    glyph_id,
+                    glyph_width,
+                    glyph_height,
+                    FontFace::Regular,
+                )
+            }
+            None => self.rasterize_glyph_with_ct_font(
+                font.ct_font(),
+                font.metrics.ascent,
+                font.metrics.descent,
+                font.metrics.line_height,
+                regular_id,
+                glyph_width,
+                glyph_height,
+                face,
+            ),
+        };
+
+        let region = MTLRegion {
+            origin: objc2_metal::MTLOrigin {
+                x: self.cursor_x,
+                y: self.cursor_y,
+                z: 0,
+            },
+            size: objc2_metal::MTLSize {
+                width: glyph_width,
+                height: glyph_height,
+                depth: 1,
+            },
+        };
+
+        let bytes_ptr = NonNull::new(bitmap.as_ptr() as *mut std::ffi::c_void)
+            .expect("bitmap pointer should not be null");
+
+        // SAFETY: We're uploading valid bitmap data to the texture
+        unsafe {
+            self.texture
+                .replaceRegion_mipmapLevel_withBytes_bytesPerRow(region, 0, bytes_ptr, glyph_width);
+        }
+
+        let atlas_size = ATLAS_SIZE as f32;
+        let uv_min = (
+            self.cursor_x as f32 / atlas_size,
+            self.cursor_y as f32 / atlas_size,
+        );
+        let uv_max = (
+            (self.cursor_x + glyph_width) as f32 / atlas_size,
+            (self.cursor_y + glyph_height) as f32 / atlas_size,
+        );
+
+        let info = GlyphInfo {
+            uv_min,
+            uv_max,
+            width: glyph_width as f32,
+            height: glyph_height as f32,
+            bearing_x: 1.0, // Padding offset
+            bearing_y: font.metrics.ascent as f32,
+        };
+
+        self.styled_glyphs.insert((c, face), info);
+
+        self.cursor_x += glyph_width + self.padding;
+        self.row_height = self.row_height.max(glyph_height);
+
+        true
+    }
+
     // Chunk: docs/chunks/font_fallback_rendering - Fallback-aware glyph lookup
     /// Ensures a glyph is in the atlas, adding it if necessary.
     ///
@@ -643,6 +1006,126 @@ impl GlyphAtlas {
     }
 }
 
+// Chunk: docs/chunks/fallback_glyph_metrics - Scale fallback glyphs to fit cell bounds
+/// Draws a glyph into a bitmap context, scaling it down to fit the cell height
+/// when the font's line height exceeds it.
+///
+/// `synthetic` fakes a face the font doesn't have: italic slants the glyph
+/// about its baseline and bold strikes it a second time one pixel right.
+// Chunk: docs/chunks/syntax_style_modifiers - Synthesized bold and italic
+fn draw_glyph_fitted(
+    context: &CGContext,
+    ct_font: &CTFont,
+    font_descent: f64,
+    font_line_height: f64,
+    glyph_id: u16,
+    height: usize,
+    synthetic: FontFace,
+) {
+    // Compute scale factor: scale down if the font's line_height exceeds cell height
+    let cell_height = height as f64;
+    let scale = if font_line_height > cell_height {
+        cell_height / font_line_height
+    } else {
+        1.0
+    };
+
+    // Position for drawing. Core Graphics has origin at bottom-left.
+    // The baseline is positioned so that:
+    // - For scale=1.0: baseline at y = descent (standard positioning)
+    // - For scale<1.0: glyph is scaled and vertically centered
+    let (draw_x, draw_y) = if scale < 1.0 {
+        // Apply scaling transform to fit oversized glyph in cell
+        // CGContextScaleCTM scales around the origin, so we need to adjust position
+        //
+        // After scaling by `scale`, the glyph's visual extent is:
+        //   scaled_height = font_line_height * scale = cell_height
+        //
+        // To center vertically:
+        //   y_offset = (cell_height - scaled_height) / 2.0 = 0 (exactly fits)
+        //
+        // The baseline in the scaled coordinate system:
+        //   The font's descent determines how far below the baseline the glyph extends.
+        //   In the scaled space, we want the glyph centered, so position baseline at:
+        //   y = scaled_descent + vertical_centering_offset
+        //
+        // Since we're drawing at scale, positions are divided by scale in the
+        // transform, so we specify positions in scaled (cell) coordinates.
+        let scaled_descent = font_descent * scale;
+
+        // For horizontal positioning, keep small padding
+        let x = 1.0 / scale; // Account for scale transform
+
+        // Vertically center: the scaled glyph height equals cell_height,
+        // so we position the baseline at scaled_descent from the bottom
+        let y = scaled_descent;
+
+        // Apply the scale transform
+        CGContext::scale_ctm(Some(context), scale, scale);
+
+        (x, y)
+    } else {
+        // No scaling needed - use standard positioning
+        (1.0, font_descent)
+    };
+
+    if synthetic.is_italic() {
+        // Shear about the baseline so the glyph keeps its place on the line
+        let slant = CGAffineTransform {
+            a: 1.0,
+            b: 0.0,
+            c: SYNTHETIC_ITALIC_SLANT,
+            d: 1.0,
+            tx: -SYNTHETIC_ITALIC_SLANT * draw_y,
+            ty: 0.0,
+        };
+        CGContext::concat_ctm(Some(context), slant);
+    }
+
+    let position = CGPoint {
+        x: draw_x,
+        y: draw_y,
+    };
+
+    // Draw the glyph
+    unsafe {
+        ct_font.draw_glyphs(
+            NonNull::from(&glyph_id),
+            NonNull::from(&position),
+            1,
+            context,
+        );
+    }
+
+    if synthetic.is_bold() {
+        // One device pixel, whatever scale the glyph was fitted at
+        let struck = CGPoint {
+            x: draw_x + 1.0 / scale,
+            y: draw_y,
+        };
+        unsafe {
+            ct_font.draw_glyphs(NonNull::from(&glyph_id), NonNull::from(&struck), 1, context);
+        }
+    }
+}
+
+// Chunk: docs/chunks/color_emoji_wide_glyphs - Straight alpha for the image shader
+/// Converts premultiplied RGBA pixels to straight alpha in place.
+///
+/// Core Graphics only draws into premultiplied contexts, while the image
+/// fragment shader (and its blend state) expects straight alpha.
+fn unpremultiply_rgba(pixels: &mut [u8]) {
+    for pixel in pixels.chunks_exact_mut(4) {
+        let alpha = pixel[3] as u32;
+        if alpha == 0 || alpha == 255 {
+            continue;
+        }
+        for channel in &mut pixel[..3] {
+            *channel = ((*channel as u32 * 255 + alpha / 2) / alpha).min(255) as u8;
+        }
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -1061,9 +1544,11 @@ mod tests {
 
         // The glyph info should have dimensions matching cell size
         // (the scaling happens during rasterization, the GlyphInfo dimensions are fixed)
+        // Chunk: docs/chunks/color_emoji_wide_glyphs - Emoji are wide, spanning two cells
         assert_eq!(
-            g.width as usize, cell_w,
-            "Emoji glyph width should match cell width"
+            g.width as usize,
+            2 * cell_w - 2,
+            "Emoji glyph width should span two cells"
         );
         assert_eq!(
             g.height as usize, cell_h,
@@ -1100,8 +1585,14 @@ mod tests {
             let g = glyph.unwrap();
 
             // All glyphs should fit within the cell dimensions
+            // (two cells for wide characters like emoji)
+            let expected_w = if UnicodeWidthChar::width(c) == Some(2) {
+                2 * cell_w - 2
+            } else {
+                cell_w
+            };
             assert_eq!(
-                g.width as usize, cell_w,
+                g.width as usize, expected_w,
                 "Glyph '{}' width should match cell width",
                 c
             );
@@ -1190,4 +1681,118 @@ mod tests {
             );
         }
     }
+
+    // =========================================================================
+    // Color Emoji and Wide Glyph Tests
+    // Chunk: docs/chunks/color_emoji_wide_glyphs
+    // =========================================================================
+
+    #[test]
+    fn test_unpremultiply_rgba() {
+        let mut pixels = [
+            0, 0, 0, 0, // transparent
+            255, 128, 0, 255, // opaque
+            64, 32, 0, 128, // half-transparent, premultiplied
+        ];
+        unpremultiply_rgba(&mut pixels);
+        assert_eq!(&pixels[0..4], &[0, 0, 0, 0]);
+        assert_eq!(&pixels[4..8], &[255, 128, 0, 255]);
+        assert_eq!(&pixels[8..12], &[128, 64, 0, 128]);
+    }
+
+    #[test]
+    fn test_cjk_glyph_spans_two_cells() {
+        let device = get_test_device();
+        let font = Font::new("Menlo-Regular", 14.0, 1.0);
+        let mut atlas = GlyphAtlas::new(&device, &font);
+        let (cell_w, cell_h) = atlas.cell_dimensions();
+
+        let (g, is_color) = atlas.ensure_text_glyph(&font, '中').unwrap();
+        assert!(!is_color, "CJK text comes from a coverage font");
+        assert_eq!(g.width as usize, 2 * cell_w - 2);
+        assert_eq!(g.height as usize, cell_h);
+    }
+
+    #[test]
+    fn test_emoji_text_glyph_uses_color_page() {
+        let device = get_test_device();
+        let font = Font::new("Menlo-Regular", 14.0, 1.0);
+        let mut atlas = GlyphAtlas::new(&device, &font);
+
+        let (g1, is_color) = atlas.ensure_text_glyph(&font, '😀').unwrap();
+        assert!(is_color, "Emoji should be rasterized into the RGBA page");
+        let g1 = *g1;
+
+        // Cached on the second lookup
+        let (g2, is_color) = atlas.ensure_text_glyph(&font, '😀').unwrap();
+        assert!(is_color);
+        assert_eq!(g1.uv_min, g2.uv_min);
+        assert_eq!(g1.uv_max, g2.uv_max);
+    }
+
+    #[test]
+    fn test_ascii_text_glyph_not_color() {
+        let device = get_test_device();
+        let font = Font::new("Menlo-Regular", 14.0, 1.0);
+        let mut atlas = GlyphAtlas::new(&device, &font);
+
+        let (g, is_color) = atlas.ensure_text_glyph(&font, 'A').unwrap();
+        assert!(!is_color);
+        let uv_min = g.uv_min;
+        assert_eq!(uv_min, atlas.get_glyph('A').unwrap().uv_min);
+    }
+
+    #[test]
+    fn test_emoji_color_glyph_after_coverage_glyph() {
+        let device = get_test_device();
+        let font = Font::new("Menlo-Regular", 14.0, 1.0);
+        let mut atlas = GlyphAtlas::new(&device, &font);
+
+        // Chrome labels put a coverage copy in the atlas first...
+        assert!(atlas.ensure_glyph(&font, '😀').is_some());
+
+        // ...buffer text still gets the color version
+        let (_, is_color) = atlas.ensure_text_glyph(&font, '😀').unwrap();
+        assert!(is_color);
+    }
+
+    // Chunk: docs/chunks/syntax_style_modifiers - Bold and italic glyphs
+    #[test]
+    fn test_styled_glyphs_are_kept_by_face() {
+        let device = get_test_device();
+        let font = Font::new("Menlo-Regular", 14.0, 1.0);
+        let mut atlas = GlyphAtlas::new(&device, &font);
+
+        let regular = atlas.ensure_text_glyph(&font, 'a').unwrap().0.uv_min;
+        let bold = atlas
+            .ensure_styled_text_glyph(&font, 'a', FontFace::Bold)
+            .unwrap()
+            .0
+            .uv_min;
+        let italic = atlas
+            .ensure_styled_text_glyph(&font, 'a', FontFace::Italic)
+            .unwrap()
+            .0
+            .uv_min;
+        assert_ne!(regular, bold);
+        assert_ne!(bold, italic);
+
+        // Cached on the second lookup
+        let (again, _) = atlas
+            .ensure_styled_text_glyph(&font, 'a', FontFace::Bold)
+            .unwrap();
+        assert_eq!(again.uv_min, bold);
+    }
+
+    #[test]
+    fn test_styled_fallback_glyphs_stay_regular() {
+        let device = get_test_device();
+        let font = Font::new("Menlo-Regular", 14.0, 1.0);
+        let mut atlas = GlyphAtlas::new(&device, &font);
+
+        let (_, is_color) = atlas
+            .ensure_styled_text_glyph(&font, '😀', FontFace::Bold)
+            .unwrap();
+        assert!(is_color);
+    }
 }
@@ -27,10 +27,12 @@
 //! ## Quad Categories
 //!
 //! The buffer emits quads in a specific order:
-//! 1. **Selection quads** - Semi-transparent background highlights for selected text
-//! 2. **Border quads** - Left-edge indicators for continuation rows (wrapped lines)
-//! 3. **Glyph quads** - The actual text characters
-//! 4. **Cursor quad** - The block cursor at the current position
+//! 0. **Decoration quads** - Current line highlight and column rulers (editable buffers only)
+//! 1. **Match quads** - Secondary highlights for every find match on screen
+//! 2. **Selection quads** - Semi-transparent background highlights for selected text
+//! 3. **Border quads** - Left-edge indicators for continuation rows (wrapped lines)
+//! 4. **Glyph quads** - The actual text characters
+//! 5. **Cursor quad** - The block cursor at the current position
 //!
 //! Each category has its own index range tracked separately, allowing the renderer
 //! to draw each with different colors via separate draw calls.
@@ -42,8 +44,11 @@ use objc2::runtime::ProtocolObject;
 use objc2_metal::{MTLBuffer, MTLDevice, MTLResourceOptions};
 
 use crate::color_palette::ColorPalette;
-use crate::font::{Font, FontMetrics};
+// Chunk: docs/chunks/conflict_markers - Conflict block tints
+use crate::conflict_markers::{Conflict, ConflictPart};
+use crate::font::{Font, FontFace, FontMetrics};
 use crate::glyph_atlas::{GlyphAtlas, GlyphInfo};
+use crate::occurrences;
 use crate::shader::VERTEX_SIZE;
 // Chunk: docs/chunks/styled_line_cache - Styled line cache for reducing per-frame allocations
 use crate::styled_line_cache::StyledLineCache;
@@ -52,7 +57,7 @@ use crate::wrap_layout::WrapLayout;
 // Chunk: docs/chunks/buffer_view_trait - Use BufferView trait instead of TextBuffer
 // Chunk: docs/chunks/renderer_styled_content - Use Style types for per-span colors
 // Chunk: docs/chunks/styled_line_cache - DirtyLines for cache invalidation
-use lite_edit_buffer::{BufferView, CursorShape, DirtyLines, StyledLine, UnderlineStyle};
+use lite_edit_buffer::{BufferView, CursorShape, DirtyLines, Position, StyledLine, UnderlineStyle};
 // Chunk: docs/chunks/terminal_multibyte_rendering - Wide character width tracking
 use unicode_width::UnicodeWidthChar;
 // Chunk: docs/chunks/tab_rendering - Tab-aware visual width calculation
@@ -252,6 +257,49 @@ impl QuadRange {
     }
 }
 
+// Chunk: docs/chunks/line_highlight_rulers - Current line highlight and column rulers
+/// Current line highlight color (Catppuccin Mocha surface0 at 50% alpha)
+const CURRENT_LINE_COLOR: [f32; 4] = [0.192, 0.196, 0.267, 0.5];
+
+/// Column ruler color (Catppuccin Mocha surface1)
+const RULER_COLOR: [f32; 4] = [0.271, 0.278, 0.353, 1.0];
+
+// Chunk: docs/chunks/find_match_highlights - Secondary highlight for find matches
+/// Find match highlight color (Catppuccin Mocha yellow at 25% alpha), fainter
+/// than the selection drawn over the current match
+const MATCH_HIGHLIGHT_COLOR: [f32; 4] = [0.976, 0.886, 0.686, 0.25];
+
+// Chunk: docs/chunks/selection_occurrences - Other occurrences of the selected word
+/// Occurrence highlight color (Catppuccin Mocha overlay1 at 25% alpha), a
+/// neutral tint so occurrences don't read as find matches
+const OCCURRENCE_HIGHLIGHT_COLOR: [f32; 4] = [0.498, 0.518, 0.612, 0.25];
+
+// Chunk: docs/chunks/conflict_markers - Conflict block tints
+/// Conflict marker line tint (Catppuccin Mocha mauve at 30% alpha)
+const CONFLICT_MARKER_COLOR: [f32; 4] = [0.796, 0.651, 0.969, 0.3];
+
+/// Tint of our side of a conflict (Catppuccin Mocha green at 15% alpha)
+const CONFLICT_OURS_COLOR: [f32; 4] = [0.651, 0.890, 0.631, 0.15];
+
+/// Tint of the common ancestor in a conflict (Catppuccin Mocha overlay1 at
+/// 15% alpha)
+const CONFLICT_BASE_COLOR: [f32; 4] = [0.498, 0.518, 0.612, 0.15];
+
+/// Tint of their side of a conflict (Catppuccin Mocha blue at 15% alpha)
+const CONFLICT_THEIRS_COLOR: [f32; 4] = [0.537, 0.706, 0.980, 0.15];
+
+/// Decorations drawn beneath the text of editable buffers.
+///
+/// Terminal and diff views never receive decorations, since neither has an
+/// editing cursor line or a code width to guide.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LineDecorations {
+    /// Tint every screen row of the cursor's logical line
+    pub highlight_current_line: bool,
+    /// Visual columns at which to draw a 1px vertical ruler (e.g. 80, 100, 120)
+    pub rulers: Vec<usize>,
+}
+
 /// Manages vertex and index buffers for rendering text
 // Chunk: docs/chunks/renderer_styled_content - Extended with background and underline ranges
 // Chunk: docs/chunks/quad_buffer_prealloc - Persistent vertex/index buffers to eliminate per-frame allocations
@@ -268,6 +316,14 @@ pub struct GlyphBuffer {
     palette: ColorPalette,
     /// Index range for background (per-span bg color) quads
     background_range: QuadRange,
+    // Chunk: docs/chunks/find_match_highlights - Find match highlights
+    /// Index range for find match highlight quads
+    match_range: QuadRange,
+    /// Find matches to highlight, as (start, end) in buffer order
+    match_highlights: Vec<(Position, Position)>,
+    // Chunk: docs/chunks/selection_occurrences - Other occurrences of the selected word
+    /// Index range for selected-word occurrence quads
+    occurrence_range: QuadRange,
     /// Index range for selection highlight quads
     selection_range: QuadRange,
     /// Index range for continuation row border quads
@@ -278,6 +334,22 @@ pub struct GlyphBuffer {
     underline_range: QuadRange,
     /// Index range for cursor quad
     cursor_range: QuadRange,
+    // Chunk: docs/chunks/line_highlight_rulers - Current line highlight and column rulers
+    /// Index range for current-line highlight and ruler quads
+    decoration_range: QuadRange,
+    /// Which line decorations to draw for editable buffers
+    line_decorations: LineDecorations,
+    // Chunk: docs/chunks/conflict_markers - Conflict block tints
+    /// Conflict blocks whose lines are tinted on the next wrapped update
+    conflicts: Vec<Conflict>,
+    // Chunk: docs/chunks/color_emoji_wide_glyphs - Color glyphs drawn from the RGBA atlas page
+    /// Index range for color glyph (emoji) quads, sampled from the atlas color texture
+    color_glyph_range: QuadRange,
+    /// Color glyph quads collected during the glyph pass, appended after it
+    pending_color_vertices: Vec<GlyphVertex>,
+    // Chunk: docs/chunks/ime_dead_key_composition - Cursor cell for IME candidate placement
+    /// Cursor cell `(x, y, width, height)` in view pixels from the last wrapped update
+    cursor_cell: Option<(f32, f32, f32, f32)>,
     // Chunk: docs/chunks/workspace_model - Content area x offset for left rail
     /// Horizontal offset for content area (e.g., for left rail)
     x_offset: f32,
@@ -312,11 +384,20 @@ impl GlyphBuffer {
             layout: GlyphLayout::from_metrics(metrics),
             palette: ColorPalette::default(),
             background_range: QuadRange::default(),
+            match_range: QuadRange::default(),
+            match_highlights: Vec::new(),
+            occurrence_range: QuadRange::default(),
             selection_range: QuadRange::default(),
             border_range: QuadRange::default(),
             glyph_range: QuadRange::default(),
             underline_range: QuadRange::default(),
             cursor_range: QuadRange::default(),
+            decoration_range: QuadRange::default(),
+            line_decorations: LineDecorations::default(),
+            conflicts: Vec::new(),
+            color_glyph_range: QuadRange::default(),
+            pending_color_vertices: Vec::new(),
+            cursor_cell: None,
             x_offset: 0.0,
             y_offset: 0.0,
             persistent_vertices: Vec::new(),
@@ -328,6 +409,12 @@ impl GlyphBuffer {
         }
     }
 
+    // Chunk: docs/chunks/pane_tint - Palette access for pane tints
+    /// Returns the color palette used to resolve styled colors.
+    pub fn palette(&self) -> &ColorPalette {
+        &self.palette
+    }
+
     // Chunk: docs/chunks/workspace_model - Content area x offset for left rail
     /// Sets the horizontal offset for content area rendering.
     ///
@@ -400,6 +487,12 @@ impl GlyphBuffer {
         &self.layout
     }
 
+    // Chunk: docs/chunks/font_config_zoom - Relayout after a font size change
+    /// Replaces the glyph layout with one for new font metrics.
+    pub fn set_font_metrics(&mut self, metrics: &FontMetrics) {
+        self.layout = GlyphLayout::from_metrics(metrics);
+    }
+
     /// Returns the index range for background (per-span bg color) quads
     // Chunk: docs/chunks/renderer_styled_content - Background quads for styled text
     pub fn background_range(&self) -> QuadRange {
@@ -422,6 +515,12 @@ impl GlyphBuffer {
         self.glyph_range
     }
 
+    // Chunk: docs/chunks/color_emoji_wide_glyphs - Color glyphs drawn from the RGBA atlas page
+    /// Returns the index range for color glyph quads
+    pub fn color_glyph_range(&self) -> QuadRange {
+        self.color_glyph_range
+    }
+
     /// Returns the index range for underline quads
     // Chunk: docs/chunks/renderer_styled_content - Underline rendering for styled text
     pub fn underline_range(&self) -> QuadRange {
@@ -433,6 +532,56 @@ impl GlyphBuffer {
         self.cursor_range
     }
 
+    // Chunk: docs/chunks/ime_dead_key_composition - Cursor cell for IME candidate placement
+    /// Returns the cursor cell `(x, y, width, height)` in view pixels (origin at
+    /// top-left), or `None` if the last update drew no cursor.
+    pub fn cursor_cell(&self) -> Option<(f32, f32, f32, f32)> {
+        self.cursor_cell
+    }
+
+    // Chunk: docs/chunks/line_highlight_rulers - Current line highlight and column rulers
+    /// Returns the index range for current-line highlight and ruler quads
+    pub fn decoration_range(&self) -> QuadRange {
+        self.decoration_range
+    }
+
+    /// Sets which line decorations are drawn for editable buffers.
+    pub fn set_line_decorations(&mut self, decorations: LineDecorations) {
+        self.line_decorations = decorations;
+    }
+
+    /// Returns the line decorations drawn for editable buffers
+    pub fn line_decorations(&self) -> &LineDecorations {
+        &self.line_decorations
+    }
+
+    // Chunk: docs/chunks/conflict_markers - Conflict block tints
+    /// Sets the conflict blocks to tint on the next wrapped update, in buffer
+    /// order. Like the current line highlight they are drawn only for
+    /// editable buffers.
+    pub fn set_conflicts(&mut self, conflicts: Vec<Conflict>) {
+        self.conflicts = conflicts;
+    }
+
+    // Chunk: docs/chunks/find_match_highlights - Find match highlights
+    /// Returns the index range for find match highlight quads
+    pub fn match_range(&self) -> QuadRange {
+        self.match_range
+    }
+
+    // Chunk: docs/chunks/selection_occurrences - Other occurrences of the selected word
+    /// Returns the index range for selected-word occurrence quads
+    pub fn occurrence_range(&self) -> QuadRange {
+        self.occurrence_range
+    }
+
+    /// Sets the find matches to highlight on the next wrapped update, as
+    /// (start, end) pairs in buffer order. Lines that aren't rendered are
+    /// skipped, so the list may cover more than the viewport.
+    pub fn set_match_highlights(&mut self, matches: Vec<(Position, Position)>) {
+        self.match_highlights = matches;
+    }
+
     /// Updates the buffers with new text content
     ///
     /// # Arguments
@@ -638,13 +787,8 @@ impl GlyphBuffer {
         self.styled_line_cache.resize(line_count);
 
         // Populate cache for any missing lines (cache miss = recompute)
-        for line in visible_range.clone() {
-            if self.styled_line_cache.get(line).is_none() {
-                if let Some(styled) = view.styled_line(line) {
-                    self.styled_line_cache.insert(line, styled);
-                }
-            }
-        }
+        // Chunk: docs/chunks/styled_lines_batch - Fetch missing lines in batches
+        self.styled_line_cache.fill(view, visible_range.clone());
 
         // Collect references to cached styled lines
         let styled_lines: Vec<Option<&StyledLine>> = visible_range.clone()
@@ -674,6 +818,9 @@ impl GlyphBuffer {
         self.glyph_range = QuadRange::default();
         self.underline_range = QuadRange::default();
         self.cursor_range = QuadRange::default();
+        self.decoration_range = QuadRange::default();
+        self.color_glyph_range = QuadRange::default();
+        self.cursor_cell = None;
 
         if estimated_chars == 0 && cursor_quads == 0 && view.selection_range().is_none() {
             self.vertex_buffer = None;
@@ -755,7 +902,29 @@ impl GlyphBuffer {
         // ==================== Phase 2: Selection Quads ====================
         let selection_start_index = self.persistent_indices.len();
 
-        if let Some((sel_start, sel_end)) = view.selection_range() {
+        // Chunk: docs/chunks/column_selection - Block selections draw one rectangle per row
+        if let Some(block) = view.block_selection().filter(|block| !block.is_empty()) {
+            for buffer_line in visible_range.clone() {
+                if buffer_line < block.top() || buffer_line > block.bottom() {
+                    continue;
+                }
+
+                let screen_row = buffer_line - viewport.first_visible_line();
+                let quad = self.create_selection_quad_with_offset(
+                    screen_row, block.left(), block.right(), &solid_glyph, y_offset, selection_color
+                );
+                self.persistent_vertices.extend_from_slice(&quad);
+
+                self.persistent_indices.push(vertex_offset);
+                self.persistent_indices.push(vertex_offset + 1);
+                self.persistent_indices.push(vertex_offset + 2);
+                self.persistent_indices.push(vertex_offset);
+                self.persistent_indices.push(vertex_offset + 2);
+                self.persistent_indices.push(vertex_offset + 3);
+
+                vertex_offset += 4;
+            }
+        } else if let Some((sel_start, sel_end)) = view.selection_range() {
             for buffer_line in visible_range.clone() {
                 if buffer_line < sel_start.line || buffer_line > sel_end.line {
                     continue;
@@ -1225,6 +1394,34 @@ impl GlyphBuffer {
         ]
     }
 
+    // Chunk: docs/chunks/line_highlight_rulers - Vertical column ruler
+    /// Creates a 1px-wide vertical ruler quad at the left edge of visual column `col`.
+    ///
+    /// The ruler starts at the top of the content area and is `height` pixels tall.
+    /// It is independent of the scroll offset, since every screen row shares the
+    /// same column geometry.
+    fn create_ruler_quad(
+        &self,
+        col: usize,
+        height: f32,
+        solid_glyph: &GlyphInfo,
+        color: [f32; 4],
+    ) -> [GlyphVertex; 4] {
+        let x = self.x_offset + col as f32 * self.layout.glyph_width;
+        let y = self.y_offset;
+        let ruler_width = 1.0;
+
+        let (u0, v0) = solid_glyph.uv_min;
+        let (u1, v1) = solid_glyph.uv_max;
+
+        [
+            GlyphVertex::new(x, y, u0, v0, color),                          // top-left
+            GlyphVertex::new(x + ruler_width, y, u1, v0, color),            // top-right
+            GlyphVertex::new(x + ruler_width, y + height, u1, v1, color),   // bottom-right
+            GlyphVertex::new(x, y + height, u0, v1, color),                 // bottom-left
+        ]
+    }
+
     // Chunk: docs/chunks/line_wrap_rendering - Wrap-aware rendering
     // Chunk: docs/chunks/cursor_wrap_scroll_alignment - Fixed coordinate space alignment
     // Chunk: docs/chunks/terminal_styling_fidelity - Per-span foreground colors, background quads, and underline quads
@@ -1239,6 +1436,7 @@ impl GlyphBuffer {
     /// that to the correct buffer line starting point using `buffer_line_for_screen_row`.
     ///
     /// Emits quads in this order:
+    /// 0. Decoration quads (current line highlight, rulers)
     /// 1. Selection highlight quads
     /// 2. Border quads (for continuation rows)
     /// 3. Glyph quads (text characters)
@@ -1265,13 +1463,18 @@ impl GlyphBuffer {
         // In wrapped mode, scroll_offset_px is in screen row space.
         // Convert the first visible screen row to the corresponding buffer line.
         let first_visible_screen_row = viewport.first_visible_screen_row();
-        let (first_visible_buffer_line, screen_row_offset_in_line, _) =
+        // Chunk: docs/chunks/file_view_mode - Unwrapped views have one row per line
+        let (first_visible_buffer_line, screen_row_offset_in_line, _) = if view.wraps_lines() {
             Viewport::buffer_line_for_screen_row(
                 first_visible_screen_row,
                 line_count,
                 wrap_layout,
                 |line| view.line_len(line),
-            );
+            )
+        } else {
+            let line = first_visible_screen_row.min(line_count.saturating_sub(1));
+            (line, 0, line)
+        };
 
         // Chunk: docs/chunks/glyph_single_styled_line - Pre-collect styled lines to avoid redundant calls
         // Chunk: docs/chunks/quad_buffer_prealloc - Use persistent rendered_buffer_lines to avoid per-frame allocations
@@ -1297,6 +1500,16 @@ impl GlyphBuffer {
             }
         }
         estimated_quads += 1; // cursor
+        // Chunk: docs/chunks/line_highlight_rulers - Current line highlight (usually one row) and rulers
+        if view.is_editable() {
+            estimated_quads += 1 + self.line_decorations.rulers.len();
+            // Chunk: docs/chunks/conflict_markers - At most every rendered line
+            if !self.conflicts.is_empty() {
+                estimated_quads += self.rendered_buffer_lines.len();
+            }
+        }
+        // Chunk: docs/chunks/find_match_highlights - Usually one row per match
+        estimated_quads += self.match_highlights.len();
 
         // Chunk: docs/chunks/styled_line_cache - Use cache to avoid redundant styled_line() calls
         // Ensure cache is sized appropriately
@@ -1306,14 +1519,12 @@ impl GlyphBuffer {
         #[cfg(feature = "perf-instrumentation")]
         let styled_line_start = std::time::Instant::now();
 
-        // Populate cache for any missing lines (cache miss = recompute)
-        for &line in &self.rendered_buffer_lines {
-            if self.styled_line_cache.get(line).is_none() {
-                if let Some(styled) = view.styled_line(line) {
-                    self.styled_line_cache.insert(line, styled);
-                }
-            }
-        }
+        // Populate cache for any missing lines (cache miss = recompute).
+        // The rendered lines are consecutive, so they are fetched as one range.
+        // Chunk: docs/chunks/styled_lines_batch - Fetch missing lines in batches
+        let rendered_end = first_visible_buffer_line + self.rendered_buffer_lines.len();
+        let rendered_range = first_visible_buffer_line..rendered_end;
+        self.styled_line_cache.fill(view, rendered_range);
 
         // Collect references to cached styled lines
         let styled_lines: Vec<Option<&StyledLine>> = self.rendered_buffer_lines.iter()
@@ -1329,11 +1540,16 @@ impl GlyphBuffer {
         // Reset quad ranges
         // Chunk: docs/chunks/terminal_styling_fidelity - Added background and underline ranges
         self.background_range = QuadRange::default();
+        self.match_range = QuadRange::default();
+        self.occurrence_range = QuadRange::default();
         self.selection_range = QuadRange::default();
         self.border_range = QuadRange::default();
         self.glyph_range = QuadRange::default();
         self.underline_range = QuadRange::default();
         self.cursor_range = QuadRange::default();
+        self.decoration_range = QuadRange::default();
+        self.color_glyph_range = QuadRange::default();
+        self.cursor_cell = None;
 
         // Define colors for this rendering pass
         // Selection color (Catppuccin Mocha surface2 at 40% alpha)
@@ -1365,6 +1581,191 @@ impl GlyphBuffer {
 
         let mut vertex_offset: u32 = 0;
 
+        // ==================== Phase 0: Line Decoration Quads ====================
+        // Chunk: docs/chunks/line_highlight_rulers - Current line highlight and column rulers
+        // Drawn before everything else so span backgrounds, selections and text
+        // sit on top. Only editable views (file buffers) are decorated.
+        let decoration_start_index = self.persistent_indices.len();
+
+        if view.is_editable() {
+            let solid_glyph = atlas.solid_glyph();
+            let cols_per_row = wrap_layout.cols_per_row();
+
+            // Chunk: docs/chunks/conflict_markers - Tint conflict blocks by part
+            // Every visible row of a line in a conflict block is tinted: marker
+            // lines most strongly, then each side in its own color.
+            if !self.conflicts.is_empty() {
+                let mut cumulative_screen_row: usize = 0;
+                let mut is_first_buffer_line = true;
+
+                for idx in 0..self.rendered_buffer_lines.len() {
+                    if cumulative_screen_row >= max_screen_rows {
+                        break;
+                    }
+
+                    let line_visual_width: usize = if let Some(styled_line) = &styled_lines[idx] {
+                        let mut visual_col = 0;
+                        for span in &styled_line.spans {
+                            for c in span.text.chars() {
+                                visual_col += tab_width::char_visual_width(c, visual_col);
+                            }
+                        }
+                        visual_col
+                    } else {
+                        0
+                    };
+                    let rows_for_line = wrap_layout.screen_rows_for_line(line_visual_width);
+
+                    let start_row_offset = if is_first_buffer_line {
+                        screen_row_offset_in_line
+                    } else {
+                        0
+                    };
+                    is_first_buffer_line = false;
+
+                    let buffer_line = self.rendered_buffer_lines[idx];
+                    let part = self
+                        .conflicts
+                        .iter()
+                        .find(|conflict| conflict.contains(buffer_line))
+                        .and_then(|conflict| conflict.part(buffer_line));
+                    let color = part.map(|part| match part {
+                        ConflictPart::Marker => CONFLICT_MARKER_COLOR,
+                        ConflictPart::Ours => CONFLICT_OURS_COLOR,
+                        ConflictPart::Base => CONFLICT_BASE_COLOR,
+                        ConflictPart::Theirs => CONFLICT_THEIRS_COLOR,
+                    });
+
+                    if let Some(color) = color {
+                        for row_offset in start_row_offset..rows_for_line {
+                            let screen_row = cumulative_screen_row + (row_offset - start_row_offset);
+                            if screen_row >= max_screen_rows {
+                                break;
+                            }
+
+                            let quad = self.create_selection_quad_with_offset(
+                                screen_row,
+                                0,
+                                cols_per_row + 1,
+                                solid_glyph,
+                                y_offset,
+                                color,
+                            );
+                            self.persistent_vertices.extend_from_slice(&quad);
+                            self.persistent_indices.push(vertex_offset);
+                            self.persistent_indices.push(vertex_offset + 1);
+                            self.persistent_indices.push(vertex_offset + 2);
+                            self.persistent_indices.push(vertex_offset);
+                            self.persistent_indices.push(vertex_offset + 2);
+                            self.persistent_indices.push(vertex_offset + 3);
+                            vertex_offset += 4;
+                        }
+                    }
+
+                    cumulative_screen_row += rows_for_line - start_row_offset;
+                }
+            }
+
+            // The highlight is suppressed while a selection is active so the
+            // two tints don't stack on the selected rows.
+            let highlight_line = if self.line_decorations.highlight_current_line
+                && view.selection_range().is_none()
+                && view.block_selection().is_none()
+            {
+                view.cursor_info().map(|info| info.position.line)
+            } else {
+                None
+            };
+
+            if let Some(cursor_line) = highlight_line {
+                let mut cumulative_screen_row: usize = 0;
+                let mut is_first_buffer_line = true;
+
+                for idx in 0..self.rendered_buffer_lines.len() {
+                    if cumulative_screen_row >= max_screen_rows {
+                        break;
+                    }
+
+                    let line_visual_width: usize = if let Some(styled_line) = &styled_lines[idx] {
+                        let mut visual_col = 0;
+                        for span in &styled_line.spans {
+                            for c in span.text.chars() {
+                                visual_col += tab_width::char_visual_width(c, visual_col);
+                            }
+                        }
+                        visual_col
+                    } else {
+                        0
+                    };
+                    let rows_for_line = wrap_layout.screen_rows_for_line(line_visual_width);
+
+                    let start_row_offset = if is_first_buffer_line {
+                        screen_row_offset_in_line
+                    } else {
+                        0
+                    };
+                    is_first_buffer_line = false;
+
+                    if self.rendered_buffer_lines[idx] == cursor_line {
+                        // Cover every visible continuation row of the logical line.
+                        // The row extends one column past the wrap width so the
+                        // partial column at the right edge is tinted too; the
+                        // content scissor clips the overflow.
+                        for row_offset in start_row_offset..rows_for_line {
+                            let screen_row = cumulative_screen_row + (row_offset - start_row_offset);
+                            if screen_row >= max_screen_rows {
+                                break;
+                            }
+
+                            let quad = self.create_selection_quad_with_offset(
+                                screen_row,
+                                0,
+                                cols_per_row + 1,
+                                solid_glyph,
+                                y_offset,
+                                CURRENT_LINE_COLOR,
+                            );
+                            self.persistent_vertices.extend_from_slice(&quad);
+                            self.persistent_indices.push(vertex_offset);
+                            self.persistent_indices.push(vertex_offset + 1);
+                            self.persistent_indices.push(vertex_offset + 2);
+                            self.persistent_indices.push(vertex_offset);
+                            self.persistent_indices.push(vertex_offset + 2);
+                            self.persistent_indices.push(vertex_offset + 3);
+                            vertex_offset += 4;
+                        }
+                        break;
+                    }
+
+                    cumulative_screen_row += rows_for_line - start_row_offset;
+                }
+            }
+
+            // Rulers are drawn at visual columns, which with wrapping coincide on
+            // every screen row. A ruler at or past the wrap width would never
+            // have text beside it, so it is omitted.
+            let ruler_height = max_screen_rows as f32 * self.layout.line_height;
+            for ruler_idx in 0..self.line_decorations.rulers.len() {
+                let col = self.line_decorations.rulers[ruler_idx];
+                if col == 0 || col >= cols_per_row {
+                    continue;
+                }
+
+                let quad = self.create_ruler_quad(col, ruler_height, solid_glyph, RULER_COLOR);
+                self.persistent_vertices.extend_from_slice(&quad);
+                self.persistent_indices.push(vertex_offset);
+                self.persistent_indices.push(vertex_offset + 1);
+                self.persistent_indices.push(vertex_offset + 2);
+                self.persistent_indices.push(vertex_offset);
+                self.persistent_indices.push(vertex_offset + 2);
+                self.persistent_indices.push(vertex_offset + 3);
+                vertex_offset += 4;
+            }
+        }
+
+        let decoration_index_count = self.persistent_indices.len() - decoration_start_index;
+        self.decoration_range = QuadRange::new(decoration_start_index, decoration_index_count);
+
         // ==================== Phase 1: Background Quads ====================
         // Chunk: docs/chunks/terminal_styling_fidelity - Per-span background colors for terminal styling
         // Chunk: docs/chunks/tab_rendering - Tab-aware visual width for background quads
@@ -1481,12 +1882,186 @@ impl GlyphBuffer {
         let background_index_count = self.persistent_indices.len() - background_start_index;
         self.background_range = QuadRange::new(background_start_index, background_index_count);
 
+        // ==================== Phase 1.5: Match Quads ====================
+        // Chunk: docs/chunks/find_match_highlights - Every find match on screen
+        // Drawn beneath the selection, which covers the current match.
+        let match_start_index = self.persistent_indices.len();
+
+        if !self.match_highlights.is_empty() {
+            let solid_glyph = atlas.solid_glyph();
+            let cols_per_row = wrap_layout.cols_per_row();
+            let mut cumulative_screen_row: usize = 0;
+            let mut is_first_buffer_line = true;
+
+            for idx in 0..self.rendered_buffer_lines.len() {
+                let buffer_line = self.rendered_buffer_lines[idx];
+                if cumulative_screen_row >= max_screen_rows {
+                    break;
+                }
+
+                let line_content: String = if let Some(styled_line) = &styled_lines[idx] {
+                    styled_line.spans.iter().flat_map(|s| s.text.chars()).collect()
+                } else {
+                    String::new()
+                };
+                let line_visual_width = tab_width::line_visual_width(&line_content);
+                let rows_for_line = wrap_layout.screen_rows_for_line(line_visual_width);
+                let start_row_offset = if is_first_buffer_line {
+                    screen_row_offset_in_line
+                } else {
+                    0
+                };
+                is_first_buffer_line = false;
+
+                // Matches are sorted, so the ones on this line are contiguous
+                let first = self
+                    .match_highlights
+                    .partition_point(|(start, _)| start.line < buffer_line);
+                let on_line = self.match_highlights[first..]
+                    .iter()
+                    .take_while(|(start, _)| start.line == buffer_line);
+
+                for (start, end) in on_line {
+                    let end_col = if end.line == buffer_line {
+                        end.col
+                    } else {
+                        line_content.chars().count()
+                    };
+                    let start_visual = tab_width::char_col_to_visual_col(&line_content, start.col);
+                    let end_visual = tab_width::char_col_to_visual_col(&line_content, end_col);
+
+                    for row_offset in start_row_offset..rows_for_line {
+                        let screen_row = cumulative_screen_row + (row_offset - start_row_offset);
+                        if screen_row >= max_screen_rows {
+                            break;
+                        }
+                        let row_start_col = row_offset * cols_per_row;
+                        let row_end_col = (row_offset + 1) * cols_per_row;
+                        let start_on_row = start_visual.max(row_start_col);
+                        let end_on_row = end_visual.min(row_end_col);
+                        if start_on_row < end_on_row {
+                            let quad = self.create_selection_quad_with_offset(
+                                screen_row,
+                                start_on_row - row_start_col,
+                                end_on_row - row_start_col,
+                                solid_glyph,
+                                y_offset,
+                                MATCH_HIGHLIGHT_COLOR,
+                            );
+                            self.persistent_vertices.extend_from_slice(&quad);
+                            self.persistent_indices.push(vertex_offset);
+                            self.persistent_indices.push(vertex_offset + 1);
+                            self.persistent_indices.push(vertex_offset + 2);
+                            self.persistent_indices.push(vertex_offset);
+                            self.persistent_indices.push(vertex_offset + 2);
+                            self.persistent_indices.push(vertex_offset + 3);
+                            vertex_offset += 4;
+                        }
+                    }
+                }
+
+                cumulative_screen_row += rows_for_line - start_row_offset;
+            }
+        }
+
+        let match_index_count = self.persistent_indices.len() - match_start_index;
+        self.match_range = QuadRange::new(match_start_index, match_index_count);
+
+        // ==================== Phase 1.6: Occurrence Quads ====================
+        // Chunk: docs/chunks/selection_occurrences - Other occurrences of the selected word
+        // Found per render from the visible lines' text. The selection itself is
+        // skipped, since the selection quad already covers it.
+        let occurrence_start_index = self.persistent_indices.len();
+
+        let selected = view
+            .selection_range()
+            .filter(|(start, end)| view.is_editable() && start.line == end.line)
+            .and_then(|(start, end)| {
+                let cached = self.styled_line_cache.get(start.line).cloned();
+                let styled = cached.or_else(|| view.styled_line(start.line))?;
+                let text: String = styled.spans.iter().flat_map(|s| s.text.chars()).collect();
+                occurrences::selected_word(&text, start.col..end.col).map(|word| (start, word))
+            });
+
+        if let Some((selection_start, word)) = selected {
+            let solid_glyph = atlas.solid_glyph();
+            let cols_per_row = wrap_layout.cols_per_row();
+            let mut cumulative_screen_row: usize = 0;
+            let mut is_first_buffer_line = true;
+
+            for idx in 0..self.rendered_buffer_lines.len() {
+                let buffer_line = self.rendered_buffer_lines[idx];
+                if cumulative_screen_row >= max_screen_rows {
+                    break;
+                }
+
+                let line_content: String = if let Some(styled_line) = &styled_lines[idx] {
+                    styled_line.spans.iter().flat_map(|s| s.text.chars()).collect()
+                } else {
+                    String::new()
+                };
+                let line_visual_width = tab_width::line_visual_width(&line_content);
+                let rows_for_line = wrap_layout.screen_rows_for_line(line_visual_width);
+                let start_row_offset = if is_first_buffer_line {
+                    screen_row_offset_in_line
+                } else {
+                    0
+                };
+                is_first_buffer_line = false;
+
+                for cols in occurrences::word_occurrences(&line_content, &word) {
+                    if buffer_line == selection_start.line && cols.start == selection_start.col {
+                        continue;
+                    }
+                    let start_visual = tab_width::char_col_to_visual_col(&line_content, cols.start);
+                    let end_visual = tab_width::char_col_to_visual_col(&line_content, cols.end);
+
+                    for row_offset in start_row_offset..rows_for_line {
+                        let screen_row = cumulative_screen_row + (row_offset - start_row_offset);
+                        if screen_row >= max_screen_rows {
+                            break;
+                        }
+                        let row_start_col = row_offset * cols_per_row;
+                        let row_end_col = (row_offset + 1) * cols_per_row;
+                        let start_on_row = start_visual.max(row_start_col);
+                        let end_on_row = end_visual.min(row_end_col);
+                        if start_on_row < end_on_row {
+                            let quad = self.create_selection_quad_with_offset(
+                                screen_row,
+                                start_on_row - row_start_col,
+                                end_on_row - row_start_col,
+                                solid_glyph,
+                                y_offset,
+                                OCCURRENCE_HIGHLIGHT_COLOR,
+                            );
+                            self.persistent_vertices.extend_from_slice(&quad);
+                            self.persistent_indices.push(vertex_offset);
+                            self.persistent_indices.push(vertex_offset + 1);
+                            self.persistent_indices.push(vertex_offset + 2);
+                            self.persistent_indices.push(vertex_offset);
+                            self.persistent_indices.push(vertex_offset + 2);
+                            self.persistent_indices.push(vertex_offset + 3);
+                            vertex_offset += 4;
+                        }
+                    }
+                }
+
+                cumulative_screen_row += rows_for_line - start_row_offset;
+            }
+        }
+
+        let occurrence_index_count = self.persistent_indices.len() - occurrence_start_index;
+        self.occurrence_range = QuadRange::new(occurrence_start_index, occurrence_index_count);
+
         // ==================== Phase 2: Selection Quads ====================
         // Chunk: docs/chunks/cursor_wrap_scroll_alignment - Fixed screen row tracking
         // Chunk: docs/chunks/tab_rendering - Tab-aware visual column conversion for selection
+        // Chunk: docs/chunks/column_selection - Block selections draw one rectangle per row
         let selection_start_index = self.persistent_indices.len();
+        let selection = view.selection_range();
+        let block = view.block_selection();
 
-        if let Some((sel_start, sel_end)) = view.selection_range() {
+        if selection.is_some() || block.is_some() {
             let solid_glyph = atlas.solid_glyph();
             let cols_per_row = wrap_layout.cols_per_row();
 
@@ -1525,31 +2100,44 @@ impl GlyphBuffer {
                 };
                 is_first_buffer_line = false;
 
-                // Check if this buffer line intersects the selection
-                if buffer_line >= sel_start.line && buffer_line <= sel_end.line {
-                    // Calculate selection bounds for this buffer line (in character indices)
-                    let line_sel_start_char = if buffer_line == sel_start.line {
-                        sel_start.col
-                    } else {
-                        0
-                    };
-                    let line_sel_end_char = if buffer_line == sel_end.line {
-                        sel_end.col
-                    } else {
-                        // Include newline character visualization
-                        line_content.chars().count() + 1
-                    };
+                // Selection bounds on this buffer line, in visual columns.
+                // A block covers the same columns on every line, including
+                // columns past the line's end.
+                let line_bounds = if let Some(block) = block {
+                    (buffer_line >= block.top() && buffer_line <= block.bottom())
+                        .then(|| (block.left(), block.right()))
+                } else if let Some((sel_start, sel_end)) = selection {
+                    // Check if this buffer line intersects the selection
+                    (buffer_line >= sel_start.line && buffer_line <= sel_end.line).then(|| {
+                        // Calculate selection bounds for this buffer line (in character indices)
+                        let line_sel_start_char = if buffer_line == sel_start.line {
+                            sel_start.col
+                        } else {
+                            0
+                        };
+                        let line_sel_end_char = if buffer_line == sel_end.line {
+                            sel_end.col
+                        } else {
+                            // Include newline character visualization
+                            line_content.chars().count() + 1
+                        };
 
-                    // Convert character indices to visual columns
-                    // Chunk: docs/chunks/tab_rendering - Tab-aware selection bounds
-                    let line_sel_start_visual = tab_width::char_col_to_visual_col(&line_content, line_sel_start_char);
-                    let line_sel_end_visual = if line_sel_end_char > line_content.chars().count() {
-                        // Selection extends past line end (includes newline)
-                        line_visual_width + 1
-                    } else {
-                        tab_width::char_col_to_visual_col(&line_content, line_sel_end_char)
-                    };
+                        // Convert character indices to visual columns
+                        // Chunk: docs/chunks/tab_rendering - Tab-aware selection bounds
+                        let line_sel_start_visual = tab_width::char_col_to_visual_col(&line_content, line_sel_start_char);
+                        let line_sel_end_visual = if line_sel_end_char > line_content.chars().count() {
+                            // Selection extends past line end (includes newline)
+                            line_visual_width + 1
+                        } else {
+                            tab_width::char_col_to_visual_col(&line_content, line_sel_end_char)
+                        };
+                        (line_sel_start_visual, line_sel_end_visual)
+                    })
+                } else {
+                    None
+                };
 
+                if let Some((line_sel_start_visual, line_sel_end_visual)) = line_bounds {
                     if line_sel_start_visual < line_sel_end_visual {
                         // Emit selection quads for each screen row in this buffer line
                         for row_offset in start_row_offset..rows_for_line {
@@ -1558,9 +2146,16 @@ impl GlyphBuffer {
                                 break;
                             }
 
-                            // Calculate which visual columns are on this screen row
+                            // Calculate which visual columns are on this screen row.
+                            // A block may run past the text on the line's last row.
                             let row_start_col = row_offset * cols_per_row;
-                            let row_end_col = ((row_offset + 1) * cols_per_row).min(line_visual_width + 1);
+                            let row_limit = (row_offset + 1) * cols_per_row;
+                            let is_last_row = row_offset + 1 == rows_for_line;
+                            let row_end_col = if block.is_some() && is_last_row {
+                                row_limit
+                            } else {
+                                row_limit.min(line_visual_width + 1)
+                            };
 
                             // Calculate intersection with selection (in visual columns)
                             let sel_start_on_row = line_sel_start_visual.max(row_start_col);
@@ -1671,6 +2266,7 @@ impl GlyphBuffer {
         // Chunk: docs/chunks/terminal_styling_fidelity - Per-span foreground colors for terminal styling
         // Chunk: docs/chunks/tab_rendering - Tab-aware visual column tracking
         let glyph_start_index = self.persistent_indices.len();
+        self.pending_color_vertices.clear();
 
         {
             let mut cumulative_screen_row: usize = 0;
@@ -1730,6 +2326,8 @@ impl GlyphBuffer {
 
                     // Resolve foreground color for this span
                     let (fg, _) = self.palette.resolve_style_colors(&span.style);
+                    // Chunk: docs/chunks/syntax_style_modifiers - Bold and italic spans
+                    let face = FontFace::for_style(span.style.bold, span.style.italic);
 
                     for c in span.text.chars() {
                         // Get character display width using tab-aware calculation
@@ -1751,7 +2349,9 @@ impl GlyphBuffer {
 
                         // Get the glyph info from the atlas (adding on-demand if needed)
                         // Chunk: docs/chunks/terminal_background_box_drawing - On-demand glyph addition
-                        let glyph = match atlas.ensure_glyph(font, c) {
+                        // Chunk: docs/chunks/color_emoji_wide_glyphs - Color glyphs come from the RGBA page
+                        let styled = atlas.ensure_styled_text_glyph(font, c, face);
+                        let (glyph, is_color) = match styled {
                             Some(g) => g,
                             None => {
                                 visual_col += char_width;
@@ -1783,6 +2383,15 @@ impl GlyphBuffer {
                             effective_y_offset,
                             fg,
                         );
+
+                        // Color glyphs keep their own colors (only fg alpha applies)
+                        // and are drawn in a separate pass after the text
+                        if is_color {
+                            self.pending_color_vertices.extend_from_slice(&quad);
+                            visual_col += char_width;
+                            continue;
+                        }
+
                         self.persistent_vertices.extend_from_slice(&quad);
                         self.persistent_indices.push(vertex_offset);
                         self.persistent_indices.push(vertex_offset + 1);
@@ -1804,6 +2413,24 @@ impl GlyphBuffer {
         let glyph_index_count = self.persistent_indices.len() - glyph_start_index;
         self.glyph_range = QuadRange::new(glyph_start_index, glyph_index_count);
 
+        // ==================== Phase 3b: Color Glyph Quads ====================
+        // Chunk: docs/chunks/color_emoji_wide_glyphs - Color glyphs drawn from the RGBA atlas page
+        let color_glyph_start_index = self.persistent_indices.len();
+
+        for quad in self.pending_color_vertices.chunks_exact(4) {
+            self.persistent_vertices.extend_from_slice(quad);
+            self.persistent_indices.push(vertex_offset);
+            self.persistent_indices.push(vertex_offset + 1);
+            self.persistent_indices.push(vertex_offset + 2);
+            self.persistent_indices.push(vertex_offset);
+            self.persistent_indices.push(vertex_offset + 2);
+            self.persistent_indices.push(vertex_offset + 3);
+            vertex_offset += 4;
+        }
+
+        let color_glyph_index_count = self.persistent_indices.len() - color_glyph_start_index;
+        self.color_glyph_range = QuadRange::new(color_glyph_start_index, color_glyph_index_count);
+
         // ==================== Phase 4: Underline Quads ====================
         // Chunk: docs/chunks/terminal_styling_fidelity - Underline rendering for terminal styling
         // Chunk: docs/chunks/tab_rendering - Tab-aware visual width for underline quads
@@ -2009,6 +2636,15 @@ impl GlyphBuffer {
                                 self.persistent_indices.push(vertex_offset + 3);
                                 vertex_offset += 4;
                                 found_cursor = true;
+
+                                // Chunk: docs/chunks/ime_dead_key_composition - Remember where the cursor was drawn
+                                let top_left = cursor_quad[0].position;
+                                self.cursor_cell = Some((
+                                    top_left[0],
+                                    top_left[1],
+                                    self.layout.glyph_width,
+                                    self.layout.line_height,
+                                ));
                             }
                             break;
                         }
@@ -2356,6 +2992,57 @@ mod tests {
         assert_eq!(quad[0].position, [56.0, 32.0]);    // top-left
     }
 
+    // ==================== Line Decoration Tests ====================
+    // Chunk: docs/chunks/line_highlight_rulers - Current line highlight and column rulers
+
+    #[test]
+    fn test_ruler_quad_position_and_size() {
+        let mut glyph_buffer = GlyphBuffer::new(&test_metrics());
+        glyph_buffer.set_x_offset(56.0); // RAIL_WIDTH
+        glyph_buffer.set_y_offset(32.0); // TAB_BAR_HEIGHT
+
+        let solid = test_solid_glyph();
+        let color = test_color();
+
+        // Ruler at column 80, 10 rows tall
+        let quad = glyph_buffer.create_ruler_quad(80, 160.0, &solid, color);
+
+        // x = 56 + 80 * 8 = 696, 1px wide; y starts at the content top
+        assert_eq!(quad[0].position, [696.0, 32.0]);   // top-left
+        assert_eq!(quad[1].position, [697.0, 32.0]);   // top-right
+        assert_eq!(quad[2].position, [697.0, 192.0]);  // bottom-right
+        assert_eq!(quad[3].position, [696.0, 192.0]);  // bottom-left
+        assert_eq!(quad[0].uv, [0.5, 0.5]);
+        assert_eq!(quad[2].uv, [0.6, 0.6]);
+        assert_eq!(quad[0].color, color);
+    }
+
+    #[test]
+    fn test_line_decorations_default_off() {
+        let glyph_buffer = GlyphBuffer::new(&test_metrics());
+        assert!(!glyph_buffer.line_decorations().highlight_current_line);
+        assert!(glyph_buffer.line_decorations().rulers.is_empty());
+        assert!(glyph_buffer.decoration_range().is_empty());
+    }
+
+    #[test]
+    fn test_set_line_decorations() {
+        let mut glyph_buffer = GlyphBuffer::new(&test_metrics());
+        let decorations = LineDecorations {
+            highlight_current_line: true,
+            rulers: vec![80, 100, 120],
+        };
+        glyph_buffer.set_line_decorations(decorations.clone());
+        assert_eq!(glyph_buffer.line_decorations(), &decorations);
+    }
+
+    // Chunk: docs/chunks/ime_dead_key_composition - No cursor cell before the first update
+    #[test]
+    fn test_cursor_cell_empty_before_update() {
+        let glyph_buffer = GlyphBuffer::new(&test_metrics());
+        assert_eq!(glyph_buffer.cursor_cell(), None);
+    }
+
     // ==================== Wide Character Width Tests ====================
     // Chunk: docs/chunks/terminal_multibyte_rendering - Wide character width handling
 
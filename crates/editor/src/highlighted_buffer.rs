@@ -12,9 +12,14 @@
 //! When `styled_line()` is called, the view triggers viewport-batch highlighting
 //! to populate the highlighter's cache. This ensures that all visible lines are
 //! highlighted in a single pass using `QueryCursor`, rather than re-parsing the
-//! entire file for each line.
+//! entire file for each line. `styled_lines()` highlights the whole requested
+//! range with one such pass.
 
-use lite_edit_buffer::{BufferView, CursorInfo, DirtyLines, Position, StyledLine, TextBuffer};
+use std::ops::Range;
+
+use lite_edit_buffer::{
+    BlockSelection, BufferView, CursorInfo, DirtyLines, Position, StyledLine, TextBuffer,
+};
 use lite_edit_syntax::SyntaxHighlighter;
 
 /// Default viewport size for batch highlighting.
@@ -24,6 +29,37 @@ use lite_edit_syntax::SyntaxHighlighter;
 /// a screen's worth of lines to handle scrolling without re-highlighting.
 const DEFAULT_VIEWPORT_LINES: usize = 80;
 
+// Chunk: docs/chunks/styled_lines_batch - One viewport highlight per range
+/// Styles `lines` of `buffer`, stopping at its end.
+///
+/// The highlighter's viewport cache is populated once for the whole range
+/// (and at least `DEFAULT_VIEWPORT_LINES` from its start), so every line is
+/// then served from the same highlighting pass.
+fn highlighted_lines(
+    buffer: &TextBuffer,
+    highlighter: Option<&SyntaxHighlighter>,
+    lines: Range<usize>,
+) -> Vec<StyledLine> {
+    let line_count = buffer.line_count();
+    let end = lines.end.min(line_count);
+    let start = lines.start.min(end);
+
+    if let Some(hl) = highlighter.filter(|_| start < end) {
+        let viewport_end = end.max(start + DEFAULT_VIEWPORT_LINES).min(line_count);
+        hl.highlight_viewport(start, viewport_end);
+    }
+
+    (start..end)
+        .map(|line| {
+            let line_text = buffer.line_content(line);
+            match highlighter {
+                Some(hl) => StyledLine::new(hl.highlight_spans_for_line(line, &line_text)),
+                None => StyledLine::plain(line_text),
+            }
+        })
+        .collect()
+}
+
 /// A view over TextBuffer that applies syntax highlighting.
 ///
 /// This wrapper implements `BufferView` by delegating most methods to the
@@ -78,6 +114,10 @@ impl<'a> BufferView for HighlightedBufferView<'a> {
         }
     }
 
+    fn styled_lines(&self, lines: Range<usize>) -> Vec<StyledLine> {
+        highlighted_lines(self.buffer, self.highlighter, lines)
+    }
+
     fn line_len(&self, line: usize) -> usize {
         self.buffer.line_len(line)
     }
@@ -100,6 +140,10 @@ impl<'a> BufferView for HighlightedBufferView<'a> {
     fn selection_range(&self) -> Option<(Position, Position)> {
         self.buffer.selection_range()
     }
+
+    fn block_selection(&self) -> Option<BlockSelection> {
+        self.buffer.block_selection()
+    }
 }
 
 /// Mutable version of highlighted buffer view for rendering with dirty tracking.
@@ -150,6 +194,10 @@ impl<'a> BufferView for HighlightedBufferViewMut<'a> {
         }
     }
 
+    fn styled_lines(&self, lines: Range<usize>) -> Vec<StyledLine> {
+        highlighted_lines(self.buffer, self.highlighter, lines)
+    }
+
     fn line_len(&self, line: usize) -> usize {
         self.buffer.line_len(line)
     }
@@ -169,6 +217,10 @@ impl<'a> BufferView for HighlightedBufferViewMut<'a> {
     fn selection_range(&self) -> Option<(Position, Position)> {
         self.buffer.selection_range()
     }
+
+    fn block_selection(&self) -> Option<BlockSelection> {
+        self.buffer.block_selection()
+    }
 }
 
 #[cfg(test)]
@@ -205,6 +257,30 @@ mod tests {
         assert_eq!(view.line_len(1), 5);
     }
 
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of a line range
+    #[test]
+    fn test_styled_lines_matches_styled_line() {
+        use lite_edit_syntax::{LanguageRegistry, SyntaxHighlighter, SyntaxTheme};
+
+        let source = "fn main() {\n    let x = 1;\n}\n// done";
+        let buffer = TextBuffer::from_str(source);
+
+        let registry = LanguageRegistry::new();
+        let config = registry.config_for_extension("rs").expect("Rust config");
+        let theme = SyntaxTheme::catppuccin_mocha();
+        let highlighter = SyntaxHighlighter::new(config, source, theme)
+            .expect("Should create highlighter");
+
+        for hl in [None, Some(&highlighter)] {
+            let view = HighlightedBufferView::new(&buffer, hl);
+            let single: Vec<_> = (1..4).map(|line| view.styled_line(line).unwrap()).collect();
+            assert_eq!(view.styled_lines(1..4), single);
+            // The range is cut off at the end of the buffer
+            assert_eq!(view.styled_lines(2..10).len(), 2);
+            assert!(view.styled_lines(4..6).is_empty());
+        }
+    }
+
     // Chunk: docs/chunks/highlight_text_source - Integration test for stale highlighter
     #[test]
     fn test_styled_line_shows_buffer_content_when_highlighter_stale() {
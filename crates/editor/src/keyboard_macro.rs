@@ -0,0 +1,207 @@
+// Chunk: docs/chunks/keyboard_macros - Keyboard macro recording
+//!
+//! Keyboard macro recording.
+//!
+//! While recording, every key event that reaches a file buffer is appended to
+//! the macro, along with typed text (which arrives through the input method
+//! rather than as key events) converted to one character event per char.
+//! Playback feeds the stored events back through
+//! `EditorState::handle_key_buffer`, the same path live keystrokes take, so
+//! auto-indent, syntax updates and the kill ring all behave as they did
+//! while recording.
+//!
+//! Shortcuts handled before the buffer (Cmd+S, the macro keys themselves)
+//! are never recorded.
+
+use crate::input::{Key, KeyEvent, Modifiers};
+
+/// Upper bound on "play N times", so a mistyped count can't hang the editor.
+pub const MAX_PLAY_COUNT: usize = 10_000;
+
+/// Records key events into a macro and keeps the last finished one.
+#[derive(Debug, Default)]
+pub struct MacroRecorder {
+    /// Events captured so far, while recording
+    recording: Option<Vec<KeyEvent>>,
+    /// The most recently finished macro
+    last_macro: Vec<KeyEvent>,
+    /// True while a macro is being played back, so replayed events aren't
+    /// recorded again
+    playing: bool,
+}
+
+impl MacroRecorder {
+    /// Creates a recorder with no macro.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true while recording.
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Starts recording a new macro. The previous macro stays playable until
+    /// the new recording finishes.
+    pub fn start(&mut self) {
+        self.recording = Some(Vec::new());
+    }
+
+    /// Finishes recording and returns the number of events captured.
+    ///
+    /// An empty recording keeps the previous macro.
+    pub fn stop(&mut self) -> usize {
+        let events = self.recording.take().unwrap_or_default();
+        let count = events.len();
+        if !events.is_empty() {
+            self.last_macro = events;
+        }
+        count
+    }
+
+    /// Appends a key event delivered to the buffer.
+    ///
+    /// Ignored unless recording, and during playback.
+    pub fn record_key(&mut self, event: &KeyEvent) {
+        if self.playing {
+            return;
+        }
+        if let Some(events) = &mut self.recording {
+            let mut event = event.clone();
+            event.repeat = false;
+            events.push(event);
+        }
+    }
+
+    /// Appends typed text as key events.
+    ///
+    /// `replaced` characters before the cursor (the press-and-hold accent
+    /// popover's replacement) are recorded as Backspaces.
+    pub fn record_text(&mut self, replaced: usize, text: &str) {
+        if self.playing {
+            return;
+        }
+        if let Some(events) = &mut self.recording {
+            let backspace = KeyEvent::new(Key::Backspace, Modifiers::default());
+            events.extend(std::iter::repeat_n(backspace, replaced));
+            events.extend(text.chars().map(KeyEvent::char));
+        }
+    }
+
+    /// Returns the events to play, marking playback as started.
+    ///
+    /// Returns `None` while recording or if no macro has been recorded.
+    /// Callers must call [`finish_playback`](Self::finish_playback) afterwards.
+    pub fn begin_playback(&mut self) -> Option<Vec<KeyEvent>> {
+        if self.is_recording() || self.playing || self.last_macro.is_empty() {
+            return None;
+        }
+        self.playing = true;
+        Some(self.last_macro.clone())
+    }
+
+    /// Marks playback as finished.
+    pub fn finish_playback(&mut self) {
+        self.playing = false;
+    }
+}
+
+/// Parses the "play N times" prompt.
+///
+/// Accepts a positive whole number, capped at [`MAX_PLAY_COUNT`].
+pub fn parse_play_count(query: &str) -> Option<usize> {
+    match query.trim().parse::<usize>() {
+        Ok(0) | Err(_) => None,
+        Ok(n) => Some(n.min(MAX_PLAY_COUNT)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_only_while_recording() {
+        let mut recorder = MacroRecorder::new();
+        recorder.record_key(&KeyEvent::char('a'));
+        recorder.start();
+        recorder.record_key(&KeyEvent::char('b'));
+        assert_eq!(recorder.stop(), 1);
+        recorder.record_key(&KeyEvent::char('c'));
+
+        assert_eq!(recorder.begin_playback(), Some(vec![KeyEvent::char('b')]));
+    }
+
+    #[test]
+    fn test_recorded_repeats_play_as_presses() {
+        let mut recorder = MacroRecorder::new();
+        recorder.start();
+        recorder.record_key(&KeyEvent::char('x').with_repeat(true));
+        recorder.stop();
+        let events = recorder.begin_playback().unwrap();
+        assert!(!events[0].repeat);
+    }
+
+    #[test]
+    fn test_text_becomes_char_events() {
+        let mut recorder = MacroRecorder::new();
+        recorder.start();
+        recorder.record_text(1, "é!");
+        recorder.stop();
+
+        let events = recorder.begin_playback().unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].key, Key::Backspace);
+        assert_eq!(events[1], KeyEvent::char('é'));
+    }
+
+    #[test]
+    fn test_empty_recording_keeps_previous_macro() {
+        let mut recorder = MacroRecorder::new();
+        recorder.start();
+        recorder.record_key(&KeyEvent::char('a'));
+        recorder.stop();
+        recorder.start();
+        assert_eq!(recorder.stop(), 0);
+
+        assert_eq!(recorder.begin_playback(), Some(vec![KeyEvent::char('a')]));
+    }
+
+    #[test]
+    fn test_no_playback_while_recording_or_playing() {
+        let mut recorder = MacroRecorder::new();
+        assert_eq!(recorder.begin_playback(), None);
+
+        recorder.start();
+        recorder.record_key(&KeyEvent::char('a'));
+        recorder.stop();
+        assert!(recorder.begin_playback().is_some());
+        assert_eq!(recorder.begin_playback(), None);
+
+        recorder.finish_playback();
+        recorder.start();
+        assert_eq!(recorder.begin_playback(), None);
+    }
+
+    #[test]
+    fn test_playback_is_not_recorded() {
+        let mut recorder = MacroRecorder::new();
+        recorder.start();
+        recorder.record_key(&KeyEvent::char('a'));
+        recorder.stop();
+        recorder.begin_playback();
+        recorder.start();
+        recorder.record_key(&KeyEvent::char('a'));
+        recorder.finish_playback();
+        assert_eq!(recorder.stop(), 0);
+    }
+
+    #[test]
+    fn test_parse_play_count() {
+        assert_eq!(parse_play_count(" 12 "), Some(12));
+        assert_eq!(parse_play_count("0"), None);
+        assert_eq!(parse_play_count("-3"), None);
+        assert_eq!(parse_play_count("many"), None);
+        assert_eq!(parse_play_count("99999999"), Some(MAX_PLAY_COUNT));
+    }
+}
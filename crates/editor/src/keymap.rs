@@ -0,0 +1,655 @@
+// Chunk: docs/chunks/shortcut_help_overlay - Keybinding table for the shortcut help overlay
+//!
+//! The editor's keymap as data.
+//!
+//! Key handling is spread across several places: app-level shortcuts in
+//! `EditorState::handle_key`, buffer editing chords in `buffer_target`, terminal
+//! clipboard/scrollback keys in `handle_key_buffer`, and overlay navigation in
+//! `selector`, `find_target` and `confirm_dialog`. [`KEYMAP`] lists every active
+//! binding in one table, grouped by the context in which it applies. The
+//! shortcut help overlay (Cmd+?) is generated from this table, so a binding
+//! added or changed in the handlers must be updated here as well. A test in
+//! `editor_state` drives every app-level and buffer chord in the table
+//! through `EditorState::handle_key` and fails on any that nothing handles.
+//!
+//! Users can move a binding to another chord with a [`KeyOverride`] (kept in
+//! the config file and edited in the Keyboard Shortcuts tab). The handlers
+//! still match the built-in chords: [`remap`] turns a typed override chord
+//! into the built-in one before dispatch, so the built-in chord keeps working
+//! unless another override claims it. [`conflicts`] finds chords that are
+//! bound twice where both bindings apply.
+
+use serde::{Deserialize, Serialize};
+
+use crate::input::KeyEvent;
+use crate::menu::parse_chord;
+
+/// The context in which a keybinding is active.
+///
+/// Variants are declared in the order they're listed in the help overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum KeyContext {
+    /// Works regardless of the active tab type.
+    Global,
+    /// A file or scratch buffer tab.
+    Buffer,
+    /// A terminal tab.
+    Terminal,
+    /// A selector overlay (file picker, definition list, this help).
+    Selector,
+    /// The find-in-file strip.
+    Find,
+    /// A read-only diff tab.
+    Diff,
+    // Chunk: docs/chunks/problem_matcher - Problems tab bindings
+    /// A problems list tab.
+    Problems,
+    // Chunk: docs/chunks/git_commit_flow - Changes tab bindings
+    /// A Changes tab.
+    Changes,
+    // Chunk: docs/chunks/settings_tab - Settings tab bindings
+    /// A Settings tab.
+    Settings,
+    // Chunk: docs/chunks/keybinding_conflicts - Keyboard Shortcuts tab bindings
+    /// A Keyboard Shortcuts tab.
+    Shortcuts,
+    // Chunk: docs/chunks/terminal_replay - Replay tab bindings
+    /// A terminal session replay tab.
+    Replay,
+    // Chunk: docs/chunks/agent_edit_review - Review tab bindings
+    /// A Review tab of agent-proposed edits.
+    Review,
+    /// A confirm dialog.
+    Dialog,
+}
+
+impl KeyContext {
+    /// The label shown in the first column of the help overlay.
+    pub fn label(self) -> &'static str {
+        match self {
+            KeyContext::Global => "Global",
+            KeyContext::Buffer => "Buffer",
+            KeyContext::Terminal => "Terminal",
+            KeyContext::Selector => "Selector",
+            KeyContext::Find => "Find",
+            KeyContext::Diff => "Diff",
+            KeyContext::Problems => "Problems",
+            KeyContext::Changes => "Changes",
+            KeyContext::Settings => "Settings",
+            KeyContext::Shortcuts => "Shortcuts",
+            KeyContext::Replay => "Replay",
+            KeyContext::Review => "Review",
+            KeyContext::Dialog => "Dialog",
+        }
+    }
+
+    // Chunk: docs/chunks/keybinding_conflicts - Contexts named in keymap files
+    /// Every context, in declaration order.
+    pub const ALL: [KeyContext; 13] = [
+        KeyContext::Global,
+        KeyContext::Buffer,
+        KeyContext::Terminal,
+        KeyContext::Selector,
+        KeyContext::Find,
+        KeyContext::Diff,
+        KeyContext::Problems,
+        KeyContext::Changes,
+        KeyContext::Settings,
+        KeyContext::Shortcuts,
+        KeyContext::Replay,
+        KeyContext::Review,
+        KeyContext::Dialog,
+    ];
+
+    /// Returns the context with `label`, as overrides name it.
+    pub fn from_label(label: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|context| context.label() == label)
+    }
+}
+
+/// A single keybinding: the keys, what they do, and where they apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Keybinding {
+    /// The context in which the binding is active.
+    pub context: KeyContext,
+    /// The key chord as displayed, e.g. `"Cmd+Shift+T"`.
+    pub keys: &'static str,
+    /// A short description of the action.
+    pub action: &'static str,
+}
+
+const fn bind(context: KeyContext, keys: &'static str, action: &'static str) -> Keybinding {
+    Keybinding { context, keys, action }
+}
+
+// Chunk: docs/chunks/keybinding_conflicts - Only single chords can be rebound
+impl Keybinding {
+    /// Returns the key event the binding is typed with, or `None` when its
+    /// keys are a range or alternatives (e.g. `"Up/Down"`), which can't be
+    /// rebound.
+    pub fn chord(&self) -> Option<KeyEvent> {
+        parse_chord(self.keys)
+    }
+}
+
+use KeyContext::{
+    Buffer, Changes, Dialog, Diff, Find, Global, Problems, Replay, Review, Selector, Settings,
+    Shortcuts, Terminal,
+};
+
+/// Every active keybinding, grouped by context.
+pub const KEYMAP: &[Keybinding] = &[
+    // App-level shortcuts (EditorState::handle_key)
+    bind(Global, "Cmd+?", "Show keyboard shortcuts"),
+    bind(Global, "Cmd+Q", "Quit"),
+    bind(Global, "Cmd+P", "Find file in workspace"),
+    bind(Global, "Cmd+E", "Recent files"),
+    bind(Global, "Cmd+O", "Open file"),
+    bind(Global, "Cmd+F", "Find in file"),
+    bind(Global, "Cmd+Shift+F", "Search in project"),
+    bind(Global, "Cmd+N", "New workspace"),
+    bind(Global, "Cmd+T", "New tab"),
+    // Chunk: docs/chunks/scratch_buffers - New scratch buffer
+    bind(Global, "Cmd+Option+N", "New scratch buffer"),
+    bind(Global, "Cmd+Shift+T", "New terminal tab"),
+    bind(Global, "Cmd+W", "Close tab"),
+    // Chunk: docs/chunks/pinned_tabs_overflow - Pin toggle
+    bind(Global, "Cmd+Shift+K", "Pin / unpin tab"),
+    bind(Global, "Cmd+Shift+W", "Close workspace"),
+    bind(Global, "Cmd+Shift+N", "Rename workspace"),
+    bind(Global, "Cmd+Shift+]", "Next tab"),
+    bind(Global, "Cmd+Shift+[", "Previous tab"),
+    // Chunk: docs/chunks/last_tab_toggle - Last active tab toggle
+    bind(Global, "Ctrl+Tab", "Last active tab in pane (toggle)"),
+    bind(Global, "Cmd+Shift+`", "Last active tab in pane (toggle)"),
+    bind(Global, "Cmd+]", "Next workspace"),
+    bind(Global, "Cmd+[", "Previous workspace"),
+    bind(Global, "Cmd+1..9", "Switch to workspace"),
+    bind(Global, "Cmd+Shift+Arrow", "Move tab to adjacent pane"),
+    bind(Global, "Cmd+Option+Arrow", "Focus adjacent pane"),
+    bind(Global, "Cmd+Shift+Return", "Zoom the focused pane (toggle)"),
+    bind(Global, "Cmd+Shift+G", "Show uncommitted changes as a diff"),
+    // Chunk: docs/chunks/compare_tabs - Compare with another tab
+    bind(Global, "Cmd+Option+G", "Compare with another open tab"),
+    // Chunk: docs/chunks/git_commit_flow - Changes tab
+    bind(Global, "Cmd+Option+C", "Stage and commit changes"),
+    // Chunk: docs/chunks/settings_tab - Settings tab
+    bind(Global, "Cmd+,", "Show settings"),
+    // Chunk: docs/chunks/keybinding_conflicts - Keyboard Shortcuts tab
+    bind(Global, "Cmd+Option+K", "Edit keyboard shortcuts"),
+    bind(Global, "Cmd+Shift+R", "Run a shell command in a new tab"),
+    bind(Global, "Cmd+Option+R", "Run a Makefile, npm or cargo task"),
+    bind(Global, "Cmd+Shift+M", "Show compiler problems"),
+    bind(Global, "Cmd+Shift+Option+M", "Show terminal memory"),
+    // Chunk: docs/chunks/agent_resource_usage - Agents tab
+    bind(Global, "Cmd+Shift+Option+A", "Show agents and their CPU and memory"),
+    // Chunk: docs/chunks/agent_events - Agent event timeline
+    bind(Global, "Cmd+Shift+Option+E", "Show the agent's event timeline"),
+    // Chunk: docs/chunks/agent_edit_review - Review tab
+    bind(Global, "Cmd+Shift+Option+D", "Review the agent's proposed edits"),
+    // Chunk: docs/chunks/agent_templates - New agent from a template
+    bind(Global, "Cmd+Option+A", "Launch an agent from a saved template"),
+    bind(Global, "Cmd+Plus", "Increase font size"),
+    bind(Global, "Cmd+Minus", "Decrease font size"),
+    bind(Global, "Cmd+0", "Reset font size"),
+    // Chunk: docs/chunks/menu_bar - Standard AppKit menu items
+    bind(Global, "Cmd+H", "Hide lite-edit"),
+    bind(Global, "Cmd+Option+H", "Hide other apps"),
+    bind(Global, "Cmd+M", "Minimize window"),
+    bind(Global, "Cmd+Ctrl+F", "Toggle full screen"),
+    // Chunk: docs/chunks/render_stats_hud - Render stats HUD
+    #[cfg(feature = "perf-instrumentation")]
+    bind(Global, "Ctrl+Shift+H", "Toggle render stats HUD"),
+    // Buffer editing (buffer_target::resolve_command and handle_key)
+    bind(Buffer, "Cmd+S", "Save file"),
+    bind(Buffer, "Cmd+Option+P", "Print"),
+    bind(Buffer, "Cmd+Shift+Option+P", "Export as PDF"),
+    bind(Buffer, "Cmd+Shift+I", "Toggle detailed word count"),
+    // Chunk: docs/chunks/git_blame_inline - Blame mode toggle
+    bind(Buffer, "Cmd+Option+B", "Toggle inline git blame"),
+    // Chunk: docs/chunks/read_only_preview_tabs - Read-only toggle
+    bind(Buffer, "Cmd+Shift+L", "Toggle read-only"),
+    bind(Buffer, "Cmd+;", "Spelling corrections"),
+    // Chunk: docs/chunks/conflict_markers - Merge conflict navigation and resolution
+    bind(Buffer, "F7", "Next merge conflict"),
+    bind(Buffer, "Shift+F7", "Previous merge conflict"),
+    bind(Buffer, "Cmd+.", "Resolve conflict: ours, theirs or both"),
+    bind(Buffer, "Cmd+A", "Select all"),
+    bind(Buffer, "Cmd+C", "Copy"),
+    bind(Buffer, "Cmd+X", "Cut"),
+    bind(Buffer, "Cmd+V", "Paste"),
+    bind(Buffer, "Cmd+Shift+C", "Copy remote permalink for selection"),
+    bind(Buffer, "Option+Left/Right", "Move by word"),
+    bind(Buffer, "Shift+Option+Left/Right", "Select by word"),
+    bind(Buffer, "Cmd+Left / Home", "First non-blank, then line start"),
+    bind(Buffer, "Cmd+Right / End", "Line end"),
+    bind(Buffer, "Cmd+Up/Down", "Buffer start / end"),
+    bind(Buffer, "Shift+Arrow", "Extend selection"),
+    bind(Buffer, "Option+Drag", "Column (block) selection"),
+    bind(Buffer, "Triple-click", "Select line"),
+    bind(Buffer, "PageUp/PageDown", "Scroll by page"),
+    bind(Buffer, "Option+Up/Down", "Move line or selected lines"),
+    bind(Buffer, "Cmd+Shift+D", "Duplicate line or selected lines"),
+    bind(Buffer, "Option+Backspace", "Delete word backward"),
+    bind(Buffer, "Option+D / Option+Delete", "Delete word forward"),
+    bind(Buffer, "Cmd+Backspace", "Delete to line start"),
+    bind(Buffer, "Ctrl+K", "Kill to line end"),
+    bind(Buffer, "Ctrl+Space", "Set mark"),
+    bind(Buffer, "Ctrl+G", "Deactivate mark"),
+    bind(Buffer, "Ctrl+W", "Kill selection"),
+    bind(Buffer, "Ctrl+Y", "Yank last kill"),
+    bind(Buffer, "Option+Y", "Cycle to older kill after yank"),
+    bind(Buffer, "Ctrl+A / Ctrl+E", "Line start / end"),
+    bind(Buffer, "Ctrl+Shift+A/E", "Select to line start / end"),
+    bind(Buffer, "Ctrl+F / Ctrl+B", "Forward / backward char"),
+    bind(Buffer, "Ctrl+N / Ctrl+P", "Next / previous line"),
+    bind(Buffer, "Ctrl+D", "Delete character forward"),
+    bind(Buffer, "Ctrl+V", "Page down"),
+    bind(Buffer, "F12", "Go to definition"),
+    bind(Buffer, "Shift+F12", "Find references"),
+    bind(Buffer, "Ctrl+-", "Go back"),
+    bind(Buffer, "Ctrl+Q", "Start / stop recording a macro"),
+    bind(Buffer, "Ctrl+Shift+Q", "Play macro"),
+    bind(Buffer, "Ctrl+Option+Q", "Play macro N times"),
+    // Terminal tabs (EditorState::handle_key_buffer)
+    bind(Terminal, "Cmd+C", "Copy selection"),
+    bind(Terminal, "Cmd+V", "Paste"),
+    bind(Terminal, "Cmd+K", "Clear scrollback"),
+    bind(Terminal, "Cmd+Up/Down", "Previous / next prompt"),
+    bind(Terminal, "Cmd+Shift+Option+C", "Copy last command output"),
+    bind(Terminal, "Cmd+Shift+Option+R", "Set startup command"),
+    bind(Terminal, "Cmd+S", "Export scrollback as text"),
+    bind(Terminal, "Cmd+Option+S", "Export scrollback with colors"),
+    bind(Terminal, "Cmd+Shift+S", "Export session as asciicast"),
+    bind(Terminal, "Cmd+Shift+Option+T", "Replay session"),
+    bind(Terminal, "Cmd+Shift+B", "Toggle broadcast input to all terminals"),
+    bind(Terminal, "Ctrl+Shift+Space", "Toggle copy mode"),
+    bind(Terminal, "v / V (copy mode)", "Select characters / lines"),
+    bind(Terminal, "y (copy mode)", "Copy and leave copy mode"),
+    bind(Terminal, "Cmd+R (command output)", "Run the command again"),
+    bind(Terminal, "Ctrl+C (command output)", "Stop the command"),
+    // Selector overlays (selector::SelectorWidget::handle_key)
+    bind(Selector, "Up/Down", "Move selection"),
+    bind(Selector, "Return", "Choose item"),
+    bind(Selector, "Cmd+Return", "Create typed path (file picker)"),
+    bind(Selector, "Tab", "Rename / delete / reveal item (file picker)"),
+    bind(Selector, "Escape", "Close"),
+    // Find strip (find_target::FindFocusTarget)
+    bind(Find, "Return", "Find next match"),
+    bind(Find, "Escape", "Close find"),
+    bind(Find, "Option+C", "Toggle case-sensitive match"),
+    bind(Find, "Option+W", "Toggle whole-word match"),
+    // Diff tabs (EditorState::handle_key_buffer)
+    bind(Diff, "Up/Down", "Scroll by line"),
+    bind(Diff, "PageUp/PageDown / Space", "Scroll by page"),
+    bind(Diff, "Cmd+Up/Down / Home/End", "Top / bottom"),
+    bind(Diff, "S", "Toggle side-by-side / unified"),
+    // Problems tabs (EditorState::handle_key_buffer)
+    bind(Problems, "Up/Down", "Select the previous / next problem"),
+    bind(Problems, "Return", "Open the problem's location"),
+    // Changes tabs (EditorState::handle_key_buffer)
+    bind(Changes, "Up/Down", "Select the previous / next file"),
+    bind(Changes, "Space", "Stage / unstage the file"),
+    bind(Changes, "A", "Stage all files"),
+    bind(Changes, "C", "Commit the staged files"),
+    bind(Changes, "Return", "Open the file"),
+    // Settings tabs (EditorState::handle_key_buffer)
+    bind(Settings, "Up/Down", "Select the previous / next option"),
+    bind(Settings, "Return", "Toggle, or edit / save the value"),
+    bind(Settings, "Backspace", "Delete from the search or the value"),
+    bind(Settings, "Escape", "Cancel the edit, or clear the search"),
+    // Keyboard Shortcuts tabs (EditorState::handle_key_buffer)
+    bind(Shortcuts, "Up/Down", "Select the previous / next shortcut"),
+    bind(Shortcuts, "Return", "Press a new chord for the shortcut"),
+    bind(Shortcuts, "Backspace", "Restore the default (capturing)"),
+    bind(Shortcuts, "Escape", "Cancel capturing, or clear the search"),
+    bind(Shortcuts, "Cmd+S", "Export the keymap"),
+    bind(Shortcuts, "Cmd+Shift+O", "Import a keymap"),
+    // Replay tabs (EditorState::handle_key_buffer)
+    bind(Replay, "Left/Right", "Back / forward 10 seconds"),
+    bind(Replay, "Shift+Left/Right", "Back / forward a minute"),
+    bind(Replay, ", / .", "Previous / next output"),
+    bind(Replay, "Home/End", "Start / end of the session"),
+    // Review tabs (EditorState::handle_key_buffer)
+    bind(Review, "Up/Down", "Select the previous / next hunk"),
+    bind(Review, "A", "Accept the hunk"),
+    bind(Review, "R", "Reject the hunk"),
+    bind(Review, "W", "Write the files with every hunk decided"),
+    // Confirm dialogs (confirm_dialog::ConfirmDialog::handle_key)
+    bind(Dialog, "Tab / Left / Right", "Switch button"),
+    bind(Dialog, "Return", "Activate button"),
+    bind(Dialog, "Escape", "Cancel"),
+];
+
+/// Returns the help overlay lines for the bindings matching `query`.
+///
+/// Each line shows the context, keys and action in aligned columns, with the
+/// user's `overrides` applied. Lines are grouped by context in [`KeyContext`]
+/// order. The query is split on whitespace and every term must appear
+/// (case-insensitively) in the context, keys or action; an empty query lists
+/// every binding.
+pub fn help_lines(query: &str, overrides: &[KeyOverride]) -> Vec<String> {
+    let bound = bound_keys(overrides);
+    let mut bindings: Vec<&BoundKey> = bound.iter().filter(|key| key.matches(query)).collect();
+    bindings.sort_by_key(|key| key.binding.context);
+
+    let keys_width = bound.iter().map(|key| key.keys.len()).max().unwrap_or(0);
+    bindings
+        .iter()
+        .map(|key| {
+            format!(
+                "{:<9} {:<keys_width$}  {}",
+                key.binding.context.label(),
+                key.keys,
+                key.binding.action
+            )
+        })
+        .collect()
+}
+
+// =============================================================================
+// User overrides (Chunk: docs/chunks/keybinding_conflicts)
+// =============================================================================
+
+/// A user's chord for a built-in binding, as kept under `"keybindings"` in
+/// the config file and in exported keymaps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyOverride {
+    /// The binding's context label, e.g. `"Global"`
+    pub context: String,
+    /// The binding's action, e.g. `"Pin / unpin tab"`
+    pub action: String,
+    /// The chord that now triggers it, e.g. `"Cmd+K"`
+    pub keys: String,
+}
+
+impl KeyOverride {
+    /// Creates an override moving `binding` to `keys`.
+    pub fn new(binding: &Keybinding, keys: impl Into<String>) -> Self {
+        Self {
+            context: binding.context.label().to_string(),
+            action: binding.action.to_string(),
+            keys: keys.into(),
+        }
+    }
+
+    /// Returns the built-in binding this override moves, if it names one that
+    /// can be rebound and its keys are a chord.
+    pub fn binding(&self) -> Option<&'static Keybinding> {
+        let context = KeyContext::from_label(&self.context)?;
+        parse_chord(&self.keys)?;
+        KEYMAP.iter().find(|binding| {
+            binding.context == context && binding.action == self.action && binding.chord().is_some()
+        })
+    }
+}
+
+/// A keymap entry with the user's overrides applied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundKey {
+    /// The built-in binding
+    pub binding: &'static Keybinding,
+    /// The keys it is typed with now
+    pub keys: String,
+    /// True if `keys` come from an override
+    pub overridden: bool,
+}
+
+impl BoundKey {
+    /// Returns the key event the entry is typed with, if it is one chord.
+    pub fn chord(&self) -> Option<KeyEvent> {
+        parse_chord(&self.keys)
+    }
+
+    /// Returns true if every word of `query` appears in the entry's
+    /// context, keys or action, ignoring case.
+    pub fn matches(&self, query: &str) -> bool {
+        let haystack = format!(
+            "{} {} {}",
+            self.binding.context.label(),
+            self.keys,
+            self.binding.action
+        )
+        .to_lowercase();
+        query
+            .to_lowercase()
+            .split_whitespace()
+            .all(|term| haystack.contains(term))
+    }
+}
+
+/// Returns every [`KEYMAP`] entry, in order, with `overrides` applied. An
+/// override that names no rebindable binding, or whose keys aren't a chord,
+/// is ignored; of two for the same binding the later one wins.
+pub fn bound_keys(overrides: &[KeyOverride]) -> Vec<BoundKey> {
+    KEYMAP
+        .iter()
+        .map(|binding| {
+            let keys = overrides
+                .iter()
+                .rev()
+                .find(|o| o.binding().is_some_and(|b| std::ptr::eq(b, binding)))
+                .map(|o| o.keys.clone());
+            BoundKey {
+                binding,
+                overridden: keys.is_some(),
+                keys: keys.unwrap_or_else(|| binding.keys.to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Returns true if bindings in contexts `a` and `b` can both answer a chord.
+///
+/// Global shortcuts are handled before any other context, so they overlap
+/// every context.
+fn contexts_overlap(a: KeyContext, b: KeyContext) -> bool {
+    a == b || a == Global || b == Global
+}
+
+fn same_chord(a: &KeyEvent, b: &KeyEvent) -> bool {
+    a.key == b.key && a.modifiers == b.modifiers
+}
+
+/// Returns, for each entry of `bound`, the indices of the other entries
+/// typed with the same chord in an overlapping context. Only one of them
+/// runs when the chord is pressed.
+pub fn conflicts(bound: &[BoundKey]) -> Vec<Vec<usize>> {
+    let chords: Vec<Option<KeyEvent>> = bound.iter().map(BoundKey::chord).collect();
+    (0..bound.len())
+        .map(|i| {
+            let Some(chord) = &chords[i] else {
+                return Vec::new();
+            };
+            (0..bound.len())
+                .filter(|&j| {
+                    j != i
+                        && contexts_overlap(bound[i].binding.context, bound[j].binding.context)
+                        && chords[j]
+                            .as_ref()
+                            .is_some_and(|other| same_chord(chord, other))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the built-in chord to handle in place of `event` when an
+/// override assigns it to a binding active in `context` (or a global one).
+///
+/// An override in `context` takes precedence over a global one, and a later
+/// override over an earlier one.
+pub fn remap(overrides: &[KeyOverride], context: KeyContext, event: &KeyEvent) -> Option<KeyEvent> {
+    let claimed =
+        |o: &&KeyOverride| parse_chord(&o.keys).is_some_and(|chord| same_chord(&chord, event));
+    let in_context = |wanted: KeyContext| {
+        overrides
+            .iter()
+            .rev()
+            .filter(claimed)
+            .filter_map(KeyOverride::binding)
+            .find(|binding| binding.context == wanted)
+    };
+    let binding = in_context(context).or_else(|| in_context(Global))?;
+    Some(binding.chord()?.with_repeat(event.repeat))
+}
+
+/// Returns `overrides` as the text of an exported keymap: a JSON array.
+pub fn export_keymap(overrides: &[KeyOverride]) -> String {
+    let mut text = serde_json::to_string_pretty(overrides).unwrap_or_else(|_| "[]".to_string());
+    text.push('\n');
+    text
+}
+
+/// Parses an exported keymap, or a config file's `keybindings`.
+///
+/// Returns the overrides that name a binding which can be rebound, and how
+/// many were skipped because they don't.
+pub fn import_keymap(text: &str) -> Result<(Vec<KeyOverride>, usize), serde_json::Error> {
+    let mut value: serde_json::Value = serde_json::from_str(text)?;
+    if let Some(keybindings) = value.get_mut("keybindings") {
+        value = keybindings.take();
+    }
+    let overrides: Vec<KeyOverride> = serde_json::from_value(value)?;
+    let total = overrides.len();
+    let usable: Vec<KeyOverride> = overrides
+        .into_iter()
+        .filter(|o| o.binding().is_some())
+        .collect();
+    let skipped = total - usable.len();
+    Ok((usable, skipped))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_query_lists_every_binding_grouped() {
+        let lines = help_lines("", &[]);
+        assert_eq!(lines.len(), KEYMAP.len());
+        assert!(lines[0].starts_with("Global"));
+        assert!(lines.last().unwrap().starts_with("Dialog"));
+    }
+
+    #[test]
+    fn test_query_matches_all_terms_case_insensitively() {
+        let lines = help_lines("terminal SCROLLBACK", &[]);
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line.starts_with("Terminal")));
+
+        assert!(help_lines("no such binding", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_keymap_has_no_duplicate_bindings_within_a_context() {
+        for (i, a) in KEYMAP.iter().enumerate() {
+            for b in &KEYMAP[i + 1..] {
+                assert!(
+                    !(a.context == b.context && a.keys == b.keys),
+                    "{} is bound twice in {:?}",
+                    a.keys,
+                    a.context
+                );
+            }
+        }
+    }
+
+    fn pin_override(keys: &str) -> KeyOverride {
+        KeyOverride {
+            context: "Global".to_string(),
+            action: "Pin / unpin tab".to_string(),
+            keys: keys.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_built_in_keymap_has_no_conflicts() {
+        let bound = bound_keys(&[]);
+        for (key, others) in bound.iter().zip(conflicts(&bound)) {
+            assert!(
+                others.is_empty(),
+                "{} conflicts in {:?}",
+                key.keys,
+                key.binding.context
+            );
+        }
+    }
+
+    #[test]
+    fn test_overrides_apply_and_show_conflicts() {
+        let bound = bound_keys(&[pin_override("Cmd+K")]);
+        let pin = bound
+            .iter()
+            .position(|key| key.binding.action == "Pin / unpin tab")
+            .unwrap();
+        assert_eq!(bound[pin].keys, "Cmd+K");
+        assert!(bound[pin].overridden);
+
+        // The terminal's Cmd+K is shadowed by the global shortcut
+        let found = conflicts(&bound);
+        let clear = bound
+            .iter()
+            .position(|key| key.binding.action == "Clear scrollback")
+            .unwrap();
+        assert_eq!(found[pin], vec![clear]);
+        assert_eq!(found[clear], vec![pin]);
+
+        let lines = help_lines("pin", &[pin_override("Cmd+K")]);
+        assert!(lines[0].contains("Cmd+K "), "{:?}", lines);
+    }
+
+    #[test]
+    fn test_unknown_or_unchordable_overrides_are_ignored() {
+        let mut stale = pin_override("Cmd+K");
+        stale.action = "No such action".to_string();
+        let range = KeyOverride::new(&KEYMAP[0], "Cmd+1..9");
+        let moved_range = KeyOverride {
+            context: "Global".to_string(),
+            action: "Switch to workspace".to_string(),
+            keys: "Ctrl+1".to_string(),
+        };
+        let bound = bound_keys(&[stale, range, moved_range]);
+        assert!(bound.iter().all(|key| !key.overridden));
+    }
+
+    #[test]
+    fn test_export_and_import_round_trip() {
+        let overrides = vec![pin_override("Cmd+K")];
+        let text = export_keymap(&overrides);
+        assert_eq!(import_keymap(&text).unwrap(), (overrides.clone(), 0));
+
+        // A config file works too, and stale entries are skipped
+        let config = r#"{ "font_size": 13, "keybindings": [
+            { "context": "Global", "action": "Pin / unpin tab", "keys": "Cmd+K" },
+            { "context": "Nowhere", "action": "Pin / unpin tab", "keys": "Cmd+J" }
+        ] }"#;
+        assert_eq!(import_keymap(config).unwrap(), (overrides, 1));
+        assert!(import_keymap("{").is_err());
+    }
+
+    #[test]
+    fn test_remap_turns_the_new_chord_into_the_built_in_one() {
+        let overrides = [pin_override("Cmd+K")];
+        let cmd_k = parse_chord("Cmd+K").unwrap();
+        let remapped = remap(&overrides, Buffer, &cmd_k).unwrap();
+        assert_eq!(remapped, parse_chord("Cmd+Shift+K").unwrap());
+
+        // Unclaimed chords, including the old one, pass through
+        assert_eq!(
+            remap(&overrides, Buffer, &parse_chord("Cmd+Shift+K").unwrap()),
+            None
+        );
+
+        // A terminal override wins over the global one in a terminal
+        let clear = KeyOverride {
+            context: "Terminal".to_string(),
+            action: "Clear scrollback".to_string(),
+            keys: "Cmd+K".to_string(),
+        };
+        let overrides = [pin_override("Cmd+K"), clear];
+        assert_eq!(remap(&overrides, Terminal, &cmd_k), parse_chord("Cmd+K"));
+    }
+}
@@ -26,6 +26,7 @@ use sha2::{Digest, Sha256};
 use crate::glyph_atlas::{GlyphAtlas, GlyphInfo};
 use crate::glyph_buffer::{GlyphLayout, GlyphVertex, QuadRange};
 use crate::shader::VERTEX_SIZE;
+use crate::tab_bar::color_label_rgba;
 use crate::workspace::{Editor, WorkspaceStatus};
 
 // =============================================================================
@@ -44,6 +45,13 @@ pub const TILE_PADDING: f32 = 4.0;
 /// Size of the status indicator dot
 pub const STATUS_INDICATOR_SIZE: f32 = 8.0;
 
+// Chunk: docs/chunks/terminal_tab_labels - Color label dots
+/// Size of a color label dot
+pub const COLOR_LABEL_DOT_SIZE: f32 = 5.0;
+
+/// Horizontal gap between color label dots
+pub const COLOR_LABEL_DOT_GAP: f32 = 2.0;
+
 /// Vertical spacing between tiles
 pub const TILE_SPACING: f32 = 4.0;
 
@@ -86,6 +94,16 @@ pub const LABEL_COLOR: [f32; 4] = [
     1.0,
 ];
 
+// Chunk: docs/chunks/terminal_bell_attention - Bell badge color
+/// Color of the bell badge shown on a tile when one of the workspace's
+/// background terminals rang the bell.
+pub const BELL_BADGE_COLOR: [f32; 4] = [
+    0.95,
+    0.55,
+    0.15,
+    1.0,
+];
+
 /// Returns the color for a workspace status indicator.
 pub fn status_color(status: &WorkspaceStatus) -> [f32; 4] {
     match status {
@@ -246,6 +264,25 @@ pub struct LeftRailGeometry {
     pub tile_rects: Vec<TileRect>,
 }
 
+impl LeftRailGeometry {
+    /// Returns the tile slot a dragged workspace should move to when the
+    /// mouse is at screen `y`.
+    ///
+    /// Positions above the first tile map to the first slot and positions
+    /// below the last tile map to the last slot. Returns `None` when there
+    /// are no tiles.
+    // Chunk: docs/chunks/workspace_rename_reorder - Drag-to-reorder slot lookup
+    pub fn reorder_slot_at(&self, y: f32) -> Option<usize> {
+        let last = self.tile_rects.len().checked_sub(1)?;
+        let slot = self
+            .tile_rects
+            .iter()
+            .position(|rect| y < rect.y + rect.height + TILE_SPACING / 2.0)
+            .unwrap_or(last);
+        Some(slot)
+    }
+}
+
 /// Calculates the geometry for the left rail.
 ///
 /// This is a pure function suitable for unit testing.
@@ -281,6 +318,26 @@ pub fn calculate_left_rail_geometry(view_height: f32, workspace_count: usize) ->
     }
 }
 
+// Chunk: docs/chunks/terminal_tab_labels - Color label dots
+/// Returns the x positions of up to `count` color label dots on a tile.
+///
+/// The dots are centered along the bottom of the tile, between the agent
+/// badge on the left and the bell badge on the right. Only as many as fit
+/// between the badges are placed.
+pub fn color_label_dot_xs(tile_rect: &TileRect, count: usize) -> Vec<f32> {
+    let badge_space = 4.0 + STATUS_INDICATOR_SIZE + COLOR_LABEL_DOT_GAP;
+    let available = tile_rect.width - 2.0 * badge_space;
+    let fits = ((available + COLOR_LABEL_DOT_GAP) / (COLOR_LABEL_DOT_SIZE + COLOR_LABEL_DOT_GAP))
+        .floor()
+        .max(0.0) as usize;
+    let count = count.min(fits);
+    let width = count as f32 * (COLOR_LABEL_DOT_SIZE + COLOR_LABEL_DOT_GAP) - COLOR_LABEL_DOT_GAP;
+    let start = tile_rect.x + (tile_rect.width - width) / 2.0;
+    (0..count)
+        .map(|i| start + i as f32 * (COLOR_LABEL_DOT_SIZE + COLOR_LABEL_DOT_GAP))
+        .collect()
+}
+
 // =============================================================================
 // LeftRailGlyphBuffer
 // =============================================================================
@@ -311,6 +368,14 @@ pub struct LeftRailGlyphBuffer {
     status_indicator_range: QuadRange,
     /// Workspace identicons (5×5 grid per workspace)
     identicon_range: QuadRange,
+    // Chunk: docs/chunks/terminal_bell_attention - Bell badges
+    // Chunk: docs/chunks/agent_notifications - Agent attention badges share this range
+    /// Bell badges for workspaces with a pending terminal bell, and agent
+    /// badges for workspaces whose agent is waiting on the user
+    bell_badge_range: QuadRange,
+    // Chunk: docs/chunks/terminal_tab_labels - Color label dots
+    /// Dots for the color labels of each workspace's tabs
+    color_label_range: QuadRange,
 
     // Chunk: docs/chunks/quad_buffer_prealloc - Persistent buffers to avoid per-frame heap allocations
     /// Persistent vertex data buffer, reused across frames
@@ -333,6 +398,8 @@ impl LeftRailGlyphBuffer {
             active_tile_range: QuadRange::default(),
             status_indicator_range: QuadRange::default(),
             identicon_range: QuadRange::default(),
+            bell_badge_range: QuadRange::default(),
+            color_label_range: QuadRange::default(),
             persistent_vertices: Vec::new(),
             persistent_indices: Vec::new(),
         }
@@ -378,6 +445,18 @@ impl LeftRailGlyphBuffer {
         self.identicon_range
     }
 
+    // Chunk: docs/chunks/terminal_bell_attention - Bell badges
+    /// Returns the index range for bell badges.
+    pub fn bell_badge_range(&self) -> QuadRange {
+        self.bell_badge_range
+    }
+
+    // Chunk: docs/chunks/terminal_tab_labels - Color label dots
+    /// Returns the index range for color label dots.
+    pub fn color_label_range(&self) -> QuadRange {
+        self.color_label_range
+    }
+
     /// Updates the buffers from the editor state and geometry.
     ///
     /// Builds vertex data in this order:
@@ -386,6 +465,8 @@ impl LeftRailGlyphBuffer {
     /// 3. Active tile highlight
     /// 4. Status indicators
     /// 5. Workspace identicons (5×5 grids)
+    /// 6. Bell and agent attention badges
+    /// 7. Color label dots
     ///
     /// # Arguments
     /// * `device` - The Metal device for buffer creation
@@ -399,10 +480,11 @@ impl LeftRailGlyphBuffer {
         editor: &Editor,
         geometry: &LeftRailGeometry,
     ) {
-        // Estimate capacity: 1 background + tiles + indicators + identicon cells
-        // Each workspace has up to 25 identicon cells (5×5 grid)
+        // Estimate capacity: 1 background + tiles + indicators + identicon cells + bell/agent badges
+        // + color label dots. Each workspace has up to 25 identicon cells (5×5 grid)
         let workspace_count = editor.workspace_count();
-        let estimated_quads = 1 + workspace_count * 2 + workspace_count + workspace_count * 25;
+        let estimated_quads = 1 + workspace_count * 2 + workspace_count + workspace_count * 25 + workspace_count * 2
+            + workspace_count * 3;
 
         // Chunk: docs/chunks/quad_buffer_prealloc - Reuse persistent buffers instead of allocating new ones
         self.persistent_vertices.clear();
@@ -424,6 +506,8 @@ impl LeftRailGlyphBuffer {
         self.active_tile_range = QuadRange::default();
         self.status_indicator_range = QuadRange::default();
         self.identicon_range = QuadRange::default();
+        self.bell_badge_range = QuadRange::default();
+        self.color_label_range = QuadRange::default();
 
         let solid_glyph = atlas.solid_glyph();
         let active_workspace = editor.active_workspace;
@@ -565,6 +649,77 @@ impl LeftRailGlyphBuffer {
         }
         self.identicon_range = QuadRange::new(identicon_start, self.persistent_indices.len() - identicon_start);
 
+        // ==================== Phase 6: Bell and Agent Badges ====================
+        // Chunk: docs/chunks/terminal_bell_attention - Bell badge in bottom-right of tile
+        let bell_start = self.persistent_indices.len();
+        for (idx, tile_rect) in geometry.tile_rects.iter().enumerate() {
+            let Some(workspace) = editor.workspaces.get(idx) else {
+                break;
+            };
+            let badge_y = tile_rect.y + tile_rect.height - STATUS_INDICATOR_SIZE - 4.0;
+
+            if workspace.has_pending_bell() {
+                let badge_x = tile_rect.x + tile_rect.width - STATUS_INDICATOR_SIZE - 4.0;
+                let quad = self.create_rect_quad(
+                    badge_x,
+                    badge_y,
+                    STATUS_INDICATOR_SIZE,
+                    STATUS_INDICATOR_SIZE,
+                    solid_glyph,
+                    BELL_BADGE_COLOR,
+                );
+                self.persistent_vertices.extend_from_slice(&quad);
+                Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+                vertex_offset += 4;
+            }
+
+            // Chunk: docs/chunks/agent_notifications - Agent badge in bottom-left of tile
+            // Colored by the status the agent is waiting in (input, done, error)
+            if workspace.agent_attention {
+                let badge_x = tile_rect.x + 4.0;
+                let quad = self.create_rect_quad(
+                    badge_x,
+                    badge_y,
+                    STATUS_INDICATOR_SIZE,
+                    STATUS_INDICATOR_SIZE,
+                    solid_glyph,
+                    status_color(&workspace.status),
+                );
+                self.persistent_vertices.extend_from_slice(&quad);
+                Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+                vertex_offset += 4;
+            }
+        }
+        self.bell_badge_range = QuadRange::new(bell_start, self.persistent_indices.len() - bell_start);
+
+        // ==================== Phase 7: Color Label Dots ====================
+        // Chunk: docs/chunks/terminal_tab_labels - One dot per color label, along the bottom
+        let label_start = self.persistent_indices.len();
+        for (idx, tile_rect) in geometry.tile_rects.iter().enumerate() {
+            let Some(workspace) = editor.workspaces.get(idx) else {
+                break;
+            };
+            let colors = workspace.color_labels();
+            // Centered on the badges' row
+            let badge_center_y = tile_rect.y + tile_rect.height - 4.0 - STATUS_INDICATOR_SIZE / 2.0;
+            let dot_y = badge_center_y - COLOR_LABEL_DOT_SIZE / 2.0;
+            let dot_xs = color_label_dot_xs(tile_rect, colors.len());
+            for (color, dot_x) in colors.iter().zip(dot_xs) {
+                let quad = self.create_rect_quad(
+                    dot_x,
+                    dot_y,
+                    COLOR_LABEL_DOT_SIZE,
+                    COLOR_LABEL_DOT_SIZE,
+                    solid_glyph,
+                    color_label_rgba(*color),
+                );
+                self.persistent_vertices.extend_from_slice(&quad);
+                Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+                vertex_offset += 4;
+            }
+        }
+        self.color_label_range = QuadRange::new(label_start, self.persistent_indices.len() - label_start);
+
         // ==================== Create GPU Buffers ====================
         if self.persistent_vertices.is_empty() {
             self.vertex_buffer = None;
@@ -729,6 +884,45 @@ mod tests {
         assert_eq!(geom.width, RAIL_WIDTH);
     }
 
+    #[test]
+    fn test_reorder_slot_at() {
+        let geom = calculate_left_rail_geometry(600.0, 3);
+
+        // Above the first tile and within it
+        assert_eq!(geom.reorder_slot_at(0.0), Some(0));
+        assert_eq!(geom.reorder_slot_at(TOP_MARGIN + 10.0), Some(0));
+        // Within the second tile
+        let second = &geom.tile_rects[1];
+        assert_eq!(geom.reorder_slot_at(second.y + 1.0), Some(1));
+        // Far below the last tile
+        assert_eq!(geom.reorder_slot_at(590.0), Some(2));
+
+        let empty = calculate_left_rail_geometry(600.0, 0);
+        assert_eq!(empty.reorder_slot_at(100.0), None);
+    }
+
+    // Chunk: docs/chunks/terminal_tab_labels - Color label dots
+    #[test]
+    fn test_color_label_dots_fit_between_badges() {
+        let geom = calculate_left_rail_geometry(600.0, 1);
+        let tile = &geom.tile_rects[0];
+
+        assert!(color_label_dot_xs(tile, 0).is_empty());
+
+        let one = color_label_dot_xs(tile, 1);
+        assert_eq!(one.len(), 1);
+        let center = one[0] + COLOR_LABEL_DOT_SIZE / 2.0;
+        assert!((center - (tile.x + tile.width / 2.0)).abs() < 0.01);
+
+        // More colors than fit are dropped, and the rest clear both badges
+        let many = color_label_dot_xs(tile, 10);
+        assert_eq!(many.len(), 3);
+        let left_badge_end = tile.x + 4.0 + STATUS_INDICATOR_SIZE;
+        let right_badge_start = tile.x + tile.width - 4.0 - STATUS_INDICATOR_SIZE;
+        assert!(many[0] > left_badge_end);
+        assert!(many[2] + COLOR_LABEL_DOT_SIZE < right_badge_start);
+    }
+
     #[test]
     fn test_tile_rect_contains() {
         let rect = TileRect::new(10.0, 20.0, 30.0, 40.0);
@@ -25,6 +25,9 @@ pub mod row_scroller;
 // Chunk: docs/chunks/dirty_region - Dirty region tracking
 mod dirty_region;
 
+// Chunk: docs/chunks/partial_redraw - Frame damage tracking
+mod frame_damage;
+
 // Chunk: docs/chunks/wrap_layout - Word wrapping layout
 mod wrap_layout;
 
@@ -52,6 +55,49 @@ mod file_picker;
 // Chunk: docs/chunks/workspace_model - Workspace model for the editor
 pub mod workspace;
 
+// Chunk: docs/chunks/prose_word_count - Incremental word count for prose buffers
+pub mod word_count;
+
+// Chunk: docs/chunks/shortcut_help_overlay - Keybinding table for the shortcut help overlay
+pub mod keymap;
+
+// Chunk: docs/chunks/git_permalink - Remote permalinks for file line ranges
+pub mod git_remote;
+
+// Chunk: docs/chunks/diff_view - Colors shared by the generated tabs
+pub mod view_colors;
+
+// Chunk: docs/chunks/diff_view - Read-only diff buffer
+pub mod diff_view;
+
+// Chunk: docs/chunks/shell_command_runner - One-off shell commands in an output tab
+pub mod command_runner;
+
+// Chunk: docs/chunks/task_runner - Workspace task detection
+pub mod task_runner;
+
+// Chunk: docs/chunks/problem_matcher - Compiler diagnostics from build output
+pub mod problem_matcher;
+
+// Chunk: docs/chunks/spell_check - Spell checking for comments, strings and prose
+pub mod spell_check;
+
+// Chunk: docs/chunks/menu_bar - Menu bar layout
+pub mod menu;
+// Chunk: docs/chunks/cli_launcher - Socket server for the `lite` command
+pub mod remote_server;
+// Chunk: docs/chunks/macos_services - Selection offered to macOS services
+pub mod services;
+// Chunk: docs/chunks/print_pdf_export - Page layout for printing
+pub mod print_layout;
+
+// Chunk: docs/chunks/picker_file_ops - File management for the picker
+pub mod file_ops;
+// Chunk: docs/chunks/fuzzy_ranked_scoring - fzf-style fuzzy scorer
+pub mod fuzzy;
+// Chunk: docs/chunks/project_content_search - Trigram index for project search
+pub mod content_index;
+
 // Chunk: docs/chunks/viewport_scrolling - Viewport scroll state
 pub mod viewport;
 
@@ -60,6 +106,10 @@ pub mod pane_layout;
 
 // Chunk: docs/chunks/workspace_session_persistence - Session persistence
 pub mod session;
+// Chunk: docs/chunks/welcome_recents - Recently opened workspaces and files
+pub mod recents;
+// Chunk: docs/chunks/line_highlight_rulers - User configuration file
+pub mod config;
 
 // Chunk: docs/chunks/dragdrop_file_paste - Shell escaping for drag-and-drop paths
 pub mod shell_escape;
@@ -67,6 +117,40 @@ pub mod shell_escape;
 // Chunk: docs/chunks/three_way_merge - Line-level three-way merge for concurrent edits
 pub mod merge;
 
+// Chunk: docs/chunks/save_transforms - Save-time whitespace cleanups
+pub mod save_transforms;
+// Chunk: docs/chunks/format_on_save - External formatters run on save
+pub mod format_on_save;
+// Chunk: docs/chunks/background_file_io - Large files read and written off the main thread
+pub mod file_io;
+// Chunk: docs/chunks/file_view_mode - Memory-mapped views of giant files
+pub mod file_view;
+// Chunk: docs/chunks/read_only_preview_tabs - Files that open read-only
+pub mod read_only;
+
+// Chunk: docs/chunks/scratch_buffers - Scratch buffers kept in the session
+pub mod scratch;
+
+// Chunk: docs/chunks/git_status_watcher - Branch and changed files per workspace
+pub mod git_status;
+// Chunk: docs/chunks/git_commit_flow - Changes tab for staging and committing
+pub mod git_command;
+pub mod git_changes;
+// Chunk: docs/chunks/settings_tab - Searchable settings tab
+pub mod settings;
+// Chunk: docs/chunks/keybinding_conflicts - Keyboard Shortcuts tab
+pub mod shortcuts;
+// Chunk: docs/chunks/terminal_memory_budget - Terminal Memory tab
+pub mod terminal_memory;
+// Chunk: docs/chunks/agent_resource_usage - Agents dashboard
+pub mod agent_dashboard;
+// Chunk: docs/chunks/agent_events - Agent event timeline
+pub mod agent_timeline;
+// Chunk: docs/chunks/agent_edit_review - Review of agent-proposed edits
+pub mod edit_review;
+// Chunk: docs/chunks/agent_templates - Saved agent launch templates
+pub mod agent_templates;
+
 // Chunk: docs/chunks/styled_line_cache - Styled line cache for reducing per-frame allocations
 mod styled_line_cache;
 
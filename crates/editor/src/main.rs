@@ -38,6 +38,8 @@
 mod activity_assertion;
 mod buffer_target;
 mod clipboard;
+// Chunk: docs/chunks/emacs_kill_ring - Kill ring shared with the system clipboard
+mod clipboard_history;
 // Chunk: docs/chunks/renderer_styled_content - ColorPalette for styled text
 mod color_palette;
 // Chunk: docs/chunks/dirty_tab_close_confirm - Confirm dialog widget
@@ -48,8 +50,12 @@ mod context;
 // Chunk: docs/chunks/workspace_dir_picker - Directory picker for new workspaces
 mod dir_picker;
 mod dirty_region;
+// Chunk: docs/chunks/partial_redraw - Frame damage tracking
+mod frame_damage;
 // Chunk: docs/chunks/file_open_picker - File picker for opening files via Cmd+O
 mod file_picker;
+// Chunk: docs/chunks/terminal_scrollback_export - Save panel for scrollback export
+mod save_panel;
 // Chunk: docs/chunks/pty_wakeup_reentrant - Unified event queue
 mod drain_loop;
 // Chunk: docs/chunks/pty_wakeup_reentrant - Editor event types
@@ -82,6 +88,10 @@ mod left_rail;
 mod metal_view;
 // Chunk: docs/chunks/mini_buffer_model - MiniBuffer single-line editing model
 mod mini_buffer;
+// Chunk: docs/chunks/agent_notifications - macOS user notifications
+mod notifications;
+// Chunk: docs/chunks/feedback_hooks - Sound and haptic feedback
+mod feedback;
 // Chunk: docs/chunks/tiling_workspace_integration - Pane layout data structures
 mod pane_layout;
 // Chunk: docs/chunks/tiling_multi_pane_render - Pane frame rendering
@@ -89,6 +99,8 @@ mod pane_frame_buffer;
 mod renderer;
 // Chunk: docs/chunks/row_scroller_extract - Reusable scroll arithmetic
 mod row_scroller;
+// Chunk: docs/chunks/pane_scrollbar - Overlay scrollbar geometry and fading
+mod scrollbar;
 // Chunk: docs/chunks/pty_wakeup_reentrant - CFRunLoopSource wrapper
 mod runloop_source;
 mod tab_bar;
@@ -101,31 +113,115 @@ mod viewport;
 // Chunk: docs/chunks/welcome_screen - Welcome screen for empty file tabs
 mod welcome_screen;
 mod workspace;
+// Chunk: docs/chunks/prose_word_count - Incremental word count for prose buffers
+mod word_count;
+// Chunk: docs/chunks/shortcut_help_overlay - Keybinding table for the shortcut help overlay
+mod keymap;
+// Chunk: docs/chunks/git_permalink - Remote permalinks for file line ranges
+mod git_remote;
+// Chunk: docs/chunks/diff_view - Colors shared by the generated tabs
+mod view_colors;
+// Chunk: docs/chunks/diff_view - Read-only diff buffer
+mod diff_view;
+// Chunk: docs/chunks/shell_command_runner - One-off shell commands in an output tab
+mod command_runner;
+// Chunk: docs/chunks/task_runner - Workspace task detection
+mod task_runner;
+// Chunk: docs/chunks/problem_matcher - Compiler diagnostics from build output
+mod problem_matcher;
+// Chunk: docs/chunks/spell_check - Spell checking for comments, strings and prose
+mod spell_check;
+// Chunk: docs/chunks/menu_bar - Menu bar layout and its AppKit menus
+mod menu;
+mod menu_bar;
+// Chunk: docs/chunks/cli_launcher - Socket server for the `lite` command
+mod remote_server;
+// Chunk: docs/chunks/macos_services - Selection offered to macOS services
+mod services;
+// Chunk: docs/chunks/print_pdf_export - Printing and PDF export
+mod print_layout;
+mod print_output;
+// Chunk: docs/chunks/picker_file_ops - File management for the picker
+mod file_ops;
+// Chunk: docs/chunks/fuzzy_ranked_scoring - fzf-style fuzzy scorer
+mod fuzzy;
+// Chunk: docs/chunks/project_content_search - Trigram index for project search
+mod content_index;
 mod wrap_layout;
 // Chunk: docs/chunks/tab_rendering - Tab character rendering and tab-aware coordinate mapping
 mod tab_width;
 // Chunk: docs/chunks/workspace_session_persistence - Session persistence
 mod session;
+// Chunk: docs/chunks/welcome_recents - Recently opened workspaces and files
+mod recents;
+// Chunk: docs/chunks/line_highlight_rulers - User configuration file
+mod config;
+// Chunk: docs/chunks/save_transforms - Save-time whitespace cleanups
+mod save_transforms;
+// Chunk: docs/chunks/format_on_save - External formatters run on save
+mod format_on_save;
+// Chunk: docs/chunks/background_file_io - Large files read and written off the main thread
+mod file_io;
+// Chunk: docs/chunks/file_view_mode - Memory-mapped views of giant files
+mod file_view;
+// Chunk: docs/chunks/read_only_preview_tabs - Files that open read-only
+mod read_only;
+// Chunk: docs/chunks/keyboard_macros - Keyboard macro recording
+mod keyboard_macro;
+// Chunk: docs/chunks/selection_occurrences - Other occurrences of the selected word
+mod occurrences;
+// Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb trail and symbol outline
+mod breadcrumbs;
 #[cfg(feature = "perf-instrumentation")]
 mod perf_stats;
+// Chunk: docs/chunks/render_stats_hud - Render stats HUD
+#[cfg(feature = "perf-instrumentation")]
+mod perf_hud;
+// Chunk: docs/chunks/scratch_buffers - Scratch buffers kept in the session
+mod scratch;
+// Chunk: docs/chunks/git_blame_inline - Inline git blame for the cursor line
+mod git_blame;
+// Chunk: docs/chunks/git_status_watcher - Branch and changed files per workspace
+mod git_status;
+// Chunk: docs/chunks/git_commit_flow - Changes tab for staging and committing
+mod git_command;
+mod git_changes;
+// Chunk: docs/chunks/conflict_markers - Merge conflict navigation and resolution
+mod conflict_markers;
+// Chunk: docs/chunks/settings_tab - Searchable settings tab
+mod settings;
+// Chunk: docs/chunks/keybinding_conflicts - Keyboard Shortcuts tab
+mod shortcuts;
+// Chunk: docs/chunks/terminal_memory_budget - Terminal Memory tab
+mod terminal_memory;
+// Chunk: docs/chunks/agent_resource_usage - Agents dashboard
+mod agent_dashboard;
+// Chunk: docs/chunks/agent_events - Agent event timeline
+mod agent_timeline;
+// Chunk: docs/chunks/agent_edit_review - Review of agent-proposed edits
+mod edit_review;
+// Chunk: docs/chunks/agent_templates - Saved agent launch templates
+mod agent_templates;
 
 pub use file_index::FileIndex;
 pub use row_scroller::RowScroller;
 
 use std::cell::RefCell;
+use std::path::PathBuf;
 use std::ptr::NonNull;
 
 use block2::RcBlock;
 use objc2::rc::Retained;
-use objc2::runtime::ProtocolObject;
-use objc2::{define_class, msg_send, DefinedClass, MainThreadOnly};
+use objc2::runtime::{AnyObject, ProtocolObject};
+use objc2::{define_class, msg_send, ClassType, DefinedClass, MainThreadOnly};
 use objc2_app_kit::{
     NSApplication, NSApplicationActivationPolicy, NSApplicationDelegate, NSBackingStoreType,
-    NSWindow, NSWindowDelegate, NSWindowStyleMask,
+    NSPasteboard, NSPasteboardTypeString, NSWindow, NSWindowCollectionBehavior, NSWindowDelegate,
+    NSWindowStyleMask, NSWindowTabbingMode,
 };
 use objc2_foundation::{
-    ns_string, MainThreadMarker, NSNotification, NSObject, NSObjectProtocol, NSPoint, NSRect,
-    NSRunLoop, NSSize, NSTimer,
+    ns_string, MainThreadMarker, NSArray, NSNotification, NSObject, NSObjectProtocol, NSPoint,
+    NSRect, NSRunLoop, NSSize, NSString, NSTimer, NSURL,
 };
 
 // Chunk: docs/chunks/pty_wakeup_reentrant - Unified event queue components
@@ -136,12 +232,23 @@ use crate::runloop_source::{create_waker, RunLoopSource};
 // (Chunk: docs/chunks/pty_wakeup_reentrant - removed direct import)
 
 use crate::editor_state::EditorState;
+use crate::feedback::FeedbackSettings;
+use crate::input::TextInputEvent;
+use crate::menu::MenuStates;
 use crate::metal_view::MetalView;
+use crate::glyph_buffer::LineDecorations;
 use crate::renderer::Renderer;
+use crate::services::ServiceSelectionSnapshot;
 
 /// Cursor blink interval in seconds
 const CURSOR_BLINK_INTERVAL: f64 = 0.5;
 
+// Chunk: docs/chunks/native_fullscreen - Minimum window size
+/// Minimum window width in points
+const MIN_WINDOW_WIDTH: f64 = 400.0;
+/// Minimum window height in points
+const MIN_WINDOW_HEIGHT: f64 = 240.0;
+
 // Chunk: docs/chunks/pty_wakeup_reentrant - Global drain loop pointer for the CFRunLoopSource callback
 // The drain loop is stored in a global because the CFRunLoopSource callback
 // receives a raw void* context. We use Box::leak to get a 'static reference.
@@ -161,6 +268,16 @@ struct AppDelegateIvars {
     event_sender: RefCell<Option<EventSender>>,
     /// The cursor blink timer
     blink_timer: RefCell<Option<Retained<NSTimer>>>,
+    // Chunk: docs/chunks/menu_bar - Menu items don't retain their target
+    /// Target of the menu bar's command items
+    menu_target: RefCell<Option<Retained<NSObject>>>,
+    // Chunk: docs/chunks/finder_open - Files opened before the window exists
+    /// Paths opened from Finder or `open` while launching, sent once the
+    /// event channel exists
+    pending_open: RefCell<Vec<PathBuf>>,
+    // Chunk: docs/chunks/macos_services - Selection offered to services
+    /// The editor's selection, refreshed by the drain loop
+    service_selection: ServiceSelectionSnapshot,
 }
 
 impl Default for AppDelegateIvars {
@@ -169,6 +286,9 @@ impl Default for AppDelegateIvars {
             window: RefCell::new(None),
             event_sender: RefCell::new(None),
             blink_timer: RefCell::new(None),
+            menu_target: RefCell::new(None),
+            pending_open: RefCell::new(Vec::new()),
+            service_selection: ServiceSelectionSnapshot::default(),
         }
     }
 }
@@ -191,9 +311,22 @@ define_class!(
         #[unsafe(method(applicationDidFinishLaunching:))]
         fn application_did_finish_launching(&self, _notification: &NSNotification) {
             let mtm = MainThreadMarker::from(self);
+            self.register_services(mtm);
             self.setup_window(mtm);
         }
 
+        // Chunk: docs/chunks/finder_open - Open files from Finder and `open`
+        // When the app is launched to open files this arrives before
+        // applicationDidFinishLaunching:, so the paths wait for the window.
+        #[unsafe(method(application:openURLs:))]
+        fn application_open_urls(&self, _application: &NSApplication, urls: &NSArray<NSURL>) {
+            let paths: Vec<PathBuf> = urls
+                .iter()
+                .filter_map(|url| url.path().map(|path| PathBuf::from(path.to_string())))
+                .collect();
+            self.open_paths(paths);
+        }
+
         #[unsafe(method(applicationShouldTerminateAfterLastWindowClosed:))]
         fn application_should_terminate_after_last_window_closed(
             &self,
@@ -210,10 +343,18 @@ define_class!(
             // We're on the main thread and the app is terminating, so no race.
             unsafe {
                 if let Some(drain_loop_ptr) = DRAIN_LOOP {
-                    let drain_loop = &*drain_loop_ptr;
+                    let drain_loop = &mut *drain_loop_ptr;
                     if let Err(e) = session::save_session(drain_loop.editor()) {
                         eprintln!("Failed to save session: {}", e);
                     }
+                    // Chunk: docs/chunks/welcome_recents - Save recents on clean exit
+                    if let Err(e) = recents::save_recents(&drain_loop.editor().recents) {
+                        eprintln!("Failed to save recents: {}", e);
+                    }
+                    // Chunk: docs/chunks/pty_graceful_shutdown - Hang up shells before exit
+                    drain_loop.shutdown_terminals();
+                    // Chunk: docs/chunks/background_file_io - Let background saves land
+                    drain_loop.finish_file_io();
                 }
             }
         }
@@ -241,6 +382,26 @@ define_class!(
             }
         }
 
+        // Chunk: docs/chunks/native_fullscreen - Resize after full screen transitions
+        // The content view can change size as the transition finishes (the title
+        // bar is hidden or restored) without a final windowDidResize:, so the
+        // drawable, viewport and cursor regions are resynced when it completes.
+        #[unsafe(method(windowDidEnterFullScreen:))]
+        fn window_did_enter_full_screen(&self, _notification: &NSNotification) {
+            let sender = self.ivars().event_sender.borrow();
+            if let Some(sender) = sender.as_ref() {
+                let _ = sender.send_resize();
+            }
+        }
+
+        #[unsafe(method(windowDidExitFullScreen:))]
+        fn window_did_exit_full_screen(&self, _notification: &NSNotification) {
+            let sender = self.ivars().event_sender.borrow();
+            if let Some(sender) = sender.as_ref() {
+                let _ = sender.send_resize();
+            }
+        }
+
         // Chunk: docs/chunks/app_nap_blink_timer - Stop blink timer when backgrounded for App Nap
         // Chunk: docs/chunks/app_nap_activity_assertions - Release activity assertion when backgrounded
         // Chunk: docs/chunks/app_nap_file_watcher_pause - Pause file watchers for App Nap
@@ -287,6 +448,76 @@ define_class!(
             }
         }
     }
+
+    // Chunk: docs/chunks/macos_services - Services requestor and provider
+    // The delegate ends the window's responder chain, so AppKit reaches it
+    // after the editor view, which keeps no selection AppKit can see.
+    impl AppDelegate {
+        /// Offers the editor for services that take and return plain text,
+        /// going by the snapshot of its selection.
+        #[unsafe(method(validRequestorForSendType:returnType:))]
+        fn valid_requestor(
+            &self,
+            send_type: Option<&NSString>,
+            return_type: Option<&NSString>,
+        ) -> *mut AnyObject {
+            let string_type = unsafe { NSPasteboardTypeString };
+            let is_text = |ty: Option<&NSString>| ty.is_none_or(|ty| ty == string_type);
+            let selection = self.ivars().service_selection.borrow();
+            if is_text(send_type)
+                && is_text(return_type)
+                && selection.accepts(send_type.is_some(), return_type.is_some())
+            {
+                self as *const Self as *mut AnyObject
+            } else {
+                std::ptr::null_mut()
+            }
+        }
+
+        /// Writes the selected text for the service being run.
+        #[unsafe(method(writeSelectionToPasteboard:types:))]
+        fn write_selection_to_pasteboard(
+            &self,
+            pasteboard: &NSPasteboard,
+            types: &NSArray<NSString>,
+        ) -> bool {
+            self.write_service_selection(pasteboard, types)
+        }
+
+        /// Replaces the selection with the text a service returned, as if it
+        /// was typed.
+        #[unsafe(method(readSelectionFromPasteboard:))]
+        fn read_selection_from_pasteboard(&self, pasteboard: &NSPasteboard) -> bool {
+            self.read_service_selection(pasteboard)
+        }
+
+        /// Opens the files and folders sent by the "Open in lite-edit"
+        /// service, e.g. from a Finder Quick Action.
+        #[unsafe(method(openFiles:userData:error:))]
+        fn open_files_service(
+            &self,
+            pasteboard: &NSPasteboard,
+            _user_data: Option<&NSString>,
+            _error: *mut *mut NSString,
+        ) {
+            let class_array = NSArray::from_slice(&[NSURL::class()]);
+            // SAFETY: We're passing the correct class type (NSURL) and no options
+            let urls: Option<Retained<NSArray>> =
+                unsafe { pasteboard.readObjectsForClasses_options(&class_array, None) };
+            let Some(urls) = urls else {
+                return;
+            };
+            let paths: Vec<PathBuf> = urls
+                .iter()
+                .filter_map(|obj| {
+                    // SAFETY: We requested NSURL class, so the objects are NSURL instances
+                    let url: &NSURL = unsafe { &*(&*obj as *const AnyObject as *const NSURL) };
+                    url.path().map(|path| PathBuf::from(path.to_string()))
+                })
+                .collect();
+            self.open_paths(paths);
+        }
+    }
 );
 
 // Chunk: docs/chunks/metal_surface - Application delegate handling lifecycle and window setup
@@ -298,17 +529,76 @@ impl AppDelegate {
         unsafe { msg_send![super(this), init] }
     }
 
+    // Chunk: docs/chunks/finder_open - Files opened before the window exists
+    /// Sends paths opened from outside the app to the drain loop, or keeps
+    /// them until the event channel exists.
+    fn open_paths(&self, paths: Vec<PathBuf>) {
+        if paths.is_empty() {
+            return;
+        }
+        let sender = self.ivars().event_sender.borrow();
+        match sender.as_ref() {
+            Some(sender) => {
+                let _ = sender.send_open_files(paths);
+            }
+            None => self.ivars().pending_open.borrow_mut().extend(paths),
+        }
+    }
+
+    // Chunk: docs/chunks/macos_services - Register with the Services menu
+    /// Tells AppKit the editor can send and receive plain text through
+    /// services, and makes the delegate the provider of the services listed
+    /// under `NSServices` in Info.plist.
+    fn register_services(&self, mtm: MainThreadMarker) {
+        let app = NSApplication::sharedApplication(mtm);
+        let text_types = NSArray::from_slice(&[unsafe { NSPasteboardTypeString }]);
+        app.registerServicesMenuSendTypes_returnTypes(&text_types, &text_types);
+        unsafe { app.setServicesProvider(Some(self.as_ref())) };
+    }
+
+    /// Writes the selected text to `pasteboard` for the service being run,
+    /// returning whether there was plain text to write.
+    fn write_service_selection(&self, pasteboard: &NSPasteboard, types: &NSArray<NSString>) -> bool {
+        let string_type = unsafe { NSPasteboardTypeString };
+        if !types.iter().any(|ty| &*ty == string_type) {
+            return false;
+        }
+        let selection = self.ivars().service_selection.borrow();
+        let Some(text) = selection.text.as_deref() else {
+            return false;
+        };
+        pasteboard.clearContents();
+        pasteboard.setString_forType(&NSString::from_str(text), string_type)
+    }
+
+    /// Sends the text a service returned to the drain loop to replace the
+    /// selection, returning whether there was any.
+    fn read_service_selection(&self, pasteboard: &NSPasteboard) -> bool {
+        let Some(text) = pasteboard.stringForType(unsafe { NSPasteboardTypeString }) else {
+            return false;
+        };
+        let sender = self.ivars().event_sender.borrow();
+        let Some(sender) = sender.as_ref() else {
+            return false;
+        };
+        sender.send_insert_text(TextInputEvent::new(text.to_string())).is_ok()
+    }
+
     // Chunk: docs/chunks/startup_workspace_dialog - Resolve startup directory from CLI or dialog
     /// Resolves the startup directory for the initial workspace.
     ///
     /// This function implements the startup directory resolution logic:
     /// 1. If a directory argument is provided on the command line, use it
-    /// 2. Otherwise, show the NSOpenPanel directory picker
-    /// 3. Return None if the user cancels the picker (and no CLI arg was provided)
+    /// 2. Otherwise, if the app was launched to open files, use the first one's
+    ///    directory (or the first one, if it is a directory)
+    /// 3. Otherwise, reopen the most recently opened workspace that still exists
+    /// 4. Otherwise, show the NSOpenPanel directory picker
+    /// 5. Return None if the user cancels the picker (and no CLI arg was provided)
     ///
     /// For CLI argument validation: if a path is provided but doesn't exist or
-    /// isn't a directory, falls back to showing the picker (graceful degradation).
-    fn resolve_startup_directory(&self) -> Option<std::path::PathBuf> {
+    /// isn't a directory, falls back to the recents or the picker (graceful degradation).
+    // Chunk: docs/chunks/welcome_recents - Start in the most recent workspace
+    fn resolve_startup_directory(&self, recents: &recents::Recents) -> Option<std::path::PathBuf> {
         // Check for command-line argument (first arg after program name)
         if let Some(arg) = std::env::args().nth(1) {
             let path = std::path::PathBuf::from(&arg);
@@ -320,7 +610,25 @@ impl AppDelegate {
             // (graceful degradation rather than error)
         }
 
-        // No valid CLI argument, show directory picker
+        // Chunk: docs/chunks/finder_open - Start where the opened files are
+        if let Some(path) = self.ivars().pending_open.borrow().first() {
+            let dir = if path.is_dir() {
+                Some(path.as_path())
+            } else {
+                path.parent()
+            };
+            if let Some(dir) = dir {
+                return Some(dir.to_path_buf());
+            }
+        }
+
+        // No valid CLI argument: the most recent workspace, whose siblings are
+        // listed on its welcome screen
+        if let Some(root) = recents.workspaces.iter().find(|root| root.is_dir()) {
+            return Some(root.clone());
+        }
+
+        // Nothing to reopen, show directory picker
         dir_picker::pick_directory()
     }
 
@@ -348,6 +656,12 @@ impl AppDelegate {
         // current macOS space/desktop so the NSOpenPanel modal dialog appears
         // in front rather than on a hidden desktop (which looks like a hang,
         // especially when launching from a full-screen terminal).
+        // Chunk: docs/chunks/lazy_subsystem_init - Startup phase timing
+        #[cfg(feature = "perf-instrumentation")]
+        let mut startup = perf_stats::StartupProfile::default();
+        #[cfg(feature = "perf-instrumentation")]
+        startup.mark("app launched");
+
         let app = NSApplication::sharedApplication(mtm);
         #[allow(deprecated)]
         app.activateIgnoringOtherApps(true);
@@ -374,6 +688,21 @@ impl AppDelegate {
         window.setTitle(ns_string!("lite-edit"));
         window.center();
 
+        // Chunk: docs/chunks/native_fullscreen - Full screen, Split View and window tabbing
+        // The green button takes the window full screen, and holding it tiles
+        // the window next to another app's in Split View.
+        window.setCollectionBehavior(
+            NSWindowCollectionBehavior::FullScreenPrimary
+                | NSWindowCollectionBehavior::FullScreenAllowsTiling,
+        );
+        // Small enough to share a screen in Split View, large enough that the
+        // rail, tab bar and a few lines of text still fit.
+        window.setMinSize(NSSize::new(MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT));
+        // Workspaces and tabs live inside this one window. AppKit's automatic
+        // window tabbing would add a second, empty tab bar above the editor's
+        // and View menu items that can't do anything.
+        window.setTabbingMode(NSWindowTabbingMode::Disallowed);
+
         // Create the Metal-backed view and attach it to the window.
         let metal_view = MetalView::new(mtm, content_rect);
         window.setContentView(Some(&metal_view));
@@ -382,18 +711,35 @@ impl AppDelegate {
         // Make the window visible now so the app owns a space on the current
         // desktop. The directory picker modal will then appear on top of it.
         window.makeKeyAndOrderFront(None);
+        #[cfg(feature = "perf-instrumentation")]
+        startup.mark("window visible");
 
         // The renderer needs the correct scale factor to rasterize the font
         // and glyph atlas at native resolution (e.g., 2x on Retina).
         // viewDidChangeBackingProperties may not fire synchronously during
         // setContentView. sync_backing_properties above already handled this.
 
+        // Chunk: docs/chunks/font_config_zoom - Font family and size from the user config
+        let config = config::load_config();
+
         // Create the renderer
-        let mut renderer = Renderer::new(&metal_view);
+        let mut renderer = Renderer::new(&metal_view, config.font_family.clone(), config.font_point_size());
+        // Chunk: docs/chunks/line_highlight_rulers - Apply user line decorations
+        renderer.set_line_decorations(LineDecorations {
+            highlight_current_line: config.highlight_current_line,
+            rulers: config.ruler_columns(),
+        });
+        // Chunk: docs/chunks/dim_inactive_panes - Dim unfocused panes
+        renderer.set_dim_inactive_panes(config.dim_inactive_panes);
+        #[cfg(feature = "perf-instrumentation")]
+        startup.mark("renderer ready");
 
         // Get font metrics from the renderer
         let font_metrics = renderer.font_metrics();
 
+        // Chunk: docs/chunks/welcome_recents - Load recent workspaces and files
+        let saved_recents = recents::load_recents();
+
         // Chunk: docs/chunks/workspace_session_persistence - Session restoration or directory picker
         // Try to restore from session first (unless CLI argument was provided).
         // If no session exists or restoration fails, fall back to directory picker.
@@ -405,6 +751,8 @@ impl AppDelegate {
                         // Session restored successfully
                         let mut state = EditorState::new_deferred(font_metrics);
                         state.editor = editor;
+                        // Chunk: docs/chunks/syntax_style_modifiers - Theme before any tab is highlighted
+                        state.syntax_theme = config.syntax_theme();
 
                         // Chunk: docs/chunks/gotodef_session_restore - Initialize symbol indexing
                         // Start background symbol indexing for all restored workspaces.
@@ -413,7 +761,8 @@ impl AppDelegate {
                         state.initialize_symbol_indexing_for_all_workspaces();
 
                         // Chunk: docs/chunks/highlight_restore - Apply highlighting to restored tabs
-                        state.setup_all_tab_highlighting();
+                        // Chunk: docs/chunks/lazy_subsystem_init - Visible tabs now, the rest during warm-up
+                        state.setup_visible_tab_highlighting();
 
                         Some(state)
                     }
@@ -436,7 +785,7 @@ impl AppDelegate {
                 // Chunk: docs/chunks/startup_workspace_dialog - Resolve directory before initializing editor
                 // Resolve the startup directory after the window is visible so that the
                 // NSOpenPanel appears on the same space as the app window.
-                let startup_dir = match self.resolve_startup_directory() {
+                let startup_dir = match self.resolve_startup_directory(&saved_recents) {
                     Some(dir) => dir,
                     None => {
                         // User cancelled the directory picker, terminate gracefully
@@ -449,11 +798,32 @@ impl AppDelegate {
                 // Create the editor state with deferred initialization (no workspace yet),
                 // then add the startup workspace with the user-selected directory.
                 let mut state = EditorState::new_deferred(font_metrics);
+                // Chunk: docs/chunks/syntax_style_modifiers - Theme before any tab is highlighted
+                state.syntax_theme = config.syntax_theme();
                 state.add_startup_workspace(startup_dir);
                 state
             }
         };
 
+        // Chunk: docs/chunks/welcome_recents - Keep saved recents behind this launch's
+        state.editor.restore_recents(saved_recents);
+        // Chunk: docs/chunks/feedback_hooks - Feedback settings from the user config
+        state.feedback = FeedbackSettings::from_config(&config);
+        // Chunk: docs/chunks/save_transforms - Save transforms from the user config
+        state.save_config = config.save.clone();
+        // Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb strip over file panes
+        state.show_breadcrumbs = config.breadcrumbs;
+        // Chunk: docs/chunks/agent_queue - Agents run at once from the user config
+        let max_concurrent = config.agents.max_concurrent;
+        state.agent_queue.set_max_concurrent(max_concurrent);
+        // Chunk: docs/chunks/settings_tab - The config the Settings tab starts from
+        state.config = config;
+
+        // Note: when the directory picker was shown, this includes the time
+        // the user spent choosing a directory.
+        #[cfg(feature = "perf-instrumentation")]
+        startup.mark("editor state ready");
+
         // Update viewport size based on window dimensions
         let frame = metal_view.frame();
         let scale = metal_view.scale_factor();
@@ -491,6 +861,9 @@ impl AppDelegate {
         // Set the event sender on EditorState so terminals can create PtyWakeup handles
         // that signal through the event channel.
         state.set_event_sender(sender.clone());
+        // Chunk: docs/chunks/terminal_tab_labels - Shells for restored terminal tabs
+        // Spawned now that their output can wake the run loop.
+        state.spawn_restored_terminals();
 
         // Create the drain loop (owns the state, renderer, and view)
         let mut drain_loop = EventDrainLoop::new(
@@ -504,6 +877,17 @@ impl AppDelegate {
         // Set up the event sender on the MetalView
         metal_view.set_event_sender(sender.clone());
 
+        // Chunk: docs/chunks/menu_bar - Install the menu bar
+        // Menu commands go through the event channel like key presses; their
+        // enabled/checked states are refreshed by the drain loop.
+        let menu_states = MenuStates::default();
+        let menu_target = menu_bar::install_menu_bar(mtm, sender.clone(), menu_states.clone());
+        // Chunk: docs/chunks/mouse_buttons - Right-click context menu
+        metal_view.set_context_menu(menu_bar::build_context_menu(mtm, &menu_target));
+        drain_loop.set_menu_states(menu_states);
+        // Chunk: docs/chunks/macos_services - Selection snapshot for services
+        drain_loop.set_service_selection(self.ivars().service_selection.clone());
+
         // Make the view first responder to receive key events
         window.makeFirstResponder(Some(&metal_view));
 
@@ -513,6 +897,11 @@ impl AppDelegate {
 
         // Perform initial render
         drain_loop.initial_render();
+        #[cfg(feature = "perf-instrumentation")]
+        {
+            startup.mark("first frame");
+            drain_loop.set_startup_profile(startup);
+        }
 
         // Set up cursor blink timer
         let blink_timer = self.setup_cursor_blink_timer(mtm, sender.clone());
@@ -526,10 +915,29 @@ impl AppDelegate {
             DRAIN_LOOP = Some(drain_loop_ptr);
         }
 
+        // Chunk: docs/chunks/lazy_subsystem_init - Start warm-up after the first frame
+        // Deferred subsystems (language configs, file index walks, highlighting
+        // of hidden tabs) are brought up in small steps from the event loop.
+        let _ = sender.send_warmup();
+
+        // Chunk: docs/chunks/finder_open - Open the files the app was launched with
+        let pending_open = self.ivars().pending_open.take();
+        if !pending_open.is_empty() {
+            let _ = sender.send_open_files(pending_open);
+        }
+
+        // Chunk: docs/chunks/cli_launcher - Listen for the `lite` command
+        if let Some(socket) = lite_edit_cli::socket_path() {
+            if let Err(e) = remote_server::start_remote_server(&socket, sender.clone()) {
+                eprintln!("Not listening for the lite command: {}", e);
+            }
+        }
+
         // Store state in ivars
         *self.ivars().window.borrow_mut() = Some(window.clone());
         *self.ivars().event_sender.borrow_mut() = Some(sender);
         *self.ivars().blink_timer.borrow_mut() = Some(blink_timer);
+        *self.ivars().menu_target.borrow_mut() = Some(menu_target);
 
         // The RunLoopSource is kept alive by being added to the run loop.
         // We don't need to store it explicitly (it's never removed).
@@ -578,6 +986,10 @@ impl AppDelegate {
 // =============================================================================
 
 fn main() {
+    // Chunk: docs/chunks/lazy_subsystem_init - Startup phases are measured from here
+    #[cfg(feature = "perf-instrumentation")]
+    perf_stats::process_start();
+
     // Get main thread marker - panics if not on main thread
     let mtm = MainThreadMarker::new().expect("must be on main thread");
 
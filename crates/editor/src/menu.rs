@@ -0,0 +1,456 @@
+// Chunk: docs/chunks/menu_bar - Menu bar layout as data
+//!
+//! The menu bar as data.
+//!
+//! [`MENU_BAR`] lists the menus and their items. Most items run a keybinding
+//! from [`KEYMAP`](crate::keymap::KEYMAP): choosing the item sends the same
+//! key event the shortcut would, so a menu command and its shortcut can't
+//! behave differently. The remaining items send a standard AppKit action
+//! (hide, minimize, full screen) through the responder chain.
+//!
+//! The AppKit side (`menu_bar`) builds the `NSMenu`s from this table. Item
+//! enabled and checked states come from `EditorState::menu_item_state`,
+//! snapshotted into [`MenuStates`] after each change so that menu validation
+//! never reaches into the editor while it is busy.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::input::{Key, KeyEvent, Modifiers};
+
+/// One item in a menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuItem {
+    /// Runs the keybinding `keys`, written as in `KEYMAP`.
+    Command {
+        /// The item's title.
+        title: &'static str,
+        /// The keybinding, e.g. `"Cmd+Shift+T"`.
+        keys: &'static str,
+    },
+    /// Sends the AppKit action `selector` to the first responder.
+    System {
+        /// The item's title.
+        title: &'static str,
+        /// The action's selector name, e.g. `"performMiniaturize:"`.
+        selector: &'static str,
+        /// The key equivalent, or `""` for none.
+        keys: &'static str,
+    },
+    // Chunk: docs/chunks/macos_services - Services submenu
+    /// The Services submenu, which AppKit fills with the services that apply
+    /// to the current selection.
+    Services,
+    /// A separator line.
+    Separator,
+}
+
+/// The role AppKit gives a menu, beyond holding items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuRole {
+    /// An ordinary menu.
+    Plain,
+    /// The application menu (titled with the app name by the system).
+    App,
+    /// The Window menu, to which AppKit adds the window list.
+    Window,
+    /// The Help menu, to which AppKit adds the menu search field.
+    Help,
+}
+
+/// A menu in the menu bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Menu {
+    /// The menu's title.
+    pub title: &'static str,
+    /// What AppKit should treat the menu as.
+    pub role: MenuRole,
+    /// The menu's items, top to bottom.
+    pub items: &'static [MenuItem],
+}
+
+const fn command(title: &'static str, keys: &'static str) -> MenuItem {
+    MenuItem::Command { title, keys }
+}
+
+const fn system(title: &'static str, selector: &'static str, keys: &'static str) -> MenuItem {
+    MenuItem::System {
+        title,
+        selector,
+        keys,
+    }
+}
+
+use MenuItem::Separator;
+
+/// The menu bar, left to right.
+pub const MENU_BAR: &[Menu] = &[
+    Menu {
+        title: "lite-edit",
+        role: MenuRole::App,
+        items: &[
+            system("About lite-edit", "orderFrontStandardAboutPanel:", ""),
+            Separator,
+            // Chunk: docs/chunks/settings_tab - Settings tab
+            command("Settings…", "Cmd+,"),
+            Separator,
+            MenuItem::Services,
+            Separator,
+            system("Hide lite-edit", "hide:", "Cmd+H"),
+            system("Hide Others", "hideOtherApplications:", "Cmd+Option+H"),
+            system("Show All", "unhideAllApplications:", ""),
+            Separator,
+            command("Quit lite-edit", "Cmd+Q"),
+        ],
+    },
+    Menu {
+        title: "File",
+        role: MenuRole::Plain,
+        items: &[
+            command("New Tab", "Cmd+T"),
+            command("New Scratch Buffer…", "Cmd+Option+N"),
+            command("New Workspace…", "Cmd+N"),
+            command("Open File…", "Cmd+O"),
+            command("Find File in Workspace…", "Cmd+P"),
+            command("Recent Files…", "Cmd+E"),
+            Separator,
+            command("Save", "Cmd+S"),
+            command("Read-Only", "Cmd+Shift+L"),
+            Separator,
+            command("Export as PDF…", "Cmd+Shift+Option+P"),
+            command("Print…", "Cmd+Option+P"),
+            Separator,
+            command("Rename Workspace…", "Cmd+Shift+N"),
+            command("Close Tab", "Cmd+W"),
+            command("Close Workspace", "Cmd+Shift+W"),
+        ],
+    },
+    Menu {
+        title: "Edit",
+        role: MenuRole::Plain,
+        items: &[
+            command("Cut", "Cmd+X"),
+            command("Copy", "Cmd+C"),
+            command("Paste", "Cmd+V"),
+            command("Select All", "Cmd+A"),
+            command("Copy Remote Permalink", "Cmd+Shift+C"),
+            Separator,
+            command("Duplicate Line", "Cmd+Shift+D"),
+            command("Spelling Corrections…", "Cmd+;"),
+            Separator,
+            command("Next Conflict", "F7"),
+            command("Previous Conflict", "Shift+F7"),
+            command("Resolve Conflict…", "Cmd+."),
+            Separator,
+            command("Find…", "Cmd+F"),
+            command("Search in Project…", "Cmd+Shift+F"),
+            command("Go to Definition", "F12"),
+            command("Find References", "Shift+F12"),
+        ],
+    },
+    Menu {
+        title: "View",
+        role: MenuRole::Plain,
+        items: &[
+            command("Zoom Pane", "Cmd+Shift+Return"),
+            command("Detailed Word Count", "Cmd+Shift+I"),
+            command("Inline Blame", "Cmd+Option+B"),
+            Separator,
+            command("Show Uncommitted Changes", "Cmd+Shift+G"),
+            command("Stage and Commit…", "Cmd+Option+C"),
+            command("Compare With…", "Cmd+Option+G"),
+            command("Show Problems", "Cmd+Shift+M"),
+            Separator,
+            command("Increase Font Size", "Cmd+Plus"),
+            command("Decrease Font Size", "Cmd+Minus"),
+            command("Reset Font Size", "Cmd+0"),
+            Separator,
+            system("Enter Full Screen", "toggleFullScreen:", "Cmd+Ctrl+F"),
+        ],
+    },
+    Menu {
+        title: "Terminal",
+        role: MenuRole::Plain,
+        items: &[
+            command("New Terminal Tab", "Cmd+Shift+T"),
+            command("Run Command…", "Cmd+Shift+R"),
+            command("Run Task…", "Cmd+Option+R"),
+            command("New Agent…", "Cmd+Option+A"),
+            Separator,
+            command("Clear Scrollback", "Cmd+K"),
+            command("Copy Last Command Output", "Cmd+Shift+Option+C"),
+            command("Set Startup Command…", "Cmd+Shift+Option+R"),
+            command("Broadcast Input", "Cmd+Shift+B"),
+            command("Copy Mode", "Ctrl+Shift+Space"),
+            Separator,
+            command("Replay Session", "Cmd+Shift+Option+T"),
+            command("Export Session Recording…", "Cmd+Shift+S"),
+            command("Terminal Memory", "Cmd+Shift+Option+M"),
+            command("Agents", "Cmd+Shift+Option+A"),
+            command("Agent Timeline", "Cmd+Shift+Option+E"),
+            command("Review Proposed Edits", "Cmd+Shift+Option+D"),
+        ],
+    },
+    Menu {
+        title: "Window",
+        role: MenuRole::Window,
+        items: &[
+            system("Minimize", "performMiniaturize:", "Cmd+M"),
+            system("Zoom", "performZoom:", ""),
+            Separator,
+            command("Pin Tab", "Cmd+Shift+K"),
+            command("Next Tab", "Cmd+Shift+]"),
+            command("Previous Tab", "Cmd+Shift+["),
+            command("Last Active Tab", "Ctrl+Tab"),
+            command("Next Workspace", "Cmd+]"),
+            command("Previous Workspace", "Cmd+["),
+        ],
+    },
+    Menu {
+        title: "Help",
+        role: MenuRole::Help,
+        items: &[
+            command("Keyboard Shortcuts", "Cmd+?"),
+            // Chunk: docs/chunks/keybinding_conflicts - Keyboard Shortcuts tab
+            command("Edit Keyboard Shortcuts…", "Cmd+Option+K"),
+        ],
+    },
+];
+
+// Chunk: docs/chunks/mouse_buttons - Right-click context menu
+/// Items of the context menu shown on a right-click.
+///
+/// Every command here is also in [`MENU_BAR`], whose validation state it
+/// shares.
+pub const CONTEXT_MENU: &[MenuItem] = &[
+    command("Cut", "Cmd+X"),
+    command("Copy", "Cmd+C"),
+    command("Paste", "Cmd+V"),
+    // Chunk: docs/chunks/remote_url_pushed_check - Share a location from the context menu
+    command("Copy Remote Permalink", "Cmd+Shift+C"),
+    Separator,
+    command("Select All", "Cmd+A"),
+    Separator,
+    command("Go to Definition", "F12"),
+    command("Find References", "Shift+F12"),
+];
+
+/// Enabled and checked state of a menu item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MenuItemState {
+    /// The item can be chosen.
+    pub enabled: bool,
+    /// The item shows a check mark.
+    pub checked: bool,
+}
+
+impl Default for MenuItemState {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            checked: false,
+        }
+    }
+}
+
+/// The state of every command item, keyed by keybinding.
+///
+/// Written by the drain loop, read when AppKit validates a menu item.
+pub type MenuStates = Rc<RefCell<HashMap<&'static str, MenuItemState>>>;
+
+/// Returns the keybinding of every command item in the menu bar.
+pub fn command_keys() -> impl Iterator<Item = &'static str> {
+    MENU_BAR
+        .iter()
+        .flat_map(|menu| menu.items)
+        .filter_map(|item| match item {
+            MenuItem::Command { keys, .. } => Some(*keys),
+            MenuItem::System { .. } | MenuItem::Services | MenuItem::Separator => None,
+        })
+}
+
+// Chunk: docs/chunks/keybinding_conflicts - Every key a rebound chord can use
+/// Keys written by name in chords, other than `Space`, `Plus` and `Minus`.
+const NAMED_KEYS: &[(&str, Key)] = &[
+    ("Return", Key::Return),
+    ("Tab", Key::Tab),
+    ("Escape", Key::Escape),
+    ("Backspace", Key::Backspace),
+    ("Delete", Key::Delete),
+    ("Up", Key::Up),
+    ("Down", Key::Down),
+    ("Left", Key::Left),
+    ("Right", Key::Right),
+    ("Home", Key::Home),
+    ("End", Key::End),
+    ("PageUp", Key::PageUp),
+    ("PageDown", Key::PageDown),
+    ("F1", Key::F1),
+    ("F2", Key::F2),
+    ("F3", Key::F3),
+    ("F4", Key::F4),
+    ("F5", Key::F5),
+    ("F6", Key::F6),
+    ("F7", Key::F7),
+    ("F8", Key::F8),
+    ("F9", Key::F9),
+    ("F10", Key::F10),
+    ("F11", Key::F11),
+    ("F12", Key::F12),
+];
+
+/// Parses a single chord such as `"Cmd+Shift+T"` into the key event that
+/// typing it produces.
+///
+/// Letters are lowercase with `shift` set, as the view reports them. Returns
+/// `None` for anything that isn't one chord (ranges, mouse gestures,
+/// alternatives).
+pub fn parse_chord(keys: &str) -> Option<KeyEvent> {
+    let mut modifiers = Modifiers::default();
+    let mut parts: Vec<&str> = keys.split('+').collect();
+    let key_name = parts.pop()?;
+    for part in parts {
+        match part {
+            "Cmd" => modifiers.command = true,
+            "Shift" => modifiers.shift = true,
+            "Option" => modifiers.option = true,
+            "Ctrl" => modifiers.control = true,
+            _ => return None,
+        }
+    }
+    let key = match key_name {
+        "Return" => Key::Return,
+        "Tab" => Key::Tab,
+        "Space" => Key::Char(' '),
+        "Plus" => Key::Char('+'),
+        "Minus" => Key::Char('-'),
+        _ => match NAMED_KEYS.iter().find(|(name, _)| *name == key_name) {
+            Some((_, key)) => key.clone(),
+            None => {
+                let mut chars = key_name.chars();
+                let ch = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                Key::Char(ch.to_ascii_lowercase())
+            }
+        },
+    };
+    Some(KeyEvent::new(key, modifiers))
+}
+
+// Chunk: docs/chunks/keybinding_conflicts - Captured chords as keymap text
+/// Formats a key event as the chord [`parse_chord`] reads back, with
+/// modifiers in the keymap's order, e.g. `"Cmd+Shift+T"`.
+///
+/// Returns `None` for keys that have no name in a chord (e.g. Insert).
+pub fn format_chord(event: &KeyEvent) -> Option<String> {
+    let key = match &event.key {
+        Key::Char(' ') => "Space".to_string(),
+        Key::Char('+') => "Plus".to_string(),
+        Key::Char('-') => "Minus".to_string(),
+        Key::Char(ch) => ch.to_uppercase().to_string(),
+        key => NAMED_KEYS
+            .iter()
+            .find(|(_, named)| named == key)
+            .map(|(name, _)| name.to_string())?,
+    };
+    let modifiers = &event.modifiers;
+    let mut parts = Vec::new();
+    for (held, name) in [
+        (modifiers.command, "Cmd"),
+        (modifiers.control, "Ctrl"),
+        (modifiers.shift, "Shift"),
+        (modifiers.option, "Option"),
+    ] {
+        if held {
+            parts.push(name.to_string());
+        }
+    }
+    parts.push(key);
+    Some(parts.join("+"))
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keymap::KEYMAP;
+
+    #[test]
+    fn test_every_command_item_is_a_listed_chord() {
+        for keys in command_keys() {
+            assert!(
+                KEYMAP.iter().any(|binding| binding.keys == keys),
+                "{} is not in the keymap",
+                keys
+            );
+            assert!(parse_chord(keys).is_some(), "{} does not parse", keys);
+        }
+    }
+
+    #[test]
+    fn test_no_key_equivalent_is_used_twice() {
+        let mut seen = Vec::new();
+        for item in MENU_BAR.iter().flat_map(|menu| menu.items) {
+            let keys = match item {
+                MenuItem::Command { keys, .. } | MenuItem::System { keys, .. } => *keys,
+                MenuItem::Services | MenuItem::Separator => continue,
+            };
+            if !keys.is_empty() {
+                assert!(!seen.contains(&keys), "{} is used twice", keys);
+                seen.push(keys);
+            }
+        }
+    }
+
+    // Chunk: docs/chunks/mouse_buttons - Right-click context menu
+    #[test]
+    fn test_context_menu_commands_are_in_the_menu_bar() {
+        for item in CONTEXT_MENU {
+            if let MenuItem::Command { keys, .. } = item {
+                assert!(
+                    command_keys().any(|k| k == *keys),
+                    "{} is not in the menu bar",
+                    keys
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_chord() {
+        let event = parse_chord("Cmd+Shift+T").unwrap();
+        assert_eq!(event.key, Key::Char('t'));
+        assert!(event.modifiers.command && event.modifiers.shift);
+        assert!(!event.modifiers.option && !event.modifiers.control);
+
+        let event = parse_chord("Ctrl+Shift+Space").unwrap();
+        assert_eq!(event.key, Key::Char(' '));
+        assert!(event.modifiers.control && event.modifiers.shift);
+
+        assert_eq!(parse_chord("Shift+F12").unwrap().key, Key::F12);
+        assert!(parse_chord("Cmd+1..9").is_none());
+        assert!(parse_chord("Option+Drag").is_none());
+    }
+
+    #[test]
+    fn test_format_chord_round_trips() {
+        for keys in [
+            "Cmd+Shift+T",
+            "Cmd+Ctrl+F",
+            "Ctrl+Shift+Space",
+            "Cmd+Plus",
+            "Option+F5",
+        ] {
+            let event = parse_chord(keys).unwrap();
+            assert_eq!(format_chord(&event).as_deref(), Some(keys));
+        }
+        let insert = KeyEvent::new(Key::Insert, Modifiers::default());
+        assert_eq!(format_chord(&insert), None);
+    }
+}
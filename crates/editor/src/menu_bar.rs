@@ -0,0 +1,247 @@
+// Chunk: docs/chunks/menu_bar - AppKit menu bar
+//!
+//! The application's menu bar.
+//!
+//! Builds the `NSMenu`s listed in [`MENU_BAR`]. Command items target a
+//! `MenuTarget`, which turns the chosen item back into its keybinding's key
+//! event and sends it through the event channel, like a key press on the view.
+//! AppKit offers key equivalents to the menu bar before the view sees them,
+//! so typing a shortcut and choosing its item take the same path. Standard
+//! items (hide, minimize, full screen) have no target and go to the first
+//! responder, and AppKit fills the Services submenu itself.
+//!
+//! Validation reads the [`MenuStates`] snapshot kept by the drain loop.
+
+use std::ffi::CString;
+
+use objc2::rc::Retained;
+use objc2::runtime::{NSObject, Sel};
+use objc2::{define_class, msg_send, sel, DefinedClass, MainThreadOnly};
+use objc2_app_kit::{
+    NSApplication, NSControlStateValueOff, NSControlStateValueOn, NSEventModifierFlags,
+    NSEventType, NSMenu, NSMenuItem,
+};
+use objc2_foundation::{MainThreadMarker, NSObjectProtocol, NSString};
+
+use crate::event_channel::EventSender;
+use crate::input::{Key, KeyEvent};
+use crate::menu::{
+    command_keys, parse_chord, MenuItem, MenuRole, MenuStates, CONTEXT_MENU, MENU_BAR,
+};
+
+// Chunk: docs/chunks/conflict_markers - F7 for conflict navigation
+/// `NSF7FunctionKey`, the key equivalent character for F7
+const F7_FUNCTION_KEY: char = '\u{F70A}';
+
+/// `NSF12FunctionKey`, the key equivalent character for F12
+const F12_FUNCTION_KEY: char = '\u{F70F}';
+
+/// Internal state for the menu target.
+struct MenuTargetIvars {
+    /// Receives the key events of chosen command items
+    sender: EventSender,
+    /// Enabled and checked states, by keybinding
+    states: MenuStates,
+}
+
+define_class!(
+    // SAFETY: MenuTarget follows Objective-C memory management rules
+    // and is only accessed from the main thread
+    #[unsafe(super = NSObject)]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = MenuTargetIvars]
+    #[name = "LiteEditMenuTarget"]
+    struct MenuTarget;
+
+    // SAFETY: NSObjectProtocol is correctly implemented - we inherit from NSObject
+    unsafe impl NSObjectProtocol for MenuTarget {}
+
+    impl MenuTarget {
+        /// Sends the chosen item's key event, as if its shortcut was typed.
+        #[unsafe(method(performMenuCommand:))]
+        fn __perform_menu_command(&self, item: &NSMenuItem) {
+            let Some(event) = item_keys(item).and_then(parse_chord) else {
+                return;
+            };
+            // Holding a key equivalent repeats the item. Carry the repeat flag
+            // so one-shot shortcuts still fire once per press.
+            let app = NSApplication::sharedApplication(MainThreadMarker::from(self));
+            let repeat = app
+                .currentEvent()
+                .is_some_and(|current| current.r#type() == NSEventType::KeyDown && current.isARepeat());
+            let _ = self.ivars().sender.send_key(event.with_repeat(repeat));
+        }
+
+        /// Enables and checks a command item from the editor's state.
+        #[unsafe(method(validateMenuItem:))]
+        fn __validate_menu_item(&self, item: &NSMenuItem) -> bool {
+            self.validate_menu_item(item)
+        }
+    }
+);
+
+impl MenuTarget {
+    fn new(mtm: MainThreadMarker, sender: EventSender, states: MenuStates) -> Retained<Self> {
+        let this = mtm.alloc::<Self>();
+        let this = this.set_ivars(MenuTargetIvars { sender, states });
+        unsafe { msg_send![super(this), init] }
+    }
+
+    /// Sets a command item's check mark and returns whether it is enabled.
+    /// Items without a command are always enabled.
+    fn validate_menu_item(&self, item: &NSMenuItem) -> bool {
+        let Some(keys) = item_keys(item) else {
+            return true;
+        };
+        let state = self.ivars().states.borrow().get(keys).copied().unwrap_or_default();
+        item.setState(if state.checked {
+            NSControlStateValueOn
+        } else {
+            NSControlStateValueOff
+        });
+        state.enabled
+    }
+}
+
+/// Returns the keybinding of a command item, from its tag.
+fn item_keys(item: &NSMenuItem) -> Option<&'static str> {
+    let index = usize::try_from(item.tag()).ok()?;
+    command_keys().nth(index)
+}
+
+/// Returns the key equivalent and modifier mask that display and trigger
+/// `event`, or `None` for keys AppKit can't show.
+fn key_equivalent(event: &KeyEvent) -> Option<(String, NSEventModifierFlags)> {
+    let key = match event.key {
+        Key::Char(ch) => ch.to_string(),
+        Key::Return => "\r".to_string(),
+        Key::Tab => "\t".to_string(),
+        Key::F7 => F7_FUNCTION_KEY.to_string(),
+        Key::F12 => F12_FUNCTION_KEY.to_string(),
+        _ => return None,
+    };
+    let mut mask = NSEventModifierFlags::empty();
+    for (held, flag) in [
+        (event.modifiers.command, NSEventModifierFlags::Command),
+        (event.modifiers.shift, NSEventModifierFlags::Shift),
+        (event.modifiers.option, NSEventModifierFlags::Option),
+        (event.modifiers.control, NSEventModifierFlags::Control),
+    ] {
+        if held {
+            mask |= flag;
+        }
+    }
+    Some((key, mask))
+}
+
+/// Creates a menu item with the key equivalent for `keys` (`""` for none).
+fn menu_item(
+    mtm: MainThreadMarker,
+    title: &str,
+    action: Sel,
+    keys: &str,
+) -> Retained<NSMenuItem> {
+    let (key, mask) = parse_chord(keys)
+        .and_then(|event| key_equivalent(&event))
+        .unwrap_or_else(|| (String::new(), NSEventModifierFlags::empty()));
+    let item = unsafe {
+        NSMenuItem::initWithTitle_action_keyEquivalent(
+            mtm.alloc(),
+            &NSString::from_str(title),
+            Some(action),
+            &NSString::from_str(&key),
+        )
+    };
+    item.setKeyEquivalentModifierMask(mask);
+    item
+}
+
+/// Builds the menu bar and installs it as the application's main menu.
+///
+/// Returns the target of the command items. Menu items don't retain their
+/// target, so the caller must keep it alive for as long as the menu bar.
+pub fn install_menu_bar(
+    mtm: MainThreadMarker,
+    sender: EventSender,
+    states: MenuStates,
+) -> Retained<NSObject> {
+    let app = NSApplication::sharedApplication(mtm);
+    let target = MenuTarget::new(mtm, sender, states);
+    let main_menu = NSMenu::new(mtm);
+    let mut tag = 0;
+
+    for menu in MENU_BAR {
+        let submenu = NSMenu::initWithTitle(mtm.alloc(), &NSString::from_str(menu.title));
+        for item in menu.items {
+            let ns_item = match *item {
+                MenuItem::Command { title, keys } => {
+                    let ns_item = menu_item(mtm, title, sel!(performMenuCommand:), keys);
+                    unsafe { ns_item.setTarget(Some(&target)) };
+                    ns_item.setTag(tag);
+                    tag += 1;
+                    ns_item
+                }
+                MenuItem::System {
+                    title,
+                    selector,
+                    keys,
+                } => {
+                    let name = CString::new(selector).expect("selector contains a nul byte");
+                    menu_item(mtm, title, Sel::register(&name), keys)
+                }
+                // Chunk: docs/chunks/macos_services - Services submenu
+                MenuItem::Services => {
+                    let title = NSString::from_str("Services");
+                    let services = NSMenu::initWithTitle(mtm.alloc(), &title);
+                    app.setServicesMenu(Some(&services));
+                    let ns_item = NSMenuItem::new(mtm);
+                    ns_item.setTitle(&title);
+                    ns_item.setSubmenu(Some(&services));
+                    ns_item
+                }
+                MenuItem::Separator => NSMenuItem::separatorItem(mtm),
+            };
+            submenu.addItem(&ns_item);
+        }
+
+        match menu.role {
+            MenuRole::Plain | MenuRole::App => {}
+            MenuRole::Window => app.setWindowsMenu(Some(&submenu)),
+            MenuRole::Help => app.setHelpMenu(Some(&submenu)),
+        }
+
+        let bar_item = NSMenuItem::new(mtm);
+        bar_item.setSubmenu(Some(&submenu));
+        main_menu.addItem(&bar_item);
+    }
+
+    app.setMainMenu(Some(&main_menu));
+    Retained::into_super(target)
+}
+
+// Chunk: docs/chunks/mouse_buttons - Right-click context menu
+/// Builds the context menu shown on a right-click.
+///
+/// Its command items go to `target`, the target returned by
+/// [`install_menu_bar`], tagged like the same items in the menu bar.
+pub fn build_context_menu(mtm: MainThreadMarker, target: &NSObject) -> Retained<NSMenu> {
+    let menu = NSMenu::new(mtm);
+    for item in CONTEXT_MENU {
+        let ns_item = match *item {
+            MenuItem::Command { title, keys } => {
+                let ns_item = menu_item(mtm, title, sel!(performMenuCommand:), keys);
+                unsafe { ns_item.setTarget(Some(target)) };
+                let tag = command_keys()
+                    .position(|k| k == keys)
+                    .expect("context menu command is in the menu bar");
+                ns_item.setTag(tag as isize);
+                ns_item
+            }
+            MenuItem::System { .. } | MenuItem::Services | MenuItem::Separator => {
+                NSMenuItem::separatorItem(mtm)
+            }
+        };
+        menu.addItem(&ns_item);
+    }
+    menu
+}
@@ -40,15 +40,17 @@ use objc2::{define_class, msg_send, ClassType, DefinedClass, MainThreadOnly};
 // Chunk: docs/chunks/dragdrop_file_paste - NSDragOperation and NSDraggingInfo for drag-drop support
 // Chunk: docs/chunks/input_keystroke_regression - NSTextInputClient protocol conformance
 use objc2_app_kit::{
-    NSCursor, NSDragOperation, NSDraggingInfo, NSEvent, NSEventModifierFlags,
-    NSPasteboardTypeFileURL, NSTextInputClient, NSView,
+    NSCursor, NSDragOperation, NSDraggingInfo, NSEvent, NSEventModifierFlags, NSMenu,
+    NSPasteboardTypeFileURL, NSTextInputClient, NSTrackingArea, NSTrackingAreaOptions, NSView,
+};
+use objc2_foundation::{
+    MainThreadMarker, NSArray, NSObjectProtocol, NSRange, NSRect, NSSize, NSURL,
 };
-use objc2_foundation::{MainThreadMarker, NSArray, NSObjectProtocol, NSRect, NSSize, NSURL};
 use objc2_metal::MTLDevice;
 use objc2_quartz_core::{CALayer, CAMetalLayer};
 
 use crate::event_channel::EventSender;
-use crate::input::{Key, KeyEvent, MarkedTextEvent, Modifiers, MouseEvent, MouseEventKind, ScrollDelta, TextInputEvent};
+use crate::input::{Key, KeyEvent, MarkedTextEvent, Modifiers, MouseButton, MouseEvent, MouseEventKind, ScrollDelta, TextInputEvent};
 
 // CGFloat is a type alias for f64 on 64-bit systems
 type CGFloat = f64;
@@ -122,6 +124,11 @@ pub struct CursorRegions {
     pub pointer_rects: Vec<CursorRect>,
     /// Regions with I-beam cursor (buffer text area, mini-buffer input, etc.)
     pub ibeam_rects: Vec<CursorRect>,
+    // Chunk: docs/chunks/pane_divider_drag - Resize cursors over pane dividers
+    /// Regions with left-right resize cursor (dividers between side-by-side panes)
+    pub resize_horizontal_rects: Vec<CursorRect>,
+    /// Regions with up-down resize cursor (dividers between stacked panes)
+    pub resize_vertical_rects: Vec<CursorRect>,
 }
 
 impl CursorRegions {
@@ -140,10 +147,22 @@ impl CursorRegions {
         self.ibeam_rects.push(rect);
     }
 
+    /// Adds a left-right resize cursor region.
+    pub fn add_resize_horizontal(&mut self, rect: CursorRect) {
+        self.resize_horizontal_rects.push(rect);
+    }
+
+    /// Adds an up-down resize cursor region.
+    pub fn add_resize_vertical(&mut self, rect: CursorRect) {
+        self.resize_vertical_rects.push(rect);
+    }
+
     /// Clears all regions.
     pub fn clear(&mut self) {
         self.pointer_rects.clear();
         self.ibeam_rects.clear();
+        self.resize_horizontal_rects.clear();
+        self.resize_vertical_rects.clear();
     }
 }
 
@@ -167,6 +186,20 @@ pub struct MetalViewIvars {
     // Chunk: docs/chunks/cursor_pointer_ui_hints - Cursor regions for dynamic cursor display
     /// Cursor regions for different cursor types (pointer vs I-beam)
     cursor_regions: RefCell<CursorRegions>,
+    // Chunk: docs/chunks/ime_dead_key_composition - Composition state for NSTextInputClient queries
+    /// UTF-16 length of the current marked (preedit) text; 0 when not composing
+    marked_text_len: Cell<usize>,
+    /// Selection within the marked text, as reported by the input method
+    marked_selection: Cell<NSRange>,
+    /// Cursor cell in view coordinates (points), used to place the candidate window
+    text_input_rect: Cell<Option<CursorRect>>,
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Text the accent popover may replace
+    /// Text committed by the last `insertText:`, treated as the text just before
+    /// the caret. Cleared by any key that skips the input method.
+    last_inserted_text: RefCell<String>,
+    // Chunk: docs/chunks/mouse_buttons - Right-click context menu
+    /// Menu shown on a right-click, if one was set
+    context_menu: RefCell<Option<Retained<NSMenu>>>,
 }
 
 impl Default for MetalViewIvars {
@@ -184,8 +217,10 @@ impl Default for MetalViewIvars {
         // Use BGRA8 pixel format (standard for display)
         metal_layer.setPixelFormat(objc2_metal::MTLPixelFormat::BGRA8Unorm);
 
-        // We don't need to read back from the drawable
-        metal_layer.setFramebufferOnly(true);
+        // Chunk: docs/chunks/partial_redraw - Frames are copied into the drawable
+        // The renderer blits its retained frame texture into the drawable,
+        // which a framebuffer-only drawable doesn't allow
+        metal_layer.setFramebufferOnly(false);
 
         // Initialize with scale factor 1.0 (will be updated when attached to window)
         metal_layer.setContentsScale(1.0);
@@ -199,6 +234,11 @@ impl Default for MetalViewIvars {
             mouse_handler: RefCell::new(None),
             scroll_handler: RefCell::new(None),
             cursor_regions: RefCell::new(CursorRegions::new()),
+            marked_text_len: Cell::new(0),
+            marked_selection: Cell::new(NSRange::new(0, 0)),
+            text_input_rect: Cell::new(None),
+            last_inserted_text: RefCell::new(String::new()),
+            context_menu: RefCell::new(None),
         }
     }
 }
@@ -344,6 +384,10 @@ define_class!(
             let has_command = flags.contains(NSEventModifierFlags::Command);
             let has_control = flags.contains(NSEventModifierFlags::Control);
             let has_option = flags.contains(NSEventModifierFlags::Option);
+            // Chunk: docs/chunks/ime_dead_key_composition - Dead keys produce no characters
+            // A dead key (Option+E, Option+U, Option+N on US layouts) reports an empty
+            // `characters` string; the accent only appears once the next key is composed.
+            let is_dead_key = event.characters().is_none_or(|c| c.length() == 0);
 
             // Bypass the text input system for command shortcuts, control shortcuts,
             // option shortcuts, and function keys.
@@ -352,7 +396,16 @@ define_class!(
             // Ctrl+A becomes moveToBeginningOfParagraph: instead of moveToBeginningOfLine:.
             // By routing Ctrl+key through convert_key_event() directly, we preserve the full key+modifiers
             // and let resolve_command() handle the mapping to editor commands.
-            if has_command || has_control || has_option || is_escape || is_function_key || is_navigation_key {
+            let bypass = bypasses_text_input(KeyDownRoute {
+                has_marked_text: self.ivars().marked_text_len.get() > 0,
+                has_command,
+                has_control,
+                has_option,
+                is_dead_key,
+                is_special_key: is_escape || is_function_key || is_navigation_key,
+            });
+            if bypass {
+                self.ivars().last_inserted_text.borrow_mut().clear();
                 if let Some(key_event) = self.convert_key_event(event) {
                     let sender = self.ivars().event_sender.borrow();
                     if let Some(sender) = sender.as_ref() {
@@ -392,13 +445,28 @@ define_class!(
         /// The `string` parameter is an `NSString` or `NSAttributedString` containing
         /// the text to insert. The `replacement_range` indicates which existing text
         /// to replace (or `NSNotFound` for insertion at cursor).
+        // Chunk: docs/chunks/press_and_hold_key_repeat - Accent popover replaces the held character
         #[unsafe(method(insertText:replacementRange:))]
-        fn __insert_text(&self, string: &objc2::runtime::AnyObject, _replacement_range: objc2_foundation::NSRange) {
+        fn __insert_text(&self, string: &objc2::runtime::AnyObject, replacement_range: objc2_foundation::NSRange) {
             // Convert the string to Rust. The string can be NSString or NSAttributedString,
             // but we can use the description method to get the text content.
             let text: Retained<objc2_foundation::NSString> = unsafe { msg_send![string, description] };
             let text_str = text.to_string();
 
+            // Committing ends any composition in progress. A replacement range that
+            // arrives outside a composition comes from the press-and-hold popover and
+            // points into the text this view inserted last.
+            let was_composing = self.ivars().marked_text_len.replace(0) > 0;
+            let replaced = if was_composing {
+                0
+            } else {
+                chars_replaced_before_caret(
+                    &self.ivars().last_inserted_text.borrow(),
+                    replacement_range.location,
+                )
+            };
+            *self.ivars().last_inserted_text.borrow_mut() = text_str.clone();
+
             if text_str.is_empty() {
                 return;
             }
@@ -406,7 +474,7 @@ define_class!(
             // Send the text input event
             let sender = self.ivars().event_sender.borrow();
             if let Some(sender) = sender.as_ref() {
-                let _ = sender.send_insert_text(TextInputEvent::new(text_str));
+                let _ = sender.send_insert_text(TextInputEvent::replacing_before_cursor(text_str, replaced));
             }
         }
 
@@ -431,6 +499,12 @@ define_class!(
             let text: Retained<objc2_foundation::NSString> = unsafe { msg_send![string, description] };
             let text_str = text.to_string();
 
+            // Chunk: docs/chunks/ime_dead_key_composition - Track marked length for hasMarkedText
+            // An empty string ends the composition (e.g. backspacing over a dead key)
+            self.ivars().marked_text_len.set(text.length());
+            self.ivars().marked_selection.set(selected_range);
+            self.ivars().last_inserted_text.borrow_mut().clear();
+
             // Convert NSRange to Rust range
             let selected_start = selected_range.location as usize;
             let selected_end = selected_start + selected_range.length as usize;
@@ -452,6 +526,7 @@ define_class!(
         /// or when focus changes away from the text field.
         #[unsafe(method(unmarkText))]
         fn __unmark_text(&self) {
+            self.ivars().marked_text_len.set(0);
             let sender = self.ivars().event_sender.borrow();
             if let Some(sender) = sender.as_ref() {
                 let _ = sender.send_unmark_text();
@@ -462,42 +537,46 @@ define_class!(
         /// Returns whether the view currently has marked text.
         ///
         /// The text input system calls this to determine the composition state.
-        /// For now, we return NO since we don't track marked text state in the view.
-        /// The actual marked text state is in TextBuffer.
-        ///
-        /// TODO: Consider adding a callback to query the buffer's marked text state.
+        /// The view mirrors the length of the last `setMarkedText:` so it can answer
+        /// without querying the buffer, which owns the marked text itself.
+        // Chunk: docs/chunks/ime_dead_key_composition - Report the tracked composition state
         #[unsafe(method(hasMarkedText))]
         fn __has_marked_text(&self) -> bool {
-            false
+            self.ivars().marked_text_len.get() > 0
         }
 
         // Chunk: docs/chunks/unicode_ime_input - NSTextInputClient: markedRange
         /// Returns the range of marked text in the document.
         ///
-        /// Returns `{NSNotFound, 0}` when there's no marked text.
+        /// Returns `{NSNotFound, 0}` when there's no marked text. The view doesn't
+        /// expose the document to the input method, so the marked text is reported
+        /// as starting at location 0.
+        // Chunk: docs/chunks/ime_dead_key_composition - Report the tracked marked range
         #[unsafe(method(markedRange))]
         fn __marked_range(&self) -> objc2_foundation::NSRange {
-            // Return NSNotFound to indicate no marked text
-            // NSNotFound is defined as NSIntegerMax, which is isize::MAX
-            objc2_foundation::NSRange {
-                location: usize::MAX,
-                length: 0,
+            match self.ivars().marked_text_len.get() {
+                // NSNotFound is defined as NSIntegerMax, which is isize::MAX
+                0 => NSRange::new(NS_NOT_FOUND, 0),
+                len => NSRange::new(0, len),
             }
         }
 
         // Chunk: docs/chunks/unicode_ime_input - NSTextInputClient: selectedRange
         /// Returns the range of selected text in the document.
         ///
-        /// Returns `{NSNotFound, 0}` when there's no selection.
-        /// The text input system uses this to know where to insert text.
-        ///
-        /// TODO: Consider adding a callback to query the buffer's selection state.
+        /// While composing, this is the input method's selection within the marked
+        /// text. Otherwise the view doesn't track document positions, so the caret
+        /// is reported just after the text it inserted last, which is what the
+        /// press-and-hold popover asks to replace.
+        // Chunk: docs/chunks/ime_dead_key_composition - Selection within the marked text
+        // Chunk: docs/chunks/press_and_hold_key_repeat - Caret after the last inserted text
         #[unsafe(method(selectedRange))]
         fn __selected_range(&self) -> objc2_foundation::NSRange {
-            // Return empty range at "position 0" - we don't track document position here
-            objc2_foundation::NSRange {
-                location: 0,
-                length: 0,
+            if self.ivars().marked_text_len.get() > 0 {
+                self.ivars().marked_selection.get()
+            } else {
+                let caret = self.ivars().last_inserted_text.borrow().encode_utf16().count();
+                NSRange::new(caret, 0)
             }
         }
 
@@ -514,29 +593,36 @@ define_class!(
         /// Returns the attributed substring for a given range.
         ///
         /// The text input system calls this for candidate window positioning and
-        /// reconversion. For now, we return nil.
+        /// reconversion. Only the text inserted last is known to the view, so the
+        /// press-and-hold popover can read the held character; other ranges are nil.
+        // Chunk: docs/chunks/press_and_hold_key_repeat - Expose the held character
         #[unsafe(method_id(attributedSubstringForProposedRange:actualRange:))]
         fn __attributed_substring_for_proposed_range(
             &self,
-            _range: objc2_foundation::NSRange,
-            _actual_range: *mut objc2_foundation::NSRange,
+            range: objc2_foundation::NSRange,
+            actual_range: *mut objc2_foundation::NSRange,
         ) -> Option<Retained<objc2_foundation::NSAttributedString>> {
-            None
+            self.attributed_substring(range, actual_range)
         }
 
         // Chunk: docs/chunks/unicode_ime_input - NSTextInputClient: firstRectForCharacterRange:actualRange:
         /// Returns the screen rect for a character range (for IME candidate window positioning).
         ///
         /// The text input system calls this to position the IME candidate window near
-        /// the composition point. For now, we return a rect near the top-left of the view.
-        ///
-        /// TODO: Return accurate cursor position for proper candidate window placement.
+        /// the composition point. The drain loop pushes the focused cursor's cell after
+        /// each render; before the first render we fall back to the view's top-left.
+        // Chunk: docs/chunks/ime_dead_key_composition - Candidate window follows the cursor
         #[unsafe(method(firstRectForCharacterRange:actualRange:))]
         fn __first_rect_for_character_range(
             &self,
             _range: objc2_foundation::NSRange,
             _actual_range: *mut objc2_foundation::NSRange,
         ) -> NSRect {
+            if let (Some(window), Some(rect)) = (self.window(), self.ivars().text_input_rect.get()) {
+                let in_window = self.convertRect_toView(rect.to_ns_rect(), None);
+                return window.convertRectToScreen(in_window);
+            }
+
             // Return a rect relative to the screen
             // For now, return the window's frame origin + some offset
             // This is a fallback - a proper implementation would return the cursor position
@@ -584,6 +670,9 @@ define_class!(
             let sel_name_cstr = selector.name();
             let sel_name = sel_name_cstr.to_str().unwrap_or("");
 
+            // Chunk: docs/chunks/press_and_hold_key_repeat - Commands move the caret
+            self.ivars().last_inserted_text.borrow_mut().clear();
+
             let key = match sel_name {
                 "insertNewline:" => Some(Key::Return),
                 "insertTab:" => Some(Key::Tab),
@@ -709,6 +798,120 @@ define_class!(
             }
         }
 
+        // Chunk: docs/chunks/hover_tracking - Mouse moves without a button held
+        /// Handle mouse moves with no button held, from the tracking area.
+        #[unsafe(method(mouseMoved:))]
+        fn __mouse_moved(&self, event: &NSEvent) {
+            self.dispatch_mouse_event(self.convert_hover_event(event));
+        }
+
+        /// Handle the mouse leaving the view, so hover state can be cleared.
+        #[unsafe(method(mouseExited:))]
+        fn __mouse_exited(&self, event: &NSEvent) {
+            self.dispatch_mouse_event(self.convert_hover_event(event));
+        }
+
+        // Chunk: docs/chunks/cursor_position_history - Mouse back/forward buttons
+        // Chunk: docs/chunks/mouse_buttons - Middle button presses
+        /// Handle presses of buttons other than left/right.
+        ///
+        /// Buttons 4 and 5 (AppKit button numbers 3 and 4) navigate back and
+        /// forward. The middle button is sent as a press; other buttons are
+        /// ignored.
+        #[unsafe(method(otherMouseDown:))]
+        fn __other_mouse_down(&self, event: &NSEvent) {
+            let kind = match event.buttonNumber() {
+                2 => MouseEventKind::Down,
+                3 => MouseEventKind::Back,
+                4 => MouseEventKind::Forward,
+                _ => return,
+            };
+            if let Some(mouse_event) = self.convert_mouse_event(event, kind) {
+                self.dispatch_mouse_event(mouse_event);
+            }
+        }
+
+        // Chunk: docs/chunks/mouse_buttons - Middle button releases and drags
+        /// Handle releases of buttons other than left/right (only the middle
+        /// button's are sent).
+        #[unsafe(method(otherMouseUp:))]
+        fn __other_mouse_up(&self, event: &NSEvent) {
+            if event.buttonNumber() != 2 {
+                return;
+            }
+            if let Some(mouse_event) = self.convert_mouse_event(event, MouseEventKind::Up) {
+                self.dispatch_mouse_event(mouse_event);
+            }
+        }
+
+        /// Handle drags with a button other than left/right held (only the
+        /// middle button's are sent).
+        #[unsafe(method(otherMouseDragged:))]
+        fn __other_mouse_dragged(&self, event: &NSEvent) {
+            if event.buttonNumber() != 2 {
+                return;
+            }
+            if let Some(mouse_event) = self.convert_mouse_event(event, MouseEventKind::Moved) {
+                self.dispatch_mouse_event(mouse_event);
+            }
+        }
+
+        // Chunk: docs/chunks/mouse_buttons - Right button and the context menu
+        /// Handle right button presses.
+        ///
+        /// The press is sent first, so a right-click in a file moves the cursor
+        /// before AppKit shows the context menu from `menuForEvent:`. The menu
+        /// takes the matching mouse-up while it is open.
+        #[unsafe(method(rightMouseDown:))]
+        fn __right_mouse_down(&self, event: &NSEvent) {
+            if let Some(mouse_event) = self.convert_mouse_event(event, MouseEventKind::Down) {
+                self.dispatch_mouse_event(mouse_event);
+            }
+            let _: () = unsafe { msg_send![super(self), rightMouseDown: event] };
+        }
+
+        /// Handle right button releases.
+        #[unsafe(method(rightMouseUp:))]
+        fn __right_mouse_up(&self, event: &NSEvent) {
+            if let Some(mouse_event) = self.convert_mouse_event(event, MouseEventKind::Up) {
+                self.dispatch_mouse_event(mouse_event);
+            }
+        }
+
+        /// Handle drags with the right button held.
+        #[unsafe(method(rightMouseDragged:))]
+        fn __right_mouse_dragged(&self, event: &NSEvent) {
+            if let Some(mouse_event) = self.convert_mouse_event(event, MouseEventKind::Moved) {
+                self.dispatch_mouse_event(mouse_event);
+            }
+        }
+
+        /// Returns the context menu for a right-click.
+        #[unsafe(method_id(menuForEvent:))]
+        fn __menu_for_event(&self, _event: &NSEvent) -> Option<Retained<NSMenu>> {
+            self.ivars().context_menu.borrow().clone()
+        }
+
+        // Chunk: docs/chunks/cursor_position_history - Trackpad swipe navigation
+        /// Handle trackpad swipe gestures.
+        ///
+        /// A positive `deltaX` (swipe right) navigates back, negative navigates
+        /// forward, matching Safari and Finder.
+        #[unsafe(method(swipeWithEvent:))]
+        fn __swipe_with_event(&self, event: &NSEvent) {
+            let delta_x = event.deltaX();
+            let kind = if delta_x > 0.0 {
+                MouseEventKind::Back
+            } else if delta_x < 0.0 {
+                MouseEventKind::Forward
+            } else {
+                return;
+            };
+            if let Some(mouse_event) = self.convert_swipe_event(event, kind) {
+                self.dispatch_mouse_event(mouse_event);
+            }
+        }
+
         // Chunk: docs/chunks/viewport_scrolling - macOS scrollWheel event handler
         // Chunk: docs/chunks/pty_wakeup_reentrant - Prefer EventSender over closure
         /// Handle scroll wheel events (trackpad, mouse wheel)
@@ -739,6 +942,7 @@ define_class!(
         ///
         /// - Pointer (arrow) cursor for clickable UI elements (left rail, tabs, etc.)
         /// - I-beam cursor for text editing areas (buffer, mini-buffer)
+        /// - Resize cursors over the dividers between panes
         ///
         /// Regions are applied in order with I-beam regions added last, so they
         /// take precedence in overlapping areas. This ensures the text editing
@@ -762,6 +966,17 @@ define_class!(
                 self.addCursorRect_cursor(rect.to_ns_rect(), &ibeam_cursor);
             }
 
+            // Divider resize rects go on top so the grab area wins over the
+            // text and tab bar regions it overlaps
+            let left_right_cursor = NSCursor::columnResizeCursor();
+            for rect in &regions.resize_horizontal_rects {
+                self.addCursorRect_cursor(rect.to_ns_rect(), &left_right_cursor);
+            }
+            let up_down_cursor = NSCursor::rowResizeCursor();
+            for rect in &regions.resize_vertical_rects {
+                self.addCursorRect_cursor(rect.to_ns_rect(), &up_down_cursor);
+            }
+
             // If no regions are defined, fall back to I-beam for entire bounds
             // (maintains backwards compatibility with existing behavior)
             if regions.pointer_rects.is_empty() && regions.ibeam_rects.is_empty() {
@@ -873,6 +1088,23 @@ impl MetalView {
         let drag_types = NSArray::from_slice(&[file_url_type]);
         this.registerForDraggedTypes(&drag_types);
 
+        // Chunk: docs/chunks/hover_tracking - Mouse moves without a button held
+        // InVisibleRect keeps the area matched to the view as it resizes, so
+        // it never needs replacing in updateTrackingAreas.
+        let tracking_area = unsafe {
+            NSTrackingArea::initWithRect_options_owner_userInfo(
+                mtm.alloc(),
+                NSRect::ZERO,
+                NSTrackingAreaOptions::MouseMoved
+                    | NSTrackingAreaOptions::MouseEnteredAndExited
+                    | NSTrackingAreaOptions::ActiveInKeyWindow
+                    | NSTrackingAreaOptions::InVisibleRect,
+                Some(&this),
+                None,
+            )
+        };
+        this.addTrackingArea(&tracking_area);
+
         this
     }
 
@@ -941,6 +1173,12 @@ impl MetalView {
         *self.ivars().event_sender.borrow_mut() = Some(sender);
     }
 
+    // Chunk: docs/chunks/mouse_buttons - Right-click context menu
+    /// Sets the menu shown on a right-click.
+    pub fn set_context_menu(&self, menu: Retained<NSMenu>) {
+        *self.ivars().context_menu.borrow_mut() = Some(menu);
+    }
+
     /// Sets the key event handler callback (legacy)
     ///
     /// The handler will be called for each keyDown event, with the
@@ -1004,11 +1242,49 @@ impl MetalView {
         }
     }
 
+    // Chunk: docs/chunks/ime_dead_key_composition - Cursor cell for candidate window placement
+    /// Sets the focused cursor's cell in view coordinates (points, origin at
+    /// bottom-left).
+    ///
+    /// Input methods ask for this rect through `firstRectForCharacterRange:` to
+    /// place the candidate window beside the text being composed.
+    pub fn set_text_input_rect(&self, rect: CursorRect) {
+        self.ivars().text_input_rect.set(Some(rect));
+    }
+
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Expose the held character
+    /// Returns `range` of the text inserted last, for
+    /// `attributedSubstringForProposedRange:actualRange:`, or `None` while
+    /// composing or if the range falls outside it.
+    fn attributed_substring(
+        &self,
+        range: objc2_foundation::NSRange,
+        actual_range: *mut objc2_foundation::NSRange,
+    ) -> Option<Retained<objc2_foundation::NSAttributedString>> {
+        if self.ivars().marked_text_len.get() > 0 {
+            return None;
+        }
+        let last = self.ivars().last_inserted_text.borrow();
+        let units: Vec<u16> = last.encode_utf16().collect();
+        let end = range.location.checked_add(range.length)?;
+        if range.location >= units.len() || end > units.len() {
+            return None;
+        }
+        let substring = String::from_utf16(&units[range.location..end]).ok()?;
+        if !actual_range.is_null() {
+            // SAFETY: AppKit passes either null or a valid out-pointer
+            unsafe { *actual_range = range };
+        }
+        let substring = objc2_foundation::NSString::from_str(&substring);
+        Some(objc2_foundation::NSAttributedString::from_nsstring(&substring))
+    }
+
     /// Converts an NSEvent to our KeyEvent type
     fn convert_key_event(&self, event: &NSEvent) -> Option<KeyEvent> {
         let modifiers = self.convert_modifiers(event);
         let key = self.convert_key(event)?;
-        Some(KeyEvent::new(key, modifiers))
+        // Chunk: docs/chunks/press_and_hold_key_repeat - Carry the auto-repeat flag
+        Some(KeyEvent::new(key, modifiers).with_repeat(event.isARepeat()))
     }
 
     // Chunk: docs/chunks/mouse_click_cursor - NSEvent to MouseEvent conversion with scale factor handling
@@ -1047,14 +1323,74 @@ impl MetalView {
         // Extract click count for double-click detection
         let click_count = event.clickCount() as u32;
 
+        // Chunk: docs/chunks/mouse_buttons - Which button a mouse event is for
+        let button = MouseButton::from_number(event.buttonNumber());
+
         Some(MouseEvent {
             kind,
             position,
             modifiers,
             click_count,
+            button,
         })
     }
 
+    // Chunk: docs/chunks/cursor_position_history - Swipe gesture conversion
+    /// Converts a swipe gesture NSEvent to a navigation MouseEvent.
+    ///
+    /// Gesture events don't carry a click count (`clickCount` raises for
+    /// non-mouse events), so this reports a single click at the gesture location.
+    fn convert_swipe_event(&self, event: &NSEvent, kind: MouseEventKind) -> Option<MouseEvent> {
+        let location_in_window = event.locationInWindow();
+        let location_in_view: objc2_foundation::NSPoint =
+            unsafe { msg_send![self, convertPoint: location_in_window, fromView: std::ptr::null::<NSView>()] };
+        let scale = self.ivars().scale_factor.get();
+
+        Some(MouseEvent {
+            kind,
+            position: (location_in_view.x * scale, location_in_view.y * scale),
+            modifiers: self.convert_modifiers(event),
+            click_count: 1,
+            button: MouseButton::Left,
+        })
+    }
+
+    // Chunk: docs/chunks/hover_tracking - Hover event conversion
+    /// Converts a mouse-moved or mouse-exited NSEvent to a hover MouseEvent.
+    ///
+    /// Tracking-area events have no button or click count (`buttonNumber` and
+    /// `clickCount` raise for them), so this reports neither.
+    fn convert_hover_event(&self, event: &NSEvent) -> MouseEvent {
+        let location_in_window = event.locationInWindow();
+        let location_in_view: objc2_foundation::NSPoint =
+            unsafe { msg_send![self, convertPoint: location_in_window, fromView: std::ptr::null::<NSView>()] };
+        let scale = self.ivars().scale_factor.get();
+
+        MouseEvent {
+            kind: MouseEventKind::Hover,
+            position: (location_in_view.x * scale, location_in_view.y * scale),
+            modifiers: self.convert_modifiers(event),
+            click_count: 0,
+            button: MouseButton::Left,
+        }
+    }
+
+    // Chunk: docs/chunks/cursor_position_history - Shared mouse dispatch
+    /// Sends a mouse event through the event channel, falling back to the
+    /// mouse handler closure when no sender is installed.
+    fn dispatch_mouse_event(&self, mouse_event: MouseEvent) {
+        let sender = self.ivars().event_sender.borrow();
+        if let Some(sender) = sender.as_ref() {
+            let _ = sender.send_mouse(mouse_event);
+        } else {
+            drop(sender);
+            let handler = self.ivars().mouse_handler.borrow();
+            if let Some(handler) = handler.as_ref() {
+                handler(mouse_event);
+            }
+        }
+    }
+
     // Chunk: docs/chunks/scroll_wheel_speed - Line height constant for scroll conversion
     /// Default line height for mouse wheel scroll conversion.
     /// Mouse wheel events report line-based deltas; we convert to pixels
@@ -1097,7 +1433,8 @@ impl MetalView {
         // Check if this is a precise (trackpad) or non-precise (mouse wheel) event
         // For mouse wheel events, multiply by line height to convert from line-based
         // to pixel-based deltas
-        let (dx, dy) = if event.hasPreciseScrollingDeltas() {
+        let precise = event.hasPreciseScrollingDeltas();
+        let (dx, dy) = if precise {
             // Trackpad: use deltas as-is (already in pixels)
             (raw_dx, raw_dy)
         } else {
@@ -1138,7 +1475,9 @@ impl MetalView {
         // - Positive dy = scroll down (show content further in the document)
         //
         // So we negate the delta to match our convention.
-        Some(ScrollDelta::with_position(-dx, -dy, x_px, y_px))
+        // Chunk: docs/chunks/smooth_scroll_animation - Wheel ticks scroll smoothly
+        // Mouse wheel ticks would jump a whole line per tick; animate them.
+        Some(ScrollDelta::with_position(-dx, -dy, x_px, y_px).animated(!precise))
     }
 
     /// Converts NSEvent modifier flags to our Modifiers type
@@ -1270,6 +1609,68 @@ impl MetalView {
 // Helper Functions
 // =============================================================================
 
+/// `NSNotFound`, which is defined as `NSIntegerMax`
+const NS_NOT_FOUND: usize = isize::MAX as usize;
+
+// Chunk: docs/chunks/press_and_hold_key_repeat - Replacement range to character count
+/// Returns how many characters at the end of `last_inserted` a replacement
+/// starting at UTF-16 offset `location` covers.
+///
+/// The view reports its caret just after `last_inserted`, so a replacement from
+/// `location` to the caret removes that many trailing UTF-16 units, rounded out
+/// to whole characters. `NSNotFound` and out-of-range locations replace nothing.
+fn chars_replaced_before_caret(last_inserted: &str, location: usize) -> usize {
+    let total: usize = last_inserted.encode_utf16().count();
+    if location >= total {
+        return 0;
+    }
+    let mut units = total - location;
+    let mut chars = 0;
+    for ch in last_inserted.chars().rev() {
+        if units == 0 {
+            break;
+        }
+        units = units.saturating_sub(ch.len_utf16());
+        chars += 1;
+    }
+    chars
+}
+
+// Chunk: docs/chunks/ime_dead_key_composition - Key routing decision
+/// What `keyDown:` knows about a key press when choosing its route.
+#[derive(Debug, Clone, Copy, Default)]
+struct KeyDownRoute {
+    /// An input method composition (or pending dead key) is in progress
+    has_marked_text: bool,
+    has_command: bool,
+    has_control: bool,
+    has_option: bool,
+    /// The key produced no characters, i.e. it starts a dead-key sequence
+    is_dead_key: bool,
+    /// Escape, function keys, and the navigation keys handled directly
+    is_special_key: bool,
+}
+
+/// Returns true if a key press should skip `interpretKeyEvents:` and go
+/// straight to the key handler.
+///
+/// Command shortcuts always bypass. While the input method is composing, every
+/// other key goes to it so Return, Escape, arrows and Backspace drive the
+/// composition rather than the buffer. Option dead keys also go to it so the
+/// accent can compose with the next key.
+fn bypasses_text_input(route: KeyDownRoute) -> bool {
+    if route.has_command {
+        return true;
+    }
+    if route.has_marked_text {
+        return false;
+    }
+    if route.has_option && !route.has_control && route.is_dead_key {
+        return false;
+    }
+    route.has_control || route.has_option || route.is_special_key
+}
+
 /// Gets the system default Metal device
 fn get_default_metal_device() -> Option<Retained<ProtocolObject<dyn MTLDevice>>> {
     // MTLCreateSystemDefaultDevice is a C function we need to call
@@ -1382,4 +1783,98 @@ mod tests {
         assert!(regions.pointer_rects.is_empty());
         assert!(regions.ibeam_rects.is_empty());
     }
+
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Replacement range tests
+    #[test]
+    fn test_press_and_hold_replaces_held_character() {
+        // Holding 'e' inserts "e"; the popover then replaces {0, 1}
+        assert_eq!(chars_replaced_before_caret("e", 0), 1);
+        assert_eq!(chars_replaced_before_caret("ab", 1), 1);
+        assert_eq!(chars_replaced_before_caret("ab", 0), 2);
+    }
+
+    #[test]
+    fn test_replacement_counts_surrogate_pairs_as_one_char() {
+        // U+1F600 is two UTF-16 units but one char
+        assert_eq!(chars_replaced_before_caret("a\u{1F600}", 1), 1);
+        assert_eq!(chars_replaced_before_caret("a\u{1F600}", 2), 1);
+    }
+
+    #[test]
+    fn test_replacement_outside_last_insert_is_ignored() {
+        assert_eq!(chars_replaced_before_caret("e", NS_NOT_FOUND), 0);
+        assert_eq!(chars_replaced_before_caret("e", 1), 0);
+        assert_eq!(chars_replaced_before_caret("", 0), 0);
+    }
+
+    // Chunk: docs/chunks/ime_dead_key_composition - Key routing tests
+    #[test]
+    fn test_plain_keys_use_text_input() {
+        assert!(!bypasses_text_input(KeyDownRoute::default()));
+    }
+
+    #[test]
+    fn test_shortcuts_bypass_text_input() {
+        let command = KeyDownRoute {
+            has_command: true,
+            ..Default::default()
+        };
+        let control = KeyDownRoute {
+            has_control: true,
+            ..Default::default()
+        };
+        let option = KeyDownRoute {
+            has_option: true,
+            ..Default::default()
+        };
+        let escape = KeyDownRoute {
+            is_special_key: true,
+            ..Default::default()
+        };
+        assert!(bypasses_text_input(command));
+        assert!(bypasses_text_input(control));
+        assert!(bypasses_text_input(option));
+        assert!(bypasses_text_input(escape));
+    }
+
+    #[test]
+    fn test_option_dead_key_uses_text_input() {
+        let dead_key = KeyDownRoute {
+            has_option: true,
+            is_dead_key: true,
+            ..Default::default()
+        };
+        assert!(!bypasses_text_input(dead_key));
+
+        // Control+Option stays a shortcut even if it yields no characters
+        let chord = KeyDownRoute {
+            has_control: true,
+            ..dead_key
+        };
+        assert!(bypasses_text_input(chord));
+    }
+
+    #[test]
+    fn test_composition_captures_all_but_command() {
+        let composing = KeyDownRoute {
+            has_marked_text: true,
+            ..Default::default()
+        };
+        assert!(!bypasses_text_input(KeyDownRoute {
+            is_special_key: true,
+            ..composing
+        }));
+        assert!(!bypasses_text_input(KeyDownRoute {
+            has_option: true,
+            ..composing
+        }));
+        assert!(!bypasses_text_input(KeyDownRoute {
+            has_control: true,
+            ..composing
+        }));
+        assert!(bypasses_text_input(KeyDownRoute {
+            has_command: true,
+            ..composing
+        }));
+    }
 }
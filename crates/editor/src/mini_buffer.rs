@@ -190,6 +190,15 @@ impl MiniBuffer {
             self.handle_key(KeyEvent::char(ch));
         }
     }
+
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Accent replaces the held key's character
+    /// Deletes `count` characters before the cursor, then inserts `text`.
+    pub fn replace_before_cursor(&mut self, count: usize, text: &str) {
+        for _ in 0..count {
+            self.handle_key(KeyEvent::new(Key::Backspace, Default::default()));
+        }
+        self.handle_text_input(text);
+    }
 }
 
 #[cfg(test)]
@@ -588,4 +597,14 @@ mod tests {
         mb.handle_text_input("abc");
         assert_eq!(mb.cursor_col(), 3);
     }
+
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Accent replacement test
+    #[test]
+    fn test_replace_before_cursor() {
+        let mut mb = MiniBuffer::new(test_font_metrics());
+        mb.handle_text_input("cafe");
+        mb.replace_before_cursor(1, "é");
+        assert_eq!(mb.content(), "café");
+        assert_eq!(mb.cursor_col(), 4);
+    }
 }
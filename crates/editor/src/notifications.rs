@@ -0,0 +1,104 @@
+// Chunk: docs/chunks/agent_notifications - macOS user notifications
+//!
+//! Desktop notifications for macOS via UNUserNotificationCenter.
+//!
+//! This module provides a thin wrapper for posting a user notification. Like
+//! `clipboard`, it is intentionally minimal ("humble object" pattern) - the
+//! decision of when to notify stays in the caller.
+//!
+//! ## Test isolation
+//!
+//! Under `cfg(test)` no notification is delivered. Instead, posted
+//! notifications are recorded in a `thread_local!` list that tests can inspect
+//! with `take_posted()`.
+
+// ── production notifications (UNUserNotificationCenter) ──────────────────────
+
+#[cfg(not(test))]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(not(test))]
+use block2::RcBlock;
+#[cfg(not(test))]
+use objc2::runtime::Bool;
+#[cfg(not(test))]
+use objc2_foundation::{NSBundle, NSError, NSString};
+#[cfg(not(test))]
+use objc2_user_notifications::{
+    UNAuthorizationOptions, UNMutableNotificationContent, UNNotificationRequest,
+    UNUserNotificationCenter,
+};
+
+/// Posts a user notification with the given title and body.
+///
+/// Delivery is best-effort: macOS may suppress or group notifications
+/// according to the user's notification settings, and the first post asks
+/// the user for permission. Nothing is posted when running outside an app
+/// bundle (e.g. from `cargo run`), where the notification center is
+/// unavailable.
+#[cfg(not(test))]
+pub fn post(title: &str, body: &str) {
+    /// Distinguishes requests, so a new notification doesn't replace the last.
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+    if NSBundle::mainBundle().bundleIdentifier().is_none() {
+        return;
+    }
+    let center = UNUserNotificationCenter::currentNotificationCenter();
+    // Only the first request prompts; later ones return the saved answer
+    center.requestAuthorizationWithOptions_completionHandler(
+        UNAuthorizationOptions::Alert | UNAuthorizationOptions::Sound,
+        &RcBlock::new(|_granted: Bool, _error: *mut NSError| {}),
+    );
+
+    let content = UNMutableNotificationContent::new();
+    content.setTitle(&NSString::from_str(title));
+    content.setBody(&NSString::from_str(body));
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let identifier = NSString::from_str(&format!("lite-edit-{}", id));
+    let request =
+        UNNotificationRequest::requestWithIdentifier_content_trigger(&identifier, &content, None);
+    center.addNotificationRequest_withCompletionHandler(&request, None);
+}
+
+// ── test notifications (thread-local record) ─────────────────────────────────
+
+#[cfg(test)]
+use std::cell::RefCell;
+
+#[cfg(test)]
+thread_local! {
+    /// Notifications posted on the current thread, as `(title, body)` pairs.
+    static POSTED: RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records a notification instead of delivering it.
+#[cfg(test)]
+pub fn post(title: &str, body: &str) {
+    POSTED.with(|p| p.borrow_mut().push((title.to_string(), body.to_string())));
+}
+
+/// Returns and clears the notifications posted on the current thread.
+///
+/// This function is only available in test builds.
+#[cfg(test)]
+pub fn take_posted() -> Vec<(String, String)> {
+    POSTED.with(|p| std::mem::take(&mut *p.borrow_mut()))
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_post_records_notification() {
+        post("main", "Agent is waiting for input");
+        assert_eq!(
+            take_posted(),
+            vec![("main".to_string(), "Agent is waiting for input".to_string())]
+        );
+        assert!(take_posted().is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+// Chunk: docs/chunks/selection_occurrences - Highlight other occurrences of the selected word
+//!
+//! Finding the other occurrences of a selected word on screen.
+//!
+//! When the selection covers exactly one word (as a double-click selects),
+//! the glyph buffer tints every other whole-word occurrence of it among the
+//! lines it renders. Nothing is indexed: occurrences are found per render
+//! from the visible lines' text, so the cost is bounded by the viewport.
+
+use std::ops::Range;
+
+/// Characters that continue an identifier for whole-word matching.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Returns the word selected by `cols` (char columns) of `line`, or `None`
+/// when the selection isn't exactly one whole word.
+pub fn selected_word(line: &str, cols: Range<usize>) -> Option<String> {
+    let chars: Vec<char> = line.chars().collect();
+    if cols.is_empty() || cols.end > chars.len() {
+        return None;
+    }
+    let word = &chars[cols.clone()];
+    let before = cols.start.checked_sub(1).map(|col| chars[col]);
+    let after = chars.get(cols.end).copied();
+    let is_whole_word = word.iter().all(|&c| is_word_char(c))
+        && !before.is_some_and(is_word_char)
+        && !after.is_some_and(is_word_char);
+    is_whole_word.then(|| word.iter().collect())
+}
+
+/// Returns the char columns of every whole-word occurrence of `word` in
+/// `line`, in order.
+pub fn word_occurrences(line: &str, word: &str) -> Vec<Range<usize>> {
+    let chars: Vec<char> = line.chars().collect();
+    let word: Vec<char> = word.chars().collect();
+    let mut occurrences = Vec::new();
+    if word.is_empty() {
+        return occurrences;
+    }
+    let mut col = 0;
+    while col + word.len() <= chars.len() {
+        let end = col + word.len();
+        let at_start = col == 0 || !is_word_char(chars[col - 1]);
+        let at_end = !chars.get(end).is_some_and(|&c| is_word_char(c));
+        if at_start && at_end && chars[col..end] == word[..] {
+            occurrences.push(col..end);
+            col = end;
+        } else {
+            col += 1;
+        }
+    }
+    occurrences
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selected_word_must_be_one_whole_word() {
+        let line = "let count = count_all(items);";
+        assert_eq!(selected_word(line, 4..9), Some("count".to_string()));
+        // Part of a word, more than a word, and nothing at all
+        assert_eq!(selected_word(line, 12..17), None);
+        assert_eq!(selected_word(line, 0..9), None);
+        assert_eq!(selected_word(line, 4..4), None);
+        assert_eq!(selected_word(line, 20..40), None);
+    }
+
+    #[test]
+    fn test_word_occurrences_are_whole_words() {
+        let line = "count + count_all(count) + recount";
+        assert_eq!(word_occurrences(line, "count"), vec![0..5, 18..23]);
+        assert!(word_occurrences(line, "missing").is_empty());
+        assert!(word_occurrences(line, "").is_empty());
+    }
+
+    #[test]
+    fn test_word_occurrences_use_char_columns() {
+        assert_eq!(word_occurrences("é x é", "é"), vec![0..1, 4..5]);
+    }
+}
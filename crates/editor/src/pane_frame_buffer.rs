@@ -6,6 +6,9 @@
 //! rendering pane visual elements:
 //! - **Divider lines**: 1px lines at the boundary between adjacent panes
 //! - **Focus border**: 2px colored border around the focused pane
+//! - **Drop highlight**: translucent fill over the target of a dragged tab
+//! - **Inactive dimming**: translucent fill over every unfocused pane, when
+//!   the user config turns it on
 //!
 //! Following the project's Humble View Architecture, geometry calculations are
 //! pure functions that can be unit tested without Metal dependencies.
@@ -259,6 +262,24 @@ pub fn calculate_focus_border(pane_rect: &PaneRect) -> [FocusBorderSegment; 4] {
     ]
 }
 
+// =============================================================================
+// Inactive Pane Dimming
+// =============================================================================
+
+// Chunk: docs/chunks/dim_inactive_panes - Dim unfocused panes
+/// Returns the rects of the panes to dim: every pane but the focused one.
+///
+/// A lone pane is never dimmed, since it is always the focused one.
+pub fn inactive_pane_rects(pane_rects: &[PaneRect], focused_pane_id: PaneId) -> Vec<&PaneRect> {
+    if pane_rects.len() <= 1 {
+        return Vec::new();
+    }
+    pane_rects
+        .iter()
+        .filter(|rect| rect.pane_id != focused_pane_id)
+        .collect()
+}
+
 // =============================================================================
 // PaneFrameBuffer
 // =============================================================================
@@ -269,6 +290,8 @@ pub fn calculate_focus_border(pane_rect: &PaneRect) -> [FocusBorderSegment; 4] {
 /// Renders:
 /// - Divider lines between panes (1px)
 /// - Focus border around the active pane (2px)
+/// - Drop highlight while a tab is being dragged
+/// - Dimming over unfocused panes
 pub struct PaneFrameBuffer {
     /// The vertex buffer containing quad vertices
     vertex_buffer: Option<Retained<ProtocolObject<dyn MTLBuffer>>>,
@@ -288,6 +311,12 @@ pub struct PaneFrameBuffer {
     divider_range: QuadRange,
     /// Focus border quads
     focus_border_range: QuadRange,
+    // Chunk: docs/chunks/tab_drag_drop - Drop target highlight
+    /// Drop highlight quad
+    drop_highlight_range: QuadRange,
+    // Chunk: docs/chunks/dim_inactive_panes - Dim unfocused panes
+    /// Dimming quads over unfocused panes
+    dim_range: QuadRange,
 }
 
 impl PaneFrameBuffer {
@@ -302,6 +331,8 @@ impl PaneFrameBuffer {
             persistent_indices: Vec::new(),
             divider_range: QuadRange::default(),
             focus_border_range: QuadRange::default(),
+            drop_highlight_range: QuadRange::default(),
+            dim_range: QuadRange::default(),
         }
     }
 
@@ -330,9 +361,20 @@ impl PaneFrameBuffer {
         self.focus_border_range
     }
 
+    /// Returns the index range for the drop highlight.
+    pub fn drop_highlight_range(&self) -> QuadRange {
+        self.drop_highlight_range
+    }
+
+    /// Returns the index range for the unfocused pane dimming.
+    pub fn dim_range(&self) -> QuadRange {
+        self.dim_range
+    }
+
     /// Updates the buffers with current pane layout.
     ///
-    /// Builds vertex data for divider lines and focus border.
+    /// Builds vertex data for divider lines, focus border, drop highlight and
+    /// unfocused pane dimming.
     ///
     /// # Arguments
     /// * `device` - The Metal device for buffer creation
@@ -341,6 +383,10 @@ impl PaneFrameBuffer {
     /// * `atlas` - The glyph atlas (for solid glyph)
     /// * `divider_color` - Color for divider lines
     /// * `focus_color` - Color for focus border
+    /// * `drop_highlight` - Area `(x, y, width, height)` under a dragged tab, if any
+    /// * `drop_color` - Color for the drop highlight
+    /// * `dim_color` - Color laid over unfocused panes, or `None` not to dim them
+    #[allow(clippy::too_many_arguments)]
     pub fn update(
         &mut self,
         device: &ProtocolObject<dyn MTLDevice>,
@@ -349,6 +395,9 @@ impl PaneFrameBuffer {
         atlas: &GlyphAtlas,
         divider_color: [f32; 4],
         focus_color: [f32; 4],
+        drop_highlight: Option<(f32, f32, f32, f32)>,
+        drop_color: [f32; 4],
+        dim_color: Option<[f32; 4]>,
     ) {
         // Calculate divider lines
         let divider_lines = calculate_divider_lines(pane_rects);
@@ -359,7 +408,12 @@ impl PaneFrameBuffer {
         // Estimate capacity
         let divider_count = divider_lines.len();
         let border_count = if focused_rect.is_some() && pane_rects.len() > 1 { 4 } else { 0 };
-        let total_quads = divider_count + border_count;
+        let highlight_count = usize::from(drop_highlight.is_some());
+        let dimmed_panes = match dim_color {
+            Some(_) => inactive_pane_rects(pane_rects, focused_pane_id),
+            None => Vec::new(),
+        };
+        let total_quads = divider_count + border_count + highlight_count + dimmed_panes.len();
 
         if total_quads == 0 {
             self.vertex_buffer = None;
@@ -367,6 +421,8 @@ impl PaneFrameBuffer {
             self.index_count = 0;
             self.divider_range = QuadRange::default();
             self.focus_border_range = QuadRange::default();
+            self.drop_highlight_range = QuadRange::default();
+            self.dim_range = QuadRange::default();
             return;
         }
 
@@ -419,6 +475,32 @@ impl PaneFrameBuffer {
         }
         self.focus_border_range = QuadRange::new(border_start, self.persistent_indices.len() - border_start);
 
+        // ==================== Drop Highlight ====================
+        // Chunk: docs/chunks/tab_drag_drop - Drop target highlight quad
+        let highlight_start = self.persistent_indices.len();
+        if let Some((x, y, width, height)) = drop_highlight {
+            let quad = create_rect_quad(x, y, width, height, solid_glyph, drop_color);
+            self.persistent_vertices.extend_from_slice(&quad);
+            push_quad_indices(&mut self.persistent_indices, vertex_offset);
+            vertex_offset += 4;
+        }
+        self.drop_highlight_range =
+            QuadRange::new(highlight_start, self.persistent_indices.len() - highlight_start);
+
+        // ==================== Inactive Dimming ====================
+        // Chunk: docs/chunks/dim_inactive_panes - Dim unfocused panes
+        let dim_start = self.persistent_indices.len();
+        if let Some(color) = dim_color {
+            for rect in &dimmed_panes {
+                let quad =
+                    create_rect_quad(rect.x, rect.y, rect.width, rect.height, solid_glyph, color);
+                self.persistent_vertices.extend_from_slice(&quad);
+                push_quad_indices(&mut self.persistent_indices, vertex_offset);
+                vertex_offset += 4;
+            }
+        }
+        self.dim_range = QuadRange::new(dim_start, self.persistent_indices.len() - dim_start);
+
         // ==================== Create GPU Buffers ====================
         if self.persistent_vertices.is_empty() {
             self.vertex_buffer = None;
@@ -682,6 +764,28 @@ mod tests {
         assert_eq!(segments[3].height, 300.0);
     }
 
+    // Chunk: docs/chunks/dim_inactive_panes - Dim unfocused panes
+    #[test]
+    fn test_inactive_pane_rects() {
+        let pane = |pane_id, x| PaneRect {
+            x,
+            y: 0.0,
+            width: 400.0,
+            height: 600.0,
+            pane_id,
+        };
+        let pane_rects = vec![pane(1, 0.0), pane(2, 400.0), pane(3, 800.0)];
+
+        let inactive: Vec<PaneId> = inactive_pane_rects(&pane_rects, 2)
+            .iter()
+            .map(|rect| rect.pane_id)
+            .collect();
+        assert_eq!(inactive, vec![1, 3]);
+
+        // A lone pane is always the focused one
+        assert!(inactive_pane_rects(&pane_rects[..1], 2).is_empty());
+    }
+
     // =========================================================================
     // PaneFrameBuffer Tests
     // =========================================================================
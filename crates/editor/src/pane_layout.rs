@@ -22,7 +22,7 @@
 //! This creates a layout where Pane A is on the left half, and Panes B and C
 //! share the right half (B on top, C on bottom).
 
-use crate::workspace::{Tab, WorkspaceId};
+use crate::workspace::{Tab, TabId, WorkspaceId};
 
 // =============================================================================
 // ID Types
@@ -194,6 +194,11 @@ pub struct Pane {
     pub active_tab: usize,
     /// Horizontal scroll offset for tab bar overflow (in pixels)
     pub tab_bar_view_offset: f32,
+    // Chunk: docs/chunks/last_tab_toggle - Per-pane MRU tab history
+    /// Tabs that were active before the current one, least recent first.
+    ///
+    /// Holds each tab at most once; closed tabs are dropped.
+    tab_history: Vec<TabId>,
 }
 
 impl Pane {
@@ -205,13 +210,33 @@ impl Pane {
             tabs: Vec::new(),
             active_tab: 0,
             tab_bar_view_offset: 0.0,
+            tab_history: Vec::new(),
+        }
+    }
+
+    // Chunk: docs/chunks/last_tab_toggle - Per-pane MRU tab history
+    /// Records that the active tab is about to give way to another.
+    fn remember_active_tab(&mut self) {
+        if let Some(id) = self.tabs.get(self.active_tab).map(|tab| tab.id) {
+            self.tab_history.retain(|&other| other != id);
+            self.tab_history.push(id);
         }
     }
 
     /// Adds a tab to the pane and makes it active.
+    ///
+    /// A pinned tab (moved from another pane) joins the end of the pinned
+    /// tabs; any other tab is added last.
+    // Chunk: docs/chunks/pinned_tabs_overflow - Pinned tabs stay left-most
     pub fn add_tab(&mut self, tab: Tab) {
-        self.tabs.push(tab);
-        self.active_tab = self.tabs.len() - 1;
+        let index = if tab.pinned {
+            self.tabs.iter().take_while(|tab| tab.pinned).count()
+        } else {
+            self.tabs.len()
+        };
+        self.remember_active_tab();
+        self.tabs.insert(index, tab);
+        self.active_tab = index;
     }
 
     /// Closes a tab at the given index, returning the removed tab.
@@ -224,6 +249,7 @@ impl Pane {
         }
 
         let removed = self.tabs.remove(index);
+        self.tab_history.retain(|&id| id != removed.id);
 
         // Adjust active_tab to remain valid
         if !self.tabs.is_empty() {
@@ -242,11 +268,104 @@ impl Pane {
     /// Switches to the tab at the given index.
     ///
     /// Does nothing if the index is out of bounds. When switching to a new tab,
-    /// clears its unread state.
+    /// clears its unread state and any pending bell.
+    // Chunk: docs/chunks/terminal_bell_attention - Clear bell on tab focus
     pub fn switch_tab(&mut self, index: usize) {
         if index < self.tabs.len() {
+            if index != self.active_tab {
+                self.remember_active_tab();
+            }
             self.active_tab = index;
             self.tabs[index].clear_unread();
+            self.tabs[index].clear_bell();
+        }
+    }
+
+    // Chunk: docs/chunks/pinned_tabs_overflow - Pinned tabs stay left-most
+    /// Pins or unpins the tab at `index`, moving it to the end of the pinned
+    /// tabs when pinning and to just after them when unpinning.
+    ///
+    /// The active tab stays active. Returns the tab's new index, or `None` if
+    /// the index is out of bounds.
+    pub fn set_tab_pinned(&mut self, index: usize, pinned: bool) -> Option<usize> {
+        if index >= self.tabs.len() {
+            return None;
+        }
+        let active_id = self.tabs[self.active_tab.min(self.tabs.len() - 1)].id;
+
+        let mut tab = self.tabs.remove(index);
+        tab.pinned = pinned;
+        let new_index = self.tabs.iter().take_while(|tab| tab.pinned).count();
+        self.tabs.insert(new_index, tab);
+
+        self.active_tab = self
+            .tabs
+            .iter()
+            .position(|tab| tab.id == active_id)
+            .unwrap_or(new_index);
+        Some(new_index)
+    }
+
+    // Chunk: docs/chunks/tab_reorder_drag - Reordering tabs within a pane
+    /// Clamps `slot`, a position between tabs (0 is before the first tab,
+    /// `tab_count()` after the last), to where the tab at `from` may be
+    /// dropped: pinned tabs stay among the pinned tabs and the rest after
+    /// them.
+    pub fn clamp_drop_slot(&self, from: usize, slot: usize) -> usize {
+        let pinned = self.tabs.iter().take_while(|tab| tab.pinned).count();
+        if self.tabs.get(from).is_some_and(|tab| tab.pinned) {
+            slot.min(pinned)
+        } else {
+            slot.clamp(pinned, self.tabs.len())
+        }
+    }
+
+    /// Moves the tab at `from` to `slot` (see `clamp_drop_slot`).
+    ///
+    /// The active tab stays active. Returns false if nothing moved.
+    pub fn move_tab_to_slot(&mut self, from: usize, slot: usize) -> bool {
+        if from >= self.tabs.len() {
+            return false;
+        }
+        let slot = self.clamp_drop_slot(from, slot);
+        let to = if slot > from { slot - 1 } else { slot };
+        if to == from {
+            return false;
+        }
+        let active_id = self.tabs[self.active_tab.min(self.tabs.len() - 1)].id;
+
+        let tab = self.tabs.remove(from);
+        self.tabs.insert(to, tab);
+
+        self.active_tab = self
+            .tabs
+            .iter()
+            .position(|tab| tab.id == active_id)
+            .unwrap_or(to);
+        true
+    }
+
+    // Chunk: docs/chunks/last_tab_toggle - Toggle to the last active tab
+    /// Returns the index of the tab that was active before the current one,
+    /// if it is still open.
+    pub fn previous_tab_index(&self) -> Option<usize> {
+        let active_id = self.active_tab().map(|tab| tab.id);
+        self.tab_history
+            .iter()
+            .rev()
+            .filter(|&&id| Some(id) != active_id)
+            .find_map(|&id| self.tabs.iter().position(|tab| tab.id == id))
+    }
+
+    /// Switches to the tab that was active before the current one, so that
+    /// repeating it toggles between the two. Returns false if there is none.
+    pub fn switch_to_previous_tab(&mut self) -> bool {
+        match self.previous_tab_index() {
+            Some(index) => {
+                self.switch_tab(index);
+                true
+            }
+            None => false,
         }
     }
 
@@ -345,6 +464,31 @@ impl PaneRect {
     pub fn contains(&self, x: f32, y: f32) -> bool {
         x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
     }
+
+    // Chunk: docs/chunks/triple_click_drag_autoscroll - Hit-testing points outside the pane
+    /// Resolves a screen-space point against this pane, whether or not the
+    /// point is inside it.
+    ///
+    /// Points above the content origin report `HitZone::TabBar`; local
+    /// coordinates may be negative or exceed the pane's size. Drags use this
+    /// to keep tracking the pane they started in.
+    pub fn hit(&self, x: f32, y: f32, tab_bar_height: f32) -> PaneHit {
+        let zone = if y < self.y + tab_bar_height {
+            HitZone::TabBar
+        } else {
+            HitZone::Content
+        };
+
+        PaneHit {
+            pane_id: self.pane_id,
+            zone,
+            // local_x: relative to pane's left edge
+            // local_y: relative to pane's content top (after tab bar)
+            local_x: x - self.x,
+            local_y: y - self.y - tab_bar_height,
+            pane_rect: self.clone(),
+        }
+    }
 }
 
 // =============================================================================
@@ -652,36 +796,25 @@ pub fn resolve_pane_hit(
     tab_bar_height: f32,
 ) -> Option<PaneHit> {
     // Calculate pane rectangles using renderer-consistent bounds
-    let pane_rects = calculate_pane_rects(bounds, pane_root);
+    resolve_pane_hit_in_rects(x, y, calculate_pane_rects(bounds, pane_root), tab_bar_height)
+}
 
+/// Resolves a screen-space point against already-calculated pane rects.
+///
+/// This is the hit-testing half of `resolve_pane_hit`, for callers whose
+/// visible rects differ from the full layout (e.g. while a pane is zoomed).
+// Chunk: docs/chunks/pane_zoom - Hit-testing against visible pane rects
+pub fn resolve_pane_hit_in_rects(
+    x: f32,
+    y: f32,
+    pane_rects: Vec<PaneRect>,
+    tab_bar_height: f32,
+) -> Option<PaneHit> {
     // Find which pane contains the point
-    for pane_rect in pane_rects {
-        if pane_rect.contains(x, y) {
-            // Determine if click is in TabBar or Content zone
-            let tab_bar_y_end = pane_rect.y + tab_bar_height;
-            let zone = if y < tab_bar_y_end {
-                HitZone::TabBar
-            } else {
-                HitZone::Content
-            };
-
-            // Compute pane-local coordinates relative to content origin
-            // local_x: relative to pane's left edge
-            // local_y: relative to pane's content top (after tab bar)
-            let local_x = x - pane_rect.x;
-            let local_y = y - pane_rect.y - tab_bar_height;
-
-            return Some(PaneHit {
-                pane_id: pane_rect.pane_id,
-                zone,
-                local_x,
-                local_y,
-                pane_rect,
-            });
-        }
-    }
-
-    None
+    pane_rects
+        .into_iter()
+        .find(|pane_rect| pane_rect.contains(x, y))
+        .map(|pane_rect| pane_rect.hit(x, y, tab_bar_height))
 }
 
 // =============================================================================
@@ -746,6 +879,207 @@ pub fn calculate_pane_rects(
     }
 }
 
+/// Calculates the screen rectangles of the panes that are visible.
+///
+/// When `zoomed` names a pane in the tree, that pane alone fills `bounds` and
+/// the rest of the layout is hidden. Otherwise this is `calculate_pane_rects`.
+// Chunk: docs/chunks/pane_zoom - Zoom-aware layout calculation
+pub fn calculate_visible_pane_rects(
+    bounds: (f32, f32, f32, f32),
+    node: &PaneLayoutNode,
+    zoomed: Option<PaneId>,
+) -> Vec<PaneRect> {
+    match zoomed {
+        Some(pane_id) if node.contains_pane(pane_id) => {
+            let (x, y, width, height) = bounds;
+            vec![PaneRect {
+                x,
+                y,
+                width,
+                height,
+                pane_id,
+            }]
+        }
+        _ => calculate_pane_rects(bounds, node),
+    }
+}
+
+// =============================================================================
+// Divider Hit-Testing (Chunk: docs/chunks/pane_divider_drag)
+// =============================================================================
+
+/// Smallest ratio a divider can be dragged to, so neither pane collapses.
+pub const MIN_SPLIT_RATIO: f32 = 0.1;
+
+/// Distance in pixels on either side of a divider that still grabs it.
+pub const DIVIDER_GRAB_WIDTH: f32 = 4.0;
+
+/// The boundary between the two children of a split node.
+///
+/// Dividers are identified by their path from the root so that a drag can
+/// update the ratio of the right split node even after the tree is re-borrowed.
+// Chunk: docs/chunks/pane_divider_drag - Divider geometry for drag-to-resize
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaneDivider {
+    /// Path from the root to the split node (`false` = first child, `true` = second child)
+    pub path: Vec<bool>,
+    /// The direction of the split this divider belongs to
+    pub direction: SplitDirection,
+    /// The divider's position: x for Horizontal splits, y for Vertical splits
+    pub position: f32,
+    /// The bounds `(x, y, width, height)` of the split node
+    pub split_bounds: (f32, f32, f32, f32),
+}
+
+impl PaneDivider {
+    /// Returns the divider's grab area as `(x, y, width, height)`.
+    pub fn grab_rect(&self) -> (f32, f32, f32, f32) {
+        let (x, y, width, height) = self.split_bounds;
+        match self.direction {
+            SplitDirection::Horizontal => (
+                self.position - DIVIDER_GRAB_WIDTH,
+                y,
+                DIVIDER_GRAB_WIDTH * 2.0,
+                height,
+            ),
+            SplitDirection::Vertical => (
+                x,
+                self.position - DIVIDER_GRAB_WIDTH,
+                width,
+                DIVIDER_GRAB_WIDTH * 2.0,
+            ),
+        }
+    }
+
+    /// Returns true if the point is within the divider's grab area.
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        let (rx, ry, rw, rh) = self.grab_rect();
+        x >= rx && x < rx + rw && y >= ry && y < ry + rh
+    }
+
+    /// Returns the split ratio that places the divider under the given point,
+    /// clamped to `[MIN_SPLIT_RATIO, 1.0 - MIN_SPLIT_RATIO]`.
+    pub fn ratio_at(&self, x: f32, y: f32) -> f32 {
+        let (sx, sy, width, height) = self.split_bounds;
+        let (offset, extent) = match self.direction {
+            SplitDirection::Horizontal => (x - sx, width),
+            SplitDirection::Vertical => (y - sy, height),
+        };
+        if extent <= 0.0 {
+            return 0.5;
+        }
+        (offset / extent).clamp(MIN_SPLIT_RATIO, 1.0 - MIN_SPLIT_RATIO)
+    }
+}
+
+/// Calculates the dividers of every split node in a layout tree.
+///
+/// Uses the same geometry as `calculate_pane_rects`, so dividers line up with
+/// the pane edges the renderer draws. Dividers are returned parent-first.
+// Chunk: docs/chunks/pane_divider_drag - Divider geometry for drag-to-resize
+pub fn calculate_divider_rects(
+    bounds: (f32, f32, f32, f32),
+    node: &PaneLayoutNode,
+) -> Vec<PaneDivider> {
+    let mut dividers = Vec::new();
+    collect_dividers(bounds, node, &mut Vec::new(), &mut dividers);
+    dividers
+}
+
+fn collect_dividers(
+    bounds: (f32, f32, f32, f32),
+    node: &PaneLayoutNode,
+    path: &mut Vec<bool>,
+    out: &mut Vec<PaneDivider>,
+) {
+    let PaneLayoutNode::Split {
+        direction,
+        ratio,
+        first,
+        second,
+    } = node
+    else {
+        return;
+    };
+
+    let (x, y, width, height) = bounds;
+    let (position, first_bounds, second_bounds) = match direction {
+        SplitDirection::Horizontal => {
+            let first_width = width * ratio;
+            (
+                x + first_width,
+                (x, y, first_width, height),
+                (x + first_width, y, width * (1.0 - ratio), height),
+            )
+        }
+        SplitDirection::Vertical => {
+            let first_height = height * ratio;
+            (
+                y + first_height,
+                (x, y, width, first_height),
+                (x, y + first_height, width, height * (1.0 - ratio)),
+            )
+        }
+    };
+
+    out.push(PaneDivider {
+        path: path.clone(),
+        direction: *direction,
+        position,
+        split_bounds: bounds,
+    });
+
+    path.push(false);
+    collect_dividers(first_bounds, first, path, out);
+    path.pop();
+    path.push(true);
+    collect_dividers(second_bounds, second, path, out);
+    path.pop();
+}
+
+/// Returns the divider whose grab area contains the point, if any.
+///
+/// Where a nested divider meets its parent's, the nested one wins, since the
+/// parent divider remains grabbable along the rest of its length.
+// Chunk: docs/chunks/pane_divider_drag - Divider hit-testing
+pub fn find_divider_at(
+    x: f32,
+    y: f32,
+    bounds: (f32, f32, f32, f32),
+    node: &PaneLayoutNode,
+) -> Option<PaneDivider> {
+    calculate_divider_rects(bounds, node)
+        .into_iter()
+        .rev()
+        .find(|divider| divider.contains(x, y))
+}
+
+impl PaneLayoutNode {
+    /// Sets the ratio of the split node at `path`.
+    ///
+    /// The ratio is clamped to `[MIN_SPLIT_RATIO, 1.0 - MIN_SPLIT_RATIO]`.
+    /// Returns false if `path` does not lead to a split node.
+    // Chunk: docs/chunks/pane_divider_drag - Persist dragged ratios in the layout tree
+    pub fn set_split_ratio(&mut self, path: &[bool], new_ratio: f32) -> bool {
+        match self {
+            PaneLayoutNode::Leaf(_) => false,
+            PaneLayoutNode::Split {
+                ratio,
+                first,
+                second,
+                ..
+            } => match path.split_first() {
+                None => {
+                    *ratio = new_ratio.clamp(MIN_SPLIT_RATIO, 1.0 - MIN_SPLIT_RATIO);
+                    true
+                }
+                Some((false, rest)) => first.set_split_ratio(rest, new_ratio),
+                Some((true, rest)) => second.set_split_ratio(rest, new_ratio),
+            },
+        }
+    }
+}
+
 // =============================================================================
 // Tab Movement Operations
 // =============================================================================
@@ -814,7 +1148,7 @@ pub fn move_tab(
             // Create a new pane via split
             let new_pane_id = new_pane_id_fn();
             let workspace_id = root.get_pane(pane_id).map(|p| p.workspace_id).unwrap_or(0);
-            move_tab_to_new_split(root, pane_id, dir, new_pane_id, workspace_id)
+            move_tab_to_new_split(root, source_pane_id, pane_id, dir, new_pane_id, workspace_id)
         }
     };
 
@@ -824,6 +1158,163 @@ pub fn move_tab(
     result
 }
 
+// =============================================================================
+// Tab Drag-and-Drop (Chunk: docs/chunks/tab_drag_drop)
+// =============================================================================
+
+/// Fraction of a pane's content area, measured in from each edge, where a
+/// dropped tab splits the pane instead of joining it.
+pub const DROP_EDGE_FRACTION: f32 = 0.25;
+
+/// Where within a pane a dragged tab would land.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropZone {
+    /// Join the pane's tabs
+    Center,
+    /// Split the pane, with the tab in a new pane on this side
+    Edge(Direction),
+}
+
+/// The pane and zone under a dragged tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TabDropTarget {
+    /// The pane the tab would be dropped onto
+    pub pane_id: PaneId,
+    /// Where within that pane
+    pub zone: DropZone,
+}
+
+/// Resolves the drop target for a tab dragged to a screen-space point.
+///
+/// A point over a pane's tab bar, or in the middle of its content, targets
+/// the pane itself. A point within `DROP_EDGE_FRACTION` of a content edge
+/// targets a split on the nearest edge.
+// Chunk: docs/chunks/tab_drag_drop - Drop target hit-testing
+pub fn resolve_tab_drop(
+    x: f32,
+    y: f32,
+    pane_rects: &[PaneRect],
+    tab_bar_height: f32,
+) -> Option<TabDropTarget> {
+    let rect = pane_rects.iter().find(|r| r.contains(x, y))?;
+
+    let content_y = rect.y + tab_bar_height;
+    let content_height = rect.height - tab_bar_height;
+    if y < content_y || content_height <= 0.0 || rect.width <= 0.0 {
+        return Some(TabDropTarget {
+            pane_id: rect.pane_id,
+            zone: DropZone::Center,
+        });
+    }
+
+    let fx = (x - rect.x) / rect.width;
+    let fy = (y - content_y) / content_height;
+    let (distance, direction) = [
+        (fx, Direction::Left),
+        (1.0 - fx, Direction::Right),
+        (fy, Direction::Up),
+        (1.0 - fy, Direction::Down),
+    ]
+    .into_iter()
+    .fold((f32::INFINITY, Direction::Left), |best, candidate| {
+        if candidate.0 < best.0 {
+            candidate
+        } else {
+            best
+        }
+    });
+
+    let zone = if distance < DROP_EDGE_FRACTION {
+        DropZone::Edge(direction)
+    } else {
+        DropZone::Center
+    };
+    Some(TabDropTarget {
+        pane_id: rect.pane_id,
+        zone,
+    })
+}
+
+/// Returns the area to highlight for a drop zone as `(x, y, width, height)`.
+///
+/// `Center` highlights the whole pane; an edge highlights the half of the
+/// content area the new pane would take.
+// Chunk: docs/chunks/tab_drag_drop - Drop target highlight geometry
+pub fn drop_highlight_rect(
+    rect: &PaneRect,
+    zone: DropZone,
+    tab_bar_height: f32,
+) -> (f32, f32, f32, f32) {
+    let content_y = rect.y + tab_bar_height;
+    let content_height = (rect.height - tab_bar_height).max(0.0);
+    let half_width = rect.width / 2.0;
+    let half_height = content_height / 2.0;
+
+    match zone {
+        DropZone::Center => (rect.x, rect.y, rect.width, rect.height),
+        DropZone::Edge(Direction::Left) => (rect.x, content_y, half_width, content_height),
+        DropZone::Edge(Direction::Right) => {
+            (rect.x + half_width, content_y, half_width, content_height)
+        }
+        DropZone::Edge(Direction::Up) => (rect.x, content_y, rect.width, half_height),
+        DropZone::Edge(Direction::Down) => {
+            (rect.x, content_y + half_height, rect.width, half_height)
+        }
+    }
+}
+
+/// Moves the active tab of a source pane to a drop target.
+///
+/// - `Center` on another pane moves the tab into that pane.
+/// - `Edge(direction)` splits the target pane and puts the tab in a new pane
+///   on that side.
+///
+/// Dropping onto the source pane's own center is rejected, as is splitting
+/// the source pane when it has only one tab (it would collapse straight back).
+/// Empty panes are cleaned up afterwards.
+// Chunk: docs/chunks/tab_drag_drop - Drop a dragged tab onto a pane or pane edge
+pub fn move_tab_to_drop_target(
+    root: &mut PaneLayoutNode,
+    source_pane_id: PaneId,
+    target: TabDropTarget,
+    mut new_pane_id_fn: impl FnMut() -> PaneId,
+) -> MoveResult {
+    let source_tab_count = match root.get_pane(source_pane_id) {
+        Some(pane) if !pane.tabs.is_empty() => pane.tab_count(),
+        _ => return MoveResult::SourceNotFound,
+    };
+    if !root.contains_pane(target.pane_id) {
+        return MoveResult::SourceNotFound;
+    }
+
+    let result = match target.zone {
+        DropZone::Center if target.pane_id == source_pane_id => return MoveResult::Rejected,
+        DropZone::Center => move_tab_to_existing(root, source_pane_id, target.pane_id),
+        DropZone::Edge(_) if target.pane_id == source_pane_id && source_tab_count == 1 => {
+            return MoveResult::Rejected;
+        }
+        DropZone::Edge(direction) => {
+            let new_pane_id = new_pane_id_fn();
+            let workspace_id = root
+                .get_pane(target.pane_id)
+                .map(|p| p.workspace_id)
+                .unwrap_or(0);
+            move_tab_to_new_split(
+                root,
+                source_pane_id,
+                target.pane_id,
+                direction,
+                new_pane_id,
+                workspace_id,
+            )
+        }
+    };
+
+    cleanup_empty_panes(root);
+
+    result
+}
+
 /// Moves the active tab from source pane to an existing target pane.
 fn move_tab_to_existing(
     root: &mut PaneLayoutNode,
@@ -861,10 +1352,14 @@ fn move_tab_to_existing(
     }
 }
 
-/// Moves the active tab from source pane to a new pane via split.
+/// Moves the active tab from source pane to a new pane split off `split_pane_id`.
+///
+/// `split_pane_id` is the source pane itself for directional moves, or the
+/// pane a tab was dropped onto for drag-and-drop.
 fn move_tab_to_new_split(
     root: &mut PaneLayoutNode,
     source_pane_id: PaneId,
+    split_pane_id: PaneId,
     direction: Direction,
     new_pane_id: PaneId,
     workspace_id: WorkspaceId,
@@ -885,8 +1380,8 @@ fn move_tab_to_new_split(
     let mut new_pane = Pane::new(new_pane_id, workspace_id);
     new_pane.add_tab(tab);
 
-    // Replace the source pane with a split containing both
-    let success = root.replace_pane_with_split(source_pane_id, new_pane, direction);
+    // Replace the split pane with a split containing both
+    let success = root.replace_pane_with_split(split_pane_id, new_pane, direction);
 
     if success {
         MoveResult::MovedToNew {
@@ -1216,6 +1711,40 @@ mod tests {
         assert_eq!(pane.active_tab, 0);
     }
 
+    // Chunk: docs/chunks/last_tab_toggle - Toggle to the last active tab
+    #[test]
+    fn test_pane_switch_to_previous_tab_toggles() {
+        let mut pane = test_pane(1);
+        pane.add_tab(test_tab(1));
+        pane.add_tab(test_tab(2));
+        pane.add_tab(test_tab(3));
+        pane.switch_tab(0);
+        // History: 2, 3; active 1
+
+        assert!(pane.switch_to_previous_tab());
+        assert_eq!(pane.active_tab().unwrap().id, 3);
+        assert!(pane.switch_to_previous_tab());
+        assert_eq!(pane.active_tab().unwrap().id, 1);
+        assert!(pane.switch_to_previous_tab());
+        assert_eq!(pane.active_tab().unwrap().id, 3);
+    }
+
+    #[test]
+    fn test_pane_previous_tab_skips_closed_tabs() {
+        let mut pane = test_pane(1);
+        assert!(!pane.switch_to_previous_tab());
+        pane.add_tab(test_tab(1));
+        assert!(!pane.switch_to_previous_tab());
+        pane.add_tab(test_tab(2));
+        pane.add_tab(test_tab(3));
+        // History: 1, 2; active 3
+
+        pane.close_tab(1);
+        assert_eq!(pane.previous_tab_index(), Some(0));
+        pane.close_tab(0);
+        assert_eq!(pane.previous_tab_index(), None);
+    }
+
     #[test]
     fn test_pane_switch_tab_invalid() {
         let mut pane = test_pane(1);
@@ -1266,6 +1795,57 @@ mod tests {
         assert!(pane.tabs[0].dirty);
     }
 
+    // Chunk: docs/chunks/pinned_tabs_overflow - Pinning moves tabs left-most
+    #[test]
+    fn test_pane_set_tab_pinned_keeps_pinned_tabs_first() {
+        let mut pane = test_pane(1);
+        for id in 1..=4 {
+            pane.add_tab(test_tab(id));
+        }
+        pane.switch_tab(1);
+        let ids = |pane: &Pane| pane.tabs.iter().map(|tab| tab.id).collect::<Vec<_>>();
+
+        // Pinned tabs line up at the front in the order they were pinned
+        assert_eq!(pane.set_tab_pinned(2, true), Some(0));
+        assert_eq!(pane.set_tab_pinned(3, true), Some(1));
+        assert_eq!(ids(&pane), vec![3, 4, 1, 2]);
+        assert_eq!(pane.active_tab().unwrap().id, 2);
+
+        // An unpinned tab goes just after the remaining pinned tabs
+        assert_eq!(pane.set_tab_pinned(0, false), Some(1));
+        assert_eq!(ids(&pane), vec![4, 3, 1, 2]);
+        assert!(pane.tabs[0].pinned && !pane.tabs[1].pinned);
+
+        assert_eq!(pane.set_tab_pinned(10, true), None);
+    }
+
+    // Chunk: docs/chunks/tab_reorder_drag - Reordering within a pane
+    #[test]
+    fn test_pane_move_tab_to_slot() {
+        let mut pane = test_pane(1);
+        for id in 1..=4 {
+            pane.add_tab(test_tab(id));
+        }
+        pane.switch_tab(0);
+        let ids = |pane: &Pane| pane.tabs.iter().map(|tab| tab.id).collect::<Vec<_>>();
+
+        // Slots count positions between tabs, including the moved tab's own
+        assert!(pane.move_tab_to_slot(0, 3));
+        assert_eq!(ids(&pane), vec![2, 3, 1, 4]);
+        assert_eq!(pane.active_tab, 2);
+        assert!(pane.move_tab_to_slot(2, 0));
+        assert_eq!(ids(&pane), vec![1, 2, 3, 4]);
+        assert!(!pane.move_tab_to_slot(1, 2));
+        assert!(!pane.move_tab_to_slot(9, 0));
+
+        // Unpinned tabs can't move in front of pinned ones, nor pinned behind
+        pane.set_tab_pinned(3, true);
+        assert_eq!(ids(&pane), vec![4, 1, 2, 3]);
+        assert!(pane.move_tab_to_slot(3, 0));
+        assert_eq!(ids(&pane), vec![4, 3, 1, 2]);
+        assert!(!pane.move_tab_to_slot(0, 4));
+    }
+
     // =========================================================================
     // PaneRect Tests (Step 3)
     // =========================================================================
@@ -1430,6 +2010,95 @@ mod tests {
         assert!(approx_eq(second.width, 700.0));
     }
 
+    // =========================================================================
+    // Divider Tests (Chunk: docs/chunks/pane_divider_drag)
+    // =========================================================================
+
+    fn nested_tree() -> PaneLayoutNode {
+        // HSplit(Pane[A], VSplit(Pane[B], Pane[C]))
+        PaneLayoutNode::Split {
+            direction: SplitDirection::Horizontal,
+            ratio: 0.5,
+            first: Box::new(PaneLayoutNode::Leaf(test_pane(1))),
+            second: Box::new(PaneLayoutNode::Split {
+                direction: SplitDirection::Vertical,
+                ratio: 0.5,
+                first: Box::new(PaneLayoutNode::Leaf(test_pane(2))),
+                second: Box::new(PaneLayoutNode::Leaf(test_pane(3))),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_divider_rects_match_pane_edges() {
+        let tree = nested_tree();
+        let dividers = calculate_divider_rects((0.0, 0.0, 800.0, 600.0), &tree);
+
+        assert_eq!(dividers.len(), 2);
+
+        assert!(dividers[0].path.is_empty());
+        assert_eq!(dividers[0].direction, SplitDirection::Horizontal);
+        assert!(approx_eq(dividers[0].position, 400.0));
+
+        assert_eq!(dividers[1].path, vec![true]);
+        assert_eq!(dividers[1].direction, SplitDirection::Vertical);
+        assert!(approx_eq(dividers[1].position, 300.0));
+        assert_eq!(dividers[1].split_bounds, (400.0, 0.0, 400.0, 600.0));
+    }
+
+    #[test]
+    fn test_single_pane_has_no_dividers() {
+        let tree = PaneLayoutNode::single_pane(test_pane(1));
+        assert!(calculate_divider_rects((0.0, 0.0, 800.0, 600.0), &tree).is_empty());
+        assert!(find_divider_at(400.0, 300.0, (0.0, 0.0, 800.0, 600.0), &tree).is_none());
+    }
+
+    #[test]
+    fn test_find_divider_at() {
+        let tree = nested_tree();
+        let bounds = (0.0, 0.0, 800.0, 600.0);
+
+        let hit = find_divider_at(402.0, 100.0, bounds, &tree).unwrap();
+        assert!(hit.path.is_empty());
+
+        let hit = find_divider_at(600.0, 297.0, bounds, &tree).unwrap();
+        assert_eq!(hit.path, vec![true]);
+
+        // The nested divider doesn't extend into the left pane
+        let hit = find_divider_at(200.0, 300.0, bounds, &tree);
+        assert!(hit.is_none());
+
+        assert!(find_divider_at(410.0, 100.0, bounds, &tree).is_none());
+    }
+
+    #[test]
+    fn test_divider_ratio_at_is_clamped() {
+        let tree = nested_tree();
+        let dividers = calculate_divider_rects((0.0, 0.0, 800.0, 600.0), &tree);
+
+        assert!(approx_eq(dividers[0].ratio_at(200.0, 0.0), 0.25));
+        assert!(approx_eq(dividers[0].ratio_at(0.0, 0.0), MIN_SPLIT_RATIO));
+        assert!(approx_eq(dividers[0].ratio_at(800.0, 0.0), 1.0 - MIN_SPLIT_RATIO));
+
+        // Vertical divider is relative to its own split bounds
+        assert!(approx_eq(dividers[1].ratio_at(0.0, 450.0), 0.75));
+    }
+
+    #[test]
+    fn test_set_split_ratio_by_path() {
+        let mut tree = nested_tree();
+
+        assert!(tree.set_split_ratio(&[true], 0.25));
+        assert!(tree.set_split_ratio(&[], 0.95));
+        assert!(!tree.set_split_ratio(&[false], 0.5));
+
+        let rects = calculate_pane_rects((0.0, 0.0, 1000.0, 600.0), &tree);
+        let a = rects.iter().find(|r| r.pane_id == 1).unwrap();
+        assert!(approx_eq(a.width, 900.0));
+        let b = rects.iter().find(|r| r.pane_id == 2).unwrap();
+        assert!(approx_eq(b.height, 150.0));
+    }
+
     // =========================================================================
     // Tree Traversal Tests (Step 7 & 8)
     // =========================================================================
@@ -2007,6 +2676,145 @@ mod tests {
         assert!(new_rect.y < source_rect.y);
     }
 
+    // =========================================================================
+    // Tab Drag-and-Drop Tests (Chunk: docs/chunks/tab_drag_drop)
+    // =========================================================================
+
+    fn rect(pane_id: PaneId, x: f32, y: f32, width: f32, height: f32) -> PaneRect {
+        PaneRect {
+            x,
+            y,
+            width,
+            height,
+            pane_id,
+        }
+    }
+
+    #[test]
+    fn test_resolve_tab_drop_zones() {
+        // Pane 400x432 with a 32px tab bar: content is 400x400 starting at y=32
+        let rects = [rect(1, 0.0, 0.0, 400.0, 432.0), rect(2, 400.0, 0.0, 400.0, 432.0)];
+        let zone = |x, y| resolve_tab_drop(x, y, &rects, 32.0).map(|t| (t.pane_id, t.zone));
+
+        assert_eq!(zone(200.0, 10.0), Some((1, DropZone::Center)));
+        assert_eq!(zone(200.0, 232.0), Some((1, DropZone::Center)));
+        assert_eq!(zone(20.0, 232.0), Some((1, DropZone::Edge(Direction::Left))));
+        assert_eq!(zone(390.0, 232.0), Some((1, DropZone::Edge(Direction::Right))));
+        assert_eq!(zone(200.0, 40.0), Some((1, DropZone::Edge(Direction::Up))));
+        assert_eq!(zone(200.0, 420.0), Some((1, DropZone::Edge(Direction::Down))));
+        assert_eq!(zone(600.0, 232.0), Some((2, DropZone::Center)));
+        assert_eq!(zone(900.0, 232.0), None);
+    }
+
+    #[test]
+    fn test_drop_highlight_rect() {
+        let pane = rect(1, 100.0, 0.0, 400.0, 432.0);
+
+        assert_eq!(drop_highlight_rect(&pane, DropZone::Center, 32.0), (100.0, 0.0, 400.0, 432.0));
+        assert_eq!(
+            drop_highlight_rect(&pane, DropZone::Edge(Direction::Right), 32.0),
+            (300.0, 32.0, 200.0, 400.0)
+        );
+        assert_eq!(
+            drop_highlight_rect(&pane, DropZone::Edge(Direction::Down), 32.0),
+            (100.0, 232.0, 400.0, 200.0)
+        );
+    }
+
+    fn two_pane_tree() -> PaneLayoutNode {
+        // HSplit(Pane[A, B], Pane[C])
+        let mut pane1 = test_pane(1);
+        pane1.add_tab(test_tab(1));
+        pane1.add_tab(test_tab(2));
+        let mut pane2 = test_pane(2);
+        pane2.add_tab(test_tab(3));
+        PaneLayoutNode::Split {
+            direction: SplitDirection::Horizontal,
+            ratio: 0.5,
+            first: Box::new(PaneLayoutNode::Leaf(pane1)),
+            second: Box::new(PaneLayoutNode::Leaf(pane2)),
+        }
+    }
+
+    #[test]
+    fn test_drop_tab_onto_other_pane() {
+        let mut tree = two_pane_tree();
+        let target = TabDropTarget {
+            pane_id: 2,
+            zone: DropZone::Center,
+        };
+
+        let result = move_tab_to_drop_target(&mut tree, 1, target, || 3);
+
+        assert!(matches!(result, MoveResult::MovedToExisting { source_pane_id: 1, target_pane_id: 2 }));
+        assert_eq!(tree.get_pane(1).unwrap().tab_count(), 1);
+        assert_eq!(tree.get_pane(2).unwrap().tabs[1].id, 2);
+    }
+
+    #[test]
+    fn test_drop_tab_onto_other_pane_edge_splits_it() {
+        let mut tree = two_pane_tree();
+        let target = TabDropTarget {
+            pane_id: 2,
+            zone: DropZone::Edge(Direction::Down),
+        };
+
+        let result = move_tab_to_drop_target(&mut tree, 1, target, || 3);
+
+        assert!(matches!(result, MoveResult::MovedToNew { source_pane_id: 1, new_pane_id: 3 }));
+        assert_eq!(tree.pane_count(), 3);
+
+        // Pane 3 is below pane 2 in the right half
+        let rects = calculate_pane_rects((0.0, 0.0, 800.0, 600.0), &tree);
+        let new_rect = rects.iter().find(|r| r.pane_id == 3).unwrap();
+        assert!(approx_eq(new_rect.x, 400.0));
+        assert!(approx_eq(new_rect.y, 300.0));
+        assert_eq!(tree.get_pane(3).unwrap().tabs[0].id, 2);
+    }
+
+    #[test]
+    fn test_drop_last_tab_onto_other_pane_removes_source() {
+        let mut tree = two_pane_tree();
+        let target = TabDropTarget {
+            pane_id: 1,
+            zone: DropZone::Center,
+        };
+
+        let result = move_tab_to_drop_target(&mut tree, 2, target, || 3);
+
+        assert!(matches!(result, MoveResult::MovedToExisting { .. }));
+        assert_eq!(tree.pane_count(), 1);
+        assert_eq!(tree.get_pane(1).unwrap().tab_count(), 3);
+    }
+
+    #[test]
+    fn test_drop_tab_onto_own_pane() {
+        let mut tree = two_pane_tree();
+
+        // Own center is a no-op
+        let center = TabDropTarget {
+            pane_id: 1,
+            zone: DropZone::Center,
+        };
+        assert_eq!(move_tab_to_drop_target(&mut tree, 1, center, || 3), MoveResult::Rejected);
+
+        // Splitting a single-tab pane would collapse straight back
+        let edge = TabDropTarget {
+            pane_id: 2,
+            zone: DropZone::Edge(Direction::Left),
+        };
+        assert_eq!(move_tab_to_drop_target(&mut tree, 2, edge, || 3), MoveResult::Rejected);
+
+        // A pane with several tabs can split itself
+        let edge = TabDropTarget {
+            pane_id: 1,
+            zone: DropZone::Edge(Direction::Left),
+        };
+        let result = move_tab_to_drop_target(&mut tree, 1, edge, || 3);
+        assert!(matches!(result, MoveResult::MovedToNew { new_pane_id: 3, .. }));
+        assert_eq!(tree.pane_count(), 3);
+    }
+
     // =========================================================================
     // remove_active_tab Tests
     // =========================================================================
@@ -2381,6 +3189,27 @@ mod tests {
         assert!(approx_eq(hit.local_y, 70.0));
     }
 
+    #[test]
+    fn test_pane_rect_hit_outside_pane() {
+        // A drag past the bottom of the window still resolves against the pane
+        let rect = PaneRect {
+            x: TEST_RAIL_WIDTH,
+            y: 0.0,
+            width: 400.0,
+            height: 600.0,
+            pane_id: 1,
+        };
+
+        let hit = rect.hit(TEST_RAIL_WIDTH - 10.0, 650.0, TEST_TAB_BAR_HEIGHT);
+        assert_eq!(hit.zone, HitZone::Content);
+        assert!(approx_eq(hit.local_x, -10.0));
+        assert!(approx_eq(hit.local_y, 620.0));
+
+        let above = rect.hit(TEST_RAIL_WIDTH, -20.0, TEST_TAB_BAR_HEIGHT);
+        assert_eq!(above.zone, HitZone::TabBar);
+        assert!(approx_eq(above.local_y, -50.0));
+    }
+
     #[test]
     fn test_resolve_pane_hit_horizontal_split_right_pane() {
         // HSplit(Pane[1], Pane[2]) - click in right pane content
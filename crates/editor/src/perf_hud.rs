@@ -0,0 +1,234 @@
+// Chunk: docs/chunks/render_stats_hud - Render stats HUD
+//!
+//! Render stats HUD (perf-instrumentation feature only)
+//!
+//! Ctrl+Shift+H toggles a small panel in the top-right corner of the window
+//! that shows how expensive the last frames were: frame time, styled line
+//! fetch time, the number of glyph quads built, the layout skip rate and the
+//! output rate of each terminal. The renderer's overlay module draws the
+//! lines built here.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::perf_stats::fmt_duration;
+use crate::selector_overlay::{STATUS_BAR_PADDING_X, STATUS_BAR_PADDING_Y};
+use crate::terminal_memory::format_bytes;
+use crate::workspace::TabId;
+
+/// How long output is sampled before a terminal's rate is updated.
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Gap between the HUD and the window's right edge and the tab bar.
+const HUD_MARGIN: f32 = 8.0;
+
+/// The figures shown in the HUD.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HudStats {
+    /// Latency of the last frame, from the first event to the end of rendering
+    pub frame_time: Option<Duration>,
+    /// 95th percentile of recent frame latencies
+    pub frame_time_p95: Option<Duration>,
+    /// Time spent fetching styled lines in the last frame, and how many lines
+    pub styled_lines: Option<(Duration, usize)>,
+    /// Glyph quads built for the panes drawn in the last frame
+    pub glyph_quads: usize,
+    /// Fraction of frames that reused the cached pane layout
+    pub layout_skip_rate: f64,
+    /// Each terminal's name and output rate in bytes per second
+    pub terminals: Vec<(String, f64)>,
+}
+
+/// Formats `stats` as the HUD's lines of text.
+pub fn hud_lines(stats: &HudStats) -> Vec<String> {
+    let or_dash = |d: Option<Duration>| d.map_or_else(|| "-".to_string(), fmt_duration);
+    let mut lines = vec![
+        format!(
+            "Frame    {} (P95 {})",
+            or_dash(stats.frame_time),
+            or_dash(stats.frame_time_p95)
+        ),
+        match stats.styled_lines {
+            Some((duration, lines)) => format!("Styled   {} / {} lines", fmt_duration(duration), lines),
+            None => "Styled   -".to_string(),
+        },
+        format!("Quads    {}", stats.glyph_quads),
+        format!("Layout   {:.1}% skipped", stats.layout_skip_rate * 100.0),
+    ];
+    for (name, rate) in &stats.terminals {
+        lines.push(format!("PTY      {}/s  {}", format_bytes(*rate as u64), name));
+    }
+    lines
+}
+
+/// Where the HUD is drawn: one strip per line, stacked downward from
+/// (`x`, `y`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HudGeometry {
+    /// Left edge of the strips
+    pub x: f32,
+    /// Top edge of the first strip
+    pub y: f32,
+    /// Width of the strips
+    pub width: f32,
+    /// Height of each strip (line height plus padding)
+    pub row_height: f32,
+}
+
+/// Places the HUD in the top-right corner of the window, just below the tab
+/// bar, wide enough for the longest of `lines`.
+pub fn hud_geometry(
+    lines: &[String],
+    view_width: f32,
+    top: f32,
+    line_height: f32,
+    glyph_width: f32,
+) -> HudGeometry {
+    let longest = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+    // One extra column so the last glyph isn't clipped by the strip's padding
+    let text_width = (longest + 1) as f32 * glyph_width;
+    let width = (text_width + 2.0 * STATUS_BAR_PADDING_X).min(view_width);
+    HudGeometry {
+        x: (view_width - width - HUD_MARGIN).max(0.0),
+        y: top + HUD_MARGIN,
+        width,
+        row_height: line_height + 2.0 * STATUS_BAR_PADDING_Y,
+    }
+}
+
+/// One terminal's last sample.
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    at: Instant,
+    bytes: u64,
+    rate: f64,
+}
+
+/// Turns each terminal's running output byte count into a rate.
+///
+/// A terminal's rate is recomputed once its last sample is at least a second
+/// old, so the HUD shows a steady figure rather than per-frame noise.
+#[derive(Debug, Default)]
+pub struct PtyThroughput {
+    samples: HashMap<TabId, Sample>,
+}
+
+impl PtyThroughput {
+    /// Creates a tracker with no samples.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that terminal `id` had processed `bytes` of output at `now`
+    /// and returns its rate in bytes per second.
+    ///
+    /// A terminal seen for the first time has a rate of 0.
+    pub fn update(&mut self, id: TabId, bytes: u64, now: Instant) -> f64 {
+        let sample = self.samples.entry(id).or_insert(Sample {
+            at: now,
+            bytes,
+            rate: 0.0,
+        });
+        let elapsed = now.saturating_duration_since(sample.at);
+        if elapsed >= RATE_WINDOW {
+            sample.rate = bytes.saturating_sub(sample.bytes) as f64 / elapsed.as_secs_f64();
+            sample.at = now;
+            sample.bytes = bytes;
+        }
+        sample.rate
+    }
+
+    /// Forgets terminals not in `ids`.
+    pub fn retain(&mut self, ids: &[TabId]) {
+        self.samples.retain(|id, _| ids.contains(id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_is_zero_until_a_window_has_passed() {
+        let mut throughput = PtyThroughput::new();
+        let start = Instant::now();
+        assert_eq!(throughput.update(1, 100, start), 0.0);
+        assert_eq!(throughput.update(1, 5000, start + Duration::from_millis(500)), 0.0);
+        assert_eq!(throughput.update(1, 2100, start + Duration::from_secs(2)), 1000.0);
+    }
+
+    #[test]
+    fn test_rate_holds_between_windows() {
+        let mut throughput = PtyThroughput::new();
+        let start = Instant::now();
+        throughput.update(1, 0, start);
+        assert_eq!(throughput.update(1, 4096, start + Duration::from_secs(1)), 4096.0);
+        assert_eq!(
+            throughput.update(1, 9999, start + Duration::from_millis(1500)),
+            4096.0
+        );
+        // Idle for the next window
+        assert_eq!(throughput.update(1, 9999, start + Duration::from_secs(2)), 5903.0);
+        assert_eq!(throughput.update(1, 9999, start + Duration::from_secs(3)), 0.0);
+    }
+
+    #[test]
+    fn test_retain_forgets_closed_terminals() {
+        let mut throughput = PtyThroughput::new();
+        let start = Instant::now();
+        throughput.update(1, 0, start);
+        throughput.update(2, 0, start);
+        throughput.retain(&[2]);
+        // Terminal 1 starts over as if new
+        assert_eq!(throughput.update(1, 5000, start + Duration::from_secs(1)), 0.0);
+        assert_eq!(throughput.update(2, 5000, start + Duration::from_secs(1)), 5000.0);
+    }
+
+    #[test]
+    fn test_hud_lines() {
+        let stats = HudStats {
+            frame_time: Some(Duration::from_micros(1500)),
+            frame_time_p95: Some(Duration::from_millis(12)),
+            styled_lines: Some((Duration::from_micros(250), 42)),
+            glyph_quads: 1234,
+            layout_skip_rate: 0.963,
+            terminals: vec![("lite-edit › zsh".to_string(), 2048.0)],
+        };
+        assert_eq!(
+            hud_lines(&stats),
+            vec![
+                "Frame    1.5ms (P95 12ms)",
+                "Styled   250µs / 42 lines",
+                "Quads    1234",
+                "Layout   96.3% skipped",
+                "PTY      2.0 KB/s  lite-edit › zsh",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hud_lines_without_data() {
+        let lines = hud_lines(&HudStats::default());
+        assert_eq!(lines[0], "Frame    - (P95 -)");
+        assert_eq!(lines[1], "Styled   -");
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn test_hud_geometry_sits_top_right_below_the_tab_bar() {
+        let lines = vec!["12345".to_string(), "123".to_string()];
+        let geometry = hud_geometry(&lines, 1000.0, 32.0, 20.0, 10.0);
+        assert_eq!(geometry.width, 60.0 + 2.0 * STATUS_BAR_PADDING_X);
+        assert_eq!(geometry.x, 1000.0 - geometry.width - HUD_MARGIN);
+        assert_eq!(geometry.y, 32.0 + HUD_MARGIN);
+        assert_eq!(geometry.row_height, 20.0 + 2.0 * STATUS_BAR_PADDING_Y);
+    }
+
+    #[test]
+    fn test_hud_geometry_fits_narrow_windows() {
+        let lines = vec!["x".repeat(200)];
+        let geometry = hud_geometry(&lines, 300.0, 32.0, 20.0, 10.0);
+        assert_eq!(geometry.width, 300.0);
+        assert_eq!(geometry.x, 0.0);
+    }
+}
@@ -6,10 +6,13 @@
 //! 1. **Keystroke-to-present latency** — P50/P95/P99 over a rolling 1000-sample window
 //! 2. **Dirty region hit rate** — partial vs full viewport vs skipped frame counts
 //! 3. **styled_line() cost** — per-frame aggregate timing of the styled_line collection
+//! 4. **Startup phases** — time from process start to each launch milestone
+//!    (window visible, first frame, warm-up complete)
 //!
 //! Stats are auto-printed to stderr every 1000 frames (~17s at 60 fps) and can be
 //! dumped on-demand via Ctrl+Shift+P (sets `EditorState::dump_perf_stats`).
 
+use std::sync::OnceLock;
 use std::time::{Duration, Instant};
 
 use crate::dirty_region::DirtyRegion;
@@ -20,6 +23,52 @@ const RING_CAP: usize = 1000;
 /// How often (in frames) to auto-report to stderr.
 const AUTO_REPORT_INTERVAL: u64 = 1000;
 
+// Chunk: docs/chunks/lazy_subsystem_init - Startup phase timing
+/// The instant the process started, as captured by the first call to `process_start()`.
+static PROCESS_START: OnceLock<Instant> = OnceLock::new();
+
+/// Returns the process start instant.
+///
+/// `main()` calls this first thing so that startup phases are measured from launch.
+pub fn process_start() -> Instant {
+    *PROCESS_START.get_or_init(Instant::now)
+}
+
+/// Timings of the startup phases, measured from `process_start()`.
+#[derive(Debug, Default)]
+pub struct StartupProfile {
+    /// `(phase, elapsed since process start)` in the order the phases were reached.
+    phases: Vec<(&'static str, Duration)>,
+}
+
+impl StartupProfile {
+    /// Records that `phase` was reached now.
+    pub fn mark(&mut self, phase: &'static str) {
+        self.mark_at(phase, process_start().elapsed());
+    }
+
+    /// Records that `phase` was reached `elapsed` after process start.
+    fn mark_at(&mut self, phase: &'static str, elapsed: Duration) {
+        self.phases.push((phase, elapsed));
+    }
+
+    /// Formats each phase with its time since launch and since the previous phase.
+    pub fn report(&self) -> String {
+        let mut out = String::from("[lite-edit perf] Startup\n");
+        let mut previous = Duration::ZERO;
+        for (phase, elapsed) in &self.phases {
+            out.push_str(&format!(
+                "  {:<22} {:>7}  (+{})\n",
+                format!("{}:", phase),
+                fmt_duration(*elapsed),
+                fmt_duration(elapsed.saturating_sub(previous)),
+            ));
+            previous = *elapsed;
+        }
+        out
+    }
+}
+
 /// Performance statistics collector.
 ///
 /// All fields are zero-cost when the `perf-instrumentation` feature is disabled
@@ -55,6 +104,9 @@ pub struct PerfStats {
     layout_skipped: u64,
     /// Number of frames where layout recalculation was performed.
     layout_performed: u64,
+    // Chunk: docs/chunks/lazy_subsystem_init - Startup phases
+    /// Startup phase timings, handed over by `main` once the drain loop exists.
+    startup: StartupProfile,
 }
 
 impl PerfStats {
@@ -75,9 +127,21 @@ impl PerfStats {
             // Chunk: docs/chunks/invalidation_separation - Initialize layout counters
             layout_skipped: 0,
             layout_performed: 0,
+            startup: StartupProfile::default(),
         }
     }
 
+    // Chunk: docs/chunks/lazy_subsystem_init - Startup phase access
+    /// Returns the startup profile so later phases can be recorded.
+    pub fn startup_mut(&mut self) -> &mut StartupProfile {
+        &mut self.startup
+    }
+
+    /// Replaces the startup profile with the phases recorded during launch.
+    pub fn set_startup_profile(&mut self, profile: StartupProfile) {
+        self.startup = profile;
+    }
+
     /// Called at the top of `process_pending_events` to start the frame timer.
     pub fn mark_frame_start(&mut self) {
         self.frame_start = Some(Instant::now());
@@ -126,6 +190,28 @@ impl PerfStats {
         self.layout_performed = performed as u64;
     }
 
+    // Chunk: docs/chunks/render_stats_hud - Latest figures for the HUD
+    /// Returns the latency of the most recent frame.
+    pub fn last_frame_latency(&self) -> Option<Duration> {
+        ring_last(&self.frame_latencies, self.frame_lat_cursor)
+    }
+
+    /// Returns the 95th percentile of the recorded frame latencies.
+    pub fn frame_latency_p95(&self) -> Option<Duration> {
+        if self.frame_latencies.is_empty() {
+            return None;
+        }
+        let mut sorted = self.frame_latencies.clone();
+        sorted.sort();
+        Some(percentile(&sorted, 95))
+    }
+
+    /// Returns the styled line cost `(time, line count)` of the most recent
+    /// frame that fetched any.
+    pub fn last_styled_line_batch(&self) -> Option<(Duration, usize)> {
+        ring_last(&self.styled_line_costs, self.styled_cursor)
+    }
+
     /// Returns `true` every `AUTO_REPORT_INTERVAL` frames.
     pub fn should_auto_report(&self) -> bool {
         self.frame_count > 0 && self.frame_count % AUTO_REPORT_INTERVAL == 0
@@ -205,6 +291,11 @@ impl PerfStats {
             ));
         }
 
+        // Chunk: docs/chunks/lazy_subsystem_init - Startup phases in the on-demand report
+        if !self.startup.phases.is_empty() {
+            out.push_str(&self.startup.report());
+        }
+
         out
     }
 }
@@ -224,6 +315,16 @@ fn ring_push<T>(buf: &mut Vec<T>, cursor: &mut usize, full: &mut bool, value: T)
     *cursor = (*cursor + 1) % RING_CAP;
 }
 
+// Chunk: docs/chunks/render_stats_hud - Newest ring buffer entry
+/// Returns the most recently pushed value of a ring buffer.
+fn ring_last<T: Copy>(buf: &[T], cursor: usize) -> Option<T> {
+    if buf.is_empty() {
+        return None;
+    }
+    // `cursor` is where the next value goes, so the newest is just before it
+    Some(buf[(cursor + buf.len() - 1) % buf.len()])
+}
+
 /// Returns a snapshot (clone) of the ring buffer contents in insertion order.
 fn ring_snapshot<T: Clone>(buf: &[T], cursor: usize, full: bool) -> Vec<T> {
     if !full {
@@ -253,7 +354,7 @@ fn percentile(sorted: &[Duration], pct: usize) -> Duration {
 }
 
 /// Formats a Duration as a human-friendly string (µs or ms).
-fn fmt_duration(d: Duration) -> String {
+pub fn fmt_duration(d: Duration) -> String {
     let micros = d.as_micros();
     if micros < 1000 {
         format!("{}µs", micros)
@@ -370,6 +471,43 @@ mod tests {
         assert!(report.contains("styled_line:"));
     }
 
+    #[test]
+    fn ring_last_is_the_newest_value() {
+        let mut buf = Vec::new();
+        let mut cursor = 0;
+        let mut full = false;
+        assert_eq!(ring_last(&buf, cursor), None);
+
+        for i in 0..(RING_CAP + 3) {
+            ring_push(&mut buf, &mut cursor, &mut full, i);
+            assert_eq!(ring_last(&buf, cursor), Some(i));
+        }
+    }
+
+    #[test]
+    fn latest_styled_line_batch() {
+        let mut stats = PerfStats::new();
+        assert_eq!(stats.last_styled_line_batch(), None);
+        stats.record_styled_line_batch(Duration::from_micros(100), 40);
+        stats.record_styled_line_batch(Duration::from_micros(300), 12);
+        assert_eq!(
+            stats.last_styled_line_batch(),
+            Some((Duration::from_micros(300), 12))
+        );
+    }
+
+    #[test]
+    fn startup_report_shows_elapsed_and_delta() {
+        let mut profile = StartupProfile::default();
+        profile.mark_at("window visible", Duration::from_millis(40));
+        profile.mark_at("first frame", Duration::from_millis(85));
+
+        let report = profile.report();
+        assert!(report.contains("window visible:"));
+        assert!(report.contains("40ms  (+40ms)"));
+        assert!(report.contains("85ms  (+45ms)"));
+    }
+
     #[test]
     fn fmt_duration_micros() {
         assert_eq!(fmt_duration(Duration::from_micros(320)), "320µs");
@@ -0,0 +1,333 @@
+// Chunk: docs/chunks/print_pdf_export - Page layout for printing and PDF export
+//!
+//! Page layout for printing a buffer.
+//!
+//! A [`PrintDocument`] holds a buffer's syntax-highlighted lines. [`paginate`]
+//! expands tabs, wraps long lines at the page width and splits the result into
+//! pages of numbered rows; `print_output` draws those pages with Core Graphics,
+//! into a PDF file or for an `NSPrintOperation`. The editor's colors are made
+//! for a dark background, so [`paper_color`] maps them to their Catppuccin
+//! Latte counterparts, which read well on white paper.
+
+use lite_edit_buffer::{Color, Span, StyledLine};
+
+use crate::tab_width::char_visual_width;
+
+/// Color of plain text on paper (Latte "text", #4c4f69).
+pub const TEXT_COLOR: [f64; 3] = rgb(0x4c, 0x4f, 0x69);
+
+/// Color of the header and line numbers (Latte "overlay1", #8c8fa1).
+pub const MUTED_COLOR: [f64; 3] = rgb(0x8c, 0x8f, 0xa1);
+
+/// An 8-bit RGB color.
+type Rgb8 = (u8, u8, u8);
+
+/// Mocha syntax colors and the Latte colors printed in their place.
+const PAPER_COLORS: &[(Rgb8, Rgb8)] = &[
+    ((0xcb, 0xa6, 0xf7), (0x88, 0x39, 0xef)), // mauve
+    ((0x89, 0xb4, 0xfa), (0x1e, 0x66, 0xf5)), // blue
+    ((0x74, 0xc7, 0xec), (0x20, 0x9f, 0xb5)), // sapphire
+    ((0xa6, 0xe3, 0xa1), (0x40, 0xa0, 0x2b)), // green
+    ((0xf5, 0xc2, 0xe7), (0xea, 0x76, 0xcb)), // pink
+    ((0xfa, 0xb3, 0x87), (0xfe, 0x64, 0x0b)), // peach
+    ((0xf9, 0xe2, 0xaf), (0xdf, 0x8e, 0x1d)), // yellow
+    ((0xeb, 0xa0, 0xac), (0xe6, 0x45, 0x53)), // maroon
+    ((0xf3, 0x8b, 0xa8), (0xd2, 0x0f, 0x39)), // red
+    ((0xb4, 0xbe, 0xfe), (0x72, 0x87, 0xfd)), // lavender
+    ((0x89, 0xdc, 0xeb), (0x04, 0xa5, 0xe5)), // sky
+    ((0x6c, 0x70, 0x86), (0x9c, 0xa0, 0xb0)), // overlay0
+    ((0xa6, 0xad, 0xc8), (0x6c, 0x6f, 0x85)), // subtext0
+    ((0xcd, 0xd6, 0xf4), (0x4c, 0x4f, 0x69)), // text
+];
+
+/// Columns between the line numbers and the text.
+pub const GUTTER_GAP: usize = 2;
+
+const fn rgb(r: u8, g: u8, b: u8) -> [f64; 3] {
+    [r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0]
+}
+
+/// A buffer to print.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintDocument {
+    /// Shown in each page's header: the file's path, or the tab's label for
+    /// an unsaved buffer.
+    pub title: String,
+    /// The buffer's lines, with their syntax colors.
+    pub lines: Vec<StyledLine>,
+}
+
+/// Page size and text metrics, in points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageGeometry {
+    /// Width of the paper.
+    pub width: f64,
+    /// Height of the paper.
+    pub height: f64,
+    /// Blank space around the header and text on every side.
+    pub margin: f64,
+    /// Advance of one column of the monospace font.
+    pub char_width: f64,
+    /// Height of one row.
+    pub line_height: f64,
+}
+
+impl PageGeometry {
+    /// Height of the header, including the gap below its rule.
+    pub fn header_height(&self) -> f64 {
+        self.line_height * 2.0
+    }
+
+    /// Number of columns that fit across the page, line numbers included.
+    pub fn columns(&self) -> usize {
+        let width = self.width - 2.0 * self.margin;
+        ((width / self.char_width).floor() as usize).max(1)
+    }
+
+    /// Number of rows that fit below the header.
+    pub fn rows(&self) -> usize {
+        let height = self.height - 2.0 * self.margin - self.header_height();
+        ((height / self.line_height).floor() as usize).max(1)
+    }
+
+    /// Distance from the top of the page to the top of `row`.
+    pub fn row_top(&self, row: usize) -> f64 {
+        self.margin + self.header_height() + row as f64 * self.line_height
+    }
+}
+
+/// One printed row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintRow {
+    /// 1-based buffer line number, on the first row of each line only.
+    pub number: Option<usize>,
+    /// The row's text, with tabs expanded to spaces.
+    pub spans: Vec<Span>,
+}
+
+/// One printed page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintPage {
+    /// The page's rows, top to bottom.
+    pub rows: Vec<PrintRow>,
+}
+
+/// Returns the number of columns taken by line numbers and the gap after
+/// them, for a document of `line_count` lines.
+pub fn gutter_columns(line_count: usize) -> usize {
+    line_count.max(1).to_string().len() + GUTTER_GAP
+}
+
+/// Lays `lines` out on pages of `geometry`.
+///
+/// Tabs are expanded to the next tab stop and lines wider than the page wrap
+/// onto continuation rows, which have no line number. An empty document
+/// still has one (blank) page.
+pub fn paginate(lines: &[StyledLine], geometry: &PageGeometry) -> Vec<PrintPage> {
+    let text_columns = geometry
+        .columns()
+        .saturating_sub(gutter_columns(lines.len()))
+        .max(1);
+    let rows: Vec<PrintRow> = lines
+        .iter()
+        .enumerate()
+        .flat_map(|(index, line)| wrap_line(index + 1, line, text_columns))
+        .collect();
+
+    if rows.is_empty() {
+        return vec![PrintPage { rows }];
+    }
+    rows.chunks(geometry.rows())
+        .map(|rows| PrintPage {
+            rows: rows.to_vec(),
+        })
+        .collect()
+}
+
+/// Splits line `number` into rows of at most `columns` columns.
+fn wrap_line(number: usize, line: &StyledLine, columns: usize) -> Vec<PrintRow> {
+    let mut rows = vec![PrintRow {
+        number: Some(number),
+        spans: Vec::new(),
+    }];
+    // Visual column within the line (for tab stops) and within the row
+    let mut line_col = 0;
+    let mut row_col = 0;
+
+    for span in &line.spans {
+        for c in span.text.chars() {
+            let width = char_visual_width(c, line_col);
+            line_col += width;
+            // A tab becomes spaces, which may wrap individually
+            let (c, count, width) = if c == '\t' { (' ', width, 1) } else { (c, 1, width) };
+            for _ in 0..count {
+                if row_col + width > columns && row_col > 0 {
+                    rows.push(PrintRow {
+                        number: None,
+                        spans: Vec::new(),
+                    });
+                    row_col = 0;
+                }
+                row_col += width;
+                push_char(rows.last_mut().unwrap(), c, span);
+            }
+        }
+    }
+    rows
+}
+
+/// Appends `c` in `span`'s style to `row`, extending its last span when the
+/// style matches.
+fn push_char(row: &mut PrintRow, c: char, span: &Span) {
+    match row.spans.last_mut() {
+        Some(last) if last.style == span.style => last.text.push(c),
+        _ => row.spans.push(Span::new(c, span.style)),
+    }
+}
+
+/// Returns the page counter shown in the header.
+pub fn page_label(page: usize, page_count: usize) -> String {
+    format!("Page {} of {}", page + 1, page_count)
+}
+
+/// Returns the color to print for a span's foreground `color`, as RGB
+/// components in 0..=1.
+///
+/// Theme colors become their Latte counterparts. Other light colors are
+/// darkened so they stay readable on white; terminal palette colors never
+/// occur in file buffers and print as plain text.
+pub fn paper_color(color: Color) -> [f64; 3] {
+    let Color::Rgb { r, g, b } = color else {
+        return TEXT_COLOR;
+    };
+    if let Some(&(_, (pr, pg, pb))) = PAPER_COLORS.iter().find(|(mocha, _)| *mocha == (r, g, b)) {
+        return rgb(pr, pg, pb);
+    }
+    let [r, g, b] = rgb(r, g, b);
+    let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    if luminance > 0.5 {
+        let scale = 0.5 / luminance;
+        [r * scale, g * scale, b * scale]
+    } else {
+        [r, g, b]
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lite_edit_buffer::Style;
+
+    /// A page with 10 columns and 4 rows after the header.
+    fn small_page() -> PageGeometry {
+        PageGeometry {
+            width: 120.0,
+            height: 100.0,
+            margin: 10.0,
+            char_width: 10.0,
+            line_height: 10.0,
+        }
+    }
+
+    fn row_text(row: &PrintRow) -> String {
+        row.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_geometry() {
+        let page = small_page();
+        assert_eq!(page.columns(), 10);
+        assert_eq!(page.rows(), 6);
+        assert_eq!(page.row_top(0), 30.0);
+        assert_eq!(page.row_top(2), 50.0);
+    }
+
+    #[test]
+    fn test_paginate_numbers_lines_and_splits_pages() {
+        let lines: Vec<StyledLine> = (0..8).map(|i| StyledLine::plain(format!("l{}", i))).collect();
+        let pages = paginate(&lines, &small_page());
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].rows.len(), 6);
+        assert_eq!(pages[1].rows.len(), 2);
+        assert_eq!(pages[0].rows[0].number, Some(1));
+        assert_eq!(row_text(&pages[1].rows[1]), "l7");
+        assert_eq!(pages[1].rows[1].number, Some(8));
+    }
+
+    #[test]
+    fn test_long_lines_wrap_without_numbers() {
+        // One-digit line numbers and the gap leave 7 columns for text
+        let pages = paginate(&[StyledLine::plain("abcdefghijk")], &small_page());
+        let rows = &pages[0].rows;
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[0].number, row_text(&rows[0])), (Some(1), "abcdefg".to_string()));
+        assert_eq!((rows[1].number, row_text(&rows[1])), (None, "hijk".to_string()));
+    }
+
+    #[test]
+    fn test_wrapping_keeps_styles_and_expands_tabs() {
+        let keyword = Style {
+            fg: Color::Rgb {
+                r: 0xcb,
+                g: 0xa6,
+                b: 0xf7,
+            },
+            ..Style::default()
+        };
+        let line = StyledLine::new(vec![Span::new("fn", keyword), Span::plain("\tx")]);
+        let pages = paginate(&[line], &small_page());
+        let row = &pages[0].rows[0];
+
+        assert_eq!(row_text(row), "fn  x");
+        assert_eq!(row.spans.len(), 2);
+        assert_eq!(row.spans[0].style, keyword);
+    }
+
+    #[test]
+    fn test_wide_characters_move_to_the_next_row_whole() {
+        let pages = paginate(&[StyledLine::plain("abcdef漢字")], &small_page());
+        let rows = &pages[0].rows;
+
+        assert_eq!(row_text(&rows[0]), "abcdef");
+        assert_eq!(row_text(&rows[1]), "漢字");
+    }
+
+    #[test]
+    fn test_empty_document_has_one_page() {
+        let pages = paginate(&[], &small_page());
+        assert_eq!(pages, vec![PrintPage { rows: Vec::new() }]);
+    }
+
+    #[test]
+    fn test_paper_colors() {
+        let mauve = Color::Rgb {
+            r: 0xcb,
+            g: 0xa6,
+            b: 0xf7,
+        };
+        assert_eq!(paper_color(mauve), rgb(0x88, 0x39, 0xef));
+        assert_eq!(paper_color(Color::Default), TEXT_COLOR);
+
+        // Unknown light colors are darkened, dark ones kept
+        let [r, g, b] = paper_color(Color::Rgb {
+            r: 255,
+            g: 255,
+            b: 255,
+        });
+        assert!(r < 0.6 && g < 0.6 && b < 0.6);
+        assert_eq!(paper_color(Color::Rgb { r: 0, g: 0, b: 128 }), rgb(0, 0, 128));
+    }
+
+    #[test]
+    fn test_page_label() {
+        assert_eq!(page_label(0, 3), "Page 1 of 3");
+        assert_eq!(gutter_columns(0), 3);
+        assert_eq!(gutter_columns(120), 5);
+    }
+}
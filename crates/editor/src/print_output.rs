@@ -0,0 +1,418 @@
+// Chunk: docs/chunks/print_pdf_export - Printing and PDF export with Core Graphics
+//!
+//! Printing and PDF export.
+//!
+//! Pages laid out by `print_layout` are drawn with Core Graphics and Core
+//! Text, separately from the Metal renderer: into a PDF context for
+//! [`export_pdf`], and from the `drawRect:` of an offscreen view that an
+//! `NSPrintOperation` paginates for [`print_document`]. Both draw through the
+//! same `PrintJob::draw_page`, so the printout and the PDF look alike.
+//!
+//! ## Test isolation
+//!
+//! Under `cfg(test)` neither Core Graphics nor the print panel is touched.
+//! Instead, a `thread_local!` records what would have been printed or
+//! exported, so that tests can check it without modal dialogs or files.
+
+use std::path::Path;
+
+use crate::print_layout::PrintDocument;
+
+// ── production output (Core Graphics) ────────────────────────────────────────
+
+#[cfg(not(test))]
+use std::ptr::NonNull;
+
+#[cfg(not(test))]
+use lite_edit_buffer::Style;
+#[cfg(not(test))]
+use objc2::rc::Retained;
+#[cfg(not(test))]
+use objc2::{define_class, msg_send, DefinedClass, MainThreadOnly};
+#[cfg(not(test))]
+use objc2_app_kit::{NSGraphicsContext, NSPrintInfo, NSPrintOperation, NSView};
+#[cfg(not(test))]
+use objc2_core_foundation::{CFString, CFURLPathStyle, CGFloat, CGPoint, CGRect, CGSize, CFURL};
+#[cfg(not(test))]
+use objc2_core_graphics::{
+    CGContext, CGPDFContextBeginPage, CGPDFContextClose, CGPDFContextCreateWithURL,
+    CGPDFContextEndPage,
+};
+#[cfg(not(test))]
+use objc2_foundation::{
+    MainThreadMarker, NSCopying, NSInteger, NSObjectProtocol, NSPoint, NSRange, NSRect, NSSize,
+    NSString,
+};
+
+#[cfg(not(test))]
+use crate::font::{Font, GlyphFont};
+#[cfg(not(test))]
+use crate::print_layout::{
+    gutter_columns, page_label, paginate, paper_color, PageGeometry, PrintPage, GUTTER_GAP,
+    MUTED_COLOR,
+};
+#[cfg(not(test))]
+use crate::tab_width::char_visual_width;
+
+/// Size of the printed text, in points.
+#[cfg(not(test))]
+const FONT_SIZE: f64 = 9.0;
+
+/// Blank space around the page content, in points (half an inch).
+#[cfg(not(test))]
+const PAGE_MARGIN: f64 = 36.0;
+
+/// The monospace fonts for each weight and slant of printed text.
+#[cfg(not(test))]
+struct PrintFonts {
+    regular: Font,
+    bold: Font,
+    italic: Font,
+    bold_italic: Font,
+}
+
+#[cfg(not(test))]
+impl PrintFonts {
+    fn load() -> Self {
+        Self {
+            regular: Font::new("Menlo-Regular", FONT_SIZE, 1.0),
+            bold: Font::new("Menlo-Bold", FONT_SIZE, 1.0),
+            italic: Font::new("Menlo-Italic", FONT_SIZE, 1.0),
+            bold_italic: Font::new("Menlo-BoldItalic", FONT_SIZE, 1.0),
+        }
+    }
+
+    fn for_style(&self, style: &Style) -> &Font {
+        match (style.bold, style.italic) {
+            (false, false) => &self.regular,
+            (true, false) => &self.bold,
+            (false, true) => &self.italic,
+            (true, true) => &self.bold_italic,
+        }
+    }
+}
+
+/// A document laid out on pages of a given paper size.
+#[cfg(not(test))]
+struct PrintJob {
+    title: String,
+    pages: Vec<PrintPage>,
+    geometry: PageGeometry,
+    /// Columns taken by line numbers
+    gutter: usize,
+    fonts: PrintFonts,
+}
+
+#[cfg(not(test))]
+impl PrintJob {
+    fn new(document: &PrintDocument, width: f64, height: f64) -> Self {
+        let fonts = PrintFonts::load();
+        let geometry = PageGeometry {
+            width,
+            height,
+            margin: PAGE_MARGIN,
+            char_width: fonts.regular.metrics.advance_width,
+            line_height: fonts.regular.metrics.line_height,
+        };
+        Self {
+            title: document.title.clone(),
+            pages: paginate(&document.lines, &geometry),
+            geometry,
+            gutter: gutter_columns(document.lines.len()),
+            fonts,
+        }
+    }
+
+    /// Draws page `index` with its bottom-left corner at the origin.
+    fn draw_page(&self, context: &CGContext, index: usize) {
+        let geometry = &self.geometry;
+        let char_width = geometry.char_width;
+
+        // Header: the title on the left, the page counter on the right, and
+        // a rule below. A title too long for the line keeps its end.
+        let label = page_label(index, self.pages.len());
+        let label_columns = label.chars().count();
+        let title_columns = geometry.columns().saturating_sub(label_columns + 2);
+        let title = truncate_start(&self.title, title_columns);
+        let header_top = geometry.margin;
+        let label_x = geometry.width - geometry.margin - label_columns as f64 * char_width;
+        let regular = &self.fonts.regular;
+        self.draw_text(context, regular, &title, geometry.margin, header_top, MUTED_COLOR);
+        self.draw_text(context, regular, &label, label_x, header_top, MUTED_COLOR);
+
+        let rule_y = geometry.height - header_top - geometry.line_height * 1.5;
+        let [r, g, b] = MUTED_COLOR;
+        CGContext::set_rgb_fill_color(Some(context), r, g, b, 1.0);
+        CGContext::fill_rect(
+            Some(context),
+            CGRect {
+                origin: CGPoint {
+                    x: geometry.margin,
+                    y: rule_y,
+                },
+                size: CGSize {
+                    width: geometry.width - 2.0 * geometry.margin,
+                    height: 0.5,
+                },
+            },
+        );
+
+        let text_x = geometry.margin + self.gutter as f64 * char_width;
+        for (row_index, row) in self.pages[index].rows.iter().enumerate() {
+            let top = geometry.row_top(row_index);
+            if let Some(number) = row.number {
+                let number = format!("{:>1$}", number, self.gutter - GUTTER_GAP);
+                self.draw_text(context, regular, &number, geometry.margin, top, MUTED_COLOR);
+            }
+            let mut x = text_x;
+            for span in &row.spans {
+                let font = self.fonts.for_style(&span.style);
+                let color = paper_color(span.style.fg);
+                x = self.draw_text(context, font, &span.text, x, top, color);
+            }
+        }
+    }
+
+    /// Draws `text` on the row whose top is `top` points below the top of
+    /// the page, starting `x` points from the left edge. Returns the `x` just
+    /// past the text.
+    fn draw_text(
+        &self,
+        context: &CGContext,
+        font: &Font,
+        text: &str,
+        mut x: f64,
+        top: f64,
+        color: [f64; 3],
+    ) -> f64 {
+        let baseline = self.geometry.height - top - font.metrics.ascent;
+        let [r, g, b] = color;
+        CGContext::set_rgb_fill_color(Some(context), r, g, b, 1.0);
+
+        for c in text.chars() {
+            let width = char_visual_width(c, 0) as f64 * self.geometry.char_width;
+            if c != ' ' {
+                if let Some(source) = font.glyph_for_char_with_fallback(c) {
+                    let ct_font = match &source.font {
+                        GlyphFont::Primary => font.ct_font(),
+                        GlyphFont::Fallback(fallback) => fallback,
+                    };
+                    let position = CGPoint {
+                        x: x as CGFloat,
+                        y: baseline as CGFloat,
+                    };
+                    unsafe {
+                        ct_font.draw_glyphs(
+                            NonNull::from(&source.glyph_id),
+                            NonNull::from(&position),
+                            1,
+                            context,
+                        );
+                    }
+                }
+            }
+            x += width;
+        }
+        x
+    }
+}
+
+/// Returns `text` cut to its last `columns` characters, with a leading
+/// ellipsis when anything was cut.
+#[cfg(not(test))]
+fn truncate_start(text: &str, columns: usize) -> String {
+    let count = text.chars().count();
+    if count <= columns {
+        return text.to_string();
+    }
+    let kept: String = text.chars().skip(count + 1 - columns.max(1)).collect();
+    format!("…{}", kept)
+}
+
+/// Returns the paper size chosen in Page Setup, in points.
+#[cfg(not(test))]
+fn paper_size(info: &NSPrintInfo) -> (f64, f64) {
+    let size = info.paperSize();
+    (size.width, size.height)
+}
+
+/// Writes `document` to a PDF file at `path`.
+///
+/// Returns the number of pages written.
+#[cfg(not(test))]
+pub fn export_pdf(document: &PrintDocument, path: &Path) -> Result<usize, String> {
+    let (width, height) = paper_size(&NSPrintInfo::sharedPrintInfo());
+    let job = PrintJob::new(document, width, height);
+
+    let path_string = CFString::from_str(&path.to_string_lossy());
+    let url = CFURL::with_file_system_path(
+        None,
+        Some(&path_string),
+        CFURLPathStyle::CFURLPOSIXPathStyle,
+        false,
+    )
+    .ok_or_else(|| format!("invalid path {}", path.display()))?;
+    let media_box = CGRect {
+        origin: CGPoint { x: 0.0, y: 0.0 },
+        size: CGSize { width, height },
+    };
+    let context = unsafe { CGPDFContextCreateWithURL(Some(&url), &media_box, None) }
+        .ok_or_else(|| format!("can't write {}", path.display()))?;
+
+    for index in 0..job.pages.len() {
+        unsafe { CGPDFContextBeginPage(Some(&context), None) };
+        job.draw_page(&context, index);
+        CGPDFContextEndPage(Some(&context));
+    }
+    CGPDFContextClose(Some(&context));
+    Ok(job.pages.len())
+}
+
+/// Shows the print panel for `document` and prints it unless cancelled.
+///
+/// # Safety
+///
+/// This function must be called from the main thread, like every AppKit
+/// panel.
+#[cfg(not(test))]
+pub fn print_document(document: &PrintDocument) {
+    let mtm = MainThreadMarker::new().expect("print_document must be called from main thread");
+
+    // The pages have margins of their own, so the printer adds none
+    let info = NSPrintInfo::sharedPrintInfo().copy();
+    info.setTopMargin(0.0);
+    info.setBottomMargin(0.0);
+    info.setLeftMargin(0.0);
+    info.setRightMargin(0.0);
+
+    let (width, height) = paper_size(&info);
+    let job = PrintJob::new(document, width, height);
+    let view = PrintView::new(mtm, job);
+    let operation = NSPrintOperation::printOperationWithView_printInfo(&view, &info);
+    operation.setJobTitle(Some(&NSString::from_str(&document.title)));
+    operation.runOperation();
+}
+
+#[cfg(not(test))]
+define_class!(
+    // SAFETY: PrintView follows Objective-C memory management rules
+    // and is only accessed from the main thread
+    #[unsafe(super = NSView)]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = PrintJob]
+    #[name = "LiteEditPrintView"]
+    struct PrintView;
+
+    // SAFETY: NSObjectProtocol is correctly implemented - we inherit from NSView
+    unsafe impl NSObjectProtocol for PrintView {}
+
+    impl PrintView {
+        /// Tells the print operation how many pages there are.
+        #[unsafe(method(knowsPageRange:))]
+        unsafe fn __knows_page_range(&self, range: *mut NSRange) -> bool {
+            unsafe { *range = NSRange::new(1, self.ivars().pages.len()) };
+            true
+        }
+
+        /// Returns where page `page` (1-based) is in the view.
+        #[unsafe(method(rectForPage:))]
+        fn __rect_for_page(&self, page: NSInteger) -> NSRect {
+            self.page_rect(page)
+        }
+
+        /// Draws the page being printed.
+        #[unsafe(method(drawRect:))]
+        fn __draw_rect(&self, _dirty_rect: NSRect) {
+            let mtm = MainThreadMarker::from(self);
+            let Some(operation) = NSPrintOperation::currentOperation(mtm) else {
+                return;
+            };
+            let Some(graphics) = NSGraphicsContext::currentContext() else {
+                return;
+            };
+            let context = graphics.CGContext();
+            let page = operation.currentPage();
+            let origin = self.page_rect(page).origin;
+            let index = (page.max(1) - 1) as usize;
+
+            CGContext::save_g_state(Some(&context));
+            CGContext::translate_ctm(Some(&context), origin.x, origin.y);
+            self.ivars().draw_page(&context, index);
+            CGContext::restore_g_state(Some(&context));
+        }
+    }
+);
+
+#[cfg(not(test))]
+impl PrintView {
+    fn new(mtm: MainThreadMarker, job: PrintJob) -> Retained<Self> {
+        let frame = NSRect::new(
+            NSPoint::new(0.0, 0.0),
+            NSSize::new(
+                job.geometry.width,
+                job.geometry.height * job.pages.len() as f64,
+            ),
+        );
+        let this = mtm.alloc::<Self>();
+        let this = this.set_ivars(job);
+        unsafe { msg_send![super(this), initWithFrame: frame] }
+    }
+
+    /// Returns where page `page` (1-based) is in the view. The view isn't
+    /// flipped, so page 1 is at the top.
+    fn page_rect(&self, page: NSInteger) -> NSRect {
+        let job = self.ivars();
+        let below = job.pages.len().saturating_sub(page.max(1) as usize);
+        let geometry = &job.geometry;
+        NSRect::new(
+            NSPoint::new(0.0, below as f64 * geometry.height),
+            NSSize::new(geometry.width, geometry.height),
+        )
+    }
+}
+
+// ── test output (thread-local record) ────────────────────────────────────────
+
+#[cfg(test)]
+use std::cell::RefCell;
+#[cfg(test)]
+use std::path::PathBuf;
+
+/// Where a document went in a test.
+#[cfg(test)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockOutput {
+    /// Sent to the printer
+    Printer(PrintDocument),
+    /// Exported as a PDF file
+    Pdf(PrintDocument, PathBuf),
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Documents printed or exported by unit tests on the current thread.
+    static MOCK_OUTPUT: RefCell<Vec<MockOutput>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records `document` as exported to `path`, as one page.
+#[cfg(test)]
+pub fn export_pdf(document: &PrintDocument, path: &Path) -> Result<usize, String> {
+    let output = MockOutput::Pdf(document.clone(), path.to_path_buf());
+    MOCK_OUTPUT.with(|o| o.borrow_mut().push(output));
+    Ok(1)
+}
+
+/// Records `document` as printed.
+#[cfg(test)]
+pub fn print_document(document: &PrintDocument) {
+    let output = MockOutput::Printer(document.clone());
+    MOCK_OUTPUT.with(|o| o.borrow_mut().push(output));
+}
+
+/// Returns and clears what was printed or exported on this thread.
+///
+/// This function is only available in test builds.
+#[cfg(test)]
+pub fn mock_take_output() -> Vec<MockOutput> {
+    MOCK_OUTPUT.with(|o| std::mem::take(&mut *o.borrow_mut()))
+}
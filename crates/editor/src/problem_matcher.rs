@@ -0,0 +1,612 @@
+// Chunk: docs/chunks/problem_matcher - Compiler diagnostics from build output
+//!
+//! Problem matching.
+//!
+//! [`parse_problems`] picks compiler diagnostics out of command output:
+//! - rustc: an `error[E0308]: message` line followed by ` --> path:line:col`
+//! - gcc and clang: `path:line:col: error: message`
+//! - tsc: `path(line,col): error TS2322: message`, or the pretty form
+//!   `path:line:col - error TS2322: message`
+//!
+//! A [`ProblemsBuffer`] lists the problems grouped by file and presents them
+//! through `BufferView`, so a `TabKind::Problems` tab renders through the same
+//! pipeline as diff tabs. One problem is selected at a time; the editor opens
+//! its location on Return.
+//!
+//! The workspace publishes each problem to its file's annotation layer as a
+//! squiggle under the text it points at, with a gutter icon
+//! ([`Problem::annotation`]).
+
+use std::path::{Component, Path, PathBuf};
+
+use lite_edit_buffer::{
+    Annotation, AnnotationSource, BufferView, Color, CursorInfo, DirtyLines, GutterIcon, Span,
+    Style, StyledLine, UnderlineStyle,
+};
+
+use crate::view_colors::{BLUE, MAUVE, RED, SELECTED_BG, YELLOW};
+
+/// The annotation source problems are published under.
+pub const PROBLEMS_SOURCE: AnnotationSource = "problems";
+
+/// The gutter icon beside a line with a problem, in the severity's color.
+const GUTTER_GLYPH: char = '●';
+
+// =============================================================================
+// Problems
+// =============================================================================
+
+/// How serious a problem is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Severity {
+    /// Parses the severity word a compiler prints before the message.
+    fn parse(word: &str) -> Option<Self> {
+        match word {
+            "error" | "fatal error" => Some(Severity::Error),
+            "warning" => Some(Severity::Warning),
+            "note" => Some(Severity::Note),
+            _ => None,
+        }
+    }
+
+    /// Returns the severity as shown in the problems list.
+    pub fn label(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+        }
+    }
+
+    /// Returns the color of the severity's label and squiggle.
+    pub fn color(self) -> Color {
+        match self {
+            Severity::Error => RED,
+            Severity::Warning => YELLOW,
+            Severity::Note => BLUE,
+        }
+    }
+}
+
+/// A diagnostic reported by a compiler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// The file, resolved against the directory the command ran in.
+    pub path: PathBuf,
+    /// 1-based line number, as reported.
+    pub line: usize,
+    /// 1-based column, as reported.
+    pub column: usize,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Problem {
+    /// Returns the 0-based buffer position the problem points at.
+    pub fn position(&self) -> (usize, usize) {
+        (self.line.saturating_sub(1), self.column.saturating_sub(1))
+    }
+
+    // Chunk: docs/chunks/buffer_annotations - Problems as buffer annotations
+    /// Returns the problem's mark in its file: a squiggle in the severity's
+    /// color under the word it points at, and a dot in the gutter.
+    pub fn annotation(&self) -> Annotation {
+        let (line, col) = self.position();
+        let color = self.severity.color();
+        let icon = GutterIcon {
+            glyph: GUTTER_GLYPH,
+            color,
+        };
+        Annotation::word_at(line, col, UnderlineStyle::Curly, Some(color)).with_gutter(icon)
+    }
+}
+
+/// Parses the compiler diagnostics in `lines`, the output of a command run
+/// in `cwd`. Repeated diagnostics are reported once.
+pub fn parse_problems<'a>(lines: impl IntoIterator<Item = &'a str>, cwd: &Path) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = Vec::new();
+    // A rustc header waiting for its ` --> ` location line
+    let mut pending: Option<(Severity, String)> = None;
+
+    for line in lines {
+        let line = line.trim_end();
+        let problem = if let Some(location) = line.trim_start().strip_prefix("--> ") {
+            pending.take().and_then(|(severity, message)| {
+                let (path, line, column, _) = split_location(location)?;
+                Some(Problem {
+                    path: resolve(cwd, path),
+                    line,
+                    column,
+                    severity,
+                    message,
+                })
+            })
+        } else if let Some(header) = rustc_header(line) {
+            pending = Some(header);
+            None
+        } else {
+            compiler_line(line, cwd)
+        };
+
+        if let Some(problem) = problem {
+            if !problems.contains(&problem) {
+                problems.push(problem);
+            }
+        }
+    }
+    problems
+}
+
+/// Parses the problems in the text of `view`, such as a terminal whose
+/// command ran in `cwd`.
+// Chunk: docs/chunks/styled_lines_batch - Read the whole view in one batch
+pub fn problems_in_view(view: &dyn BufferView, cwd: &Path) -> Vec<Problem> {
+    let lines: Vec<String> = view
+        .styled_lines(0..view.line_count())
+        .iter()
+        .map(|styled| styled.spans.iter().map(|span| span.text.as_str()).collect())
+        .collect();
+    parse_problems(lines.iter().map(String::as_str), cwd)
+}
+
+/// Parses a rustc diagnostic header: `error[E0308]: message` or `warning: message`.
+fn rustc_header(line: &str) -> Option<(Severity, String)> {
+    let (head, message) = line.split_once(": ")?;
+    let word = head.split_once('[').map_or(head, |(word, _)| word);
+    match word {
+        "error" | "warning" => Some((Severity::parse(word)?, message.to_string())),
+        _ => None,
+    }
+}
+
+/// Parses a gcc, clang or tsc diagnostic that fits on one line.
+fn compiler_line(line: &str, cwd: &Path) -> Option<Problem> {
+    let (path, line_number, column, rest) =
+        split_location(line).or_else(|| split_tsc_location(line))?;
+    // clang: `: error: message`; tsc: ` - error TS2322: message` or `: error TS2322: message`
+    let rest = rest
+        .strip_prefix(": ")
+        .or_else(|| rest.strip_prefix(" - "))?;
+    let (severity, message) = ["fatal error", "error", "warning", "note"]
+        .iter()
+        .find_map(|word| {
+            let message = rest.strip_prefix(word)?;
+            let message = message
+                .strip_prefix(": ")
+                .or_else(|| message.strip_prefix(' ').filter(|m| m.starts_with("TS")))?;
+            Some((Severity::parse(word)?, message))
+        })?;
+    Some(Problem {
+        path: resolve(cwd, path),
+        line: line_number,
+        column,
+        severity,
+        message: message.to_string(),
+    })
+}
+
+/// Splits `path:line:col<rest>` into its parts.
+fn split_location(text: &str) -> Option<(&str, usize, usize, &str)> {
+    for (colon, _) in text.match_indices(':').filter(|&(i, _)| i > 0) {
+        let after = &text[colon + 1..];
+        let Some((line, after)) = leading_number(after) else {
+            continue;
+        };
+        let Some((column, rest)) = after.strip_prefix(':').and_then(leading_number) else {
+            continue;
+        };
+        return Some((&text[..colon], line, column, rest));
+    }
+    None
+}
+
+/// Splits tsc's `path(line,col)<rest>` into its parts.
+fn split_tsc_location(text: &str) -> Option<(&str, usize, usize, &str)> {
+    let open = text.find('(').filter(|&i| i > 0)?;
+    let (line, after) = leading_number(&text[open + 1..])?;
+    let (column, after) = leading_number(after.strip_prefix(',')?)?;
+    Some((&text[..open], line, column, after.strip_prefix(')')?))
+}
+
+/// Splits a run of digits off the front of `text`.
+fn leading_number(text: &str) -> Option<(usize, &str)> {
+    let end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    Some((text[..end].parse().ok()?, &text[end..]))
+}
+
+/// Joins a reported path to the command's directory, dropping `./` parts.
+fn resolve(cwd: &Path, path: &str) -> PathBuf {
+    cwd.join(path)
+        .components()
+        .filter(|c| *c != Component::CurDir)
+        .collect()
+}
+
+// =============================================================================
+// Problems list
+// =============================================================================
+
+/// One row of the problems list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    /// A file's heading, with the index of its first problem
+    File(usize),
+    /// A problem, by index
+    Problem(usize),
+    /// The gap between two files
+    Blank,
+}
+
+/// The problems list shown in a problems tab, grouped by file.
+#[derive(Debug, Clone)]
+pub struct ProblemsBuffer {
+    /// The problems, sorted by file, then position.
+    problems: Vec<Problem>,
+    /// Paths are shown relative to this directory.
+    root: PathBuf,
+    rows: Vec<Row>,
+    /// The selected row, always a problem row when there are problems.
+    selected: usize,
+    dirty: DirtyLines,
+}
+
+impl ProblemsBuffer {
+    /// Creates a list of `problems`, showing paths relative to `root`.
+    pub fn new(problems: Vec<Problem>, root: PathBuf) -> Self {
+        let mut buffer = Self {
+            problems: Vec::new(),
+            root,
+            rows: Vec::new(),
+            selected: 0,
+            dirty: DirtyLines::None,
+        };
+        buffer.set_problems(problems);
+        buffer
+    }
+
+    /// Replaces the listed problems and selects the first one.
+    pub fn set_problems(&mut self, mut problems: Vec<Problem>) {
+        problems.sort_by(|a, b| {
+            (&a.path, a.line, a.column, a.severity).cmp(&(&b.path, b.line, b.column, b.severity))
+        });
+        let mut rows = Vec::new();
+        for (i, problem) in problems.iter().enumerate() {
+            if i == 0 || problems[i - 1].path != problem.path {
+                if i > 0 {
+                    rows.push(Row::Blank);
+                }
+                rows.push(Row::File(i));
+            }
+            rows.push(Row::Problem(i));
+        }
+        self.problems = problems;
+        self.rows = rows;
+        let first_problem = self.problem_rows().next().unwrap_or(0);
+        self.selected = first_problem;
+        self.dirty = DirtyLines::FromLineToEnd(0);
+    }
+
+    /// Returns the problems, sorted by file, then position.
+    pub fn problems(&self) -> &[Problem] {
+        &self.problems
+    }
+
+    /// Returns the selected row.
+    pub fn selected_row(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns the selected problem, if there are any.
+    pub fn selected_problem(&self) -> Option<&Problem> {
+        match self.rows.get(self.selected)? {
+            Row::Problem(i) => self.problems.get(*i),
+            _ => None,
+        }
+    }
+
+    /// Moves the selection `delta` problems down (or up, if negative),
+    /// stopping at the first and last problem.
+    pub fn move_selection(&mut self, delta: isize) {
+        let rows: Vec<usize> = self.problem_rows().collect();
+        let Some(current) = rows.iter().position(|&row| row == self.selected) else {
+            return;
+        };
+        let target = (current as isize + delta).clamp(0, rows.len() as isize - 1) as usize;
+        if rows[target] != self.selected {
+            self.dirty.merge(DirtyLines::Single(self.selected));
+            self.selected = rows[target];
+            self.dirty.merge(DirtyLines::Single(self.selected));
+        }
+    }
+
+    /// Returns a summary such as "2 errors, 1 warning".
+    pub fn summary(&self) -> String {
+        let count = |severity| {
+            self.problems
+                .iter()
+                .filter(|p| p.severity == severity)
+                .count()
+        };
+        let plural =
+            |n: usize, word: &str| format!("{} {}{}", n, word, if n == 1 { "" } else { "s" });
+        if self.problems.is_empty() {
+            return "No problems".to_string();
+        }
+        let mut parts = vec![
+            plural(count(Severity::Error), "error"),
+            plural(count(Severity::Warning), "warning"),
+        ];
+        let notes = count(Severity::Note);
+        if notes > 0 {
+            parts.push(plural(notes, "note"));
+        }
+        parts.join(", ")
+    }
+
+    fn problem_rows(&self) -> impl Iterator<Item = usize> + '_ {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| matches!(row, Row::Problem(_)))
+            .map(|(i, _)| i)
+    }
+
+    fn render_row(&self, row: usize) -> Option<StyledLine> {
+        if self.problems.is_empty() {
+            return (row == 0).then(|| StyledLine::plain("No problems"));
+        }
+        let line = match *self.rows.get(row)? {
+            Row::Blank => StyledLine::empty(),
+            Row::File(first) => {
+                let path = &self.problems[first].path;
+                let count = self.problems[first..]
+                    .iter()
+                    .take_while(|p| &p.path == path)
+                    .count();
+                let shown = path.strip_prefix(&self.root).unwrap_or(path);
+                let file_style = Style {
+                    fg: MAUVE,
+                    bold: true,
+                    ..Style::default()
+                };
+                let count_style = Style {
+                    dim: true,
+                    ..Style::default()
+                };
+                StyledLine::new(vec![
+                    Span::new(shown.display().to_string(), file_style),
+                    Span::new(format!(" ({})", count), count_style),
+                ])
+            }
+            Row::Problem(i) => {
+                let problem = &self.problems[i];
+                let location_style = Style {
+                    dim: true,
+                    ..Style::default()
+                };
+                let severity_style = Style {
+                    fg: problem.severity.color(),
+                    ..Style::default()
+                };
+                let mut spans = vec![
+                    Span::new(
+                        format!("  {:>9}  ", format!("{}:{}", problem.line, problem.column)),
+                        location_style,
+                    ),
+                    Span::new(format!("{:<8}", problem.severity.label()), severity_style),
+                    Span::plain(problem.message.clone()),
+                ];
+                if row == self.selected {
+                    for span in &mut spans {
+                        span.style.bg = SELECTED_BG;
+                    }
+                }
+                StyledLine::new(spans)
+            }
+        };
+        Some(line)
+    }
+}
+
+impl BufferView for ProblemsBuffer {
+    fn line_count(&self) -> usize {
+        self.rows.len().max(1)
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        self.render_row(line)
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.render_row(line)
+            .map(|row| row.char_count())
+            .unwrap_or(0)
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        None
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &StyledLine) -> String {
+        line.spans.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_parse_rustc_diagnostics() {
+        let output = "\
+   Compiling app v0.1.0 (/work/app)
+error[E0308]: mismatched types
+ --> src/main.rs:4:18
+  |
+4 |     let x: u32 = \"a\";
+  |            ---   ^^^ expected `u32`, found `&str`
+warning: unused import: `std::fmt`
+ --> ./src/lib.rs:1:5
+error: could not compile `app` (bin \"app\") due to 1 previous error
+";
+        let problems = parse_problems(output.lines(), Path::new("/work/app"));
+        assert_eq!(
+            problems,
+            vec![
+                Problem {
+                    path: PathBuf::from("/work/app/src/main.rs"),
+                    line: 4,
+                    column: 18,
+                    severity: Severity::Error,
+                    message: "mismatched types".to_string(),
+                },
+                Problem {
+                    path: PathBuf::from("/work/app/src/lib.rs"),
+                    line: 1,
+                    column: 5,
+                    severity: Severity::Warning,
+                    message: "unused import: `std::fmt`".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_clang_and_tsc_diagnostics() {
+        let output = "\
+main.c:3:10: fatal error: 'foo.h' file not found
+/abs/util.c:12:1: warning: control reaches end of non-void function
+src/app.ts(7,3): error TS2322: Type 'string' is not assignable to type 'number'.
+src/app.ts:9:1 - error TS1005: ';' expected.
+main.c:3:10: fatal error: 'foo.h' file not found
+Makefile:4: recipe for target 'all' failed
+";
+        let problems = parse_problems(output.lines(), Path::new("/work"));
+        let summary: Vec<(String, usize, usize, Severity, &str)> = problems
+            .iter()
+            .map(|p| {
+                let path = p.path.display().to_string();
+                (path, p.line, p.column, p.severity, p.message.as_str())
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "/work/main.c".to_string(),
+                    3,
+                    10,
+                    Severity::Error,
+                    "'foo.h' file not found"
+                ),
+                (
+                    "/abs/util.c".to_string(),
+                    12,
+                    1,
+                    Severity::Warning,
+                    "control reaches end of non-void function"
+                ),
+                (
+                    "/work/src/app.ts".to_string(),
+                    7,
+                    3,
+                    Severity::Error,
+                    "TS2322: Type 'string' is not assignable to type 'number'."
+                ),
+                (
+                    "/work/src/app.ts".to_string(),
+                    9,
+                    1,
+                    Severity::Error,
+                    "TS1005: ';' expected."
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_problem_annotation_points_at_word() {
+        let problem = Problem {
+            path: PathBuf::from("/a.rs"),
+            line: 3,
+            column: 7,
+            severity: Severity::Warning,
+            message: String::new(),
+        };
+        let annotation = problem.annotation();
+        assert_eq!((annotation.line, annotation.start_col), (2, 6));
+        assert_eq!(annotation.end_col, annotation.start_col);
+        assert_eq!(annotation.underline, UnderlineStyle::Curly);
+        assert_eq!(annotation.color, Some(YELLOW));
+        assert_eq!(annotation.gutter.map(|icon| icon.color), Some(YELLOW));
+    }
+
+    #[test]
+    fn test_problems_buffer_groups_by_file() {
+        let problem = |path: &str, line, severity| Problem {
+            path: PathBuf::from(path),
+            line,
+            column: 1,
+            severity,
+            message: format!("at {}", line),
+        };
+        let mut buffer = ProblemsBuffer::new(
+            vec![
+                problem("/w/b.rs", 3, Severity::Warning),
+                problem("/w/a.rs", 9, Severity::Error),
+                problem("/w/a.rs", 2, Severity::Error),
+            ],
+            PathBuf::from("/w"),
+        );
+
+        let rows: Vec<String> = (0..buffer.line_count())
+            .map(|i| text(&buffer.styled_line(i).unwrap()))
+            .collect();
+        assert_eq!(rows[0], "a.rs (2)");
+        assert!(rows[1].contains("2:1") && rows[1].ends_with("at 2"));
+        assert!(rows[2].ends_with("at 9"));
+        assert_eq!(rows[3], "");
+        assert_eq!(rows[4], "b.rs (1)");
+        assert_eq!(buffer.summary(), "2 errors, 1 warning");
+
+        assert_eq!(buffer.selected_problem().unwrap().line, 2);
+        buffer.move_selection(1);
+        buffer.move_selection(1);
+        assert_eq!(buffer.selected_row(), 5);
+        assert_eq!(
+            buffer.selected_problem().unwrap().path,
+            PathBuf::from("/w/b.rs")
+        );
+        buffer.move_selection(5);
+        assert_eq!(buffer.selected_row(), 5);
+    }
+
+    #[test]
+    fn test_empty_problems_buffer() {
+        let buffer = ProblemsBuffer::new(Vec::new(), PathBuf::from("/w"));
+        assert_eq!(buffer.line_count(), 1);
+        assert_eq!(text(&buffer.styled_line(0).unwrap()), "No problems");
+        assert!(buffer.selected_problem().is_none());
+        assert_eq!(buffer.summary(), "No problems");
+    }
+}
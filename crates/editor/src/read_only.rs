@@ -0,0 +1,88 @@
+// Chunk: docs/chunks/read_only_preview_tabs - Files that open read-only
+//!
+//! Deciding which files open read-only.
+//!
+//! Generated files (lock files, and files whose first lines say they were
+//! generated) and files outside the workspace open in read-only tabs, so a
+//! stray keystroke can't change something the user didn't mean to edit. The
+//! user can still make such a tab editable with "Toggle Read-Only".
+
+use std::path::Path;
+
+/// File names of lock files, which package managers rewrite wholesale.
+const LOCK_FILES: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "Gemfile.lock",
+    "poetry.lock",
+    "composer.lock",
+    "flake.lock",
+    "go.sum",
+];
+
+/// Markers that code generators put near the top of their output, matched
+/// case-insensitively.
+const GENERATED_MARKERS: &[&str] = &["@generated", "do not edit", "code generated", "auto-generated"];
+
+/// How many lines from the top of a file are searched for a marker.
+const MARKER_LINES: usize = 5;
+
+/// Returns true if `path`, with contents `content`, is a generated file.
+pub fn is_generated_file(path: &Path, content: &str) -> bool {
+    let is_lock_file = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| LOCK_FILES.contains(&name));
+    is_lock_file
+        || content.lines().take(MARKER_LINES).any(|line| {
+            let line = line.to_lowercase();
+            GENERATED_MARKERS.iter().any(|marker| line.contains(marker))
+        })
+}
+
+/// Returns true if a file opened from `path` should be read-only in a
+/// workspace rooted at `root`.
+pub fn opens_read_only(path: &Path, content: &str, root: &Path) -> bool {
+    !path.starts_with(root) || is_generated_file(path, content)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_files_are_generated() {
+        assert!(is_generated_file(Path::new("/p/Cargo.lock"), "# This file is automatically"));
+        assert!(is_generated_file(Path::new("/p/web/package-lock.json"), "{}"));
+        assert!(!is_generated_file(Path::new("/p/Cargo.toml"), "[package]\n"));
+    }
+
+    #[test]
+    fn test_generated_markers_near_the_top() {
+        let path = Path::new("/p/src/api.rs");
+        assert!(is_generated_file(path, "// @generated by protoc\nfn a() {}\n"));
+        assert!(is_generated_file(
+            Path::new("/p/api.pb.go"),
+            "// Code generated by protoc-gen-go. DO NOT EDIT.\npackage api\n"
+        ));
+        assert!(!is_generated_file(path, "fn a() {}\n"));
+
+        // A marker further down is just a mention
+        let mention = format!("{}// do not edit this by hand\n", "fn a() {}\n".repeat(10));
+        assert!(!is_generated_file(path, &mention));
+    }
+
+    #[test]
+    fn test_files_outside_the_workspace_open_read_only() {
+        let root = Path::new("/work/project");
+        assert!(!opens_read_only(Path::new("/work/project/src/main.rs"), "", root));
+        assert!(opens_read_only(Path::new("/usr/include/stdio.h"), "", root));
+        assert!(opens_read_only(Path::new("/work/project/Cargo.lock"), "", root));
+    }
+}
@@ -0,0 +1,225 @@
+// Chunk: docs/chunks/welcome_recents - Recently opened workspaces and files
+//!
+//! Recently opened workspaces and files.
+//!
+//! The editor remembers the workspace directories and files opened most
+//! recently, across launches. The welcome screen lists them so a click
+//! reopens one, and a launch without a session starts in the most recent
+//! workspace instead of asking for a directory.
+//!
+//! Both lists are most recent first, hold no duplicates, and keep at most
+//! `MAX_RECENTS` entries. Entries whose path no longer exists are dropped
+//! when the lists are loaded.
+//!
+//! ## File Location
+//!
+//! The lists are stored next to the session file, at
+//! `~/Library/Application Support/lite-edit/recents.json`. They are written
+//! at quit and, when they changed, by the idle session autosave.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::session;
+
+/// Most entries kept in each list.
+pub const MAX_RECENTS: usize = 10;
+
+/// Recents file name.
+const RECENTS_FILENAME: &str = "recents.json";
+
+/// The recently opened workspaces and files, most recent first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Recents {
+    /// Workspace root directories.
+    pub workspaces: Vec<PathBuf>,
+    /// Files opened in any workspace.
+    pub files: Vec<PathBuf>,
+    /// True when the lists changed since the last `take_changed`.
+    #[serde(skip)]
+    changed: bool,
+}
+
+impl Recents {
+    /// Creates empty lists.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves `root` to the front of the workspace list.
+    pub fn note_workspace(&mut self, root: &Path) {
+        self.changed |= note(&mut self.workspaces, root);
+    }
+
+    /// Moves `path` to the front of the file list.
+    pub fn note_file(&mut self, path: &Path) {
+        self.changed |= note(&mut self.files, path);
+    }
+
+    /// Adds the entries of `older` after the current ones, for lists noted
+    /// before the saved ones were loaded.
+    pub fn append_older(&mut self, older: Recents) {
+        for path in older.workspaces {
+            if !self.workspaces.contains(&path) {
+                self.workspaces.push(path);
+            }
+        }
+        for path in older.files {
+            if !self.files.contains(&path) {
+                self.files.push(path);
+            }
+        }
+        self.workspaces.truncate(MAX_RECENTS);
+        self.files.truncate(MAX_RECENTS);
+    }
+
+    /// Drops entries whose path no longer exists.
+    pub fn retain_existing(&mut self) {
+        self.workspaces.retain(|path| path.is_dir());
+        self.files.retain(|path| path.is_file());
+    }
+
+    /// Returns true if the lists changed since the last call.
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+}
+
+/// Moves `path` to the front of `list`, returning true if the list changed.
+fn note(list: &mut Vec<PathBuf>, path: &Path) -> bool {
+    if list.first().map(PathBuf::as_path) == Some(path) {
+        return false;
+    }
+    list.retain(|p| p != path);
+    list.insert(0, path.to_path_buf());
+    list.truncate(MAX_RECENTS);
+    true
+}
+
+/// Returns the path to the recents file, next to the session file.
+pub fn recents_file_path() -> Option<PathBuf> {
+    Some(session::session_file_path()?.with_file_name(RECENTS_FILENAME))
+}
+
+/// Loads the recents from disk, dropping entries that no longer exist.
+///
+/// A missing or unreadable file gives empty lists.
+pub fn load_recents() -> Recents {
+    let Some(path) = recents_file_path() else {
+        return Recents::new();
+    };
+    let mut recents = read_recents_file(&path).unwrap_or_default();
+    recents.retain_existing();
+    recents
+}
+
+/// Saves the recents to disk.
+pub fn save_recents(recents: &Recents) -> io::Result<()> {
+    let path = recents_file_path().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "Could not determine recents file path",
+        )
+    })?;
+    write_recents_file(&path, recents)
+}
+
+/// Reads recents from `path`, or `None` if it is missing or malformed.
+fn read_recents_file(path: &Path) -> Option<Recents> {
+    let contents = fs::read_to_string(path).ok()?;
+    match serde_json::from_str(&contents) {
+        Ok(recents) => Some(recents),
+        Err(e) => {
+            eprintln!("Failed to parse recents file: {}", e);
+            None
+        }
+    }
+}
+
+/// Writes recents to `path` atomically (temp file, then rename).
+fn write_recents_file(path: &Path, recents: &Recents) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(recents)?;
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, json)?;
+    fs::rename(&temp_path, path)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_note_moves_entry_to_front() {
+        let mut recents = Recents::new();
+        recents.note_workspace(Path::new("/a"));
+        recents.note_workspace(Path::new("/b"));
+        recents.note_workspace(Path::new("/a"));
+        assert_eq!(
+            recents.workspaces,
+            vec![PathBuf::from("/a"), PathBuf::from("/b")]
+        );
+        assert!(recents.take_changed());
+
+        // Noting the most recent entry again changes nothing
+        recents.note_workspace(Path::new("/a"));
+        assert!(!recents.take_changed());
+    }
+
+    #[test]
+    fn test_lists_are_capped() {
+        let mut recents = Recents::new();
+        for i in 0..MAX_RECENTS + 3 {
+            recents.note_file(&PathBuf::from(format!("/f{}", i)));
+        }
+        assert_eq!(recents.files.len(), MAX_RECENTS);
+        assert_eq!(
+            recents.files[0],
+            PathBuf::from(format!("/f{}", MAX_RECENTS + 2))
+        );
+    }
+
+    #[test]
+    fn test_append_older_keeps_newer_first() {
+        let mut recents = Recents::new();
+        recents.note_workspace(Path::new("/new"));
+        let mut older = Recents::new();
+        older.note_workspace(Path::new("/oldest"));
+        older.note_workspace(Path::new("/old"));
+        older.note_workspace(Path::new("/new"));
+
+        recents.append_older(older);
+        let expected: Vec<PathBuf> = ["/new", "/old", "/oldest"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        assert_eq!(recents.workspaces, expected);
+    }
+
+    #[test]
+    fn test_recents_file_round_trip_drops_missing_paths() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let file = dir.path().join("notes.md");
+        fs::write(&file, "notes").unwrap();
+        let mut recents = Recents::new();
+        recents.note_workspace(dir.path());
+        recents.note_workspace(Path::new("/no/such/dir"));
+        recents.note_file(&file);
+
+        let path = dir.path().join(RECENTS_FILENAME);
+        write_recents_file(&path, &recents).unwrap();
+        let mut loaded = read_recents_file(&path).unwrap();
+        loaded.retain_existing();
+        assert_eq!(loaded.workspaces, vec![dir.path().to_path_buf()]);
+        assert_eq!(loaded.files, vec![file]);
+        assert!(!loaded.take_changed());
+
+        assert!(read_recents_file(&dir.path().join("missing.json")).is_none());
+    }
+}
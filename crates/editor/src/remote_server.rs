@@ -0,0 +1,151 @@
+// Chunk: docs/chunks/cli_launcher - Socket server for the `lite` command
+//!
+//! Listens for requests from the `lite` command.
+//!
+//! A background thread accepts connections on the socket at
+//! [`lite_edit_cli::socket_path`], reads one request from each and sends it
+//! to the drain loop as a `RemoteRequest` event together with the connection,
+//! which the drain loop replies on. Requests are read on the thread so that a
+//! slow or stuck client can't hold up the event loop.
+
+use std::fs;
+use std::io::{self, BufRead, BufReader};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use lite_edit_cli::Request;
+
+use crate::event_channel::EventSender;
+
+/// How long a client has to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Starts listening on `path` and forwarding requests to `sender`.
+///
+/// A socket left behind by an instance that exited is replaced. Fails with
+/// `AddrInUse` if another instance is listening, so that it keeps
+/// receiving the requests.
+pub fn start_remote_server(path: &Path, sender: EventSender) -> io::Result<()> {
+    if UnixStream::connect(path).is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            "another lite-edit is listening",
+        ));
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    // Nothing answered, so any socket file is stale
+    let _ = fs::remove_file(path);
+    let listener = UnixListener::bind(path)?;
+
+    thread::Builder::new()
+        .name("lite-remote".to_string())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else {
+                    continue;
+                };
+                if let Some(request) = read_request(&stream) {
+                    if sender.send_remote_request(request, stream).is_err() {
+                        // The drain loop is gone; the app is exiting
+                        return;
+                    }
+                }
+            }
+        })?;
+    Ok(())
+}
+
+/// Reads one request, up to its terminating blank line.
+///
+/// Returns `None` if the client sends something malformed, stalls, or
+/// disconnects first; dropping the connection then tells it so.
+fn read_request(stream: &UnixStream) -> Option<Request> {
+    stream.set_read_timeout(Some(READ_TIMEOUT)).ok()?;
+    let mut lines = Vec::new();
+    for line in BufReader::new(stream).lines() {
+        let line = line.ok()?;
+        if line.is_empty() {
+            return Request::decode(lines.iter().map(String::as_str));
+        }
+        lines.push(line);
+    }
+    None
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::path::PathBuf;
+    use std::time::Instant;
+
+    use lite_edit_cli::Location;
+
+    use crate::editor_event::EditorEvent;
+    use crate::event_channel::create_event_channel;
+
+    #[test]
+    fn test_request_is_forwarded_with_its_connection() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("lite.sock");
+        let (sender, receiver) = create_event_channel(|| {});
+        start_remote_server(&path, sender.clone()).unwrap();
+
+        // A second instance doesn't take the socket over
+        let err = start_remote_server(&path, sender).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AddrInUse);
+
+        let mut client = UnixStream::connect(&path).unwrap();
+        client
+            .write_all(b"wait\nopen\t/project/main.rs\t3\t\n\n")
+            .unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let event = loop {
+            if let Some(event) = receiver.try_recv() {
+                break event;
+            }
+            assert!(Instant::now() < deadline, "no request arrived");
+            thread::sleep(Duration::from_millis(10));
+        };
+        match event {
+            EditorEvent::RemoteRequest { request, mut reply } => {
+                assert!(request.wait);
+                assert_eq!(
+                    request.locations,
+                    vec![Location {
+                        path: PathBuf::from("/project/main.rs"),
+                        line: Some(3),
+                        column: None,
+                    }]
+                );
+                writeln!(reply, "opened").unwrap();
+            }
+            _ => panic!("Expected RemoteRequest event"),
+        }
+
+        let mut reply = String::new();
+        BufReader::new(client).read_line(&mut reply).unwrap();
+        assert_eq!(reply, "opened\n");
+    }
+
+    #[test]
+    fn test_stale_socket_is_replaced() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("lite.sock");
+        drop(UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+
+        let (sender, _receiver) = create_event_channel(|| {});
+        start_remote_server(&path, sender).unwrap();
+        assert!(UnixStream::connect(&path).is_ok());
+    }
+}
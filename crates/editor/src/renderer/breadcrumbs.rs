@@ -0,0 +1,97 @@
+// Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb strip rendering
+
+//! Breadcrumb strip rendering implementation.
+//!
+//! This module draws the one-line breadcrumb strip along the top of a pane's
+//! content area. The text comes from `EditorState::breadcrumb_strips` by way
+//! of `Renderer::set_breadcrumbs`; the strip reuses the status bar's glyph
+//! buffer type, since both are a background quad with a line of text.
+
+use std::ptr::NonNull;
+
+use objc2::runtime::ProtocolObject;
+use objc2_metal::{MTLIndexType, MTLPrimitiveType, MTLRenderCommandEncoder};
+
+use crate::glyph_buffer::GlyphLayout;
+use crate::pane_layout::PaneId;
+use crate::selector_overlay::{calculate_breadcrumb_geometry, StatusBarGlyphBuffer};
+
+use super::constants::Uniforms;
+use super::Renderer;
+
+impl Renderer {
+    /// Draws `pane_id`'s breadcrumb strip, if it has one, along the top of
+    /// `content_rect` (x, y, width, height).
+    ///
+    /// The caller sets the scissor rect; the strip draws over the first row
+    /// of content.
+    pub(super) fn draw_breadcrumbs(
+        &mut self,
+        encoder: &ProtocolObject<dyn MTLRenderCommandEncoder>,
+        pane_id: PaneId,
+        content_rect: (f32, f32, f32, f32),
+        view_width: f32,
+        view_height: f32,
+    ) {
+        let Some(text) = self
+            .breadcrumbs
+            .iter()
+            .find(|(id, _)| *id == pane_id)
+            .map(|(_, text)| text.clone())
+        else {
+            return;
+        };
+        let line_height = self.font.metrics.line_height as f32;
+        let glyph_width = self.font.metrics.advance_width as f32;
+        let (x, y, width, _) = content_rect;
+        let geometry = calculate_breadcrumb_geometry(x, y, width, line_height, glyph_width);
+
+        if self.breadcrumb_buffer.is_none() {
+            let layout = GlyphLayout::from_metrics(&self.font.metrics);
+            self.breadcrumb_buffer = Some(StatusBarGlyphBuffer::new(layout));
+        }
+        let breadcrumb_buffer = self.breadcrumb_buffer.as_mut().unwrap();
+        breadcrumb_buffer.update(&self.device, &self.atlas, &text, &geometry);
+
+        let (Some(vertex_buffer), Some(index_buffer)) = (
+            breadcrumb_buffer.vertex_buffer(),
+            breadcrumb_buffer.index_buffer(),
+        ) else {
+            return;
+        };
+
+        encoder.setRenderPipelineState(self.pipeline.pipeline_state());
+        unsafe {
+            encoder.setVertexBuffer_offset_atIndex(Some(vertex_buffer), 0, 0);
+        }
+        let uniforms = Uniforms {
+            viewport_size: [view_width, view_height],
+        };
+        let uniforms_ptr =
+            NonNull::new(&uniforms as *const Uniforms as *mut std::ffi::c_void).unwrap();
+        unsafe {
+            encoder.setVertexBytes_length_atIndex(uniforms_ptr, std::mem::size_of::<Uniforms>(), 1);
+            encoder.setFragmentTexture_atIndex(Some(self.atlas.texture()), 0);
+        }
+
+        // Background, then text
+        for range in [
+            breadcrumb_buffer.background_range(),
+            breadcrumb_buffer.text_range(),
+        ] {
+            if range.is_empty() {
+                continue;
+            }
+            let index_offset = range.start * std::mem::size_of::<u32>();
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    index_offset,
+                );
+            }
+        }
+    }
+}
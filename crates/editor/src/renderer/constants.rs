@@ -66,6 +66,70 @@ pub(super) const FOCUSED_PANE_BORDER_COLOR: [f32; 4] = [
     0.6,   // 60% opacity
 ];
 
+// Chunk: docs/chunks/tab_drag_drop - Drop target highlight color
+/// Tab drop highlight color: #89b4fa at 20% (Catppuccin Mocha blue)
+/// Fills the pane, or pane half, a dragged tab would be dropped into.
+pub(super) const TAB_DROP_HIGHLIGHT_COLOR: [f32; 4] = [
+    0.537, // 0x89 / 255
+    0.706, // 0xb4 / 255
+    0.980, // 0xfa / 255
+    0.2,   // 20% opacity
+];
+
+// Chunk: docs/chunks/dim_inactive_panes - Unfocused pane dimming color
+/// Unfocused pane dimming color: #11111b at 35% (Catppuccin Mocha crust)
+/// Laid over every pane but the focused one, pulling it toward the background.
+pub(super) const INACTIVE_PANE_DIM_COLOR: [f32; 4] = [
+    0.067, // 0x11 / 255
+    0.067, // 0x11 / 255
+    0.106, // 0x1b / 255
+    0.35,  // 35% opacity
+];
+
+// Chunk: docs/chunks/pane_scrollbar - Scrollbar thumb color
+/// Scrollbar thumb color: #9399b2 at 50% (Catppuccin Mocha overlay2)
+/// Multiplied by the thumb's fade opacity.
+pub(super) const SCROLLBAR_THUMB_COLOR: [f32; 4] = [
+    0.576, // 0x93 / 255
+    0.600, // 0x99 / 255
+    0.698, // 0xb2 / 255
+    0.5,   // 50% opacity
+];
+
+// Chunk: docs/chunks/find_scrollbar_ticks - Find-match tick color
+/// Find-match tick color: #f9e2af (Catppuccin Mocha yellow)
+pub(super) const SCROLLBAR_MATCH_TICK_COLOR: [f32; 4] = [
+    0.976, // 0xf9 / 255
+    0.886, // 0xe2 / 255
+    0.686, // 0xaf / 255
+    1.0,
+];
+
+// Chunk: docs/chunks/terminal_shell_integration - Prompt mark colors
+/// Mark for a prompt whose command succeeded: #a6e3a1 (Catppuccin Mocha green)
+pub(super) const PROMPT_MARK_SUCCEEDED_COLOR: [f32; 4] = [
+    0.651, // 0xa6 / 255
+    0.890, // 0xe3 / 255
+    0.631, // 0xa1 / 255
+    1.0,
+];
+
+/// Mark for a prompt whose command failed: #f38ba8 (Catppuccin Mocha red)
+pub(super) const PROMPT_MARK_FAILED_COLOR: [f32; 4] = [
+    0.953, // 0xf3 / 255
+    0.545, // 0x8b / 255
+    0.659, // 0xa8 / 255
+    1.0,
+];
+
+/// Mark for a prompt with no finished command yet: #9399b2 (Catppuccin Mocha overlay2)
+pub(super) const PROMPT_MARK_PENDING_COLOR: [f32; 4] = [
+    0.576, // 0x93 / 255
+    0.600, // 0x99 / 255
+    0.698, // 0xb2 / 255
+    1.0,
+];
+
 // =============================================================================
 // Uniforms
 // =============================================================================
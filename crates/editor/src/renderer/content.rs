@@ -46,7 +46,9 @@ impl Renderer {
         // Create wrap layout for current content width (viewport - RAIL_WIDTH).
         // Using content_width_px ensures the same cols_per_row value is computed
         // here as in wrap_layout(), which is used for click hit-testing.
-        let wrap_layout = WrapLayout::new(self.content_width_px, &self.font.metrics);
+        // Chunk: docs/chunks/file_view_mode - Unwrapped views are cut off at the pane's edge
+        let wrap_width = if view.wraps_lines() { self.content_width_px } else { f32::MAX };
+        let wrap_layout = WrapLayout::new(wrap_width, &self.font.metrics);
 
         // Use wrap-aware rendering with mutable atlas for on-demand glyph addition
         self.glyph_buffer.update_from_buffer_with_wrap(
@@ -59,6 +61,20 @@ impl Renderer {
             cursor_visible,
             y_offset,
         );
+        // Chunk: docs/chunks/render_stats_hud - Glyph quad count
+        #[cfg(feature = "perf-instrumentation")]
+        {
+            self.frame_quad_count += self.glyph_buffer.index_count() / 6;
+        }
+    }
+
+    // Chunk: docs/chunks/ime_dead_key_composition - Focused cursor cell for IME placement
+    /// Records the cursor cell from the glyph buffer just updated for the
+    /// focused pane. Frames where the cursor blinks off keep the previous cell.
+    pub(super) fn remember_text_input_cursor(&mut self) {
+        if let Some(cell) = self.glyph_buffer.cursor_cell() {
+            self.text_input_cursor = Some(cell);
+        }
     }
 
     /// Sets the text content to display
@@ -127,7 +143,23 @@ impl Renderer {
 
         // Chunk: docs/chunks/renderer_styled_content - Per-vertex colors, no per-draw uniforms needed
         // With per-vertex colors, we draw all quads in a single pass with no uniform changes.
-        // Draw order: background → selection → glyphs → underlines → cursor
+        // Draw order: decorations → background → selection → glyphs → color glyphs → underlines → cursor
+
+        // ==================== Draw Decoration Quads ====================
+        // Chunk: docs/chunks/line_highlight_rulers - Current line highlight and rulers beneath text
+        let decoration_range = self.glyph_buffer.decoration_range();
+        if !decoration_range.is_empty() {
+            let index_offset = decoration_range.start * std::mem::size_of::<u32>();
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    decoration_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    index_offset,
+                );
+            }
+        }
 
         // ==================== Draw Background Quads ====================
         let background_range = self.glyph_buffer.background_range();
@@ -144,6 +176,38 @@ impl Renderer {
             }
         }
 
+        // ==================== Draw Match Quads ====================
+        // Chunk: docs/chunks/find_match_highlights - Every find match beneath the selection
+        let match_range = self.glyph_buffer.match_range();
+        if !match_range.is_empty() {
+            let index_offset = match_range.start * std::mem::size_of::<u32>();
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    match_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    index_offset,
+                );
+            }
+        }
+
+        // ==================== Draw Occurrence Quads ====================
+        // Chunk: docs/chunks/selection_occurrences - Other occurrences of the selected word
+        let occurrence_range = self.glyph_buffer.occurrence_range();
+        if !occurrence_range.is_empty() {
+            let index_offset = occurrence_range.start * std::mem::size_of::<u32>();
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    occurrence_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    index_offset,
+                );
+            }
+        }
+
         // ==================== Draw Selection Quads ====================
         let selection_range = self.glyph_buffer.selection_range();
         if !selection_range.is_empty() {
@@ -202,6 +266,28 @@ impl Renderer {
             }
         }
 
+        // ==================== Draw Color Glyph Quads ====================
+        // Chunk: docs/chunks/color_emoji_wide_glyphs - Emoji sampled from the RGBA atlas page
+        // The image pipeline keeps texel colors; the text pipeline is restored
+        // afterwards for the remaining solid-color quads.
+        let color_glyph_range = self.glyph_buffer.color_glyph_range();
+        if !color_glyph_range.is_empty() {
+            encoder.setRenderPipelineState(self.image_pipeline.pipeline_state());
+            let index_offset = color_glyph_range.start * std::mem::size_of::<u32>();
+            unsafe {
+                encoder.setFragmentTexture_atIndex(Some(self.atlas.color_texture()), 0);
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    color_glyph_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    index_offset,
+                );
+                encoder.setFragmentTexture_atIndex(Some(self.atlas.texture()), 0);
+            }
+            encoder.setRenderPipelineState(self.pipeline.pipeline_state());
+        }
+
         // ==================== Draw Underline Quads ====================
         let underline_range = self.glyph_buffer.underline_range();
         if !underline_range.is_empty() {
@@ -0,0 +1,266 @@
+// Chunk: docs/chunks/partial_redraw - Retained frame and damaged-pane redraw
+
+//! Partial redraw over the retained previous frame.
+//!
+//! Editor frames are rendered into an offscreen texture that outlives the
+//! drawable, then copied into the drawable before presenting. When
+//! `DamageTracker` reports that only some panes changed, just those panes
+//! are cleared and drawn again over that texture; everything else on screen
+//! is the previous frame's pixels.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2_metal::{
+    MTLBlitCommandEncoder, MTLCommandBuffer, MTLCommandEncoder, MTLDevice, MTLDrawable,
+    MTLRenderCommandEncoder, MTLStorageMode, MTLTexture, MTLTextureDescriptor, MTLTextureUsage,
+};
+use objc2_quartz_core::CAMetalDrawable;
+
+use crate::frame_damage::{FrameLayout, PaneFrame};
+use crate::left_rail::RAIL_WIDTH;
+use crate::metal_view::MetalView;
+use crate::pane_layout::{PaneId, PaneRect};
+use crate::selector_overlay::{FindStripState, StatusBarState};
+use crate::workspace::Editor;
+
+use super::constants::BACKGROUND_COLOR;
+use super::scissor::{full_viewport_scissor_rect, pane_scissor_rect};
+use super::Renderer;
+
+/// The clear color as an opaque quad color.
+fn background_rgba() -> [f32; 4] {
+    [
+        BACKGROUND_COLOR.red as f32,
+        BACKGROUND_COLOR.green as f32,
+        BACKGROUND_COLOR.blue as f32,
+        BACKGROUND_COLOR.alpha as f32,
+    ]
+}
+
+/// Returns true if the top-left corner of `rect` lies inside `pane`.
+fn starts_in_pane((x, y, _, _): (f32, f32, f32, f32), pane: &PaneRect) -> bool {
+    x >= pane.x && x < pane.x + pane.width && y >= pane.y && y < pane.y + pane.height
+}
+
+fn hash_rect(hasher: &mut DefaultHasher, (x, y, width, height): (f32, f32, f32, f32)) {
+    for value in [x, y, width, height] {
+        value.to_bits().hash(hasher);
+    }
+}
+
+impl Renderer {
+    /// Returns the offscreen texture frames are rendered into, recreating it
+    /// when the drawable's size or format changed.
+    ///
+    /// A new texture holds nothing, so the damage tracker is reset and the
+    /// next frame is drawn in full. Returns `None` if the texture can't be
+    /// created; the frame is then drawn straight into the drawable.
+    pub(super) fn frame_texture_for(
+        &mut self,
+        drawable_texture: &ProtocolObject<dyn MTLTexture>,
+    ) -> Option<Retained<ProtocolObject<dyn MTLTexture>>> {
+        let (width, height) = (drawable_texture.width(), drawable_texture.height());
+        let pixel_format = drawable_texture.pixelFormat();
+        let reusable = self.frame_texture.as_ref().is_some_and(|texture| {
+            texture.width() == width
+                && texture.height() == height
+                && texture.pixelFormat() == pixel_format
+        });
+
+        if !reusable {
+            self.damage_tracker.reset();
+            let descriptor = unsafe {
+                MTLTextureDescriptor::texture2DDescriptorWithPixelFormat_width_height_mipmapped(
+                    pixel_format,
+                    width,
+                    height,
+                    false,
+                )
+            };
+            descriptor.setUsage(MTLTextureUsage::RenderTarget);
+            descriptor.setStorageMode(MTLStorageMode::Private);
+            self.frame_texture = self.device.newTextureWithDescriptor(&descriptor);
+        }
+        self.frame_texture.clone()
+    }
+
+    /// Describes the frame about to be drawn, for the damage tracker.
+    ///
+    /// Each pane's chrome fingerprint covers the scrollbar thumbs, prompt
+    /// marks and match ticks that start inside it and its breadcrumbs; the
+    /// focused pane's also covers the find strip or status bar drawn in it.
+    pub(super) fn frame_layout(
+        &self,
+        pane_rects: &[PaneRect],
+        focused_pane_id: PaneId,
+        selector_active: bool,
+        find_strip: Option<&FindStripState<'_>>,
+        status_bar: Option<&StatusBarState<'_>>,
+    ) -> FrameLayout {
+        let panes = pane_rects
+            .iter()
+            .map(|pane| {
+                let mut hasher = DefaultHasher::new();
+                for thumb in self
+                    .scrollbars
+                    .iter()
+                    .filter(|t| starts_in_pane(t.rect, pane))
+                {
+                    hash_rect(&mut hasher, thumb.rect);
+                    thumb.opacity.to_bits().hash(&mut hasher);
+                }
+                for &(rect, status) in &self.prompt_marks {
+                    if starts_in_pane(rect, pane) {
+                        hash_rect(&mut hasher, rect);
+                        (status as u8).hash(&mut hasher);
+                    }
+                }
+                for &tick in self
+                    .match_ticks
+                    .iter()
+                    .filter(|&&t| starts_in_pane(t, pane))
+                {
+                    hash_rect(&mut hasher, tick);
+                }
+                for (_, text) in self
+                    .breadcrumbs
+                    .iter()
+                    .filter(|(id, _)| *id == pane.pane_id)
+                {
+                    text.hash(&mut hasher);
+                }
+                if pane.pane_id == focused_pane_id {
+                    if let Some(find) = find_strip {
+                        find.query.hash(&mut hasher);
+                        find.cursor_col.hash(&mut hasher);
+                        find.cursor_visible.hash(&mut hasher);
+                        find.options.case_sensitive.hash(&mut hasher);
+                        find.options.whole_word.hash(&mut hasher);
+                    } else if let Some(status) = status_bar {
+                        status.text.hash(&mut hasher);
+                    }
+                }
+                PaneFrame {
+                    pane_id: pane.pane_id,
+                    rect: (pane.x, pane.y, pane.width, pane.height),
+                    chrome: hasher.finish(),
+                }
+            })
+            .collect();
+
+        FrameLayout {
+            panes,
+            focused_pane_id,
+            overlay: selector_active
+                || self.tab_drop_highlight.is_some()
+                || self.tab_reorder_slot.is_some(),
+        }
+    }
+
+    /// Redraws the left rail and the `damaged` panes over the previous frame.
+    ///
+    /// Each damaged pane is clipped to its rect, filled with the background
+    /// color and drawn the way the full multi-pane path draws it: content,
+    /// scrollbars, the find strip or status bar if it is focused, then the
+    /// pane frames.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn render_damaged_panes(
+        &mut self,
+        encoder: &ProtocolObject<dyn MTLRenderCommandEncoder>,
+        view: &MetalView,
+        editor: &Editor,
+        pane_rects: &[PaneRect],
+        damaged: &[PaneId],
+        focused_pane_id: PaneId,
+        find_strip: Option<&FindStripState<'_>>,
+        status_bar: Option<&StatusBarState<'_>>,
+        view_width: f32,
+        view_height: f32,
+    ) {
+        let Some(ws) = editor.active_workspace() else {
+            return;
+        };
+
+        // The rail is cheap and changes with workspace state the panes don't
+        // report, so it is redrawn with every frame
+        encoder.setScissorRect(full_viewport_scissor_rect(view_width, view_height));
+        self.draw_solid_rect(
+            encoder,
+            view,
+            (0.0, 0.0, RAIL_WIDTH, view_height),
+            background_rgba(),
+        );
+        self.draw_left_rail(encoder, view, editor);
+
+        for pane_rect in pane_rects.iter().filter(|r| damaged.contains(&r.pane_id)) {
+            let pane_scissor = pane_scissor_rect(pane_rect, view_width, view_height);
+            let bounds = (pane_rect.x, pane_rect.y, pane_rect.width, pane_rect.height);
+            encoder.setScissorRect(pane_scissor);
+            self.draw_solid_rect(encoder, view, bounds, background_rgba());
+
+            self.render_pane(encoder, view, ws, pane_rect, view_width, view_height);
+
+            encoder.setScissorRect(pane_scissor);
+            self.draw_scrollbars(encoder, view);
+
+            if pane_rect.pane_id == focused_pane_id {
+                self.draw_strip_in_pane(
+                    encoder,
+                    view,
+                    find_strip,
+                    status_bar,
+                    pane_rect,
+                    view_width,
+                    view_height,
+                );
+            }
+
+            encoder.setScissorRect(pane_scissor);
+            self.draw_pane_frames(encoder, view, pane_rects, focused_pane_id);
+        }
+    }
+
+    /// Copies the finished frame texture into the drawable's texture.
+    fn copy_frame_to_drawable(
+        &self,
+        command_buffer: &ProtocolObject<dyn MTLCommandBuffer>,
+        frame_texture: &ProtocolObject<dyn MTLTexture>,
+        drawable_texture: &ProtocolObject<dyn MTLTexture>,
+    ) {
+        let Some(blit) = command_buffer.blitCommandEncoder() else {
+            eprintln!("Failed to create blit command encoder");
+            return;
+        };
+        unsafe {
+            blit.copyFromTexture_toTexture(frame_texture, drawable_texture);
+        }
+        blit.endEncoding();
+    }
+
+    /// Copies the frame into `drawable` (unless it was drawn there directly),
+    /// draws the render stats HUD over it, then presents and commits.
+    pub(super) fn present_frame(
+        &mut self,
+        command_buffer: &ProtocolObject<dyn MTLCommandBuffer>,
+        drawable: &ProtocolObject<dyn CAMetalDrawable>,
+        frame_texture: Option<&ProtocolObject<dyn MTLTexture>>,
+    ) {
+        let drawable_texture = drawable.texture();
+        if let Some(frame_texture) = frame_texture {
+            self.copy_frame_to_drawable(command_buffer, frame_texture, &drawable_texture);
+        }
+
+        // Chunk: docs/chunks/render_stats_hud - HUD over the presented frame
+        // The HUD goes into the drawable, not the retained frame, so its
+        // pixels never survive into a partial redraw
+        #[cfg(feature = "perf-instrumentation")]
+        self.draw_perf_hud(command_buffer, &drawable_texture);
+
+        let mtl_drawable: &ProtocolObject<dyn MTLDrawable> = ProtocolObject::from_ref(drawable);
+        command_buffer.presentDrawable(mtl_drawable);
+        command_buffer.commit();
+    }
+}
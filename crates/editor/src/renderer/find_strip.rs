@@ -12,6 +12,7 @@ use objc2_metal::{
     MTLIndexType, MTLPrimitiveType, MTLRenderCommandEncoder, MTLScissorRect,
 };
 
+use crate::find_target::FindOptions;
 use crate::glyph_buffer::GlyphLayout;
 use crate::metal_view::MetalView;
 use crate::pane_layout::PaneRect;
@@ -45,6 +46,7 @@ impl Renderer {
     /// * `query` - The find query text
     /// * `cursor_col` - The cursor column position in the query
     /// * `cursor_visible` - Whether to render the cursor
+    /// * `options` - Matching options, shown as badges
     // Chunk: docs/chunks/find_in_file - Find strip rendering
     pub(super) fn draw_find_strip(
         &mut self,
@@ -53,6 +55,7 @@ impl Renderer {
         query: &str,
         cursor_col: usize,
         cursor_visible: bool,
+        options: FindOptions,
     ) {
         let frame = view.frame();
         let scale = view.scale_factor();
@@ -84,6 +87,7 @@ impl Renderer {
             query,
             &geometry,
             cursor_visible,
+            options,
         );
 
         // Get buffers
@@ -182,6 +186,21 @@ impl Renderer {
                 );
             }
         }
+
+        // Chunk: docs/chunks/find_options - Draw option badges
+        let badge_range = find_strip_buffer.badge_range();
+        if !badge_range.is_empty() {
+            let index_offset = badge_range.start * std::mem::size_of::<u32>();
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    badge_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    index_offset,
+                );
+            }
+        }
     }
 
     // Chunk: docs/chunks/find_strip_multi_pane - Pane-constrained find strip rendering
@@ -197,6 +216,7 @@ impl Renderer {
     /// * `query` - The find query text
     /// * `cursor_col` - The cursor column position in the query
     /// * `cursor_visible` - Whether to render the cursor
+    /// * `options` - Matching options, shown as badges
     /// * `pane_rect` - The bounds of the pane to render within
     /// * `view_width` - Full viewport width (for uniforms)
     /// * `view_height` - Full viewport height (for uniforms)
@@ -207,6 +227,7 @@ impl Renderer {
         query: &str,
         cursor_col: usize,
         cursor_visible: bool,
+        options: FindOptions,
         pane_rect: &PaneRect,
         view_width: f32,
         view_height: f32,
@@ -248,6 +269,7 @@ impl Renderer {
             query,
             &geometry,
             cursor_visible,
+            options,
         );
 
         // Get buffers
@@ -346,5 +368,20 @@ impl Renderer {
                 );
             }
         }
+
+        // Chunk: docs/chunks/find_options - Draw option badges
+        let badge_range = find_strip_buffer.badge_range();
+        if !badge_range.is_empty() {
+            let index_offset = badge_range.start * std::mem::size_of::<u32>();
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    badge_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    index_offset,
+                );
+            }
+        }
     }
 }
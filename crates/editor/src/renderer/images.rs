@@ -0,0 +1,177 @@
+// Chunk: docs/chunks/terminal_kitty_graphics - Terminal image compositing
+
+//! Terminal image rendering.
+//!
+//! Images placed by programs through the Kitty graphics protocol are drawn as
+//! textured quads over a terminal pane's text. Each image is uploaded once to
+//! an RGBA texture and cached by its key; a texture is released once the
+//! terminal no longer holds the image.
+
+use std::ptr::NonNull;
+use std::sync::{Arc, Weak};
+
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2_metal::{
+    MTLDevice, MTLPixelFormat, MTLPrimitiveType, MTLRegion, MTLRenderCommandEncoder, MTLTexture,
+    MTLTextureDescriptor,
+};
+
+use crate::glyph_buffer::GlyphVertex;
+use crate::metal_view::MetalView;
+use crate::workspace::{Tab, Workspace};
+use lite_edit_terminal::{TerminalBuffer, TerminalImage};
+
+use super::constants::Uniforms;
+use super::Renderer;
+
+/// A GPU texture holding one terminal image's pixels.
+pub(super) struct ImageTexture {
+    /// The image the texture was uploaded from, used to detect when it's gone.
+    image: Weak<TerminalImage>,
+    /// The uploaded RGBA texture.
+    texture: Retained<ProtocolObject<dyn MTLTexture>>,
+}
+
+/// Returns the terminal backing `tab`, if it is a terminal or agent tab.
+pub(super) fn tab_terminal<'a>(workspace: &'a Workspace, tab: &'a Tab) -> Option<&'a TerminalBuffer> {
+    if tab.is_agent_tab() {
+        workspace.agent_terminal()
+    } else {
+        tab.as_terminal_buffer()
+    }
+}
+
+impl Renderer {
+    /// Draws the terminal's images that are visible in the current viewport.
+    ///
+    /// Must be called after the pane's viewport and content offsets have been
+    /// configured (i.e. after the pane's text has been rendered), so images
+    /// line up with the terminal's rows.
+    pub(super) fn draw_terminal_images(
+        &mut self,
+        encoder: &ProtocolObject<dyn MTLRenderCommandEncoder>,
+        view: &MetalView,
+        terminal: &TerminalBuffer,
+    ) {
+        // Release textures for images the terminal has dropped
+        self.image_textures
+            .retain(|_, cached| cached.image.strong_count() > 0);
+
+        let first_visible = self.viewport.first_visible_line();
+        let visible_end = first_visible + self.viewport.visible_lines() + 1;
+        let placements = terminal.image_placements(first_visible..visible_end);
+        if placements.is_empty() {
+            return;
+        }
+
+        encoder.setRenderPipelineState(self.image_pipeline.pipeline_state());
+
+        let frame = view.frame();
+        let scale = view.scale_factor();
+        let uniforms = Uniforms {
+            viewport_size: [
+                (frame.size.width * scale) as f32,
+                (frame.size.height * scale) as f32,
+            ],
+        };
+        let uniforms_ptr =
+            NonNull::new(&uniforms as *const Uniforms as *mut std::ffi::c_void).unwrap();
+        unsafe {
+            encoder.setVertexBytes_length_atIndex(
+                uniforms_ptr,
+                std::mem::size_of::<Uniforms>(),
+                1,
+            );
+        }
+
+        let cell_width = self.font.metrics.advance_width as f32;
+        let line_height = self.viewport.line_height();
+        let origin_x = self.glyph_buffer.x_offset();
+        let origin_y = self.glyph_buffer.y_offset() - self.viewport.scroll_fraction_px();
+        let color = [1.0, 1.0, 1.0, 1.0];
+
+        for placement in &placements {
+            let Some(texture) = self.image_texture(&placement.image) else {
+                continue;
+            };
+
+            let x = origin_x + placement.col as f32 * cell_width;
+            let y = origin_y + (placement.line as f32 - first_visible as f32) * line_height;
+            let (w, h) = if placement.stretch {
+                (placement.cols as f32 * cell_width, placement.rows as f32 * line_height)
+            } else {
+                (placement.image.width as f32, placement.image.height as f32)
+            };
+
+            // Triangle strip order: top-left, top-right, bottom-left, bottom-right
+            let vertices = [
+                GlyphVertex::new(x, y, 0.0, 0.0, color),
+                GlyphVertex::new(x + w, y, 1.0, 0.0, color),
+                GlyphVertex::new(x, y + h, 0.0, 1.0, color),
+                GlyphVertex::new(x + w, y + h, 1.0, 1.0, color),
+            ];
+            let vertices_ptr =
+                NonNull::new(vertices.as_ptr() as *mut std::ffi::c_void).unwrap();
+
+            unsafe {
+                encoder.setVertexBytes_length_atIndex(
+                    vertices_ptr,
+                    std::mem::size_of_val(&vertices),
+                    0,
+                );
+                encoder.setFragmentTexture_atIndex(Some(&texture), 0);
+                encoder.drawPrimitives_vertexStart_vertexCount(
+                    MTLPrimitiveType::TriangleStrip,
+                    0,
+                    vertices.len(),
+                );
+            }
+        }
+    }
+
+    /// Returns the texture for `image`, uploading it on first use.
+    fn image_texture(
+        &mut self,
+        image: &Arc<TerminalImage>,
+    ) -> Option<Retained<ProtocolObject<dyn MTLTexture>>> {
+        if let Some(cached) = self.image_textures.get(&image.key()) {
+            return Some(cached.texture.clone());
+        }
+
+        let (width, height) = (image.width as usize, image.height as usize);
+        let descriptor = unsafe {
+            MTLTextureDescriptor::texture2DDescriptorWithPixelFormat_width_height_mipmapped(
+                MTLPixelFormat::RGBA8Unorm,
+                width,
+                height,
+                false,
+            )
+        };
+        let texture = self.device.newTextureWithDescriptor(&descriptor)?;
+
+        let region = MTLRegion {
+            origin: objc2_metal::MTLOrigin { x: 0, y: 0, z: 0 },
+            size: objc2_metal::MTLSize {
+                width,
+                height,
+                depth: 1,
+            },
+        };
+        let bytes_ptr = NonNull::new(image.rgba.as_ptr() as *mut std::ffi::c_void)?;
+
+        // SAFETY: rgba holds width * height * 4 bytes of RGBA pixel data
+        unsafe {
+            texture.replaceRegion_mipmapLevel_withBytes_bytesPerRow(region, 0, bytes_ptr, width * 4);
+        }
+
+        self.image_textures.insert(
+            image.key(),
+            ImageTexture {
+                image: Arc::downgrade(image),
+                texture: texture.clone(),
+            },
+        );
+        Some(texture)
+    }
+}
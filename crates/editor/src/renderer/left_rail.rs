@@ -189,5 +189,20 @@ impl Renderer {
                 );
             }
         }
+
+        // Draw bell badges on top of the identicons (per-vertex colors)
+        // Chunk: docs/chunks/terminal_bell_attention - Bell badges
+        let bell_range = left_rail_buffer.bell_badge_range();
+        if !bell_range.is_empty() {
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    bell_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    bell_range.start * std::mem::size_of::<u32>(),
+                );
+            }
+        }
     }
 }
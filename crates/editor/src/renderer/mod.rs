@@ -29,16 +29,23 @@
 //! - `constants` - Color constants and uniform types
 //! - `scissor` - Scissor rect helper functions
 //! - `content` - Text buffer content rendering
+//! - `damage` - Partial redraw of damaged panes over the retained frame
 //! - `tab_bar` - Tab bar rendering (global and per-pane)
 //! - `left_rail` - Left rail (workspace tiles) rendering
 //! - `overlay` - Selector and confirm dialog overlays
 //! - `find_strip` - Find-in-file strip rendering
+//! - `images` - Terminal image (Kitty graphics) compositing
 //! - `panes` - Multi-pane layout rendering
 //! - `welcome` - Welcome screen rendering
 
+// Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb strip rendering
+mod breadcrumbs;
 mod constants;
 mod content;
+// Chunk: docs/chunks/partial_redraw - Retained frame and damaged-pane redraw
+mod damage;
 mod find_strip;
+mod images;
 mod left_rail;
 mod overlay;
 mod panes;
@@ -50,22 +57,27 @@ mod welcome;
 use objc2::rc::Retained;
 use objc2::runtime::ProtocolObject;
 use objc2_metal::{
-    MTLCommandBuffer, MTLCommandEncoder, MTLCommandQueue, MTLDevice, MTLDrawable,
-    MTLLoadAction, MTLRenderCommandEncoder, MTLRenderPassDescriptor, MTLStoreAction,
+    MTLCommandBuffer, MTLCommandEncoder, MTLCommandQueue, MTLDevice, MTLDrawable, MTLLoadAction,
+    MTLRenderCommandEncoder, MTLRenderPassDescriptor, MTLStoreAction, MTLTexture,
 };
 use objc2_quartz_core::CAMetalDrawable;
 
 // Subsystem: docs/subsystems/renderer - GPU-accelerated text and UI rendering
 use crate::confirm_dialog::{ConfirmDialog, ConfirmDialogGlyphBuffer};
-use crate::dirty_region::DirtyRegion;
-use crate::font::Font;
+// Chunk: docs/chunks/conflict_markers - Conflict blocks of the rendered file
+use crate::conflict_markers::find_conflicts;
+use crate::dirty_region::{DirtyRegion, InvalidationKind};
+use crate::font::{Font, FontZoom};
+// Chunk: docs/chunks/partial_redraw - Frame damage tracking
+use crate::frame_damage::{DamageTracker, FrameDamage};
 use crate::glyph_atlas::GlyphAtlas;
-use crate::glyph_buffer::GlyphBuffer;
+use crate::glyph_buffer::{GlyphBuffer, LineDecorations};
 use crate::highlighted_buffer::HighlightedBufferView;
 use crate::left_rail::{LeftRailGlyphBuffer, RAIL_WIDTH};
 use crate::metal_view::MetalView;
 use crate::pane_frame_buffer::PaneFrameBuffer;
-use crate::pane_layout::{calculate_pane_rects, PaneId, PaneRect};
+use crate::pane_layout::{PaneId, PaneRect};
+use crate::scrollbar::ScrollbarThumb;
 use crate::selector::SelectorWidget;
 // Chunk: docs/chunks/renderer_styled_content - Per-vertex colors, overlay colors now in vertices
 // Chunk: docs/chunks/find_in_file - Find strip rendering
@@ -78,10 +90,12 @@ use crate::shader::GlyphPipeline;
 // Chunk: docs/chunks/content_tab_bar - Content tab bar rendering
 use crate::tab_bar::{TabBarGlyphBuffer, TAB_BAR_HEIGHT};
 use crate::viewport::Viewport;
+use crate::welcome_screen::WelcomeRecents;
 use crate::workspace::Editor;
 use crate::wrap_layout::WrapLayout;
 // Chunk: docs/chunks/renderer_polymorphic_buffer - Import BufferView for polymorphic rendering
-use lite_edit_buffer::DirtyLines;
+use lite_edit_buffer::{AnnotatedView, Annotations, DirtyLines, Position};
+use lite_edit_terminal::CommandStatus;
 
 use constants::BACKGROUND_COLOR;
 use scissor::{buffer_content_scissor_rect, full_viewport_scissor_rect};
@@ -97,12 +111,26 @@ pub struct Renderer {
     command_queue: Retained<ProtocolObject<dyn MTLCommandQueue>>,
     /// The font used for text rendering
     font: Font,
+    // Chunk: docs/chunks/font_config_zoom - Font settings for live rebuilds
+    /// Installed font family in use, or `None` for the bundled Intel One Mono
+    font_family: Option<String>,
+    /// Current font size in points (changed by zoom)
+    font_size: f64,
+    /// Font size from the user's config, restored by Cmd+0
+    configured_font_size: f64,
+    /// Display scale factor the font is rasterized at
+    scale_factor: f64,
     /// The glyph atlas containing rasterized characters
     atlas: GlyphAtlas,
     /// The glyph vertex buffer manager
     glyph_buffer: GlyphBuffer,
     /// The compiled shader pipeline
     pipeline: GlyphPipeline,
+    // Chunk: docs/chunks/terminal_kitty_graphics - Terminal image compositing
+    /// The pipeline for drawing RGBA image textures (terminal graphics)
+    image_pipeline: GlyphPipeline,
+    /// Uploaded terminal image textures, keyed by `TerminalImage::key`
+    image_textures: std::collections::HashMap<u64, images::ImageTexture>,
     /// The device reference for buffer creation
     device: Retained<ProtocolObject<dyn MTLDevice>>,
     /// The viewport for buffer-to-screen coordinate mapping
@@ -124,9 +152,17 @@ pub struct Renderer {
     // Chunk: docs/chunks/gotodef_status_render - Status bar rendering
     /// The glyph buffer for status bar rendering (lazy-initialized)
     status_bar_buffer: Option<StatusBarGlyphBuffer>,
+    // Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb strip rendering
+    /// The glyph buffer for breadcrumb strips, shared by every pane
+    /// (lazy-initialized)
+    breadcrumb_buffer: Option<StatusBarGlyphBuffer>,
     // Chunk: docs/chunks/welcome_screen - Welcome screen rendering
     /// The glyph buffer for welcome screen rendering (lazy-initialized)
     welcome_screen_buffer: Option<crate::welcome_screen::WelcomeScreenGlyphBuffer>,
+    // Chunk: docs/chunks/welcome_recents - Recent lists for the welcome screen
+    /// The recent workspaces and files listed on the welcome screen, taken
+    /// from the editor at the start of each frame
+    welcome_recents: WelcomeRecents,
     // Chunk: docs/chunks/tiling_multi_pane_render - Pane frame rendering
     /// The buffer for pane dividers and focus borders (lazy-initialized)
     pane_frame_buffer: Option<PaneFrameBuffer>,
@@ -148,8 +184,48 @@ pub struct Renderer {
     cached_pane_rects: Vec<PaneRect>,
     /// Focused pane ID from the last layout calculation.
     cached_focused_pane_id: PaneId,
+    // Chunk: docs/chunks/pane_zoom - Cached zoom state
+    /// Zoomed pane ID from the last layout calculation.
+    cached_zoomed_pane_id: Option<PaneId>,
     /// Whether the cached pane rects are valid (false until first layout)
     pane_rects_valid: bool,
+    // Chunk: docs/chunks/tab_drag_drop - Drop target highlight
+    /// Area under a dragged tab to highlight, in screen space
+    tab_drop_highlight: Option<(f32, f32, f32, f32)>,
+    // Chunk: docs/chunks/tab_reorder_drag - Drop gap in the tab bar
+    /// Pane and slot where a tab dragged along its tab bar would be dropped
+    tab_reorder_slot: Option<(PaneId, usize)>,
+    // Chunk: docs/chunks/dim_inactive_panes - Dim unfocused panes
+    /// Whether panes other than the focused one are dimmed
+    dim_inactive_panes: bool,
+    // Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb strip rendering
+    /// Breadcrumb text to draw along the top of each pane that has some
+    breadcrumbs: Vec<(PaneId, String)>,
+    // Chunk: docs/chunks/pane_scrollbar - Overlay scrollbar thumbs
+    /// Scrollbar thumbs to draw over the panes, in screen space
+    scrollbars: Vec<ScrollbarThumb>,
+    // Chunk: docs/chunks/find_scrollbar_ticks - Find-match ticks
+    /// Find-match ticks to draw on the scrollbar column, in screen space
+    match_ticks: Vec<(f32, f32, f32, f32)>,
+    // Chunk: docs/chunks/terminal_shell_integration - Prompt marks
+    /// Terminal prompt marks to draw on the scrollbar column, in screen space
+    prompt_marks: Vec<((f32, f32, f32, f32), CommandStatus)>,
+    // Chunk: docs/chunks/find_match_highlights - Find matches in the focused pane
+    /// Find matches to highlight in the focused pane, as (start, end)
+    find_matches: Vec<(Position, Position)>,
+    // Chunk: docs/chunks/ime_dead_key_composition - Focused cursor cell for IME placement
+    /// Focused pane's cursor cell `(x, y, width, height)` in view pixels, kept
+    /// across frames where the blinking cursor is hidden
+    text_input_cursor: Option<(f32, f32, f32, f32)>,
+    // Chunk: docs/chunks/partial_redraw - Retained frame
+    /// Offscreen texture editor frames are rendered into and copied to the
+    /// drawable from, so the next frame can redraw only what changed
+    /// (lazy-initialized, recreated on resize)
+    frame_texture: Option<Retained<ProtocolObject<dyn MTLTexture>>>,
+    /// Decides which panes of the retained frame each frame redraws
+    damage_tracker: DamageTracker,
+    /// Invalidations since the last editor frame was drawn
+    frame_invalidation: InvalidationKind,
     // Chunk: docs/chunks/invalidation_separation - Perf instrumentation counters
     /// Counter for frames where layout recalculation was skipped
     #[cfg(feature = "perf-instrumentation")]
@@ -157,11 +233,42 @@ pub struct Renderer {
     /// Counter for frames where layout recalculation was performed
     #[cfg(feature = "perf-instrumentation")]
     layout_recalc_performed: usize,
+    // Chunk: docs/chunks/render_stats_hud - Render stats HUD
+    /// Glyph quads built for the panes drawn in the current frame
+    #[cfg(feature = "perf-instrumentation")]
+    frame_quad_count: usize,
+    /// Lines of the render stats HUD, or `None` when it is hidden
+    #[cfg(feature = "perf-instrumentation")]
+    perf_hud: Option<Vec<String>>,
+    /// One glyph buffer per HUD line (lazy-initialized)
+    #[cfg(feature = "perf-instrumentation")]
+    perf_hud_buffers: Vec<StatusBarGlyphBuffer>,
+}
+
+// Chunk: docs/chunks/font_config_zoom - Configured font family with bundled fallback
+/// Loads `family` if it is installed, otherwise the bundled Intel One Mono.
+///
+/// Returns the font and the family actually in use, so a missing family is
+/// reported once and not looked up again on every zoom.
+fn load_font(family: Option<String>, point_size: f64, scale_factor: f64) -> (Font, Option<String>) {
+    if let Some(family) = family {
+        if let Some(font) = Font::from_family(&family, point_size, scale_factor) {
+            return (font, Some(family));
+        }
+        eprintln!("Font {:?} is not installed; using Intel One Mono", family);
+    }
+
+    const FONT_DATA: &[u8] = include_bytes!("../../../../resources/IntelOneMono-Regular.ttf");
+    (Font::from_data(FONT_DATA, point_size, scale_factor), None)
 }
 
 impl Renderer {
     /// Creates a new renderer using the device from the given MetalView
-    pub fn new(view: &MetalView) -> Self {
+    ///
+    /// `font_family` names an installed font to use instead of the bundled
+    /// Intel One Mono; `font_size` is in points.
+    // Chunk: docs/chunks/font_config_zoom - Configurable font family and size
+    pub fn new(view: &MetalView, font_family: Option<String>, font_size: f64) -> Self {
         let device = view.device();
 
         // Create the command queue
@@ -172,9 +279,8 @@ impl Renderer {
         // Get the scale factor for proper glyph sizing
         let scale_factor = view.scale_factor();
 
-        // Load the bundled Intel One Mono font at the appropriate scale
-        const FONT_DATA: &[u8] = include_bytes!("../../../../resources/IntelOneMono-Regular.ttf");
-        let font = Font::from_data(FONT_DATA, 14.0, scale_factor);
+        // Load the configured font (or the bundled one) at the appropriate scale
+        let (font, font_family) = load_font(font_family, font_size, scale_factor);
 
         // Create the glyph atlas (pre-populates ASCII)
         let atlas = GlyphAtlas::new(device, &font);
@@ -184,6 +290,7 @@ impl Renderer {
 
         // Create the shader pipeline
         let pipeline = GlyphPipeline::new(device);
+        let image_pipeline = GlyphPipeline::new_image(device);
 
         // Clone the device for later use
         // We need to use unsafe since the MTLDevice trait doesn't have Clone
@@ -208,9 +315,15 @@ impl Renderer {
         Self {
             command_queue,
             font,
+            font_family,
+            font_size,
+            configured_font_size: font_size,
+            scale_factor,
             atlas,
             glyph_buffer,
             pipeline,
+            image_pipeline,
+            image_textures: std::collections::HashMap::new(),
             device: device_retained,
             viewport,
             // Chunk: docs/chunks/renderer_polymorphic_buffer - No longer owns buffer
@@ -220,7 +333,9 @@ impl Renderer {
             tab_bar_buffer: None,
             find_strip_buffer: None,
             status_bar_buffer: None,
+            breadcrumb_buffer: None,
             welcome_screen_buffer: None,
+            welcome_recents: WelcomeRecents::default(),
             pane_frame_buffer: None,
             confirm_dialog_buffer: None,
             viewport_width_px,
@@ -228,11 +343,30 @@ impl Renderer {
             // Chunk: docs/chunks/invalidation_separation - Initialize cached pane layout
             cached_pane_rects: Vec::new(),
             cached_focused_pane_id: 0,
+            cached_zoomed_pane_id: None,
             pane_rects_valid: false,
+            tab_drop_highlight: None,
+            tab_reorder_slot: None,
+            dim_inactive_panes: false,
+            breadcrumbs: Vec::new(),
+            scrollbars: Vec::new(),
+            match_ticks: Vec::new(),
+            prompt_marks: Vec::new(),
+            find_matches: Vec::new(),
+            text_input_cursor: None,
+            frame_texture: None,
+            damage_tracker: DamageTracker::new(),
+            frame_invalidation: InvalidationKind::None,
             #[cfg(feature = "perf-instrumentation")]
             layout_recalc_skipped: 0,
             #[cfg(feature = "perf-instrumentation")]
             layout_recalc_performed: 0,
+            #[cfg(feature = "perf-instrumentation")]
+            frame_quad_count: 0,
+            #[cfg(feature = "perf-instrumentation")]
+            perf_hud: None,
+            #[cfg(feature = "perf-instrumentation")]
+            perf_hud_buffers: Vec::new(),
         }
     }
 
@@ -254,6 +388,65 @@ impl Renderer {
         self.font.metrics
     }
 
+    // Chunk: docs/chunks/font_config_zoom - Runtime font size change
+    /// Returns the current font size in points
+    pub fn font_size(&self) -> f64 {
+        self.font_size
+    }
+
+    /// Applies a font zoom, rebuilding the font and glyph atlas at the new size.
+    ///
+    /// Returns `false` if the size didn't change (e.g. already at the limit).
+    /// On `true`, callers must propagate the new `font_metrics()` to the editor
+    /// state and re-run `update_viewport_size()`.
+    pub fn apply_font_zoom(&mut self, zoom: FontZoom) -> bool {
+        let size = zoom.apply(self.font_size, self.configured_font_size);
+        if size == self.font_size {
+            return false;
+        }
+        self.set_font_size(size);
+        true
+    }
+
+    // Chunk: docs/chunks/settings_tab - Font changed in the Settings tab
+    /// Switches to a newly configured font family and size, dropping any zoom.
+    ///
+    /// As after `apply_font_zoom`, callers must propagate the new
+    /// `font_metrics()` to the editor state and re-run `update_viewport_size()`.
+    pub fn set_configured_font(&mut self, family: Option<String>, point_size: f64) {
+        self.font_family = family;
+        self.configured_font_size = point_size;
+        self.set_font_size(point_size);
+    }
+
+    /// Rebuilds the font, glyph atlas and every font-derived layout at `point_size`.
+    fn set_font_size(&mut self, point_size: f64) {
+        let (font, font_family) = load_font(self.font_family.take(), point_size, self.scale_factor);
+        self.font = font;
+        self.font_family = font_family;
+        self.font_size = point_size;
+
+        self.atlas = GlyphAtlas::new(&self.device, &self.font);
+        self.glyph_buffer.set_font_metrics(&self.font.metrics);
+        self.glyph_buffer.clear_styled_line_cache();
+        self.viewport.set_line_height(self.font.metrics.line_height as f32);
+
+        // The overlay and chrome buffers captured the old glyph layout when
+        // they were created; drop them so they're recreated at the new size.
+        self.selector_buffer = None;
+        self.left_rail_buffer = None;
+        self.tab_bar_buffer = None;
+        self.find_strip_buffer = None;
+        self.status_bar_buffer = None;
+        self.breadcrumb_buffer = None;
+        self.welcome_screen_buffer = None;
+        self.confirm_dialog_buffer = None;
+        #[cfg(feature = "perf-instrumentation")]
+        self.perf_hud_buffers.clear();
+        self.pane_rects_valid = false;
+        self.damage_tracker.reset();
+    }
+
     /// Returns the current viewport width in pixels
     pub fn viewport_width_px(&self) -> f32 {
         self.viewport_width_px
@@ -302,6 +495,15 @@ impl Renderer {
         self.pane_rects_valid = false;
     }
 
+    // Chunk: docs/chunks/partial_redraw - Invalidation drives frame damage
+    /// Records what changed since the last frame, so the next editor frame
+    /// can redraw only the panes it affects.
+    ///
+    /// Invalidations accumulate until a frame is drawn.
+    pub fn set_frame_invalidation(&mut self, invalidation: InvalidationKind) {
+        self.frame_invalidation.merge(invalidation);
+    }
+
     // Chunk: docs/chunks/invalidation_separation - Perf instrumentation
     /// Returns the layout skip rate (skipped / total frames).
     ///
@@ -324,11 +526,83 @@ impl Renderer {
         (self.layout_recalc_skipped, self.layout_recalc_performed)
     }
 
+    // Chunk: docs/chunks/render_stats_hud - Render stats HUD
+    /// Returns the number of glyph quads built for the panes drawn in the
+    /// last editor frame.
+    #[cfg(feature = "perf-instrumentation")]
+    pub fn frame_quad_count(&self) -> usize {
+        self.frame_quad_count
+    }
+
+    /// Sets the render stats HUD's lines, or hides it with `None`.
+    ///
+    /// The HUD is drawn over the next frame presented.
+    #[cfg(feature = "perf-instrumentation")]
+    pub fn set_perf_hud(&mut self, lines: Option<Vec<String>>) {
+        self.perf_hud = lines;
+    }
+
     /// Sets cursor visibility
     pub fn set_cursor_visible(&mut self, visible: bool) {
         self.cursor_visible = visible;
     }
 
+    // Chunk: docs/chunks/ime_dead_key_composition - Focused cursor cell for IME placement
+    /// Returns the focused pane's cursor cell `(x, y, width, height)` in view
+    /// pixels (origin at top-left), or `None` before a cursor has been drawn.
+    pub fn text_input_cursor(&self) -> Option<(f32, f32, f32, f32)> {
+        self.text_input_cursor
+    }
+
+    // Chunk: docs/chunks/tab_drag_drop - Drop target highlight
+    /// Sets the area to highlight under a dragged tab (`None` when not dragging).
+    pub fn set_tab_drop_highlight(&mut self, highlight: Option<(f32, f32, f32, f32)>) {
+        self.tab_drop_highlight = highlight;
+    }
+
+    // Chunk: docs/chunks/dim_inactive_panes - Dim unfocused panes
+    /// Sets whether panes other than the focused one are dimmed.
+    pub fn set_dim_inactive_panes(&mut self, dim: bool) {
+        self.dim_inactive_panes = dim;
+    }
+
+    // Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb strip rendering
+    /// Sets the breadcrumb text for each pane that shows a strip.
+    pub fn set_breadcrumbs(&mut self, breadcrumbs: Vec<(PaneId, String)>) {
+        self.breadcrumbs = breadcrumbs;
+    }
+
+    // Chunk: docs/chunks/tab_reorder_drag - Drop gap in the tab bar
+    /// Sets the pane and slot where a tab dragged along its tab bar would be
+    /// dropped (`None` when not reordering).
+    pub fn set_tab_reorder_slot(&mut self, slot: Option<(PaneId, usize)>) {
+        self.tab_reorder_slot = slot;
+    }
+
+    // Chunk: docs/chunks/pane_scrollbar - Overlay scrollbar thumbs
+    /// Sets the scrollbar thumbs to draw over the panes.
+    pub fn set_scrollbars(&mut self, scrollbars: Vec<ScrollbarThumb>) {
+        self.scrollbars = scrollbars;
+    }
+
+    // Chunk: docs/chunks/find_scrollbar_ticks - Find-match ticks
+    /// Sets the find-match ticks to draw on the scrollbar column.
+    pub fn set_match_ticks(&mut self, ticks: Vec<(f32, f32, f32, f32)>) {
+        self.match_ticks = ticks;
+    }
+
+    // Chunk: docs/chunks/terminal_shell_integration - Prompt marks
+    /// Sets the terminal prompt marks to draw on the scrollbar column.
+    pub fn set_prompt_marks(&mut self, marks: Vec<((f32, f32, f32, f32), CommandStatus)>) {
+        self.prompt_marks = marks;
+    }
+
+    // Chunk: docs/chunks/find_match_highlights - Find matches in the focused pane
+    /// Sets the find matches to highlight in the focused pane.
+    pub fn set_find_matches(&mut self, matches: Vec<(Position, Position)>) {
+        self.find_matches = matches;
+    }
+
     /// Takes the last styled_line timing from the glyph buffer (perf-instrumentation only).
     #[cfg(feature = "perf-instrumentation")]
     pub fn take_styled_line_timing(&mut self) -> Option<(std::time::Duration, usize)> {
@@ -353,6 +627,12 @@ impl Renderer {
         self.glyph_buffer.set_y_offset(offset);
     }
 
+    // Chunk: docs/chunks/line_highlight_rulers - Current line highlight and column rulers
+    /// Sets the current line highlight and ruler columns drawn in file buffers.
+    pub fn set_line_decorations(&mut self, decorations: LineDecorations) {
+        self.glyph_buffer.set_line_decorations(decorations);
+    }
+
     // Chunk: docs/chunks/renderer_polymorphic_buffer - Accept line_count parameter
     /// Converts buffer-space DirtyLines to screen-space DirtyRegion
     ///
@@ -611,6 +891,13 @@ impl Renderer {
         self.set_content_x_offset(RAIL_WIDTH);
         // Chunk: docs/chunks/content_tab_bar - Content area y offset for tab bar
         self.set_content_y_offset(TAB_BAR_HEIGHT);
+        // Chunk: docs/chunks/welcome_recents - Welcome screen lists the editor's recents
+        self.welcome_recents = WelcomeRecents::from_recents(&editor.recents);
+        // Chunk: docs/chunks/render_stats_hud - Count this frame's glyph quads
+        #[cfg(feature = "perf-instrumentation")]
+        {
+            self.frame_quad_count = 0;
+        }
 
         // Chunk: docs/chunks/terminal_single_pane_refresh - Glyph buffer update moved to content rendering
         // The glyph buffer update for single-pane mode is now done inside the content rendering
@@ -627,42 +914,6 @@ impl Renderer {
             }
         };
 
-        // Create a render pass descriptor
-        let render_pass_descriptor = MTLRenderPassDescriptor::new();
-
-        // Configure the color attachment
-        let color_attachments = render_pass_descriptor.colorAttachments();
-        let color_attachment = unsafe { color_attachments.objectAtIndexedSubscript(0) };
-
-        // Set the drawable's texture as the render target
-        color_attachment.setTexture(Some(drawable.texture().as_ref()));
-
-        // Clear to our background color
-        color_attachment.setLoadAction(MTLLoadAction::Clear);
-        color_attachment.setClearColor(BACKGROUND_COLOR);
-
-        // Store the result
-        color_attachment.setStoreAction(MTLStoreAction::Store);
-
-        // Create a command buffer
-        let command_buffer = match self.command_queue.commandBuffer() {
-            Some(cb) => cb,
-            None => {
-                eprintln!("Failed to create command buffer");
-                return;
-            }
-        };
-
-        // Create a render command encoder
-        let encoder =
-            match command_buffer.renderCommandEncoderWithDescriptor(&render_pass_descriptor) {
-                Some(e) => e,
-                None => {
-                    eprintln!("Failed to create render command encoder");
-                    return;
-                }
-            };
-
         // Chunk: docs/chunks/tab_bar_content_clip - Extract view dimensions for scissor rect
         // Get view dimensions early for scissor rect calculation
         let frame = view.frame();
@@ -670,9 +921,6 @@ impl Renderer {
         let view_width = (frame.size.width * scale) as f32;
         let view_height = (frame.size.height * scale) as f32;
 
-        // Render left rail first (background layer)
-        self.draw_left_rail(&encoder, view, editor);
-
         // Chunk: docs/chunks/tiling_multi_pane_render - Calculate pane rects for multi-pane rendering
         // Chunk: docs/chunks/invalidation_separation - Conditional pane rect calculation
         // Only recalculate pane rectangles when the cache is invalid (Layout invalidation)
@@ -684,8 +932,10 @@ impl Renderer {
         let focused_pane_id: PaneId;
         if let Some(ws) = editor.active_workspace() {
             // Check if we need to recalculate
+            // Chunk: docs/chunks/pane_zoom - Zoom state is part of the cache key
             let needs_recalc = !self.pane_rects_valid
-                || ws.active_pane_id != self.cached_focused_pane_id;
+                || ws.active_pane_id != self.cached_focused_pane_id
+                || ws.zoomed_pane_id() != self.cached_zoomed_pane_id;
 
             if needs_recalc {
                 // Bounds for the pane area: starts after left rail
@@ -696,8 +946,9 @@ impl Renderer {
                     view_width - RAIL_WIDTH,  // width: remaining horizontal space
                     view_height,              // height: full height
                 );
-                self.cached_pane_rects = calculate_pane_rects(bounds, &ws.pane_root);
+                self.cached_pane_rects = ws.visible_pane_rects(bounds);
                 self.cached_focused_pane_id = ws.active_pane_id;
+                self.cached_zoomed_pane_id = ws.zoomed_pane_id();
                 self.pane_rects_valid = true;
                 #[cfg(feature = "perf-instrumentation")]
                 {
@@ -718,6 +969,89 @@ impl Renderer {
             focused_pane_id = 0;
         }
 
+        // Chunk: docs/chunks/partial_redraw - Render into the retained frame
+        // The frame is drawn into an offscreen texture that keeps its pixels
+        // between frames. When only some panes changed, the texture is loaded
+        // rather than cleared and just those panes are drawn again.
+        let drawable_texture = drawable.texture();
+        let frame_texture = self.frame_texture_for(&drawable_texture);
+        let layout = self.frame_layout(
+            &pane_rects,
+            focused_pane_id,
+            selector.is_some(),
+            find_strip.as_ref(),
+            status_bar.as_ref(),
+        );
+        let invalidation = std::mem::take(&mut self.frame_invalidation);
+        let mut damage = self.damage_tracker.damage(invalidation, layout);
+        if frame_texture.is_none() {
+            self.damage_tracker.reset();
+            damage = FrameDamage::Full;
+        }
+
+        // Create a render pass descriptor
+        let render_pass_descriptor = MTLRenderPassDescriptor::new();
+
+        // Configure the color attachment
+        let color_attachments = render_pass_descriptor.colorAttachments();
+        let color_attachment = unsafe { color_attachments.objectAtIndexedSubscript(0) };
+
+        // Render into the retained frame, or the drawable if there is none
+        let target = frame_texture.as_deref().unwrap_or(&*drawable_texture);
+        color_attachment.setTexture(Some(target));
+
+        // Clear to our background color, or keep the previous frame
+        if damage.is_full() {
+            color_attachment.setLoadAction(MTLLoadAction::Clear);
+        } else {
+            color_attachment.setLoadAction(MTLLoadAction::Load);
+        }
+        color_attachment.setClearColor(BACKGROUND_COLOR);
+
+        // Store the result
+        color_attachment.setStoreAction(MTLStoreAction::Store);
+
+        // Create a command buffer
+        let command_buffer = match self.command_queue.commandBuffer() {
+            Some(cb) => cb,
+            None => {
+                eprintln!("Failed to create command buffer");
+                return;
+            }
+        };
+
+        // Create a render command encoder
+        let encoder =
+            match command_buffer.renderCommandEncoderWithDescriptor(&render_pass_descriptor) {
+                Some(e) => e,
+                None => {
+                    eprintln!("Failed to create render command encoder");
+                    return;
+                }
+            };
+
+        // Chunk: docs/chunks/partial_redraw - Redraw only the damaged panes
+        if let FrameDamage::Panes(damaged) = &damage {
+            self.render_damaged_panes(
+                &encoder,
+                view,
+                editor,
+                &pane_rects,
+                damaged,
+                focused_pane_id,
+                find_strip.as_ref(),
+                status_bar.as_ref(),
+                view_width,
+                view_height,
+            );
+            encoder.endEncoding();
+            self.present_frame(&command_buffer, &drawable, frame_texture.as_deref());
+            return;
+        }
+
+        // Render left rail first (background layer)
+        self.draw_left_rail(&encoder, view, editor);
+
         // Chunk: docs/chunks/tiling_multi_pane_render - Multi-pane or single-pane rendering
         if pane_rects.len() <= 1 {
             // Single-pane case: render as before (global tab bar, no dividers)
@@ -752,6 +1086,20 @@ impl Renderer {
                             self.clear_styled_line_cache();
                         }
 
+                        // Chunk: docs/chunks/pane_tint - Tint the content area by tab kind
+                        let content_rect = (RAIL_WIDTH, TAB_BAR_HEIGHT, content_width, content_height);
+                        self.draw_pane_tint(&encoder, view, tab, content_rect);
+
+                        // Chunk: docs/chunks/find_match_highlights - The single pane is focused
+                        self.glyph_buffer.set_match_highlights(self.find_matches.clone());
+
+                        // Chunk: docs/chunks/conflict_markers - Tint the file's conflict blocks
+                        let conflicts = tab
+                            .as_text_buffer()
+                            .map(|buffer| find_conflicts(buffer.content().lines()))
+                            .unwrap_or_default();
+                        self.glyph_buffer.set_conflicts(conflicts);
+
                         if tab.is_agent_tab() {
                             if let Some(terminal) = ws.agent_terminal() {
                                 self.update_glyph_buffer(terminal);
@@ -761,11 +1109,20 @@ impl Renderer {
                                 text_buffer,
                                 tab.highlighter(),
                             );
-                            self.update_glyph_buffer(&highlighted_view);
+                            // Chunk: docs/chunks/buffer_annotations - Underline the file's annotations
+                            let no_annotations = Annotations::new();
+                            let annotations = ws.tab_annotations(tab).unwrap_or(&no_annotations);
+                            let annotated_view = AnnotatedView::new(&highlighted_view, annotations);
+                            self.update_glyph_buffer(&annotated_view);
+                        } else if let Some(annotations) = tab.command_annotations() {
+                            // Chunk: docs/chunks/terminal_command_status - Command summaries
+                            let annotated_view = AnnotatedView::new(tab.buffer(), &annotations);
+                            self.update_glyph_buffer(&annotated_view);
                         } else {
                             // Terminal or other buffer type
                             self.update_glyph_buffer(tab.buffer());
                         }
+                        self.remember_text_input_cursor();
                     }
                 }
 
@@ -773,6 +1130,31 @@ impl Renderer {
                 if self.glyph_buffer.index_count() > 0 {
                     self.render_text(&encoder, view);
                 }
+
+                // Chunk: docs/chunks/terminal_kitty_graphics - Composite terminal images over text
+                if let Some(ws) = editor.active_workspace() {
+                    if let Some(terminal) = ws.active_tab().and_then(|tab| images::tab_terminal(ws, tab)) {
+                        self.draw_terminal_images(&encoder, view, terminal);
+                    }
+                }
+
+                // Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb strip over the content
+                let content_rect = (
+                    RAIL_WIDTH,
+                    TAB_BAR_HEIGHT,
+                    view_width - RAIL_WIDTH,
+                    view_height - TAB_BAR_HEIGHT,
+                );
+                self.draw_breadcrumbs(
+                    &encoder,
+                    focused_pane_id,
+                    content_rect,
+                    view_width,
+                    view_height,
+                );
+
+                // Chunk: docs/chunks/pane_scrollbar - Scrollbar over the content
+                self.draw_scrollbars(&encoder, view);
             }
 
             // Chunk: docs/chunks/find_strip_multi_pane - Find strip rendering in single-pane mode
@@ -787,6 +1169,7 @@ impl Renderer {
                     find_state.query,
                     find_state.cursor_col,
                     find_state.cursor_visible,
+                    find_state.options,
                 );
             } else if let Some(ref status_state) = status_bar {
                 // Chunk: docs/chunks/gotodef_status_render - Status bar rendering in single-pane mode
@@ -796,6 +1179,14 @@ impl Renderer {
                 encoder.setScissorRect(full_scissor);
                 self.draw_status_bar(&encoder, view, status_state.text);
             }
+
+            // Chunk: docs/chunks/tab_drag_drop - Drop highlight in single-pane mode
+            // A tab dragged to an edge of the only pane splits it
+            if self.tab_drop_highlight.is_some() {
+                let full_scissor = full_viewport_scissor_rect(view_width, view_height);
+                encoder.setScissorRect(full_scissor);
+                self.draw_pane_frames(&encoder, view, &pane_rects, focused_pane_id);
+            }
         } else {
             // Multi-pane case: render each pane independently
             if let Some(ws) = editor.active_workspace() {
@@ -804,36 +1195,25 @@ impl Renderer {
                 }
             }
 
+            // Chunk: docs/chunks/pane_scrollbar - Scrollbars over every pane's content
+            // Each thumb lies inside its own pane's content area.
+            let full_scissor = full_viewport_scissor_rect(view_width, view_height);
+            encoder.setScissorRect(full_scissor);
+            self.draw_scrollbars(&encoder, view);
+
             // Chunk: docs/chunks/find_strip_multi_pane - Find strip rendering in multi-pane mode
-            // Draw find strip within the focused pane's bounds
-            if let Some(ref find_state) = find_strip {
-                // Find the focused pane's rect
-                if let Some(focused_rect) = pane_rects.iter().find(|r| r.pane_id == focused_pane_id) {
-                    self.draw_find_strip_in_pane(
-                        &encoder,
-                        view,
-                        find_state.query,
-                        find_state.cursor_col,
-                        find_state.cursor_visible,
-                        focused_rect,
-                        view_width,
-                        view_height,
-                    );
-                }
-            } else if let Some(ref status_state) = status_bar {
-                // Chunk: docs/chunks/gotodef_status_render - Status bar rendering in multi-pane mode
-                // Draw status bar within the focused pane's bounds
-                // Only render if find strip is not active (find strip takes precedence)
-                if let Some(focused_rect) = pane_rects.iter().find(|r| r.pane_id == focused_pane_id) {
-                    self.draw_status_bar_in_pane(
-                        &encoder,
-                        view,
-                        status_state.text,
-                        focused_rect,
-                        view_width,
-                        view_height,
-                    );
-                }
+            // Chunk: docs/chunks/gotodef_status_render - Status bar rendering in multi-pane mode
+            // Draw the find strip (or else the status bar) within the focused pane's bounds
+            if let Some(focused_rect) = pane_rects.iter().find(|r| r.pane_id == focused_pane_id) {
+                self.draw_strip_in_pane(
+                    &encoder,
+                    view,
+                    find_strip.as_ref(),
+                    status_bar.as_ref(),
+                    focused_rect,
+                    view_width,
+                    view_height,
+                );
             }
 
             // Reset scissor to full viewport before drawing frames on top
@@ -857,12 +1237,9 @@ impl Renderer {
         // End encoding
         encoder.endEncoding();
 
-        // Present the drawable
-        let mtl_drawable: &ProtocolObject<dyn MTLDrawable> = ProtocolObject::from_ref(&*drawable);
-        command_buffer.presentDrawable(mtl_drawable);
-
-        // Commit the command buffer
-        command_buffer.commit();
+        // Chunk: docs/chunks/partial_redraw - Show the retained frame
+        // Copy the frame into the drawable, present it and commit
+        self.present_frame(&command_buffer, &drawable, frame_texture.as_deref());
     }
 
     // Chunk: docs/chunks/workspace_model - Content area offset
@@ -900,9 +1277,14 @@ impl Renderer {
         editor: &Editor,
         dialog: Option<&ConfirmDialog>,
     ) {
+        // Chunk: docs/chunks/partial_redraw - Dialog frames bypass the retained frame
+        // Start the next editor frame from scratch once the dialog is gone
+        self.damage_tracker.reset();
+
         // Set content area offset to account for left rail and tab bar
         self.set_content_x_offset(RAIL_WIDTH);
         self.set_content_y_offset(TAB_BAR_HEIGHT);
+        self.welcome_recents = WelcomeRecents::from_recents(&editor.recents);
 
         // Chunk: docs/chunks/terminal_single_pane_refresh - Glyph buffer update moved to content rendering
         // The glyph buffer update for single-pane mode is now done inside the content rendering
@@ -971,8 +1353,10 @@ impl Renderer {
         let focused_pane_id: PaneId;
         if let Some(ws) = editor.active_workspace() {
             // Check if we need to recalculate
+            // Chunk: docs/chunks/pane_zoom - Zoom state is part of the cache key
             let needs_recalc = !self.pane_rects_valid
-                || ws.active_pane_id != self.cached_focused_pane_id;
+                || ws.active_pane_id != self.cached_focused_pane_id
+                || ws.zoomed_pane_id() != self.cached_zoomed_pane_id;
 
             if needs_recalc {
                 let bounds = (
@@ -981,8 +1365,9 @@ impl Renderer {
                     view_width - RAIL_WIDTH,
                     view_height,
                 );
-                self.cached_pane_rects = calculate_pane_rects(bounds, &ws.pane_root);
+                self.cached_pane_rects = ws.visible_pane_rects(bounds);
                 self.cached_focused_pane_id = ws.active_pane_id;
+                self.cached_zoomed_pane_id = ws.zoomed_pane_id();
                 self.pane_rects_valid = true;
                 #[cfg(feature = "perf-instrumentation")]
                 {
@@ -1033,6 +1418,20 @@ impl Renderer {
                             self.clear_styled_line_cache();
                         }
 
+                        // Chunk: docs/chunks/pane_tint - Tint the content area by tab kind
+                        let content_rect = (RAIL_WIDTH, TAB_BAR_HEIGHT, content_width, content_height);
+                        self.draw_pane_tint(&encoder, view, tab, content_rect);
+
+                        // Chunk: docs/chunks/find_match_highlights - The single pane is focused
+                        self.glyph_buffer.set_match_highlights(self.find_matches.clone());
+
+                        // Chunk: docs/chunks/conflict_markers - Tint the file's conflict blocks
+                        let conflicts = tab
+                            .as_text_buffer()
+                            .map(|buffer| find_conflicts(buffer.content().lines()))
+                            .unwrap_or_default();
+                        self.glyph_buffer.set_conflicts(conflicts);
+
                         if tab.is_agent_tab() {
                             if let Some(terminal) = ws.agent_terminal() {
                                 self.update_glyph_buffer(terminal);
@@ -1042,11 +1441,20 @@ impl Renderer {
                                 text_buffer,
                                 tab.highlighter(),
                             );
-                            self.update_glyph_buffer(&highlighted_view);
+                            // Chunk: docs/chunks/buffer_annotations - Underline the file's annotations
+                            let no_annotations = Annotations::new();
+                            let annotations = ws.tab_annotations(tab).unwrap_or(&no_annotations);
+                            let annotated_view = AnnotatedView::new(&highlighted_view, annotations);
+                            self.update_glyph_buffer(&annotated_view);
+                        } else if let Some(annotations) = tab.command_annotations() {
+                            // Chunk: docs/chunks/terminal_command_status - Command summaries
+                            let annotated_view = AnnotatedView::new(tab.buffer(), &annotations);
+                            self.update_glyph_buffer(&annotated_view);
                         } else {
                             // Terminal or other buffer type
                             self.update_glyph_buffer(tab.buffer());
                         }
+                        self.remember_text_input_cursor();
                     }
                 }
 
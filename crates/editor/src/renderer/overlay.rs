@@ -5,6 +5,7 @@
 //! This module contains the methods for rendering overlay panels:
 //! - Selector overlay (file picker, command palette)
 //! - Confirm dialog
+//! - Render stats HUD (perf-instrumentation feature only)
 
 use std::ptr::NonNull;
 
@@ -12,6 +13,11 @@ use objc2::runtime::ProtocolObject;
 use objc2_metal::{
     MTLIndexType, MTLPrimitiveType, MTLRenderCommandEncoder,
 };
+#[cfg(feature = "perf-instrumentation")]
+use objc2_metal::{
+    MTLCommandBuffer, MTLCommandEncoder, MTLLoadAction, MTLRenderPassDescriptor, MTLStoreAction,
+    MTLTexture,
+};
 
 use crate::confirm_dialog::{
     calculate_confirm_dialog_geometry, ConfirmDialog, ConfirmDialogGlyphBuffer,
@@ -22,6 +28,12 @@ use crate::selector::SelectorWidget;
 use crate::selector_overlay::{
     calculate_overlay_geometry, SelectorGlyphBuffer,
 };
+#[cfg(feature = "perf-instrumentation")]
+use crate::perf_hud::hud_geometry;
+#[cfg(feature = "perf-instrumentation")]
+use crate::selector_overlay::{calculate_breadcrumb_geometry, StatusBarGlyphBuffer};
+#[cfg(feature = "perf-instrumentation")]
+use crate::tab_bar::TAB_BAR_HEIGHT;
 
 use super::constants::Uniforms;
 use super::scissor::{full_viewport_scissor_rect, selector_list_scissor_rect};
@@ -357,6 +369,21 @@ impl Renderer {
             }
         }
 
+        // Chunk: docs/chunks/quit_running_summary - Draw alternate button background
+        let alternate_bg_range = confirm_dialog_buffer.alternate_bg_range();
+        if !alternate_bg_range.is_empty() {
+            let index_offset = alternate_bg_range.start * std::mem::size_of::<u32>();
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    alternate_bg_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    index_offset,
+                );
+            }
+        }
+
         // Draw prompt text
         let prompt_range = confirm_dialog_buffer.prompt_range();
         if !prompt_range.is_empty() {
@@ -372,6 +399,21 @@ impl Renderer {
             }
         }
 
+        // Chunk: docs/chunks/quit_running_summary - Draw detail lines
+        let details_range = confirm_dialog_buffer.details_range();
+        if !details_range.is_empty() {
+            let index_offset = details_range.start * std::mem::size_of::<u32>();
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    details_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    index_offset,
+                );
+            }
+        }
+
         // Draw Cancel button text
         let cancel_text_range = confirm_dialog_buffer.cancel_text_range();
         if !cancel_text_range.is_empty() {
@@ -401,5 +443,112 @@ impl Renderer {
                 );
             }
         }
+
+        // Chunk: docs/chunks/quit_running_summary - Draw alternate button text
+        let alternate_text_range = confirm_dialog_buffer.alternate_text_range();
+        if !alternate_text_range.is_empty() {
+            let index_offset = alternate_text_range.start * std::mem::size_of::<u32>();
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    alternate_text_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    index_offset,
+                );
+            }
+        }
+    }
+
+    // Chunk: docs/chunks/render_stats_hud - Render stats HUD
+    /// Draws the render stats HUD, if it is shown, over `target` in a render
+    /// pass of its own.
+    ///
+    /// Each line is a strip like a pane's breadcrumbs: a background quad
+    /// with a line of text, stacked in the top-right corner below the tab bar.
+    #[cfg(feature = "perf-instrumentation")]
+    pub(super) fn draw_perf_hud(
+        &mut self,
+        command_buffer: &ProtocolObject<dyn MTLCommandBuffer>,
+        target: &ProtocolObject<dyn MTLTexture>,
+    ) {
+        let Some(lines) = self.perf_hud.as_ref() else {
+            return;
+        };
+        let view_width = target.width() as f32;
+        let view_height = target.height() as f32;
+        let line_height = self.font.metrics.line_height as f32;
+        let glyph_width = self.font.metrics.advance_width as f32;
+        let geometry = hud_geometry(lines, view_width, TAB_BAR_HEIGHT, line_height, glyph_width);
+
+        // Draw over what's already in the target
+        let render_pass_descriptor = MTLRenderPassDescriptor::new();
+        let color_attachments = render_pass_descriptor.colorAttachments();
+        let color_attachment = unsafe { color_attachments.objectAtIndexedSubscript(0) };
+        color_attachment.setTexture(Some(target));
+        color_attachment.setLoadAction(MTLLoadAction::Load);
+        color_attachment.setStoreAction(MTLStoreAction::Store);
+        let Some(encoder) =
+            command_buffer.renderCommandEncoderWithDescriptor(&render_pass_descriptor)
+        else {
+            eprintln!("Failed to create render command encoder");
+            return;
+        };
+
+        encoder.setRenderPipelineState(self.pipeline.pipeline_state());
+        let uniforms = Uniforms {
+            viewport_size: [view_width, view_height],
+        };
+        let uniforms_ptr =
+            NonNull::new(&uniforms as *const Uniforms as *mut std::ffi::c_void).unwrap();
+        unsafe {
+            encoder.setVertexBytes_length_atIndex(uniforms_ptr, std::mem::size_of::<Uniforms>(), 1);
+            encoder.setFragmentTexture_atIndex(Some(self.atlas.texture()), 0);
+        }
+
+        while self.perf_hud_buffers.len() < lines.len() {
+            let layout = GlyphLayout::from_metrics(&self.font.metrics);
+            self.perf_hud_buffers
+                .push(StatusBarGlyphBuffer::new(layout));
+        }
+        for (row, (line, buffer)) in lines.iter().zip(&mut self.perf_hud_buffers).enumerate() {
+            let strip = calculate_breadcrumb_geometry(
+                geometry.x,
+                geometry.y + row as f32 * geometry.row_height,
+                geometry.width,
+                line_height,
+                glyph_width,
+            );
+            buffer.update(&self.device, &self.atlas, line, &strip);
+
+            let (Some(vertex_buffer), Some(index_buffer)) =
+                (buffer.vertex_buffer(), buffer.index_buffer())
+            else {
+                continue;
+            };
+            unsafe {
+                encoder.setVertexBuffer_offset_atIndex(Some(vertex_buffer), 0, 0);
+            }
+
+            // Background, then text
+            for range in [buffer.background_range(), buffer.text_range()] {
+                if range.is_empty() {
+                    continue;
+                }
+                let index_offset = range.start * std::mem::size_of::<u32>();
+                unsafe {
+                    encoder
+                        .drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                            MTLPrimitiveType::Triangle,
+                            range.count,
+                            MTLIndexType::UInt32,
+                            index_buffer,
+                            index_offset,
+                        );
+                }
+            }
+        }
+
+        encoder.endEncoding();
     }
 }
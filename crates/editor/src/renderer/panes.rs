@@ -5,6 +5,7 @@
 //! This module contains the methods for rendering pane layouts:
 //! - Per-pane content rendering
 //! - Pane frame rendering (dividers and focus borders)
+//! - Overlay scrollbars
 //! - Viewport configuration for panes
 
 use std::ptr::NonNull;
@@ -14,15 +15,26 @@ use objc2_metal::{
     MTLIndexType, MTLPrimitiveType, MTLRenderCommandEncoder,
 };
 
+use lite_edit_buffer::{AnnotatedView, Annotations};
+use lite_edit_terminal::CommandStatus;
+
+// Chunk: docs/chunks/conflict_markers - Conflict blocks of the rendered file
+use crate::conflict_markers::find_conflicts;
+use crate::glyph_buffer::GlyphVertex;
 use crate::highlighted_buffer::HighlightedBufferView;
 use crate::metal_view::MetalView;
 use crate::pane_frame_buffer::PaneFrameBuffer;
 use crate::pane_layout::{PaneId, PaneRect};
+use crate::selector_overlay::{FindStripState, StatusBarState};
 use crate::tab_bar::TAB_BAR_HEIGHT;
 use crate::viewport::Viewport;
-use crate::workspace::Workspace;
+use crate::workspace::{Tab, Workspace};
 
-use super::constants::{FOCUSED_PANE_BORDER_COLOR, PANE_DIVIDER_COLOR, Uniforms};
+use super::constants::{
+    Uniforms, FOCUSED_PANE_BORDER_COLOR, INACTIVE_PANE_DIM_COLOR, PANE_DIVIDER_COLOR,
+    PROMPT_MARK_FAILED_COLOR, PROMPT_MARK_PENDING_COLOR, PROMPT_MARK_SUCCEEDED_COLOR,
+    SCROLLBAR_MATCH_TICK_COLOR, SCROLLBAR_THUMB_COLOR, TAB_DROP_HIGHLIGHT_COLOR,
+};
 use super::scissor::{pane_content_scissor_rect, pane_scissor_rect};
 use super::Renderer;
 
@@ -61,6 +73,148 @@ impl Renderer {
         self.content_width_px = pane_width;
     }
 
+    // Chunk: docs/chunks/pane_tint - Per-pane background tint
+    /// Tints the background of a pane's content area according to its tab kind.
+    ///
+    /// Draws one translucent solid quad over `(x, y, width, height)` using the
+    /// palette's tint for the tab's kind; file panes are left untouched. Must be
+    /// called after the content scissor is set and before the pane's text, so
+    /// the tint sits between the clear color and the content.
+    pub(super) fn draw_pane_tint(
+        &self,
+        encoder: &ProtocolObject<dyn MTLRenderCommandEncoder>,
+        view: &MetalView,
+        tab: &Tab,
+        rect: (f32, f32, f32, f32),
+    ) {
+        let Some(color) = self.glyph_buffer.palette().pane_tint(tab.kind) else {
+            return;
+        };
+        self.draw_solid_rect(encoder, view, rect, color);
+    }
+
+    // Chunk: docs/chunks/pane_scrollbar - Overlay scrollbar thumbs
+    /// Draws the scrollbar thumbs set by `set_scrollbars`, each faded by its
+    /// opacity, then the prompt marks set by `set_prompt_marks` and the
+    /// find-match ticks set by `set_match_ticks`.
+    ///
+    /// Must be called after the pane content so the thumbs sit on top of it.
+    pub(super) fn draw_scrollbars(
+        &self,
+        encoder: &ProtocolObject<dyn MTLRenderCommandEncoder>,
+        view: &MetalView,
+    ) {
+        for thumb in &self.scrollbars {
+            let mut color = SCROLLBAR_THUMB_COLOR;
+            color[3] *= thumb.opacity;
+            self.draw_solid_rect(encoder, view, thumb.rect, color);
+        }
+        // Chunk: docs/chunks/terminal_shell_integration - Prompt marks colored by exit status
+        for &(rect, status) in &self.prompt_marks {
+            let color = match status {
+                CommandStatus::Succeeded => PROMPT_MARK_SUCCEEDED_COLOR,
+                CommandStatus::Failed => PROMPT_MARK_FAILED_COLOR,
+                CommandStatus::Pending => PROMPT_MARK_PENDING_COLOR,
+            };
+            self.draw_solid_rect(encoder, view, rect, color);
+        }
+        // Chunk: docs/chunks/find_scrollbar_ticks - Ticks on top of the thumbs
+        for &tick in &self.match_ticks {
+            self.draw_solid_rect(encoder, view, tick, SCROLLBAR_MATCH_TICK_COLOR);
+        }
+    }
+
+    /// Draws one solid quad over `(x, y, width, height)` in screen space.
+    pub(super) fn draw_solid_rect(
+        &self,
+        encoder: &ProtocolObject<dyn MTLRenderCommandEncoder>,
+        view: &MetalView,
+        (x, y, width, height): (f32, f32, f32, f32),
+        color: [f32; 4],
+    ) {
+        encoder.setRenderPipelineState(self.pipeline.pipeline_state());
+
+        let frame = view.frame();
+        let scale = view.scale_factor();
+        let uniforms = Uniforms {
+            viewport_size: [
+                (frame.size.width * scale) as f32,
+                (frame.size.height * scale) as f32,
+            ],
+        };
+        let uniforms_ptr =
+            NonNull::new(&uniforms as *const Uniforms as *mut std::ffi::c_void).unwrap();
+
+        let solid = self.atlas.solid_glyph();
+        let (u0, v0) = solid.uv_min;
+        let (u1, v1) = solid.uv_max;
+        // Triangle strip order: top-left, top-right, bottom-left, bottom-right
+        let vertices = [
+            GlyphVertex::new(x, y, u0, v0, color),
+            GlyphVertex::new(x + width, y, u1, v0, color),
+            GlyphVertex::new(x, y + height, u0, v1, color),
+            GlyphVertex::new(x + width, y + height, u1, v1, color),
+        ];
+        let vertices_ptr = NonNull::new(vertices.as_ptr() as *mut std::ffi::c_void).unwrap();
+
+        unsafe {
+            encoder.setVertexBytes_length_atIndex(
+                uniforms_ptr,
+                std::mem::size_of::<Uniforms>(),
+                1,
+            );
+            encoder.setVertexBytes_length_atIndex(
+                vertices_ptr,
+                std::mem::size_of_val(&vertices),
+                0,
+            );
+            encoder.setFragmentTexture_atIndex(Some(self.atlas.texture()), 0);
+            encoder.drawPrimitives_vertexStart_vertexCount(
+                MTLPrimitiveType::TriangleStrip,
+                0,
+                vertices.len(),
+            );
+        }
+    }
+
+    // Chunk: docs/chunks/partial_redraw - Shared by full and partial multi-pane frames
+    /// Draws the find strip, or the status bar when there is no find strip,
+    /// at the bottom of `pane_rect`.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn draw_strip_in_pane(
+        &mut self,
+        encoder: &ProtocolObject<dyn MTLRenderCommandEncoder>,
+        view: &MetalView,
+        find_strip: Option<&FindStripState<'_>>,
+        status_bar: Option<&StatusBarState<'_>>,
+        pane_rect: &PaneRect,
+        view_width: f32,
+        view_height: f32,
+    ) {
+        if let Some(find_state) = find_strip {
+            self.draw_find_strip_in_pane(
+                encoder,
+                view,
+                find_state.query,
+                find_state.cursor_col,
+                find_state.cursor_visible,
+                find_state.options,
+                pane_rect,
+                view_width,
+                view_height,
+            );
+        } else if let Some(status_state) = status_bar {
+            self.draw_status_bar_in_pane(
+                encoder,
+                view,
+                status_state.text,
+                pane_rect,
+                view_width,
+                view_height,
+            );
+        }
+    }
+
     // =========================================================================
     // Pane Frame Rendering (Chunk: docs/chunks/tiling_multi_pane_render)
     // =========================================================================
@@ -69,6 +223,8 @@ impl Renderer {
     ///
     /// Divider lines appear between adjacent panes (1px).
     /// A focus border appears around the active pane when multiple panes exist (2px).
+    /// While a tab is dragged, the drop target is highlighted beneath both,
+    /// including in single-pane layouts.
     ///
     /// # Arguments
     /// * `encoder` - The active render command encoder
@@ -82,8 +238,9 @@ impl Renderer {
         pane_rects: &[PaneRect],
         focused_pane_id: PaneId,
     ) {
-        // Skip if only one pane (no dividers or focus border needed)
-        if pane_rects.len() <= 1 {
+        // Skip if only one pane (no dividers or focus border needed) and no
+        // tab is being dragged
+        if pane_rects.len() <= 1 && self.tab_drop_highlight.is_none() {
             return;
         }
 
@@ -106,6 +263,9 @@ impl Renderer {
             &self.atlas,
             PANE_DIVIDER_COLOR,
             FOCUSED_PANE_BORDER_COLOR,
+            self.tab_drop_highlight,
+            TAB_DROP_HIGHLIGHT_COLOR,
+            self.dim_inactive_panes.then_some(INACTIVE_PANE_DIM_COLOR),
         );
 
         // Get buffers
@@ -145,6 +305,34 @@ impl Renderer {
             encoder.setFragmentTexture_atIndex(Some(self.atlas.texture()), 0);
         }
 
+        // Chunk: docs/chunks/dim_inactive_panes - Dimming over content, under everything else
+        let dim_range = pane_frame_buffer.dim_range();
+        if !dim_range.is_empty() {
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    dim_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    dim_range.start * std::mem::size_of::<u32>(),
+                );
+            }
+        }
+
+        // Chunk: docs/chunks/tab_drag_drop - Drop highlight under the frame lines
+        let highlight_range = pane_frame_buffer.drop_highlight_range();
+        if !highlight_range.is_empty() {
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    highlight_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    highlight_range.start * std::mem::size_of::<u32>(),
+                );
+            }
+        }
+
         // Draw divider lines (colors are baked into vertices)
         let divider_range = pane_frame_buffer.divider_range();
         if !divider_range.is_empty() {
@@ -228,8 +416,10 @@ impl Renderer {
 
         // Check if welcome screen should be shown for this pane
         let is_focused = pane_rect.pane_id == workspace.active_pane_id;
+        // Chunk: docs/chunks/scratch_buffers - Not for scratch buffer tabs
         let should_show_welcome = is_focused
             && tab.kind == crate::workspace::TabKind::File
+            && tab.scratch.is_none()
             && tab.as_text_buffer().map(|b| b.is_empty()).unwrap_or(false);
 
         if should_show_welcome {
@@ -237,6 +427,11 @@ impl Renderer {
             let scroll = tab.welcome_scroll_offset_px();
             self.draw_welcome_screen_in_pane(encoder, view, pane_rect, scroll);
         } else {
+            // Chunk: docs/chunks/pane_tint - Tint the pane by its tab kind
+            let content_height = pane_rect.height - TAB_BAR_HEIGHT;
+            let content_rect = (pane_rect.x, pane_rect.y + TAB_BAR_HEIGHT, pane_rect.width, content_height);
+            self.draw_pane_tint(encoder, view, tab, content_rect);
+
             // Set content offsets for this pane
             self.set_content_x_offset(pane_rect.x);
             self.set_content_y_offset(pane_rect.y + TAB_BAR_HEIGHT);
@@ -257,6 +452,17 @@ impl Renderer {
             // incorrectly served when rendering pane B's line 5, causing content mirroring.
             self.clear_styled_line_cache();
 
+            // Chunk: docs/chunks/find_match_highlights - Only the focused pane is searched
+            let find_matches = if is_focused { self.find_matches.clone() } else { Vec::new() };
+            self.glyph_buffer.set_match_highlights(find_matches);
+
+            // Chunk: docs/chunks/conflict_markers - Tint the file's conflict blocks
+            let conflicts = tab
+                .as_text_buffer()
+                .map(|buffer| find_conflicts(buffer.content().lines()))
+                .unwrap_or_default();
+            self.glyph_buffer.set_conflicts(conflicts);
+
             // Update glyph buffer from tab's buffer with pane-specific cursor visibility
             if tab.is_agent_tab() {
                 if let Some(terminal) = workspace.agent_terminal() {
@@ -267,15 +473,40 @@ impl Renderer {
                     text_buffer,
                     tab.highlighter(),
                 );
-                self.update_glyph_buffer_with_cursor_visible(&highlighted_view, pane_cursor_visible);
+                // Chunk: docs/chunks/buffer_annotations - Underline the file's annotations
+                let no_annotations = Annotations::new();
+                let annotations = workspace.tab_annotations(tab).unwrap_or(&no_annotations);
+                let annotated_view = AnnotatedView::new(&highlighted_view, annotations);
+                self.update_glyph_buffer_with_cursor_visible(&annotated_view, pane_cursor_visible);
+            } else if let Some(annotations) = tab.command_annotations() {
+                // Chunk: docs/chunks/terminal_command_status - Command summaries
+                let annotated_view = AnnotatedView::new(tab.buffer(), &annotations);
+                self.update_glyph_buffer_with_cursor_visible(&annotated_view, pane_cursor_visible);
             } else {
                 self.update_glyph_buffer_with_cursor_visible(tab.buffer(), pane_cursor_visible);
             }
+            if is_focused {
+                self.remember_text_input_cursor();
+            }
 
             // Render text
             if self.glyph_buffer.index_count() > 0 {
                 self.render_text(encoder, view);
             }
+
+            // Chunk: docs/chunks/terminal_kitty_graphics - Composite terminal images over text
+            if let Some(terminal) = super::images::tab_terminal(workspace, tab) {
+                self.draw_terminal_images(encoder, view, terminal);
+            }
+
+            // Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb strip over the content
+            self.draw_breadcrumbs(
+                encoder,
+                pane_rect.pane_id,
+                content_rect,
+                view_width,
+                view_height,
+            );
         }
     }
 }
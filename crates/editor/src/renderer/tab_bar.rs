@@ -66,7 +66,13 @@ impl Renderer {
         let tabs = tabs_from_workspace(workspace);
 
         // Calculate tab bar geometry with scroll offset
-        let geometry = calculate_tab_bar_geometry(view_width, &tabs, glyph_width, workspace.tab_bar_view_offset());
+        let mut geometry = calculate_tab_bar_geometry(view_width, &tabs, glyph_width, workspace.tab_bar_view_offset());
+        // Chunk: docs/chunks/tab_reorder_drag - Part the tabs for a dragged tab
+        if let Some((pane_id, slot)) = self.tab_reorder_slot {
+            if pane_id == workspace.active_pane_id {
+                geometry.open_drop_gap(slot);
+            }
+        }
 
         // Ensure tab bar buffer is initialized
         if self.tab_bar_buffer.is_none() {
@@ -209,6 +215,36 @@ impl Renderer {
                 );
             }
         }
+
+        // Chunk: docs/chunks/tab_reorder_drag - Drop gap marker
+        // Color is baked into vertex data
+        let gap_range = tab_bar_buffer.drop_gap_range();
+        if !gap_range.is_empty() {
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    gap_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    gap_range.start * std::mem::size_of::<u32>(),
+                );
+            }
+        }
+
+        // Chunk: docs/chunks/pinned_tabs_overflow - Draw the overflow button last
+        // Colors are baked into vertex data (background, then the chevron)
+        let overflow_range = tab_bar_buffer.overflow_button_range();
+        if !overflow_range.is_empty() {
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    overflow_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    overflow_range.start * std::mem::size_of::<u32>(),
+                );
+            }
+        }
     }
 
     // Chunk: docs/chunks/tiling_multi_pane_render - Pane tab bar rendering
@@ -240,7 +276,7 @@ impl Renderer {
         let tabs = tabs_from_pane(pane);
 
         // Calculate geometry for this pane's tab bar
-        let geometry = calculate_pane_tab_bar_geometry(
+        let mut geometry = calculate_pane_tab_bar_geometry(
             pane_rect.x,
             pane_rect.y,
             pane_rect.width,
@@ -248,6 +284,12 @@ impl Renderer {
             glyph_width,
             pane.tab_bar_view_offset,
         );
+        // Chunk: docs/chunks/tab_reorder_drag - Part the tabs for a dragged tab
+        if let Some((pane_id, slot)) = self.tab_reorder_slot {
+            if pane_id == pane.id {
+                geometry.open_drop_gap(slot);
+            }
+        }
 
         // Ensure tab bar buffer is initialized
         if self.tab_bar_buffer.is_none() {
@@ -389,5 +431,35 @@ impl Renderer {
                 );
             }
         }
+
+        // Chunk: docs/chunks/tab_reorder_drag - Drop gap marker
+        // Color is baked into vertex data
+        let gap_range = tab_bar_buffer.drop_gap_range();
+        if !gap_range.is_empty() {
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    gap_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    gap_range.start * std::mem::size_of::<u32>(),
+                );
+            }
+        }
+
+        // Chunk: docs/chunks/pinned_tabs_overflow - Draw the overflow button last
+        // Colors are baked into vertex data (background, then the chevron)
+        let overflow_range = tab_bar_buffer.overflow_button_range();
+        if !overflow_range.is_empty() {
+            unsafe {
+                encoder.drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset(
+                    MTLPrimitiveType::Triangle,
+                    overflow_range.count,
+                    MTLIndexType::UInt32,
+                    index_buffer,
+                    overflow_range.start * std::mem::size_of::<u32>(),
+                );
+            }
+        }
     }
 }
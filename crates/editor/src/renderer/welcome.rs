@@ -67,6 +67,7 @@ impl Renderer {
             glyph_width,
             line_height,
             scroll_offset_px,
+            &self.welcome_recents,
         );
 
         // Offset the geometry to account for left rail and tab bar
@@ -81,7 +82,7 @@ impl Renderer {
 
         // Update the welcome screen buffer
         let welcome_buffer = self.welcome_screen_buffer.as_mut().unwrap();
-        welcome_buffer.update(&self.device, &self.atlas, &geometry);
+        welcome_buffer.update(&self.device, &self.atlas, &geometry, &self.welcome_recents);
 
         // Get buffers
         let vertex_buffer = match welcome_buffer.vertex_buffer() {
@@ -170,6 +171,7 @@ impl Renderer {
             glyph_width,
             line_height,
             scroll_offset_px,
+            &self.welcome_recents,
         );
 
         // Offset to pane position
@@ -184,7 +186,7 @@ impl Renderer {
 
         // Update and render the welcome screen
         let welcome_buffer = self.welcome_screen_buffer.as_mut().unwrap();
-        welcome_buffer.update(&self.device, &self.atlas, &geometry);
+        welcome_buffer.update(&self.device, &self.atlas, &geometry, &self.welcome_recents);
 
         // Get buffers
         let vertex_buffer = match welcome_buffer.vertex_buffer() {
@@ -10,6 +10,7 @@
 //! - Computing which rows are visible based on scroll offset and viewport height
 //! - Clamping scroll position to valid bounds
 //! - Converting between row indices and screen positions
+//! - Animating toward a target offset for smooth scrolling
 //!
 //! This is a pure data structure with no platform dependencies, making it fully
 //! testable without mocking. It differs from `Viewport` in that it has no knowledge
@@ -32,6 +33,16 @@
 
 use std::ops::Range;
 
+// Chunk: docs/chunks/smooth_scroll_animation - Animation tuning
+/// Time constant of the smooth scroll easing, in seconds.
+///
+/// Each frame closes `1 - e^(-dt / SMOOTH_SCROLL_TIME_CONSTANT)` of the remaining
+/// distance, so a scroll settles in roughly 150ms regardless of frame rate.
+const SMOOTH_SCROLL_TIME_CONSTANT: f32 = 0.05;
+
+/// An animation within this many pixels of its target snaps to it and ends.
+const SMOOTH_SCROLL_SNAP_PX: f32 = 0.5;
+
 /// Scroll state and arithmetic for uniform-height row lists.
 ///
 /// This struct maintains the scroll position and computes visible ranges for any
@@ -49,6 +60,10 @@ pub struct RowScroller {
     visible_rows: usize,
     /// Height of each row in pixels
     row_height: f32,
+    // Chunk: docs/chunks/smooth_scroll_animation - Smooth scroll target
+    /// Offset a smooth scroll is easing toward, if one is running.
+    /// `scroll_offset_px` stays the offset actually on screen.
+    scroll_target_px: Option<f32>,
 }
 
 impl RowScroller {
@@ -61,6 +76,7 @@ impl RowScroller {
             scroll_offset_px: 0.0,
             visible_rows: 0,
             row_height,
+            scroll_target_px: None,
         }
     }
 
@@ -114,10 +130,66 @@ impl RowScroller {
     /// `max_offset_px = (row_count - visible_rows) * row_height`
     ///
     /// This ensures the viewport doesn't scroll past the start or end of the content.
+    ///
+    /// Cancels any smooth scroll in progress.
     pub fn set_scroll_offset_px(&mut self, px: f32, row_count: usize) {
+        self.scroll_target_px = None;
+        self.scroll_offset_px = self.clamp_offset_px(px, row_count);
+    }
+
+    /// Clamps `px` to `[0.0, max_offset_px]` for `row_count` rows.
+    fn clamp_offset_px(&self, px: f32, row_count: usize) -> f32 {
         let max_rows = row_count.saturating_sub(self.visible_rows);
         let max_offset_px = max_rows as f32 * self.row_height;
-        self.scroll_offset_px = px.clamp(0.0, max_offset_px);
+        px.clamp(0.0, max_offset_px)
+    }
+
+    // Chunk: docs/chunks/smooth_scroll_animation - Smooth scroll animation
+    /// Starts (or retargets) a smooth scroll toward `px`, clamped like
+    /// `set_scroll_offset_px`.
+    ///
+    /// The visible offset doesn't move until `step_animation` is called, so
+    /// hit-testing keeps matching what is on screen.
+    pub fn animate_to_px(&mut self, px: f32, row_count: usize) {
+        let target = self.clamp_offset_px(px, row_count);
+        self.animate_to_px_unclamped(target);
+    }
+
+    /// Like `animate_to_px`, for a target the caller has already clamped.
+    pub(crate) fn animate_to_px_unclamped(&mut self, px: f32) {
+        self.scroll_target_px = if px == self.scroll_offset_px { None } else { Some(px) };
+    }
+
+    /// Returns where the scroll offset is heading: the smooth scroll target
+    /// while one is running, otherwise the current offset.
+    ///
+    /// Successive scroll ticks add to this so that they accumulate instead of
+    /// restarting from a mid-animation position.
+    pub fn scroll_target_px(&self) -> f32 {
+        self.scroll_target_px.unwrap_or(self.scroll_offset_px)
+    }
+
+    /// Returns true while a smooth scroll is running.
+    pub fn is_animating(&self) -> bool {
+        self.scroll_target_px.is_some()
+    }
+
+    /// Advances a running smooth scroll by `dt_secs` of wall-clock time.
+    ///
+    /// Returns `true` if the scroll offset moved.
+    pub fn step_animation(&mut self, dt_secs: f32) -> bool {
+        let Some(target) = self.scroll_target_px else {
+            return false;
+        };
+        let remaining = target - self.scroll_offset_px;
+        let eased = remaining * (1.0 - (-dt_secs.max(0.0) / SMOOTH_SCROLL_TIME_CONSTANT).exp());
+        if (remaining - eased).abs() < SMOOTH_SCROLL_SNAP_PX {
+            self.scroll_offset_px = target;
+            self.scroll_target_px = None;
+        } else {
+            self.scroll_offset_px += eased;
+        }
+        true
     }
 
     /// Updates the viewport size based on height in pixels.
@@ -132,7 +204,11 @@ impl RowScroller {
             0
         };
         // Re-clamp scroll offset to new valid bounds
-        self.set_scroll_offset_px(self.scroll_offset_px, row_count);
+        // Chunk: docs/chunks/smooth_scroll_animation - Resizing keeps the animation running
+        self.scroll_offset_px = self.clamp_offset_px(self.scroll_offset_px, row_count);
+        if let Some(target) = self.scroll_target_px {
+            self.animate_to_px(target, row_count);
+        }
     }
 
     /// Returns the range of rows visible in the viewport.
@@ -249,6 +325,7 @@ impl RowScroller {
     /// This is used by `Viewport::ensure_visible_wrapped` which does its own
     /// clamping based on wrapped line counts rather than buffer line counts.
     pub(crate) fn set_scroll_offset_unclamped(&mut self, px: f32) {
+        self.scroll_target_px = None;
         self.scroll_offset_px = px;
     }
 
@@ -261,6 +338,22 @@ impl RowScroller {
     pub fn set_visible_rows(&mut self, rows: usize) {
         self.visible_rows = rows;
     }
+
+    // Chunk: docs/chunks/font_config_zoom - Row height changes on font zoom
+    /// Changes the row height, keeping the same (fractional) row at the top.
+    ///
+    /// The scroll offset is rescaled so the content doesn't jump, and any
+    /// smooth scroll is cancelled. `visible_rows` is left as-is; call
+    /// `update_size()` afterwards to recompute it for the new height.
+    pub fn set_row_height(&mut self, row_height: f32) {
+        if self.row_height > 0.0 && row_height > 0.0 {
+            self.scroll_offset_px = self.scroll_offset_px / self.row_height * row_height;
+        } else {
+            self.scroll_offset_px = 0.0;
+        }
+        self.scroll_target_px = None;
+        self.row_height = row_height;
+    }
 }
 
 #[cfg(test)]
@@ -870,4 +963,130 @@ mod tests {
             "Row 10 is beyond effective partial row with margin=1, should scroll"
         );
     }
+
+    // ==================== Smooth scroll animation ====================
+    // Chunk: docs/chunks/smooth_scroll_animation - Animation tests
+
+    #[test]
+    fn test_animate_to_px_does_not_move_until_stepped() {
+        let mut scroller = RowScroller::new(16.0);
+        scroller.update_size(160.0, 100);
+
+        scroller.animate_to_px(160.0, 100);
+
+        assert!(scroller.is_animating());
+        assert_eq!(scroller.scroll_offset_px(), 0.0);
+        assert_eq!(scroller.scroll_target_px(), 160.0);
+    }
+
+    #[test]
+    fn test_step_animation_eases_toward_target_and_settles() {
+        let mut scroller = RowScroller::new(16.0);
+        scroller.update_size(160.0, 100);
+        scroller.animate_to_px(160.0, 100);
+
+        assert!(scroller.step_animation(1.0 / 60.0));
+        let first = scroller.scroll_offset_px();
+        assert!(first > 0.0 && first < 160.0);
+
+        for _ in 0..60 {
+            scroller.step_animation(1.0 / 60.0);
+        }
+        assert_eq!(scroller.scroll_offset_px(), 160.0);
+        assert!(!scroller.is_animating());
+        assert!(!scroller.step_animation(1.0 / 60.0));
+    }
+
+    #[test]
+    fn test_step_animation_is_frame_rate_independent() {
+        let mut at_60hz = RowScroller::new(16.0);
+        at_60hz.update_size(160.0, 100);
+        at_60hz.animate_to_px(320.0, 100);
+        let mut at_120hz = at_60hz.clone();
+
+        at_60hz.step_animation(1.0 / 60.0);
+        at_120hz.step_animation(1.0 / 120.0);
+        at_120hz.step_animation(1.0 / 120.0);
+
+        assert!((at_60hz.scroll_offset_px() - at_120hz.scroll_offset_px()).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_animate_to_px_clamps_target() {
+        let mut scroller = RowScroller::new(16.0);
+        scroller.update_size(160.0, 20); // max offset = 10 rows = 160px
+
+        scroller.animate_to_px(10_000.0, 20);
+        assert_eq!(scroller.scroll_target_px(), 160.0);
+
+        scroller.animate_to_px(-50.0, 20);
+        assert!(!scroller.is_animating(), "Target equals current offset");
+    }
+
+    #[test]
+    fn test_set_scroll_offset_cancels_animation() {
+        let mut scroller = RowScroller::new(16.0);
+        scroller.update_size(160.0, 100);
+        scroller.animate_to_px(320.0, 100);
+
+        scroller.set_scroll_offset_px(48.0, 100);
+
+        assert!(!scroller.is_animating());
+        assert_eq!(scroller.scroll_target_px(), 48.0);
+        assert!(!scroller.step_animation(1.0 / 60.0));
+        assert_eq!(scroller.scroll_offset_px(), 48.0);
+    }
+
+    #[test]
+    fn test_update_size_reclamps_target_and_keeps_animating() {
+        let mut scroller = RowScroller::new(16.0);
+        scroller.update_size(160.0, 30); // max offset = 20 rows = 320px
+        scroller.animate_to_px(320.0, 30);
+
+        scroller.update_size(320.0, 30); // max offset = 10 rows = 160px
+
+        assert!(scroller.is_animating());
+        assert_eq!(scroller.scroll_target_px(), 160.0);
+    }
+
+    // ==================== Row height changes ====================
+    // Chunk: docs/chunks/font_config_zoom - Row height changes on font zoom
+
+    #[test]
+    fn test_set_row_height_keeps_top_row() {
+        let mut scroller = RowScroller::new(16.0);
+        scroller.update_size(160.0, 100);
+        scroller.set_scroll_offset_px(88.0, 100); // row 5 plus 8px
+
+        scroller.set_row_height(20.0);
+
+        assert_eq!(scroller.row_height(), 20.0);
+        assert_eq!(scroller.first_visible_row(), 5);
+        assert_eq!(scroller.scroll_offset_px(), 110.0); // 5.5 rows * 20px
+    }
+
+    #[test]
+    fn test_set_row_height_cancels_animation() {
+        let mut scroller = RowScroller::new(16.0);
+        scroller.update_size(160.0, 100);
+        scroller.animate_to_px(320.0, 100);
+        assert!(scroller.is_animating());
+
+        scroller.set_row_height(12.0);
+
+        assert!(!scroller.is_animating());
+        assert_eq!(scroller.scroll_offset_px(), 0.0);
+    }
+
+    #[test]
+    fn test_update_size_after_set_row_height() {
+        let mut scroller = RowScroller::new(16.0);
+        scroller.update_size(160.0, 100);
+        assert_eq!(scroller.visible_rows(), 10);
+
+        scroller.set_row_height(20.0);
+        scroller.update_size(160.0, 100);
+
+        assert_eq!(scroller.visible_rows(), 8);
+    }
 }
@@ -0,0 +1,90 @@
+// Chunk: docs/chunks/terminal_scrollback_export - Save panel for scrollback export
+//!
+//! Save location picker for macOS via NSSavePanel.
+//!
+//! This module provides a thin wrapper around NSSavePanel for choosing where
+//! to write a file. Like `file_picker`, it is intentionally minimal ("humble
+//! object" pattern) - all business logic stays in the caller.
+//!
+//! ## Test isolation
+//!
+//! Under `cfg(test)` the real NSSavePanel is never touched. Instead, a
+//! `thread_local!` option acts as a mock save panel. This prevents unit tests
+//! from opening modal dialogs during test runs.
+
+use std::path::PathBuf;
+
+// ── production save panel (NSSavePanel) ──────────────────────────────────────
+
+#[cfg(not(test))]
+use objc2_app_kit::{NSModalResponseOK, NSSavePanel};
+#[cfg(not(test))]
+use objc2_foundation::{MainThreadMarker, NSString};
+
+/// Opens a save panel pre-filled with `default_name` and returns the chosen path.
+///
+/// Returns `Some(PathBuf)` with the chosen destination, or `None` if the user
+/// cancelled the dialog.
+///
+/// # Safety
+///
+/// This function must be called from the main thread. On macOS, UI operations
+/// including NSSavePanel must be performed on the main thread.
+#[cfg(not(test))]
+pub fn pick_save_path(default_name: &str) -> Option<PathBuf> {
+    let mtm = MainThreadMarker::new().expect("pick_save_path must be called from main thread");
+
+    let panel = NSSavePanel::savePanel(mtm);
+    panel.setNameFieldStringValue(&NSString::from_str(default_name));
+    panel.setCanCreateDirectories(true);
+
+    let response = panel.runModal();
+    if response == NSModalResponseOK {
+        panel.URL().and_then(|url| url.path().map(|p| PathBuf::from(p.to_string())))
+    } else {
+        None
+    }
+}
+
+// ── test save panel (thread-local mock) ──────────────────────────────────────
+
+#[cfg(test)]
+use std::cell::RefCell;
+
+#[cfg(test)]
+thread_local! {
+    /// In-process save panel mock used by all unit tests on the current thread.
+    /// Never touches NSSavePanel, so tests can run without modal dialogs.
+    static MOCK_SAVE_PATH: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+/// Opens a save panel and returns the chosen path.
+///
+/// In test mode, returns the value set by `mock_set_next_save_path()`.
+/// The mock value is consumed after one call.
+#[cfg(test)]
+pub fn pick_save_path(_default_name: &str) -> Option<PathBuf> {
+    MOCK_SAVE_PATH.with(|p| p.borrow_mut().take())
+}
+
+/// Sets the path that `pick_save_path()` will return on its next call.
+///
+/// This function is only available in test builds.
+#[cfg(test)]
+pub fn mock_set_next_save_path(path: Option<PathBuf>) {
+    MOCK_SAVE_PATH.with(|p| *p.borrow_mut() = path);
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_pick_save_path_consumes_value() {
+        mock_set_next_save_path(Some(PathBuf::from("/tmp/out.txt")));
+        assert_eq!(pick_save_path("default.txt"), Some(PathBuf::from("/tmp/out.txt")));
+        assert_eq!(pick_save_path("default.txt"), None);
+    }
+}
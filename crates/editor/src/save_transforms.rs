@@ -0,0 +1,291 @@
+// Chunk: docs/chunks/save_transforms - Whitespace cleanups applied on save
+//!
+//! Whitespace cleanups applied to a file's contents when it is saved.
+//!
+//! [`SaveConfig`] is the `"save"` section of the user config. It turns each
+//! cleanup on or off for every file and lets a language override that by its
+//! name (`"rust"`, `"markdown"`, ...):
+//!
+//! ```json
+//! "save": {
+//!   "trim_trailing_whitespace": true,
+//!   "ensure_final_newline": true,
+//!   "languages": { "markdown": { "trim_trailing_whitespace": false } }
+//! }
+//! ```
+//!
+//! [`apply_save_transforms`] rewrites the contents; `EditorState::save_file`
+//! puts the result back in the buffer before writing it, so what is on disk
+//! and what is shown stay the same.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use similar::{DiffOp, TextDiff};
+
+use crate::tab_width::{char_visual_width, TAB_WIDTH};
+
+/// The cleanups to apply to one file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaveTransforms {
+    /// Remove spaces and tabs at the end of lines changed since the file was
+    /// opened or last saved
+    pub trim_trailing_whitespace: bool,
+    /// End a non-empty file with exactly one newline
+    pub ensure_final_newline: bool,
+    /// Re-indent every line with whichever of tabs or spaces most lines use
+    pub normalize_indentation: bool,
+}
+
+/// Per-language overrides of [`SaveTransforms`]; unset fields keep the
+/// global setting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaveTransformOverrides {
+    pub trim_trailing_whitespace: Option<bool>,
+    pub ensure_final_newline: Option<bool>,
+    pub normalize_indentation: Option<bool>,
+}
+
+/// The `"save"` section of the user config.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaveConfig {
+    /// Cleanups for every file
+    #[serde(flatten)]
+    pub transforms: SaveTransforms,
+    /// Overrides by language name, as the syntax registry names languages
+    pub languages: HashMap<String, SaveTransformOverrides>,
+    // Chunk: docs/chunks/format_on_save - Formatter commands by language
+    /// Formatter command (program then arguments) by language name, run
+    /// before the cleanups; see `format_on_save`
+    pub formatters: HashMap<String, Vec<String>>,
+}
+
+impl SaveConfig {
+    /// Returns the cleanups for a file in `language` (`None` when the file's
+    /// language isn't known).
+    pub fn transforms_for(&self, language: Option<&str>) -> SaveTransforms {
+        let global = self.transforms;
+        let Some(overrides) = language.and_then(|name| self.languages.get(name)) else {
+            return global;
+        };
+        SaveTransforms {
+            trim_trailing_whitespace: overrides
+                .trim_trailing_whitespace
+                .unwrap_or(global.trim_trailing_whitespace),
+            ensure_final_newline: overrides
+                .ensure_final_newline
+                .unwrap_or(global.ensure_final_newline),
+            normalize_indentation: overrides
+                .normalize_indentation
+                .unwrap_or(global.normalize_indentation),
+        }
+    }
+
+    // Chunk: docs/chunks/format_on_save - Formatter commands by language
+    /// Returns the formatter command for a file in `language`, if one is
+    /// configured.
+    pub fn formatter_for(&self, language: Option<&str>) -> Option<&[String]> {
+        let command = self.formatters.get(language?)?;
+        (!command.is_empty()).then_some(command.as_slice())
+    }
+}
+
+/// Returns `content` with `transforms` applied.
+///
+/// `base` is the content last loaded from or saved to disk. Trailing
+/// whitespace is only trimmed from lines that differ from it, so saving a
+/// small change doesn't rewrite untouched lines; with no `base` every line
+/// counts as changed.
+pub fn apply_save_transforms(
+    content: &str,
+    base: Option<&str>,
+    transforms: SaveTransforms,
+) -> String {
+    let mut lines: Vec<String> = content.split('\n').map(str::to_string).collect();
+
+    if transforms.trim_trailing_whitespace {
+        let changed = changed_lines(base, content, lines.len());
+        for (line, changed) in lines.iter_mut().zip(changed) {
+            if changed {
+                line.truncate(line.trim_end_matches([' ', '\t']).len());
+            }
+        }
+    }
+
+    if transforms.normalize_indentation {
+        if let Some(use_tabs) = dominant_indentation(&lines) {
+            for line in &mut lines {
+                reindent(line, use_tabs);
+            }
+        }
+    }
+
+    let mut result = lines.join("\n");
+    if transforms.ensure_final_newline {
+        result.truncate(result.trim_end_matches('\n').len());
+        if !result.is_empty() {
+            result.push('\n');
+        }
+    }
+    result
+}
+
+/// Returns, for each of the `count` lines of `content`, whether it differs
+/// from `base`.
+fn changed_lines(base: Option<&str>, content: &str, count: usize) -> Vec<bool> {
+    let Some(base) = base else {
+        return vec![true; count];
+    };
+    let mut changed = vec![false; count];
+    let diff = TextDiff::from_lines(base, content);
+    for op in diff.ops() {
+        if let DiffOp::Insert {
+            new_index, new_len, ..
+        }
+        | DiffOp::Replace {
+            new_index, new_len, ..
+        } = *op
+        {
+            let end = (new_index + new_len).min(count);
+            for flag in changed.iter_mut().take(end).skip(new_index) {
+                *flag = true;
+            }
+        }
+    }
+    changed
+}
+
+/// Returns whether most indented lines start with a tab (`Some(true)`) or a
+/// space (`Some(false)`), or `None` when no line is indented.
+///
+/// Ties go to spaces.
+fn dominant_indentation(lines: &[String]) -> Option<bool> {
+    let (mut tabs, mut spaces) = (0, 0);
+    for line in lines {
+        match line.chars().next() {
+            Some('\t') => tabs += 1,
+            Some(' ') => spaces += 1,
+            _ => {}
+        }
+    }
+    match (tabs, spaces) {
+        (0, 0) => None,
+        _ => Some(tabs > spaces),
+    }
+}
+
+/// Rewrites the leading whitespace of `line` to the same width in tabs (then
+/// spaces for any remainder) or in spaces only.
+///
+/// Whitespace-only lines are left alone, since their width has no meaning.
+fn reindent(line: &mut String, use_tabs: bool) {
+    let indent_len = line.len() - line.trim_start_matches([' ', '\t']).len();
+    if indent_len == 0 || indent_len == line.len() {
+        return;
+    }
+    let width = line[..indent_len]
+        .chars()
+        .fold(0, |col, c| col + char_visual_width(c, col));
+    let indent = if use_tabs {
+        "\t".repeat(width / TAB_WIDTH) + &" ".repeat(width % TAB_WIDTH)
+    } else {
+        " ".repeat(width)
+    };
+    line.replace_range(..indent_len, &indent);
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRIM: SaveTransforms = SaveTransforms {
+        trim_trailing_whitespace: true,
+        ensure_final_newline: false,
+        normalize_indentation: false,
+    };
+
+    #[test]
+    fn test_trim_only_changed_lines() {
+        let base = "keep  \nold\n";
+        let content = "keep  \nnew  \nadded\t\n";
+        let result = apply_save_transforms(content, Some(base), TRIM);
+        assert_eq!(result, "keep  \nnew\nadded\n");
+
+        // Without a base every line is trimmed
+        assert_eq!(apply_save_transforms(content, None, TRIM), "keep\nnew\nadded\n");
+    }
+
+    #[test]
+    fn test_ensure_single_final_newline() {
+        let transforms = SaveTransforms {
+            ensure_final_newline: true,
+            ..SaveTransforms::default()
+        };
+        assert_eq!(apply_save_transforms("a", None, transforms), "a\n");
+        assert_eq!(apply_save_transforms("a\n\n\n", None, transforms), "a\n");
+        assert_eq!(apply_save_transforms("a\n", None, transforms), "a\n");
+        assert_eq!(apply_save_transforms("", None, transforms), "");
+    }
+
+    #[test]
+    fn test_normalize_indentation_to_dominant_style() {
+        let transforms = SaveTransforms {
+            normalize_indentation: true,
+            ..SaveTransforms::default()
+        };
+        let content = "fn a() {\n\tx;\n\ty;\n    z;\n}\n";
+        assert_eq!(
+            apply_save_transforms(content, None, transforms),
+            "fn a() {\n\tx;\n\ty;\n\tz;\n}\n"
+        );
+
+        let content = "a:\n  b: 1\n  c: 2\n\td: 3\n";
+        assert_eq!(
+            apply_save_transforms(content, None, transforms),
+            "a:\n  b: 1\n  c: 2\n    d: 3\n"
+        );
+    }
+
+    #[test]
+    fn test_disabled_transforms_keep_content() {
+        let content = "a  \n\tb\n    c\n\n\n";
+        assert_eq!(apply_save_transforms(content, None, SaveTransforms::default()), content);
+    }
+
+    #[test]
+    fn test_language_overrides() {
+        let config: SaveConfig = serde_json::from_str(
+            r#"{
+                "trim_trailing_whitespace": true,
+                "ensure_final_newline": true,
+                "languages": { "markdown": { "trim_trailing_whitespace": false } }
+            }"#,
+        )
+        .unwrap();
+
+        let rust = config.transforms_for(Some("rust"));
+        assert!(rust.trim_trailing_whitespace && rust.ensure_final_newline);
+        let markdown = config.transforms_for(Some("markdown"));
+        assert!(!markdown.trim_trailing_whitespace && markdown.ensure_final_newline);
+        assert_eq!(config.transforms_for(None), config.transforms);
+    }
+
+    // Chunk: docs/chunks/format_on_save - Formatter commands by language
+    #[test]
+    fn test_formatter_for_language() {
+        let config: SaveConfig =
+            serde_json::from_str(r#"{ "formatters": { "rust": ["rustfmt"], "go": [] } }"#).unwrap();
+        let rustfmt = ["rustfmt".to_string()];
+        assert_eq!(config.formatter_for(Some("rust")), Some(&rustfmt[..]));
+        assert_eq!(config.formatter_for(Some("go")), None);
+        assert_eq!(config.formatter_for(Some("python")), None);
+        assert_eq!(config.formatter_for(None), None);
+    }
+}
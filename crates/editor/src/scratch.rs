@@ -0,0 +1,102 @@
+// Chunk: docs/chunks/scratch_buffers - Scratch buffers for notes and snippets
+//!
+//! Scratch buffers.
+//!
+//! A scratch buffer is a file tab that is never associated with a file: it
+//! can't be saved, never asks to be saved when closed, and its text is kept
+//! in the session file instead, so it survives restarts without anything
+//! being written into the workspace. The user can pick a language when
+//! creating one to get syntax highlighting.
+
+/// The languages offered when creating a scratch buffer: display name and
+/// the extension the language registry knows it by (`None` for plain text).
+pub const SCRATCH_LANGUAGES: &[(&str, Option<&str>)] = &[
+    ("Plain Text", None),
+    ("Markdown", Some("md")),
+    ("Rust", Some("rs")),
+    ("Python", Some("py")),
+    ("JavaScript", Some("js")),
+    ("TypeScript", Some("ts")),
+    ("Go", Some("go")),
+    ("C", Some("c")),
+    ("C++", Some("cpp")),
+    ("Shell", Some("sh")),
+    ("JSON", Some("json")),
+    ("YAML", Some("yaml")),
+    ("TOML", Some("toml")),
+    ("HTML", Some("html")),
+    ("CSS", Some("css")),
+];
+
+/// What a scratch buffer tab keeps beyond its text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scratch {
+    /// Extension of the language used for highlighting (e.g. `"rs"`), or
+    /// `None` for plain text
+    pub language: Option<String>,
+}
+
+impl Scratch {
+    /// Returns the tab label: "Scratch", followed by the language's name
+    /// when one was chosen.
+    pub fn label(&self) -> String {
+        let Some(ext) = self.language.as_deref() else {
+            return "Scratch".to_string();
+        };
+        let name = SCRATCH_LANGUAGES
+            .iter()
+            .find(|(_, language)| *language == Some(ext))
+            .map_or(ext, |(name, _)| *name);
+        format!("Scratch ({})", name)
+    }
+}
+
+/// Returns the indices into [`SCRATCH_LANGUAGES`] of the languages whose name
+/// or extension contains `query` (case-insensitive), in table order.
+pub fn filter_scratch_languages(query: &str) -> Vec<usize> {
+    let query = query.trim().to_lowercase();
+    SCRATCH_LANGUAGES
+        .iter()
+        .enumerate()
+        .filter(|(_, (name, ext))| {
+            name.to_lowercase().contains(&query) || ext.is_some_and(|ext| ext.contains(&query))
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labels() {
+        assert_eq!(Scratch::default().label(), "Scratch");
+        let rust = Scratch {
+            language: Some("rs".to_string()),
+        };
+        assert_eq!(rust.label(), "Scratch (Rust)");
+        // A language from an older table still gets a label
+        let lua = Scratch {
+            language: Some("lua".to_string()),
+        };
+        assert_eq!(lua.label(), "Scratch (lua)");
+    }
+
+    #[test]
+    fn test_filter_by_name_or_extension() {
+        let names = |query| -> Vec<&str> {
+            filter_scratch_languages(query)
+                .into_iter()
+                .map(|index| SCRATCH_LANGUAGES[index].0)
+                .collect()
+        };
+        assert_eq!(names("script"), vec!["JavaScript", "TypeScript"]);
+        assert_eq!(names("RS"), vec!["Rust"]);
+        assert_eq!(names("").len(), SCRATCH_LANGUAGES.len());
+    }
+}
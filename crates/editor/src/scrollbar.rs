@@ -0,0 +1,358 @@
+// Chunk: docs/chunks/pane_scrollbar - Overlay scrollbar geometry and fading
+//!
+//! Overlay scrollbar for panes.
+//!
+//! Each pane gets a slim thumb along the right edge of its content area. The
+//! thumb is drawn over the text rather than in a gutter of its own, appears
+//! when the pane scrolls and fades out once scrolling stops.
+//!
+//! This module holds the pure parts: thumb geometry from a viewport's scroll
+//! state, the inverse mapping used by click-to-jump and thumb dragging, and
+//! the fade animation. `EditorState` owns the per-pane state and the renderer
+//! only draws the thumbs it is handed.
+
+/// Width of the thumb, in pixels
+pub const SCROLLBAR_WIDTH: f32 = 6.0;
+
+/// Gap between the thumb and the pane's right, top and bottom edges
+pub const SCROLLBAR_INSET: f32 = 2.0;
+
+/// Width of the strip along the right edge that responds to the mouse.
+/// Wider than the thumb so it is easy to grab.
+pub const SCROLLBAR_HIT_WIDTH: f32 = 14.0;
+
+/// Shortest the thumb gets, however long the content
+pub const MIN_THUMB_HEIGHT: f32 = 24.0;
+
+/// How long the scrollbar stays fully visible after the last scroll
+pub const SCROLLBAR_HOLD_SECS: f32 = 1.0;
+
+/// Duration of the fade in when scrolling starts
+pub const SCROLLBAR_FADE_IN_SECS: f32 = 0.1;
+
+/// Duration of the fade out once the hold time has passed
+pub const SCROLLBAR_FADE_OUT_SECS: f32 = 0.4;
+
+// Chunk: docs/chunks/find_scrollbar_ticks - Find-match tick sizing
+/// Height of a find-match tick, in pixels
+pub const MATCH_TICK_HEIGHT: f32 = 2.0;
+
+/// How far above or below a tick a press still lands on it
+pub const MATCH_TICK_HIT_SLOP: f32 = 3.0;
+
+/// Where the scrollbar sits in a pane, and where its thumb is.
+///
+/// All coordinates are in screen space (y=0 at top).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollbarGeometry {
+    /// Left edge of the thumb
+    pub x: f32,
+    /// Top of the range the thumb travels in
+    pub track_y: f32,
+    /// Height of the range the thumb travels in
+    pub track_height: f32,
+    /// Top of the thumb
+    pub thumb_y: f32,
+    /// Height of the thumb
+    pub thumb_height: f32,
+    /// Largest scroll offset of the content, in pixels
+    pub max_offset_px: f32,
+}
+
+/// What a press on the scrollbar landed on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollbarHit {
+    /// The thumb, `grab_offset` pixels below its top
+    Thumb { grab_offset: f32 },
+    /// The track above or below the thumb
+    Track,
+}
+
+/// Computes the scrollbar for a content area showing `visible_rows` of
+/// `total_rows` rows, scrolled `scroll_offset_px` from the top.
+///
+/// `content_rect` is `(x, y, width, height)` of the pane's content area, below
+/// its tab bar. Returns `None` when the content fits and can't scroll.
+pub fn calculate_scrollbar_geometry(
+    content_rect: (f32, f32, f32, f32),
+    total_rows: usize,
+    visible_rows: usize,
+    row_height: f32,
+    scroll_offset_px: f32,
+) -> Option<ScrollbarGeometry> {
+    let (x, y, width, height) = content_rect;
+    let max_offset_px = total_rows.saturating_sub(visible_rows) as f32 * row_height;
+    let track_height = height - 2.0 * SCROLLBAR_INSET;
+    if max_offset_px <= 0.0 || track_height <= 0.0 || total_rows == 0 {
+        return None;
+    }
+
+    let visible_fraction = visible_rows as f32 / total_rows as f32;
+    let thumb_height = (track_height * visible_fraction)
+        .max(MIN_THUMB_HEIGHT)
+        .min(track_height);
+    let scrolled_fraction = (scroll_offset_px / max_offset_px).clamp(0.0, 1.0);
+    let track_y = y + SCROLLBAR_INSET;
+
+    Some(ScrollbarGeometry {
+        x: x + width - SCROLLBAR_WIDTH - SCROLLBAR_INSET,
+        track_y,
+        track_height,
+        thumb_y: track_y + (track_height - thumb_height) * scrolled_fraction,
+        thumb_height,
+        max_offset_px,
+    })
+}
+
+impl ScrollbarGeometry {
+    /// Returns what a press at `(x, y)` lands on, if anything.
+    ///
+    /// The scrollbar responds across a strip `SCROLLBAR_HIT_WIDTH` wide at
+    /// the pane's right edge, for the whole height of the track.
+    pub fn hit_test(&self, x: f32, y: f32) -> Option<ScrollbarHit> {
+        let right = self.x + SCROLLBAR_WIDTH + SCROLLBAR_INSET;
+        let in_strip = x >= right - SCROLLBAR_HIT_WIDTH && x < right;
+        let in_track = y >= self.track_y && y < self.track_y + self.track_height;
+        if !in_strip || !in_track {
+            return None;
+        }
+        if y >= self.thumb_y && y < self.thumb_y + self.thumb_height {
+            Some(ScrollbarHit::Thumb {
+                grab_offset: y - self.thumb_y,
+            })
+        } else {
+            Some(ScrollbarHit::Track)
+        }
+    }
+
+    /// Returns the scroll offset that puts the top of the thumb at
+    /// `thumb_top`, clamped to the content.
+    pub fn offset_for_thumb_top(&self, thumb_top: f32) -> f32 {
+        let travel = self.track_height - self.thumb_height;
+        if travel <= 0.0 {
+            return 0.0;
+        }
+        let fraction = ((thumb_top - self.track_y) / travel).clamp(0.0, 1.0);
+        fraction * self.max_offset_px
+    }
+
+    /// Returns the thumb as `(x, y, width, height)`.
+    pub fn thumb_rect(&self) -> (f32, f32, f32, f32) {
+        (self.x, self.thumb_y, SCROLLBAR_WIDTH, self.thumb_height)
+    }
+}
+
+// Chunk: docs/chunks/find_scrollbar_ticks - Find-match tick placement
+/// Returns the top of the tick for a match on screen row `row` of
+/// `total_rows`, in a content area `(x, y, width, height)`.
+///
+/// Ticks span the scrollbar's track whether or not the content scrolls, so
+/// a match halfway down the file always sits halfway down the pane.
+pub fn match_tick_y(content_rect: (f32, f32, f32, f32), row: usize, total_rows: usize) -> f32 {
+    let (_, y, _, height) = content_rect;
+    let travel = (height - 2.0 * SCROLLBAR_INSET - MATCH_TICK_HEIGHT).max(0.0);
+    let fraction = if total_rows == 0 {
+        0.0
+    } else {
+        (row as f32 / total_rows as f32).min(1.0)
+    };
+    y + SCROLLBAR_INSET + travel * fraction
+}
+
+/// Returns the tick with top `tick_y` as `(x, y, width, height)`, in the
+/// thumb's column.
+pub fn match_tick_rect(content_rect: (f32, f32, f32, f32), tick_y: f32) -> (f32, f32, f32, f32) {
+    let (x, _, width, _) = content_rect;
+    let tick_x = x + width - SCROLLBAR_WIDTH - SCROLLBAR_INSET;
+    (tick_x, tick_y, SCROLLBAR_WIDTH, MATCH_TICK_HEIGHT)
+}
+
+/// Returns the index of the tick a press at `(x, y)` lands on, if any.
+///
+/// `tick_ys` are tick tops. The press must be in the scrollbar's hit strip
+/// and within `MATCH_TICK_HIT_SLOP` of a tick; the nearest tick wins.
+pub fn match_tick_at(
+    content_rect: (f32, f32, f32, f32),
+    tick_ys: &[f32],
+    x: f32,
+    y: f32,
+) -> Option<usize> {
+    let (content_x, _, width, _) = content_rect;
+    let right = content_x + width;
+    if x < right - SCROLLBAR_HIT_WIDTH || x >= right {
+        return None;
+    }
+    let center_offset = MATCH_TICK_HEIGHT / 2.0;
+    tick_ys
+        .iter()
+        .map(|tick_y| (y - (tick_y + center_offset)).abs())
+        .enumerate()
+        .filter(|(_, distance)| *distance <= center_offset + MATCH_TICK_HIT_SLOP)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
+
+/// A thumb for the renderer to draw.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollbarThumb {
+    /// `(x, y, width, height)` in screen space
+    pub rect: (f32, f32, f32, f32),
+    /// 0.0 (hidden) to 1.0 (fully visible)
+    pub opacity: f32,
+}
+
+/// The fade state of one pane's scrollbar.
+///
+/// `show` (re)starts the hold time; `step` advances the fade in or out.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScrollbarFade {
+    /// Current opacity, 0.0 to 1.0
+    opacity: f32,
+    /// Seconds left before the fade out starts
+    hold_secs: f32,
+}
+
+impl ScrollbarFade {
+    /// Starts, or keeps, the scrollbar showing.
+    pub fn show(&mut self) {
+        self.hold_secs = SCROLLBAR_HOLD_SECS;
+    }
+
+    /// Returns the current opacity.
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    /// Returns true while the scrollbar is visible or about to be.
+    pub fn is_active(&self) -> bool {
+        self.hold_secs > 0.0 || self.opacity > 0.0
+    }
+
+    /// Advances the fade by `dt_secs`. Returns true if the opacity changed.
+    pub fn step(&mut self, dt_secs: f32) -> bool {
+        let before = self.opacity;
+        if self.hold_secs > 0.0 {
+            self.hold_secs = (self.hold_secs - dt_secs).max(0.0);
+            self.opacity = (self.opacity + dt_secs / SCROLLBAR_FADE_IN_SECS).min(1.0);
+        } else {
+            self.opacity = (self.opacity - dt_secs / SCROLLBAR_FADE_OUT_SECS).max(0.0);
+        }
+        self.opacity != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: (f32, f32, f32, f32) = (100.0, 32.0, 400.0, 204.0);
+
+    #[test]
+    fn test_no_scrollbar_when_content_fits() {
+        assert!(calculate_scrollbar_geometry(CONTENT, 10, 12, 16.0, 0.0).is_none());
+        assert!(calculate_scrollbar_geometry(CONTENT, 12, 12, 16.0, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_thumb_tracks_scroll_position() {
+        // 100 rows, 10 visible: the thumb is a tenth of the 200px track
+        let top = calculate_scrollbar_geometry(CONTENT, 100, 10, 16.0, 0.0).unwrap();
+        assert_eq!(top.x, 100.0 + 400.0 - SCROLLBAR_WIDTH - SCROLLBAR_INSET);
+        assert_eq!(top.track_y, 34.0);
+        assert_eq!(top.thumb_y, top.track_y);
+        assert_eq!(top.thumb_height, MIN_THUMB_HEIGHT);
+
+        let bottom = calculate_scrollbar_geometry(CONTENT, 100, 10, 16.0, 90.0 * 16.0).unwrap();
+        assert_eq!(bottom.thumb_y + bottom.thumb_height, 34.0 + 200.0);
+    }
+
+    #[test]
+    fn test_thumb_height_is_proportional() {
+        let geometry = calculate_scrollbar_geometry(CONTENT, 20, 10, 16.0, 0.0).unwrap();
+        assert_eq!(geometry.thumb_height, 100.0);
+    }
+
+    #[test]
+    fn test_hit_test_thumb_and_track() {
+        let geometry = calculate_scrollbar_geometry(CONTENT, 20, 10, 16.0, 0.0).unwrap();
+        let x = 100.0 + 400.0 - 4.0;
+
+        assert_eq!(
+            geometry.hit_test(x, 44.0),
+            Some(ScrollbarHit::Thumb { grab_offset: 10.0 })
+        );
+        assert_eq!(geometry.hit_test(x, 200.0), Some(ScrollbarHit::Track));
+        // Left of the strip, and below the track
+        assert_eq!(geometry.hit_test(100.0 + 400.0 - 20.0, 44.0), None);
+        assert_eq!(geometry.hit_test(x, 235.0), None);
+    }
+
+    #[test]
+    fn test_offset_for_thumb_top_inverts_geometry() {
+        let geometry = calculate_scrollbar_geometry(CONTENT, 20, 10, 16.0, 0.0).unwrap();
+        // Travel is 200 - 100 = 100px over 160px of content
+        assert_eq!(geometry.offset_for_thumb_top(geometry.track_y + 50.0), 80.0);
+        assert_eq!(geometry.offset_for_thumb_top(0.0), 0.0);
+        assert_eq!(geometry.offset_for_thumb_top(1000.0), 160.0);
+
+        let moved = calculate_scrollbar_geometry(CONTENT, 20, 10, 16.0, 80.0).unwrap();
+        assert_eq!(moved.thumb_y, geometry.track_y + 50.0);
+    }
+
+    #[test]
+    fn test_match_ticks_span_the_track() {
+        assert_eq!(match_tick_y(CONTENT, 0, 10), 34.0);
+        // Travel is 204 - 4 - 2 = 198px
+        assert_eq!(match_tick_y(CONTENT, 5, 10), 34.0 + 99.0);
+        assert_eq!(match_tick_y(CONTENT, 10, 10), 34.0 + 198.0);
+
+        let (x, y, width, height) = match_tick_rect(CONTENT, 50.0);
+        assert_eq!(x, 100.0 + 400.0 - SCROLLBAR_WIDTH - SCROLLBAR_INSET);
+        assert_eq!(y, 50.0);
+        assert_eq!((width, height), (SCROLLBAR_WIDTH, MATCH_TICK_HEIGHT));
+    }
+
+    #[test]
+    fn test_match_tick_hit_picks_nearest() {
+        let ticks = [40.0, 46.0, 120.0];
+        let x = 100.0 + 400.0 - 4.0;
+
+        assert_eq!(match_tick_at(CONTENT, &ticks, x, 41.0), Some(0));
+        assert_eq!(match_tick_at(CONTENT, &ticks, x, 46.5), Some(1));
+        assert_eq!(match_tick_at(CONTENT, &ticks, x, 124.0), Some(2));
+        assert_eq!(match_tick_at(CONTENT, &ticks, x, 90.0), None);
+        // Left of the strip
+        let left = 100.0 + 400.0 - 20.0;
+        assert_eq!(match_tick_at(CONTENT, &ticks, left, 41.0), None);
+    }
+
+    #[test]
+    fn test_fade_in_hold_and_out() {
+        let mut fade = ScrollbarFade::default();
+        assert!(!fade.is_active());
+
+        fade.show();
+        assert!(fade.is_active());
+        fade.step(SCROLLBAR_FADE_IN_SECS);
+        assert_eq!(fade.opacity(), 1.0);
+
+        // Stays visible for the hold time, then fades out
+        fade.step(SCROLLBAR_HOLD_SECS);
+        assert_eq!(fade.opacity(), 1.0);
+        fade.step(SCROLLBAR_FADE_OUT_SECS / 2.0);
+        assert!(fade.opacity() < 1.0 && fade.opacity() > 0.0);
+        fade.step(SCROLLBAR_FADE_OUT_SECS);
+        assert_eq!(fade.opacity(), 0.0);
+        assert!(!fade.is_active());
+    }
+
+    #[test]
+    fn test_show_restarts_hold() {
+        let mut fade = ScrollbarFade::default();
+        fade.show();
+        fade.step(SCROLLBAR_HOLD_SECS);
+        fade.show();
+        fade.step(SCROLLBAR_HOLD_SECS / 2.0);
+        assert_eq!(fade.opacity(), 1.0);
+    }
+}
@@ -82,6 +82,10 @@ pub struct SelectorWidget {
     mini_buffer: MiniBuffer,
     /// The current list of displayable strings.
     items: Vec<String>,
+    // Chunk: docs/chunks/fuzzy_ranked_scoring - Per-item match highlights
+    /// Char indices of query matches in each item, parallel to `items`.
+    /// Empty when the items were set without match information.
+    match_positions: Vec<Vec<usize>>,
     /// Index into `items` of the currently highlighted entry.
     /// Always clamped to valid bounds (0..items.len(), or 0 if empty).
     selected_index: usize,
@@ -112,6 +116,7 @@ impl SelectorWidget {
         Self {
             mini_buffer: MiniBuffer::new(metrics),
             items: Vec::new(),
+            match_positions: Vec::new(),
             selected_index: 0,
             scroll: RowScroller::new(metrics.line_height as f32),
         }
@@ -130,6 +135,12 @@ impl SelectorWidget {
         self.selected_index
     }
 
+    // Chunk: docs/chunks/recent_files_switcher - Initial selection for the recent files switcher
+    /// Highlights the item at `index`, clamped to the current items list.
+    pub fn set_selected_index(&mut self, index: usize) {
+        self.selected_index = index.min(self.items.len().saturating_sub(1));
+    }
+
     /// Returns the current items list.
     pub fn items(&self) -> &[String] {
         &self.items
@@ -169,6 +180,7 @@ impl SelectorWidget {
     /// to zero (e.g., after a query narrows results).
     pub fn set_items(&mut self, items: Vec<String>) {
         self.items = items;
+        self.match_positions.clear();
         // Clamp selected_index to valid range
         if self.items.is_empty() {
             self.selected_index = 0;
@@ -311,7 +323,10 @@ impl SelectorWidget {
                     SelectorOutcome::Pending
                 }
             }
-            MouseEventKind::Moved => SelectorOutcome::Pending,
+            MouseEventKind::Moved
+            | MouseEventKind::Back
+            | MouseEventKind::Forward
+            | MouseEventKind::Hover => SelectorOutcome::Pending,
         }
     }
 
@@ -346,18 +361,54 @@ impl SelectorWidget {
         self.scroll.visible_range(self.items.len())
     }
 
+    // Chunk: docs/chunks/fuzzy_ranked_scoring - Items with match highlights
+    /// Like [`set_items`](Self::set_items), also recording which characters of
+    /// each item matched the query so the overlay can highlight them.
+    ///
+    /// `match_positions[i]` holds char indices into `items[i]`.
+    pub fn set_items_with_matches(&mut self, items: Vec<String>, match_positions: Vec<Vec<usize>>) {
+        debug_assert_eq!(items.len(), match_positions.len());
+        self.set_items(items);
+        self.match_positions = match_positions;
+    }
+
+    /// Returns the char indices of query matches in the item at `index`.
+    ///
+    /// Empty if the item has no recorded matches.
+    pub fn item_match_positions(&self, index: usize) -> &[usize] {
+        self.match_positions.get(index).map(Vec::as_slice).unwrap_or(&[])
+    }
+
     // Chunk: docs/chunks/minibuffer_input - Text input support for selector
     /// Handles text input events (from IME, keyboard, paste).
     ///
     /// Inserts text into the query field and resets selection to index 0
     /// if the query changed. Use this for macOS `insertText:` events.
     pub fn handle_text_input(&mut self, text: &str) {
+        self.replace_before_cursor(0, text);
+    }
+
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Accent replaces the held key's character
+    /// Like `handle_text_input`, but first deletes `count` characters before
+    /// the cursor.
+    pub fn replace_before_cursor(&mut self, count: usize, text: &str) {
         let prev_query = self.mini_buffer.content();
-        self.mini_buffer.handle_text_input(text);
+        self.mini_buffer.replace_before_cursor(count, text);
         if self.mini_buffer.content() != prev_query {
             self.selected_index = 0;
         }
     }
+
+    // Chunk: docs/chunks/workspace_rename_reorder - Prefilled query for rename prompts
+    /// Replaces the query with `text`, leaving the cursor at its end.
+    ///
+    /// Used to prefill the query when the selector serves as a text prompt
+    /// (e.g. renaming a workspace starts from the current name).
+    pub fn set_query(&mut self, text: &str) {
+        self.mini_buffer.clear();
+        self.mini_buffer.handle_text_input(text);
+        self.selected_index = 0;
+    }
 }
 
 #[cfg(test)]
@@ -807,6 +858,18 @@ mod tests {
     // Step 3: Keyboard navigation (Up/Down)
     // =========================================================================
 
+    #[test]
+    fn set_selected_index_clamps_to_items() {
+        let mut widget = SelectorWidget::new();
+        widget.set_items(vec!["a".into(), "b".into(), "c".into()]);
+
+        widget.set_selected_index(1);
+        assert_eq!(widget.selected_index(), 1);
+
+        widget.set_selected_index(10);
+        assert_eq!(widget.selected_index(), 2);
+    }
+
     #[test]
     fn down_from_index_zero_increments_to_one() {
         let mut widget = SelectorWidget::new();
@@ -938,6 +1001,35 @@ mod tests {
         assert_eq!(widget.selected_index(), 0);
     }
 
+    #[test]
+    fn set_items_with_matches_records_positions() {
+        let mut widget = SelectorWidget::new();
+        widget.set_items_with_matches(
+            vec!["src/main.rs".into(), "lib.rs".into()],
+            vec![vec![4, 5], vec![0]],
+        );
+        assert_eq!(widget.item_match_positions(0), &[4, 5]);
+        assert_eq!(widget.item_match_positions(1), &[0]);
+        assert!(widget.item_match_positions(2).is_empty());
+
+        // Plain set_items drops stale highlights
+        widget.set_items(vec!["src/main.rs".into()]);
+        assert!(widget.item_match_positions(0).is_empty());
+    }
+
+    #[test]
+    fn set_query_replaces_query() {
+        let mut widget = SelectorWidget::new();
+        widget.handle_key(&KeyEvent::char('x'));
+
+        widget.set_query("backend");
+        assert_eq!(widget.query(), "backend");
+
+        // Typing continues from the end of the prefilled query
+        widget.handle_key(&KeyEvent::char('s'));
+        assert_eq!(widget.query(), "backends");
+    }
+
     #[test]
     fn backspace_removes_last_char() {
         let mut widget = SelectorWidget::new();
@@ -25,6 +25,7 @@ use objc2::rc::Retained;
 use objc2::runtime::ProtocolObject;
 use objc2_metal::{MTLBuffer, MTLDevice, MTLResourceOptions};
 
+use crate::find_target::FindOptions;
 use crate::glyph_atlas::{GlyphAtlas, GlyphInfo};
 use crate::glyph_buffer::{GlyphLayout, GlyphVertex, QuadRange};
 use crate::selector::SelectorWidget;
@@ -75,6 +76,21 @@ pub const OVERLAY_SELECTION_COLOR: [f32; 4] = [
     1.0,
 ];
 
+// Chunk: docs/chunks/fuzzy_ranked_scoring - Matched character highlight
+/// Color for item characters matched by the query: #fab387 (peach)
+pub const OVERLAY_MATCH_COLOR: [f32; 4] = [
+    0.980, // 0xfa / 255
+    0.702, // 0xb3 / 255
+    0.529, // 0x87 / 255
+    1.0,
+];
+
+/// Horizontal offset of the second strike used to embolden matched characters.
+///
+/// The glyph atlas has a single (regular) face, so matches are drawn twice,
+/// one pixel apart.
+pub const MATCH_EMBOLDEN_OFFSET: f32 = 1.0;
+
 /// Separator line color: subdued grey
 pub const OVERLAY_SEPARATOR_COLOR: [f32; 4] = [
     0.4, // slightly lighter than background
@@ -504,8 +520,10 @@ impl SelectorGlyphBuffer {
             for (draw_idx, item) in items[visible_range.clone()].iter().enumerate() {
                 let y = list_y + draw_idx as f32 * geometry.item_height;
                 let mut x = geometry.content_x;
+                // Chunk: docs/chunks/fuzzy_ranked_scoring - Emboldened, colored query matches
+                let matches = widget.item_match_positions(visible_range.start + draw_idx);
 
-                for c in item.chars() {
+                for (char_idx, c) in item.chars().enumerate() {
                     // Skip if past content boundary (clip long items)
                     if x + self.layout.glyph_width > max_x {
                         break;
@@ -518,10 +536,19 @@ impl SelectorGlyphBuffer {
                     }
 
                     if let Some(glyph) = atlas.get_glyph(c) {
-                        let quad = self.create_glyph_quad_at(x, y, glyph, text_color);
+                        let matched = matches.binary_search(&char_idx).is_ok();
+                        let color = if matched { OVERLAY_MATCH_COLOR } else { text_color };
+                        let quad = self.create_glyph_quad_at(x, y, glyph, color);
                         self.persistent_vertices.extend_from_slice(&quad);
                         Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
                         vertex_offset += 4;
+                        if matched {
+                            let quad =
+                                self.create_glyph_quad_at(x + MATCH_EMBOLDEN_OFFSET, y, glyph, color);
+                            self.persistent_vertices.extend_from_slice(&quad);
+                            Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+                            vertex_offset += 4;
+                        }
                     }
                     x += self.layout.glyph_width;
                 }
@@ -747,6 +774,31 @@ pub fn calculate_status_bar_geometry_in_pane(
     }
 }
 
+// Chunk: docs/chunks/pane_breadcrumbs - Breadcrumb strip geometry
+/// Calculates the geometry for a pane's breadcrumb strip.
+///
+/// The strip has the status bar's height and padding but sits along the top
+/// of the pane's content area, whose top-left corner is
+/// (`content_x`, `content_y`), over the first row of text.
+pub fn calculate_breadcrumb_geometry(
+    content_x: f32,
+    content_y: f32,
+    content_width: f32,
+    line_height: f32,
+    glyph_width: f32,
+) -> StatusBarGeometry {
+    StatusBarGeometry {
+        strip_x: content_x,
+        strip_y: content_y,
+        strip_width: content_width,
+        strip_height: line_height + 2.0 * STATUS_BAR_PADDING_Y,
+        text_x: content_x + STATUS_BAR_PADDING_X,
+        text_y: content_y + STATUS_BAR_PADDING_Y,
+        glyph_width,
+        line_height,
+    }
+}
+
 // Chunk: docs/chunks/gotodef_status_render - Status bar glyph buffer
 /// Manages vertex and index buffers for rendering the status bar.
 ///
@@ -1011,6 +1063,9 @@ pub struct FindStripState<'a> {
     pub cursor_col: usize,
     /// Whether the cursor is currently visible (for blinking)
     pub cursor_visible: bool,
+    // Chunk: docs/chunks/find_options - Toggle badges
+    /// Matching options, shown as badges at the right of the strip
+    pub options: FindOptions,
 }
 
 /// Horizontal padding for the find strip
@@ -1025,6 +1080,18 @@ const FIND_LABEL_TEXT: &str = "find:";
 /// Dim text color for the "find:" label
 pub const FIND_LABEL_COLOR: [f32; 4] = [0.5, 0.5, 0.5, 1.0];
 
+// Chunk: docs/chunks/find_options - Toggle badges
+/// Badge labels for the case-sensitivity and whole-word toggles, left to right
+pub const FIND_OPTION_BADGES: [&str; 2] = ["Aa", "W"];
+
+/// Background of a badge whose option is on: #89b4fa at 35% (Catppuccin Mocha blue)
+pub const FIND_BADGE_ACTIVE_COLOR: [f32; 4] = [
+    0.537, // 0x89 / 255
+    0.706, // 0xb4 / 255
+    0.980, // 0xfa / 255
+    0.35,
+];
+
 /// Computed geometry for the find strip (bottom-anchored, 1 line tall)
 ///
 /// All values are in screen coordinates (pixels).
@@ -1053,6 +1120,24 @@ pub struct FindStripGeometry {
     pub line_height: f32,
 }
 
+impl FindStripGeometry {
+    // Chunk: docs/chunks/find_options - Toggle badges
+    /// Returns `(x, width)` of each badge in `FIND_OPTION_BADGES`.
+    ///
+    /// Badges are right-aligned in the strip, one glyph apart, each with a
+    /// glyph of padding either side of its label.
+    pub fn option_badges(&self) -> [(f32, f32); 2] {
+        let mut right = self.strip_x + self.strip_width - FIND_STRIP_PADDING_X;
+        let mut badges = [(0.0, 0.0); 2];
+        for (badge, label) in badges.iter_mut().zip(FIND_OPTION_BADGES).rev() {
+            let width = (label.chars().count() + 2) as f32 * self.glyph_width;
+            *badge = (right - width, width);
+            right -= width + self.glyph_width;
+        }
+        badges
+    }
+}
+
 /// Calculates the geometry for the find strip
 ///
 /// The find strip is anchored to the bottom of the viewport, is 1 line tall
@@ -1168,6 +1253,9 @@ pub struct FindStripGlyphBuffer {
     query_text_range: QuadRange,
     /// Query cursor quad (if visible)
     cursor_range: QuadRange,
+    // Chunk: docs/chunks/find_options - Toggle badges
+    /// Option badge backgrounds and labels
+    badge_range: QuadRange,
 
     // Chunk: docs/chunks/quad_buffer_prealloc - Persistent buffers to avoid per-frame heap allocations
     /// Persistent vertex data buffer, reused across frames
@@ -1189,6 +1277,7 @@ impl FindStripGlyphBuffer {
             label_range: QuadRange::default(),
             query_text_range: QuadRange::default(),
             cursor_range: QuadRange::default(),
+            badge_range: QuadRange::default(),
             persistent_vertices: Vec::new(),
             persistent_indices: Vec::new(),
         }
@@ -1229,6 +1318,11 @@ impl FindStripGlyphBuffer {
         self.cursor_range
     }
 
+    /// Returns the index range for the option badges
+    pub fn badge_range(&self) -> QuadRange {
+        self.badge_range
+    }
+
     /// Updates the buffers with find strip content
     ///
     /// # Arguments
@@ -1237,6 +1331,7 @@ impl FindStripGlyphBuffer {
     /// * `query` - The current find query text
     /// * `geometry` - The computed find strip geometry
     /// * `cursor_visible` - Whether to render the cursor
+    /// * `options` - Matching options, shown as badges
     pub fn update(
         &mut self,
         device: &ProtocolObject<dyn MTLDevice>,
@@ -1244,11 +1339,14 @@ impl FindStripGlyphBuffer {
         query: &str,
         geometry: &FindStripGeometry,
         cursor_visible: bool,
+        options: FindOptions,
     ) {
         // Estimate capacity
         let label_len = FIND_LABEL_TEXT.len();
         let query_len = query.chars().count();
-        let estimated_quads = 1 + label_len + query_len + 1; // bg + label + query + cursor
+        let badge_len: usize = FIND_OPTION_BADGES.iter().map(|label| label.len() + 1).sum();
+        // bg + label + query + cursor + badges
+        let estimated_quads = 1 + label_len + query_len + 1 + badge_len;
 
         // Chunk: docs/chunks/quad_buffer_prealloc - Reuse persistent buffers instead of allocating new ones
         self.persistent_vertices.clear();
@@ -1269,6 +1367,7 @@ impl FindStripGlyphBuffer {
         self.label_range = QuadRange::default();
         self.query_text_range = QuadRange::default();
         self.cursor_range = QuadRange::default();
+        self.badge_range = QuadRange::default();
 
         let solid_glyph = atlas.solid_glyph();
 
@@ -1351,11 +1450,50 @@ impl FindStripGlyphBuffer {
             );
             self.persistent_vertices.extend_from_slice(&quad);
             Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
-            #[allow(unused_assignments)]
-            { vertex_offset += 4; }
+            vertex_offset += 4;
         }
         self.cursor_range = QuadRange::new(cursor_start, self.persistent_indices.len() - cursor_start);
 
+        // ==================== Phase 5: Option Badges ====================
+        // Chunk: docs/chunks/find_options - Toggle badges
+        // An option that is on gets a tinted badge and bright label; one that
+        // is off just shows its label, dimmed.
+        let badge_start = self.persistent_indices.len();
+        {
+            let enabled = [options.case_sensitive, options.whole_word];
+            let badges = geometry.option_badges();
+            for ((&(badge_x, badge_width), label), on) in
+                badges.iter().zip(FIND_OPTION_BADGES).zip(enabled)
+            {
+                if on {
+                    let quad = self.create_rect_quad(
+                        badge_x,
+                        geometry.text_y,
+                        badge_width,
+                        geometry.line_height,
+                        solid_glyph,
+                        FIND_BADGE_ACTIVE_COLOR,
+                    );
+                    self.persistent_vertices.extend_from_slice(&quad);
+                    Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+                    vertex_offset += 4;
+                }
+
+                let color = if on { text_color } else { FIND_LABEL_COLOR };
+                let mut x = badge_x + geometry.glyph_width;
+                for c in label.chars() {
+                    if let Some(glyph) = atlas.get_glyph(c) {
+                        let quad = self.create_glyph_quad_at(x, geometry.text_y, glyph, color);
+                        self.persistent_vertices.extend_from_slice(&quad);
+                        Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+                        vertex_offset += 4;
+                    }
+                    x += geometry.glyph_width;
+                }
+            }
+        }
+        self.badge_range = QuadRange::new(badge_start, self.persistent_indices.len() - badge_start);
+
         // ==================== Create GPU Buffers ====================
         if self.persistent_vertices.is_empty() {
             self.vertex_buffer = None;
@@ -1682,6 +1820,20 @@ mod tests {
         // but that's handled by scissor clipping
     }
 
+    // Chunk: docs/chunks/find_options - Badge placement
+    #[test]
+    fn find_option_badges_are_right_aligned() {
+        let geometry =
+            calculate_find_strip_geometry_in_pane(100.0, 50.0, 400.0, 300.0, 16.0, 8.0, 0);
+        let [case, whole_word] = geometry.option_badges();
+
+        // "W" plus padding is 3 glyphs, flush with the right padding
+        let right = 100.0 + 400.0 - FIND_STRIP_PADDING_X;
+        assert_eq!(whole_word, (right - 24.0, 24.0));
+        // "Aa" plus padding is 4 glyphs, one glyph to the left of it
+        assert_eq!(case, (whole_word.0 - 8.0 - 32.0, 32.0));
+    }
+
     #[test]
     fn find_strip_in_pane_vs_viewport_geometry_differs() {
         // Verify that pane geometry produces different results than viewport geometry
@@ -0,0 +1,72 @@
+// Chunk: docs/chunks/macos_services - Selection offered to macOS services
+//!
+//! The selection offered to macOS services.
+//!
+//! AppKit asks the responder chain for a requestor whenever it updates the
+//! Services menu, and asks the chosen requestor to write its selection to a
+//! pasteboard when a service runs. The app delegate answers both from a
+//! [`ServiceSelectionSnapshot`], which the drain loop refreshes from
+//! `EditorState::service_selection` after each change, as it does the menu
+//! item states, so AppKit never reaches into the editor while it is busy.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// What the editor can give to and take from a service.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServiceSelection {
+    /// The selected text, sent to services that take text.
+    pub text: Option<String>,
+    /// Whether text a service returns can replace the selection, or be
+    /// inserted at the cursor when nothing is selected.
+    pub editable: bool,
+}
+
+impl ServiceSelection {
+    /// Returns whether the editor can run a service that takes text (when
+    /// `sends_text`) and returns text (when `returns_text`).
+    ///
+    /// Services that take nothing and return nothing are left to other
+    /// requestors.
+    pub fn accepts(&self, sends_text: bool, returns_text: bool) -> bool {
+        if !sends_text && !returns_text {
+            return false;
+        }
+        (!sends_text || self.text.is_some()) && (!returns_text || self.editable)
+    }
+}
+
+/// The current service selection.
+///
+/// Written by the drain loop, read when AppKit looks for a service requestor.
+pub type ServiceSelectionSnapshot = Rc<RefCell<ServiceSelection>>;
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts() {
+        let terminal = ServiceSelection {
+            text: Some("ls -la".to_string()),
+            editable: false,
+        };
+        assert!(terminal.accepts(true, false));
+        assert!(!terminal.accepts(true, true));
+        assert!(!terminal.accepts(false, true));
+
+        let empty_file = ServiceSelection {
+            text: None,
+            editable: true,
+        };
+        assert!(!empty_file.accepts(true, false));
+        assert!(empty_file.accepts(false, true));
+
+        assert!(!ServiceSelection::default().accepts(false, false));
+        assert!(!terminal.accepts(false, false));
+    }
+}
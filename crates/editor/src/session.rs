@@ -12,9 +12,21 @@
 //! - All open workspaces with their root paths
 //! - The pane layout (split structure) for each workspace
 //! - For each pane: the ordered list of file tabs (by absolute path) and active tab
+//! - The text and language of scratch buffers, which have no file of their own
+//! - The name and color label of terminal tabs, their last known working
+//!   directory and the startup command set for them
 //! - Which workspace was active at exit
 //!
-//! Terminal tabs are NOT restored (they cannot be meaningfully serialized).
+//! A terminal's scrollback and running programs are not saved. Its tab comes
+//! back with a fresh shell in the saved directory, started by
+//! `EditorState::spawn_restored_terminals` once the event loop can wake for
+//! its output, which then runs the tab's startup command if it has one.
+//!
+//! ## Idle Autosave
+//!
+//! Besides the save at quit, `SessionAutosave` rewrites the session file after
+//! the user has been idle for a few seconds, at most once per
+//! `AUTOSAVE_INTERVAL`, so a crash or force-kill loses little session state.
 //!
 //! ## File Location
 //!
@@ -28,13 +40,17 @@
 
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 
+use crate::file_view::{view_mode_size, FileView};
 use crate::pane_layout::{gen_pane_id, Pane, PaneId, PaneLayoutNode, SplitDirection};
-use crate::workspace::{Editor, Tab, TabKind, Workspace};
+use crate::scratch::Scratch;
+use crate::workspace::{Editor, Tab, TabColor, TabKind, Workspace};
 use lite_edit_buffer::TextBuffer;
+use lite_edit_terminal::TerminalBuffer;
 
 /// Current schema version for the session file.
 ///
@@ -47,6 +63,13 @@ const APP_NAME: &str = "lite-edit";
 /// Session file name.
 const SESSION_FILENAME: &str = "session.json";
 
+// Chunk: docs/chunks/session_idle_autosave - Autosave timing
+/// How long the user must be idle before an autosave is attempted.
+pub const AUTOSAVE_IDLE_THRESHOLD: Duration = Duration::from_secs(5);
+
+/// Minimum time between autosave attempts.
+pub const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(60);
+
 // =============================================================================
 // Serializable Data Types
 // =============================================================================
@@ -133,12 +156,101 @@ pub struct PaneData {
 
 /// Serializable representation of a tab.
 ///
-/// Only file tabs with an associated file path are serialized.
-/// Terminals, agent tabs, and unsaved new files are skipped.
+/// Only file tabs with an associated file path, scratch buffers and terminals
+/// are serialized. Agent tabs and unsaved new files are skipped.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TabData {
-    /// The absolute path to the file.
+    /// The absolute path to the file (empty for a scratch buffer).
+    #[serde(default)]
     pub file_path: PathBuf,
+    // Chunk: docs/chunks/scratch_buffers - Scratch buffers live in the session
+    /// The scratch buffer this tab holds, if it is one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scratch: Option<ScratchData>,
+    // Chunk: docs/chunks/terminal_tab_labels - Terminal tabs live in the session
+    /// The terminal this tab holds, if it is one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal: Option<TerminalData>,
+    // Chunk: docs/chunks/session_idle_autosave - Tabs reopen where they were scrolled to
+    /// How far the tab was scrolled, in pixels. Terminals always reopen at
+    /// the top, as their scrollback isn't saved.
+    #[serde(default)]
+    pub scroll_offset_px: f32,
+}
+
+// Chunk: docs/chunks/scratch_buffers - Scratch buffers live in the session
+/// Serializable representation of a scratch buffer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScratchData {
+    /// The buffer's text.
+    pub content: String,
+    /// Extension of the buffer's language, if one was chosen.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+// Chunk: docs/chunks/terminal_tab_labels - Terminal tabs live in the session
+/// Serializable representation of a terminal tab.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalData {
+    /// The tab's name.
+    pub label: String,
+    /// Name of the tab's color label, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    // Chunk: docs/chunks/terminal_session_restore - Directory and startup command
+    /// The shell's last known working directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<PathBuf>,
+    /// The command to run in the new shell, e.g. `npm run dev`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub startup_command: Option<String>,
+}
+
+impl TabData {
+    /// Creates a TabData from a live Tab, or `None` if the tab isn't saved
+    /// in the session.
+    fn from_tab(tab: &Tab) -> Option<Self> {
+        // Chunk: docs/chunks/terminal_tab_labels - Terminals keep their name and color
+        if tab.kind == TabKind::Terminal {
+            return Some(TabData {
+                file_path: PathBuf::new(),
+                scratch: None,
+                terminal: Some(TerminalData {
+                    label: tab.label.clone(),
+                    color: tab.color_label.map(|color| color.name().to_string()),
+                    // A restored shell that hasn't started yet keeps the
+                    // directory it was saved with
+                    cwd: tab
+                        .as_terminal_buffer()
+                        .and_then(|terminal| terminal.current_dir())
+                        .or_else(|| tab.restored_cwd.clone()),
+                    startup_command: tab.startup_command.clone(),
+                }),
+                scroll_offset_px: 0.0,
+            });
+        }
+        if tab.kind != TabKind::File {
+            return None;
+        }
+        if let Some(scratch) = &tab.scratch {
+            return Some(TabData {
+                file_path: PathBuf::new(),
+                scratch: Some(ScratchData {
+                    content: tab.as_text_buffer()?.content(),
+                    language: scratch.language.clone(),
+                }),
+                terminal: None,
+                scroll_offset_px: tab.viewport.scroll_offset_px(),
+            });
+        }
+        tab.associated_file.as_ref().map(|path| TabData {
+            file_path: path.clone(),
+            scratch: None,
+            terminal: None,
+            scroll_offset_px: tab.viewport.scroll_offset_px(),
+        })
+    }
 }
 
 // =============================================================================
@@ -251,45 +363,22 @@ impl PaneLayoutData {
 impl PaneData {
     /// Creates a PaneData from a live Pane.
     ///
-    /// Only file tabs with an associated file are included.
-    /// Terminals, agent tabs, and unsaved files are filtered out.
+    /// Only file tabs with an associated file, scratch buffers and terminals
+    /// are included. Agent tabs and unsaved files are filtered out.
     fn from_pane(pane: &Pane) -> Self {
-        let tabs: Vec<TabData> = pane
-            .tabs
-            .iter()
-            .filter_map(|tab| {
-                // Only include file tabs with an associated file
-                if tab.kind == TabKind::File {
-                    tab.associated_file.as_ref().map(|path| TabData {
-                        file_path: path.clone(),
-                    })
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        // Adjust active_tab to account for skipped tabs
-        // Find the index of the active tab in our filtered list
-        let active_tab = if pane.active_tab < pane.tabs.len() {
-            let active_file = pane.tabs.get(pane.active_tab).and_then(|t| {
-                if t.kind == TabKind::File {
-                    t.associated_file.as_ref()
-                } else {
-                    None
-                }
-            });
-
-            // Find this file's position in the filtered tabs list
-            if let Some(file) = active_file {
-                tabs.iter().position(|t| &t.file_path == file).unwrap_or(0)
-            } else {
-                // Active tab was filtered out, default to first
-                0
+        let mut tabs: Vec<TabData> = Vec::new();
+        // Adjust active_tab to account for skipped tabs; if the active tab
+        // was filtered out, default to first
+        let mut active_tab = 0;
+        for (index, tab) in pane.tabs.iter().enumerate() {
+            let Some(data) = TabData::from_tab(tab) else {
+                continue;
+            };
+            if index == pane.active_tab {
+                active_tab = tabs.len();
             }
-        } else {
-            0
-        };
+            tabs.push(data);
+        }
 
         PaneData {
             id: pane.id,
@@ -323,15 +412,82 @@ pub fn save_session(editor: &Editor) -> io::Result<()> {
 
     let session_data = SessionData::from_editor(editor);
     let json = serde_json::to_string_pretty(&session_data)?;
+    write_session_file(&path, &json)
+}
 
-    // Atomic write: write to temp file, then rename
+/// Writes serialized session JSON to `path`.
+///
+/// Atomic write: write to temp file, then rename, so a crash mid-write never
+/// leaves a truncated session file behind.
+fn write_session_file(path: &Path, json: &str) -> io::Result<()> {
     let temp_path = path.with_extension("json.tmp");
     fs::write(&temp_path, json)?;
-    fs::rename(&temp_path, &path)?;
-
+    fs::rename(&temp_path, path)?;
     Ok(())
 }
 
+// =============================================================================
+// Idle Autosave
+// =============================================================================
+
+// Chunk: docs/chunks/session_idle_autosave - Periodic session save during idle periods
+/// Tracks when the session was last autosaved and what was written.
+///
+/// The drain loop calls `maybe_save` on each blink timer tick. A save happens
+/// only when the user has been idle for `AUTOSAVE_IDLE_THRESHOLD` and
+/// `AUTOSAVE_INTERVAL` has elapsed since the previous attempt. The file is
+/// rewritten only if the serialized session differs from the last write.
+#[derive(Debug)]
+pub struct SessionAutosave {
+    /// When the last autosave attempt was made (successful or not).
+    last_attempt: Instant,
+    /// The JSON written by the last successful autosave.
+    last_written: Option<String>,
+}
+
+impl SessionAutosave {
+    /// Creates a tracker whose first autosave is due one interval from `now`.
+    pub fn new(now: Instant) -> Self {
+        Self {
+            last_attempt: now,
+            last_written: None,
+        }
+    }
+
+    /// Returns true if an autosave should be attempted at `now`, given the
+    /// time of the user's last input.
+    pub fn is_due(&self, now: Instant, last_activity: Instant) -> bool {
+        now.saturating_duration_since(last_activity) >= AUTOSAVE_IDLE_THRESHOLD
+            && now.saturating_duration_since(self.last_attempt) >= AUTOSAVE_INTERVAL
+    }
+
+    /// Saves the session to `path` if an autosave is due and the session
+    /// changed since the last autosave.
+    ///
+    /// Returns `Ok(true)` if the file was written.
+    pub fn maybe_save(
+        &mut self,
+        editor: &Editor,
+        path: &Path,
+        now: Instant,
+        last_activity: Instant,
+    ) -> io::Result<bool> {
+        if !self.is_due(now, last_activity) {
+            return Ok(false);
+        }
+        self.last_attempt = now;
+
+        let json = serde_json::to_string_pretty(&SessionData::from_editor(editor))?;
+        if self.last_written.as_deref() == Some(json.as_str()) {
+            return Ok(false);
+        }
+
+        write_session_file(path, &json)?;
+        self.last_written = Some(json);
+        Ok(true)
+    }
+}
+
 // =============================================================================
 // Load Session
 // =============================================================================
@@ -509,6 +665,15 @@ impl PaneLayoutData {
     }
 }
 
+// Chunk: docs/chunks/session_idle_autosave - Tabs reopen where they were scrolled to
+/// Scrolls a restored `tab` to `px`, clamped to its content. The pane's size
+/// isn't known yet; the first resize clamps it again.
+fn scrolled_to(mut tab: Tab, px: f32) -> Tab {
+    let line_count = tab.buffer().line_count();
+    tab.viewport.set_scroll_offset_px(px, line_count);
+    tab
+}
+
 /// Generates a new unique tab ID.
 fn gen_tab_id(next_id: &mut u64) -> u64 {
     let id = *next_id;
@@ -543,6 +708,35 @@ impl PaneData {
         let mut pane = Pane::new(pane_id, workspace_id);
 
         for tab_data in self.tabs {
+            let scroll_offset_px = tab_data.scroll_offset_px;
+            // Chunk: docs/chunks/scratch_buffers - Scratch buffers come back from the session
+            if let Some(scratch) = tab_data.scratch {
+                let tab_id = gen_tab_id(next_tab_id);
+                let tab = Tab::new_scratch(
+                    tab_id,
+                    &scratch.content,
+                    Scratch {
+                        language: scratch.language,
+                    },
+                    line_height,
+                );
+                pane.add_tab(scrolled_to(tab, scroll_offset_px));
+                continue;
+            }
+
+            // Chunk: docs/chunks/terminal_tab_labels - Terminal tabs come back unspawned
+            // The shell is spawned later, by EditorState::spawn_restored_terminals
+            if let Some(terminal) = tab_data.terminal {
+                let tab_id = gen_tab_id(next_tab_id);
+                let buffer = TerminalBuffer::new(80, 24, 5000);
+                let mut tab = Tab::new_terminal(tab_id, buffer, terminal.label, line_height);
+                tab.color_label = terminal.color.as_deref().and_then(TabColor::from_name);
+                tab.restored_cwd = terminal.cwd;
+                tab.startup_command = terminal.startup_command;
+                pane.add_tab(tab);
+                continue;
+            }
+
             // Skip files that no longer exist
             if !tab_data.file_path.is_file() {
                 eprintln!(
@@ -552,6 +746,19 @@ impl PaneData {
                 continue;
             }
 
+            // Chunk: docs/chunks/file_view_mode - Giant files come back as views
+            if view_mode_size(&tab_data.file_path).is_some() {
+                match FileView::open(&tab_data.file_path) {
+                    Ok(view) => {
+                        let tab_id = gen_tab_id(next_tab_id);
+                        let tab = Tab::new_file_view(tab_id, view, line_height);
+                        pane.add_tab(scrolled_to(tab, scroll_offset_px));
+                    }
+                    Err(e) => eprintln!("Skipping tab {:?}: {}", tab_data.file_path, e),
+                }
+                continue;
+            }
+
             // Load file content
             let content = match fs::read_to_string(&tab_data.file_path) {
                 Ok(c) => c,
@@ -577,7 +784,7 @@ impl PaneData {
                 Some(tab_data.file_path),
                 line_height,
             );
-            pane.add_tab(tab);
+            pane.add_tab(scrolled_to(tab, scroll_offset_px));
         }
 
         // If no tabs were restored, add an empty file tab
@@ -635,10 +842,29 @@ mod tests {
         assert_eq!(session.workspaces[0].label, "test");
     }
 
+    // Chunk: docs/chunks/workspace_rename_reorder - Names and order persist
+    #[test]
+    fn test_session_data_captures_renamed_and_reordered_workspaces() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+
+        let mut editor = Editor::new_deferred(TEST_LINE_HEIGHT);
+        editor.new_workspace("first".to_string(), root.clone());
+        editor.new_workspace("second".to_string(), root.clone());
+        editor.rename_workspace(0, "api");
+        editor.move_workspace(1, 0);
+
+        let session = SessionData::from_editor(&editor);
+
+        let labels: Vec<&str> = session.workspaces.iter().map(|ws| ws.label.as_str()).collect();
+        assert_eq!(labels, vec!["second", "api"]);
+        assert_eq!(session.active_workspace, 0);
+    }
+
     #[test]
     fn test_session_data_filters_non_file_tabs() {
-        // This test verifies that terminals and unsaved files are not included
-        // in the session data.
+        // This test verifies that unsaved files are not included in the
+        // session data.
         let temp = TempDir::new().unwrap();
         let root = temp.path().to_path_buf();
 
@@ -697,6 +923,152 @@ mod tests {
         }
     }
 
+    // Chunk: docs/chunks/scratch_buffers - Scratch buffers survive a restart
+    #[test]
+    fn test_scratch_buffers_roundtrip_through_session() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+
+        let mut editor = Editor::new_deferred(TEST_LINE_HEIGHT);
+        editor.new_workspace("test".to_string(), root.clone());
+        let tab_id = editor.gen_tab_id();
+        let scratch = Scratch {
+            language: Some("rs".to_string()),
+        };
+        let tab = Tab::new_scratch(tab_id, "fn main() {}\n", scratch, TEST_LINE_HEIGHT);
+        editor.active_workspace_mut().unwrap().add_tab(tab);
+
+        let json = serde_json::to_string(&SessionData::from_editor(&editor)).unwrap();
+        let session: SessionData = serde_json::from_str(&json).unwrap();
+        match &session.workspaces[0].pane_root {
+            PaneLayoutData::Leaf(pane) => {
+                // The untitled tab is skipped; the scratch is active
+                assert_eq!(pane.tabs.len(), 1);
+                assert_eq!(pane.active_tab, 0);
+            }
+            _ => panic!("Expected leaf node"),
+        }
+
+        let editor = session.restore_into_editor(TEST_LINE_HEIGHT).unwrap();
+        let tab = editor.active_workspace().unwrap().active_tab().unwrap();
+        assert_eq!(tab.label, "Scratch (Rust)");
+        assert!(tab.associated_file.is_none());
+        let language = tab.scratch.as_ref().and_then(|s| s.language.as_deref());
+        assert_eq!(language, Some("rs"));
+        assert_eq!(tab.as_text_buffer().unwrap().content(), "fn main() {}\n");
+        // Nothing was written into the workspace
+        assert_eq!(std::fs::read_dir(&root).unwrap().count(), 0);
+    }
+
+    // Chunk: docs/chunks/terminal_tab_labels - Terminal names and colors survive a restart
+    #[test]
+    fn test_terminal_tabs_roundtrip_through_session() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+
+        let mut editor = Editor::new_deferred(TEST_LINE_HEIGHT);
+        editor.new_workspace("test".to_string(), root.clone());
+        for (label, color) in [("server", Some(TabColor::Green)), ("tests", None)] {
+            let tab_id = editor.gen_tab_id();
+            let terminal = TerminalBuffer::new(80, 24, 100);
+            let mut tab = Tab::new_terminal(tab_id, terminal, label.to_string(), TEST_LINE_HEIGHT);
+            tab.color_label = color;
+            editor.active_workspace_mut().unwrap().add_tab(tab);
+        }
+
+        let json = serde_json::to_string(&SessionData::from_editor(&editor)).unwrap();
+        let session: SessionData = serde_json::from_str(&json).unwrap();
+        let editor = session.restore_into_editor(TEST_LINE_HEIGHT).unwrap();
+
+        let pane = editor.active_workspace().unwrap().active_pane().unwrap();
+        assert_eq!(pane.tabs.len(), 2);
+        assert_eq!(pane.tabs[0].label, "server");
+        assert_eq!(pane.tabs[0].kind, TabKind::Terminal);
+        assert_eq!(pane.tabs[0].color_label, Some(TabColor::Green));
+        assert_eq!(pane.tabs[1].label, "tests");
+        assert_eq!(pane.tabs[1].color_label, None);
+        // The shells are spawned once the editor is running
+        assert!(!pane.tabs[0].as_terminal_buffer().unwrap().is_spawned());
+        assert_eq!(pane.active_tab, 1);
+    }
+
+    // Chunk: docs/chunks/terminal_session_restore - Directory and startup command
+    #[test]
+    fn test_terminal_cwd_and_startup_command_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+
+        let mut editor = Editor::new_deferred(TEST_LINE_HEIGHT);
+        editor.new_workspace("test".to_string(), root.clone());
+        let tab_id = editor.gen_tab_id();
+        let mut terminal = TerminalBuffer::new(80, 24, 100);
+        terminal.feed_bytes(b"\x1b]7;file://localhost/srv/app\x07$ ");
+        let mut tab = Tab::new_terminal(tab_id, terminal, "server".to_string(), TEST_LINE_HEIGHT);
+        tab.startup_command = Some("npm run dev".to_string());
+        editor.active_workspace_mut().unwrap().add_tab(tab);
+
+        let json = serde_json::to_string(&SessionData::from_editor(&editor)).unwrap();
+        let session: SessionData = serde_json::from_str(&json).unwrap();
+        let editor = session.restore_into_editor(TEST_LINE_HEIGHT).unwrap();
+
+        let pane = editor.active_workspace().unwrap().active_pane().unwrap();
+        let tab = &pane.tabs[0];
+        assert_eq!(tab.restored_cwd, Some(PathBuf::from("/srv/app")));
+        assert_eq!(tab.startup_command.as_deref(), Some("npm run dev"));
+
+        // Saving again before the shell starts keeps the directory
+        let json = serde_json::to_string(&SessionData::from_editor(&editor)).unwrap();
+        assert!(json.contains("/srv/app"));
+    }
+
+    // Chunk: docs/chunks/session_idle_autosave - Tabs reopen where they were scrolled to
+    #[test]
+    fn test_scroll_offsets_roundtrip_through_session() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+        let content: String = (0..100).map(|i| format!("line {}\n", i)).collect();
+
+        let mut editor = Editor::new_deferred(TEST_LINE_HEIGHT);
+        editor.new_workspace("test".to_string(), root.clone());
+        for name in ["long.txt", "shrinks.txt"] {
+            let path = root.join(name);
+            std::fs::write(&path, &content).unwrap();
+            let tab_id = editor.gen_tab_id();
+            let buffer = TextBuffer::from_str(&content);
+            let mut tab = Tab::new_file(tab_id, buffer, name.to_string(), Some(path), TEST_LINE_HEIGHT);
+            tab.viewport.set_scroll_offset_px(40.0 * TEST_LINE_HEIGHT, 101);
+            editor.active_workspace_mut().unwrap().add_tab(tab);
+        }
+
+        let json = serde_json::to_string(&SessionData::from_editor(&editor)).unwrap();
+        let session: SessionData = serde_json::from_str(&json).unwrap();
+        std::fs::write(root.join("shrinks.txt"), "one\ntwo\n").unwrap();
+        let editor = session.restore_into_editor(TEST_LINE_HEIGHT).unwrap();
+
+        let pane = editor.active_workspace().unwrap().active_pane().unwrap();
+        assert_eq!(pane.tabs[0].viewport.scroll_offset_px(), 40.0 * TEST_LINE_HEIGHT);
+        // A file that got shorter isn't scrolled past its end
+        assert!(pane.tabs[1].viewport.scroll_offset_px() <= 3.0 * TEST_LINE_HEIGHT);
+    }
+
+    #[test]
+    fn test_unknown_terminal_color_is_dropped() {
+        let json = r#"{"file_path": "", "terminal": {"label": "db", "color": "Teal"}}"#;
+        let tab: TabData = serde_json::from_str(json).unwrap();
+        let terminal = tab.terminal.unwrap();
+        assert_eq!(terminal.label, "db");
+        let color = terminal.color.as_deref().and_then(TabColor::from_name);
+        assert_eq!(color, None);
+    }
+
+    #[test]
+    fn test_tab_data_without_scratch_still_parses() {
+        let tab: TabData = serde_json::from_str(r#"{"file_path": "/p/a.rs"}"#).unwrap();
+        assert_eq!(tab.file_path, PathBuf::from("/p/a.rs"));
+        assert!(tab.scratch.is_none());
+        assert_eq!(tab.scroll_offset_px, 0.0);
+    }
+
     // =========================================================================
     // Serialization Round-Trip Tests
     // =========================================================================
@@ -714,6 +1086,9 @@ mod tests {
                     id: 1,
                     tabs: vec![TabData {
                         file_path: PathBuf::from("/test/path/file.txt"),
+                        scratch: None,
+                        terminal: None,
+                        scroll_offset_px: 0.0,
                     }],
                     active_tab: 0,
                 }),
@@ -847,6 +1222,9 @@ mod tests {
                     id: 0,
                     tabs: vec![TabData {
                         file_path: file_path.clone(),
+                        scratch: None,
+                        terminal: None,
+                        scroll_offset_px: 0.0,
                     }],
                     active_tab: 0,
                 }),
@@ -878,7 +1256,12 @@ mod tests {
                 active_pane_id: 0,
                 pane_root: PaneLayoutData::Leaf(PaneData {
                     id: 0,
-                    tabs: vec![TabData { file_path }],
+                    tabs: vec![TabData {
+                        file_path,
+                        scratch: None,
+                        terminal: None,
+                        scroll_offset_px: 0.0,
+                    }],
                     active_tab: 0,
                 }),
             }],
@@ -916,6 +1299,9 @@ mod tests {
                         id: 1,
                         tabs: vec![TabData {
                             file_path: file1.clone(),
+                            scratch: None,
+                            terminal: None,
+                            scroll_offset_px: 0.0,
                         }],
                         active_tab: 0,
                     })),
@@ -923,6 +1309,9 @@ mod tests {
                         id: 2,
                         tabs: vec![TabData {
                             file_path: file2.clone(),
+                            scratch: None,
+                            terminal: None,
+                            scroll_offset_px: 0.0,
                         }],
                         active_tab: 0,
                     })),
@@ -1006,6 +1395,9 @@ mod tests {
                     id: 0,
                     tabs: vec![TabData {
                         file_path: file_path.clone(),
+                        scratch: None,
+                        terminal: None,
+                        scroll_offset_px: 0.0,
                     }],
                     active_tab: 0,
                 }),
@@ -1042,4 +1434,56 @@ mod tests {
             SplitDirection::Vertical
         );
     }
+
+    // =========================================================================
+    // Idle Autosave Tests
+    // =========================================================================
+
+    #[test]
+    fn test_autosave_waits_for_idle_and_interval() {
+        let start = Instant::now();
+        let autosave = SessionAutosave::new(start);
+
+        // Interval not yet elapsed
+        let soon = start + Duration::from_secs(10);
+        assert!(!autosave.is_due(soon, start));
+
+        // Interval elapsed, but the user is still active
+        let later = start + AUTOSAVE_INTERVAL;
+        assert!(!autosave.is_due(later, later - Duration::from_secs(1)));
+
+        // Interval elapsed and the user has been idle long enough
+        assert!(autosave.is_due(later, later - AUTOSAVE_IDLE_THRESHOLD));
+    }
+
+    #[test]
+    fn test_autosave_writes_only_when_changed() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(SESSION_FILENAME);
+
+        let mut editor = Editor::new_deferred(TEST_LINE_HEIGHT);
+        editor.new_workspace("test".to_string(), temp.path().to_path_buf());
+
+        let start = Instant::now();
+        let mut autosave = SessionAutosave::new(start);
+
+        let t1 = start + AUTOSAVE_INTERVAL + AUTOSAVE_IDLE_THRESHOLD;
+        assert!(autosave.maybe_save(&editor, &path, t1, start).unwrap());
+        assert!(path.exists());
+
+        // Nothing changed: the next due attempt skips the write
+        let t2 = t1 + AUTOSAVE_INTERVAL;
+        fs::remove_file(&path).unwrap();
+        assert!(!autosave.maybe_save(&editor, &path, t2, start).unwrap());
+        assert!(!path.exists());
+
+        // A change is picked up on the following attempt
+        editor.new_workspace("second".to_string(), temp.path().to_path_buf());
+        let t3 = t2 + AUTOSAVE_INTERVAL;
+        assert!(autosave.maybe_save(&editor, &path, t3, start).unwrap());
+
+        let json = fs::read_to_string(&path).unwrap();
+        let saved: SessionData = serde_json::from_str(&json).unwrap();
+        assert_eq!(saved.workspaces.len(), 2);
+    }
 }
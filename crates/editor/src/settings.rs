@@ -0,0 +1,578 @@
+// Chunk: docs/chunks/settings_tab - Searchable settings tab
+//!
+//! The Settings tab: the user config's options, grouped and editable in place.
+//!
+//! A [`SettingsBuffer`] lists the options in [`SETTINGS`] under their group
+//! headings, narrowed by a search typed into the tab, and presents them
+//! through `BufferView`, so a `TabKind::Settings` tab renders through the
+//! same pipeline as the Changes tab. Return flips a toggle, or starts editing
+//! a value in its row; a second Return commits the edit.
+//!
+//! Values are read and written through the config's JSON form, so an option
+//! is only a dotted key (e.g. `"save.ensure_final_newline"`) and a
+//! [`SettingKind`] saying how to show and parse it. The buffer never touches
+//! the disk: a committed change comes back as a [`SettingChange`], which the
+//! editor writes to the config file and applies through
+//! `EditorEvent::SettingsChanged`.
+//!
+//! Options without a one-line form (syntax styles, per-language save
+//! overrides and formatters) are still edited in the config file.
+
+use lite_edit_buffer::{BufferView, CursorInfo, DirtyLines, Span, Style, StyledLine};
+use serde_json::Value;
+
+use crate::config::{json_get, EditorConfig};
+use crate::view_colors::{BLUE, MAUVE, SELECTED_BG, YELLOW};
+
+/// Columns the labels are padded to, so values line up.
+const LABEL_WIDTH: usize = 36;
+
+/// The keys' summary shown below the options.
+const HINT: &str = "Type to search · Return toggles or edits · Escape cancels or clears";
+
+/// How an option is shown and edited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    /// On or off; Return flips it
+    Toggle,
+    /// A number
+    Number,
+    /// Free text; empty means unset
+    Text,
+    /// Column numbers separated by commas
+    Columns,
+}
+
+/// One option of the user config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Setting {
+    /// The heading it is listed under
+    pub group: &'static str,
+    /// Its dotted key in the config file
+    pub key: &'static str,
+    pub label: &'static str,
+    pub kind: SettingKind,
+}
+
+const fn setting(
+    group: &'static str,
+    key: &'static str,
+    label: &'static str,
+    kind: SettingKind,
+) -> Setting {
+    Setting {
+        group,
+        key,
+        label,
+        kind,
+    }
+}
+
+use SettingKind::{Columns, Number, Text, Toggle};
+
+/// Every option the Settings tab offers, grouped in display order.
+pub const SETTINGS: &[Setting] = &[
+    setting("Font", "font_family", "Font family (empty for Intel One Mono)", Text),
+    setting("Font", "font_size", "Font size in points", Number),
+    setting("Editor", "highlight_current_line", "Highlight the cursor's line", Toggle),
+    setting("Editor", "rulers", "Ruler columns", Columns),
+    setting("Panes", "dim_inactive_panes", "Dim unfocused panes", Toggle),
+    setting("Panes", "breadcrumbs", "Breadcrumbs over file panes", Toggle),
+    setting("Feedback", "alert_sound", "Alert sound when there's nothing to do", Toggle),
+    setting("Feedback", "haptic_feedback", "Trackpad tap when find wraps", Toggle),
+    setting("Feedback", "agent_finished_sound", "Sound when an agent finishes", Text),
+    setting("Saving", "save.trim_trailing_whitespace", "Trim trailing whitespace", Toggle),
+    setting("Saving", "save.ensure_final_newline", "Ensure a final newline", Toggle),
+    setting("Saving", "save.normalize_indentation", "Normalize indentation", Toggle),
+    // Chunk: docs/chunks/terminal_memory_budget - Terminal scrollback limits
+    setting("Terminal", "terminal.hot_scrollback_lines", "Scrollback lines in memory", Number),
+    setting("Terminal", "terminal.cold_cache_kb", "Disk scrollback cache, KB", Number),
+    // Chunk: docs/chunks/terminal_replay - Session recording limit
+    setting("Terminal", "terminal.recording_mb", "Session recording limit, MB", Number),
+    // Chunk: docs/chunks/agent_queue - Agents run at once
+    setting("Agents", "agents.max_concurrent", "Queued agents running at once", Number),
+];
+
+impl Setting {
+    /// Returns true if every word of `query` appears in the option's group,
+    /// label or key, ignoring case.
+    pub fn matches(&self, query: &str) -> bool {
+        let haystack = format!("{} {} {}", self.group, self.label, self.key).to_lowercase();
+        query
+            .to_lowercase()
+            .split_whitespace()
+            .all(|word| haystack.contains(word))
+    }
+
+    /// Returns `value` as it is shown and edited, e.g. "80, 120" for rulers.
+    pub fn display(&self, value: Option<&Value>) -> String {
+        match (self.kind, value) {
+            (Columns, Some(Value::Array(columns))) => columns
+                .iter()
+                .map(|column| column.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            (_, Some(Value::String(text))) => text.clone(),
+            (_, Some(Value::Null) | None) => String::new(),
+            (_, Some(value)) => value.to_string(),
+        }
+    }
+
+    /// Parses edited text into the option's JSON value.
+    pub fn parse(&self, text: &str) -> Result<Value, String> {
+        let text = text.trim();
+        match self.kind {
+            Toggle => text
+                .parse::<bool>()
+                .map(Value::Bool)
+                .map_err(|_| format!("{} is on or off", self.label)),
+            // Whole numbers stay integers, so counts like scrollback lines parse
+            Number => text
+                .parse::<u64>()
+                .map(Value::from)
+                .ok()
+                .or_else(|| {
+                    let number = text.parse::<f64>().ok()?;
+                    serde_json::Number::from_f64(number).map(Value::Number)
+                })
+                .ok_or_else(|| format!("{} must be a number", self.label)),
+            Text if text.is_empty() => Ok(Value::Null),
+            Text => Ok(Value::String(text.to_string())),
+            Columns => text
+                .split(',')
+                .map(str::trim)
+                .filter(|column| !column.is_empty())
+                .map(|column| column.parse::<u64>().map(Value::from))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array)
+                .map_err(|_| format!("{} are numbers, e.g. 80, 120", self.label)),
+        }
+    }
+}
+
+/// A committed change to one option.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingChange {
+    /// The option's dotted key
+    pub key: &'static str,
+    /// Its new JSON value
+    pub value: Value,
+    /// The whole config with the change made
+    pub config: EditorConfig,
+}
+
+/// What a row of the tab shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    Search,
+    Blank,
+    Group(&'static str),
+    /// An option, by index into `matches`
+    Setting(usize),
+    Hint,
+}
+
+/// The config's options, filtered by a search, with one selected.
+pub struct SettingsBuffer {
+    config: EditorConfig,
+    /// The config's JSON form, which values are read from
+    values: Value,
+    query: String,
+    /// Indices into [`SETTINGS`] of the options matching the query
+    matches: Vec<usize>,
+    /// Index into `matches` of the selected option
+    selected: usize,
+    /// The text of the value being edited in the selected row
+    editing: Option<String>,
+    dirty: DirtyLines,
+}
+
+impl SettingsBuffer {
+    /// Creates the tab's list for `config`.
+    pub fn new(config: EditorConfig) -> Self {
+        let mut buffer = Self {
+            values: Value::Null,
+            config: EditorConfig::default(),
+            query: String::new(),
+            matches: (0..SETTINGS.len()).collect(),
+            selected: 0,
+            editing: None,
+            dirty: DirtyLines::FromLineToEnd(0),
+        };
+        buffer.set_config(config);
+        buffer
+    }
+
+    /// Returns the config the list shows.
+    pub fn config(&self) -> &EditorConfig {
+        &self.config
+    }
+
+    /// Shows `config`, e.g. after it was changed in another Settings tab.
+    /// An edit in progress is kept.
+    pub fn set_config(&mut self, config: EditorConfig) {
+        self.values = serde_json::to_value(&config).unwrap_or(Value::Null);
+        self.config = config;
+        self.dirty = DirtyLines::FromLineToEnd(0);
+    }
+
+    /// Returns the search text.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns true while a value is being edited.
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    /// Returns the selected option, if any matches the search.
+    pub fn selected_setting(&self) -> Option<&'static Setting> {
+        self.matches.get(self.selected).map(|&index| &SETTINGS[index])
+    }
+
+    /// Returns the selected option's row.
+    pub fn selected_row(&self) -> usize {
+        self.rows()
+            .iter()
+            .position(|row| *row == Row::Setting(self.selected))
+            .unwrap_or(0)
+    }
+
+    /// Returns the status bar text, e.g. "12 settings" or "2 of 12 settings".
+    pub fn summary(&self) -> String {
+        if self.editing.is_some() {
+            "Return saves, Escape cancels".to_string()
+        } else if self.query.is_empty() {
+            format!("{} settings", SETTINGS.len())
+        } else {
+            format!("{} of {} settings", self.matches.len(), SETTINGS.len())
+        }
+    }
+
+    /// Moves the selection `delta` options down (or up, if negative),
+    /// stopping at the first and last. Abandons an edit in progress.
+    pub fn move_selection(&mut self, delta: isize) {
+        let last = self.matches.len().saturating_sub(1) as isize;
+        let target = (self.selected as isize + delta).clamp(0, last) as usize;
+        if target != self.selected {
+            self.editing = None;
+            self.selected = target;
+            self.dirty = DirtyLines::FromLineToEnd(0);
+        }
+    }
+
+    /// Adds a typed character to the value being edited, or to the search.
+    pub fn type_char(&mut self, ch: char) {
+        match &mut self.editing {
+            Some(text) => text.push(ch),
+            None => {
+                self.query.push(ch);
+                self.refilter();
+            }
+        }
+        self.dirty = DirtyLines::FromLineToEnd(0);
+    }
+
+    /// Deletes the last character of the value being edited, or of the search.
+    pub fn backspace(&mut self) {
+        match &mut self.editing {
+            Some(text) => {
+                text.pop();
+            }
+            None => {
+                if self.query.pop().is_none() {
+                    return;
+                }
+                self.refilter();
+            }
+        }
+        self.dirty = DirtyLines::FromLineToEnd(0);
+    }
+
+    /// Abandons the edit in progress, or else clears the search. Returns
+    /// false if there was neither.
+    pub fn cancel(&mut self) -> bool {
+        if self.editing.take().is_none() {
+            if self.query.is_empty() {
+                return false;
+            }
+            self.query.clear();
+            self.refilter();
+        }
+        self.dirty = DirtyLines::FromLineToEnd(0);
+        true
+    }
+
+    /// Acts on Return: flips a toggle, starts editing another kind of value,
+    /// or commits the edit in progress.
+    ///
+    /// Returns the change to save and apply, an error for a value that
+    /// doesn't parse (the edit stays open), or `None` when an edit started.
+    pub fn activate(&mut self) -> Option<Result<SettingChange, String>> {
+        let setting = self.selected_setting()?;
+        let current = json_get(&self.values, setting.key);
+        let value = match (setting.kind, self.editing.take()) {
+            (_, Some(text)) => match setting.parse(&text) {
+                Ok(value) => value,
+                Err(message) => {
+                    self.editing = Some(text);
+                    return Some(Err(message));
+                }
+            },
+            (Toggle, None) => Value::Bool(!current.and_then(Value::as_bool).unwrap_or(false)),
+            (_, None) => {
+                self.editing = Some(setting.display(current));
+                self.dirty = DirtyLines::FromLineToEnd(0);
+                return None;
+            }
+        };
+        self.dirty = DirtyLines::FromLineToEnd(0);
+        let config = match self.config.with_setting(setting.key, value.clone()) {
+            Ok(config) => config,
+            Err(err) => return Some(Err(format!("{}: {}", setting.label, err))),
+        };
+        self.set_config(config.clone());
+        Some(Ok(SettingChange {
+            key: setting.key,
+            value,
+            config,
+        }))
+    }
+
+    /// Recomputes the options matching the search, selecting the first.
+    fn refilter(&mut self) {
+        self.matches = (0..SETTINGS.len())
+            .filter(|&index| SETTINGS[index].matches(&self.query))
+            .collect();
+        self.selected = 0;
+        self.editing = None;
+    }
+
+    /// Returns what each row shows, top to bottom.
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = vec![Row::Search];
+        let mut group = None;
+        for (index, &setting) in self.matches.iter().enumerate() {
+            let setting = &SETTINGS[setting];
+            if group != Some(setting.group) {
+                group = Some(setting.group);
+                rows.push(Row::Blank);
+                rows.push(Row::Group(setting.group));
+            }
+            rows.push(Row::Setting(index));
+        }
+        rows.push(Row::Blank);
+        rows.push(Row::Hint);
+        rows
+    }
+
+    fn render_row(&self, row: usize) -> Option<StyledLine> {
+        let dim = Style {
+            dim: true,
+            ..Style::default()
+        };
+        let line = match *self.rows().get(row)? {
+            Row::Search if self.query.is_empty() => StyledLine::new(vec![
+                Span::plain("Search: "),
+                Span::new("type to filter the settings", dim),
+            ]),
+            Row::Search => StyledLine::new(vec![
+                Span::plain("Search: "),
+                Span::plain(self.query.clone()),
+            ]),
+            Row::Blank => StyledLine::empty(),
+            Row::Group(group) => {
+                let style = Style {
+                    fg: MAUVE,
+                    bold: true,
+                    ..Style::default()
+                };
+                StyledLine::new(vec![Span::new(group, style)])
+            }
+            Row::Setting(index) => self.render_setting(index),
+            Row::Hint => StyledLine::new(vec![Span::new(HINT, dim)]),
+        };
+        Some(line)
+    }
+
+    fn render_setting(&self, index: usize) -> StyledLine {
+        let setting = &SETTINGS[self.matches[index]];
+        let value = json_get(&self.values, setting.key);
+        let selected = index == self.selected;
+        let mut spans = match setting.kind {
+            Toggle => {
+                let on = value.and_then(Value::as_bool).unwrap_or(false);
+                let checkbox = if on { "[x]" } else { "[ ]" };
+                vec![Span::plain(format!("  {} {}", checkbox, setting.label))]
+            }
+            _ => {
+                let label = format!("      {:<width$}", setting.label, width = LABEL_WIDTH);
+                let value = match (&self.editing, selected) {
+                    (Some(text), true) => Span::new(
+                        format!("{}▏", text),
+                        Style {
+                            fg: YELLOW,
+                            ..Style::default()
+                        },
+                    ),
+                    _ => match setting.display(value) {
+                        text if text.is_empty() => Span::new(
+                            "(none)",
+                            Style {
+                                dim: true,
+                                ..Style::default()
+                            },
+                        ),
+                        text => Span::new(
+                            text,
+                            Style {
+                                fg: BLUE,
+                                ..Style::default()
+                            },
+                        ),
+                    },
+                };
+                vec![Span::plain(label), Span::plain(" "), value]
+            }
+        };
+        if selected {
+            for span in &mut spans {
+                span.style.bg = SELECTED_BG;
+            }
+        }
+        StyledLine::new(spans)
+    }
+}
+
+impl BufferView for SettingsBuffer {
+    fn line_count(&self) -> usize {
+        self.rows().len()
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        self.render_row(line)
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.render_row(line)
+            .map(|row| row.char_count())
+            .unwrap_or(0)
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        None
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(buffer: &SettingsBuffer, row: usize) -> String {
+        let line = buffer.styled_line(row).unwrap();
+        line.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    fn select(buffer: &mut SettingsBuffer, key: &str) {
+        buffer.move_selection(-(SETTINGS.len() as isize));
+        while buffer.selected_setting().unwrap().key != key {
+            buffer.move_selection(1);
+        }
+    }
+
+    #[test]
+    fn test_settings_are_grouped_and_searchable() {
+        let mut buffer = SettingsBuffer::new(EditorConfig::default());
+        assert_eq!(text(&buffer, 2), "Font");
+        assert_eq!(buffer.selected_row(), 3);
+        assert_eq!(buffer.summary(), format!("{} settings", SETTINGS.len()));
+
+        for ch in "newline".chars() {
+            buffer.type_char(ch);
+        }
+        assert_eq!(text(&buffer, 0), "Search: newline");
+        assert_eq!(text(&buffer, 2), "Saving");
+        assert_eq!(text(&buffer, 3), "  [ ] Ensure a final newline");
+        assert_eq!(buffer.line_count(), 6);
+
+        assert!(buffer.cancel());
+        assert_eq!(buffer.query(), "");
+        assert!(!buffer.cancel());
+    }
+
+    #[test]
+    fn test_return_flips_a_toggle() {
+        let mut buffer = SettingsBuffer::new(EditorConfig::default());
+        select(&mut buffer, "save.ensure_final_newline");
+        let change = buffer.activate().unwrap().unwrap();
+        assert_eq!(change.key, "save.ensure_final_newline");
+        assert_eq!(change.value, Value::Bool(true));
+        assert!(change.config.save.transforms.ensure_final_newline);
+        assert!(buffer.config().save.transforms.ensure_final_newline);
+    }
+
+    #[test]
+    fn test_values_are_edited_in_place() {
+        let mut buffer = SettingsBuffer::new(EditorConfig::default());
+        select(&mut buffer, "rulers");
+        assert!(buffer.activate().is_none());
+        assert!(buffer.is_editing());
+        for ch in "80, x".chars() {
+            buffer.type_char(ch);
+        }
+        let error = buffer.activate().unwrap().unwrap_err();
+        assert_eq!(error, "Ruler columns are numbers, e.g. 80, 120");
+        assert!(buffer.is_editing());
+
+        buffer.backspace();
+        buffer.type_char('1');
+        buffer.type_char('2');
+        buffer.type_char('0');
+        let change = buffer.activate().unwrap().unwrap();
+        assert_eq!(change.config.rulers, vec![80, 120]);
+        assert!(!buffer.is_editing());
+        let row = text(&buffer, buffer.selected_row());
+        assert!(row.ends_with(" 80, 120"), "{:?}", row);
+
+        // Emptying a text option unsets it
+        select(&mut buffer, "font_family");
+        assert!(buffer.activate().is_none());
+        let change = buffer.activate().unwrap().unwrap();
+        assert_eq!(change.value, Value::Null);
+        assert!(text(&buffer, 3).ends_with("(none)"));
+    }
+
+    #[test]
+    fn test_parse_by_kind() {
+        let by_key = |key: &str| SETTINGS.iter().find(|s| s.key == key).unwrap();
+        assert_eq!(by_key("font_size").parse(" 13.5 "), Ok(serde_json::json!(13.5)));
+        assert!(by_key("font_size").parse("big").is_err());
+        // Chunk: docs/chunks/terminal_memory_budget - Whole numbers fit count options
+        let lines = by_key("terminal.hot_scrollback_lines")
+            .parse("5000")
+            .unwrap();
+        assert_eq!(lines, serde_json::json!(5000));
+        let config = EditorConfig::default()
+            .with_setting("terminal.hot_scrollback_lines", lines)
+            .unwrap();
+        assert_eq!(config.terminal.hot_scrollback_lines, 5000);
+        assert_eq!(
+            by_key("agent_finished_sound").parse("Glass"),
+            Ok(Value::String("Glass".to_string()))
+        );
+        assert_eq!(by_key("rulers").parse(""), Ok(Value::Array(Vec::new())));
+    }
+}
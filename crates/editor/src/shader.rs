@@ -97,12 +97,29 @@ impl GlyphPipeline {
     /// # Panics
     /// Panics if shader compilation or pipeline creation fails.
     pub fn new(device: &ProtocolObject<dyn MTLDevice>) -> Self {
+        Self::with_fragment(device, "glyph_fragment")
+    }
+
+    // Chunk: docs/chunks/terminal_kitty_graphics - Image pipeline
+    /// Creates a pipeline for drawing RGBA image textures
+    ///
+    /// Uses the same vertex layout and shader as glyphs, with a fragment
+    /// function that samples full-color texels instead of atlas coverage.
+    ///
+    /// # Panics
+    /// Panics if shader compilation or pipeline creation fails.
+    pub fn new_image(device: &ProtocolObject<dyn MTLDevice>) -> Self {
+        Self::with_fragment(device, "image_fragment")
+    }
+
+    /// Creates a pipeline using `glyph_vertex` and the named fragment function
+    fn with_fragment(device: &ProtocolObject<dyn MTLDevice>, fragment_name: &str) -> Self {
         // Compile the shader source
         let library = Self::compile_shader(device);
 
         // Get the shader functions
         let vertex_function = Self::get_function(&library, "glyph_vertex");
-        let fragment_function = Self::get_function(&library, "glyph_fragment");
+        let fragment_function = Self::get_function(&library, fragment_name);
 
         // Create the pipeline descriptor
         let descriptor = MTLRenderPipelineDescriptor::new();
@@ -194,6 +211,12 @@ mod tests {
         let _pipeline = GlyphPipeline::new(&device);
     }
 
+    #[test]
+    fn test_image_shader_compilation() {
+        let device = get_test_device();
+        let _pipeline = GlyphPipeline::new_image(&device);
+    }
+
     #[test]
     fn test_vertex_descriptor() {
         // Just verify it creates without panicking
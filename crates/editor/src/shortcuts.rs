@@ -0,0 +1,483 @@
+// Chunk: docs/chunks/keybinding_conflicts - Keyboard Shortcuts tab
+//!
+//! The Keyboard Shortcuts tab: every bound chord, its conflicts, and rebinding.
+//!
+//! A [`ShortcutsBuffer`] lists the [`KEYMAP`](crate::keymap::KEYMAP) with the
+//! user's overrides applied, grouped by context and narrowed by a search typed
+//! into the tab. It presents them through `BufferView`, so a
+//! `TabKind::Shortcuts` tab renders through the same pipeline as the Settings
+//! tab. An entry typed with the same chord as another entry in an overlapping
+//! context is marked with what it conflicts with.
+//!
+//! Return starts capturing a new chord for the selected entry: the next key
+//! pressed (with Cmd, Ctrl or Option, or a function key) becomes its keys.
+//! The buffer never touches the disk: a capture comes back as the new list
+//! of overrides, which the editor saves as the config's `keybindings`.
+
+use lite_edit_buffer::{BufferView, CursorInfo, DirtyLines, Span, Style, StyledLine};
+
+use crate::input::{Key, KeyEvent};
+use crate::keymap::{bound_keys, conflicts, BoundKey, KeyContext, KeyOverride};
+use crate::menu::format_chord;
+use crate::view_colors::{BLUE, MAUVE, RED, SELECTED_BG, YELLOW};
+
+/// Columns the keys are padded to, so actions line up.
+const KEYS_WIDTH: usize = 24;
+
+/// The keys' summary shown below the shortcuts.
+const HINT: &str = "Type to search · Return rebinds · Cmd+S exports · Cmd+Shift+O imports";
+
+/// What a row of the tab shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    Search,
+    Blank,
+    Group(KeyContext),
+    /// A shortcut, by index into `matches`
+    Shortcut(usize),
+    Hint,
+}
+
+/// The keymap with overrides applied, filtered by a search, with one entry
+/// selected.
+pub struct ShortcutsBuffer {
+    overrides: Vec<KeyOverride>,
+    bound: Vec<BoundKey>,
+    /// For each entry of `bound`, the entries it conflicts with
+    conflicts: Vec<Vec<usize>>,
+    query: String,
+    /// Indices into `bound` of the entries matching the query, by context
+    matches: Vec<usize>,
+    /// Index into `matches` of the selected entry
+    selected: usize,
+    /// True while the next key pressed becomes the selected entry's chord
+    capturing: bool,
+    dirty: DirtyLines,
+}
+
+impl ShortcutsBuffer {
+    /// Creates the tab's list for the user's `overrides`.
+    pub fn new(overrides: Vec<KeyOverride>) -> Self {
+        let mut buffer = Self {
+            overrides: Vec::new(),
+            bound: Vec::new(),
+            conflicts: Vec::new(),
+            query: String::new(),
+            matches: Vec::new(),
+            selected: 0,
+            capturing: false,
+            dirty: DirtyLines::FromLineToEnd(0),
+        };
+        buffer.set_overrides(overrides);
+        buffer
+    }
+
+    /// Returns the overrides the list shows.
+    pub fn overrides(&self) -> &[KeyOverride] {
+        &self.overrides
+    }
+
+    /// Shows `overrides`, e.g. after a keymap was imported. The search and
+    /// selection are kept.
+    pub fn set_overrides(&mut self, overrides: Vec<KeyOverride>) {
+        self.bound = bound_keys(&overrides);
+        self.conflicts = conflicts(&self.bound);
+        self.overrides = overrides;
+        self.refilter();
+        self.dirty = DirtyLines::FromLineToEnd(0);
+    }
+
+    /// Returns the search text.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns true while waiting for the selected entry's new chord.
+    pub fn is_capturing(&self) -> bool {
+        self.capturing
+    }
+
+    /// Returns the selected entry, if any matches the search.
+    pub fn selected_key(&self) -> Option<&BoundKey> {
+        self.matches
+            .get(self.selected)
+            .map(|&index| &self.bound[index])
+    }
+
+    /// Returns the number of entries that conflict with another.
+    pub fn conflict_count(&self) -> usize {
+        self.conflicts
+            .iter()
+            .filter(|others| !others.is_empty())
+            .count()
+    }
+
+    /// Returns the selected entry's row.
+    pub fn selected_row(&self) -> usize {
+        self.rows()
+            .iter()
+            .position(|row| *row == Row::Shortcut(self.selected))
+            .unwrap_or(0)
+    }
+
+    /// Returns the status bar text, e.g. "124 shortcuts, 2 in conflict".
+    pub fn summary(&self) -> String {
+        if self.capturing {
+            return "Press the new chord · Backspace restores the default · Escape cancels"
+                .to_string();
+        }
+        let count = if self.query.is_empty() {
+            format!("{} shortcuts", self.bound.len())
+        } else {
+            format!("{} of {} shortcuts", self.matches.len(), self.bound.len())
+        };
+        match self.conflict_count() {
+            0 => count,
+            conflicting => format!("{}, {} in conflict", count, conflicting),
+        }
+    }
+
+    /// Moves the selection `delta` entries down (or up, if negative),
+    /// stopping at the first and last.
+    pub fn move_selection(&mut self, delta: isize) {
+        let last = self.matches.len().saturating_sub(1) as isize;
+        let target = (self.selected as isize + delta).clamp(0, last) as usize;
+        if target != self.selected {
+            self.selected = target;
+            self.dirty = DirtyLines::FromLineToEnd(0);
+        }
+    }
+
+    /// Adds a typed character to the search.
+    pub fn type_char(&mut self, ch: char) {
+        self.query.push(ch);
+        self.selected = 0;
+        self.refilter();
+        self.dirty = DirtyLines::FromLineToEnd(0);
+    }
+
+    /// Deletes the last character of the search.
+    pub fn backspace(&mut self) {
+        if self.query.pop().is_some() {
+            self.selected = 0;
+            self.refilter();
+            self.dirty = DirtyLines::FromLineToEnd(0);
+        }
+    }
+
+    /// Clears the search. Returns false if there was none.
+    pub fn cancel(&mut self) -> bool {
+        if self.query.is_empty() {
+            return false;
+        }
+        self.query.clear();
+        self.selected = 0;
+        self.refilter();
+        self.dirty = DirtyLines::FromLineToEnd(0);
+        true
+    }
+
+    /// Starts capturing a new chord for the selected entry. Fails for
+    /// entries whose keys are a range or alternatives.
+    pub fn start_capture(&mut self) -> Result<(), String> {
+        let Some(key) = self.selected_key() else {
+            return Ok(());
+        };
+        if key.binding.chord().is_none() {
+            return Err(format!("{} can't be rebound", key.binding.keys));
+        }
+        self.capturing = true;
+        self.dirty = DirtyLines::FromLineToEnd(0);
+        Ok(())
+    }
+
+    /// Handles a key pressed while capturing: Escape cancels, Backspace
+    /// restores the built-in chord, and any other chord becomes the selected
+    /// entry's keys.
+    ///
+    /// Returns the new overrides to save, an error for a key that can't be a
+    /// shortcut (capturing continues), or `None` when capturing was cancelled.
+    pub fn capture(&mut self, event: &KeyEvent) -> Option<Result<Vec<KeyOverride>, String>> {
+        let plain = event.modifiers == Default::default();
+        let binding = self.selected_key()?.binding;
+        let keys = match event.key {
+            Key::Escape if plain => {
+                self.capturing = false;
+                self.dirty = DirtyLines::FromLineToEnd(0);
+                return None;
+            }
+            Key::Backspace if plain => binding.keys.to_string(),
+            _ => {
+                let modified =
+                    event.modifiers.command || event.modifiers.control || event.modifiers.option;
+                if !modified && !is_function_key(&event.key) {
+                    return Some(Err("Shortcuts need Cmd, Ctrl or Option".to_string()));
+                }
+                match format_chord(event) {
+                    Some(keys) => keys,
+                    None => return Some(Err("That key can't be part of a shortcut".to_string())),
+                }
+            }
+        };
+
+        let mut overrides: Vec<KeyOverride> = self
+            .overrides
+            .iter()
+            .filter(|o| !o.binding().is_some_and(|b| std::ptr::eq(b, binding)))
+            .cloned()
+            .collect();
+        if keys != binding.keys {
+            overrides.push(KeyOverride::new(binding, keys));
+        }
+        self.capturing = false;
+        self.set_overrides(overrides.clone());
+        Some(Ok(overrides))
+    }
+
+    /// Recomputes the entries matching the search, keeping the selection in
+    /// range.
+    fn refilter(&mut self) {
+        let mut matches: Vec<usize> = (0..self.bound.len())
+            .filter(|&index| self.bound[index].matches(&self.query))
+            .collect();
+        matches.sort_by_key(|&index| self.bound[index].binding.context);
+        self.matches = matches;
+        self.selected = self.selected.min(self.matches.len().saturating_sub(1));
+        if self.matches.is_empty() {
+            self.capturing = false;
+        }
+    }
+
+    /// Returns what each row shows, top to bottom.
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = vec![Row::Search];
+        let mut context = None;
+        for (index, &entry) in self.matches.iter().enumerate() {
+            let entry_context = self.bound[entry].binding.context;
+            if context != Some(entry_context) {
+                context = Some(entry_context);
+                rows.push(Row::Blank);
+                rows.push(Row::Group(entry_context));
+            }
+            rows.push(Row::Shortcut(index));
+        }
+        rows.push(Row::Blank);
+        rows.push(Row::Hint);
+        rows
+    }
+
+    fn render_row(&self, row: usize) -> Option<StyledLine> {
+        let dim = Style {
+            dim: true,
+            ..Style::default()
+        };
+        let line = match *self.rows().get(row)? {
+            Row::Search if self.query.is_empty() => StyledLine::new(vec![
+                Span::plain("Search: "),
+                Span::new("type to filter by context, keys or action", dim),
+            ]),
+            Row::Search => StyledLine::new(vec![
+                Span::plain("Search: "),
+                Span::plain(self.query.clone()),
+            ]),
+            Row::Blank => StyledLine::empty(),
+            Row::Group(context) => {
+                let style = Style {
+                    fg: MAUVE,
+                    bold: true,
+                    ..Style::default()
+                };
+                StyledLine::new(vec![Span::new(context.label(), style)])
+            }
+            Row::Shortcut(index) => self.render_shortcut(index),
+            Row::Hint => StyledLine::new(vec![Span::new(HINT, dim)]),
+        };
+        Some(line)
+    }
+
+    fn render_shortcut(&self, index: usize) -> StyledLine {
+        let entry = self.matches[index];
+        let key = &self.bound[entry];
+        let selected = index == self.selected;
+
+        let keys = if selected && self.capturing {
+            Span::new(
+                format!("  {:<width$}", "press a chord…", width = KEYS_WIDTH),
+                Style {
+                    fg: YELLOW,
+                    ..Style::default()
+                },
+            )
+        } else {
+            Span::new(
+                format!("  {:<width$}", key.keys, width = KEYS_WIDTH),
+                Style {
+                    fg: BLUE,
+                    bold: key.overridden,
+                    ..Style::default()
+                },
+            )
+        };
+        let mut spans = vec![keys, Span::plain(" "), Span::plain(key.binding.action)];
+        if key.overridden {
+            spans.push(Span::new(
+                format!("  (default {})", key.binding.keys),
+                Style {
+                    dim: true,
+                    ..Style::default()
+                },
+            ));
+        }
+        let others = &self.conflicts[entry];
+        if !others.is_empty() {
+            let names: Vec<String> = others
+                .iter()
+                .map(|&other| {
+                    let binding = self.bound[other].binding;
+                    format!("{}: {}", binding.context.label(), binding.action)
+                })
+                .collect();
+            spans.push(Span::new(
+                format!("  ⚠ also {}", names.join("; ")),
+                Style {
+                    fg: RED,
+                    ..Style::default()
+                },
+            ));
+        }
+        if selected {
+            for span in &mut spans {
+                span.style.bg = SELECTED_BG;
+            }
+        }
+        StyledLine::new(spans)
+    }
+}
+
+/// Returns true for F1 through F12, which may be bound without a modifier.
+fn is_function_key(key: &Key) -> bool {
+    matches!(
+        key,
+        Key::F1
+            | Key::F2
+            | Key::F3
+            | Key::F4
+            | Key::F5
+            | Key::F6
+            | Key::F7
+            | Key::F8
+            | Key::F9
+            | Key::F10
+            | Key::F11
+            | Key::F12
+    )
+}
+
+impl BufferView for ShortcutsBuffer {
+    fn line_count(&self) -> usize {
+        self.rows().len()
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        self.render_row(line)
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.render_row(line)
+            .map(|row| row.char_count())
+            .unwrap_or(0)
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        None
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Modifiers;
+    use crate::keymap::KEYMAP;
+    use crate::menu::parse_chord;
+
+    fn text(buffer: &ShortcutsBuffer, row: usize) -> String {
+        let line = buffer.styled_line(row).unwrap();
+        line.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    fn search(buffer: &mut ShortcutsBuffer, query: &str) {
+        for ch in query.chars() {
+            buffer.type_char(ch);
+        }
+    }
+
+    #[test]
+    fn test_shortcuts_are_grouped_and_searchable() {
+        let mut buffer = ShortcutsBuffer::new(Vec::new());
+        assert_eq!(text(&buffer, 2), "Global");
+        assert_eq!(buffer.summary(), format!("{} shortcuts", KEYMAP.len()));
+
+        search(&mut buffer, "pin unpin");
+        assert_eq!(text(&buffer, 0), "Search: pin unpin");
+        assert_eq!(
+            text(&buffer, 3).trim_end(),
+            format!("  {:<24} Pin / unpin tab", "Cmd+Shift+K")
+        );
+        assert_eq!(buffer.summary(), format!("1 of {} shortcuts", KEYMAP.len()));
+
+        assert!(buffer.cancel());
+        assert!(!buffer.cancel());
+    }
+
+    #[test]
+    fn test_capture_rebinds_and_marks_conflicts() {
+        let mut buffer = ShortcutsBuffer::new(Vec::new());
+        search(&mut buffer, "pin unpin");
+        buffer.start_capture().unwrap();
+        assert!(buffer.is_capturing());
+
+        // A plain letter is typing, not a shortcut
+        let error = buffer.capture(&KeyEvent::char('k')).unwrap().unwrap_err();
+        assert_eq!(error, "Shortcuts need Cmd, Ctrl or Option");
+        assert!(buffer.is_capturing());
+
+        let overrides = buffer
+            .capture(&parse_chord("Cmd+K").unwrap())
+            .unwrap()
+            .unwrap();
+        let pin = buffer.selected_key().unwrap().binding;
+        assert_eq!(overrides, vec![KeyOverride::new(pin, "Cmd+K")]);
+        assert!(!buffer.is_capturing());
+        let row = text(&buffer, 3);
+        assert!(row.contains("(default Cmd+Shift+K)"), "{:?}", row);
+        assert!(row.ends_with("Terminal: Clear scrollback"), "{:?}", row);
+        assert_eq!(buffer.conflict_count(), 2);
+
+        // Backspace while capturing restores the built-in chord
+        buffer.start_capture().unwrap();
+        let backspace = KeyEvent::new(Key::Backspace, Modifiers::default());
+        assert_eq!(buffer.capture(&backspace), Some(Ok(Vec::new())));
+        assert_eq!(buffer.conflict_count(), 0);
+    }
+
+    #[test]
+    fn test_ranges_cannot_be_rebound() {
+        let mut buffer = ShortcutsBuffer::new(Vec::new());
+        search(&mut buffer, "switch to workspace");
+        let error = buffer.start_capture().unwrap_err();
+        assert_eq!(error, "Cmd+1..9 can't be rebound");
+        assert!(!buffer.is_capturing());
+    }
+}
@@ -0,0 +1,466 @@
+// Chunk: docs/chunks/spell_check - Spell checking for comments, strings and prose
+//!
+//! Spell checking.
+//!
+//! Prose files (see [`crate::word_count::is_prose_path`]) are checked
+//! throughout, apart from fenced code blocks and inline code. Other files are
+//! checked inside comments and strings only, as found by the tree-sitter
+//! highlight captures; a file without a highlighter isn't checked.
+//!
+//! Misspelled words are published to the file's annotation layer under
+//! [`SPELLING_SOURCE`] with a dotted underline. "Spelling corrections"
+//! (Cmd+;) lists the guesses for the misspelled word at the cursor, plus an
+//! item that adds the word to the user's dictionary.
+//!
+//! Only plain words are checked. Identifiers (`snake_case`, `camelCase`,
+//! words with digits), all-caps acronyms, URLs, escapes like `\n`, words
+//! attached to paths or member accesses, and words shorter than
+//! [`MIN_WORD_CHARS`] are skipped, since code comments are full of them.
+//!
+//! Words are looked up with NSSpellChecker, and each verdict is cached for the
+//! session.
+//!
+//! ## Test isolation
+//!
+//! Under `cfg(test)` NSSpellChecker is never touched. A short fixed list of
+//! misspellings stands in for the system dictionary, and learned words are
+//! kept in a `thread_local!` list, so tests never add words to the user's
+//! dictionary.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use lite_edit_buffer::{
+    Annotation, AnnotationSource, Color, Position, TextBuffer, UnderlineStyle,
+};
+use lite_edit_syntax::SyntaxHighlighter;
+
+/// The annotation source that misspellings are published under.
+pub const SPELLING_SOURCE: AnnotationSource = "spelling";
+
+/// Shortest word that is checked. Shorter words are mostly abbreviations.
+pub const MIN_WORD_CHARS: usize = 3;
+
+/// Underline color for misspelled words (Catppuccin Mocha red).
+const UNDERLINE_COLOR: Color = Color::Rgb {
+    r: 0xf3,
+    g: 0x8b,
+    b: 0xa8,
+};
+
+/// Highlight captures whose text is checked in code files.
+const CHECKED_CAPTURES: &[&str] = &["comment", "string"];
+
+/// A misspelled word in a buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Misspelling {
+    /// The line of the word (0-indexed).
+    pub line: usize,
+    /// The first column of the word (0-indexed, in characters).
+    pub start_col: usize,
+    /// The column after the word.
+    pub end_col: usize,
+    /// The word as written.
+    pub word: String,
+}
+
+impl Misspelling {
+    /// Returns the annotation that underlines the word.
+    pub fn annotation(&self) -> Annotation {
+        Annotation::underline(
+            self.line,
+            self.start_col,
+            self.end_col,
+            UnderlineStyle::Dotted,
+            Some(UNDERLINE_COLOR),
+        )
+    }
+}
+
+/// Checks words against the system dictionary, caching the verdicts.
+#[derive(Debug, Default)]
+pub struct SpellChecker {
+    /// Whether each word seen so far is spelled correctly.
+    verdicts: HashMap<String, bool>,
+}
+
+impl SpellChecker {
+    /// Creates a checker with an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if `word` is spelled correctly.
+    pub fn is_correct(&mut self, word: &str) -> bool {
+        if let Some(&verdict) = self.verdicts.get(word) {
+            return verdict;
+        }
+        let verdict = dictionary::is_correct(word);
+        self.verdicts.insert(word.to_string(), verdict);
+        verdict
+    }
+
+    /// Returns the suggested corrections for `word`, best first.
+    pub fn guesses(&self, word: &str) -> Vec<String> {
+        dictionary::guesses(word)
+    }
+
+    /// Adds `word` to the user's dictionary.
+    pub fn learn(&mut self, word: &str) {
+        dictionary::learn(word);
+        self.verdicts.insert(word.to_string(), true);
+    }
+
+    /// Returns the misspelled words in the `columns` of `line`, whose text is `chars`.
+    pub fn check_columns(
+        &mut self,
+        line: usize,
+        chars: &[char],
+        columns: Range<usize>,
+    ) -> Vec<Misspelling> {
+        words(chars, columns)
+            .into_iter()
+            .filter_map(|range| {
+                let word: String = chars[range.clone()].iter().collect();
+                (!self.is_correct(&word)).then_some(Misspelling {
+                    line,
+                    start_col: range.start,
+                    end_col: range.end,
+                    word,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the misspelled words in `lines` of `buffer`.
+///
+/// A prose buffer is checked outside code; otherwise only the comments and
+/// strings found by `highlighter` are checked.
+pub fn check_lines(
+    checker: &mut SpellChecker,
+    buffer: &TextBuffer,
+    highlighter: Option<&SyntaxHighlighter>,
+    prose: bool,
+    lines: Range<usize>,
+) -> Vec<Misspelling> {
+    let end = lines.end.min(buffer.line_count());
+    let mut found = Vec::new();
+    if prose {
+        // Fences are tracked from the top of the file
+        let mut in_fence = false;
+        for line in 0..end {
+            let text = buffer.line_content(line);
+            if is_fence(&text) {
+                in_fence = !in_fence;
+                continue;
+            }
+            if in_fence || line < lines.start {
+                continue;
+            }
+            let chars: Vec<char> = text.chars().collect();
+            for columns in outside_inline_code(&chars) {
+                found.extend(checker.check_columns(line, &chars, columns));
+            }
+        }
+    } else if let Some(highlighter) = highlighter {
+        let mut chars: Option<(usize, Vec<char>)> = None;
+        for (line, start, stop) in highlighter.capture_columns(lines.start, end, CHECKED_CAPTURES)
+        {
+            if chars.as_ref().map(|(l, _)| *l) != Some(line) {
+                chars = Some((line, buffer.line_content(line).chars().collect()));
+            }
+            let Some((_, ref text)) = chars else {
+                continue;
+            };
+            found.extend(checker.check_columns(line, text, start..stop.min(text.len())));
+        }
+        // Nested captures (e.g. an escape inside a string) can repeat words
+        found.sort_by_key(|m| (m.line, m.start_col));
+        found.dedup();
+    }
+    found
+}
+
+/// Returns the misspelling the corrections popover should offer for a cursor
+/// at `cursor`: the one under the cursor, else the next one after it, else
+/// the first in the file.
+pub fn misspelling_at(spelling: &[Annotation], cursor: Position) -> Option<&Annotation> {
+    spelling
+        .iter()
+        .find(|a| a.line == cursor.line && (a.start_col..=a.end_col).contains(&cursor.col))
+        .or_else(|| {
+            spelling
+                .iter()
+                .find(|a| (a.line, a.start_col) > (cursor.line, cursor.col))
+        })
+        .or_else(|| spelling.first())
+}
+
+/// Returns the selector items for correcting `word`: its guesses, then the
+/// item that learns it.
+pub fn correction_items(word: &str, guesses: &[String]) -> Vec<String> {
+    let mut items = guesses.to_vec();
+    items.push(format!("Learn spelling of \"{}\"", word));
+    items
+}
+
+/// Returns true if `line` opens or closes a fenced code block.
+fn is_fence(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```") || trimmed.starts_with("~~~")
+}
+
+/// Returns the column ranges of `chars` outside inline code spans.
+fn outside_inline_code(chars: &[char]) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut prose_start = 0;
+    let mut code_start = None;
+    for (col, &c) in chars.iter().enumerate() {
+        if c != '`' {
+            continue;
+        }
+        match code_start.take() {
+            None => {
+                ranges.push(prose_start..col);
+                code_start = Some(col);
+            }
+            Some(_) => prose_start = col + 1,
+        }
+    }
+    // An unclosed backtick is literal, so the rest of the line is prose
+    ranges.push(code_start.map_or(prose_start, |open| open + 1)..chars.len());
+    ranges
+}
+
+/// Returns the column ranges of the checkable words in `columns` of `chars`.
+///
+/// Neighbouring characters outside `columns` are still looked at, so a
+/// string's quotes don't hide that a word belongs to an identifier.
+pub fn words(chars: &[char], columns: Range<usize>) -> Vec<Range<usize>> {
+    let end = columns.end.min(chars.len());
+    let is_letter = |col: usize| chars.get(col).is_some_and(|c| c.is_alphabetic());
+    let mut words = Vec::new();
+    let mut col = columns.start;
+    while col < end {
+        if !is_letter(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        // Apostrophes between letters belong to the word ("don't")
+        while col < end && (is_letter(col) || (is_apostrophe(chars[col]) && is_letter(col + 1))) {
+            col += 1;
+        }
+        if is_checkable(chars, start, col) {
+            words.push(start..col);
+        }
+    }
+    words
+}
+
+/// Returns true if the letters at `start..end` of `chars` read as a plain word.
+fn is_checkable(chars: &[char], start: usize, end: usize) -> bool {
+    if end - start < MIN_WORD_CHARS || chars[start + 1..end].iter().any(|c| c.is_uppercase()) {
+        return false;
+    }
+    let before = start.checked_sub(1).map(|col| chars[col]);
+    let after = chars.get(end).copied();
+    let after_next = chars.get(end + 1).copied();
+    // Part of a longer word that `columns` cut through
+    if before.is_some_and(char::is_alphabetic) || after.is_some_and(char::is_alphabetic) {
+        return false;
+    }
+    if before.is_some_and(|c| c.is_ascii_digit() || "_\\/.$%{@#:-".contains(c)) {
+        return false;
+    }
+    if after.is_some_and(|c| c.is_ascii_digit() || "_(/@".contains(c)) {
+        return false;
+    }
+    // `file.rs`, `mod::item`: a separator followed directly by more text
+    if after.is_some_and(|c| ".:".contains(c)) && after_next.is_some_and(|c| c.is_alphanumeric()) {
+        return false;
+    }
+    !in_url(chars, start)
+}
+
+/// Returns true if column `col` of `chars` is inside a URL.
+fn in_url(chars: &[char], col: usize) -> bool {
+    let start = chars[..col]
+        .iter()
+        .rposition(|c| c.is_whitespace())
+        .map_or(0, |i| i + 1);
+    let end = chars[col..]
+        .iter()
+        .position(|c| c.is_whitespace())
+        .map_or(chars.len(), |i| col + i);
+    let token: String = chars[start..end].iter().collect();
+    token.contains("://") || token.starts_with("www.")
+}
+
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '’'
+}
+
+// ── production dictionary (NSSpellChecker) ───────────────────────────────────
+
+#[cfg(not(test))]
+mod dictionary {
+    use objc2_app_kit::NSSpellChecker;
+    use objc2_foundation::{NSRange, NSString};
+
+    /// Returns true if the system spell checker finds no misspelling in `word`.
+    pub fn is_correct(word: &str) -> bool {
+        let checker = NSSpellChecker::sharedSpellChecker();
+        let word = NSString::from_str(word);
+        checker.checkSpellingOfString_startingAt(&word, 0).length == 0
+    }
+
+    /// Returns the system spell checker's guesses for `word`.
+    pub fn guesses(word: &str) -> Vec<String> {
+        let checker = NSSpellChecker::sharedSpellChecker();
+        let word = NSString::from_str(word);
+        let range = NSRange::new(0, word.length());
+        checker
+            .guessesForWordRange_inString_language_inSpellDocumentWithTag(range, &word, None, 0)
+            .map(|guesses| guesses.iter().map(|guess| guess.to_string()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Adds `word` to the user's dictionary.
+    pub fn learn(word: &str) {
+        let checker = NSSpellChecker::sharedSpellChecker();
+        checker.learnWord(&NSString::from_str(word));
+    }
+}
+
+// ── test dictionary (fixed misspellings) ─────────────────────────────────────
+
+#[cfg(test)]
+mod dictionary {
+    use std::cell::RefCell;
+
+    /// The misspellings tests use, with their guesses.
+    const MISSPELLINGS: &[(&str, &[&str])] = &[
+        ("teh", &["the", "ten"]),
+        ("recieve", &["receive"]),
+        ("wrod", &["word", "wood"]),
+    ];
+
+    thread_local! {
+        /// Words learned by tests on the current thread.
+        static LEARNED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn lookup(word: &str) -> Option<&'static [&'static str]> {
+        let lower = word.to_lowercase();
+        MISSPELLINGS
+            .iter()
+            .find(|(misspelled, _)| *misspelled == lower)
+            .map(|(_, guesses)| *guesses)
+    }
+
+    pub fn is_correct(word: &str) -> bool {
+        lookup(word).is_none() || LEARNED.with(|learned| learned.borrow().iter().any(|w| w == word))
+    }
+
+    pub fn guesses(word: &str) -> Vec<String> {
+        lookup(word)
+            .unwrap_or_default()
+            .iter()
+            .map(|guess| guess.to_string())
+            .collect()
+    }
+
+    pub fn learn(word: &str) {
+        LEARNED.with(|learned| learned.borrow_mut().push(word.to_string()));
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word_list(text: &str) -> Vec<String> {
+        let chars: Vec<char> = text.chars().collect();
+        words(&chars, 0..chars.len())
+            .into_iter()
+            .map(|range| chars[range].iter().collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_words_skip_identifiers_and_urls() {
+        assert_eq!(
+            word_list("Don't parse my_value or parseLine, see https://example.com/docs"),
+            vec!["Don't", "parse", "see"]
+        );
+        assert_eq!(
+            word_list("call foo() in main.rs, then HTTP \\nline $HOME a1b"),
+            vec!["call", "then"]
+        );
+    }
+
+    #[test]
+    fn test_check_lines_skips_markdown_code() {
+        let buffer = TextBuffer::from_str("teh intro\n```\nteh code\n```\nsee `teh` and wrod");
+        let mut checker = SpellChecker::new();
+
+        let found = check_lines(&mut checker, &buffer, None, true, 0..5);
+        let spots: Vec<(usize, usize, &str)> = found
+            .iter()
+            .map(|m| (m.line, m.start_col, m.word.as_str()))
+            .collect();
+        assert_eq!(spots, vec![(0, 0, "teh"), (4, 14, "wrod")]);
+
+        // Rechecking a line inside the fence still knows it is code
+        assert!(check_lines(&mut checker, &buffer, None, true, 2..3).is_empty());
+        // Code without a highlighter isn't checked
+        assert!(check_lines(&mut checker, &buffer, None, false, 0..5).is_empty());
+    }
+
+    #[test]
+    fn test_learned_word_is_no_longer_misspelled() {
+        let mut checker = SpellChecker::new();
+        assert!(!checker.is_correct("recieve"));
+        assert_eq!(checker.guesses("recieve"), vec!["receive"]);
+
+        checker.learn("recieve");
+        assert!(checker.is_correct("recieve"));
+        assert!(SpellChecker::new().is_correct("recieve"));
+    }
+
+    #[test]
+    fn test_misspelling_at_prefers_cursor_then_next() {
+        let at = |line, start, end| {
+            Misspelling {
+                line,
+                start_col: start,
+                end_col: end,
+                word: String::new(),
+            }
+            .annotation()
+        };
+        let spelling = vec![at(1, 4, 8), at(3, 0, 3)];
+
+        let pick = |line, col| misspelling_at(&spelling, Position::new(line, col)).map(|a| a.line);
+        assert_eq!(pick(1, 8), Some(1));
+        assert_eq!(pick(1, 9), Some(3));
+        assert_eq!(pick(0, 0), Some(1));
+        assert_eq!(pick(5, 0), Some(1));
+        assert_eq!(misspelling_at(&[], Position::new(0, 0)), None);
+    }
+
+    #[test]
+    fn test_correction_items_end_with_learn() {
+        let guesses = vec!["the".to_string(), "ten".to_string()];
+        assert_eq!(
+            correction_items("teh", &guesses),
+            vec!["the", "ten", "Learn spelling of \"teh\""]
+        );
+    }
+}
@@ -27,7 +27,9 @@
 //! - `DirtyLines::FromLineToEnd(line)`: Truncate cache at that line (handles
 //!   line insertion/deletion which shifts all subsequent lines)
 
-use lite_edit_buffer::{DirtyLines, StyledLine};
+use std::ops::Range;
+
+use lite_edit_buffer::{BufferView, DirtyLines, StyledLine};
 
 /// Cache for computed `StyledLine` results, keyed by buffer line index.
 ///
@@ -76,6 +78,36 @@ impl StyledLineCache {
         self.lines[line] = Some(styled);
     }
 
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of missing lines
+    /// Computes the lines in `lines` that aren't cached.
+    ///
+    /// Each run of consecutive missing lines is fetched from `view` with one
+    /// `styled_lines()` call, so views backed by a lock or a file pay that
+    /// cost once per run rather than once per line. Lines past the end of
+    /// the view stay missing.
+    pub fn fill(&mut self, view: &dyn BufferView, lines: Range<usize>) {
+        let mut line = lines.start;
+        while line < lines.end {
+            if self.get(line).is_some() {
+                line += 1;
+                continue;
+            }
+            let run_end = (line..lines.end)
+                .find(|&l| self.get(l).is_some())
+                .unwrap_or(lines.end);
+            let fetched = view.styled_lines(line..run_end);
+            let complete = fetched.len() == run_end - line;
+            for (offset, styled) in fetched.into_iter().enumerate() {
+                self.insert(line + offset, styled);
+            }
+            if !complete {
+                // The view ended inside the run
+                return;
+            }
+            line = run_end;
+        }
+    }
+
     /// Invalidates cache entries based on dirty line information.
     ///
     /// This method handles each `DirtyLines` variant appropriately:
@@ -231,6 +263,68 @@ mod tests {
         assert_eq!(cache.get(5).unwrap(), &StyledLine::plain("second"));
     }
 
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of missing lines
+    #[test]
+    fn test_fill_fetches_each_missing_run_once() {
+        use std::cell::RefCell;
+
+        use lite_edit_buffer::CursorInfo;
+
+        /// Numbered lines that record every `styled_lines()` call.
+        struct CountingView {
+            line_count: usize,
+            batches: RefCell<Vec<Range<usize>>>,
+        }
+
+        impl BufferView for CountingView {
+            fn line_count(&self) -> usize {
+                self.line_count
+            }
+            fn styled_line(&self, line: usize) -> Option<StyledLine> {
+                (line < self.line_count).then(|| StyledLine::plain(line.to_string()))
+            }
+            fn styled_lines(&self, lines: Range<usize>) -> Vec<StyledLine> {
+                self.batches.borrow_mut().push(lines.clone());
+                lines.map_while(|line| self.styled_line(line)).collect()
+            }
+            fn line_len(&self, _line: usize) -> usize {
+                0
+            }
+            fn take_dirty(&mut self) -> DirtyLines {
+                DirtyLines::None
+            }
+            fn is_editable(&self) -> bool {
+                false
+            }
+            fn cursor_info(&self) -> Option<CursorInfo> {
+                None
+            }
+        }
+
+        let view = CountingView {
+            line_count: 12,
+            batches: RefCell::new(Vec::new()),
+        };
+        let mut cache = StyledLineCache::new();
+        cache.resize(12);
+        cache.insert(4, StyledLine::plain("cached"));
+
+        cache.fill(&view, 2..10);
+        assert_eq!(*view.batches.borrow(), vec![2..4, 5..10]);
+        assert_eq!(cache.get(3).unwrap(), &StyledLine::plain("3"));
+        assert_eq!(cache.get(4).unwrap(), &StyledLine::plain("cached"));
+        assert_eq!(cache.get(9).unwrap(), &StyledLine::plain("9"));
+
+        // Everything cached: no fetch. Past the end: lines stay missing.
+        view.batches.borrow_mut().clear();
+        cache.fill(&view, 2..10);
+        assert!(view.batches.borrow().is_empty());
+        cache.fill(&view, 10..15);
+        assert_eq!(*view.batches.borrow(), vec![10..15]);
+        assert!(cache.get(11).is_some());
+        assert!(cache.get(12).is_none());
+    }
+
     // ==================== Invalidation ====================
 
     #[test]
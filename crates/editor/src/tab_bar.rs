@@ -1,5 +1,7 @@
 // Chunk: docs/chunks/content_tab_bar - Content tab bar rendering and interaction
 // Chunk: docs/chunks/tab_bar_interaction - Label derivation and left-truncation
+// Chunk: docs/chunks/pinned_tabs_overflow - Pinned tabs and the overflow button
+// Chunk: docs/chunks/tab_reorder_drag - Drop gap while dragging a tab
 //!
 //! Tab bar layout and rendering for content tabs within a workspace.
 //!
@@ -18,7 +20,13 @@
 //! - A close button
 //!
 //! The active tab is visually highlighted. When tabs overflow the available width,
-//! horizontal scrolling is supported via `view_offset`.
+//! horizontal scrolling is supported via `view_offset`, and an overflow button at
+//! the right end of the bar lists the tabs that are out of view.
+//!
+//! Pinned tabs come first, have a fixed compact width and no close button.
+//!
+//! While a tab is dragged along its own tab bar, the tabs part to open a gap
+//! where it would be dropped.
 
 use std::ptr::NonNull;
 
@@ -30,7 +38,7 @@ use crate::glyph_atlas::{GlyphAtlas, GlyphInfo};
 use crate::glyph_buffer::{GlyphLayout, GlyphVertex, QuadRange};
 use crate::left_rail::RAIL_WIDTH;
 use crate::shader::VERTEX_SIZE;
-use crate::workspace::{Tab, TabKind, Workspace};
+use crate::workspace::{Tab, TabColor, TabKind, Workspace};
 
 // =============================================================================
 // Layout Constants
@@ -66,6 +74,20 @@ pub const INDICATOR_GAP: f32 = 4.0;
 /// Spacing between tabs
 pub const TAB_SPACING: f32 = 1.0;
 
+// Chunk: docs/chunks/pinned_tabs_overflow - Pinned tab and overflow button sizes
+/// Width of a pinned tab, which shows the first few characters of its label
+pub const PINNED_TAB_WIDTH: f32 = 56.0;
+
+/// Width of the overflow button at the right end of an overflowing tab bar
+pub const OVERFLOW_BUTTON_WIDTH: f32 = 28.0;
+
+// Chunk: docs/chunks/tab_reorder_drag - Drop gap size
+/// Width of the gap opened where a dragged tab would be dropped
+pub const DROP_GAP_WIDTH: f32 = 24.0;
+
+/// Width of the marker drawn in the middle of the drop gap
+pub const DROP_GAP_MARKER_WIDTH: f32 = 2.0;
+
 // =============================================================================
 // Colors (Catppuccin Mocha theme, consistent with left_rail.rs)
 // =============================================================================
@@ -102,6 +124,11 @@ pub const TAB_LABEL_COLOR: [f32; 4] = [
     1.0,
 ];
 
+// Chunk: docs/chunks/read_only_preview_tabs - Italic preview tab labels
+/// Horizontal shift per pixel of glyph height that slants a preview tab's
+/// label. The glyph atlas has no italic face, so the quads are sheared.
+pub const PREVIEW_LABEL_SLANT: f32 = 0.2;
+
 /// Dirty indicator color (yellow/orange)
 pub const DIRTY_INDICATOR_COLOR: [f32; 4] = [
     0.9,
@@ -118,6 +145,23 @@ pub const UNREAD_INDICATOR_COLOR: [f32; 4] = [
     1.0,
 ];
 
+// Chunk: docs/chunks/tab_reorder_drag - Drop gap marker color
+/// Drop gap marker color (blue, matching the unread indicator)
+pub const DROP_GAP_COLOR: [f32; 4] = UNREAD_INDICATOR_COLOR;
+
+// Chunk: docs/chunks/terminal_bell_attention - Bell indicator color
+/// Bell indicator color (orange)
+///
+/// Shown on terminal tabs whose program rang the bell (BEL) while the tab
+/// was in the background. Distinct from the blue unread indicator so that
+/// "wants attention" stands out from "has new output".
+pub const BELL_INDICATOR_COLOR: [f32; 4] = [
+    0.95,
+    0.55,
+    0.15,
+    1.0,
+];
+
 // Chunk: docs/chunks/conflict_mode_lifecycle - Conflict indicator color
 /// Conflict indicator color (Catppuccin red/pink #f38ba8)
 ///
@@ -131,6 +175,21 @@ pub const CONFLICT_INDICATOR_COLOR: [f32; 4] = [
     1.0,
 ];
 
+// Chunk: docs/chunks/terminal_tab_labels - Color label dot colors
+/// Returns the color of a tab's color label dot.
+///
+/// Also used for the label dots on the left rail tiles.
+pub fn color_label_rgba(color: TabColor) -> [f32; 4] {
+    match color {
+        TabColor::Red => [0.90, 0.30, 0.30, 1.0],
+        TabColor::Orange => [0.95, 0.60, 0.25, 1.0],
+        TabColor::Yellow => [0.95, 0.85, 0.35, 1.0],
+        TabColor::Green => [0.40, 0.80, 0.40, 1.0],
+        TabColor::Blue => [0.35, 0.55, 0.95, 1.0],
+        TabColor::Purple => [0.70, 0.45, 0.90, 1.0],
+    }
+}
+
 /// Close button color (dimmed)
 pub const CLOSE_BUTTON_COLOR: [f32; 4] = [
     0.5,
@@ -194,6 +253,23 @@ impl CloseButtonRect {
     }
 }
 
+// Chunk: docs/chunks/pinned_tabs_overflow - Overflow button hit area
+/// Rectangle describing the overflow button's hit area.
+#[derive(Debug, Clone, Copy)]
+pub struct OverflowButtonRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl OverflowButtonRect {
+    /// Returns true if the point (px, py) is inside the overflow button.
+    pub fn contains(&self, px: f32, py: f32) -> bool {
+        px >= self.x && px < self.x + self.width && py >= self.y && py < self.y + self.height
+    }
+}
+
 /// A tab rectangle with hit areas for the tab body and close button.
 #[derive(Debug, Clone, Copy)]
 pub struct TabRect {
@@ -251,6 +327,52 @@ pub struct TabBarGeometry {
     pub view_offset: f32,
     /// Total width of all tabs (may exceed visible width)
     pub total_tabs_width: f32,
+    // Chunk: docs/chunks/pinned_tabs_overflow - Overflow button and hidden tabs
+    /// The overflow button, present when the tabs don't all fit
+    pub overflow_button: Option<OverflowButtonRect>,
+    /// Indices of the tabs that aren't fully visible, in tab order
+    pub hidden_tabs: Vec<usize>,
+    // Chunk: docs/chunks/tab_reorder_drag - Drop gap while dragging a tab
+    /// Left edge of the gap opened by `open_drop_gap`, if any
+    pub drop_gap: Option<f32>,
+}
+
+impl TabBarGeometry {
+    // Chunk: docs/chunks/tab_reorder_drag - Drop slot under a dragged tab
+    /// Returns the slot a tab dragged to `x` would be dropped in: the index
+    /// of the first visible tab whose middle is right of `x`, or one past the
+    /// last visible tab.
+    pub fn drop_slot_at(&self, x: f32) -> usize {
+        match self
+            .tab_rects
+            .iter()
+            .find(|rect| x < rect.x + rect.width / 2.0)
+        {
+            Some(rect) => rect.tab_index,
+            None => self.tab_rects.last().map_or(0, |rect| rect.tab_index + 1),
+        }
+    }
+
+    /// Opens a `DROP_GAP_WIDTH` gap before the tab at `slot` (or after the
+    /// last tab) by moving the tabs from `slot` on to the right.
+    pub fn open_drop_gap(&mut self, slot: usize) {
+        let gap_x = match self.tab_rects.iter().find(|rect| rect.tab_index >= slot) {
+            Some(rect) => rect.x,
+            None => match self.tab_rects.last() {
+                Some(rect) => rect.x + rect.width + TAB_SPACING,
+                None => return,
+            },
+        };
+        let moved = self
+            .tab_rects
+            .iter_mut()
+            .filter(|rect| rect.tab_index >= slot);
+        for rect in moved {
+            rect.x += DROP_GAP_WIDTH;
+            rect.close_button.x += DROP_GAP_WIDTH;
+        }
+        self.drop_gap = Some(gap_x);
+    }
 }
 
 // Chunk: docs/chunks/content_tab_bar - Tab metadata (label, kind, dirty, unread) used for rendering
@@ -268,6 +390,18 @@ pub struct TabInfo {
     pub is_unread: bool,
     /// Whether this tab is in conflict mode (merge conflict markers present)
     pub is_conflict: bool,
+    // Chunk: docs/chunks/terminal_bell_attention - Bell badge for background terminals
+    /// Whether this tab's terminal rang the bell since the tab was last focused
+    pub is_bell: bool,
+    // Chunk: docs/chunks/read_only_preview_tabs - Preview tabs have italic labels
+    /// Whether this is a preview tab, which the next single-clicked file replaces
+    pub is_preview: bool,
+    // Chunk: docs/chunks/pinned_tabs_overflow - Pinned tabs are drawn compact
+    /// Whether the tab is pinned
+    pub is_pinned: bool,
+    // Chunk: docs/chunks/terminal_tab_labels - Color label dot
+    /// The tab's color label, if the user gave it one
+    pub color_label: Option<TabColor>,
     /// Tab index in the workspace
     pub index: usize,
 }
@@ -281,7 +415,8 @@ impl TabInfo {
     /// path at render time, using the filename component. This ensures the label always
     /// reflects the current file path rather than a stale snapshot.
     ///
-    /// For non-file tabs (Terminal, AgentOutput, Diff), the static `tab.label` is used.
+    /// For non-file tabs (Terminal, AgentOutput, Diff, CommandOutput, Problems),
+    /// the static `tab.label` is used.
     pub fn from_tab(tab: &Tab, index: usize, is_active: bool) -> Self {
         let label = match tab.kind {
             TabKind::File => {
@@ -294,9 +429,24 @@ impl TabInfo {
                     .unwrap_or_else(|| "Untitled".to_string())
             }
             // Non-file tabs use the static label
-            TabKind::Terminal | TabKind::AgentOutput | TabKind::Diff => {
-                tab.label.clone()
-            }
+            TabKind::Terminal
+            | TabKind::AgentOutput
+            | TabKind::Diff
+            | TabKind::CommandOutput
+            | TabKind::Problems
+            | TabKind::Changes
+            | TabKind::Settings
+            | TabKind::Shortcuts
+            | TabKind::TerminalMemory
+            | TabKind::Replay
+            | TabKind::Agents
+            | TabKind::AgentTimeline
+            | TabKind::Review => tab.label.clone(),
+        };
+        // Chunk: docs/chunks/background_file_io - Load progress on the tab
+        let label = match &tab.loading {
+            Some(progress) => format!("{} {}%", label, progress.percent()),
+            None => label,
         };
 
         Self {
@@ -305,6 +455,10 @@ impl TabInfo {
             is_dirty: tab.dirty,
             is_unread: tab.unread,
             is_conflict: tab.conflict_mode,
+            is_bell: tab.has_pending_bell(),
+            is_preview: tab.preview,
+            is_pinned: tab.pinned,
+            color_label: tab.color_label,
             index,
         }
     }
@@ -320,6 +474,17 @@ pub fn calculate_tab_width(label: &str, glyph_width: f32) -> f32 {
     content_width.clamp(TAB_MIN_WIDTH, TAB_MAX_WIDTH)
 }
 
+// Chunk: docs/chunks/pinned_tabs_overflow - Pinned tabs have a fixed width
+/// Returns the width of `tab`: compact when pinned, otherwise sized to its
+/// label by `calculate_tab_width`.
+pub fn tab_width(tab: &TabInfo, glyph_width: f32) -> f32 {
+    if tab.is_pinned {
+        PINNED_TAB_WIDTH
+    } else {
+        calculate_tab_width(&tab.label, glyph_width)
+    }
+}
+
 /// Calculates the geometry for the tab bar.
 ///
 /// This is a pure function suitable for unit testing.
@@ -340,51 +505,8 @@ pub fn calculate_tab_bar_geometry(
     glyph_width: f32,
     view_offset: f32,
 ) -> TabBarGeometry {
-    let bar_x = RAIL_WIDTH;
-    let bar_y = 0.0;
     let bar_width = (view_width - RAIL_WIDTH).max(0.0);
-    let bar_height = TAB_BAR_HEIGHT;
-
-    let mut tab_rects = Vec::with_capacity(tabs.len());
-    let mut x = bar_x - view_offset;
-    let y = bar_y;
-
-    for (idx, tab_info) in tabs.iter().enumerate() {
-        let tab_width = calculate_tab_width(&tab_info.label, glyph_width);
-
-        // Only add tabs that are at least partially visible
-        let tab_right = x + tab_width;
-        let visible_left = bar_x;
-        let visible_right = bar_x + bar_width;
-
-        if tab_right > visible_left && x < visible_right {
-            // Calculate close button position (right side of tab)
-            let close_x = x + tab_width - TAB_PADDING_H - CLOSE_BUTTON_SIZE;
-            let close_y = y + (bar_height - CLOSE_BUTTON_SIZE) / 2.0;
-            let close_button = CloseButtonRect::new(close_x, close_y, CLOSE_BUTTON_SIZE);
-
-            tab_rects.push(TabRect::new(x, y, tab_width, bar_height, close_button, idx));
-        }
-
-        x += tab_width + TAB_SPACING;
-    }
-
-    // Calculate total width of all tabs
-    let total_tabs_width: f32 = tabs.iter()
-        .map(|t| calculate_tab_width(&t.label, glyph_width) + TAB_SPACING)
-        .sum::<f32>()
-        .max(0.0)
-        - TAB_SPACING; // Remove trailing spacing
-
-    TabBarGeometry {
-        x: bar_x,
-        y: bar_y,
-        width: bar_width,
-        height: bar_height,
-        tab_rects,
-        view_offset,
-        total_tabs_width: total_tabs_width.max(0.0),
-    }
+    layout_tab_bar(RAIL_WIDTH, 0.0, bar_width, tabs, glyph_width, view_offset)
 }
 
 // Chunk: docs/chunks/tiling_multi_pane_render - Pane-specific tab bar geometry
@@ -411,42 +533,80 @@ pub fn calculate_pane_tab_bar_geometry(
     glyph_width: f32,
     view_offset: f32,
 ) -> TabBarGeometry {
-    let bar_x = pane_x;
-    let bar_y = pane_y;
-    let bar_width = pane_width.max(0.0);
+    layout_tab_bar(
+        pane_x,
+        pane_y,
+        pane_width.max(0.0),
+        tabs,
+        glyph_width,
+        view_offset,
+    )
+}
+
+/// Lays out a tab bar at (`bar_x`, `bar_y`) that is `bar_width` wide.
+///
+/// When the tabs don't all fit, the overflow button takes the right end of
+/// the bar and the tabs are laid out in what is left.
+// Chunk: docs/chunks/pinned_tabs_overflow - Shared layout with the overflow button
+fn layout_tab_bar(
+    bar_x: f32,
+    bar_y: f32,
+    bar_width: f32,
+    tabs: &[TabInfo],
+    glyph_width: f32,
+    view_offset: f32,
+) -> TabBarGeometry {
     let bar_height = TAB_BAR_HEIGHT;
 
+    // Calculate total width of all tabs
+    let total_tabs_width: f32 = tabs
+        .iter()
+        .map(|t| tab_width(t, glyph_width) + TAB_SPACING)
+        .sum::<f32>()
+        .max(0.0)
+        - TAB_SPACING; // Remove trailing spacing
+    let total_tabs_width = total_tabs_width.max(0.0);
+
+    let overflow_button = (total_tabs_width > bar_width).then_some(OverflowButtonRect {
+        x: bar_x + bar_width - OVERFLOW_BUTTON_WIDTH,
+        y: bar_y,
+        width: OVERFLOW_BUTTON_WIDTH,
+        height: bar_height,
+    });
+    let visible_left = bar_x;
+    let visible_right = overflow_button.map_or(bar_x + bar_width, |button| button.x);
+
     let mut tab_rects = Vec::with_capacity(tabs.len());
+    let mut hidden_tabs = Vec::new();
     let mut x = bar_x - view_offset;
     let y = bar_y;
 
     for (idx, tab_info) in tabs.iter().enumerate() {
-        let tab_width = calculate_tab_width(&tab_info.label, glyph_width);
+        let tab_width = tab_width(tab_info, glyph_width);
 
         // Only add tabs that are at least partially visible
         let tab_right = x + tab_width;
-        let visible_left = bar_x;
-        let visible_right = bar_x + bar_width;
 
         if tab_right > visible_left && x < visible_right {
-            // Calculate close button position (right side of tab)
-            let close_x = x + tab_width - TAB_PADDING_H - CLOSE_BUTTON_SIZE;
+            // Calculate close button position (right side of tab). Pinned
+            // tabs have none, so theirs is empty and marks where the label ends
             let close_y = y + (bar_height - CLOSE_BUTTON_SIZE) / 2.0;
-            let close_button = CloseButtonRect::new(close_x, close_y, CLOSE_BUTTON_SIZE);
+            let close_button = if tab_info.is_pinned {
+                CloseButtonRect::new(x + tab_width - TAB_PADDING_H, close_y, 0.0)
+            } else {
+                let close_x = x + tab_width - TAB_PADDING_H - CLOSE_BUTTON_SIZE;
+                CloseButtonRect::new(close_x, close_y, CLOSE_BUTTON_SIZE)
+            };
 
             tab_rects.push(TabRect::new(x, y, tab_width, bar_height, close_button, idx));
         }
+        if x < visible_left || tab_right > visible_right {
+            hidden_tabs.push(idx);
+        }
 
         x += tab_width + TAB_SPACING;
     }
 
-    // Calculate total width of all tabs
-    let total_tabs_width: f32 = tabs.iter()
-        .map(|t| calculate_tab_width(&t.label, glyph_width) + TAB_SPACING)
-        .sum::<f32>()
-        .max(0.0)
-        - TAB_SPACING; // Remove trailing spacing
-
     TabBarGeometry {
         x: bar_x,
         y: bar_y,
@@ -454,7 +614,42 @@ pub fn calculate_pane_tab_bar_geometry(
         height: bar_height,
         tab_rects,
         view_offset,
-        total_tabs_width: total_tabs_width.max(0.0),
+        total_tabs_width,
+        overflow_button,
+        hidden_tabs,
+        drop_gap: None,
+    }
+}
+
+// Chunk: docs/chunks/pinned_tabs_overflow - Scrolling a tab into view
+/// Returns the view offset that brings `tabs[index]` fully into view in a tab
+/// bar `bar_width` wide, moving `view_offset` as little as possible.
+pub fn view_offset_showing_tab(
+    tabs: &[TabInfo],
+    index: usize,
+    bar_width: f32,
+    glyph_width: f32,
+    view_offset: f32,
+) -> f32 {
+    let Some(tab) = tabs.get(index) else {
+        return view_offset;
+    };
+    let left: f32 = tabs[..index]
+        .iter()
+        .map(|t| tab_width(t, glyph_width) + TAB_SPACING)
+        .sum();
+    let right = left + tab_width(tab, glyph_width);
+
+    let geometry = layout_tab_bar(0.0, 0.0, bar_width, tabs, glyph_width, view_offset);
+    let visible_width = geometry
+        .overflow_button
+        .map_or(bar_width, |button| button.x);
+    if left < view_offset {
+        left
+    } else if right > view_offset + visible_width {
+        right - visible_width
+    } else {
+        view_offset
     }
 }
 
@@ -572,6 +767,12 @@ pub struct TabBarGlyphBuffer {
     close_button_range: QuadRange,
     /// Tab labels
     label_range: QuadRange,
+    // Chunk: docs/chunks/pinned_tabs_overflow - Overflow button quads
+    /// Overflow button background and chevron
+    overflow_button_range: QuadRange,
+    // Chunk: docs/chunks/tab_reorder_drag - Drop gap marker quads
+    /// Marker in the drop gap of a dragged tab
+    drop_gap_range: QuadRange,
 }
 
 impl TabBarGlyphBuffer {
@@ -591,6 +792,8 @@ impl TabBarGlyphBuffer {
             indicator_range: QuadRange::default(),
             close_button_range: QuadRange::default(),
             label_range: QuadRange::default(),
+            overflow_button_range: QuadRange::default(),
+            drop_gap_range: QuadRange::default(),
         }
     }
 
@@ -639,6 +842,18 @@ impl TabBarGlyphBuffer {
         self.label_range
     }
 
+    // Chunk: docs/chunks/pinned_tabs_overflow - Overflow button draw range
+    /// Returns the index range for the overflow button.
+    pub fn overflow_button_range(&self) -> QuadRange {
+        self.overflow_button_range
+    }
+
+    // Chunk: docs/chunks/tab_reorder_drag - Drop gap draw range
+    /// Returns the index range for the drop gap marker.
+    pub fn drop_gap_range(&self) -> QuadRange {
+        self.drop_gap_range
+    }
+
     /// Updates the buffers from the workspace and geometry.
     // Chunk: docs/chunks/tab_bar_interaction - Left-truncation of labels to preserve file extension
     ///
@@ -649,6 +864,8 @@ impl TabBarGlyphBuffer {
     /// 4. Dirty/unread indicators
     /// 5. Close button icons
     /// 6. Tab labels
+    /// 7. Drop gap marker
+    /// 8. Overflow button, over any tab partly beneath it
     ///
     /// # Arguments
     /// * `device` - The Metal device for buffer creation
@@ -666,7 +883,7 @@ impl TabBarGlyphBuffer {
         // Estimate capacity: 1 background + tabs + indicators + close buttons + label chars
         let label_chars: usize = tabs.iter().map(|t| t.label.chars().count()).sum();
         let tab_count = geometry.tab_rects.len();
-        let estimated_quads = 1 + tab_count * 3 + label_chars; // bg + tabs + indicators + close + labels
+        let estimated_quads = 1 + tab_count * 3 + label_chars + 3; // bg + tabs + indicators + close + labels + gap + overflow
 
         // Chunk: docs/chunks/quad_buffer_prealloc - Clear and reserve persistent buffers
         self.persistent_vertices.clear();
@@ -686,6 +903,8 @@ impl TabBarGlyphBuffer {
         self.indicator_range = QuadRange::default();
         self.close_button_range = QuadRange::default();
         self.label_range = QuadRange::default();
+        self.overflow_button_range = QuadRange::default();
+        self.drop_gap_range = QuadRange::default();
 
         let solid_glyph = atlas.solid_glyph();
 
@@ -773,12 +992,19 @@ impl TabBarGlyphBuffer {
             let tab_info = &tabs[tab_rect.tab_index];
 
             // Conflict mode takes priority (conflict implies dirty, but we want distinct color)
-            // Then dirty, then unread
+            // Then dirty, then bell, then the color label, then unread
             let indicator_color = if tab_info.is_conflict && tab_info.is_dirty {
                 // Conflict mode - show distinct conflict indicator
                 Some(CONFLICT_INDICATOR_COLOR)
             } else if tab_info.is_dirty {
                 Some(DIRTY_INDICATOR_COLOR)
+            } else if tab_info.is_bell {
+                // Chunk: docs/chunks/terminal_bell_attention - Bell badge
+                Some(BELL_INDICATOR_COLOR)
+            } else if let Some(color) = tab_info.color_label {
+                // Chunk: docs/chunks/terminal_tab_labels - The label says which tab this is,
+                // which matters more than the unread dot it replaces
+                Some(color_label_rgba(color))
             } else if tab_info.is_unread {
                 Some(UNREAD_INDICATOR_COLOR)
             } else {
@@ -808,6 +1034,11 @@ impl TabBarGlyphBuffer {
         // ==================== Phase 5: Close Buttons ====================
         let close_start = self.persistent_indices.len();
         for tab_rect in &geometry.tab_rects {
+            // Chunk: docs/chunks/pinned_tabs_overflow - Pinned tabs can't be closed by click
+            if tabs[tab_rect.tab_index].is_pinned {
+                continue;
+            }
+
             // Draw close button as an "×" character
             let close_rect = &tab_rect.close_button;
 
@@ -832,7 +1063,12 @@ impl TabBarGlyphBuffer {
 
             // Calculate label position (after indicator if present)
             // Chunk: docs/chunks/conflict_mode_lifecycle - Account for conflict indicator
-            let has_indicator = tab_info.is_dirty || tab_info.is_unread || tab_info.is_conflict;
+            // Chunk: docs/chunks/terminal_tab_labels - Account for the color label dot
+            let has_indicator = tab_info.is_dirty
+                || tab_info.is_unread
+                || tab_info.is_conflict
+                || tab_info.is_bell
+                || tab_info.color_label.is_some();
             let label_x = if has_indicator {
                 tab_rect.x + TAB_PADDING_H + INDICATOR_SIZE + INDICATOR_GAP
             } else {
@@ -848,7 +1084,10 @@ impl TabBarGlyphBuffer {
             // Chunk: docs/chunks/tab_bar_interaction - Left-truncation to preserve file extension
             // Truncate from the left when label exceeds available space, preserving the
             // end of the filename (which typically contains the extension and distinguishing chars)
-            let label: String = {
+            // Chunk: docs/chunks/pinned_tabs_overflow - Pinned labels keep their first characters
+            let label: String = if tab_info.is_pinned {
+                tab_info.label.chars().take(max_chars).collect()
+            } else {
                 let char_count = tab_info.label.chars().count();
                 if char_count > max_chars && max_chars > 1 {
                     // Left-truncate: skip (char_count - max_chars + 1) chars, prepend ellipsis
@@ -875,7 +1114,11 @@ impl TabBarGlyphBuffer {
 
                 if let Some(glyph) = atlas.get_glyph(c) {
                     let x = label_x + char_idx as f32 * self.layout.glyph_width;
-                    let quad = self.create_glyph_quad_at(x, label_y, glyph, TAB_LABEL_COLOR);
+                    let mut quad = self.create_glyph_quad_at(x, label_y, glyph, TAB_LABEL_COLOR);
+                    // Chunk: docs/chunks/read_only_preview_tabs - Slant preview labels
+                    if tab_info.is_preview {
+                        slant_quad(&mut quad, PREVIEW_LABEL_SLANT);
+                    }
                     self.persistent_vertices.extend_from_slice(&quad);
                     Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
                     vertex_offset += 4;
@@ -884,6 +1127,53 @@ impl TabBarGlyphBuffer {
         }
         self.label_range = QuadRange::new(label_start, self.persistent_indices.len() - label_start);
 
+        // ==================== Phase 7: Drop Gap Marker ====================
+        // Chunk: docs/chunks/tab_reorder_drag - Where a dragged tab would land
+        let gap_start = self.persistent_indices.len();
+        if let Some(gap_x) = geometry.drop_gap {
+            let quad = self.create_rect_quad(
+                gap_x + (DROP_GAP_WIDTH - DROP_GAP_MARKER_WIDTH) / 2.0,
+                geometry.y + TAB_PADDING_V,
+                DROP_GAP_MARKER_WIDTH,
+                geometry.height - 2.0 * TAB_PADDING_V,
+                solid_glyph,
+                DROP_GAP_COLOR,
+            );
+            self.persistent_vertices.extend_from_slice(&quad);
+            Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+            vertex_offset += 4;
+        }
+        self.drop_gap_range = QuadRange::new(gap_start, self.persistent_indices.len() - gap_start);
+
+        // ==================== Phase 8: Overflow Button ====================
+        // Chunk: docs/chunks/pinned_tabs_overflow - Chevron listing the hidden tabs
+        let overflow_start = self.persistent_indices.len();
+        if let Some(button) = geometry.overflow_button {
+            let quad = self.create_rect_quad(
+                button.x,
+                button.y,
+                button.width,
+                button.height,
+                solid_glyph,
+                TAB_BAR_BACKGROUND_COLOR,
+            );
+            self.persistent_vertices.extend_from_slice(&quad);
+            Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+            vertex_offset += 4;
+
+            if let Some(glyph) = atlas.get_glyph('»').or_else(|| atlas.get_glyph('>')) {
+                let glyph_x = button.x + (button.width - glyph.width) / 2.0;
+                let glyph_y = button.y + (button.height - glyph.height) / 2.0;
+                let quad = self.create_glyph_quad_at(glyph_x, glyph_y, glyph, TAB_LABEL_COLOR);
+                self.persistent_vertices.extend_from_slice(&quad);
+                Self::push_quad_indices(&mut self.persistent_indices, vertex_offset);
+            }
+        }
+        self.overflow_button_range = QuadRange::new(
+            overflow_start,
+            self.persistent_indices.len() - overflow_start,
+        );
+
         // ==================== Create GPU Buffers ====================
         // Chunk: docs/chunks/quad_buffer_prealloc - Use persistent buffers for GPU upload
         if self.persistent_vertices.is_empty() {
@@ -978,6 +1268,17 @@ impl TabBarGlyphBuffer {
     }
 }
 
+// Chunk: docs/chunks/read_only_preview_tabs - Italic preview tab labels
+/// Shears a glyph quad (top-left, top-right, bottom-right, bottom-left) so
+/// it leans right by `slant` pixels per pixel of height, about its middle.
+fn slant_quad(quad: &mut [GlyphVertex; 4], slant: f32) {
+    let shift = (quad[3].position[1] - quad[0].position[1]) * slant / 2.0;
+    quad[0].position[0] += shift;
+    quad[1].position[0] += shift;
+    quad[2].position[0] -= shift;
+    quad[3].position[0] -= shift;
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -1014,6 +1315,10 @@ mod tests {
             is_dirty: false,
             is_unread: false,
             is_conflict: false,
+            is_bell: false,
+            is_preview: false,
+            is_pinned: false,
+            color_label: None,
             index: 0,
         }];
         let geom = calculate_tab_bar_geometry(800.0, &tabs, test_glyph_width(), 0.0);
@@ -1035,6 +1340,10 @@ mod tests {
                 is_dirty: false,
                 is_unread: false,
                 is_conflict: false,
+                is_bell: false,
+                is_preview: false,
+                is_pinned: false,
+                color_label: None,
                 index: i,
             })
             .collect();
@@ -1057,6 +1366,10 @@ mod tests {
                 is_dirty: false,
                 is_unread: false,
                 is_conflict: false,
+                is_bell: false,
+                is_preview: false,
+                is_pinned: false,
+                color_label: None,
                 index: i,
             })
             .collect();
@@ -1131,6 +1444,10 @@ mod tests {
                 is_dirty: false,
                 is_unread: false,
                 is_conflict: false,
+                is_bell: false,
+                is_preview: false,
+                is_pinned: false,
+                color_label: None,
                 index: i,
             })
             .collect();
@@ -1144,6 +1461,143 @@ mod tests {
         }
     }
 
+    // Chunk: docs/chunks/pinned_tabs_overflow - Pinned tab geometry
+    #[test]
+    fn test_pinned_tabs_are_compact_without_close_button() {
+        let tabs: Vec<TabInfo> = (0..2)
+            .map(|i| TabInfo {
+                label: format!("longfilename{}.rs", i),
+                is_active: false,
+                is_dirty: false,
+                is_unread: false,
+                is_conflict: false,
+                is_bell: false,
+                is_preview: false,
+                is_pinned: i == 0,
+                color_label: None,
+                index: i,
+            })
+            .collect();
+        let geom = calculate_tab_bar_geometry(800.0, &tabs, test_glyph_width(), 0.0);
+
+        let pinned = &geom.tab_rects[0];
+        assert_eq!(pinned.width, PINNED_TAB_WIDTH);
+        assert!(!pinned.is_close_button(pinned.close_button.x, pinned.close_button.y));
+        let after_pinned = RAIL_WIDTH + PINNED_TAB_WIDTH + TAB_SPACING;
+        assert_eq!(geom.tab_rects[1].x, after_pinned);
+        assert!(geom.tab_rects[1].width > PINNED_TAB_WIDTH);
+    }
+
+    // Chunk: docs/chunks/pinned_tabs_overflow - Overflow button and hidden tabs
+    #[test]
+    fn test_overflow_button_lists_hidden_tabs() {
+        let tabs: Vec<TabInfo> = (0..8)
+            .map(|i| TabInfo {
+                label: format!("longfilename{}.rs", i),
+                is_active: false,
+                is_dirty: false,
+                is_unread: false,
+                is_conflict: false,
+                is_bell: false,
+                is_preview: false,
+                is_pinned: false,
+                color_label: None,
+                index: i,
+            })
+            .collect();
+
+        // Everything fits in a wide bar
+        let geom =
+            calculate_pane_tab_bar_geometry(0.0, 0.0, 2000.0, &tabs, test_glyph_width(), 0.0);
+        assert!(geom.overflow_button.is_none());
+        assert!(geom.hidden_tabs.is_empty());
+
+        // In a narrow one the button takes the right end and tabs stop short of it
+        let geom = calculate_pane_tab_bar_geometry(0.0, 0.0, 500.0, &tabs, test_glyph_width(), 0.0);
+        let button = geom.overflow_button.expect("tabs overflow");
+        assert_eq!(button.x, 500.0 - OVERFLOW_BUTTON_WIDTH);
+        assert!(button.contains(490.0, 10.0));
+        assert!(geom.tab_rects.iter().all(|rect| rect.x < button.x));
+        let first_hidden = geom.hidden_tabs[0];
+        assert_eq!(geom.hidden_tabs, (first_hidden..8).collect::<Vec<_>>());
+
+        // Scrolling right hides the first tab instead
+        let geom =
+            calculate_pane_tab_bar_geometry(0.0, 0.0, 500.0, &tabs, test_glyph_width(), 50.0);
+        assert_eq!(geom.hidden_tabs[0], 0);
+    }
+
+    // Chunk: docs/chunks/pinned_tabs_overflow - Scrolling a tab into view
+    #[test]
+    fn test_view_offset_showing_tab() {
+        let tabs: Vec<TabInfo> = (0..8)
+            .map(|i| TabInfo {
+                label: format!("longfilename{}.rs", i),
+                is_active: false,
+                is_dirty: false,
+                is_unread: false,
+                is_conflict: false,
+                is_bell: false,
+                is_preview: false,
+                is_pinned: false,
+                color_label: None,
+                index: i,
+            })
+            .collect();
+        let glyph_width = test_glyph_width();
+
+        // A visible tab leaves the offset alone
+        let offset = view_offset_showing_tab(&tabs, 0, 500.0, glyph_width, 0.0);
+        assert_eq!(offset, 0.0);
+
+        // The last tab's right edge lines up with the overflow button
+        let offset = view_offset_showing_tab(&tabs, 7, 500.0, glyph_width, 0.0);
+        let geom = calculate_pane_tab_bar_geometry(0.0, 0.0, 500.0, &tabs, glyph_width, offset);
+        assert!(!geom.hidden_tabs.contains(&7));
+
+        // Going back to the first tab scrolls all the way left
+        let offset = view_offset_showing_tab(&tabs, 0, 500.0, glyph_width, offset);
+        assert_eq!(offset, 0.0);
+    }
+
+    // Chunk: docs/chunks/tab_reorder_drag - Drop slots and the drop gap
+    #[test]
+    fn test_drop_slot_and_gap() {
+        let tabs: Vec<TabInfo> = (0..3)
+            .map(|i| TabInfo {
+                label: format!("file{}.rs", i),
+                is_active: false,
+                is_dirty: false,
+                is_unread: false,
+                is_conflict: false,
+                is_bell: false,
+                is_preview: false,
+                is_pinned: false,
+                color_label: None,
+                index: i,
+            })
+            .collect();
+        let glyph_width = test_glyph_width();
+        let mut geom = calculate_pane_tab_bar_geometry(0.0, 0.0, 800.0, &tabs, glyph_width, 0.0);
+        let (first, second) = (geom.tab_rects[0], geom.tab_rects[1]);
+
+        // Left of a tab's middle is the slot before it, right of it the one after
+        assert_eq!(geom.drop_slot_at(first.x), 0);
+        assert_eq!(geom.drop_slot_at(second.x + 1.0), 1);
+        assert_eq!(geom.drop_slot_at(second.x + second.width - 1.0), 2);
+        assert_eq!(geom.drop_slot_at(790.0), 3);
+
+        // Opening the gap moves the tabs from the slot on to the right
+        geom.open_drop_gap(1);
+        assert_eq!(geom.drop_gap, Some(second.x));
+        assert_eq!(geom.tab_rects[0].x, first.x);
+        assert_eq!(geom.tab_rects[1].x, second.x + DROP_GAP_WIDTH);
+        assert_eq!(
+            geom.tab_rects[1].close_button.x,
+            second.close_button.x + DROP_GAP_WIDTH
+        );
+    }
+
     // =========================================================================
     // TabInfo Tests
     // =========================================================================
@@ -1156,6 +1610,10 @@ mod tests {
             is_dirty: true,
             is_unread: false,
             is_conflict: false,
+            is_bell: false,
+            is_preview: false,
+            is_pinned: false,
+            color_label: None,
             index: 0,
         }];
 
@@ -1171,6 +1629,10 @@ mod tests {
             is_dirty: false,
             is_unread: true,
             is_conflict: false,
+            is_bell: false,
+            is_preview: false,
+            is_pinned: false,
+            color_label: None,
             index: 0,
         }];
 
@@ -1526,6 +1988,15 @@ mod tests {
         assert_eq!(color, Some(DIRTY_INDICATOR_COLOR));
     }
 
+    // Chunk: docs/chunks/terminal_bell_attention - Bell indicator distinctness
+    #[test]
+    fn test_bell_indicator_color_is_distinct() {
+        // The bell badge must not be confused with the dirty or unread dots
+        assert_ne!(BELL_INDICATOR_COLOR, DIRTY_INDICATOR_COLOR);
+        assert_ne!(BELL_INDICATOR_COLOR, UNREAD_INDICATOR_COLOR);
+        assert_ne!(BELL_INDICATOR_COLOR, CONFLICT_INDICATOR_COLOR);
+    }
+
     #[test]
     fn test_conflict_indicator_color_is_distinct() {
         // Conflict indicator should be visually distinct from dirty and unread
@@ -1595,4 +2066,93 @@ mod tests {
         assert!(!tabs[0].is_conflict, "New tab should not be in conflict mode");
         assert!(!tabs[0].is_dirty, "New tab should not be dirty");
     }
+
+    // Chunk: docs/chunks/terminal_tab_labels - Color label dots
+    #[test]
+    fn test_tab_info_includes_color_label() {
+        use std::path::PathBuf;
+        use crate::workspace::{Tab, TabColor, Workspace};
+        use lite_edit_terminal::TerminalBuffer;
+
+        let mut ws = Workspace::new(1, "test".to_string(), PathBuf::from("/test"));
+        let terminal = TerminalBuffer::new(80, 24, 1000);
+        let mut tab = Tab::new_terminal(1, terminal, "server".to_string(), 16.0);
+        tab.color_label = Some(TabColor::Green);
+        ws.add_tab(tab);
+
+        let tabs = tabs_from_workspace(&ws);
+        assert_eq!(tabs[0].label, "server");
+        assert_eq!(tabs[0].color_label, Some(TabColor::Green));
+    }
+
+    #[test]
+    fn test_color_label_colors_are_distinct() {
+        use crate::workspace::TabColor;
+
+        for (i, a) in TabColor::ALL.iter().enumerate() {
+            for b in &TabColor::ALL[i + 1..] {
+                assert_ne!(
+                    color_label_rgba(*a),
+                    color_label_rgba(*b),
+                    "{:?} vs {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    // Chunk: docs/chunks/background_file_io - Load progress on the tab
+    #[test]
+    fn test_loading_tab_label_shows_progress() {
+        use std::path::PathBuf;
+        use crate::workspace::Tab;
+        use lite_edit_buffer::TextBuffer;
+
+        let mut tab = Tab::new_file(
+            1,
+            TextBuffer::new(),
+            "big.log".to_string(),
+            Some(PathBuf::from("/test/big.log")),
+            16.0,
+        );
+        tab.start_loading(200);
+        tab.append_loaded_text("hello\n", 50, 200);
+        assert_eq!(TabInfo::from_tab(&tab, 0, true).label, "big.log 25%");
+
+        tab.loading = None;
+        assert_eq!(TabInfo::from_tab(&tab, 0, true).label, "big.log");
+    }
+
+    // Chunk: docs/chunks/read_only_preview_tabs - Italic preview tab labels
+    #[test]
+    fn test_preview_tab_label_is_slanted() {
+        use std::path::PathBuf;
+        use crate::workspace::Tab;
+        use lite_edit_buffer::TextBuffer;
+
+        let mut tab = Tab::new_file(
+            1,
+            TextBuffer::new(),
+            "file.rs".to_string(),
+            Some(PathBuf::from("/test/file.rs")),
+            16.0,
+        );
+        tab.preview = true;
+        assert!(TabInfo::from_tab(&tab, 0, true).is_preview);
+
+        let color = TAB_LABEL_COLOR;
+        let mut quad = [
+            GlyphVertex::new(0.0, 0.0, 0.0, 0.0, color),
+            GlyphVertex::new(8.0, 0.0, 1.0, 0.0, color),
+            GlyphVertex::new(8.0, 10.0, 1.0, 1.0, color),
+            GlyphVertex::new(0.0, 10.0, 0.0, 1.0, color),
+        ];
+        slant_quad(&mut quad, 0.2);
+        // The top leans right and the bottom left, about the middle
+        assert_eq!(quad[0].position, [1.0, 0.0]);
+        assert_eq!(quad[1].position, [9.0, 0.0]);
+        assert_eq!(quad[2].position, [7.0, 10.0]);
+        assert_eq!(quad[3].position, [-1.0, 10.0]);
+    }
 }
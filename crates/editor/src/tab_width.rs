@@ -11,11 +11,10 @@
 //! All functions in this module are pure (no side effects) and operate on
 //! character/visual column indices.
 
-use unicode_width::UnicodeWidthChar;
-
 /// The number of columns per tab stop. This is a compile-time constant.
 /// A runtime configuration system does not exist yet.
-pub const TAB_WIDTH: usize = 4;
+// Chunk: docs/chunks/unicode_width_awareness - Shared with the buffer's vertical movement
+pub const TAB_WIDTH: usize = lite_edit_buffer::TAB_WIDTH;
 
 /// Returns the next tab stop column after `visual_col`.
 ///
@@ -58,14 +57,10 @@ pub fn next_tab_stop(visual_col: usize) -> usize {
 /// assert_eq!(char_visual_width('a', 0), 1);
 /// assert_eq!(char_visual_width('a', 5), 1);
 /// ```
+// Chunk: docs/chunks/unicode_width_awareness - Same widths the buffer uses for cursor movement
 #[inline]
 pub fn char_visual_width(c: char, visual_col: usize) -> usize {
-    if c == '\t' {
-        next_tab_stop(visual_col) - visual_col
-    } else {
-        // Use unicode_width for proper wide character handling
-        c.width().unwrap_or(0)
-    }
+    lite_edit_buffer::char_display_width(c, visual_col)
 }
 
 /// Returns the total visual width of a string, accounting for tabs and wide chars.
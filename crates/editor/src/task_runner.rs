@@ -0,0 +1,254 @@
+// Chunk: docs/chunks/task_runner - Workspace task detection
+//!
+//! Workspace tasks.
+//!
+//! "Run task" (Cmd+Option+R) lists the tasks defined in the workspace root
+//! and runs the chosen one in a command output tab:
+//! - Makefile targets, run as `make <target>`
+//! - package.json scripts, run with the package manager whose lockfile is
+//!   present (npm if there is none)
+//! - cargo aliases from `.cargo/config.toml`, run as `cargo <alias>`
+//!
+//! Only the workspace root is searched. A missing or unreadable file
+//! contributes no tasks. The parsers are deliberately shallow: they pick out
+//! task names and leave everything else to the tool that runs them.
+
+use std::path::Path;
+
+/// Where a task is defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskSource {
+    /// A Makefile target
+    Make,
+    /// A package.json script
+    Package,
+    /// A cargo alias
+    Cargo,
+}
+
+/// A task that can be run from the workspace root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Task {
+    /// Where the task is defined.
+    pub source: TaskSource,
+    /// The task's name in its definition.
+    pub name: String,
+    /// The command line that runs the task. This is also how it is listed.
+    pub command: String,
+}
+
+/// Returns the tasks defined in `root`: Makefile targets first, then
+/// package.json scripts, then cargo aliases.
+pub fn detect_tasks(root: &Path) -> Vec<Task> {
+    let mut tasks = Vec::new();
+
+    let makefile = ["GNUmakefile", "makefile", "Makefile"]
+        .iter()
+        .find_map(|name| std::fs::read_to_string(root.join(name)).ok());
+    if let Some(text) = makefile {
+        tasks.extend(makefile_targets(&text).into_iter().map(|name| Task {
+            source: TaskSource::Make,
+            command: format!("make {}", name),
+            name,
+        }));
+    }
+
+    if let Ok(text) = std::fs::read_to_string(root.join("package.json")) {
+        let manager = package_manager(root);
+        tasks.extend(package_scripts(&text).into_iter().map(|name| Task {
+            source: TaskSource::Package,
+            command: format!("{} run {}", manager, name),
+            name,
+        }));
+    }
+
+    let cargo_config = ["config.toml", "config"]
+        .iter()
+        .find_map(|name| std::fs::read_to_string(root.join(".cargo").join(name)).ok());
+    if let Some(text) = cargo_config {
+        tasks.extend(cargo_aliases(&text).into_iter().map(|name| Task {
+            source: TaskSource::Cargo,
+            command: format!("cargo {}", name),
+            name,
+        }));
+    }
+
+    tasks
+}
+
+/// Returns the tasks whose command contains every whitespace-separated term
+/// of `query` (case-insensitive), in their original order.
+pub fn filter_tasks<'a>(tasks: &'a [Task], query: &str) -> Vec<&'a Task> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    tasks
+        .iter()
+        .filter(|task| {
+            let haystack = task.command.to_lowercase();
+            terms.iter().all(|term| haystack.contains(term.as_str()))
+        })
+        .collect()
+}
+
+/// Returns the explicit targets of a Makefile, in order of first appearance.
+///
+/// Recipe lines, comments and variable assignments are skipped, as are
+/// special targets (`.PHONY`), pattern rules (`%.o`) and targets built from
+/// variables (`$(OBJ)`).
+pub fn makefile_targets(text: &str) -> Vec<String> {
+    let mut targets: Vec<String> = Vec::new();
+    for line in text.lines() {
+        if line.starts_with('\t') || line.trim_start().starts_with('#') {
+            continue;
+        }
+        let Some(colon) = line.find(':') else {
+            continue;
+        };
+        let (head, rest) = line.split_at(colon);
+        // `A := b`, `A ::= b` and `A = b:c` are assignments, not rules
+        if head.contains('=') || rest[1..].trim_start_matches(':').starts_with('=') {
+            continue;
+        }
+        for target in head.split_whitespace() {
+            let special = target.starts_with('.') || target.contains(['%', '$']);
+            if !special && !targets.iter().any(|t| t == target) {
+                targets.push(target.to_string());
+            }
+        }
+    }
+    targets
+}
+
+/// Returns the script names of a package.json, or none if it isn't valid JSON.
+pub fn package_scripts(text: &str) -> Vec<String> {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(text) else {
+        return Vec::new();
+    };
+    json.get("scripts")
+        .and_then(|scripts| scripts.as_object())
+        .map(|scripts| scripts.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Returns the aliases in the `[alias]` table of a cargo config file.
+pub fn cargo_aliases(text: &str) -> Vec<String> {
+    let mut aliases = Vec::new();
+    let mut in_alias = false;
+    for line in text.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_alias = line == "[alias]";
+            continue;
+        }
+        if !in_alias || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, _)) = line.split_once('=') {
+            let key = key.trim().trim_matches('"');
+            if !key.is_empty() {
+                aliases.push(key.to_string());
+            }
+        }
+    }
+    aliases
+}
+
+/// Returns the package manager that runs package.json scripts in `root`,
+/// going by the lockfile.
+fn package_manager(root: &Path) -> &'static str {
+    if root.join("pnpm-lock.yaml").exists() {
+        "pnpm"
+    } else if root.join("yarn.lock").exists() {
+        "yarn"
+    } else if root.join("bun.lockb").exists() {
+        "bun"
+    } else {
+        "npm"
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_makefile_targets_skip_recipes_and_assignments() {
+        let text = "\
+CC := clang
+FLAGS = -o out:dir
+.PHONY: all test
+# comment: not a rule
+all: build
+\t$(CC) main.c
+
+build test: deps
+%.o: %.c
+$(OBJ): config.h
+install::
+";
+        assert_eq!(
+            makefile_targets(text),
+            vec!["all", "build", "test", "install"]
+        );
+    }
+
+    #[test]
+    fn test_package_scripts_reads_script_names() {
+        let text = r#"{"name": "app", "scripts": {"test": "jest", "build": "tsc"}}"#;
+        let mut scripts = package_scripts(text);
+        scripts.sort();
+        assert_eq!(scripts, vec!["build", "test"]);
+
+        assert!(package_scripts(r#"{"name": "app"}"#).is_empty());
+        assert!(package_scripts("not json").is_empty());
+    }
+
+    #[test]
+    fn test_cargo_aliases_reads_alias_table() {
+        let text = "\
+[build]
+target-dir = \"out\"
+
+[alias]
+# Run the lints
+lint = \"clippy --workspace\"
+xtask = [\"run\", \"-p\", \"xtask\", \"--\"]
+
+[env]
+RUST_LOG = \"debug\"
+";
+        assert_eq!(cargo_aliases(text), vec!["lint", "xtask"]);
+    }
+
+    #[test]
+    fn test_detect_tasks_uses_lockfile_package_manager() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::write(root.join("Makefile"), "check:\n\ttrue\n").unwrap();
+        std::fs::write(root.join("package.json"), r#"{"scripts": {"dev": "vite"}}"#).unwrap();
+        std::fs::write(root.join("yarn.lock"), "").unwrap();
+
+        let commands: Vec<String> = detect_tasks(root).into_iter().map(|t| t.command).collect();
+        assert_eq!(commands, vec!["make check", "yarn run dev"]);
+    }
+
+    #[test]
+    fn test_filter_tasks_matches_all_terms() {
+        let task = |command: &str| Task {
+            source: TaskSource::Make,
+            name: command.to_string(),
+            command: command.to_string(),
+        };
+        let tasks = vec![task("make test"), task("npm run test"), task("npm run lint")];
+
+        let matched: Vec<&str> = filter_tasks(&tasks, "NPM test")
+            .iter()
+            .map(|t| t.command.as_str())
+            .collect();
+        assert_eq!(matched, vec!["npm run test"]);
+        assert_eq!(filter_tasks(&tasks, "").len(), 3);
+    }
+}
@@ -0,0 +1,317 @@
+// Chunk: docs/chunks/terminal_memory_budget - Per-terminal memory view
+//!
+//! The Terminal Memory tab: where each open terminal's memory goes.
+//!
+//! A [`TerminalMemoryBuffer`] lists every terminal in every workspace with its
+//! `TerminalBuffer::memory_usage()` broken down by structure, against the
+//! ~7 MB per terminal the scrollback design targets, and presents the table
+//! through `BufferView`, so a `TabKind::TerminalMemory` tab renders through
+//! the same pipeline as the Problems tab. The editor refreshes it while
+//! terminals print; the limits it is tuned with are the `terminal` options
+//! of the user config.
+
+use lite_edit_buffer::{BufferView, CursorInfo, DirtyLines, Span, Style, StyledLine};
+use lite_edit_terminal::MemoryUsage;
+
+use crate::config::TerminalConfig;
+use crate::view_colors::{MAUVE, RED, YELLOW};
+
+/// The memory each terminal is designed to stay under, in bytes.
+pub const TERMINAL_MEMORY_TARGET: usize = 7 * 1024 * 1024;
+
+/// Columns the terminal names are padded to.
+const NAME_WIDTH: usize = 28;
+
+/// Rows above the first terminal: title, limits, blank, column headings.
+const HEADER_ROWS: usize = 4;
+
+/// One terminal's row of the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalMemory {
+    /// Workspace and tab, e.g. "lite-edit › server"
+    pub name: String,
+    pub usage: MemoryUsage,
+}
+
+/// Formats a byte count as B, KB or MB.
+pub fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = 1024 * 1024;
+    if bytes < KB {
+        format!("{} B", bytes)
+    } else if bytes < MB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    }
+}
+
+/// The Terminal Memory tab's table.
+pub struct TerminalMemoryBuffer {
+    terminals: Vec<TerminalMemory>,
+    limits: TerminalConfig,
+    dirty: DirtyLines,
+}
+
+impl TerminalMemoryBuffer {
+    /// Creates the table for `terminals`, kept within `limits`.
+    pub fn new(terminals: Vec<TerminalMemory>, limits: TerminalConfig) -> Self {
+        Self {
+            terminals,
+            limits,
+            dirty: DirtyLines::FromLineToEnd(0),
+        }
+    }
+
+    /// Returns the listed terminals.
+    pub fn terminals(&self) -> &[TerminalMemory] {
+        &self.terminals
+    }
+
+    /// Shows fresh figures, redrawing only if something changed.
+    pub fn set_terminals(&mut self, terminals: Vec<TerminalMemory>, limits: TerminalConfig) {
+        if terminals != self.terminals || limits != self.limits {
+            self.terminals = terminals;
+            self.limits = limits;
+            self.dirty.merge(DirtyLines::FromLineToEnd(0));
+        }
+    }
+
+    /// Returns the estimated memory of all listed terminals, in bytes.
+    pub fn total_bytes(&self) -> usize {
+        self.terminals.iter().map(|t| t.usage.total_bytes()).sum()
+    }
+
+    fn render_row(&self, row: usize) -> Option<StyledLine> {
+        let dim = Style {
+            dim: true,
+            ..Style::default()
+        };
+        let line = match row {
+            0 => StyledLine::new(vec![
+                Span::new(
+                    "Terminal Memory",
+                    Style {
+                        fg: MAUVE,
+                        bold: true,
+                        ..Style::default()
+                    },
+                ),
+                Span::new(
+                    format!(
+                        "  target {} per terminal",
+                        format_bytes(TERMINAL_MEMORY_TARGET as u64)
+                    ),
+                    dim,
+                ),
+            ]),
+            1 => StyledLine::new(vec![Span::new(
+                format!(
+                    "Each terminal keeps {} scrollback lines in memory and caches {} \
+                     read back from disk (Settings › Terminal)",
+                    self.limits.hot_scrollback_lines,
+                    format_bytes(self.limits.cold_cache_kb as u64 * 1024),
+                ),
+                dim,
+            )]),
+            2 => StyledLine::empty(),
+            3 => StyledLine::new(vec![Span::new(
+                format!(
+                    "{:<width$}{:>10}{:>10}{:>10}{:>10}{:>10}{:>10}{:>10}{:>10}",
+                    "Terminal",
+                    "Memory",
+                    "Grid",
+                    "Lines",
+                    "Cold",
+                    "Index",
+                    "Cache",
+                    "Images",
+                    "On disk",
+                    width = NAME_WIDTH,
+                ),
+                dim,
+            )]),
+            _ if self.terminals.is_empty() => match row - HEADER_ROWS {
+                0 => StyledLine::new(vec![Span::new("No terminals are open", dim)]),
+                _ => return None,
+            },
+            _ => {
+                let index = row - HEADER_ROWS;
+                if let Some(terminal) = self.terminals.get(index) {
+                    terminal_row(terminal)
+                } else if index == self.terminals.len() {
+                    StyledLine::empty()
+                } else if index == self.terminals.len() + 1 {
+                    self.total_row()
+                } else {
+                    return None;
+                }
+            }
+        };
+        Some(line)
+    }
+
+    fn total_row(&self) -> StyledLine {
+        let count = self.terminals.len();
+        let label = match count {
+            1 => "1 terminal".to_string(),
+            _ => format!("All {} terminals", count),
+        };
+        let disk: u64 = self.terminals.iter().map(|t| t.usage.cold_file_bytes).sum();
+        StyledLine::new(vec![Span::new(
+            format!(
+                "{:<width$}{:>10}{:>70}",
+                label,
+                format_bytes(self.total_bytes() as u64),
+                format_bytes(disk),
+                width = NAME_WIDTH,
+            ),
+            Style {
+                bold: true,
+                ..Style::default()
+            },
+        )])
+    }
+}
+
+/// Formats one terminal's row, with its total colored as it nears the target.
+fn terminal_row(terminal: &TerminalMemory) -> StyledLine {
+    let usage = &terminal.usage;
+    let total = usage.total_bytes();
+    let total_style = if total > TERMINAL_MEMORY_TARGET {
+        Style {
+            fg: RED,
+            ..Style::default()
+        }
+    } else if total > TERMINAL_MEMORY_TARGET / 4 * 3 {
+        Style {
+            fg: YELLOW,
+            ..Style::default()
+        }
+    } else {
+        Style::default()
+    };
+
+    let mut name: String = terminal.name.chars().take(NAME_WIDTH - 2).collect();
+    if name.chars().count() < terminal.name.chars().count() {
+        name.push('…');
+    }
+    StyledLine::new(vec![
+        Span::plain(format!("{:<width$}", name, width = NAME_WIDTH)),
+        Span::new(format!("{:>10}", format_bytes(total as u64)), total_style),
+        Span::plain(format!(
+            "{:>10}{:>10}{:>10}{:>10}{:>10}{:>10}{:>10}",
+            format_bytes(usage.grid_bytes as u64),
+            usage.grid_lines,
+            usage.cold_lines,
+            format_bytes(usage.cold_index_bytes as u64),
+            format_bytes(usage.page_cache_bytes as u64),
+            format_bytes(usage.image_bytes as u64),
+            format_bytes(usage.cold_file_bytes),
+        )),
+    ])
+}
+
+impl BufferView for TerminalMemoryBuffer {
+    fn line_count(&self) -> usize {
+        HEADER_ROWS + self.terminals.len().max(1) + 2 * usize::from(!self.terminals.is_empty())
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        self.render_row(line)
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.render_row(line)
+            .map(|row| row.char_count())
+            .unwrap_or(0)
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        None
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &StyledLine) -> String {
+        line.spans.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    fn terminal(name: &str, grid_bytes: usize) -> TerminalMemory {
+        TerminalMemory {
+            name: name.to_string(),
+            usage: MemoryUsage {
+                grid_lines: 2024,
+                grid_bytes,
+                cold_lines: 50_000,
+                cold_file_bytes: 3 * 1024 * 1024,
+                ..MemoryUsage::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(7 * 1024 * 1024), "7.0 MB");
+    }
+
+    #[test]
+    fn test_rows_and_totals() {
+        let terminals = vec![
+            terminal("app › server", 2 * 1024 * 1024),
+            terminal("app › an extremely long terminal tab name", 8 * 1024 * 1024),
+        ];
+        let buffer = TerminalMemoryBuffer::new(terminals, TerminalConfig::default());
+
+        assert_eq!(buffer.line_count(), HEADER_ROWS + 2 + 2);
+        assert_eq!(buffer.total_bytes(), 10 * 1024 * 1024);
+        let first = buffer.styled_line(HEADER_ROWS).unwrap();
+        assert!(text(&first).starts_with("app › server "));
+        assert!(text(&first).contains("2.0 MB"));
+        assert!(text(&first).contains("50000"));
+
+        // Over the target shows in the warning color; long names are cut
+        let second = buffer.styled_line(HEADER_ROWS + 1).unwrap();
+        assert_eq!(second.spans[1].style.fg, RED);
+        assert_eq!(first.spans[1].style.fg, Style::default().fg);
+        assert!(text(&second).contains('…'));
+
+        let total = text(&buffer.styled_line(HEADER_ROWS + 3).unwrap());
+        assert!(total.starts_with("All 2 terminals"));
+        assert!(total.contains("10.0 MB") && total.ends_with("6.0 MB"));
+        assert!(buffer.styled_line(HEADER_ROWS + 4).is_none());
+    }
+
+    #[test]
+    fn test_no_terminals_and_refresh() {
+        let mut buffer = TerminalMemoryBuffer::new(Vec::new(), TerminalConfig::default());
+        assert_eq!(buffer.line_count(), HEADER_ROWS + 1);
+        let empty = buffer.styled_line(HEADER_ROWS).unwrap();
+        assert_eq!(text(&empty), "No terminals are open");
+        buffer.take_dirty();
+
+        // Unchanged figures don't redraw; new ones do
+        buffer.set_terminals(Vec::new(), TerminalConfig::default());
+        assert_eq!(buffer.take_dirty(), DirtyLines::None);
+        buffer.set_terminals(vec![terminal("app › tests", 1024)], TerminalConfig::default());
+        assert_eq!(buffer.take_dirty(), DirtyLines::FromLineToEnd(0));
+        assert_eq!(buffer.terminals().len(), 1);
+    }
+}
@@ -0,0 +1,24 @@
+// Chunk: docs/chunks/diff_view - Colors shared by the generated tabs
+//!
+//! Colors shared by the tabs the editor generates rather than loads: diffs,
+//! problems, changes, settings, keyboard shortcuts, terminal memory and the
+//! agent tabs.
+//!
+//! They are Catppuccin Mocha accents, matching the editor's palette (see
+//! `color_palette`), so each tab uses the same color for the same role:
+//! headings in mauve, removals and errors in red, additions in green.
+
+use lite_edit_buffer::Color;
+
+/// Catppuccin Mocha "mauve": headings, file names and groups.
+pub const MAUVE: Color = Color::Rgb { r: 0xcb, g: 0xa6, b: 0xf7 };
+/// Catppuccin Mocha "blue": hunk headers, keys and values.
+pub const BLUE: Color = Color::Rgb { r: 0x89, g: 0xb4, b: 0xfa };
+/// Catppuccin Mocha "green": additions and accepted or staged items.
+pub const GREEN: Color = Color::Rgb { r: 0xa6, g: 0xe3, b: 0xa1 };
+/// Catppuccin Mocha "red": removals, errors and conflicts.
+pub const RED: Color = Color::Rgb { r: 0xf3, g: 0x8b, b: 0xa8 };
+/// Catppuccin Mocha "yellow": warnings and items awaiting input.
+pub const YELLOW: Color = Color::Rgb { r: 0xf9, g: 0xe2, b: 0xaf };
+/// Catppuccin Mocha "surface0": the selected row's background.
+pub const SELECTED_BG: Color = Color::Rgb { r: 0x31, g: 0x32, b: 0x44 };
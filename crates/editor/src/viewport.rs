@@ -178,6 +178,14 @@ impl Viewport {
         self.scroller.update_size(window_height, buffer_line_count);
     }
 
+    // Chunk: docs/chunks/font_config_zoom - Line height changes on font zoom
+    /// Changes the line height, keeping the same line at the top of the view.
+    ///
+    /// Call `update_size()` afterwards so `visible_lines` reflects the new height.
+    pub fn set_line_height(&mut self, line_height: f32) {
+        self.scroller.set_row_height(line_height);
+    }
+
     /// Returns the range of buffer lines visible in the viewport
     ///
     /// The range is `[first_visible_line, min(first_visible_line + visible_lines, buffer_line_count))`.
@@ -448,8 +456,50 @@ impl Viewport {
         self.scroller.set_scroll_offset_unclamped(px.clamp(0.0, max_offset_px));
     }
 
-    /// Helper: computes total screen rows for all buffer lines
-    fn compute_total_screen_rows<F>(
+    // Chunk: docs/chunks/smooth_scroll_animation - Smooth scroll animation
+    /// Starts (or retargets) a smooth scroll toward `px`, clamped like
+    /// `set_scroll_offset_px`. See `RowScroller::animate_to_px`.
+    pub fn animate_scroll_offset_px(&mut self, px: f32, buffer_line_count: usize) {
+        self.scroller.animate_to_px(px, buffer_line_count);
+    }
+
+    /// Starts (or retargets) a smooth scroll toward `px`, clamped like
+    /// `set_scroll_offset_px_wrapped`.
+    pub fn animate_scroll_offset_px_wrapped<F>(
+        &mut self,
+        px: f32,
+        line_count: usize,
+        wrap_layout: &crate::wrap_layout::WrapLayout,
+        line_len_fn: F,
+    ) where
+        F: Fn(usize) -> usize,
+    {
+        let total_screen_rows = self.compute_total_screen_rows(line_count, wrap_layout, &line_len_fn);
+        let max_rows = total_screen_rows.saturating_sub(self.visible_lines());
+        let max_offset_px = max_rows as f32 * self.line_height();
+        self.scroller.animate_to_px_unclamped(px.clamp(0.0, max_offset_px));
+    }
+
+    /// Returns the offset a smooth scroll is heading to, or the current
+    /// offset when none is running.
+    pub fn scroll_target_px(&self) -> f32 {
+        self.scroller.scroll_target_px()
+    }
+
+    /// Returns true while a smooth scroll is running.
+    pub fn is_scroll_animating(&self) -> bool {
+        self.scroller.is_animating()
+    }
+
+    /// Advances a running smooth scroll by `dt_secs`. Returns `true` if the
+    /// scroll offset moved.
+    pub fn step_scroll_animation(&mut self, dt_secs: f32) -> bool {
+        self.scroller.step_animation(dt_secs)
+    }
+
+    // Chunk: docs/chunks/pane_scrollbar - Exposed for scrollbar thumb sizing
+    /// Computes total screen rows for all buffer lines
+    pub fn compute_total_screen_rows<F>(
         &self,
         line_count: usize,
         wrap_layout: &crate::wrap_layout::WrapLayout,
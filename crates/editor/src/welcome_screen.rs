@@ -8,6 +8,7 @@
 //!
 //! - A feather ASCII art logo (lite-edit branding)
 //! - The editor name and tagline
+//! - The recently opened workspaces and files, which open when clicked
 //! - A categorized hotkey reference table
 //!
 //! The content is centered both horizontally and vertically within the buffer
@@ -19,7 +20,9 @@
 //!
 //! The welcome screen is purely a function of buffer state - no additional
 //! state machine is needed. Empty buffer + file tab → show welcome.
-//! Non-empty buffer → normal render.
+//! Non-empty buffer → normal render. The recent lists come from the editor's
+//! `Recents`; the layout depends on them, so the renderer and the click
+//! handling both compute it from the same `WelcomeRecents`.
 //!
 //! ## Colors
 //!
@@ -29,6 +32,7 @@
 //! - Key combos: Blue accent
 //! - Descriptions: Dimmed text (Subtext1)
 
+use std::path::{Path, PathBuf};
 use std::ptr::NonNull;
 
 use objc2::rc::Retained;
@@ -37,6 +41,7 @@ use objc2_metal::{MTLBuffer, MTLDevice, MTLResourceOptions};
 
 use crate::glyph_atlas::{GlyphAtlas, GlyphInfo};
 use crate::glyph_buffer::{GlyphLayout, GlyphVertex, QuadRange};
+use crate::recents::Recents;
 use crate::shader::VERTEX_SIZE;
 
 // =============================================================================
@@ -112,6 +117,134 @@ const HOTKEYS: &[(&str, &[(&str, &str)])] = &[
     ]),
 ];
 
+// =============================================================================
+// Recent Workspaces and Files
+// =============================================================================
+
+/// Most workspaces, and most files, listed on the welcome screen.
+const MAX_LISTED_RECENTS: usize = 5;
+
+/// Longest path listed before its start is replaced by an ellipsis.
+const MAX_RECENT_PATH_CHARS: usize = 48;
+
+// Chunk: docs/chunks/welcome_recents - Clickable recent entries
+/// What a recent entry on the welcome screen opens when clicked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WelcomeItem {
+    /// A workspace root directory
+    Workspace(PathBuf),
+    /// A file
+    File(PathBuf),
+}
+
+/// One line of the recents block.
+enum RecentRow {
+    /// A section header
+    Header(&'static str),
+    /// A clickable entry
+    Entry(WelcomeItem),
+    /// A blank line between sections
+    Gap,
+}
+
+// Chunk: docs/chunks/welcome_recents - Recent lists shown on the welcome screen
+/// The recent workspaces and files listed on the welcome screen, most
+/// recent first.
+///
+/// An empty list gets no section. With both empty, the welcome screen is
+/// laid out as if there were no recents block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WelcomeRecents {
+    workspaces: Vec<PathBuf>,
+    files: Vec<PathBuf>,
+}
+
+impl WelcomeRecents {
+    /// Takes the first entries of the editor's recent lists.
+    pub fn from_recents(recents: &Recents) -> Self {
+        let first = |list: &[PathBuf]| list.iter().take(MAX_LISTED_RECENTS).cloned().collect();
+        Self {
+            workspaces: first(&recents.workspaces),
+            files: first(&recents.files),
+        }
+    }
+
+    /// Returns true if there is nothing to list.
+    pub fn is_empty(&self) -> bool {
+        self.workspaces.is_empty() && self.files.is_empty()
+    }
+
+    /// Returns the lines of the recents block.
+    fn rows(&self) -> Vec<RecentRow> {
+        use WelcomeItem::{File, Workspace};
+        let mut rows = Vec::new();
+        type Section<'a> = (&'static str, &'a [PathBuf], fn(PathBuf) -> WelcomeItem);
+        let sections: [Section; 2] = [
+            ("Recent workspaces", &self.workspaces, Workspace),
+            ("Recent files", &self.files, File),
+        ];
+        for (header, paths, item) in sections {
+            if paths.is_empty() {
+                continue;
+            }
+            if !rows.is_empty() {
+                rows.push(RecentRow::Gap);
+            }
+            rows.push(RecentRow::Header(header));
+            for path in paths {
+                rows.push(RecentRow::Entry(item(path.clone())));
+            }
+        }
+        rows
+    }
+
+    /// Returns the width of the recents block in characters.
+    fn width(&self) -> usize {
+        self.rows()
+            .iter()
+            .map(|row| match row {
+                RecentRow::Header(header) => header.len(),
+                RecentRow::Entry(item) => HOTKEY_PADDING + recent_label(item).chars().count(),
+                RecentRow::Gap => 0,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the height of the recents block in lines, including the gap
+    /// before the hotkey table.
+    fn height(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.rows().len() + RECENTS_HOTKEYS_GAP
+        }
+    }
+}
+
+/// Returns the text listed for a recent entry.
+fn recent_label(item: &WelcomeItem) -> String {
+    match item {
+        WelcomeItem::Workspace(path) | WelcomeItem::File(path) => display_path(path),
+    }
+}
+
+/// Returns `path` with the home directory shown as `~`, cut from the front
+/// to `MAX_RECENT_PATH_CHARS`.
+fn display_path(path: &Path) -> String {
+    let home = dirs::home_dir().unwrap_or_default();
+    let text = match path.strip_prefix(&home).ok().filter(|_| home.has_root()) {
+        Some(rest) => Path::new("~").join(rest).display().to_string(),
+        None => path.display().to_string(),
+    };
+    let len = text.chars().count();
+    if len <= MAX_RECENT_PATH_CHARS {
+        return text;
+    }
+    let skip = len - (MAX_RECENT_PATH_CHARS - 1);
+    format!("…{}", text.chars().skip(skip).collect::<String>())
+}
+
 // =============================================================================
 // Colors (Catppuccin Mocha palette)
 // =============================================================================
@@ -154,6 +287,9 @@ const NAME_TAGLINE_GAP: usize = 1;
 /// Vertical spacing between tagline and hotkey table (in lines)
 const TAGLINE_HOTKEYS_GAP: usize = 3;
 
+/// Vertical spacing between the recents block and hotkey table (in lines)
+const RECENTS_HOTKEYS_GAP: usize = 2;
+
 /// Vertical spacing between hotkey categories (in lines)
 const CATEGORY_GAP: usize = 1;
 
@@ -199,20 +335,23 @@ pub struct WelcomeScreenGeometry {
 /// * `line_height` - Height of a line in pixels
 /// * `scroll_offset_px` - Vertical scroll offset in pixels (0 = top). Clamped to
 ///   `[0, max(0, content_height_px - viewport_height_px)]` internally.
+/// * `recents` - The recent workspaces and files listed
 ///
 /// When `viewport_height >= content_height_px`, `max_scroll = 0` and any scroll
 /// offset is clamped to 0, preserving the centered layout exactly as before.
 // Chunk: docs/chunks/welcome_screen - Calculates centered positioning for welcome screen content
 // Chunk: docs/chunks/welcome_scroll - Welcome screen vertical scrolling
+// Chunk: docs/chunks/welcome_recents - Layout depends on the recent lists
 pub fn calculate_welcome_geometry(
     viewport_width: f32,
     viewport_height: f32,
     glyph_width: f32,
     line_height: f32,
     scroll_offset_px: f32,
+    recents: &WelcomeRecents,
 ) -> WelcomeScreenGeometry {
     // Calculate content dimensions
-    let (content_width_chars, content_height_lines) = calculate_content_dimensions();
+    let (content_width_chars, content_height_lines) = calculate_content_dimensions(recents);
 
     // Calculate pixel dimensions
     let content_width_px = content_width_chars as f32 * glyph_width;
@@ -238,7 +377,7 @@ pub fn calculate_welcome_geometry(
 
 /// Calculates the total content dimensions (width in chars, height in lines).
 // Chunk: docs/chunks/welcome_scroll - Made pub(crate) to expose deterministic content dimensions for scroll clamping
-pub(crate) fn calculate_content_dimensions() -> (usize, usize) {
+pub(crate) fn calculate_content_dimensions(recents: &WelcomeRecents) -> (usize, usize) {
     // Logo width and height
     let logo_width = FEATHER_LOGO.iter().map(|(s, _)| s.len()).max().unwrap_or(0);
     let logo_height = FEATHER_LOGO.len();
@@ -255,6 +394,7 @@ pub(crate) fn calculate_content_dimensions() -> (usize, usize) {
     let total_width = logo_width
         .max(name_width)
         .max(tagline_width)
+        .max(recents.width())
         .max(hotkey_width);
 
     // Total height includes all sections and gaps
@@ -264,11 +404,41 @@ pub(crate) fn calculate_content_dimensions() -> (usize, usize) {
         + NAME_TAGLINE_GAP
         + 1 // tagline
         + TAGLINE_HOTKEYS_GAP
+        + recents.height()
         + hotkey_height;
 
     (total_width, total_height)
 }
 
+// Chunk: docs/chunks/welcome_recents - Click hit-testing for recent entries
+/// Returns the recent entry drawn at `(x, y)`, in the same coordinate space
+/// as `geometry`.
+pub fn recent_item_at(
+    geometry: &WelcomeScreenGeometry,
+    recents: &WelcomeRecents,
+    x: f32,
+    y: f32,
+) -> Option<WelcomeItem> {
+    if x < geometry.content_x || y < geometry.content_y {
+        return None;
+    }
+    let col = ((x - geometry.content_x) / geometry.glyph_width) as usize;
+    if col >= geometry.content_width_chars {
+        return None;
+    }
+    let line = ((y - geometry.content_y) / geometry.line_height) as usize;
+    let row = line.checked_sub(recents_first_line())?;
+    match recents.rows().into_iter().nth(row)? {
+        RecentRow::Entry(item) => Some(item),
+        RecentRow::Header(_) | RecentRow::Gap => None,
+    }
+}
+
+/// Returns the line the recents block starts on.
+fn recents_first_line() -> usize {
+    FEATHER_LOGO.len() + LOGO_NAME_GAP + 1 + NAME_TAGLINE_GAP + 1 + TAGLINE_HOTKEYS_GAP
+}
+
 /// Calculates the width of the hotkey table in characters.
 fn calculate_hotkey_table_width() -> usize {
     let mut max_width = 0;
@@ -331,6 +501,9 @@ pub struct WelcomeScreenGlyphBuffer {
     logo_range: QuadRange,
     /// Editor name and tagline glyphs
     title_range: QuadRange,
+    // Chunk: docs/chunks/welcome_recents - Recents glyph range
+    /// Recent workspaces and files glyphs
+    recents_range: QuadRange,
     /// Hotkey table glyphs (keys and descriptions)
     hotkey_range: QuadRange,
 }
@@ -348,6 +521,7 @@ impl WelcomeScreenGlyphBuffer {
             persistent_indices: Vec::new(),
             logo_range: QuadRange::default(),
             title_range: QuadRange::default(),
+            recents_range: QuadRange::default(),
             hotkey_range: QuadRange::default(),
         }
     }
@@ -377,6 +551,11 @@ impl WelcomeScreenGlyphBuffer {
         self.title_range
     }
 
+    /// Returns the index range for recent workspace and file glyphs.
+    pub fn recents_range(&self) -> QuadRange {
+        self.recents_range
+    }
+
     /// Returns the index range for hotkey glyphs.
     pub fn hotkey_range(&self) -> QuadRange {
         self.hotkey_range
@@ -390,11 +569,14 @@ impl WelcomeScreenGlyphBuffer {
     /// * `device` - The Metal device for buffer creation
     /// * `atlas` - The glyph atlas for text rendering
     /// * `geometry` - The computed welcome screen geometry
+    /// * `recents` - The recent workspaces and files `geometry` was computed for
+    // Chunk: docs/chunks/welcome_recents - Recents block between tagline and hotkeys
     pub fn update(
         &mut self,
         device: &ProtocolObject<dyn MTLDevice>,
         atlas: &GlyphAtlas,
         geometry: &WelcomeScreenGeometry,
+        recents: &WelcomeRecents,
     ) {
         // Estimate capacity: logo + name + tagline + all hotkeys
         let logo_chars: usize = FEATHER_LOGO.iter().map(|(s, _)| s.len()).sum();
@@ -405,7 +587,16 @@ impl WelcomeScreenGlyphBuffer {
                 std::iter::once(cat.len()).chain(keys.iter().map(|(k, d)| k.len() + d.len()))
             })
             .sum();
-        let estimated_quads = logo_chars + name_chars + hotkey_chars;
+        let recent_rows = recents.rows();
+        let recents_chars: usize = recent_rows
+            .iter()
+            .map(|row| match row {
+                RecentRow::Header(header) => header.len(),
+                RecentRow::Entry(item) => recent_label(item).chars().count(),
+                RecentRow::Gap => 0,
+            })
+            .sum();
+        let estimated_quads = logo_chars + name_chars + recents_chars + hotkey_chars;
 
         // Chunk: docs/chunks/quad_buffer_prealloc - Clear and reserve persistent buffers instead of allocating new ones
         self.persistent_vertices.clear();
@@ -421,6 +612,7 @@ impl WelcomeScreenGlyphBuffer {
         // Reset ranges
         self.logo_range = QuadRange::default();
         self.title_range = QuadRange::default();
+        self.recents_range = QuadRange::default();
         self.hotkey_range = QuadRange::default();
 
         let mut current_line: usize = 0;
@@ -496,12 +688,48 @@ impl WelcomeScreenGlyphBuffer {
 
         self.title_range = QuadRange::new(title_start, self.persistent_indices.len() - title_start);
 
-        // ==================== Phase 3: Hotkey Table ====================
-        let hotkey_start = self.persistent_indices.len();
-
         // Gap after tagline
         current_line += TAGLINE_HOTKEYS_GAP;
 
+        // ==================== Phase 3: Recent Workspaces and Files ====================
+        let recents_start = self.persistent_indices.len();
+
+        // Centered block; entries are indented under their section header
+        let recents_x_offset = (geometry.content_width_chars.saturating_sub(recents.width())) / 2;
+        for row in &recent_rows {
+            let (text, x_offset, color) = match row {
+                RecentRow::Header(header) => (header.to_string(), recents_x_offset, COLOR_OVERLAY),
+                RecentRow::Entry(item) => (
+                    recent_label(item),
+                    recents_x_offset + HOTKEY_PADDING,
+                    COLOR_BLUE,
+                ),
+                RecentRow::Gap => (String::new(), 0, COLOR_OVERLAY),
+            };
+            Self::emit_line_static(
+                &mut self.persistent_vertices,
+                &mut self.persistent_indices,
+                &mut vertex_offset,
+                &self.layout,
+                atlas,
+                geometry,
+                &text,
+                current_line,
+                x_offset,
+                color,
+            );
+            current_line += 1;
+        }
+        if !recent_rows.is_empty() {
+            current_line += RECENTS_HOTKEYS_GAP;
+        }
+
+        self.recents_range =
+            QuadRange::new(recents_start, self.persistent_indices.len() - recents_start);
+
+        // ==================== Phase 4: Hotkey Table ====================
+        let hotkey_start = self.persistent_indices.len();
+
         // Calculate hotkey table centering
         let table_width = calculate_hotkey_table_width();
         let table_x_offset = (geometry.content_width_chars.saturating_sub(table_width)) / 2;
@@ -690,7 +918,7 @@ mod tests {
 
     #[test]
     fn test_content_dimensions_are_reasonable() {
-        let (width, height) = calculate_content_dimensions();
+        let (width, height) = calculate_content_dimensions(&WelcomeRecents::default());
         // Content should be at least logo-sized
         assert!(width >= 10);
         assert!(height >= 10);
@@ -702,7 +930,7 @@ mod tests {
     #[test]
     fn test_geometry_calculation() {
         // Use a large viewport that can fit all content
-        let geometry = calculate_welcome_geometry(1200.0, 1000.0, 8.0, 16.0, 0.0);
+        let geometry = calculate_welcome_geometry(1200.0, 1000.0, 8.0, 16.0, 0.0, &WelcomeRecents::default());
 
         // Content should be centered (positive x offset)
         assert!(geometry.content_x > 0.0, "content_x should be > 0 for large viewport");
@@ -721,7 +949,7 @@ mod tests {
     #[test]
     fn test_geometry_small_viewport() {
         // Very small viewport should clamp content_x and content_y to 0
-        let geometry = calculate_welcome_geometry(50.0, 50.0, 8.0, 16.0, 0.0);
+        let geometry = calculate_welcome_geometry(50.0, 50.0, 8.0, 16.0, 0.0, &WelcomeRecents::default());
 
         // Should not be negative
         assert!(geometry.content_x >= 0.0);
@@ -731,12 +959,12 @@ mod tests {
     #[test]
     fn test_geometry_scroll_zero_unchanged() {
         // scroll_offset_px = 0.0 must produce the same result as the old behavior
-        let (_, content_height_lines) = calculate_content_dimensions();
+        let (_, content_height_lines) = calculate_content_dimensions(&WelcomeRecents::default());
         let line_height = 16.0_f32;
         let content_height_px = content_height_lines as f32 * line_height;
         // Use a large viewport so content is centered
         let viewport_height = content_height_px + 200.0;
-        let g = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, 0.0);
+        let g = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, 0.0, &WelcomeRecents::default());
         let expected_y = (viewport_height - content_height_px) / 2.0;
         assert!((g.content_y - expected_y).abs() < 0.001);
     }
@@ -744,13 +972,13 @@ mod tests {
     #[test]
     fn test_geometry_scroll_offsets_content_y() {
         // With a small viewport (content overflows), scroll should shift content up
-        let (_, content_height_lines) = calculate_content_dimensions();
+        let (_, content_height_lines) = calculate_content_dimensions(&WelcomeRecents::default());
         let line_height = 16.0_f32;
         let content_height_px = content_height_lines as f32 * line_height;
         // Viewport is shorter than content by 100px
         let viewport_height = content_height_px - 100.0;
         let scroll = 40.0_f32;
-        let g = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, scroll);
+        let g = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, scroll, &WelcomeRecents::default());
         // When content overflows, content_y without scroll = 0.0, then subtract scroll
         let expected_y = 0.0 - scroll;
         assert!((g.content_y - expected_y).abs() < 0.001);
@@ -759,12 +987,12 @@ mod tests {
     #[test]
     fn test_geometry_scroll_clamps_at_top() {
         // Negative scroll_offset_px should clamp to 0 (content_y unchanged)
-        let (_, content_height_lines) = calculate_content_dimensions();
+        let (_, content_height_lines) = calculate_content_dimensions(&WelcomeRecents::default());
         let line_height = 16.0_f32;
         let content_height_px = content_height_lines as f32 * line_height;
         let viewport_height = content_height_px - 100.0;
-        let g_no_scroll = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, 0.0);
-        let g_neg_scroll = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, -50.0);
+        let g_no_scroll = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, 0.0, &WelcomeRecents::default());
+        let g_neg_scroll = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, -50.0, &WelcomeRecents::default());
         // Negative scroll clamps to 0, content_y should be the same as no scroll
         assert!((g_neg_scroll.content_y - g_no_scroll.content_y).abs() < 0.001);
     }
@@ -772,13 +1000,13 @@ mod tests {
     #[test]
     fn test_geometry_scroll_clamps_at_bottom() {
         // scroll_offset_px > max_scroll should clamp to max_scroll
-        let (_, content_height_lines) = calculate_content_dimensions();
+        let (_, content_height_lines) = calculate_content_dimensions(&WelcomeRecents::default());
         let line_height = 16.0_f32;
         let content_height_px = content_height_lines as f32 * line_height;
         let viewport_height = content_height_px - 100.0;
         let max_scroll = content_height_px - viewport_height;
         // Scroll way past the bottom
-        let g = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, max_scroll + 9999.0);
+        let g = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, max_scroll + 9999.0, &WelcomeRecents::default());
         let expected_y = 0.0 - max_scroll;
         assert!((g.content_y - expected_y).abs() < 0.001);
     }
@@ -786,12 +1014,12 @@ mod tests {
     #[test]
     fn test_geometry_large_viewport_ignores_scroll() {
         // When viewport > content, max_scroll = 0 and any scroll has no effect
-        let (_, content_height_lines) = calculate_content_dimensions();
+        let (_, content_height_lines) = calculate_content_dimensions(&WelcomeRecents::default());
         let line_height = 16.0_f32;
         let content_height_px = content_height_lines as f32 * line_height;
         let viewport_height = content_height_px + 200.0;
-        let g_no_scroll = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, 0.0);
-        let g_with_scroll = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, 500.0);
+        let g_no_scroll = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, 0.0, &WelcomeRecents::default());
+        let g_with_scroll = calculate_welcome_geometry(800.0, viewport_height, 8.0, line_height, 500.0, &WelcomeRecents::default());
         // Scroll is clamped to 0 when viewport > content; centering preserved
         assert!((g_no_scroll.content_y - g_with_scroll.content_y).abs() < 0.001);
         assert!(g_no_scroll.content_y > 0.0, "content should be centered (y > 0)");
@@ -811,4 +1039,54 @@ mod tests {
         let min_height: usize = HOTKEYS.iter().map(|(_, ks)| 1 + ks.len()).sum();
         assert!(height >= min_height);
     }
+
+    fn sample_recents() -> WelcomeRecents {
+        let mut recents = Recents::new();
+        recents.note_workspace(Path::new("/projects/alpha"));
+        recents.note_workspace(Path::new("/projects/beta"));
+        recents.note_file(Path::new("/projects/beta/main.rs"));
+        WelcomeRecents::from_recents(&recents)
+    }
+
+    #[test]
+    fn test_recents_extend_content_height() {
+        let (_, plain_height) = calculate_content_dimensions(&WelcomeRecents::default());
+        let (_, height) = calculate_content_dimensions(&sample_recents());
+        // Two headers, three entries, the gap between sections and the gap
+        // before the hotkey table
+        assert_eq!(height, plain_height + 2 + 3 + 1 + RECENTS_HOTKEYS_GAP);
+    }
+
+    #[test]
+    fn test_recent_item_at_finds_clicked_entry() {
+        let recents = sample_recents();
+        let g = calculate_welcome_geometry(1200.0, 2000.0, 8.0, 16.0, 0.0, &recents);
+        let x = g.content_x + g.content_width_chars as f32 * g.glyph_width / 2.0;
+        let line_y = |line: usize| g.content_y + (line as f32 + 0.5) * g.line_height;
+        let first = recents_first_line();
+
+        // Header lines and gaps are not clickable
+        assert_eq!(recent_item_at(&g, &recents, x, line_y(first)), None);
+        assert_eq!(recent_item_at(&g, &recents, x, line_y(first + 3)), None);
+        assert_eq!(
+            recent_item_at(&g, &recents, x, line_y(first + 1)),
+            Some(WelcomeItem::Workspace(PathBuf::from("/projects/beta")))
+        );
+        assert_eq!(
+            recent_item_at(&g, &recents, x, line_y(first + 5)),
+            Some(WelcomeItem::File(PathBuf::from("/projects/beta/main.rs")))
+        );
+        // Outside the content area
+        assert_eq!(recent_item_at(&g, &recents, 0.0, line_y(first + 1)), None);
+    }
+
+    #[test]
+    fn test_display_path_truncates_from_the_front() {
+        let long = format!("/{}/file.rs", "d".repeat(80));
+        let label = display_path(Path::new(&long));
+        assert_eq!(label.chars().count(), MAX_RECENT_PATH_CHARS);
+        assert!(label.starts_with('…'));
+        assert!(label.ends_with("/file.rs"));
+        assert_eq!(display_path(Path::new("/tmp/a.rs")), "/tmp/a.rs");
+    }
 }
@@ -0,0 +1,242 @@
+// Chunk: docs/chunks/prose_word_count - Incremental word count for prose buffers
+//!
+//! Live word count and reading-time statistics for prose files.
+//!
+//! `WordCount` caches per-line statistics and running totals. After an edit,
+//! only the lines named by the edit's `DirtyLines` are recounted, so typing in
+//! a long Markdown document costs one line of work rather than a full rescan.
+
+use std::path::Path;
+
+use lite_edit_buffer::{DirtyLines, TextBuffer};
+
+/// Average silent reading speed used for the reading-time estimate.
+pub const WORDS_PER_MINUTE: usize = 200;
+
+/// File extensions treated as prose.
+const PROSE_EXTENSIONS: &[&str] = &["md", "markdown", "mdx", "txt", "text", "rst", "adoc", "org"];
+
+/// Returns true if the file at `path` should show prose statistics.
+pub fn is_prose_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| PROSE_EXTENSIONS.iter().any(|p| p.eq_ignore_ascii_case(ext)))
+        .unwrap_or(false)
+}
+
+/// Word and character counts for a single line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct LineStats {
+    words: usize,
+    chars: usize,
+}
+
+impl LineStats {
+    fn of(line: &str) -> Self {
+        Self {
+            words: line.split_whitespace().count(),
+            chars: line.chars().count(),
+        }
+    }
+}
+
+/// Incrementally maintained statistics for a text buffer.
+#[derive(Debug, Default)]
+pub struct WordCount {
+    lines: Vec<LineStats>,
+    words: usize,
+    chars: usize,
+}
+
+impl WordCount {
+    /// Creates statistics by scanning the whole buffer.
+    pub fn new(buffer: &TextBuffer) -> Self {
+        let mut count = Self::default();
+        count.rescan_from(buffer, 0);
+        count
+    }
+
+    /// Updates the statistics after an edit described by `dirty`.
+    ///
+    /// Single lines and ranges are recounted in place when the buffer's line
+    /// count is unchanged. If lines were inserted or removed, everything from
+    /// the first dirty line to the end is recounted. A line count change with
+    /// no dirty lines (an edit that bypassed dirty tracking) triggers a full
+    /// rescan.
+    pub fn update(&mut self, buffer: &TextBuffer, dirty: &DirtyLines) {
+        let line_count = buffer.line_count();
+        let same_shape = line_count == self.lines.len();
+
+        match *dirty {
+            DirtyLines::None if same_shape => {}
+            DirtyLines::None => self.rescan_from(buffer, 0),
+            DirtyLines::Single(line) if same_shape => self.recount_range(buffer, line, line + 1),
+            DirtyLines::Range { from, to } if same_shape => self.recount_range(buffer, from, to),
+            DirtyLines::Single(from) | DirtyLines::Range { from, .. } | DirtyLines::FromLineToEnd(from) => {
+                self.rescan_from(buffer, from)
+            }
+        }
+    }
+
+    /// Total number of whitespace-separated words.
+    pub fn words(&self) -> usize {
+        self.words
+    }
+
+    /// Total number of characters, excluding line breaks.
+    pub fn chars(&self) -> usize {
+        self.chars
+    }
+
+    /// Number of lines.
+    pub fn lines(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Estimated reading time in whole minutes, rounded up.
+    ///
+    /// Returns 0 only for an empty document.
+    pub fn reading_minutes(&self) -> usize {
+        self.words.div_ceil(WORDS_PER_MINUTE)
+    }
+
+    /// One-line summary for the status bar, e.g. `"1,204 words"`.
+    pub fn summary(&self) -> String {
+        let noun = if self.words == 1 { "word" } else { "words" };
+        format!("{} {}", format_count(self.words), noun)
+    }
+
+    /// Detailed statistics, e.g. `"1,204 words · 6,810 chars · 98 lines · ~7 min read"`.
+    pub fn details(&self) -> String {
+        format!(
+            "{} · {} chars · {} lines · ~{} min read",
+            self.summary(),
+            format_count(self.chars),
+            format_count(self.lines()),
+            self.reading_minutes().max(1)
+        )
+    }
+
+    /// Recounts lines `[from, to)` in place, adjusting the totals.
+    fn recount_range(&mut self, buffer: &TextBuffer, from: usize, to: usize) {
+        let to = to.min(self.lines.len());
+        for line in from..to {
+            let new = LineStats::of(&buffer.line_content(line));
+            let old = std::mem::replace(&mut self.lines[line], new);
+            self.words = self.words - old.words + new.words;
+            self.chars = self.chars - old.chars + new.chars;
+        }
+    }
+
+    /// Drops cached stats from `from` onward and recounts to the end of the buffer.
+    fn rescan_from(&mut self, buffer: &TextBuffer, from: usize) {
+        let from = from.min(self.lines.len());
+        for old in self.lines.drain(from..) {
+            self.words -= old.words;
+            self.chars -= old.chars;
+        }
+        for line in from..buffer.line_count() {
+            let stats = LineStats::of(&buffer.line_content(line));
+            self.words += stats.words;
+            self.chars += stats.chars;
+            self.lines.push(stats);
+        }
+    }
+}
+
+/// Formats a count with thousands separators (`12345` → `"12,345"`).
+fn format_count(n: usize) -> String {
+    let digits = n.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lite_edit_buffer::Position;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_is_prose_path() {
+        assert!(is_prose_path(&PathBuf::from("README.md")));
+        assert!(is_prose_path(&PathBuf::from("notes/todo.TXT")));
+        assert!(!is_prose_path(&PathBuf::from("main.rs")));
+        assert!(!is_prose_path(&PathBuf::from("Makefile")));
+    }
+
+    #[test]
+    fn test_initial_count() {
+        let buffer = TextBuffer::from_str("hello world\n\nthe  quick fox");
+        let count = WordCount::new(&buffer);
+        assert_eq!(count.words(), 5);
+        assert_eq!(count.chars(), 11 + 14);
+        assert_eq!(count.lines(), 3);
+    }
+
+    #[test]
+    fn test_incremental_single_line_edit() {
+        let mut buffer = TextBuffer::from_str("one two\nthree");
+        let mut count = WordCount::new(&buffer);
+
+        buffer.set_cursor(Position::new(0, 7));
+        let dirty = buffer.insert_str(" four");
+        count.update(&buffer, &dirty);
+
+        assert_eq!(count.words(), 4);
+        assert_eq!(count.words(), WordCount::new(&buffer).words());
+    }
+
+    #[test]
+    fn test_incremental_line_split_and_join() {
+        let mut buffer = TextBuffer::from_str("alpha beta\ngamma");
+        let mut count = WordCount::new(&buffer);
+
+        buffer.set_cursor(Position::new(0, 5));
+        let dirty = buffer.insert_newline();
+        count.update(&buffer, &dirty);
+        assert_eq!(count.lines(), 3);
+        assert_eq!(count.words(), 3);
+
+        let dirty = buffer.delete_backward();
+        count.update(&buffer, &dirty);
+        assert_eq!(count.lines(), 2);
+        assert_eq!(count.words(), 3);
+        assert_eq!(count.chars(), WordCount::new(&buffer).chars());
+    }
+
+    #[test]
+    fn test_untracked_line_count_change_rescans() {
+        let buffer = TextBuffer::from_str("a b c");
+        let mut count = WordCount::new(&buffer);
+
+        let replaced = TextBuffer::from_str("x\ny");
+        count.update(&replaced, &DirtyLines::None);
+        assert_eq!(count.words(), 2);
+        assert_eq!(count.lines(), 2);
+    }
+
+    #[test]
+    fn test_reading_time_and_details() {
+        let text = "word ".repeat(401);
+        let buffer = TextBuffer::from_str(&text);
+        let count = WordCount::new(&buffer);
+        assert_eq!(count.reading_minutes(), 3);
+        assert_eq!(count.summary(), "401 words");
+        assert!(count.details().ends_with("~3 min read"));
+    }
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(0), "0");
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1000), "1,000");
+        assert_eq!(format_count(1234567), "1,234,567");
+    }
+}
@@ -11,19 +11,58 @@
 //! The workspace model enables Composer-like multi-agent workflows where each
 //! workspace represents an independent working context.
 
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
+use crate::command_runner::CommandRun;
+use crate::content_index::ContentIndex;
+use crate::diff_view::DiffBuffer;
 use crate::event_channel::EventSender;
 use crate::file_index::FileIndex;
+// Chunk: docs/chunks/background_file_io - Load progress of a tab
+use crate::file_io::{LoadProgress, PendingSave};
+// Chunk: docs/chunks/file_view_mode - Memory-mapped views of giant files
+use crate::file_view::FileView;
 use crate::pane_layout::{gen_pane_id, Pane, PaneId, PaneLayoutNode};
+use crate::problem_matcher::{Problem, ProblemsBuffer, PROBLEMS_SOURCE};
+// Chunk: docs/chunks/git_commit_flow - Changes tab buffer
+use crate::git_changes::ChangesBuffer;
+use crate::git_status::RepoStatus;
+// Chunk: docs/chunks/settings_tab - Settings tab buffer
+use crate::config::EditorConfig;
+// Chunk: docs/chunks/terminal_memory_budget - Scrollback limits
+use crate::config::TerminalConfig;
+use crate::settings::SettingsBuffer;
+// Chunk: docs/chunks/keybinding_conflicts - Keyboard Shortcuts tab buffer
+use crate::keymap::KeyOverride;
+use crate::shortcuts::ShortcutsBuffer;
+// Chunk: docs/chunks/terminal_memory_budget - Terminal Memory tab buffer
+use crate::terminal_memory::{TerminalMemory, TerminalMemoryBuffer};
+// Chunk: docs/chunks/agent_resource_usage - Agents dashboard buffer
+use crate::agent_dashboard::{command_name, state_label, AgentDashboardBuffer, AgentSummary};
+// Chunk: docs/chunks/agent_events - Agent event timeline buffer
+use crate::agent_timeline::AgentTimelineBuffer;
+// Chunk: docs/chunks/agent_edit_review - Review of agent-proposed edits
+use crate::edit_review::{EditReviewBuffer, ProposedEdit};
+// Chunk: docs/chunks/welcome_recents - Recently opened workspaces and files
+use crate::recents::Recents;
+// Chunk: docs/chunks/scratch_buffers - Scratch buffer state
+use crate::scratch::Scratch;
 use crate::viewport::Viewport;
-use lite_edit_buffer::{BufferView, DirtyLines, StyledLine, TextBuffer};
+use crate::word_count::WordCount;
+use lite_edit_buffer::{
+    Annotation, AnnotationSource, Annotations, BufferView, DirtyLines, Position, StyledLine,
+    TextBuffer,
+};
 // Chunk: docs/chunks/treesitter_symbol_index - Symbol index for cross-file go-to-definition
 use lite_edit_syntax::{LanguageRegistry, SymbolIndex, SyntaxHighlighter, SyntaxTheme};
 // Chunk: docs/chunks/terminal_flood_starvation - PollResult for byte-budgeted polling
-use lite_edit_terminal::{AgentConfig, AgentHandle, AgentState, PollResult, TerminalBuffer};
+use lite_edit_terminal::{
+    AgentConfig, AgentEvent, AgentHandle, AgentState, CommandMark, CopyModeTarget, PollResult,
+    TerminalBuffer, TerminalReplay,
+};
 
 // =============================================================================
 // ID Types
@@ -35,6 +74,10 @@ pub type WorkspaceId = u64;
 /// Unique identifier for a tab within a workspace.
 pub type TabId = u64;
 
+// Chunk: docs/chunks/terminal_command_status - Command summaries after their output
+/// The annotation source terminal command summaries are drawn under.
+const COMMANDS_SOURCE: AnnotationSource = "commands";
+
 // =============================================================================
 // WorkspaceStatus
 // =============================================================================
@@ -59,6 +102,26 @@ pub enum WorkspaceStatus {
     Errored,
 }
 
+impl WorkspaceStatus {
+    /// Returns the notification text for a transition from `previous` to this
+    /// status, if the new status means the agent is waiting on the user.
+    ///
+    /// Only entering NeedsInput, Completed or Errored notifies; Stale follows
+    /// NeedsInput, which has already notified.
+    // Chunk: docs/chunks/agent_notifications - Transitions that warrant a notification
+    pub fn attention_message(self, previous: WorkspaceStatus) -> Option<&'static str> {
+        if self == previous {
+            return None;
+        }
+        match self {
+            WorkspaceStatus::NeedsInput => Some("Agent is waiting for input"),
+            WorkspaceStatus::Completed => Some("Agent finished"),
+            WorkspaceStatus::Errored => Some("Agent exited with an error"),
+            _ => None,
+        }
+    }
+}
+
 // =============================================================================
 // TabKind
 // =============================================================================
@@ -75,6 +138,85 @@ pub enum TabKind {
     AgentOutput,
     /// A diff view
     Diff,
+    // Chunk: docs/chunks/shell_command_runner - Read-only command output tab
+    /// Output of a one-off shell command
+    CommandOutput,
+    // Chunk: docs/chunks/problem_matcher - Problems list tab
+    /// Compiler problems, grouped by file
+    Problems,
+    // Chunk: docs/chunks/git_commit_flow - Changes tab
+    /// Changed files to stage and commit
+    Changes,
+    // Chunk: docs/chunks/settings_tab - Settings tab
+    /// The user config's options, editable in place
+    Settings,
+    // Chunk: docs/chunks/keybinding_conflicts - Keyboard Shortcuts tab
+    /// Every bound chord, with conflicts and rebinding
+    Shortcuts,
+    // Chunk: docs/chunks/terminal_memory_budget - Terminal Memory tab
+    /// Each terminal's memory, by structure
+    TerminalMemory,
+    // Chunk: docs/chunks/terminal_replay - Session replay tab
+    /// A terminal session's recording, scrubbed through time
+    Replay,
+    // Chunk: docs/chunks/agent_resource_usage - Agents dashboard
+    /// Every workspace's agent with its CPU and memory use
+    Agents,
+    // Chunk: docs/chunks/agent_events - Agent event timeline
+    /// The events a workspace's agent reported
+    AgentTimeline,
+    // Chunk: docs/chunks/agent_edit_review - Review of agent-proposed edits
+    /// Edits an agent proposed, accepted or rejected hunk by hunk
+    Review,
+}
+
+// =============================================================================
+// TabColor
+// =============================================================================
+
+// Chunk: docs/chunks/terminal_tab_labels - Color labels for terminal tabs
+/// A color label the user gave a terminal tab, drawn as a dot in the tab
+/// and on its workspace's left rail tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabColor {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+}
+
+impl TabColor {
+    /// Every color, in the order they're offered.
+    pub const ALL: [TabColor; 6] = [
+        TabColor::Red,
+        TabColor::Orange,
+        TabColor::Yellow,
+        TabColor::Green,
+        TabColor::Blue,
+        TabColor::Purple,
+    ];
+
+    /// The color's name, as shown in the rename prompt and saved in the
+    /// session.
+    pub fn name(self) -> &'static str {
+        match self {
+            TabColor::Red => "Red",
+            TabColor::Orange => "Orange",
+            TabColor::Yellow => "Yellow",
+            TabColor::Green => "Green",
+            TabColor::Blue => "Blue",
+            TabColor::Purple => "Purple",
+        }
+    }
+
+    /// Returns the color called `name`, ignoring case.
+    pub fn from_name(name: &str) -> Option<TabColor> {
+        Self::ALL
+            .into_iter()
+            .find(|color| color.name().eq_ignore_ascii_case(name))
+    }
 }
 
 // =============================================================================
@@ -168,6 +310,39 @@ pub enum TabBuffer {
     /// Chrome's "Aw, Snap!" error page for terminals.
     // Chunk: docs/chunks/terminal_spawn_reliability - Error state for failed terminal spawns
     Error(ErrorBuffer),
+    /// A read-only diff view.
+    // Chunk: docs/chunks/diff_view - Diff tab buffer
+    Diff(DiffBuffer),
+    /// A read-only problems list.
+    // Chunk: docs/chunks/problem_matcher - Problems tab buffer
+    Problems(ProblemsBuffer),
+    /// The changed files of the workspace's repository.
+    // Chunk: docs/chunks/git_commit_flow - Changes tab buffer
+    Changes(ChangesBuffer),
+    /// The user config's options.
+    // Chunk: docs/chunks/settings_tab - Settings tab buffer
+    Settings(SettingsBuffer),
+    /// The keymap with the user's overrides.
+    // Chunk: docs/chunks/keybinding_conflicts - Keyboard Shortcuts tab buffer
+    Shortcuts(ShortcutsBuffer),
+    /// Each terminal's memory.
+    // Chunk: docs/chunks/terminal_memory_budget - Terminal Memory tab buffer
+    TerminalMemory(TerminalMemoryBuffer),
+    /// A terminal session replayed from its recording.
+    // Chunk: docs/chunks/terminal_replay - Session replay tab buffer
+    Replay(TerminalReplay),
+    /// Every workspace's agent.
+    // Chunk: docs/chunks/agent_resource_usage - Agents dashboard buffer
+    Agents(AgentDashboardBuffer),
+    /// The events an agent reported.
+    // Chunk: docs/chunks/agent_events - Agent event timeline buffer
+    AgentTimeline(AgentTimelineBuffer),
+    /// Edits an agent proposed.
+    // Chunk: docs/chunks/agent_edit_review - Review buffer
+    Review(EditReviewBuffer),
+    /// A memory-mapped, read-only view of a file too large to edit.
+    // Chunk: docs/chunks/file_view_mode - File view buffer
+    FileView(FileView),
 }
 
 impl std::fmt::Debug for TabBuffer {
@@ -177,6 +352,23 @@ impl std::fmt::Debug for TabBuffer {
             TabBuffer::Terminal(_) => f.debug_tuple("Terminal").field(&"<TerminalBuffer>").finish(),
             TabBuffer::AgentTerminal => write!(f, "AgentTerminal"),
             TabBuffer::Error(buf) => f.debug_tuple("Error").field(&buf.message).finish(),
+            TabBuffer::Diff(buf) => f.debug_tuple("Diff").field(&buf.files().len()).finish(),
+            TabBuffer::Problems(buf) => f.debug_tuple("Problems").field(&buf.problems().len()).finish(),
+            TabBuffer::Changes(buf) => f.debug_tuple("Changes").field(&buf.files().len()).finish(),
+            TabBuffer::Settings(buf) => f.debug_tuple("Settings").field(&buf.query()).finish(),
+            TabBuffer::Shortcuts(buf) => f.debug_tuple("Shortcuts").field(&buf.query()).finish(),
+            TabBuffer::TerminalMemory(buf) => f
+                .debug_tuple("TerminalMemory")
+                .field(&buf.terminals().len())
+                .finish(),
+            TabBuffer::Replay(buf) => f.debug_tuple("Replay").field(&buf.position()).finish(),
+            TabBuffer::Agents(buf) => f.debug_tuple("Agents").field(&buf.agents().len()).finish(),
+            TabBuffer::AgentTimeline(buf) => f
+                .debug_tuple("AgentTimeline")
+                .field(&buf.entries().len())
+                .finish(),
+            TabBuffer::Review(buf) => f.debug_tuple("Review").field(&buf.paths().count()).finish(),
+            TabBuffer::FileView(buf) => f.debug_tuple("FileView").field(&buf.path()).finish(),
         }
     }
 }
@@ -196,6 +388,17 @@ impl TabBuffer {
                 panic!("AgentTerminal is a placeholder - use Workspace::agent_terminal()")
             }
             TabBuffer::Error(buf) => buf,
+            TabBuffer::Diff(buf) => buf,
+            TabBuffer::Problems(buf) => buf,
+            TabBuffer::Changes(buf) => buf,
+            TabBuffer::Settings(buf) => buf,
+            TabBuffer::Shortcuts(buf) => buf,
+            TabBuffer::TerminalMemory(buf) => buf,
+            TabBuffer::Replay(buf) => buf,
+            TabBuffer::Agents(buf) => buf,
+            TabBuffer::AgentTimeline(buf) => buf,
+            TabBuffer::Review(buf) => buf,
+            TabBuffer::FileView(buf) => buf,
         }
     }
 
@@ -213,6 +416,17 @@ impl TabBuffer {
                 panic!("AgentTerminal is a placeholder - use Workspace::agent_terminal_mut()")
             }
             TabBuffer::Error(buf) => buf,
+            TabBuffer::Diff(buf) => buf,
+            TabBuffer::Problems(buf) => buf,
+            TabBuffer::Changes(buf) => buf,
+            TabBuffer::Settings(buf) => buf,
+            TabBuffer::Shortcuts(buf) => buf,
+            TabBuffer::TerminalMemory(buf) => buf,
+            TabBuffer::Replay(buf) => buf,
+            TabBuffer::Agents(buf) => buf,
+            TabBuffer::AgentTimeline(buf) => buf,
+            TabBuffer::Review(buf) => buf,
+            TabBuffer::FileView(buf) => buf,
         }
     }
 
@@ -222,7 +436,20 @@ impl TabBuffer {
     pub fn as_text_buffer(&self) -> Option<&TextBuffer> {
         match self {
             TabBuffer::File(buf) => Some(buf),
-            TabBuffer::Terminal(_) | TabBuffer::AgentTerminal | TabBuffer::Error(_) => None,
+            TabBuffer::Terminal(_)
+            | TabBuffer::AgentTerminal
+            | TabBuffer::Error(_)
+            | TabBuffer::Diff(_)
+            | TabBuffer::Problems(_)
+            | TabBuffer::Changes(_)
+            | TabBuffer::Settings(_)
+            | TabBuffer::Shortcuts(_)
+            | TabBuffer::TerminalMemory(_)
+            | TabBuffer::Replay(_)
+            | TabBuffer::Agents(_)
+            | TabBuffer::AgentTimeline(_)
+            | TabBuffer::Review(_)
+            | TabBuffer::FileView(_) => None,
         }
     }
 
@@ -232,7 +459,20 @@ impl TabBuffer {
     pub fn as_text_buffer_mut(&mut self) -> Option<&mut TextBuffer> {
         match self {
             TabBuffer::File(buf) => Some(buf),
-            TabBuffer::Terminal(_) | TabBuffer::AgentTerminal | TabBuffer::Error(_) => None,
+            TabBuffer::Terminal(_)
+            | TabBuffer::AgentTerminal
+            | TabBuffer::Error(_)
+            | TabBuffer::Diff(_)
+            | TabBuffer::Problems(_)
+            | TabBuffer::Changes(_)
+            | TabBuffer::Settings(_)
+            | TabBuffer::Shortcuts(_)
+            | TabBuffer::TerminalMemory(_)
+            | TabBuffer::Replay(_)
+            | TabBuffer::Agents(_)
+            | TabBuffer::AgentTimeline(_)
+            | TabBuffer::Review(_)
+            | TabBuffer::FileView(_) => None,
         }
     }
 
@@ -243,7 +483,20 @@ impl TabBuffer {
     pub fn as_terminal_buffer(&self) -> Option<&TerminalBuffer> {
         match self {
             TabBuffer::Terminal(buf) => Some(buf),
-            TabBuffer::File(_) | TabBuffer::AgentTerminal | TabBuffer::Error(_) => None,
+            TabBuffer::File(_)
+            | TabBuffer::AgentTerminal
+            | TabBuffer::Error(_)
+            | TabBuffer::Diff(_)
+            | TabBuffer::Problems(_)
+            | TabBuffer::Changes(_)
+            | TabBuffer::Settings(_)
+            | TabBuffer::Shortcuts(_)
+            | TabBuffer::TerminalMemory(_)
+            | TabBuffer::Replay(_)
+            | TabBuffer::Agents(_)
+            | TabBuffer::AgentTimeline(_)
+            | TabBuffer::Review(_)
+            | TabBuffer::FileView(_) => None,
         }
     }
 
@@ -254,7 +507,20 @@ impl TabBuffer {
     pub fn as_terminal_buffer_mut(&mut self) -> Option<&mut TerminalBuffer> {
         match self {
             TabBuffer::Terminal(buf) => Some(buf),
-            TabBuffer::File(_) | TabBuffer::AgentTerminal | TabBuffer::Error(_) => None,
+            TabBuffer::File(_)
+            | TabBuffer::AgentTerminal
+            | TabBuffer::Error(_)
+            | TabBuffer::Diff(_)
+            | TabBuffer::Problems(_)
+            | TabBuffer::Changes(_)
+            | TabBuffer::Settings(_)
+            | TabBuffer::Shortcuts(_)
+            | TabBuffer::TerminalMemory(_)
+            | TabBuffer::Replay(_)
+            | TabBuffer::Agents(_)
+            | TabBuffer::AgentTimeline(_)
+            | TabBuffer::Review(_)
+            | TabBuffer::FileView(_) => None,
         }
     }
 
@@ -268,6 +534,127 @@ impl TabBuffer {
     pub fn is_error(&self) -> bool {
         matches!(self, TabBuffer::Error(_))
     }
+
+    /// Attempts to get a reference to the underlying `DiffBuffer`.
+    ///
+    /// Returns `Some` for diff tabs, `None` for other tab types.
+    // Chunk: docs/chunks/diff_view - Diff buffer access
+    pub fn as_diff_buffer(&self) -> Option<&DiffBuffer> {
+        match self {
+            TabBuffer::Diff(buf) => Some(buf),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get a reference to the underlying `ProblemsBuffer`.
+    ///
+    /// Returns `Some` for problems tabs, `None` for other tab types.
+    // Chunk: docs/chunks/problem_matcher - Problems buffer access
+    pub fn as_problems_buffer(&self) -> Option<&ProblemsBuffer> {
+        match self {
+            TabBuffer::Problems(buf) => Some(buf),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get a reference to the underlying `ChangesBuffer`.
+    ///
+    /// Returns `Some` for Changes tabs, `None` for other tab types.
+    // Chunk: docs/chunks/git_commit_flow - Changes buffer access
+    pub fn as_changes_buffer(&self) -> Option<&ChangesBuffer> {
+        match self {
+            TabBuffer::Changes(buf) => Some(buf),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get a reference to the underlying `SettingsBuffer`.
+    ///
+    /// Returns `Some` for Settings tabs, `None` for other tab types.
+    // Chunk: docs/chunks/settings_tab - Settings buffer access
+    pub fn as_settings_buffer(&self) -> Option<&SettingsBuffer> {
+        match self {
+            TabBuffer::Settings(buf) => Some(buf),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get a reference to the underlying `ShortcutsBuffer`.
+    ///
+    /// Returns `Some` for Keyboard Shortcuts tabs, `None` for other tab types.
+    // Chunk: docs/chunks/keybinding_conflicts - Shortcuts buffer access
+    pub fn as_shortcuts_buffer(&self) -> Option<&ShortcutsBuffer> {
+        match self {
+            TabBuffer::Shortcuts(buf) => Some(buf),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get a reference to the underlying `TerminalMemoryBuffer`.
+    ///
+    /// Returns `Some` for Terminal Memory tabs, `None` for other tab types.
+    // Chunk: docs/chunks/terminal_memory_budget - Terminal Memory buffer access
+    pub fn as_terminal_memory_buffer(&self) -> Option<&TerminalMemoryBuffer> {
+        match self {
+            TabBuffer::TerminalMemory(buf) => Some(buf),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get a reference to the underlying `TerminalReplay`.
+    ///
+    /// Returns `Some` for replay tabs, `None` for other tab types.
+    // Chunk: docs/chunks/terminal_replay - Replay access
+    pub fn as_replay(&self) -> Option<&TerminalReplay> {
+        match self {
+            TabBuffer::Replay(buf) => Some(buf),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get a reference to the underlying `AgentDashboardBuffer`.
+    ///
+    /// Returns `Some` for Agents tabs, `None` for other tab types.
+    // Chunk: docs/chunks/agent_resource_usage - Agents dashboard access
+    pub fn as_agent_dashboard(&self) -> Option<&AgentDashboardBuffer> {
+        match self {
+            TabBuffer::Agents(buf) => Some(buf),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get a reference to the underlying `AgentTimelineBuffer`.
+    ///
+    /// Returns `Some` for Timeline tabs, `None` for other tab types.
+    // Chunk: docs/chunks/agent_events - Agent event timeline access
+    pub fn as_agent_timeline(&self) -> Option<&AgentTimelineBuffer> {
+        match self {
+            TabBuffer::AgentTimeline(buf) => Some(buf),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get a reference to the underlying `EditReviewBuffer`.
+    ///
+    /// Returns `Some` for Review tabs, `None` for other tab types.
+    // Chunk: docs/chunks/agent_edit_review - Review access
+    pub fn as_edit_review(&self) -> Option<&EditReviewBuffer> {
+        match self {
+            TabBuffer::Review(buf) => Some(buf),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get a reference to the underlying `FileView`.
+    ///
+    /// Returns `Some` for file tabs showing a view, `None` for other tab types.
+    // Chunk: docs/chunks/file_view_mode - File view access
+    pub fn as_file_view(&self) -> Option<&FileView> {
+        match self {
+            TabBuffer::FileView(buf) => Some(buf),
+            _ => None,
+        }
+    }
 }
 
 // =============================================================================
@@ -334,6 +721,52 @@ pub struct Tab {
     /// net to detect external modifications when the file watcher misses events
     /// (e.g., on pane focus change or workspace switch).
     pub last_known_mtime: Option<SystemTime>,
+    // Chunk: docs/chunks/cursor_position_history - Back/forward navigation history
+    /// History of significant cursor movements, navigated with the mouse
+    /// back/forward buttons or trackpad swipes.
+    pub cursor_history: CursorHistory,
+    // Chunk: docs/chunks/prose_word_count - Incremental prose statistics
+    /// Live word count, maintained only for prose files (Markdown, text).
+    word_count: Option<WordCount>,
+    // Chunk: docs/chunks/terminal_copy_mode - Per-tab copy mode
+    /// Copy mode state while the terminal tab is in copy mode.
+    pub copy_mode: Option<CopyModeTarget>,
+    // Chunk: docs/chunks/shell_command_runner - The command behind an output tab
+    /// The command shown in a command output tab.
+    pub command_run: Option<CommandRun>,
+    // Chunk: docs/chunks/read_only_preview_tabs - Read-only and preview tab modes
+    /// Whether edits to the buffer are refused (generated files, files
+    /// outside the workspace, or toggled by the user).
+    pub read_only: bool,
+    /// Whether this is the pane's preview tab, which the next file opened
+    /// with a single click replaces. Editing, saving or double-clicking the
+    /// tab keeps it.
+    pub preview: bool,
+    // Chunk: docs/chunks/pinned_tabs_overflow - Pinned tabs
+    /// Whether the tab is pinned: kept left-most in its pane, drawn compact
+    /// and not closed by Cmd+W.
+    pub pinned: bool,
+    // Chunk: docs/chunks/scratch_buffers - Scratch buffer tabs
+    /// Set for a scratch buffer, which has no file, is never dirty and is
+    /// kept in the session instead.
+    pub scratch: Option<Scratch>,
+    // Chunk: docs/chunks/terminal_tab_labels - Color label for terminal tabs
+    /// The color label the user gave this terminal tab, if any.
+    pub color_label: Option<TabColor>,
+    // Chunk: docs/chunks/terminal_session_restore - Terminal restore settings
+    /// A command this terminal tab runs when the session brings it back,
+    /// e.g. `npm run dev`.
+    pub startup_command: Option<String>,
+    /// The directory a terminal restored from the session starts its shell
+    /// in. Taken when the shell is spawned.
+    pub restored_cwd: Option<PathBuf>,
+    // Chunk: docs/chunks/background_file_io - Tabs loading in the background
+    /// How far the background read of this tab's file has got, while its
+    /// file is still loading.
+    pub loading: Option<LoadProgress>,
+    /// The content being written by background saves of this tab. The tab
+    /// stays dirty until the last of them has finished.
+    pub saving: Option<PendingSave>,
 }
 
 impl Tab {
@@ -353,6 +786,19 @@ impl Tab {
             base_content: None,
             conflict_mode: false,
             last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
         }
     }
 
@@ -361,6 +807,18 @@ impl Tab {
         Self::new_file(id, TextBuffer::new(), "Untitled".to_string(), None, line_height)
     }
 
+    // Chunk: docs/chunks/scratch_buffers - Scratch buffer constructor
+    /// Creates a scratch buffer tab holding `content`.
+    ///
+    /// Call `setup_highlighting` afterwards to highlight the scratch's
+    /// language.
+    pub fn new_scratch(id: TabId, content: &str, scratch: Scratch, line_height: f32) -> Self {
+        let label = scratch.label();
+        let mut tab = Self::new_file(id, TextBuffer::from_str(content), label, None, line_height);
+        tab.scratch = Some(scratch);
+        tab
+    }
+
     /// Creates a new agent terminal tab.
     ///
     /// This is a placeholder tab - the actual terminal buffer lives in `Workspace.agent`.
@@ -380,6 +838,19 @@ impl Tab {
             base_content: None,
             conflict_mode: false,
             last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
         }
     }
 
@@ -399,6 +870,19 @@ impl Tab {
             base_content: None,
             conflict_mode: false,
             last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
         }
     }
 
@@ -422,9 +906,388 @@ impl Tab {
             base_content: None,
             conflict_mode: false,
             last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
+        }
+    }
+
+    // Chunk: docs/chunks/diff_view - Diff tab constructor
+    /// Creates a new read-only diff tab.
+    pub fn new_diff(id: TabId, diff: DiffBuffer, label: String, line_height: f32) -> Self {
+        Self {
+            id,
+            label,
+            buffer: TabBuffer::Diff(diff),
+            viewport: Viewport::new(line_height),
+            kind: TabKind::Diff,
+            dirty: false,
+            unread: false,
+            associated_file: None,
+            highlighter: None,
+            welcome_scroll_offset_px: 0.0,
+            base_content: None,
+            conflict_mode: false,
+            last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
+        }
+    }
+
+    // Chunk: docs/chunks/problem_matcher - Problems tab constructor
+    /// Creates a new read-only problems tab.
+    pub fn new_problems(id: TabId, problems: ProblemsBuffer, line_height: f32) -> Self {
+        Self {
+            id,
+            label: "Problems".to_string(),
+            buffer: TabBuffer::Problems(problems),
+            viewport: Viewport::new(line_height),
+            kind: TabKind::Problems,
+            dirty: false,
+            unread: false,
+            associated_file: None,
+            highlighter: None,
+            welcome_scroll_offset_px: 0.0,
+            base_content: None,
+            conflict_mode: false,
+            last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
+        }
+    }
+
+    // Chunk: docs/chunks/git_commit_flow - Changes tab constructor
+    /// Creates a new Changes tab.
+    pub fn new_changes(id: TabId, changes: ChangesBuffer, line_height: f32) -> Self {
+        Self {
+            id,
+            label: "Changes".to_string(),
+            buffer: TabBuffer::Changes(changes),
+            viewport: Viewport::new(line_height),
+            kind: TabKind::Changes,
+            dirty: false,
+            unread: false,
+            associated_file: None,
+            highlighter: None,
+            welcome_scroll_offset_px: 0.0,
+            base_content: None,
+            conflict_mode: false,
+            last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
+        }
+    }
+
+    // Chunk: docs/chunks/settings_tab - Settings tab constructor
+    /// Creates a new Settings tab.
+    pub fn new_settings(id: TabId, settings: SettingsBuffer, line_height: f32) -> Self {
+        Self {
+            id,
+            label: "Settings".to_string(),
+            buffer: TabBuffer::Settings(settings),
+            viewport: Viewport::new(line_height),
+            kind: TabKind::Settings,
+            dirty: false,
+            unread: false,
+            associated_file: None,
+            highlighter: None,
+            welcome_scroll_offset_px: 0.0,
+            base_content: None,
+            conflict_mode: false,
+            last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
+        }
+    }
+
+    // Chunk: docs/chunks/keybinding_conflicts - Keyboard Shortcuts tab constructor
+    /// Creates a new Keyboard Shortcuts tab.
+    pub fn new_shortcuts(id: TabId, shortcuts: ShortcutsBuffer, line_height: f32) -> Self {
+        Self {
+            id,
+            label: "Keyboard Shortcuts".to_string(),
+            buffer: TabBuffer::Shortcuts(shortcuts),
+            viewport: Viewport::new(line_height),
+            kind: TabKind::Shortcuts,
+            dirty: false,
+            unread: false,
+            associated_file: None,
+            highlighter: None,
+            welcome_scroll_offset_px: 0.0,
+            base_content: None,
+            conflict_mode: false,
+            last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
+        }
+    }
+
+    // Chunk: docs/chunks/terminal_memory_budget - Terminal Memory tab constructor
+    /// Creates a new read-only Terminal Memory tab.
+    pub fn new_terminal_memory(id: TabId, memory: TerminalMemoryBuffer, line_height: f32) -> Self {
+        Self {
+            id,
+            label: "Terminal Memory".to_string(),
+            buffer: TabBuffer::TerminalMemory(memory),
+            viewport: Viewport::new(line_height),
+            kind: TabKind::TerminalMemory,
+            dirty: false,
+            unread: false,
+            associated_file: None,
+            highlighter: None,
+            welcome_scroll_offset_px: 0.0,
+            base_content: None,
+            conflict_mode: false,
+            last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
+        }
+    }
+
+    // Chunk: docs/chunks/agent_resource_usage - Agents tab constructor
+    /// Creates a new read-only Agents tab.
+    pub fn new_agent_dashboard(
+        id: TabId,
+        dashboard: AgentDashboardBuffer,
+        line_height: f32,
+    ) -> Self {
+        Self {
+            id,
+            label: "Agents".to_string(),
+            buffer: TabBuffer::Agents(dashboard),
+            viewport: Viewport::new(line_height),
+            kind: TabKind::Agents,
+            dirty: false,
+            unread: false,
+            associated_file: None,
+            highlighter: None,
+            welcome_scroll_offset_px: 0.0,
+            base_content: None,
+            conflict_mode: false,
+            last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
         }
     }
 
+    // Chunk: docs/chunks/agent_events - Timeline tab constructor
+    /// Creates a new read-only Timeline tab for a workspace's agent.
+    pub fn new_agent_timeline(id: TabId, timeline: AgentTimelineBuffer, line_height: f32) -> Self {
+        Self {
+            id,
+            label: "Timeline".to_string(),
+            buffer: TabBuffer::AgentTimeline(timeline),
+            viewport: Viewport::new(line_height),
+            kind: TabKind::AgentTimeline,
+            dirty: false,
+            unread: false,
+            associated_file: None,
+            highlighter: None,
+            welcome_scroll_offset_px: 0.0,
+            base_content: None,
+            conflict_mode: false,
+            last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
+        }
+    }
+
+    // Chunk: docs/chunks/agent_edit_review - Review tab constructor
+    /// Creates a new Review tab for edits an agent proposed.
+    pub fn new_edit_review(id: TabId, review: EditReviewBuffer, line_height: f32) -> Self {
+        Self {
+            id,
+            label: "Review".to_string(),
+            buffer: TabBuffer::Review(review),
+            viewport: Viewport::new(line_height),
+            kind: TabKind::Review,
+            dirty: false,
+            unread: false,
+            associated_file: None,
+            highlighter: None,
+            welcome_scroll_offset_px: 0.0,
+            base_content: None,
+            conflict_mode: false,
+            last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
+        }
+    }
+
+    // Chunk: docs/chunks/file_view_mode - File view tab constructor
+    /// Creates a file tab showing `view`, read-only, labelled after the
+    /// view's file.
+    pub fn new_file_view(id: TabId, view: FileView, line_height: f32) -> Self {
+        let path = view.path().to_path_buf();
+        let label = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Untitled".to_string());
+        let mut tab = Self::new_file(id, TextBuffer::new(), label, Some(path), line_height);
+        tab.buffer = TabBuffer::FileView(view);
+        tab.read_only = true;
+        tab
+    }
+
+    // Chunk: docs/chunks/terminal_replay - Session replay tab constructor
+    /// Creates a new tab replaying a terminal session, labelled after the
+    /// terminal's tab.
+    pub fn new_replay(id: TabId, replay: TerminalReplay, label: &str, line_height: f32) -> Self {
+        Self {
+            id,
+            label: format!("Replay: {}", label),
+            buffer: TabBuffer::Replay(replay),
+            viewport: Viewport::new(line_height),
+            kind: TabKind::Replay,
+            dirty: false,
+            unread: false,
+            associated_file: None,
+            highlighter: None,
+            welcome_scroll_offset_px: 0.0,
+            base_content: None,
+            conflict_mode: false,
+            last_known_mtime: None,
+            cursor_history: CursorHistory::default(),
+            word_count: None,
+            copy_mode: None,
+            command_run: None,
+            read_only: false,
+            preview: false,
+            pinned: false,
+            scratch: None,
+            color_label: None,
+            startup_command: None,
+            restored_cwd: None,
+            loading: None,
+            saving: None,
+        }
+    }
+
+    // Chunk: docs/chunks/shell_command_runner - Command output tab constructor
+    /// Creates a new read-only tab showing the output of `run`.
+    ///
+    /// `terminal` must already be running the command.
+    pub fn new_command_output(
+        id: TabId,
+        terminal: TerminalBuffer,
+        run: CommandRun,
+        line_height: f32,
+    ) -> Self {
+        let mut tab = Self::new_terminal(id, terminal, run.tab_label(), line_height);
+        tab.kind = TabKind::CommandOutput;
+        tab.command_run = Some(run);
+        tab
+    }
+
     /// Returns true if this is an agent terminal tab.
     pub fn is_agent_tab(&self) -> bool {
         self.buffer.is_agent_terminal()
@@ -467,6 +1330,198 @@ impl Tab {
         self.buffer.as_terminal_buffer_mut()
     }
 
+    // Chunk: docs/chunks/diff_view - Diff buffer access
+    /// Returns a reference to the underlying `DiffBuffer` if this is a diff tab.
+    pub fn as_diff_buffer(&self) -> Option<&DiffBuffer> {
+        self.buffer.as_diff_buffer()
+    }
+
+    /// Returns mutable references to both the diff buffer and viewport.
+    ///
+    /// Returns `None` if this is not a diff tab.
+    pub fn diff_and_viewport_mut(&mut self) -> Option<(&mut DiffBuffer, &mut Viewport)> {
+        match &mut self.buffer {
+            TabBuffer::Diff(diff) => Some((diff, &mut self.viewport)),
+            _ => None,
+        }
+    }
+
+    // Chunk: docs/chunks/problem_matcher - Problems buffer access
+    /// Returns a reference to the underlying `ProblemsBuffer` if this is a problems tab.
+    pub fn as_problems_buffer(&self) -> Option<&ProblemsBuffer> {
+        self.buffer.as_problems_buffer()
+    }
+
+    /// Returns mutable references to both the problems buffer and viewport.
+    ///
+    /// Returns `None` if this is not a problems tab.
+    pub fn problems_and_viewport_mut(&mut self) -> Option<(&mut ProblemsBuffer, &mut Viewport)> {
+        match &mut self.buffer {
+            TabBuffer::Problems(problems) => Some((problems, &mut self.viewport)),
+            _ => None,
+        }
+    }
+
+    // Chunk: docs/chunks/git_commit_flow - Changes buffer access
+    /// Returns a reference to the underlying `ChangesBuffer` if this is a Changes tab.
+    pub fn as_changes_buffer(&self) -> Option<&ChangesBuffer> {
+        self.buffer.as_changes_buffer()
+    }
+
+    /// Returns mutable references to both the changes buffer and viewport.
+    ///
+    /// Returns `None` if this is not a Changes tab.
+    pub fn changes_and_viewport_mut(&mut self) -> Option<(&mut ChangesBuffer, &mut Viewport)> {
+        match &mut self.buffer {
+            TabBuffer::Changes(changes) => Some((changes, &mut self.viewport)),
+            _ => None,
+        }
+    }
+
+    // Chunk: docs/chunks/settings_tab - Settings buffer access
+    /// Returns a reference to the underlying `SettingsBuffer` if this is a Settings tab.
+    pub fn as_settings_buffer(&self) -> Option<&SettingsBuffer> {
+        self.buffer.as_settings_buffer()
+    }
+
+    /// Returns mutable references to both the settings buffer and viewport.
+    ///
+    /// Returns `None` if this is not a Settings tab.
+    pub fn settings_and_viewport_mut(&mut self) -> Option<(&mut SettingsBuffer, &mut Viewport)> {
+        match &mut self.buffer {
+            TabBuffer::Settings(settings) => Some((settings, &mut self.viewport)),
+            _ => None,
+        }
+    }
+
+    // Chunk: docs/chunks/keybinding_conflicts - Shortcuts buffer access
+    /// Returns a reference to the underlying `ShortcutsBuffer` if this is a
+    /// Keyboard Shortcuts tab.
+    pub fn as_shortcuts_buffer(&self) -> Option<&ShortcutsBuffer> {
+        self.buffer.as_shortcuts_buffer()
+    }
+
+    /// Returns mutable references to both the shortcuts buffer and viewport.
+    ///
+    /// Returns `None` if this is not a Keyboard Shortcuts tab.
+    pub fn shortcuts_and_viewport_mut(&mut self) -> Option<(&mut ShortcutsBuffer, &mut Viewport)> {
+        match &mut self.buffer {
+            TabBuffer::Shortcuts(shortcuts) => Some((shortcuts, &mut self.viewport)),
+            _ => None,
+        }
+    }
+
+    // Chunk: docs/chunks/terminal_memory_budget - Terminal Memory buffer access
+    /// Returns a reference to the underlying `TerminalMemoryBuffer` if this is
+    /// a Terminal Memory tab.
+    pub fn as_terminal_memory_buffer(&self) -> Option<&TerminalMemoryBuffer> {
+        self.buffer.as_terminal_memory_buffer()
+    }
+
+    /// Returns mutable references to both the terminal memory buffer and
+    /// viewport.
+    ///
+    /// Returns `None` if this is not a Terminal Memory tab.
+    pub fn terminal_memory_and_viewport_mut(
+        &mut self,
+    ) -> Option<(&mut TerminalMemoryBuffer, &mut Viewport)> {
+        match &mut self.buffer {
+            TabBuffer::TerminalMemory(memory) => Some((memory, &mut self.viewport)),
+            _ => None,
+        }
+    }
+
+    // Chunk: docs/chunks/terminal_replay - Replay access
+    /// Returns a reference to the underlying `TerminalReplay` if this is a
+    /// replay tab.
+    pub fn as_replay(&self) -> Option<&TerminalReplay> {
+        self.buffer.as_replay()
+    }
+
+    /// Returns mutable references to both the replay and viewport.
+    ///
+    /// Returns `None` if this is not a replay tab.
+    pub fn replay_and_viewport_mut(&mut self) -> Option<(&mut TerminalReplay, &mut Viewport)> {
+        match &mut self.buffer {
+            TabBuffer::Replay(replay) => Some((replay, &mut self.viewport)),
+            _ => None,
+        }
+    }
+
+    // Chunk: docs/chunks/agent_resource_usage - Agents dashboard access
+    /// Returns a reference to the underlying `AgentDashboardBuffer` if this
+    /// is an Agents tab.
+    pub fn as_agent_dashboard(&self) -> Option<&AgentDashboardBuffer> {
+        self.buffer.as_agent_dashboard()
+    }
+
+    /// Returns mutable references to both the agents table and viewport.
+    ///
+    /// Returns `None` if this is not an Agents tab.
+    pub fn agent_dashboard_and_viewport_mut(
+        &mut self,
+    ) -> Option<(&mut AgentDashboardBuffer, &mut Viewport)> {
+        match &mut self.buffer {
+            TabBuffer::Agents(dashboard) => Some((dashboard, &mut self.viewport)),
+            _ => None,
+        }
+    }
+
+    // Chunk: docs/chunks/agent_events - Agent event timeline access
+    /// Returns a reference to the underlying `AgentTimelineBuffer` if this
+    /// is a Timeline tab.
+    pub fn as_agent_timeline(&self) -> Option<&AgentTimelineBuffer> {
+        self.buffer.as_agent_timeline()
+    }
+
+    /// Returns mutable references to both the timeline and viewport.
+    ///
+    /// Returns `None` if this is not a Timeline tab.
+    pub fn agent_timeline_and_viewport_mut(
+        &mut self,
+    ) -> Option<(&mut AgentTimelineBuffer, &mut Viewport)> {
+        match &mut self.buffer {
+            TabBuffer::AgentTimeline(timeline) => Some((timeline, &mut self.viewport)),
+            _ => None,
+        }
+    }
+
+    // Chunk: docs/chunks/agent_edit_review - Review access
+    /// Returns a reference to the underlying `EditReviewBuffer` if this is a
+    /// Review tab.
+    pub fn as_edit_review(&self) -> Option<&EditReviewBuffer> {
+        self.buffer.as_edit_review()
+    }
+
+    /// Returns mutable references to both the review and viewport.
+    ///
+    /// Returns `None` if this is not a Review tab.
+    pub fn edit_review_and_viewport_mut(
+        &mut self,
+    ) -> Option<(&mut EditReviewBuffer, &mut Viewport)> {
+        match &mut self.buffer {
+            TabBuffer::Review(review) => Some((review, &mut self.viewport)),
+            _ => None,
+        }
+    }
+
+    // Chunk: docs/chunks/file_view_mode - File view access
+    /// Returns a reference to the underlying `FileView` if this file tab
+    /// shows a view.
+    pub fn as_file_view(&self) -> Option<&FileView> {
+        self.buffer.as_file_view()
+    }
+
+    /// Returns mutable references to both the file view and viewport.
+    ///
+    /// Returns `None` if this tab doesn't show a view.
+    pub fn file_view_and_viewport_mut(&mut self) -> Option<(&mut FileView, &mut Viewport)> {
+        match &mut self.buffer {
+            TabBuffer::FileView(view) => Some((view, &mut self.viewport)),
+            _ => None,
+        }
+    }
+
     /// Returns mutable references to both the text buffer and viewport.
     ///
     /// This method is needed to satisfy the borrow checker when both need
@@ -475,7 +1530,20 @@ impl Tab {
     pub fn buffer_and_viewport_mut(&mut self) -> Option<(&mut TextBuffer, &mut Viewport)> {
         match &mut self.buffer {
             TabBuffer::File(buf) => Some((buf, &mut self.viewport)),
-            TabBuffer::Terminal(_) | TabBuffer::AgentTerminal | TabBuffer::Error(_) => None,
+            TabBuffer::Terminal(_)
+            | TabBuffer::AgentTerminal
+            | TabBuffer::Error(_)
+            | TabBuffer::Diff(_)
+            | TabBuffer::Problems(_)
+            | TabBuffer::Changes(_)
+            | TabBuffer::Settings(_)
+            | TabBuffer::Shortcuts(_)
+            | TabBuffer::TerminalMemory(_)
+            | TabBuffer::Replay(_)
+            | TabBuffer::Agents(_)
+            | TabBuffer::AgentTimeline(_)
+            | TabBuffer::Review(_)
+            | TabBuffer::FileView(_) => None,
         }
     }
 
@@ -490,7 +1558,20 @@ impl Tab {
     pub fn terminal_and_viewport_mut(&mut self) -> Option<(&mut TerminalBuffer, &mut Viewport)> {
         match &mut self.buffer {
             TabBuffer::Terminal(term) => Some((term, &mut self.viewport)),
-            TabBuffer::File(_) | TabBuffer::AgentTerminal | TabBuffer::Error(_) => None,
+            TabBuffer::File(_)
+            | TabBuffer::AgentTerminal
+            | TabBuffer::Error(_)
+            | TabBuffer::Diff(_)
+            | TabBuffer::Problems(_)
+            | TabBuffer::Changes(_)
+            | TabBuffer::Settings(_)
+            | TabBuffer::Shortcuts(_)
+            | TabBuffer::TerminalMemory(_)
+            | TabBuffer::Replay(_)
+            | TabBuffer::Agents(_)
+            | TabBuffer::AgentTimeline(_)
+            | TabBuffer::Review(_)
+            | TabBuffer::FileView(_) => None,
         }
     }
 
@@ -500,28 +1581,184 @@ impl Tab {
         self.welcome_scroll_offset_px
     }
 
-    /// Sets the vertical scroll offset for the welcome screen, in pixels.
+    /// Sets the vertical scroll offset for the welcome screen, in pixels.
+    ///
+    /// Enforces the lower bound (≥ 0). The upper bound is enforced at render time
+    /// by `calculate_welcome_geometry`.
+    pub fn set_welcome_scroll_offset_px(&mut self, offset: f32) {
+        self.welcome_scroll_offset_px = offset.max(0.0);
+    }
+
+    // Chunk: docs/chunks/content_tab_bar - Unread badge support
+    /// Marks the tab as having unread content.
+    ///
+    /// This is typically used for terminal tabs when output arrives while the
+    /// tab is not active.
+    pub fn mark_unread(&mut self) {
+        self.unread = true;
+    }
+
+    /// Clears the unread state.
+    ///
+    /// Called when the tab becomes active to indicate the user has seen the content.
+    pub fn clear_unread(&mut self) {
+        self.unread = false;
+    }
+
+    // Chunk: docs/chunks/terminal_bell_attention - Bell badge support
+    /// Returns true if this tab's terminal rang the bell since the tab was last focused.
+    pub fn has_pending_bell(&self) -> bool {
+        self.as_terminal_buffer()
+            .map(|term| term.has_pending_bell())
+            .unwrap_or(false)
+    }
+
+    /// Clears the pending bell for this tab's terminal, if any.
+    ///
+    /// Called when the tab becomes focused.
+    pub fn clear_bell(&mut self) {
+        if let Some(term) = self.as_terminal_buffer_mut() {
+            term.clear_bell();
+        }
+    }
+
+    // Chunk: docs/chunks/terminal_command_status - Command summaries after their output
+    /// Returns the summaries of finished commands on this terminal tab's
+    /// visible lines, as annotations to draw over the terminal.
+    ///
+    /// Returns `None` if this is not a terminal tab.
+    pub fn command_annotations(&self) -> Option<Annotations> {
+        let terminal = self.as_terminal_buffer()?;
+        let first = self.viewport.first_visible_line();
+        let visible = first..first + self.viewport.visible_lines() + 1;
+        let mut layer = Annotations::new();
+        layer.set(COMMANDS_SOURCE, terminal.command_annotations(visible));
+        Some(layer)
+    }
+
+    // =========================================================================
+    // Background Loading (Chunk: docs/chunks/background_file_io)
+    // =========================================================================
+
+    /// Empties the buffer of this file tab and marks it as loading `total`
+    /// bytes in the background.
+    ///
+    /// Until `finish_loading`, the tab is read-only and unhighlighted, and
+    /// its buffer grows with each chunk passed to `append_loaded_text`.
+    pub fn start_loading(&mut self, total: u64) {
+        if let TabBuffer::File(buffer) = &mut self.buffer {
+            *buffer = TextBuffer::new();
+        }
+        self.highlighter = None;
+        self.word_count = None;
+        self.base_content = None;
+        self.last_known_mtime = None;
+        self.read_only = true;
+        self.loading = Some(LoadProgress::new(total));
+    }
+
+    /// Appends the next chunk of the file being loaded.
+    ///
+    /// The cursor and selection stay where they are, so the user can read and
+    /// select what has arrived while the rest loads. Returns false if the tab
+    /// isn't loading (it was reloaded or is no longer a file tab).
+    pub fn append_loaded_text(&mut self, text: &str, bytes_read: u64, total: u64) -> bool {
+        let (Some(progress), TabBuffer::File(buffer)) = (self.loading.as_mut(), &mut self.buffer)
+        else {
+            return false;
+        };
+        progress.bytes_read = bytes_read;
+        progress.total = total;
+
+        let cursor = buffer.cursor_position();
+        let anchor = buffer.selection_anchor();
+        buffer.move_to_buffer_end();
+        buffer.insert_str(text);
+        buffer.set_cursor(cursor);
+        if let Some(anchor) = anchor {
+            buffer.set_selection_anchor(anchor);
+        }
+        true
+    }
+
+    /// Ends the background load: the buffer now holds the whole file, last
+    /// modified at `mtime`.
+    ///
+    /// Records the content as the merge base and sets up highlighting. The
+    /// caller decides whether the tab stays read-only.
+    pub fn finish_loading(
+        &mut self,
+        mtime: Option<std::time::SystemTime>,
+        registry: &LanguageRegistry,
+        theme: SyntaxTheme,
+    ) {
+        self.loading = None;
+        self.base_content = self.buffer.as_text_buffer().map(|buffer| buffer.content());
+        self.last_known_mtime = mtime;
+        self.setup_highlighting(registry, theme);
+    }
+
+    // =========================================================================
+    // File View (Chunk: docs/chunks/file_view_mode)
+    // =========================================================================
+
+    /// Shows `view` in this file tab in place of its buffer.
     ///
-    /// Enforces the lower bound (≥ 0). The upper bound is enforced at render time
-    /// by `calculate_welcome_geometry`.
-    pub fn set_welcome_scroll_offset_px(&mut self, offset: f32) {
-        self.welcome_scroll_offset_px = offset.max(0.0);
+    /// The tab becomes read-only and drops what only a buffer has: its
+    /// highlighting, word count and merge base.
+    pub fn show_file_view(&mut self, view: FileView) {
+        self.buffer = TabBuffer::FileView(view);
+        self.highlighter = None;
+        self.word_count = None;
+        self.base_content = None;
+        self.loading = None;
+        self.read_only = true;
+    }
+
+    /// Replaces the file view of this tab with an empty buffer, for the
+    /// caller to load the file into. Returns false if the tab doesn't show
+    /// a view.
+    pub fn promote_file_view(&mut self) -> bool {
+        if !matches!(self.buffer, TabBuffer::FileView(_)) {
+            return false;
+        }
+        self.buffer = TabBuffer::File(TextBuffer::new());
+        self.read_only = false;
+        true
     }
 
-    // Chunk: docs/chunks/content_tab_bar - Unread badge support
-    /// Marks the tab as having unread content.
-    ///
-    /// This is typically used for terminal tabs when output arrives while the
-    /// tab is not active.
-    pub fn mark_unread(&mut self) {
-        self.unread = true;
+    // =========================================================================
+    // Word Count (Chunk: docs/chunks/prose_word_count)
+    // =========================================================================
+
+    /// Returns the live word count if this is a prose file tab.
+    pub fn word_count(&self) -> Option<&WordCount> {
+        self.word_count.as_ref()
     }
 
-    /// Clears the unread state.
+    /// Brings the word count up to date with the buffer.
     ///
-    /// Called when the tab becomes active to indicate the user has seen the content.
-    pub fn clear_unread(&mut self) {
-        self.unread = false;
+    /// `dirty` describes the lines edited since the last refresh; with `full`
+    /// the buffer is rescanned from scratch (e.g. after a tab switch or reload,
+    /// when the accumulated dirty lines may not describe this tab). Tabs that
+    /// aren't prose files have no word count.
+    pub fn refresh_word_count(&mut self, dirty: &DirtyLines, full: bool) {
+        let is_prose = self
+            .associated_file
+            .as_deref()
+            .is_some_and(crate::word_count::is_prose_path);
+        let buffer = match self.buffer.as_text_buffer() {
+            Some(buffer) if is_prose => buffer,
+            _ => {
+                self.word_count = None;
+                return;
+            }
+        };
+
+        match self.word_count.as_mut() {
+            Some(count) if !full => count.update(buffer, dirty),
+            _ => self.word_count = Some(WordCount::new(buffer)),
+        }
     }
 
     // =========================================================================
@@ -532,6 +1769,7 @@ impl Tab {
     ///
     /// Call this after loading file content. If the extension is recognized,
     /// a highlighter is created with the full source for initial parsing.
+    /// A scratch buffer uses the extension of its chosen language.
     ///
     /// # Arguments
     ///
@@ -545,13 +1783,19 @@ impl Tab {
         theme: SyntaxTheme,
     ) -> bool {
         // Only file tabs can have highlighting
-        let (path, buffer) = match (&self.associated_file, &self.buffer) {
-            (Some(p), TabBuffer::File(buf)) => (p, buf),
+        let buffer = match &self.buffer {
+            TabBuffer::File(buf) => buf,
             _ => return false,
         };
 
         // Get extension from path
-        let ext = match path.extension().and_then(|e| e.to_str()) {
+        // Chunk: docs/chunks/scratch_buffers - Or from the scratch buffer's language
+        let ext = match (&self.associated_file, &self.scratch) {
+            (Some(path), _) => path.extension().and_then(|e| e.to_str()),
+            (None, Some(scratch)) => scratch.language.as_deref(),
+            (None, None) => None,
+        };
+        let ext = match ext {
             Some(e) => e,
             None => return false,
         };
@@ -726,6 +1970,95 @@ impl Default for JumpStack {
     }
 }
 
+// =============================================================================
+// Cursor History (Chunk: docs/chunks/cursor_position_history)
+// =============================================================================
+
+/// Minimum line distance for a cursor move to be recorded in the history.
+///
+/// Small moves (arrow keys, typing) are not interesting navigation targets;
+/// only jumps of at least this many lines are remembered.
+pub const SIGNIFICANT_CURSOR_MOVE_LINES: usize = 10;
+
+// Chunk: docs/chunks/cursor_position_history - Per-tab back/forward cursor history
+/// A per-tab, browser-style history of significant cursor positions.
+///
+/// Recording a new position pushes the previous location onto the back list
+/// and discards any forward entries. Navigating back or forward swaps the
+/// current position between the two lists, so the user can step back and
+/// forth through the positions they visited.
+#[derive(Debug)]
+pub struct CursorHistory {
+    back: Vec<Position>,
+    forward: Vec<Position>,
+    max_size: usize,
+}
+
+impl CursorHistory {
+    /// Creates an empty history that retains at most `max_size` back entries.
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            back: Vec::new(),
+            forward: Vec::new(),
+            max_size,
+        }
+    }
+
+    /// Returns true if a move from `from` to `to` is significant enough to record.
+    pub fn is_significant_move(from: Position, to: Position) -> bool {
+        from.line.abs_diff(to.line) >= SIGNIFICANT_CURSOR_MOVE_LINES
+    }
+
+    /// Records `from` as the position the cursor left.
+    ///
+    /// Clears the forward list. Consecutive duplicates are collapsed, and
+    /// the oldest entry is dropped when the history is at capacity.
+    pub fn record(&mut self, from: Position) {
+        self.forward.clear();
+        if self.back.last() == Some(&from) {
+            return;
+        }
+        if self.back.len() >= self.max_size {
+            self.back.remove(0);
+        }
+        self.back.push(from);
+    }
+
+    /// Steps back from `current`, returning the position to move to.
+    ///
+    /// `current` is pushed onto the forward list so it can be revisited.
+    pub fn back(&mut self, current: Position) -> Option<Position> {
+        let target = self.back.pop()?;
+        self.forward.push(current);
+        Some(target)
+    }
+
+    /// Steps forward from `current`, returning the position to move to.
+    ///
+    /// `current` is pushed onto the back list so it can be revisited.
+    pub fn forward(&mut self, current: Position) -> Option<Position> {
+        let target = self.forward.pop()?;
+        self.back.push(current);
+        Some(target)
+    }
+
+    /// Returns true if there is a position to go back to.
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    /// Returns true if there is a position to go forward to.
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+}
+
+impl Default for CursorHistory {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
 // =============================================================================
 // Workspace
 // =============================================================================
@@ -766,6 +2099,11 @@ pub struct Workspace {
     /// When an agent is attached, its terminal is accessible via `agent_terminal()`.
     /// The first tab is typically an `AgentTerminal` placeholder that renders from here.
     pub agent: Option<AgentHandle>,
+    // Chunk: docs/chunks/agent_notifications - Agent attention badge
+    /// True when the agent started waiting on the user (needs input or exited)
+    /// while its tab wasn't focused. Drives the agent badge on the left rail
+    /// tile; cleared when the agent tab is focused.
+    pub agent_attention: bool,
     // Chunk: docs/chunks/workspace_dir_picker - Per-workspace file index
     /// The file index for fuzzy file matching in this workspace.
     ///
@@ -790,6 +2128,33 @@ pub struct Workspace {
     /// The index is initialized via `start_symbol_indexing()` after workspace creation.
     /// It's `None` until initialization.
     pub symbol_index: Option<SymbolIndex>,
+    // Chunk: docs/chunks/project_content_search - Trigram index for project search
+    /// The content index for project search and find references.
+    ///
+    /// Started on first use by `content_index()`, which also keeps its file
+    /// list in step with `file_index`. It's `None` until then.
+    pub content_index: Option<ContentIndex>,
+    // Chunk: docs/chunks/pane_zoom - Pane zoom toggle
+    /// The pane shown full-size in place of the split layout, if any.
+    ///
+    /// The layout tree is left untouched while zoomed. Zoom only applies while
+    /// this pane is the active pane; see `zoomed_pane_id()`.
+    pub zoomed_pane: Option<PaneId>,
+    // Chunk: docs/chunks/terminal_broadcast_input - Broadcast input toggle
+    /// When true, keys typed into a terminal tab are also sent to every other
+    /// terminal tab in this workspace (Cmd+Shift+B).
+    pub broadcast_input: bool,
+    // Chunk: docs/chunks/problem_matcher - Matched compiler problems
+    /// Compiler problems from the last command that finished, or from the
+    /// terminal last scanned with Cmd+Shift+M.
+    pub problems: Vec<Problem>,
+    // Chunk: docs/chunks/buffer_annotations - Per-file annotation layers
+    /// Annotations published for files in this workspace, by path. Open and
+    /// unopened files alike, so a tab opened later shows them.
+    annotations: HashMap<PathBuf, Annotations>,
+    // Chunk: docs/chunks/agent_edit_review - Proposals already collected
+    /// The agent timeline's revision when proposed edits were last collected.
+    edit_proposals_seen: u64,
 }
 
 impl Workspace {
@@ -854,12 +2219,19 @@ impl Workspace {
             next_pane_id,
             status: WorkspaceStatus::Idle,
             agent: None,
+            agent_attention: false,
             file_index,
             last_cache_version: 0,
             jump_stack: JumpStack::default(),
             // Chunk: docs/chunks/treesitter_symbol_index - Initialize symbol_index as None
             // Call start_symbol_indexing() to begin background indexing
             symbol_index: None,
+            content_index: None,
+            zoomed_pane: None,
+            broadcast_input: false,
+            problems: Vec::new(),
+            annotations: HashMap::new(),
+            edit_proposals_seen: 0,
         }
     }
 
@@ -956,6 +2328,29 @@ impl Workspace {
         }
     }
 
+    // Chunk: docs/chunks/project_content_search - Lazily started content index
+    /// Returns the workspace's content index, starting it on first use.
+    ///
+    /// Each call first syncs the index's file list with the file index crawl,
+    /// which is a no-op unless the crawl found or lost files since last time.
+    pub fn content_index(&mut self) -> &ContentIndex {
+        let index = self
+            .content_index
+            .get_or_insert_with(|| ContentIndex::start(self.root_path.clone()));
+        index.sync_with(&self.file_index);
+        index
+    }
+
+    // Chunk: docs/chunks/project_content_search - Keep content index current
+    /// Re-indexes `file_path` (absolute) after it was saved or changed on disk.
+    ///
+    /// A no-op if the content index hasn't been started.
+    pub fn update_content_index_for_file(&self, file_path: &std::path::Path) {
+        if let Some(ref index) = self.content_index {
+            index.update_file(file_path);
+        }
+    }
+
     // Chunk: docs/chunks/pane_mirror_restore - next_pane_id synchronization
     /// Sets the next pane ID counter.
     ///
@@ -995,6 +2390,441 @@ impl Workspace {
         self.pane_root.all_panes_mut()
     }
 
+    // =========================================================================
+    // Pane zoom (Chunk: docs/chunks/pane_zoom)
+    // =========================================================================
+
+    /// Toggles zoom of the active pane.
+    ///
+    /// A zoomed pane is laid out over the whole pane area while the layout tree
+    /// stays intact, so toggling again restores the split exactly as it was.
+    /// A layout with a single pane can't be zoomed.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the active pane is zoomed after the toggle.
+    pub fn toggle_pane_zoom(&mut self) -> bool {
+        if self.zoomed_pane_id().is_some() || self.pane_root.pane_count() < 2 {
+            self.zoomed_pane = None;
+        } else {
+            self.zoomed_pane = Some(self.active_pane_id);
+        }
+        self.zoomed_pane.is_some()
+    }
+
+    /// Returns the zoomed pane, if zoom is in effect.
+    ///
+    /// Zoom is in effect only while the zoomed pane is still the active pane
+    /// and there is more than one pane to hide.
+    pub fn zoomed_pane_id(&self) -> Option<PaneId> {
+        self.zoomed_pane.filter(|&pane_id| {
+            pane_id == self.active_pane_id && self.pane_root.pane_count() > 1
+        })
+    }
+
+    /// Calculates the screen rectangles of the visible panes.
+    ///
+    /// This is `calculate_pane_rects` with zoom applied: while a pane is
+    /// zoomed, it is the only rect and fills `bounds`.
+    pub fn visible_pane_rects(
+        &self,
+        bounds: (f32, f32, f32, f32),
+    ) -> Vec<crate::pane_layout::PaneRect> {
+        crate::pane_layout::calculate_visible_pane_rects(
+            bounds,
+            &self.pane_root,
+            self.zoomed_pane_id(),
+        )
+    }
+
+    /// Resolves a screen-space point to a pane hit, with zoom applied.
+    ///
+    /// See `pane_layout::resolve_pane_hit`.
+    pub fn resolve_pane_hit(
+        &self,
+        x: f32,
+        y: f32,
+        bounds: (f32, f32, f32, f32),
+        tab_bar_height: f32,
+    ) -> Option<crate::pane_layout::PaneHit> {
+        crate::pane_layout::resolve_pane_hit_in_rects(
+            x,
+            y,
+            self.visible_pane_rects(bounds),
+            tab_bar_height,
+        )
+    }
+
+    // =========================================================================
+    // Problems (Chunk: docs/chunks/problem_matcher)
+    // =========================================================================
+
+    /// Replaces the workspace's problems, refreshes any open problems tab and
+    /// publishes them to their files' annotations.
+    pub fn set_problems(&mut self, problems: Vec<Problem>) {
+        for pane in self.pane_root.all_panes_mut() {
+            for tab in &mut pane.tabs {
+                if let Some((list, viewport)) = tab.problems_and_viewport_mut() {
+                    list.set_problems(problems.clone());
+                    viewport.set_scroll_offset_px(0.0, list.line_count());
+                }
+            }
+        }
+        let mut by_path: HashMap<PathBuf, Vec<Annotation>> = HashMap::new();
+        for problem in &problems {
+            by_path
+                .entry(problem.path.clone())
+                .or_default()
+                .push(problem.annotation());
+        }
+        self.publish_annotations(PROBLEMS_SOURCE, by_path);
+        self.problems = problems;
+    }
+
+    // =========================================================================
+    // Changes (Chunk: docs/chunks/git_commit_flow)
+    // =========================================================================
+
+    /// Refreshes any open Changes tab with the repository's latest status.
+    /// Returns true if there was one.
+    pub fn set_git_status(&mut self, status: &RepoStatus) -> bool {
+        let mut any = false;
+        for pane in self.pane_root.all_panes_mut() {
+            for tab in &mut pane.tabs {
+                if let Some((changes, viewport)) = tab.changes_and_viewport_mut() {
+                    changes.set_status(status.clone());
+                    viewport.ensure_visible(changes.selected_row(), changes.line_count());
+                    any = true;
+                }
+            }
+        }
+        any
+    }
+
+    // =========================================================================
+    // Settings (Chunk: docs/chunks/settings_tab)
+    // =========================================================================
+
+    /// Shows `config` in any open Settings tab.
+    pub fn set_settings_config(&mut self, config: &EditorConfig) {
+        for pane in self.pane_root.all_panes_mut() {
+            for tab in &mut pane.tabs {
+                if let Some((settings, _)) = tab.settings_and_viewport_mut() {
+                    settings.set_config(config.clone());
+                }
+            }
+        }
+    }
+
+    // Chunk: docs/chunks/keybinding_conflicts - Keep Keyboard Shortcuts tabs current
+    /// Shows `overrides` in any open Keyboard Shortcuts tab.
+    pub fn set_key_overrides(&mut self, overrides: &[KeyOverride]) {
+        for pane in self.pane_root.all_panes_mut() {
+            for tab in &mut pane.tabs {
+                if let Some((shortcuts, _)) = tab.shortcuts_and_viewport_mut() {
+                    if shortcuts.overrides() != overrides {
+                        shortcuts.set_overrides(overrides.to_vec());
+                    }
+                }
+            }
+        }
+    }
+
+    // =========================================================================
+    // Terminal Memory (Chunk: docs/chunks/terminal_memory_budget)
+    // =========================================================================
+
+    /// Applies the scrollback limits to every terminal in this workspace,
+    /// the agent's included.
+    pub fn set_scrollback_limits(&mut self, limits: &TerminalConfig) {
+        for pane in self.pane_root.all_panes_mut() {
+            for tab in &mut pane.tabs {
+                if let Some(terminal) = tab.as_terminal_buffer_mut() {
+                    limits.apply(terminal);
+                }
+            }
+        }
+        if let Some(terminal) = self.agent_terminal_mut() {
+            limits.apply(terminal);
+        }
+    }
+
+    /// Returns the memory of each terminal in this workspace, in tab order,
+    /// named "workspace › tab".
+    pub fn terminal_memory(&self) -> Vec<TerminalMemory> {
+        let mut terminals = Vec::new();
+        for pane in self.pane_root.all_panes() {
+            for tab in &pane.tabs {
+                let terminal = if tab.buffer.is_agent_terminal() {
+                    self.agent_terminal()
+                } else {
+                    tab.as_terminal_buffer()
+                };
+                if let Some(terminal) = terminal {
+                    terminals.push(TerminalMemory {
+                        name: format!("{} › {}", self.label, tab.label),
+                        usage: terminal.memory_usage(),
+                    });
+                }
+            }
+        }
+        terminals
+    }
+
+    // Chunk: docs/chunks/render_stats_hud - PTY throughput for the render stats HUD
+    /// Returns the id, "workspace › tab" name and total output bytes of each
+    /// terminal in this workspace, in tab order.
+    #[cfg(feature = "perf-instrumentation")]
+    pub fn terminal_output_bytes(&self) -> Vec<(TabId, String, u64)> {
+        let mut terminals = Vec::new();
+        for pane in self.pane_root.all_panes() {
+            for tab in &pane.tabs {
+                let terminal = if tab.buffer.is_agent_terminal() {
+                    self.agent_terminal()
+                } else {
+                    tab.as_terminal_buffer()
+                };
+                if let Some(terminal) = terminal {
+                    terminals.push((
+                        tab.id,
+                        format!("{} › {}", self.label, tab.label),
+                        terminal.output_bytes(),
+                    ));
+                }
+            }
+        }
+        terminals
+    }
+
+    /// Shows `terminals` in any open Terminal Memory tab.
+    pub fn set_terminal_memory(&mut self, terminals: &[TerminalMemory], limits: &TerminalConfig) {
+        for pane in self.pane_root.all_panes_mut() {
+            for tab in &mut pane.tabs {
+                if let Some((memory, viewport)) = tab.terminal_memory_and_viewport_mut() {
+                    memory.set_terminals(terminals.to_vec(), *limits);
+                    // Keep the scroll position, clamped to the new row count
+                    let offset = viewport.scroll_offset_px();
+                    viewport.set_scroll_offset_px(offset, memory.line_count());
+                }
+            }
+        }
+    }
+
+    // =========================================================================
+    // Agents dashboard (Chunk: docs/chunks/agent_resource_usage)
+    // =========================================================================
+
+    /// Returns this workspace's agent as a row of the Agents tab, if it has one.
+    pub fn agent_summary(&self) -> Option<AgentSummary> {
+        let agent = self.agent.as_ref()?;
+        Some(AgentSummary {
+            workspace: self.label.clone(),
+            command: command_name(&agent.config().command).to_string(),
+            state: state_label(agent.state()),
+            usage: agent.resource_usage(),
+            queue_position: None,
+        })
+    }
+
+    /// Shows `agents` in any open Agents tab, of which at most
+    /// `max_concurrent` run at once.
+    pub fn set_agent_summaries(&mut self, agents: &[AgentSummary], max_concurrent: usize) {
+        for pane in self.pane_root.all_panes_mut() {
+            for tab in &mut pane.tabs {
+                if let Some((dashboard, viewport)) = tab.agent_dashboard_and_viewport_mut() {
+                    dashboard.set_agents(agents.to_vec(), max_concurrent);
+                    let offset = viewport.scroll_offset_px();
+                    viewport.set_scroll_offset_px(offset, dashboard.line_count());
+                }
+            }
+        }
+    }
+
+    // =========================================================================
+    // Agent timeline (Chunk: docs/chunks/agent_events)
+    // =========================================================================
+
+    /// Shows the agent's latest events in any open Timeline tab. A tab
+    /// scrolled to its end stays there, following new events. Returns true
+    /// if a tab changed.
+    pub fn refresh_agent_timeline(&mut self) -> bool {
+        let Some(agent) = &self.agent else {
+            return false;
+        };
+        let structured = agent.config().structured_events;
+        let mut changed = false;
+        for pane in self.pane_root.all_panes_mut() {
+            for tab in &mut pane.tabs {
+                if let Some((timeline, viewport)) = tab.agent_timeline_and_viewport_mut() {
+                    let following = viewport.is_at_bottom(timeline.line_count());
+                    if timeline.set_timeline(agent.timeline(), structured) {
+                        changed = true;
+                        if following {
+                            viewport.scroll_to_bottom(timeline.line_count());
+                        }
+                    }
+                }
+            }
+        }
+        changed
+    }
+
+    // =========================================================================
+    // Edit review (Chunk: docs/chunks/agent_edit_review)
+    // =========================================================================
+
+    /// Takes the edits the agent proposed since the last call, reading each
+    /// proposal's contents. Paths are relative to the agent's working
+    /// directory. Proposals whose contents can't be read are skipped.
+    pub fn take_proposed_edits(&mut self) -> Vec<ProposedEdit> {
+        let Some(agent) = &self.agent else {
+            return Vec::new();
+        };
+        let cwd = &agent.config().cwd;
+        let timeline = agent.timeline();
+        let mut edits = Vec::new();
+        for entry in timeline.since(self.edit_proposals_seen) {
+            if let AgentEvent::FileEdit {
+                path,
+                summary,
+                proposed: Some(proposed),
+            } = &entry.event
+            {
+                let (path, proposed) = (cwd.join(path), cwd.join(proposed));
+                let proposal = ProposedEdit::read(path, &proposed, summary.clone());
+                edits.extend(proposal.ok());
+            }
+        }
+        self.edit_proposals_seen = timeline.revision();
+        edits
+    }
+
+    /// Returns the open Review tab, if there is one.
+    pub fn edit_review_tab_mut(&mut self) -> Option<&mut Tab> {
+        self.pane_root
+            .all_panes_mut()
+            .into_iter()
+            .flat_map(|pane| pane.tabs.iter_mut())
+            .find(|tab| tab.kind == TabKind::Review)
+    }
+
+    // =========================================================================
+    // Annotations (Chunk: docs/chunks/buffer_annotations)
+    // =========================================================================
+
+    /// Replaces everything `source` has published with `by_path`, the
+    /// annotations for each file. Files missing from `by_path` lose the
+    /// source's annotations.
+    pub fn publish_annotations(
+        &mut self,
+        source: AnnotationSource,
+        mut by_path: HashMap<PathBuf, Vec<Annotation>>,
+    ) {
+        for (path, layer) in &mut self.annotations {
+            layer.set(source, by_path.remove(path).unwrap_or_default());
+        }
+        for (path, annotations) in by_path {
+            self.annotations
+                .entry(path)
+                .or_default()
+                .set(source, annotations);
+        }
+    }
+
+    /// Replaces the annotations `source` has published for one file.
+    pub fn set_file_annotations(
+        &mut self,
+        path: &Path,
+        source: AnnotationSource,
+        annotations: Vec<Annotation>,
+    ) {
+        match self.annotations.get_mut(path) {
+            Some(layer) => layer.set(source, annotations),
+            None if annotations.is_empty() => {}
+            None => {
+                let mut layer = Annotations::new();
+                layer.set(source, annotations);
+                self.annotations.insert(path.to_path_buf(), layer);
+            }
+        }
+    }
+
+    /// Returns the annotations published for `path`, if any.
+    pub fn annotations_for(&self, path: &Path) -> Option<&Annotations> {
+        self.annotations.get(path)
+    }
+
+    /// Returns the annotations for the file shown in `tab`, if any.
+    pub fn tab_annotations(&self, tab: &Tab) -> Option<&Annotations> {
+        self.annotations.get(tab.associated_file.as_deref()?)
+    }
+
+    /// Drains the lines whose annotations changed, for each file with changes.
+    pub fn take_annotation_dirty(&mut self) -> Vec<(PathBuf, DirtyLines)> {
+        self.annotations
+            .iter_mut()
+            .map(|(path, layer)| (path, layer.take_dirty()))
+            .filter(|(_, dirty)| !dirty.is_none())
+            .map(|(path, dirty)| (path.clone(), dirty))
+            .collect()
+    }
+
+    // =========================================================================
+    // Broadcast input (Chunk: docs/chunks/terminal_broadcast_input)
+    // =========================================================================
+
+    /// Returns the number of terminal tabs across all panes.
+    pub fn terminal_tab_count(&self) -> usize {
+        self.all_panes()
+            .iter()
+            .flat_map(|pane| pane.tabs.iter())
+            .filter(|tab| tab.kind == TabKind::Terminal && tab.as_terminal_buffer().is_some())
+            .count()
+    }
+
+    /// Writes input to every terminal tab in the workspace except `except`.
+    ///
+    /// `encode` is called with each terminal's own modes, so a program that
+    /// enabled bracketed paste or application cursor keys gets the bytes it
+    /// expects. Terminals scrolled back into history snap to the bottom, as
+    /// they do when typed into directly.
+    ///
+    /// # Returns
+    ///
+    /// The number of terminals written to.
+    pub fn broadcast_terminal_input(
+        &mut self,
+        except: TabId,
+        encode: impl Fn(lite_edit_terminal::TermMode) -> Vec<u8>,
+    ) -> usize {
+        let mut written = 0;
+        for pane in self.all_panes_mut() {
+            // Chunk: docs/chunks/shell_command_runner - Command output tabs take no input
+            let targets = pane
+                .tabs
+                .iter_mut()
+                .filter(|tab| tab.id != except && tab.kind == TabKind::Terminal);
+            for tab in targets {
+                let Some((terminal, viewport)) = tab.terminal_and_viewport_mut() else {
+                    continue;
+                };
+                let bytes = encode(terminal.term_mode());
+                if bytes.is_empty() {
+                    continue;
+                }
+                if !terminal.is_alt_screen() {
+                    let line_count = terminal.line_count();
+                    if !viewport.is_at_bottom(line_count) {
+                        viewport.scroll_to_bottom(line_count);
+                    }
+                }
+                let _ = terminal.write_input(&bytes);
+                written += 1;
+            }
+        }
+        written
+    }
+
     // =========================================================================
     // Pane focus and tab movement (Chunk: docs/chunks/tiling_focus_keybindings)
     // =========================================================================
@@ -1012,18 +2842,55 @@ impl Workspace {
     pub fn switch_focus(&mut self, direction: crate::pane_layout::Direction) -> bool {
         use crate::pane_layout::MoveTarget;
 
-        let target = self.pane_root.find_target_in_direction(self.active_pane_id, direction);
+        let target = self.pane_root.find_target_in_direction(self.active_pane_id, direction);
+
+        match target {
+            MoveTarget::ExistingPane(target_id) => {
+                self.active_pane_id = target_id;
+                // Chunk: docs/chunks/pane_zoom - Focusing another pane unzooms
+                self.zoomed_pane = None;
+                true
+            }
+            MoveTarget::SplitPane(_, _) => {
+                // No adjacent pane in that direction - focus stays put
+                false
+            }
+        }
+    }
+
+    // Chunk: docs/chunks/tab_drag_drop - Drop a dragged tab onto a pane
+    /// Moves the active tab of `source_pane_id` to a drag-and-drop target.
+    ///
+    /// Focus follows the moved tab, as with `move_active_tab`.
+    ///
+    /// # Returns
+    ///
+    /// The `MoveResult` indicating what happened.
+    pub fn drop_active_tab(
+        &mut self,
+        source_pane_id: PaneId,
+        target: crate::pane_layout::TabDropTarget,
+    ) -> crate::pane_layout::MoveResult {
+        use crate::pane_layout::{move_tab_to_drop_target, MoveResult};
+
+        let new_pane_id = self.gen_pane_id();
+        let result = move_tab_to_drop_target(&mut self.pane_root, source_pane_id, target, || new_pane_id);
 
-        match target {
-            MoveTarget::ExistingPane(target_id) => {
-                self.active_pane_id = target_id;
-                true
+        match result {
+            MoveResult::MovedToExisting { target_pane_id, .. } => {
+                self.active_pane_id = target_pane_id;
+                self.zoomed_pane = None;
             }
-            MoveTarget::SplitPane(_, _) => {
-                // No adjacent pane in that direction - focus stays put
-                false
+            MoveResult::MovedToNew { new_pane_id, .. } => {
+                self.active_pane_id = new_pane_id;
+                self.zoomed_pane = None;
+            }
+            MoveResult::Rejected | MoveResult::SourceNotFound => {
+                // Focus unchanged
             }
         }
+
+        result
     }
 
     // Chunk: docs/chunks/pane_close_last_tab - Cleanup empty panes on last tab close
@@ -1079,6 +2946,14 @@ impl Workspace {
             || new_pane_id,
         );
 
+        // Chunk: docs/chunks/pane_zoom - Layout changes unzoom
+        if matches!(
+            result,
+            MoveResult::MovedToExisting { .. } | MoveResult::MovedToNew { .. }
+        ) {
+            self.zoomed_pane = None;
+        }
+
         // Update focus to follow the moved tab
         match result {
             MoveResult::MovedToExisting { target_pane_id, .. } => {
@@ -1216,6 +3091,36 @@ impl Workspace {
         None
     }
 
+    // Chunk: docs/chunks/terminal_session_restore - Tab lookup for its startup command
+    /// Find a mutable tab by its ID in any pane of this workspace.
+    pub fn find_tab_mut(&mut self, tab_id: TabId) -> Option<&mut Tab> {
+        self.pane_root
+            .all_panes_mut()
+            .into_iter()
+            .flat_map(|pane| pane.tabs.iter_mut())
+            .find(|tab| tab.id == tab_id)
+    }
+
+    // Chunk: docs/chunks/terminal_tab_labels - Terminal tab rename and color label
+    /// Renames the tab with the given ID and sets its color label.
+    ///
+    /// Surrounding whitespace is trimmed. Returns `false` (and leaves the tab
+    /// unchanged) if no tab has the ID or the trimmed label is empty.
+    pub fn label_tab(&mut self, tab_id: TabId, label: &str, color: Option<TabColor>) -> bool {
+        let label = label.trim();
+        if label.is_empty() {
+            return false;
+        }
+        for pane in self.pane_root.all_panes_mut() {
+            if let Some(tab) = pane.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                tab.label = label.to_string();
+                tab.color_label = color;
+                return true;
+            }
+        }
+        false
+    }
+
     // =========================================================================
     // Cross-tab navigation (Chunk: docs/chunks/gotodef_cross_file_nav)
     // =========================================================================
@@ -1232,6 +3137,10 @@ impl Workspace {
                 if tab.id == tab_id {
                     // Found it! Need to switch to this pane and this tab
                     let pane_id = pane.id;
+                    // Chunk: docs/chunks/pane_zoom - Jumping to another pane unzooms
+                    if pane_id != self.active_pane_id {
+                        self.zoomed_pane = None;
+                    }
                     // Switch to the pane
                     self.active_pane_id = pane_id;
                     // Switch to the tab within the pane
@@ -1245,6 +3154,53 @@ impl Workspace {
         false
     }
 
+    // =========================================================================
+    // Bell attention (Chunk: docs/chunks/terminal_bell_attention)
+    // =========================================================================
+
+    /// Returns true if any terminal tab in this workspace has a pending bell.
+    ///
+    /// Drives the bell badge on the workspace's left rail tile.
+    pub fn has_pending_bell(&self) -> bool {
+        self.pane_root
+            .all_panes()
+            .iter()
+            .any(|pane| pane.tabs.iter().any(|tab| tab.has_pending_bell()))
+    }
+
+    // Chunk: docs/chunks/terminal_tab_labels - Color label dots on the rail tile
+    /// Returns the color labels of this workspace's tabs, each color once,
+    /// in tab order.
+    ///
+    /// Drives the color label dots on the workspace's left rail tile.
+    pub fn color_labels(&self) -> Vec<TabColor> {
+        let mut colors = Vec::new();
+        for pane in self.pane_root.all_panes() {
+            for color in pane.tabs.iter().filter_map(|tab| tab.color_label) {
+                if !colors.contains(&color) {
+                    colors.push(color);
+                }
+            }
+        }
+        colors
+    }
+
+    /// Clears the pending bell on the focused tab (active tab of the active pane).
+    ///
+    /// The focused terminal is in front of the user, so its bell never needs a badge.
+    pub fn clear_focused_bell(&mut self) {
+        if let Some(tab) = self.active_tab_mut() {
+            tab.clear_bell();
+        }
+    }
+
+    // Chunk: docs/chunks/agent_notifications - Agent tab focus check
+    /// Returns true if the focused tab (active tab of the active pane) is the
+    /// agent terminal.
+    pub fn agent_tab_focused(&self) -> bool {
+        self.active_tab().is_some_and(|tab| tab.is_agent_tab())
+    }
+
     // =========================================================================
     // Agent lifecycle methods (Chunk: docs/chunks/agent_lifecycle)
     // =========================================================================
@@ -1331,6 +3287,8 @@ impl Workspace {
 
         // Store the agent handle
         self.agent = Some(agent);
+        // Chunk: docs/chunks/agent_edit_review - A new agent's timeline starts over
+        self.edit_proposals_seen = 0;
 
         // Update status
         self.status = self.compute_status();
@@ -1388,6 +3346,25 @@ impl Workspace {
     // Chunk: docs/chunks/terminal_tab_spawn - Poll standalone terminals
     // Chunk: docs/chunks/terminal_scrollback_viewport - Auto-follow on new output
     // Chunk: docs/chunks/tiling_workspace_integration - Iterate all panes
+    // Chunk: docs/chunks/terminal_command_status - Finished commands for notifications
+    /// Drains the commands that finished in each terminal tab since the last
+    /// call, with the ID and label of the tab they ran in.
+    pub fn take_finished_commands(&mut self) -> Vec<(TabId, String, CommandMark)> {
+        let mut finished = Vec::new();
+        for pane in self.pane_root.all_panes_mut() {
+            for tab in &mut pane.tabs {
+                let (id, label) = (tab.id, tab.label.clone());
+                let Some(terminal) = tab.as_terminal_buffer_mut() else {
+                    continue;
+                };
+                for mark in terminal.take_finished_commands() {
+                    finished.push((id, label.clone(), mark));
+                }
+            }
+        }
+        finished
+    }
+
     // Chunk: docs/chunks/terminal_tab_spawn - Polls PTY events for all standalone terminal tabs
     // Chunk: docs/chunks/terminal_flood_starvation - Needs rewakeup propagation
     /// Polls PTY events for all standalone terminal tabs across all panes.
@@ -1405,13 +3382,18 @@ impl Workspace {
 
         let mut had_events = false;
         let mut needs_rewakeup = false;
+        let mut finished_problems = None;
 
         for pane in self.pane_root.all_panes_mut() {
             for tab in &mut pane.tabs {
                 if let Some((terminal, viewport)) = tab.terminal_and_viewport_mut() {
                     // Track if we're at bottom before polling (for auto-follow)
                     // Also track if we're in alt screen (no auto-follow in alt screen)
-                    let was_at_bottom = viewport.is_at_bottom(terminal.line_count());
+                    // Chunk: docs/chunks/smooth_scroll_animation - Don't fight a running scroll
+                    // A wheel scroll up from the bottom hasn't moved yet on its
+                    // first frame; following output would cancel it.
+                    let was_at_bottom = viewport.is_at_bottom(terminal.line_count())
+                        && !viewport.is_scroll_animating();
                     let was_alt_screen = terminal.is_alt_screen();
 
                     let result = terminal.poll_events();
@@ -1444,8 +3426,28 @@ impl Workspace {
                         PollResult::Idle => {}
                     }
                 }
+
+                // Chunk: docs/chunks/shell_command_runner - Record when a command finishes
+                if tab.command_run.as_ref().is_some_and(CommandRun::is_running) {
+                    let exit_code = tab.as_terminal_buffer_mut().and_then(|t| t.try_wait());
+                    if let (Some(code), Some(run)) = (exit_code, tab.command_run.as_mut()) {
+                        run.exit_code = Some(code);
+                        // Chunk: docs/chunks/task_runner - Show the outcome in the tab label
+                        tab.label = run.tab_label();
+                        // Chunk: docs/chunks/problem_matcher - Match problems in the output
+                        let cwd = run.cwd.clone();
+                        if let Some(terminal) = tab.as_terminal_buffer() {
+                            finished_problems =
+                                Some(crate::problem_matcher::problems_in_view(terminal, &cwd));
+                        }
+                        had_events = true;
+                    }
+                }
             }
         }
+        if let Some(problems) = finished_problems {
+            self.set_problems(problems);
+        }
         (had_events, needs_rewakeup)
     }
 }
@@ -1486,6 +3488,9 @@ pub struct Editor {
     line_height: f32,
     /// Event sender for file change callbacks (cloned to each workspace's FileIndex)
     event_sender: Option<EventSender>,
+    // Chunk: docs/chunks/welcome_recents - Recents listed on the welcome screen
+    /// Recently opened workspaces and files, across launches.
+    pub recents: Recents,
 }
 
 impl std::fmt::Debug for Editor {
@@ -1497,6 +3502,7 @@ impl std::fmt::Debug for Editor {
             .field("next_tab_id", &self.next_tab_id)
             .field("line_height", &self.line_height)
             .field("event_sender", &self.event_sender.as_ref().map(|_| "<EventSender>"))
+            .field("recents", &self.recents)
             .finish()
     }
 }
@@ -1511,6 +3517,7 @@ impl Editor {
             next_tab_id: 0,
             line_height,
             event_sender: None,
+            recents: Recents::new(),
         };
 
         // Create an initial empty workspace
@@ -1539,6 +3546,7 @@ impl Editor {
             next_tab_id: 0,
             line_height,
             event_sender: None,
+            recents: Recents::new(),
         }
     }
 
@@ -1599,6 +3607,8 @@ impl Editor {
     ///
     /// Returns the ID of the new workspace.
     pub fn new_workspace(&mut self, label: String, root_path: PathBuf) -> WorkspaceId {
+        // Chunk: docs/chunks/welcome_recents - Remember opened workspaces
+        self.recents.note_workspace(&root_path);
         let ws_id = self.new_workspace_internal(label, root_path, true);
         // Switch to the new workspace
         self.active_workspace = self.workspaces.len() - 1;
@@ -1610,11 +3620,20 @@ impl Editor {
     ///
     /// Returns the ID of the new workspace.
     pub fn new_workspace_without_tab(&mut self, label: String, root_path: PathBuf) -> WorkspaceId {
+        // Chunk: docs/chunks/welcome_recents - Remember opened workspaces
+        self.recents.note_workspace(&root_path);
         let ws_id = self.new_workspace_internal(label, root_path, false);
         self.active_workspace = self.workspaces.len() - 1;
         ws_id
     }
 
+    // Chunk: docs/chunks/welcome_recents - Merge the saved recents
+    /// Puts the recents saved by earlier launches after those noted so far
+    /// in this one (workspaces opened before they were loaded).
+    pub fn restore_recents(&mut self, saved: Recents) {
+        self.recents.append_older(saved);
+    }
+
     /// Closes the workspace at the given index.
     ///
     /// Returns the removed workspace, or `None` if the index is invalid or
@@ -1660,16 +3679,81 @@ impl Editor {
         }
     }
 
+    // Chunk: docs/chunks/workspace_rename_reorder - Workspace rename
+    /// Renames the workspace at the given index.
+    ///
+    /// Surrounding whitespace is trimmed. Returns `false` (and leaves the
+    /// label unchanged) if the index is invalid or the trimmed label is empty.
+    pub fn rename_workspace(&mut self, index: usize, label: &str) -> bool {
+        let label = label.trim();
+        if label.is_empty() {
+            return false;
+        }
+        match self.workspaces.get_mut(index) {
+            Some(ws) => {
+                ws.label = label.to_string();
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Chunk: docs/chunks/workspace_rename_reorder - Workspace reorder
+    /// Moves the workspace at `from` so that it ends up at index `to`.
+    ///
+    /// The active workspace stays active (its index follows the move), so
+    /// Cmd+1..9 immediately reflect the new order. Returns `false` if either
+    /// index is out of bounds or the indices are equal.
+    pub fn move_workspace(&mut self, from: usize, to: usize) -> bool {
+        let len = self.workspaces.len();
+        if from >= len || to >= len || from == to {
+            return false;
+        }
+
+        let active_id = self.workspaces[self.active_workspace].id;
+        let workspace = self.workspaces.remove(from);
+        self.workspaces.insert(to, workspace);
+        if let Some(idx) = self.workspaces.iter().position(|ws| ws.id == active_id) {
+            self.active_workspace = idx;
+        }
+        true
+    }
+
     /// Returns the number of workspaces.
     pub fn workspace_count(&self) -> usize {
         self.workspaces.len()
     }
 
+    // Chunk: docs/chunks/cli_launcher - Tab lookup for `lite --wait`
+    /// Returns true if any workspace still has the tab `tab_id` open.
+    pub fn has_tab(&self, tab_id: TabId) -> bool {
+        self.workspaces.iter().any(|ws| {
+            ws.all_panes()
+                .iter()
+                .any(|pane| pane.tabs.iter().any(|tab| tab.id == tab_id))
+        })
+    }
+
     /// Returns the line height used for creating new tabs.
     pub fn line_height(&self) -> f32 {
         self.line_height
     }
 
+    // Chunk: docs/chunks/font_config_zoom - Propagate font zoom to every tab
+    /// Sets the line height for new tabs and every existing tab's viewport.
+    ///
+    /// Viewports keep their top line; callers re-sync viewport sizes afterwards.
+    pub fn set_line_height(&mut self, line_height: f32) {
+        self.line_height = line_height;
+        for workspace in &mut self.workspaces {
+            for pane in workspace.all_panes_mut() {
+                for tab in &mut pane.tabs {
+                    tab.viewport.set_line_height(line_height);
+                }
+            }
+        }
+    }
+
     // Chunk: docs/chunks/renderer_polymorphic_buffer - Polymorphic buffer access
     /// Returns a reference to the active tab's BufferView.
     ///
@@ -1733,12 +3817,40 @@ impl Editor {
             return false;
         }
 
+        // Chunk: docs/chunks/scratch_buffers - Nor for scratch buffer tabs, which start empty
+        if tab.scratch.is_some() {
+            return false;
+        }
+
         // Check if the buffer is empty
         match tab.as_text_buffer() {
             Some(buffer) => buffer.is_empty(),
             None => false,
         }
     }
+
+    // Chunk: docs/chunks/pty_graceful_shutdown - Graceful shutdown on app termination
+    /// Shuts down every shell and agent process, waiting at most `grace`.
+    ///
+    /// Covers terminal tabs and agents in all workspaces. Processes get
+    /// SIGHUP, then SIGTERM, then SIGKILL, so shells can save their history
+    /// before the app exits instead of losing their PTYs abruptly.
+    pub fn shutdown_terminals(&mut self, grace: Duration) {
+        let mut terminals: Vec<&mut TerminalBuffer> = Vec::new();
+        for ws in &mut self.workspaces {
+            if let Some(agent) = ws.agent.as_mut() {
+                terminals.push(agent.terminal_mut());
+            }
+            for pane in ws.pane_root.all_panes_mut() {
+                for tab in pane.tabs.iter_mut() {
+                    if let Some(terminal) = tab.as_terminal_buffer_mut() {
+                        terminals.push(terminal);
+                    }
+                }
+            }
+        }
+        lite_edit_terminal::shutdown_terminals(terminals, grace);
+    }
 }
 
 // =============================================================================
@@ -1802,6 +3914,63 @@ mod tests {
         assert!(tab.associated_file.is_none());
     }
 
+    // Chunk: docs/chunks/background_file_io - Tabs loading in the background
+    #[test]
+    fn test_tab_loading_appends_chunks_around_the_cursor() {
+        let buffer = TextBuffer::from_str("old content");
+        let mut tab = Tab::new_file(1, buffer, "big.log".to_string(), Some(PathBuf::from("/big.log")), TEST_LINE_HEIGHT);
+        tab.start_loading(12);
+        assert!(tab.read_only);
+        assert_eq!(tab.as_text_buffer().unwrap().content(), "");
+
+        assert!(tab.append_loaded_text("first\nsec", 9, 12));
+        // The user selects part of what has arrived
+        let buffer = tab.as_text_buffer_mut().unwrap();
+        buffer.set_cursor(Position::new(0, 1));
+        buffer.set_selection_anchor(Position::new(0, 3));
+        assert!(tab.append_loaded_text("ond\n", 12, 12));
+
+        let buffer = tab.as_text_buffer().unwrap();
+        assert_eq!(buffer.content(), "first\nsecond\n");
+        assert_eq!(buffer.cursor_position(), Position::new(0, 1));
+        assert_eq!(buffer.selection_anchor(), Some(Position::new(0, 3)));
+        assert_eq!(tab.loading.map(|p| p.percent()), Some(100));
+
+        let registry = LanguageRegistry::new();
+        tab.finish_loading(None, &registry, SyntaxTheme::catppuccin_mocha());
+        assert!(tab.loading.is_none());
+        assert_eq!(tab.base_content.as_deref(), Some("first\nsecond\n"));
+        // Chunks for a tab that is no longer loading are dropped
+        assert!(!tab.append_loaded_text("late", 16, 16));
+    }
+
+    // Chunk: docs/chunks/file_view_mode - File tabs showing a view
+    #[test]
+    fn test_file_view_tab_until_promoted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("big.log");
+        std::fs::write(&path, "one\ntwo\n").unwrap();
+        let view = FileView::open(&path).unwrap();
+
+        let mut tab = Tab::new_file_view(1, view, TEST_LINE_HEIGHT);
+        assert_eq!(tab.kind, TabKind::File);
+        assert_eq!(tab.label, "big.log");
+        assert_eq!(tab.associated_file.as_deref(), Some(path.as_path()));
+        assert!(tab.read_only);
+        assert!(tab.as_text_buffer().is_none());
+        assert_eq!(tab.buffer().line_count(), 3);
+        assert!(!tab.buffer().is_editable());
+
+        assert!(tab.promote_file_view());
+        assert!(!tab.read_only);
+        assert_eq!(tab.as_text_buffer().unwrap().content(), "");
+        assert!(!tab.promote_file_view());
+
+        tab.show_file_view(FileView::open(&path).unwrap());
+        assert!(tab.read_only);
+        assert!(tab.as_file_view().is_some());
+    }
+
     #[test]
     fn test_tab_as_text_buffer() {
         let buffer = TextBuffer::from_str("hello");
@@ -1822,6 +3991,32 @@ mod tests {
         assert_eq!(text_buf.content(), "pre hello");
     }
 
+    // =========================================================================
+    // Agent attention (Chunk: docs/chunks/agent_notifications)
+    // =========================================================================
+
+    #[test]
+    fn test_attention_message_on_waiting_transitions() {
+        use WorkspaceStatus::*;
+        assert!(NeedsInput.attention_message(Running).is_some());
+        assert!(Completed.attention_message(Running).is_some());
+        assert!(Errored.attention_message(NeedsInput).is_some());
+        // No repeat while the status holds, and no notice for Running/Stale
+        assert_eq!(NeedsInput.attention_message(NeedsInput), None);
+        assert_eq!(Stale.attention_message(NeedsInput), None);
+        assert_eq!(Running.attention_message(NeedsInput), None);
+    }
+
+    #[test]
+    fn test_agent_tab_focused() {
+        let mut ws = Workspace::with_empty_tab(1, 1, "test".to_string(), PathBuf::from("/test"), TEST_LINE_HEIGHT);
+        assert!(!ws.agent_tab_focused());
+        assert!(!ws.agent_attention);
+
+        ws.add_tab(Tab::new_agent(2, "Agent".to_string(), TEST_LINE_HEIGHT));
+        assert!(ws.agent_tab_focused());
+    }
+
     // =========================================================================
     // Workspace Tests (Chunk: docs/chunks/tiling_workspace_integration)
     // =========================================================================
@@ -1937,6 +4132,22 @@ mod tests {
         assert_eq!(editor.active_workspace().unwrap().id, ws_id);
     }
 
+    // Chunk: docs/chunks/welcome_recents - Opened workspaces are remembered
+    #[test]
+    fn test_new_workspace_notes_recent_workspace() {
+        let mut editor = Editor::new_deferred(TEST_LINE_HEIGHT);
+        editor.new_workspace("a".to_string(), PathBuf::from("/a"));
+        editor.new_workspace_without_tab("b".to_string(), PathBuf::from("/b"));
+
+        let mut saved = Recents::new();
+        saved.note_workspace(Path::new("/older"));
+        saved.note_workspace(Path::new("/a"));
+        editor.restore_recents(saved);
+
+        let expected: Vec<PathBuf> = ["/b", "/a", "/older"].iter().map(PathBuf::from).collect();
+        assert_eq!(editor.recents.workspaces, expected);
+    }
+
     #[test]
     fn test_editor_switch_workspace() {
         let mut editor = Editor::new(TEST_LINE_HEIGHT);
@@ -1992,6 +4203,84 @@ mod tests {
         assert_eq!(editor.active_workspace, 1);
     }
 
+    // Chunk: docs/chunks/workspace_rename_reorder - Rename and reorder tests
+    #[test]
+    fn test_editor_rename_workspace() {
+        let mut editor = Editor::new(TEST_LINE_HEIGHT);
+
+        assert!(editor.rename_workspace(0, "  backend  "));
+        assert_eq!(editor.workspaces[0].label, "backend");
+
+        // Empty names and invalid indices are rejected
+        assert!(!editor.rename_workspace(0, "   "));
+        assert_eq!(editor.workspaces[0].label, "backend");
+        assert!(!editor.rename_workspace(5, "other"));
+    }
+
+    // Chunk: docs/chunks/terminal_tab_labels - Tab labels and rail dots
+    #[test]
+    fn test_label_tab_and_color_labels() {
+        let mut ws = Workspace::new(1, "test".to_string(), PathBuf::from("/test"));
+        for id in 1..=3 {
+            let terminal = TerminalBuffer::new(80, 24, 100);
+            let tab = Tab::new_terminal(id, terminal, "Terminal".to_string(), TEST_LINE_HEIGHT);
+            ws.add_tab(tab);
+        }
+
+        assert!(ws.label_tab(1, "  server ", Some(TabColor::Green)));
+        assert!(ws.label_tab(2, "tests", Some(TabColor::Red)));
+        assert!(ws.label_tab(3, "logs", Some(TabColor::Green)));
+        let tab = ws.all_panes()[0].tabs.iter().find(|t| t.id == 1).unwrap();
+        assert_eq!(tab.label, "server");
+
+        // Each color once, in tab order
+        assert_eq!(ws.color_labels(), vec![TabColor::Green, TabColor::Red]);
+
+        // Empty names and unknown tabs are rejected
+        assert!(!ws.label_tab(2, "  ", None));
+        assert!(!ws.label_tab(99, "other", None));
+        assert_eq!(ws.color_labels(), vec![TabColor::Green, TabColor::Red]);
+
+        assert!(ws.label_tab(2, "tests", None));
+        assert_eq!(ws.color_labels(), vec![TabColor::Green]);
+    }
+
+    #[test]
+    fn test_editor_move_workspace_keeps_active() {
+        let mut editor = Editor::new(TEST_LINE_HEIGHT);
+        editor.new_workspace("a".to_string(), PathBuf::from("/a"));
+        editor.new_workspace("b".to_string(), PathBuf::from("/b"));
+        editor.switch_workspace(1); // "a" is active
+
+        assert!(editor.move_workspace(2, 0));
+
+        let labels: Vec<&str> = editor.workspaces.iter().map(|ws| ws.label.as_str()).collect();
+        assert_eq!(labels, vec!["b", "untitled", "a"]);
+        assert_eq!(editor.active_workspace().unwrap().label, "a");
+        assert_eq!(editor.active_workspace, 2);
+    }
+
+    #[test]
+    fn test_editor_move_workspace_moves_active() {
+        let mut editor = Editor::new(TEST_LINE_HEIGHT);
+        editor.new_workspace("a".to_string(), PathBuf::from("/a"));
+        editor.switch_workspace(0);
+
+        assert!(editor.move_workspace(0, 1));
+        assert_eq!(editor.workspaces[1].label, "untitled");
+        assert_eq!(editor.active_workspace, 1);
+    }
+
+    #[test]
+    fn test_editor_move_workspace_invalid() {
+        let mut editor = Editor::new(TEST_LINE_HEIGHT);
+        editor.new_workspace("a".to_string(), PathBuf::from("/a"));
+
+        assert!(!editor.move_workspace(0, 0));
+        assert!(!editor.move_workspace(0, 2));
+        assert!(!editor.move_workspace(3, 0));
+    }
+
     #[test]
     fn test_editor_workspace_count() {
         let mut editor = Editor::new(TEST_LINE_HEIGHT);
@@ -2161,6 +4450,25 @@ mod tests {
         assert_eq!(editor.line_height(), TEST_LINE_HEIGHT);
     }
 
+    // Chunk: docs/chunks/font_config_zoom - Line height propagates to all tabs
+    #[test]
+    fn test_editor_set_line_height_updates_all_tabs() {
+        let mut editor = Editor::new_deferred(TEST_LINE_HEIGHT);
+        editor.new_workspace("a".to_string(), PathBuf::from("/a"));
+        editor.new_workspace("b".to_string(), PathBuf::from("/b"));
+
+        editor.set_line_height(24.0);
+
+        assert_eq!(editor.line_height(), 24.0);
+        for workspace in &editor.workspaces {
+            for pane in workspace.all_panes() {
+                for tab in &pane.tabs {
+                    assert_eq!(tab.viewport.line_height(), 24.0);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_editor_new_deferred_first_workspace_gets_tab() {
         let mut editor = Editor::new_deferred(TEST_LINE_HEIGHT);
@@ -2331,6 +4639,108 @@ mod tests {
         assert_eq!(ws.pane_root.pane_count(), 1);
     }
 
+    // =========================================================================
+    // Tab drag-and-drop Tests (Chunk: docs/chunks/tab_drag_drop)
+    // =========================================================================
+
+    #[test]
+    fn test_drop_active_tab_focuses_new_pane() {
+        use crate::pane_layout::{DropZone, MoveResult, TabDropTarget};
+
+        let mut ws = create_hsplit_workspace();
+        let target = TabDropTarget {
+            pane_id: 2,
+            zone: DropZone::Edge(Direction::Down),
+        };
+
+        let result = ws.drop_active_tab(1, target);
+
+        assert!(matches!(result, MoveResult::MovedToNew { new_pane_id: 3, .. }));
+        assert_eq!(ws.active_pane_id, 3);
+        assert_eq!(ws.pane_root.pane_count(), 3);
+        assert_eq!(ws.pane_root.get_pane(1).unwrap().tab_count(), 1);
+    }
+
+    #[test]
+    fn test_drop_active_tab_rejected_keeps_focus() {
+        use crate::pane_layout::{DropZone, MoveResult, TabDropTarget};
+
+        let mut ws = create_hsplit_workspace();
+        let target = TabDropTarget {
+            pane_id: 1,
+            zone: DropZone::Center,
+        };
+
+        assert_eq!(ws.drop_active_tab(1, target), MoveResult::Rejected);
+        assert_eq!(ws.active_pane_id, 1);
+        assert_eq!(ws.pane_root.get_pane(1).unwrap().tab_count(), 2);
+    }
+
+    // =========================================================================
+    // Pane zoom Tests (Chunk: docs/chunks/pane_zoom)
+    // =========================================================================
+
+    #[test]
+    fn test_toggle_pane_zoom() {
+        let mut ws = create_hsplit_workspace();
+
+        assert!(ws.toggle_pane_zoom());
+        assert_eq!(ws.zoomed_pane_id(), Some(1));
+
+        let rects = ws.visible_pane_rects((0.0, 0.0, 800.0, 600.0));
+        assert_eq!(rects.len(), 1);
+        assert_eq!(rects[0].pane_id, 1);
+        assert_eq!(rects[0].width, 800.0);
+
+        assert!(!ws.toggle_pane_zoom());
+        assert_eq!(ws.zoomed_pane_id(), None);
+        assert_eq!(ws.visible_pane_rects((0.0, 0.0, 800.0, 600.0)).len(), 2);
+        // The layout tree was never touched
+        assert_eq!(ws.pane_root.pane_count(), 2);
+    }
+
+    #[test]
+    fn test_single_pane_cannot_zoom() {
+        let mut ws = Workspace::new(1, "test".to_string(), PathBuf::from("/test"));
+        assert!(!ws.toggle_pane_zoom());
+        assert_eq!(ws.zoomed_pane_id(), None);
+    }
+
+    #[test]
+    fn test_zoomed_pane_hit_covers_pane_area() {
+        let mut ws = create_hsplit_workspace();
+        ws.active_pane_id = 2;
+        ws.toggle_pane_zoom();
+
+        // A point in the left half now lands on the zoomed right pane
+        let hit = ws.resolve_pane_hit(100.0, 300.0, (0.0, 0.0, 800.0, 600.0), 32.0).unwrap();
+        assert_eq!(hit.pane_id, 2);
+        assert_eq!(hit.local_x, 100.0);
+    }
+
+    #[test]
+    fn test_switch_focus_unzooms() {
+        let mut ws = create_hsplit_workspace();
+        ws.toggle_pane_zoom();
+
+        assert!(ws.switch_focus(Direction::Right));
+        assert_eq!(ws.zoomed_pane_id(), None);
+
+        // Focusing back doesn't bring the zoom back
+        assert!(ws.switch_focus(Direction::Left));
+        assert_eq!(ws.zoomed_pane_id(), None);
+    }
+
+    #[test]
+    fn test_move_tab_unzooms() {
+        let mut ws = create_hsplit_workspace();
+        ws.toggle_pane_zoom();
+
+        ws.move_active_tab(Direction::Right);
+
+        assert_eq!(ws.zoomed_pane_id(), None);
+    }
+
     // =========================================================================
     // find_fallback_focus Tests (Chunk: docs/chunks/pane_close_last_tab)
     // =========================================================================
@@ -2641,6 +5051,64 @@ mod tests {
         // (We test this indirectly by checking the stack works)
     }
 
+    // =========================================================================
+    // CursorHistory Tests (Chunk: docs/chunks/cursor_position_history)
+    // =========================================================================
+
+    #[test]
+    fn test_cursor_history_back_and_forward() {
+        let mut history = CursorHistory::new(10);
+        assert!(!history.can_go_back());
+
+        history.record(Position::new(0, 0));
+        history.record(Position::new(50, 3));
+
+        // At line 100: back goes to 50, then 0
+        assert_eq!(history.back(Position::new(100, 0)), Some(Position::new(50, 3)));
+        assert_eq!(history.back(Position::new(50, 3)), Some(Position::new(0, 0)));
+        assert_eq!(history.back(Position::new(0, 0)), None);
+
+        // Forward retraces the steps
+        assert!(history.can_go_forward());
+        assert_eq!(history.forward(Position::new(0, 0)), Some(Position::new(50, 3)));
+        assert_eq!(history.forward(Position::new(50, 3)), Some(Position::new(100, 0)));
+        assert_eq!(history.forward(Position::new(100, 0)), None);
+    }
+
+    #[test]
+    fn test_cursor_history_record_clears_forward() {
+        let mut history = CursorHistory::new(10);
+        history.record(Position::new(0, 0));
+        history.back(Position::new(40, 0));
+        assert!(history.can_go_forward());
+
+        history.record(Position::new(0, 0));
+        assert!(!history.can_go_forward());
+    }
+
+    #[test]
+    fn test_cursor_history_collapses_duplicates_and_bounds_size() {
+        let mut history = CursorHistory::new(2);
+        history.record(Position::new(5, 0));
+        history.record(Position::new(5, 0));
+        history.record(Position::new(30, 0));
+        history.record(Position::new(60, 0));
+
+        assert_eq!(history.back(Position::new(90, 0)), Some(Position::new(60, 0)));
+        assert_eq!(history.back(Position::new(60, 0)), Some(Position::new(30, 0)));
+        // Line 5 was evicted when the history reached capacity
+        assert_eq!(history.back(Position::new(30, 0)), None);
+    }
+
+    #[test]
+    fn test_cursor_history_significant_move_threshold() {
+        let from = Position::new(20, 0);
+        assert!(!CursorHistory::is_significant_move(from, Position::new(21, 4)));
+        assert!(!CursorHistory::is_significant_move(from, Position::new(20 + SIGNIFICANT_CURSOR_MOVE_LINES - 1, 0)));
+        assert!(CursorHistory::is_significant_move(from, Position::new(20 + SIGNIFICANT_CURSOR_MOVE_LINES, 0)));
+        assert!(CursorHistory::is_significant_move(from, Position::new(0, 0)));
+    }
+
     // =========================================================================
     // Indent Computation Tests (Chunk: docs/chunks/treesitter_indent)
     // =========================================================================
@@ -2879,4 +5347,41 @@ mod tests {
         // Verify unread was cleared
         assert!(!ws.active_tab().unwrap().unread, "Unread flag should be cleared after switching");
     }
+
+    // Chunk: docs/chunks/buffer_annotations - Problems published as file annotations
+    #[test]
+    fn test_set_problems_publishes_file_annotations() {
+        use crate::problem_matcher::Severity;
+        let mut ws = Workspace::new(1, "test".to_string(), PathBuf::from("/test"));
+        let problem = |path: &str, line| Problem {
+            path: PathBuf::from(path),
+            line,
+            column: 1,
+            severity: Severity::Error,
+            message: String::new(),
+        };
+        ws.set_problems(vec![problem("/test/a.rs", 3), problem("/test/b.rs", 1)]);
+
+        let a = ws.annotations_for(Path::new("/test/a.rs")).unwrap();
+        assert_eq!(a.from_source(PROBLEMS_SOURCE).len(), 1);
+        let mut dirty = ws.take_annotation_dirty();
+        dirty.sort_by(|x, y| x.0.cmp(&y.0));
+        assert_eq!(
+            dirty,
+            vec![
+                (PathBuf::from("/test/a.rs"), DirtyLines::Single(2)),
+                (PathBuf::from("/test/b.rs"), DirtyLines::Single(0)),
+            ]
+        );
+
+        // A file with no problems left loses its annotations
+        ws.set_problems(vec![problem("/test/a.rs", 3)]);
+        let b = ws.annotations_for(Path::new("/test/b.rs")).unwrap();
+        assert!(b.is_empty());
+        let b_dirty = ws
+            .take_annotation_dirty()
+            .into_iter()
+            .find(|(path, _)| path.ends_with("b.rs"));
+        assert_eq!(b_dirty.map(|(_, dirty)| dirty), Some(DirtyLines::Single(0)));
+    }
 }
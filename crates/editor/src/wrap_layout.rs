@@ -211,11 +211,15 @@ impl WrapLayout {
     ///
     /// # Returns
     /// The character index in the buffer line. If the visual column falls inside a tab
-    /// character's span, returns the tab's character index.
+    /// or wide character's span, returns that character's index. The result is snapped
+    /// to the start of its grapheme cluster, so clicks never land inside a combining
+    /// sequence or ZWJ emoji.
+    // Chunk: docs/chunks/unicode_width_awareness - Grapheme-snapped hit-testing
     #[inline]
     pub fn screen_pos_to_char_col(&self, line: &str, row_offset: usize, screen_col: usize) -> usize {
         let visual_col = self.screen_pos_to_buffer_col(row_offset, screen_col);
-        tab_width::visual_col_to_char_col(line, visual_col)
+        let chars: Vec<char> = line.chars().collect();
+        lite_edit_buffer::char_col_for_display_col(&chars, visual_col)
     }
 
     // Chunk: docs/chunks/line_wrap_rendering - Continuation row detection
@@ -568,4 +572,30 @@ mod tests {
         assert_eq!(layout.screen_pos_to_char_col("a\tb", 0, 3), 1); // inside tab
         assert_eq!(layout.screen_pos_to_char_col("a\tb", 0, 4), 2); // 'b'
     }
+
+    // Chunk: docs/chunks/unicode_width_awareness - Wide and combining character hit-testing
+    #[test]
+    fn test_screen_pos_to_char_col_wide_chars() {
+        let layout = WrapLayout::new(80.0, &test_metrics()); // 10 cols
+        // "中文ab": 中 spans cols 0-1, 文 spans cols 2-3
+        assert_eq!(layout.screen_pos_to_char_col("中文ab", 0, 1), 0);
+        assert_eq!(layout.screen_pos_to_char_col("中文ab", 0, 2), 1);
+        assert_eq!(layout.screen_pos_to_char_col("中文ab", 0, 4), 2);
+    }
+
+    #[test]
+    fn test_screen_pos_to_char_col_never_splits_cluster() {
+        let layout = WrapLayout::new(80.0, &test_metrics()); // 10 cols
+        // The woman emoji of a ZWJ sequence starts at col 2 but belongs to the cluster
+        assert_eq!(layout.screen_pos_to_char_col("👨\u{200D}👩x", 0, 2), 0);
+        // 'x' after "e + combining acute" is at col 1, char 2
+        assert_eq!(layout.screen_pos_to_char_col("e\u{0301}x", 0, 1), 2);
+    }
+
+    #[test]
+    fn test_screen_rows_for_line_content_wide_chars() {
+        let layout = WrapLayout::new(80.0, &test_metrics()); // 10 cols
+        // 6 CJK chars = 12 columns = 2 rows, though only 6 chars
+        assert_eq!(layout.screen_rows_for_line_content("一二三四五六"), 2);
+    }
 }
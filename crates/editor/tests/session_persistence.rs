@@ -19,6 +19,16 @@ use lite_edit_buffer::TextBuffer;
 
 const TEST_LINE_HEIGHT: f32 = 16.0;
 
+/// Session data for a file tab.
+fn file_tab(file_path: std::path::PathBuf) -> TabData {
+    TabData {
+        file_path,
+        scratch: None,
+        terminal: None,
+        scroll_offset_px: 0.0,
+    }
+}
+
 /// Tests that an Editor with multiple workspaces and tabs round-trips through
 /// save/restore correctly.
 #[test]
@@ -145,12 +155,12 @@ fn test_split_layout_persistence() {
                 ratio: 0.5,
                 first: Box::new(PaneLayoutData::Leaf(PaneData {
                     id: 1,
-                    tabs: vec![TabData { file_path: file1.clone() }],
+                    tabs: vec![file_tab(file1.clone())],
                     active_tab: 0,
                 })),
                 second: Box::new(PaneLayoutData::Leaf(PaneData {
                     id: 2,
-                    tabs: vec![TabData { file_path: file2.clone() }],
+                    tabs: vec![file_tab(file2.clone())],
                     active_tab: 0,
                 })),
             },
@@ -216,8 +226,8 @@ fn test_partial_restoration() {
                 pane_root: PaneLayoutData::Leaf(PaneData {
                     id: 0,
                     tabs: vec![
-                        TabData { file_path: valid_file.clone() },
-                        TabData { file_path: missing_file },
+                        file_tab(valid_file.clone()),
+                        file_tab(missing_file),
                     ],
                     active_tab: 0,
                 }),
@@ -315,9 +325,9 @@ fn test_active_tab_preservation() {
             pane_root: PaneLayoutData::Leaf(PaneData {
                 id: 0,
                 tabs: vec![
-                    TabData { file_path: file1.clone() },
-                    TabData { file_path: file2.clone() },
-                    TabData { file_path: file3.clone() },
+                    file_tab(file1.clone()),
+                    file_tab(file2.clone()),
+                    file_tab(file3.clone()),
                 ],
                 active_tab: 1, // Second tab is active
             }),
@@ -365,7 +375,7 @@ fn test_nested_split_persistence() {
                 ratio: 0.5,
                 first: Box::new(PaneLayoutData::Leaf(PaneData {
                     id: 1,
-                    tabs: vec![TabData { file_path: file_a.clone() }],
+                    tabs: vec![file_tab(file_a.clone())],
                     active_tab: 0,
                 })),
                 second: Box::new(PaneLayoutData::Split {
@@ -373,12 +383,12 @@ fn test_nested_split_persistence() {
                     ratio: 0.5,
                     first: Box::new(PaneLayoutData::Leaf(PaneData {
                         id: 2,
-                        tabs: vec![TabData { file_path: file_b.clone() }],
+                        tabs: vec![file_tab(file_b.clone())],
                         active_tab: 0,
                     })),
                     second: Box::new(PaneLayoutData::Leaf(PaneData {
                         id: 3,
-                        tabs: vec![TabData { file_path: file_c.clone() }],
+                        tabs: vec![file_tab(file_c.clone())],
                         active_tab: 0,
                     })),
                 }),
@@ -475,7 +485,7 @@ fn test_empty_pane_restore_no_id_collision() {
                 ratio: 0.5,
                 first: Box::new(PaneLayoutData::Leaf(PaneData {
                     id: 1,
-                    tabs: vec![TabData { file_path: file_left.clone() }],
+                    tabs: vec![file_tab(file_left.clone())],
                     active_tab: 0,
                 })),
                 second: Box::new(PaneLayoutData::Leaf(PaneData {
@@ -550,8 +560,8 @@ fn test_create_pane_after_restore() {
                 first: Box::new(PaneLayoutData::Leaf(PaneData {
                     id: 1,
                     tabs: vec![
-                        TabData { file_path: file1.clone() },
-                        TabData { file_path: file2.clone() },
+                        file_tab(file1.clone()),
+                        file_tab(file2.clone()),
                     ],
                     active_tab: 1, // Second tab active
                 })),
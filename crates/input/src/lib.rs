@@ -34,6 +34,9 @@ pub struct KeyEvent {
     pub key: Key,
     /// Modifier keys held during the event
     pub modifiers: Modifiers,
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Auto-repeat flag
+    /// True when the system generated this event by auto-repeat while the key is held
+    pub repeat: bool,
 }
 
 // Chunk: docs/chunks/unicode_ime_input - NSTextInputClient for IME support
@@ -56,6 +59,12 @@ pub struct TextInputEvent {
     /// Optional range to replace (for IME replacement). None = insert at cursor.
     /// The range is in buffer character offsets from the start of the document.
     pub replacement_range: Option<std::ops::Range<usize>>,
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Accent replaces the held key's character
+    /// Number of characters immediately before the cursor that this text replaces.
+    ///
+    /// The press-and-hold accent popover inserts the held key's character first,
+    /// then replaces it with the chosen accent.
+    pub replaced_before_cursor: usize,
 }
 
 impl TextInputEvent {
@@ -64,6 +73,7 @@ impl TextInputEvent {
         Self {
             text: text.into(),
             replacement_range: None,
+            replaced_before_cursor: 0,
         }
     }
 
@@ -72,6 +82,17 @@ impl TextInputEvent {
         Self {
             text: text.into(),
             replacement_range: Some(range),
+            replaced_before_cursor: 0,
+        }
+    }
+
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Accent replaces the held key's character
+    /// Creates a text input event that replaces the `count` characters before the cursor.
+    pub fn replacing_before_cursor(text: impl Into<String>, count: usize) -> Self {
+        Self {
+            text: text.into(),
+            replacement_range: None,
+            replaced_before_cursor: count,
         }
     }
 }
@@ -130,7 +151,18 @@ impl MarkedTextEvent {
 impl KeyEvent {
     /// Creates a new KeyEvent with the given key and modifiers.
     pub fn new(key: Key, modifiers: Modifiers) -> Self {
-        Self { key, modifiers }
+        Self {
+            key,
+            modifiers,
+            repeat: false,
+        }
+    }
+
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Auto-repeat flag
+    /// Returns this event marked as an auto-repeat (or not).
+    pub fn with_repeat(mut self, repeat: bool) -> Self {
+        self.repeat = repeat;
+        self
     }
 
     /// Creates a KeyEvent for a single character with no modifiers.
@@ -138,6 +170,7 @@ impl KeyEvent {
         Self {
             key: Key::Char(ch),
             modifiers: Modifiers::default(),
+            repeat: false,
         }
     }
 
@@ -149,6 +182,7 @@ impl KeyEvent {
                 shift: true,
                 ..Default::default()
             },
+            repeat: false,
         }
     }
 }
@@ -258,6 +292,13 @@ pub struct ScrollDelta {
     /// as mouse events: origin at top-left, y increasing downward, in pixel units.
     /// Used for hover-scroll behavior in multi-pane layouts.
     pub mouse_position: Option<(f64, f64)>,
+    // Chunk: docs/chunks/smooth_scroll_animation - Animated scroll mode
+    /// Whether the scroll should ease toward its destination over a few frames
+    /// instead of jumping there.
+    ///
+    /// Set for coarse, line-based deltas (mouse wheel ticks). Trackpad deltas
+    /// are already smoothed and given momentum by the OS, so they apply directly.
+    pub animate: bool,
 }
 
 impl ScrollDelta {
@@ -269,6 +310,7 @@ impl ScrollDelta {
             dx,
             dy,
             mouse_position: None,
+            animate: false,
         }
     }
 
@@ -281,8 +323,16 @@ impl ScrollDelta {
             dx,
             dy,
             mouse_position: Some((x, y)),
+            animate: false,
         }
     }
+
+    // Chunk: docs/chunks/smooth_scroll_animation - Animated scroll mode
+    /// Returns this delta with `animate` set.
+    pub fn animated(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
 }
 
 /// A mouse event.
@@ -297,6 +347,36 @@ pub struct MouseEvent {
     // Chunk: docs/chunks/word_double_click_select - Double-click word selection
     /// Number of consecutive clicks (1 for single, 2 for double, etc.)
     pub click_count: u32,
+    // Chunk: docs/chunks/mouse_buttons - Which button a mouse event is for
+    /// The button pressed, released or held while moving
+    pub button: MouseButton,
+}
+
+// Chunk: docs/chunks/mouse_buttons - Which button a mouse event is for
+/// A mouse button.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MouseButton {
+    /// The primary button
+    #[default]
+    Left,
+    /// The secondary button
+    Right,
+    /// The middle button (or wheel click)
+    Middle,
+    /// Any other button, by AppKit button number (3 and up)
+    Other(u8),
+}
+
+impl MouseButton {
+    /// Returns the button with AppKit button number `number`.
+    pub fn from_number(number: isize) -> Self {
+        match number {
+            0 => MouseButton::Left,
+            1 => MouseButton::Right,
+            2 => MouseButton::Middle,
+            n => MouseButton::Other(u8::try_from(n).unwrap_or(u8::MAX)),
+        }
+    }
 }
 
 /// Kind of mouse event.
@@ -308,6 +388,15 @@ pub enum MouseEventKind {
     Up,
     /// Mouse moved (with button held for drag)
     Moved,
+    // Chunk: docs/chunks/cursor_position_history - Back/forward navigation events
+    /// Navigate back (mouse button 4, or a trackpad swipe right)
+    Back,
+    /// Navigate forward (mouse button 5, or a trackpad swipe left)
+    Forward,
+    // Chunk: docs/chunks/hover_tracking - Mouse moves with no button held
+    /// Mouse moved with no button held. Also sent when the mouse leaves the
+    /// view, with its position outside the view.
+    Hover,
 }
 
 // Chunk: docs/chunks/pty_wakeup_reentrant - WakeupSignal trait for cross-crate PTY wakeup
@@ -412,6 +501,23 @@ mod tests {
         assert_eq!(event.replacement_range, Some(0..4));
     }
 
+    // Chunk: docs/chunks/press_and_hold_key_repeat - Replacement before the cursor
+    #[test]
+    fn test_text_input_event_replacing_before_cursor() {
+        let event = TextInputEvent::replacing_before_cursor("é", 1);
+        assert_eq!(event.text, "é");
+        assert_eq!(event.replaced_before_cursor, 1);
+        assert!(event.replacement_range.is_none());
+        assert_eq!(TextInputEvent::new("e").replaced_before_cursor, 0);
+    }
+
+    #[test]
+    fn test_key_event_repeat() {
+        let event = KeyEvent::char('e');
+        assert!(!event.repeat);
+        assert!(event.with_repeat(true).repeat);
+    }
+
     #[test]
     fn test_text_input_event_unicode() {
         let event = TextInputEvent::new("日本語");
@@ -1455,6 +1455,71 @@ impl SyntaxHighlighter {
         &self.tree
     }
 
+    // Chunk: docs/chunks/spell_check - Comment and string columns for spell checking
+    /// Returns the columns covered by captures of the given `kinds` in lines
+    /// `start_line..end_line`.
+    ///
+    /// A capture matches a kind if its name is the kind or starts with the
+    /// kind and a dot, so `"comment"` also matches `comment.documentation`.
+    /// Each entry is `(line, start_col, end_col)` with columns in characters;
+    /// a capture spanning several lines yields one entry per line. Only the
+    /// host language is searched, not injected languages.
+    pub fn capture_columns(
+        &self,
+        start_line: usize,
+        end_line: usize,
+        kinds: &[&str],
+    ) -> Vec<(usize, usize, usize)> {
+        let end_line = end_line.min(self.line_count());
+        if start_line >= end_line {
+            return Vec::new();
+        }
+        let start_byte = self.line_byte_range(start_line).map_or(0, |(s, _)| s);
+        let end_byte = self
+            .line_byte_range(end_line - 1)
+            .map_or(self.source.len(), |(_, e)| e);
+        let wanted: Vec<bool> = self
+            .query
+            .capture_names()
+            .iter()
+            .map(|name| {
+                kinds.iter().any(|kind| {
+                    name.strip_prefix(kind)
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+                })
+            })
+            .collect();
+
+        let mut columns = Vec::new();
+        let mut cursor = QueryCursor::new();
+        cursor.set_byte_range(start_byte..end_byte);
+        let root = self.tree.root_node();
+        let mut captures = cursor.captures(&self.query, root, self.source.as_bytes());
+        while let Some((mat, capture_idx)) = captures.next() {
+            let capture = &mat.captures[*capture_idx];
+            if !wanted[capture.index as usize] {
+                continue;
+            }
+            let node = capture.node;
+            let first = node.start_position().row.max(start_line);
+            let last = node.end_position().row.min(end_line - 1);
+            for line in first..=last {
+                let Some((line_start, line_end)) = self.line_byte_range(line) else {
+                    continue;
+                };
+                let start = node.start_byte().clamp(line_start, line_end);
+                let end = node.end_byte().clamp(line_start, line_end);
+                if start < end {
+                    let col = |byte: usize| self.source[line_start..byte].chars().count();
+                    columns.push((line, col(start), col(end)));
+                }
+            }
+        }
+        columns.sort_unstable();
+        columns.dedup();
+        columns
+    }
+
     // Chunk: docs/chunks/highlight_text_source - Buffer-sourced span generation
     /// Returns style spans for a line using externally-provided text content.
     ///
@@ -3060,4 +3125,20 @@ date: 2024-01-01
             title_span.style
         );
     }
+
+    // Chunk: docs/chunks/spell_check - Comment and string columns
+    #[test]
+    fn test_capture_columns_finds_comments_and_strings() {
+        let source = "// a note\nfn main() {\n    let s = \"hello\"; /* more */\n}";
+        let hl = make_rust_highlighter(source).unwrap();
+
+        let columns = hl.capture_columns(0, 4, &["comment", "string"]);
+        assert!(columns.contains(&(0, 0, 9)), "got {:?}", columns);
+        assert!(columns.contains(&(2, 12, 19)), "got {:?}", columns);
+        assert!(columns.contains(&(2, 21, 31)), "got {:?}", columns);
+        assert!(columns.iter().all(|(line, _, _)| *line != 1 && *line != 3));
+
+        // Lines outside the range are skipped
+        assert_eq!(hl.capture_columns(1, 2, &["comment", "string"]), vec![]);
+    }
 }
@@ -41,6 +41,8 @@ mod highlighter;
 pub mod queries;
 // Chunk: docs/chunks/treesitter_indent - Indent computation module
 mod indent;
+// Chunk: docs/chunks/pane_breadcrumbs - Document outline from tags queries
+mod outline;
 mod registry;
 // Chunk: docs/chunks/treesitter_symbol_index - Cross-file symbol index
 mod symbol_index;
@@ -52,8 +54,10 @@ pub use gotodef::{identifier_at_position, LocalsResolver};
 pub use highlighter::SyntaxHighlighter;
 // Chunk: docs/chunks/treesitter_indent - Export indent types
 pub use indent::{IndentComputer, IndentConfig};
+// Chunk: docs/chunks/pane_breadcrumbs - Export outline types
+pub use outline::{OutlineSymbol, SymbolOutliner};
 pub use registry::{LanguageConfig, LanguageRegistry};
 // Chunk: docs/chunks/treesitter_symbol_index - Export symbol index types
 pub use symbol_index::{SymbolIndex, SymbolKind, SymbolLocation};
-pub use theme::SyntaxTheme;
+pub use theme::{StyleModifiers, SyntaxTheme};
 
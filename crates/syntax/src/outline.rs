@@ -0,0 +1,215 @@
+// Chunk: docs/chunks/pane_breadcrumbs - Document outline from tags queries
+//!
+//! The definitions in one document, from the tree-sitter `tags.scm` query
+//! that also feeds the workspace `SymbolIndex`.
+//!
+//! [`SymbolOutliner`] compiles a language's tags query once and answers two
+//! questions about a parsed document: which definitions it contains (the
+//! outline), and which definitions enclose a given byte offset (the trail of
+//! symbols shown in a pane's breadcrumbs).
+
+use std::ops::Range;
+
+use streaming_iterator::StreamingIterator;
+use tree_sitter::{Language, Node, Query, QueryCursor, QueryMatch, Tree};
+
+use crate::symbol_index::SymbolKind;
+
+/// A definition found in a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineSymbol {
+    /// The symbol's name
+    pub name: String,
+    /// The kind of definition
+    pub kind: SymbolKind,
+    /// Byte range of the whole definition, body included
+    pub range: Range<usize>,
+    /// Byte offset of the symbol's name
+    pub name_byte: usize,
+    /// Number of definitions enclosing this one
+    pub depth: usize,
+}
+
+/// Finds definitions in documents of one language.
+pub struct SymbolOutliner {
+    query: Query,
+}
+
+impl SymbolOutliner {
+    /// Compiles `tags_query` for `language`.
+    ///
+    /// Returns `None` when the language has no tags query or it doesn't
+    /// compile.
+    pub fn new(language: &Language, tags_query: &str) -> Option<Self> {
+        if tags_query.is_empty() {
+            return None;
+        }
+        let query = Query::new(language, tags_query).ok()?;
+        Some(Self { query })
+    }
+
+    /// Returns every definition in the document, in document order, with
+    /// each definition's depth set.
+    pub fn outline(&self, tree: &Tree, source: &[u8]) -> Vec<OutlineSymbol> {
+        let mut symbols = self.definitions(tree.root_node(), source);
+        let mut open: Vec<Range<usize>> = Vec::new();
+        for symbol in &mut symbols {
+            while open
+                .last()
+                .is_some_and(|outer| symbol.range.end > outer.end)
+            {
+                open.pop();
+            }
+            symbol.depth = open.len();
+            open.push(symbol.range.clone());
+        }
+        symbols
+    }
+
+    /// Returns the definitions enclosing `byte_offset`, outermost first.
+    ///
+    /// Only the top-level node containing the offset is searched, so this is
+    /// cheap enough to run on every cursor move.
+    pub fn enclosing_symbols(
+        &self,
+        tree: &Tree,
+        source: &[u8],
+        byte_offset: usize,
+    ) -> Vec<OutlineSymbol> {
+        let root = tree.root_node();
+        let Some(mut top_level) = root.descendant_for_byte_range(byte_offset, byte_offset) else {
+            return Vec::new();
+        };
+        while let Some(parent) = top_level.parent() {
+            if parent.id() == root.id() {
+                break;
+            }
+            top_level = parent;
+        }
+
+        let mut symbols: Vec<OutlineSymbol> = self
+            .definitions(top_level, source)
+            .into_iter()
+            .filter(|symbol| symbol.range.start <= byte_offset && byte_offset < symbol.range.end)
+            .collect();
+        for (depth, symbol) in symbols.iter_mut().enumerate() {
+            symbol.depth = depth;
+        }
+        symbols
+    }
+
+    /// Returns the definitions at or under `node`, ordered by start and then
+    /// outermost first.
+    ///
+    /// A definition matched by more than one pattern (a Rust method is also a
+    /// function) appears once.
+    fn definitions(&self, node: Node, source: &[u8]) -> Vec<OutlineSymbol> {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&self.query, node, source);
+        let mut symbols = Vec::new();
+        while let Some(query_match) = matches.next() {
+            if let Some(symbol) = self.symbol_from_match(query_match, source) {
+                symbols.push(symbol);
+            }
+        }
+        symbols.sort_by_key(|symbol| (symbol.range.start, std::cmp::Reverse(symbol.range.end)));
+        symbols.dedup_by(|a, b| a.range == b.range);
+        symbols
+    }
+
+    /// Returns the definition captured by `query_match`, if it is one.
+    fn symbol_from_match(&self, query_match: &QueryMatch, source: &[u8]) -> Option<OutlineSymbol> {
+        let mut name = None;
+        let mut definition = None;
+        for capture in query_match.captures {
+            let capture_name = self.query.capture_names()[capture.index as usize];
+            if capture_name == "name" {
+                name = Some(capture.node);
+            } else if capture_name.starts_with("definition.") {
+                let kind = SymbolKind::from_capture_name(capture_name)?;
+                definition = Some((capture.node, kind));
+            }
+        }
+        let (name, (definition, kind)) = (name?, definition?);
+        Some(OutlineSymbol {
+            name: name.utf8_text(source).ok()?.to_string(),
+            kind,
+            range: definition.byte_range(),
+            name_byte: name.start_byte(),
+            depth: 0,
+        })
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::LanguageRegistry;
+    use tree_sitter::Parser;
+
+    const SOURCE: &str = "\
+struct Point {
+    x: i32,
+}
+
+impl Point {
+    fn new() -> Self {
+        Point { x: 0 }
+    }
+}
+
+fn main() {}
+";
+
+    fn parse(source: &str) -> (SymbolOutliner, Tree) {
+        let registry = LanguageRegistry::new();
+        let config = registry.config_for_extension("rs").unwrap();
+        let mut parser = Parser::new();
+        parser.set_language(&config.language).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        let outliner = SymbolOutliner::new(&config.language, config.tags_query).unwrap();
+        (outliner, tree)
+    }
+
+    #[test]
+    fn test_outline_lists_definitions_in_order() {
+        let (outliner, tree) = parse(SOURCE);
+        let outline = outliner.outline(&tree, SOURCE.as_bytes());
+        let names: Vec<(&str, usize)> = outline
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.depth))
+            .collect();
+        assert_eq!(names, vec![("Point", 0), ("new", 0), ("main", 0)]);
+        assert_eq!(outline[1].name_byte, SOURCE.find("new").unwrap());
+    }
+
+    #[test]
+    fn test_enclosing_symbols() {
+        let source = "mod shapes {\n    fn area() {\n        let x = 1;\n    }\n}\n";
+        let (outliner, tree) = parse(source);
+        let offset = source.find("let x").unwrap();
+        let names: Vec<String> = outliner
+            .enclosing_symbols(&tree, source.as_bytes(), offset)
+            .into_iter()
+            .map(|symbol| symbol.name)
+            .collect();
+        assert_eq!(names, vec!["shapes", "area"]);
+
+        // Between definitions nothing encloses the offset
+        let (outliner, tree) = parse(SOURCE);
+        let offset = SOURCE.find("\nfn main").unwrap();
+        let symbols = outliner.enclosing_symbols(&tree, SOURCE.as_bytes(), offset);
+        assert!(symbols.is_empty());
+    }
+
+    #[test]
+    fn test_no_outliner_without_tags_query() {
+        let registry = LanguageRegistry::new();
+        let config = registry.config_for_extension("cpp").unwrap();
+        assert!(SymbolOutliner::new(&config.language, config.tags_query).is_none());
+    }
+}
@@ -9,6 +9,7 @@
 
 use crate::queries;
 use std::collections::HashMap;
+use std::sync::OnceLock;
 use tree_sitter::Language;
 
 /// Configuration for a language's syntax highlighting.
@@ -69,9 +70,14 @@ impl LanguageConfig {
 ///
 /// Supports 13 languages: Rust, C++, C, Python, TypeScript, JavaScript,
 /// Go, JSON, TOML, Markdown, HTML, CSS, and Bash.
+///
+/// The language configs are built on first lookup rather than in `new()`, so
+/// creating a registry at startup is free. Call `warm_up()` from a background
+/// thread to build them ahead of the first lookup.
 pub struct LanguageRegistry {
+    // Chunk: docs/chunks/lazy_subsystem_init - Configs built on first lookup
     /// Map from extension (without leading dot) to language config
-    configs: HashMap<&'static str, LanguageConfig>,
+    configs: OnceLock<HashMap<&'static str, LanguageConfig>>,
 }
 
 impl LanguageRegistry {
@@ -82,12 +88,40 @@ impl LanguageRegistry {
     /// the overhead of initializing all language configs.
     pub fn empty() -> Self {
         Self {
-            configs: HashMap::new(),
+            configs: OnceLock::from(HashMap::new()),
         }
     }
 
     /// Creates a new language registry with all supported languages.
+    ///
+    /// The language configs are not built until the first lookup (or `warm_up()`).
     pub fn new() -> Self {
+        Self {
+            configs: OnceLock::new(),
+        }
+    }
+
+    // Chunk: docs/chunks/lazy_subsystem_init - Build configs ahead of first lookup
+    /// Builds the language configs now if they haven't been built yet.
+    ///
+    /// Safe to call from any thread; a lookup that races with the warm-up
+    /// waits for it instead of building the configs twice.
+    pub fn warm_up(&self) {
+        self.configs();
+    }
+
+    /// Returns true if the language configs have been built.
+    pub fn is_warm(&self) -> bool {
+        self.configs.get().is_some()
+    }
+
+    /// Returns the extension-to-config map, building it on first use.
+    fn configs(&self) -> &HashMap<&'static str, LanguageConfig> {
+        self.configs.get_or_init(Self::build_configs)
+    }
+
+    /// Builds the configs for all supported languages.
+    fn build_configs() -> HashMap<&'static str, LanguageConfig> {
         let mut configs = HashMap::new();
 
         // Rust (uses HIGHLIGHTS_QUERY and custom locals query for go-to-def)
@@ -351,7 +385,7 @@ impl LanguageRegistry {
         configs.insert("bash", bash_config.clone());
         configs.insert("zsh", bash_config);
 
-        Self { configs }
+        configs
     }
 
     /// Returns the language configuration for a file extension.
@@ -359,12 +393,12 @@ impl LanguageRegistry {
     /// The extension can be with or without a leading dot (e.g., ".rs" or "rs").
     pub fn config_for_extension(&self, ext: &str) -> Option<&LanguageConfig> {
         let ext = ext.strip_prefix('.').unwrap_or(ext);
-        self.configs.get(ext)
+        self.configs().get(ext)
     }
 
     /// Returns an iterator over all supported extensions.
     pub fn supported_extensions(&self) -> impl Iterator<Item = &str> {
-        self.configs.keys().copied()
+        self.configs().keys().copied()
     }
 
     // Chunk: docs/chunks/highlight_injection - Language name lookup for injection support
@@ -454,6 +488,21 @@ impl Clone for LanguageConfig {
 mod tests {
     use super::*;
 
+    // Chunk: docs/chunks/lazy_subsystem_init - Configs are built on first lookup
+    #[test]
+    fn test_configs_built_lazily() {
+        let registry = LanguageRegistry::new();
+        assert!(!registry.is_warm());
+
+        assert!(registry.config_for_extension("rs").is_some());
+        assert!(registry.is_warm());
+
+        let warmed = LanguageRegistry::new();
+        warmed.warm_up();
+        assert!(warmed.is_warm());
+        assert!(LanguageRegistry::empty().is_warm());
+    }
+
     #[test]
     fn test_rust_extension() {
         let registry = LanguageRegistry::new();
@@ -86,7 +86,7 @@ impl SymbolKind {
     /// Tags capture names follow the pattern `@definition.{kind}` or just
     /// `@name` when nested inside a definition pattern.
     // Chunk: docs/chunks/gotodef_index_captures - Filter reference captures, fix method interleaving
-    fn from_capture_name(name: &str) -> Option<Self> {
+    pub(crate) fn from_capture_name(name: &str) -> Option<Self> {
         // Handle both "definition.function" and "name" capture patterns
         let kind_str = if name.starts_with("definition.") {
             &name["definition.".len()..]
@@ -5,8 +5,12 @@
 //! This module provides a `SyntaxTheme` that maps tree-sitter capture names
 //! (e.g., "keyword", "string", "comment") to visual styles using the
 //! Catppuccin Mocha color palette.
+//!
+//! Weight, slant and underline can be changed per capture name with
+//! [`StyleModifiers`] (see [`SyntaxTheme::with_modifiers`]), leaving the
+//! palette's colors alone.
 
-use lite_edit_buffer::Style;
+use lite_edit_buffer::{Style, UnderlineStyle};
 use std::collections::HashMap;
 
 /// Catppuccin Mocha color palette constants.
@@ -85,11 +89,43 @@ mod catppuccin {
     }; // #a6adc8
 }
 
+// Chunk: docs/chunks/syntax_style_modifiers - Per-capture style modifiers
+/// Weight, slant and underline for a capture, layered over a theme's style.
+///
+/// `None` keeps the theme's own setting, so a modifier can turn an attribute
+/// off as well as on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StyleModifiers {
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub underline: Option<bool>,
+}
+
+impl StyleModifiers {
+    /// Applies the set modifiers to `style`.
+    pub fn apply(&self, style: &mut Style) {
+        if let Some(bold) = self.bold {
+            style.bold = bold;
+        }
+        if let Some(italic) = self.italic {
+            style.italic = italic;
+        }
+        if let Some(underline) = self.underline {
+            style.underline = if underline {
+                UnderlineStyle::Single
+            } else {
+                UnderlineStyle::None
+            };
+        }
+    }
+}
+
 /// A mapping from tree-sitter capture names to visual styles.
 ///
 /// The theme holds a map from capture name prefixes to `Style` values.
 /// When looking up a capture like "function.method", it first tries the
 /// exact match, then falls back to prefix matches ("function").
+#[derive(Debug, Clone)]
 pub struct SyntaxTheme {
     /// Map from capture name to style
     styles: HashMap<&'static str, Style>,
@@ -395,6 +431,31 @@ impl SyntaxTheme {
         }
     }
 
+    // Chunk: docs/chunks/syntax_style_modifiers - Per-capture style modifiers
+    /// Returns the theme with `modifiers` applied, by capture name.
+    ///
+    /// Modifiers for a name apply to its own style and to every more specific
+    /// one ("function" also changes "function.method"); the more specific
+    /// name's modifiers win. Names the theme has no style for are ignored.
+    pub fn with_modifiers<'a>(
+        mut self,
+        modifiers: impl IntoIterator<Item = (&'a str, StyleModifiers)>,
+    ) -> Self {
+        let mut modifiers: Vec<_> = modifiers.into_iter().collect();
+        modifiers.sort_by_key(|(name, _)| name.matches('.').count());
+        for (name, modifier) in modifiers {
+            for (capture, style) in self.styles.iter_mut() {
+                let applies = capture
+                    .strip_prefix(name)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'));
+                if applies {
+                    modifier.apply(style);
+                }
+            }
+        }
+        self
+    }
+
     /// Returns the style for a capture name, if defined.
     ///
     /// First tries an exact match, then tries prefix matching
@@ -555,6 +616,54 @@ mod tests {
         );
     }
 
+    // Chunk: docs/chunks/syntax_style_modifiers - Per-capture style modifiers
+    #[test]
+    fn test_modifiers_keep_colors() {
+        let stock = SyntaxTheme::catppuccin_mocha();
+        let theme = stock.clone().with_modifiers([
+            (
+                "keyword",
+                StyleModifiers {
+                    bold: Some(true),
+                    ..StyleModifiers::default()
+                },
+            ),
+            (
+                "comment",
+                StyleModifiers {
+                    italic: Some(false),
+                    ..StyleModifiers::default()
+                },
+            ),
+        ]);
+        let keyword = theme.style_for_capture("keyword").unwrap();
+        assert!(keyword.bold);
+        assert_eq!(keyword.fg, stock.style_for_capture("keyword").unwrap().fg);
+        assert!(!theme.style_for_capture("comment").unwrap().italic);
+        let documentation = theme.style_for_capture("comment.documentation").unwrap();
+        assert!(!documentation.italic);
+    }
+
+    #[test]
+    fn test_specific_modifiers_win() {
+        let underline = |on| StyleModifiers {
+            underline: Some(on),
+            ..StyleModifiers::default()
+        };
+        // Listed most specific first, applied least specific first
+        let theme = SyntaxTheme::catppuccin_mocha().with_modifiers([
+            ("function.method", underline(false)),
+            ("function", underline(true)),
+        ]);
+        let function = theme.style_for_capture("function").unwrap();
+        assert_eq!(function.underline, UnderlineStyle::Single);
+        let method = theme.style_for_capture("function.method").unwrap();
+        assert_eq!(method.underline, UnderlineStyle::None);
+        // Unrelated captures are untouched
+        let other = theme.style_for_capture("type").unwrap();
+        assert_eq!(other.underline, UnderlineStyle::None);
+    }
+
     #[test]
     fn test_comment_is_overlay0() {
         let theme = SyntaxTheme::catppuccin_mocha();
@@ -18,6 +18,18 @@
 //!                                  Exited
 //! ```
 //!
+//! Silence is a slow and imprecise signal, so `AgentConfig::input_patterns` can
+//! also recognize prompts directly: when output arrives and the lines around
+//! the cursor match one of the patterns (e.g. `Proceed? [y/n]`), the agent
+//! moves to NeedsInput immediately (Running ──(prompt)──> NeedsInput).
+//!
+//! Agents that can describe their own work run in structured mode
+//! (`AgentConfig::structured_events`) instead: they send [`AgentEvent`]s
+//! alongside their output, which are kept on the agent's [`AgentTimeline`]
+//! and replace the guesswork. A question moves the agent to NeedsInput, and
+//! only a progress or file edit event moves it back to Running; silence and
+//! prompt-like output change nothing.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -31,6 +43,11 @@
 //!     cwd: PathBuf::from("/home/user/project"),
 //!     needs_input_timeout: Duration::from_secs(5),
 //!     stale_timeout: Duration::from_secs(60),
+//!     input_patterns: AgentConfig::builtin_input_patterns("claude"),
+//!     transcript: None,
+//!     structured_events: false,
+//!     env: vec![],
+//!     initial_prompt: None,
 //! };
 //!
 //! let mut agent = AgentHandle::spawn(config, 80, 24).unwrap();
@@ -51,11 +68,47 @@
 //! }
 //! ```
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
+use regex::Regex;
+
+use crate::agent_events::{AgentEvent, AgentTimeline};
+use crate::input_encoder::InputEncoder;
+use crate::process_usage::{ResourceUsage, UsageSampler};
+use crate::transcript::{Transcript, TranscriptConfig};
 use crate::TerminalBuffer;
 
+// Chunk: docs/chunks/agent_input_patterns - Prompt detection window
+/// Number of lines, ending at the cursor line, that input patterns are matched against.
+const PROMPT_CONTEXT_LINES: usize = 6;
+
+/// Prompts recognized for any agent command: yes/no confirmations and
+/// "press enter" pauses.
+const GENERIC_INPUT_PATTERNS: &[&str] = &[
+    r"(?m)[\[(][yY]/[nN][\])]\s*:?\s*$",
+    r"(?im)press (enter|return) to continue",
+];
+
+/// Prompts specific to known agent CLIs, keyed by executable name.
+const AGENT_INPUT_PATTERNS: &[(&str, &[&str])] = &[
+    (
+        "claude",
+        &[
+            r"(?i)do you want to (proceed|make this edit[^?\n]*|create \S+|run this command)\?",
+            r"(?m)^\s*[❯>]\s*1\.\s*Yes\b",
+        ],
+    ),
+    (
+        "aider",
+        &[r"(?m)\(Y\)es/\(N\)o.*\[(Yes|No)\]:\s*$"],
+    ),
+    (
+        "codex",
+        &[r"(?i)(would you like to|allow (codex|command) to) (run|make|apply)"],
+    ),
+];
+
 // =============================================================================
 // AgentState
 // =============================================================================
@@ -123,6 +176,38 @@ pub struct AgentConfig {
     ///
     /// Default: 60 seconds.
     pub stale_timeout: Duration,
+    // Chunk: docs/chunks/agent_input_patterns - Configurable prompt detection
+    /// Patterns that identify an input prompt in the agent's recent output.
+    ///
+    /// When output arrives and the lines around the cursor match any of these,
+    /// the agent transitions to NeedsInput without waiting for
+    /// `needs_input_timeout`.
+    ///
+    /// Default: generic `[y/n]`-style prompts. `AgentConfig::new()` adds the
+    /// built-in patterns for known CLIs (claude, aider, codex).
+    pub input_patterns: Vec<Regex>,
+    // Chunk: docs/chunks/agent_transcript - Optional session transcript
+    /// Where to record a transcript of the agent's terminal session, if at all.
+    ///
+    /// Each spawn (including restarts) starts a new transcript file named
+    /// after the command. Default: None.
+    pub transcript: Option<TranscriptConfig>,
+    // Chunk: docs/chunks/agent_events - Structured agent events
+    /// Whether the agent reports its work as [`AgentEvent`]s, which then
+    /// drive its state in place of silence and input patterns.
+    ///
+    /// Default: false.
+    pub structured_events: bool,
+    // Chunk: docs/chunks/agent_templates - Environment and first prompt
+    /// Environment variables set for the agent on top of the editor's own.
+    ///
+    /// Default: none.
+    pub env: Vec<(String, String)>,
+    /// Text typed into the agent, as a paste followed by Return, the first
+    /// time it waits for input after each spawn.
+    ///
+    /// Default: None.
+    pub initial_prompt: Option<String>,
 }
 
 impl Default for AgentConfig {
@@ -133,19 +218,85 @@ impl Default for AgentConfig {
             cwd: PathBuf::from("."),
             needs_input_timeout: Duration::from_secs(5),
             stale_timeout: Duration::from_secs(60),
+            input_patterns: compile_patterns(GENERIC_INPUT_PATTERNS),
+            transcript: None,
+            structured_events: false,
+            env: Vec::new(),
+            initial_prompt: None,
         }
     }
 }
 
 impl AgentConfig {
     /// Creates a new agent config with the given command.
+    ///
+    /// The input patterns are the built-in ones for the command (see
+    /// `builtin_input_patterns()`).
     pub fn new(command: impl Into<String>) -> Self {
+        let command = command.into();
         Self {
-            command: command.into(),
+            input_patterns: Self::builtin_input_patterns(&command),
+            command,
             ..Default::default()
         }
     }
 
+    // Chunk: docs/chunks/agent_input_patterns - Built-in prompt patterns per CLI
+    /// Returns the built-in input patterns for `command`.
+    ///
+    /// These are the generic yes/no patterns plus the patterns for the agent CLI
+    /// named by the command's file name (`claude`, `aider` or `codex`), so
+    /// `/usr/local/bin/aider` gets aider's patterns too.
+    pub fn builtin_input_patterns(command: &str) -> Vec<Regex> {
+        let name = Path::new(command)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(command);
+
+        let mut patterns = compile_patterns(GENERIC_INPUT_PATTERNS);
+        for (agent, agent_patterns) in AGENT_INPUT_PATTERNS {
+            if *agent == name {
+                patterns.extend(compile_patterns(agent_patterns));
+            }
+        }
+        patterns
+    }
+
+    /// Adds an input pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid regular expression.
+    pub fn with_input_pattern(mut self, pattern: &str) -> Result<Self, regex::Error> {
+        self.input_patterns.push(Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    /// Replaces the input patterns.
+    pub fn with_input_patterns(mut self, patterns: Vec<Regex>) -> Self {
+        self.input_patterns = patterns;
+        self
+    }
+
+    /// Records a transcript of each session.
+    pub fn with_transcript(mut self, transcript: TranscriptConfig) -> Self {
+        self.transcript = Some(transcript);
+        self
+    }
+
+    // Chunk: docs/chunks/agent_events - Structured agent events
+    /// Runs the agent in structured mode, where the events it reports drive
+    /// its state.
+    pub fn with_structured_events(mut self) -> Self {
+        self.structured_events = true;
+        self
+    }
+
+    /// Returns true if any input pattern matches `recent_output`.
+    pub fn matches_input_prompt(&self, recent_output: &str) -> bool {
+        self.input_patterns.iter().any(|p| p.is_match(recent_output))
+    }
+
     /// Sets the arguments for the command.
     pub fn with_args(mut self, args: Vec<String>) -> Self {
         self.args = args;
@@ -169,6 +320,59 @@ impl AgentConfig {
         self.stale_timeout = timeout;
         self
     }
+
+    // Chunk: docs/chunks/agent_templates - Environment and first prompt
+    /// Sets an environment variable for the agent.
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Types `prompt` into the agent once it first waits for input.
+    pub fn with_initial_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.initial_prompt = Some(prompt.into());
+        self
+    }
+
+    /// Returns the program and arguments that start the agent. Environment
+    /// variables are set by running the command through `env`, which leaves
+    /// the terminal's own spawning untouched.
+    fn command_line(&self) -> (String, Vec<String>) {
+        if self.env.is_empty() {
+            return (self.command.clone(), self.args.clone());
+        }
+        let mut args: Vec<String> = self
+            .env
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        args.push(self.command.clone());
+        args.extend(self.args.iter().cloned());
+        ("env".to_string(), args)
+    }
+}
+
+// Chunk: docs/chunks/agent_transcript - One transcript per agent session
+/// Opens a transcript for a new session of `config`'s command, if configured.
+///
+/// Files are named after the command's file name, e.g. `claude-<timestamp>.cast`.
+fn open_transcript(config: &AgentConfig, cols: usize, rows: usize) -> std::io::Result<Option<Transcript>> {
+    let Some(transcript) = &config.transcript else {
+        return Ok(None);
+    };
+    let name = Path::new(&config.command)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("agent");
+    Transcript::create(transcript.clone(), name, cols, rows).map(Some)
+}
+
+/// Compiles the built-in patterns, which are known to be valid.
+fn compile_patterns(patterns: &[&str]) -> Vec<Regex> {
+    patterns
+        .iter()
+        .map(|p| Regex::new(p).expect("built-in input pattern must compile"))
+        .collect()
 }
 
 // =============================================================================
@@ -224,10 +428,16 @@ impl AgentStateMachine {
     /// - Starting → Running
     /// - NeedsInput → Running
     /// - Stale → Running (unlikely but possible)
+    ///
+    /// In structured mode output only ends Starting: an agent waiting for an
+    /// answer may well keep redrawing its screen.
     pub fn on_output(&mut self, now: Instant) {
         self.last_output_at = Some(now);
 
         match self.state {
+            // Chunk: docs/chunks/agent_events - Only events end a wait in structured mode
+            AgentState::NeedsInput { .. } | AgentState::Stale { .. }
+                if self.config.structured_events => {}
             AgentState::Starting | AgentState::NeedsInput { .. } | AgentState::Stale { .. } => {
                 self.state = AgentState::Running;
                 self.state_entered_at = now;
@@ -241,13 +451,58 @@ impl AgentStateMachine {
         }
     }
 
+    // Chunk: docs/chunks/agent_input_patterns - Immediate NeedsInput on a recognized prompt
+    /// Called instead of `on_output()` when the agent's recent output matches
+    /// an input pattern.
+    ///
+    /// Transitions:
+    /// - Starting → NeedsInput
+    /// - Running → NeedsInput
+    ///
+    /// NeedsInput and Stale are kept as they are: output that still shows the
+    /// prompt (e.g. a redraw) isn't the agent resuming work, so the stale
+    /// timeout keeps running from when the agent first started waiting.
+    pub fn on_input_prompt(&mut self, now: Instant) {
+        self.last_output_at = Some(now);
+
+        if self.state.is_active() {
+            self.state = AgentState::NeedsInput { since: now };
+            self.state_entered_at = now;
+        }
+    }
+
+    // Chunk: docs/chunks/agent_events - Reported events drive the state
+    /// Called when the agent reports an event in structured mode.
+    ///
+    /// Transitions:
+    /// - Starting, Running or Stale → NeedsInput on a question
+    /// - Starting, NeedsInput or Stale → Running on progress or a file edit
+    pub fn on_event(&mut self, event: &AgentEvent, now: Instant) {
+        self.last_output_at = Some(now);
+
+        let next = match event {
+            AgentEvent::Question { .. } => AgentState::NeedsInput { since: now },
+            AgentEvent::Progress { .. } | AgentEvent::FileEdit { .. } => AgentState::Running,
+        };
+        let unchanged = std::mem::discriminant(&next) == std::mem::discriminant(&self.state);
+        if !unchanged && !self.state.is_terminal() {
+            self.state = next;
+            self.state_entered_at = now;
+        }
+    }
+
     /// Called periodically to check for timeout-based transitions.
     ///
     /// Transitions:
     /// - Running → NeedsInput (after needs_input_timeout of silence)
     /// - NeedsInput → Stale (after stale_timeout)
+    ///
+    /// In structured mode silence is not a question, so only NeedsInput →
+    /// Stale applies.
     pub fn tick(&mut self, now: Instant) {
         match &self.state {
+            // Chunk: docs/chunks/agent_events - Silence means nothing in structured mode
+            AgentState::Starting | AgentState::Running if self.config.structured_events => {}
             AgentState::Starting => {
                 // Check if we've been starting too long (treat as NeedsInput)
                 if now.duration_since(self.state_entered_at) > self.config.needs_input_timeout {
@@ -308,6 +563,15 @@ pub struct AgentHandle {
     state_machine: AgentStateMachine,
     /// Configuration for this agent.
     config: AgentConfig,
+    // Chunk: docs/chunks/agent_resource_usage - CPU and memory of the agent's processes
+    /// Resource use of the agent's process tree.
+    usage: UsageSampler,
+    // Chunk: docs/chunks/agent_events - Structured agent events
+    /// Events the agent reported in structured mode.
+    timeline: AgentTimeline,
+    // Chunk: docs/chunks/agent_templates - Prompt typed once the agent asks
+    /// The configured initial prompt until it has been typed into this spawn.
+    pending_prompt: Option<String>,
 }
 
 impl AgentHandle {
@@ -326,16 +590,24 @@ impl AgentHandle {
         let mut terminal = TerminalBuffer::new(cols, rows, 5000);
 
         // Convert args to &str for spawn_command
-        let args_refs: Vec<&str> = config.args.iter().map(|s| s.as_str()).collect();
+        let (command, args) = config.command_line();
+        let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
 
-        terminal.spawn_command(&config.command, &args_refs, &config.cwd)?;
+        terminal.spawn_command(&command, &args_refs, &config.cwd)?;
+        terminal.set_transcript(open_transcript(&config, cols, rows)?);
+        terminal.set_collect_agent_events(config.structured_events);
 
-        let state_machine = AgentStateMachine::new(config.clone(), Instant::now());
+        let now = Instant::now();
+        let state_machine = AgentStateMachine::new(config.clone(), now);
+        let pending_prompt = config.initial_prompt.clone();
 
         Ok(Self {
             terminal,
             state_machine,
             config,
+            usage: UsageSampler::new(),
+            timeline: AgentTimeline::new(now),
+            pending_prompt,
         })
     }
 
@@ -354,7 +626,23 @@ impl AgentHandle {
 
         // Update state machine based on what happened
         if had_output {
-            self.state_machine.on_output(now);
+            // Chunk: docs/chunks/agent_input_patterns - Recognize prompts in fresh output
+            let at_prompt = !self.config.structured_events
+                && !self.config.input_patterns.is_empty()
+                && self
+                    .config
+                    .matches_input_prompt(&self.terminal.text_near_cursor(PROMPT_CONTEXT_LINES));
+            if at_prompt {
+                self.state_machine.on_input_prompt(now);
+            } else {
+                self.state_machine.on_output(now);
+            }
+        }
+
+        // Chunk: docs/chunks/agent_events - Reported events drive the state
+        for event in self.terminal.take_agent_events() {
+            self.state_machine.on_event(&event, now);
+            self.timeline.push(event, now);
         }
 
         // Check for process exit
@@ -362,9 +650,29 @@ impl AgentHandle {
             self.state_machine.on_exit(exit_code, now);
         }
 
+        // Chunk: docs/chunks/agent_resource_usage - Sample the process tree
+        match self.terminal.process_id() {
+            Some(pid) if !matches!(self.state(), AgentState::Exited { .. }) => {
+                self.usage.sample_if_due(pid, now)
+            }
+            _ => self.usage.clear(),
+        }
+
         // Tick for timeout-based transitions
         self.state_machine.tick(now);
 
+        // Chunk: docs/chunks/agent_templates - Type the first prompt once asked
+        if matches!(
+            self.state(),
+            AgentState::NeedsInput { .. } | AgentState::Stale { .. }
+        ) {
+            if let Some(prompt) = self.pending_prompt.take() {
+                let mut bytes = InputEncoder::encode_paste(&prompt, self.terminal.term_mode());
+                bytes.push(b'\r');
+                let _ = self.terminal.write_input(&bytes);
+            }
+        }
+
         had_output
     }
 
@@ -373,6 +681,22 @@ impl AgentHandle {
         self.state_machine.state()
     }
 
+    // Chunk: docs/chunks/agent_resource_usage - CPU and memory of the agent's processes
+    /// Returns the CPU and memory use of the agent process and its
+    /// descendants, sampled once a second by [`poll`](Self::poll).
+    ///
+    /// None until the first sample and after the agent exits.
+    pub fn resource_usage(&self) -> Option<ResourceUsage> {
+        self.usage.usage()
+    }
+
+    // Chunk: docs/chunks/agent_events - Structured agent events
+    /// Returns the events the agent has reported in this session, which stay
+    /// empty unless it runs in structured mode.
+    pub fn timeline(&self) -> &AgentTimeline {
+        &self.timeline
+    }
+
     /// Returns a reference to the terminal buffer.
     pub fn terminal(&self) -> &TerminalBuffer {
         &self.terminal
@@ -431,14 +755,22 @@ impl AgentHandle {
         let (cols, rows) = self.terminal.size();
         let mut new_terminal = TerminalBuffer::new(cols, rows, 5000);
 
-        let args_refs: Vec<&str> = self.config.args.iter().map(|s| s.as_str()).collect();
-        new_terminal.spawn_command(&self.config.command, &args_refs, &self.config.cwd)?;
+        let (command, args) = self.config.command_line();
+        let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        new_terminal.spawn_command(&command, &args_refs, &self.config.cwd)?;
+        new_terminal.set_transcript(open_transcript(&self.config, cols, rows)?);
+        new_terminal.set_collect_agent_events(self.config.structured_events);
 
         // Replace our terminal with the new one
         self.terminal = new_terminal;
 
         // Reset the state machine
-        self.state_machine.reset(Instant::now());
+        let now = Instant::now();
+        self.state_machine.reset(now);
+        self.usage.clear();
+        // Chunk: docs/chunks/agent_edit_review - Revisions keep counting across restarts
+        self.timeline.clear(now);
+        self.pending_prompt = self.config.initial_prompt.clone();
 
         Ok(())
     }
@@ -567,6 +899,20 @@ mod tests {
         assert_eq!(config.stale_timeout, Duration::from_secs(120));
     }
 
+    // Chunk: docs/chunks/agent_templates - Environment through env(1)
+    #[test]
+    fn test_command_line_sets_env_through_env() {
+        let config = AgentConfig::new("claude").with_args(vec!["--verbose".into()]);
+        let (command, args) = config.command_line();
+        assert_eq!(command, "claude");
+        assert_eq!(args, vec!["--verbose"]);
+
+        let config = config.with_env("MODEL", "opus").with_env("EMPTY", "");
+        let (command, args) = config.command_line();
+        assert_eq!(command, "env");
+        assert_eq!(args, vec!["MODEL=opus", "EMPTY=", "claude", "--verbose"]);
+    }
+
     #[test]
     fn test_config_default() {
         let config = AgentConfig::default();
@@ -576,6 +922,46 @@ mod tests {
         assert_eq!(config.stale_timeout, Duration::from_secs(60));
     }
 
+    #[test]
+    fn test_generic_input_patterns() {
+        let config = AgentConfig::default();
+        assert!(config.matches_input_prompt("Overwrite file? [y/N] "));
+        assert!(config.matches_input_prompt("Proceed? (y/n):"));
+        assert!(config.matches_input_prompt("Done.\nPress Enter to continue..."));
+        assert!(!config.matches_input_prompt("Compiling lite-edit (y/n options parsed)"));
+        assert!(!config.matches_input_prompt("Reading files..."));
+    }
+
+    #[test]
+    fn test_builtin_input_patterns_by_command_name() {
+        let claude = AgentConfig::new("claude");
+        assert!(claude.matches_input_prompt("Do you want to make this edit to main.rs?"));
+        assert!(claude.matches_input_prompt("Edit file\n ❯ 1. Yes\n   2. No"));
+
+        let aider = AgentConfig::new("/usr/local/bin/aider");
+        assert!(aider.matches_input_prompt("Add src/lib.rs to the chat? (Y)es/(N)o [Yes]: "));
+
+        let codex = AgentConfig::new("codex");
+        assert!(codex.matches_input_prompt("Would you like to run the following command?"));
+
+        // Agent-specific patterns only apply to their own CLI
+        let other = AgentConfig::new("bash");
+        assert!(!other.matches_input_prompt("Do you want to make this edit to main.rs?"));
+        assert_eq!(other.input_patterns.len(), GENERIC_INPUT_PATTERNS.len());
+    }
+
+    #[test]
+    fn test_custom_input_patterns() {
+        let config = AgentConfig::new("my-agent")
+            .with_input_patterns(Vec::new())
+            .with_input_pattern(r"(?m)^approve\?\s*$")
+            .unwrap();
+        assert!(config.matches_input_prompt("plan ready\napprove?"));
+        assert!(!config.matches_input_prompt("Proceed? [y/n]"));
+
+        assert!(AgentConfig::new("my-agent").with_input_pattern("(").is_err());
+    }
+
     // =========================================================================
     // AgentStateMachine tests (pure state logic)
     // =========================================================================
@@ -587,6 +973,11 @@ mod tests {
             cwd: PathBuf::from("/tmp"),
             needs_input_timeout: Duration::from_millis(100),
             stale_timeout: Duration::from_millis(200),
+            input_patterns: Vec::new(),
+            transcript: None,
+            structured_events: false,
+            env: Vec::new(),
+            initial_prompt: None,
         }
     }
 
@@ -627,6 +1018,26 @@ mod tests {
         assert!(matches!(sm.state(), AgentState::NeedsInput { .. }));
     }
 
+    #[test]
+    fn test_running_to_needs_input_on_prompt() {
+        let start = Instant::now();
+        let mut sm = AgentStateMachine::new(test_config(), start);
+        sm.on_output(start);
+
+        // A recognized prompt doesn't wait for the idle timeout
+        let prompt_at = start + Duration::from_millis(10);
+        sm.on_input_prompt(prompt_at);
+        assert!(matches!(sm.state(), AgentState::NeedsInput { since } if *since == prompt_at));
+
+        // A repeated match keeps the original NeedsInput timestamp
+        sm.on_input_prompt(start + Duration::from_millis(20));
+        assert!(matches!(sm.state(), AgentState::NeedsInput { since } if *since == prompt_at));
+
+        // Stale is still measured from entering NeedsInput
+        sm.tick(prompt_at + Duration::from_millis(250));
+        assert!(matches!(sm.state(), AgentState::Stale { .. }));
+    }
+
     #[test]
     fn test_needs_input_to_running_on_output() {
         let start = Instant::now();
@@ -761,4 +1172,49 @@ mod tests {
         sm.on_output(start + Duration::from_millis(500));
         assert!(matches!(sm.state(), AgentState::Running));
     }
+
+    // Chunk: docs/chunks/agent_events - Reported events drive the state
+    #[test]
+    fn test_structured_mode_ignores_silence() {
+        let start = Instant::now();
+        let mut sm = AgentStateMachine::new(test_config().with_structured_events(), start);
+
+        sm.tick(start + Duration::from_millis(150));
+        assert!(matches!(sm.state(), AgentState::Starting));
+        sm.on_output(start + Duration::from_millis(150));
+        sm.tick(start + Duration::from_secs(10));
+        assert!(matches!(sm.state(), AgentState::Running));
+    }
+
+    #[test]
+    fn test_structured_mode_waits_on_questions() {
+        let start = Instant::now();
+        let mut sm = AgentStateMachine::new(test_config().with_structured_events(), start);
+        let question = AgentEvent::Question {
+            text: "Deploy?".to_string(),
+        };
+        let progress = AgentEvent::Progress {
+            message: "Deploying".to_string(),
+            fraction: None,
+        };
+
+        sm.on_event(&question, start);
+        assert_eq!(sm.state(), &AgentState::NeedsInput { since: start });
+
+        // Redrawing the screen isn't an answer, and asking again keeps the wait
+        let later = start + Duration::from_millis(50);
+        sm.on_output(later);
+        sm.on_event(&question, later);
+        assert_eq!(sm.state(), &AgentState::NeedsInput { since: start });
+
+        // The wait still goes stale
+        sm.tick(start + Duration::from_millis(300));
+        assert!(matches!(sm.state(), AgentState::Stale { .. }));
+
+        sm.on_event(&progress, start + Duration::from_millis(400));
+        assert!(matches!(sm.state(), AgentState::Running));
+        sm.on_exit(0, start + Duration::from_millis(500));
+        sm.on_event(&progress, start + Duration::from_millis(600));
+        assert_eq!(sm.state(), &AgentState::Exited { code: 0 });
+    }
 }
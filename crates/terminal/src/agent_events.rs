@@ -0,0 +1,231 @@
+// Chunk: docs/chunks/agent_events - Structured agent events
+//! Structured events an agent reports alongside its terminal output.
+//!
+//! Inferring an agent's state from its output is guesswork: silence may be
+//! a question or a long compile. In structured mode
+//! (`AgentConfig::structured_events`) an agent says what it is doing with
+//! JSON objects sent as OSC strings on its terminal:
+//!
+//! ```text
+//! ESC ] lite-edit-event ; {"type":"progress","message":"Running tests"} BEL
+//! ```
+//!
+//! which a shell script can send with
+//! `printf '\033]lite-edit-event;%s\007' '{"type":"question","text":"Deploy?"}'`.
+//!
+//! The terminal picks these out of the output as it does shell integration
+//! sequences, and the emulator ignores them, so they never show on screen.
+//! Each becomes an [`AgentEvent`] on the agent's [`AgentTimeline`]. Objects
+//! of an unknown type or shape are skipped, so agents may send events that
+//! only newer editors understand. Like other OSC strings, events over 4 KB
+//! are dropped, which is why a proposed edit names a file holding the new
+//! contents rather than carrying them.
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+/// What an OSC string carrying an agent event starts with.
+const EVENT_OSC_PREFIX: &[u8] = b"lite-edit-event;";
+
+/// Maximum number of events remembered per agent; the oldest are dropped
+/// first.
+const MAX_TIMELINE_ENTRIES: usize = 10_000;
+
+/// Something an agent reported about its work.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AgentEvent {
+    /// Work under way, e.g. "Running tests".
+    Progress {
+        message: String,
+        /// How much of the work is done, from 0 to 1, if the agent knows.
+        fraction: Option<f32>,
+    },
+    /// The agent changed a file.
+    FileEdit {
+        /// The file, absolute or relative to the agent's working directory.
+        path: PathBuf,
+        /// What changed, e.g. "Add retry to fetch".
+        summary: Option<String>,
+        // Chunk: docs/chunks/agent_edit_review - Edits proposed for review
+        /// Where the agent wrote the contents it proposes for the file, if it
+        /// wants them reviewed before they replace it. The file itself is
+        /// left alone until the edit is accepted.
+        proposed: Option<PathBuf>,
+    },
+    /// The agent is waiting for an answer.
+    Question { text: String },
+}
+
+impl AgentEvent {
+    /// Returns the event carried by an OSC string body, or None if the body
+    /// isn't an agent event or its JSON isn't one this version understands.
+    pub(crate) fn from_osc(body: &[u8]) -> Option<Self> {
+        let json = body.strip_prefix(EVENT_OSC_PREFIX)?;
+        serde_json::from_slice(json).ok()
+    }
+}
+
+/// An event and when it arrived.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineEntry {
+    /// Time since the agent was spawned.
+    pub elapsed: Duration,
+    pub event: AgentEvent,
+}
+
+/// The events an agent has reported, oldest first.
+#[derive(Debug)]
+pub struct AgentTimeline {
+    started: Instant,
+    entries: VecDeque<TimelineEntry>,
+    /// Bumped with every event, so viewers can tell when to refresh even
+    /// once the oldest entries are being dropped.
+    revision: u64,
+}
+
+impl AgentTimeline {
+    /// Creates an empty timeline for an agent spawned at `started`.
+    pub fn new(started: Instant) -> Self {
+        Self {
+            started,
+            entries: VecDeque::new(),
+            revision: 0,
+        }
+    }
+
+    /// Adds an event that arrived at `now`.
+    pub fn push(&mut self, event: AgentEvent, now: Instant) {
+        if self.entries.len() == MAX_TIMELINE_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(TimelineEntry {
+            elapsed: now.saturating_duration_since(self.started),
+            event,
+        });
+        self.revision += 1;
+    }
+
+    /// Returns the events, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &TimelineEntry> {
+        self.entries.iter()
+    }
+
+    // Chunk: docs/chunks/agent_edit_review - New events since a revision
+    /// Returns the events added after `revision`, oldest first, as far as
+    /// they are still remembered.
+    pub fn since(&self, revision: u64) -> impl Iterator<Item = &TimelineEntry> {
+        let added = self.revision.saturating_sub(revision) as usize;
+        let skip = self.entries.len().saturating_sub(added);
+        self.entries.iter().skip(skip)
+    }
+
+    /// Forgets the events, for an agent restarted at `started`. The revision
+    /// keeps counting up, so viewers see the change.
+    pub fn clear(&mut self, started: Instant) {
+        self.started = started;
+        self.entries.clear();
+        self.revision += 1;
+    }
+
+    /// Returns how many events are remembered.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the agent hasn't reported anything.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns a number that changes whenever an event is added.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_from_osc_bodies() {
+        let progress =
+            br#"lite-edit-event;{"type":"progress","message":"Running tests","fraction":0.5}"#;
+        assert_eq!(
+            AgentEvent::from_osc(progress),
+            Some(AgentEvent::Progress {
+                message: "Running tests".to_string(),
+                fraction: Some(0.5),
+            })
+        );
+
+        let edit = br#"lite-edit-event;{"type":"file_edit","path":"src/lib.rs"}"#;
+        assert_eq!(
+            AgentEvent::from_osc(edit),
+            Some(AgentEvent::FileEdit {
+                path: PathBuf::from("src/lib.rs"),
+                summary: None,
+                proposed: None,
+            })
+        );
+
+        // Other OSC strings, unknown events and bad JSON are skipped
+        assert_eq!(AgentEvent::from_osc(b"7;file:///tmp"), None);
+        let unknown = br#"lite-edit-event;{"type":"dance"}"#;
+        assert_eq!(AgentEvent::from_osc(unknown), None);
+        assert_eq!(AgentEvent::from_osc(b"lite-edit-event;{"), None);
+    }
+
+    #[test]
+    fn test_timeline_keeps_newest_entries() {
+        let started = Instant::now();
+        let mut timeline = AgentTimeline::new(started);
+        assert!(timeline.is_empty());
+
+        let question = |n: usize| AgentEvent::Question {
+            text: n.to_string(),
+        };
+        for n in 0..MAX_TIMELINE_ENTRIES + 2 {
+            timeline.push(question(n), started + Duration::from_secs(n as u64));
+        }
+        assert_eq!(timeline.len(), MAX_TIMELINE_ENTRIES);
+        assert_eq!(timeline.revision(), MAX_TIMELINE_ENTRIES as u64 + 2);
+        let first = timeline.entries().next().unwrap();
+        assert_eq!(first.event, question(2));
+        assert_eq!(first.elapsed, Duration::from_secs(2));
+    }
+
+    // Chunk: docs/chunks/agent_edit_review - New events since a revision
+    #[test]
+    fn test_events_since_a_revision() {
+        let started = Instant::now();
+        let mut timeline = AgentTimeline::new(started);
+        let question = |text: &str| AgentEvent::Question {
+            text: text.to_string(),
+        };
+        timeline.push(question("a"), started);
+        let seen = timeline.revision();
+        timeline.push(question("b"), started);
+        timeline.push(question("c"), started);
+
+        let new: Vec<_> = timeline.since(seen).map(|entry| &entry.event).collect();
+        assert_eq!(new, [&question("b"), &question("c")]);
+        assert_eq!(timeline.since(timeline.revision()).count(), 0);
+
+        // A restart forgets the events but not the revision
+        let seen = timeline.revision();
+        timeline.clear(started);
+        assert!(timeline.is_empty());
+        timeline.push(question("d"), started);
+        let new: Vec<_> = timeline.since(seen).map(|entry| &entry.event).collect();
+        assert_eq!(new, [&question("d")]);
+    }
+}
@@ -0,0 +1,175 @@
+// Chunk: docs/chunks/agent_queue - Queue of agents run a few at a time
+//! Batch-running agents with a limit on how many run at once.
+//!
+//! An [`AgentQueue`] holds agent configurations in the order they were
+//! queued. It does not own running agents: the caller counts those it is
+//! running and asks the queue which agents to start with
+//! [`AgentQueue::take_ready`], typically after each poll, so the next queued
+//! agent starts as soon as a running one exits.
+
+use std::collections::VecDeque;
+
+use crate::agent::AgentConfig;
+
+/// Identifies a queued agent while it waits.
+pub type QueuedAgentId = u64;
+
+/// An agent waiting for a free slot.
+#[derive(Debug, Clone)]
+pub struct QueuedAgent {
+    pub id: QueuedAgentId,
+    /// What the agent is for, e.g. "fix lint in parser".
+    pub label: String,
+    pub config: AgentConfig,
+}
+
+/// Agents waiting to run, started in order with at most `max_concurrent`
+/// running at once.
+#[derive(Debug)]
+pub struct AgentQueue {
+    max_concurrent: usize,
+    waiting: VecDeque<QueuedAgent>,
+    next_id: QueuedAgentId,
+}
+
+impl AgentQueue {
+    /// How many agents run at once unless configured otherwise.
+    pub const DEFAULT_MAX_CONCURRENT: usize = 4;
+
+    /// Creates an empty queue running at most `max_concurrent` agents at
+    /// once (at least one).
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            max_concurrent: max_concurrent.max(1),
+            waiting: VecDeque::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Returns how many agents may run at once.
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    /// Changes how many agents may run at once (at least one). Agents already
+    /// running over a lowered limit keep running.
+    pub fn set_max_concurrent(&mut self, max_concurrent: usize) {
+        self.max_concurrent = max_concurrent.max(1);
+    }
+
+    /// Queues an agent behind those already waiting.
+    pub fn push(&mut self, label: impl Into<String>, config: AgentConfig) -> QueuedAgentId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.waiting.push_back(QueuedAgent {
+            id,
+            label: label.into(),
+            config,
+        });
+        id
+    }
+
+    /// Takes a waiting agent out of the queue.
+    pub fn remove(&mut self, id: QueuedAgentId) -> Option<QueuedAgent> {
+        let index = self.waiting.iter().position(|agent| agent.id == id)?;
+        self.waiting.remove(index)
+    }
+
+    /// Returns where a waiting agent is in the queue, 1 for the next to run.
+    pub fn position(&self, id: QueuedAgentId) -> Option<usize> {
+        self.waiting
+            .iter()
+            .position(|agent| agent.id == id)
+            .map(|index| index + 1)
+    }
+
+    /// Returns the waiting agents, the next to run first.
+    pub fn waiting(&self) -> impl Iterator<Item = &QueuedAgent> {
+        self.waiting.iter()
+    }
+
+    /// Returns how many agents are waiting.
+    pub fn len(&self) -> usize {
+        self.waiting.len()
+    }
+
+    /// Returns true if no agents are waiting.
+    pub fn is_empty(&self) -> bool {
+        self.waiting.is_empty()
+    }
+
+    /// Takes the agents to start now that `running` are running, in the
+    /// order they were queued. The caller must start every agent returned.
+    pub fn take_ready(&mut self, running: usize) -> Vec<QueuedAgent> {
+        let free = self.max_concurrent.saturating_sub(running);
+        let count = free.min(self.waiting.len());
+        self.waiting.drain(..count).collect()
+    }
+}
+
+impl Default for AgentQueue {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_CONCURRENT)
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(agents: &[QueuedAgent]) -> Vec<&str> {
+        agents.iter().map(|agent| agent.label.as_str()).collect()
+    }
+
+    #[test]
+    fn test_starts_in_order_up_to_the_limit() {
+        let mut queue = AgentQueue::new(2);
+        for label in ["a", "b", "c", "d"] {
+            queue.push(label, AgentConfig::new("claude"));
+        }
+
+        assert_eq!(labels(&queue.take_ready(0)), ["a", "b"]);
+        assert!(queue.take_ready(2).is_empty());
+
+        // One exits, so the next starts
+        assert_eq!(labels(&queue.take_ready(1)), ["c"]);
+        assert_eq!(queue.len(), 1);
+
+        // Raising the limit frees slots at once
+        queue.set_max_concurrent(4);
+        assert_eq!(labels(&queue.take_ready(2)), ["d"]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_positions_and_removal() {
+        let mut queue = AgentQueue::new(1);
+        let first = queue.push("first", AgentConfig::new("claude"));
+        let second = queue.push("second", AgentConfig::new("aider"));
+        let third = queue.push("third", AgentConfig::new("codex"));
+        assert_eq!(queue.position(third), Some(3));
+
+        let removed = queue.remove(second).unwrap();
+        assert_eq!(removed.config.command, "aider");
+        assert_eq!(queue.position(third), Some(2));
+        assert_eq!(queue.position(second), None);
+
+        queue.take_ready(0);
+        assert_eq!(queue.position(first), None);
+        assert_eq!(queue.position(third), Some(1));
+    }
+
+    #[test]
+    fn test_limit_is_at_least_one() {
+        let mut queue = AgentQueue::new(0);
+        assert_eq!(queue.max_concurrent(), 1);
+        queue.push("only", AgentConfig::new("claude"));
+        // Over a lowered limit nothing new starts
+        assert!(queue.take_ready(3).is_empty());
+        assert_eq!(queue.take_ready(0).len(), 1);
+    }
+}
@@ -30,6 +30,7 @@
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
 use std::time::Instant;
 
 use lite_edit_buffer::{Color, NamedColor, Span, Style, StyledLine, UnderlineStyle};
@@ -450,48 +451,51 @@ impl ColdScrollback {
         Ok(())
     }
 
-    /// Reads a line by index from cold storage.
-    pub fn get(&mut self, line: usize) -> io::Result<StyledLine> {
-        if line >= self.line_count {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!("Line index {} out of bounds (count: {})", line, self.line_count),
-            ));
-        }
-
-        let offset = self.line_offsets[line];
-
-        // Seek to the line's position
-        self.file.seek(SeekFrom::Start(offset))?;
-
-        // Read the length prefix
-        let mut len_buf = [0u8; 4];
-        self.file.read_exact(&mut len_buf)?;
-        let total_len = u32::from_le_bytes(len_buf) as usize;
-
-        // Read the full record
-        let mut data = vec![0u8; total_len];
-        data[0..4].copy_from_slice(&len_buf);
-        self.file.read_exact(&mut data[4..])?;
-
-        deserialize_styled_line(&data)
-    }
-
     /// Returns the number of lines stored.
     #[allow(dead_code)]
     pub fn line_count(&self) -> usize {
         self.line_count
     }
 
+    // Chunk: docs/chunks/terminal_memory_budget - Cold scrollback footprint
+    /// Returns the bytes of the in-memory line index.
+    pub fn index_bytes(&self) -> usize {
+        self.line_offsets.capacity() * std::mem::size_of::<u64>()
+    }
+
+    /// Returns the size of the backing file in bytes.
+    pub fn file_bytes(&self) -> u64 {
+        self.write_pos
+    }
+
+    // Chunk: docs/chunks/styled_lines_batch - One read per range
     /// Reads a range of lines for page cache loading.
     ///
     /// Returns lines in the range [start, min(start + count, line_count)).
+    /// Records are stored back to back, so the range is read with a single
+    /// seek and read and then split at the indexed offsets.
     pub fn get_range(&mut self, start: usize, count: usize) -> io::Result<Vec<StyledLine>> {
-        let end = (start + count).min(self.line_count);
-        let mut lines = Vec::with_capacity(end - start);
+        let end = start.saturating_add(count).min(self.line_count);
+        if start >= end {
+            return Ok(Vec::new());
+        }
+
+        let first = self.line_offsets[start];
+        let last = self.line_offsets.get(end).copied().unwrap_or(self.write_pos);
 
+        self.file.seek(SeekFrom::Start(first))?;
+        let mut data = vec![0u8; (last - first) as usize];
+        self.file.read_exact(&mut data)?;
+
+        let mut lines = Vec::with_capacity(end - start);
         for i in start..end {
-            lines.push(self.get(i)?);
+            let record_start = (self.line_offsets[i] - first) as usize;
+            let record_end = if i + 1 < end {
+                (self.line_offsets[i + 1] - first) as usize
+            } else {
+                data.len()
+            };
+            lines.push(deserialize_styled_line(&data[record_start..record_end])?);
         }
 
         Ok(lines)
@@ -529,6 +533,10 @@ impl CachePage {
         }
     }
 
+    fn contains(&self, line: usize) -> bool {
+        line >= self.start_line && line - self.start_line < self.lines.len()
+    }
+
     fn get(&mut self, line: usize) -> Option<&StyledLine> {
         let offset = line.checked_sub(self.start_line)?;
         if offset < self.lines.len() {
@@ -574,16 +582,58 @@ impl PageCache {
     /// Returns a clone of the line (since we can't return a reference into
     /// the mutable cold scrollback).
     pub fn get(&mut self, line: usize, cold: &mut ColdScrollback) -> io::Result<StyledLine> {
+        let page = self.page_with(line, cold)?;
+        page.get(line)
+            .cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Line not in loaded page"))
+    }
+
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of a line range
+    /// Gets the lines in `lines`, loading each page they span at most once.
+    ///
+    /// Fails if any line in the range is not in cold storage.
+    pub fn get_range(
+        &mut self,
+        lines: Range<usize>,
+        cold: &mut ColdScrollback,
+    ) -> io::Result<Vec<StyledLine>> {
+        let mut result = Vec::with_capacity(lines.len());
+        let mut line = lines.start;
+        while line < lines.end {
+            let page = self.page_with(line, cold)?;
+            page.last_access = Instant::now();
+            let offset = line - page.start_line;
+            let end = (lines.end - page.start_line).min(page.lines.len());
+            result.extend_from_slice(&page.lines[offset..end]);
+            line = page.start_line + end;
+        }
+        Ok(result)
+    }
+
+    /// Returns the cached page holding `line`, loading it from cold storage
+    /// if it is missing or was cached before `line` was appended.
+    fn page_with(&mut self, line: usize, cold: &mut ColdScrollback) -> io::Result<&mut CachePage> {
         let page_num = line / self.page_size;
+        let cached = self.pages.get(&page_num).is_some_and(|page| page.contains(line));
+        if !cached {
+            self.load_page(page_num, line, cold)?;
+        }
 
-        // Check if page is in cache
-        if let Some(page) = self.pages.get_mut(&page_num) {
-            if let Some(styled_line) = page.get(line) {
-                return Ok(styled_line.clone());
-            }
+        let page = self.pages.get_mut(&page_num).unwrap();
+        if page.contains(line) {
+            Ok(page)
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidInput, "Line not in loaded page"))
         }
+    }
 
-        // Page not in cache, load it
+    /// Loads page `page_num` from cold storage, evicting pages to make room.
+    fn load_page(
+        &mut self,
+        page_num: usize,
+        line: usize,
+        cold: &mut ColdScrollback,
+    ) -> io::Result<()> {
         let page_start = page_num * self.page_size;
         let lines = cold.get_range(page_start, self.page_size)?;
 
@@ -602,15 +652,13 @@ impl PageCache {
             self.evict_oldest();
         }
 
-        // Insert the new page
+        // Insert the new page. A short last page is reloaded once more lines
+        // are appended, so the copy it replaces stops counting.
         self.current_bytes += page_size_bytes;
-        self.pages.insert(page_num, page);
-
-        // Get the line from the newly loaded page
-        let page = self.pages.get_mut(&page_num).unwrap();
-        page.get(line)
-            .cloned()
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Line not in loaded page"))
+        if let Some(replaced) = self.pages.insert(page_num, page) {
+            self.current_bytes = self.current_bytes.saturating_sub(replaced.size_bytes);
+        }
+        Ok(())
     }
 
     /// Evicts the oldest accessed page.
@@ -632,11 +680,43 @@ impl PageCache {
         self.current_bytes = 0;
     }
 
+    // Chunk: docs/chunks/terminal_cold_selection - Keep full pages across appends
+    /// Drops the cached pages that hold `line` or anything after it.
+    ///
+    /// Cold storage is append-only, so after an append only the page that
+    /// was last (and possibly short) can be stale. Earlier pages stay cached.
+    pub fn invalidate_from(&mut self, line: usize) {
+        let first_stale = line / self.page_size;
+        let mut freed = 0;
+        self.pages.retain(|&page_num, page| {
+            let keep = page_num < first_stale;
+            if !keep {
+                freed += page.size_bytes;
+            }
+            keep
+        });
+        self.current_bytes = self.current_bytes.saturating_sub(freed);
+    }
+
     /// Returns the current cache size in bytes.
-    #[cfg(test)]
     pub fn size_bytes(&self) -> usize {
         self.current_bytes
     }
+
+    // Chunk: docs/chunks/terminal_memory_budget - Configurable cache budget
+    /// Returns the maximum cache size in bytes.
+    pub fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// Changes the maximum cache size, evicting the least recently used
+    /// pages until the cache fits.
+    pub fn set_max_bytes(&mut self, max_bytes: usize) {
+        self.max_bytes = max_bytes;
+        while self.current_bytes > self.max_bytes && !self.pages.is_empty() {
+            self.evict_oldest();
+        }
+    }
 }
 
 // =============================================================================
@@ -874,13 +954,11 @@ mod tests {
 
         assert_eq!(cold.line_count(), 3);
 
-        let read1 = cold.get(0).unwrap();
-        let read2 = cold.get(1).unwrap();
-        let read3 = cold.get(2).unwrap();
+        let read = cold.get_range(0, 3).unwrap();
 
-        assert_eq!(read1.spans[0].text, "Line 1");
-        assert_eq!(read2.spans[0].text, "Line 2");
-        assert_eq!(read3.spans[0].text, "Line 3");
+        assert_eq!(read[0].spans[0].text, "Line 1");
+        assert_eq!(read[1].spans[0].text, "Line 2");
+        assert_eq!(read[2].spans[0].text, "Line 3");
     }
 
     #[test]
@@ -896,13 +974,11 @@ mod tests {
         assert_eq!(cold.line_count(), 1000);
 
         // Verify random access
-        let line_0 = cold.get(0).unwrap();
-        let line_500 = cold.get(500).unwrap();
-        let line_999 = cold.get(999).unwrap();
+        let line = |cold: &mut ColdScrollback, i| cold.get_range(i, 1).unwrap().remove(0);
 
-        assert_eq!(line_0.spans[0].text, "Line 0000");
-        assert_eq!(line_500.spans[0].text, "Line 0500");
-        assert_eq!(line_999.spans[0].text, "Line 0999");
+        assert_eq!(line(&mut cold, 0).spans[0].text, "Line 0000");
+        assert_eq!(line(&mut cold, 500).spans[0].text, "Line 0500");
+        assert_eq!(line(&mut cold, 999).spans[0].text, "Line 0999");
     }
 
     #[test]
@@ -910,8 +986,8 @@ mod tests {
         let mut cold = ColdScrollback::new().unwrap();
         cold.append(&StyledLine::plain("test")).unwrap();
 
-        assert!(cold.get(1).is_err());
-        assert!(cold.get(100).is_err());
+        assert!(cold.get_range(1, 1).unwrap().is_empty());
+        assert!(cold.get_range(100, 1).unwrap().is_empty());
     }
 
     #[test]
@@ -930,6 +1006,31 @@ mod tests {
         // Range past end of lines
         let range2 = cold.get_range(95, 10).unwrap();
         assert_eq!(range2.len(), 5); // Only 5 lines available
+        assert_eq!(range2[4].spans[0].text, "Line 99");
+
+        // Range starting past the end
+        assert!(cold.get_range(100, 10).unwrap().is_empty());
+    }
+
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of a line range
+    #[test]
+    fn test_cold_scrollback_get_range_keeps_styles() {
+        let mut cold = ColdScrollback::new().unwrap();
+        let mut bold = StyledLine::plain("bold ");
+        bold.spans[0].style.bold = true;
+        bold.spans.push(Span::plain("and plain"));
+        let lines = vec![
+            StyledLine::plain("first"),
+            bold,
+            StyledLine::empty(),
+            StyledLine::plain("wide 日本語"),
+        ];
+        for line in &lines {
+            cold.append(line).unwrap();
+        }
+
+        assert_eq!(cold.get_range(0, 4).unwrap(), lines);
+        assert_eq!(cold.get_range(1, 2).unwrap(), lines[1..3]);
     }
 
     // ==================== PageCache Tests ====================
@@ -1011,6 +1112,69 @@ mod tests {
         assert_eq!(cache.size_bytes(), 0);
     }
 
+    #[test]
+    fn test_page_cache_invalidate_from_keeps_earlier_pages() {
+        let mut cold = ColdScrollback::new().unwrap();
+        for i in 0..100 {
+            cold.append(&StyledLine::plain(format!("Line {}", i))).unwrap();
+        }
+
+        let mut cache = PageCache::new(1024 * 1024, 64);
+        let _ = cache.get(10, &mut cold).unwrap();
+        let _ = cache.get(80, &mut cold).unwrap();
+        let first_page_bytes = cache.pages[&0].size_bytes;
+
+        // Appending fills out the short second page; only it goes stale.
+        cold.append(&StyledLine::plain("Line 100")).unwrap();
+        cache.invalidate_from(100);
+
+        assert!(cache.pages.contains_key(&0));
+        assert!(!cache.pages.contains_key(&1));
+        assert_eq!(cache.size_bytes(), first_page_bytes);
+        assert_eq!(cache.get(100, &mut cold).unwrap().spans[0].text, "Line 100");
+    }
+
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of a line range
+    #[test]
+    fn test_page_cache_get_range_spans_pages() {
+        let mut cold = ColdScrollback::new().unwrap();
+        for i in 0..100 {
+            cold.append(&StyledLine::plain(format!("Line {}", i))).unwrap();
+        }
+
+        let mut cache = PageCache::new(1024 * 1024, 16);
+        let lines = cache.get_range(10..40, &mut cold).unwrap();
+
+        let texts: Vec<_> = lines.iter().map(|l| l.spans[0].text.clone()).collect();
+        let expected: Vec<_> = (10..40).map(|i| format!("Line {}", i)).collect();
+        assert_eq!(texts, expected);
+        // Lines 10..40 span pages 0, 1 and 2
+        assert_eq!(cache.pages.len(), 3);
+
+        assert!(cache.get_range(5..5, &mut cold).unwrap().is_empty());
+        assert!(cache.get_range(90..110, &mut cold).is_err());
+    }
+
+    // Chunk: docs/chunks/terminal_memory_budget - Configurable cache budget
+    #[test]
+    fn test_page_cache_shrinking_budget_evicts() {
+        let mut cold = ColdScrollback::new().unwrap();
+        for i in 0..200 {
+            cold.append(&StyledLine::plain(format!("Line {}", i))).unwrap();
+        }
+
+        let mut cache = PageCache::new(1024 * 1024, 64);
+        let _ = cache.get(10, &mut cold).unwrap();
+        let _ = cache.get(150, &mut cold).unwrap();
+        let budget = cache.pages[&0].size_bytes.max(cache.pages[&2].size_bytes);
+
+        cache.set_max_bytes(budget);
+
+        assert_eq!(cache.max_bytes(), budget);
+        assert_eq!(cache.pages.len(), 1);
+        assert!(cache.size_bytes() <= budget);
+    }
+
     // ==================== Size Reduction Tests ====================
 
     #[test]
@@ -0,0 +1,559 @@
+// Chunk: docs/chunks/terminal_copy_mode - Keyboard-driven scrollback selection
+//!
+//! Copy mode focus target for terminal tabs.
+//!
+//! This module provides `CopyModeTarget`, a tmux-style copy mode layered over
+//! `TerminalFocusTarget`. While it is active, keys move a cursor through the
+//! screen and scrollback and build a selection instead of being encoded and
+//! written to the PTY. The selection is the terminal's own selection, so it is
+//! rendered and copied exactly like a mouse selection.
+//!
+//! Keys follow tmux's vi table:
+//!
+//! - `h`/`j`/`k`/`l` and the arrow keys move by character and line
+//! - `w`/`b`/`e` move by word, `0`/`^`/`$` (and Home/End) within the line
+//! - `g`/`G` jump to the top of the scrollback and the bottom of the screen
+//! - Ctrl+U/Ctrl+D move half a page, Ctrl+B/Ctrl+F (and Page Up/Down) a page
+//! - `v` or Space starts a selection, `V` a line selection
+//! - `y`, Return or Cmd+C copy the selection (or the cursor line) and leave
+//! - Escape clears the selection, then leaves; `q` and Ctrl+C leave at once
+
+use lite_edit_buffer::{BufferView, Position};
+use lite_edit_input::{Key, KeyEvent};
+
+use crate::terminal_buffer::TerminalBuffer;
+
+/// What the caller should do after copy mode handles a key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CopyModeAction {
+    /// The cursor or selection changed; keep the cursor in view and redraw.
+    Handled,
+    /// The key has no meaning in copy mode. It must still not reach the PTY.
+    Ignored,
+    /// Put the text on the clipboard and leave copy mode.
+    Copy(String),
+    /// Leave copy mode without copying.
+    Exit,
+}
+
+/// How the selection grows from its anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SelectionKind {
+    /// From the anchor character to the cursor character, inclusive.
+    Char,
+    /// Whole lines from the anchor line to the cursor line.
+    Line,
+}
+
+/// Focus target for copy mode in a terminal tab.
+///
+/// Positions are document positions (cold scrollback, hot scrollback, then
+/// the screen), like the terminal's selection. The target owns no reference
+/// to the terminal; each call is given the terminal it was entered on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyModeTarget {
+    /// The copy mode cursor.
+    cursor: Position,
+    /// Where the selection started and how it grows, if selecting.
+    anchor: Option<(Position, SelectionKind)>,
+}
+
+impl CopyModeTarget {
+    /// Enters copy mode on `terminal` with the cursor at `cursor`.
+    ///
+    /// Any mouse selection is cleared, and the terminal shows the copy mode
+    /// cursor in place of its own until [`Self::exit`].
+    pub fn enter(terminal: &mut TerminalBuffer, cursor: Position) -> Self {
+        terminal.clear_selection();
+        let mut target = Self {
+            cursor: Position::new(0, 0),
+            anchor: None,
+        };
+        target.move_to(clamp(cursor, terminal), terminal);
+        target
+    }
+
+    /// Leaves copy mode, clearing the selection and restoring the terminal's
+    /// own cursor.
+    pub fn exit(self, terminal: &mut TerminalBuffer) {
+        terminal.clear_selection();
+        terminal.set_copy_cursor(None);
+    }
+
+    /// Returns the copy mode cursor.
+    pub fn cursor(&self) -> Position {
+        self.cursor
+    }
+
+    /// Returns true while a selection is being made.
+    pub fn is_selecting(&self) -> bool {
+        self.anchor.is_some()
+    }
+
+    /// Handles a keyboard event.
+    ///
+    /// `page_lines` is the number of lines visible in the pane, used for the
+    /// page and half-page motions.
+    pub fn handle_key(
+        &mut self,
+        event: &KeyEvent,
+        terminal: &mut TerminalBuffer,
+        page_lines: usize,
+    ) -> CopyModeAction {
+        self.follow_capture(terminal);
+
+        let mods = &event.modifiers;
+        if mods.command {
+            return match event.key {
+                Key::Char('c') | Key::Char('C') => self.copy(terminal),
+                _ => CopyModeAction::Ignored,
+            };
+        }
+
+        let Position { line, col } = self.cursor;
+        let last_line = terminal.line_count().saturating_sub(1);
+        let half_page = (page_lines / 2).max(1);
+        let page = page_lines.saturating_sub(1).max(1);
+        let chars = line_chars(terminal, line);
+
+        let target = match event.key {
+            Key::Char('c') if mods.control => return CopyModeAction::Exit,
+            Key::Char('u') if mods.control => Position::new(line.saturating_sub(half_page), col),
+            Key::Char('d') if mods.control => Position::new(line + half_page, col),
+            Key::Char('b') if mods.control => Position::new(line.saturating_sub(page), col),
+            Key::Char('f') if mods.control => Position::new(line + page, col),
+            _ if mods.control => return CopyModeAction::Ignored,
+
+            Key::Escape => {
+                if self.anchor.take().is_some() {
+                    terminal.clear_selection();
+                    return CopyModeAction::Handled;
+                }
+                return CopyModeAction::Exit;
+            }
+            Key::Char('q') => return CopyModeAction::Exit,
+            Key::Char('y') | Key::Return => return self.copy(terminal),
+            Key::Char('v') | Key::Char(' ') => {
+                self.toggle_selection(SelectionKind::Char, terminal);
+                return CopyModeAction::Handled;
+            }
+            Key::Char('V') => {
+                self.toggle_selection(SelectionKind::Line, terminal);
+                return CopyModeAction::Handled;
+            }
+
+            Key::Char('h') | Key::Left => Position::new(line, col.saturating_sub(1)),
+            Key::Char('l') | Key::Right => Position::new(line, col + 1),
+            Key::Char('k') | Key::Up => Position::new(line.saturating_sub(1), col),
+            Key::Char('j') | Key::Down => Position::new(line + 1, col),
+            Key::PageUp => Position::new(line.saturating_sub(page), col),
+            Key::PageDown => Position::new(line + page, col),
+            Key::Char('0') | Key::Home => Position::new(line, 0),
+            Key::Char('^') => {
+                let first = chars.iter().position(|c| !c.is_whitespace());
+                Position::new(line, first.unwrap_or(0))
+            }
+            Key::Char('$') | Key::End => {
+                let last = chars.iter().rposition(|c| !c.is_whitespace());
+                Position::new(line, last.unwrap_or(0))
+            }
+            Key::Char('g') => Position::new(0, 0),
+            Key::Char('G') => Position::new(last_line, 0),
+            Key::Char('w') => next_word_start(terminal, self.cursor),
+            Key::Char('b') => prev_word_start(terminal, self.cursor),
+            Key::Char('e') => next_word_end(terminal, self.cursor),
+            _ => return CopyModeAction::Ignored,
+        };
+
+        self.move_to(clamp(target, terminal), terminal);
+        CopyModeAction::Handled
+    }
+
+    // Chunk: docs/chunks/terminal_cold_selection - Stay on the same text across captures
+    /// Catches up with lines moved to cold scrollback since the last key.
+    ///
+    /// A capture moves hot lines, and the terminal's copy cursor with them,
+    /// further down the document. The anchor moves too unless it was already
+    /// in cold scrollback.
+    fn follow_capture(&mut self, terminal: &TerminalBuffer) {
+        let Some(shown) = terminal.copy_cursor() else {
+            return;
+        };
+        let moved = shown.line.saturating_sub(self.cursor.line);
+        if moved == 0 {
+            return;
+        }
+        let old_cold_count = terminal.cold_line_count().saturating_sub(moved);
+        self.cursor = shown;
+        if let Some((anchor, _)) = &mut self.anchor {
+            if anchor.line >= old_cold_count {
+                anchor.line += moved;
+            }
+        }
+    }
+
+    /// Moves the cursor and updates the terminal's cursor and selection.
+    fn move_to(&mut self, pos: Position, terminal: &mut TerminalBuffer) {
+        self.cursor = pos;
+        terminal.set_copy_cursor(Some(pos));
+        self.sync_selection(terminal);
+    }
+
+    /// Starts a selection of `kind` at the cursor, switches an existing
+    /// selection to `kind`, or ends the selection if it already is `kind`.
+    fn toggle_selection(&mut self, kind: SelectionKind, terminal: &mut TerminalBuffer) {
+        match self.anchor {
+            Some((_, current)) if current == kind => {
+                self.anchor = None;
+                terminal.clear_selection();
+            }
+            Some((anchor, _)) => {
+                self.anchor = Some((anchor, kind));
+                self.sync_selection(terminal);
+            }
+            None => {
+                self.anchor = Some((self.cursor, kind));
+                self.sync_selection(terminal);
+            }
+        }
+    }
+
+    /// Writes the selection to the terminal as an anchor and an exclusive head.
+    ///
+    /// Copy mode selections include the character under the cursor at both
+    /// ends, so the later end is extended by one column.
+    fn sync_selection(&self, terminal: &mut TerminalBuffer) {
+        let Some((anchor, kind)) = self.anchor else {
+            return;
+        };
+        let (start, end) = if anchor <= self.cursor {
+            (anchor, self.cursor)
+        } else {
+            (self.cursor, anchor)
+        };
+        let (start, end) = match kind {
+            SelectionKind::Char => (start, Position::new(end.line, end.col + 1)),
+            SelectionKind::Line => (
+                Position::new(start.line, 0),
+                Position::new(end.line, terminal.size().0),
+            ),
+        };
+        terminal.set_selection_anchor(start);
+        terminal.set_selection_head(end);
+    }
+
+    /// Returns the text to copy: the selection, or the cursor line if there
+    /// is none.
+    fn copy(&self, terminal: &TerminalBuffer) -> CopyModeAction {
+        let text = if self.anchor.is_some() {
+            terminal.selected_text()
+        } else {
+            let line: String = line_chars(terminal, self.cursor.line).into_iter().collect();
+            Some(line.trim_end().to_string())
+        };
+        match text {
+            Some(text) if !text.is_empty() => CopyModeAction::Copy(text),
+            _ => CopyModeAction::Exit,
+        }
+    }
+}
+
+/// Returns the characters of a document line.
+fn line_chars(terminal: &TerminalBuffer, line: usize) -> Vec<char> {
+    terminal
+        .styled_line(line)
+        .map(|styled| {
+            styled
+                .spans
+                .iter()
+                .flat_map(|span| span.text.chars())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Clamps a position to the document and the terminal width.
+fn clamp(pos: Position, terminal: &TerminalBuffer) -> Position {
+    let last_line = terminal.line_count().saturating_sub(1);
+    let last_col = terminal.size().0.saturating_sub(1);
+    Position::new(pos.line.min(last_line), pos.col.min(last_col))
+}
+
+/// Character class for word motions: whitespace, word characters, and
+/// punctuation, as in vi's lowercase word motions.
+fn char_class(c: char) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if c.is_alphanumeric() || c == '_' {
+        1
+    } else {
+        2
+    }
+}
+
+/// Returns the class of the character at `pos`; the end of a line counts as
+/// whitespace so words never run across lines.
+fn class_at(lines: &mut LineCache<'_>, pos: Position) -> u8 {
+    lines
+        .chars(pos.line)
+        .get(pos.col)
+        .map_or(0, |&c| char_class(c))
+}
+
+/// Caches the characters of the line last looked at while stepping through
+/// the document.
+struct LineCache<'a> {
+    terminal: &'a TerminalBuffer,
+    line: usize,
+    chars: Vec<char>,
+}
+
+impl<'a> LineCache<'a> {
+    fn new(terminal: &'a TerminalBuffer) -> Self {
+        Self {
+            terminal,
+            line: usize::MAX,
+            chars: Vec::new(),
+        }
+    }
+
+    fn chars(&mut self, line: usize) -> &[char] {
+        if line != self.line {
+            self.line = line;
+            self.chars = line_chars(self.terminal, line);
+        }
+        &self.chars
+    }
+}
+
+/// Steps one character forward through the document, wrapping to the next
+/// line. Returns `None` at the end.
+fn step_forward(terminal: &TerminalBuffer, pos: Position) -> Option<Position> {
+    let cols = terminal.size().0;
+    if pos.col + 1 < cols {
+        Some(Position::new(pos.line, pos.col + 1))
+    } else if pos.line + 1 < terminal.line_count() {
+        Some(Position::new(pos.line + 1, 0))
+    } else {
+        None
+    }
+}
+
+/// Steps one character back through the document, wrapping to the end of
+/// the previous line. Returns `None` at the start.
+fn step_back(terminal: &TerminalBuffer, pos: Position) -> Option<Position> {
+    if pos.col > 0 {
+        Some(Position::new(pos.line, pos.col - 1))
+    } else if pos.line > 0 {
+        Some(Position::new(pos.line - 1, terminal.size().0.saturating_sub(1)))
+    } else {
+        None
+    }
+}
+
+/// Returns the start of the next word after `pos` (vi `w`).
+fn next_word_start(terminal: &TerminalBuffer, pos: Position) -> Position {
+    let mut lines = LineCache::new(terminal);
+    let class = class_at(&mut lines, pos);
+    let mut cur = pos;
+    // Leave the current word
+    while class != 0 && class_at(&mut lines, cur) == class {
+        match step_forward(terminal, cur) {
+            Some(next) if next.line == cur.line => cur = next,
+            Some(next) => {
+                cur = next;
+                break;
+            }
+            None => return cur,
+        }
+    }
+    // Skip whitespace up to the next word
+    while class_at(&mut lines, cur) == 0 {
+        match step_forward(terminal, cur) {
+            Some(next) => cur = next,
+            None => return cur,
+        }
+    }
+    cur
+}
+
+/// Returns the end of the word at or after `pos` (vi `e`).
+fn next_word_end(terminal: &TerminalBuffer, pos: Position) -> Position {
+    let mut lines = LineCache::new(terminal);
+    let Some(mut cur) = step_forward(terminal, pos) else {
+        return pos;
+    };
+    while class_at(&mut lines, cur) == 0 {
+        match step_forward(terminal, cur) {
+            Some(next) => cur = next,
+            None => return pos,
+        }
+    }
+    let class = class_at(&mut lines, cur);
+    while let Some(next) = step_forward(terminal, cur) {
+        if next.line != cur.line || class_at(&mut lines, next) != class {
+            break;
+        }
+        cur = next;
+    }
+    cur
+}
+
+/// Returns the start of the word before `pos` (vi `b`).
+fn prev_word_start(terminal: &TerminalBuffer, pos: Position) -> Position {
+    let mut lines = LineCache::new(terminal);
+    let Some(mut cur) = step_back(terminal, pos) else {
+        return pos;
+    };
+    while class_at(&mut lines, cur) == 0 {
+        match step_back(terminal, cur) {
+            Some(prev) => cur = prev,
+            None => return cur,
+        }
+    }
+    let class = class_at(&mut lines, cur);
+    while let Some(prev) = step_back(terminal, cur) {
+        if prev.line != cur.line || class_at(&mut lines, prev) != class {
+            break;
+        }
+        cur = prev;
+    }
+    cur
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lite_edit_input::Modifiers;
+
+    fn key(key: Key) -> KeyEvent {
+        KeyEvent::new(key, Modifiers::default())
+    }
+
+    fn char_key(ch: char) -> KeyEvent {
+        key(Key::Char(ch))
+    }
+
+    fn ctrl(ch: char) -> KeyEvent {
+        KeyEvent::new(
+            Key::Char(ch),
+            Modifiers {
+                control: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn terminal_with(text: &str) -> TerminalBuffer {
+        let mut terminal = TerminalBuffer::new(40, 5, 1000);
+        terminal.feed_bytes(text.as_bytes());
+        terminal
+    }
+
+    #[test]
+    fn test_enter_shows_copy_cursor() {
+        let mut terminal = terminal_with("hello");
+        let target = CopyModeTarget::enter(&mut terminal, Position::new(0, 2));
+        assert_eq!(target.cursor(), Position::new(0, 2));
+        assert_eq!(
+            terminal.cursor_info().unwrap().position,
+            Position::new(0, 2)
+        );
+
+        target.exit(&mut terminal);
+        assert_eq!(terminal.cursor_info().unwrap().position, Position::new(0, 5));
+    }
+
+    #[test]
+    fn test_movement_is_clamped() {
+        let mut terminal = terminal_with("hello");
+        let mut target = CopyModeTarget::enter(&mut terminal, Position::new(0, 0));
+        target.handle_key(&char_key('k'), &mut terminal, 5);
+        target.handle_key(&char_key('h'), &mut terminal, 5);
+        assert_eq!(target.cursor(), Position::new(0, 0));
+
+        target.handle_key(&char_key('G'), &mut terminal, 5);
+        assert_eq!(target.cursor(), Position::new(4, 0));
+        target.handle_key(&key(Key::End), &mut terminal, 5);
+        target.handle_key(&ctrl('d'), &mut terminal, 5);
+        assert_eq!(target.cursor(), Position::new(4, 0));
+    }
+
+    #[test]
+    fn test_word_motions() {
+        let mut terminal = terminal_with("foo bar.baz\r\n  qux");
+        let mut target = CopyModeTarget::enter(&mut terminal, Position::new(0, 0));
+        let mut w = |target: &mut CopyModeTarget, ch| {
+            target.handle_key(&char_key(ch), &mut terminal, 5);
+            target.cursor()
+        };
+        assert_eq!(w(&mut target, 'w'), Position::new(0, 4));
+        assert_eq!(w(&mut target, 'w'), Position::new(0, 7));
+        assert_eq!(w(&mut target, 'w'), Position::new(0, 8));
+        assert_eq!(w(&mut target, 'w'), Position::new(1, 2));
+        assert_eq!(w(&mut target, 'b'), Position::new(0, 8));
+        assert_eq!(w(&mut target, 'e'), Position::new(0, 10));
+        assert_eq!(w(&mut target, '^'), Position::new(0, 0));
+        assert_eq!(w(&mut target, '$'), Position::new(0, 10));
+    }
+
+    #[test]
+    fn test_char_selection_is_inclusive() {
+        let mut terminal = terminal_with("hello world");
+        let mut target = CopyModeTarget::enter(&mut terminal, Position::new(0, 0));
+        target.handle_key(&char_key('v'), &mut terminal, 5);
+        target.handle_key(&char_key('e'), &mut terminal, 5);
+        assert!(target.is_selecting());
+        assert_eq!(
+            target.handle_key(&char_key('y'), &mut terminal, 5),
+            CopyModeAction::Copy("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn test_line_selection_copies_whole_lines() {
+        let mut terminal = terminal_with("one\r\ntwo\r\nthree");
+        let mut target = CopyModeTarget::enter(&mut terminal, Position::new(1, 2));
+        target.handle_key(&char_key('V'), &mut terminal, 5);
+        target.handle_key(&char_key('k'), &mut terminal, 5);
+        assert_eq!(
+            target.handle_key(&key(Key::Return), &mut terminal, 5),
+            CopyModeAction::Copy("one\ntwo".to_string())
+        );
+    }
+
+    #[test]
+    fn test_yank_without_selection_copies_cursor_line() {
+        let mut terminal = terminal_with("$ make test   ");
+        let mut target = CopyModeTarget::enter(&mut terminal, Position::new(0, 3));
+        assert_eq!(
+            target.handle_key(&char_key('y'), &mut terminal, 5),
+            CopyModeAction::Copy("$ make test".to_string())
+        );
+    }
+
+    #[test]
+    fn test_escape_clears_selection_then_exits() {
+        let mut terminal = terminal_with("hello");
+        let mut target = CopyModeTarget::enter(&mut terminal, Position::new(0, 0));
+        target.handle_key(&char_key('v'), &mut terminal, 5);
+        target.handle_key(&char_key('l'), &mut terminal, 5);
+        assert!(terminal.selected_text().is_some());
+
+        let escape = key(Key::Escape);
+        assert_eq!(target.handle_key(&escape, &mut terminal, 5), CopyModeAction::Handled);
+        assert!(terminal.selected_text().is_none());
+        assert_eq!(target.handle_key(&escape, &mut terminal, 5), CopyModeAction::Exit);
+    }
+
+    #[test]
+    fn test_unbound_keys_are_swallowed() {
+        let mut terminal = terminal_with("hello");
+        let mut target = CopyModeTarget::enter(&mut terminal, Position::new(0, 0));
+        assert_eq!(
+            target.handle_key(&char_key('x'), &mut terminal, 5),
+            CopyModeAction::Ignored
+        );
+        assert_eq!(target.handle_key(&ctrl('c'), &mut terminal, 5), CopyModeAction::Exit);
+    }
+}
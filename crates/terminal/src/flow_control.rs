@@ -0,0 +1,193 @@
+// Chunk: docs/chunks/terminal_flow_control - Backpressure for runaway output
+//! Flow control between the PTY reader thread and the main thread.
+//!
+//! The reader thread queues PTY output as fast as the program writes it, but
+//! `TerminalBuffer::poll_events()` only processes a byte budget per drain
+//! cycle. A program that floods output (`cat` of a huge log, a runaway loop)
+//! would otherwise grow the queue without bound, and with it memory.
+//!
+//! `FlowControl` counts the bytes queued but not yet processed. Once they
+//! reach the pause threshold the reader thread stops reading from the PTY;
+//! the kernel's PTY buffer then fills and the program blocks in `write()`,
+//! as it would on a slow serial line after XOFF. Reading resumes once the
+//! main thread has worked the queue down to the resume threshold, so the
+//! reader doesn't flap between the two states on every chunk.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+/// Shared between a `PtyHandle` and its reader thread.
+#[derive(Debug)]
+pub(crate) struct FlowControl {
+    /// Bytes queued by the reader thread and not yet taken by the main thread.
+    pending: AtomicUsize,
+    /// True while the reader thread waits for the queue to drain.
+    paused: AtomicBool,
+    /// Set when the handle is dropped, so a paused reader thread exits.
+    closed: AtomicBool,
+    pause_bytes: usize,
+    resume_bytes: usize,
+    lock: Mutex<()>,
+    drained: Condvar,
+}
+
+impl FlowControl {
+    /// Queued bytes at which the reader thread stops reading.
+    pub const PAUSE_BYTES: usize = 2 * 1024 * 1024;
+
+    /// Queued bytes at which a paused reader thread reads again.
+    pub const RESUME_BYTES: usize = 512 * 1024;
+
+    /// How often a paused reader thread checks whether the handle was dropped.
+    const CLOSE_POLL: Duration = Duration::from_millis(50);
+
+    /// Creates flow control with the default thresholds.
+    pub fn new() -> Self {
+        Self::with_thresholds(Self::PAUSE_BYTES, Self::RESUME_BYTES)
+    }
+
+    /// Creates flow control that pauses at `pause_bytes` queued and resumes
+    /// at `resume_bytes`.
+    pub fn with_thresholds(pause_bytes: usize, resume_bytes: usize) -> Self {
+        Self {
+            pending: AtomicUsize::new(0),
+            paused: AtomicBool::new(false),
+            closed: AtomicBool::new(false),
+            pause_bytes,
+            resume_bytes: resume_bytes.min(pause_bytes),
+            lock: Mutex::new(()),
+            drained: Condvar::new(),
+        }
+    }
+
+    /// Records `bytes` queued by the reader thread.
+    ///
+    /// Must be called before the bytes are sent, so the main thread never
+    /// takes more than was counted.
+    pub fn produced(&self, bytes: usize) {
+        self.pending.fetch_add(bytes, Ordering::AcqRel);
+    }
+
+    /// Records `bytes` taken off the queue by the main thread, waking a
+    /// paused reader thread once the queue is down to the resume threshold.
+    pub fn consumed(&self, bytes: usize) {
+        let before = self.pending.fetch_sub(bytes, Ordering::AcqRel);
+        let after = before.saturating_sub(bytes);
+        if after <= self.resume_bytes && self.paused.load(Ordering::Acquire) {
+            let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+            self.drained.notify_all();
+        }
+    }
+
+    /// Returns the bytes queued and not yet processed.
+    pub fn pending_bytes(&self) -> usize {
+        self.pending.load(Ordering::Acquire)
+    }
+
+    /// Returns true while the reader thread has stopped reading.
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Acquire)
+    }
+
+    /// Called by the reader thread before each read. Returns at once while
+    /// the queue is under the pause threshold; otherwise blocks until it is
+    /// down to the resume threshold.
+    ///
+    /// Returns false if the handle was dropped, telling the thread to exit.
+    pub fn wait_for_room(&self) -> bool {
+        if self.pending_bytes() < self.pause_bytes {
+            return !self.closed.load(Ordering::Acquire);
+        }
+
+        let mut guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        self.paused.store(true, Ordering::Release);
+        while self.pending_bytes() > self.resume_bytes && !self.closed.load(Ordering::Acquire) {
+            guard = match self.drained.wait_timeout(guard, Self::CLOSE_POLL) {
+                Ok((guard, _)) => guard,
+                Err(e) => e.into_inner().0,
+            };
+        }
+        self.paused.store(false, Ordering::Release);
+        !self.closed.load(Ordering::Acquire)
+    }
+
+    /// Tells the reader thread to exit, waking it if paused.
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        self.drained.notify_all();
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Instant;
+
+    /// Waits up to a second for `condition`.
+    fn eventually(condition: impl Fn() -> bool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while Instant::now() < deadline {
+            if condition() {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        false
+    }
+
+    #[test]
+    fn test_reads_freely_under_threshold() {
+        let flow = FlowControl::with_thresholds(100, 20);
+        flow.produced(99);
+        assert!(flow.wait_for_room());
+        assert!(!flow.is_paused());
+        flow.consumed(99);
+        assert_eq!(flow.pending_bytes(), 0);
+    }
+
+    #[test]
+    fn test_pauses_until_drained_to_resume_threshold() {
+        let flow = Arc::new(FlowControl::with_thresholds(100, 20));
+        flow.produced(150);
+
+        let reader = {
+            let flow = Arc::clone(&flow);
+            thread::spawn(move || flow.wait_for_room())
+        };
+        assert!(eventually(|| flow.is_paused()));
+
+        // Under the pause threshold but over the resume one: still paused
+        flow.consumed(60);
+        thread::sleep(Duration::from_millis(20));
+        assert!(flow.is_paused());
+        assert!(!reader.is_finished());
+
+        flow.consumed(70);
+        assert!(reader.join().unwrap());
+        assert!(!flow.is_paused());
+        assert_eq!(flow.pending_bytes(), 20);
+    }
+
+    #[test]
+    fn test_close_releases_paused_reader() {
+        let flow = Arc::new(FlowControl::with_thresholds(100, 20));
+        flow.produced(100);
+
+        let reader = {
+            let flow = Arc::clone(&flow);
+            thread::spawn(move || flow.wait_for_room())
+        };
+        assert!(eventually(|| flow.is_paused()));
+        flow.close();
+        assert!(!reader.join().unwrap());
+        assert!(!flow.wait_for_room());
+    }
+}
@@ -0,0 +1,854 @@
+// Chunk: docs/chunks/terminal_kitty_graphics - Kitty graphics protocol
+//! Kitty graphics protocol support.
+//!
+//! alacritty_terminal ignores APC strings, so image commands are split out of
+//! the PTY byte stream before it reaches the VTE processor:
+//!
+//! - `ApcSplitter` separates `ESC _ G ... ESC \` commands from ordinary output,
+//!   keeping its state across reads so commands split between PTY chunks work.
+//! - `KittyCommand` parses a command's `key=value` control data.
+//! - `ImageStore` decodes transmitted images and records placements anchored
+//!   to absolute buffer lines, so images scroll with the surrounding text.
+//!
+//! Supported: direct (`t=d`) transmission of RGB, RGBA and PNG data, chunked
+//! transfers (`m=1`), display (`a=T`, `a=p`), support queries (`a=q`) and
+//! deletion of everything or by image id (`a=d`). File and shared-memory
+//! transmission, zlib compression and animation are answered with
+//! `ENOTSUPPORTED`. Sixel is not supported.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::sync::Arc;
+
+use base64::alphabet;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine;
+
+const ESC: u8 = 0x1b;
+
+/// Maximum size of a single APC string. Longer strings are discarded.
+const MAX_APC_LEN: usize = 16 * 1024 * 1024;
+
+/// Maximum decoded size of one image (RGBA bytes).
+const MAX_IMAGE_BYTES: usize = 64 * 1024 * 1024;
+
+/// Maximum base64 payload accumulated across the chunks of one transmission,
+/// enough to encode the largest image allowed.
+const MAX_PAYLOAD_LEN: usize = MAX_IMAGE_BYTES.div_ceil(3) * 4;
+
+/// Budget for decoded image data kept by id for later `a=p` placements.
+const MAX_STORED_IMAGE_BYTES: usize = 256 * 1024 * 1024;
+
+/// Maximum number of placements kept; the oldest are dropped first.
+const MAX_PLACEMENTS: usize = 1024;
+
+/// Kitty payloads are standard base64, but chunked transfers may omit padding.
+const BASE64: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+// =============================================================================
+// APC Splitting
+// =============================================================================
+
+/// A piece of PTY output after graphics commands have been split out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Segment {
+    /// Ordinary output for the VTE processor.
+    Output(Vec<u8>),
+    /// The body of a Kitty graphics command (the bytes after `ESC _ G`).
+    Graphics(Vec<u8>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SplitState {
+    /// Passing bytes through.
+    Ground,
+    /// Saw ESC in ground state; waiting to see if an APC starts.
+    Escape,
+    /// Inside an APC string.
+    Apc,
+    /// Saw ESC inside an APC string; waiting for the `\` of ST.
+    ApcEscape,
+}
+
+/// Streaming splitter that extracts Kitty graphics APC strings.
+///
+/// APC strings that are not graphics commands are dropped, matching what the
+/// VTE processor would do with them.
+#[derive(Debug)]
+pub(crate) struct ApcSplitter {
+    state: SplitState,
+    apc: Vec<u8>,
+}
+
+impl ApcSplitter {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: SplitState::Ground,
+            apc: Vec::new(),
+        }
+    }
+
+    /// Returns true if `data` can be fed straight to the VTE processor.
+    ///
+    /// This is the fast path for ordinary output: the splitter is between
+    /// sequences and `data` cannot start an APC string.
+    pub(crate) fn can_pass_through(&self, data: &[u8]) -> bool {
+        self.state == SplitState::Ground
+            && data.last() != Some(&ESC)
+            && !data.windows(2).any(|w| w == [ESC, b'_'])
+    }
+
+    /// Splits `data` into output and graphics segments, in stream order.
+    pub(crate) fn split(&mut self, data: &[u8]) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        let mut output = Vec::with_capacity(data.len());
+
+        for &byte in data {
+            match self.state {
+                SplitState::Ground => {
+                    if byte == ESC {
+                        self.state = SplitState::Escape;
+                    } else {
+                        output.push(byte);
+                    }
+                }
+                SplitState::Escape => self.escape_byte(byte, &mut output),
+                SplitState::Apc => {
+                    if byte == ESC {
+                        self.state = SplitState::ApcEscape;
+                    } else if self.apc.len() < MAX_APC_LEN {
+                        self.apc.push(byte);
+                    }
+                }
+                SplitState::ApcEscape => {
+                    if byte == b'\\' {
+                        self.state = SplitState::Ground;
+                        let apc = std::mem::take(&mut self.apc);
+                        if apc.first() == Some(&b'G') && apc.len() < MAX_APC_LEN {
+                            if !output.is_empty() {
+                                segments.push(Segment::Output(std::mem::take(&mut output)));
+                            }
+                            segments.push(Segment::Graphics(apc[1..].to_vec()));
+                        }
+                    } else {
+                        // An unterminated APC aborted by a new escape sequence
+                        self.apc.clear();
+                        self.escape_byte(byte, &mut output);
+                    }
+                }
+            }
+        }
+
+        if !output.is_empty() {
+            segments.push(Segment::Output(output));
+        }
+        segments
+    }
+
+    /// Handles the byte following an ESC in ground state.
+    fn escape_byte(&mut self, byte: u8, output: &mut Vec<u8>) {
+        match byte {
+            b'_' => {
+                self.state = SplitState::Apc;
+                self.apc.clear();
+            }
+            ESC => {
+                output.push(ESC);
+                self.state = SplitState::Escape;
+            }
+            _ => {
+                output.push(ESC);
+                output.push(byte);
+                self.state = SplitState::Ground;
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Command Parsing
+// =============================================================================
+
+/// A parsed Kitty graphics command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct KittyCommand {
+    /// Action (`a`): t=transmit, T=transmit and display, p=put, d=delete, q=query.
+    action: u8,
+    /// Pixel format (`f`): 24=RGB, 32=RGBA, 100=PNG.
+    format: u32,
+    /// Transmission medium (`t`): d=direct.
+    medium: u8,
+    /// Compression (`o`), if any.
+    compression: Option<u8>,
+    /// Source width in pixels (`s`).
+    width: u32,
+    /// Source height in pixels (`v`).
+    height: u32,
+    /// Client-chosen image id (`i`), 0 if unset.
+    image_id: u32,
+    /// Whether more chunks follow (`m=1`).
+    more: bool,
+    /// Display width in cells (`c`), 0 to derive from the image size.
+    cols: u32,
+    /// Display height in cells (`r`), 0 to derive from the image size.
+    rows: u32,
+    /// Response suppression (`q`): 1 suppresses OK, 2 suppresses errors too.
+    quiet: u8,
+    /// Delete target (`d`).
+    delete: u8,
+    /// Cursor movement policy (`C`): 1 leaves the cursor in place.
+    cursor_movement: u32,
+    /// Base64 payload, still encoded.
+    payload: Vec<u8>,
+}
+
+impl KittyCommand {
+    /// Parses the body of a graphics APC (`<control data>;<payload>`).
+    ///
+    /// Unknown keys are ignored. Returns `None` if a known numeric key has a
+    /// malformed value.
+    pub(crate) fn parse(body: &[u8]) -> Option<Self> {
+        let (control, payload) = match body.iter().position(|&b| b == b';') {
+            Some(i) => (&body[..i], &body[i + 1..]),
+            None => (body, &[][..]),
+        };
+
+        let mut cmd = KittyCommand {
+            action: b't',
+            format: 32,
+            medium: b'd',
+            compression: None,
+            width: 0,
+            height: 0,
+            image_id: 0,
+            more: false,
+            cols: 0,
+            rows: 0,
+            quiet: 0,
+            delete: b'a',
+            cursor_movement: 0,
+            payload: payload.to_vec(),
+        };
+
+        for pair in control.split(|&b| b == b',').filter(|p| !p.is_empty()) {
+            let (&key, value) = match pair {
+                [key, b'=', value @ ..] if !value.is_empty() => (key, value),
+                _ => continue,
+            };
+            let number = || -> Option<u32> { std::str::from_utf8(value).ok()?.parse().ok() };
+            match key {
+                b'a' => cmd.action = value[0],
+                b't' => cmd.medium = value[0],
+                b'o' => cmd.compression = Some(value[0]),
+                b'd' => cmd.delete = value[0],
+                b'f' => cmd.format = number()?,
+                b's' => cmd.width = number()?,
+                b'v' => cmd.height = number()?,
+                b'i' => cmd.image_id = number()?,
+                b'm' => cmd.more = number()? == 1,
+                b'c' => cmd.cols = number()?,
+                b'r' => cmd.rows = number()?,
+                b'q' => cmd.quiet = number()?.min(2) as u8,
+                b'C' => cmd.cursor_movement = number()?,
+                _ => {}
+            }
+        }
+
+        Some(cmd)
+    }
+
+    /// Formats the reply for this command, or `None` if no reply is due.
+    ///
+    /// Kitty only replies to commands that carry an image id, and `q`
+    /// suppresses OK (`q=1`) or all (`q=2`) replies.
+    fn response(&self, result: &Result<(), &'static str>) -> Option<Vec<u8>> {
+        if self.image_id == 0 || self.action == b'd' {
+            return None;
+        }
+        let message = match result {
+            Ok(()) if self.quiet == 0 => "OK",
+            Err(e) if self.quiet < 2 => e,
+            _ => return None,
+        };
+        Some(format!("\x1b_Gi={};{}\x1b\\", self.image_id, message).into_bytes())
+    }
+}
+
+// =============================================================================
+// Images and Placements
+// =============================================================================
+
+/// A decoded image transmitted by the hosted program.
+#[derive(Debug)]
+pub struct TerminalImage {
+    /// Unique key for this image data (distinct per transmission).
+    key: u64,
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// Pixel data, 8-bit RGBA, row-major.
+    pub rgba: Vec<u8>,
+}
+
+impl TerminalImage {
+    /// Returns a key identifying this image's pixel data.
+    ///
+    /// Re-transmitting an image under the same protocol id produces a new key,
+    /// so renderers can cache uploaded textures by key.
+    pub fn key(&self) -> u64 {
+        self.key
+    }
+}
+
+/// An image displayed at a cell position in the terminal.
+#[derive(Debug, Clone)]
+pub struct ImagePlacement {
+    /// The image to draw.
+    pub image: Arc<TerminalImage>,
+    /// Buffer line of the image's top edge (same coordinates as `styled_line`).
+    pub line: usize,
+    /// Column of the image's left edge.
+    pub col: usize,
+    /// Width of the placement in cells.
+    pub cols: usize,
+    /// Height of the placement in cells.
+    pub rows: usize,
+    /// True if the client sized the placement in cells (`c`/`r`), so the
+    /// image is scaled to fill it; false draws it at its native pixel size.
+    pub stretch: bool,
+    /// Whether the placement belongs to the alternate screen.
+    alt_screen: bool,
+}
+
+/// A placement the terminal should anchor at its cursor.
+#[derive(Debug)]
+pub(crate) struct PlacementRequest {
+    image: Arc<TerminalImage>,
+    pub(crate) cols: usize,
+    pub(crate) rows: usize,
+    stretch: bool,
+    /// Whether the cursor moves past the image afterwards (`C` != 1).
+    pub(crate) move_cursor: bool,
+}
+
+/// Images and placements for one terminal.
+#[derive(Debug, Default)]
+pub(crate) struct ImageStore {
+    /// Images kept by client id for later `a=p` placements.
+    images: HashMap<u32, Arc<TerminalImage>>,
+    /// Placements in the order they were made.
+    placements: Vec<ImagePlacement>,
+    /// First chunk of a chunked transmission in progress.
+    pending: Option<KittyCommand>,
+    /// Whether the rest of an oversized chunked transmission is being skipped.
+    discarding: bool,
+    /// Key for the next decoded image.
+    next_key: u64,
+}
+
+impl ImageStore {
+    /// Handles one graphics command.
+    ///
+    /// `cell_size` is the terminal cell size in pixels, used to size
+    /// placements that don't specify columns and rows. Returns the reply to
+    /// write to the PTY (if any) and a placement to anchor at the cursor.
+    pub(crate) fn handle(
+        &mut self,
+        body: &[u8],
+        cell_size: (f32, f32),
+    ) -> (Option<Vec<u8>>, Option<PlacementRequest>) {
+        let Some(cmd) = KittyCommand::parse(body) else {
+            return (None, None);
+        };
+
+        if self.discarding {
+            self.discarding = cmd.more;
+            return (None, None);
+        }
+
+        // Continuation chunks carry only `m` (and maybe `q`) plus payload;
+        // the keys of the first chunk apply to the whole transmission.
+        let cmd = if let Some(mut first) = self.pending.take() {
+            if first.payload.len() + cmd.payload.len() > MAX_PAYLOAD_LEN {
+                // Drop the transfer and skip its remaining chunks
+                self.discarding = cmd.more;
+                return (first.response(&Err("EFBIG:image too large")), None);
+            }
+            first.payload.extend_from_slice(&cmd.payload);
+            if cmd.more {
+                self.pending = Some(first);
+                return (None, None);
+            }
+            first.more = false;
+            first
+        } else if cmd.more && matches!(cmd.action, b't' | b'T' | b'q') {
+            self.pending = Some(cmd);
+            return (None, None);
+        } else {
+            cmd
+        };
+
+        let (result, request) = match self.execute(&cmd, cell_size) {
+            Ok(request) => (Ok(()), request),
+            Err(e) => (Err(e), None),
+        };
+        (cmd.response(&result), request)
+    }
+
+    fn execute(
+        &mut self,
+        cmd: &KittyCommand,
+        cell_size: (f32, f32),
+    ) -> Result<Option<PlacementRequest>, &'static str> {
+        match cmd.action {
+            b't' | b'T' | b'q' => {
+                let image = self.decode(cmd)?;
+                if cmd.action == b'q' {
+                    return Ok(None);
+                }
+                let image = Arc::new(image);
+                if cmd.image_id != 0 {
+                    self.store_image(cmd.image_id, Arc::clone(&image));
+                }
+                if cmd.action == b'T' {
+                    Ok(Some(placement_request(image, cmd, cell_size)))
+                } else {
+                    Ok(None)
+                }
+            }
+            b'p' => {
+                let image = self
+                    .images
+                    .get(&cmd.image_id)
+                    .cloned()
+                    .ok_or("ENOENT:image not found")?;
+                Ok(Some(placement_request(image, cmd, cell_size)))
+            }
+            b'd' => {
+                self.delete(cmd);
+                Ok(None)
+            }
+            _ => Err("ENOTSUPPORTED:unsupported action"),
+        }
+    }
+
+    /// Decodes the payload of a transmission into RGBA pixels.
+    fn decode(&mut self, cmd: &KittyCommand) -> Result<TerminalImage, &'static str> {
+        if cmd.medium != b'd' {
+            return Err("ENOTSUPPORTED:only direct transmission is supported");
+        }
+        if cmd.compression.is_some() {
+            return Err("ENOTSUPPORTED:compression is not supported");
+        }
+
+        let data = BASE64
+            .decode(&cmd.payload)
+            .map_err(|_| "EINVAL:invalid base64 payload")?;
+
+        let (width, height, rgba) = match cmd.format {
+            24 | 32 => {
+                let bpp = (cmd.format / 8) as usize;
+                let pixels = (cmd.width as usize)
+                    .checked_mul(cmd.height as usize)
+                    .filter(|&p| p.checked_mul(4).is_some_and(|n| n <= MAX_IMAGE_BYTES))
+                    .ok_or("EFBIG:image too large")?;
+                if pixels == 0 || data.len() != pixels * bpp {
+                    return Err("EINVAL:pixel data does not match image size");
+                }
+                let rgba = if bpp == 4 {
+                    data
+                } else {
+                    data.chunks_exact(3)
+                        .flat_map(|p| [p[0], p[1], p[2], 0xff])
+                        .collect()
+                };
+                (cmd.width, cmd.height, rgba)
+            }
+            100 => decode_png(&data)?,
+            _ => return Err("EINVAL:unknown format"),
+        };
+
+        self.next_key += 1;
+        Ok(TerminalImage {
+            key: self.next_key,
+            width,
+            height,
+            rgba,
+        })
+    }
+
+    /// Keeps an image by id, evicting the oldest images over budget.
+    fn store_image(&mut self, id: u32, image: Arc<TerminalImage>) {
+        self.images.insert(id, image);
+        let mut total: usize = self.images.values().map(|i| i.rgba.len()).sum();
+        while total > MAX_STORED_IMAGE_BYTES && self.images.len() > 1 {
+            let oldest = self
+                .images
+                .iter()
+                .min_by_key(|(_, image)| image.key)
+                .map(|(&id, _)| id);
+            let Some(oldest) = oldest else { break };
+            if let Some(image) = self.images.remove(&oldest) {
+                total -= image.rgba.len();
+            }
+        }
+    }
+
+    fn delete(&mut self, cmd: &KittyCommand) {
+        match cmd.delete {
+            b'a' => self.placements.clear(),
+            b'A' => {
+                self.placements.clear();
+                self.images.clear();
+            }
+            b'i' | b'I' => {
+                let key = self.images.get(&cmd.image_id).map(|i| i.key);
+                self.placements.retain(|p| Some(p.image.key) != key);
+                if cmd.delete == b'I' {
+                    self.images.remove(&cmd.image_id);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Records a placement with its top-left cell at (`line`, `col`).
+    pub(crate) fn place(
+        &mut self,
+        request: PlacementRequest,
+        line: usize,
+        col: usize,
+        alt_screen: bool,
+    ) {
+        self.placements.push(ImagePlacement {
+            image: request.image,
+            line,
+            col,
+            cols: request.cols,
+            rows: request.rows,
+            stretch: request.stretch,
+            alt_screen,
+        });
+        if self.placements.len() > MAX_PLACEMENTS {
+            let excess = self.placements.len() - MAX_PLACEMENTS;
+            self.placements.drain(..excess);
+        }
+    }
+
+    /// Returns placements for the given screen that overlap `lines`.
+    pub(crate) fn placements_in(&self, lines: Range<usize>, alt_screen: bool) -> Vec<ImagePlacement> {
+        self.placements
+            .iter()
+            .filter(|p| p.alt_screen == alt_screen)
+            .filter(|p| p.line < lines.end && p.line + p.rows > lines.start)
+            .cloned()
+            .collect()
+    }
+
+    /// Removes placements for the given screen at or below buffer line `line`.
+    pub(crate) fn clear_from(&mut self, line: usize, alt_screen: bool) {
+        self.placements
+            .retain(|p| p.alt_screen != alt_screen || p.line + p.rows <= line);
+    }
+
+    /// Removes all alternate screen placements.
+    pub(crate) fn clear_alt_screen(&mut self) {
+        self.placements.retain(|p| !p.alt_screen);
+    }
+
+    /// Removes every placement and stored image.
+    pub(crate) fn clear(&mut self) {
+        self.placements.clear();
+        self.images.clear();
+        self.pending = None;
+        self.discarding = false;
+    }
+
+    // Chunk: docs/chunks/terminal_memory_budget - Image memory
+    /// Returns the bytes of pixel data held by stored and placed images,
+    /// counting an image that is both stored and placed once.
+    pub(crate) fn memory_bytes(&self) -> usize {
+        let mut seen = HashSet::new();
+        self.images
+            .values()
+            .chain(self.placements.iter().map(|p| &p.image))
+            .filter(|image| seen.insert(image.key))
+            .map(|image| image.rgba.len())
+            .sum()
+    }
+
+    /// Shifts primary screen placements up by `removed` lines after the
+    /// oldest scrollback lines were discarded, dropping those that scrolled
+    /// away entirely.
+    pub(crate) fn discard_lines(&mut self, removed: usize) {
+        self.placements.retain_mut(|p| {
+            if p.alt_screen {
+                return true;
+            }
+            if p.line < removed {
+                return false;
+            }
+            p.line -= removed;
+            true
+        });
+    }
+}
+
+/// Sizes a placement in cells.
+fn placement_request(
+    image: Arc<TerminalImage>,
+    cmd: &KittyCommand,
+    cell_size: (f32, f32),
+) -> PlacementRequest {
+    let cells = |cells: u32, pixels: u32, cell_px: f32| -> usize {
+        if cells > 0 {
+            cells as usize
+        } else {
+            ((pixels as f32 / cell_px.max(1.0)).ceil() as usize).max(1)
+        }
+    };
+    PlacementRequest {
+        cols: cells(cmd.cols, image.width, cell_size.0),
+        rows: cells(cmd.rows, image.height, cell_size.1),
+        stretch: cmd.cols > 0 || cmd.rows > 0,
+        move_cursor: cmd.cursor_movement != 1,
+        image,
+    }
+}
+
+/// Decodes PNG data to 8-bit RGBA.
+fn decode_png(data: &[u8]) -> Result<(u32, u32, Vec<u8>), &'static str> {
+    const INVALID: &str = "EBADPNG:invalid PNG data";
+
+    let mut decoder = png::Decoder::new(data);
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().map_err(|_| INVALID)?;
+    let (width, height) = (reader.info().width, reader.info().height);
+    let bytes = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|pixels| pixels.checked_mul(4));
+    if bytes.is_none_or(|n| n > MAX_IMAGE_BYTES) {
+        return Err("EFBIG:image too large");
+    }
+
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let frame = reader.next_frame(&mut buf).map_err(|_| INVALID)?;
+    buf.truncate(frame.buffer_size());
+
+    let rgba = match frame.color_type {
+        png::ColorType::Rgba => buf,
+        png::ColorType::Rgb => buf.chunks_exact(3).flat_map(|p| [p[0], p[1], p[2], 0xff]).collect(),
+        png::ColorType::GrayscaleAlpha => buf.chunks_exact(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+        png::ColorType::Grayscale => buf.iter().flat_map(|&g| [g, g, g, 0xff]).collect(),
+        png::ColorType::Indexed => return Err(INVALID),
+    };
+    Ok((width, height, rgba))
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CELL: (f32, f32) = (10.0, 20.0);
+
+    fn rgba_command(control: &str, width: u32, height: u32) -> Vec<u8> {
+        let pixels = vec![0x80u8; (width * height * 4) as usize];
+        format!(
+            "f=32,s={},v={},{};{}",
+            width,
+            height,
+            control,
+            base64::engine::general_purpose::STANDARD.encode(pixels)
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_splitter_passes_plain_output() {
+        let mut splitter = ApcSplitter::new();
+        assert!(splitter.can_pass_through(b"hello \x1b[1mworld"));
+        assert_eq!(
+            splitter.split(b"hello \x1b[1mworld"),
+            vec![Segment::Output(b"hello \x1b[1mworld".to_vec())]
+        );
+    }
+
+    #[test]
+    fn test_splitter_extracts_graphics_across_chunks() {
+        let mut splitter = ApcSplitter::new();
+        assert!(!splitter.can_pass_through(b"ab\x1b_Ga=T"));
+
+        let first = splitter.split(b"ab\x1b_Ga=T");
+        assert_eq!(first, vec![Segment::Output(b"ab".to_vec())]);
+
+        let second = splitter.split(b",f=32;AAAA\x1b");
+        assert!(second.is_empty());
+
+        let third = splitter.split(b"\\cd");
+        assert_eq!(
+            third,
+            vec![
+                Segment::Graphics(b"a=T,f=32;AAAA".to_vec()),
+                Segment::Output(b"cd".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_splitter_drops_other_apc_strings() {
+        let mut splitter = ApcSplitter::new();
+        assert_eq!(
+            splitter.split(b"x\x1b_other\x1b\\y"),
+            vec![Segment::Output(b"xy".to_vec())]
+        );
+    }
+
+    #[test]
+    fn test_parse_command() {
+        let cmd = KittyCommand::parse(b"a=T,f=100,i=7,c=4,r=2,q=1,m=1;QUJD").unwrap();
+        assert_eq!(cmd.action, b'T');
+        assert_eq!(cmd.format, 100);
+        assert_eq!(cmd.image_id, 7);
+        assert_eq!((cmd.cols, cmd.rows), (4, 2));
+        assert_eq!(cmd.quiet, 1);
+        assert!(cmd.more);
+        assert_eq!(cmd.payload, b"QUJD");
+
+        assert!(KittyCommand::parse(b"a=T,s=abc").is_none());
+    }
+
+    #[test]
+    fn test_transmit_and_display_sizes_from_pixels() {
+        let mut store = ImageStore::default();
+        let (response, request) = store.handle(&rgba_command("a=T,i=1", 25, 30), CELL);
+
+        assert_eq!(response.unwrap(), b"\x1b_Gi=1;OK\x1b\\");
+        let request = request.unwrap();
+        assert_eq!((request.cols, request.rows), (3, 2));
+        assert!(!request.stretch);
+        assert!(request.move_cursor);
+    }
+
+    // Chunk: docs/chunks/terminal_memory_budget - Image memory
+    #[test]
+    fn test_memory_bytes_counts_each_image_once() {
+        let mut store = ImageStore::default();
+        assert_eq!(store.memory_bytes(), 0);
+
+        // Stored under an id and placed: counted once
+        let (_, request) = store.handle(&rgba_command("a=T,i=1", 25, 30), CELL);
+        store.place(request.unwrap(), 0, 0, false);
+        assert_eq!(store.memory_bytes(), 25 * 30 * 4);
+
+        store.clear();
+        assert_eq!(store.memory_bytes(), 0);
+    }
+
+    #[test]
+    fn test_chunked_transmission_then_put() {
+        let mut store = ImageStore::default();
+        let full = rgba_command("a=t,i=5,q=2", 2, 2);
+        let split_at = full.iter().position(|&b| b == b';').unwrap() + 5;
+
+        let mut first = full[..split_at].to_vec();
+        first.splice(0..0, b"m=1,".iter().copied());
+        let mut second = b"m=0;".to_vec();
+        second.extend_from_slice(&full[split_at..]);
+
+        let (response, request) = store.handle(&first, CELL);
+        assert!(response.is_none());
+        assert!(request.is_none());
+        let (response, request) = store.handle(&second, CELL);
+        assert!(response.is_none());
+        assert!(request.is_none());
+
+        let (_, request) = store.handle(b"a=p,i=5,c=4,r=1", CELL);
+        let request = request.unwrap();
+        assert_eq!((request.cols, request.rows), (4, 1));
+        assert!(request.stretch);
+    }
+
+    #[test]
+    fn test_oversized_chunked_transmission_is_dropped() {
+        let mut store = ImageStore::default();
+        let chunk = vec![b'A'; MAX_APC_LEN / 2];
+        let mut first = b"a=t,i=6,s=1,v=1,m=1;".to_vec();
+        first.extend_from_slice(&chunk);
+        let mut more = b"m=1;".to_vec();
+        more.extend_from_slice(&chunk);
+
+        assert!(store.handle(&first, CELL).0.is_none());
+        let mut response = None;
+        for _ in 0..MAX_PAYLOAD_LEN / chunk.len() + 1 {
+            response = store.handle(&more, CELL).0;
+            if response.is_some() {
+                break;
+            }
+        }
+        let response = String::from_utf8(response.unwrap()).unwrap();
+        assert!(response.contains("i=6;EFBIG"));
+        assert!(store.pending.is_none());
+
+        // The transmission's remaining chunks are ignored
+        assert!(store.handle(&more, CELL).0.is_none());
+        assert!(store.handle(b"m=0;AAAA", CELL).0.is_none());
+        assert!(!store.discarding);
+
+        // Later transmissions work again
+        let (response, _) = store.handle(&rgba_command("a=q,i=7", 1, 1), CELL);
+        assert_eq!(response.unwrap(), b"\x1b_Gi=7;OK\x1b\\");
+    }
+
+    #[test]
+    fn test_huge_pixel_dimensions_rejected() {
+        let mut store = ImageStore::default();
+        for size in ["s=2147483648,v=2147483648", "s=4294967295,v=4294967295"] {
+            let body = format!("a=T,i=8,f=32,{}", size);
+            let (response, request) = store.handle(body.as_bytes(), CELL);
+            let response = String::from_utf8(response.unwrap()).unwrap();
+            assert!(response.contains("i=8;EFBIG"), "{}", response);
+            assert!(request.is_none());
+        }
+    }
+
+    #[test]
+    fn test_query_and_errors() {
+        let mut store = ImageStore::default();
+        let (response, request) = store.handle(&rgba_command("a=q,i=31", 1, 1), CELL);
+        assert_eq!(response.unwrap(), b"\x1b_Gi=31;OK\x1b\\");
+        assert!(request.is_none());
+
+        let (response, _) = store.handle(b"a=q,i=32,t=f;L3RtcC94", CELL);
+        assert!(String::from_utf8(response.unwrap())
+            .unwrap()
+            .contains("ENOTSUPPORTED"));
+
+        let (response, _) = store.handle(b"a=p,i=99", CELL);
+        assert!(String::from_utf8(response.unwrap()).unwrap().contains("ENOENT"));
+    }
+
+    #[test]
+    fn test_placements_query_delete_and_discard() {
+        let mut store = ImageStore::default();
+        let (_, request) = store.handle(&rgba_command("a=T,i=1,q=2", 10, 40), CELL);
+        store.place(request.unwrap(), 100, 0, false);
+
+        assert_eq!(store.placements_in(90..101, false).len(), 1);
+        assert_eq!(store.placements_in(102..110, false).len(), 0);
+        assert_eq!(store.placements_in(90..101, true).len(), 0);
+
+        store.discard_lines(60);
+        assert_eq!(store.placements_in(40..41, false)[0].line, 40);
+
+        store.handle(b"a=d,d=i,i=1", CELL);
+        assert!(store.placements_in(0..1000, false).is_empty());
+    }
+}
@@ -7,7 +7,7 @@
 //! active modes (APP_CURSOR, BRACKETED_PASTE, SGR_MOUSE, etc.).
 
 use alacritty_terminal::term::TermMode;
-use lite_edit_input::{Key, KeyEvent, Modifiers, MouseEvent, MouseEventKind};
+use lite_edit_input::{Key, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseEventKind};
 
 /// Encodes input events into terminal escape sequences.
 ///
@@ -249,6 +249,11 @@ impl InputEncoder {
             return Vec::new();
         }
 
+        // Chunk: docs/chunks/hover_tracking - Buttonless motion is any-event tracking only
+        if event.kind == MouseEventKind::Hover && !modes.contains(TermMode::MOUSE_MOTION) {
+            return Vec::new();
+        }
+
         // Determine button code
         let button = Self::mouse_button_code(event, &event.modifiers);
 
@@ -269,20 +274,34 @@ impl InputEncoder {
     /// - 3 = release (in normal mode)
     /// - 64 = scroll up
     /// - 65 = scroll down
+    /// - 128 = back button (xterm button 8)
+    /// - 129 = forward button (xterm button 9)
     ///
     /// Modifier bits are added:
     /// - 4 = Shift
     /// - 8 = Alt/Option
     /// - 16 = Ctrl
     /// - 32 = motion (for drag events)
+    ///
+    /// Motion with no button held is reported as 35 (motion + release).
     fn mouse_button_code(event: &MouseEvent, modifiers: &Modifiers) -> u8 {
-        // Base button code
-        // For now, assume left button (0). In a real implementation,
-        // we'd need to track which button was pressed.
+        // Chunk: docs/chunks/mouse_buttons - Report the button actually pressed
+        let pressed: u8 = match event.button {
+            MouseButton::Left => 0,
+            MouseButton::Middle => 1,
+            MouseButton::Right => 2,
+            // AppKit buttons 3 and up are xterm buttons 8 and up
+            MouseButton::Other(number) => 128 + number.saturating_sub(3).min(3),
+        };
         let mut button: u8 = match event.kind {
-            MouseEventKind::Down => 0,      // Left button press
-            MouseEventKind::Up => 3,        // Release
-            MouseEventKind::Moved => 32,    // Motion (with button 0 held)
+            MouseEventKind::Down => pressed,
+            MouseEventKind::Up => 3,               // Release
+            MouseEventKind::Moved => 32 + pressed, // Motion with the button held
+            // Chunk: docs/chunks/cursor_position_history - Extended navigation buttons
+            MouseEventKind::Back => 128,    // Button 8
+            MouseEventKind::Forward => 129, // Button 9
+            // Chunk: docs/chunks/hover_tracking - Motion with no button held
+            MouseEventKind::Hover => 35,
         };
 
         // Add modifier bits
@@ -400,6 +419,36 @@ impl InputEncoder {
 
         result
     }
+
+    // Chunk: docs/chunks/alt_screen_scroll_passthrough - Wheel as arrow keys on the alternate screen
+    /// Encode a scroll wheel event as arrow keys for alternate screen programs
+    /// that don't enable mouse reporting (less, man, git log).
+    ///
+    /// This is xterm's "alternate scroll" mode (DECSET 1007): each line scrolled
+    /// becomes one Up or Down arrow press, in the encoding the program asked
+    /// for (APP_CURSOR or normal).
+    ///
+    /// Returns an empty vector outside the alternate screen, when alternate
+    /// scroll is turned off, or when a mouse mode is active (the wheel is then
+    /// reported with [`Self::encode_scroll`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - Number of lines to scroll (positive = down, negative = up)
+    /// * `modes` - Terminal mode flags
+    pub fn encode_alternate_scroll(lines: i32, modes: TermMode) -> Vec<u8> {
+        if !modes.contains(TermMode::ALT_SCREEN | TermMode::ALTERNATE_SCROLL)
+            || modes.intersects(
+                TermMode::MOUSE_REPORT_CLICK | TermMode::MOUSE_MOTION | TermMode::MOUSE_DRAG,
+            )
+        {
+            return Vec::new();
+        }
+
+        let direction = if lines < 0 { b'A' } else { b'B' };
+        let arrow = Self::encode_arrow(direction, &Modifiers::default(), modes);
+        arrow.repeat(lines.unsigned_abs() as usize)
+    }
 }
 
 #[cfg(test)]
@@ -443,6 +492,7 @@ mod tests {
                 control: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         let result = InputEncoder::encode_key(&event, TermMode::NONE);
         assert_eq!(result, vec![0x03]); // ETX
@@ -456,6 +506,7 @@ mod tests {
                 control: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         let result = InputEncoder::encode_key(&event, TermMode::NONE);
         assert_eq!(result, vec![0x04]); // EOT
@@ -469,6 +520,7 @@ mod tests {
                 control: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         let result = InputEncoder::encode_key(&event, TermMode::NONE);
         assert_eq!(result, vec![0x1a]); // SUB
@@ -482,6 +534,7 @@ mod tests {
                 control: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         let result = InputEncoder::encode_key(&event, TermMode::NONE);
         assert_eq!(result, vec![0x1b]); // ESC
@@ -558,6 +611,7 @@ mod tests {
                 shift: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         // Shift = modifier code 2
         assert_eq!(InputEncoder::encode_key(&event, TermMode::NONE), b"\x1b[1;2A");
@@ -571,6 +625,7 @@ mod tests {
                 control: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         // Ctrl = modifier code 5
         assert_eq!(InputEncoder::encode_key(&event, TermMode::NONE), b"\x1b[1;5C");
@@ -585,6 +640,7 @@ mod tests {
                 control: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         // Shift + Ctrl = modifier code 6
         assert_eq!(InputEncoder::encode_key(&event, TermMode::NONE), b"\x1b[1;6D");
@@ -658,6 +714,7 @@ mod tests {
                 shift: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         assert_eq!(InputEncoder::encode_key(&event, TermMode::NONE), b"\x1b[15;2~");
     }
@@ -723,6 +780,7 @@ mod tests {
                 option: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         let result = InputEncoder::encode_key(&event, TermMode::NONE);
         assert_eq!(result, b"\x1ba"); // ESC + a
@@ -737,6 +795,7 @@ mod tests {
                 option: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         let result = InputEncoder::encode_key(&event, TermMode::NONE);
         // Alt+Backspace should send ESC + DEL for backward word delete
@@ -752,6 +811,7 @@ mod tests {
                 command: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         let result = InputEncoder::encode_key(&event, TermMode::NONE);
         // Cmd+Backspace should send Ctrl+U (NAK) for kill-line-backward
@@ -785,6 +845,7 @@ mod tests {
             position: (100.0, 200.0),
             modifiers: Modifiers::default(),
             click_count: 1,
+            button: MouseButton::Left,
         };
         let result = InputEncoder::encode_mouse(&event, 10, 5, TermMode::NONE);
         assert!(result.is_empty());
@@ -797,6 +858,7 @@ mod tests {
             position: (100.0, 200.0),
             modifiers: Modifiers::default(),
             click_count: 1,
+            button: MouseButton::Left,
         };
         let result = InputEncoder::encode_mouse(&event, 10, 5, TermMode::MOUSE_REPORT_CLICK | TermMode::SGR_MOUSE);
         // Button 0 (left), col 11 (1-based), row 6 (1-based), M for press
@@ -810,6 +872,7 @@ mod tests {
             position: (100.0, 200.0),
             modifiers: Modifiers::default(),
             click_count: 1,
+            button: MouseButton::Left,
         };
         let result = InputEncoder::encode_mouse(&event, 10, 5, TermMode::MOUSE_REPORT_CLICK | TermMode::SGR_MOUSE);
         // Button 3 (release), lowercase m for release
@@ -823,6 +886,7 @@ mod tests {
             position: (100.0, 200.0),
             modifiers: Modifiers::default(),
             click_count: 1,
+            button: MouseButton::Left,
         };
         let result = InputEncoder::encode_mouse(&event, 10, 5, TermMode::MOUSE_REPORT_CLICK);
         // ESC [ M, button+32, x+32+1, y+32+1
@@ -841,12 +905,68 @@ mod tests {
                 ..Default::default()
             },
             click_count: 1,
+            button: MouseButton::Left,
         };
         let result = InputEncoder::encode_mouse(&event, 10, 5, TermMode::MOUSE_REPORT_CLICK | TermMode::SGR_MOUSE);
         // Button 0 + shift(4) + ctrl(16) = 20
         assert_eq!(result, b"\x1b[<20;11;6M");
     }
 
+    // Chunk: docs/chunks/cursor_position_history - Extended navigation buttons
+    #[test]
+    fn test_encode_mouse_sgr_back_forward_buttons() {
+        let mut event = MouseEvent {
+            kind: MouseEventKind::Back,
+            position: (100.0, 200.0),
+            modifiers: Modifiers::default(),
+            click_count: 1,
+            button: MouseButton::Left,
+        };
+        let modes = TermMode::MOUSE_REPORT_CLICK | TermMode::SGR_MOUSE;
+        assert_eq!(InputEncoder::encode_mouse(&event, 10, 5, modes), b"\x1b[<128;11;6M");
+
+        event.kind = MouseEventKind::Forward;
+        assert_eq!(InputEncoder::encode_mouse(&event, 10, 5, modes), b"\x1b[<129;11;6M");
+    }
+
+    // Chunk: docs/chunks/mouse_buttons - Middle and right buttons
+    #[test]
+    fn test_encode_mouse_sgr_middle_and_right_buttons() {
+        let mut event = MouseEvent {
+            kind: MouseEventKind::Down,
+            position: (100.0, 200.0),
+            modifiers: Modifiers::default(),
+            click_count: 1,
+            button: MouseButton::Middle,
+        };
+        let modes = TermMode::MOUSE_REPORT_CLICK | TermMode::SGR_MOUSE;
+        assert_eq!(InputEncoder::encode_mouse(&event, 10, 5, modes), b"\x1b[<1;11;6M");
+
+        event.button = MouseButton::Right;
+        assert_eq!(InputEncoder::encode_mouse(&event, 10, 5, modes), b"\x1b[<2;11;6M");
+
+        // Dragging with the right button held
+        event.kind = MouseEventKind::Moved;
+        assert_eq!(InputEncoder::encode_mouse(&event, 10, 5, modes), b"\x1b[<34;11;6M");
+    }
+
+    // Chunk: docs/chunks/hover_tracking - Motion with no button held
+    #[test]
+    fn test_encode_mouse_hover_needs_any_event_tracking() {
+        let event = MouseEvent {
+            kind: MouseEventKind::Hover,
+            position: (100.0, 200.0),
+            modifiers: Modifiers::default(),
+            click_count: 0,
+            button: MouseButton::Left,
+        };
+        let drag_only = TermMode::MOUSE_DRAG | TermMode::SGR_MOUSE;
+        assert!(InputEncoder::encode_mouse(&event, 10, 5, drag_only).is_empty());
+
+        let any_motion = TermMode::MOUSE_MOTION | TermMode::SGR_MOUSE;
+        assert_eq!(InputEncoder::encode_mouse(&event, 10, 5, any_motion), b"\x1b[<35;11;6M");
+    }
+
     // =========================================================================
     // Scroll Encoding Tests
     // Chunk: docs/chunks/terminal_scrollback_viewport - Scroll wheel encoding tests
@@ -917,4 +1037,25 @@ mod tests {
         // Button 65 + shift(4) + ctrl(16) = 85
         assert_eq!(result, b"\x1b[<85;11;6M");
     }
+
+    // Chunk: docs/chunks/alt_screen_scroll_passthrough - Alternate scroll tests
+    #[test]
+    fn test_encode_alternate_scroll_arrows() {
+        let modes = TermMode::ALT_SCREEN | TermMode::ALTERNATE_SCROLL;
+        assert_eq!(InputEncoder::encode_alternate_scroll(2, modes), b"\x1b[B\x1b[B");
+        assert_eq!(InputEncoder::encode_alternate_scroll(-1, modes), b"\x1b[A");
+        assert!(InputEncoder::encode_alternate_scroll(0, modes).is_empty());
+
+        let app_cursor = modes | TermMode::APP_CURSOR;
+        assert_eq!(InputEncoder::encode_alternate_scroll(-1, app_cursor), b"\x1bOA");
+    }
+
+    #[test]
+    fn test_encode_alternate_scroll_requires_mode() {
+        // Primary screen, alternate scroll turned off, or mouse reporting on
+        assert!(InputEncoder::encode_alternate_scroll(1, TermMode::ALTERNATE_SCROLL).is_empty());
+        assert!(InputEncoder::encode_alternate_scroll(1, TermMode::ALT_SCREEN).is_empty());
+        let mouse = TermMode::ALT_SCREEN | TermMode::ALTERNATE_SCROLL | TermMode::MOUSE_REPORT_CLICK;
+        assert!(InputEncoder::encode_alternate_scroll(1, mouse).is_empty());
+    }
 }
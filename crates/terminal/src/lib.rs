@@ -69,19 +69,46 @@
 //! ```
 
 mod agent;
+// Chunk: docs/chunks/agent_events - Structured agent events
+mod agent_events;
+// Chunk: docs/chunks/agent_queue - Queue of agents run a few at a time
+mod agent_queue;
 mod cold_scrollback;
+// Chunk: docs/chunks/terminal_copy_mode - Keyboard-driven scrollback selection
+mod copy_mode;
 mod event;
+// Chunk: docs/chunks/terminal_flow_control - Backpressure for runaway output
+mod flow_control;
+// Chunk: docs/chunks/terminal_kitty_graphics - Kitty graphics protocol
+mod graphics;
 // Chunk: docs/chunks/terminal_input_encoding - Terminal input encoding
 mod input_encoder;
+// Chunk: docs/chunks/agent_resource_usage - CPU and memory of a process tree
+mod process_usage;
 mod pty;
 // Chunk: docs/chunks/terminal_pty_wakeup - Run-loop wakeup for PTY output
 mod pty_wakeup;
+// Chunk: docs/chunks/terminal_replay - Timestamped session recording and replay
+mod recording;
+mod replay;
+// Chunk: docs/chunks/terminal_cwd_picker - Shell integration sequences
+mod shell_integration;
 mod style_convert;
 mod terminal_buffer;
 // Chunk: docs/chunks/terminal_input_encoding - Terminal input encoding
 mod terminal_target;
+// Chunk: docs/chunks/agent_transcript - Session transcript capture
+mod transcript;
 
 pub use agent::{AgentConfig, AgentHandle, AgentState, AgentStateMachine};
+// Chunk: docs/chunks/agent_events - Structured agent events
+pub use agent_events::{AgentEvent, AgentTimeline, TimelineEntry};
+// Chunk: docs/chunks/agent_queue - Queue of agents run a few at a time
+pub use agent_queue::{AgentQueue, QueuedAgent, QueuedAgentId};
+// Chunk: docs/chunks/agent_resource_usage - CPU and memory of a process tree
+pub use process_usage::ResourceUsage;
+// Chunk: docs/chunks/terminal_copy_mode - Copy mode focus target
+pub use copy_mode::{CopyModeAction, CopyModeTarget};
 // Chunk: docs/chunks/terminal_input_encoding - Terminal input encoding
 pub use input_encoder::InputEncoder;
 // Chunk: docs/chunks/terminal_pty_wakeup - Run-loop wakeup for PTY output
@@ -92,8 +119,25 @@ pub use pty_wakeup::PtyWakeup;
 pub use lite_edit_input::WakeupSignal;
 // Chunk: docs/chunks/terminal_flood_starvation - Byte-budgeted VTE processing
 pub use terminal_buffer::{PollResult, TerminalBuffer};
+// Chunk: docs/chunks/terminal_memory_budget - Per-terminal memory report
+pub use terminal_buffer::MemoryUsage;
+// Chunk: docs/chunks/pty_graceful_shutdown - Shutdown of all terminals on quit
+pub use terminal_buffer::shutdown_terminals;
+// Chunk: docs/chunks/terminal_scrollback_export - Scrollback export format
+pub use terminal_buffer::ScrollbackFormat;
+// Chunk: docs/chunks/terminal_kitty_graphics - Image placement types for rendering
+pub use graphics::{ImagePlacement, TerminalImage};
+// Chunk: docs/chunks/terminal_shell_integration - Prompt marks for the gutter
+pub use shell_integration::{CommandMark, CommandStatus};
+// Chunk: docs/chunks/terminal_command_status - Duration format for notifications
+pub use shell_integration::format_duration;
 // Chunk: docs/chunks/terminal_scrollback_viewport - Terminal scroll action result
 pub use terminal_target::{ScrollAction, TerminalFocusTarget};
+// Chunk: docs/chunks/agent_transcript - Session transcript capture
+pub use transcript::{Transcript, TranscriptConfig};
+// Chunk: docs/chunks/terminal_replay - Timestamped session recording and replay
+pub use recording::{Recording, RecordingSnapshot};
+pub use replay::TerminalReplay;
 
 // Re-export BufferView and related types for convenience
 pub use lite_edit_buffer::{BufferView, CursorInfo, CursorShape, DirtyLines, Position, StyledLine};
@@ -0,0 +1,366 @@
+// Chunk: docs/chunks/agent_resource_usage - CPU and memory of a process tree
+//! CPU and memory use of a process and everything it has spawned.
+//!
+//! An agent is rarely one process: a CLI agent runs shells, compilers and
+//! test runners below it. [`UsageSampler`] walks the tree under the root
+//! process at each sample, sums resident memory, and derives CPU use from
+//! how much CPU time the tree consumed since the previous sample. Children
+//! that exit between samples take their CPU time with them, so a tree that
+//! churns through short-lived processes reads lower than it really is.
+
+use std::time::{Duration, Instant};
+
+/// CPU and memory use of a process tree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResourceUsage {
+    /// CPU use since the previous sample, where 100 is one core kept busy.
+    pub cpu_percent: f32,
+    /// Resident memory of all processes in the tree.
+    pub memory_bytes: u64,
+    /// Number of processes in the tree, including the root.
+    pub processes: usize,
+}
+
+/// One process as seen by a sample.
+#[derive(Debug, Clone, Copy)]
+struct ProcessSample {
+    /// User plus system CPU time consumed so far.
+    cpu_time: Duration,
+    memory_bytes: u64,
+}
+
+/// A process in a list of every process, where the tree is found by
+/// following parent ids.
+#[cfg(any(target_os = "linux", test))]
+#[derive(Debug, Clone, Copy)]
+struct ListedProcess {
+    pid: u32,
+    parent: u32,
+    sample: ProcessSample,
+}
+
+/// Samples the resource use of a process tree at most once per interval.
+#[derive(Debug)]
+pub(crate) struct UsageSampler {
+    usage: Option<ResourceUsage>,
+    /// When the tree was last sampled, and its total CPU time then.
+    last: Option<(Instant, Duration)>,
+}
+
+impl UsageSampler {
+    /// How often the tree is sampled.
+    pub const INTERVAL: Duration = Duration::from_secs(1);
+
+    pub fn new() -> Self {
+        Self {
+            usage: None,
+            last: None,
+        }
+    }
+
+    /// Returns the latest sample, or None before the first one and once the
+    /// root process is gone.
+    pub fn usage(&self) -> Option<ResourceUsage> {
+        self.usage
+    }
+
+    /// Samples the tree under `root` if the interval has passed since the
+    /// last sample.
+    pub fn sample_if_due(&mut self, root: u32, now: Instant) {
+        if self
+            .last
+            .is_some_and(|(at, _)| now.duration_since(at) < Self::INTERVAL)
+        {
+            return;
+        }
+        let tree = process_tree(root);
+        self.record(&tree, now);
+    }
+
+    /// Forgets the previous samples, for when the process has exited.
+    pub fn clear(&mut self) {
+        self.usage = None;
+        self.last = None;
+    }
+
+    fn record(&mut self, tree: &[ProcessSample], now: Instant) {
+        if tree.is_empty() {
+            self.clear();
+            return;
+        }
+        let cpu_time: Duration = tree.iter().map(|process| process.cpu_time).sum();
+        let memory_bytes = tree.iter().map(|process| process.memory_bytes).sum();
+
+        // CPU use needs two samples; until then only memory is known
+        let cpu_percent = match self.last {
+            Some((at, last_cpu)) => {
+                let wall = now.duration_since(at).as_secs_f32();
+                if wall > 0.0 {
+                    cpu_time.saturating_sub(last_cpu).as_secs_f32() / wall * 100.0
+                } else {
+                    0.0
+                }
+            }
+            None => 0.0,
+        };
+        self.usage = Some(ResourceUsage {
+            cpu_percent,
+            memory_bytes,
+            processes: tree.len(),
+        });
+        self.last = Some((now, cpu_time));
+    }
+}
+
+/// Returns `root` and its descendants out of `all`, root first.
+#[cfg(any(target_os = "linux", test))]
+fn tree_members(root: u32, all: &[ListedProcess]) -> Vec<ListedProcess> {
+    let Some(root) = all.iter().find(|process| process.pid == root) else {
+        return Vec::new();
+    };
+    let mut tree = vec![*root];
+    let mut next = 0;
+    while next < tree.len() {
+        let parent = tree[next].pid;
+        tree.extend(
+            all.iter()
+                .filter(|process| process.parent == parent && process.pid != parent),
+        );
+        next += 1;
+    }
+    tree
+}
+
+/// Returns `root` and its descendants, or nothing if `root` isn't running.
+#[cfg(target_os = "linux")]
+fn process_tree(root: u32) -> Vec<ProcessSample> {
+    // SAFETY: sysconf has no preconditions.
+    let (ticks_per_sec, page_size) = unsafe {
+        (
+            libc::sysconf(libc::_SC_CLK_TCK),
+            libc::sysconf(libc::_SC_PAGESIZE),
+        )
+    };
+    if ticks_per_sec <= 0 || page_size <= 0 {
+        return Vec::new();
+    }
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    let all: Vec<ListedProcess> = entries
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str()?.parse::<u32>().ok())
+        .filter_map(|pid| {
+            let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+            parse_stat(pid, &stat, ticks_per_sec as u64, page_size as u64)
+        })
+        .collect();
+    tree_members(root, &all)
+        .into_iter()
+        .map(|process| process.sample)
+        .collect()
+}
+
+/// Parses `/proc/<pid>/stat`.
+#[cfg(target_os = "linux")]
+fn parse_stat(pid: u32, stat: &str, ticks_per_sec: u64, page_size: u64) -> Option<ListedProcess> {
+    // The command name is parenthesized and may itself contain spaces or
+    // parentheses, so fields are counted from the last ')': state, ppid, ...
+    let fields: Vec<&str> = stat
+        .get(stat.rfind(')')? + 1..)?
+        .split_whitespace()
+        .collect();
+    let field = |index: usize| fields.get(index)?.parse::<u64>().ok();
+    let ticks = field(11)? + field(12)?;
+    Some(ListedProcess {
+        pid,
+        parent: field(1)? as u32,
+        sample: ProcessSample {
+            cpu_time: Duration::from_nanos(ticks * 1_000_000_000 / ticks_per_sec),
+            memory_bytes: field(21)? * page_size,
+        },
+    })
+}
+
+/// Returns `root` and its descendants, or nothing if `root` isn't running.
+#[cfg(target_os = "macos")]
+fn process_tree(root: u32) -> Vec<ProcessSample> {
+    let mut tree = Vec::new();
+    let mut pending = vec![root];
+    while let Some(pid) = pending.pop() {
+        let Some(sample) = task_info(pid) else {
+            continue;
+        };
+        tree.push(sample);
+        pending.extend(child_pids(pid));
+    }
+    tree
+}
+
+/// Returns the CPU time and resident memory of `pid`.
+#[cfg(target_os = "macos")]
+fn task_info(pid: u32) -> Option<ProcessSample> {
+    let mut info: libc::proc_taskinfo = unsafe { std::mem::zeroed() };
+    let size = std::mem::size_of::<libc::proc_taskinfo>() as libc::c_int;
+    // SAFETY: proc_pidinfo writes at most `size` bytes into `info`, which is
+    // a zeroed proc_taskinfo.
+    let written = unsafe {
+        libc::proc_pidinfo(
+            pid as libc::c_int,
+            libc::PROC_PIDTASKINFO,
+            0,
+            &mut info as *mut _ as *mut libc::c_void,
+            size,
+        )
+    };
+    if written != size {
+        return None;
+    }
+    // CPU times are in Mach absolute time units, not nanoseconds, on Apple
+    // silicon
+    let mut timebase = mach2::mach_time::mach_timebase_info { numer: 0, denom: 0 };
+    // SAFETY: mach_timebase_info fills in the struct it is given.
+    let ok = unsafe { mach2::mach_time::mach_timebase_info(&mut timebase) } == 0
+        && timebase.denom != 0;
+    let ticks = info.pti_total_user + info.pti_total_system;
+    let nanos = if ok {
+        (ticks as u128 * timebase.numer as u128 / timebase.denom as u128) as u64
+    } else {
+        ticks
+    };
+    Some(ProcessSample {
+        cpu_time: Duration::from_nanos(nanos),
+        memory_bytes: info.pti_resident_size,
+    })
+}
+
+/// Returns the direct children of `pid`.
+#[cfg(target_os = "macos")]
+fn child_pids(pid: u32) -> Vec<u32> {
+    let mut pids: Vec<libc::pid_t> = vec![0; 64];
+    loop {
+        let size = (pids.len() * std::mem::size_of::<libc::pid_t>()) as libc::c_int;
+        // SAFETY: proc_listchildpids writes at most `size` bytes of pids into
+        // the buffer and returns how many it wrote.
+        let count = unsafe {
+            libc::proc_listchildpids(
+                pid as libc::pid_t,
+                pids.as_mut_ptr() as *mut libc::c_void,
+                size,
+            )
+        };
+        if count <= 0 {
+            return Vec::new();
+        }
+        let count = count as usize;
+        // A full buffer may have been cut short; retry with a bigger one
+        if count < pids.len() || pids.len() >= 4096 {
+            return pids[..count.min(pids.len())]
+                .iter()
+                .map(|&pid| pid as u32)
+                .collect();
+        }
+        pids.resize(pids.len() * 2, 0);
+    }
+}
+
+/// Returns `root` and its descendants, or nothing if `root` isn't running.
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn process_tree(_root: u32) -> Vec<ProcessSample> {
+    Vec::new()
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(cpu_ms: u64, memory_bytes: u64) -> ProcessSample {
+        ProcessSample {
+            cpu_time: Duration::from_millis(cpu_ms),
+            memory_bytes,
+        }
+    }
+
+    fn listed(pid: u32, parent: u32) -> ListedProcess {
+        ListedProcess {
+            pid,
+            parent,
+            sample: process(0, 1024),
+        }
+    }
+
+    #[test]
+    fn test_tree_members_follows_descendants_only() {
+        let all = [
+            listed(1, 0),
+            listed(10, 1),
+            listed(11, 10),
+            listed(12, 11),
+            listed(20, 1),
+        ];
+        let tree = tree_members(10, &all);
+        let pids: Vec<u32> = tree.iter().map(|p| p.pid).collect();
+        assert_eq!(pids, [10, 11, 12]);
+        let memory: u64 = tree.iter().map(|p| p.sample.memory_bytes).sum();
+        assert_eq!(memory, 3 * 1024);
+        assert!(tree_members(99, &all).is_empty());
+    }
+
+    #[test]
+    fn test_cpu_percent_from_cpu_time_between_samples() {
+        let mut sampler = UsageSampler::new();
+        let start = Instant::now();
+        sampler.record(
+            &[process(1000, 4096), process(0, 1024)],
+            start,
+        );
+        let first = sampler.usage().unwrap();
+        assert_eq!(first.cpu_percent, 0.0);
+        assert_eq!(first.memory_bytes, 5120);
+        assert_eq!(first.processes, 2);
+
+        // 2 s of CPU over 1 s of wall time across two processes
+        let later = start + Duration::from_secs(1);
+        sampler.record(
+            &[process(2000, 4096), process(1000, 1024)],
+            later,
+        );
+        assert!((sampler.usage().unwrap().cpu_percent - 200.0).abs() < 0.01);
+
+        // The root has gone
+        sampler.record(&[], later + Duration::from_secs(1));
+        assert_eq!(sampler.usage(), None);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[test]
+    fn test_samples_a_running_process_tree() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("5")
+            .spawn()
+            .unwrap();
+        let mut sampler = UsageSampler::new();
+        sampler.sample_if_due(std::process::id(), Instant::now());
+        let usage = sampler.usage().unwrap();
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert!(usage.memory_bytes > 0);
+        assert!(usage.processes >= 2);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_stat_with_parenthesized_name() {
+        let stat = "42 (a (weird) name) S 7 42 42 0 -1 4194560 100 0 0 0 250 50 0 0 20 0 1 0 \
+                    1000 12345678 300 18446744073709551615";
+        let process = parse_stat(42, stat, 100, 4096).unwrap();
+        assert_eq!(process.parent, 7);
+        assert_eq!(process.sample.cpu_time, Duration::from_secs(3));
+        assert_eq!(process.sample.memory_bytes, 300 * 4096);
+    }
+}
@@ -5,13 +5,17 @@
 //! on a background thread.
 
 use std::io::{Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
 
 use crate::event::TerminalEvent;
+// Chunk: docs/chunks/terminal_flow_control - Backpressure for runaway output
+use crate::flow_control::FlowControl;
 // Chunk: docs/chunks/terminal_pty_wakeup - Run-loop wakeup for PTY output
 use crate::pty_wakeup::PtyWakeup;
 
@@ -66,6 +70,10 @@ pub struct PtyHandle {
     /// Receiver that signals when the reader thread has exited.
     /// Used by Drop to perform a timed join of the reader thread.
     reader_done_rx: Receiver<()>,
+    // Chunk: docs/chunks/terminal_flow_control - Backpressure for runaway output
+    /// Output queued by the reader thread, which pauses reading when too
+    /// much of it is waiting.
+    flow: Arc<FlowControl>,
 }
 
 impl PtyHandle {
@@ -160,16 +168,21 @@ impl PtyHandle {
 
         // Spawn reader thread
         let tx = event_tx.clone();
+        let flow = Arc::new(FlowControl::new());
+        let reader_flow = Arc::clone(&flow);
         let reader_thread = thread::spawn(move || {
             let mut buf = [0u8; 4096];
-            loop {
+            // Chunk: docs/chunks/terminal_flow_control - Stop reading while the queue drains
+            while reader_flow.wait_for_room() {
                 match reader.read(&mut buf) {
                     Ok(0) => {
                         // EOF - PTY closed
                         break;
                     }
                     Ok(n) => {
-                        // Send output to main thread
+                        // Send output to main thread, counted first so the
+                        // main thread never takes more than was counted
+                        reader_flow.produced(n);
                         if tx.send(TerminalEvent::PtyOutput(buf[..n].to_vec())).is_err() {
                             // Channel closed, main thread dropped
                             break;
@@ -193,6 +206,7 @@ impl PtyHandle {
             reader_thread: Some(reader_thread),
             event_tx,
             reader_done_rx: done_rx,
+            flow,
         })
     }
 
@@ -287,16 +301,21 @@ impl PtyHandle {
 
         // Spawn reader thread with wakeup support
         let tx = event_tx.clone();
+        let flow = Arc::new(FlowControl::new());
+        let reader_flow = Arc::clone(&flow);
         let reader_thread = thread::spawn(move || {
             let mut buf = [0u8; 4096];
-            loop {
+            // Chunk: docs/chunks/terminal_flow_control - Stop reading while the queue drains
+            while reader_flow.wait_for_room() {
                 match reader.read(&mut buf) {
                     Ok(0) => {
                         // EOF - PTY closed
                         break;
                     }
                     Ok(n) => {
-                        // Send output to main thread
+                        // Send output to main thread, counted first so the
+                        // main thread never takes more than was counted
+                        reader_flow.produced(n);
                         if tx.send(TerminalEvent::PtyOutput(buf[..n].to_vec())).is_err() {
                             // Channel closed, main thread dropped
                             break;
@@ -322,6 +341,7 @@ impl PtyHandle {
             reader_thread: Some(reader_thread),
             event_tx,
             reader_done_rx: done_rx,
+            flow,
         })
     }
 
@@ -333,12 +353,16 @@ impl PtyHandle {
     }
 
     /// Resizes the PTY to the given dimensions.
-    pub fn resize(&self, rows: u16, cols: u16) -> std::io::Result<()> {
+    ///
+    /// The pixel size is reported to programs through `TIOCGWINSZ`; pass 0
+    /// if unknown.
+    // Chunk: docs/chunks/terminal_kitty_graphics - Report pixel size for image sizing
+    pub fn resize(&self, rows: u16, cols: u16, pixel_width: u16, pixel_height: u16) -> std::io::Result<()> {
         let size = PtySize {
             rows,
             cols,
-            pixel_width: 0,
-            pixel_height: 0,
+            pixel_width,
+            pixel_height,
         };
         self.master
             .resize(size)
@@ -353,7 +377,25 @@ impl PtyHandle {
 
     /// Tries to receive an event without blocking.
     pub fn try_recv(&self) -> Option<TerminalEvent> {
-        self.event_rx.try_recv().ok()
+        let event = self.event_rx.try_recv().ok()?;
+        // Chunk: docs/chunks/terminal_flow_control - Taking output makes room for more
+        if let TerminalEvent::PtyOutput(ref data) = event {
+            self.flow.consumed(data.len());
+        }
+        Some(event)
+    }
+
+    // Chunk: docs/chunks/terminal_flow_control - Backlog and throttled state
+    /// Returns the bytes of output read from the PTY and not yet taken with
+    /// `try_recv()`.
+    pub fn pending_output_bytes(&self) -> usize {
+        self.flow.pending_bytes()
+    }
+
+    /// Returns true while the reader thread has stopped reading because too
+    /// much output is waiting to be processed.
+    pub fn is_output_throttled(&self) -> bool {
+        self.flow.is_paused()
     }
 
     /// Checks if the child process has exited.
@@ -382,12 +424,125 @@ impl PtyHandle {
     pub fn process_id(&self) -> Option<u32> {
         self.child.process_id()
     }
+
+    // Chunk: docs/chunks/quit_running_summary - Foreground job detection
+    /// Returns the PTY's foreground process group, if it can be read.
+    ///
+    /// This is the shell's own process group at the prompt, and the job's
+    /// process group while a command started from the shell is running.
+    pub fn foreground_process_group(&self) -> Option<u32> {
+        self.master.process_group_leader().map(|pgid| pgid as u32)
+    }
+
+    // Chunk: docs/chunks/terminal_cwd_picker - Process working directory fallback
+    /// Returns the child process's current working directory, read from the
+    /// operating system (procfs on Linux, libproc on macOS).
+    pub fn working_directory(&self) -> Option<PathBuf> {
+        process_cwd(self.process_id()?)
+    }
+
+    // Chunk: docs/chunks/pty_graceful_shutdown - Signal delivery
+    /// Sends `signal` to the child process.
+    pub fn signal(&self, signal: i32) -> std::io::Result<()> {
+        let pid = self.process_id().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "No child process ID")
+        })?;
+        // SAFETY: libc::kill is a standard POSIX function. We're signalling a
+        // process we spawned and own.
+        if unsafe { libc::kill(pid as i32, signal) } == 0 {
+            Ok(())
+        } else {
+            Err(std::io::Error::last_os_error())
+        }
+    }
+}
+
+// Chunk: docs/chunks/terminal_cwd_picker - Process working directory fallback
+/// Returns the current working directory of process `pid`.
+#[cfg(target_os = "linux")]
+fn process_cwd(pid: u32) -> Option<PathBuf> {
+    std::fs::read_link(format!("/proc/{}/cwd", pid)).ok()
+}
+
+/// Returns the current working directory of process `pid`.
+#[cfg(target_os = "macos")]
+fn process_cwd(pid: u32) -> Option<PathBuf> {
+    use std::ffi::{CStr, OsStr};
+    use std::os::unix::ffi::OsStrExt;
+
+    let mut info: libc::proc_vnodepathinfo = unsafe { std::mem::zeroed() };
+    let size = std::mem::size_of::<libc::proc_vnodepathinfo>() as libc::c_int;
+    // SAFETY: proc_pidinfo writes at most `size` bytes into `info`, which is
+    // a zeroed proc_vnodepathinfo.
+    let written = unsafe {
+        libc::proc_pidinfo(
+            pid as libc::c_int,
+            libc::PROC_PIDVNODEPATHINFO,
+            0,
+            &mut info as *mut _ as *mut libc::c_void,
+            size,
+        )
+    };
+    if written != size {
+        return None;
+    }
+    // SAFETY: vip_path is a NUL-terminated MAXPATHLEN buffer (declared as
+    // nested arrays by libc), and `info` was zeroed before the call.
+    let path = unsafe { CStr::from_ptr(info.pvi_cdir.vip_path.as_ptr() as *const libc::c_char) };
+    let path = path.to_bytes();
+    (!path.is_empty()).then(|| PathBuf::from(OsStr::from_bytes(path)))
+}
+
+/// Returns the current working directory of process `pid`.
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn process_cwd(_pid: u32) -> Option<PathBuf> {
+    None
+}
+
+// Chunk: docs/chunks/pty_graceful_shutdown - SIGHUP, then SIGTERM, then SIGKILL
+/// Shuts down the child processes of `ptys`, giving them a chance to exit cleanly.
+///
+/// Every process still running gets SIGHUP, as if its terminal had been
+/// closed, so shells can save history and pass the hangup on to their jobs.
+/// Processes still running after half of `grace` get SIGTERM, and any left
+/// when `grace` is up get SIGKILL. Each phase ends early once every process
+/// has exited, so well-behaved shells cost a few milliseconds, and the total
+/// wait is bounded by `grace` however many PTYs there are.
+pub(crate) fn shutdown_ptys(ptys: &mut [&mut PtyHandle], grace: Duration) {
+    const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+    let phase = grace / 2;
+    for signal in [libc::SIGHUP, libc::SIGTERM] {
+        let mut running: Vec<&mut PtyHandle> = ptys.iter_mut().map(|pty| &mut **pty).collect();
+        running.retain_mut(|pty| pty.try_wait().is_none());
+        if running.is_empty() {
+            return;
+        }
+        for pty in running.iter() {
+            let _ = pty.signal(signal);
+        }
+        let deadline = Instant::now() + phase;
+        while Instant::now() < deadline {
+            running.retain_mut(|pty| pty.try_wait().is_none());
+            if running.is_empty() {
+                return;
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    for pty in ptys.iter_mut() {
+        if pty.try_wait().is_none() {
+            let _ = pty.kill();
+        }
+    }
 }
 
 // Chunk: docs/chunks/terminal_spawn_reliability - Timed join for PTY cleanup
 impl Drop for PtyHandle {
     fn drop(&mut self) {
-        use std::time::Duration;
+        // Chunk: docs/chunks/terminal_flow_control - Release a paused reader thread
+        self.flow.close();
 
         // Kill the process if it's still running to ensure the reader thread
         // will hit EOF or an error and exit.
@@ -417,7 +572,6 @@ impl Drop for PtyHandle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::time::Duration;
 
     #[test]
     fn test_spawn_echo() {
@@ -716,4 +870,81 @@ mod tests {
             final_handle.err()
         );
     }
+
+    // Chunk: docs/chunks/pty_graceful_shutdown - Graceful shutdown tests
+
+    fn spawn_sh(script: &str) -> PtyHandle {
+        PtyHandle::spawn("sh", &["-c", script], Path::new("/tmp"), 24, 80, false)
+            .expect("Failed to spawn PTY")
+    }
+
+    #[test]
+    fn test_shutdown_ptys_hangup_exits_promptly() {
+        let mut handle = spawn_sh("sleep 30");
+        let start = Instant::now();
+        shutdown_ptys(&mut [&mut handle], Duration::from_secs(2));
+
+        assert!(handle.try_wait().is_some(), "process should have exited");
+        assert!(
+            start.elapsed() < Duration::from_secs(1),
+            "SIGHUP should end the process well before the grace period"
+        );
+    }
+
+    #[test]
+    fn test_shutdown_ptys_kills_processes_ignoring_signals() {
+        let mut stubborn = spawn_sh("trap '' HUP TERM; while true; do sleep 1; done");
+        let mut polite = spawn_sh("sleep 30");
+        // Give the shell time to install its traps.
+        std::thread::sleep(Duration::from_millis(100));
+
+        let grace = Duration::from_millis(200);
+        let start = Instant::now();
+        shutdown_ptys(&mut [&mut stubborn, &mut polite], grace);
+
+        assert!(polite.try_wait().is_some());
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while stubborn.try_wait().is_none() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert!(
+            stubborn.try_wait().is_some(),
+            "SIGKILL should end the process"
+        );
+        assert!(start.elapsed() < grace + Duration::from_millis(500));
+    }
+
+    // Chunk: docs/chunks/terminal_flow_control - Flooding output pauses the reader
+    #[test]
+    fn test_flooding_output_pauses_reader_until_drained() {
+        let handle = spawn_sh("yes");
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !handle.is_output_throttled() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert!(
+            handle.is_output_throttled(),
+            "unread output should pause reading"
+        );
+        // Reading stops within one read of the threshold
+        std::thread::sleep(Duration::from_millis(50));
+        let pending = handle.pending_output_bytes();
+        assert!(pending >= FlowControl::PAUSE_BYTES);
+        assert!(pending < FlowControl::PAUSE_BYTES + 4096);
+
+        // Taking the backlog resumes reading
+        while handle.pending_output_bytes() > FlowControl::RESUME_BYTES {
+            handle.try_recv();
+        }
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while handle.is_output_throttled() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert!(!handle.is_output_throttled());
+
+        let start = Instant::now();
+        drop(handle);
+        assert!(start.elapsed() < Duration::from_millis(500));
+    }
 }
@@ -0,0 +1,418 @@
+// Chunk: docs/chunks/terminal_replay - Timestamped session recording
+//! A terminal session's output with the time each stretch of it arrived.
+//!
+//! A [`Recording`] appends everything a terminal's program prints to a temp
+//! file, as cold scrollback does with finished lines, and keeps a small
+//! in-memory index of when each stretch of output arrived and where the
+//! terminal was resized. Output arriving within [`Recording::COALESCE_MS`] of
+//! the previous stretch extends it, so a flood costs one index entry per
+//! tenth of a second rather than one per PTY read. Recording stops once the
+//! file reaches its size limit.
+//!
+//! A [`RecordingSnapshot`] freezes the index and reads through its own handle
+//! on the file, so a replay or an export can run while the terminal keeps
+//! recording. Snapshots can be written out in the asciicast v2 format used by
+//! transcripts, or replayed in a fresh emulator with
+//! [`TerminalReplay`](crate::TerminalReplay).
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::os::unix::fs::FileExt;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::transcript::{json_string, take_utf8};
+
+/// A stretch of output: when it started arriving and where it starts in the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Chunk {
+    at_ms: u64,
+    offset: u64,
+}
+
+/// A resize, applied before the output byte at `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Resize {
+    pub at_ms: u64,
+    pub offset: u64,
+    pub cols: usize,
+    pub rows: usize,
+}
+
+/// A terminal's output so far, on disk, with arrival times.
+pub struct Recording {
+    file: File,
+    /// Bytes of output recorded.
+    len: u64,
+    max_bytes: u64,
+    chunks: Vec<Chunk>,
+    resizes: Vec<Resize>,
+    /// Time origin for the chunk timestamps.
+    started_at: Instant,
+    /// Wall-clock start, in seconds since the Unix epoch.
+    started_unix_secs: u64,
+    /// Terminal size when recording started, as (cols, rows).
+    size: (usize, usize),
+}
+
+impl Recording {
+    /// Default size limit of a recording, in bytes.
+    pub const DEFAULT_MAX_BYTES: u64 = 64 * 1024 * 1024;
+
+    /// Output arriving within this many milliseconds of the start of the
+    /// previous stretch is recorded as part of it.
+    pub const COALESCE_MS: u64 = 100;
+
+    /// Starts a recording of a `cols` × `rows` terminal in a new temp file.
+    pub fn new(cols: usize, rows: usize) -> io::Result<Self> {
+        let started_unix_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Ok(Self {
+            file: tempfile::tempfile()?,
+            len: 0,
+            max_bytes: Self::DEFAULT_MAX_BYTES,
+            chunks: Vec::new(),
+            resizes: Vec::new(),
+            started_at: Instant::now(),
+            started_unix_secs,
+            size: (cols, rows),
+        })
+    }
+
+    /// Sets the size limit. A limit at or under the bytes already recorded
+    /// stops the recording; what was recorded is kept.
+    pub fn set_max_bytes(&mut self, bytes: u64) {
+        self.max_bytes = bytes;
+    }
+
+    /// Returns the size limit, in bytes.
+    pub fn max_bytes(&self) -> u64 {
+        self.max_bytes
+    }
+
+    /// Returns true once the recording has reached its size limit.
+    pub fn is_full(&self) -> bool {
+        self.len >= self.max_bytes
+    }
+
+    /// Returns the bytes of output recorded.
+    pub fn len_bytes(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns the memory used by the index, in bytes.
+    pub fn index_bytes(&self) -> usize {
+        self.chunks.capacity() * std::mem::size_of::<Chunk>()
+            + self.resizes.capacity() * std::mem::size_of::<Resize>()
+    }
+
+    /// Records output the terminal's program just printed.
+    pub(crate) fn record_output(&mut self, data: &[u8]) -> io::Result<()> {
+        let at_ms = self.started_at.elapsed().as_millis() as u64;
+        self.record_output_at(at_ms, data)
+    }
+
+    /// Records output that arrived `at_ms` after the recording started.
+    pub(crate) fn record_output_at(&mut self, at_ms: u64, data: &[u8]) -> io::Result<()> {
+        let room = self.max_bytes.saturating_sub(self.len);
+        let data = &data[..data.len().min(room as usize)];
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.file.write_all_at(data, self.len)?;
+        let extends_last = self
+            .chunks
+            .last()
+            .is_some_and(|chunk| at_ms < chunk.at_ms + Self::COALESCE_MS);
+        if !extends_last {
+            self.chunks.push(Chunk {
+                at_ms,
+                offset: self.len,
+            });
+        }
+        self.len += data.len() as u64;
+        Ok(())
+    }
+
+    /// Records that the terminal was resized to `cols` × `rows`.
+    pub(crate) fn record_resize(&mut self, cols: usize, rows: usize) {
+        let at_ms = self.started_at.elapsed().as_millis() as u64;
+        self.record_resize_at(at_ms, cols, rows);
+    }
+
+    /// Records a resize `at_ms` after the recording started.
+    pub(crate) fn record_resize_at(&mut self, at_ms: u64, cols: usize, rows: usize) {
+        if self.is_full() {
+            return;
+        }
+        let resize = Resize {
+            at_ms,
+            offset: self.len,
+            cols,
+            rows,
+        };
+        // While a window is dragged, only the last size before more output matters
+        match self.resizes.last_mut() {
+            Some(last) if last.offset == self.len => *last = resize,
+            _ if self.len == 0 => self.size = (cols, rows),
+            _ => self.resizes.push(resize),
+        }
+    }
+
+    /// Returns a frozen copy of the recording so far.
+    pub fn snapshot(&self) -> io::Result<RecordingSnapshot> {
+        Ok(RecordingSnapshot {
+            file: self.file.try_clone()?,
+            len: self.len,
+            truncated: self.is_full(),
+            chunks: self.chunks.clone(),
+            resizes: self.resizes.clone(),
+            started_unix_secs: self.started_unix_secs,
+            size: self.size,
+        })
+    }
+}
+
+/// A recording as it was when the snapshot was taken.
+pub struct RecordingSnapshot {
+    file: File,
+    len: u64,
+    truncated: bool,
+    chunks: Vec<Chunk>,
+    resizes: Vec<Resize>,
+    started_unix_secs: u64,
+    size: (usize, usize),
+}
+
+impl RecordingSnapshot {
+    /// Returns the time the last output arrived, from the start.
+    pub fn duration(&self) -> Duration {
+        self.chunks
+            .last()
+            .map_or(Duration::ZERO, |chunk| Duration::from_millis(chunk.at_ms))
+    }
+
+    /// Returns the bytes of output recorded.
+    pub fn len_bytes(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns true if the recording stopped at its size limit.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the terminal size when recording started, as (cols, rows).
+    pub fn initial_size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    /// Returns when the recording started, in seconds since the Unix epoch.
+    pub fn started_unix_secs(&self) -> u64 {
+        self.started_unix_secs
+    }
+
+    /// Returns the end of the output that had started arriving by `at`.
+    pub fn offset_at(&self, at: Duration) -> u64 {
+        let at_ms = at.as_millis() as u64;
+        let arrived = self.chunks.partition_point(|chunk| chunk.at_ms <= at_ms);
+        self.chunks.get(arrived).map_or(self.len, |chunk| chunk.offset)
+    }
+
+    /// Returns when the first stretch of output after `at` arrived.
+    pub fn next_output_after(&self, at: Duration) -> Option<Duration> {
+        let at_ms = at.as_millis() as u64;
+        let arrived = self.chunks.partition_point(|chunk| chunk.at_ms <= at_ms);
+        self.chunks
+            .get(arrived)
+            .map(|chunk| Duration::from_millis(chunk.at_ms))
+    }
+
+    /// Returns when the last stretch of output before `at` arrived.
+    pub fn previous_output_before(&self, at: Duration) -> Option<Duration> {
+        let at_ms = at.as_millis() as u64;
+        let before = self.chunks.partition_point(|chunk| chunk.at_ms < at_ms);
+        before
+            .checked_sub(1)
+            .map(|index| Duration::from_millis(self.chunks[index].at_ms))
+    }
+
+    /// Returns the resizes, in the order they happened.
+    pub(crate) fn resizes(&self) -> &[Resize] {
+        &self.resizes
+    }
+
+    /// Reads the output between byte offsets `start` and `end`.
+    pub(crate) fn read(&self, start: u64, end: u64) -> io::Result<Vec<u8>> {
+        let mut data = vec![0; end.saturating_sub(start) as usize];
+        self.file.read_exact_at(&mut data, start)?;
+        Ok(data)
+    }
+
+    /// Writes the recording in the asciicast v2 format: a JSON header line
+    /// then one `[seconds, "o", text]` line per stretch of output, with
+    /// `[seconds, "r", "COLSxROWS"]` lines for resizes.
+    pub fn write_asciicast(&self, out: &mut impl Write, title: &str) -> io::Result<()> {
+        writeln!(
+            out,
+            "{{\"version\":2,\"width\":{},\"height\":{},\"timestamp\":{},\"title\":{}}}",
+            self.size.0,
+            self.size.1,
+            self.started_unix_secs,
+            json_string(title)
+        )?;
+
+        let mut pending = Vec::new();
+        let mut resizes = self.resizes.iter().peekable();
+        // Event times never go backwards, even for output after a resize
+        // inside a coalesced stretch
+        let mut last_ms = 0;
+        for (index, chunk) in self.chunks.iter().enumerate() {
+            let end = self.chunks.get(index + 1).map_or(self.len, |next| next.offset);
+            let mut start = chunk.offset;
+            let mut at_ms = chunk.at_ms.max(last_ms);
+            loop {
+                let split = match resizes.peek() {
+                    Some(resize) if resize.offset < end => resize.offset.max(start),
+                    _ => end,
+                };
+                let text = take_utf8(&mut pending, &self.read(start, split)?);
+                if !text.is_empty() {
+                    write_event(out, at_ms, "o", &text)?;
+                }
+                start = split;
+                if split == end {
+                    break;
+                }
+                let resize = resizes.next().expect("peeked");
+                at_ms = resize.at_ms.max(at_ms);
+                let size = format!("{}x{}", resize.cols, resize.rows);
+                write_event(out, at_ms, "r", &size)?;
+            }
+            last_ms = at_ms;
+        }
+        // Resizes after the last output
+        for resize in resizes {
+            last_ms = resize.at_ms.max(last_ms);
+            let size = format!("{}x{}", resize.cols, resize.rows);
+            write_event(out, last_ms, "r", &size)?;
+        }
+        out.flush()
+    }
+
+    /// Writes the recording to `path` in the asciicast v2 format, which
+    /// `asciinema play` replays.
+    pub fn export_asciicast(&self, path: &Path, title: &str) -> io::Result<()> {
+        let mut out = io::BufWriter::new(File::create(path)?);
+        self.write_asciicast(&mut out, title)
+    }
+}
+
+/// Writes one asciicast event line.
+fn write_event(out: &mut impl Write, at_ms: u64, code: &str, data: &str) -> io::Result<()> {
+    let seconds = at_ms as f64 / 1000.0;
+    writeln!(out, "[{:.6},\"{}\",{}]", seconds, code, json_string(data))
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording() -> Recording {
+        Recording::new(80, 24).unwrap()
+    }
+
+    #[test]
+    fn test_coalesces_output_arriving_together() {
+        let mut rec = recording();
+        rec.record_output_at(0, b"one ").unwrap();
+        rec.record_output_at(50, b"two ").unwrap();
+        rec.record_output_at(400, b"three").unwrap();
+
+        let snap = rec.snapshot().unwrap();
+        assert_eq!(rec.chunks.len(), 2);
+        assert_eq!(snap.duration(), Duration::from_millis(400));
+        assert_eq!(snap.read(0, snap.len_bytes()).unwrap(), b"one two three");
+        assert_eq!(snap.offset_at(Duration::from_millis(399)), 8);
+        assert_eq!(snap.offset_at(Duration::from_millis(400)), 13);
+        assert_eq!(
+            snap.next_output_after(Duration::ZERO),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(snap.previous_output_before(Duration::from_millis(400)), Some(Duration::ZERO));
+        assert_eq!(snap.previous_output_before(Duration::ZERO), None);
+    }
+
+    #[test]
+    fn test_stops_at_size_limit() {
+        let mut rec = recording();
+        rec.set_max_bytes(6);
+        rec.record_output_at(0, b"abcd").unwrap();
+        rec.record_output_at(500, b"efgh").unwrap();
+        assert!(rec.is_full());
+        rec.record_output_at(900, b"ijkl").unwrap();
+        rec.record_resize_at(950, 100, 30);
+
+        let snap = rec.snapshot().unwrap();
+        assert!(snap.is_truncated());
+        assert_eq!(snap.read(0, snap.len_bytes()).unwrap(), b"abcdef");
+        assert!(snap.resizes().is_empty());
+    }
+
+    #[test]
+    fn test_resizes_before_output_and_while_dragging() {
+        let mut rec = recording();
+        // Before any output, a resize just changes the starting size
+        rec.record_resize_at(0, 90, 30);
+        rec.record_output_at(10, b"a").unwrap();
+        rec.record_resize_at(20, 100, 30);
+        rec.record_resize_at(30, 120, 40);
+
+        let snap = rec.snapshot().unwrap();
+        assert_eq!(snap.initial_size(), (90, 30));
+        assert_eq!(snap.resizes().len(), 1);
+        assert_eq!((snap.resizes()[0].cols, snap.resizes()[0].rows), (120, 40));
+    }
+
+    #[test]
+    fn test_writes_asciicast() {
+        let mut rec = recording();
+        rec.record_output_at(0, "caf\u{e9}".as_bytes()).unwrap();
+        rec.record_output_at(1500, b"\x1b[1mdone\r\n").unwrap();
+        rec.record_resize_at(1550, 100, 30);
+        rec.record_output_at(1560, b"more").unwrap();
+
+        let mut out = Vec::new();
+        let snap = rec.snapshot().unwrap();
+        snap.write_asciicast(&mut out, "build").unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert!(lines[0].starts_with("{\"version\":2,\"width\":80,\"height\":24,"));
+        assert!(lines[0].ends_with(",\"title\":\"build\"}"));
+        assert_eq!(lines[1], "[0.000000,\"o\",\"caf\u{e9}\"]");
+        assert_eq!(lines[2], "[1.500000,\"o\",\"\\u001b[1mdone\\r\\n\"]");
+        // The resize falls inside the coalesced stretch; later output keeps its time
+        assert_eq!(lines[3], "[1.550000,\"r\",\"100x30\"]");
+        assert_eq!(lines[4], "[1.550000,\"o\",\"more\"]");
+        assert_eq!(lines.len(), 5);
+    }
+
+    #[test]
+    fn test_snapshot_reads_while_recording_continues() {
+        let mut rec = recording();
+        rec.record_output_at(0, b"before").unwrap();
+        let snap = rec.snapshot().unwrap();
+        rec.record_output_at(500, b" after").unwrap();
+
+        assert_eq!(snap.len_bytes(), 6);
+        assert_eq!(snap.read(0, 6).unwrap(), b"before");
+        assert_eq!(rec.snapshot().unwrap().read(6, 12).unwrap(), b" after");
+    }
+}
@@ -0,0 +1,234 @@
+// Chunk: docs/chunks/terminal_replay - Replay a recorded session
+//! Replaying a terminal session at any point in its recording.
+//!
+//! A [`TerminalReplay`] feeds a [`RecordingSnapshot`] into a fresh emulator
+//! with no PTY attached, up to the output that had arrived by the chosen
+//! moment, applying resizes where they happened. Seeking forward feeds only
+//! the output in between; seeking backward rebuilds the emulator from the
+//! start, since escape sequences can't be unapplied.
+
+use std::io;
+use std::time::Duration;
+
+use lite_edit_buffer::{BufferView, CursorInfo, DirtyLines, Position, StyledLine};
+
+use crate::recording::RecordingSnapshot;
+use crate::terminal_buffer::TerminalBuffer;
+
+/// A recorded terminal session shown as it was at a chosen moment.
+pub struct TerminalReplay {
+    recording: RecordingSnapshot,
+    terminal: TerminalBuffer,
+    /// The moment shown, from the start of the recording.
+    position: Duration,
+    /// Bytes of the recording fed to `terminal`.
+    fed: u64,
+    /// Index of the first resize not yet applied to `terminal`.
+    next_resize: usize,
+}
+
+impl TerminalReplay {
+    /// Scrollback lines kept by the replay emulator.
+    pub const SCROLLBACK: usize = 5000;
+
+    /// Bytes fed to the emulator at a time while seeking.
+    const FEED_BLOCK: u64 = 64 * 1024;
+
+    /// Creates a replay of `recording`, showing its start.
+    pub fn new(recording: RecordingSnapshot) -> Self {
+        let (cols, rows) = recording.initial_size();
+        Self {
+            recording,
+            terminal: TerminalBuffer::new(cols, rows, Self::SCROLLBACK),
+            position: Duration::ZERO,
+            fed: 0,
+            next_resize: 0,
+        }
+    }
+
+    /// Returns the recording being replayed.
+    pub fn recording(&self) -> &RecordingSnapshot {
+        &self.recording
+    }
+
+    /// Returns the moment shown, from the start of the recording.
+    pub fn position(&self) -> Duration {
+        self.position
+    }
+
+    /// Returns the length of the recording.
+    pub fn duration(&self) -> Duration {
+        self.recording.duration()
+    }
+
+    /// Returns the emulator showing the replayed session.
+    pub fn terminal(&self) -> &TerminalBuffer {
+        &self.terminal
+    }
+
+    /// Shows the session as it was at `at`, clamped to the recording.
+    pub fn seek(&mut self, at: Duration) -> io::Result<()> {
+        let at = at.min(self.duration());
+        let target = self.recording.offset_at(at);
+        if at < self.position {
+            let (cols, rows) = self.recording.initial_size();
+            self.terminal = TerminalBuffer::new(cols, rows, Self::SCROLLBACK);
+            self.fed = 0;
+            self.next_resize = 0;
+        }
+
+        let at_ms = at.as_millis() as u64;
+        while self.fed < target {
+            // Resizes due before the next byte, then output up to the next one
+            self.apply_resizes(|_, _| true);
+            let next_resize = self
+                .recording
+                .resizes()
+                .get(self.next_resize)
+                .map_or(u64::MAX, |resize| resize.offset);
+            let end = target.min(self.fed + Self::FEED_BLOCK).min(next_resize);
+            let data = self.recording.read(self.fed, end)?;
+            self.terminal.feed_recorded(&data);
+            self.fed = end;
+        }
+        // A resize after the last output shown, if it had happened by `at`
+        let fed = self.fed;
+        self.apply_resizes(|offset, resize_ms| offset < fed || resize_ms <= at_ms);
+
+        self.position = at;
+        Ok(())
+    }
+
+    /// Applies the pending resizes up to the output fed so far, while
+    /// `due(offset, at_ms)` holds.
+    fn apply_resizes(&mut self, due: impl Fn(u64, u64) -> bool) {
+        while let Some(resize) = self.recording.resizes().get(self.next_resize) {
+            if resize.offset > self.fed || !due(resize.offset, resize.at_ms) {
+                break;
+            }
+            let (cols, rows) = (resize.cols, resize.rows);
+            self.terminal.resize(cols, rows);
+            self.next_resize += 1;
+        }
+    }
+
+    /// Moves to the next stretch of output, or the previous one if
+    /// `forward` is false. Returns false if there is none.
+    pub fn step(&mut self, forward: bool) -> io::Result<bool> {
+        let to = if forward {
+            self.recording.next_output_after(self.position)
+        } else {
+            self.recording.previous_output_before(self.position)
+        };
+        match to {
+            Some(to) => self.seek(to).map(|()| true),
+            None => Ok(false),
+        }
+    }
+}
+
+impl BufferView for TerminalReplay {
+    fn line_count(&self) -> usize {
+        self.terminal.line_count()
+    }
+
+    fn styled_line(&self, line: usize) -> Option<StyledLine> {
+        self.terminal.styled_line(line)
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.terminal.line_len(line)
+    }
+
+    fn take_dirty(&mut self) -> DirtyLines {
+        self.terminal.take_dirty()
+    }
+
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    fn cursor_info(&self) -> Option<CursorInfo> {
+        self.terminal.cursor_info()
+    }
+
+    fn selection_range(&self) -> Option<(Position, Position)> {
+        None
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::Recording;
+
+    fn screen_text(replay: &TerminalReplay) -> String {
+        (0..replay.line_count())
+            .filter_map(|line| replay.styled_line(line))
+            .map(|line| line.spans.iter().map(|span| span.text.as_str()).collect::<String>())
+            .map(|line| line.trim_end().to_string())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn recorded_session() -> RecordingSnapshot {
+        let mut rec = Recording::new(40, 5).unwrap();
+        rec.record_output_at(0, b"first\r\n").unwrap();
+        rec.record_output_at(1000, b"second\r\n").unwrap();
+        rec.record_output_at(2000, b"\x1b[2Jcleared").unwrap();
+        rec.snapshot().unwrap()
+    }
+
+    #[test]
+    fn test_seeks_forward_and_back() {
+        let mut replay = TerminalReplay::new(recorded_session());
+        assert_eq!(replay.duration(), Duration::from_secs(2));
+
+        replay.seek(Duration::from_millis(1500)).unwrap();
+        assert_eq!(screen_text(&replay), "first\nsecond");
+
+        replay.seek(Duration::from_secs(10)).unwrap();
+        assert_eq!(replay.position(), Duration::from_secs(2));
+        assert!(screen_text(&replay).ends_with("cleared"));
+
+        // Going back rebuilds the screen the clear wiped
+        replay.seek(Duration::ZERO).unwrap();
+        assert_eq!(screen_text(&replay), "first");
+    }
+
+    #[test]
+    fn test_steps_between_stretches_of_output() {
+        let mut replay = TerminalReplay::new(recorded_session());
+        assert!(replay.step(true).unwrap());
+        assert_eq!(replay.position(), Duration::from_secs(1));
+        assert!(replay.step(true).unwrap());
+        assert!(!replay.step(true).unwrap());
+        assert!(replay.step(false).unwrap());
+        assert_eq!(replay.position(), Duration::from_secs(1));
+        assert_eq!(screen_text(&replay), "first\nsecond");
+    }
+
+    #[test]
+    fn test_applies_resizes_where_they_happened() {
+        let mut rec = Recording::new(40, 5).unwrap();
+        rec.record_output_at(0, b"narrow").unwrap();
+        rec.record_resize_at(500, 60, 10);
+        rec.record_output_at(1000, b"\r\nwide").unwrap();
+        let mut replay = TerminalReplay::new(rec.snapshot().unwrap());
+
+        replay.seek(Duration::from_millis(100)).unwrap();
+        assert_eq!(replay.terminal().size(), (40, 5));
+        replay.seek(Duration::from_millis(600)).unwrap();
+        assert_eq!(replay.terminal().size(), (60, 10));
+        replay.seek(Duration::ZERO).unwrap();
+        assert_eq!(replay.terminal().size(), (40, 5));
+        replay.seek(Duration::from_secs(1)).unwrap();
+        assert_eq!(replay.terminal().size(), (60, 10));
+        assert_eq!(screen_text(&replay), "narrow\nwide");
+    }
+}
@@ -0,0 +1,546 @@
+// Chunk: docs/chunks/terminal_cwd_picker - Shell-reported working directory
+// Chunk: docs/chunks/terminal_shell_integration - OSC 133 command marks
+// Chunk: docs/chunks/terminal_command_status - Command duration and exit status
+//! Shell integration sequences observed in PTY output.
+//!
+//! alacritty_terminal ignores the OSC strings shells use to describe their
+//! state, so they are picked out of the byte stream as it is fed to the VTE
+//! processor. The bytes themselves still reach the processor unchanged.
+//!
+//! - `OscScanner` collects the bodies of `ESC ] ... BEL` / `ESC ] ... ESC \`
+//!   strings, keeping its state across reads so sequences split between PTY
+//!   chunks work.
+//! - `parse_osc7` decodes the `file://host/path` URL of an OSC 7 working
+//!   directory report, ignoring reports from other hosts (e.g. over ssh).
+//! - `parse_osc133` decodes the FinalTerm prompt marks (`OSC 133 ; A` prompt
+//!   start, `B` command start, `C` output start, `D ; status` command
+//!   finished), which `CommandMarks` turns into a list of commands anchored
+//!   to buffer lines, timed from `C` to `D`.
+
+use std::ffi::OsStr;
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use lite_edit_buffer::Position;
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+
+/// Maximum length of an OSC body that is collected. Longer strings (such as
+/// OSC 52 clipboard payloads) are not shell integration sequences and are
+/// skipped.
+const MAX_OSC_LEN: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScanState {
+    /// Outside any OSC string.
+    Ground,
+    /// Saw ESC in ground state; waiting to see if an OSC starts.
+    Escape,
+    /// Inside an OSC string.
+    Osc,
+    /// Saw ESC inside an OSC string; waiting for the `\` of the terminator.
+    OscEscape,
+}
+
+/// Collects OSC string bodies from PTY output.
+#[derive(Debug)]
+pub(crate) struct OscScanner {
+    state: ScanState,
+    osc: Vec<u8>,
+    /// True once the current OSC has exceeded `MAX_OSC_LEN`.
+    overflowed: bool,
+}
+
+impl OscScanner {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: ScanState::Ground,
+            osc: Vec::new(),
+            overflowed: false,
+        }
+    }
+
+    /// Returns the bodies of the OSC strings completed by `data`, in stream
+    /// order, each with the offset in `data` just past its terminator.
+    pub(crate) fn scan(&mut self, data: &[u8]) -> Vec<(usize, Vec<u8>)> {
+        let mut bodies = Vec::new();
+        if self.state == ScanState::Ground && !data.contains(&ESC) {
+            return bodies;
+        }
+
+        for (offset, &byte) in data.iter().enumerate() {
+            match self.state {
+                ScanState::Ground => {
+                    if byte == ESC {
+                        self.state = ScanState::Escape;
+                    }
+                }
+                ScanState::Escape => self.escape_byte(byte),
+                ScanState::Osc => match byte {
+                    BEL => self.finish(offset + 1, &mut bodies),
+                    ESC => self.state = ScanState::OscEscape,
+                    // CAN and SUB abort the string
+                    0x18 | 0x1a => self.state = ScanState::Ground,
+                    _ if self.osc.len() < MAX_OSC_LEN => self.osc.push(byte),
+                    _ => self.overflowed = true,
+                },
+                ScanState::OscEscape => {
+                    if byte == b'\\' {
+                        self.finish(offset + 1, &mut bodies);
+                    } else {
+                        // An unterminated OSC aborted by a new escape sequence
+                        self.escape_byte(byte);
+                    }
+                }
+            }
+        }
+
+        bodies
+    }
+
+    /// Handles the byte after an ESC outside an OSC string.
+    fn escape_byte(&mut self, byte: u8) {
+        self.state = match byte {
+            b']' => {
+                self.osc.clear();
+                self.overflowed = false;
+                ScanState::Osc
+            }
+            ESC => ScanState::Escape,
+            _ => ScanState::Ground,
+        };
+    }
+
+    fn finish(&mut self, end: usize, bodies: &mut Vec<(usize, Vec<u8>)>) {
+        self.state = ScanState::Ground;
+        let osc = std::mem::take(&mut self.osc);
+        if !self.overflowed {
+            bodies.push((end, osc));
+        }
+    }
+}
+
+/// Returns the directory reported by an OSC 7 body (`7;file://host/path`).
+///
+/// Returns `None` for other OSC strings, malformed URLs, and directories on
+/// another host.
+pub(crate) fn parse_osc7(body: &[u8]) -> Option<PathBuf> {
+    let url = body.strip_prefix(b"7;")?;
+    let rest = url.strip_prefix(b"file://")?;
+    let slash = rest.iter().position(|&b| b == b'/')?;
+    let (host, path) = rest.split_at(slash);
+    if !is_local_host(host) {
+        return None;
+    }
+    Some(PathBuf::from(OsStr::from_bytes(&percent_decode(path))))
+}
+
+/// Returns true if `host` names this machine. Shells report `$HOSTNAME`,
+/// which may or may not include the domain, so only the first label is
+/// compared.
+fn is_local_host(host: &[u8]) -> bool {
+    if host.is_empty() || host.eq_ignore_ascii_case(b"localhost") {
+        return true;
+    }
+    let Some(local) = local_hostname() else {
+        return false;
+    };
+    short_name(host).eq_ignore_ascii_case(short_name(&local))
+}
+
+/// Returns the first label of a host name.
+fn short_name(host: &[u8]) -> &[u8] {
+    host.split(|&b| b == b'.').next().unwrap_or(host)
+}
+
+fn local_hostname() -> Option<Vec<u8>> {
+    let mut buf = [0u8; 256];
+    // SAFETY: gethostname writes at most `buf.len()` bytes into `buf`.
+    if unsafe { libc::gethostname(buf.as_mut_ptr() as *mut libc::c_char, buf.len()) } != 0 {
+        return None;
+    }
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    Some(buf[..len].to_vec())
+}
+
+/// Decodes `%XX` escapes. Invalid escapes are kept as they are.
+fn percent_decode(bytes: &[u8]) -> Vec<u8> {
+    let hex = |b: u8| (b as char).to_digit(16).map(|d| d as u8);
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(hi), Some(lo)) = (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                out.push((hi << 4) | lo);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    out
+}
+
+// =============================================================================
+// OSC 133 Command Marks
+// =============================================================================
+
+/// Maximum number of commands remembered per terminal; the oldest are
+/// dropped first.
+const MAX_COMMAND_MARKS: usize = 10_000;
+
+/// One OSC 133 prompt mark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ShellMark {
+    /// `A`: the prompt is about to be drawn.
+    PromptStart,
+    /// `B`: the prompt ended and the user is typing a command.
+    CommandStart,
+    /// `C`: the command was entered and its output follows.
+    OutputStart,
+    /// `D`: the command finished, with its exit status if the shell sent one.
+    CommandFinished(Option<i32>),
+}
+
+/// Returns the mark in an OSC 133 body (`133;A`, `133;D;1`, ...).
+///
+/// Parameters after the mark letter other than `D`'s exit status (such as
+/// `aid=` or `cl=`) are ignored.
+pub(crate) fn parse_osc133(body: &[u8]) -> Option<ShellMark> {
+    let mut fields = body.strip_prefix(b"133;")?.split(|&b| b == b';');
+    let mark = match fields.next()? {
+        b"A" => ShellMark::PromptStart,
+        b"B" => ShellMark::CommandStart,
+        b"C" => ShellMark::OutputStart,
+        b"D" => {
+            let status = fields
+                .next()
+                .and_then(|field| std::str::from_utf8(field).ok())
+                .and_then(|field| field.parse().ok());
+            ShellMark::CommandFinished(status)
+        }
+        _ => return None,
+    };
+    Some(mark)
+}
+
+/// How a command turned out, for coloring its prompt mark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandStatus {
+    /// No command has run from this prompt yet, it is still running, or the
+    /// shell didn't report an exit status.
+    Pending,
+    /// The command exited with status 0.
+    Succeeded,
+    /// The command exited with a nonzero status.
+    Failed,
+}
+
+/// A prompt and the command run from it, anchored to buffer lines.
+///
+/// Lines and positions use the same coordinates as
+/// `BufferView::styled_line`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandMark {
+    /// Line the prompt starts on.
+    pub prompt_line: usize,
+    /// Where the command's output starts, once it has been entered.
+    pub output_start: Option<Position>,
+    /// Where the command's output ends, once it has finished.
+    pub output_end: Option<Position>,
+    /// The exit status the shell reported when the command finished.
+    pub exit_code: Option<i32>,
+    /// When the command's output started.
+    pub started_at: Option<Instant>,
+    /// How long the command ran, once it has finished.
+    pub duration: Option<Duration>,
+}
+
+impl CommandMark {
+    /// Returns how the command turned out.
+    pub fn status(&self) -> CommandStatus {
+        match (self.output_end, self.exit_code) {
+            (Some(_), Some(0)) => CommandStatus::Succeeded,
+            (Some(_), Some(_)) => CommandStatus::Failed,
+            _ => CommandStatus::Pending,
+        }
+    }
+
+    /// Returns the note shown after the output of a finished command:
+    /// `✓ 2.3s` when it succeeded, `✗ exit 1` when it failed.
+    pub fn summary(&self) -> Option<String> {
+        let duration = format_duration(self.duration?);
+        Some(match (self.status(), self.exit_code) {
+            (CommandStatus::Failed, Some(code)) => format!("✗ exit {}", code),
+            (CommandStatus::Succeeded, _) => format!("✓ {}", duration),
+            _ => duration,
+        })
+    }
+
+    /// Returns the line the summary goes on once the command has finished:
+    /// the last line of its output, or the command line itself when there
+    /// was no output.
+    pub fn summary_line(&self) -> Option<usize> {
+        let end = self.output_end?;
+        if end.col == 0 {
+            end.line.checked_sub(1)
+        } else {
+            Some(end.line)
+        }
+    }
+}
+
+/// Formats a command's running time: `0.4s`, `2.3s`, `1m 05s`, `2h 03m`.
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs < 60 {
+        format!("{:.1}s", duration.as_secs_f64())
+    } else if secs < 60 * 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, secs / 60 % 60)
+    }
+}
+
+/// The commands a terminal's shell has marked, oldest first.
+#[derive(Debug, Default)]
+pub(crate) struct CommandMarks {
+    marks: Vec<CommandMark>,
+    /// Commands finished since the last `take_finished`.
+    finished: Vec<CommandMark>,
+}
+
+impl CommandMarks {
+    /// Records `mark`, which the shell emitted at `now` with the cursor at
+    /// `cursor`.
+    pub(crate) fn record(&mut self, mark: ShellMark, cursor: Position, now: Instant) {
+        match mark {
+            ShellMark::PromptStart => {
+                // A prompt redrawn in place (e.g. after a resize) is the same prompt
+                let redrawn = self.marks.last().is_some_and(|last| {
+                    last.prompt_line == cursor.line && last.output_start.is_none()
+                });
+                if redrawn {
+                    return;
+                }
+                if self.marks.len() == MAX_COMMAND_MARKS {
+                    self.marks.remove(0);
+                }
+                self.marks.push(CommandMark {
+                    prompt_line: cursor.line,
+                    output_start: None,
+                    output_end: None,
+                    exit_code: None,
+                    started_at: None,
+                    duration: None,
+                });
+            }
+            ShellMark::CommandStart => {}
+            ShellMark::OutputStart => {
+                if let Some(last) = self.marks.last_mut() {
+                    if last.output_start.is_none() {
+                        last.output_start = Some(cursor);
+                        last.started_at = Some(now);
+                    }
+                }
+            }
+            ShellMark::CommandFinished(status) => {
+                // Shells also send D for an empty command line or Ctrl+C at
+                // the prompt; only a command that produced output is finished.
+                if let Some(last) = self.marks.last_mut() {
+                    if last.output_start.is_some() && last.output_end.is_none() {
+                        last.output_end = Some(cursor);
+                        last.exit_code = status;
+                        last.duration = last
+                            .started_at
+                            .map(|started| now.saturating_duration_since(started));
+                        self.finished.push(*last);
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn as_slice(&self) -> &[CommandMark] {
+        &self.marks
+    }
+
+    /// Returns the commands finished since the last call, oldest first.
+    pub(crate) fn take_finished(&mut self) -> Vec<CommandMark> {
+        std::mem::take(&mut self.finished)
+    }
+
+    /// Returns the line of the last prompt above `line`.
+    pub(crate) fn previous_prompt(&self, line: usize) -> Option<usize> {
+        let index = self.marks.partition_point(|mark| mark.prompt_line < line);
+        index
+            .checked_sub(1)
+            .map(|index| self.marks[index].prompt_line)
+    }
+
+    /// Returns the line of the first prompt below `line`.
+    pub(crate) fn next_prompt(&self, line: usize) -> Option<usize> {
+        let index = self.marks.partition_point(|mark| mark.prompt_line <= line);
+        self.marks.get(index).map(|mark| mark.prompt_line)
+    }
+
+    /// Returns the output range of the most recent finished command.
+    pub(crate) fn last_output(&self) -> Option<(Position, Position)> {
+        self.marks
+            .iter()
+            .rev()
+            .find_map(|mark| Some((mark.output_start?, mark.output_end?)))
+    }
+
+    /// Shifts the marks up by `removed` lines after the oldest scrollback
+    /// lines were discarded, dropping commands whose prompt scrolled away.
+    pub(crate) fn discard_lines(&mut self, removed: usize) {
+        self.marks.retain_mut(|mark| {
+            if mark.prompt_line < removed {
+                return false;
+            }
+            mark.prompt_line -= removed;
+            let positions = [&mut mark.output_start, &mut mark.output_end];
+            for position in positions.into_iter().flatten() {
+                position.line -= removed;
+            }
+            true
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner_collects_bel_and_st_terminated_strings() {
+        let mut scanner = OscScanner::new();
+        let data = b"a\x1b]0;title\x07b\x1b[1mc\x1b]7;file:///tmp\x1b\\d";
+        let bodies = scanner.scan(data);
+        assert_eq!(
+            bodies,
+            vec![(11, b"0;title".to_vec()), (34, b"7;file:///tmp".to_vec())]
+        );
+        assert_eq!(&data[bodies[1].0..], b"d");
+        assert!(scanner.scan(b"plain output").is_empty());
+    }
+
+    #[test]
+    fn test_scanner_handles_strings_split_across_reads() {
+        let mut scanner = OscScanner::new();
+        assert!(scanner.scan(b"$ \x1b").is_empty());
+        assert!(scanner.scan(b"]7;file://").is_empty());
+        assert!(scanner.scan(b"/home\x1b").is_empty());
+        assert_eq!(scanner.scan(b"\\$ "), vec![(1, b"7;file:///home".to_vec())]);
+    }
+
+    #[test]
+    fn test_parse_osc7() {
+        assert_eq!(
+            parse_osc7(b"7;file:///tmp/a%20b"),
+            Some(PathBuf::from("/tmp/a b"))
+        );
+        assert_eq!(
+            parse_osc7(b"7;file://localhost/srv"),
+            Some(PathBuf::from("/srv"))
+        );
+        assert_eq!(parse_osc7(b"7;file://some-remote-host.invalid/srv"), None);
+        assert_eq!(parse_osc7(b"0;title"), None);
+        assert_eq!(parse_osc7(b"7;http://localhost/srv"), None);
+    }
+
+    #[test]
+    fn test_parse_osc133() {
+        assert_eq!(parse_osc133(b"133;A"), Some(ShellMark::PromptStart));
+        assert_eq!(
+            parse_osc133(b"133;A;cl=m;aid=42"),
+            Some(ShellMark::PromptStart)
+        );
+        assert_eq!(parse_osc133(b"133;C"), Some(ShellMark::OutputStart));
+        assert_eq!(
+            parse_osc133(b"133;D;2"),
+            Some(ShellMark::CommandFinished(Some(2)))
+        );
+        assert_eq!(
+            parse_osc133(b"133;D"),
+            Some(ShellMark::CommandFinished(None))
+        );
+        assert_eq!(parse_osc133(b"133;Z"), None);
+        assert_eq!(parse_osc133(b"7;file:///tmp"), None);
+    }
+
+    #[test]
+    fn test_command_marks_track_prompts_and_output() {
+        let at = |line, col| Position::new(line, col);
+        let now = Instant::now();
+        let mut marks = CommandMarks::default();
+        marks.record(ShellMark::PromptStart, at(0, 0), now);
+        marks.record(ShellMark::CommandStart, at(0, 2), now);
+        marks.record(ShellMark::OutputStart, at(1, 0), now);
+        marks.record(ShellMark::CommandFinished(Some(0)), at(3, 0), now);
+        marks.record(ShellMark::PromptStart, at(3, 0), now);
+        // An empty command line finishes nothing
+        marks.record(ShellMark::CommandFinished(Some(130)), at(4, 0), now);
+        marks.record(ShellMark::PromptStart, at(4, 0), now);
+        marks.record(ShellMark::PromptStart, at(4, 0), now);
+        marks.record(ShellMark::OutputStart, at(5, 0), now);
+        marks.record(ShellMark::CommandFinished(Some(1)), at(6, 0), now);
+
+        let statuses: Vec<_> = marks.as_slice().iter().map(|m| m.status()).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                CommandStatus::Succeeded,
+                CommandStatus::Pending,
+                CommandStatus::Failed
+            ]
+        );
+        assert_eq!(marks.previous_prompt(4), Some(3));
+        assert_eq!(marks.previous_prompt(0), None);
+        assert_eq!(marks.next_prompt(3), Some(4));
+        assert_eq!(marks.next_prompt(4), None);
+        assert_eq!(marks.last_output(), Some((at(5, 0), at(6, 0))));
+
+        marks.discard_lines(2);
+        assert_eq!(marks.as_slice().len(), 2);
+        assert_eq!(marks.as_slice()[0].prompt_line, 1);
+        assert_eq!(marks.last_output(), Some((at(3, 0), at(4, 0))));
+    }
+
+    #[test]
+    fn test_finished_commands_are_timed() {
+        let at = |line, col| Position::new(line, col);
+        let start = Instant::now();
+        let tenths = |tenths: u64| start + Duration::from_millis(tenths * 100);
+        let mut marks = CommandMarks::default();
+        marks.record(ShellMark::PromptStart, at(0, 0), start);
+        marks.record(ShellMark::OutputStart, at(1, 0), tenths(10));
+        assert!(marks.take_finished().is_empty());
+        marks.record(ShellMark::CommandFinished(Some(0)), at(3, 0), tenths(33));
+        marks.record(ShellMark::PromptStart, at(3, 0), tenths(33));
+        marks.record(ShellMark::OutputStart, at(4, 0), tenths(40));
+        marks.record(ShellMark::CommandFinished(Some(1)), at(4, 0), tenths(41));
+
+        let finished = marks.take_finished();
+        assert_eq!(finished.len(), 2);
+        assert_eq!(finished[0].duration, Some(Duration::from_millis(2300)));
+        assert_eq!(finished[0].summary().as_deref(), Some("✓ 2.3s"));
+        assert_eq!(finished[0].summary_line(), Some(2));
+        assert_eq!(finished[1].summary().as_deref(), Some("✗ exit 1"));
+        // No output: the summary goes on the command line
+        assert_eq!(finished[1].summary_line(), Some(3));
+        assert!(marks.take_finished().is_empty());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_millis(400)), "0.4s");
+        assert_eq!(format_duration(Duration::from_secs(65)), "1m 05s");
+        let hours = Duration::from_secs(2 * 3600 + 180);
+        assert_eq!(format_duration(hours), "2h 03m");
+    }
+}
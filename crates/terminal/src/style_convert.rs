@@ -200,6 +200,143 @@ where
     }
 }
 
+// Chunk: docs/chunks/terminal_scrollback_export - Plain-text serialization for export
+/// Convert a StyledLine to plain text, trimming trailing whitespace.
+pub fn styled_line_to_plain(line: &StyledLine) -> String {
+    let text: String = line.spans.iter().map(|span| span.text.as_str()).collect();
+    text.trim_end().to_string()
+}
+
+// Chunk: docs/chunks/terminal_scrollback_export - ANSI serialization for export
+/// Convert a StyledLine back into text with SGR escape sequences.
+///
+/// Each span is prefixed with a full SGR reset plus its attributes, so lines
+/// can be concatenated or viewed individually (e.g. with `less -R`). Trailing
+/// whitespace in an unstyled final span is trimmed, and the line ends with a
+/// reset when any styling was emitted.
+pub fn styled_line_to_ansi(line: &StyledLine) -> String {
+    let mut out = String::new();
+    let mut styled = false;
+    let last = line.spans.len().saturating_sub(1);
+
+    for (i, span) in line.spans.iter().enumerate() {
+        let text = if i == last && span.style == Style::default() {
+            span.text.trim_end()
+        } else {
+            span.text.as_str()
+        };
+        if text.is_empty() {
+            continue;
+        }
+
+        if span.style == Style::default() {
+            if styled {
+                out.push_str("\x1b[0m");
+                styled = false;
+            }
+        } else {
+            out.push_str(&style_to_sgr(&span.style));
+            styled = true;
+        }
+        out.push_str(text);
+    }
+
+    if styled {
+        out.push_str("\x1b[0m");
+    }
+    out
+}
+
+/// Build the SGR sequence (`ESC [ 0 ; ... m`) that selects `style`.
+fn style_to_sgr(style: &Style) -> String {
+    let mut params: Vec<String> = vec!["0".to_string()];
+
+    if style.bold {
+        params.push("1".to_string());
+    }
+    if style.dim {
+        params.push("2".to_string());
+    }
+    if style.italic {
+        params.push("3".to_string());
+    }
+    match style.underline {
+        UnderlineStyle::None => {}
+        UnderlineStyle::Single => params.push("4".to_string()),
+        UnderlineStyle::Double => params.push("4:2".to_string()),
+        UnderlineStyle::Curly => params.push("4:3".to_string()),
+        UnderlineStyle::Dotted => params.push("4:4".to_string()),
+        UnderlineStyle::Dashed => params.push("4:5".to_string()),
+    }
+    if style.inverse {
+        params.push("7".to_string());
+    }
+    if style.hidden {
+        params.push("8".to_string());
+    }
+    if style.strikethrough {
+        params.push("9".to_string());
+    }
+    if let Some(p) = color_sgr(style.fg, 30, 90, 38) {
+        params.push(p);
+    }
+    if let Some(p) = color_sgr(style.bg, 40, 100, 48) {
+        params.push(p);
+    }
+    if let Some(color) = style.underline_color {
+        match color {
+            Color::Default => {}
+            Color::Named(named) => params.push(format!("58;5;{}", named_color_index(named))),
+            Color::Indexed(idx) => params.push(format!("58;5;{}", idx)),
+            Color::Rgb { r, g, b } => params.push(format!("58;2;{};{};{}", r, g, b)),
+        }
+    }
+
+    format!("\x1b[{}m", params.join(";"))
+}
+
+/// SGR parameter for a foreground or background color.
+///
+/// `base` is 30/40 for the normal colors, `bright_base` 90/100 for the
+/// bright colors, and `extended` 38/48 for indexed and truecolor.
+fn color_sgr(color: Color, base: u8, bright_base: u8, extended: u8) -> Option<String> {
+    match color {
+        Color::Default => None,
+        Color::Named(named) => {
+            let idx = named_color_index(named);
+            if idx < 8 {
+                Some((base + idx).to_string())
+            } else {
+                Some((bright_base + idx - 8).to_string())
+            }
+        }
+        Color::Indexed(idx) => Some(format!("{};5;{}", extended, idx)),
+        Color::Rgb { r, g, b } => Some(format!("{};2;{};{};{}", extended, r, g, b)),
+    }
+}
+
+/// The 256-color palette index of a named ANSI color.
+fn named_color_index(named: NamedColor) -> u8 {
+    match named {
+        NamedColor::Black => 0,
+        NamedColor::Red => 1,
+        NamedColor::Green => 2,
+        NamedColor::Yellow => 3,
+        NamedColor::Blue => 4,
+        NamedColor::Magenta => 5,
+        NamedColor::Cyan => 6,
+        NamedColor::White => 7,
+        NamedColor::BrightBlack => 8,
+        NamedColor::BrightRed => 9,
+        NamedColor::BrightGreen => 10,
+        NamedColor::BrightYellow => 11,
+        NamedColor::BrightBlue => 12,
+        NamedColor::BrightMagenta => 13,
+        NamedColor::BrightCyan => 14,
+        NamedColor::BrightWhite => 15,
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -647,4 +784,50 @@ mod tests {
         assert!(!line.spans[2].style.bold);
         assert!(!line.spans[2].style.italic);
     }
+
+    // ==================== Export Serialization Tests ====================
+
+    #[test]
+    fn test_styled_line_to_plain_trims_trailing_whitespace() {
+        let line = StyledLine::new(vec![
+            Span::new("hello", Style { bold: true, ..Style::default() }),
+            Span::new(" world   ", Style::default()),
+        ]);
+        assert_eq!(styled_line_to_plain(&line), "hello world");
+    }
+
+    #[test]
+    fn test_styled_line_to_ansi_unstyled_is_plain() {
+        let line = StyledLine::new(vec![Span::new("plain text   ", Style::default())]);
+        assert_eq!(styled_line_to_ansi(&line), "plain text");
+    }
+
+    #[test]
+    fn test_styled_line_to_ansi_emits_sgr_and_reset() {
+        let red_bold = Style {
+            fg: Color::Named(NamedColor::Red),
+            bold: true,
+            ..Style::default()
+        };
+        let line = StyledLine::new(vec![
+            Span::new("err", red_bold),
+            Span::new(": oops", Style::default()),
+        ]);
+        assert_eq!(styled_line_to_ansi(&line), "\x1b[0;1;31merr\x1b[0m: oops");
+    }
+
+    #[test]
+    fn test_styled_line_to_ansi_extended_colors() {
+        let style = Style {
+            fg: Color::Indexed(208),
+            bg: Color::Rgb { r: 1, g: 2, b: 3 },
+            ..Style::default()
+        };
+        let line = StyledLine::new(vec![Span::new("x", style)]);
+        assert_eq!(styled_line_to_ansi(&line), "\x1b[0;38;5;208;48;2;1;2;3mx\x1b[0m");
+
+        let bright = Style { fg: Color::Named(NamedColor::BrightCyan), ..Style::default() };
+        let line = StyledLine::new(vec![Span::new("y", bright)]);
+        assert_eq!(styled_line_to_ansi(&line), "\x1b[0;96my\x1b[0m");
+    }
 }
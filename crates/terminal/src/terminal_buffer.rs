@@ -23,7 +23,9 @@
 //! The `BufferView::styled_line()` API transparently serves from either region.
 
 use std::cell::RefCell;
-use std::path::Path;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use alacritty_terminal::event::{Event, EventListener};
 use alacritty_terminal::grid::Dimensions;
@@ -32,18 +34,34 @@ use alacritty_terminal::term::test::TermSize;
 use alacritty_terminal::term::{Config, Term, TermMode};
 use alacritty_terminal::vte::ansi::{CursorShape as VteCursorShape, Processor};
 use crossbeam_channel::{unbounded, Receiver, Sender};
+use lite_edit_input::Modifiers;
 
 // Chunk: docs/chunks/terminal_clipboard_selection - Terminal selection types
 use lite_edit_buffer::{
     BufferView, CursorInfo, CursorShape, DirtyLines, Position, StyledLine,
 };
+// Chunk: docs/chunks/terminal_command_status - Command summaries as annotations
+use lite_edit_buffer::{Annotation, Color, NamedColor};
 
 use crate::cold_scrollback::{ColdScrollback, PageCache};
 use crate::event::TerminalEvent;
-use crate::pty::PtyHandle;
+// Chunk: docs/chunks/terminal_kitty_graphics - Kitty graphics protocol
+use crate::graphics::{ApcSplitter, ImagePlacement, ImageStore, Segment};
+use crate::input_encoder::InputEncoder;
+// Chunk: docs/chunks/agent_events - Structured agent events
+use crate::agent_events::AgentEvent;
+use crate::pty::{shutdown_ptys, PtyHandle};
 // Chunk: docs/chunks/terminal_pty_wakeup - Run-loop wakeup for PTY output
 use crate::pty_wakeup::PtyWakeup;
-use crate::style_convert::row_to_styled_line;
+// Chunk: docs/chunks/terminal_replay - Timestamped session recording
+use crate::recording::Recording;
+// Chunk: docs/chunks/terminal_cwd_picker - Shell-reported working directory
+// Chunk: docs/chunks/terminal_shell_integration - OSC 133 command marks
+use crate::shell_integration::{
+    parse_osc133, parse_osc7, CommandMark, CommandMarks, CommandStatus, OscScanner, ShellMark,
+};
+use crate::style_convert::{row_to_styled_line, styled_line_to_ansi, styled_line_to_plain};
+use crate::transcript::Transcript;
 
 // Chunk: docs/chunks/terminal_flood_starvation - Byte-budgeted VTE processing
 /// Result of a `poll_events()` call.
@@ -62,6 +80,47 @@ pub enum PollResult {
     MorePending,
 }
 
+// Chunk: docs/chunks/terminal_scrollback_export - Export format selection
+/// Output format for `TerminalBuffer::dump_scrollback()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollbackFormat {
+    /// Plain text with trailing whitespace trimmed.
+    Plain,
+    /// Text with SGR escape sequences preserving colors and attributes.
+    Ansi,
+}
+
+// Chunk: docs/chunks/terminal_memory_budget - Per-terminal memory report
+/// Where a terminal's memory goes, as returned by
+/// `TerminalBuffer::memory_usage()`.
+///
+/// Byte counts are estimates from element sizes and lengths, not allocator
+/// statistics, but they track the structures that grow with output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Lines in alacritty's grid: the viewport plus hot scrollback.
+    pub grid_lines: usize,
+    /// Bytes of the cells holding those lines.
+    pub grid_bytes: usize,
+    /// Lines written to the cold scrollback file.
+    pub cold_lines: usize,
+    /// Bytes of the cold scrollback's in-memory line index.
+    pub cold_index_bytes: usize,
+    /// Bytes of cold scrollback pages cached in memory.
+    pub page_cache_bytes: usize,
+    /// Bytes of pixel data of images the program displayed.
+    pub image_bytes: usize,
+    /// Size of the cold scrollback file on disk (not counted in memory).
+    pub cold_file_bytes: u64,
+}
+
+impl MemoryUsage {
+    /// Returns the estimated bytes held in memory.
+    pub fn total_bytes(&self) -> usize {
+        self.grid_bytes + self.cold_index_bytes + self.page_cache_bytes + self.image_bytes
+    }
+}
+
 // Chunk: docs/chunks/tty_cursor_reporting - DSR/CPR event forwarding
 /// Event listener that captures terminal events and forwards them via a channel.
 ///
@@ -104,7 +163,9 @@ impl EventListener for EventSender {
 /// - Cold storage: On disk, only paged into memory on demand
 ///
 /// This enables 10+ concurrent terminals with 100K+ line histories while
-/// keeping memory usage under ~7 MB per terminal.
+/// keeping memory usage under ~7 MB per terminal. `memory_usage()` reports
+/// where a terminal's memory actually goes, and both limits can be changed
+/// per terminal.
 // Chunk: docs/chunks/terminal_file_backed_scrollback - File-backed cold scrollback
 pub struct TerminalBuffer {
     /// The alacritty terminal emulator.
@@ -149,6 +210,48 @@ pub struct TerminalBuffer {
     /// we force a full viewport repaint to ensure fullscreen apps like Vim paint
     /// their initial content immediately.
     was_alt_screen: bool,
+    // Chunk: docs/chunks/terminal_bell_attention - Bell attention tracking
+    /// Whether the hosted program rang the bell (BEL) since the flag was last
+    /// cleared. The editor surfaces this as a badge on background terminals.
+    bell_pending: bool,
+    // Chunk: docs/chunks/terminal_kitty_graphics - Image placements
+    /// Splits Kitty graphics commands out of PTY output before VTE processing.
+    apc_splitter: ApcSplitter,
+    /// Images and placements created by the hosted program.
+    images: ImageStore,
+    /// Cell size in pixels, used to size images that don't specify cells.
+    cell_size_px: (f32, f32),
+    // Chunk: docs/chunks/agent_transcript - Transcript capture
+    /// Transcript that PTY output (and optionally input) is teed to.
+    transcript: Option<Transcript>,
+    // Chunk: docs/chunks/alt_screen_scroll_passthrough - Sub-line scroll accumulation
+    /// Scroll distance, in pixels, not yet sent to the alternate screen
+    /// program because it is less than a line.
+    alt_scroll_remainder_px: f32,
+    // Chunk: docs/chunks/terminal_copy_mode - Copy mode cursor
+    /// The copy mode cursor, shown in place of the terminal's cursor while
+    /// copy mode is active.
+    copy_cursor: Option<Position>,
+    // Chunk: docs/chunks/terminal_cwd_picker - Shell-reported working directory
+    /// Picks shell integration OSC strings out of PTY output.
+    osc_scanner: OscScanner,
+    /// The working directory last reported by the shell via OSC 7.
+    reported_cwd: Option<PathBuf>,
+    // Chunk: docs/chunks/terminal_shell_integration - OSC 133 command marks
+    /// Prompts and commands marked by the shell with OSC 133.
+    command_marks: CommandMarks,
+    // Chunk: docs/chunks/terminal_replay - Timestamped session recording
+    /// Output of the spawned program with arrival times, for replay and export.
+    recording: Option<Recording>,
+    /// Size limit for the recording started at spawn; 0 disables recording.
+    recording_max_bytes: u64,
+    // Chunk: docs/chunks/agent_events - Structured agent events
+    /// Agent events received and not yet taken, or None when the program's
+    /// events aren't collected.
+    agent_events: Option<Vec<AgentEvent>>,
+    // Chunk: docs/chunks/render_stats_hud - PTY throughput
+    /// Bytes of program output processed since the terminal was created.
+    output_bytes: u64,
 }
 
 impl TerminalBuffer {
@@ -174,6 +277,10 @@ impl TerminalBuffer {
     /// well under 100ms even during terminal output floods.
     pub const DEFAULT_BYTES_PER_POLL: usize = 4 * 1024; // 4KB
 
+    // Chunk: docs/chunks/terminal_kitty_graphics - Image sizing fallback
+    /// Cell size assumed for image sizing until `set_cell_size_px` is called.
+    pub const DEFAULT_CELL_SIZE_PX: (f32, f32) = (8.0, 16.0);
+
     /// Creates a new terminal buffer with the given dimensions.
     ///
     /// # Arguments
@@ -212,6 +319,20 @@ impl TerminalBuffer {
             selection_head: None,
             event_rx,
             was_alt_screen: false, // Terminal starts in primary screen mode
+            bell_pending: false,
+            apc_splitter: ApcSplitter::new(),
+            images: ImageStore::default(),
+            cell_size_px: Self::DEFAULT_CELL_SIZE_PX,
+            transcript: None,
+            alt_scroll_remainder_px: 0.0,
+            copy_cursor: None,
+            osc_scanner: OscScanner::new(),
+            reported_cwd: None,
+            command_marks: CommandMarks::default(),
+            recording: None,
+            recording_max_bytes: Recording::DEFAULT_MAX_BYTES,
+            agent_events: None,
+            output_bytes: 0,
         }
     }
 
@@ -222,6 +343,40 @@ impl TerminalBuffer {
         self.hot_scrollback_limit = limit;
     }
 
+    // Chunk: docs/chunks/terminal_memory_budget - Configurable scrollback limits
+    /// Returns the hot scrollback limit.
+    pub fn hot_scrollback_limit(&self) -> usize {
+        self.hot_scrollback_limit
+    }
+
+    /// Sets how many bytes of cold scrollback may be cached in memory for
+    /// reading, evicting cached pages if the cache is over the new budget.
+    pub fn set_page_cache_bytes(&mut self, bytes: usize) {
+        self.page_cache.borrow_mut().set_max_bytes(bytes);
+    }
+
+    /// Returns the cold scrollback page cache budget in bytes.
+    pub fn page_cache_bytes(&self) -> usize {
+        self.page_cache.borrow().max_bytes()
+    }
+
+    /// Returns an estimate of the memory this terminal uses, by structure.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        use alacritty_terminal::term::cell::Cell;
+
+        let grid_lines = self.term.grid().total_lines();
+        let cold = self.cold_scrollback.borrow();
+        MemoryUsage {
+            grid_lines,
+            grid_bytes: grid_lines * self.term.grid().columns() * std::mem::size_of::<Cell>(),
+            cold_lines: self.cold_line_count,
+            cold_index_bytes: cold.as_ref().map_or(0, |cold| cold.index_bytes()),
+            page_cache_bytes: self.page_cache.borrow().size_bytes(),
+            image_bytes: self.images.memory_bytes(),
+            cold_file_bytes: cold.as_ref().map_or(0, |cold| cold.file_bytes()),
+        }
+    }
+
     // Chunk: docs/chunks/terminal_shell_env - Login shell spawning for full environment
     /// Spawns a login shell in this terminal.
     ///
@@ -238,6 +393,8 @@ impl TerminalBuffer {
         let (cols, rows) = self.size;
         let handle = PtyHandle::spawn("", &[], cwd, rows as u16, cols as u16, true)?;
         self.pty = Some(handle);
+        self.sync_pty_size();
+        self.start_recording();
         Ok(())
     }
 
@@ -261,9 +418,43 @@ impl TerminalBuffer {
         let (cols, rows) = self.size;
         let handle = PtyHandle::spawn(cmd, args, cwd, rows as u16, cols as u16, false)?;
         self.pty = Some(handle);
+        self.sync_pty_size();
+        self.start_recording();
         Ok(())
     }
 
+    // Chunk: docs/chunks/terminal_tab_labels - Terminals restored before their shell
+    /// Returns true once a shell or command has been spawned in this terminal.
+    pub fn is_spawned(&self) -> bool {
+        self.pty.is_some()
+    }
+
+    // Chunk: docs/chunks/terminal_flow_control - Backlog and throttled state
+    /// Returns the bytes of output read from the program and not yet
+    /// processed by `poll_events()`.
+    pub fn pending_output_bytes(&self) -> usize {
+        self.pty
+            .as_ref()
+            .map_or(0, |pty| pty.pending_output_bytes())
+    }
+
+    // Chunk: docs/chunks/render_stats_hud - PTY throughput
+    /// Returns the bytes of program output processed by `poll_events()`
+    /// since the terminal was created. Sampled over time it gives the
+    /// terminal's output rate.
+    pub fn output_bytes(&self) -> u64 {
+        self.output_bytes
+    }
+
+    /// Returns true while output arrives faster than `poll_events()`
+    /// processes it and reading from the program is paused until the backlog
+    /// drains.
+    pub fn is_output_throttled(&self) -> bool {
+        self.pty
+            .as_ref()
+            .is_some_and(|pty| pty.is_output_throttled())
+    }
+
     // Chunk: docs/chunks/terminal_pty_wakeup - Run-loop wakeup for PTY output
     // Chunk: docs/chunks/terminal_shell_env - Login shell spawning for full environment
     /// Spawns a login shell with run-loop wakeup support.
@@ -281,6 +472,8 @@ impl TerminalBuffer {
         let (cols, rows) = self.size;
         let handle = PtyHandle::spawn_with_wakeup("", &[], cwd, rows as u16, cols as u16, wakeup, true)?;
         self.pty = Some(handle);
+        self.sync_pty_size();
+        self.start_recording();
         Ok(())
     }
 
@@ -299,6 +492,8 @@ impl TerminalBuffer {
         let (cols, rows) = self.size;
         let handle = PtyHandle::spawn_with_wakeup(cmd, args, cwd, rows as u16, cols as u16, wakeup, false)?;
         self.pty = Some(handle);
+        self.sync_pty_size();
+        self.start_recording();
         Ok(())
     }
 
@@ -317,20 +512,42 @@ impl TerminalBuffer {
     /// The byte budget ensures that processing a single terminal's output
     /// doesn't starve other terminals or user input handling.
     pub fn poll_events(&mut self) -> PollResult {
-        let Some(ref pty) = self.pty else {
+        if self.pty.is_none() {
             return PollResult::Idle;
-        };
+        }
 
         let mut bytes_processed: usize = 0;
         let mut processed_any = false;
+        // Chunk: docs/chunks/terminal_cold_selection - Scrollback before this batch of output
+        let scrollback_end = if self.is_alt_screen() {
+            0
+        } else {
+            self.cold_line_count + self.history_size()
+        };
 
-        // Drain events up to the byte budget
-        while let Some(event) = pty.try_recv() {
+        // Drain events up to the byte budget. The PTY is re-borrowed each
+        // iteration because `advance` needs `&mut self`.
+        while let Some(event) = self.pty.as_ref().and_then(|pty| pty.try_recv()) {
             match event {
                 TerminalEvent::PtyOutput(data) => {
                     bytes_processed += data.len();
+                    self.output_bytes += data.len() as u64;
+                    // Chunk: docs/chunks/agent_transcript - Tee output to the transcript
+                    if let Some(ref mut transcript) = self.transcript {
+                        if let Err(e) = transcript.record_output(&data) {
+                            eprintln!("Failed to write terminal transcript: {}", e);
+                            self.transcript = None;
+                        }
+                    }
+                    // Chunk: docs/chunks/terminal_replay - Record output for replay
+                    if let Some(ref mut recording) = self.recording {
+                        if let Err(e) = recording.record_output(&data) {
+                            eprintln!("Failed to write terminal recording: {}", e);
+                            self.recording = None;
+                        }
+                    }
                     // Feed bytes to the terminal emulator
-                    self.processor.advance(&mut self.term, &data);
+                    self.advance(&data);
                     processed_any = true;
 
                     // Check budget after processing (we always process at least one chunk)
@@ -351,31 +568,31 @@ impl TerminalBuffer {
         }
 
         // Chunk: docs/chunks/tty_cursor_reporting - Process terminal-generated events
-        // Handle events from alacritty_terminal (DSR responses, title changes, etc.)
+        // Handle events from alacritty_terminal (DSR responses, bell, etc.)
         // These events are generated when processing PTY output above (e.g., when the
         // hosted program sends a DSR query, alacritty responds with a PtyWrite event).
-        while let Ok(event) = self.event_rx.try_recv() {
-            match event {
-                Event::PtyWrite(text) => {
-                    // Write the response back to the PTY.
-                    // This handles DSR (Device Status Report) responses like cursor
-                    // position queries (ESC[6n → ESC[row;colR).
-                    if let Some(ref mut pty) = self.pty {
-                        let _ = pty.write(text.as_bytes());
-                    }
-                    processed_any = true;
-                }
-                // Other events (Title, Bell, ClipboardStore, etc.) could be handled
-                // here in the future, but are out of scope for this chunk.
-                _ => {}
-            }
+        if self.process_term_events() {
+            processed_any = true;
         }
 
         if processed_any {
             // Chunk: docs/chunks/terminal_clipboard_selection - Clear selection on PTY output
             // Clear selection when new output arrives to avoid stale/misaligned highlights.
             // This is standard terminal emulator behavior.
-            self.clear_selection();
+            // Chunk: docs/chunks/terminal_copy_mode - Copy mode keeps its selection
+            // Chunk: docs/chunks/terminal_cold_selection - Scrollback selections survive output
+            // Lines already in scrollback can't be rewritten, so a selection
+            // lying entirely there stays valid while the program keeps writing,
+            // unless the scrollback was cleared or the alternate screen entered.
+            let scrollback_kept = !self.is_alt_screen()
+                && self.cold_line_count + self.history_size() >= scrollback_end;
+            let in_scrollback = scrollback_kept
+                && self
+                    .selection_range()
+                    .is_some_and(|(_, end)| end.line < scrollback_end);
+            if self.copy_cursor.is_none() && !in_scrollback {
+                self.clear_selection();
+            }
 
             // Update dirty tracking based on terminal damage
             self.update_damage();
@@ -391,6 +608,12 @@ impl TerminalBuffer {
                 // Mode transition detected - force full viewport dirty
                 self.dirty = DirtyLines::FromLineToEnd(0);
                 self.was_alt_screen = is_alt;
+                // Chunk: docs/chunks/alt_screen_scroll_passthrough - Partial lines don't carry between programs
+                self.alt_scroll_remainder_px = 0.0;
+                // Chunk: docs/chunks/terminal_kitty_graphics - Alt screen images end with the alt screen
+                if !is_alt {
+                    self.images.clear_alt_screen();
+                }
             }
 
             // Check if we need to flush lines to cold storage
@@ -407,9 +630,294 @@ impl TerminalBuffer {
         }
     }
 
+    /// Drains events emitted by alacritty_terminal while processing output.
+    ///
+    /// Returns true if any event was handled.
+    fn process_term_events(&mut self) -> bool {
+        let mut handled = false;
+        while let Ok(event) = self.event_rx.try_recv() {
+            match event {
+                Event::PtyWrite(text) => {
+                    // Write the response back to the PTY.
+                    // This handles DSR (Device Status Report) responses like cursor
+                    // position queries (ESC[6n → ESC[row;colR).
+                    if let Some(ref mut pty) = self.pty {
+                        let _ = pty.write(text.as_bytes());
+                    }
+                    handled = true;
+                }
+                // Chunk: docs/chunks/terminal_bell_attention - Record BEL for attention badges
+                Event::Bell => {
+                    self.bell_pending = true;
+                    handled = true;
+                }
+                // Other events (Title, ClipboardStore, etc.) could be handled
+                // here in the future.
+                _ => {}
+            }
+        }
+        handled
+    }
+
+    // Chunk: docs/chunks/terminal_bell_attention - Bell attention tracking
+    /// Returns true if the terminal rang the bell since it was last cleared.
+    pub fn has_pending_bell(&self) -> bool {
+        self.bell_pending
+    }
+
+    /// Clears the pending bell flag.
+    ///
+    /// Called when the user focuses the tab hosting this terminal.
+    pub fn clear_bell(&mut self) {
+        self.bell_pending = false;
+    }
+
+    // =========================================================================
+    // Scrollback Export and Clear
+    // Chunk: docs/chunks/terminal_scrollback_export - Archive or reset long sessions
+    // =========================================================================
+
+    /// Writes the full scrollback (cold + hot + viewport) to `path`.
+    ///
+    /// Lines are written oldest first, one per line, in the requested format.
+    /// Trailing blank lines (e.g. empty viewport rows below the prompt) are
+    /// omitted. In alternate screen mode only the alternate screen is written,
+    /// matching what `line_count()` reports.
+    ///
+    /// Returns the number of lines written.
+    pub fn dump_scrollback(&self, path: &Path, format: ScrollbackFormat) -> std::io::Result<usize> {
+        use std::io::Write;
+
+        let mut lines: Vec<String> = (0..self.line_count())
+            .map(|line| {
+                let styled = self.styled_line(line).unwrap_or_else(StyledLine::empty);
+                match format {
+                    ScrollbackFormat::Plain => styled_line_to_plain(&styled),
+                    ScrollbackFormat::Ansi => styled_line_to_ansi(&styled),
+                }
+            })
+            .collect();
+        while lines.last().is_some_and(|l| l.is_empty()) {
+            lines.pop();
+        }
+
+        let mut writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+        for line in &lines {
+            writer.write_all(line.as_bytes())?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(lines.len())
+    }
+
+    /// Discards all scrollback history above the viewport.
+    ///
+    /// Drops both alacritty's in-memory history and the cold scrollback file.
+    /// The visible screen is left untouched, as is the alternate screen (which
+    /// has no scrollback) - calling this in alternate screen mode is a no-op.
+    pub fn clear_scrollback(&mut self) {
+        if self.is_alt_screen() {
+            return;
+        }
+
+        // Chunk: docs/chunks/terminal_kitty_graphics - Keep images aligned with the screen
+        self.images
+            .discard_lines(self.cold_line_count + self.history_size());
+        // Chunk: docs/chunks/terminal_shell_integration - Keep prompt marks aligned too
+        self.command_marks
+            .discard_lines(self.cold_line_count + self.history_size());
+        self.term.grid_mut().clear_history();
+        *self.cold_scrollback.borrow_mut() = None;
+        self.page_cache.borrow_mut().invalidate();
+        self.cold_line_count = 0;
+        self.last_history_size = 0;
+        self.selection_anchor = None;
+        self.selection_head = None;
+        self.dirty = DirtyLines::FromLineToEnd(0);
+    }
+
+    // Chunk: docs/chunks/terminal_kitty_graphics - Graphics-aware VTE feeding
+    /// Feeds PTY output to the emulator, handling Kitty graphics commands.
+    ///
+    /// Graphics commands are executed at their position in the stream, so an
+    /// image is anchored at the cursor position the program left it at.
+    fn advance(&mut self, data: &[u8]) {
+        // Chunk: docs/chunks/terminal_shell_integration - Shell integration sequences in stream order
+        // Output up to the end of each shell integration sequence is processed
+        // before the sequence is handled, so a prompt mark records the cursor
+        // position the shell left it at.
+        let mut fed = 0;
+        for (end, body) in self.osc_scanner.scan(data) {
+            self.advance_output(&data[fed..end]);
+            fed = end;
+            self.handle_shell_integration(&body);
+        }
+        self.advance_output(&data[fed..]);
+    }
+
+    /// Feeds a stretch of PTY output to the emulator, executing any Kitty
+    /// graphics commands in it.
+    fn advance_output(&mut self, data: &[u8]) {
+        if data.is_empty() {
+            return;
+        }
+        if self.apc_splitter.can_pass_through(data) {
+            self.processor.advance(&mut self.term, data);
+            self.forget_cleared_images(data);
+            return;
+        }
+
+        for segment in self.apc_splitter.split(data) {
+            match segment {
+                Segment::Output(bytes) => {
+                    self.processor.advance(&mut self.term, &bytes);
+                    self.forget_cleared_images(&bytes);
+                }
+                Segment::Graphics(body) => self.handle_graphics(&body),
+            }
+        }
+    }
+
+    // Chunk: docs/chunks/terminal_cwd_picker - Observe OSC 7 reports
+    // Chunk: docs/chunks/terminal_shell_integration - Record OSC 133 marks
+    /// Handles the body of an OSC string from the shell: working directory
+    /// reports, prompt marks and agent events. Other OSC strings are left to
+    /// the emulator.
+    fn handle_shell_integration(&mut self, body: &[u8]) {
+        // Chunk: docs/chunks/agent_events - Structured agent events
+        if let Some(events) = &mut self.agent_events {
+            if let Some(event) = AgentEvent::from_osc(body) {
+                events.push(event);
+                return;
+            }
+        }
+        if let Some(cwd) = parse_osc7(body) {
+            self.reported_cwd = Some(cwd);
+        } else if let Some(mark) = parse_osc133(body) {
+            // Full-screen programs don't mark prompts in the scrollback
+            if self.is_alt_screen() {
+                return;
+            }
+            let finishing = matches!(mark, ShellMark::CommandFinished(_));
+            let cursor = self.term.grid().cursor.point;
+            let line = self.screen_top() + cursor.line.0.max(0) as usize;
+            self.command_marks
+                .record(mark, Position::new(line, cursor.column.0), Instant::now());
+            // Chunk: docs/chunks/terminal_command_status - Redraw the line that gets the summary
+            if finishing {
+                let last = self.command_marks.as_slice().last();
+                if let Some(line) = last.and_then(CommandMark::summary_line) {
+                    self.dirty.merge(DirtyLines::Single(line));
+                }
+            }
+        }
+    }
+
+    /// Executes one graphics command and anchors any resulting placement at
+    /// the cursor.
+    fn handle_graphics(&mut self, body: &[u8]) {
+        let (response, request) = self.images.handle(body, self.cell_size_px);
+
+        if let Some(mut request) = request {
+            // Client-supplied sizes are untrusted; no placement needs to be
+            // larger than the screen.
+            request.cols = request.cols.min(self.term.grid().columns());
+            request.rows = request.rows.min(self.screen_lines());
+            let alt = self.is_alt_screen();
+            let cursor = self.term.grid().cursor.point;
+            let line = self.screen_top() + cursor.line.0.max(0) as usize;
+            let (cols, rows, move_cursor) = (request.cols, request.rows, request.move_cursor);
+            self.images.place(request, line, cursor.column.0, alt);
+
+            // Like kitty, leave the cursor on the image's last row, just past
+            // its right edge, scrolling if the image runs off the bottom.
+            if move_cursor {
+                let mut movement = b"\n".repeat(rows.saturating_sub(1));
+                movement.extend_from_slice(format!("\x1b[{}C", cols).as_bytes());
+                self.processor.advance(&mut self.term, &movement);
+            }
+            self.dirty.merge(DirtyLines::FromLineToEnd(line));
+        }
+
+        if let Some(response) = response {
+            let _ = self.write_input(&response);
+        }
+    }
+
+    /// Drops image placements removed by a screen clear in `output`.
+    ///
+    /// `ESC [ 2 J` clears images on the screen. `ESC [ 3 J` discards hot
+    /// scrollback, which renumbers the screen lines, so it clears every image
+    /// outside cold scrollback. `ESC c` resets everything.
+    fn forget_cleared_images(&mut self, output: &[u8]) {
+        if !output.contains(&0x1b) {
+            return;
+        }
+        let contains = |needle: &[u8]| output.windows(needle.len()).any(|w| w == needle);
+        let alt = self.is_alt_screen();
+
+        if contains(b"\x1bc") {
+            self.images.clear();
+        } else if contains(b"\x1b[3J") {
+            let from = if alt { 0 } else { self.cold_line_count };
+            self.images.clear_from(from, alt);
+        } else if contains(b"\x1b[2J") {
+            self.images.clear_from(self.screen_top(), alt);
+        }
+    }
+
+    /// Returns the buffer line of the top of the visible screen.
+    fn screen_top(&self) -> usize {
+        if self.is_alt_screen() {
+            0
+        } else {
+            self.cold_line_count + self.history_size()
+        }
+    }
+
+    /// Returns image placements overlapping the given buffer lines.
+    ///
+    /// Line numbers use the same coordinates as `BufferView::styled_line`.
+    /// Only placements belonging to the current screen (primary or alternate)
+    /// are returned.
+    pub fn image_placements(&self, lines: Range<usize>) -> Vec<ImagePlacement> {
+        self.images.placements_in(lines, self.is_alt_screen())
+    }
+
+    /// Sets the cell size in pixels, used to size images in cells.
+    ///
+    /// The resulting window size in pixels is also reported to the PTY, which
+    /// image tools use to scale their output.
+    pub fn set_cell_size_px(&mut self, width: f32, height: f32) {
+        self.cell_size_px = (width, height);
+        self.sync_pty_size();
+    }
+
+    /// Pushes the current size in cells and pixels to the PTY, if attached.
+    fn sync_pty_size(&self) {
+        let Some(ref pty) = self.pty else {
+            return;
+        };
+        let (cols, rows) = self.size;
+        let pixels = |cells: usize, cell_px: f32| (cells as f32 * cell_px).min(u16::MAX as f32) as u16;
+        let _ = pty.resize(
+            rows as u16,
+            cols as u16,
+            pixels(cols, self.cell_size_px.0),
+            pixels(rows, self.cell_size_px.1),
+        );
+    }
+
     /// Writes input data to the PTY stdin.
     pub fn write_input(&mut self, data: &[u8]) -> std::io::Result<()> {
         if let Some(ref mut pty) = self.pty {
+            // Chunk: docs/chunks/agent_transcript - Tee input to the transcript
+            if let Some(ref mut transcript) = self.transcript {
+                if let Err(e) = transcript.record_input(data) {
+                    eprintln!("Failed to write terminal transcript: {}", e);
+                    self.transcript = None;
+                }
+            }
             pty.write(data)
         } else {
             Err(std::io::Error::new(
@@ -432,14 +940,86 @@ impl TerminalBuffer {
         self.term.resize(size);
 
         // Resize the PTY
-        if let Some(ref pty) = self.pty {
-            let _ = pty.resize(rows as u16, cols as u16);
+        self.sync_pty_size();
+
+        if let Some(ref mut transcript) = self.transcript {
+            if let Err(e) = transcript.record_resize(cols, rows) {
+                eprintln!("Failed to write terminal transcript: {}", e);
+                self.transcript = None;
+            }
+        }
+        if let Some(ref mut recording) = self.recording {
+            recording.record_resize(cols, rows);
         }
 
         // Mark everything dirty
         self.dirty = DirtyLines::FromLineToEnd(0);
     }
 
+    // Chunk: docs/chunks/agent_transcript - Transcript capture
+    /// Starts teeing PTY output (and input, if the transcript records it) to
+    /// `transcript`, replacing any previous one. `None` stops recording.
+    ///
+    /// A transcript that fails to write is dropped after logging the error, so
+    /// a full disk never interrupts the session itself.
+    pub fn set_transcript(&mut self, transcript: Option<Transcript>) {
+        self.transcript = transcript;
+    }
+
+    /// Returns the active transcript, if any.
+    pub fn transcript(&self) -> Option<&Transcript> {
+        self.transcript.as_ref()
+    }
+
+    // Chunk: docs/chunks/terminal_replay - Timestamped session recording
+    /// Sets the size limit, in bytes, for recording the program's output.
+    ///
+    /// Applies to a recording already running and to the one started when a
+    /// program is spawned. 0 disables recording, discarding a running one.
+    pub fn set_recording_max_bytes(&mut self, bytes: u64) {
+        self.recording_max_bytes = bytes;
+        if bytes == 0 {
+            self.recording = None;
+        } else if let Some(ref mut recording) = self.recording {
+            recording.set_max_bytes(bytes);
+        }
+    }
+
+    /// Returns the size limit for recording the program's output, in bytes.
+    pub fn recording_max_bytes(&self) -> u64 {
+        self.recording_max_bytes
+    }
+
+    /// Returns the recording of the program's output, if one is running.
+    pub fn recording(&self) -> Option<&Recording> {
+        self.recording.as_ref()
+    }
+
+    /// Starts recording output from a freshly spawned program, unless
+    /// recording is disabled.
+    fn start_recording(&mut self) {
+        if self.recording_max_bytes == 0 {
+            return;
+        }
+        let (cols, rows) = self.size;
+        match Recording::new(cols, rows) {
+            Ok(mut recording) => {
+                recording.set_max_bytes(self.recording_max_bytes);
+                self.recording = Some(recording);
+            }
+            Err(e) => eprintln!("Failed to start terminal recording: {}", e),
+        }
+    }
+
+    /// Feeds recorded output to the emulator as if the program had printed it.
+    pub(crate) fn feed_recorded(&mut self, data: &[u8]) {
+        self.advance(data);
+        self.process_term_events();
+        self.was_alt_screen = self.is_alt_screen();
+        self.dirty = DirtyLines::FromLineToEnd(0);
+        self.check_scrollback_overflow();
+    }
+
     /// Returns the terminal size as (cols, rows).
     pub fn size(&self) -> (usize, usize) {
         self.size
@@ -459,6 +1039,43 @@ impl TerminalBuffer {
         *self.term.mode()
     }
 
+    // Chunk: docs/chunks/alt_screen_scroll_passthrough - Scroll input for alternate screen programs
+    /// Forwards a vertical scroll of `dy_px` pixels to the program on the
+    /// alternate screen.
+    ///
+    /// Every `line_height` pixels is one line. Programs with mouse reporting
+    /// get wheel events at (`col`, `row`); the rest get Up/Down arrow keys
+    /// (see [`InputEncoder::encode_alternate_scroll`]), which is how less, man
+    /// and fzf scroll. Trackpads deliver many deltas smaller than a line, so the
+    /// leftover part of a line carries over to the next call.
+    ///
+    /// Returns true if anything was written to the PTY.
+    pub fn scroll_alt_screen(
+        &mut self,
+        dy_px: f32,
+        line_height: f32,
+        col: usize,
+        row: usize,
+    ) -> bool {
+        if line_height <= 0.0 || !self.is_alt_screen() {
+            return false;
+        }
+        let total_px = self.alt_scroll_remainder_px + dy_px;
+        let lines = (total_px / line_height).trunc();
+        self.alt_scroll_remainder_px = total_px - lines * line_height;
+        let lines = lines as i32;
+        if lines == 0 {
+            return false;
+        }
+
+        let modes = self.term_mode();
+        let mut bytes = InputEncoder::encode_scroll(lines, col, row, &Modifiers::default(), modes);
+        if bytes.is_empty() {
+            bytes = InputEncoder::encode_alternate_scroll(lines, modes);
+        }
+        !bytes.is_empty() && self.write_input(&bytes).is_ok()
+    }
+
     /// Returns the number of lines in scrollback.
     fn history_size(&self) -> usize {
         self.term.grid().history_size()
@@ -508,6 +1125,20 @@ impl TerminalBuffer {
         }
     }
 
+    // Chunk: docs/chunks/pty_graceful_shutdown - Graceful single-terminal shutdown
+    /// Shuts the PTY process down gracefully, waiting at most `grace`.
+    ///
+    /// Sends SIGHUP, then SIGTERM after half of `grace`, then SIGKILL; see
+    /// [`shutdown_terminals`] for the details.
+    ///
+    /// Returns the exit code, or `None` if there is no PTY or the process
+    /// could not be reaped.
+    pub fn shutdown(&mut self, grace: Duration) -> Option<i32> {
+        let pty = self.pty.as_mut()?;
+        shutdown_ptys(&mut [pty], grace);
+        pty.try_wait()
+    }
+
     /// Returns the process ID of the PTY child process, if available.
     ///
     /// This is used for sending signals (e.g., SIGTERM for graceful shutdown).
@@ -515,6 +1146,123 @@ impl TerminalBuffer {
         self.pty.as_ref().and_then(|pty| pty.process_id())
     }
 
+    // Chunk: docs/chunks/terminal_cwd_picker - Shell working directory
+    /// Returns the shell's current working directory.
+    ///
+    /// Prefers the directory the shell last reported with OSC 7, which shells
+    /// with integration enabled send at every prompt. Otherwise falls back to
+    /// asking the operating system for the child process's working directory.
+    pub fn current_dir(&self) -> Option<PathBuf> {
+        self.reported_cwd
+            .clone()
+            .or_else(|| self.pty.as_ref().and_then(|pty| pty.working_directory()))
+    }
+
+    // Chunk: docs/chunks/agent_events - Structured agent events
+    /// Starts or stops collecting the structured events the program sends
+    /// (see [`AgentEvent`]). Off by default, so events from programs that
+    /// aren't agents are ignored.
+    pub fn set_collect_agent_events(&mut self, collect: bool) {
+        self.agent_events = collect.then(Vec::new);
+    }
+
+    /// Takes the events received since the last call, in the order they
+    /// were sent.
+    pub fn take_agent_events(&mut self) -> Vec<AgentEvent> {
+        match &mut self.agent_events {
+            Some(events) => std::mem::take(events),
+            None => Vec::new(),
+        }
+    }
+
+    // Chunk: docs/chunks/terminal_shell_integration - Prompt navigation
+    /// Returns the prompts and commands the shell marked with OSC 133, oldest
+    /// first.
+    ///
+    /// Empty unless the shell has shell integration enabled.
+    pub fn command_marks(&self) -> &[CommandMark] {
+        self.command_marks.as_slice()
+    }
+
+    /// Returns the line of the last marked prompt above `line`.
+    pub fn previous_prompt(&self, line: usize) -> Option<usize> {
+        self.command_marks.previous_prompt(line)
+    }
+
+    /// Returns the line of the first marked prompt below `line`.
+    pub fn next_prompt(&self, line: usize) -> Option<usize> {
+        self.command_marks.next_prompt(line)
+    }
+
+    /// Returns the output of the most recent finished command, or `None` if
+    /// no command has been marked as finished.
+    pub fn last_command_output(&self) -> Option<String> {
+        let (start, end) = self.command_marks.last_output()?;
+        let text = self.text_between(start, end);
+        Some(text.trim_end_matches('\n').to_string())
+    }
+
+    // Chunk: docs/chunks/terminal_command_status - Command duration and exit status
+    /// Returns the commands that finished since the last call, oldest first.
+    pub fn take_finished_commands(&mut self) -> Vec<CommandMark> {
+        self.command_marks.take_finished()
+    }
+
+    /// Returns annotations showing each finished command's summary
+    /// (`✓ 2.3s`, `✗ exit 1`) after the last line of its output, for the
+    /// summaries on `lines`.
+    ///
+    /// A summary that doesn't fit in the row after the line's text is left
+    /// out rather than wrapped.
+    pub fn command_annotations(&self, lines: Range<usize>) -> Vec<Annotation> {
+        let marks = self.command_marks.as_slice();
+        // A command's summary is never above its prompt
+        let end = marks.partition_point(|mark| mark.prompt_line < lines.end);
+        let mut annotations = Vec::new();
+        for mark in marks[..end].iter().rev() {
+            let Some(line) = mark.summary_line() else {
+                continue;
+            };
+            if line < lines.start {
+                break;
+            }
+            if line >= lines.end {
+                continue;
+            }
+            let (Some(summary), Some(styled)) = (mark.summary(), self.styled_line(line)) else {
+                continue;
+            };
+            let text = format!("  {}", summary);
+            let used = styled_line_to_plain(&styled).chars().count();
+            if used + text.chars().count() > self.size.0 {
+                continue;
+            }
+            let color = match mark.status() {
+                CommandStatus::Succeeded => Some(Color::Named(NamedColor::Green)),
+                CommandStatus::Failed => Some(Color::Named(NamedColor::Red)),
+                CommandStatus::Pending => None,
+            };
+            annotations.push(Annotation::trailing(line, text, color));
+        }
+        annotations
+    }
+
+    // Chunk: docs/chunks/quit_running_summary - Foreground job detection
+    /// Returns true if a program other than the shell holds the terminal.
+    ///
+    /// Compares the PTY's foreground process group with the child's process
+    /// ID. They differ while a command started from the shell is running, so
+    /// an idle shell at its prompt reports `false`.
+    pub fn has_foreground_job(&self) -> bool {
+        let Some(pty) = self.pty.as_ref() else {
+            return false;
+        };
+        match (pty.process_id(), pty.foreground_process_group()) {
+            (Some(pid), Some(pgid)) => pid != pgid,
+            _ => false,
+        }
+    }
+
     // =========================================================================
     // Selection Support
     // Chunk: docs/chunks/terminal_clipboard_selection - Selection state management
@@ -569,6 +1317,62 @@ impl TerminalBuffer {
         self.selection_head = None;
     }
 
+    // Chunk: docs/chunks/terminal_copy_mode - Copy mode cursor
+    /// Shows a copy mode cursor at `pos` in place of the terminal's cursor,
+    /// or restores the terminal's cursor with `None`.
+    ///
+    /// While a copy mode cursor is shown, PTY output no longer clears the
+    /// selection.
+    pub fn set_copy_cursor(&mut self, pos: Option<Position>) {
+        for line in [self.copy_cursor, pos].into_iter().flatten() {
+            self.dirty.merge(DirtyLines::Single(line.line));
+        }
+        self.copy_cursor = pos;
+    }
+
+    /// Returns the copy mode cursor, if copy mode is active.
+    pub fn copy_cursor(&self) -> Option<Position> {
+        self.copy_cursor
+    }
+
+    // Chunk: docs/chunks/terminal_copy_mode - The program's cursor, ignoring copy mode
+    /// Returns the cursor of the hosted program, in document coordinates.
+    fn terminal_cursor(&self) -> CursorInfo {
+        let grid = self.term.grid();
+        let cursor_point = grid.cursor.point;
+        let history_len = self.history_size();
+
+        // In alt screen, history_len is effectively 0 for cursor positioning
+        // and there's no cold scrollback
+        let doc_line = if self.is_alt_screen() {
+            cursor_point.line.0 as usize
+        } else {
+            // Add cold lines + hot history to viewport line
+            self.cold_line_count + history_len + cursor_point.line.0 as usize
+        };
+        let col = cursor_point.column.0;
+
+        // Map cursor shape from alacritty to our CursorShape.
+        // Mirror alacritty's RenderableCursor::new(): when SHOW_CURSOR mode is off
+        // (ESC[?25l), force Hidden regardless of the configured cursor style.
+        let cursor_style = self.term.cursor_style();
+        let shape = if !self.term.mode().contains(TermMode::SHOW_CURSOR) {
+            CursorShape::Hidden
+        } else {
+            match cursor_style.shape {
+                VteCursorShape::Block | VteCursorShape::HollowBlock => CursorShape::Block,
+                VteCursorShape::Underline => CursorShape::Underline,
+                VteCursorShape::Beam => CursorShape::Beam,
+                VteCursorShape::Hidden => CursorShape::Hidden,
+            }
+        };
+
+        // Check if cursor should blink
+        let blinking = cursor_style.blinking;
+
+        CursorInfo::new(Position::new(doc_line, col), shape, blinking)
+    }
+
     /// Returns the selection anchor, if any.
     pub fn selection_anchor(&self) -> Option<Position> {
         self.selection_anchor
@@ -579,23 +1383,34 @@ impl TerminalBuffer {
         self.selection_head
     }
 
+    // Chunk: docs/chunks/terminal_cold_selection - Selections reaching into cold scrollback
     /// Returns the selected text as a string.
     ///
     /// Extracts text from the terminal grid between the selection anchor and head.
     /// Multi-line selections are joined with newlines. Trailing spaces on each
-    /// line are trimmed (standard terminal behavior).
+    /// line are trimmed (standard terminal behavior). Lines in cold scrollback
+    /// are read back from disk, so a selection may span any part of the history.
     ///
     /// Returns `None` if there is no active selection.
     pub fn selected_text(&self) -> Option<String> {
         let (start, end) = self.selection_range()?;
+        let result = self.text_between(start, end);
+
+        if result.is_empty() {
+            None
+        } else {
+            Some(result)
+        }
+    }
 
+    // Chunk: docs/chunks/terminal_shell_integration - Shared with command output copying
+    /// Returns the text from `start` up to `end` (exclusive), with trailing
+    /// spaces trimmed from each line.
+    fn text_between(&self, start: Position, end: Position) -> String {
         let mut result = String::new();
 
-        for line_idx in start.line..=end.line {
-            let Some(styled_line) = self.styled_line(line_idx) else {
-                continue;
-            };
-
+        let lines = self.read_lines(start.line..end.line + 1);
+        for (line_idx, styled_line) in (start.line..).zip(lines) {
             // Convert styled line to plain text
             let line_text: String = styled_line.spans.iter()
                 .map(|span| span.text.as_str())
@@ -627,11 +1442,53 @@ impl TerminalBuffer {
             }
         }
 
-        if result.is_empty() {
-            None
-        } else {
-            Some(result)
+        result
+    }
+
+    // Chunk: docs/chunks/terminal_cold_selection - Bulk line reads for copying
+    /// Returns one styled line per document line in `lines`.
+    ///
+    /// Cold lines are read straight from the backing file rather than through
+    /// the page cache, so copying a long selection doesn't evict the pages
+    /// on screen. Lines that can't be read come back empty, keeping the line
+    /// structure of the result intact.
+    fn read_lines(&self, lines: Range<usize>) -> Vec<StyledLine> {
+        let mut result = Vec::with_capacity(lines.len());
+
+        if !self.is_alt_screen() && lines.start < self.cold_line_count {
+            let cold_end = lines.end.min(self.cold_line_count);
+            let cold_lines = self
+                .cold_scrollback
+                .borrow_mut()
+                .as_mut()
+                .and_then(|cold| cold.get_range(lines.start, cold_end - lines.start).ok())
+                .unwrap_or_default();
+            result.extend(cold_lines);
+            result.resize_with(cold_end - lines.start, StyledLine::empty);
         }
+
+        let hot_start = lines.start + result.len();
+        result.extend(
+            (hot_start..lines.end)
+                .map(|line| self.styled_line(line).unwrap_or_else(StyledLine::empty)),
+        );
+        result
+    }
+
+    // Chunk: docs/chunks/agent_input_patterns - Recent output for prompt detection
+    /// Returns the plain text of up to `lines` lines ending at the cursor line.
+    ///
+    /// Lines are joined with newlines and trailing spaces are trimmed, so
+    /// patterns anchored with `$` in multi-line mode match the end of a prompt.
+    pub fn text_near_cursor(&self, lines: usize) -> String {
+        let end = self.terminal_cursor().position.line + 1;
+        let start = end.saturating_sub(lines);
+
+        (start..end)
+            .filter_map(|line| self.styled_line(line))
+            .map(|styled| styled_line_to_plain(&styled))
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
     // =========================================================================
@@ -743,10 +1600,25 @@ impl TerminalBuffer {
         // Note: We track this separately because we can't actually remove
         // lines from alacritty's scrollback. This count represents how many
         // of the "oldest" lines from a logical perspective are in cold storage.
+        let old_cold_count = self.cold_line_count;
         self.cold_line_count += actual_count;
 
-        // Invalidate the page cache since line indices have shifted
-        self.page_cache.borrow_mut().invalidate();
+        // Chunk: docs/chunks/terminal_cold_selection - Only the last cold page goes stale
+        // Cold lines never move, so pages before the appended lines stay valid.
+        // Keeping them cached means a selection dragged into cold scrollback
+        // isn't re-read from disk every time output arrives.
+        self.page_cache.borrow_mut().invalidate_from(old_cold_count);
+
+        // Hot lines now start `actual_count` lines later in the document, so
+        // move the selection and copy cursor to stay on the same text.
+        let shift = |pos: &mut Position| {
+            if pos.line >= old_cold_count {
+                pos.line += actual_count;
+            }
+        };
+        self.selection_anchor.iter_mut().for_each(shift);
+        self.selection_head.iter_mut().for_each(shift);
+        self.copy_cursor.iter_mut().for_each(shift);
     }
 
     /// Returns the number of lines in cold storage.
@@ -762,11 +1634,29 @@ impl TerminalBuffer {
         self.page_cache.borrow_mut().get(line, cold).ok()
     }
 
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of a line range
+    /// Gets a range of lines from cold storage, borrowing the store and the
+    /// page cache once. Returns nothing if the lines can't be read.
+    fn get_cold_lines(&self, lines: Range<usize>) -> Vec<StyledLine> {
+        let mut cold_ref = self.cold_scrollback.borrow_mut();
+        let Some(cold) = cold_ref.as_mut() else {
+            return Vec::new();
+        };
+        self.page_cache.borrow_mut().get_range(lines, cold).unwrap_or_default()
+    }
+
     // Chunk: docs/chunks/terminal_file_backed_scrollback - Hot scrollback line retrieval
     /// Returns a styled line from the hot scrollback region.
     ///
     /// This handles lines in alacritty's in-memory scrollback and viewport.
     fn styled_line_hot(&self, line: usize) -> Option<StyledLine> {
+        self.styled_lines_hot(line..line + 1).pop()
+    }
+
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of a line range
+    /// Returns the styled lines of a range of the hot region, stopping at
+    /// its end. The grid and terminal mode are consulted once per range.
+    fn styled_lines_hot(&self, lines: Range<usize>) -> Vec<StyledLine> {
         let grid = self.term.grid();
         let cols = self.size.0;
         let history_len = grid.history_size();
@@ -844,28 +1734,28 @@ impl TerminalBuffer {
         //    - Logical line n corresponds to alacritty scrollback position (n - cold_line_count)
         //    - But we also need to account for alacritty's own indexing
 
-        if line < history_len {
-            // This line is in alacritty's scrollback
-            // Line 0 (after cold offset) = oldest line we haven't captured yet
-            // = alacritty scrollback index (history_len - 1 - line)
-            let scroll_idx = history_len - 1 - line;
-            let row = &grid[Line(-(scroll_idx as i32) - 1)];
-            let cells: Vec<_> = (0..cols)
-                .map(|col| &row[alacritty_terminal::index::Column(col)])
-                .collect();
-            Some(row_to_styled_line(cells.iter().copied(), cols, strip_inverse))
-        } else {
-            // This line is in the viewport
-            let viewport_line = line - history_len;
-            if viewport_line >= screen_lines {
-                return None;
-            }
-            let row = &grid[Line(viewport_line as i32)];
-            let cells: Vec<_> = (0..cols)
-                .map(|col| &row[alacritty_terminal::index::Column(col)])
-                .collect();
-            Some(row_to_styled_line(cells.iter().copied(), cols, strip_inverse))
-        }
+        lines
+            .map_while(|line| {
+                let row = if line < history_len {
+                    // This line is in alacritty's scrollback
+                    // Line 0 (after cold offset) = oldest line we haven't captured yet
+                    // = alacritty scrollback index (history_len - 1 - line)
+                    let scroll_idx = history_len - 1 - line;
+                    &grid[Line(-(scroll_idx as i32) - 1)]
+                } else {
+                    // This line is in the viewport
+                    let viewport_line = line - history_len;
+                    if viewport_line >= screen_lines {
+                        return None;
+                    }
+                    &grid[Line(viewport_line as i32)]
+                };
+                let cells: Vec<_> = (0..cols)
+                    .map(|col| &row[alacritty_terminal::index::Column(col)])
+                    .collect();
+                Some(row_to_styled_line(cells.iter().copied(), cols, strip_inverse))
+            })
+            .collect()
     }
 
     /// Returns a styled line from the alternate screen.
@@ -915,6 +1805,30 @@ impl BufferView for TerminalBuffer {
         self.styled_line_hot(hot_line)
     }
 
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of a line range
+    fn styled_lines(&self, lines: Range<usize>) -> Vec<StyledLine> {
+        if self.is_alt_screen() {
+            return lines.map_while(|line| self.styled_line_alt_screen(line)).collect();
+        }
+
+        // Cold lines come from one page cache walk, the rest from one pass
+        // over alacritty's grid
+        let end = lines.end.min(self.line_count());
+        let cold_end = end.min(self.cold_line_count);
+        let mut styled = Vec::new();
+        if lines.start < cold_end {
+            styled = self.get_cold_lines(lines.start..cold_end);
+            if styled.len() < cold_end - lines.start {
+                return styled;
+            }
+        }
+
+        let hot_start = lines.start.max(self.cold_line_count) - self.cold_line_count;
+        let hot_end = end.saturating_sub(self.cold_line_count);
+        styled.extend(self.styled_lines_hot(hot_start..hot_end));
+        styled
+    }
+
     fn line_len(&self, _line: usize) -> usize {
         // Terminal lines are always the terminal width
         self.size.0
@@ -931,43 +1845,11 @@ impl BufferView for TerminalBuffer {
     }
 
     fn cursor_info(&self) -> Option<CursorInfo> {
-        let grid = self.term.grid();
-        let cursor_point = grid.cursor.point;
-        let history_len = self.history_size();
-
-        // In alt screen, history_len is effectively 0 for cursor positioning
-        // and there's no cold scrollback
-        let doc_line = if self.is_alt_screen() {
-            cursor_point.line.0 as usize
-        } else {
-            // Add cold lines + hot history to viewport line
-            self.cold_line_count + history_len + cursor_point.line.0 as usize
-        };
-        let col = cursor_point.column.0;
-
-        // Map cursor shape from alacritty to our CursorShape.
-        // Mirror alacritty's RenderableCursor::new(): when SHOW_CURSOR mode is off
-        // (ESC[?25l), force Hidden regardless of the configured cursor style.
-        let cursor_style = self.term.cursor_style();
-        let shape = if !self.term.mode().contains(TermMode::SHOW_CURSOR) {
-            CursorShape::Hidden
-        } else {
-            match cursor_style.shape {
-                VteCursorShape::Block | VteCursorShape::HollowBlock => CursorShape::Block,
-                VteCursorShape::Underline => CursorShape::Underline,
-                VteCursorShape::Beam => CursorShape::Beam,
-                VteCursorShape::Hidden => CursorShape::Hidden,
-            }
-        };
-
-        // Check if cursor should blink
-        let blinking = cursor_style.blinking;
-
-        Some(CursorInfo::new(
-            Position::new(doc_line, col),
-            shape,
-            blinking,
-        ))
+        // Chunk: docs/chunks/terminal_copy_mode - Copy mode cursor replaces the terminal's
+        if let Some(pos) = self.copy_cursor {
+            return Some(CursorInfo::new(pos, CursorShape::Block, false));
+        }
+        Some(self.terminal_cursor())
     }
 
     // Chunk: docs/chunks/terminal_clipboard_selection - Selection range for rendering
@@ -994,16 +1876,36 @@ impl TerminalBuffer {
     /// Feeds raw bytes directly to the terminal emulator.
     ///
     /// This is a test helper that bypasses the PTY, allowing tests to verify
-    /// terminal state after processing escape sequences and text.
-    #[cfg(test)]
+    /// terminal state after processing escape sequences and text. Other
+    /// crates' tests get it through the `test-support` feature.
+    #[cfg(any(test, feature = "test-support"))]
     pub fn feed_bytes(&mut self, data: &[u8]) {
-        self.processor.advance(&mut self.term, data);
+        self.advance(data);
+        self.process_term_events();
         // Track mode transitions for consistency with poll_events()
         self.was_alt_screen = self.is_alt_screen();
         self.dirty = DirtyLines::FromLineToEnd(0);
     }
 }
 
+// Chunk: docs/chunks/pty_graceful_shutdown - Graceful shutdown of many terminals at once
+/// Shuts down the PTY processes of all `terminals` together.
+///
+/// Each process gets SIGHUP, then SIGTERM after half of `grace`, then SIGKILL
+/// once `grace` is up. The signals go to every terminal at once, so the whole
+/// call takes at most `grace` however many terminals there are, and returns
+/// as soon as they have all exited. Terminals without a PTY are skipped.
+pub fn shutdown_terminals<'a>(
+    terminals: impl IntoIterator<Item = &'a mut TerminalBuffer>,
+    grace: Duration,
+) {
+    let mut ptys: Vec<&mut PtyHandle> = terminals
+        .into_iter()
+        .filter_map(|terminal| terminal.pty.as_mut())
+        .collect();
+    shutdown_ptys(&mut ptys, grace);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1321,6 +2223,122 @@ mod tests {
         assert_eq!(cursor.position.col, 5, "Cursor should be at column 5 after typing 'Hello'");
     }
 
+    #[test]
+    fn test_text_near_cursor_ends_at_cursor_line() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(b"one\r\ntwo\r\nthree\r\nProceed? [y/n] ");
+
+        assert_eq!(terminal.text_near_cursor(2), "three\nProceed? [y/n]");
+        assert_eq!(terminal.text_near_cursor(10), "one\ntwo\nthree\nProceed? [y/n]");
+    }
+
+    // Chunk: docs/chunks/terminal_cwd_picker - OSC 7 working directory reports
+    #[test]
+    fn test_current_dir_follows_osc7_reports() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        assert_eq!(terminal.current_dir(), None);
+
+        terminal.feed_bytes(b"\x1b]7;file://localhost/tmp/project\x07$ ");
+        assert_eq!(terminal.current_dir(), Some(PathBuf::from("/tmp/project")));
+
+        // Reports from another host (e.g. inside ssh) are ignored
+        terminal.feed_bytes(b"\x1b]7;file://elsewhere.invalid/srv\x1b\\$ ");
+        assert_eq!(terminal.current_dir(), Some(PathBuf::from("/tmp/project")));
+
+        // The sequence itself is not displayed
+        assert_eq!(terminal.text_near_cursor(1), "$ $");
+    }
+
+    #[test]
+    fn test_osc133_marks_prompts_and_output() {
+        use crate::shell_integration::CommandStatus;
+
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(b"\x1b]133;A\x07$ ls\r\n");
+        terminal.feed_bytes(b"\x1b]133;C\x07a.txt\r\nb.txt\r\n\x1b]133;D;0\x07");
+        terminal.feed_bytes(b"\x1b]133;A\x07$ false\r\n\x1b]133;C\x07\x1b]133;D;1\x07");
+        terminal.feed_bytes(b"\x1b]133;A\x07$ ");
+
+        let marks = terminal.command_marks();
+        assert_eq!(marks.len(), 3);
+        assert_eq!(marks[0].prompt_line, 0);
+        assert_eq!(marks[0].status(), CommandStatus::Succeeded);
+        assert_eq!(marks[1].prompt_line, 3);
+        assert_eq!(marks[1].status(), CommandStatus::Failed);
+        assert_eq!(marks[2].prompt_line, 4);
+        assert_eq!(marks[2].status(), CommandStatus::Pending);
+
+        assert_eq!(terminal.previous_prompt(4), Some(3));
+        assert_eq!(terminal.next_prompt(0), Some(3));
+        assert_eq!(terminal.next_prompt(4), None);
+
+        // The most recent finished command printed nothing
+        assert_eq!(terminal.last_command_output(), Some(String::new()));
+    }
+
+    // Chunk: docs/chunks/agent_events - Structured agent events
+    #[test]
+    fn test_agent_events_are_collected_only_when_asked() {
+        use crate::agent_events::AgentEvent;
+
+        let event = b"\x1b]lite-edit-event;{\"type\":\"question\",\"text\":\"Deploy?\"}\x07";
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(event);
+        assert!(terminal.take_agent_events().is_empty());
+
+        terminal.set_collect_agent_events(true);
+        terminal.feed_bytes(b"working ");
+        terminal.feed_bytes(event);
+        terminal.feed_bytes(b"done");
+        let events = terminal.take_agent_events();
+        assert_eq!(
+            events,
+            [AgentEvent::Question {
+                text: "Deploy?".to_string()
+            }]
+        );
+        assert!(terminal.take_agent_events().is_empty());
+        // The event itself is not displayed
+        assert_eq!(terminal.text_near_cursor(1), "working done");
+    }
+
+    #[test]
+    fn test_command_annotations_follow_output() {
+        let mut terminal = TerminalBuffer::new(40, 24, 1000);
+        terminal.feed_bytes(b"\x1b]133;A\x07$ ls\r\n");
+        terminal.feed_bytes(b"\x1b]133;C\x07a.txt\r\n\x1b]133;D;0\x07");
+        terminal.feed_bytes(b"\x1b]133;A\x07$ false\r\n\x1b]133;C\x07\x1b]133;D;1\x07");
+        terminal.feed_bytes(b"\x1b]133;A\x07$ ");
+
+        let finished = terminal.take_finished_commands();
+        assert_eq!(finished.len(), 2);
+        assert!(terminal.take_finished_commands().is_empty());
+
+        let annotations = terminal.command_annotations(0..24);
+        let placed: Vec<_> = annotations
+            .iter()
+            .map(|a| (a.line, a.trailing.clone().unwrap()))
+            .collect();
+        // Newest first: the failed command's summary is on its command line
+        assert_eq!(placed[0], (2, "  ✗ exit 1".to_string()));
+        assert_eq!(placed[1].0, 1);
+        assert!(placed[1].1.starts_with("  ✓ "));
+        assert!(terminal.command_annotations(3..24).is_empty());
+    }
+
+    #[test]
+    fn test_last_command_output_spans_lines() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(b"\x1b]133;A\x07$ ls\r\n");
+        terminal.feed_bytes(b"\x1b]133;C\x07a.txt\r\nb.txt\r\n\x1b]133;D;0\x07");
+        terminal.feed_bytes(b"\x1b]133;A\x07$ ");
+
+        assert_eq!(
+            terminal.last_command_output().as_deref(),
+            Some("a.txt\nb.txt")
+        );
+    }
+
     #[test]
     fn test_cursor_position_after_newline() {
         let mut terminal = TerminalBuffer::new(80, 24, 1000);
@@ -1651,6 +2669,27 @@ mod tests {
         assert!(found, "INVERSE should be preserved when cursor is hidden");
     }
 
+    // Chunk: docs/chunks/alt_screen_scroll_passthrough - Sub-line scroll accumulation
+    #[test]
+    fn test_scroll_alt_screen_accumulates_partial_lines() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+
+        // Primary screen: scrolling is the viewport's job
+        assert!(!terminal.scroll_alt_screen(40.0, 16.0, 0, 0));
+        assert_eq!(terminal.alt_scroll_remainder_px, 0.0);
+
+        terminal.feed_bytes(b"\x1b[?1049h");
+        // Less than a line is kept for the next event
+        terminal.scroll_alt_screen(10.0, 16.0, 0, 0);
+        assert_eq!(terminal.alt_scroll_remainder_px, 10.0);
+        // Two small deltas add up to one line, with the rest carried over
+        terminal.scroll_alt_screen(10.0, 16.0, 0, 0);
+        assert_eq!(terminal.alt_scroll_remainder_px, 4.0);
+        // Reversing direction cancels the carried distance first
+        terminal.scroll_alt_screen(-20.0, 16.0, 0, 0);
+        assert_eq!(terminal.alt_scroll_remainder_px, 0.0);
+    }
+
     // =========================================================================
     // Alt Screen Mode Transition Tests
     // Chunk: docs/chunks/terminal_fullscreen_paint - Mode transition dirty tracking
@@ -1869,4 +2908,289 @@ mod tests {
             cold_count_second, cold_count_first
         );
     }
+
+    // Chunk: docs/chunks/terminal_memory_budget - Per-terminal memory report
+    #[test]
+    fn test_memory_usage_reports_grid_and_cold_scrollback() {
+        let mut terminal = TerminalBuffer::new(80, 24, 10000);
+        terminal.set_hot_scrollback_limit(50);
+        let fresh = terminal.memory_usage();
+        assert_eq!(fresh.grid_lines, 24);
+        assert_eq!(fresh.cold_lines, 0);
+        assert_eq!(fresh.cold_file_bytes, 0);
+        assert_eq!(fresh.total_bytes(), fresh.grid_bytes);
+
+        for i in 0..100 {
+            terminal.feed_bytes(format!("Line {:03}\r\n", i).as_bytes());
+        }
+        terminal.check_scrollback_overflow();
+        // Reading a cold line pages it into the cache
+        assert!(terminal.styled_line(0).is_some());
+
+        let usage = terminal.memory_usage();
+        assert!(usage.grid_lines > fresh.grid_lines);
+        assert!(usage.grid_bytes > fresh.grid_bytes);
+        assert_eq!(usage.cold_lines, terminal.cold_line_count());
+        assert!(usage.cold_file_bytes > 0);
+        assert!(usage.cold_index_bytes > 0);
+        assert!(usage.page_cache_bytes > 0);
+
+        // A zero budget empties the cache
+        terminal.set_page_cache_bytes(0);
+        assert_eq!(terminal.page_cache_bytes(), 0);
+        assert_eq!(terminal.memory_usage().page_cache_bytes, 0);
+    }
+
+    // =========================================================================
+    // Bell Tests
+    // Chunk: docs/chunks/terminal_bell_attention - Bell attention tracking
+    // =========================================================================
+
+    #[test]
+    fn test_bell_initially_not_pending() {
+        let terminal = TerminalBuffer::new(80, 24, 1000);
+        assert!(!terminal.has_pending_bell());
+    }
+
+    #[test]
+    fn test_bel_sets_pending_bell() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(b"done\x07");
+        assert!(terminal.has_pending_bell());
+    }
+
+    #[test]
+    fn test_plain_output_does_not_ring_bell() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(b"hello world\r\n");
+        assert!(!terminal.has_pending_bell());
+    }
+
+    #[test]
+    fn test_clear_bell_resets_pending() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(b"\x07");
+        terminal.clear_bell();
+        assert!(!terminal.has_pending_bell());
+
+        // A subsequent bell sets it again
+        terminal.feed_bytes(b"\x07");
+        assert!(terminal.has_pending_bell());
+    }
+
+    // =========================================================================
+    // Scrollback Export and Clear Tests
+    // Chunk: docs/chunks/terminal_scrollback_export
+    // =========================================================================
+
+    #[test]
+    fn test_dump_scrollback_plain() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(b"one\r\n\x1b[31mtwo\x1b[0m\r\n");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("scrollback.txt");
+        let written = terminal.dump_scrollback(&path, ScrollbackFormat::Plain).unwrap();
+
+        assert_eq!(written, 2);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "one\ntwo\n");
+    }
+
+    #[test]
+    fn test_dump_scrollback_ansi_preserves_color() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(b"\x1b[31mred\x1b[0m plain\r\n");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("scrollback.ans");
+        terminal.dump_scrollback(&path, ScrollbackFormat::Ansi).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, "\x1b[0;31mred\x1b[0m plain\n");
+    }
+
+    #[test]
+    fn test_dump_scrollback_includes_history() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        for i in 0..100 {
+            terminal.feed_bytes(format!("Line {:03}\r\n", i).as_bytes());
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("scrollback.txt");
+        let written = terminal.dump_scrollback(&path, ScrollbackFormat::Plain).unwrap();
+
+        assert_eq!(written, 100);
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with("Line 000\n"));
+        assert!(contents.ends_with("Line 099\n"));
+    }
+
+    #[test]
+    fn test_clear_scrollback_drops_history() {
+        let mut terminal = TerminalBuffer::new(80, 24, 10000);
+        terminal.set_hot_scrollback_limit(50);
+        for i in 0..200 {
+            terminal.feed_bytes(format!("Line {:03}\r\n", i).as_bytes());
+        }
+        terminal.check_scrollback_overflow();
+        assert!(terminal.cold_line_count() > 0);
+        assert!(terminal.line_count() > 24);
+
+        terminal.clear_scrollback();
+
+        assert_eq!(terminal.cold_line_count(), 0);
+        assert_eq!(terminal.line_count(), 24);
+        // The visible screen is preserved
+        let last_row: String = terminal.styled_line(22).unwrap().spans.iter()
+            .map(|s| s.text.as_str())
+            .collect();
+        assert_eq!(last_row.trim_end(), "Line 199");
+    }
+
+    // =========================================================================
+    // Cold Selection Tests
+    // Chunk: docs/chunks/terminal_cold_selection
+    // =========================================================================
+
+    #[test]
+    fn test_selected_text_reads_cold_scrollback() {
+        let mut terminal = TerminalBuffer::new(80, 24, 10000);
+        terminal.set_hot_scrollback_limit(50);
+        for i in 0..200 {
+            terminal.feed_bytes(format!("Line {:03}\r\n", i).as_bytes());
+        }
+        terminal.check_scrollback_overflow();
+        let cold = terminal.cold_line_count();
+        assert!(cold > 110);
+
+        terminal.set_selection_anchor(Position::new(100, 5));
+        terminal.set_selection_head(Position::new(102, 8));
+        assert_eq!(terminal.selected_text().unwrap(), "100\nLine 101\nLine 102");
+
+        // Across the cold/hot boundary, every line appears once, as displayed
+        terminal.set_selection_anchor(Position::new(cold - 2, 0));
+        terminal.set_selection_head(Position::new(cold + 2, 80));
+        let expected: Vec<String> = (cold - 2..=cold + 2)
+            .map(|line| styled_line_to_plain(&terminal.styled_line(line).unwrap()))
+            .collect();
+        assert_eq!(terminal.selected_text().unwrap(), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_selection_follows_lines_captured_to_cold_storage() {
+        let mut terminal = TerminalBuffer::new(80, 24, 10000);
+        terminal.set_hot_scrollback_limit(50);
+        for i in 0..40 {
+            terminal.feed_bytes(format!("Line {:03}\r\n", i).as_bytes());
+        }
+        assert_eq!(terminal.check_scrollback_overflow(), 0);
+
+        terminal.set_selection_anchor(Position::new(5, 0));
+        terminal.set_selection_head(Position::new(5, 8));
+        assert_eq!(terminal.selected_text().unwrap(), "Line 005");
+
+        for i in 40..100 {
+            terminal.feed_bytes(format!("Line {:03}\r\n", i).as_bytes());
+        }
+        let captured = terminal.check_scrollback_overflow();
+        assert!(captured > 0);
+
+        let moved_anchor = Position::new(5 + captured, 0);
+        assert_eq!(terminal.selection_anchor(), Some(moved_anchor));
+        assert_eq!(terminal.selected_text().unwrap(), "Line 005");
+    }
+
+    // Chunk: docs/chunks/styled_lines_batch - Batch fetch of a line range
+    #[test]
+    fn test_styled_lines_matches_styled_line_across_cold_boundary() {
+        let mut terminal = TerminalBuffer::new(80, 24, 10000);
+        terminal.set_hot_scrollback_limit(50);
+        for i in 0..200 {
+            terminal.feed_bytes(format!("\x1b[1mLine\x1b[0m {:03}\r\n", i).as_bytes());
+        }
+        terminal.check_scrollback_overflow();
+        let cold = terminal.cold_line_count();
+        let count = terminal.line_count();
+        assert!(cold > 0);
+
+        let single = |range: Range<usize>| -> Vec<StyledLine> {
+            range.map(|line| terminal.styled_line(line).unwrap()).collect()
+        };
+        assert_eq!(terminal.styled_lines(cold - 5..cold + 5), single(cold - 5..cold + 5));
+        assert_eq!(terminal.styled_lines(0..count), single(0..count));
+        // The range is cut off at the end of the buffer
+        assert_eq!(terminal.styled_lines(count - 2..count + 10).len(), 2);
+        assert!(terminal.styled_lines(count..count + 1).is_empty());
+    }
+
+    // =========================================================================
+    // Kitty Graphics Tests
+    // Chunk: docs/chunks/terminal_kitty_graphics
+    // =========================================================================
+
+    /// A 2x2 RGBA image transmitted and displayed with the given extra keys.
+    fn kitty_image(extra: &str) -> Vec<u8> {
+        // 16 bytes of RGBA pixel data, base64-encoded
+        format!("\x1b_Ga=T,f=32,s=2,v=2,q=2{};gICAgICAgICAgICAgICAgA==\x1b\\", extra).into_bytes()
+    }
+
+    #[test]
+    fn test_kitty_image_placed_at_cursor() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(b"ab\r\ncd");
+        terminal.feed_bytes(&kitty_image(",c=4,r=3"));
+
+        let placements = terminal.image_placements(0..24);
+        assert_eq!(placements.len(), 1);
+        let placement = &placements[0];
+        assert_eq!((placement.line, placement.col), (1, 2));
+        assert_eq!((placement.cols, placement.rows), (4, 3));
+        assert_eq!(placement.image.width, 2);
+
+        // The cursor moves to the image's last row, past its right edge
+        let cursor = terminal.cursor_info().unwrap().position;
+        assert_eq!((cursor.line, cursor.col), (3, 6));
+    }
+
+    #[test]
+    fn test_kitty_image_size_clamped_to_screen() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(&kitty_image(",c=4294967295,r=4294967295"));
+
+        let placements = terminal.image_placements(0..24);
+        assert_eq!(placements.len(), 1);
+        assert_eq!((placements[0].cols, placements[0].rows), (80, 24));
+    }
+
+    #[test]
+    fn test_kitty_image_cursor_movement_suppressed() {
+        let mut terminal = TerminalBuffer::new(80, 24, 1000);
+        terminal.feed_bytes(&kitty_image(",c=4,r=3,C=1"));
+        let cursor = terminal.cursor_info().unwrap().position;
+        assert_eq!((cursor.line, cursor.col), (0, 0));
+    }
+
+    #[test]
+    fn test_kitty_image_scrolls_with_output_and_clears() {
+        let mut terminal = TerminalBuffer::new(80, 10, 1000);
+        terminal.feed_bytes(&kitty_image(",r=1"));
+        for i in 0..20 {
+            terminal.feed_bytes(format!("\r\nLine {}", i).as_bytes());
+        }
+
+        // The placement keeps its buffer line as it scrolls into history
+        assert_eq!(terminal.image_placements(0..1).len(), 1);
+
+        // Clearing scrollback discards it along with its line
+        terminal.clear_scrollback();
+        assert!(terminal.image_placements(0..terminal.line_count()).is_empty());
+
+        // After a screen clear no image remains on the visible screen
+        terminal.feed_bytes(&kitty_image(",r=1"));
+        assert_eq!(terminal.image_placements(0..10).len(), 1);
+        terminal.feed_bytes(b"\x1b[H\x1b[2J");
+        let count = terminal.line_count();
+        assert!(terminal.image_placements(count - 10..count).is_empty());
+    }
 }
@@ -16,7 +16,7 @@ use alacritty_terminal::term::TermMode;
 
 // Chunk: docs/chunks/terminal_clipboard_selection - MouseEventKind import for selection
 use lite_edit_buffer::{BufferView, Position};
-use lite_edit_input::{Key, KeyEvent, MouseEvent, MouseEventKind, ScrollDelta};
+use lite_edit_input::{Key, KeyEvent, MouseButton, MouseEvent, MouseEventKind, ScrollDelta};
 
 use crate::input_encoder::InputEncoder;
 use crate::terminal_buffer::TerminalBuffer;
@@ -116,54 +116,32 @@ impl TerminalFocusTarget {
     ///   viewport should be scrolled. The actual viewport scrolling is handled by
     ///   EditorState since it owns the Viewport.
     ///
-    /// - **Alternate screen**: Encodes scroll as mouse wheel sequences and writes
-    ///   them to the PTY. Returns `ScrollAction::SentToPty` or `ScrollAction::None`.
+    /// - **Alternate screen**: Sends the scroll to the program as mouse wheel
+    ///   events, or as arrow keys if it doesn't use the mouse (see
+    ///   [`TerminalBuffer::scroll_alt_screen`]). Returns `ScrollAction::SentToPty`
+    ///   or `ScrollAction::None`.
     ///
     /// # Arguments
     ///
     /// * `delta` - The scroll delta in pixels
     /// * `mouse_col` - Optional column position for alternate screen encoding (defaults to 0)
     /// * `mouse_row` - Optional row position for alternate screen encoding (defaults to 0)
+    // Chunk: docs/chunks/alt_screen_scroll_passthrough - Arrow keys and sub-line accumulation
     pub fn handle_scroll(
         &mut self,
         delta: ScrollDelta,
         mouse_col: usize,
         mouse_row: usize,
     ) -> ScrollAction {
-        let terminal = self.terminal.borrow();
-        let is_alt_screen = terminal.is_alt_screen();
-        let modes = terminal.term_mode();
-        drop(terminal);
+        let mut terminal = self.terminal.borrow_mut();
 
-        if is_alt_screen {
+        if terminal.is_alt_screen() {
             // Alternate screen mode (vim, htop, less): send scroll to PTY
             let line_height = self.cell_size.1;
-            if line_height <= 0.0 {
-                return ScrollAction::None;
-            }
-
-            // Convert pixel delta to line count
-            // Use a threshold to avoid sending too many events for small deltas
-            let lines = (delta.dy as f32 / line_height).round() as i32;
-            if lines == 0 {
-                return ScrollAction::None;
-            }
-
-            let bytes = InputEncoder::encode_scroll(
-                lines,
-                mouse_col,
-                mouse_row,
-                &lite_edit_input::Modifiers::default(),
-                modes,
-            );
-
-            if bytes.is_empty() {
-                return ScrollAction::None;
-            }
-
-            match self.terminal.borrow_mut().write_input(&bytes) {
-                Ok(_) => ScrollAction::SentToPty,
-                Err(_) => ScrollAction::None,
+            if terminal.scroll_alt_screen(delta.dy as f32, line_height, mouse_col, mouse_row) {
+                ScrollAction::SentToPty
+            } else {
+                ScrollAction::None
             }
         } else {
             // Primary screen: let EditorState handle viewport scrolling
@@ -217,6 +195,11 @@ impl TerminalFocusTarget {
             };
         }
 
+        // Chunk: docs/chunks/mouse_buttons - Only the left button selects
+        if event.button != MouseButton::Left {
+            return false;
+        }
+
         // Mouse mode not active - handle selection
         let (col, row) = self.pixel_to_cell(event.position, view_origin);
         // Convert screen row to document line (accounting for viewport scroll)
@@ -258,6 +241,8 @@ impl TerminalFocusTarget {
                 }
                 true
             }
+            // Chunk: docs/chunks/cursor_position_history - Navigation events don't select
+            MouseEventKind::Back | MouseEventKind::Forward | MouseEventKind::Hover => false,
         }
     }
 
@@ -430,6 +415,7 @@ mod tests {
             position: (100.0, 200.0),
             modifiers: Modifiers::default(),
             click_count: 1,
+            button: MouseButton::Left,
         };
         // viewport_offset = 0, so row 12 (200/16) becomes doc line 12
         let result = target.handle_mouse(event, (0.0, 0.0), 0);
@@ -453,6 +439,7 @@ mod tests {
                 command: true,
                 ..Default::default()
             },
+            repeat: false,
         };
         let result = target.handle_key(event);
         assert!(!result);
@@ -536,6 +523,7 @@ mod tests {
             position: (50.0, 60.0),
             modifiers: Modifiers::default(),
             click_count: 1,
+            button: MouseButton::Left,
         };
         target.handle_mouse(event, (0.0, 0.0), 0);
 
@@ -555,6 +543,7 @@ mod tests {
             position: (50.0, 60.0),
             modifiers: Modifiers::default(),
             click_count: 1,
+            button: MouseButton::Left,
         };
         target.handle_mouse(down_event, (0.0, 0.0), 0);
 
@@ -564,6 +553,7 @@ mod tests {
             position: (150.0, 100.0), // col 15, row 5
             modifiers: Modifiers::default(),
             click_count: 0,
+            button: MouseButton::Left,
         };
         target.handle_mouse(move_event, (0.0, 0.0), 0);
 
@@ -583,6 +573,7 @@ mod tests {
             position: (50.0, 60.0),
             modifiers: Modifiers::default(),
             click_count: 1,
+            button: MouseButton::Left,
         };
         target.handle_mouse(down_event, (0.0, 0.0), 0);
 
@@ -592,6 +583,7 @@ mod tests {
             position: (50.0, 60.0),
             modifiers: Modifiers::default(),
             click_count: 0,
+            button: MouseButton::Left,
         };
         target.handle_mouse(up_event, (0.0, 0.0), 0);
 
@@ -613,6 +605,7 @@ mod tests {
             position: (50.0, 40.0), // col 5, screen row 2
             modifiers: Modifiers::default(),
             click_count: 1,
+            button: MouseButton::Left,
         };
         target.handle_mouse(event, (0.0, 0.0), 100);
 
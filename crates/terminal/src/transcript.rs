@@ -0,0 +1,428 @@
+// Chunk: docs/chunks/agent_transcript - Session transcript capture
+//! Transcript capture for terminal sessions.
+//!
+//! A [`Transcript`] tees everything a terminal's PTY produces (and optionally
+//! everything written to it) into files on disk, so an agent session can be
+//! audited or replayed after its terminal is gone.
+//!
+//! Files use the [asciicast v2] format: a JSON header line followed by one
+//! `[elapsed_seconds, code, data]` line per event, where `code` is `"o"` for
+//! output, `"i"` for input and `"r"` for a resize. They can be replayed with
+//! `asciinema play <file>`, or inspected with any JSON-lines tool.
+//!
+//! Each file is named `<name>-<UTC timestamp>.cast`. When a file grows past
+//! `max_file_bytes` a new one is started, and the oldest files with the same
+//! name prefix are deleted so at most `max_files` remain.
+//!
+//! [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Where and how a transcript is recorded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptConfig {
+    /// Directory the transcript files are written to. Created if missing.
+    pub dir: PathBuf,
+
+    /// Whether input written to the PTY is recorded alongside its output.
+    ///
+    /// Default: false (input may contain secrets typed at password prompts).
+    pub record_input: bool,
+
+    /// Size after which the current file is closed and a new one started.
+    ///
+    /// Default: 10 MiB.
+    pub max_file_bytes: u64,
+
+    /// Maximum number of files kept per name prefix; older ones are deleted.
+    ///
+    /// Default: 10.
+    pub max_files: usize,
+}
+
+impl TranscriptConfig {
+    /// Default rotation size.
+    pub const DEFAULT_MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
+
+    /// Default number of files kept.
+    pub const DEFAULT_MAX_FILES: usize = 10;
+
+    /// Creates a config that records output to files in `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            record_input: false,
+            max_file_bytes: Self::DEFAULT_MAX_FILE_BYTES,
+            max_files: Self::DEFAULT_MAX_FILES,
+        }
+    }
+
+    /// Sets whether input is recorded.
+    pub fn with_record_input(mut self, record_input: bool) -> Self {
+        self.record_input = record_input;
+        self
+    }
+
+    /// Sets the rotation size.
+    pub fn with_max_file_bytes(mut self, bytes: u64) -> Self {
+        self.max_file_bytes = bytes;
+        self
+    }
+
+    /// Sets the number of files kept.
+    pub fn with_max_files(mut self, files: usize) -> Self {
+        self.max_files = files;
+        self
+    }
+}
+
+/// An open transcript that terminal I/O is appended to.
+pub struct Transcript {
+    config: TranscriptConfig,
+    /// File name prefix, e.g. the agent command's name.
+    name: String,
+    /// Terminal size recorded in each file's header.
+    size: (usize, usize),
+    /// The file currently being written.
+    file: File,
+    path: PathBuf,
+    /// Timestamp in the current file's name; later files are named after it
+    /// even if the clock hasn't moved, so a pruned name is never reused.
+    stamp: u64,
+    bytes_written: u64,
+    /// Time origin for the current file's event timestamps.
+    started_at: Instant,
+    /// Trailing bytes of an incomplete UTF-8 sequence, per stream.
+    pending_output: Vec<u8>,
+    pending_input: Vec<u8>,
+}
+
+impl Transcript {
+    /// Creates the transcript directory and opens the first file.
+    ///
+    /// `name` is the file name prefix; characters other than ASCII
+    /// alphanumerics, `-` and `_` are replaced with `_`. `cols`/`rows` is the
+    /// terminal size recorded in the header.
+    pub fn create(config: TranscriptConfig, name: &str, cols: usize, rows: usize) -> io::Result<Self> {
+        let name = sanitize_name(name);
+        fs::create_dir_all(&config.dir)?;
+        let (file, path, stamp) = open_new_file(&config.dir, &name, 0)?;
+
+        let mut transcript = Self {
+            config,
+            name,
+            size: (cols, rows),
+            file,
+            path,
+            stamp,
+            bytes_written: 0,
+            started_at: Instant::now(),
+            pending_output: Vec::new(),
+            pending_input: Vec::new(),
+        };
+        transcript.write_header()?;
+        transcript.prune()?;
+        Ok(transcript)
+    }
+
+    /// Returns the path of the file currently being written.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns whether input is recorded.
+    pub fn records_input(&self) -> bool {
+        self.config.record_input
+    }
+
+    /// Appends PTY output.
+    pub fn record_output(&mut self, data: &[u8]) -> io::Result<()> {
+        let text = take_utf8(&mut self.pending_output, data);
+        self.write_event("o", &text)
+    }
+
+    /// Appends input written to the PTY. Does nothing unless input recording
+    /// is enabled.
+    pub fn record_input(&mut self, data: &[u8]) -> io::Result<()> {
+        if !self.config.record_input {
+            return Ok(());
+        }
+        let text = take_utf8(&mut self.pending_input, data);
+        self.write_event("i", &text)
+    }
+
+    /// Records a terminal resize.
+    pub fn record_resize(&mut self, cols: usize, rows: usize) -> io::Result<()> {
+        self.size = (cols, rows);
+        self.write_event("r", &format!("{}x{}", cols, rows))
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let header = format!(
+            "{{\"version\":2,\"width\":{},\"height\":{},\"timestamp\":{},\"title\":{}}}\n",
+            self.size.0,
+            self.size.1,
+            timestamp,
+            json_string(&self.name)
+        );
+        self.append(header.as_bytes())
+    }
+
+    fn write_event(&mut self, code: &str, data: &str) -> io::Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        if self.bytes_written >= self.config.max_file_bytes {
+            self.rotate()?;
+        }
+        let elapsed = self.started_at.elapsed().as_secs_f64();
+        let line = format!("[{:.6},\"{}\",{}]\n", elapsed, code, json_string(data));
+        self.append(line.as_bytes())
+    }
+
+    fn append(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.file.write_all(bytes)?;
+        self.bytes_written += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Closes the current file and starts a new one.
+    fn rotate(&mut self) -> io::Result<()> {
+        let (file, path, stamp) = open_new_file(&self.config.dir, &self.name, self.stamp + 1)?;
+        self.file = file;
+        self.path = path;
+        self.stamp = stamp;
+        self.bytes_written = 0;
+        self.started_at = Instant::now();
+        self.write_header()?;
+        self.prune()
+    }
+
+    /// Deletes the oldest files with this transcript's prefix beyond `max_files`.
+    fn prune(&self) -> io::Result<()> {
+        let prefix = format!("{}-", self.name);
+        let mut files: Vec<PathBuf> = fs::read_dir(&self.config.dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|n| n.to_str())
+                    .and_then(|n| n.strip_prefix(&prefix))
+                    // The timestamp follows directly, so "claude-code-..." isn't
+                    // mistaken for one of "claude"'s files
+                    .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()) && rest.ends_with(".cast"))
+            })
+            .collect();
+        // Timestamped names sort chronologically
+        files.sort();
+
+        let keep = self.config.max_files.max(1);
+        let excess = files.len().saturating_sub(keep);
+        for path in &files[..excess] {
+            if *path != self.path {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Opens a new, uniquely named transcript file in `dir`, stamped with the
+/// current time or `min_millis`, whichever is later.
+///
+/// Returns the file, its path and the stamp used in its name.
+fn open_new_file(dir: &Path, name: &str, min_millis: u64) -> io::Result<(File, PathBuf, u64)> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    let mut millis = (now.as_millis() as u64).max(min_millis);
+    loop {
+        let path = dir.join(format!("{}-{}.cast", name, format_utc_millis(millis)));
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((file, path, millis)),
+            // Another file was started within the same millisecond
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => millis += 1,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Replaces characters that are awkward in file names.
+fn sanitize_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    if name.is_empty() {
+        "session".to_string()
+    } else {
+        name
+    }
+}
+
+/// Appends `data` to `pending` and returns the longest valid UTF-8 prefix,
+/// leaving an incomplete trailing sequence in `pending` for the next chunk.
+///
+/// Invalid bytes are replaced with U+FFFD.
+pub(crate) fn take_utf8(pending: &mut Vec<u8>, data: &[u8]) -> String {
+    pending.extend_from_slice(data);
+    let valid_up_to = match std::str::from_utf8(pending) {
+        Ok(_) => pending.len(),
+        // error_len() is None when the input ends mid-sequence
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        Err(_) => pending.len(),
+    };
+    let text = String::from_utf8_lossy(&pending[..valid_up_to]).into_owned();
+    pending.drain(..valid_up_to);
+    text
+}
+
+/// Formats `s` as a JSON string literal.
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 || c == '\u{7f}' => {
+                out.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Formats milliseconds since the Unix epoch as `YYYYMMDDTHHMMSS.mmmZ`.
+fn format_utc_millis(millis: u64) -> String {
+    let secs = millis / 1000;
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let rem = secs % 86_400;
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        (rem % 3600) / 60,
+        rem % 60,
+        millis % 1000
+    )
+}
+
+/// Converts days since 1970-01-01 to a (year, month, day) civil date.
+///
+/// Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_lines(path: &Path) -> Vec<String> {
+        fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_records_output_as_asciicast() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut transcript =
+            Transcript::create(TranscriptConfig::new(dir.path()), "claude", 80, 24).unwrap();
+
+        transcript.record_output(b"hello \"world\"\r\n\x1b[1m").unwrap();
+        transcript.record_input(b"secret\r").unwrap();
+        transcript.record_resize(100, 30).unwrap();
+
+        let name = transcript.path().file_name().unwrap().to_str().unwrap();
+        assert!(name.starts_with("claude-") && name.ends_with("Z.cast"), "{}", name);
+
+        let lines = read_lines(transcript.path());
+        assert_eq!(lines.len(), 3, "input is not recorded by default: {:?}", lines);
+        assert!(lines[0].starts_with("{\"version\":2,\"width\":80,\"height\":24,"));
+        assert!(lines[1].ends_with(",\"o\",\"hello \\\"world\\\"\\r\\n\\u001b[1m\"]"));
+        assert!(lines[2].ends_with(",\"r\",\"100x30\"]"));
+    }
+
+    #[test]
+    fn test_records_input_when_enabled() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = TranscriptConfig::new(dir.path()).with_record_input(true);
+        let mut transcript = Transcript::create(config, "aider", 80, 24).unwrap();
+
+        transcript.record_input(b"y\r").unwrap();
+
+        let lines = read_lines(transcript.path());
+        assert!(lines[1].ends_with(",\"i\",\"y\\r\"]"));
+    }
+
+    #[test]
+    fn test_utf8_split_across_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut transcript =
+            Transcript::create(TranscriptConfig::new(dir.path()), "agent", 80, 24).unwrap();
+
+        let bytes = "→".as_bytes();
+        transcript.record_output(&bytes[..1]).unwrap();
+        transcript.record_output(&bytes[1..]).unwrap();
+
+        let lines = read_lines(transcript.path());
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].ends_with(",\"o\",\"→\"]"));
+    }
+
+    #[test]
+    fn test_rotation_keeps_newest_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = TranscriptConfig::new(dir.path())
+            .with_max_file_bytes(1)
+            .with_max_files(2);
+        let mut transcript = Transcript::create(config, "codex", 80, 24).unwrap();
+
+        let mut paths = vec![transcript.path().to_path_buf()];
+        for i in 0..3 {
+            transcript.record_output(format!("chunk {}", i).as_bytes()).unwrap();
+            paths.push(transcript.path().to_path_buf());
+        }
+        paths.dedup();
+        assert_eq!(paths.len(), 4, "each event past the limit starts a new file");
+
+        // Only the newest two files survive, and each starts with a header
+        assert!(!paths[0].exists() && !paths[1].exists());
+        for path in &paths[2..] {
+            assert!(read_lines(path)[0].starts_with("{\"version\":2"));
+        }
+        let count = fs::read_dir(dir.path()).unwrap().count();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_format_utc_millis() {
+        assert_eq!(format_utc_millis(0), "19700101T000000.000Z");
+        // 2024-02-29 12:34:56.789 UTC
+        assert_eq!(format_utc_millis(1_709_210_096_789), "20240229T123456.789Z");
+    }
+}
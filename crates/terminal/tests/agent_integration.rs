@@ -7,7 +7,9 @@ use std::path::PathBuf;
 use std::thread;
 use std::time::Duration;
 
-use lite_edit_terminal::{AgentConfig, AgentHandle, AgentState, BufferView};
+use lite_edit_terminal::{
+    AgentConfig, AgentEvent, AgentHandle, AgentState, BufferView, TranscriptConfig,
+};
 
 /// Creates a test config with short timeouts for faster tests.
 fn test_config(command: &str, args: &[&str]) -> AgentConfig {
@@ -17,6 +19,11 @@ fn test_config(command: &str, args: &[&str]) -> AgentConfig {
         cwd: PathBuf::from("/tmp"),
         needs_input_timeout: Duration::from_millis(100),
         stale_timeout: Duration::from_millis(200),
+        input_patterns: Vec::new(),
+        transcript: None,
+        structured_events: false,
+        env: Vec::new(),
+        initial_prompt: None,
     }
 }
 
@@ -306,6 +313,91 @@ fn test_agent_resize() {
     let _ = agent.stop();
 }
 
+// Chunk: docs/chunks/agent_transcript - Transcript capture integration test
+#[test]
+fn test_agent_transcript_records_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = test_config("echo", &["transcript-marker"])
+        .with_transcript(TranscriptConfig::new(dir.path()));
+    let mut agent = AgentHandle::spawn(config, 80, 24).unwrap();
+
+    for _ in 0..100 {
+        agent.poll();
+        if agent.state().is_terminal() {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    // Drain any output that arrived alongside the exit
+    agent.poll();
+
+    let path = agent.terminal().transcript().unwrap().path().to_path_buf();
+    assert!(path.file_name().unwrap().to_str().unwrap().starts_with("echo-"));
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(contents.starts_with("{\"version\":2,\"width\":80,\"height\":24,"));
+    assert!(
+        contents.contains("transcript-marker"),
+        "transcript should contain the agent's output: {}",
+        contents
+    );
+}
+
+// Chunk: docs/chunks/agent_events - Structured events integration test
+#[test]
+fn test_agent_structured_events_reach_the_timeline() {
+    let script =
+        r#"printf '\033]lite-edit-event;{"type":"question","text":"Deploy?"}\007'; sleep 5"#;
+    let config = test_config("sh", &["-c", script]).with_structured_events();
+    let mut agent = AgentHandle::spawn(config, 80, 24).unwrap();
+
+    for _ in 0..100 {
+        agent.poll();
+        if !agent.timeline().is_empty() {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    let entry = agent.timeline().entries().next().expect("no event arrived");
+    assert_eq!(
+        entry.event,
+        AgentEvent::Question {
+            text: "Deploy?".to_string()
+        }
+    );
+    assert!(matches!(agent.state(), AgentState::NeedsInput { .. }));
+
+    // Far past the needs-input timeout, silence still doesn't change it
+    thread::sleep(Duration::from_millis(150));
+    agent.poll();
+    assert!(matches!(agent.state(), AgentState::NeedsInput { .. }));
+    let _ = agent.stop();
+}
+
+// Chunk: docs/chunks/agent_templates - Environment and first prompt integration test
+#[test]
+fn test_agent_env_and_initial_prompt() {
+    let script = r#"read line; echo "answer=$GREETING-$line"; sleep 5"#;
+    let config = test_config("sh", &["-c", script])
+        .with_env("GREETING", "hello")
+        .with_initial_prompt("world");
+    let mut agent = AgentHandle::spawn(config, 80, 24).unwrap();
+
+    // Silent at start, so the prompt goes in once the agent counts as waiting
+    let mut text = String::new();
+    for _ in 0..200 {
+        agent.poll();
+        text = agent.terminal().text_near_cursor(4);
+        if text.contains("answer=") {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert!(text.contains("answer=hello-world"), "{}", text);
+    let _ = agent.stop();
+}
+
 // =============================================================================
 // Config Tests
 // =============================================================================
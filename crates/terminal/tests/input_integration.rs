@@ -105,6 +105,7 @@ fn test_ctrl_c_sends_interrupt() {
             control: true,
             ..Default::default()
         },
+        repeat: false,
     };
     target.handle_key(event);
 
@@ -219,6 +220,7 @@ fn test_arrow_keys_send_escape_sequences() {
             control: true,
             ..Default::default()
         },
+        repeat: false,
     };
     let encoded_ctrl = InputEncoder::encode_key(&ctrl_right, TermMode::NONE);
     assert_eq!(encoded_ctrl, b"\x1b[1;5C", "Ctrl+Right should be ESC [ 1 ; 5 C");
@@ -279,6 +281,7 @@ fn test_ctrl_d_sends_eof() {
             control: true,
             ..Default::default()
         },
+        repeat: false,
     };
     target.handle_key(event);
 
@@ -370,6 +373,7 @@ fn test_alt_backspace_deletes_word() {
             option: true,
             ..Default::default()
         },
+        repeat: false,
     };
     target.handle_key(alt_backspace);
 
@@ -413,6 +417,7 @@ fn test_cmd_backspace_deletes_to_line_start() {
             command: true,
             ..Default::default()
         },
+        repeat: false,
     };
     target.handle_key(cmd_backspace);
 
@@ -162,6 +162,23 @@ fn test_command_with_args() {
     assert!(found, "Expected 'test args' in terminal output");
 }
 
+// Chunk: docs/chunks/render_stats_hud - PTY throughput
+/// Test that processed program output is counted.
+#[test]
+fn test_output_bytes_counts_program_output() {
+    let mut term = TerminalBuffer::new(80, 24, 1000);
+    assert_eq!(term.output_bytes(), 0);
+    term.spawn_command("printf", &["hello world"], Path::new("/tmp"))
+        .unwrap();
+
+    for _ in 0..10 {
+        std::thread::sleep(Duration::from_millis(50));
+        term.poll_events();
+    }
+
+    assert!(term.output_bytes() >= "hello world".len() as u64);
+}
+
 // =============================================================================
 // File-backed scrollback tests
 // Chunk: docs/chunks/terminal_file_backed_scrollback - File-backed cold scrollback
@@ -1283,3 +1300,54 @@ fn test_dsr_cursor_position_report() {
         all_text.lines().take(30).collect::<Vec<_>>().join("\n")
     );
 }
+
+// =============================================================================
+// Session recording tests
+// Chunk: docs/chunks/terminal_replay - Timestamped session recording and replay
+// =============================================================================
+
+/// Test that a spawned command's output is recorded, replays, and exports.
+#[test]
+fn test_command_output_recorded_for_replay() {
+    use lite_edit_terminal::TerminalReplay;
+
+    let mut term = TerminalBuffer::new(80, 24, 1000);
+    term.spawn_command("echo", &["recorded"], Path::new("/tmp"))
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(100));
+    term.poll_events();
+
+    let snapshot = term.recording().unwrap().snapshot().unwrap();
+    assert!(snapshot.len_bytes() > 0);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.cast");
+    snapshot.export_asciicast(&path, "echo").unwrap();
+    let cast = std::fs::read_to_string(&path).unwrap();
+    assert!(cast.starts_with("{\"version\":2,\"width\":80,\"height\":24,"));
+    assert!(cast.contains("recorded"));
+
+    let mut replay = TerminalReplay::new(snapshot);
+    let end = replay.duration();
+    replay.seek(end).unwrap();
+    let text: String = (0..replay.line_count())
+        .filter_map(|line| replay.styled_line(line))
+        .flat_map(|line| line.spans.into_iter().map(|s| s.text))
+        .collect();
+    assert!(text.contains("recorded"), "Expected 'recorded' in replay");
+}
+
+/// Test that recording can be turned off before or after spawning.
+#[test]
+fn test_recording_disabled() {
+    let mut term = TerminalBuffer::new(80, 24, 1000);
+    term.set_recording_max_bytes(0);
+    term.spawn_command("true", &[], Path::new("/tmp")).unwrap();
+    assert!(term.recording().is_none());
+
+    let mut term = TerminalBuffer::new(80, 24, 1000);
+    term.spawn_command("true", &[], Path::new("/tmp")).unwrap();
+    assert!(term.recording().is_some());
+    term.set_recording_max_bytes(0);
+    assert!(term.recording().is_none());
+}
@@ -11,6 +11,8 @@ PROJECT_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
 
 # Paths
 BINARY="${PROJECT_ROOT}/target/release/lite-edit"
+# Chunk: docs/chunks/cli_launcher - The `lite` command ships inside the bundle
+CLI_BINARY="${PROJECT_ROOT}/target/release/lite"
 ICNS="${PROJECT_ROOT}/target/LiteEdit.icns"
 INFO_PLIST="${PROJECT_ROOT}/resources/Info.plist"
 
@@ -27,6 +29,12 @@ if [[ ! -f "$BINARY" ]]; then
     exit 1
 fi
 
+if [[ ! -f "$CLI_BINARY" ]]; then
+    echo "Error: lite command not found at $CLI_BINARY"
+    echo "Run 'cargo build --release' first."
+    exit 1
+fi
+
 if [[ ! -f "$ICNS" ]]; then
     echo "Error: Icon not found at $ICNS"
     echo "Run 'scripts/make-icns.sh' first."
@@ -49,6 +57,8 @@ mkdir -p "$RESOURCES_DIR"
 echo "  Copying binary..."
 cp "$BINARY" "$MACOS_DIR/lite-edit"
 chmod +x "$MACOS_DIR/lite-edit"
+cp "$CLI_BINARY" "$MACOS_DIR/lite"
+chmod +x "$MACOS_DIR/lite"
 
 # Copy the icon
 echo "  Copying icon..."